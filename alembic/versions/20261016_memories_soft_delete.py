@@ -0,0 +1,30 @@
+"""Add soft delete (trash) support to memories.
+
+Revision ID: 20261016_soft_delete
+Revises: 9ee276de27bb
+Create Date: 2026-10-16 10:00:00.000000
+"""
+from alembic import op
+import sqlalchemy as sa
+
+# revision identifiers, used by Alembic.
+revision = '20261016_soft_delete'
+down_revision = '9ee276de27bb'
+branch_labels = None
+depends_on = None
+
+
+def upgrade():
+    op.add_column("memories", sa.Column("deleted_at", sa.DateTime(), nullable=True))
+    # Only trashed rows are indexed; purge_deleted scans by deleted_at
+    op.create_index(
+        "idx_memories_deleted_at",
+        "memories",
+        ["deleted_at"],
+        postgresql_where=sa.text("deleted_at IS NOT NULL"),
+    )
+
+
+def downgrade():
+    op.drop_index("idx_memories_deleted_at", table_name="memories")
+    op.drop_column("memories", "deleted_at")
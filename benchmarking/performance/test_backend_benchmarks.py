@@ -0,0 +1,229 @@
+import asyncio
+import os
+import random
+import statistics
+from dataclasses import dataclass
+from typing import Any
+from uuid import UUID
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+
+# Default matrix is every backend at 10k, 100k and 1M memories (and as many
+# graph edges); lower it for quick runs, e.g. RAE_BENCH_SCALES=10000
+SCALES = [
+    int(s) for s in os.getenv("RAE_BENCH_SCALES", "10000,100000,1000000").split(",")
+]
+BACKENDS = os.getenv("RAE_BENCH_BACKENDS", "memory,sqlite").split(",")
+# Timed calls per benchmark; the latency percentiles are taken over them
+ROUNDS = int(os.getenv("RAE_BENCH_ROUNDS", "200"))
+SEED = 42
+TENANT = "bench"
+AGENT = "bench-agent"
+DIMENSION = 64
+BATCH = 1000
+LAYERS = ("working", "episodic", "semantic")
+# Vocabulary of the synthetic contents (and of the keyword queries)
+WORDS = tuple(
+    "invoice deploy incident billing latency database rollback customer payment "
+    "cluster backup schema release outage refund queue cache index token "
+    "migration alert report contract budget".split()
+)
+
+
+@dataclass
+class Backends:
+    storage: Any
+    vectors: Any
+    graph: Any
+    nodes: list[UUID]
+
+
+def _content(rng):
+    return " ".join(rng.choices(WORDS, k=8))
+
+
+def _vector(rng):
+    return [rng.uniform(-1.0, 1.0) for _ in range(DIMENSION)]
+
+
+def _open(name, path):
+    if name == "memory":
+        storage = InMemoryStorage(history_limit=0)
+        return storage, storage, InMemoryGraphStore()
+    if name == "sqlite":
+        from rae_core.adapters.sqlite.graph import SQLiteGraphStore
+        from rae_core.adapters.sqlite.storage import SQLiteStorage
+        from rae_core.adapters.sqlite.vector import SQLiteVectorStore
+
+        db_path = str(path / "bench.db")
+        return (
+            SQLiteStorage(db_path, history_limit=0),
+            SQLiteVectorStore(db_path),
+            SQLiteGraphStore(db_path),
+        )
+    raise ValueError(f"Unknown benchmark backend: {name}")
+
+
+async def _populate(storage, vectors, graph, scale):
+    """
+    Stores the synthetic dataset of a scale.
+
+    Contents, vectors and edges come from one seeded generator, so every
+    backend is measured on the same data and runs are reproducible.
+    """
+    rng = random.Random(SEED)
+    pending = []
+    for _ in range(scale):
+        memory_id = await storage.store_memory(
+            content=_content(rng),
+            tenant_id=TENANT,
+            agent_id=AGENT,
+            layer=rng.choice(LAYERS),
+            tags=[rng.choice(WORDS)],
+        )
+        pending.append((memory_id, _vector(rng), {"agent_id": AGENT}))
+        if len(pending) == BATCH:
+            await vectors.batch_store_vectors(pending, TENANT)
+            pending = []
+    if pending:
+        await vectors.batch_store_vectors(pending, TENANT)
+
+    nodes = [UUID(int=rng.getrandbits(128)) for _ in range(max(scale // 10, 2))]
+    for node in nodes:
+        await graph.create_node(node, "entity", TENANT)
+    for _ in range(scale):
+        source, target = rng.sample(nodes, 2)
+        await graph.create_edge(source, target, "related", TENANT, rng.random())
+    return nodes
+
+
+def _report_latency(benchmark):
+    """
+    Adds latency percentiles (ms) and throughput (ops/s) to the report.
+    """
+    data = benchmark.stats.stats.data
+    cuts = statistics.quantiles(data, n=100, method="inclusive")
+    for p in (50, 95, 99):
+        benchmark.extra_info[f"p{p}_ms"] = round(cuts[p - 1] * 1000, 3)
+    benchmark.extra_info["ops_per_s"] = round(len(data) / sum(data), 1)
+
+
+@pytest.fixture(scope="module")
+def loop():
+    """
+    One event loop for the module: contended locks stay bound to it.
+    """
+    loop = asyncio.new_event_loop()
+    yield loop
+    loop.close()
+
+
+@pytest.fixture(scope="module", params=BACKENDS)
+def backend(request):
+    return request.param
+
+
+@pytest.fixture(scope="module", params=SCALES, ids=lambda s: f"{s // 1000}k")
+def scale(request):
+    return request.param
+
+
+@pytest.fixture(scope="module")
+def backends(loop, backend, scale, tmp_path_factory):
+    """
+    Builds a backend holding the dataset of a scale.
+    """
+    storage, vectors, graph = _open(backend, tmp_path_factory.mktemp(backend))
+    nodes = loop.run_until_complete(_populate(storage, vectors, graph, scale))
+    yield Backends(storage, vectors, graph, nodes)
+    for store in {id(s): s for s in (storage, vectors, graph)}.values():
+        close = getattr(store, "close", None)
+        if close is not None:
+            loop.run_until_complete(close())
+
+
+@pytest.mark.performance
+def test_full_text_search(benchmark, loop, backends):
+    """
+    Benchmarks a keyword search of the agent's memories.
+    """
+    rng = random.Random(SEED)
+
+    def run():
+        return loop.run_until_complete(
+            backends.storage.search_memories(
+                rng.choice(WORDS), TENANT, AGENT, limit=10
+            )
+        )
+
+    result = benchmark.pedantic(run, rounds=ROUNDS, iterations=1, warmup_rounds=3)
+    _report_latency(benchmark)
+
+    assert len(result) <= 10
+
+
+@pytest.mark.performance
+def test_vector_search(benchmark, loop, backends):
+    """
+    Benchmarks a top-10 similarity search over the tenant's vectors.
+    """
+    rng = random.Random(SEED)
+
+    def run():
+        return loop.run_until_complete(
+            backends.vectors.search_similar(_vector(rng), TENANT, limit=10)
+        )
+
+    result = benchmark.pedantic(run, rounds=ROUNDS, iterations=1, warmup_rounds=3)
+    _report_latency(benchmark)
+
+    assert len(result) == 10
+
+
+@pytest.mark.performance
+@pytest.mark.parametrize("depth", [1, 2])
+def test_traversal(benchmark, loop, backends, depth):
+    """
+    Benchmarks neighbor expansion from a random node.
+    """
+    rng = random.Random(SEED)
+
+    def run():
+        return loop.run_until_complete(
+            backends.graph.get_neighbors(
+                rng.choice(backends.nodes), TENANT, max_depth=depth
+            )
+        )
+
+    result = benchmark.pedantic(run, rounds=ROUNDS, iterations=1, warmup_rounds=3)
+    _report_latency(benchmark)
+
+    assert isinstance(result, list)
+
+
+@pytest.mark.performance
+def test_store(benchmark, loop, backends):
+    """
+    Benchmarks storing one memory and its vector into the populated backend.
+
+    Runs last for each backend and scale: it grows the dataset by ROUNDS
+    memories.
+    """
+    rng = random.Random(SEED + 1)
+
+    async def store():
+        memory_id = await backends.storage.store_memory(
+            content=_content(rng), tenant_id=TENANT, agent_id=AGENT, layer="episodic"
+        )
+        await backends.vectors.store_vector(memory_id, _vector(rng), TENANT)
+        return memory_id
+
+    memory_id = benchmark.pedantic(
+        lambda: loop.run_until_complete(store()), rounds=ROUNDS, iterations=1
+    )
+    _report_latency(benchmark)
+
+    assert memory_id is not None
@@ -0,0 +1,107 @@
+import asyncio
+import os
+import random
+from uuid import UUID
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+
+TENANT = "bench"
+# Default scale is one tenant with 1M edges; lower it for quick local runs
+EDGE_COUNT = int(os.getenv("RAE_BENCH_GRAPH_EDGES", "1000000"))
+NODE_COUNT = max(EDGE_COUNT // 10, 2)
+
+
+@pytest.fixture(scope="module")
+def graph():
+    """
+    Builds a random graph with EDGE_COUNT edges over NODE_COUNT nodes.
+    """
+    rng = random.Random(42)
+    nodes = [UUID(int=rng.getrandbits(128)) for _ in range(NODE_COUNT)]
+    store = InMemoryGraphStore()
+
+    async def build():
+        for _ in range(EDGE_COUNT):
+            source, target = rng.sample(nodes, 2)
+            await store.create_edge(source, target, "related", TENANT, rng.random())
+
+    asyncio.run(build())
+    return store, nodes, rng
+
+
+@pytest.fixture(scope="module")
+def snapshot(graph):
+    store, _, _ = graph
+    return asyncio.run(store.snapshot(TENANT))
+
+
+@pytest.mark.performance
+@pytest.mark.parametrize("depth", [1, 2])
+def test_get_neighbors(benchmark, graph, depth):
+    """
+    Benchmarks bounded neighbor expansion from a random node.
+    """
+    store, nodes, rng = graph
+
+    def run():
+        return asyncio.run(
+            store.get_neighbors(rng.choice(nodes), TENANT, max_depth=depth)
+        )
+
+    result = benchmark(run)
+
+    assert isinstance(result, list)
+
+
+@pytest.mark.performance
+def test_shortest_path(benchmark, graph):
+    """
+    Benchmarks shortest path between two random nodes.
+    """
+    store, nodes, rng = graph
+
+    def run():
+        source, target = rng.sample(nodes, 2)
+        return asyncio.run(store.shortest_path(source, target, TENANT, max_depth=4))
+
+    benchmark(run)
+
+
+@pytest.mark.performance
+def test_snapshot_build(benchmark, graph):
+    """
+    Benchmarks building a CSR snapshot of the whole tenant graph.
+    """
+    store, _, _ = graph
+
+    result = benchmark.pedantic(
+        lambda: asyncio.run(store.snapshot(TENANT)), rounds=3, iterations=1
+    )
+
+    assert 0 < result.edge_count <= EDGE_COUNT
+
+
+@pytest.mark.performance
+def test_snapshot_pagerank(benchmark, snapshot):
+    """
+    Benchmarks PageRank on a snapshot, outside the store's lock.
+    """
+    ranks = benchmark.pedantic(
+        lambda: snapshot.pagerank(max_iterations=20), rounds=1, iterations=1
+    )
+
+    assert sum(ranks.values()) == pytest.approx(1.0)
+
+
+@pytest.mark.performance
+def test_snapshot_communities(benchmark, snapshot):
+    """
+    Benchmarks label propagation community detection on a snapshot.
+    """
+    communities = benchmark.pedantic(
+        lambda: snapshot.communities(max_iterations=5), rounds=1, iterations=1
+    )
+
+    assert sum(len(c) for c in communities) == snapshot.node_count
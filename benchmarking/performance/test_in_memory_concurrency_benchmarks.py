@@ -0,0 +1,105 @@
+import asyncio
+import os
+import random
+import time
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+
+# Default scale is 50 tenants with 2,000 vectors each; lower it for quick runs
+TENANT_COUNT = int(os.getenv("RAE_BENCH_TENANTS", "50"))
+MEMORIES_PER_TENANT = int(os.getenv("RAE_BENCH_TENANT_MEMORIES", "2000"))
+DIMENSION = 64
+
+
+def _vector(rng):
+    return [rng.uniform(-1.0, 1.0) for _ in range(DIMENSION)]
+
+
+@pytest.fixture(scope="module")
+def loop():
+    """
+    One event loop for the module: contended locks stay bound to it.
+    """
+    loop = asyncio.new_event_loop()
+    yield loop
+    loop.close()
+
+
+@pytest.fixture(scope="module")
+def storage(loop):
+    """
+    Builds a store holding TENANT_COUNT tenants of MEMORIES_PER_TENANT vectors.
+    """
+    rng = random.Random(42)
+    store = InMemoryStorage()
+
+    async def build():
+        for t in range(TENANT_COUNT):
+            for i in range(MEMORIES_PER_TENANT):
+                await store.store_memory(
+                    content=f"memory {i}",
+                    tenant_id=f"tenant-{t}",
+                    embedding=_vector(rng),
+                )
+
+    loop.run_until_complete(build())
+    return store, rng
+
+
+@pytest.mark.performance
+def test_concurrent_searches_across_tenants(benchmark, loop, storage):
+    """
+    Benchmarks one search per tenant, all issued concurrently.
+
+    Each search scans only its own tenant's vectors, so the round costs
+    about one pass over the store instead of one pass per tenant.
+    """
+    store, rng = storage
+
+    async def round_():
+        return await asyncio.gather(
+            *(
+                store.search_similar(_vector(rng), f"tenant-{t}", limit=10)
+                for t in range(TENANT_COUNT)
+            )
+        )
+
+    results = benchmark.pedantic(lambda: loop.run_until_complete(round_()), rounds=3)
+
+    assert len(results) == TENANT_COUNT
+    assert all(len(r) <= 10 for r in results)
+
+
+@pytest.mark.performance
+def test_writes_during_long_scan(benchmark, loop, storage):
+    """
+    Benchmarks writes of other tenants while one tenant runs searches.
+
+    Scans yield to the event loop and hold only their tenant's lock shard,
+    so the writes interleave with them instead of queueing behind them.
+    """
+    store, rng = storage
+    latencies = []
+
+    async def write(t):
+        started = time.perf_counter()
+        await store.store_memory(
+            content="new", tenant_id=f"tenant-{t}", embedding=_vector(rng)
+        )
+        latencies.append(time.perf_counter() - started)
+
+    async def round_():
+        searches = [
+            asyncio.create_task(store.search_similar(_vector(rng), "tenant-0"))
+            for _ in range(5)
+        ]
+        await asyncio.sleep(0)
+        await asyncio.gather(*(write(t) for t in range(1, TENANT_COUNT)))
+        await asyncio.gather(*searches)
+
+    benchmark.pedantic(lambda: loop.run_until_complete(round_()), rounds=3)
+    benchmark.extra_info["max_write_latency_ms"] = max(latencies) * 1000
+
+    assert len(latencies) == 3 * (TENANT_COUNT - 1)
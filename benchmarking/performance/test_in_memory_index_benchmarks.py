@@ -0,0 +1,113 @@
+import asyncio
+import os
+import random
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+
+# Default scale is 1M memories over 1,000 tenants; lower it for quick runs
+MEMORY_COUNT = int(os.getenv("RAE_BENCH_MEMORIES", "1000000"))
+TENANT_COUNT = int(os.getenv("RAE_BENCH_INDEX_TENANTS", "1000"))
+AGENTS = ("planner", "coder", "reviewer")
+LAYERS = ("working", "episodic", "semantic")
+TAGS = ("billing", "deploy", "incident", "design")
+
+
+@pytest.fixture(scope="module")
+def loop():
+    """
+    One event loop for the module: contended locks stay bound to it.
+    """
+    loop = asyncio.new_event_loop()
+    yield loop
+    loop.close()
+
+
+@pytest.fixture(scope="module")
+def storage(loop):
+    """
+    Builds a store of MEMORY_COUNT memories spread over TENANT_COUNT tenants.
+
+    One small tenant ("needle") holds 10 memories, so its lookups show
+    whether their cost follows the results or the whole store.
+    """
+    rng = random.Random(42)
+    store = InMemoryStorage(history_limit=0)
+
+    async def build():
+        for i in range(MEMORY_COUNT):
+            await store.store_memory(
+                content=f"memory {i}",
+                tenant_id=f"tenant-{rng.randrange(TENANT_COUNT)}",
+                agent_id=rng.choice(AGENTS),
+                layer=rng.choice(LAYERS),
+                tags=[rng.choice(TAGS)],
+            )
+        for i in range(10):
+            await store.store_memory(
+                content=f"needle {i}",
+                tenant_id="needle",
+                agent_id="planner",
+                layer="semantic",
+                tags=["incident"],
+            )
+
+    loop.run_until_complete(build())
+    return store
+
+
+@pytest.mark.performance
+def test_list_small_tenant(benchmark, loop, storage):
+    """
+    Benchmarks listing a 10-memory tenant among 1M memories.
+    """
+    result = benchmark(
+        lambda: loop.run_until_complete(storage.list_memories("needle", limit=100))
+    )
+
+    assert len(result) == 10
+
+
+@pytest.mark.performance
+def test_list_by_tag_page(benchmark, loop, storage):
+    """
+    Benchmarks the first page of a tag lookup in a large tenant.
+    """
+    result = benchmark(
+        lambda: loop.run_until_complete(
+            storage.list_memories("tenant-0", tags=["incident"], limit=20)
+        )
+    )
+
+    assert 0 < len(result) <= 20
+    assert all("incident" in m["tags"] for m in result)
+
+
+@pytest.mark.performance
+def test_count_by_agent_and_layer(benchmark, loop, storage):
+    """
+    Benchmarks counting one agent's memories of one layer in a tenant.
+    """
+    count = benchmark(
+        lambda: loop.run_until_complete(
+            storage.count_memories("tenant-0", agent_id="coder", layer="working")
+        )
+    )
+    expected = loop.run_until_complete(
+        storage.list_memories(
+            "tenant-0", agent_id="coder", layer="working", limit=MEMORY_COUNT
+        )
+    )
+
+    assert count == len(expected)
+
+
+@pytest.mark.performance
+def test_count_tenant(benchmark, loop, storage):
+    """
+    Benchmarks counting a tenant's memories (answered from index sizes).
+    """
+    count = benchmark(lambda: loop.run_until_complete(storage.count_memories("needle")))
+
+    assert count == 10
@@ -0,0 +1,112 @@
+"""Backend wiring shared by the examples.
+
+open_engine() composes RAEEngine over persistent SQLite storage, vector and
+graph stores in one data directory. Embeddings come from a provider of the
+embedding registry (e.g. "ollama") or, by default, from
+HashingEmbeddingProvider, a dependency-free bag-of-words embedder that keeps
+the examples runnable offline.
+
+Requires the engine and sqlite extras: pip install "rae-core[engine,sqlite]"
+"""
+
+import hashlib
+import math
+import re
+from collections.abc import AsyncIterator
+from contextlib import asynccontextmanager
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from rae_core.adapters.sqlite import SQLiteGraphStore, SQLiteStorage, SQLiteVectorStore
+from rae_core.config import RAESettings
+from rae_core.embedding.registry import EmbeddingProviderRegistry
+from rae_core.engine import RAEEngine
+from rae_core.interfaces.embedding import IEmbeddingProvider
+
+HASHING_EMBEDDER = "hashing"
+
+_TOKEN = re.compile(r"\w+")
+
+
+class HashingEmbeddingProvider(IEmbeddingProvider):
+    """Feature-hashed bag of words, L2-normalized.
+
+    Texts sharing words get similar vectors; there is no semantics beyond
+    that, which is enough to exercise the retrieval path end to end.
+    """
+
+    def __init__(self, dimension: int = 256):
+        self.dimension = dimension
+
+    async def embed_text(
+        self, text: str, task_type: str = "search_document"
+    ) -> list[float]:
+        vector = [0.0] * self.dimension
+        for token in _TOKEN.findall(text.lower()):
+            digest = hashlib.sha1(token.encode("utf-8")).digest()
+            index = int.from_bytes(digest[:4], "little") % self.dimension
+            vector[index] += 1.0 if digest[4] & 1 else -1.0
+        norm = math.sqrt(sum(v * v for v in vector)) or 1.0
+        return [v / norm for v in vector]
+
+    async def embed_batch(
+        self, texts: list[str], task_type: str = "search_document"
+    ) -> list[list[float]]:
+        return [await self.embed_text(text, task_type) for text in texts]
+
+    def get_dimension(self) -> int:
+        return self.dimension
+
+
+@dataclass
+class Backends:
+    """An engine and the stores it was composed from."""
+
+    engine: RAEEngine
+    storage: SQLiteStorage
+    vectors: SQLiteVectorStore
+    graph: SQLiteGraphStore
+
+
+def create_embedder(name: str, **options: Any) -> IEmbeddingProvider:
+    """The offline hashing embedder, or a provider of the embedding registry."""
+    if name == HASHING_EMBEDDER:
+        return HashingEmbeddingProvider(**options)
+    return EmbeddingProviderRegistry().create(name, **options)
+
+
+@asynccontextmanager
+async def open_engine(
+    data_dir: str | Path, embedder: str = HASHING_EMBEDDER
+) -> AsyncIterator[Backends]:
+    """RAEEngine over SQLite backends in data_dir, closed on exit."""
+    data_dir = Path(data_dir)
+    data_dir.mkdir(parents=True, exist_ok=True)
+    storage = SQLiteStorage(str(data_dir / "memories.db"))
+    vectors = SQLiteVectorStore(str(data_dir / "vectors.db"))
+    graph = SQLiteGraphStore(str(data_dir / "graph.db"))
+    await storage.initialize()
+    await vectors.initialize()
+    await graph.initialize()
+
+    engine = RAEEngine(
+        memory_storage=storage,
+        vector_store=vectors,
+        embedding_provider=create_embedder(embedder),
+        settings=RAESettings(),
+    )
+    try:
+        yield Backends(engine, storage, vectors, graph)
+    finally:
+        await vectors.close()
+        await storage.close()
+
+
+def print_memories(title: str, memories: list[dict[str, Any]]) -> None:
+    print(title)
+    if not memories:
+        print("  (nothing relevant)")
+    for memory in memories:
+        score = float(memory.get("math_score") or 0.0)
+        print(f"  [{score:.2f}] {memory.get('content')}")
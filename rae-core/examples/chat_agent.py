@@ -0,0 +1,107 @@
+"""Chat agent: remember, recall and reflect through RAEEngine.
+
+Plays a scripted conversation against persistent SQLite backends. Before
+each user turn is remembered the agent recalls what it already knows about
+it; when the conversation ends the session is closed (its working memories
+move to the episodic layer), the agent reflects on the session and then
+answers a follow-up question from memory alone.
+
+    python examples/chat_agent.py [--data-dir DIR] [--embedder ollama]
+
+Without --data-dir the run uses a temporary directory. The example doubles
+as a smoke test of the composed API (tests/unit/test_examples.py).
+"""
+
+import argparse
+import asyncio
+import sys
+import tempfile
+from pathlib import Path
+from typing import Any
+
+sys.path.insert(0, str(Path(__file__).resolve().parent))
+
+from _backends import HASHING_EMBEDDER, open_engine, print_memories  # noqa: E402
+
+from rae_core.reflection.engine import ReflectionEngine  # noqa: E402
+
+TENANT = "example"
+AGENT = "chat-agent"
+SESSION = "session-1"
+
+CONVERSATION = [
+    "Hi, I'm Marta and I maintain the billing service.",
+    "The billing service is written in Python and deployed on Kubernetes.",
+    "Please always answer me with short bullet points.",
+    "Our billing database is PostgreSQL 15.",
+    "Next week we migrate the billing service to a new Kubernetes cluster.",
+]
+
+QUESTION = "Where is the billing service deployed?"
+
+
+async def run(data_dir: str | Path, embedder: str = HASHING_EMBEDDER) -> dict[str, Any]:
+    """Play the conversation; returns what each stage produced."""
+    async with open_engine(data_dir, embedder) as backends:
+        engine = backends.engine
+
+        remembered = []
+        for turn in CONVERSATION:
+            print(f"user> {turn}")
+            context = await engine.recall(
+                turn, TENANT, agent_id=AGENT, top_k=3, floor=0.0
+            )
+            print_memories("  recalled:", context.memories)
+            memory_id = await engine.store_memory(
+                content=turn,
+                tenant_id=TENANT,
+                agent_id=AGENT,
+                layer="working",
+                session_id=SESSION,
+                tags=["conversation"],
+                importance=0.6,
+            )
+            if memory_id is not None:
+                remembered.append(memory_id)
+
+        consolidated = await engine.close_session(TENANT, SESSION, agent_id=AGENT)
+        print(f"session closed: {len(consolidated)} memories consolidated")
+
+        reflection = await ReflectionEngine(backends.storage).generate_reflection(
+            consolidated, TENANT, AGENT, reflection_type="consolidation"
+        )
+        print(f"reflection: {reflection.get('content')}")
+
+        answer = await engine.recall(
+            QUESTION, TENANT, agent_id=AGENT, layer="episodic", top_k=3, floor=0.0
+        )
+        print_memories(f"question> {QUESTION}", answer.memories)
+
+        return {
+            "remembered": remembered,
+            "consolidated": consolidated,
+            "reflection": reflection,
+            "answer": answer.memories,
+        }
+
+
+def main(argv: list[str] | None = None) -> int:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--data-dir", help="Directory of the SQLite databases")
+    parser.add_argument(
+        "--embedder",
+        default=HASHING_EMBEDDER,
+        help="Embedding provider name (default: offline hashing embedder)",
+    )
+    args = parser.parse_args(argv)
+
+    if args.data_dir:
+        result = asyncio.run(run(args.data_dir, args.embedder))
+    else:
+        with tempfile.TemporaryDirectory() as data_dir:
+            result = asyncio.run(run(data_dir, args.embedder))
+    return 0 if result["answer"] else 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
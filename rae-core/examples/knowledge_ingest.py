@@ -0,0 +1,110 @@
+"""Knowledge ingest: seed an agent, add documents and query them.
+
+Bootstraps an agent from the markdown and JSON files in examples/seed (one
+semantic memory per section or fact, entities linked in the SQLite graph
+store), ingests a longer runbook through the chunking pipeline, then answers
+questions with recall. The last question, which the knowledge base cannot
+answer, is asked with a stricter relevance floor so recall can report that
+nothing is relevant instead of returning weak matches.
+
+    python examples/knowledge_ingest.py [--data-dir DIR] [--seed-dir DIR]
+                                        [--embedder ollama]
+
+Without --data-dir the run uses a temporary directory. The example doubles
+as a smoke test of the composed API (tests/unit/test_examples.py).
+"""
+
+import argparse
+import asyncio
+import sys
+import tempfile
+from pathlib import Path
+from typing import Any
+
+sys.path.insert(0, str(Path(__file__).resolve().parent))
+
+from _backends import HASHING_EMBEDDER, open_engine, print_memories  # noqa: E402
+
+TENANT = "example"
+AGENT = "knowledge-agent"
+SEED_DIR = Path(__file__).resolve().parent / "seed"
+
+RUNBOOK = """Invoice run recovery.
+
+When the nightly invoice run fails, first check the billing service logs in
+the eu-west cluster. Most failures are caused by an expired PostgreSQL
+password; rotate it with the vault CLI and restart the job.
+
+If the run fails a second time, page the Payments team and open an incident.
+Never re-run the job for customers that already received an invoice: issue a
+credit note instead.
+"""
+
+QUESTIONS = [
+    ("Where are invoices stored?", None),
+    ("What should I do when the invoice run fails?", None),
+    ("Which programming language is the mobile app written in?", 0.5),
+]
+
+
+async def run(
+    data_dir: str | Path,
+    seed_dir: str | Path = SEED_DIR,
+    embedder: str = HASHING_EMBEDDER,
+) -> dict[str, Any]:
+    """Ingest the seed files and runbook; returns what each stage produced."""
+    async with open_engine(data_dir, embedder) as backends:
+        engine = backends.engine
+
+        report = await engine.bootstrap_agent(
+            TENANT, AGENT, seed_dir, graph_store=backends.graph
+        )
+        print(
+            f"seeded {len(report.memory_ids)} facts from {len(report.files)} "
+            f"files ({report.entities} entities)"
+        )
+
+        runbook_id = await engine.store_memory(
+            content=RUNBOOK,
+            tenant_id=TENANT,
+            agent_id=AGENT,
+            layer="semantic",
+            tags=["runbook"],
+            source="runbook.txt",
+        )
+        print(f"ingested runbook as {runbook_id}")
+
+        answers = {}
+        for question, floor in QUESTIONS:
+            result = await engine.recall(
+                question, TENANT, agent_id=AGENT, top_k=3, floor=floor
+            )
+            print_memories(f"question> {question}", result.memories)
+            answers[question] = result
+
+        return {"report": report, "runbook_id": runbook_id, "answers": answers}
+
+
+def main(argv: list[str] | None = None) -> int:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--data-dir", help="Directory of the SQLite databases")
+    parser.add_argument(
+        "--seed-dir", default=str(SEED_DIR), help="Seed knowledge directory"
+    )
+    parser.add_argument(
+        "--embedder",
+        default=HASHING_EMBEDDER,
+        help="Embedding provider name (default: offline hashing embedder)",
+    )
+    args = parser.parse_args(argv)
+
+    if args.data_dir:
+        result = asyncio.run(run(args.data_dir, args.seed_dir, args.embedder))
+    else:
+        with tempfile.TemporaryDirectory() as data_dir:
+            result = asyncio.run(run(data_dir, args.seed_dir, args.embedder))
+    return 0 if result["report"].memory_ids else 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
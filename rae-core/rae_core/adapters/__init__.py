@@ -3,31 +3,63 @@
 This module provides concrete implementations of the abstract interfaces:
 - PostgreSQLStorage: IMemoryStorage implementation using asyncpg
 - QdrantVectorStore: IVectorStore implementation using Qdrant
+- PgVectorStore: IVectorStore implementation using pgvector (can share the
+  PostgreSQLStorage connection pool)
+- LanceDBVectorStore: IVectorStore persisted on local disk with LanceDB
 - RedisCache: ICacheProvider implementation using Redis
 - SQLiteStorage: IMemoryStorage implementation using SQLite (Phase 1)
 - SQLiteVectorStore: IVectorStore implementation using SQLite (Phase 1)
 - InMemoryStorage: IMemoryStorage for testing (Phase 1)
 - InMemoryVectorStore: IVectorStore for testing (Phase 1)
 - InMemoryCache: ICacheProvider for testing (Phase 1)
+- InMemoryGraphStore: IGraphStore for testing
+- InMemoryAuditLogger / SQLiteAuditLogger: IAuditLogger implementations
+- InMemoryOutboxStore / SQLiteOutboxStore: IOutboxStore implementations
+- ReadReplicaStorage: read/write splitting over a primary and replicas
+- KnowledgePackStore: read-only backend over a memory-mapped knowledge pack
+- OverlayStorage / OverlayVectorStore: writable store layered over read-only ones
+- EncryptedStorage: per-tenant AES-GCM encryption of content and metadata
+- ArchiveTieredStorage: cold archive tier (filesystem or S3) with rehydration
+- AttachmentStorage: content-addressed blob attachments (filesystem or S3)
 
 Adapters follow dependency injection pattern for easy testing and swapping.
 """
 
+from .archive import (
+    ArchiveTieredStorage,
+    FilesystemArchiveStorage,
+    S3ArchiveStorage,
+)
+from .blobs import AttachmentStorage, FilesystemBlobStore, S3BlobStore
+from .encrypted import EncryptedStorage, LocalKeyProvider
+from .memory.audit import InMemoryAuditLogger
 from .memory.cache import InMemoryCache
+from .memory.graph import InMemoryGraphStore
+from .memory.outbox import InMemoryOutboxStore
 from .memory.storage import InMemoryStorage
 from .memory.vector import InMemoryVectorStore
+from .overlay import OverlayStorage, OverlayVectorStore
+from .pack import KnowledgePackStore, build_knowledge_pack
+from .replicas import ReadReplicaStorage
 
 # Conditional imports for optional dependencies
 try:
+    from .sqlite.audit import SQLiteAuditLogger
+    from .sqlite.outbox import SQLiteOutboxStore
     from .sqlite.storage import SQLiteStorage
     from .sqlite.vector import SQLiteVectorStore
 except ImportError:
+    SQLiteAuditLogger = None  # type: ignore
+    SQLiteOutboxStore = None  # type: ignore
     SQLiteStorage = None  # type: ignore
     SQLiteVectorStore = None  # type: ignore
 
 try:
+    from .pgvector import PgVectorIndex, PgVectorStore
     from .postgres import PostgreSQLStorage
 except ImportError:
+    PgVectorIndex = None  # type: ignore
+    PgVectorStore = None  # type: ignore
     PostgreSQLStorage = None  # type: ignore
 
 try:
@@ -35,6 +67,12 @@ try:
 except ImportError:
     QdrantVectorStore = None  # type: ignore
 
+try:
+    from .lance import LanceDBVectorStore, LanceIndex
+except ImportError:
+    LanceDBVectorStore = None  # type: ignore
+    LanceIndex = None  # type: ignore
+
 try:
     from .redis import RedisCache
 except ImportError:
@@ -47,13 +85,35 @@ RedisCacheAdapter = RedisCache
 
 __all__ = [
     "PostgreSQLStorage",
+    "PgVectorStore",
+    "PgVectorIndex",
     "QdrantVectorStore",
+    "LanceDBVectorStore",
+    "LanceIndex",
     "RedisCache",
     "SQLiteStorage",
     "SQLiteVectorStore",
     "InMemoryStorage",
     "InMemoryVectorStore",
     "InMemoryCache",
+    "InMemoryGraphStore",
+    "InMemoryAuditLogger",
+    "SQLiteAuditLogger",
+    "InMemoryOutboxStore",
+    "SQLiteOutboxStore",
+    "ReadReplicaStorage",
+    "KnowledgePackStore",
+    "build_knowledge_pack",
+    "OverlayStorage",
+    "OverlayVectorStore",
+    "EncryptedStorage",
+    "LocalKeyProvider",
+    "ArchiveTieredStorage",
+    "FilesystemArchiveStorage",
+    "S3ArchiveStorage",
+    "AttachmentStorage",
+    "FilesystemBlobStore",
+    "S3BlobStore",
     # Aliases
     "PostgresMemoryAdapter",
     "QdrantVectorAdapter",
@@ -0,0 +1,294 @@
+"""Archive tier: cold storage for memories moved out of the hot storage.
+
+An IArchiveStorage keeps whole memory records as gzipped JSON, either in a
+local directory (FilesystemArchiveStorage) or in an S3 bucket
+(S3ArchiveStorage, requires boto3). ArchiveTieredStorage wraps the hot
+IMemoryStorage:
+
+- archive_memory() moves a memory (and drops its vector) to the archive;
+  governance.forgetting.ForgettingPruner does so with PruneAction.COLD
+- get_memory() falls back to the archive, so an archived memory is still
+  returned, with ARCHIVED_KEY and REHYDRATION_MS_KEY set in its metadata
+  to tell callers it came from the slow tier
+- delete_memory() removes the archived copy too
+
+Archived memories are no longer searched; they are only reachable by id.
+"""
+
+import asyncio
+import gzip
+import json
+import time
+from datetime import datetime
+from pathlib import Path
+from typing import Any
+from urllib.parse import quote
+from uuid import UUID
+
+import structlog
+
+from rae_core.interfaces.archive import IArchiveStorage
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+
+logger = structlog.get_logger(__name__)
+
+# Metadata flags of a memory read back from the archive
+ARCHIVED_KEY = "archived"
+REHYDRATION_MS_KEY = "rehydration_ms"
+
+_DATETIME_FIELDS = (
+    "created_at",
+    "updated_at",
+    "last_accessed_at",
+    "expires_at",
+    "deleted_at",
+)
+_SUFFIX = ".json.gz"
+
+
+def _json_default(obj: Any) -> str:
+    if isinstance(obj, UUID):
+        return str(obj)
+    if isinstance(obj, datetime):
+        return obj.isoformat()
+    value = getattr(obj, "value", None)
+    if isinstance(value, str):
+        return value
+    raise TypeError(f"Type {type(obj)} not serializable")
+
+
+def encode_record(memory: dict[str, Any]) -> bytes:
+    """Gzipped JSON of a memory record."""
+    return gzip.compress(json.dumps(memory, default=_json_default).encode("utf-8"))
+
+
+def decode_record(data: bytes) -> dict[str, Any]:
+    """Memory record from encode_record output, with ids and dates restored."""
+    memory: dict[str, Any] = json.loads(gzip.decompress(data))
+    memory["id"] = UUID(str(memory["id"]))
+    for field in _DATETIME_FIELDS:
+        if isinstance(memory.get(field), str):
+            memory[field] = datetime.fromisoformat(memory[field])
+    return memory
+
+
+def _tenant_key(tenant_id: str) -> str:
+    return quote(tenant_id, safe="")
+
+
+class FilesystemArchiveStorage(IArchiveStorage):
+    """Archive in a local directory, one file per memory."""
+
+    def __init__(self, root: str | Path):
+        """Initialize archive.
+
+        Args:
+            root: Directory holding one subdirectory per tenant
+        """
+        self.root = Path(root)
+
+    def _path(self, memory_id: UUID, tenant_id: str) -> Path:
+        return self.root / _tenant_key(tenant_id) / f"{memory_id}{_SUFFIX}"
+
+    async def put(self, memory: dict[str, Any], tenant_id: str) -> None:
+        path = self._path(UUID(str(memory["id"])), tenant_id)
+        data = encode_record(memory)
+
+        def write() -> None:
+            path.parent.mkdir(parents=True, exist_ok=True)
+            partial = path.with_name(path.name + ".tmp")
+            partial.write_bytes(data)
+            partial.replace(path)
+
+        await asyncio.to_thread(write)
+
+    async def get(self, memory_id: UUID, tenant_id: str) -> dict[str, Any] | None:
+        path = self._path(memory_id, tenant_id)
+        try:
+            data = await asyncio.to_thread(path.read_bytes)
+        except FileNotFoundError:
+            return None
+        return decode_record(data)
+
+    async def delete(self, memory_id: UUID, tenant_id: str) -> bool:
+        path = self._path(memory_id, tenant_id)
+        try:
+            await asyncio.to_thread(path.unlink)
+        except FileNotFoundError:
+            return False
+        return True
+
+    async def list_ids(self, tenant_id: str) -> list[UUID]:
+        directory = self.root / _tenant_key(tenant_id)
+        if not directory.is_dir():
+            return []
+        return sorted(
+            UUID(path.name[: -len(_SUFFIX)]) for path in directory.glob(f"*{_SUFFIX}")
+        )
+
+
+class S3ArchiveStorage(IArchiveStorage):
+    """Archive in an S3 (or S3-compatible) bucket, one object per memory.
+
+    Requires boto3 (pip install boto3) unless a client is given; calls run
+    in a worker thread.
+    """
+
+    def __init__(
+        self,
+        bucket: str,
+        prefix: str = "rae-archive",
+        client: Any = None,
+        **client_options: Any,
+    ):
+        """Initialize archive.
+
+        Args:
+            bucket: Bucket holding the records
+            prefix: Key prefix of the records
+            client: boto3 S3 client (created from client_options if None)
+            **client_options: Options of boto3.client("s3", ...), e.g.
+                endpoint_url or region_name
+        """
+        if client is None:
+            try:
+                import boto3
+            except ImportError as e:
+                raise ImportError(
+                    "boto3 is required for the S3 archive. "
+                    "Install with: pip install boto3"
+                ) from e
+            client = boto3.client("s3", **client_options)
+        self.client = client
+        self.bucket = bucket
+        self.prefix = prefix.strip("/")
+
+    def _tenant_prefix(self, tenant_id: str) -> str:
+        return f"{self.prefix}/{_tenant_key(tenant_id)}/"
+
+    def _key(self, memory_id: UUID, tenant_id: str) -> str:
+        return f"{self._tenant_prefix(tenant_id)}{memory_id}{_SUFFIX}"
+
+    async def put(self, memory: dict[str, Any], tenant_id: str) -> None:
+        await asyncio.to_thread(
+            self.client.put_object,
+            Bucket=self.bucket,
+            Key=self._key(UUID(str(memory["id"])), tenant_id),
+            Body=encode_record(memory),
+            ContentType="application/json",
+            ContentEncoding="gzip",
+        )
+
+    async def get(self, memory_id: UUID, tenant_id: str) -> dict[str, Any] | None:
+        try:
+            response = await asyncio.to_thread(
+                self.client.get_object,
+                Bucket=self.bucket,
+                Key=self._key(memory_id, tenant_id),
+            )
+        except Exception as e:
+            if _is_missing(e):
+                return None
+            raise
+        data = await asyncio.to_thread(response["Body"].read)
+        return decode_record(data)
+
+    async def delete(self, memory_id: UUID, tenant_id: str) -> bool:
+        key = self._key(memory_id, tenant_id)
+        try:
+            await asyncio.to_thread(
+                self.client.head_object, Bucket=self.bucket, Key=key
+            )
+        except Exception as e:
+            if _is_missing(e):
+                return False
+            raise
+        await asyncio.to_thread(self.client.delete_object, Bucket=self.bucket, Key=key)
+        return True
+
+    async def list_ids(self, tenant_id: str) -> list[UUID]:
+        prefix = self._tenant_prefix(tenant_id)
+
+        def keys() -> list[str]:
+            paginator = self.client.get_paginator("list_objects_v2")
+            return [
+                item["Key"]
+                for page in paginator.paginate(Bucket=self.bucket, Prefix=prefix)
+                for item in page.get("Contents", [])
+            ]
+
+        return sorted(
+            UUID(key[len(prefix) : -len(_SUFFIX)])
+            for key in await asyncio.to_thread(keys)
+            if key.endswith(_SUFFIX)
+        )
+
+
+def _is_missing(error: Exception) -> bool:
+    """Whether a botocore ClientError reports a missing object."""
+    code = getattr(error, "response", {}).get("Error", {}).get("Code")
+    return code in ("NoSuchKey", "404", "NotFound")
+
+
+class ArchiveTieredStorage:
+    """Wraps an IMemoryStorage with a cold archive tier.
+
+    Calls other than get_memory and delete_memory are forwarded unchanged.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        archive: IArchiveStorage,
+        vector_store: IVectorStore | None = None,
+    ):
+        """Initialize tiered storage.
+
+        Args:
+            storage: Hot storage
+            archive: Cold storage receiving archived memories
+            vector_store: Vector store whose vectors are dropped on archiving
+        """
+        self.storage = storage
+        self.archive = archive
+        self.vector_store = vector_store
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self.storage, name)
+
+    async def archive_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        """Move a memory to the archive; False if it is not in hot storage."""
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        if memory is None:
+            return False
+        await self.archive.put(dict(memory), tenant_id)
+        if self.vector_store is not None:
+            await self.vector_store.delete_vector(memory_id, tenant_id)
+        await self.storage.delete_memory(memory_id, tenant_id)
+        logger.info("memory_archived", memory_id=str(memory_id), tenant_id=tenant_id)
+        return True
+
+    async def get_memory(
+        self, memory_id: UUID, tenant_id: str
+    ) -> dict[str, Any] | None:
+        """Memory from hot storage, else rehydrated from the archive."""
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        if memory is not None:
+            return memory
+        started = time.perf_counter()
+        memory = await self.archive.get(memory_id, tenant_id)
+        if memory is None:
+            return None
+        memory["metadata"] = {
+            **(memory.get("metadata") or {}),
+            ARCHIVED_KEY: True,
+            REHYDRATION_MS_KEY: round((time.perf_counter() - started) * 1000, 3),
+        }
+        return memory
+
+    async def delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        """Delete a memory from both tiers."""
+        deleted = await self.storage.delete_memory(memory_id, tenant_id)
+        archived = await self.archive.delete(memory_id, tenant_id)
+        return bool(deleted or archived)
@@ -0,0 +1,355 @@
+"""Content-addressable blob stores and memory attachments.
+
+Images, documents and other binary content are kept out of memory records:
+an IBlobStore holds the bytes under their SHA-256 digest, either in a
+local directory (FilesystemBlobStore) or in an S3 bucket (S3BlobStore,
+requires boto3), and memories reference them through Attachment entries
+stored in metadata[ATTACHMENTS_KEY].
+
+AttachmentStorage wraps an IMemoryStorage and ties the blobs' lifecycle to
+their memories:
+
+- store_memory(attachments=[...]) records attachments (whose blobs must
+  already be stored); attach() stores bytes and attaches them in one call
+- delete_memory(), purge_deleted(tenant_id=...) and clear_tenant() remove
+  the blobs no remaining memory of the tenant references; soft-deleted
+  memories keep theirs so they can be restored
+"""
+
+import asyncio
+import hashlib
+import re
+from pathlib import Path
+from typing import Any
+from urllib.parse import quote
+from uuid import UUID
+
+import structlog
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.blobs import IBlobStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.memory import Attachment
+
+logger = structlog.get_logger(__name__)
+
+# Metadata key listing a memory's attachments
+ATTACHMENTS_KEY = "attachments"
+
+_DIGEST = re.compile(r"^[0-9a-f]{64}$")
+_PAGE = 500
+
+
+def blob_digest(data: bytes) -> str:
+    """SHA-256 hex digest addressing a blob."""
+    return hashlib.sha256(data).hexdigest()
+
+
+def _check_digest(digest: str) -> str:
+    if not _DIGEST.match(digest):
+        raise ValidationError(f"Invalid blob digest: {digest!r}")
+    return digest
+
+
+def _tenant_key(tenant_id: str) -> str:
+    return quote(tenant_id, safe="")
+
+
+class FilesystemBlobStore(IBlobStore):
+    """Blobs in a local directory, one file per digest."""
+
+    def __init__(self, root: str | Path):
+        """Initialize blob store.
+
+        Args:
+            root: Directory holding one subdirectory per tenant
+        """
+        self.root = Path(root)
+
+    def _path(self, digest: str, tenant_id: str) -> Path:
+        _check_digest(digest)
+        return self.root / _tenant_key(tenant_id) / digest[:2] / digest
+
+    async def put(self, data: bytes, tenant_id: str) -> str:
+        digest = blob_digest(data)
+        path = self._path(digest, tenant_id)
+
+        def write() -> None:
+            if path.exists():
+                return
+            path.parent.mkdir(parents=True, exist_ok=True)
+            partial = path.with_name(path.name + ".tmp")
+            partial.write_bytes(data)
+            partial.replace(path)
+
+        await asyncio.to_thread(write)
+        return digest
+
+    async def get(self, digest: str, tenant_id: str) -> bytes | None:
+        path = self._path(digest, tenant_id)
+        try:
+            return await asyncio.to_thread(path.read_bytes)
+        except FileNotFoundError:
+            return None
+
+    async def exists(self, digest: str, tenant_id: str) -> bool:
+        return await asyncio.to_thread(self._path(digest, tenant_id).is_file)
+
+    async def delete(self, digest: str, tenant_id: str) -> bool:
+        path = self._path(digest, tenant_id)
+        try:
+            await asyncio.to_thread(path.unlink)
+        except FileNotFoundError:
+            return False
+        return True
+
+    async def list_digests(self, tenant_id: str) -> list[str]:
+        directory = self.root / _tenant_key(tenant_id)
+        if not directory.is_dir():
+            return []
+        return sorted(
+            path.name for path in directory.glob("*/*") if _DIGEST.match(path.name)
+        )
+
+
+class S3BlobStore(IBlobStore):
+    """Blobs in an S3 (or S3-compatible) bucket, one object per digest.
+
+    Requires boto3 (pip install boto3) unless a client is given; calls run
+    in a worker thread.
+    """
+
+    def __init__(
+        self,
+        bucket: str,
+        prefix: str = "rae-blobs",
+        client: Any = None,
+        **client_options: Any,
+    ):
+        """Initialize blob store.
+
+        Args:
+            bucket: Bucket holding the blobs
+            prefix: Key prefix of the blobs
+            client: boto3 S3 client (created from client_options if None)
+            **client_options: Options of boto3.client("s3", ...), e.g.
+                endpoint_url or region_name
+        """
+        if client is None:
+            try:
+                import boto3
+            except ImportError as e:
+                raise ImportError(
+                    "boto3 is required for the S3 blob store. "
+                    "Install with: pip install boto3"
+                ) from e
+            client = boto3.client("s3", **client_options)
+        self.client = client
+        self.bucket = bucket
+        self.prefix = prefix.strip("/")
+
+    def _tenant_prefix(self, tenant_id: str) -> str:
+        return f"{self.prefix}/{_tenant_key(tenant_id)}/"
+
+    def _key(self, digest: str, tenant_id: str) -> str:
+        return f"{self._tenant_prefix(tenant_id)}{_check_digest(digest)}"
+
+    async def put(self, data: bytes, tenant_id: str) -> str:
+        digest = blob_digest(data)
+        if not await self.exists(digest, tenant_id):
+            await asyncio.to_thread(
+                self.client.put_object,
+                Bucket=self.bucket,
+                Key=self._key(digest, tenant_id),
+                Body=data,
+            )
+        return digest
+
+    async def get(self, digest: str, tenant_id: str) -> bytes | None:
+        try:
+            response = await asyncio.to_thread(
+                self.client.get_object,
+                Bucket=self.bucket,
+                Key=self._key(digest, tenant_id),
+            )
+        except Exception as e:
+            if _is_missing(e):
+                return None
+            raise
+        data: bytes = await asyncio.to_thread(response["Body"].read)
+        return data
+
+    async def exists(self, digest: str, tenant_id: str) -> bool:
+        try:
+            await asyncio.to_thread(
+                self.client.head_object,
+                Bucket=self.bucket,
+                Key=self._key(digest, tenant_id),
+            )
+        except Exception as e:
+            if _is_missing(e):
+                return False
+            raise
+        return True
+
+    async def delete(self, digest: str, tenant_id: str) -> bool:
+        if not await self.exists(digest, tenant_id):
+            return False
+        await asyncio.to_thread(
+            self.client.delete_object,
+            Bucket=self.bucket,
+            Key=self._key(digest, tenant_id),
+        )
+        return True
+
+    async def list_digests(self, tenant_id: str) -> list[str]:
+        prefix = self._tenant_prefix(tenant_id)
+
+        def keys() -> list[str]:
+            paginator = self.client.get_paginator("list_objects_v2")
+            return [
+                item["Key"]
+                for page in paginator.paginate(Bucket=self.bucket, Prefix=prefix)
+                for item in page.get("Contents", [])
+            ]
+
+        return sorted(
+            key[len(prefix) :]
+            for key in await asyncio.to_thread(keys)
+            if _DIGEST.match(key[len(prefix) :])
+        )
+
+
+def _is_missing(error: Exception) -> bool:
+    """Whether a botocore ClientError reports a missing object."""
+    code = getattr(error, "response", {}).get("Error", {}).get("Code")
+    return code in ("NoSuchKey", "404", "NotFound")
+
+
+def attachments_of(memory: dict[str, Any]) -> list[Attachment]:
+    """Attachments recorded in a memory's metadata."""
+    entries = (memory.get("metadata") or {}).get(ATTACHMENTS_KEY) or []
+    return [Attachment.model_validate(entry) for entry in entries]
+
+
+class AttachmentStorage:
+    """Wraps an IMemoryStorage with blob attachments.
+
+    Calls other than those listed in the module docstring are forwarded
+    unchanged.
+    """
+
+    def __init__(self, storage: IMemoryStorage, blobs: IBlobStore):
+        """Initialize attachment storage.
+
+        Args:
+            storage: Storage holding the memories
+            blobs: Blob store holding the attached bytes
+        """
+        self.storage = storage
+        self.blobs = blobs
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self.storage, name)
+
+    async def store_memory(self, **kwargs: Any) -> UUID:
+        """Store a memory with optional attachments.
+
+        Raises:
+            ValidationError: An attachment's blob is not stored
+        """
+        attachments = [
+            Attachment.model_validate(a) for a in kwargs.pop("attachments", None) or []
+        ]
+        if attachments:
+            tenant_id = kwargs.get("tenant_id", "default")
+            for attachment in attachments:
+                if not await self.blobs.exists(attachment.digest, tenant_id):
+                    raise ValidationError(f"Blob not stored: {attachment.digest}")
+            kwargs["metadata"] = {
+                **(kwargs.get("metadata") or {}),
+                ATTACHMENTS_KEY: [a.model_dump() for a in attachments],
+            }
+        memory_id: UUID = await self.storage.store_memory(**kwargs)
+        return memory_id
+
+    async def attach(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        data: bytes,
+        media_type: str = "application/octet-stream",
+        name: str | None = None,
+    ) -> Attachment | None:
+        """Store bytes and attach them to a memory; None if it is missing."""
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        if memory is None:
+            return None
+        digest = await self.blobs.put(data, tenant_id)
+        attachment = Attachment(
+            digest=digest, media_type=media_type, size=len(data), name=name
+        )
+        metadata = dict(memory.get("metadata") or {})
+        metadata[ATTACHMENTS_KEY] = [
+            *(a.model_dump() for a in attachments_of(memory)),
+            attachment.model_dump(),
+        ]
+        await self.storage.update_memory(
+            memory_id, tenant_id, {"metadata": metadata}, "attachments"
+        )
+        return attachment
+
+    async def read_attachment(self, attachment: Attachment, tenant_id: str) -> bytes:
+        """Bytes of an attachment.
+
+        Raises:
+            ValidationError: The blob is not stored
+        """
+        data = await self.blobs.get(attachment.digest, tenant_id)
+        if data is None:
+            raise ValidationError(f"Blob not stored: {attachment.digest}")
+        return data
+
+    async def delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        """Delete a memory and the blobs only it referenced."""
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        deleted = bool(await self.storage.delete_memory(memory_id, tenant_id))
+        if deleted and memory is not None and attachments_of(memory):
+            await self.collect_garbage(tenant_id)
+        return deleted
+
+    async def purge_deleted(self, before: Any, tenant_id: str | None = None) -> int:
+        """Purge the trash; with a tenant_id, also its unreferenced blobs."""
+        purged: int = await self.storage.purge_deleted(before, tenant_id=tenant_id)
+        if purged and tenant_id is not None:
+            await self.collect_garbage(tenant_id)
+        return purged
+
+    async def clear_tenant(self, tenant_id: str) -> int:
+        """Delete all memories and blobs of a tenant."""
+        cleared: int = await self.storage.clear_tenant(tenant_id)
+        for digest in await self.blobs.list_digests(tenant_id):
+            await self.blobs.delete(digest, tenant_id)
+        return cleared
+
+    async def collect_garbage(self, tenant_id: str) -> int:
+        """Delete the tenant's blobs no memory (trash included) references."""
+        referenced: set[str] = set()
+        offset = 0
+        while True:
+            page = await self.storage.list_memories(
+                tenant_id, limit=_PAGE, offset=offset, include_deleted=True
+            )
+            for memory in page:
+                referenced.update(a.digest for a in attachments_of(memory))
+            if len(page) < _PAGE:
+                break
+            offset += _PAGE
+
+        removed = 0
+        for digest in await self.blobs.list_digests(tenant_id):
+            if digest not in referenced and await self.blobs.delete(digest, tenant_id):
+                removed += 1
+        if removed:
+            logger.info("blobs_collected", tenant_id=tenant_id, removed=removed)
+        return removed
@@ -0,0 +1,292 @@
+"""Encryption at rest for memory content and metadata.
+
+EncryptedStorage seals the content and metadata of every memory with
+AES-GCM before they reach the wrapped storage, using per-tenant keys from
+an IKeyProvider. Ids, tags, layer, importance and timestamps stay in
+cleartext so the backend can still filter and sort on them.
+
+A sealed value is "rae-enc:v1:<key id>:<base64 nonce + ciphertext>". The
+key id allows key rotation: new writes use the tenant's current key while
+older values stay readable. The tenant and field name are bound into each
+ciphertext, so a value copied to another tenant or field fails to decrypt.
+
+Requires the crypto extra: pip install rae-core[crypto]
+"""
+
+import base64
+import json
+import os
+from collections.abc import Iterable
+from typing import Any
+from uuid import UUID
+
+from rae_core.exceptions.base import StorageError
+from rae_core.interfaces.keys import IKeyProvider
+from rae_core.interfaces.storage import IMemoryStorage
+
+try:
+    from cryptography.exceptions import InvalidTag
+    from cryptography.hazmat.primitives import hashes
+    from cryptography.hazmat.primitives.ciphers.aead import AESGCM
+    from cryptography.hazmat.primitives.kdf.hkdf import HKDF
+except ImportError:
+    AESGCM = None
+
+TOKEN_PREFIX = "rae-enc:v1"
+# Metadata key holding the sealed metadata; other keys are cleartext
+ENCRYPTED_METADATA_KEY = "_encrypted"
+_NONCE_SIZE = 12
+_SEALED_FIELDS = ("content", "metadata")
+
+
+def _require_crypto() -> None:
+    if AESGCM is None:
+        raise ImportError(
+            "cryptography is required for encryption at rest. "
+            "Install with: pip install rae-core[crypto]"
+        )
+
+
+def is_sealed(value: Any) -> bool:
+    """Whether value is an encrypted field value."""
+    return isinstance(value, str) and value.startswith(f"{TOKEN_PREFIX}:")
+
+
+class LocalKeyProvider:
+    """IKeyProvider deriving tenant keys from local master keys.
+
+    Each tenant key is HKDF-SHA256 of a master key with the tenant id, so
+    no per-tenant state is stored. To rotate, add a new master key and make
+    it current; keep the old one while data sealed with it remains.
+    """
+
+    def __init__(
+        self, master_keys: dict[str, bytes], current_key_id: str | None = None
+    ):
+        """Initialize the provider.
+
+        Args:
+            master_keys: Master keys by key id
+            current_key_id: Key for new data (the last one if None)
+        """
+        _require_crypto()
+        if not master_keys:
+            raise ValueError("At least one master key is required")
+        self._master_keys = dict(master_keys)
+        self.current_key_id = current_key_id or list(master_keys)[-1]
+        if self.current_key_id not in self._master_keys:
+            raise ValueError(f"Unknown key id: {self.current_key_id}")
+        self._derived: dict[tuple[str, str], bytes] = {}
+
+    def add_key(self, key_id: str, master_key: bytes, current: bool = True) -> None:
+        """Add a master key, by default making it current."""
+        self._master_keys[key_id] = master_key
+        if current:
+            self.current_key_id = key_id
+
+    async def current_key(self, tenant_id: str) -> tuple[str, bytes]:
+        return self.current_key_id, self._derive(tenant_id, self.current_key_id)
+
+    async def get_key(self, tenant_id: str, key_id: str) -> bytes:
+        if key_id not in self._master_keys:
+            raise KeyError(key_id)
+        return self._derive(tenant_id, key_id)
+
+    def _derive(self, tenant_id: str, key_id: str) -> bytes:
+        key = self._derived.get((tenant_id, key_id))
+        if key is None:
+            key = HKDF(
+                algorithm=hashes.SHA256(),
+                length=32,
+                salt=None,
+                info=f"rae-tenant-key:{tenant_id}".encode(),
+            ).derive(self._master_keys[key_id])
+            self._derived[(tenant_id, key_id)] = key
+        return key
+
+
+class EncryptedStorage:
+    """Wraps an IMemoryStorage and encrypts memory content and metadata.
+
+    Metadata keys listed in cleartext_metadata are left readable for
+    filtering; the rest of the metadata is sealed as one value. The wrapped
+    storage only sees ciphertext, so its full-text search cannot match on
+    content and metadata filters only match cleartext keys. Values written
+    before encryption was enabled are returned as they are. All other
+    calls are forwarded unchanged.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        key_provider: IKeyProvider,
+        cleartext_metadata: Iterable[str] = ("info_class",),
+    ):
+        """Initialize the decorator.
+
+        Args:
+            storage: Storage backend receiving the sealed memories
+            key_provider: Source of per-tenant keys
+            cleartext_metadata: Metadata keys stored unencrypted
+        """
+        _require_crypto()
+        self.storage = storage
+        self.key_provider = key_provider
+        self.cleartext_metadata = frozenset(cleartext_metadata)
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self.storage, name)
+
+    async def store_memory(self, **kwargs: Any) -> UUID:
+        sealed = await self._seal_fields(kwargs["tenant_id"], kwargs)
+        return await self.storage.store_memory(**{**kwargs, **sealed})
+
+    async def update_memory(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        updates: dict[str, Any],
+        changed_by: str | None = None,
+        expected_version: int | None = None,
+    ) -> bool:
+        sealed = await self._seal_fields(tenant_id, updates)
+        extra: dict[str, Any] = {}
+        if changed_by is not None:
+            extra["changed_by"] = changed_by
+        if expected_version is not None:
+            extra["expected_version"] = expected_version
+        return await self.storage.update_memory(
+            memory_id, tenant_id, {**updates, **sealed}, **extra
+        )
+
+    async def get_memory(
+        self, memory_id: UUID, tenant_id: str
+    ) -> dict[str, Any] | None:
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        return None if memory is None else await self._open_memory(memory, tenant_id)
+
+    async def get_memories_batch(
+        self, memory_ids: list[UUID], tenant_id: str
+    ) -> list[dict[str, Any]]:
+        memories = await self.storage.get_memories_batch(memory_ids, tenant_id)
+        return [await self._open_memory(m, tenant_id) for m in memories]
+
+    async def list_memories(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        **kwargs: Any,
+    ) -> list[dict[str, Any]]:
+        memories = await self.storage.list_memories(
+            tenant_id, agent_id=agent_id, layer=layer, **kwargs
+        )
+        return [await self._open_memory(m, tenant_id) for m in memories]
+
+    async def search_memories(
+        self, query: str, tenant_id: str, *args: Any, **kwargs: Any
+    ) -> list[dict[str, Any]]:
+        memories = await self.storage.search_memories(
+            query, tenant_id, *args, **kwargs
+        )
+        return [await self._open_memory(m, tenant_id) for m in memories]
+
+    async def get_memory_history(
+        self, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        history = await self.storage.get_memory_history(memory_id, tenant_id)
+        return [await self._open_memory(m, tenant_id) for m in history]
+
+    async def get_change_log(
+        self, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        """Change log with sealed old/new values decrypted.
+
+        Edits recorded for long sealed text refer to the ciphertext and are
+        returned as they are.
+        """
+        entries = await self.storage.get_change_log(memory_id, tenant_id)
+        opened = []
+        for entry in entries:
+            changes = []
+            for change in entry.get("changes", []):
+                change = dict(change)
+                if change.get("field") in _SEALED_FIELDS:
+                    for key in ("old_value", "new_value"):
+                        if key in change:
+                            change[key] = await self._open_field(
+                                tenant_id, change["field"], change[key]
+                            )
+                changes.append(change)
+            opened.append({**entry, "changes": changes})
+        return opened
+
+    async def _seal_fields(
+        self, tenant_id: str, fields: dict[str, Any]
+    ) -> dict[str, Any]:
+        """Sealed content and metadata of fields (only those present)."""
+        if all(fields.get(name) is None for name in _SEALED_FIELDS):
+            return {}
+        key_id, key = await self.key_provider.current_key(tenant_id)
+        sealed: dict[str, Any] = {}
+        if fields.get("content") is not None:
+            sealed["content"] = self._seal(
+                key_id, key, tenant_id, "content", fields["content"].encode("utf-8")
+            )
+        if fields.get("metadata") is not None:
+            private = dict(fields["metadata"])
+            metadata = {
+                k: private.pop(k) for k in self.cleartext_metadata if k in private
+            }
+            if private:
+                plaintext = json.dumps(private, default=str).encode("utf-8")
+                metadata[ENCRYPTED_METADATA_KEY] = self._seal(
+                    key_id, key, tenant_id, "metadata", plaintext
+                )
+            sealed["metadata"] = metadata
+        return sealed
+
+    @staticmethod
+    def _seal(
+        key_id: str, key: bytes, tenant_id: str, field: str, plaintext: bytes
+    ) -> str:
+        nonce = os.urandom(_NONCE_SIZE)
+        aad = f"{tenant_id}:{field}".encode()
+        ciphertext = AESGCM(key).encrypt(nonce, plaintext, aad)
+        payload = base64.b64encode(nonce + ciphertext).decode("ascii")
+        return f"{TOKEN_PREFIX}:{key_id}:{payload}"
+
+    async def _unseal(self, tenant_id: str, field: str, token: str) -> bytes:
+        key_id, _, payload = token[len(TOKEN_PREFIX) + 1 :].partition(":")
+        try:
+            key = await self.key_provider.get_key(tenant_id, key_id)
+            data = base64.b64decode(payload)
+            aad = f"{tenant_id}:{field}".encode()
+            return AESGCM(key).decrypt(
+                data[:_NONCE_SIZE], data[_NONCE_SIZE:], aad
+            )
+        except (KeyError, ValueError, InvalidTag) as e:
+            raise StorageError(
+                f"Cannot decrypt {field} for tenant {tenant_id} (key {key_id})"
+            ) from e
+
+    async def _open_field(self, tenant_id: str, field: str, value: Any) -> Any:
+        if field == "content":
+            if is_sealed(value):
+                return (await self._unseal(tenant_id, field, value)).decode("utf-8")
+            return value
+        if isinstance(value, dict) and is_sealed(value.get(ENCRYPTED_METADATA_KEY)):
+            metadata = dict(value)
+            token = metadata.pop(ENCRYPTED_METADATA_KEY)
+            private = json.loads(await self._unseal(tenant_id, field, token))
+            return {**metadata, **private}
+        return value
+
+    async def _open_memory(
+        self, memory: dict[str, Any], tenant_id: str
+    ) -> dict[str, Any]:
+        opened = dict(memory)
+        for field in _SEALED_FIELDS:
+            if field in opened:
+                opened[field] = await self._open_field(tenant_id, field, opened[field])
+        return opened
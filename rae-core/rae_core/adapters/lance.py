@@ -0,0 +1,456 @@
+"""LanceDB vector store adapter.
+
+Persists vectors in Lance columnar files in a local directory, so embedded
+and single-process deployments get durable vector search without running
+a vector database:
+
+    vector_store = LanceDBVectorStore("/var/lib/agent/vectors", embedding_dim=384)
+
+Default vectors live in one table; named vectors (one per embedding model)
+get a table each, since a Lance vector column has a fixed dimension. Small
+tables are searched exactly. Once a table holds index_min_rows vectors an
+ANN index (IVF-PQ or HNSW) is trained on it; create_index() retrains it
+after the data has grown, and optimize() compacts the files many small
+writes leave behind.
+"""
+
+import json
+import math
+import re
+from enum import Enum
+from typing import Any
+from uuid import UUID
+
+import lancedb
+import pyarrow as pa
+import structlog
+from lancedb.index import HnswSq, IvfPq
+
+from rae_core.config.defaults import DEFAULT_LANCE_INDEX_MIN_ROWS
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.math.distance import MetricConfig, collection_metric, euclid_score
+from rae_core.types.enums import DistanceMetric
+
+logger = structlog.get_logger(__name__)
+
+_NAME = re.compile(r"^[A-Za-z0-9_-]+$")
+
+# Metadata keys kept in their own columns, so search can filter on them
+_FILTER_COLUMNS = ("layer", "agent_id", "session_id", "project")
+
+# Candidates fetched per result when generic metadata filters are applied
+_FILTER_OVERFETCH = 10
+
+# Collection name of the default vectors in a distance_metric dict
+_DEFAULT_COLLECTION = "default"
+
+_DISTANCE_TYPES = {
+    DistanceMetric.COSINE: "cosine",
+    DistanceMetric.EUCLID: "l2",
+    DistanceMetric.DOT: "dot",
+}
+
+
+class LanceIndex(str, Enum):
+    """ANN index trained on a table once it is large enough."""
+
+    IVF_PQ = "ivf_pq"  # Compact, fast to build
+    HNSW = "hnsw"  # Better recall, larger (HNSW over IVF partitions)
+    NONE = "none"  # Exact search only
+
+
+def _quote(value: Any) -> str:
+    return "'" + str(value).replace("'", "''") + "'"
+
+
+def _score(metric: DistanceMetric, distance: float) -> float:
+    """Score of a Lance _distance (see math.distance)."""
+    if metric == DistanceMetric.EUCLID:
+        # Lance reports squared l2 distances
+        return euclid_score(math.sqrt(max(distance, 0.0)))
+    # Cosine distance is 1 - cosine, dot distance 1 - dot product
+    return 1.0 - distance
+
+
+class LanceDBVectorStore(IVectorStore):
+    """LanceDB implementation of the Vector Store interface."""
+
+    def __init__(
+        self,
+        path: str,
+        table_name: str = "memory_vectors",
+        embedding_dim: int = 384,
+        index_type: LanceIndex | str = LanceIndex.IVF_PQ,
+        index_min_rows: int = DEFAULT_LANCE_INDEX_MIN_ROWS,
+        nprobes: int | None = None,
+        distance_metric: MetricConfig = DistanceMetric.COSINE,
+    ):
+        """Initialize LanceDB vector store.
+
+        Args:
+            path: Directory of the database (created if missing)
+            table_name: Table of the default vectors; named vectors go to
+                "<table_name>__<vector_name>"
+            embedding_dim: Dimension of the default vectors
+            index_type: ANN index trained once a table is large enough
+            index_min_rows: Vectors a table needs before it is indexed
+            nprobes: IVF partitions searched per query (LanceDB default if
+                None)
+            distance_metric: Metric of every table, or of the collections
+                named in a dict ("default" for the default vectors, cosine
+                for the others)
+        """
+        if not _NAME.match(table_name):
+            raise ValueError(f"Invalid table name: {table_name!r}")
+        self.path = path
+        self.table_name = table_name
+        self.embedding_dim = embedding_dim
+        self.index_type = LanceIndex(index_type)
+        self.index_min_rows = index_min_rows
+        self.nprobes = nprobes
+        self.distance_metric = distance_metric
+        self._db: Any = None
+        self._tables: dict[str, Any] = {}
+        self._indexed: set[str] = set()
+
+    async def initialize(self) -> None:
+        """Open the database and the default table."""
+        await self._table(self.table_name, self.embedding_dim)
+
+    async def _connect(self) -> Any:
+        if self._db is None:
+            self._db = await lancedb.connect_async(self.path)
+        return self._db
+
+    def _metric(self, name: str) -> DistanceMetric:
+        """Metric of the table called name."""
+        prefix = f"{self.table_name}__"
+        collection = (
+            name[len(prefix) :] if name.startswith(prefix) else _DEFAULT_COLLECTION
+        )
+        return collection_metric(self.distance_metric, collection)
+
+    def _named_table(self, vector_name: str) -> str:
+        if not _NAME.match(vector_name):
+            raise ValidationError(f"Invalid vector name: {vector_name!r}")
+        return f"{self.table_name}__{vector_name}"
+
+    @staticmethod
+    def _schema(dimension: int) -> pa.Schema:
+        return pa.schema(
+            [
+                pa.field("id", pa.string(), nullable=False),
+                pa.field("tenant_id", pa.string(), nullable=False),
+                pa.field("vector", pa.list_(pa.float32(), dimension)),
+                *(pa.field(column, pa.string()) for column in _FILTER_COLUMNS),
+                pa.field("metadata", pa.string()),
+            ]
+        )
+
+    async def _table(self, name: str, dimension: int | None = None) -> Any:
+        """Open a table; create it if a dimension is given, else None if absent."""
+        if name in self._tables:
+            return self._tables[name]
+        db = await self._connect()
+        if name in await db.table_names():
+            table = await db.open_table(name)
+            indices = await table.list_indices()
+            if any("vector" in index.columns for index in indices):
+                self._indexed.add(name)
+        elif dimension is None:
+            return None
+        else:
+            table = await db.create_table(
+                name, schema=self._schema(dimension), exist_ok=True
+            )
+        self._tables[name] = table
+        return table
+
+    async def _tables_of(self) -> list[Any]:
+        """The default table and every named vector table."""
+        db = await self._connect()
+        names = [
+            name
+            for name in await db.table_names()
+            if name == self.table_name or name.startswith(f"{self.table_name}__")
+        ]
+        return [await self._table(name) for name in names]
+
+    @staticmethod
+    def _row(
+        memory_id: UUID,
+        vector: list[float],
+        tenant_id: str,
+        metadata: dict[str, Any] | None,
+    ) -> dict[str, Any]:
+        metadata = metadata or {}
+        row = {"id": str(memory_id), "tenant_id": tenant_id, "vector": vector}
+        for column in _FILTER_COLUMNS:
+            value = metadata.get(column)
+            row[column] = None if value is None else str(value)
+        row["metadata"] = json.dumps(metadata, default=str)
+        return row
+
+    async def _upsert(
+        self, name: str, rows: list[dict[str, Any]], insert_only: bool = False
+    ) -> None:
+        """Write rows keyed by id; insert_only keeps existing rows as they are."""
+        if not rows:
+            return
+        dimension = len(rows[0]["vector"])
+        table = await self._table(name, dimension)
+        data = pa.Table.from_pylist(rows, schema=self._schema(dimension))
+        builder = table.merge_insert("id")
+        if not insert_only:
+            builder = builder.when_matched_update_all()
+        await builder.when_not_matched_insert_all().execute(data)
+        await self._maybe_index(name, table)
+
+    async def _maybe_index(self, name: str, table: Any) -> None:
+        if self.index_type == LanceIndex.NONE or name in self._indexed:
+            return
+        if await table.count_rows() >= self.index_min_rows:
+            await self._create_index(name, table)
+
+    async def _create_index(self, name: str, table: Any) -> None:
+        distance_type = _DISTANCE_TYPES[self._metric(name)]
+        if self.index_type == LanceIndex.HNSW:
+            config: Any = HnswSq(distance_type=distance_type)
+        else:
+            config = IvfPq(distance_type=distance_type)
+        await table.create_index("vector", config=config, replace=True)
+        self._indexed.add(name)
+        logger.info("lance_index_created", table=name, index=self.index_type.value)
+
+    async def create_index(self, vector_name: str | None = None) -> None:
+        """Train (or retrain) the ANN index of a table on its current rows.
+
+        Args:
+            vector_name: Embedding model whose table is indexed (the default
+                vectors if None)
+        """
+        if self.index_type == LanceIndex.NONE:
+            return
+        name = (
+            self.table_name if vector_name is None else self._named_table(vector_name)
+        )
+        table = await self._table(name)
+        if table is not None:
+            await self._create_index(name, table)
+
+    async def optimize(self) -> None:
+        """Compact the data files and bring the indexes up to date."""
+        for table in await self._tables_of():
+            await table.optimize()
+
+    def _check_dimension(self, vector: list[float]) -> None:
+        if len(vector) != self.embedding_dim:
+            raise ValidationError(
+                f"Expected a {self.embedding_dim}-dimensional vector, "
+                f"got {len(vector)}"
+            )
+
+    def _split(
+        self,
+        memory_id: UUID,
+        embedding: list[float] | dict[str, list[float]],
+        tenant_id: str,
+        metadata: dict[str, Any] | None,
+    ) -> tuple[dict[str, Any], bool, dict[str, dict[str, Any]]]:
+        """Default vector row, whether it only fills a gap, and named rows."""
+        if not isinstance(embedding, dict):
+            self._check_dimension(embedding)
+            return self._row(memory_id, embedding, tenant_id, metadata), False, {}
+        # The default or dense entry replaces the default vector; any other
+        # first entry only fills a missing one
+        vector = embedding.get("default") or embedding.get("dense")
+        insert_only = not vector
+        if not vector:
+            vector = next(iter(embedding.values()))
+        self._check_dimension(vector)
+        named = {
+            self._named_table(name): self._row(memory_id, v, tenant_id, metadata)
+            for name, v in embedding.items()
+        }
+        return self._row(memory_id, vector, tenant_id, metadata), insert_only, named
+
+    async def store_vector(
+        self,
+        memory_id: UUID,
+        embedding: list[float] | dict[str, list[float]],
+        tenant_id: str,
+        metadata: dict[str, Any] | None = None,
+    ) -> bool:
+        """Store a vector embedding (or a dict of named vectors)."""
+        row, insert_only, named = self._split(
+            memory_id, embedding, tenant_id, metadata
+        )
+        await self._upsert(self.table_name, [row], insert_only=insert_only)
+        for name, named_row in named.items():
+            await self._upsert(name, [named_row])
+        return True
+
+    async def search_similar(
+        self,
+        query_embedding: list[float],
+        tenant_id: str,
+        layer: str | None = None,
+        limit: int = 10,
+        score_threshold: float | None = None,
+        agent_id: str | None = None,
+        session_id: str | None = None,
+        filters: dict[str, Any] | None = None,
+        project: str | None = None,
+        vector_name: str | None = None,
+        **kwargs: Any,
+    ) -> list[tuple[UUID, float]]:
+        """Search for similar vectors by the collection's distance metric."""
+        name = self.table_name
+        if vector_name is not None:
+            name = self._named_table(vector_name)
+        metric = self._metric(name)
+        # A zero query has no direction to compare
+        if metric == DistanceMetric.COSINE and not any(query_embedding):
+            return []
+        if vector_name is None:
+            self._check_dimension(query_embedding)
+            table = await self._table(name, self.embedding_dim)
+        else:
+            table = await self._table(name)
+            if table is None:
+                return []
+
+        conditions = [f"tenant_id = {_quote(tenant_id)}"]
+        values = {
+            "layer": layer,
+            "agent_id": agent_id,
+            "session_id": session_id,
+            "project": project,
+        }
+        for column in _FILTER_COLUMNS:
+            if values[column] is not None:
+                conditions.append(f"{column} = {_quote(values[column])}")
+
+        query = (
+            table.query()
+            .nearest_to(query_embedding)
+            .distance_type(_DISTANCE_TYPES[metric])
+            .where(" AND ".join(conditions))
+            .limit(limit * _FILTER_OVERFETCH if filters else limit)
+        )
+        if self.nprobes is not None:
+            query = query.nprobes(self.nprobes)
+        rows = await query.to_list()
+
+        results = []
+        for row in rows:
+            if filters:
+                metadata = json.loads(row.get("metadata") or "{}")
+                if any(metadata.get(k) != v for k, v in filters.items()):
+                    continue
+            score = _score(metric, float(row["_distance"]))
+            if score_threshold is not None and score < score_threshold:
+                continue
+            results.append((UUID(row["id"]), score))
+        return results[:limit]
+
+    async def delete_vector(self, memory_id: UUID, tenant_id: str) -> bool:
+        """Delete a vector and all its named vectors."""
+        where = f"id = {_quote(memory_id)} AND tenant_id = {_quote(tenant_id)}"
+        deleted = False
+        for table in await self._tables_of():
+            if await table.count_rows(where):
+                await table.delete(where)
+                deleted = True
+        return deleted
+
+    async def update_vector(
+        self,
+        memory_id: UUID,
+        embedding: list[float] | dict[str, list[float]],
+        tenant_id: str,
+        metadata: dict[str, Any] | None = None,
+    ) -> bool:
+        """Update a vector embedding; False if the memory has none."""
+        if await self.get_vector(memory_id, tenant_id) is None:
+            return False
+        return await self.store_vector(memory_id, embedding, tenant_id, metadata)
+
+    async def get_vector(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        vector_name: str | None = None,
+    ) -> list[float] | None:
+        """Retrieve a vector embedding."""
+        name = (
+            self.table_name if vector_name is None else self._named_table(vector_name)
+        )
+        table = await self._table(name)
+        if table is None:
+            return None
+        rows = (
+            await table.query()
+            .where(f"id = {_quote(memory_id)} AND tenant_id = {_quote(tenant_id)}")
+            .select(["vector"])
+            .limit(1)
+            .to_list()
+        )
+        return [float(x) for x in rows[0]["vector"]] if rows else None
+
+    async def batch_store_vectors(
+        self,
+        vectors: list[
+            tuple[UUID, list[float] | dict[str, list[float]], dict[str, Any]]
+        ],
+        tenant_id: str,
+    ) -> int:
+        """Store multiple vectors with one write per table, skipping invalid ones."""
+        replace: list[dict[str, Any]] = []
+        fill: list[dict[str, Any]] = []
+        named: dict[str, list[dict[str, Any]]] = {}
+        for memory_id, embedding, metadata in vectors:
+            try:
+                row, insert_only, named_rows = self._split(
+                    memory_id, embedding, tenant_id, metadata
+                )
+            except (ValidationError, StopIteration, TypeError):
+                logger.warning("lance_invalid_vector", memory_id=str(memory_id))
+                continue
+            (fill if insert_only else replace).append(row)
+            for name, named_row in named_rows.items():
+                named.setdefault(name, []).append(named_row)
+
+        await self._upsert(self.table_name, replace)
+        await self._upsert(self.table_name, fill, insert_only=True)
+        for name, rows in named.items():
+            await self._upsert(name, rows)
+        return len(replace) + len(fill)
+
+    async def clear_tenant(self, tenant_id: str) -> int:
+        """Delete all vectors of a tenant.
+
+        Returns:
+            Number of default vectors deleted
+        """
+        where = f"tenant_id = {_quote(tenant_id)}"
+        count = 0
+        for table in await self._tables_of():
+            rows = await table.count_rows(where)
+            if table is self._tables.get(self.table_name):
+                count = rows
+            if rows:
+                await table.delete(where)
+        return count
+
+    async def health_check(self) -> bool:
+        """Check the database directory opens."""
+        db = await self._connect()
+        await db.table_names()
+        return True
+
+    async def close(self) -> None:
+        """Drop the open tables; the data is already on disk."""
+        self._tables.clear()
+        self._db = None
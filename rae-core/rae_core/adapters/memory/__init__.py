@@ -3,7 +3,10 @@
 Fast, thread-safe implementations for testing and lightweight deployments.
 """
 
+from rae_core.adapters.memory.audit import InMemoryAuditLogger
 from rae_core.adapters.memory.cache import InMemoryCache
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.outbox import InMemoryOutboxStore
 from rae_core.adapters.memory.storage import InMemoryStorage
 from rae_core.adapters.memory.vector import InMemoryVectorStore
 
@@ -11,4 +14,7 @@ __all__ = [
     "InMemoryStorage",
     "InMemoryVectorStore",
     "InMemoryCache",
+    "InMemoryGraphStore",
+    "InMemoryAuditLogger",
+    "InMemoryOutboxStore",
 ]
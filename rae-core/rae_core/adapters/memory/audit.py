@@ -0,0 +1,50 @@
+"""In-Memory audit logger for RAE-core."""
+
+import asyncio
+from datetime import datetime
+from uuid import UUID
+
+from rae_core.interfaces.audit import IAuditLogger
+from rae_core.models.audit import AuditEntry, AuditOperation
+
+
+class InMemoryAuditLogger(IAuditLogger):
+    """Append-only audit log kept in process memory.
+
+    Entries are partitioned per tenant; useful for tests and single-process
+    deployments.
+    """
+
+    def __init__(self) -> None:
+        # {tenant_id: [entry, ...]} in recording order
+        self._entries: dict[str, list[AuditEntry]] = {}
+        self._lock = asyncio.Lock()
+
+    async def record(self, entry: AuditEntry) -> None:
+        async with self._lock:
+            self._entries.setdefault(entry.tenant_id, []).append(entry)
+
+    async def query(
+        self,
+        tenant_id: str,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        actor: str | None = None,
+        operation: AuditOperation | None = None,
+        memory_id: UUID | None = None,
+        limit: int = 100,
+    ) -> list[AuditEntry]:
+        async with self._lock:
+            entries = list(self._entries.get(tenant_id, []))
+
+        results = [
+            e
+            for e in entries
+            if (start is None or e.timestamp >= start)
+            and (end is None or e.timestamp < end)
+            and (actor is None or e.actor == actor)
+            and (operation is None or e.operation == operation)
+            and (memory_id is None or e.memory_id == memory_id)
+        ]
+        results.sort(key=lambda e: e.timestamp)
+        return results[:limit]
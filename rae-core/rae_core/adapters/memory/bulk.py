@@ -0,0 +1,178 @@
+"""Bulk vector ingest for the in-memory storage.
+
+Inserting vectors one at a time pays the storage lock, a log record and
+(with the default fsync policy) an fsync per vector. VectorBulkIngest
+quantizes a batch into side arenas without touching the main index, then
+merges it in one step: a single lock acquisition, one arena extend per model
+and one batched log write.
+"""
+
+from collections import defaultdict
+from typing import TYPE_CHECKING, Any
+from uuid import UUID
+
+from rae_core.math.quantization_bytes import (
+    dequantize_vector_bytes,
+    quantize_vector_bytes,
+)
+from rae_core.math.vector_quantization import FixedPointCodec, VectorCodec
+
+if TYPE_CHECKING:
+    from rae_core.adapters.memory.storage import InMemoryStorage
+
+
+class VectorBulkIngest:
+    """Side index collecting vectors for InMemoryStorage.
+
+        async with storage.bulk_ingest() as batch:
+            for memory_id, embedding in rows:
+                batch.add(memory_id, embedding, tenant_id)
+
+    Added vectors become searchable when the batch is merged (on leaving
+    the context, or by calling merge()). Vectors of memories that no longer
+    exist at merge time are skipped.
+    """
+
+    def __init__(self, storage: "InMemoryStorage"):
+        self._storage = storage
+        self._arenas: dict[str, bytearray] = defaultdict(bytearray)
+        # {model_name: {memory_id: (offset in side arena, metadata)}}
+        self._entries: dict[str, dict[UUID, tuple[int, dict[str, Any]]]] = (
+            defaultdict(dict)
+        )
+        self._dims: dict[str, int] = {}
+
+    def __len__(self) -> int:
+        return len({mid for entries in self._entries.values() for mid in entries})
+
+    async def __aenter__(self) -> "VectorBulkIngest":
+        return self
+
+    async def __aexit__(self, exc_type: Any, exc: Any, tb: Any) -> None:
+        if exc_type is None:
+            await self.merge()
+
+    def add(
+        self,
+        memory_id: UUID,
+        embedding: list[float] | dict[str, list[float]],
+        tenant_id: str,
+        metadata: dict[str, Any] | None = None,
+    ) -> bool:
+        """Stage a vector (or named vectors) for a memory.
+
+        Returns:
+            False if the embedding is not a vector or dict of vectors
+
+        Raises:
+            ValueError: If a vector's dimension differs from its model's
+        """
+        if isinstance(embedding, list):
+            vectors = {"default": embedding}
+        elif isinstance(embedding, dict):
+            vectors = embedding
+        else:
+            return False
+        if not all(isinstance(v, (list, tuple)) for v in vectors.values()):
+            return False
+
+        for model_name, vector in vectors.items():
+            expected = self._storage._vector_dims.get(
+                model_name, self._dims.get(model_name)
+            )
+            if expected is not None and expected != len(vector):
+                raise ValueError(
+                    f"Dimension mismatch for model {model_name}: "
+                    f"expected {expected}, got {len(vector)}"
+                )
+            self._dims[model_name] = len(vector)
+
+        for model_name, vector in vectors.items():
+            data = quantize_vector_bytes(vector)
+            arena = self._arenas[model_name]
+            entries = self._entries[model_name]
+            meta = {**(metadata or {}), "tenant_id": tenant_id}
+            if memory_id in entries:
+                offset = entries[memory_id][0]
+                arena[offset : offset + len(data)] = data
+            else:
+                offset = len(arena)
+                arena.extend(data)
+            entries[memory_id] = (offset, meta)
+        return True
+
+    async def merge(self) -> int:
+        """Merge the staged vectors into the main index.
+
+        Returns:
+            Number of memories whose vectors were stored
+        """
+        storage = self._storage
+        merged: set[UUID] = set()
+        async with storage._locks.all():
+            records = []
+            for model_name, entries in self._entries.items():
+                dim = self._dims[model_name]
+                if storage._vector_dims.setdefault(model_name, dim) != dim:
+                    raise ValueError(
+                        f"Dimension mismatch for model {model_name}: "
+                        f"expected {storage._vector_dims[model_name]}, got {dim}"
+                    )
+                side = self._arenas[model_name]
+                codec = storage._codec(model_name, dim)
+                if not isinstance(codec, FixedPointCodec):
+                    # Staged as fixed point; convert to the collection's codes
+                    side, entries = self._reencode(side, entries, dim, codec)
+                arena = storage._vector_arenas[model_name]
+                index = storage._vector_indices[model_name]
+                stride = codec.code_size
+
+                live = [mid for mid in entries if mid in storage._memories]
+                if len(live) == len(entries) and index.keys().isdisjoint(live):
+                    # Fast path: append the whole side arena at once
+                    base = len(arena)
+                    arena.extend(side)
+                    for mid, (offset, meta) in entries.items():
+                        index[mid] = base + offset
+                        storage._set_vector_metadata(model_name, mid, meta)
+                else:
+                    for mid in live:
+                        offset, meta = entries[mid]
+                        data = side[offset : offset + stride]
+                        if mid in index:
+                            start = index[mid]
+                            arena[start : start + stride] = data
+                        else:
+                            index[mid] = len(arena)
+                            arena.extend(data)
+                        storage._set_vector_metadata(model_name, mid, meta)
+
+                merged.update(live)
+                if storage._wal:
+                    records.extend(
+                        storage._vector_record(model_name, mid) for mid in live
+                    )
+            storage._log_many(records)
+            for model_name in self._entries:
+                storage._maybe_train(model_name)
+
+        self._arenas.clear()
+        self._entries.clear()
+        self._dims.clear()
+        return len(merged)
+
+    @staticmethod
+    def _reencode(
+        side: bytearray,
+        entries: dict[UUID, tuple[int, dict[str, Any]]],
+        dim: int,
+        codec: VectorCodec,
+    ) -> tuple[bytearray, dict[UUID, tuple[int, dict[str, Any]]]]:
+        stride = dim * 4
+        encoded = bytearray()
+        moved = {}
+        for mid, (offset, meta) in entries.items():
+            vector = dequantize_vector_bytes(bytes(side[offset : offset + stride]))
+            moved[mid] = (len(encoded), meta)
+            encoded.extend(codec.encode(vector))
+        return encoded, moved
@@ -331,6 +331,10 @@ class InMemoryCache(ICacheProvider):
                 "keys_with_ttl": keys_with_ttl,
             }
 
+    async def health_check(self) -> bool:
+        """In-process cache; always available."""
+        return True
+
     async def clear_all(self) -> int:
         """Clear all data (use with caution!).
 
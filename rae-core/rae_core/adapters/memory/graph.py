@@ -0,0 +1,373 @@
+"""In-Memory graph store adapter for RAE-core.
+
+Dictionary-based knowledge graph for testing and lightweight deployments.
+"""
+
+from datetime import datetime
+from typing import Any
+from uuid import UUID
+
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.models.graph import TraversalLimits
+from rae_core.utils.clock import IClock, SystemClock
+from rae_core.utils.graph_snapshot import GraphSnapshot
+from rae_core.utils.graph_traversal import (
+    NeighborEdge,
+    apply_visit_budget,
+    bidirectional_path,
+    bounded_bfs,
+)
+from rae_core.utils.locks import TenantLocks
+from rae_core.utils.wal import FsyncPolicy, WriteAheadLog
+
+# (source_id, target_id, edge_type)
+EdgeKey = tuple[UUID, UUID, str]
+
+
+class InMemoryGraphStore(IGraphStore):
+    """In-memory implementation of IGraphStore.
+
+    Features:
+    - Tenant-isolated nodes and edges
+    - Upsert semantics matching SQLiteGraphStore
+    - Traversal safeguards via TraversalLimits
+    - Outgoing/incoming adjacency maps, so traversal cost follows node degree
+    - Immutable CSR snapshots for whole-graph analytics
+    - Per-tenant lock shards, so tenants do not wait on each other
+    - Optional write-ahead log (wal_path) replayed on startup
+    """
+
+    def __init__(
+        self,
+        clock: IClock | None = None,
+        wal_path: str | None = None,
+        fsync_policy: FsyncPolicy | str = FsyncPolicy.ALWAYS,
+        compact_after: int | None = None,
+    ) -> None:
+        """Initialize in-memory graph store.
+
+        Args:
+            clock: Time source
+            wal_path: Append-only log file; state is replayed from it on startup
+            fsync_policy: When log records are fsynced ("always", "batch", "never")
+            compact_after: Compact the log after this many appended records
+        """
+        self._clock = clock or SystemClock()
+
+        # {tenant_id: {node_id: node}}
+        self._nodes: dict[str, dict[UUID, dict[str, Any]]] = {}
+        # {tenant_id: {(source_id, target_id, type): edge}}
+        self._edges: dict[str, dict[EdgeKey, dict[str, Any]]] = {}
+        # {tenant_id: {node_id: ordered set of edge keys}}, by edge direction
+        self._outgoing: dict[str, dict[UUID, dict[EdgeKey, None]]] = {}
+        self._incoming: dict[str, dict[UUID, dict[EdgeKey, None]]] = {}
+
+        # Per-tenant lock shards (see utils.locks)
+        self._locks = TenantLocks()
+
+        # Write-ahead log (optional durability)
+        self._wal = WriteAheadLog(wal_path, fsync_policy) if wal_path else None
+        self._compact_after = compact_after
+        if self._wal:
+            for record in self._wal.replay():
+                self._apply_wal_record(record)
+
+    async def create_node(
+        self,
+        node_id: UUID,
+        node_type: str,
+        tenant_id: str,
+        properties: dict[str, Any] | None = None,
+    ) -> bool:
+        """Create a graph node, updating properties if it already exists."""
+        async with self._locks.hold(tenant_id):
+            nodes = self._nodes.setdefault(tenant_id, {})
+            if node_id in nodes:
+                nodes[node_id]["properties"] = dict(properties or {})
+            else:
+                nodes[node_id] = {
+                    "id": node_id,
+                    "type": node_type,
+                    "tenant_id": tenant_id,
+                    "properties": dict(properties or {}),
+                    "created_at": self._clock.now(),
+                }
+            self._log({"op": "node", "node": nodes[node_id]})
+            return True
+
+    async def create_edge(
+        self,
+        source_id: UUID,
+        target_id: UUID,
+        edge_type: str,
+        tenant_id: str,
+        weight: float = 1.0,
+        properties: dict[str, Any] | None = None,
+    ) -> bool:
+        """Create a graph edge, updating weight/properties if it exists."""
+        async with self._locks.hold(tenant_id):
+            edges = self._edges.setdefault(tenant_id, {})
+            key = (source_id, target_id, edge_type)
+            if key in edges:
+                created_at: datetime = edges[key]["created_at"]
+            else:
+                created_at = self._clock.now()
+                self._index_edge(tenant_id, key)
+            edges[key] = {
+                "source_id": source_id,
+                "target_id": target_id,
+                "type": edge_type,
+                "weight": weight,
+                "tenant_id": tenant_id,
+                "properties": dict(properties or {}),
+                "created_at": created_at,
+            }
+            self._log({"op": "edge", "edge": edges[key]})
+            return True
+
+    async def get_neighbors(
+        self,
+        node_id: UUID,
+        tenant_id: str,
+        edge_type: str | None = None,
+        direction: str = "both",
+        max_depth: int = 1,
+        limits: TraversalLimits | None = None,
+    ) -> list[UUID]:
+        """Get neighboring nodes using bounded BFS traversal."""
+
+        async def expand(current: UUID) -> list[NeighborEdge]:
+            async with self._locks.hold(tenant_id):
+                return self._direct_neighbors(
+                    self._adjacent_edges(tenant_id, current, direction), edge_type
+                )
+
+        return await bounded_bfs(node_id, expand, max_depth, limits)
+
+    async def delete_node(self, node_id: UUID, tenant_id: str) -> bool:
+        """Delete a node and its edges."""
+        async with self._locks.hold(tenant_id):
+            nodes = self._nodes.get(tenant_id, {})
+            if node_id not in nodes:
+                return False
+            self._delete_node_sync(node_id, tenant_id)
+            self._log({"op": "delete_node", "id": node_id, "tenant_id": tenant_id})
+            return True
+
+    async def delete_edge(
+        self,
+        source_id: UUID,
+        target_id: UUID,
+        edge_type: str,
+        tenant_id: str,
+    ) -> bool:
+        """Delete an edge."""
+        async with self._locks.hold(tenant_id):
+            edges = self._edges.get(tenant_id, {})
+            key = (source_id, target_id, edge_type)
+            if edges.pop(key, None) is None:
+                return False
+            self._unindex_edge(tenant_id, key)
+            self._log(
+                {
+                    "op": "delete_edge",
+                    "key": [source_id, target_id, edge_type],
+                    "tenant_id": tenant_id,
+                }
+            )
+            return True
+
+    async def shortest_path(
+        self,
+        source_id: UUID,
+        target_id: UUID,
+        tenant_id: str,
+        max_depth: int = 5,
+    ) -> list[UUID] | None:
+        """Find shortest (undirected) path using bidirectional BFS."""
+        async with self._locks.hold(tenant_id):
+            outgoing = self._outgoing.get(tenant_id, {})
+            incoming = self._incoming.get(tenant_id, {})
+
+            def adjacent(node_id: UUID) -> list[UUID]:
+                return [key[1] for key in outgoing.get(node_id, ())] + [
+                    key[0] for key in incoming.get(node_id, ())
+                ]
+
+            return bidirectional_path(source_id, target_id, adjacent, max_depth)
+
+    async def get_subgraph(
+        self,
+        node_ids: list[UUID],
+        tenant_id: str,
+        include_edges: bool = True,
+        limits: TraversalLimits | None = None,
+    ) -> dict[str, Any]:
+        """Extract a subgraph."""
+        node_ids = apply_visit_budget(list(node_ids), limits)
+        wanted = set(node_ids)
+
+        async with self._locks.hold(tenant_id):
+            nodes = self._nodes.get(tenant_id, {})
+            result: dict[str, Any] = {
+                "nodes": [dict(nodes[nid]) for nid in node_ids if nid in nodes],
+                "edges": [],
+            }
+            if include_edges:
+                edges = self._edges.get(tenant_id, {})
+                outgoing = self._outgoing.get(tenant_id, {})
+                result["edges"] = [
+                    dict(edges[key])
+                    for source in dict.fromkeys(node_ids)
+                    for key in outgoing.get(source, ())
+                    if key[1] in wanted
+                ]
+        return result
+
+    async def snapshot(
+        self, tenant_id: str, edge_type: str | None = None
+    ) -> GraphSnapshot:
+        """Immutable CSR snapshot of a tenant's graph.
+
+        The lock is only held while edges are copied; the CSR arrays are
+        built afterwards, and analytics on the snapshot (PageRank,
+        communities) run without blocking writers.
+
+        Args:
+            tenant_id: Tenant identifier
+            edge_type: Only include edges of this type
+        """
+        async with self._locks.hold(tenant_id):
+            nodes = list(self._nodes.get(tenant_id, {}))
+            edges = [
+                (edge["source_id"], edge["target_id"], edge["weight"])
+                for edge in self._edges.get(tenant_id, {}).values()
+                if edge_type is None or edge["type"] == edge_type
+            ]
+        return GraphSnapshot.from_edges(edges, nodes)
+
+    async def health_check(self) -> bool:
+        """In-process store; always available."""
+        return True
+
+    async def close(self) -> None:
+        """Flush and close the write-ahead log."""
+        if self._wal:
+            self._wal.close()
+
+    async def compact_log(self) -> int:
+        """Rewrite the write-ahead log as a minimal snapshot of current state.
+
+        Returns:
+            Number of records in the compacted log (0 without a log)
+        """
+        async with self._locks.all():
+            return self._compact_log_sync()
+
+    def _delete_node_sync(self, node_id: UUID, tenant_id: str) -> None:
+        """Remove a node and its edges (assumes lock is held)."""
+        self._nodes.get(tenant_id, {}).pop(node_id, None)
+        edges = self._edges.get(tenant_id, {})
+        keys = {
+            **self._outgoing.get(tenant_id, {}).get(node_id, {}),
+            **self._incoming.get(tenant_id, {}).get(node_id, {}),
+        }
+        for key in keys:
+            del edges[key]
+            self._unindex_edge(tenant_id, key)
+
+    def _index_edge(self, tenant_id: str, key: EdgeKey) -> None:
+        self._outgoing.setdefault(tenant_id, {}).setdefault(key[0], {})[key] = None
+        self._incoming.setdefault(tenant_id, {}).setdefault(key[1], {})[key] = None
+
+    def _unindex_edge(self, tenant_id: str, key: EdgeKey) -> None:
+        for index, node_id in (
+            (self._outgoing.get(tenant_id, {}), key[0]),
+            (self._incoming.get(tenant_id, {}), key[1]),
+        ):
+            keys = index.get(node_id)
+            if keys is None:
+                continue
+            keys.pop(key, None)
+            if not keys:
+                del index[node_id]
+
+    def _adjacent_edges(
+        self, tenant_id: str, node_id: UUID, direction: str
+    ) -> list[tuple[UUID, dict[str, Any]]]:
+        """(neighbor, edge) pairs of a node (assumes lock is held).
+
+        Only the adjacency map of the requested direction is read, so a hub
+        with many incoming edges stays cheap to expand outwards.
+        """
+        edges = self._edges.get(tenant_id, {})
+        pairs: list[tuple[UUID, dict[str, Any]]] = []
+        if direction in ("out", "both"):
+            keys = self._outgoing.get(tenant_id, {}).get(node_id, ())
+            pairs.extend((key[1], edges[key]) for key in keys)
+        if direction in ("in", "both"):
+            keys = self._incoming.get(tenant_id, {}).get(node_id, ())
+            pairs.extend((key[0], edges[key]) for key in keys)
+        return pairs
+
+    def _log(self, record: dict[str, Any]) -> None:
+        """Append a state change to the log (assumes lock is held)."""
+        if not self._wal:
+            return
+        self._wal.append(record)
+        if self._compact_after and self._wal.growth >= self._compact_after:
+            self._compact_log_sync()
+
+    def _compact_log_sync(self) -> int:
+        if not self._wal:
+            return 0
+
+        def snapshot() -> Any:
+            for nodes in self._nodes.values():
+                for node in nodes.values():
+                    yield {"op": "node", "node": node}
+            for edges in self._edges.values():
+                for edge in edges.values():
+                    yield {"op": "edge", "edge": edge}
+
+        self._wal.compact(snapshot())
+        return self._wal.record_count
+
+    def _apply_wal_record(self, record: dict[str, Any]) -> None:
+        """Re-apply a logged state change during replay."""
+        op = record.get("op")
+        if op == "node":
+            node = record["node"]
+            self._nodes.setdefault(node["tenant_id"], {})[node["id"]] = node
+        elif op == "edge":
+            edge = record["edge"]
+            key = (edge["source_id"], edge["target_id"], edge["type"])
+            self._edges.setdefault(edge["tenant_id"], {})[key] = edge
+            self._index_edge(edge["tenant_id"], key)
+        elif op == "delete_node":
+            self._delete_node_sync(record["id"], record["tenant_id"])
+        elif op == "delete_edge":
+            source_id, target_id, edge_type = record["key"]
+            key = (source_id, target_id, edge_type)
+            if self._edges.get(record["tenant_id"], {}).pop(key, None) is not None:
+                self._unindex_edge(record["tenant_id"], key)
+
+    @staticmethod
+    def _direct_neighbors(
+        pairs: list[tuple[UUID, dict[str, Any]]],
+        edge_type: str | None,
+    ) -> list[NeighborEdge]:
+        """One hop of neighbors, outgoing edges first, each in insertion order.
+
+        A neighbor linked by several edges keeps its strongest one.
+        """
+        neighbors: dict[UUID, NeighborEdge] = {}
+        for other, edge in pairs:
+            if edge_type and edge["type"] != edge_type:
+                continue
+            current = neighbors.get(other)
+            if current is None or edge["weight"] > current.weight:
+                neighbors[other] = NeighborEdge(
+                    other, edge["weight"], edge["created_at"]
+                )
+        return list(neighbors.values())
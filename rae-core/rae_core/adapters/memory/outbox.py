@@ -0,0 +1,58 @@
+"""In-Memory event outbox for RAE-core."""
+
+import asyncio
+
+from rae_core.events.models import MemoryEvent
+from rae_core.interfaces.outbox import IOutboxStore
+from rae_core.models.outbox import OutboxEntry
+
+
+class InMemoryOutboxStore(IOutboxStore):
+    """Event outbox kept in process memory.
+
+    Survives nothing beyond the process; useful for tests and for decoupling
+    slow sinks from the write path in single-process deployments.
+    """
+
+    def __init__(self) -> None:
+        self._entries: list[OutboxEntry] = []
+        self._cursors: dict[str, int] = {}
+        self._last_sequence = 0
+        self._lock = asyncio.Lock()
+
+    async def append(self, events: list[MemoryEvent]) -> list[int]:
+        async with self._lock:
+            sequences = []
+            for event in events:
+                self._last_sequence += 1
+                self._entries.append(
+                    OutboxEntry(
+                        sequence=self._last_sequence,
+                        event_id=event.event_id,
+                        event_type=type(event).__name__,
+                        tenant_id=event.tenant_id,
+                        payload=event.model_dump(mode="json"),
+                    )
+                )
+                sequences.append(self._last_sequence)
+            return sequences
+
+    async def publish(self, event: MemoryEvent) -> int:
+        (sequence,) = await self.append([event])
+        return sequence
+
+    async def read(self, after: int, limit: int = 100) -> list[OutboxEntry]:
+        async with self._lock:
+            return [e for e in self._entries if e.sequence > after][:limit]
+
+    async def get_cursor(self, sink: str) -> int:
+        return self._cursors.get(sink, 0)
+
+    async def set_cursor(self, sink: str, sequence: int) -> None:
+        self._cursors[sink] = sequence
+
+    async def prune(self, up_to: int) -> int:
+        async with self._lock:
+            before = len(self._entries)
+            self._entries = [e for e in self._entries if e.sequence > up_to]
+            return before - len(self._entries)
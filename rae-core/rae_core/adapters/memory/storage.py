@@ -5,20 +5,41 @@ and fixed-point arithmetic for absolute determinism (System 87.0).
 """
 
 import asyncio
-from collections import defaultdict
+import copy
+import heapq
+from collections import Counter, defaultdict
+from collections.abc import Iterable
 from datetime import datetime, timezone
-from typing import Any, cast
+from typing import Any
 from uuid import UUID, uuid4
 
-from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.adapters.memory.bulk import VectorBulkIngest
+from rae_core.exceptions.base import NotFoundError, VersionConflictError
+from rae_core.interfaces.storage import LIFECYCLE_FIELDS, IMemoryStorage
 from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.query import RANGE_FILTERS, TIME_FILTERS, matches_range
+from rae_core.utils.changelog import change_entry, field_changes
 from rae_core.utils.clock import IClock, SystemClock
-from rae_core.math.quantization_bytes import (
-    quantize_vector_bytes,
-    cosine_similarity_bytes,
-    dequantize_vector_bytes
+from rae_core.math.distance import MetricConfig, collection_metric, similarity
+from rae_core.math.quantization_bytes import dequantize_vector_bytes
+from rae_core.math.vector_quantization import (
+    FixedPointCodec,
+    ProductQuantizer,
+    ScalarQuantizer,
+    VectorCodec,
+    make_codec,
 )
+from rae_core.models.quantization import QuantizationConfig
+from rae_core.types.enums import DistanceMetric
 from rae_core.utils.hashing import bloom_filter_fingerprint, stable_hash
+from rae_core.utils.locks import TenantLocks
+from rae_core.utils.wal import FsyncPolicy, WriteAheadLog
+
+# Fields captured in each memory revision
+VERSIONED_FIELDS = ("content", "layer", "tags", "metadata", "importance")
+# Vectors compared by a search between yields to the event loop
+_SCAN_YIELD_EVERY = 2048
+_NO_IDS: frozenset[UUID] = frozenset()
 
 
 class InMemoryStorage(IMemoryStorage, IVectorStore):
@@ -28,11 +49,40 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
     - Contiguous Memory Arenas (bytearray) for vectors to ensure L1/L2 cache locality.
     - Fixed-Point Quantization (int32) for deterministic arithmetic.
     - Offset-based indexing instead of object references.
+    - Optional write-ahead log (wal_path) replayed on startup for durability.
+    - Optional per-collection quantization (scalar or product codes) in place
+      of the int32 vectors, with the top candidates of a search re-scored.
+    - Per-collection distance metric (cosine, dot product or euclidean).
     """
 
-    def __init__(self, clock: IClock | None = None) -> None:
-        """Initialize in-memory storage."""
+    def __init__(
+        self,
+        clock: IClock | None = None,
+        wal_path: str | None = None,
+        fsync_policy: FsyncPolicy | str = FsyncPolicy.ALWAYS,
+        compact_after: int | None = None,
+        history_limit: int = 10,
+        quantization: QuantizationConfig | dict[str, QuantizationConfig] | None = None,
+        distance_metric: MetricConfig = DistanceMetric.COSINE,
+    ) -> None:
+        """Initialize in-memory storage.
+
+        Args:
+            clock: Time source
+            wal_path: Append-only log file; state is replayed from it on startup
+            fsync_policy: When log records are fsynced ("always", "batch", "never")
+            compact_after: Compact the log after this many appended records
+            history_limit: Past revisions retained per memory (0 disables history)
+            quantization: Compression of every vector collection, or of the
+                collections (embedding models) named in a dict. Quantized
+                collections keep only the codes, so get_vector returns an
+                approximation and re-scoring uses the decoded vectors.
+            distance_metric: Metric scoring every vector collection, or the
+                collections named in a dict (cosine for the others); see
+                math.distance
+        """
         self._clock = clock or SystemClock()
+        self._history_limit = max(0, history_limit)
         
         # Main storage: {memory_id: memory_dict}
         self._memories: dict[UUID, dict[str, Any]] = {}
@@ -49,6 +99,16 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         self._by_layer: dict[tuple[str, str], set[UUID]] = defaultdict(set)
         self._by_tags: dict[tuple[str, str], set[UUID]] = defaultdict(set)
 
+        # Soft-deleted memories (trash), overall and per tenant
+        self._deleted: set[UUID] = set()
+        self._deleted_by_tenant: dict[str, set[UUID]] = defaultdict(set)
+
+        # Past revisions: {memory_id: [revision, ...]} oldest first
+        self._history: dict[UUID, list[dict[str, Any]]] = {}
+
+        # Field-level change log: {memory_id: [entry, ...]} oldest first
+        self._changelog: dict[UUID, list[dict[str, Any]]] = {}
+
         # Vector Arenas: {model_name: bytearray}
         # Stores packed int32 vectors contiguously.
         self._vector_arenas: dict[str, bytearray] = defaultdict(bytearray)
@@ -61,12 +121,36 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         # Stores metadata alongside vectors for filtering.
         self._vector_metadata: dict[str, dict[UUID, dict[str, Any]]] = defaultdict(dict)
         
+        # Vectors per tenant: {model_name: {tenant_id: {memory_id}}}
+        # Searches scan only their tenant's vectors.
+        self._vectors_by_tenant: dict[str, dict[Any, set[UUID]]] = defaultdict(
+            lambda: defaultdict(set)
+        )
+
         # Vector Dimensions: {model_name: dimension_size}
         # Used to validate vector sizes and calculate stride.
         self._vector_dims: dict[str, int] = {}
 
-        # Thread safety
-        self._lock = asyncio.Lock()
+        # Vector Codecs: {model_name: codec of the arena}
+        # int32 fixed point unless the collection is quantized; a product
+        # quantized collection stays fixed point until its codebooks are
+        # trained (see _maybe_train).
+        self._quantization = quantization
+        self._distance_metric = distance_metric
+        self._codecs: dict[str, VectorCodec] = {}
+        self._untrained: dict[str, ProductQuantizer] = {}
+        # Codebooks read from the log, to decode logged product codes
+        self._logged_codebooks: dict[str, ProductQuantizer] = {}
+
+        # Per-tenant lock shards (see utils.locks)
+        self._locks = TenantLocks()
+
+        # Write-ahead log (optional durability)
+        self._wal = WriteAheadLog(wal_path, fsync_policy) if wal_path else None
+        self._compact_after = compact_after
+        if self._wal:
+            for record in self._wal.replay():
+                self._apply_wal_record(record)
 
     # =========================================================================
     # IVectorStore Implementation (The "Arena" & "Scalpel")
@@ -80,7 +164,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         metadata: dict[str, Any] | None = None,
     ) -> bool:
         """Store a vector embedding in contiguous arena."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             # Check if memory exists before storing vector
             if memory_id not in self._memories:
                 return False
@@ -109,8 +193,8 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                         f"expected {self._vector_dims[model_name]}, got {dim}"
                     )
 
-                # Quantize to bytes (Fixed-Point)
-                vector_bytes = quantize_vector_bytes(vector)
+                # Encode with the collection's codec (fixed point by default)
+                vector_bytes = self._codec(model_name, dim).encode(vector)
                 vector_len = len(vector_bytes)
 
                 # Check if update or insert
@@ -130,9 +214,11 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 meta = metadata or {}
                 # Ensure tenant_id is in metadata for security filtering
                 meta["tenant_id"] = tenant_id
-                self._vector_metadata[model_name][memory_id] = meta
+                self._set_vector_metadata(model_name, memory_id, meta)
+                self._log_vector(model_name, memory_id)
 
-            return True
+        await self._train_when_due(vectors)
+        return True
 
     async def search_similar(
         self,
@@ -145,28 +231,32 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         session_id: str | None = None,
         filters: dict[str, Any] | None = None,
         project: str | None = None,
+        vector_name: str | None = None,
         **kwargs: Any,
     ) -> list[tuple[UUID, float]]:
         """Search for similar vectors using deterministic fixed-point arithmetic."""
-        async with self._lock:
-            model_name = kwargs.get("model_name", "default")
+        async with self._locks.hold(tenant_id):
+            # model_name is the legacy spelling of vector_name
+            model_name = vector_name or kwargs.get("model_name", "default")
+            include_deleted = kwargs.get("include_deleted", False)
             
             if model_name not in self._vector_arenas:
                 return []
 
             # Prepare query
-            query_bytes = quantize_vector_bytes(query_embedding)
-            dim_bytes = len(query_bytes)
-            
             if model_name in self._vector_dims:
-                 expected_dim = self._vector_dims[model_name] * 4
-                 if dim_bytes != expected_dim:
+                 if len(query_embedding) != self._vector_dims[model_name]:
                      # Fail silently or raise? Standard is usually empty result on mismatch
                      return []
+            codec = self._codec(model_name, len(query_embedding))
+            prepared = codec.prepare(query_embedding)
+            dim_bytes = codec.code_size
 
             arena = self._vector_arenas[model_name]
             indices = self._vector_indices[model_name]
             metadatas = self._vector_metadata[model_name]
+            rescore = self._rescoring(model_name)
+            metric = collection_metric(self._distance_metric, model_name)
 
             results: list[tuple[UUID, float]] = []
 
@@ -179,8 +269,17 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
 
             # Linear Scan (Simulating low-level scan)
             # In C++ this would be a SIMD loop. In Python, we iterate keys to look up offsets.
-            # Iterating keys is faster than slicing bytearray repeatedly in Python.
-            for mem_id, offset in indices.items():
+            # Only the tenant's vectors are scanned; the snapshot keeps the scan
+            # valid while other tenants write during its pauses.
+            candidates = list(self._vectors_by_tenant[model_name].get(tenant_id, ()))
+            for scanned, mem_id in enumerate(candidates, 1):
+                # Let other tenants run during long scans
+                if scanned % _SCAN_YIELD_EVERY == 0:
+                    await asyncio.sleep(0)
+                offset = indices.get(mem_id)
+                if offset is None:
+                    continue
+
                 # 0. Bloom Filter Check (O(1) Bitwise Rejection) - Phase 2
                 if query_mask:
                      mem_mask = self._bloom_filters.get(mem_id, 0)
@@ -192,6 +291,8 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 meta = metadatas.get(mem_id, {})
                 if meta.get("tenant_id") != tenant_id:
                     continue
+                if not include_deleted and mem_id in self._deleted:
+                    continue
                 
                 if layer and meta.get("layer") != layer:
                     continue
@@ -229,12 +330,21 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 vec_bytes = arena[offset : offset + dim_bytes]
 
                 # 3. Compute Similarity (Deterministic)
-                score = cosine_similarity_bytes(query_bytes, vec_bytes)
+                if metric == DistanceMetric.COSINE:
+                    score = codec.similarity(prepared, vec_bytes)
+                else:
+                    vector = codec.decode(bytes(vec_bytes))
+                    score = similarity(metric, query_embedding, vector)
 
                 if score <= 0.0:
                     continue
 
-                if score_threshold is not None and score < score_threshold:
+                # Re-scored candidates are thresholded on their re-scored value
+                if (
+                    rescore is None
+                    and score_threshold is not None
+                    and score < score_threshold
+                ):
                     continue
 
                 results.append((mem_id, score))
@@ -243,6 +353,18 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
             # Python's sort is stable.
             results.sort(key=lambda x: (x[1], x[0].hex), reverse=True)
 
+            if rescore is not None:
+                # Re-score the best candidates with the float query
+                rescored = []
+                for mem_id, _ in results[: limit * rescore.rescore_factor]:
+                    offset = indices[mem_id]
+                    vector = codec.decode(bytes(arena[offset : offset + dim_bytes]))
+                    score = similarity(metric, query_embedding, vector)
+                    if score_threshold is None or score >= score_threshold:
+                        rescored.append((mem_id, score))
+                rescored.sort(key=lambda x: (x[1], x[0].hex), reverse=True)
+                results = rescored
+
             return results[:limit]
 
     async def search_similar_batch(
@@ -263,14 +385,17 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         self,
         memory_id: UUID,
         tenant_id: str,
+        vector_name: str | None = None,
     ) -> list[float] | None:
         """Retrieve a vector embedding."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             # Default model strategy: Try "default", then fallback to any available
-            model_name = "default"
+            model_name = vector_name or "default"
             
             # Check if default exists for this ID
             if not (model_name in self._vector_indices and memory_id in self._vector_indices[model_name]):
+                if vector_name is not None:
+                    return None
                 # Fallback: Find first model containing this ID
                 found_model = None
                 for m_name, index in self._vector_indices.items():
@@ -283,8 +408,8 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 model_name = found_model
             
             offset = self._vector_indices[model_name][memory_id]
-            dim = self._vector_dims[model_name]
-            byte_len = dim * 4
+            codec = self._codec(model_name, self._vector_dims[model_name])
+            byte_len = codec.code_size
             
             vec_bytes = self._vector_arenas[model_name][offset : offset + byte_len]
             
@@ -293,7 +418,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
             if meta.get("tenant_id") != tenant_id:
                 return None
                 
-            return dequantize_vector_bytes(bytes(vec_bytes))
+            return codec.decode(bytes(vec_bytes))
 
     async def delete_vector(
         self,
@@ -301,17 +426,19 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         tenant_id: str,
     ) -> bool:
         """Delete a vector."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             deleted = False
             for model_name in list(self._vector_indices.keys()):
                 if memory_id in self._vector_indices[model_name]:
                     # Check tenant
                     meta = self._vector_metadata[model_name].get(memory_id, {})
                     if meta.get("tenant_id") == tenant_id:
-                        del self._vector_indices[model_name][memory_id]
-                        del self._vector_metadata[model_name][memory_id]
+                        self._drop_vector(model_name, memory_id)
                         # We don't compact the arena immediately (expensive). 
                         # Fragmentation is accepted in this simulated version.
+                        self._log(
+                            {"op": "delete_vector", "id": memory_id, "model": model_name}
+                        )
                         deleted = True
             return deleted
 
@@ -332,12 +459,15 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         ],
         tenant_id: str,
     ) -> int:
-        """Store multiple vectors."""
-        count = 0
+        """Store multiple vectors in one merge (see bulk_ingest)."""
+        batch = self.bulk_ingest()
         for mid, emb, meta in vectors:
-            if await self.store_vector(mid, emb, tenant_id, meta):
-                count += 1
-        return count
+            batch.add(mid, emb, tenant_id, meta)
+        return await batch.merge()
+
+    def bulk_ingest(self) -> VectorBulkIngest:
+        """Start a bulk vector ingest merged into the index in one step."""
+        return VectorBulkIngest(self)
 
     # =========================================================================
     # IMemoryStorage Implementation (Legacy + Core)
@@ -352,12 +482,12 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         **kwargs: Any,
     ) -> bool:
         """Legacy alias for store_vector."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             memory = self._memories.get(memory_id)
             if not memory:
                 return False
             if memory["tenant_id"] != tenant_id:
-                raise ValueError("Access Denied")
+                raise NotFoundError("Memory", memory_id, tenant_id)
         
         # Wrap single vector as dict for store_vector
         return await self.store_vector(
@@ -380,7 +510,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         metadata: dict[str, Any] | None = None,
     ) -> UUID:
         """Store a reflection audit result."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             audit_id = uuid4()
             self._reflection_audits[audit_id] = {
                 "id": audit_id,
@@ -395,17 +525,18 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 "metadata": metadata or {},
                 "created_at": self._clock.now()
             }
+            self._log({"op": "audit", "audit": self._reflection_audits[audit_id]})
             return audit_id
 
     async def store_memory(self, **kwargs: Any) -> UUID:
         """Store a new memory."""
-        async with self._lock:
+        tenant_id = kwargs.get("tenant_id", "default")
+        async with self._locks.hold(tenant_id):
             memory_id = uuid4()
             now = self._clock.now()
 
             content = kwargs.get("content", "")
             layer = kwargs.get("layer", "episodic")
-            tenant_id = kwargs.get("tenant_id", "default")
             agent_id = kwargs.get("agent_id", "default")
             tags = kwargs.get("tags") or []
             metadata = kwargs.get("metadata") or {}
@@ -433,29 +564,21 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 "modified_at": now,
                 "last_accessed_at": now,
                 "expires_at": expires_at,
+                "deleted_at": None,
                 "access_count": 0,
                 "usage_count": 0,
                 "memory_type": memory_type,
                 "strength": strength,
                 "version": 1,
+                "session_id": kwargs.get("session_id"),
             }
 
             # Store memory
             self._memories[memory_id] = memory
 
-            # Update indexes
-            self._by_tenant[tenant_id].add(memory_id)
-            self._by_agent[(tenant_id, agent_id)].add(memory_id)
-            self._by_layer[(tenant_id, layer)].add(memory_id)
+            # Update indexes (and the tag Bloom filter)
+            self._index_memory(memory)
 
-            for tag in tags or []:
-                self._by_tags[(tenant_id, tag)].add(memory_id)
-                
-            # Bloom Filter (Phase 2: The Scalpel)
-            if tags:
-                mask = bloom_filter_fingerprint(tags)
-                self._bloom_filters[memory_id] = mask
-                
             # If embedding provided, store in vector store part as well
             if embedding:
                 # Need to release lock if calling self.store_vector which acquires lock?
@@ -477,10 +600,10 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 
                 for m_name, vec in vectors.items():
                     # Quantize
-                    v_bytes = quantize_vector_bytes(vec)
                     dim = len(vec)
                     if m_name not in self._vector_dims:
                         self._vector_dims[m_name] = dim
+                    v_bytes = self._codec(m_name, dim).encode(vec)
                     
                     if m_name not in self._vector_arenas:
                         self._vector_arenas[m_name] = bytearray()
@@ -497,9 +620,16 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                         "agent_id": agent_id,
                         "tags": tags or []
                     })
-                    self._vector_metadata[m_name][memory_id] = v_meta
+                    self._set_vector_metadata(m_name, memory_id, v_meta)
+
+            self._log_memory(memory_id)
+            if embedding:
+                for m_name in vectors:
+                    self._log_vector(m_name, memory_id)
 
-            return memory_id
+        if embedding:
+            await self._train_when_due(vectors)
+        return memory_id
 
     async def get_memory(
         self,
@@ -507,7 +637,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         tenant_id: str,
     ) -> dict[str, Any] | None:
         """Retrieve a memory by ID."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             memory = self._memories.get(memory_id)
 
             if memory and memory["tenant_id"] == tenant_id:
@@ -521,7 +651,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         tenant_id: str,
     ) -> list[dict[str, Any]]:
         """Retrieve multiple memories by IDs."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             results = []
             for mid in memory_ids:
                 memory = self._memories.get(mid)
@@ -534,41 +664,38 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         memory_id: UUID,
         tenant_id: str,
         updates: dict[str, Any],
+        changed_by: str | None = None,
+        expected_version: int | None = None,
     ) -> bool:
         """Update a memory."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             memory = self._memories.get(memory_id)
 
             if not memory or memory["tenant_id"] != tenant_id:
                 return False
 
-            # Handle tag updates (update index)
-            if "tags" in updates:
-                old_tags = set(memory.get("tags", []))
-                new_tags = set(updates.get("tags", []))
+            version = memory.get("version", 1)
+            if expected_version is not None and version != expected_version:
+                raise VersionConflictError(memory_id, expected_version, version)
 
-                removed_tags = old_tags - new_tags
-                added_tags = new_tags - old_tags
+            # Keep the superseded revision before overwriting
+            self._record_revision(memory)
+            changes = field_changes(memory, updates)
 
-                for tag in removed_tags:
-                    self._by_tags[(tenant_id, tag)].discard(memory_id)
-
-                for tag in added_tags:
-                    self._by_tags[(tenant_id, tag)].add(memory_id)
-
-            # Handle layer updates (update index)
-            if "layer" in updates:
-                old_layer = memory.get("layer")
-                new_layer = updates.get("layer")
-
-                if old_layer != new_layer:
-                    self._by_layer[(tenant_id, cast(str, old_layer))].discard(memory_id)
-                    self._by_layer[(tenant_id, cast(str, new_layer))].add(memory_id)
-
-            # Update memory
+            # Update memory and its index entries (tags, layer, ...)
+            self._unindex_memory(memory)
             memory.update(updates)
+            self._index_memory(memory)
             memory["modified_at"] = self._clock.now()
             memory["version"] = memory.get("version", 1) + 1
+            self._log_memory(memory_id)
+
+            if changes:
+                entry = change_entry(
+                    changes, memory["version"], memory["modified_at"], changed_by
+                )
+                self._changelog.setdefault(memory_id, []).append(entry)
+                self._log({"op": "change", "id": memory_id, "entry": entry})
 
             return True
 
@@ -578,70 +705,205 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         tenant_id: str,
     ) -> bool:
         """Delete a memory."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             memory = self._memories.get(memory_id)
 
             if not memory or memory["tenant_id"] != tenant_id:
                 return False
 
             # Remove from indexes
-            self._by_tenant[tenant_id].discard(memory_id)
-            self._by_agent[(tenant_id, memory["agent_id"])].discard(memory_id)
-            self._by_layer[(tenant_id, memory["layer"])].discard(memory_id)
-
-            for tag in memory.get("tags", []):
-                self._by_tags[(tenant_id, tag)].discard(memory_id)
+            self._unindex_memory(memory)
 
             # Remove memory
             del self._memories[memory_id]
+            self._history.pop(memory_id, None)
+            self._changelog.pop(memory_id, None)
             
             # Also remove vectors if present (naive approach without calling delete_vector to avoid deadlock)
             # Just remove from indices, arena remains fragmented
             for model_name in list(self._vector_indices.keys()):
                 if memory_id in self._vector_indices[model_name]:
-                    del self._vector_indices[model_name][memory_id]
-                    del self._vector_metadata[model_name][memory_id]
+                    self._drop_vector(model_name, memory_id)
+
+            self._log({"op": "delete", "id": memory_id})
+            return True
+
+    async def get_change_log(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+    ) -> list[dict[str, Any]]:
+        """Get field-level changes of a memory, oldest first."""
+        async with self._locks.hold(tenant_id):
+            memory = self._memories.get(memory_id)
+
+            if not memory or memory["tenant_id"] != tenant_id:
+                return []
+
+            return copy.deepcopy(self._changelog.get(memory_id, []))
+
+    async def get_memory_history(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+    ) -> list[dict[str, Any]]:
+        """Get retained revisions, oldest first, ending with the current one."""
+        async with self._locks.hold(tenant_id):
+            memory = self._memories.get(memory_id)
+
+            if not memory or memory["tenant_id"] != tenant_id:
+                return []
+
+            history = [dict(r) for r in self._history.get(memory_id, [])]
+            history.append(self._revision_of(memory))
+            return history
+
+    async def revert_to_version(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        version: int,
+    ) -> bool:
+        """Restore a past revision; the revert itself becomes a new version."""
+        async with self._locks.hold(tenant_id):
+            memory = self._memories.get(memory_id)
+            if not memory or memory["tenant_id"] != tenant_id:
+                return False
+            history = self._history.get(memory_id, [])
+            revision = next((r for r in history if r["version"] == version), None)
+            if revision is None:
+                return False
+            updates = {field: revision[field] for field in VERSIONED_FIELDS}
+
+        return await self.update_memory(memory_id, tenant_id, updates)
+
+    async def soft_delete_memory(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+    ) -> bool:
+        """Move a memory to the trash."""
+        async with self._locks.hold(tenant_id):
+            memory = self._memories.get(memory_id)
+
+            if not memory or memory["tenant_id"] != tenant_id:
+                return False
+            if memory.get("deleted_at") is not None:
+                return False
+
+            memory["deleted_at"] = self._clock.now()
+            self._deleted.add(memory_id)
+            self._deleted_by_tenant[tenant_id].add(memory_id)
+            self._log_memory(memory_id)
+
+            return True
+
+    async def restore_memory(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+    ) -> bool:
+        """Restore a memory from the trash."""
+        async with self._locks.hold(tenant_id):
+            memory = self._memories.get(memory_id)
+
+            if not memory or memory["tenant_id"] != tenant_id:
+                return False
+            if memory.get("deleted_at") is None:
+                return False
+
+            memory["deleted_at"] = None
+            self._deleted.discard(memory_id)
+            self._discard(self._deleted_by_tenant, tenant_id, memory_id)
+            self._log_memory(memory_id)
+
+            return True
+
+    async def set_lifecycle(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        fields: dict[str, Any],
+    ) -> bool:
+        """Overwrite timestamps, counters and trash state of a memory."""
+        async with self._locks.hold(tenant_id):
+            memory = self._memories.get(memory_id)
+
+            if not memory or memory["tenant_id"] != tenant_id:
+                return False
+
+            self._unindex_memory(memory)
+            memory.update({k: v for k, v in fields.items() if k in LIFECYCLE_FIELDS})
+            self._index_memory(memory)
+            self._log_memory(memory_id)
 
             return True
 
+    async def purge_deleted(
+        self,
+        before: datetime,
+        tenant_id: str | None = None,
+    ) -> int:
+        """Permanently delete memories trashed before the given time."""
+        async with self._locks.hold(tenant_id):
+            matching_ids = []
+            trash = (
+                self._deleted_by_tenant.get(tenant_id, _NO_IDS)
+                if tenant_id
+                else self._deleted
+            )
+            for memory_id in trash:
+                memory = self._memories[memory_id]
+                if memory["deleted_at"] < before:
+                    matching_ids.append(memory_id)
+
+            for memory_id in matching_ids:
+                self._delete_memory_sync(memory_id)
+
+            return len(matching_ids)
+
     async def list_memories(
         self, tenant_id: str, **kwargs: Any
     ) -> list[dict[str, Any]]:
         """List memories with filtering."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             agent_id = kwargs.get("agent_id")
             layer = kwargs.get("layer")
             tags = kwargs.get("tags")
             limit = kwargs.get("limit", 100)
             offset = kwargs.get("offset", 0)
-
-            # Start with tenant memories
-            candidate_ids = self._by_tenant[tenant_id].copy()
-
-            # Apply filters using indexes
-            if agent_id:
-                candidate_ids &= self._by_agent[(tenant_id, agent_id)]
-
-            if layer:
-                candidate_ids &= self._by_layer[(tenant_id, layer)]
-
-            if tags:
-                # OR logic for tags
-                tag_ids = set()
-                for tag in tags:
-                    tag_ids |= self._by_tags[(tenant_id, tag)]
-                candidate_ids &= tag_ids
-
-            # Get memories and sort by created_at
+            include_deleted = kwargs.get("include_deleted", False)
+            filters = kwargs.get("filters") or {}
+            session_id = kwargs.get("session_id")
+
+            # Indexed filters first (tags use OR logic)
+            candidate_ids = self._select(
+                tenant_id, agent_id, layer, tags, include_deleted
+            )
             memories = [
-                self._memories[mid].copy()
-                for mid in candidate_ids
-                if mid in self._memories
+                self._memories[mid] for mid in candidate_ids if mid in self._memories
             ]
-            memories.sort(key=lambda m: m["created_at"], reverse=True)
+            if session_id:
+                memories = [m for m in memories if m.get("session_id") == session_id]
+            # Metadata equality filters (same semantics as SQLiteStorage)
+            if filters:
+                memories = [
+                    m
+                    for m in memories
+                    if all(
+                        (m.get("metadata") or {}).get(k) == v
+                        for k, v in filters.items()
+                    )
+                ]
+            bounds = {k: kwargs[k] for k in RANGE_FILTERS if kwargs.get(k) is not None}
+            if bounds:
+                memories = [m for m in memories if matches_range(m, **bounds)]
 
-            # Apply pagination
-            return memories[offset : offset + limit]
+            # Newest first; only the requested page is sorted and copied
+            page = heapq.nlargest(
+                offset + limit, memories, key=lambda m: m["created_at"]
+            )
+            return [m.copy() for m in page[offset:]]
 
     async def count_memories(
         self,
@@ -650,25 +912,18 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         layer: str | None = None,
     ) -> int:
         """Count memories matching filters."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             if not tenant_id:
-                return len(self._memories)
-
-            # Start with tenant memories
-            candidate_ids = self._by_tenant[tenant_id].copy()
-
-            # Apply filters
-            if agent_id:
-                candidate_ids &= self._by_agent[(tenant_id, agent_id)]
-
-            if layer:
-                candidate_ids &= self._by_layer[(tenant_id, layer)]
-
-            return len(candidate_ids)
+                return len(self._memories) - len(self._deleted)
+            if not agent_id and not layer:
+                return len(self._by_tenant.get(tenant_id, _NO_IDS)) - len(
+                    self._deleted_by_tenant.get(tenant_id, _NO_IDS)
+                )
+            return len(self._select(tenant_id, agent_id, layer))
 
     async def get_statistics(self) -> dict[str, Any]:
         """Get storage statistics."""
-        async with self._lock:
+        async with self._locks.all():
             return {
                 "total_memories": len(self._memories),
                 "tenants": len(self._by_tenant),
@@ -681,7 +936,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
 
     async def clear_all(self) -> int:
         """Clear all data."""
-        async with self._lock:
+        async with self._locks.all():
             count = len(self._memories)
 
             self._memories.clear()
@@ -689,11 +944,19 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
             self._by_agent.clear()
             self._by_layer.clear()
             self._by_tags.clear()
+            self._deleted.clear()
+            self._deleted_by_tenant.clear()
+            self._history.clear()
+            self._changelog.clear()
             
             self._vector_arenas.clear()
             self._vector_indices.clear()
             self._vector_metadata.clear()
+            self._vectors_by_tenant.clear()
             self._vector_dims.clear()
+            self._codecs.clear()
+            self._untrained.clear()
+            self._log({"op": "clear"})
 
             return count
 
@@ -705,11 +968,16 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         metadata_filter: dict[str, Any] | None = None,
     ) -> int:
         """Delete memories matching metadata filter."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             matching_ids = []
-            for memory_id, memory in self._memories.items():
-                if tenant_id and memory["tenant_id"] != tenant_id:
-                    continue
+            if tenant_id:
+                candidate_ids: Any = self._select(
+                    tenant_id, agent_id, layer, include_deleted=True
+                )
+            else:
+                candidate_ids = list(self._memories)
+            for memory_id in candidate_ids:
+                memory = self._memories[memory_id]
                 if agent_id and memory["agent_id"] != agent_id:
                     continue
                 if layer and memory["layer"] != layer:
@@ -735,16 +1003,14 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         importance_threshold: float,
     ) -> int:
         """Delete memories below importance threshold."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             matching_ids = [
                 memory_id
-                for memory_id, memory in self._memories.items()
-                if (
-                    memory["tenant_id"] == tenant_id
-                    and memory["agent_id"] == agent_id
-                    and memory["layer"] == layer
-                    and memory.get("importance", 0) < importance_threshold
+                for memory_id in self._select(
+                    tenant_id, agent_id, layer, include_deleted=True
                 )
+                if self._memories[memory_id].get("importance", 0)
+                < importance_threshold
             ]
 
             for memory_id in matching_ids:
@@ -762,7 +1028,9 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         **kwargs: Any,
     ) -> list[dict[str, Any]]:
         """Search memories using simple substring matching."""
-        async with self._lock:
+        bounds = {k: kwargs[k] for k in TIME_FILTERS if kwargs.get(k) is not None}
+        session_id = kwargs.get("session_id")
+        async with self._locks.hold(tenant_id):
             results = []
             query_lower = query.lower()
 
@@ -771,6 +1039,9 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                     memory["tenant_id"] == tenant_id
                     and memory["agent_id"] == agent_id
                     and (layer is None or memory["layer"] == layer)
+                    and memory.get("deleted_at") is None
+                    and (not bounds or matches_range(memory, **bounds))
+                    and (not session_id or memory.get("session_id") == session_id)
                 ):
                     # Simple substring search in content
                     content_lower = memory["content"].lower()
@@ -819,17 +1090,13 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         layer: str | None = None,
     ) -> int:
         """Delete expired memories."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             now = self._clock.now()
             matching_ids = []
-            for memory_id, memory in self._memories.items():
-                if memory["tenant_id"] != tenant_id:
-                    continue
-                if agent_id and memory["agent_id"] != agent_id:
-                    continue
-                if layer and memory["layer"] != layer:
-                    continue
-
+            for memory_id in self._select(
+                tenant_id, agent_id, layer, include_deleted=True
+            ):
+                memory = self._memories[memory_id]
                 if memory.get("expires_at") and memory["expires_at"] < now:
                     matching_ids.append(memory_id)
 
@@ -844,7 +1111,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         tenant_id: str,
     ) -> bool:
         """Update last access time and increment usage count."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             memory = self._memories.get(memory_id)
 
             if not memory or memory["tenant_id"] != tenant_id:
@@ -853,6 +1120,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
             memory["last_accessed_at"] = self._clock.now()
             memory["access_count"] = memory.get("access_count", 0) + 1
             memory["usage_count"] = memory.get("usage_count", 0) + 1
+            self._log_memory(memory_id)
 
             return True
 
@@ -867,7 +1135,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         expires_at: datetime | None,
     ) -> bool:
         """Update memory expiration time."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             memory = self._memories.get(memory_id)
 
             if not memory or memory["tenant_id"] != tenant_id:
@@ -875,6 +1143,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
 
             memory["expires_at"] = expires_at
             memory["modified_at"] = self._clock.now()
+            self._log_memory(memory_id)
 
             return True
 
@@ -886,11 +1155,10 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         filters: dict[str, Any] | None = None,
     ) -> float:
         """Calculate aggregate metric."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             values = []
-            for memory in self._memories.values():
-                if memory["tenant_id"] != tenant_id:
-                    continue
+            for memory_id in self._by_tenant.get(tenant_id, _NO_IDS):
+                memory = self._memories[memory_id]
 
                 # Apply filters
                 if filters:
@@ -931,6 +1199,29 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
             await self.update_memory_access(mid, tenant_id)
         return True
 
+    async def record_access(
+        self,
+        memory_ids: list[UUID],
+        tenant_id: str,
+        accessed_at: datetime | None = None,
+    ) -> int:
+        """Record reads of memories under a single hold of the tenant's lock."""
+        accessed_at = accessed_at or self._clock.now()
+        updated = 0
+        async with self._locks.hold(tenant_id):
+            for memory_id, count in Counter(memory_ids).items():
+                memory = self._memories.get(memory_id)
+                if not memory or memory["tenant_id"] != tenant_id:
+                    continue
+                last = memory.get("last_accessed_at")
+                if last is None or last < accessed_at:
+                    memory["last_accessed_at"] = accessed_at
+                memory["access_count"] = memory.get("access_count", 0) + count
+                memory["usage_count"] = memory.get("usage_count", 0) + count
+                self._log_memory(memory_id)
+                updated += 1
+        return updated
+
     async def adjust_importance(
         self,
         memory_id: UUID,
@@ -938,7 +1229,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         tenant_id: str,
     ) -> float:
         """Adjust memory importance."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             memory = self._memories.get(memory_id)
             if not memory or memory["tenant_id"] != tenant_id:
                 return 0.0
@@ -947,6 +1238,7 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
             new_imp = max(0.0, min(1.0, new_imp))
             memory["importance"] = new_imp
             memory["modified_at"] = self._clock.now()
+            self._log_memory(memory_id)
             return new_imp
 
     async def decay_importance(
@@ -955,9 +1247,9 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
         decay_factor: float,
     ) -> int:
         """Apply importance decay to all memories for a tenant."""
-        async with self._lock:
+        async with self._locks.hold(tenant_id):
             count = 0
-            for memory_id in self._by_tenant[tenant_id]:
+            for memory_id in self._by_tenant.get(tenant_id, _NO_IDS):
                 memory = self._memories.get(memory_id)
                 if not memory:
                     continue
@@ -965,13 +1257,14 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 current = float(memory.get("importance", 0.5))
                 new_val = current * decay_factor
                 memory["importance"] = new_val
+                self._log_memory(memory_id)
                 count += 1
             return count
 
     async def clear_tenant(self, tenant_id: str) -> int:
         """Delete all memories for a tenant."""
-        async with self._lock:
-            mids = list(self._by_tenant[tenant_id])
+        async with self._locks.hold(tenant_id):
+            mids = list(self._by_tenant.get(tenant_id, _NO_IDS))
             for mid in mids:
                 self._delete_memory_sync(mid)
             
@@ -981,9 +1274,23 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 
             return len(mids)
 
+    async def health_check(self) -> bool:
+        """In-process store; always available."""
+        return True
+
     async def close(self) -> None:
         """Close storage connection."""
-        pass
+        if self._wal:
+            self._wal.close()
+
+    async def compact_log(self) -> int:
+        """Rewrite the write-ahead log as a minimal snapshot of current state.
+
+        Returns:
+            Number of records in the compacted log (0 without a log)
+        """
+        async with self._locks.all():
+            return self._compact_log_sync()
 
     def _matches_metadata_filter(
         self, metadata: dict[str, Any], filter_dict: dict[str, Any]
@@ -994,29 +1301,382 @@ class InMemoryStorage(IMemoryStorage, IVectorStore):
                 return False
         return True
 
-    def _delete_memory_sync(self, memory_id: UUID) -> None:
+    @staticmethod
+    def _revision_of(memory: dict[str, Any]) -> dict[str, Any]:
+        """Snapshot of the versioned fields of a memory."""
+        revision = {
+            field: copy.deepcopy(memory.get(field)) for field in VERSIONED_FIELDS
+        }
+        revision["version"] = memory.get("version", 1)
+        revision["modified_at"] = memory.get("modified_at")
+        return revision
+
+    def _record_revision(self, memory: dict[str, Any]) -> None:
+        """Retain the current state as a past revision (assumes lock is held)."""
+        if not self._history_limit:
+            return
+        revision = self._revision_of(memory)
+        self._append_revision(memory["id"], revision)
+        self._log({"op": "revision", "id": memory["id"], "revision": revision})
+
+    def _append_revision(self, memory_id: UUID, revision: dict[str, Any]) -> None:
+        history = self._history.setdefault(memory_id, [])
+        history.append(revision)
+        if len(history) > self._history_limit:
+            del history[: len(history) - self._history_limit]
+
+    @staticmethod
+    def _discard(index: dict[Any, set[UUID]], key: Any, memory_id: UUID) -> None:
+        """Remove an id from an index entry, dropping the entry once empty."""
+        ids = index.get(key)
+        if ids is not None:
+            ids.discard(memory_id)
+            if not ids:
+                del index[key]
+
+    def _index_memory(self, memory: dict[str, Any]) -> None:
+        """Add a memory to the secondary indexes."""
+        memory_id = memory["id"]
+        tenant_id = memory["tenant_id"]
+        self._by_tenant[tenant_id].add(memory_id)
+        self._by_agent[(tenant_id, memory["agent_id"])].add(memory_id)
+        self._by_layer[(tenant_id, memory["layer"])].add(memory_id)
+        tags = memory.get("tags") or []
+        for tag in tags:
+            self._by_tags[(tenant_id, tag)].add(memory_id)
+        if tags:
+            self._bloom_filters[memory_id] = bloom_filter_fingerprint(tags)
+        if memory.get("deleted_at") is not None:
+            self._deleted.add(memory_id)
+            self._deleted_by_tenant[tenant_id].add(memory_id)
+
+    def _unindex_memory(self, memory: dict[str, Any]) -> None:
+        """Remove a memory from the secondary indexes."""
+        memory_id = memory["id"]
+        tenant_id = memory["tenant_id"]
+        self._discard(self._by_tenant, tenant_id, memory_id)
+        self._discard(self._by_agent, (tenant_id, memory["agent_id"]), memory_id)
+        self._discard(self._by_layer, (tenant_id, memory["layer"]), memory_id)
+        for tag in memory.get("tags") or []:
+            self._discard(self._by_tags, (tenant_id, tag), memory_id)
+        self._bloom_filters.pop(memory_id, None)
+        self._deleted.discard(memory_id)
+        self._discard(self._deleted_by_tenant, tenant_id, memory_id)
+
+    def _select(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        tags: list[str] | None = None,
+        include_deleted: bool = False,
+    ) -> list[UUID]:
+        """Ids of a tenant's memories matching the indexed filters.
+
+        Walks the smallest matching index and probes the others, so the cost
+        follows the size of that index, not of the tenant or the store.
+        """
+        indexes = [self._by_tenant.get(tenant_id, _NO_IDS)]
+        if agent_id:
+            indexes.append(self._by_agent.get((tenant_id, agent_id), _NO_IDS))
+        if layer:
+            indexes.append(self._by_layer.get((tenant_id, layer), _NO_IDS))
+        if tags:
+            indexes.append(
+                set().union(
+                    *(self._by_tags.get((tenant_id, tag), _NO_IDS) for tag in tags)
+                )
+            )
+        trash = (
+            _NO_IDS
+            if include_deleted
+            else self._deleted_by_tenant.get(tenant_id, _NO_IDS)
+        )
+        smallest, *others = sorted(indexes, key=len)
+        return [
+            memory_id
+            for memory_id in smallest
+            if memory_id not in trash and all(memory_id in ids for ids in others)
+        ]
+
+    def _set_vector_metadata(
+        self, model_name: str, memory_id: UUID, meta: dict[str, Any]
+    ) -> None:
+        """Store a vector's metadata and index it under its tenant."""
+        by_tenant = self._vectors_by_tenant[model_name]
+        old = self._vector_metadata[model_name].get(memory_id)
+        if old is not None:
+            by_tenant[old.get("tenant_id")].discard(memory_id)
+        self._vector_metadata[model_name][memory_id] = meta
+        by_tenant[meta.get("tenant_id")].add(memory_id)
+
+    def _drop_vector(self, model_name: str, memory_id: UUID) -> None:
+        """Remove a vector from the indexes (its arena space is not reused)."""
+        self._vector_indices[model_name].pop(memory_id, None)
+        meta = self._vector_metadata[model_name].pop(memory_id, None)
+        if meta is not None:
+            self._vectors_by_tenant[model_name][meta.get("tenant_id")].discard(
+                memory_id
+            )
+
+    # =========================================================================
+    # Vector Quantization
+    # =========================================================================
+
+    def _quantization_config(self, model_name: str) -> QuantizationConfig | None:
+        if isinstance(self._quantization, dict):
+            return self._quantization.get(model_name)
+        return self._quantization
+
+    def _codec(self, model_name: str, dim: int) -> VectorCodec:
+        """Codec of a collection's arena (created with the collection)."""
+        codec = self._codecs.get(model_name)
+        if codec is None:
+            codec = make_codec(self._quantization_config(model_name), dim)
+            if isinstance(codec, ProductQuantizer) and not codec.trained:
+                self._untrained[model_name] = codec
+                codec = FixedPointCodec(dim)
+            self._codecs[model_name] = codec
+        return codec
+
+    def _rescoring(self, model_name: str) -> QuantizationConfig | None:
+        """Quantization config of a collection whose searches re-score."""
+        config = self._quantization_config(model_name)
+        codec = self._codecs.get(model_name)
+        if config is None or not config.rescore or isinstance(codec, FixedPointCodec):
+            return None
+        return config
+
+    def _training_due(self, model_name: str) -> bool:
+        """Whether a collection's product codebooks should be trained now."""
+        config = self._quantization_config(model_name)
+        return (
+            model_name in self._untrained
+            and config is not None
+            and len(self._vector_indices[model_name]) >= config.train_size
+        )
+
+    async def _train_when_due(self, model_names: Iterable[str]) -> None:
+        """Train due collections under every shard.
+
+        Re-encoding replaces a collection's arena and offsets, which other
+        tenants' searches read across their pauses; with every shard held
+        no search is in flight.
+        """
+        due = [name for name in model_names if self._training_due(name)]
+        if not due:
+            return
+        async with self._locks.all():
+            for model_name in due:
+                self._maybe_train(model_name)
+
+    def _maybe_train(self, model_name: str) -> None:
+        """Train product codebooks once the collection is large enough.
+
+        The collection is then re-encoded, which also reclaims the arena
+        space of deleted vectors (assumes every shard is held).
+        """
+        quantizer = self._untrained.get(model_name)
+        config = self._quantization_config(model_name)
+        index = self._vector_indices[model_name]
+        if quantizer is None or config is None or len(index) < config.train_size:
+            return
+        codec = self._codecs[model_name]
+        arena = self._vector_arenas[model_name]
+        stride = codec.code_size
+        vectors = [
+            codec.decode(bytes(arena[offset : offset + stride]))
+            for offset in index.values()
+        ]
+        quantizer.train(vectors, config.train_iterations, config.seed)
+        del self._untrained[model_name]
+        self._reencode(model_name, quantizer)
+        # The log is rewritten with the codebook and the compressed vectors
+        if self._wal:
+            self._compact_log_sync()
+
+    def _reencode(self, model_name: str, codec: VectorCodec) -> None:
+        """Rewrite a collection's arena with another codec (lock held)."""
+        old = self._codecs[model_name]
+        arena = self._vector_arenas[model_name]
+        index = self._vector_indices[model_name]
+        stride = old.code_size
+        rewritten = bytearray()
+        for memory_id, offset in index.items():
+            vector = old.decode(bytes(arena[offset : offset + stride]))
+            index[memory_id] = len(rewritten)
+            rewritten.extend(codec.encode(vector))
+        self._vector_arenas[model_name] = rewritten
+        self._codecs[model_name] = codec
+
+    def _delete_memory_sync(self, memory_id: UUID, log: bool = True) -> None:
         """Internal delete helper (assumes lock is held)."""
         memory = self._memories.get(memory_id)
         if not memory:
             return
 
-        # Remove from main storage
+        # Remove from main storage and indexes
         del self._memories[memory_id]
+        self._unindex_memory(memory)
+        self._history.pop(memory_id, None)
+        self._changelog.pop(memory_id, None)
 
-        # Remove from indexes
-        tenant_id = memory["tenant_id"]
-        agent_id = memory["agent_id"]
-        layer = memory["layer"]
-
-        self._by_tenant[tenant_id].discard(memory_id)
-        self._by_agent[(tenant_id, agent_id)].discard(memory_id)
-        self._by_layer[(tenant_id, layer)].discard(memory_id)
-
-        for tag in memory.get("tags", []):
-            self._by_tags[(tenant_id, tag)].discard(memory_id)
-        
         # Remove from vector index (fragmentation remains)
         for model_name in list(self._vector_indices.keys()):
-             if memory_id in self._vector_indices[model_name]:
-                 del self._vector_indices[model_name][memory_id]
-                 del self._vector_metadata[model_name][memory_id]
+            if memory_id in self._vector_indices[model_name]:
+                self._drop_vector(model_name, memory_id)
+
+        if log:
+            self._log({"op": "delete", "id": memory_id})
+
+    # =========================================================================
+    # Write-Ahead Log
+    # =========================================================================
+
+    def _log(self, record: dict[str, Any]) -> None:
+        """Append a state change to the log (assumes lock is held)."""
+        if not self._wal:
+            return
+        self._wal.append(record)
+        if self._compact_after and self._wal.growth >= self._compact_after:
+            self._compact_log_sync()
+
+    def _log_many(self, records: list[dict[str, Any]]) -> None:
+        """Append a batch of state changes with one fsync (lock held)."""
+        if not self._wal or not records:
+            return
+        self._wal.append_many(records)
+        if self._compact_after and self._wal.growth >= self._compact_after:
+            self._compact_log_sync()
+
+    def _log_memory(self, memory_id: UUID) -> None:
+        if self._wal:
+            self._log({"op": "memory", "memory": self._memories[memory_id]})
+
+    def _log_vector(self, model_name: str, memory_id: UUID) -> None:
+        if self._wal:
+            self._log(self._vector_record(model_name, memory_id))
+
+    def _vector_record(self, model_name: str, memory_id: UUID) -> dict[str, Any]:
+        offset = self._vector_indices[model_name][memory_id]
+        dim = self._vector_dims[model_name]
+        codec = self._codec(model_name, dim)
+        byte_len = codec.code_size
+        return {
+            "op": "vector",
+            "id": memory_id,
+            "model": model_name,
+            "data": bytes(self._vector_arenas[model_name][offset : offset + byte_len]),
+            "metadata": self._vector_metadata[model_name][memory_id],
+            "dim": dim,
+            "codec": _codec_name(codec),
+        }
+
+    def _compact_log_sync(self) -> int:
+        if not self._wal:
+            return 0
+
+        def snapshot() -> Any:
+            for audit in self._reflection_audits.values():
+                yield {"op": "audit", "audit": audit}
+            for memory in self._memories.values():
+                yield {"op": "memory", "memory": memory}
+            for memory_id, history in self._history.items():
+                for revision in history:
+                    yield {"op": "revision", "id": memory_id, "revision": revision}
+            for memory_id, entries in self._changelog.items():
+                for entry in entries:
+                    yield {"op": "change", "id": memory_id, "entry": entry}
+            for model_name, codec in self._codecs.items():
+                if isinstance(codec, ProductQuantizer):
+                    yield {
+                        "op": "codebook",
+                        "model": model_name,
+                        "codebook": codec.to_dict(),
+                    }
+            for model_name, index in self._vector_indices.items():
+                for memory_id in index:
+                    yield self._vector_record(model_name, memory_id)
+
+        self._wal.compact(snapshot())
+        return self._wal.record_count
+
+    def _apply_wal_record(self, record: dict[str, Any]) -> None:
+        """Re-apply a logged state change during replay."""
+        op = record.get("op")
+        if op == "memory":
+            memory = record["memory"]
+            memory_id = memory["id"]
+            old = self._memories.get(memory_id)
+            if old:
+                self._unindex_memory(old)
+            self._memories[memory_id] = memory
+            self._index_memory(memory)
+        elif op == "vector":
+            model_name, memory_id, data = record["model"], record["id"], record["data"]
+            dim = record.get("dim", len(data) // 4)
+            self._vector_dims[model_name] = dim
+            # Logged with another codec (quantization changed): re-encode
+            codec = self._codec(model_name, dim)
+            logged = record.get("codec", "fixed")
+            if logged != _codec_name(codec):
+                if logged == "fixed":
+                    vector = dequantize_vector_bytes(data)
+                elif logged == "scalar":
+                    vector = ScalarQuantizer(dim).decode(data)
+                else:
+                    # Product codes are only logged after their codebook
+                    vector = self._logged_codebooks[model_name].decode(data)
+                data = codec.encode(vector)
+            index = self._vector_indices[model_name]
+            if memory_id in index:
+                offset = index[memory_id]
+                self._vector_arenas[model_name][offset : offset + len(data)] = data
+            else:
+                index[memory_id] = len(self._vector_arenas[model_name])
+                self._vector_arenas[model_name].extend(data)
+            self._set_vector_metadata(model_name, memory_id, record["metadata"])
+        elif op == "codebook":
+            model_name = record["model"]
+            quantizer = ProductQuantizer.from_dict(record["codebook"])
+            self._logged_codebooks[model_name] = quantizer
+            self._codec(model_name, quantizer.dimension)
+            if self._untrained.pop(model_name, None) is not None:
+                self._reencode(model_name, quantizer)
+        elif op == "delete_vector":
+            self._drop_vector(record["model"], record["id"])
+        elif op == "delete":
+            self._delete_memory_sync(record["id"], log=False)
+        elif op == "revision":
+            if self._history_limit:
+                self._append_revision(record["id"], record["revision"])
+        elif op == "change":
+            self._changelog.setdefault(record["id"], []).append(record["entry"])
+        elif op == "audit":
+            self._reflection_audits[record["audit"]["id"]] = record["audit"]
+        elif op == "clear":
+            self._memories.clear()
+            self._by_tenant.clear()
+            self._by_agent.clear()
+            self._by_layer.clear()
+            self._by_tags.clear()
+            self._deleted.clear()
+            self._deleted_by_tenant.clear()
+            self._history.clear()
+            self._changelog.clear()
+            self._vector_arenas.clear()
+            self._vector_indices.clear()
+            self._vector_metadata.clear()
+            self._vectors_by_tenant.clear()
+            self._vector_dims.clear()
+            self._codecs.clear()
+            self._untrained.clear()
+
+
+def _codec_name(codec: VectorCodec) -> str:
+    """Name of a codec in vector log records."""
+    if isinstance(codec, ScalarQuantizer):
+        return "scalar"
+    if isinstance(codec, ProductQuantizer):
+        return "product"
+    return "fixed"
@@ -0,0 +1,586 @@
+"""Overlay composition of a writable store over read-only stores.
+
+Like an overlay filesystem, reads resolve through the stack top-down and
+all writes go to the upper store; lower layers (e.g. a KnowledgePackStore)
+are never modified. Changing a lower-layer memory first copies it up into
+the upper store, and deleting one records a whiteout there that hides it.
+
+Copies and whiteouts are ordinary upper-store records carrying SHADOW_TAG
+and the id of the memory they shadow in metadata, so the overlay state
+persists with the upper store. Copies are served under the id of the
+memory they replace.
+"""
+
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+from rae_core.exceptions.base import VersionConflictError
+from rae_core.health import components_healthy
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.sync.snapshot import _RESTORABLE_FIELDS
+
+SHADOW_TAG = "_overlay_shadow"
+# Metadata of shadow records: id of the lower-layer memory and whether the
+# record is a whiteout rather than a copy
+SHADOW_OF_KEY = "_overlay_of"
+WHITEOUT_KEY = "_overlay_whiteout"
+
+_SHADOW_PAGE_SIZE = 500
+
+
+def _is_shadow(memory: dict[str, Any]) -> bool:
+    return SHADOW_TAG in (memory.get("tags") or [])
+
+
+class OverlayStorage:
+    """IMemoryStorage resolving reads through a stack of stores.
+
+    Bulk maintenance (expiry, decay, purges, filtered deletes and
+    clear_tenant) only touches the upper store; clearing a tenant also drops
+    its whiteouts, making the lower layers visible again. Access tracking of
+    lower-layer memories is not recorded.
+    """
+
+    def __init__(self, upper: IMemoryStorage, lower: list[IMemoryStorage]):
+        """Initialize overlay.
+
+        Args:
+            upper: Writable store receiving all writes
+            lower: Read-only stores, searched in order after the upper one
+        """
+        self.upper = upper
+        self.lower = list(lower)
+        # {tenant: {lower memory id: upper copy id, or None for a whiteout}}
+        self._shadows: dict[str, dict[UUID, UUID | None]] = {}
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self.upper, name)
+
+    async def shadows(self, tenant_id: str) -> dict[UUID, UUID | None]:
+        """Lower-layer memories of a tenant that are copied up or deleted."""
+        if tenant_id not in self._shadows:
+            shadows: dict[UUID, UUID | None] = {}
+            offset = 0
+            while True:
+                page = await self.upper.list_memories(
+                    tenant_id,
+                    tags=[SHADOW_TAG],
+                    limit=_SHADOW_PAGE_SIZE,
+                    offset=offset,
+                    include_deleted=True,
+                )
+                for record in page:
+                    metadata = record.get("metadata") or {}
+                    if SHADOW_OF_KEY in metadata:
+                        shadow_of = UUID(str(metadata[SHADOW_OF_KEY]))
+                        whiteout = metadata.get(WHITEOUT_KEY)
+                        shadows[shadow_of] = None if whiteout else record["id"]
+                if len(page) < _SHADOW_PAGE_SIZE:
+                    break
+                offset += _SHADOW_PAGE_SIZE
+            self._shadows[tenant_id] = shadows
+        return self._shadows[tenant_id]
+
+    def _present(self, record: dict[str, Any]) -> dict[str, Any] | None:
+        """A shadow record as the memory it stands for (None for whiteouts)."""
+        metadata = dict(record.get("metadata") or {})
+        if metadata.pop(WHITEOUT_KEY, False):
+            return None
+        memory = dict(record)
+        memory["id"] = UUID(str(metadata.pop(SHADOW_OF_KEY)))
+        memory["tags"] = [t for t in record.get("tags") or [] if t != SHADOW_TAG]
+        memory["metadata"] = metadata
+        return memory
+
+    async def _locate(
+        self, memory_id: UUID, tenant_id: str
+    ) -> tuple[str, UUID | None, dict[str, Any] | None]:
+        """Find the layer holding a memory.
+
+        Returns "upper", "copy", "lower" or "missing", the id of the memory
+        in the upper store (None if it has no record there) and the memory.
+        """
+        memory = await self.upper.get_memory(memory_id, tenant_id)
+        if memory is not None and not _is_shadow(memory):
+            return "upper", memory_id, memory
+        shadows = await self.shadows(tenant_id)
+        if memory_id in shadows:
+            copy_id = shadows[memory_id]
+            if copy_id is None:
+                return "missing", None, None
+            record = await self.upper.get_memory(copy_id, tenant_id)
+            if record is not None:
+                return "copy", copy_id, self._present(record)
+        for store in self.lower:
+            memory = await store.get_memory(memory_id, tenant_id)
+            if memory is not None:
+                return "lower", None, memory
+        return "missing", None, None
+
+    async def _store_shadow(
+        self, memory: dict[str, Any], tenant_id: str, whiteout: bool
+    ) -> UUID:
+        fields = {k: memory[k] for k in _RESTORABLE_FIELDS if k in memory}
+        metadata = dict(memory.get("metadata") or {})
+        metadata[SHADOW_OF_KEY] = str(memory["id"])
+        if whiteout:
+            metadata[WHITEOUT_KEY] = True
+            fields["content"] = ""
+        fields["metadata"] = metadata
+        fields["tags"] = [*(memory.get("tags") or []), SHADOW_TAG]
+        record_id = await self.upper.store_memory(tenant_id=tenant_id, **fields)
+        shadows = await self.shadows(tenant_id)
+        shadows[UUID(str(memory["id"]))] = None if whiteout else record_id
+        return record_id
+
+    async def _writable_id(self, memory_id: UUID, tenant_id: str) -> UUID | None:
+        """Id of the memory in the upper store, copying it up if needed."""
+        where, upper_id, memory = await self._locate(memory_id, tenant_id)
+        if where == "lower":
+            assert memory is not None
+            return await self._store_shadow(memory, tenant_id, whiteout=False)
+        return upper_id
+
+    # =========================================================================
+    # Reads
+    # =========================================================================
+
+    async def get_memory(
+        self, memory_id: UUID, tenant_id: str
+    ) -> dict[str, Any] | None:
+        _, _, memory = await self._locate(memory_id, tenant_id)
+        return memory
+
+    async def get_memories_batch(
+        self, memory_ids: list[UUID], tenant_id: str
+    ) -> list[dict[str, Any]]:
+        memories = [await self.get_memory(mid, tenant_id) for mid in memory_ids]
+        return [m for m in memories if m is not None]
+
+    async def list_memories(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        **kwargs: Any,
+    ) -> list[dict[str, Any]]:
+        """List memories of all layers, newest first."""
+        shadows = await self.shadows(tenant_id)
+        limit = kwargs.pop("limit", 100)
+        offset = kwargs.pop("offset", 0)
+        # Each store may return shadowed records that are dropped below
+        window = offset + limit + len(shadows)
+
+        memories: list[dict[str, Any]] = []
+        for record in await self.upper.list_memories(
+            tenant_id,
+            agent_id=agent_id,
+            layer=layer,
+            limit=window,
+            offset=0,
+            **kwargs,
+        ):
+            memory = self._present(record) if _is_shadow(record) else record
+            if memory is not None:
+                memories.append(memory)
+        for store in self.lower:
+            for memory in await store.list_memories(
+                tenant_id,
+                agent_id=agent_id,
+                layer=layer,
+                limit=window,
+                offset=0,
+                **kwargs,
+            ):
+                if UUID(str(memory["id"])) not in shadows:
+                    memories.append(memory)
+        memories.sort(key=lambda m: _sort_time(m.get("created_at")), reverse=True)
+        return memories[offset : offset + limit]
+
+    async def count_memories(
+        self,
+        tenant_id: str | None = None,
+        agent_id: str | None = None,
+        layer: str | None = None,
+    ) -> int:
+        """Count visible memories.
+
+        Without a tenant, the counts of all stores are summed as they are.
+        """
+        total = 0
+        for store in [self.upper, *self.lower]:
+            total += await store.count_memories(
+                tenant_id=tenant_id, agent_id=agent_id, layer=layer
+            )
+        if tenant_id is None:
+            return total
+
+        def matches(memory: dict[str, Any] | None) -> bool:
+            return (
+                memory is not None
+                and (agent_id is None or memory.get("agent_id") == agent_id)
+                and (layer is None or memory.get("layer") == layer)
+            )
+
+        for shadow_of, copy_id in (await self.shadows(tenant_id)).items():
+            original = None
+            for store in self.lower:
+                original = await store.get_memory(shadow_of, tenant_id)
+                if original is not None:
+                    total -= matches(original)
+                    break
+            if copy_id is None:
+                # Whiteouts keep the agent and layer of the memory they hide
+                total -= matches(original)
+        return max(total, 0)
+
+    async def search_memories(
+        self,
+        query: str,
+        tenant_id: str,
+        agent_id: str,
+        layer: str | None = None,
+        limit: int = 10,
+        **kwargs: Any,
+    ) -> list[dict[str, Any]]:
+        shadows = await self.shadows(tenant_id)
+        window = limit + len(shadows)
+        results = []
+        for result in await self.upper.search_memories(
+            query, tenant_id, agent_id, layer=layer, limit=window, **kwargs
+        ):
+            record = result.get("memory") or result
+            if _is_shadow(record):
+                memory = self._present(record)
+                if memory is None:
+                    continue
+                result = {**result, "id": memory["id"], "memory": memory}
+            results.append(result)
+        for store in self.lower:
+            for result in await store.search_memories(
+                query, tenant_id, agent_id, layer=layer, limit=window, **kwargs
+            ):
+                if UUID(str(result["id"])) not in shadows:
+                    results.append(result)
+        results.sort(key=lambda r: r.get("score", 0.0), reverse=True)
+        return results[:limit]
+
+    async def get_metric_aggregate(
+        self,
+        tenant_id: str,
+        metric: str,
+        func: str,
+        filters: dict[str, Any] | None = None,
+    ) -> float:
+        """Combine per-store aggregates (shadowed memories are included)."""
+        stores = [self.upper, *self.lower]
+        if func == "avg":
+            total = 0.0
+            count = 0.0
+            for store in stores:
+                total += await store.get_metric_aggregate(
+                    tenant_id, metric, "sum", filters
+                )
+                count += await store.get_metric_aggregate(
+                    tenant_id, metric, "count", filters
+                )
+            return total / count if count else 0.0
+        values = [
+            await store.get_metric_aggregate(tenant_id, metric, func, filters)
+            for store in stores
+        ]
+        if func in ("sum", "count"):
+            return float(sum(values))
+        if func == "max":
+            return max(values)
+        if func == "min":
+            nonzero = [v for v in values if v]
+            return min(nonzero) if nonzero else 0.0
+        return 0.0
+
+    async def get_change_log(
+        self, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        return await self._history("get_change_log", memory_id, tenant_id)
+
+    async def get_memory_history(
+        self, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        return await self._history("get_memory_history", memory_id, tenant_id)
+
+    async def _history(
+        self, method: str, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        where, upper_id, _ = await self._locate(memory_id, tenant_id)
+        if upper_id is not None:
+            return list(await getattr(self.upper, method)(upper_id, tenant_id))
+        if where == "lower":
+            for store in self.lower:
+                if await store.get_memory(memory_id, tenant_id) is not None:
+                    return list(await getattr(store, method)(memory_id, tenant_id))
+        return []
+
+    # =========================================================================
+    # Writes
+    # =========================================================================
+
+    async def store_memory(self, **kwargs: Any) -> UUID:
+        return await self.upper.store_memory(**kwargs)
+
+    async def store_reflection_audit(self, *args: Any, **kwargs: Any) -> UUID:
+        return await self.upper.store_reflection_audit(*args, **kwargs)
+
+    async def update_memory(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        updates: dict[str, Any],
+        changed_by: str | None = None,
+        expected_version: int | None = None,
+    ) -> bool:
+        where, upper_id, memory = await self._locate(memory_id, tenant_id)
+        if where == "missing":
+            return False
+        if where == "lower":
+            # The lower store is never written: check the version read there,
+            # then update a fresh copy
+            assert memory is not None
+            version = memory.get("version", 1)
+            if expected_version is not None and version != expected_version:
+                raise VersionConflictError(memory_id, expected_version, version)
+            expected_version = None
+            upper_id = await self._store_shadow(memory, tenant_id, whiteout=False)
+        assert upper_id is not None
+        if upper_id != memory_id:
+            updates = dict(updates)
+            if "tags" in updates:
+                updates["tags"] = [*(updates["tags"] or []), SHADOW_TAG]
+            if "metadata" in updates:
+                updates["metadata"] = {
+                    **(updates["metadata"] or {}),
+                    SHADOW_OF_KEY: str(memory_id),
+                }
+        extra: dict[str, Any] = {}
+        if expected_version is not None:
+            extra["expected_version"] = expected_version
+        return await self.upper.update_memory(
+            upper_id, tenant_id, updates, changed_by, **extra
+        )
+
+    async def delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        where, upper_id, memory = await self._locate(memory_id, tenant_id)
+        if where == "missing":
+            return False
+        if where == "upper":
+            assert upper_id is not None
+            return await self.upper.delete_memory(upper_id, tenant_id)
+        assert memory is not None
+        if upper_id is not None:
+            await self.upper.delete_memory(upper_id, tenant_id)
+        await self._store_shadow(memory, tenant_id, whiteout=True)
+        return True
+
+    async def _on_upper(
+        self, method: str, memory_id: UUID, tenant_id: str, *args: Any
+    ) -> Any:
+        upper_id = await self._writable_id(memory_id, tenant_id)
+        if upper_id is None:
+            return False
+        return await getattr(self.upper, method)(upper_id, tenant_id, *args)
+
+    async def soft_delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        return bool(await self._on_upper("soft_delete_memory", memory_id, tenant_id))
+
+    async def restore_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        return bool(await self._on_upper("restore_memory", memory_id, tenant_id))
+
+    async def set_lifecycle(
+        self, memory_id: UUID, tenant_id: str, fields: dict[str, Any]
+    ) -> bool:
+        return bool(
+            await self._on_upper("set_lifecycle", memory_id, tenant_id, fields)
+        )
+
+    async def revert_to_version(
+        self, memory_id: UUID, tenant_id: str, version: int
+    ) -> bool:
+        return bool(
+            await self._on_upper("revert_to_version", memory_id, tenant_id, version)
+        )
+
+    async def update_memory_expiration(
+        self, memory_id: UUID, tenant_id: str, expires_at: datetime | None
+    ) -> bool:
+        return bool(
+            await self._on_upper(
+                "update_memory_expiration", memory_id, tenant_id, expires_at
+            )
+        )
+
+    async def adjust_importance(
+        self, memory_id: UUID, delta: float, tenant_id: str
+    ) -> float:
+        upper_id = await self._writable_id(memory_id, tenant_id)
+        if upper_id is None:
+            return 0.0
+        return float(await self.upper.adjust_importance(upper_id, delta, tenant_id))
+
+    async def save_embedding(
+        self,
+        memory_id: UUID,
+        model_name: str,
+        embedding: list[float],
+        tenant_id: str,
+        **kwargs: Any,
+    ) -> bool:
+        upper_id = await self._writable_id(memory_id, tenant_id)
+        if upper_id is None:
+            return False
+        return await self.upper.save_embedding(
+            upper_id, model_name, embedding, tenant_id, **kwargs
+        )
+
+    # Access tracking is recorded for memories of the upper store only
+    async def _upper_only(self, memory_id: UUID, tenant_id: str) -> UUID | None:
+        where, upper_id, _ = await self._locate(memory_id, tenant_id)
+        return upper_id if where in ("upper", "copy") else None
+
+    async def update_memory_access(self, memory_id: UUID, tenant_id: str) -> bool:
+        upper_id = await self._upper_only(memory_id, tenant_id)
+        if upper_id is None:
+            return False
+        return await self.upper.update_memory_access(upper_id, tenant_id)
+
+    async def increment_access_count(self, memory_id: UUID, tenant_id: str) -> bool:
+        upper_id = await self._upper_only(memory_id, tenant_id)
+        if upper_id is None:
+            return False
+        return await self.upper.increment_access_count(upper_id, tenant_id)
+
+    async def update_memory_access_batch(
+        self, memory_ids: list[UUID], tenant_id: str
+    ) -> bool:
+        upper_ids = [await self._upper_only(mid, tenant_id) for mid in memory_ids]
+        ids = [mid for mid in upper_ids if mid is not None]
+        if not ids:
+            return False
+        return await self.upper.update_memory_access_batch(ids, tenant_id)
+
+    async def record_access(
+        self,
+        memory_ids: list[UUID],
+        tenant_id: str,
+        accessed_at: datetime | None = None,
+    ) -> int:
+        upper_ids = [await self._upper_only(mid, tenant_id) for mid in memory_ids]
+        ids = [mid for mid in upper_ids if mid is not None]
+        if not ids:
+            return 0
+        return await self.upper.record_access(ids, tenant_id, accessed_at)
+
+    async def clear_tenant(self, tenant_id: str) -> int:
+        self._shadows.pop(tenant_id, None)
+        return await self.upper.clear_tenant(tenant_id)
+
+    async def health_check(self) -> bool:
+        """Check the upper and all lower stores."""
+        return await components_healthy(self.upper, *self.lower)
+
+    async def close(self) -> None:
+        """Close the upper and all lower stores."""
+        for store in [self.upper, *self.lower]:
+            await store.close()
+
+
+class OverlayVectorStore:
+    """IVectorStore merging search results of a stack of vector stores.
+
+    Vectors are written to the upper store. With the OverlayStorage of the
+    same stack, memories it has deleted are left out of search results.
+    """
+
+    def __init__(
+        self,
+        upper: IVectorStore,
+        lower: list[IVectorStore],
+        storage: OverlayStorage | None = None,
+    ):
+        """Initialize overlay.
+
+        Args:
+            upper: Writable vector store
+            lower: Read-only vector stores
+            storage: Overlay whose whiteouts hide lower-layer vectors
+        """
+        self.upper = upper
+        self.lower = list(lower)
+        self.storage = storage
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self.upper, name)
+
+    async def _hidden(self, tenant_id: str) -> set[UUID]:
+        if self.storage is None:
+            return set()
+        shadows = await self.storage.shadows(tenant_id)
+        return {memory_id for memory_id, copy in shadows.items() if copy is None}
+
+    async def search_similar(
+        self,
+        query_embedding: list[float],
+        tenant_id: str,
+        layer: str | None = None,
+        limit: int = 10,
+        score_threshold: float | None = None,
+        **kwargs: Any,
+    ) -> list[tuple[UUID, float]]:
+        hidden = await self._hidden(tenant_id)
+        best: dict[UUID, float] = {}
+        for store in [self.upper, *self.lower]:
+            results = await store.search_similar(
+                query_embedding,
+                tenant_id,
+                layer=layer,
+                limit=limit + len(hidden),
+                score_threshold=score_threshold,
+                **kwargs,
+            )
+            for memory_id, score, *_ in results:
+                if memory_id not in hidden and score > best.get(memory_id, -1.0):
+                    best[memory_id] = score
+        ranked = sorted(best.items(), key=lambda x: x[1], reverse=True)
+        return ranked[:limit]
+
+    async def get_vector(
+        self, memory_id: UUID, tenant_id: str, vector_name: str | None = None
+    ) -> list[float] | None:
+        if memory_id in await self._hidden(tenant_id):
+            return None
+        for store in [self.upper, *self.lower]:
+            vector = await store.get_vector(
+                memory_id, tenant_id, vector_name=vector_name
+            )
+            if vector is not None:
+                return vector
+        return None
+
+    async def health_check(self) -> bool:
+        """Check the upper and all lower stores."""
+        return await components_healthy(self.upper, *self.lower)
+
+    async def close(self) -> None:
+        """Close the upper and all lower stores."""
+        for store in [self.upper, *self.lower]:
+            await store.close()
+
+
+def _sort_time(value: Any) -> float:
+    if isinstance(value, str):
+        value = datetime.fromisoformat(value)
+    if not isinstance(value, datetime):
+        return float("-inf")
+    if value.tzinfo is None:
+        value = value.replace(tzinfo=timezone.utc)
+    return value.timestamp()
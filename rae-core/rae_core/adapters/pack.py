@@ -0,0 +1,702 @@
+"""Read-only knowledge packs.
+
+A knowledge pack is a single file holding a tenant's long-term (semantic)
+memories, their embeddings and the entity graph between them. It is built
+with build_knowledge_pack and mounted with KnowledgePackStore, which
+memory-maps the file: only the id index is read up front, so packs can be
+shipped with an agent for offline or embedded use without loading them
+into memory.
+
+File layout (little-endian):
+
+    b"RAEPACK\\0" | u32 version | u32 manifest length | manifest JSON
+    records   JSON memory records, newest first
+    index     per memory: 16-byte id, u64 record offset, u32 record length,
+              i32 vector row (-1 if none)
+    vectors   float32 rows of manifest["dimension"] values
+    graph     JSON {"nodes": [...], "edges": [...]}
+
+Section offsets in the manifest are relative to the end of the manifest;
+the manifest checksum covers all sections.
+"""
+
+import hashlib
+import json
+import math
+import mmap
+import os
+import struct
+from collections.abc import Iterator
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any
+from uuid import UUID
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.exceptions.base import ReadOnlyStorageError, ValidationError
+from rae_core.governance.ephemeral import is_ephemeral
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.graph import TraversalLimits
+from rae_core.models.query import RANGE_FILTERS, TIME_FILTERS, matches_range
+from rae_core.sync.snapshot import _normalize_edge, _normalize_node
+
+PACK_MAGIC = b"RAEPACK\0"
+PACK_FORMAT = "rae-knowledge-pack"
+PACK_VERSION = 1
+
+_HEADER = struct.Struct("<8sII")
+_INDEX_ENTRY = struct.Struct("<16sQIi")
+_DATETIME_FIELDS = (
+    "created_at",
+    "modified_at",
+    "updated_at",
+    "last_accessed_at",
+    "expires_at",
+)
+
+
+def _json_default(obj: Any) -> str:
+    if isinstance(obj, UUID):
+        return str(obj)
+    if isinstance(obj, datetime):
+        return obj.isoformat()
+    raise TypeError(f"Type {type(obj)} not serializable")
+
+
+def _created_at(memory: dict[str, Any]) -> datetime:
+    value = memory.get("created_at")
+    if isinstance(value, str):
+        value = datetime.fromisoformat(value)
+    if not isinstance(value, datetime):
+        return datetime.min.replace(tzinfo=timezone.utc)
+    return value if value.tzinfo else value.replace(tzinfo=timezone.utc)
+
+
+async def build_knowledge_pack(
+    path: str | Path,
+    storage: IMemoryStorage,
+    tenant_id: str,
+    vector_store: IVectorStore | None = None,
+    graph_store: IGraphStore | None = None,
+    layers: tuple[str, ...] = ("semantic",),
+    vector_name: str | None = None,
+    page_size: int = 500,
+) -> dict[str, Any]:
+    """Compile memories of a tenant into a knowledge pack file.
+
+    The file is written next to path and moved into place when complete.
+
+    Args:
+        path: Output file
+        storage: Memory storage to read from
+        tenant_id: Tenant to pack
+        vector_store: Vector store holding the embeddings to include
+        graph_store: Graph store holding the entity graph to include
+        layers: Memory layers to pack (ephemeral memories are never packed)
+        vector_name: Embedding model whose vectors are packed
+        page_size: Number of memories fetched per storage call
+
+    Returns:
+        The pack manifest
+
+    Raises:
+        ValidationError: If the embeddings have different dimensions
+    """
+    memories: list[dict[str, Any]] = []
+    for layer in layers:
+        offset = 0
+        while True:
+            page = await storage.list_memories(
+                tenant_id, layer=layer, limit=page_size, offset=offset
+            )
+            memories.extend(m for m in page if not is_ephemeral(m))
+            if len(page) < page_size:
+                break
+            offset += page_size
+    memories.sort(key=_created_at, reverse=True)
+
+    records = bytearray()
+    index = bytearray()
+    vectors = bytearray()
+    dimension: int | None = None
+    rows = 0
+    for memory in memories:
+        memory_id = UUID(str(memory["id"]))
+        data = {k: v for k, v in memory.items() if k != "embedding"}
+        record = json.dumps(data, sort_keys=True, default=_json_default).encode()
+
+        row = -1
+        if vector_store is not None:
+            vector = await vector_store.get_vector(
+                memory_id, tenant_id, vector_name=vector_name
+            )
+            if vector is not None:
+                if dimension is None:
+                    dimension = len(vector)
+                elif len(vector) != dimension:
+                    raise ValidationError(
+                        f"Vector of memory {memory_id} has dimension {len(vector)}, "
+                        f"expected {dimension}"
+                    )
+                vectors += struct.pack(f"<{dimension}f", *vector)
+                row = rows
+                rows += 1
+
+        index += _INDEX_ENTRY.pack(memory_id.bytes, len(records), len(record), row)
+        records += record
+
+    graph: dict[str, list[dict[str, Any]]] = {"nodes": [], "edges": []}
+    if graph_store is not None and memories:
+        subgraph = await graph_store.get_subgraph(
+            [UUID(str(m["id"])) for m in memories], tenant_id
+        )
+        graph["nodes"] = [_normalize_node(n) for n in subgraph.get("nodes", [])]
+        graph["edges"] = [_normalize_edge(e) for e in subgraph.get("edges", [])]
+    graph_bytes = json.dumps(graph, sort_keys=True).encode()
+
+    sections: dict[str, list[int]] = {}
+    data = bytearray()
+    for name, section in (
+        ("records", records),
+        ("index", index),
+        ("vectors", vectors),
+        ("graph", graph_bytes),
+    ):
+        sections[name] = [len(data), len(section)]
+        data += section
+
+    manifest = {
+        "format": PACK_FORMAT,
+        "version": PACK_VERSION,
+        "tenant_id": tenant_id,
+        "layers": list(layers),
+        "created_at": datetime.now(timezone.utc).isoformat(),
+        "vector_name": vector_name,
+        "dimension": dimension,
+        "counts": {
+            "memories": len(memories),
+            "vectors": rows,
+            "nodes": len(graph["nodes"]),
+            "edges": len(graph["edges"]),
+        },
+        "sections": sections,
+        "checksum": hashlib.sha256(data).hexdigest(),
+    }
+    manifest_bytes = json.dumps(manifest, sort_keys=True).encode()
+
+    path = Path(path)
+    tmp_path = path.with_name(path.name + ".tmp")
+    with open(tmp_path, "wb") as f:
+        f.write(_HEADER.pack(PACK_MAGIC, PACK_VERSION, len(manifest_bytes)))
+        f.write(manifest_bytes)
+        f.write(data)
+        f.flush()
+        os.fsync(f.fileno())
+    os.replace(tmp_path, path)
+    return manifest
+
+
+class KnowledgePackStore(IMemoryStorage, IVectorStore, IGraphStore):
+    """Memory, vector and graph store serving a knowledge pack read-only.
+
+    The pack is mounted for one tenant (the packed tenant unless another is
+    given) and optionally one agent. Writes raise ReadOnlyStorageError;
+    access tracking and maintenance calls are accepted and do nothing.
+    """
+
+    def __init__(
+        self,
+        path: str | Path,
+        tenant_id: str | None = None,
+        agent_id: str | None = None,
+        verify: bool = False,
+    ):
+        """Mount a knowledge pack.
+
+        Args:
+            path: Pack file
+            tenant_id: Tenant the pack is served as (the packed tenant if None)
+            agent_id: Agent the memories are attributed to (as packed if None)
+            verify: Check the pack checksum before serving it
+
+        Raises:
+            ValidationError: If the file is not a valid knowledge pack
+        """
+        self.path = Path(path)
+        self._file = open(self.path, "rb")
+        try:
+            self._mm = mmap.mmap(self._file.fileno(), 0, access=mmap.ACCESS_READ)
+            self.manifest, self._data_offset = self._read_manifest(verify)
+        except Exception:
+            self._file.close()
+            raise
+
+        self.tenant_id = tenant_id or self.manifest["tenant_id"]
+        self.agent_id = agent_id
+        self.vector_name: str | None = self.manifest["vector_name"]
+        self.dimension: int | None = self.manifest["dimension"]
+
+        index_offset, index_length = self._section("index")
+        # memory id -> (record offset, record length, vector row)
+        self._index: dict[UUID, tuple[int, int, int]] = {}
+        for position in range(index_offset, index_offset + index_length, 32):
+            raw_id, offset, length, row = _INDEX_ENTRY.unpack_from(self._mm, position)
+            self._index[UUID(bytes=raw_id)] = (offset, length, row)
+        self._graph: InMemoryGraphStore | None = None
+
+    def _read_manifest(self, verify: bool) -> tuple[dict[str, Any], int]:
+        if len(self._mm) < _HEADER.size:
+            raise ValidationError(f"{self.path} is not a knowledge pack")
+        magic, version, length = _HEADER.unpack_from(self._mm, 0)
+        if magic != PACK_MAGIC:
+            raise ValidationError(f"{self.path} is not a knowledge pack")
+        if version != PACK_VERSION:
+            raise ValidationError(f"Unsupported knowledge pack version {version}")
+        start = _HEADER.size
+        manifest = json.loads(self._mm[start : start + length])
+        data_offset = start + length
+        if verify:
+            digest = hashlib.sha256(self._mm[data_offset:]).hexdigest()
+            if digest != manifest["checksum"]:
+                raise ValidationError(f"Knowledge pack {self.path} is corrupted")
+        return manifest, data_offset
+
+    def _section(self, name: str) -> tuple[int, int]:
+        offset, length = self.manifest["sections"][name]
+        return self._data_offset + offset, length
+
+    def _read_only(self, operation: str) -> ReadOnlyStorageError:
+        return ReadOnlyStorageError("KnowledgePackStore", operation)
+
+    def _decode(self, entry: tuple[int, int, int]) -> dict[str, Any]:
+        records_offset, _ = self._section("records")
+        offset, length, _ = entry
+        start = records_offset + offset
+        memory: dict[str, Any] = json.loads(self._mm[start : start + length])
+        memory["id"] = UUID(memory["id"])
+        memory["tenant_id"] = self.tenant_id
+        if self.agent_id is not None:
+            memory["agent_id"] = self.agent_id
+        for field in _DATETIME_FIELDS:
+            if isinstance(memory.get(field), str):
+                memory[field] = datetime.fromisoformat(memory[field])
+        return memory
+
+    def _memories(self) -> Iterator[dict[str, Any]]:
+        """All memories, newest first."""
+        for entry in sorted(self._index.values()):
+            yield self._decode(entry)
+
+    def _owned(self, tenant_id: str | None) -> bool:
+        return tenant_id is None or tenant_id == self.tenant_id
+
+    # =========================================================================
+    # IMemoryStorage
+    # =========================================================================
+
+    async def get_memory(
+        self, memory_id: UUID, tenant_id: str
+    ) -> dict[str, Any] | None:
+        entry = self._index.get(memory_id)
+        if entry is None or not self._owned(tenant_id):
+            return None
+        return self._decode(entry)
+
+    async def get_memories_batch(
+        self, memory_ids: list[UUID], tenant_id: str
+    ) -> list[dict[str, Any]]:
+        memories = [await self.get_memory(mid, tenant_id) for mid in memory_ids]
+        return [m for m in memories if m is not None]
+
+    async def list_memories(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        **kwargs: Any,
+    ) -> list[dict[str, Any]]:
+        if not self._owned(tenant_id):
+            return []
+        tags = kwargs.get("tags")
+        filters = kwargs.get("filters") or {}
+        session_id = kwargs.get("session_id")
+        bounds = {k: kwargs[k] for k in RANGE_FILTERS if kwargs.get(k) is not None}
+        limit = kwargs.get("limit", 100)
+        offset = kwargs.get("offset", 0)
+
+        results = []
+        for memory in self._memories():
+            if agent_id and memory.get("agent_id") != agent_id:
+                continue
+            if layer and memory.get("layer") != layer:
+                continue
+            if session_id and memory.get("session_id") != session_id:
+                continue
+            if tags and not set(tags) & set(memory.get("tags") or []):
+                continue
+            metadata = memory.get("metadata") or {}
+            if any(metadata.get(k) != v for k, v in filters.items()):
+                continue
+            if bounds and not matches_range(memory, **bounds):
+                continue
+            results.append(memory)
+            if len(results) >= offset + limit:
+                break
+        return results[offset:]
+
+    async def count_memories(
+        self,
+        tenant_id: str | None = None,
+        agent_id: str | None = None,
+        layer: str | None = None,
+    ) -> int:
+        if not self._owned(tenant_id):
+            return 0
+        if agent_id is None and layer is None:
+            return len(self._index)
+        return sum(
+            1
+            for m in self._memories()
+            if (agent_id is None or m.get("agent_id") == agent_id)
+            and (layer is None or m.get("layer") == layer)
+        )
+
+    async def search_memories(
+        self,
+        query: str,
+        tenant_id: str,
+        agent_id: str,
+        layer: str | None = None,
+        limit: int = 10,
+        **kwargs: Any,
+    ) -> list[dict[str, Any]]:
+        """Search memories using simple substring matching."""
+        if not self._owned(tenant_id):
+            return []
+        bounds = {k: kwargs[k] for k in TIME_FILTERS if kwargs.get(k) is not None}
+        session_id = kwargs.get("session_id")
+        query_lower = query.lower()
+        results = []
+        for memory in self._memories():
+            if agent_id and memory.get("agent_id") != agent_id:
+                continue
+            if layer is not None and memory.get("layer") != layer:
+                continue
+            if session_id and memory.get("session_id") != session_id:
+                continue
+            if bounds and not matches_range(memory, **bounds):
+                continue
+            content_lower = str(memory.get("content", "")).lower()
+            if query_lower not in content_lower:
+                continue
+            score = 1.0 - content_lower.index(query_lower) / len(content_lower)
+            results.append(
+                {
+                    "id": memory["id"],
+                    "content": memory["content"],
+                    "score": score,
+                    "importance": memory.get("importance", 0.5),
+                    "memory": memory,
+                }
+            )
+        results.sort(key=lambda x: x["score"], reverse=True)
+        return results[:limit]
+
+    async def get_metric_aggregate(
+        self,
+        tenant_id: str,
+        metric: str,
+        func: str,
+        filters: dict[str, Any] | None = None,
+    ) -> float:
+        if not self._owned(tenant_id):
+            return 0.0
+        values = [
+            float(m[metric])
+            for m in self._memories()
+            if m.get(metric) is not None
+            and all(m.get(k) == v for k, v in (filters or {}).items())
+        ]
+        if not values:
+            return 0.0
+        aggregates = {
+            "sum": sum,
+            "avg": lambda v: sum(v) / len(v),
+            "max": max,
+            "min": min,
+            "count": lambda v: float(len(v)),
+        }
+        return float(aggregates[func](values)) if func in aggregates else 0.0
+
+    async def get_change_log(
+        self, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        return []
+
+    async def get_memory_history(
+        self, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        return []
+
+    # Access tracking and maintenance sweeps are no-ops
+    async def update_memory_access(self, memory_id: UUID, tenant_id: str) -> bool:
+        return False
+
+    async def increment_access_count(self, memory_id: UUID, tenant_id: str) -> bool:
+        return False
+
+    async def update_memory_access_batch(
+        self, memory_ids: list[UUID], tenant_id: str
+    ) -> bool:
+        return False
+
+    async def record_access(
+        self,
+        memory_ids: list[UUID],
+        tenant_id: str,
+        accessed_at: datetime | None = None,
+    ) -> int:
+        return 0
+
+    async def delete_expired_memories(
+        self, tenant_id: str, agent_id: str | None = None, layer: str | None = None
+    ) -> int:
+        return 0
+
+    async def decay_importance(self, tenant_id: str, decay_factor: float) -> int:
+        return 0
+
+    # Writes
+    async def store_memory(self, **kwargs: Any) -> UUID:
+        raise self._read_only("store_memory")
+
+    async def store_reflection_audit(self, *args: Any, **kwargs: Any) -> UUID:
+        raise self._read_only("store_reflection_audit")
+
+    async def update_memory(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("update_memory")
+
+    async def delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        raise self._read_only("delete_memory")
+
+    async def revert_to_version(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("revert_to_version")
+
+    async def soft_delete_memory(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("soft_delete_memory")
+
+    async def restore_memory(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("restore_memory")
+
+    async def set_lifecycle(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("set_lifecycle")
+
+    async def purge_deleted(self, *args: Any, **kwargs: Any) -> int:
+        raise self._read_only("purge_deleted")
+
+    async def delete_memories_with_metadata_filter(
+        self, *args: Any, **kwargs: Any
+    ) -> int:
+        raise self._read_only("delete_memories_with_metadata_filter")
+
+    async def delete_memories_below_importance(self, *args: Any, **kwargs: Any) -> int:
+        raise self._read_only("delete_memories_below_importance")
+
+    async def update_memory_expiration(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("update_memory_expiration")
+
+    async def adjust_importance(self, *args: Any, **kwargs: Any) -> float:
+        raise self._read_only("adjust_importance")
+
+    async def save_embedding(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("save_embedding")
+
+    async def clear_tenant(self, tenant_id: str) -> int:
+        raise self._read_only("clear_tenant")
+
+    # =========================================================================
+    # IVectorStore
+    # =========================================================================
+
+    def _vector(self, row: int) -> tuple[float, ...]:
+        assert self.dimension is not None
+        offset, _ = self._section("vectors")
+        return struct.unpack_from(
+            f"<{self.dimension}f", self._mm, offset + row * self.dimension * 4
+        )
+
+    def _serves(self, vector_name: str | None) -> bool:
+        return vector_name is None or vector_name == (self.vector_name or "default")
+
+    async def get_vector(
+        self, memory_id: UUID, tenant_id: str, vector_name: str | None = None
+    ) -> list[float] | None:
+        entry = self._index.get(memory_id)
+        if entry is None or entry[2] < 0 or not self._owned(tenant_id):
+            return None
+        if not self._serves(vector_name):
+            return None
+        return list(self._vector(entry[2]))
+
+    async def search_similar(
+        self,
+        query_embedding: list[float],
+        tenant_id: str,
+        layer: str | None = None,
+        limit: int = 10,
+        score_threshold: float | None = None,
+        agent_id: str | None = None,
+        session_id: str | None = None,
+        filters: dict[str, Any] | None = None,
+        project: str | None = None,
+        vector_name: str | None = None,
+        **kwargs: Any,
+    ) -> list[tuple[UUID, float]]:
+        """Brute-force cosine search over the packed vectors."""
+        if not self._owned(tenant_id) or not self._serves(vector_name):
+            return []
+        if len(query_embedding) != self.dimension:
+            return []
+        query_norm = math.sqrt(sum(x * x for x in query_embedding))
+        if query_norm == 0:
+            return []
+        expected = {
+            "layer": layer,
+            "agent_id": agent_id,
+            "session_id": session_id,
+            "project": project,
+        }
+        expected = {k: v for k, v in expected.items() if v}
+
+        results: list[tuple[UUID, float]] = []
+        for memory_id, entry in self._index.items():
+            if entry[2] < 0:
+                continue
+            if expected or filters:
+                memory = self._decode(entry)
+                if any(memory.get(k) != v for k, v in expected.items()):
+                    continue
+                if filters and not self._matches_filters(memory, filters):
+                    continue
+            vector = self._vector(entry[2])
+            norm = math.sqrt(sum(x * x for x in vector))
+            if norm == 0:
+                continue
+            score = sum(a * b for a, b in zip(query_embedding, vector))
+            score /= query_norm * norm
+            if score <= 0.0:
+                continue
+            if score_threshold is not None and score < score_threshold:
+                continue
+            results.append((memory_id, score))
+        results.sort(key=lambda x: (x[1], x[0].hex), reverse=True)
+        return results[:limit]
+
+    @staticmethod
+    def _matches_filters(memory: dict[str, Any], filters: dict[str, Any]) -> bool:
+        for key, value in filters.items():
+            if key == "tags":
+                wanted = set(value) if isinstance(value, (list, tuple)) else {value}
+                if not wanted <= set(memory.get("tags") or []):
+                    return False
+            elif memory.get(key) != value:
+                return False
+        return True
+
+    async def store_vector(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("store_vector")
+
+    async def update_vector(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("update_vector")
+
+    async def delete_vector(self, memory_id: UUID, tenant_id: str) -> bool:
+        raise self._read_only("delete_vector")
+
+    async def batch_store_vectors(self, *args: Any, **kwargs: Any) -> int:
+        raise self._read_only("batch_store_vectors")
+
+    # =========================================================================
+    # IGraphStore (served from an in-memory copy built on first use)
+    # =========================================================================
+
+    async def _graph_store(self) -> InMemoryGraphStore:
+        if self._graph is None:
+            offset, length = self._section("graph")
+            graph = json.loads(self._mm[offset : offset + length])
+            store = InMemoryGraphStore()
+            for node in graph["nodes"]:
+                await store.create_node(
+                    UUID(node["id"]),
+                    node["node_type"],
+                    self.tenant_id,
+                    properties=node["properties"],
+                )
+            for edge in graph["edges"]:
+                await store.create_edge(
+                    UUID(edge["source_id"]),
+                    UUID(edge["target_id"]),
+                    edge["edge_type"],
+                    self.tenant_id,
+                    weight=edge["weight"],
+                    properties=edge["properties"],
+                )
+            self._graph = store
+        return self._graph
+
+    async def get_neighbors(
+        self,
+        node_id: UUID,
+        tenant_id: str,
+        edge_type: str | None = None,
+        direction: str = "both",
+        max_depth: int = 1,
+        limits: TraversalLimits | None = None,
+    ) -> list[UUID]:
+        graph = await self._graph_store()
+        return await graph.get_neighbors(
+            node_id, tenant_id, edge_type, direction, max_depth, limits
+        )
+
+    async def shortest_path(
+        self,
+        source_id: UUID,
+        target_id: UUID,
+        tenant_id: str,
+        max_depth: int = 5,
+    ) -> list[UUID] | None:
+        graph = await self._graph_store()
+        return await graph.shortest_path(source_id, target_id, tenant_id, max_depth)
+
+    async def get_subgraph(
+        self,
+        node_ids: list[UUID],
+        tenant_id: str,
+        include_edges: bool = True,
+        limits: TraversalLimits | None = None,
+    ) -> dict[str, Any]:
+        graph = await self._graph_store()
+        return await graph.get_subgraph(node_ids, tenant_id, include_edges, limits)
+
+    async def create_node(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("create_node")
+
+    async def create_edge(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("create_edge")
+
+    async def delete_node(self, node_id: UUID, tenant_id: str) -> bool:
+        raise self._read_only("delete_node")
+
+    async def delete_edge(self, *args: Any, **kwargs: Any) -> bool:
+        raise self._read_only("delete_edge")
+
+    async def health_check(self) -> bool:
+        """Whether the pack file is still mapped."""
+        return not self._mm.closed
+
+    async def close(self) -> None:
+        """Unmap and close the pack file."""
+        if not self._mm.closed:
+            self._mm.close()
+        self._file.close()
@@ -0,0 +1,176 @@
+"""Store decorators recording writes into a WritePlan instead of running them.
+
+Used by RAEEngine.what_if. Only calls known to be reads reach the wrapped
+store; every other call is recorded, so a dry run cannot persist anything
+even through methods added to a backend later.
+"""
+
+import inspect
+from typing import Any
+from uuid import UUID, uuid4
+
+from rae_core.models.plan import (
+    PlannedEdge,
+    PlannedNode,
+    PlannedOperation,
+    PlannedRecord,
+    PlannedVector,
+    WritePlan,
+)
+
+# Method name prefixes forwarded to the wrapped store
+READ_PREFIXES = (
+    "get",
+    "list",
+    "count",
+    "search",
+    "exists",
+    "shortest_path",
+    "health_check",
+)
+
+
+class PlanningProxy:
+    """Wraps a memory, vector, graph or cache store for a dry run.
+
+    Reads are forwarded (get_memory and get_vector also see records planned
+    earlier in the same run). Stores and creates are recorded as typed plan
+    entries and return what the real call would (new ids, True); other
+    writes become PlannedOperations and return None.
+    """
+
+    def __init__(self, target: Any, plan: WritePlan, component: str):
+        """Initialize the proxy.
+
+        Args:
+            target: Store whose reads are forwarded
+            plan: Plan receiving the writes
+            component: Name of the store in PlannedOperation.component
+        """
+        self._target = target
+        self._plan = plan
+        self._component = component
+
+    def __getattr__(self, name: str) -> Any:
+        if name.startswith(READ_PREFIXES):
+            return getattr(self._target, name)
+
+        async def record(*args: Any, **kwargs: Any) -> Any:
+            self._plan.operations.append(
+                PlannedOperation(
+                    component=self._component,
+                    method=name,
+                    arguments=self._arguments(name, args, kwargs),
+                )
+            )
+            return None
+
+        return record
+
+    def _arguments(
+        self, name: str, args: tuple[Any, ...], kwargs: dict[str, Any]
+    ) -> dict[str, Any]:
+        method = getattr(self._target, name, None)
+        try:
+            bound = inspect.signature(method).bind_partial(*args, **kwargs)
+        except (TypeError, ValueError):
+            return {"args": list(args), **kwargs}
+        arguments = dict(bound.arguments)
+        # Flatten **kwargs parameters
+        for param in inspect.signature(method).parameters.values():
+            if param.kind is param.VAR_KEYWORD and param.name in arguments:
+                arguments.update(arguments.pop(param.name))
+        return arguments
+
+    # Memory storage
+    async def store_memory(self, **kwargs: Any) -> UUID:
+        memory_id = uuid4()
+        self._plan.records.append(PlannedRecord(memory_id=memory_id, fields=kwargs))
+        return memory_id
+
+    async def get_memory(self, memory_id: UUID, tenant_id: str) -> Any:
+        for record in self._plan.records:
+            if (
+                record.memory_id == memory_id
+                and record.fields.get("tenant_id") == tenant_id
+            ):
+                return {"id": memory_id, **record.fields}
+        return await self._target.get_memory(memory_id, tenant_id)
+
+    # Vector store
+    async def store_vector(
+        self,
+        memory_id: UUID,
+        embedding: Any,
+        tenant_id: str,
+        metadata: dict[str, Any] | None = None,
+    ) -> bool:
+        self._plan.vectors.append(
+            PlannedVector(
+                memory_id=memory_id,
+                tenant_id=tenant_id,
+                embedding=embedding,
+                metadata=metadata or {},
+            )
+        )
+        return True
+
+    async def batch_store_vectors(
+        self, vectors: list[tuple[UUID, Any, dict[str, Any]]], tenant_id: str
+    ) -> int:
+        for memory_id, embedding, metadata in vectors:
+            await self.store_vector(memory_id, embedding, tenant_id, metadata)
+        return len(vectors)
+
+    async def get_vector(
+        self, memory_id: UUID, tenant_id: str, vector_name: str | None = None
+    ) -> Any:
+        for vector in reversed(self._plan.vectors):
+            if vector.memory_id != memory_id or vector.tenant_id != tenant_id:
+                continue
+            if vector_name is None:
+                return vector.embedding
+            if isinstance(vector.embedding, dict) and vector_name in vector.embedding:
+                return vector.embedding[vector_name]
+        return await self._target.get_vector(
+            memory_id, tenant_id, vector_name=vector_name
+        )
+
+    # Graph store
+    async def create_node(
+        self,
+        node_id: UUID,
+        node_type: str,
+        tenant_id: str,
+        properties: dict[str, Any] | None = None,
+    ) -> bool:
+        self._plan.nodes.append(
+            PlannedNode(
+                node_id=node_id,
+                node_type=node_type,
+                tenant_id=tenant_id,
+                properties=properties or {},
+            )
+        )
+        return True
+
+    async def create_edge(
+        self,
+        source_id: UUID,
+        target_id: UUID,
+        edge_type: str,
+        tenant_id: str,
+        weight: float = 1.0,
+        properties: dict[str, Any] | None = None,
+    ) -> bool:
+        self._plan.edges.append(
+            PlannedEdge(
+                source_id=source_id,
+                target_id=target_id,
+                edge_type=edge_type,
+                tenant_id=tenant_id,
+                weight=weight,
+                properties=properties or {},
+            )
+        )
+        return True
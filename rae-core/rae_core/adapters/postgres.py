@@ -1,4 +1,6 @@
 import json
+from collections.abc import AsyncIterator
+from contextlib import asynccontextmanager
 from datetime import datetime, timezone
 from typing import Any
 from uuid import UUID, uuid4
@@ -6,7 +8,13 @@ import re
 
 import asyncpg
 
-from ..interfaces.storage import IMemoryStorage
+from ..exceptions.backend import backend_errors
+from ..exceptions.base import VersionConflictError
+from ..interfaces.storage import LIFECYCLE_FIELDS, IMemoryStorage
+from ..utils.group_commit import CommitDurability, GroupCommitter
+
+_UPDATABLE_FIELDS = ("content", "importance", "layer", "tags", "metadata")
+_INSERT_MEMORY = "INSERT INTO memories (id, content, layer, tenant_id, agent_id, tags, metadata, importance, created_at, project) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"
 
 
 class PostgreSQLStorage(IMemoryStorage):
@@ -14,38 +22,81 @@ class PostgreSQLStorage(IMemoryStorage):
         self,
         dsn: str | None = None,
         pool: asyncpg.Pool | None = None,
+        group_commit_ms: float | None = None,
+        group_commit_max_batch: int = 256,
+        group_commit_durability: CommitDurability | str = CommitDurability.SYNC,
         **pool_kwargs: Any,
     ) -> None:
         self.dsn = dsn
         self._pool = pool
         self._pool_kwargs = pool_kwargs
+        # With group_commit_ms set, concurrent inserts share one transaction
+        self._committer: GroupCommitter[tuple[Any, ...]] | None = None
+        if group_commit_ms is not None:
+            self._committer = GroupCommitter(
+                self._insert_memories,
+                max_delay_ms=group_commit_ms,
+                max_batch=group_commit_max_batch,
+                durability=group_commit_durability,
+            )
 
     async def _get_pool(self) -> asyncpg.Pool:
         if self._pool is None:
             if not self.dsn and not self._pool:
                 raise ValueError("Either dsn or pool must be provided")
-            self._pool = await asyncpg.create_pool(self.dsn, **self._pool_kwargs)
+            with backend_errors("postgres"):
+                self._pool = await asyncpg.create_pool(self.dsn, **self._pool_kwargs)
         return self._pool
 
+    async def get_pool(self) -> asyncpg.Pool:
+        """Connection pool of the storage, created on first use.
+
+        Other Postgres adapters (PgVectorStore) can run on it instead of
+        opening their own.
+        """
+        return await self._get_pool()
+
+    @asynccontextmanager
+    async def _acquire(self, pool: asyncpg.Pool) -> AsyncIterator[Any]:
+        """Acquire a connection; asyncpg errors surface as RAEError subclasses."""
+        with backend_errors("postgres"):
+            async with pool.acquire() as conn:
+                yield conn
+
     async def store_memory(self, **kwargs: Any) -> UUID:
-        pool = await self._get_pool()
         m_id = uuid4()
-        async with pool.acquire() as conn:
-            await conn.execute(
-                "INSERT INTO memories (id, content, layer, tenant_id, agent_id, tags, metadata, importance, created_at, project) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
-                m_id,
-                kwargs.get("content"),
-                kwargs.get("layer"),
-                kwargs.get("tenant_id"),
-                kwargs.get("agent_id"),
-                kwargs.get("tags", []),
-                json.dumps(kwargs.get("metadata", {})),
-                kwargs.get("importance", 0.5),
-                datetime.now(timezone.utc).replace(tzinfo=None),
-                kwargs.get("project"),
-            )
+        row = (
+            m_id,
+            kwargs.get("content"),
+            kwargs.get("layer"),
+            kwargs.get("tenant_id"),
+            kwargs.get("agent_id"),
+            kwargs.get("tags", []),
+            json.dumps(kwargs.get("metadata", {})),
+            kwargs.get("importance", 0.5),
+            datetime.now(timezone.utc).replace(tzinfo=None),
+            kwargs.get("project"),
+        )
+        if self._committer is not None:
+            await self._committer.submit(row)
+            return m_id
+        pool = await self._get_pool()
+        async with self._acquire(pool) as conn:
+            await conn.execute(_INSERT_MEMORY, *row)
         return m_id
 
+    async def _insert_memories(self, rows: list[tuple[Any, ...]]) -> None:
+        """Insert a batch of memory rows in one transaction."""
+        pool = await self._get_pool()
+        async with self._acquire(pool) as conn:
+            async with conn.transaction():
+                await conn.executemany(_INSERT_MEMORY, rows)
+
+    async def flush(self) -> None:
+        """Commit memories still waiting for a group commit."""
+        if self._committer is not None:
+            await self._committer.flush()
+
     async def store_reflection_audit(
         self,
         query_id: str,
@@ -60,7 +111,7 @@ class PostgreSQLStorage(IMemoryStorage):
     ) -> UUID:
         pool = await self._get_pool()
         audit_id = uuid4()
-        async with pool.acquire() as conn:
+        async with self._acquire(pool) as conn:
             await conn.execute(
                 """INSERT INTO reflection_audits (
                     id, query_id, tenant_id, agent_id, fsi_score, 
@@ -124,6 +175,7 @@ class PostgreSQLStorage(IMemoryStorage):
                    ts_rank_cd(to_tsvector('english', coalesce(content, '')), websearch_to_tsquery('english', $1)) as ts_rank
             FROM memories
             WHERE tenant_id = $2 AND agent_id = $3 AND layer = $4
+            AND deleted_at IS NULL
             {"AND project = $6" if project else ""}
             AND (
                 to_tsvector('english', coalesce(content, '')) @@ websearch_to_tsquery('english', $1)
@@ -134,7 +186,7 @@ class PostgreSQLStorage(IMemoryStorage):
         SELECT * FROM candidates ORDER BY ts_rank DESC LIMIT $7
         """
         
-        async with pool.acquire() as conn:
+        async with self._acquire(pool) as conn:
             if project:
                 rows = await conn.fetch(sql, ts_query, tenant_id, agent_id, layer, f"%{query}%", project, limit)
             else:
@@ -144,28 +196,146 @@ class PostgreSQLStorage(IMemoryStorage):
 
     async def get_memory(self, memory_id: UUID, tenant_id: str) -> dict[str, Any] | None:
         pool = await self._get_pool()
-        async with pool.acquire() as conn:
+        async with self._acquire(pool) as conn:
             row = await conn.fetchrow("SELECT * FROM memories WHERE id = $1 AND tenant_id = $2", memory_id, tenant_id)
         return self._row_to_dict(row)
 
     async def list_memories(self, tenant_id: str, **kwargs: Any) -> list[dict[str, Any]]:
         pool = await self._get_pool()
         limit = kwargs.get("limit", 100)
-        async with pool.acquire() as conn:
-            rows = await conn.fetch("SELECT * FROM memories WHERE tenant_id = $1 LIMIT $2", tenant_id, limit)
+        deleted_sql = "" if kwargs.get("include_deleted") else " AND deleted_at IS NULL"
+        async with self._acquire(pool) as conn:
+            rows = await conn.fetch(f"SELECT * FROM memories WHERE tenant_id = $1{deleted_sql} LIMIT $2", tenant_id, limit)
         return [self._row_to_dict(r) for r in rows if r]
 
+    async def update_memory(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        updates: dict[str, Any],
+        changed_by: str | None = None,
+        expected_version: int | None = None,
+    ) -> bool:
+        params: list[Any] = [memory_id, tenant_id]
+        assignments = []
+        for field, value in updates.items():
+            if field in _UPDATABLE_FIELDS:
+                params.append(json.dumps(value) if field == "metadata" else value)
+                assignments.append(f"{field} = ${len(params)}")
+        if not assignments:
+            return False
+
+        sql = f"UPDATE memories SET {', '.join(assignments)}, version = version + 1 WHERE id = $1 AND tenant_id = $2"
+        if expected_version is not None:
+            params.append(expected_version)
+            sql += f" AND version = ${len(params)}"
+
+        pool = await self._get_pool()
+        async with self._acquire(pool) as conn:
+            result = await conn.execute(sql, *params)
+            if result.endswith(" 1"):
+                return True
+            current = await conn.fetchval(
+                "SELECT version FROM memories WHERE id = $1 AND tenant_id = $2", memory_id, tenant_id
+            )
+        if current is None or expected_version is None:
+            return False
+        raise VersionConflictError(memory_id, expected_version, current)
+
+    async def get_memory_history(self, memory_id: UUID, tenant_id: str) -> list[dict[str, Any]]:
+        # Past revisions are not kept by this adapter's schema
+        raise NotImplementedError("PostgreSQLStorage does not keep memory revisions")
+
+    async def revert_to_version(self, memory_id: UUID, tenant_id: str, version: int) -> bool:
+        raise NotImplementedError("PostgreSQLStorage does not keep memory revisions")
+
+    async def soft_delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        pool = await self._get_pool()
+        async with self._acquire(pool) as conn:
+            result = await conn.execute(
+                "UPDATE memories SET deleted_at = $1 WHERE id = $2 AND tenant_id = $3 AND deleted_at IS NULL",
+                datetime.now(timezone.utc).replace(tzinfo=None),
+                memory_id,
+                tenant_id,
+            )
+        return result.endswith(" 1")
+
+    async def restore_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        pool = await self._get_pool()
+        async with self._acquire(pool) as conn:
+            result = await conn.execute(
+                "UPDATE memories SET deleted_at = NULL WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NOT NULL",
+                memory_id,
+                tenant_id,
+            )
+        return result.endswith(" 1")
+
+    async def set_lifecycle(self, memory_id: UUID, tenant_id: str, fields: dict[str, Any]) -> bool:
+        updates = {
+            k: v.astimezone(timezone.utc).replace(tzinfo=None) if isinstance(v, datetime) else v
+            for k, v in fields.items()
+            if k in LIFECYCLE_FIELDS
+        }
+        if not updates:
+            return await self.get_memory(memory_id, tenant_id) is not None
+        assignments = ", ".join(f"{k} = ${i}" for i, k in enumerate(updates, start=3))
+        pool = await self._get_pool()
+        async with self._acquire(pool) as conn:
+            result = await conn.execute(
+                f"UPDATE memories SET {assignments} WHERE id = $1 AND tenant_id = $2",
+                memory_id,
+                tenant_id,
+                *updates.values(),
+            )
+        return result.endswith(" 1")
+
+    async def purge_deleted(self, before: datetime, tenant_id: str | None = None) -> int:
+        pool = await self._get_pool()
+        cutoff = before.astimezone(timezone.utc).replace(tzinfo=None)
+        async with self._acquire(pool) as conn:
+            if tenant_id:
+                result = await conn.execute(
+                    "DELETE FROM memories WHERE deleted_at < $1 AND tenant_id = $2", cutoff, tenant_id
+                )
+            else:
+                result = await conn.execute("DELETE FROM memories WHERE deleted_at < $1", cutoff)
+        return int(result.split()[-1])
+
+    async def replication_lag(self) -> float:
+        """Seconds this server's replay trails its primary (0 on a primary).
+
+        On an idle primary the value grows without new writes, so it is an
+        upper bound on staleness.
+        """
+        pool = await self._get_pool()
+        async with self._acquire(pool) as conn:
+            lag = await conn.fetchval(
+                "SELECT CASE WHEN pg_is_in_recovery() THEN COALESCE("
+                "EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()), 0) "
+                "ELSE 0 END"
+            )
+        return max(0.0, float(lag or 0.0))
+
+    async def health_check(self) -> bool:
+        """Check a pooled connection answers a query."""
+        pool = await self._get_pool()
+        async with self._acquire(pool) as conn:
+            await conn.fetchval("SELECT 1")
+        return True
+
     async def close(self) -> None:
+        await self.flush()
         if self._pool: await self._pool.close()
 
     async def delete_memories_with_metadata_filter(self, tenant_id=None, agent_id=None, layer=None, metadata_filter=None) -> int: return 0
     async def count_memories(self, tenant_id=None, agent_id=None, layer=None) -> int: return 0
     async def update_memory_access(self, memory_id, tenant_id) -> bool: return True
     async def delete_expired_memories(self, tenant_id, agent_id=None, layer=None) -> int: return 0
-    async def update_memory(self, memory_id, tenant_id, updates) -> bool: return True
     async def delete_memory(self, memory_id, tenant_id) -> bool: return True
+    async def get_change_log(self, memory_id, tenant_id) -> list[dict[str, Any]]: return []
     async def get_metric_aggregate(self, tenant_id, metric, func, filters=None) -> float: return 0.0
     async def update_memory_access_batch(self, memory_ids, tenant_id) -> bool: return True
+    async def record_access(self, memory_ids, tenant_id, accessed_at=None) -> int: return 0
     async def adjust_importance(self, memory_id, delta, tenant_id) -> float: return 0.5
     async def delete_memories_below_importance(self, tenant_id, agent_id, layer, importance_threshold) -> int: return 0
     async def decay_importance(self, tenant_id, decay_factor) -> int: return 0
@@ -17,9 +17,22 @@ from qdrant_client.models import (
 )
 
 from rae_core.interfaces.vector import IVectorStore
+from rae_core.math.distance import (
+    MetricConfig,
+    collection_metric,
+    euclid_distance_limit,
+    euclid_score,
+)
+from rae_core.types.enums import DistanceMetric
 
 logger = structlog.get_logger(__name__)
 
+_DISTANCES = {
+    DistanceMetric.COSINE: Distance.COSINE,
+    DistanceMetric.EUCLID: Distance.EUCLID,
+    DistanceMetric.DOT: Distance.DOT,
+}
+
 
 class QdrantVectorStore(IVectorStore):
     """Qdrant implementation of the Vector Store interface."""
@@ -32,6 +45,7 @@ class QdrantVectorStore(IVectorStore):
         collection_name: str = "memories",
         embedding_dim: int = 384,
         vector_name: str = "dense",
+        distance_metric: MetricConfig = DistanceMetric.COSINE,
     ):
         """Initialize Qdrant Vector Store.
 
@@ -42,6 +56,9 @@ class QdrantVectorStore(IVectorStore):
             collection_name: Name of the collection.
             embedding_dim: Dimension of embeddings (default 384).
             vector_name: Name of the named vector (default "dense").
+            distance_metric: Metric of the named vectors, or a dict of metrics
+                by vector name (cosine for the others). Applies when a named
+                vector is created.
         """
         if client:
             self.client = client
@@ -53,6 +70,7 @@ class QdrantVectorStore(IVectorStore):
         self.collection_name = collection_name
         self.embedding_dim = embedding_dim
         self.vector_name = vector_name
+        self.distance_metric = distance_metric
         self._initialized = False
         self._known_vectors: set[str] = set()
 
@@ -117,7 +135,7 @@ class QdrantVectorStore(IVectorStore):
             collection_name=self.collection_name,
             vectors_config={
                 self.vector_name: VectorParams(
-                    size=self.embedding_dim, distance=Distance.COSINE
+                    size=self.embedding_dim, distance=self._distance(self.vector_name)
                 )
             },
         )
@@ -143,7 +161,9 @@ class QdrantVectorStore(IVectorStore):
                 await self.client.update_collection(
                     collection_name=self.collection_name,
                     vectors_config={
-                        vector_name: VectorParams(size=dim, distance=Distance.COSINE)
+                        vector_name: VectorParams(
+                            size=dim, distance=self._distance(vector_name)
+                        )
                     },
                 )
 
@@ -151,6 +171,9 @@ class QdrantVectorStore(IVectorStore):
         except Exception as e:
             logger.error(f"Failed to update vector config for {vector_name}: {e}")
 
+    def _distance(self, vector_name: str) -> Distance:
+        return _DISTANCES[collection_metric(self.distance_metric, vector_name)]
+
     def _build_filter(
         self,
         tenant_id: str,
@@ -355,6 +378,14 @@ class QdrantVectorStore(IVectorStore):
         """Search for similar vectors."""
         target_vector = vector_name or self.vector_name
         await self._ensure_collection()
+        # Qdrant scores euclidean collections by distance (lower is better)
+        euclid = (
+            collection_metric(self.distance_metric, target_vector)
+            == DistanceMetric.EUCLID
+        )
+        if euclid and score_threshold is not None:
+            limit_distance = euclid_distance_limit(score_threshold)
+            score_threshold = None if math.isinf(limit_distance) else limit_distance
 
         search_filter = self._build_filter(
             tenant_id=tenant_id,
@@ -380,7 +411,8 @@ class QdrantVectorStore(IVectorStore):
             output = []
             for r in response.points:
                 if r.payload and "memory_id" in r.payload:
-                    output.append((UUID(r.payload["memory_id"]), float(r.score)))
+                    score = euclid_score(r.score) if euclid else float(r.score)
+                    output.append((UUID(r.payload["memory_id"]), score))
 
             return output
         except Exception as e:
@@ -435,6 +467,11 @@ class QdrantVectorStore(IVectorStore):
             logger.error(f"Qdrant delete_by_layer failed: {e}")
             return 0
 
+    async def health_check(self) -> bool:
+        """Check the Qdrant server answers."""
+        await self.client.get_collections()
+        return True
+
     async def close(self) -> None:
         """Close the Qdrant client."""
         await self.client.close()
@@ -270,6 +270,10 @@ class RedisCache(ICacheProvider):
         except Exception:
             return False
 
+    async def health_check(self) -> bool:
+        """Check Redis answers a PING."""
+        return await self.ping()
+
     async def close(self) -> None:
         """Close Redis connection."""
         try:
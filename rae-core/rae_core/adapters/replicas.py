@@ -0,0 +1,201 @@
+"""Read/write splitting across a primary and read replicas.
+
+Writes always go to the primary. Read methods accept an optional
+max_staleness hint (seconds) per call: a replica may serve the read if its
+replication lag is within the hint and it has already replayed the tenant's
+latest write through this router (read-your-writes). Otherwise, or if the
+replica is unreachable, the read falls back to the primary.
+"""
+
+import inspect
+import itertools
+import math
+from collections.abc import Awaitable, Callable
+from datetime import datetime
+from typing import Any
+
+import structlog
+
+from rae_core.exceptions.base import BackendUnavailableError
+from rae_core.health import components_healthy
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.utils.clock import IClock, SystemClock
+
+logger = structlog.get_logger(__name__)
+
+LagProbe = Callable[[IMemoryStorage], Awaitable[float | None]]
+
+# IMemoryStorage methods that never mutate state and may use a replica
+READ_METHODS = frozenset(
+    {
+        "get_memory",
+        "get_memories_batch",
+        "list_memories",
+        "search_memories",
+        "search_full_text",
+        "count_memories",
+        "get_metric_aggregate",
+        "get_change_log",
+        "get_memory_history",
+    }
+)
+
+
+async def default_lag_probe(replica: IMemoryStorage) -> float | None:
+    """Ask the replica for its lag; None if the backend cannot report it."""
+    probe = getattr(replica, "replication_lag", None)
+    if probe is None:
+        return None
+    return float(await probe())
+
+
+class ReadReplicaStorage:
+    """Routes IMemoryStorage reads to replicas within a staleness bound.
+
+    Replica lag is probed at most once per lag_check_interval seconds. A
+    replica whose lag cannot be determined is only used by calls accepting
+    unbounded staleness (max_staleness=math.inf).
+    """
+
+    def __init__(
+        self,
+        primary: IMemoryStorage,
+        replicas: list[IMemoryStorage],
+        default_max_staleness: float | None = None,
+        lag_check_interval: float = 1.0,
+        lag_probe: LagProbe | None = None,
+        clock: IClock | None = None,
+    ):
+        """Initialize router.
+
+        Args:
+            primary: Storage receiving all writes
+            replicas: Read-only copies of the primary
+            default_max_staleness: Staleness accepted by reads without a
+                hint (None reads from the primary)
+            lag_check_interval: Seconds a probed replica lag is reused
+            lag_probe: Measures replica lag in seconds (default: the
+                replica's replication_lag() method)
+            clock: Time source
+        """
+        self.primary = primary
+        self.replicas = list(replicas)
+        self.default_max_staleness = default_max_staleness
+        self.lag_check_interval = lag_check_interval
+        self._lag_probe = lag_probe or default_lag_probe
+        self._clock = clock or SystemClock()
+        self._rotation = itertools.cycle(range(len(self.replicas)))
+        # {replica index: (probed at, lag)}; None is unknown, inf unreachable
+        self._lags: dict[int, tuple[datetime, float | None]] = {}
+        self._last_write: dict[str, datetime] = {}
+        # Last write not scoped to a single tenant (e.g. a global purge)
+        self._last_global_write: datetime | None = None
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self.primary, name)
+        if name in READ_METHODS:
+
+            async def read(*args: Any, **kwargs: Any) -> Any:
+                return await self._read(name, args, kwargs)
+
+            return read
+        if not inspect.iscoroutinefunction(attr):
+            return attr
+
+        async def write(*args: Any, **kwargs: Any) -> Any:
+            try:
+                return await attr(*args, **kwargs)
+            finally:
+                self._mark_write(_tenant_of(attr, args, kwargs))
+
+        return write
+
+    async def health_check(self) -> bool:
+        """Check the primary and all replicas."""
+        return await components_healthy(self.primary, *self.replicas)
+
+    async def close(self) -> None:
+        """Close the primary and all replicas."""
+        for storage in [self.primary, *self.replicas]:
+            close = getattr(storage, "close", None)
+            if close is not None:
+                await close()
+
+    async def replica_lags(self) -> list[float | None]:
+        """Current lag of each replica in seconds (None when unknown)."""
+        return [await self._lag(i) for i in range(len(self.replicas))]
+
+    async def _read(
+        self, name: str, args: tuple[Any, ...], kwargs: dict[str, Any]
+    ) -> Any:
+        max_staleness = kwargs.pop("max_staleness", self.default_max_staleness)
+        tenant_id = _tenant_of(getattr(self.primary, name), args, kwargs)
+        replica = await self._choose_replica(max_staleness, tenant_id)
+        if replica is not None:
+            try:
+                return await getattr(self.replicas[replica], name)(*args, **kwargs)
+            except BackendUnavailableError as e:
+                logger.warning(
+                    "read_replica_unavailable", replica=replica, error=str(e)
+                )
+                self._lags[replica] = (self._clock.now(), math.inf)
+        return await getattr(self.primary, name)(*args, **kwargs)
+
+    async def _choose_replica(
+        self, max_staleness: float | None, tenant_id: str | None
+    ) -> int | None:
+        if max_staleness is None or max_staleness <= 0:
+            return None
+
+        now = self._clock.now()
+        writes = [self._last_write.get(tenant_id or ""), self._last_global_write]
+        last_write = max((w for w in writes if w is not None), default=None)
+        for _ in range(len(self.replicas)):
+            index = next(self._rotation)
+            lag = await self._lag(index)
+            if lag is None:
+                if math.isinf(max_staleness):
+                    return index
+                continue
+            if math.isinf(lag) or lag > max_staleness:
+                continue
+            # The replica must already contain the tenant's latest write
+            if last_write is not None and (now - last_write).total_seconds() < lag:
+                continue
+            return index
+        return None
+
+    async def _lag(self, index: int) -> float | None:
+        now = self._clock.now()
+        cached = self._lags.get(index)
+        if cached is not None:
+            probed_at, lag = cached
+            if (now - probed_at).total_seconds() < self.lag_check_interval:
+                return lag
+        try:
+            lag = await self._lag_probe(self.replicas[index])
+        except Exception as e:
+            logger.warning("replica_lag_probe_failed", replica=index, error=str(e))
+            lag = math.inf
+        self._lags[index] = (now, lag)
+        return lag
+
+    def _mark_write(self, tenant_id: str | None) -> None:
+        if tenant_id is None:
+            self._last_global_write = self._clock.now()
+        else:
+            self._last_write[tenant_id] = self._clock.now()
+
+
+def _tenant_of(
+    method: Any, args: tuple[Any, ...], kwargs: dict[str, Any]
+) -> str | None:
+    """Tenant a storage call is scoped to, if any."""
+    if kwargs.get("tenant_id") is not None:
+        return str(kwargs["tenant_id"])
+    try:
+        bound = inspect.signature(method).bind_partial(*args, **kwargs)
+    except (TypeError, ValueError):
+        return None
+    tenant_id = bound.arguments.get("tenant_id")
+    return str(tenant_id) if tenant_id is not None else None
@@ -1,5 +1,13 @@
+from rae_core.adapters.sqlite.audit import SQLiteAuditLogger
 from rae_core.adapters.sqlite.graph import SQLiteGraphStore
+from rae_core.adapters.sqlite.outbox import SQLiteOutboxStore
 from rae_core.adapters.sqlite.storage import SQLiteStorage
 from rae_core.adapters.sqlite.vector import SQLiteVectorStore
 
-__all__ = ["SQLiteStorage", "SQLiteVectorStore", "SQLiteGraphStore"]
+__all__ = [
+    "SQLiteStorage",
+    "SQLiteVectorStore",
+    "SQLiteGraphStore",
+    "SQLiteAuditLogger",
+    "SQLiteOutboxStore",
+]
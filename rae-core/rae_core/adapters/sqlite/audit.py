@@ -0,0 +1,116 @@
+"""SQLite audit logger for RAE-core."""
+
+import json
+from datetime import datetime
+from typing import Any
+from uuid import UUID
+
+import aiosqlite
+
+from rae_core.adapters.sqlite.connection import connect
+from rae_core.interfaces.audit import IAuditLogger
+from rae_core.models.audit import AuditEntry, AuditOperation
+
+
+class SQLiteAuditLogger(IAuditLogger):
+    """Audit log persisted in an append-only SQLite table.
+
+    Can share the database file of SQLiteStorage.
+    """
+
+    def __init__(self, db_path: str = ":memory:"):
+        self.db_path = db_path
+        self._initialized = False
+
+    async def initialize(self) -> None:
+        if self._initialized:
+            return
+
+        async with connect(self.db_path) as db:
+            await db.execute(
+                """
+                CREATE TABLE IF NOT EXISTS audit_log (
+                    id TEXT PRIMARY KEY,
+                    timestamp TEXT NOT NULL,
+                    tenant_id TEXT NOT NULL,
+                    actor TEXT,
+                    operation TEXT NOT NULL,
+                    memory_id TEXT NOT NULL,
+                    diff TEXT,
+                    metadata TEXT
+                )
+            """
+            )
+            await db.execute(
+                "CREATE INDEX IF NOT EXISTS idx_audit_tenant_time ON audit_log (tenant_id, timestamp)"
+            )
+            await db.commit()
+        self._initialized = True
+
+    async def record(self, entry: AuditEntry) -> None:
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            await db.execute(
+                "INSERT INTO audit_log (id, timestamp, tenant_id, actor, operation, memory_id, diff, metadata) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                (
+                    str(entry.id),
+                    entry.timestamp.isoformat(),
+                    entry.tenant_id,
+                    entry.actor,
+                    entry.operation.value,
+                    str(entry.memory_id),
+                    json.dumps(entry.diff, default=str),
+                    json.dumps(entry.metadata, default=str),
+                ),
+            )
+            await db.commit()
+
+    async def query(
+        self,
+        tenant_id: str,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        actor: str | None = None,
+        operation: AuditOperation | None = None,
+        memory_id: UUID | None = None,
+        limit: int = 100,
+    ) -> list[AuditEntry]:
+        await self.initialize()
+        where = ["tenant_id = ?"]
+        params: list[Any] = [tenant_id]
+        if start:
+            where.append("timestamp >= ?")
+            params.append(start.isoformat())
+        if end:
+            where.append("timestamp < ?")
+            params.append(end.isoformat())
+        if actor:
+            where.append("actor = ?")
+            params.append(actor)
+        if operation:
+            where.append("operation = ?")
+            params.append(AuditOperation(operation).value)
+        if memory_id:
+            where.append("memory_id = ?")
+            params.append(str(memory_id))
+        params.append(limit)
+
+        sql = f"SELECT * FROM audit_log WHERE {' AND '.join(where)} ORDER BY timestamp LIMIT ?"
+        async with connect(self.db_path) as db:
+            db.row_factory = aiosqlite.Row
+            async with db.execute(sql, params) as cursor:
+                rows = await cursor.fetchall()
+
+        return [
+            AuditEntry(
+                id=UUID(r["id"]),
+                timestamp=datetime.fromisoformat(r["timestamp"]),
+                tenant_id=r["tenant_id"],
+                actor=r["actor"],
+                operation=AuditOperation(r["operation"]),
+                memory_id=UUID(r["memory_id"]),
+                diff=json.loads(r["diff"]) if r["diff"] else [],
+                metadata=json.loads(r["metadata"]) if r["metadata"] else {},
+            )
+            for r in rows
+        ]
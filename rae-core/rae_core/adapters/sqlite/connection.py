@@ -0,0 +1,20 @@
+"""Shared connection helper for the SQLite adapters."""
+
+from collections.abc import AsyncIterator
+from contextlib import asynccontextmanager
+
+import aiosqlite
+
+from rae_core.exceptions.backend import backend_errors
+
+
+@asynccontextmanager
+async def connect(db_path: str) -> AsyncIterator[aiosqlite.Connection]:
+    """Open a connection whose driver errors surface as RAEError subclasses.
+
+    Errors handled inside the block (e.g. FTS fallbacks) are unaffected; only
+    those escaping it are translated.
+    """
+    with backend_errors("sqlite"):
+        async with aiosqlite.connect(db_path) as db:
+            yield db
@@ -4,12 +4,45 @@ Lightweight graph storage ideal for RAE-Lite offline-first architecture.
 """
 
 import json
+from datetime import datetime, timezone
 from typing import Any
 from uuid import UUID
 
 import aiosqlite
 
+from rae_core.adapters.sqlite.connection import connect
+from rae_core.exceptions.base import FanOutExceededError
 from rae_core.interfaces.graph import IGraphStore
+from rae_core.models.graph import EdgeSampling, TraversalLimits
+from rae_core.utils.graph_traversal import (
+    NeighborEdge,
+    apply_visit_budget,
+    bounded_bfs,
+    sampling_key,
+)
+
+
+def _parse_timestamp(value: Any) -> datetime | None:
+    """Parse SQLite CURRENT_TIMESTAMP values as UTC datetimes."""
+    if not value:
+        return None
+    try:
+        parsed = datetime.fromisoformat(str(value))
+    except ValueError:
+        return None
+    return parsed if parsed.tzinfo else parsed.replace(tzinfo=timezone.utc)
+
+
+def _sql_sampling_key(limits: TraversalLimits) -> Any:
+    """SQL function computing graph_traversal.sampling_key for an edge row."""
+
+    def key(
+        node_id: str, other: str, weight: float, created_at: Any, newest: Any
+    ) -> float:
+        edge = NeighborEdge(UUID(other), weight, _parse_timestamp(created_at))
+        return sampling_key(UUID(node_id), edge, limits, _parse_timestamp(newest))
+
+    return key
 
 
 class SQLiteGraphStore(IGraphStore):
@@ -29,7 +62,7 @@ class SQLiteGraphStore(IGraphStore):
         if self._initialized:
             return
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             # Enable WAL mode for better concurrency
             await db.execute("PRAGMA journal_mode=WAL")
 
@@ -86,7 +119,7 @@ class SQLiteGraphStore(IGraphStore):
         """Create a graph node."""
         await self.initialize()
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             try:
                 await db.execute(
                     """
@@ -113,7 +146,7 @@ class SQLiteGraphStore(IGraphStore):
         """Create a graph edge."""
         await self.initialize()
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             try:
                 await db.execute(
                     """
@@ -144,42 +177,109 @@ class SQLiteGraphStore(IGraphStore):
         edge_type: str | None = None,
         direction: str = "both",
         max_depth: int = 1,
+        limits: TraversalLimits | None = None,
     ) -> list[UUID]:
         """Get neighboring nodes using BFS-like traversal."""
         await self.initialize()
 
-        async with aiosqlite.connect(self.db_path) as db:
-            query_parts = []
-            params = []
-
-            if direction in ["out", "both"]:
-                sql = "SELECT target_id FROM knowledge_graph_edges WHERE source_id = ? AND tenant_id = ?"
-                if edge_type:
-                    sql += " AND type = ?"
-                    params.extend([str(node_id), tenant_id, edge_type])
-                else:
-                    params.extend([str(node_id), tenant_id])
-                query_parts.append(sql)
-
-            if direction in ["in", "both"]:
-                sql = "SELECT source_id FROM knowledge_graph_edges WHERE target_id = ? AND tenant_id = ?"
-                if edge_type:
-                    sql += " AND type = ?"
-                    params.extend([str(node_id), tenant_id, edge_type])
-                else:
-                    params.extend([str(node_id), tenant_id])
-                query_parts.append(sql)
-
-            full_query = " UNION ".join(query_parts)
-
-            async with db.execute(full_query, params) as cursor:
-                rows = await cursor.fetchall()
-                return [UUID(row[0]) for row in rows]
+        async with connect(self.db_path) as db:
+            if limits is not None and limits.max_fan_out is not None:
+                await db.create_function(
+                    "rae_sampling_key",
+                    5,
+                    _sql_sampling_key(limits),
+                    deterministic=True,
+                )
+
+            async def expand(current: UUID) -> list[NeighborEdge]:
+                return await self._direct_neighbors(
+                    db, current, tenant_id, edge_type, direction, limits
+                )
+
+            return await bounded_bfs(node_id, expand, max_depth, limits)
+
+    async def _direct_neighbors(
+        self,
+        db: aiosqlite.Connection,
+        node_id: UUID,
+        tenant_id: str,
+        edge_type: str | None,
+        direction: str,
+        limits: TraversalLimits | None = None,
+    ) -> list[NeighborEdge]:
+        """Fetch one hop of neighbors with edge weight and creation time.
+
+        With limits.max_fan_out set, the edges kept by limits.sampling are
+        selected in SQL, so hub nodes never load their whole adjacency.
+        """
+        query_parts = []
+        params: list[Any] = []
+
+        if direction in ["out", "both"]:
+            sql = "SELECT target_id, COALESCE(weight, 1.0), created_at FROM knowledge_graph_edges WHERE source_id = ? AND tenant_id = ?"
+            if edge_type:
+                sql += " AND type = ?"
+                params.extend([str(node_id), tenant_id, edge_type])
+            else:
+                params.extend([str(node_id), tenant_id])
+            query_parts.append(sql)
+
+        if direction in ["in", "both"]:
+            sql = "SELECT source_id, COALESCE(weight, 1.0), created_at FROM knowledge_graph_edges WHERE target_id = ? AND tenant_id = ?"
+            if edge_type:
+                sql += " AND type = ?"
+                params.extend([str(node_id), tenant_id, edge_type])
+            else:
+                params.extend([str(node_id), tenant_id])
+            query_parts.append(sql)
+
+        fan_out = limits.max_fan_out if limits is not None else None
+        strict = limits is not None and limits.strict
+        columns = "other, weight, created_at"
+        if fan_out is not None and strict:
+            # The window counts every neighbor, so errors report the real degree
+            columns += ", COUNT(*) OVER ()"
+
+        # A neighbor linked by several edges keeps its strongest one
+        edges = " UNION ".join(query_parts)
+        full_query = (
+            f"WITH edges(other, weight, created_at) AS ({edges}), "
+            "neighbors AS (SELECT other, MAX(weight) AS weight, created_at "
+            f"FROM edges GROUP BY other) SELECT {columns} FROM neighbors"
+        )
+        if limits is not None and fan_out is not None:
+            if limits.sampling == EdgeSampling.TOP_WEIGHT:
+                full_query += " ORDER BY weight DESC, other"
+            elif limits.sampling != EdgeSampling.TRUNCATE:
+                full_query += (
+                    " ORDER BY rae_sampling_key(?, other, weight, created_at, "
+                    "(SELECT MAX(created_at) FROM neighbors)) DESC"
+                )
+                params.append(str(node_id))
+            full_query += " LIMIT ?"
+            params.append(fan_out)
+
+        async with db.execute(full_query, params) as cursor:
+            rows = await cursor.fetchall()
+
+        if fan_out is not None and strict and rows and rows[0][3] > fan_out:
+            raise FanOutExceededError(node_id, rows[0][3], fan_out)
+        return [
+            NeighborEdge(UUID(row[0]), float(row[1]), _parse_timestamp(row[2]))
+            for row in rows
+        ]
+
+    async def health_check(self) -> bool:
+        """Check the database file opens and answers a query."""
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            await db.execute("SELECT 1")
+        return True
 
     async def delete_node(self, node_id: UUID, tenant_id: str) -> bool:
         """Delete a node and its edges."""
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             cursor = await db.execute(
                 "DELETE FROM knowledge_graph_nodes WHERE id = ? AND tenant_id = ?",
                 (str(node_id), tenant_id),
@@ -196,7 +296,7 @@ class SQLiteGraphStore(IGraphStore):
     ) -> bool:
         """Delete an edge."""
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             cursor = await db.execute(
                 "DELETE FROM knowledge_graph_edges WHERE source_id = ? AND target_id = ? AND type = ? AND tenant_id = ?",
                 (str(source_id), str(target_id), edge_type, tenant_id),
@@ -230,13 +330,18 @@ class SQLiteGraphStore(IGraphStore):
         return None
 
     async def get_subgraph(
-        self, node_ids: list[UUID], tenant_id: str, include_edges: bool = True
+        self,
+        node_ids: list[UUID],
+        tenant_id: str,
+        include_edges: bool = True,
+        limits: TraversalLimits | None = None,
     ) -> dict[str, Any]:
         """Extract a subgraph."""
         await self.initialize()
+        node_ids = apply_visit_budget(list(node_ids), limits)
         result: dict[str, Any] = {"nodes": [], "edges": []}
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             db.row_factory = aiosqlite.Row
             placeholders = ",".join(["?" for _ in node_ids])
             params = [str(nid) for nid in node_ids] + [tenant_id]
@@ -0,0 +1,137 @@
+"""SQLite event outbox for RAE-core."""
+
+import json
+from datetime import datetime, timezone
+from uuid import UUID
+
+import aiosqlite
+
+from rae_core.adapters.sqlite.connection import connect
+from rae_core.events.models import MemoryEvent
+from rae_core.interfaces.outbox import IOutboxStore
+from rae_core.models.outbox import OutboxEntry
+
+
+class SQLiteOutboxStore(IOutboxStore):
+    """Event outbox persisted in SQLite.
+
+    Give it to SQLiteStorage (same database file) to have events written in
+    the same transaction as the memory change that caused them.
+    """
+
+    def __init__(self, db_path: str = ":memory:"):
+        self.db_path = db_path
+        self._initialized = False
+
+    async def initialize(self) -> None:
+        if self._initialized:
+            return
+
+        async with connect(self.db_path) as db:
+            await self.create_schema(db)
+            await db.commit()
+        self._initialized = True
+
+    @staticmethod
+    async def create_schema(db: aiosqlite.Connection) -> None:
+        """Create the outbox tables on an open connection (no commit)."""
+        await db.execute(
+            """
+            CREATE TABLE IF NOT EXISTS event_outbox (
+                sequence INTEGER PRIMARY KEY AUTOINCREMENT,
+                event_id TEXT NOT NULL,
+                event_type TEXT NOT NULL,
+                tenant_id TEXT NOT NULL,
+                payload TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )
+        """
+        )
+        await db.execute(
+            """
+            CREATE TABLE IF NOT EXISTS event_outbox_cursors (
+                sink TEXT PRIMARY KEY,
+                sequence INTEGER NOT NULL
+            )
+        """
+        )
+
+    async def append(self, events: list[MemoryEvent]) -> list[int]:
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            sequences = await self.append_in(db, events)
+            await db.commit()
+        return sequences
+
+    async def append_in(
+        self, db: aiosqlite.Connection, events: list[MemoryEvent]
+    ) -> list[int]:
+        """Insert events within the caller's transaction (no commit)."""
+        now = datetime.now(timezone.utc).isoformat()
+        sequences = []
+        for event in events:
+            cursor = await db.execute(
+                "INSERT INTO event_outbox (event_id, event_type, tenant_id, payload, created_at) VALUES (?, ?, ?, ?, ?)",
+                (
+                    str(event.event_id),
+                    type(event).__name__,
+                    event.tenant_id,
+                    event.model_dump_json(),
+                    now,
+                ),
+            )
+            sequences.append(cursor.lastrowid)
+        return sequences
+
+    async def publish(self, event: MemoryEvent) -> int:
+        (sequence,) = await self.append([event])
+        return sequence
+
+    async def read(self, after: int, limit: int = 100) -> list[OutboxEntry]:
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            db.row_factory = aiosqlite.Row
+            async with db.execute(
+                "SELECT * FROM event_outbox WHERE sequence > ? ORDER BY sequence LIMIT ?",
+                (after, limit),
+            ) as cursor:
+                rows = await cursor.fetchall()
+
+        return [
+            OutboxEntry(
+                sequence=r["sequence"],
+                event_id=UUID(r["event_id"]),
+                event_type=r["event_type"],
+                tenant_id=r["tenant_id"],
+                payload=json.loads(r["payload"]),
+                created_at=datetime.fromisoformat(r["created_at"]),
+            )
+            for r in rows
+        ]
+
+    async def get_cursor(self, sink: str) -> int:
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            async with db.execute(
+                "SELECT sequence FROM event_outbox_cursors WHERE sink = ?", (sink,)
+            ) as cursor:
+                row = await cursor.fetchone()
+        return row[0] if row else 0
+
+    async def set_cursor(self, sink: str, sequence: int) -> None:
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            await db.execute(
+                "INSERT OR REPLACE INTO event_outbox_cursors (sink, sequence) VALUES (?, ?)",
+                (sink, sequence),
+            )
+            await db.commit()
+
+    async def prune(self, up_to: int) -> int:
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            cursor = await db.execute(
+                "DELETE FROM event_outbox WHERE sequence <= ?", (up_to,)
+            )
+            await db.commit()
+            return cursor.rowcount
@@ -1,27 +1,77 @@
 """SQLite storage adapter for RAE-core with FTS5 full-text search."""
 
 import json
+from collections import Counter
 from datetime import datetime, timezone
 from typing import Any
 from uuid import UUID, uuid4
 
 import aiosqlite
 
-from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.adapters.sqlite.connection import connect
+from rae_core.adapters.sqlite.outbox import SQLiteOutboxStore
+from rae_core.events.models import (
+    MemoryDeleted,
+    MemoryEvent,
+    MemoryPromoted,
+    MemoryRestored,
+    MemoryStored,
+    MemoryUpdated,
+)
+from rae_core.exceptions.base import NotFoundError, VersionConflictError
+from rae_core.interfaces.storage import LIFECYCLE_FIELDS, IMemoryStorage
+from rae_core.models.query import TIME_FIELDS
+from rae_core.utils.changelog import field_changes
+from rae_core.utils.group_commit import CommitDurability, GroupCommitter
+
+# Memory row and its MemoryStored event (None without an outbox)
+_PendingInsert = tuple[tuple[Any, ...], MemoryEvent | None]
 
 
 class SQLiteStorage(IMemoryStorage):
-    """SQLite implementation of IMemoryStorage with FTS5 search."""
+    """SQLite implementation of IMemoryStorage with FTS5 search.
 
-    def __init__(self, db_path: str = ":memory:"):
+    With an outbox (on the same database file), store, update, delete, soft
+    delete and restore write their lifecycle events in the same transaction
+    as the change, so no committed change is left without its event.
+
+    With group_commit_ms set, concurrent store_memory calls are collected
+    for up to that many milliseconds and inserted in one transaction, which
+    raises ingest throughput during bulk loads. With "async" durability
+    store_memory returns before the commit (call flush() before reading the
+    memories back); a failing insert fails its whole batch.
+    """
+
+    def __init__(
+        self,
+        db_path: str = ":memory:",
+        history_limit: int = 10,
+        outbox: SQLiteOutboxStore | None = None,
+        group_commit_ms: float | None = None,
+        group_commit_max_batch: int = 256,
+        group_commit_durability: CommitDurability | str = CommitDurability.SYNC,
+    ):
+        if outbox is not None and outbox.db_path != db_path:
+            raise ValueError("The outbox must use the storage database file")
         self.db_path = db_path
+        # Past revisions retained per memory (0 disables history)
+        self.history_limit = max(0, history_limit)
+        self.outbox = outbox
+        self._committer: GroupCommitter[_PendingInsert] | None = None
+        if group_commit_ms is not None:
+            self._committer = GroupCommitter(
+                self._insert_memories,
+                max_delay_ms=group_commit_ms,
+                max_batch=group_commit_max_batch,
+                durability=group_commit_durability,
+            )
         self._initialized = False
 
     async def initialize(self) -> None:
         if self._initialized:
             return
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             await db.execute("PRAGMA journal_mode=WAL")
             await db.execute(
                 """
@@ -40,10 +90,22 @@ class SQLiteStorage(IMemoryStorage):
                     access_count INTEGER DEFAULT 0,
                     version INTEGER DEFAULT 1,
                     expires_at TEXT,
-                    project TEXT
+                    project TEXT,
+                    deleted_at TEXT,
+                    session_id TEXT
                 )
             """
             )
+            # Databases created before soft delete or sessions lack the columns
+            async with db.execute("PRAGMA table_info(memories)") as cursor:
+                columns = {row[1] for row in await cursor.fetchall()}
+            for column in ("deleted_at", "session_id"):
+                if column not in columns:
+                    await db.execute(f"ALTER TABLE memories ADD COLUMN {column} TEXT")
+            await db.execute(
+                "CREATE INDEX IF NOT EXISTS idx_memories_session "
+                "ON memories(tenant_id, session_id)"
+            )
             # Support for embeddings table used in tests
             await db.execute(
                 """
@@ -56,6 +118,41 @@ class SQLiteStorage(IMemoryStorage):
                 )
             """
             )
+            # Past revisions of memories, written before each update
+            await db.execute(
+                """
+                CREATE TABLE IF NOT EXISTS memory_versions (
+                    memory_id TEXT NOT NULL,
+                    version INTEGER NOT NULL,
+                    content TEXT NOT NULL,
+                    layer TEXT NOT NULL,
+                    tags TEXT,
+                    metadata TEXT,
+                    importance REAL,
+                    modified_at TEXT NOT NULL,
+                    PRIMARY KEY (memory_id, version)
+                )
+            """
+            )
+            # Field-level change log, one row per update
+            await db.execute(
+                """
+                CREATE TABLE IF NOT EXISTS memory_changes (
+                    memory_id TEXT NOT NULL,
+                    version INTEGER NOT NULL,
+                    changed_at TEXT NOT NULL,
+                    changed_by TEXT,
+                    changes TEXT NOT NULL,
+                    PRIMARY KEY (memory_id, version)
+                )
+            """
+            )
+            await db.execute("""
+                CREATE TRIGGER IF NOT EXISTS memories_versions_ad AFTER DELETE ON memories BEGIN
+                    DELETE FROM memory_versions WHERE memory_id = old.id;
+                    DELETE FROM memory_changes WHERE memory_id = old.id;
+                END;
+            """)
             # FTS5 virtual table for lightning-fast keyword search
             await db.execute(
                 "CREATE VIRTUAL TABLE IF NOT EXISTS memories_fts USING fts5(content, content='memories')"
@@ -79,9 +176,29 @@ class SQLiteStorage(IMemoryStorage):
                 END;
             """)
             
+            if self.outbox is not None:
+                await self.outbox.create_schema(db)
             await db.commit()
         self._initialized = True
 
+    async def _emit(self, db: aiosqlite.Connection, *events: MemoryEvent) -> None:
+        """Write events to the outbox within the current transaction."""
+        if self.outbox is not None:
+            await self.outbox.append_in(db, list(events))
+
+    async def _agent_of(
+        self, db: aiosqlite.Connection, memory_id: UUID, tenant_id: str
+    ) -> str | None:
+        # Only needed for events; skip the lookup without an outbox
+        if self.outbox is None:
+            return None
+        async with db.execute(
+            "SELECT agent_id FROM memories WHERE id = ? AND tenant_id = ?",
+            (str(memory_id), tenant_id),
+        ) as cursor:
+            row = await cursor.fetchone()
+        return row[0] if row else None
+
     async def store_memory(self, **kwargs: Any) -> UUID:
         await self.initialize()
         m_id = uuid4()
@@ -93,37 +210,65 @@ class SQLiteStorage(IMemoryStorage):
         if "info_class" not in metadata:
             metadata["info_class"] = "internal"
 
-        async with aiosqlite.connect(self.db_path) as db:
-            await db.execute(
-                "INSERT INTO memories (id, content, layer, tenant_id, agent_id, tags, metadata, importance, created_at, modified_at, last_accessed_at, project, expires_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
-                (
-                    str(m_id),
-                    kwargs.get("content"),
-                    kwargs.get("layer"),
-                    kwargs.get("tenant_id"),
-                    kwargs.get("agent_id"),
-                    json.dumps(tags),
-                    json.dumps(metadata),
-                    kwargs.get("importance", 0.5),
-                    now,
-                    now,
-                    now,
-                    kwargs.get("project"),
-                    (
-                        kwargs.get("expires_at").isoformat()
-                        if kwargs.get("expires_at")
-                        else None
-                    ),
-                ),
+        row = (
+            str(m_id),
+            kwargs.get("content"),
+            kwargs.get("layer"),
+            kwargs.get("tenant_id"),
+            kwargs.get("agent_id"),
+            json.dumps(tags),
+            json.dumps(metadata),
+            kwargs.get("importance", 0.5),
+            now,
+            now,
+            now,
+            kwargs.get("project"),
+            (
+                kwargs.get("expires_at").isoformat()
+                if kwargs.get("expires_at")
+                else None
+            ),
+            kwargs.get("session_id"),
+        )
+        event = None
+        if self.outbox is not None:
+            event = MemoryStored(
+                tenant_id=kwargs["tenant_id"],
+                memory_id=m_id,
+                agent_id=kwargs.get("agent_id"),
+                layer=kwargs.get("layer") or "episodic",
+                content=kwargs.get("content", ""),
+                tags=tags,
+                metadata=metadata,
             )
-            await db.commit()
+        if self._committer is not None:
+            await self._committer.submit((row, event))
+        else:
+            await self._insert_memories([(row, event)])
         return m_id
 
+    async def _insert_memories(self, writes: list[_PendingInsert]) -> None:
+        """Insert memories and their events in one transaction."""
+        async with connect(self.db_path) as db:
+            await db.executemany(
+                "INSERT INTO memories (id, content, layer, tenant_id, agent_id, tags, metadata, importance, created_at, modified_at, last_accessed_at, project, expires_at, session_id) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                [row for row, _ in writes],
+            )
+            events = [event for _, event in writes if event is not None]
+            if events:
+                await self._emit(db, *events)
+            await db.commit()
+
+    async def flush(self) -> None:
+        """Commit memories still waiting for a group commit."""
+        if self._committer is not None:
+            await self._committer.flush()
+
     async def get_memory(
         self, memory_id: UUID, tenant_id: str
     ) -> dict[str, Any] | None:
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             db.row_factory = aiosqlite.Row
             async with db.execute(
                 "SELECT * FROM memories WHERE id = ? AND tenant_id = ?",
@@ -137,7 +282,7 @@ class SQLiteStorage(IMemoryStorage):
     ) -> list[dict[str, Any]]:
         await self.initialize()
         ids = [str(mid) for mid in memory_ids]
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             db.row_factory = aiosqlite.Row
             placeholders = ",".join(["?"] * len(ids))
             async with db.execute(
@@ -148,16 +293,25 @@ class SQLiteStorage(IMemoryStorage):
                 return [self._row_to_dict(r) for r in rows]
 
     async def update_memory(
-        self, memory_id: UUID, tenant_id: str, updates: dict[str, Any]
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        updates: dict[str, Any],
+        changed_by: str | None = None,
+        expected_version: int | None = None,
     ) -> bool:
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
+            db.row_factory = aiosqlite.Row
             async with db.execute(
-                "SELECT id FROM memories WHERE id = ? AND tenant_id = ?",
+                "SELECT * FROM memories WHERE id = ? AND tenant_id = ?",
                 (str(memory_id), tenant_id),
             ) as cursor:
-                if not await cursor.fetchone():
+                row = await cursor.fetchone()
+                if not row:
                     return False
+            if expected_version is not None and row["version"] != expected_version:
+                raise VersionConflictError(memory_id, expected_version, row["version"])
 
             if not updates:
                 return False
@@ -173,29 +327,271 @@ class SQLiteStorage(IMemoryStorage):
             if not cols:
                 return False
 
+            now = datetime.now(timezone.utc).isoformat()
             cols.append("version = version + 1")
             cols.append("modified_at = ?")
-            vals.append(datetime.now(timezone.utc).isoformat())
+            vals.append(now)
+
+            if self.history_limit:
+                await self._record_revision(db, memory_id)
 
+            applied = {k: v for k, v in updates.items() if k in valid_fields}
+            changes = field_changes(self._row_to_dict(row), applied)
+            if changes:
+                await db.execute(
+                    "INSERT OR REPLACE INTO memory_changes (memory_id, version, changed_at, changed_by, changes) VALUES (?, ?, ?, ?, ?)",
+                    (
+                        str(memory_id),
+                        row["version"] + 1,
+                        now,
+                        changed_by,
+                        json.dumps(changes, default=str),
+                    ),
+                )
+
+            # Guarding on the version read above makes a concurrent update
+            # in between fail instead of being overwritten
             sql = (
-                f"UPDATE memories SET {', '.join(cols)} WHERE id = ? AND tenant_id = ?"
+                f"UPDATE memories SET {', '.join(cols)} "
+                "WHERE id = ? AND tenant_id = ? AND version = ?"
             )
-            vals.extend([str(memory_id), tenant_id])
+            vals.extend([str(memory_id), tenant_id, row["version"]])
 
-            await db.execute(sql, vals)
+            cursor = await db.execute(sql, vals)
+            if cursor.rowcount == 0:
+                await db.rollback()
+                current = await self.get_memory(memory_id, tenant_id)
+                if current is None:
+                    return False
+                raise VersionConflictError(
+                    memory_id, row["version"], current["version"]
+                )
+            if self.outbox is not None:
+                await self._emit(
+                    db,
+                    *self._update_events(row, memory_id, applied, changes, changed_by),
+                )
             await db.commit()
             return True
 
+    @staticmethod
+    def _update_events(
+        row: aiosqlite.Row,
+        memory_id: UUID,
+        applied: dict[str, Any],
+        changes: list[dict[str, Any]],
+        changed_by: str | None,
+    ) -> list[MemoryEvent]:
+        events: list[MemoryEvent] = [
+            MemoryUpdated(
+                tenant_id=row["tenant_id"],
+                memory_id=memory_id,
+                agent_id=row["agent_id"],
+                actor=changed_by,
+                changes={c["field"]: applied[c["field"]] for c in changes},
+                diff=changes,
+            )
+        ]
+        if "layer" in applied and row["layer"] != applied["layer"]:
+            events.append(
+                MemoryPromoted(
+                    tenant_id=row["tenant_id"],
+                    memory_id=memory_id,
+                    agent_id=row["agent_id"],
+                    actor=changed_by,
+                    from_layer=str(row["layer"]),
+                    to_layer=str(applied["layer"]),
+                )
+            )
+        return events
+
+    async def _record_revision(self, db: aiosqlite.Connection, memory_id: UUID) -> None:
+        """Copy the current row into memory_versions and prune old revisions."""
+        await db.execute(
+            "INSERT OR REPLACE INTO memory_versions (memory_id, version, content, layer, tags, metadata, importance, modified_at) "
+            "SELECT id, version, content, layer, tags, metadata, importance, modified_at FROM memories WHERE id = ?",
+            (str(memory_id),),
+        )
+        await db.execute(
+            "DELETE FROM memory_versions WHERE memory_id = ? AND version NOT IN "
+            "(SELECT version FROM memory_versions WHERE memory_id = ? ORDER BY version DESC LIMIT ?)",
+            (str(memory_id), str(memory_id), self.history_limit),
+        )
+
+    async def get_change_log(
+        self, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        if not await self.get_memory(memory_id, tenant_id):
+            return []
+
+        async with connect(self.db_path) as db:
+            db.row_factory = aiosqlite.Row
+            async with db.execute(
+                "SELECT version, changed_at, changed_by, changes FROM memory_changes WHERE memory_id = ? ORDER BY version",
+                (str(memory_id),),
+            ) as cursor:
+                rows = await cursor.fetchall()
+
+        return [
+            {
+                "version": r["version"],
+                "changed_at": datetime.fromisoformat(r["changed_at"]),
+                "changed_by": r["changed_by"],
+                "changes": json.loads(r["changes"]),
+            }
+            for r in rows
+        ]
+
+    async def get_memory_history(
+        self, memory_id: UUID, tenant_id: str
+    ) -> list[dict[str, Any]]:
+        current = await self.get_memory(memory_id, tenant_id)
+        if not current:
+            return []
+
+        async with connect(self.db_path) as db:
+            db.row_factory = aiosqlite.Row
+            async with db.execute(
+                "SELECT * FROM memory_versions WHERE memory_id = ? ORDER BY version",
+                (str(memory_id),),
+            ) as cursor:
+                rows = await cursor.fetchall()
+
+        history = []
+        for row in rows:
+            revision = dict(row)
+            del revision["memory_id"]
+            revision["tags"] = json.loads(row["tags"]) if row["tags"] else []
+            revision["metadata"] = json.loads(row["metadata"]) if row["metadata"] else {}
+            history.append(revision)
+        history.append(
+            {
+                k: current[k]
+                for k in (
+                    "content",
+                    "layer",
+                    "tags",
+                    "metadata",
+                    "importance",
+                    "version",
+                    "modified_at",
+                )
+            }
+        )
+        return history
+
+    async def revert_to_version(
+        self, memory_id: UUID, tenant_id: str, version: int
+    ) -> bool:
+        history = await self.get_memory_history(memory_id, tenant_id)
+        revision = next((r for r in history[:-1] if r["version"] == version), None)
+        if revision is None:
+            return False
+
+        return await self.update_memory(
+            memory_id,
+            tenant_id,
+            {
+                k: revision[k]
+                for k in ("content", "layer", "tags", "metadata", "importance")
+            },
+        )
+
     async def delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
+            agent_id = await self._agent_of(db, memory_id, tenant_id)
             cursor = await db.execute(
                 "DELETE FROM memories WHERE id = ? AND tenant_id = ?",
                 (str(memory_id), tenant_id),
             )
+            if cursor.rowcount > 0:
+                await self._emit(
+                    db,
+                    MemoryDeleted(
+                        tenant_id=tenant_id, memory_id=memory_id, agent_id=agent_id
+                    ),
+                )
             await db.commit()
             return cursor.rowcount > 0
 
+    async def soft_delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            agent_id = await self._agent_of(db, memory_id, tenant_id)
+            cursor = await db.execute(
+                "UPDATE memories SET deleted_at = ? WHERE id = ? AND tenant_id = ? AND deleted_at IS NULL",
+                (datetime.now(timezone.utc).isoformat(), str(memory_id), tenant_id),
+            )
+            if cursor.rowcount > 0:
+                await self._emit(
+                    db,
+                    MemoryDeleted(
+                        tenant_id=tenant_id,
+                        memory_id=memory_id,
+                        agent_id=agent_id,
+                        soft=True,
+                    ),
+                )
+            await db.commit()
+            return cursor.rowcount > 0
+
+    async def restore_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            agent_id = await self._agent_of(db, memory_id, tenant_id)
+            cursor = await db.execute(
+                "UPDATE memories SET deleted_at = NULL WHERE id = ? AND tenant_id = ? AND deleted_at IS NOT NULL",
+                (str(memory_id), tenant_id),
+            )
+            if cursor.rowcount > 0:
+                await self._emit(
+                    db,
+                    MemoryRestored(
+                        tenant_id=tenant_id, memory_id=memory_id, agent_id=agent_id
+                    ),
+                )
+            await db.commit()
+            return cursor.rowcount > 0
+
+    async def set_lifecycle(
+        self, memory_id: UUID, tenant_id: str, fields: dict[str, Any]
+    ) -> bool:
+        updates = {
+            k: v.isoformat() if isinstance(v, datetime) else v
+            for k, v in fields.items()
+            if k in LIFECYCLE_FIELDS
+        }
+        if not updates:
+            return await self.get_memory(memory_id, tenant_id) is not None
+
+        await self.initialize()
+        assignments = ", ".join(f"{k} = ?" for k in updates)
+        async with connect(self.db_path) as db:
+            cursor = await db.execute(
+                f"UPDATE memories SET {assignments} WHERE id = ? AND tenant_id = ?",
+                (*updates.values(), str(memory_id), tenant_id),
+            )
+            await db.commit()
+            return cursor.rowcount > 0
+
+    async def purge_deleted(
+        self, before: datetime, tenant_id: str | None = None
+    ) -> int:
+        await self.initialize()
+        where = ["deleted_at IS NOT NULL", "deleted_at < ?"]
+        params = [before.isoformat()]
+        if tenant_id:
+            where.append("tenant_id = ?")
+            params.append(tenant_id)
+
+        async with connect(self.db_path) as db:
+            cursor = await db.execute(
+                f"DELETE FROM memories WHERE {' AND '.join(where)}", params
+            )
+            await db.commit()
+            return cursor.rowcount
+
     async def list_memories(
         self,
         tenant_id: str,
@@ -227,20 +623,37 @@ class SQLiteStorage(IMemoryStorage):
         where_clauses = ["tenant_id = ?"]
         params = [tenant_id]
 
+        if not kwargs.get("include_deleted", False):
+            where_clauses.append("deleted_at IS NULL")
         if agent_id:
             where_clauses.append("agent_id = ?")
             params.append(agent_id)
         if layer:
             where_clauses.append("layer = ?")
             params.append(layer)
+        if kwargs.get("session_id"):
+            where_clauses.append("session_id = ?")
+            params.append(kwargs["session_id"])
 
         for k, v in filters.items():
             where_clauses.append(f"json_extract(metadata, '$.{k}') = ?")
             params.append(str(v))
 
-        sql = f"SELECT * FROM memories WHERE {' AND '.join(where_clauses)} ORDER BY {order_by} {direction}"
+        self._add_time_bounds(kwargs, where_clauses, params)
+        if kwargs.get("importance_gt") is not None:
+            where_clauses.append("importance > ?")
+            params.append(kwargs["importance_gt"])
+        if kwargs.get("importance_gte") is not None:
+            where_clauses.append("importance >= ?")
+            params.append(kwargs["importance_gte"])
+
+        # id breaks ties so that limit/offset pages never overlap or skip rows
+        sql = (
+            f"SELECT * FROM memories WHERE {' AND '.join(where_clauses)} "
+            f"ORDER BY {order_by} {direction}, id {direction}"
+        )
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             db.row_factory = aiosqlite.Row
             async with db.execute(sql, params) as cursor:
                 rows = await cursor.fetchall()
@@ -266,16 +679,25 @@ class SQLiteStorage(IMemoryStorage):
         **kwargs: Any,
     ) -> list[dict[str, Any]]:
         await self.initialize()
-        where_clauses = ["tenant_id = ?", "agent_id = ?", "content LIKE ?"]
+        where_clauses = [
+            "tenant_id = ?",
+            "agent_id = ?",
+            "content LIKE ?",
+            "deleted_at IS NULL",
+        ]
         params = [tenant_id, agent_id, f"%{query}%"]
         if layer:
             where_clauses.append("layer = ?")
             params.append(layer)
+        if kwargs.get("session_id"):
+            where_clauses.append("session_id = ?")
+            params.append(kwargs["session_id"])
+        self._add_time_bounds(kwargs, where_clauses, params)
 
         params.append(limit)
         sql = f"SELECT * FROM memories WHERE {' AND '.join(where_clauses)} LIMIT ?"
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             db.row_factory = aiosqlite.Row
             async with db.execute(sql, params) as cursor:
                 rows = await cursor.fetchall()
@@ -289,6 +711,20 @@ class SQLiteStorage(IMemoryStorage):
                     for r in rows
                 ]
 
+    @staticmethod
+    def _add_time_bounds(
+        kwargs: dict[str, Any], where_clauses: list[str], params: list[Any]
+    ) -> None:
+        """Append created/updated/accessed after/before conditions."""
+        # Timestamps are stored as UTC ISO-8601 text, so bounds compare as text
+        for prefix, column in TIME_FIELDS.items():
+            for side, operator in (("after", ">="), ("before", "<")):
+                value = kwargs.get(f"{prefix}_{side}")
+                if value is None:
+                    continue
+                where_clauses.append(f"{column} {operator} ?")
+                params.append(value.astimezone(timezone.utc).isoformat())
+
     async def search_full_text(
         self, query: str, tenant_id: str, limit: int = 10
     ) -> list[dict[str, Any]]:
@@ -298,7 +734,7 @@ class SQLiteStorage(IMemoryStorage):
         if not search_term:
             return []
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             db.row_factory = aiosqlite.Row
             # Use FTS5 MATCH for high precision search
             # We join with the main memories table to get the tenant_id filtering and full metadata
@@ -306,7 +742,7 @@ class SQLiteStorage(IMemoryStorage):
                 SELECT m.* 
                 FROM memories m
                 JOIN memories_fts f ON m.rowid = f.rowid
-                WHERE m.tenant_id = ? AND memories_fts MATCH ?
+                WHERE m.tenant_id = ? AND m.deleted_at IS NULL AND memories_fts MATCH ?
                 LIMIT ?
             """
             try:
@@ -315,7 +751,7 @@ class SQLiteStorage(IMemoryStorage):
                     return [self._row_to_dict(r) for r in rows]
             except aiosqlite.OperationalError:
                 # Fallback to LIKE if MATCH fails (e.g. invalid syntax or FTS table missing)
-                sql_fallback = "SELECT * FROM memories WHERE tenant_id = ? AND content LIKE ? LIMIT ?"
+                sql_fallback = "SELECT * FROM memories WHERE tenant_id = ? AND deleted_at IS NULL AND content LIKE ? LIMIT ?"
                 async with db.execute(sql_fallback, (tenant_id, f"%{search_term}%", limit)) as cursor:
                     rows = await cursor.fetchall()
                     return [self._row_to_dict(r) for r in rows]
@@ -327,7 +763,7 @@ class SQLiteStorage(IMemoryStorage):
         layer: str | None = None,
     ) -> int:
         await self.initialize()
-        where_clauses = []
+        where_clauses = ["deleted_at IS NULL"]
         params = []
         if tenant_id:
             where_clauses.append("tenant_id = ?")
@@ -339,8 +775,8 @@ class SQLiteStorage(IMemoryStorage):
             where_clauses.append("layer = ?")
             params.append(layer)
 
-        where_sql = f"WHERE {' AND '.join(where_clauses)}" if where_clauses else ""
-        async with aiosqlite.connect(self.db_path) as db:
+        where_sql = f"WHERE {' AND '.join(where_clauses)}"
+        async with connect(self.db_path) as db:
             async with db.execute(
                 f"SELECT COUNT(*) FROM memories {where_sql}", params
             ) as cursor:
@@ -371,7 +807,7 @@ class SQLiteStorage(IMemoryStorage):
         self, tenant_id: str, agent_id: str, layer: str, importance_threshold: float
     ) -> int:
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             cursor = await db.execute(
                 "DELETE FROM memories WHERE tenant_id = ? AND agent_id = ? AND layer = ? AND importance < ?",
                 (tenant_id, agent_id, layer, importance_threshold),
@@ -394,7 +830,7 @@ class SQLiteStorage(IMemoryStorage):
             where.append("layer = ?")
             params.append(layer)
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             cursor = await db.execute(
                 f"DELETE FROM memories WHERE {' AND '.join(where)}", params
             )
@@ -403,7 +839,7 @@ class SQLiteStorage(IMemoryStorage):
 
     async def update_memory_access(self, memory_id: UUID, tenant_id: str) -> bool:
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             cursor = await db.execute(
                 "UPDATE memories SET access_count = access_count + 1, last_accessed_at = ? WHERE id = ? AND tenant_id = ?",
                 (datetime.now(timezone.utc).isoformat(), str(memory_id), tenant_id),
@@ -419,7 +855,7 @@ class SQLiteStorage(IMemoryStorage):
     ) -> bool:
         await self.initialize()
         exp_str = expires_at.isoformat() if expires_at else None
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             cursor = await db.execute(
                 "UPDATE memories SET expires_at = ? WHERE id = ? AND tenant_id = ?",
                 (exp_str, str(memory_id), tenant_id),
@@ -435,7 +871,7 @@ class SQLiteStorage(IMemoryStorage):
         filters: dict[str, Any] | None = None,
     ) -> float:
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             try:
                 async with db.execute(
                     f"SELECT {func}({metric}) FROM memories WHERE tenant_id = ?",
@@ -453,6 +889,29 @@ class SQLiteStorage(IMemoryStorage):
             await self.update_memory_access(mid, tenant_id)
         return True
 
+    async def record_access(
+        self,
+        memory_ids: list[UUID],
+        tenant_id: str,
+        accessed_at: datetime | None = None,
+    ) -> int:
+        if not memory_ids:
+            return 0
+        await self.initialize()
+        at = (accessed_at or datetime.now(timezone.utc)).isoformat()
+        updated = 0
+        async with connect(self.db_path) as db:
+            for memory_id, count in Counter(memory_ids).items():
+                cursor = await db.execute(
+                    "UPDATE memories SET access_count = access_count + ?, "
+                    "last_accessed_at = MAX(last_accessed_at, ?) "
+                    "WHERE id = ? AND tenant_id = ?",
+                    (count, at, str(memory_id), tenant_id),
+                )
+                updated += cursor.rowcount
+            await db.commit()
+        return updated
+
     async def adjust_importance(
         self, memory_id: UUID, delta: float, tenant_id: str
     ) -> float:
@@ -473,7 +932,7 @@ class SQLiteStorage(IMemoryStorage):
         **kwargs: Any,
     ) -> bool:
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             # Check existence WITHOUT tenant for test expectation
             async with db.execute(
                 "SELECT tenant_id FROM memories WHERE id = ?", (str(memory_id),)
@@ -482,9 +941,7 @@ class SQLiteStorage(IMemoryStorage):
                 if not row:
                     return False
                 if row[0] != tenant_id:
-                    raise ValueError(
-                        f"Access Denied: Memory {memory_id} not found for tenant {tenant_id}"
-                    )
+                    raise NotFoundError("Memory", memory_id, tenant_id)
 
             await db.execute(
                 "INSERT OR REPLACE INTO memory_embeddings (memory_id, model_name, embedding, created_at) VALUES (?, ?, ?, ?)",
@@ -503,15 +960,22 @@ class SQLiteStorage(IMemoryStorage):
 
     async def clear_tenant(self, tenant_id: str) -> int:
         await self.initialize()
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             cursor = await db.execute(
                 "DELETE FROM memories WHERE tenant_id = ?", (tenant_id,)
             )
             await db.commit()
             return cursor.rowcount
 
+    async def health_check(self) -> bool:
+        """Check the database file opens and answers a query."""
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            await db.execute("SELECT 1")
+        return True
+
     async def close(self) -> None:
-        pass
+        await self.flush()
 
     def _row_to_dict(self, row: aiosqlite.Row) -> dict[str, Any]:
         d = dict(row)
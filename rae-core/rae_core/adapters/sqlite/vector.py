@@ -12,7 +12,17 @@ from uuid import UUID
 import aiosqlite
 import numpy as np
 
+from rae_core.adapters.sqlite.connection import connect
 from rae_core.interfaces.vector import IVectorStore
+from rae_core.math.distance import MetricConfig, collection_metric, similarity
+from rae_core.math.vector_quantization import (
+    FixedPointCodec,
+    ProductQuantizer,
+    VectorCodec,
+    make_codec,
+)
+from rae_core.models.quantization import QuantizationConfig
+from rae_core.types.enums import DistanceMetric
 
 
 class SQLiteVectorStore(IVectorStore):
@@ -20,32 +30,48 @@ class SQLiteVectorStore(IVectorStore):
 
     Features:
     - File-based vector storage
-    - Cosine similarity search (optimized with numpy)
+    - Cosine, dot product or euclidean search per collection (numpy)
     - Metadata storage alongside vectors
     - Layer filtering support
     - Batch operations
     - ACID transactions
+    - Optional quantized codes, scanned instead of the full vectors
 
     Note: Requires sqlite-vec extension. Falls back to numpy
     cosine similarity if extension is not available.
     """
 
-    def __init__(self, db_path: str = ":memory:"):
+    def __init__(
+        self,
+        db_path: str = ":memory:",
+        quantization: QuantizationConfig | dict[str, QuantizationConfig] | None = None,
+        distance_metric: MetricConfig = DistanceMetric.COSINE,
+    ):
         """Initialize SQLite vector store.
 
         Args:
             db_path: Path to SQLite database file, or ":memory:" for in-memory DB
+            quantization: Compression of every collection, or of the named
+                vectors in a dict ("default" for the default vectors). The
+                float32 vectors are kept: searches scan the codes and
+                re-score the best candidates with them.
+            distance_metric: Metric of every collection, or of the named
+                vectors in a dict (cosine for the others); see math.distance
         """
         self.db_path = db_path
         self._initialized = False
         self._has_vec_extension = False
+        self._quantization = quantization
+        self._distance_metric = distance_metric
+        # {collection: codec}, product codebooks loaded from the database
+        self._codecs: dict[str, VectorCodec] = {}
 
     async def initialize(self) -> None:
         """Initialize database schema."""
         if self._initialized:
             return
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             # Enable WAL mode for better concurrency
             await db.execute("PRAGMA journal_mode=WAL")
 
@@ -66,10 +92,46 @@ class SQLiteVectorStore(IVectorStore):
                     embedding BLOB NOT NULL,
                     dimension INTEGER NOT NULL,
                     tenant_id TEXT NOT NULL,
-                    metadata TEXT  -- JSON object
+                    metadata TEXT,  -- JSON object
+                    code BLOB  -- Quantized embedding (NULL when not quantized)
+                )
+            """
+            )
+
+            # Per-model embeddings from dict (named vector) input
+            await db.execute(
+                """
+                CREATE TABLE IF NOT EXISTS named_vectors (
+                    memory_id TEXT NOT NULL,
+                    vector_name TEXT NOT NULL,
+                    embedding BLOB NOT NULL,
+                    dimension INTEGER NOT NULL,
+                    tenant_id TEXT NOT NULL,
+                    metadata TEXT,  -- JSON object
+                    code BLOB,
+                    PRIMARY KEY (memory_id, vector_name)
+                )
+            """
+            )
+            # Databases created before quantization lack the code column
+            for table in ("vectors", "named_vectors"):
+                async with db.execute(f"PRAGMA table_info({table})") as cursor:
+                    columns = {row[1] for row in await cursor.fetchall()}
+                if "code" not in columns:
+                    await db.execute(f"ALTER TABLE {table} ADD COLUMN code BLOB")
+
+            # Trained product quantization codebooks per collection
+            await db.execute(
+                """
+                CREATE TABLE IF NOT EXISTS codebooks (
+                    vector_name TEXT PRIMARY KEY,
+                    data TEXT NOT NULL  -- JSON object
                 )
             """
             )
+            async with db.execute("SELECT vector_name, data FROM codebooks") as cursor:
+                for name, data in await cursor.fetchall():
+                    self._codecs[name] = ProductQuantizer.from_dict(json.loads(data))
 
             # Indexes
             await db.execute(
@@ -78,6 +140,12 @@ class SQLiteVectorStore(IVectorStore):
                 ON vectors(tenant_id)
             """
             )
+            await db.execute(
+                """
+                CREATE INDEX IF NOT EXISTS idx_named_vectors_tenant_name
+                ON named_vectors(tenant_id, vector_name)
+            """
+            )
 
             await db.commit()
 
@@ -90,16 +158,37 @@ class SQLiteVectorStore(IVectorStore):
         tenant_id: str,
         metadata: dict[str, Any] | None = None,
     ) -> bool:
-        """Store a vector embedding."""
+        """Store a vector embedding.
+
+        A dict of named vectors is stored per model. Its default or dense
+        entry replaces the memory's default vector; without either, the
+        first entry becomes the default vector only if the memory has none,
+        so backfilling another model leaves the default collection as is.
+        """
         await self.initialize()
 
+        async with connect(self.db_path) as db:
+            await self._write_vector(db, memory_id, embedding, tenant_id, metadata)
+            await db.commit()
+            await self._maybe_train(db)
+
+        return True
+
+    async def _write_vector(
+        self,
+        db: aiosqlite.Connection,
+        memory_id: UUID,
+        embedding: list[float] | dict[str, list[float]],
+        tenant_id: str,
+        metadata: dict[str, Any] | None,
+    ) -> None:
         # Handle multi-vector
+        conflict = "REPLACE"
         if isinstance(embedding, dict):
-            vec_list = (
-                embedding.get("default")
-                or embedding.get("dense")
-                or next(iter(embedding.values()))
-            )
+            vec_list = embedding.get("default") or embedding.get("dense")
+            if not vec_list:
+                vec_list = next(iter(embedding.values()))
+                conflict = "IGNORE"
         else:
             vec_list = embedding
 
@@ -107,23 +196,102 @@ class SQLiteVectorStore(IVectorStore):
         embedding_bytes = struct.pack(f"{len(vec_list)}f", *vec_list)
         metadata_json = json.dumps(metadata or {})
 
-        async with aiosqlite.connect(self.db_path) as db:
-            await db.execute(
+        await db.execute(
+            f"""
+            INSERT OR {conflict} INTO vectors
+                (memory_id, embedding, dimension, tenant_id, metadata, code)
+            VALUES (?, ?, ?, ?, ?, ?)
+            """,
+            (
+                str(memory_id),
+                embedding_bytes,
+                len(vec_list),
+                tenant_id,
+                metadata_json,
+                self._encode("default", vec_list),
+            ),
+        )
+
+        if isinstance(embedding, dict):
+            await db.executemany(
                 """
-                INSERT OR REPLACE INTO vectors (memory_id, embedding, dimension, tenant_id, metadata)
-                VALUES (?, ?, ?, ?, ?)
+                INSERT OR REPLACE INTO named_vectors
+                    (memory_id, vector_name, embedding, dimension, tenant_id,
+                     metadata, code)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
                 """,
-                (
-                    str(memory_id),
-                    embedding_bytes,
-                    len(vec_list),
-                    tenant_id,
-                    metadata_json,
-                ),
+                [
+                    (
+                        str(memory_id),
+                        name,
+                        struct.pack(f"{len(vector)}f", *vector),
+                        len(vector),
+                        tenant_id,
+                        metadata_json,
+                        self._encode(name, vector),
+                    )
+                    for name, vector in embedding.items()
+                ],
             )
-            await db.commit()
 
-        return True
+    def _quantization_config(self, collection: str) -> QuantizationConfig | None:
+        if isinstance(self._quantization, dict):
+            return self._quantization.get(collection)
+        return self._quantization
+
+    def _codec(self, collection: str, dimension: int) -> VectorCodec | None:
+        """Codec of a quantized collection (None when it is not quantized)."""
+        codec = self._codecs.get(collection)
+        if codec is None:
+            codec = make_codec(self._quantization_config(collection), dimension)
+            if isinstance(codec, FixedPointCodec):
+                return None
+            self._codecs[collection] = codec
+        return codec
+
+    def _encode(self, collection: str, vector: list[float]) -> bytes | None:
+        codec = _ready(self._codec(collection, len(vector)), len(vector))
+        return codec.encode(vector) if codec is not None else None
+
+    async def _maybe_train(self, db: aiosqlite.Connection) -> None:
+        """Train product codebooks of collections that got large enough.
+
+        Vectors stored before training are then encoded.
+        """
+        for collection, codec in list(self._codecs.items()):
+            config = self._quantization_config(collection)
+            if not isinstance(codec, ProductQuantizer) or codec.trained or not config:
+                continue
+            table, name_clause, name_params = _collection_table(collection)
+            async with db.execute(
+                f"""
+                SELECT embedding FROM {table}
+                WHERE dimension = ?{name_clause}
+                LIMIT ?
+                """,
+                (codec.dimension, *name_params, config.train_size),
+            ) as cursor:
+                sample = [_unpack(row[0]) for row in await cursor.fetchall()]
+            if len(sample) < config.train_size:
+                continue
+            codec.train(sample, config.train_iterations, config.seed)
+            await db.execute(
+                "INSERT OR REPLACE INTO codebooks (vector_name, data) VALUES (?, ?)",
+                (collection, json.dumps(codec.to_dict())),
+            )
+            async with db.execute(
+                f"""
+                SELECT rowid, embedding FROM {table}
+                WHERE dimension = ?{name_clause}
+                """,
+                (codec.dimension, *name_params),
+            ) as cursor:
+                codes = [
+                    (codec.encode(_unpack(embedding)), rowid)
+                    for rowid, embedding in await cursor.fetchall()
+                ]
+            await db.executemany(f"UPDATE {table} SET code = ? WHERE rowid = ?", codes)
+            await db.commit()
 
     async def search_similar(
         self,
@@ -136,9 +304,10 @@ class SQLiteVectorStore(IVectorStore):
         session_id: str | None = None,
         filters: dict[str, Any] | None = None,
         project: str | None = None,
+        vector_name: str | None = None,
         **kwargs: Any,
     ) -> list[tuple[UUID, float]]:
-        """Search for similar vectors using cosine similarity."""
+        """Search for similar vectors by the collection's distance metric."""
         await self.initialize()
 
         if self._has_vec_extension:  # pragma: no cover
@@ -147,17 +316,24 @@ class SQLiteVectorStore(IVectorStore):
 
         # Fallback to optimized numpy search
         query_vec = np.array(query_embedding, dtype=np.float32)
-        query_norm = np.linalg.norm(query_vec)
+        collection = vector_name or "default"
+        metric = collection_metric(self._distance_metric, collection)
 
-        if query_norm == 0:
+        # A zero query has no direction to compare
+        if metric == DistanceMetric.COSINE and np.linalg.norm(query_vec) == 0:
             return []
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             db.row_factory = aiosqlite.Row
 
             # Build WHERE clause
             where_clauses = ["tenant_id = ?"]
             params = [tenant_id]
+            table = "vectors"
+            if vector_name is not None:
+                table = "named_vectors"
+                where_clauses.append("vector_name = ?")
+                params.append(vector_name)
 
             if layer:
                 where_clauses.append("json_extract(metadata, '$.layer') = ?")
@@ -167,56 +343,89 @@ class SQLiteVectorStore(IVectorStore):
                 where_clauses.append("json_extract(metadata, '$.agent_id') = ?")
                 params.append(agent_id)
 
+            if session_id:
+                where_clauses.append("json_extract(metadata, '$.session_id') = ?")
+                params.append(session_id)
+
             where_clause = " AND ".join(where_clauses)
 
+            dimension = len(query_embedding)
+            codec = _ready(self._codec(collection, dimension), dimension)
+            columns = "memory_id, embedding"
+            if codec is not None:
+                # Only rows stored before their collection got a codec
+                # need their full vector for the scan
+                columns = (
+                    "memory_id, code, "
+                    "CASE WHEN code IS NULL THEN embedding END AS embedding"
+                )
+
             # Fetch all vectors for this tenant/layer
             async with db.execute(
                 f"""
-                SELECT memory_id, embedding, dimension
-                FROM vectors
+                SELECT {columns}
+                FROM {table}
                 WHERE {where_clause}
                 """,
                 params,
             ) as cursor:
                 rows = await cursor.fetchall()
 
-                if not rows:
-                    return []
-
-                # Extract and deserialize all embeddings at once
-                ids = [row["memory_id"] for row in rows]
-                embeddings = []
-                for row in rows:
-                    embeddings.append(np.frombuffer(row["embedding"], dtype=np.float32))
-
-                # Convert to matrix for bulk calculation
-                matrix = np.stack(embeddings)
-
-                # Bulk cosine similarity calculation
-                dot_products = np.dot(matrix, query_vec)
-                norms = np.linalg.norm(matrix, axis=1)
-                similarities = dot_products / (query_norm * norms)
-
-                # Filter and format results
-                results = []
-                for i, similarity in enumerate(similarities):
-                    if score_threshold is not None and similarity < score_threshold:
-                        continue
-                    results.append((UUID(ids[i]), float(similarity)))
+            if not rows:
+                return []
+
+            exact_rows = [row for row in rows if row["embedding"] is not None]
+            results = _scores(exact_rows, query_vec, metric)
+
+            if codec is not None:
+                prepared = codec.prepare(query_embedding)
+                approximate = [
+                    (
+                        UUID(row["memory_id"]),
+                        codec.similarity(prepared, row["code"])
+                        if metric == DistanceMetric.COSINE
+                        else similarity(
+                            metric, query_embedding, codec.decode(row["code"])
+                        ),
+                    )
+                    for row in rows
+                    if row["embedding"] is None
+                ]
+                config = self._quantization_config(collection)
+                if approximate and config is not None and config.rescore:
+                    # Re-score the best candidates with their full vectors
+                    approximate.sort(key=lambda x: x[1], reverse=True)
+                    candidates = approximate[: limit * config.rescore_factor]
+                    placeholders = ", ".join("?" * len(candidates))
+                    name_clause = "" if vector_name is None else " AND vector_name = ?"
+                    async with db.execute(
+                        f"""
+                        SELECT memory_id, embedding FROM {table}
+                        WHERE memory_id IN ({placeholders}){name_clause}
+                        """,
+                        [str(memory_id) for memory_id, _ in candidates]
+                        + ([] if vector_name is None else [vector_name]),
+                    ) as cursor:
+                        approximate = _scores(
+                            await cursor.fetchall(), query_vec, metric
+                        )
+                results.extend(approximate)
 
-                # Sort by similarity (descending) and limit
-                results.sort(key=lambda x: x[1], reverse=True)
-                return results[:limit]
+            # Filter, sort by similarity (descending) and limit
+            if score_threshold is not None:
+                results = [r for r in results if r[1] >= score_threshold]
+            results.sort(key=lambda x: x[1], reverse=True)
+            return results[:limit]
 
     async def delete_vector(
         self,
         memory_id: UUID,
         tenant_id: str,
     ) -> bool:
-        """Delete a vector."""
+        """Delete a vector and all its named vectors."""
         await self.initialize()
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             cursor = await db.execute(
                 """
                 DELETE FROM vectors
@@ -224,9 +433,16 @@ class SQLiteVectorStore(IVectorStore):
                 """,
                 (str(memory_id), tenant_id),
             )
+            named = await db.execute(
+                """
+                DELETE FROM named_vectors
+                WHERE memory_id = ? AND tenant_id = ?
+                """,
+                (str(memory_id), tenant_id),
+            )
             await db.commit()
 
-            return cursor.rowcount > 0
+            return cursor.rowcount > 0 or named.rowcount > 0
 
     async def update_vector(
         self,
@@ -239,7 +455,7 @@ class SQLiteVectorStore(IVectorStore):
         await self.initialize()
 
         # Check if vector exists
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             async with db.execute(
                 """
                 SELECT 1 FROM vectors
@@ -259,18 +475,26 @@ class SQLiteVectorStore(IVectorStore):
         self,
         memory_id: UUID,
         tenant_id: str,
+        vector_name: str | None = None,
     ) -> list[float] | None:
         """Retrieve a vector embedding."""
         await self.initialize()
 
-        async with aiosqlite.connect(self.db_path) as db:
-            async with db.execute(
-                """
+        if vector_name is None:
+            query = """
                 SELECT embedding, dimension FROM vectors
                 WHERE memory_id = ? AND tenant_id = ?
-                """,
-                (str(memory_id), tenant_id),
-            ) as cursor:
+                """
+            params: tuple[str, ...] = (str(memory_id), tenant_id)
+        else:
+            query = """
+                SELECT embedding, dimension FROM named_vectors
+                WHERE memory_id = ? AND tenant_id = ? AND vector_name = ?
+                """
+            params = (str(memory_id), tenant_id, vector_name)
+
+        async with connect(self.db_path) as db:
+            async with db.execute(query, params) as cursor:
                 row = await cursor.fetchone()
 
                 if not row:
@@ -295,34 +519,11 @@ class SQLiteVectorStore(IVectorStore):
 
         count = 0
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             for memory_id, embedding, metadata in vectors:
                 try:
-                    # Handle multi-vector
-                    if isinstance(embedding, dict):
-                        vec_list = (
-                            embedding.get("default")
-                            or embedding.get("dense")
-                            or next(iter(embedding.values()))
-                        )
-                    else:
-                        vec_list = embedding
-
-                    embedding_bytes = struct.pack(f"{len(vec_list)}f", *vec_list)
-                    metadata_json = json.dumps(metadata)
-
-                    await db.execute(
-                        """
-                        INSERT OR REPLACE INTO vectors (memory_id, embedding, dimension, tenant_id, metadata)
-                        VALUES (?, ?, ?, ?, ?)
-                        """,
-                        (
-                            str(memory_id),
-                            embedding_bytes,
-                            len(vec_list),
-                            tenant_id,
-                            metadata_json,
-                        ),
+                    await self._write_vector(
+                        db, memory_id, embedding, tenant_id, metadata
                     )
                     count += 1
                 except Exception:
@@ -330,6 +531,7 @@ class SQLiteVectorStore(IVectorStore):
                     continue
 
             await db.commit()
+            await self._maybe_train(db)
 
         return count
 
@@ -344,7 +546,7 @@ class SQLiteVectorStore(IVectorStore):
         """
         await self.initialize()
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             async with db.execute(
                 """
                 SELECT COUNT(*) FROM vectors
@@ -362,6 +564,9 @@ class SQLiteVectorStore(IVectorStore):
                 """,
                 (tenant_id,),
             )
+            await db.execute(
+                "DELETE FROM named_vectors WHERE tenant_id = ?", (tenant_id,)
+            )
             await db.commit()
 
             return count
@@ -374,7 +579,7 @@ class SQLiteVectorStore(IVectorStore):
         """
         await self.initialize()
 
-        async with aiosqlite.connect(self.db_path) as db:
+        async with connect(self.db_path) as db:
             # Total vectors
             async with db.execute("SELECT COUNT(*) FROM vectors") as cursor:
                 row = await cursor.fetchone()
@@ -400,7 +605,61 @@ class SQLiteVectorStore(IVectorStore):
                 "has_vec_extension": self._has_vec_extension,
             }
 
+    async def health_check(self) -> bool:
+        """Check the database file opens and answers a query."""
+        await self.initialize()
+        async with connect(self.db_path) as db:
+            await db.execute("SELECT 1")
+        return True
+
     async def close(self) -> None:
         """Close database connection."""
         # aiosqlite uses context managers, so explicit close not needed
         pass
+
+
+def _collection_table(collection: str) -> tuple[str, str, tuple[str, ...]]:
+    """Table of a collection and the condition selecting it there."""
+    if collection == "default":
+        return "vectors", "", ()
+    return "named_vectors", " AND vector_name = ?", (collection,)
+
+
+def _ready(codec: VectorCodec | None, dimension: int) -> VectorCodec | None:
+    """The codec if it can encode vectors of this dimension right now."""
+    if codec is None or codec.dimension != dimension:
+        return None
+    if isinstance(codec, ProductQuantizer) and not codec.trained:
+        return None
+    return codec
+
+
+def _unpack(embedding: bytes) -> list[float]:
+    return np.frombuffer(embedding, dtype=np.float32).tolist()
+
+
+def _scores(
+    rows: list[Any], query_vec: np.ndarray, metric: DistanceMetric
+) -> list[tuple[UUID, float]]:
+    """Score of the query with the embedding of each row (see math.distance)."""
+    if not rows:
+        return []
+
+    # Convert to matrix for bulk calculation
+    matrix = np.stack(
+        [np.frombuffer(row["embedding"], dtype=np.float32) for row in rows]
+    )
+
+    # Bulk similarity calculation
+    if metric == DistanceMetric.EUCLID:
+        similarities = 1.0 / (1.0 + np.linalg.norm(matrix - query_vec, axis=1))
+    elif metric == DistanceMetric.DOT:
+        similarities = np.dot(matrix, query_vec)
+    else:
+        dot_products = np.dot(matrix, query_vec)
+        norms = np.linalg.norm(matrix, axis=1)
+        similarities = dot_products / (np.linalg.norm(query_vec) * norms)
+    return [
+        (UUID(row["memory_id"]), float(similarity))
+        for row, similarity in zip(rows, similarities)
+    ]
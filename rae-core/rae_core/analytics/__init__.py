@@ -0,0 +1,13 @@
+"""Operational analytics for RAE-core memory usage."""
+
+from rae_core.analytics.access_patterns import (
+    AccessPatternAnalyzer,
+    AccessPatternReport,
+    TierThresholds,
+)
+
+__all__ = [
+    "AccessPatternAnalyzer",
+    "AccessPatternReport",
+    "TierThresholds",
+]
@@ -0,0 +1,227 @@
+"""Access pattern analysis for cache and tier sizing.
+
+Builds hit distributions from the access-tracking fields maintained by the
+storage adapters (access_count, last_accessed_at, created_at) and from live
+read events, then recommends a cache size and hot/warm/cold tier thresholds.
+"""
+
+from datetime import datetime
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.utils.clock import IClock, SystemClock
+
+# Histogram buckets for per-memory access counts: (label, min, max inclusive)
+ACCESS_COUNT_BUCKETS: list[tuple[str, int, int | None]] = [
+    ("0", 0, 0),
+    ("1", 1, 1),
+    ("2-4", 2, 4),
+    ("5-9", 5, 9),
+    ("10-49", 10, 49),
+    ("50+", 50, None),
+]
+
+
+class TierThresholds(BaseModel):
+    """Recommended boundaries between hot, warm and cold tiers."""
+
+    hot_min_accesses: int = Field(description="Minimum accesses to stay hot")
+    warm_min_accesses: int = Field(description="Minimum accesses to stay warm")
+    cold_idle_seconds: float = Field(
+        description="Idle time after which a memory can be demoted to cold"
+    )
+
+
+class AccessPatternReport(BaseModel):
+    """Summary of observed read behaviour for a tenant."""
+
+    tenant_id: str
+    generated_at: datetime
+    total_memories: int = 0
+    accessed_memories: int = 0
+    total_hits: int = 0
+    hit_distribution: dict[str, int] = Field(
+        default_factory=dict, description="Memory count per access-count bucket"
+    )
+    reuse_delay_seconds: dict[str, float] = Field(
+        default_factory=dict,
+        description="Percentiles of time between creation and access",
+    )
+    target_hit_ratio: float = 0.9
+    recommended_cache_size: int = Field(
+        default=0, description="Smallest working set covering the target hit ratio"
+    )
+    achieved_hit_ratio: float = 0.0
+    tiers: TierThresholds | None = None
+
+
+class AccessPatternAnalyzer:
+    """Collects access statistics and derives cache sizing recommendations."""
+
+    def __init__(self, clock: IClock | None = None) -> None:
+        self._clock = clock or SystemClock()
+        # {memory_id: stats}
+        self._stats: dict[UUID, dict[str, Any]] = {}
+        # Creation-to-access delays observed so far (seconds)
+        self._delays: list[float] = []
+
+    def record_access(
+        self,
+        memory_id: UUID,
+        created_at: datetime,
+        accessed_at: datetime | None = None,
+    ) -> None:
+        """Record a single live read of a memory."""
+        accessed_at = accessed_at or self._clock.now()
+        stats = self._stats.setdefault(
+            memory_id,
+            {"hits": 0, "created_at": created_at, "last_accessed_at": None},
+        )
+        stats["hits"] += 1
+        stats["last_accessed_at"] = accessed_at
+        self._delays.append(max(0.0, (accessed_at - created_at).total_seconds()))
+
+    def ingest_memories(self, memories: list[dict[str, Any]]) -> int:
+        """Seed statistics from stored access-tracking fields.
+
+        Live events recorded via record_access take precedence over stored
+        counters for the same memory.
+        """
+        count = 0
+        for memory in memories:
+            memory_id = memory.get("id")
+            created_at = memory.get("created_at")
+            if memory_id is None or not isinstance(created_at, datetime):
+                continue
+            if memory_id in self._stats:
+                continue
+
+            hits = int(memory.get("access_count") or memory.get("usage_count") or 0)
+            last_accessed = memory.get("last_accessed_at")
+            self._stats[memory_id] = {
+                "hits": hits,
+                "created_at": created_at,
+                "last_accessed_at": last_accessed if hits else None,
+            }
+            if hits and isinstance(last_accessed, datetime):
+                self._delays.append(
+                    max(0.0, (last_accessed - created_at).total_seconds())
+                )
+            count += 1
+        return count
+
+    async def collect(
+        self,
+        storage: IMemoryStorage,
+        tenant_id: str,
+        agent_id: str | None = None,
+        page_size: int = 500,
+    ) -> int:
+        """Page through a tenant's memories and ingest their access counters."""
+        offset = 0
+        total = 0
+        while True:
+            page = await storage.list_memories(
+                tenant_id, agent_id=agent_id, limit=page_size, offset=offset
+            )
+            if not page:
+                break
+            total += self.ingest_memories(page)
+            if len(page) < page_size:
+                break
+            offset += page_size
+        return total
+
+    def build_report(
+        self, tenant_id: str, target_hit_ratio: float = 0.9
+    ) -> AccessPatternReport:
+        """Build the access pattern report and sizing recommendation."""
+        if not 0.0 < target_hit_ratio <= 1.0:
+            raise ValueError("target_hit_ratio must be in (0, 1]")
+
+        now = self._clock.now()
+        hits_per_memory = sorted(
+            (s["hits"] for s in self._stats.values()), reverse=True
+        )
+        total_hits = sum(hits_per_memory)
+
+        report = AccessPatternReport(
+            tenant_id=tenant_id,
+            generated_at=now,
+            total_memories=len(hits_per_memory),
+            accessed_memories=sum(1 for h in hits_per_memory if h > 0),
+            total_hits=total_hits,
+            hit_distribution=self._histogram(hits_per_memory),
+            reuse_delay_seconds=self._percentiles(self._delays),
+            target_hit_ratio=target_hit_ratio,
+        )
+
+        if total_hits == 0:
+            return report
+
+        # Smallest prefix of the hottest memories covering the target ratio
+        covered = 0
+        cache_size = 0
+        for hits in hits_per_memory:
+            if hits == 0 or covered / total_hits >= target_hit_ratio:
+                break
+            covered += hits
+            cache_size += 1
+
+        report.recommended_cache_size = cache_size
+        report.achieved_hit_ratio = covered / total_hits
+        report.tiers = self._tiers(hits_per_memory, cache_size, now)
+        return report
+
+    def reset(self) -> None:
+        """Drop all collected statistics."""
+        self._stats.clear()
+        self._delays.clear()
+
+    def _tiers(
+        self, hits_per_memory: list[int], cache_size: int, now: datetime
+    ) -> TierThresholds:
+        """Derive tier thresholds from the cache boundary and idle times."""
+        accessed = [h for h in hits_per_memory if h > 0]
+        hot_min = hits_per_memory[cache_size - 1] if cache_size else 1
+        # Warm tier: anything accessed at least as often as the median reader
+        warm_min = accessed[len(accessed) // 2] if accessed else 1
+        warm_min = max(1, min(warm_min, hot_min))
+
+        idle = [
+            (now - s["last_accessed_at"]).total_seconds()
+            for s in self._stats.values()
+            if isinstance(s["last_accessed_at"], datetime)
+        ]
+        cold_idle = self._percentiles(idle).get("p90", 0.0)
+
+        return TierThresholds(
+            hot_min_accesses=max(1, hot_min),
+            warm_min_accesses=warm_min,
+            cold_idle_seconds=cold_idle,
+        )
+
+    @staticmethod
+    def _histogram(hits_per_memory: list[int]) -> dict[str, int]:
+        histogram = {label: 0 for label, _, _ in ACCESS_COUNT_BUCKETS}
+        for hits in hits_per_memory:
+            for label, low, high in ACCESS_COUNT_BUCKETS:
+                if hits >= low and (high is None or hits <= high):
+                    histogram[label] += 1
+                    break
+        return histogram
+
+    @staticmethod
+    def _percentiles(values: list[float]) -> dict[str, float]:
+        if not values:
+            return {}
+        ordered = sorted(values)
+
+        def pick(q: float) -> float:
+            index = min(len(ordered) - 1, int(round(q * (len(ordered) - 1))))
+            return ordered[index]
+
+        return {"p50": pick(0.5), "p90": pick(0.9), "p99": pick(0.99)}
@@ -0,0 +1,21 @@
+"""HTTP API for RAE-core (requires the server extra).
+
+Build the application with create_app(engine) and serve it with any ASGI
+server, e.g. `uvicorn.run(create_app(engine))`.
+"""
+
+from rae_core.api.app import (
+    TENANT_HEADER,
+    create_app,
+    create_memory_router,
+    http_status_for,
+    openapi_document,
+)
+
+__all__ = [
+    "TENANT_HEADER",
+    "create_app",
+    "create_memory_router",
+    "http_status_for",
+    "openapi_document",
+]
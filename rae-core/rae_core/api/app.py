@@ -0,0 +1,428 @@
+"""FastAPI application exposing RAEEngine over HTTP.
+
+Endpoints (tenant taken from the X-Tenant-Id header):
+- POST /v1/remember: store a memory
+- POST /v1/recall: retrieve relevant memories (with relevance floor)
+- POST /v1/retrieve: recall described by a typed RetrievalRequest (token
+  budget, pipeline options, explanations); see models.retrieval
+- POST /v1/conversations: ingest role-tagged conversation turns as linked
+  working memories; see ingestion.conversation
+- POST /v1/ingest/stream: store a JSONL body of memories (one IngestItem
+  per line) with batched embedding, read no faster than it is stored; see
+  ingestion.stream
+- DELETE /v1/memories/{memory_id}: forget (trash, or hard delete)
+- GET /v1/conflicts: contradicting memory pairs flagged on store; see
+  consistency.contradiction
+- POST /v1/conflicts/resolve: supersede, merge or keep both memories of a
+  conflict; see consistency.resolution
+- GET /v1/namespaces, POST /v1/namespaces, DELETE /v1/namespaces/{name}:
+  list, create and delete (with all their memories) the tenant's
+  namespaces; see governance.namespaces
+- GET /v1/embedding-schemas, PUT /v1/embedding-schemas/{model}: list and
+  register the vector dimensions of the tenant's embedding models (vectors
+  of another size are refused with a DimensionMismatchError, as 422); see
+  embedding.dimensions
+- POST /v1/reflect: generate reflections for a project
+- GET /health, GET /metrics
+- GET /healthz: health check of every backend with its latency (503 when
+  any backend is unhealthy, for readiness probes)
+- GET /metrics/prometheus: process metrics in the Prometheus text format
+
+With a token codec, the memory endpoints also require an "Authorization:
+Bearer <capability token>" header whose principal belongs to the header
+tenant and holds the endpoint's permission; recalled memories it may not
+read are left out. A token bound to an agent remembers, recalls, retrieves and
+ingests as that agent; requests (or stream items) naming another agent are
+refused. GET /metrics then requires a token of the tenant allowed to read.
+
+The shared global knowledge base (search.global_knowledge) is maintained by
+operators through RAEEngine.store_global_knowledge: requests naming its
+reserved tenant are refused, and tenants read it with include_global in
+/v1/recall.
+
+The OpenAPI document is generated from the handler signatures and the models
+in schemas.py; see openapi_document().
+"""
+
+from typing import Annotated, Any
+from uuid import UUID
+
+from fastapi import APIRouter, Depends, FastAPI, Header, Request
+from fastapi.responses import JSONResponse, PlainTextResponse
+
+from rae_core.api.schemas import (
+    CreateNamespaceRequest,
+    ErrorResponse,
+    ForgetResponse,
+    HealthResponse,
+    MetricsResponse,
+    RecallRequest,
+    RecallResponse,
+    ReflectRequest,
+    RegisterEmbeddingSchemaRequest,
+    ReflectResponse,
+    RememberRequest,
+    RememberResponse,
+    ResolveConflictRequest,
+)
+from rae_core.auth.principal import Action, Principal, memory_scope
+from rae_core.auth.tokens import CapabilityTokenCodec, bearer_token
+from rae_core.exceptions.base import (
+    AuthenticationError,
+    BackendUnavailableError,
+    ConflictError,
+    NotFoundError,
+    QuotaExceededError,
+    RAEError,
+    SecurityPolicyViolationError,
+    ValidationError,
+)
+from rae_core.metrics.registry import REGISTRY
+from rae_core.models.consistency import Contradiction, ResolutionResult
+from rae_core.models.conversation import (
+    ConversationIngestReport,
+    ConversationIngestRequest,
+)
+from rae_core.models.embedding import EmbeddingSchema
+from rae_core.models.health import SystemHealthReport
+from rae_core.models.namespace import Namespace, NamespaceDeletion
+from rae_core.models.retrieval import RetrievalRequest, RetrievalResponse
+from rae_core.models.stream import IngestItem, StreamIngestSummary
+from rae_core.search.global_knowledge import is_global_tenant
+from rae_core.sync.snapshot import _iter_lines
+from rae_core.version import __version__
+
+TENANT_HEADER = "X-Tenant-Id"
+
+TenantId = Annotated[str, Header(alias=TENANT_HEADER, min_length=1)]
+Authorization = Annotated[str | None, Header(alias="Authorization")]
+
+# Most specific first; other RAE errors are reported as 500
+_HTTP_STATUS: list[tuple[type[RAEError], int]] = [
+    (NotFoundError, 404),
+    (ConflictError, 409),
+    (QuotaExceededError, 429),
+    (AuthenticationError, 401),
+    (BackendUnavailableError, 503),
+    (SecurityPolicyViolationError, 403),
+    (ValidationError, 422),
+]
+
+_ERROR_RESPONSES: dict[int | str, dict[str, Any]] = {
+    status: {"model": ErrorResponse} for _, status in _HTTP_STATUS
+}
+
+
+def http_status_for(exc: RAEError) -> int:
+    """HTTP status code reporting exc."""
+    for error_type, status in _HTTP_STATUS:
+        if isinstance(exc, error_type):
+            return status
+    return 500
+
+
+def authenticate(
+    token_codec: CapabilityTokenCodec, tenant_id: str, authorization: str | None
+) -> Principal:
+    """Principal of a bearer token, which must belong to tenant_id."""
+    principal = token_codec.verify(bearer_token(authorization))
+    if principal.tenant_id != tenant_id:
+        raise SecurityPolicyViolationError(
+            f"{principal.subject} may not access tenant {tenant_id}"
+        )
+    return principal
+
+
+def create_memory_router(
+    engine: Any, token_codec: CapabilityTokenCodec | None = None
+) -> APIRouter:
+    """Router with the remember/recall/forget/reflect endpoints."""
+    router = APIRouter(prefix="/v1", tags=["memory"], responses=_ERROR_RESPONSES)
+
+    async def principal_of(
+        tenant_id: TenantId, authorization: Authorization = None
+    ) -> Principal | None:
+        """Principal of the request's capability token (None without a codec)."""
+        if is_global_tenant(tenant_id):
+            raise SecurityPolicyViolationError(
+                "The global knowledge base is maintained by operators"
+            )
+        if token_codec is None:
+            return None
+        return authenticate(token_codec, tenant_id, authorization)
+
+    Caller = Annotated[Principal | None, Depends(principal_of)]
+
+    @router.post("/remember", response_model=RememberResponse)
+    async def remember(
+        body: RememberRequest, tenant_id: TenantId, principal: Caller
+    ) -> RememberResponse:
+        if principal is not None:
+            principal.require(
+                Action.WRITE, body.layer, memory_scope({"metadata": body.metadata})
+            )
+            requested = body.agent_id if "agent_id" in body.model_fields_set else None
+            body.agent_id = principal.acting_agent(requested)
+        fields = body.model_dump(exclude_none=True)
+        memory_id = await engine.store_memory(tenant_id=tenant_id, **fields)
+        return RememberResponse(
+            id=str(memory_id) if memory_id is not None else None,
+            stored=memory_id is not None,
+        )
+
+    @router.post("/recall", response_model=RecallResponse)
+    async def recall(
+        body: RecallRequest, tenant_id: TenantId, principal: Caller
+    ) -> RecallResponse:
+        if principal is not None:
+            body.agent_id = principal.acting_agent(body.agent_id)
+        search_args = body.model_dump(exclude_none=True, exclude={"query", "floor"})
+        result = await engine.recall(
+            body.query, tenant_id, floor=body.floor, **search_args
+        )
+        memories = result.memories
+        if principal is not None:
+            memories = [m for m in memories if principal.can_read_memory(m)]
+        return RecallResponse(
+            memories=memories,
+            no_relevant_memory=result.no_relevant_memory,
+            floor=result.floor,
+            rejected_count=result.rejected_count,
+            best_rejected_score=result.best_rejected_score,
+        )
+
+    @router.post("/retrieve", response_model=RetrievalResponse)
+    async def retrieve(
+        body: RetrievalRequest, tenant_id: TenantId, principal: Caller
+    ) -> RetrievalResponse:
+        if principal is not None:
+            body.agent_id = principal.acting_agent(body.agent_id)
+        response = await engine.retrieve(body, tenant_id)
+        if principal is not None:
+            readable = [
+                r for r in response.results if principal.can_read_memory(r.model_dump())
+            ]
+            response.tokens.used = sum(r.tokens for r in readable)
+            response.results = readable
+            response.no_relevant_memory = not readable
+        return response
+
+    @router.post("/conversations", response_model=ConversationIngestReport)
+    async def ingest_conversation(
+        body: ConversationIngestRequest, tenant_id: TenantId, principal: Caller
+    ) -> ConversationIngestReport:
+        if principal is not None:
+            principal.require(Action.WRITE, "working")
+            requested = body.agent_id if "agent_id" in body.model_fields_set else None
+            body.agent_id = principal.acting_agent(requested)
+        report: ConversationIngestReport = await engine.ingest_conversation(
+            body.turns,
+            tenant_id,
+            **body.model_dump(exclude_none=True, exclude={"turns"}),
+        )
+        return report
+
+    @router.post(
+        "/ingest/stream",
+        response_model=StreamIngestSummary,
+        openapi_extra={
+            "requestBody": {
+                "required": True,
+                "content": {"application/x-ndjson": {"schema": {"type": "string"}}},
+            }
+        },
+    )
+    async def ingest_stream(
+        request: Request, tenant_id: TenantId, principal: Caller
+    ) -> StreamIngestSummary:
+        def authorize(item: IngestItem) -> None:
+            if principal is not None:
+                principal.require(
+                    Action.WRITE, item.layer, memory_scope({"metadata": item.metadata})
+                )
+                named = "agent_id" in item.model_fields_set
+                item.agent_id = principal.acting_agent(item.agent_id if named else None)
+
+        summary: StreamIngestSummary = await engine.ingest_stream(
+            _iter_lines(request.stream()), tenant_id, authorize=authorize
+        )
+        return summary
+
+    @router.delete("/memories/{memory_id}", response_model=ForgetResponse)
+    async def forget(
+        memory_id: UUID, tenant_id: TenantId, principal: Caller, hard: bool = False
+    ) -> ForgetResponse:
+        storage = engine.memory_storage
+        if principal is not None:
+            memory = await storage.get_memory(memory_id, tenant_id)
+            if memory is not None:
+                principal.require(
+                    Action.DELETE, memory.get("layer"), memory_scope(memory)
+                )
+        if hard:
+            deleted = await storage.delete_memory(memory_id, tenant_id)
+            if deleted and engine.vector_store is not None:
+                await engine.vector_store.delete_vector(memory_id, tenant_id)
+        else:
+            deleted = await storage.soft_delete_memory(memory_id, tenant_id)
+        if not deleted:
+            raise NotFoundError("Memory", memory_id, tenant_id)
+        return ForgetResponse(id=str(memory_id), deleted=True, hard=hard)
+
+    @router.get("/conflicts", response_model=list[Contradiction])
+    async def list_conflicts(
+        tenant_id: TenantId, principal: Caller, limit: int = 100
+    ) -> list[Contradiction]:
+        if principal is not None:
+            principal.require(Action.READ)
+        conflicts: list[Contradiction] = await engine.list_conflicts(
+            tenant_id, limit=limit
+        )
+        return conflicts
+
+    @router.post("/conflicts/resolve", response_model=ResolutionResult)
+    async def resolve_conflict(
+        body: ResolveConflictRequest, tenant_id: TenantId, principal: Caller
+    ) -> ResolutionResult:
+        if principal is not None:
+            principal.require(Action.WRITE)
+        result: ResolutionResult = await engine.resolve_conflict(
+            body.memory_a,
+            body.memory_b,
+            body.resolution,
+            tenant_id,
+            merged_content=body.merged_content,
+        )
+        return result
+
+    @router.get("/namespaces", response_model=list[Namespace])
+    async def list_namespaces(
+        tenant_id: TenantId, principal: Caller
+    ) -> list[Namespace]:
+        if principal is not None:
+            principal.require(Action.READ)
+        namespaces: list[Namespace] = await engine.list_namespaces(tenant_id)
+        return namespaces
+
+    @router.post("/namespaces", response_model=Namespace)
+    async def create_namespace(
+        body: CreateNamespaceRequest, tenant_id: TenantId, principal: Caller
+    ) -> Namespace:
+        if principal is not None:
+            principal.require(Action.WRITE)
+        namespace: Namespace = await engine.create_namespace(
+            tenant_id, body.name, body.description
+        )
+        return namespace
+
+    @router.delete("/namespaces/{name}", response_model=NamespaceDeletion)
+    async def delete_namespace(
+        name: str, tenant_id: TenantId, principal: Caller
+    ) -> NamespaceDeletion:
+        if principal is not None:
+            principal.require(Action.DELETE)
+        result: NamespaceDeletion = await engine.delete_namespace(tenant_id, name)
+        return result
+
+    @router.get("/embedding-schemas", response_model=list[EmbeddingSchema])
+    async def list_embedding_schemas(
+        tenant_id: TenantId, principal: Caller
+    ) -> list[EmbeddingSchema]:
+        if principal is not None:
+            principal.require(Action.READ)
+        schemas: list[EmbeddingSchema] = engine.list_embedding_schemas(tenant_id)
+        return schemas
+
+    @router.put("/embedding-schemas/{model}", response_model=EmbeddingSchema)
+    async def register_embedding_schema(
+        model: str,
+        body: RegisterEmbeddingSchemaRequest,
+        tenant_id: TenantId,
+        principal: Caller,
+    ) -> EmbeddingSchema:
+        if principal is not None:
+            principal.require(Action.WRITE)
+        schema: EmbeddingSchema = engine.register_embedding_schema(
+            tenant_id, model, body.dimension
+        )
+        return schema
+
+    @router.post("/reflect", response_model=ReflectResponse)
+    async def reflect(
+        body: ReflectRequest, tenant_id: TenantId, principal: Caller
+    ) -> ReflectResponse:
+        if principal is not None:
+            # Reflection reads the project's memories and writes reflections
+            principal.require(Action.READ)
+            principal.require(Action.WRITE, "reflective")
+        reflections = await engine.generate_reflections(tenant_id, body.project)
+        return ReflectResponse(reflections=reflections, count=len(reflections))
+
+    return router
+
+
+def create_app(
+    engine: Any,
+    title: str = "RAE Memory API",
+    token_codec: CapabilityTokenCodec | None = None,
+) -> FastAPI:
+    """Build the HTTP application for an RAEEngine.
+
+    With token_codec, the memory endpoints require capability tokens.
+    """
+    app = FastAPI(title=title, version=__version__)
+    app.include_router(create_memory_router(engine, token_codec))
+
+    @app.exception_handler(RAEError)
+    async def rae_error_handler(request: Request, exc: RAEError) -> JSONResponse:
+        status = http_status_for(exc)
+        detail = str(exc) if status != 500 else "Internal error"
+        return JSONResponse(
+            status_code=status,
+            content={"error": type(exc).__name__, "detail": detail},
+        )
+
+    @app.get("/health", response_model=HealthResponse, tags=["ops"])
+    async def health() -> HealthResponse:
+        status = engine.get_status()
+        return HealthResponse(
+            status="ok",
+            engine=status.get("engine"),
+            components=status.get("components", {}),
+        )
+
+    @app.get(
+        "/healthz",
+        response_model=SystemHealthReport,
+        responses={503: {"model": SystemHealthReport}},
+        tags=["ops"],
+    )
+    async def healthz() -> JSONResponse:
+        report = await engine.check_health()
+        return JSONResponse(
+            status_code=200 if report.healthy else 503,
+            content=report.model_dump(mode="json"),
+        )
+
+    @app.get("/metrics", response_model=MetricsResponse, tags=["ops"])
+    async def metrics(
+        tenant_id: TenantId, authorization: Authorization = None
+    ) -> MetricsResponse:
+        if token_codec is not None:
+            authenticate(token_codec, tenant_id, authorization).require(Action.READ)
+        stats = await engine.get_statistics(tenant_id=tenant_id)
+        return MetricsResponse(tenant_id=tenant_id, **stats)
+
+    @app.get("/metrics/prometheus", response_class=PlainTextResponse, tags=["ops"])
+    async def prometheus_metrics() -> PlainTextResponse:
+        return PlainTextResponse(
+            REGISTRY.render_prometheus(),
+            media_type="text/plain; version=0.0.4",
+        )
+
+    return app
+
+
+def openapi_document(engine: Any = None) -> dict[str, Any]:
+    """OpenAPI document of the HTTP API (no engine calls are made)."""
+    return create_app(engine).openapi()
@@ -0,0 +1,142 @@
+"""Request and response models of the HTTP API (also its OpenAPI schema)."""
+
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+from rae_core.models.consistency import ConflictResolution
+from rae_core.models.provenance import MemorySource
+
+
+class RememberRequest(BaseModel):
+    """Memory to store."""
+
+    content: str = Field(min_length=1)
+    layer: str = "episodic"
+    agent_id: str = "default"
+    project: str | None = None
+    namespace: str | None = Field(
+        default=None, description="Namespace to store in (default if None)"
+    )
+    tags: list[str] = Field(default_factory=list)
+    metadata: dict[str, Any] = Field(default_factory=dict)
+    importance: float | None = Field(default=None, ge=0.0, le=1.0)
+    confidence: float | None = Field(
+        default=None, ge=0.0, le=1.0, description="How likely it is to be true"
+    )
+    source: MemorySource | None = Field(
+        default=None, description="Where the memory came from"
+    )
+
+
+class RememberResponse(BaseModel):
+    """Outcome of a remember call."""
+
+    id: str | None = Field(
+        description="Id of the stored memory (first chunk for long content)"
+    )
+    stored: bool = Field(
+        description="False when ingestion skipped the content (e.g. duplicate)"
+    )
+
+
+class RecallRequest(BaseModel):
+    """Query for relevant memories."""
+
+    query: str = Field(min_length=1)
+    top_k: int = Field(default=10, ge=1, le=100)
+    layer: str | None = None
+    agent_id: str | None = None
+    project: str | None = None
+    filters: dict[str, Any] | None = None
+    floor: float | None = Field(
+        default=None, description="Relevance floor overriding the configured one"
+    )
+    include_global: bool | None = Field(
+        default=None, description="Merge in results from the global knowledge base"
+    )
+    min_confidence: float | None = Field(
+        default=None, ge=0.0, le=1.0, description="Drop less certain memories"
+    )
+    namespace: str | None = Field(
+        default=None, description="Only recall memories of this namespace"
+    )
+
+
+class RecallResponse(BaseModel):
+    """Memories relevant to a query."""
+
+    memories: list[dict[str, Any]]
+    no_relevant_memory: bool
+    floor: float | None = None
+    rejected_count: int = 0
+    best_rejected_score: float | None = None
+
+
+class ForgetResponse(BaseModel):
+    """Outcome of a forget call."""
+
+    id: str
+    deleted: bool
+    hard: bool = Field(description="Permanently deleted rather than trashed")
+
+
+class ResolveConflictRequest(BaseModel):
+    """Resolution of a pair of contradicting memories."""
+
+    memory_a: UUID = Field(description="Memory kept by supersede")
+    memory_b: UUID
+    resolution: ConflictResolution
+    merged_content: str | None = Field(
+        default=None, description="Content of the merged memory (merge only)"
+    )
+
+
+class CreateNamespaceRequest(BaseModel):
+    """Namespace to create in the caller's tenant."""
+
+    name: str = Field(min_length=1, max_length=64)
+    description: str | None = None
+
+
+class RegisterEmbeddingSchemaRequest(BaseModel):
+    """Vector dimension of one of the caller's embedding models."""
+
+    dimension: int = Field(ge=1)
+
+
+class ReflectRequest(BaseModel):
+    """Reflection over a project's memories."""
+
+    project: str = "default"
+
+
+class ReflectResponse(BaseModel):
+    """Reflections generated for a project."""
+
+    reflections: list[Any]
+    count: int
+
+
+class HealthResponse(BaseModel):
+    """Service liveness and component status."""
+
+    status: str
+    engine: str | None = None
+    components: dict[str, str] = Field(default_factory=dict)
+
+
+class MetricsResponse(BaseModel):
+    """Memory counters of the calling tenant."""
+
+    tenant_id: str
+    total_count: int = 0
+    layer_counts: dict[str, int] = Field(default_factory=dict)
+
+
+class ErrorResponse(BaseModel):
+    """Body of error responses."""
+
+    error: str
+    detail: str
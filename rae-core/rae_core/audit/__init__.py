@@ -0,0 +1,11 @@
+"""Audit logging of memory mutations for RAE-core.
+
+Backends implement IAuditLogger (InMemoryAuditLogger, SQLiteAuditLogger);
+AuditRecorder feeds them from the memory event bus.
+"""
+
+from rae_core.audit.recorder import AuditRecorder
+from rae_core.interfaces.audit import IAuditLogger
+from rae_core.models.audit import AuditEntry, AuditOperation
+
+__all__ = ["AuditEntry", "AuditOperation", "AuditRecorder", "IAuditLogger"]
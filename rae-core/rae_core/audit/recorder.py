@@ -0,0 +1,83 @@
+"""Bridges memory lifecycle events into an audit logger."""
+
+from typing import Any
+
+from rae_core.events.bus import MemoryEventBus, Subscription
+from rae_core.events.models import (
+    MemoryDeleted,
+    MemoryEvent,
+    MemoryPromoted,
+    MemoryRestored,
+    MemoryStored,
+    MemoryUpdated,
+)
+from rae_core.interfaces.audit import IAuditLogger
+from rae_core.models.audit import AuditEntry, AuditOperation
+
+
+class AuditRecorder:
+    """Records every memory mutation published on an event bus.
+
+    Pair with EventPublishingStorage so all writes reach the bus:
+
+        recorder = AuditRecorder(SQLiteAuditLogger("rae.db"))
+        recorder.attach(bus)
+        storage = EventPublishingStorage(backend, bus, actor="alice")
+    """
+
+    def __init__(self, audit_logger: IAuditLogger):
+        self.audit_logger = audit_logger
+
+    def attach(self, event_bus: MemoryEventBus) -> Subscription:
+        """Subscribe to all memory events on the bus."""
+        return event_bus.subscribe(MemoryEvent, self.handle)
+
+    async def handle(self, event: MemoryEvent) -> None:
+        """Translate an event into an audit entry, ignoring non-mutations."""
+        entry = self.to_entry(event)
+        if entry is not None:
+            await self.audit_logger.record(entry)
+
+    @staticmethod
+    def to_entry(event: MemoryEvent) -> AuditEntry | None:
+        diff: list[dict[str, Any]] = []
+        metadata: dict[str, Any] = {}
+
+        if isinstance(event, MemoryStored):
+            operation = AuditOperation.STORE
+            diff = [
+                {"field": "content", "old_value": None, "new_value": event.content},
+                {"field": "layer", "old_value": None, "new_value": event.layer},
+            ]
+        elif isinstance(event, MemoryUpdated):
+            operation = AuditOperation.UPDATE
+            diff = event.diff
+        elif isinstance(event, MemoryPromoted):
+            operation = AuditOperation.PROMOTE
+            diff = [
+                {
+                    "field": "layer",
+                    "old_value": event.from_layer,
+                    "new_value": event.to_layer,
+                }
+            ]
+        elif isinstance(event, MemoryDeleted):
+            operation = (
+                AuditOperation.SOFT_DELETE if event.soft else AuditOperation.DELETE
+            )
+        elif isinstance(event, MemoryRestored):
+            operation = AuditOperation.RESTORE
+        else:
+            return None
+
+        if event.agent_id:
+            metadata["agent_id"] = event.agent_id
+        return AuditEntry(
+            timestamp=event.occurred_at,
+            tenant_id=event.tenant_id,
+            actor=event.actor,
+            operation=operation,
+            memory_id=event.memory_id,
+            diff=diff,
+            metadata=metadata,
+        )
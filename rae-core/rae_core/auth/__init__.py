@@ -0,0 +1,26 @@
+"""Access control for RAE-core.
+
+Principals hold roles granting read, write and delete permissions per memory
+layer and sharing scope. Capability tokens carry a principal to the gRPC and
+HTTP layers, which verify them per request; MemoryRpcService enforces the
+permissions on every call.
+"""
+
+from rae_core.auth.principal import (
+    BUILTIN_ROLES,
+    Action,
+    Permission,
+    Principal,
+    Role,
+)
+from rae_core.auth.tokens import CapabilityTokenCodec, bearer_token
+
+__all__ = [
+    "BUILTIN_ROLES",
+    "Action",
+    "CapabilityTokenCodec",
+    "Permission",
+    "Principal",
+    "Role",
+    "bearer_token",
+]
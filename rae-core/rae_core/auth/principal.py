@@ -0,0 +1,151 @@
+"""Principals, roles and permission checks.
+
+A permission grants one action (read, write or delete) on memories of a
+layer and sharing scope, either of which may be the wildcard "*". Roles
+bundle permissions; a Principal (an agent or user of one tenant) holds roles
+plus any permissions granted to it directly.
+"""
+
+from enum import Enum
+from typing import Any
+
+from pydantic import BaseModel, ConfigDict, Field
+
+from rae_core.exceptions.base import (
+    PermissionDeniedError,
+    SecurityPolicyViolationError,
+)
+from rae_core.guards.sharing import SCOPE_KEY
+from rae_core.types.enums import MemoryScope
+
+ANY = "*"
+
+
+class Action(str, Enum):
+    """Operation on memories guarded by a permission."""
+
+    READ = "read"
+    WRITE = "write"
+    DELETE = "delete"
+
+
+class Permission(BaseModel):
+    """One action on a layer and scope ("*" matches any)."""
+
+    model_config = ConfigDict(frozen=True)
+
+    action: Action
+    layer: str = ANY
+    scope: str = ANY
+
+    @classmethod
+    def parse(cls, value: str) -> "Permission":
+        """Parse "action[:layer[:scope]]", e.g. "read:episodic:team"."""
+        action, layer, scope = (value.split(":") + [ANY, ANY])[:3]
+        return cls(action=Action(action), layer=layer or ANY, scope=scope or ANY)
+
+    def __str__(self) -> str:
+        return f"{self.action.value}:{self.layer}:{self.scope}"
+
+    def allows(
+        self, action: Action, layer: str | None = None, scope: str | None = None
+    ) -> bool:
+        """Whether this permission covers the operation.
+
+        An unknown layer or scope (None) is only covered by the wildcard,
+        e.g. listing memories of every layer needs read on "*".
+        """
+        return (
+            self.action is action
+            and self.layer in (ANY, layer)
+            and self.scope in (ANY, scope)
+        )
+
+
+class Role(BaseModel):
+    """Named set of permissions."""
+
+    name: str
+    permissions: list[Permission] = Field(default_factory=list)
+
+
+def _role(name: str, *permissions: str) -> Role:
+    return Role(name=name, permissions=[Permission.parse(p) for p in permissions])
+
+
+BUILTIN_ROLES: dict[str, Role] = {
+    role.name: role
+    for role in (
+        _role("reader", "read"),
+        _role("writer", "read", "write"),
+        _role("admin", "read", "write", "delete"),
+    )
+}
+
+
+class Principal(BaseModel):
+    """Authenticated caller acting within one tenant."""
+
+    subject: str = Field(description="Agent or user identifier")
+    tenant_id: str
+    agent_id: str | None = Field(
+        default=None, description="Agent memories are stored for (subject if None)"
+    )
+    roles: list[Role] = Field(default_factory=list)
+    permissions: list[Permission] = Field(
+        default_factory=list, description="Permissions granted besides the roles"
+    )
+
+    def all_permissions(self) -> list[Permission]:
+        granted = [p for role in self.roles for p in role.permissions]
+        return granted + self.permissions
+
+    def can(
+        self,
+        action: Action | str,
+        layer: str | None = None,
+        scope: str | None = None,
+    ) -> bool:
+        """Whether the principal may perform action on the layer and scope."""
+        action = Action(action)
+        return any(p.allows(action, layer, scope) for p in self.all_permissions())
+
+    def require(
+        self,
+        action: Action | str,
+        layer: str | None = None,
+        scope: str | None = None,
+    ) -> None:
+        """Raise PermissionDeniedError unless the principal can act."""
+        if not self.can(action, layer, scope):
+            raise PermissionDeniedError(
+                self.subject, Action(action).value, layer, scope
+            )
+
+    def can_read_memory(self, memory: dict[str, Any]) -> bool:
+        """Whether the principal may read a stored memory."""
+        return self.can(Action.READ, memory.get("layer"), memory_scope(memory))
+
+    def acting_agent(self, requested: str | None = None) -> str:
+        """Agent a request acts as: the token's agent if it names one.
+
+        Without an agent in the token, the requested agent (or the subject)
+        is used.
+
+        Raises:
+            SecurityPolicyViolationError: requested is another agent than
+                the token's
+        """
+        if self.agent_id is None:
+            return requested or self.subject
+        if requested and requested != self.agent_id:
+            raise SecurityPolicyViolationError(
+                f"{self.subject} may not act as agent {requested}"
+            )
+        return self.agent_id
+
+
+def memory_scope(memory: dict[str, Any]) -> str:
+    """Sharing scope of a stored memory; unscoped memories are private."""
+    scope = (memory.get("metadata") or {}).get(SCOPE_KEY)
+    return scope or MemoryScope.PRIVATE.value
@@ -0,0 +1,119 @@
+"""Capability tokens carrying a principal between services.
+
+A token is "rae1.<payload>.<signature>": the payload is base64url JSON
+(subject, tenant, agent, role names, extra permissions, issue and expiry
+times) and the signature an HMAC-SHA256 of "rae1.<payload>" with a secret
+shared by the issuer and the gRPC/HTTP layers. Role names are resolved when
+the token is verified, so changing a role's permissions applies to tokens
+already issued.
+"""
+
+import base64
+import binascii
+import hashlib
+import hmac
+import json
+from typing import Any
+
+from pydantic import ValidationError as PydanticValidationError
+
+from rae_core.auth.principal import BUILTIN_ROLES, Permission, Principal, Role
+from rae_core.exceptions.base import AuthenticationError
+from rae_core.utils.clock import IClock, SystemClock
+
+TOKEN_PREFIX = "rae1"
+DEFAULT_TOKEN_TTL_SECONDS = 3600
+
+
+def _b64encode(data: bytes) -> str:
+    return base64.urlsafe_b64encode(data).rstrip(b"=").decode("ascii")
+
+
+def _b64decode(data: str) -> bytes:
+    return base64.urlsafe_b64decode(data + "=" * (-len(data) % 4))
+
+
+class CapabilityTokenCodec:
+    """Issues and verifies capability tokens."""
+
+    def __init__(
+        self,
+        secret: str | bytes,
+        roles: dict[str, Role] | None = None,
+        clock: IClock | None = None,
+    ):
+        """Initialize the codec.
+
+        Args:
+            secret: HMAC key shared by issuers and verifiers
+            roles: Role table by name (BUILTIN_ROLES if None)
+            clock: Time source for issue and expiry times
+        """
+        if not secret:
+            raise ValueError("secret must not be empty")
+        self._secret = secret.encode("utf-8") if isinstance(secret, str) else secret
+        self.roles = dict(BUILTIN_ROLES if roles is None else roles)
+        self._clock = clock or SystemClock()
+
+    def issue(
+        self, principal: Principal, ttl_seconds: int = DEFAULT_TOKEN_TTL_SECONDS
+    ) -> str:
+        """Token for principal, valid for ttl_seconds."""
+        issued_at = int(self._clock.now().timestamp())
+        payload = {
+            "sub": principal.subject,
+            "tid": principal.tenant_id,
+            "aid": principal.agent_id,
+            "roles": [role.name for role in principal.roles],
+            "perms": [str(p) for p in principal.permissions],
+            "iat": issued_at,
+            "exp": issued_at + ttl_seconds,
+        }
+        body = _b64encode(json.dumps(payload, separators=(",", ":")).encode())
+        return f"{TOKEN_PREFIX}.{body}.{self._sign(body)}"
+
+    def verify(self, token: str) -> Principal:
+        """Principal of a token.
+
+        Raises:
+            AuthenticationError: Malformed, tampered or expired token, or
+                one naming an unknown role
+        """
+        parts = token.split(".")
+        if len(parts) != 3 or parts[0] != TOKEN_PREFIX:
+            raise AuthenticationError("Malformed capability token")
+        body, signature = parts[1], parts[2]
+        if not hmac.compare_digest(signature, self._sign(body)):
+            raise AuthenticationError("Invalid capability token signature")
+        try:
+            payload: dict[str, Any] = json.loads(_b64decode(body))
+        except (binascii.Error, ValueError) as e:
+            raise AuthenticationError("Malformed capability token") from e
+
+        if self._clock.now().timestamp() >= payload.get("exp", 0):
+            raise AuthenticationError("Capability token expired")
+        unknown = [name for name in payload.get("roles", []) if name not in self.roles]
+        if unknown:
+            raise AuthenticationError(f"Unknown roles in token: {unknown}")
+        try:
+            return Principal(
+                subject=payload["sub"],
+                tenant_id=payload["tid"],
+                agent_id=payload.get("aid"),
+                roles=[self.roles[name] for name in payload.get("roles", [])],
+                permissions=[Permission.parse(p) for p in payload.get("perms", [])],
+            )
+        except (KeyError, ValueError, PydanticValidationError) as e:
+            raise AuthenticationError("Malformed capability token") from e
+
+    def _sign(self, body: str) -> str:
+        message = f"{TOKEN_PREFIX}.{body}".encode("ascii")
+        return _b64encode(hmac.new(self._secret, message, hashlib.sha256).digest())
+
+
+def bearer_token(authorization: str | None) -> str:
+    """Token of an "Authorization: Bearer <token>" header value."""
+    scheme, _, token = (authorization or "").partition(" ")
+    if scheme.lower() != "bearer" or not token.strip():
+        raise AuthenticationError("Missing bearer capability token")
+    return token.strip()
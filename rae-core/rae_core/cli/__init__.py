@@ -0,0 +1,31 @@
+"""Command-line administration of a RAE deployment (the rae-cli script).
+
+    rae-cli --config rae.toml --tenant acme store "Invoices live in S3"
+    rae-cli --url http://rae:8000 --tenant acme search "invoices"
+    rae-cli --config rae.toml --tenant acme export -o acme.jsonl
+    rae-cli --config rae.toml --tenant acme maintenance run --job expiration
+
+Commands run in-process against the configured backends, or against a
+running HTTP (--url) or gRPC (--grpc) server; see cli.backends for what each
+transport offers.
+"""
+
+from rae_core.cli.backends import (
+    MAINTENANCE_JOBS,
+    CliBackend,
+    EmbeddedBackend,
+    GrpcBackend,
+    HttpBackend,
+)
+from rae_core.cli.commands import build_parser, main, run
+
+__all__ = [
+    "MAINTENANCE_JOBS",
+    "CliBackend",
+    "EmbeddedBackend",
+    "GrpcBackend",
+    "HttpBackend",
+    "build_parser",
+    "main",
+    "run",
+]
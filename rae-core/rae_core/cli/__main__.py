@@ -0,0 +1,7 @@
+"""python -m rae_core.cli"""
+
+import sys
+
+from rae_core.cli import main
+
+sys.exit(main())
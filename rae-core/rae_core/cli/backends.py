@@ -0,0 +1,369 @@
+"""Deployments the rae-cli commands run against.
+
+EmbeddedBackend opens the configured backends in-process (RAEBuilder) and
+offers every command. HttpBackend and GrpcBackend call a running server and
+offer what its API exposes:
+
+    command           embedded  HTTP          gRPC
+    store             yes       /v1/remember  StoreMemory
+    search            yes       /v1/recall    SearchMemories (full-text)
+    list              yes       -             ListMemories
+    export, import    yes       -             -
+    stats             yes       /metrics      -
+    maintenance run   yes       -             -
+
+Commands a transport does not expose raise ValidationError naming the
+embedded backend as the way to run them.
+"""
+
+from collections.abc import AsyncIterator, Iterable
+from typing import Any
+
+from rae_core.config.defaults import DEFAULT_DECAY_RATE
+from rae_core.exceptions.base import InfrastructureError, ValidationError
+from rae_core.models.maintenance import JobRun
+from rae_core.rpc.service import AUTH_HEADER, SERVICE_NAME, TENANT_HEADER
+from rae_core.scheduler import JobScheduler, decay_job, expiration_job
+from rae_core.sync.snapshot import SnapshotImportResult, export_tenant, import_tenant
+
+# Jobs of `maintenance run`, in the order they run
+MAINTENANCE_JOBS = ("expiration", "decay")
+
+
+class CliBackend:
+    """Operations behind the CLI commands; unsupported ones raise."""
+
+    transport = "none"
+
+    def _unsupported(self, command: str) -> ValidationError:
+        return ValidationError(
+            f"{command} is not available over {self.transport}; "
+            "run it against the backends directly with --config"
+        )
+
+    async def store(self, tenant_id: str, content: str, **fields: Any) -> str | None:
+        """Store a memory; returns its id (None if it was merged)."""
+        raise self._unsupported("store")
+
+    async def search(
+        self,
+        tenant_id: str,
+        query: str,
+        top_k: int = 10,
+        agent_id: str | None = None,
+        layer: str | None = None,
+    ) -> list[dict[str, Any]]:
+        """Memories relevant to a query, best first."""
+        raise self._unsupported("search")
+
+    async def list_memories(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        limit: int = 100,
+        offset: int = 0,
+    ) -> list[dict[str, Any]]:
+        """A page of the tenant's memories."""
+        raise self._unsupported("list")
+
+    def export(self, tenant_id: str) -> AsyncIterator[bytes]:
+        """JSONL snapshot of the tenant (see sync.snapshot)."""
+        raise self._unsupported("export")
+
+    async def import_snapshot(
+        self, chunks: Iterable[bytes], tenant_id: str | None = None
+    ) -> SnapshotImportResult:
+        """Restore a snapshot (into its own tenant if tenant_id is None)."""
+        raise self._unsupported("import")
+
+    async def stats(self, tenant_id: str) -> dict[str, Any]:
+        """Memory counters of the tenant."""
+        raise self._unsupported("stats")
+
+    async def run_maintenance(
+        self,
+        tenant_id: str,
+        jobs: Iterable[str] = MAINTENANCE_JOBS,
+        decay_factor: float = DEFAULT_DECAY_RATE,
+    ) -> list[JobRun]:
+        """Run maintenance jobs for the tenant now."""
+        raise self._unsupported("maintenance run")
+
+    async def close(self) -> None:
+        """Release connections and flush buffered writes."""
+
+
+class EmbeddedBackend(CliBackend):
+    """Commands run in-process against a built RAESystem."""
+
+    transport = "embedded"
+
+    def __init__(self, system: Any):
+        """Initialize backend.
+
+        Args:
+            system: factories.RAESystem (closed with the backend)
+        """
+        self.system = system
+        self.engine = system.engine
+
+    async def store(self, tenant_id: str, content: str, **fields: Any) -> str | None:
+        memory_id = await self.engine.store_memory(
+            tenant_id=tenant_id, content=content, **fields
+        )
+        return str(memory_id) if memory_id is not None else None
+
+    async def search(
+        self,
+        tenant_id: str,
+        query: str,
+        top_k: int = 10,
+        agent_id: str | None = None,
+        layer: str | None = None,
+    ) -> list[dict[str, Any]]:
+        result = await self.engine.recall(
+            query, tenant_id, top_k=top_k, agent_id=agent_id, layer=layer
+        )
+        memories: list[dict[str, Any]] = result.memories
+        return memories
+
+    async def list_memories(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        limit: int = 100,
+        offset: int = 0,
+    ) -> list[dict[str, Any]]:
+        memories: list[dict[str, Any]] = await self.system.storage.list_memories(
+            tenant_id, agent_id=agent_id, layer=layer, limit=limit, offset=offset
+        )
+        return memories
+
+    def export(self, tenant_id: str) -> AsyncIterator[bytes]:
+        schemas = self.engine.list_embedding_schemas(tenant_id)
+        return export_tenant(
+            self.system.storage,
+            tenant_id,
+            vector_store=self.system.vector_store,
+            graph_store=self.system.graph,
+            vector_names=[schema.model for schema in schemas] or None,
+        )
+
+    async def import_snapshot(
+        self, chunks: Iterable[bytes], tenant_id: str | None = None
+    ) -> SnapshotImportResult:
+        return await import_tenant(
+            self.system.storage,
+            chunks,
+            tenant_id=tenant_id,
+            vector_store=self.system.vector_store,
+            graph_store=self.system.graph,
+        )
+
+    async def stats(self, tenant_id: str) -> dict[str, Any]:
+        stats: dict[str, Any] = await self.engine.get_statistics(tenant_id=tenant_id)
+        return stats
+
+    async def run_maintenance(
+        self,
+        tenant_id: str,
+        jobs: Iterable[str] = MAINTENANCE_JOBS,
+        decay_factor: float = DEFAULT_DECAY_RATE,
+    ) -> list[JobRun]:
+        storage = self.system.storage
+        available = {
+            "expiration": lambda: expiration_job(storage),
+            "decay": lambda: decay_job(storage, decay_factor),
+        }
+        scheduler = JobScheduler(tenant_ids=[tenant_id])
+        names = []
+        for name in jobs:
+            if name not in available:
+                raise ValidationError(f"Unknown maintenance job: {name}")
+            scheduler.add(available[name](), "@daily")
+            names.append(name)
+        return [await scheduler.run_now(name, tenant_id) for name in names]
+
+    async def close(self) -> None:
+        await self.system.close()
+
+
+class HttpBackend(CliBackend):
+    """Commands sent to the HTTP API (api.app)."""
+
+    transport = "HTTP"
+
+    def __init__(
+        self,
+        url: str,
+        token: str | None = None,
+        timeout: float = 30.0,
+        client: Any = None,
+    ):
+        """Initialize backend.
+
+        Args:
+            url: Base URL of the server (without /v1)
+            token: Capability token sent as a bearer token
+            timeout: Request timeout in seconds
+            client: httpx.AsyncClient-compatible client to use
+        """
+        self.url = url.rstrip("/")
+        self.token = token
+        self.timeout = timeout
+        self._client = client
+
+    def _get_client(self) -> Any:
+        if self._client is None:
+            import httpx
+
+            self._client = httpx.AsyncClient(timeout=self.timeout)
+        return self._client
+
+    async def _request(
+        self, method: str, path: str, tenant_id: str, body: Any = None
+    ) -> Any:
+        headers = {"X-Tenant-Id": tenant_id}
+        if self.token:
+            headers["Authorization"] = f"Bearer {self.token}"
+        response = await self._get_client().request(
+            method, f"{self.url}{path}", json=body, headers=headers
+        )
+        if response.status_code >= 400:
+            try:
+                detail = response.json().get("detail")
+            except ValueError:
+                detail = response.text
+            raise InfrastructureError(
+                f"{method} {path} failed with {response.status_code}: {detail}"
+            )
+        return response.json()
+
+    async def store(self, tenant_id: str, content: str, **fields: Any) -> str | None:
+        body = {"content": content, **fields}
+        response = await self._request("POST", "/v1/remember", tenant_id, body)
+        memory_id: str | None = response.get("id")
+        return memory_id
+
+    async def search(
+        self,
+        tenant_id: str,
+        query: str,
+        top_k: int = 10,
+        agent_id: str | None = None,
+        layer: str | None = None,
+    ) -> list[dict[str, Any]]:
+        body = {"query": query, "top_k": top_k, "agent_id": agent_id, "layer": layer}
+        response = await self._request(
+            "POST",
+            "/v1/recall",
+            tenant_id,
+            {k: v for k, v in body.items() if v is not None},
+        )
+        memories: list[dict[str, Any]] = response["memories"]
+        return memories
+
+    async def stats(self, tenant_id: str) -> dict[str, Any]:
+        stats: dict[str, Any] = await self._request("GET", "/metrics", tenant_id)
+        return stats
+
+    async def close(self) -> None:
+        if self._client is not None:
+            await self._client.aclose()
+
+
+class GrpcBackend(CliBackend):
+    """Commands sent to the gRPC server (rpc.server).
+
+    Requires the grpc extra: pip install rae-core[grpc]
+    """
+
+    transport = "gRPC"
+
+    def __init__(self, address: str, token: str | None = None, channel: Any = None):
+        """Initialize backend.
+
+        Args:
+            address: host:port of the server
+            token: Capability token sent in the authorization header
+            channel: grpc.aio channel to use (insecure channel if None)
+        """
+        self.address = address
+        self.token = token
+        self._channel = channel
+
+    async def _call(
+        self, method: str, request: dict[str, Any], tenant_id: str
+    ) -> dict[str, Any]:
+        try:
+            import grpc
+            from google.protobuf import json_format, struct_pb2
+        except ImportError as e:
+            raise ImportError(
+                "grpcio and protobuf are required for --grpc. "
+                "Install with: pip install rae-core[grpc]"
+            ) from e
+        if self._channel is None:
+            self._channel = grpc.aio.insecure_channel(self.address)
+        stub = self._channel.unary_unary(
+            f"/{SERVICE_NAME}/{method}",
+            request_serializer=struct_pb2.Struct.SerializeToString,
+            response_deserializer=struct_pb2.Struct.FromString,
+        )
+        metadata = [(TENANT_HEADER, tenant_id)]
+        if self.token:
+            metadata.append((AUTH_HEADER, f"Bearer {self.token}"))
+        message = struct_pb2.Struct()
+        message.update({k: v for k, v in request.items() if v is not None})
+        try:
+            response = await stub(message, metadata=metadata)
+        except grpc.aio.AioRpcError as e:
+            raise InfrastructureError(
+                f"{method} failed with {e.code().name}: {e.details()}"
+            ) from e
+        payload: dict[str, Any] = json_format.MessageToDict(response)
+        return payload
+
+    async def store(self, tenant_id: str, content: str, **fields: Any) -> str | None:
+        response = await self._call(
+            "StoreMemory", {"content": content, **fields}, tenant_id
+        )
+        memory_id: str | None = response.get("id")
+        return memory_id
+
+    async def search(
+        self,
+        tenant_id: str,
+        query: str,
+        top_k: int = 10,
+        agent_id: str | None = None,
+        layer: str | None = None,
+    ) -> list[dict[str, Any]]:
+        request = {"query": query, "limit": top_k, "agent_id": agent_id, "layer": layer}
+        response = await self._call("SearchMemories", request, tenant_id)
+        memories: list[dict[str, Any]] = response.get("memories", [])
+        return memories
+
+    async def list_memories(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        limit: int = 100,
+        offset: int = 0,
+    ) -> list[dict[str, Any]]:
+        request = {
+            "agent_id": agent_id,
+            "layer": layer,
+            "limit": limit,
+            "offset": offset,
+        }
+        response = await self._call("ListMemories", request, tenant_id)
+        memories: list[dict[str, Any]] = response.get("memories", [])
+        return memories
+
+    async def close(self) -> None:
+        if self._channel is not None:
+            await self._channel.close()
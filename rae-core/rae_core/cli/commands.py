@@ -0,0 +1,246 @@
+"""Argument parsing and dispatch of the rae-cli commands.
+
+Results are printed to stdout as JSON (export writes the JSONL snapshot
+itself); errors are printed to stderr with exit status 1.
+"""
+
+import argparse
+import asyncio
+import json
+import os
+import sys
+from collections.abc import Sequence
+from typing import IO, Any
+
+from pydantic import BaseModel
+
+from rae_core.cli.backends import (
+    MAINTENANCE_JOBS,
+    CliBackend,
+    EmbeddedBackend,
+    GrpcBackend,
+    HttpBackend,
+)
+from rae_core.config.defaults import DEFAULT_DECAY_RATE, DEFAULT_TOP_K
+from rae_core.exceptions.base import RAEError, ValidationError
+from rae_core.rpc.service import to_wire
+from rae_core.version import __version__
+
+# Environment variables supplying defaults of the global options
+CONFIG_ENV = "RAE_CONFIG_FILE"
+URL_ENV = "RAE_URL"
+GRPC_ENV = "RAE_GRPC_ADDRESS"
+TOKEN_ENV = "RAE_TOKEN"
+TENANT_ENV = "RAE_TENANT_ID"
+
+
+def build_parser() -> argparse.ArgumentParser:
+    """Parser of the rae-cli command line."""
+    parser = argparse.ArgumentParser(
+        prog="rae-cli", description="Administer a RAE deployment."
+    )
+    parser.add_argument("--version", action="version", version=__version__)
+    target = parser.add_mutually_exclusive_group()
+    target.add_argument(
+        "--config",
+        default=os.environ.get(CONFIG_ENV),
+        help="TOML, YAML or JSON file of the backends to open in-process "
+        f"(default: ${CONFIG_ENV}, else RAE_* environment variables)",
+    )
+    target.add_argument(
+        "--url",
+        default=os.environ.get(URL_ENV),
+        help=f"Base URL of a RAE HTTP server (default: ${URL_ENV})",
+    )
+    target.add_argument(
+        "--grpc",
+        default=os.environ.get(GRPC_ENV),
+        metavar="ADDRESS",
+        help=f"host:port of a RAE gRPC server (default: ${GRPC_ENV})",
+    )
+    parser.add_argument(
+        "--token",
+        default=os.environ.get(TOKEN_ENV),
+        help=f"Capability token for the server (default: ${TOKEN_ENV})",
+    )
+    parser.add_argument(
+        "--tenant",
+        default=os.environ.get(TENANT_ENV),
+        help=f"Tenant to operate on (default: ${TENANT_ENV})",
+    )
+    commands = parser.add_subparsers(dest="command", required=True)
+
+    store = commands.add_parser("store", help="Store a memory")
+    store.add_argument("content")
+    store.add_argument("--agent", default="default")
+    store.add_argument("--layer", default="episodic")
+    store.add_argument("--tag", dest="tags", action="append", default=[])
+    store.add_argument("--importance", type=float)
+    store.add_argument("--metadata", type=json.loads, help="JSON object")
+
+    search = commands.add_parser("search", help="Recall memories relevant to a query")
+    search.add_argument("query")
+    search.add_argument("--top-k", type=int, default=DEFAULT_TOP_K)
+    search.add_argument("--agent")
+    search.add_argument("--layer")
+
+    listing = commands.add_parser("list", help="List stored memories")
+    listing.add_argument("--agent")
+    listing.add_argument("--layer")
+    listing.add_argument("--limit", type=int, default=100)
+    listing.add_argument("--offset", type=int, default=0)
+
+    export = commands.add_parser("export", help="Write a snapshot of the tenant")
+    export.add_argument("-o", "--output", default="-", help="File (default: stdout)")
+
+    restore = commands.add_parser(
+        "import", help="Restore a snapshot (into --tenant if given)"
+    )
+    restore.add_argument("input", help="Snapshot file, or - for stdin")
+
+    commands.add_parser("stats", help="Memory counts of the tenant")
+
+    maintenance = commands.add_parser("maintenance", help="Maintenance jobs")
+    maintenance_commands = maintenance.add_subparsers(dest="action", required=True)
+    run = maintenance_commands.add_parser("run", help="Run maintenance jobs now")
+    run.add_argument(
+        "--job",
+        dest="jobs",
+        action="append",
+        choices=MAINTENANCE_JOBS,
+        help="Job to run (repeatable; default: all)",
+    )
+    run.add_argument("--decay-factor", type=float, default=DEFAULT_DECAY_RATE)
+    return parser
+
+
+async def open_backend(args: argparse.Namespace) -> CliBackend:
+    """Backend selected by the global options."""
+    if args.url:
+        return HttpBackend(args.url, token=args.token)
+    if args.grpc:
+        return GrpcBackend(args.grpc, token=args.token)
+
+    from rae_core.factories import RAEBuilder
+
+    builder = RAEBuilder.from_file(args.config) if args.config else RAEBuilder()
+    return EmbeddedBackend(await builder.build())
+
+
+def _print(value: Any, out: IO[str]) -> None:
+    if isinstance(value, BaseModel):
+        value = value.model_dump(mode="json")
+    elif isinstance(value, list):
+        value = [
+            v.model_dump(mode="json") if isinstance(v, BaseModel) else v
+            for v in value
+        ]
+    out.write(json.dumps(to_wire(value), indent=2, ensure_ascii=False) + "\n")
+
+
+def _tenant(args: argparse.Namespace) -> str:
+    if not args.tenant:
+        raise ValidationError(f"--tenant (or ${TENANT_ENV}) is required")
+    tenant_id: str = args.tenant
+    return tenant_id
+
+
+async def _export(
+    backend: CliBackend, tenant_id: str, output: str, out: IO[str]
+) -> None:
+    if output == "-":
+        async for chunk in backend.export(tenant_id):
+            out.write(chunk.decode("utf-8"))
+        return
+    with open(output, "wb") as f:
+        async for chunk in backend.export(tenant_id):
+            f.write(chunk)
+
+
+async def run(
+    args: argparse.Namespace, backend: CliBackend, out: IO[str] | None = None
+) -> None:
+    """Execute the parsed command against backend.
+
+    Raises:
+        RAEError: The command failed
+    """
+    out = out or sys.stdout
+    if args.command == "store":
+        fields = {
+            "agent_id": args.agent,
+            "layer": args.layer,
+            "tags": args.tags,
+            "importance": args.importance,
+            "metadata": args.metadata,
+        }
+        memory_id = await backend.store(
+            _tenant(args),
+            args.content,
+            **{k: v for k, v in fields.items() if v is not None},
+        )
+        _print({"id": memory_id, "stored": memory_id is not None}, out)
+    elif args.command == "search":
+        _print(
+            await backend.search(
+                _tenant(args),
+                args.query,
+                top_k=args.top_k,
+                agent_id=args.agent,
+                layer=args.layer,
+            ),
+            out,
+        )
+    elif args.command == "list":
+        _print(
+            await backend.list_memories(
+                _tenant(args),
+                agent_id=args.agent,
+                layer=args.layer,
+                limit=args.limit,
+                offset=args.offset,
+            ),
+            out,
+        )
+    elif args.command == "export":
+        await _export(backend, _tenant(args), args.output, out)
+    elif args.command == "import":
+        if args.input == "-":
+            result = await backend.import_snapshot(sys.stdin.buffer, args.tenant)
+        else:
+            with open(args.input, "rb") as f:
+                result = await backend.import_snapshot(f, args.tenant)
+        _print(result, out)
+    elif args.command == "stats":
+        _print(await backend.stats(_tenant(args)), out)
+    elif args.command == "maintenance":
+        _print(
+            await backend.run_maintenance(
+                _tenant(args),
+                jobs=args.jobs or MAINTENANCE_JOBS,
+                decay_factor=args.decay_factor,
+            ),
+            out,
+        )
+
+
+async def _main(args: argparse.Namespace) -> int:
+    try:
+        backend = await open_backend(args)
+    except (RAEError, ImportError) as e:
+        print(f"rae-cli: {e}", file=sys.stderr)
+        return 1
+    try:
+        await run(args, backend)
+    except (RAEError, ImportError, OSError) as e:
+        print(f"rae-cli: {e}", file=sys.stderr)
+        return 1
+    finally:
+        await backend.close()
+    return 0
+
+
+def main(argv: Sequence[str] | None = None) -> int:
+    """Entry point of the rae-cli script; returns the exit status."""
+    args = build_parser().parse_args(argv)
+    return asyncio.run(_main(args))
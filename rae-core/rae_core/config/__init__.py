@@ -15,10 +15,13 @@ from rae_core.config.defaults import (
     get_default_reflection_config,
     get_default_search_config,
 )
+from rae_core.config.backends import BackendConfig, RAEConfig
 from rae_core.config.settings import RAESettings
 
 __all__ = [
     "RAESettings",
+    "RAEConfig",
+    "BackendConfig",
     "DEFAULT_SENSORY_SIZE",
     "DEFAULT_WORKING_SIZE",
     "DEFAULT_EPISODIC_SIZE",
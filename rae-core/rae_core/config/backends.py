@@ -0,0 +1,143 @@
+"""Declarative backend configuration.
+
+RAEConfig names the storage, vector, graph, cache and embedding backends of
+a deployment with their constructor options, plus RAESettings overrides.
+It is read from a TOML, YAML or JSON file, and RAE_* environment variables
+override the file (nested keys are separated by "__"):
+
+    # rae.toml
+    [storage]
+    backend = "sqlite"
+    options = { db_path = "rae.db" }
+
+    [vector_store]
+    backend = "qdrant"
+    options = { url = "http://localhost:6333" }
+
+    [embedding.default]
+    provider = "ollama"
+
+    RAE_STORAGE__OPTIONS__DB_PATH=/data/rae.db
+
+factories.RAEBuilder turns it into a wired RAEEngine.
+"""
+
+import json
+from pathlib import Path
+from typing import Any
+
+from pydantic import BaseModel, ConfigDict, Field, field_validator
+from pydantic import ValidationError as PydanticValidationError
+from pydantic_settings import (
+    BaseSettings,
+    PydanticBaseSettingsSource,
+    SettingsConfigDict,
+)
+
+from rae_core.config.settings import RAESettings
+from rae_core.exceptions.base import ValidationError
+from rae_core.models.embedding import EmbeddingProviderConfig
+
+try:
+    import tomllib
+except ModuleNotFoundError:  # pragma: no cover - Python 3.10
+    import tomli as tomllib
+
+
+class BackendConfig(BaseModel):
+    """Backend selected by name, with its constructor options."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    backend: str = Field(description="Registered backend name, e.g. 'sqlite'")
+    options: dict[str, Any] = Field(
+        default_factory=dict,
+        description="Keyword arguments of the backend (db_path, dsn, url, ...)",
+    )
+
+
+class RAEConfig(BaseSettings):
+    """Backends of a deployment; environment variables override the file."""
+
+    model_config = SettingsConfigDict(
+        env_prefix="RAE_",
+        env_nested_delimiter="__",
+        case_sensitive=False,
+        extra="forbid",
+    )
+
+    storage: BackendConfig = Field(
+        default_factory=lambda: BackendConfig(backend="memory")
+    )
+    vector_store: BackendConfig = Field(
+        default_factory=lambda: BackendConfig(backend="memory")
+    )
+    graph: BackendConfig | None = None
+    cache: BackendConfig | None = None
+    embedding: dict[str, EmbeddingProviderConfig] = Field(
+        default_factory=dict, description="Embedding provider by model name"
+    )
+    default_embedding_model: str = "default"
+    settings: dict[str, Any] = Field(
+        default_factory=dict,
+        description="RAESettings overrides (e.g. relevance_floor)",
+    )
+
+    @classmethod
+    def settings_customise_sources(
+        cls,
+        settings_cls: type[BaseSettings],
+        init_settings: PydanticBaseSettingsSource,
+        env_settings: PydanticBaseSettingsSource,
+        dotenv_settings: PydanticBaseSettingsSource,
+        file_secret_settings: PydanticBaseSettingsSource,
+    ) -> tuple[PydanticBaseSettingsSource, ...]:
+        # Environment first, so it overrides the values read from a file
+        return env_settings, init_settings
+
+    @field_validator("settings")
+    @classmethod
+    def _known_settings(cls, value: dict[str, Any]) -> dict[str, Any]:
+        unknown = set(value) - set(RAESettings.model_fields)
+        if unknown:
+            raise ValueError(f"Unknown settings: {sorted(unknown)}")
+        return value
+
+    def rae_settings(self) -> RAESettings:
+        """RAESettings from the environment with this config's overrides."""
+        return RAESettings(**self.settings)
+
+    @classmethod
+    def load(cls, path: str | Path | None = None) -> "RAEConfig":
+        """Read a TOML, YAML or JSON file (by suffix) and the environment.
+
+        Without a path, only the environment and defaults are used.
+
+        Raises:
+            ValidationError: Unreadable or invalid configuration
+        """
+        try:
+            data = _read_file(Path(path)) if path is not None else {}
+            return cls(**data)
+        except (OSError, ValueError, PydanticValidationError) as e:
+            raise ValidationError(f"Invalid configuration in {path}: {e}") from e
+
+
+def _read_file(path: Path) -> dict[str, Any]:
+    text = path.read_text(encoding="utf-8")
+    if path.suffix == ".toml":
+        data = tomllib.loads(text)
+    elif path.suffix in (".yaml", ".yml"):
+        import yaml
+
+        try:
+            data = yaml.safe_load(text)
+        except yaml.YAMLError as e:
+            raise ValueError(str(e)) from e
+    else:
+        data = json.loads(text)
+    if data is None:
+        return {}
+    if not isinstance(data, dict):
+        raise ValueError("the configuration must be a mapping")
+    return data
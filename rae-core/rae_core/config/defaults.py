@@ -21,6 +21,36 @@ DEFAULT_CONSOLIDATION_THRESHOLD = 0.6
 DEFAULT_TOP_K = 10
 DEFAULT_SIMILARITY_THRESHOLD = 0.7
 DEFAULT_RERANK_TOP_K = 5
+# Minimum final score for a recalled memory to count as relevant (None = off)
+DEFAULT_RELEVANCE_FLOOR: float | None = None
+# Time constant of recency-weighted scoring (score x exp(-age / tau))
+DEFAULT_RECENCY_TAU_HOURS = 168.0
+# Score multiplier of shared global knowledge merged into tenant results
+DEFAULT_GLOBAL_KNOWLEDGE_WEIGHT = 0.8
+# Novelty (1 - similarity to the closest memory) below which a write is merged
+DEFAULT_NOVELTY_THRESHOLD = 0.05
+# Finished job runs kept by the maintenance scheduler
+DEFAULT_JOB_HISTORY = 200
+# Lock shards tenants are spread over in the in-memory backends
+DEFAULT_LOCK_SHARDS = 64
+# Longest time recorded memory accesses are buffered before being written
+DEFAULT_ACCESS_FLUSH_MS = 1000.0
+# Buffered accesses that trigger an immediate write
+DEFAULT_ACCESS_MAX_PENDING = 1024
+# Similarity above which an existing memory is checked for contradicting a new one
+DEFAULT_CONTRADICTION_SIMILARITY = 0.75
+# Contradiction score (0-1) at or above which two memories are flagged
+DEFAULT_CONTRADICTION_THRESHOLD = 0.5
+# Most similar memories judged against each new memory
+DEFAULT_CONTRADICTION_CANDIDATES = 5
+# Factor applied to the importance of a memory superseded by a resolved conflict
+DEFAULT_SUPERSEDED_IMPORTANCE_FACTOR = 0.1
+# Confidence below which recalled memories are flagged as uncertain in context
+DEFAULT_LOW_CONFIDENCE_THRESHOLD = 0.5
+# Confidence kept by a memory derived from others, relative to its sources'
+DEFAULT_DERIVED_CONFIDENCE_FACTOR = 0.9
+# Stale summaries regenerated per refresh run
+DEFAULT_SUMMARY_REFRESH_BATCH = 100
 
 # Reflection parameters
 DEFAULT_MIN_MEMORIES_FOR_REFLECTION = 5
@@ -32,6 +62,16 @@ DEFAULT_LLM_TEMPERATURE = 0.7
 DEFAULT_LLM_MAX_TOKENS = 1000
 DEFAULT_LLM_TIMEOUT = 30.0
 
+# Streaming ingestion parameters
+DEFAULT_STREAM_BATCH_SIZE = 64  # Items embedded per provider call
+DEFAULT_STREAM_CONCURRENCY = 4  # Batches embedded and stored at once
+DEFAULT_STREAM_MAX_REPORTED_FAILURES = 100  # Failures listed in the summary
+
+# Workload simulation parameters
+DEFAULT_SIM_TICKS = 100  # Ticks (rounds of operations) of a simulated workload
+DEFAULT_SIM_CONCURRENCY = 8  # Operations of a tick run at once
+DEFAULT_SIM_ZIPF_EXPONENT = 1.1  # Skew of reads towards the most popular facts
+
 # Sync parameters
 DEFAULT_SYNC_BATCH_SIZE = 100
 DEFAULT_SYNC_TIMEOUT = 60.0
@@ -41,6 +81,17 @@ DEFAULT_SYNC_RETRY_ATTEMPTS = 3
 DEFAULT_CACHE_TTL = 3600  # 1 hour
 DEFAULT_CACHE_MAX_SIZE = 1000
 
+# pgvector index parameters
+DEFAULT_HNSW_M = 16  # Links per node of an HNSW graph
+DEFAULT_HNSW_EF_CONSTRUCTION = 64  # Candidate list size while building HNSW
+DEFAULT_IVFFLAT_LISTS = 100  # Inverted lists of an IVFFlat index
+
+# Vectors a LanceDB table needs before an ANN index is trained on it
+DEFAULT_LANCE_INDEX_MIN_ROWS = 4096
+
+# Seconds a backend health check may take before it counts as failed
+DEFAULT_HEALTH_CHECK_TIMEOUT = 5.0
+
 # OpenTelemetry parameters
 DEFAULT_OTEL_ENABLED = True
 DEFAULT_OTEL_SERVICE_NAME = "rae-core"
@@ -100,6 +151,7 @@ def get_default_search_config() -> dict[str, Any]:
         "top_k": DEFAULT_TOP_K,
         "similarity_threshold": DEFAULT_SIMILARITY_THRESHOLD,
         "rerank_top_k": DEFAULT_RERANK_TOP_K,
+        "relevance_floor": DEFAULT_RELEVANCE_FLOOR,
     }
 
 
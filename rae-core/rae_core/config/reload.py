@@ -0,0 +1,273 @@
+"""Hot reload of runtime configuration.
+
+Operators tune policies, schedules, ranking weights and rate limits in a
+JSON (or YAML) file; a ConfigWatcher validates each change and swaps it in
+without restarting the service. An invalid file is logged and the running
+configuration is kept.
+
+Readers never see a half-applied change: the configuration is an immutable
+RuntimeConfig behind a ConfigHandle, replaced by a single reference swap.
+Code that reads several values should take one snapshot with get() and use
+it for the whole operation.
+"""
+
+import asyncio
+import hashlib
+import inspect
+import json
+from collections.abc import Awaitable, Callable
+from pathlib import Path
+from typing import Any, Generic, TypeVar
+
+import structlog
+from pydantic import BaseModel, ConfigDict, Field, PositiveFloat, field_validator
+from pydantic import ValidationError as PydanticValidationError
+
+from rae_core.config.settings import RAESettings
+from rae_core.events.models import ConfigChanged
+from rae_core.exceptions.base import ValidationError
+from rae_core.models.load import LoadLimits
+from rae_core.models.quota import TenantQuota
+from rae_core.models.reflection import ReflectionPolicy
+
+logger = structlog.get_logger(__name__)
+
+T = TypeVar("T")
+ConfigListener = Callable[["RuntimeConfig", "RuntimeConfig"], Awaitable[None] | None]
+
+
+class RuntimeConfig(BaseModel):
+    """Settings that can change while the service runs."""
+
+    model_config = ConfigDict(frozen=True, extra="forbid")
+
+    settings: dict[str, Any] = Field(
+        default_factory=dict,
+        description="RAESettings overrides (e.g. relevance_floor, rerank_top_k)",
+    )
+    ranking_weights: dict[str, float] | None = Field(
+        default=None, description="Search strategy weights used when none are given"
+    )
+    reflection_policy: ReflectionPolicy | None = None
+    schedules: dict[str, PositiveFloat] = Field(
+        default_factory=dict, description="Job interval in seconds by job name"
+    )
+    default_quota: TenantQuota | None = None
+    quotas: dict[str, TenantQuota] = Field(default_factory=dict)
+    default_load_limits: LoadLimits | None = None
+    load_limits: dict[str, LoadLimits] = Field(default_factory=dict)
+
+    @field_validator("settings")
+    @classmethod
+    def _known_settings(cls, value: dict[str, Any]) -> dict[str, Any]:
+        unknown = set(value) - set(RAESettings.model_fields)
+        if unknown:
+            raise ValueError(f"Unknown settings: {sorted(unknown)}")
+        # Validate the values against the settings model
+        checked = RAESettings.model_validate(value)
+        return {name: getattr(checked, name) for name in value}
+
+    @field_validator("ranking_weights")
+    @classmethod
+    def _non_negative_weights(
+        cls, value: dict[str, float] | None
+    ) -> dict[str, float] | None:
+        if value and any(weight < 0 for weight in value.values()):
+            raise ValueError("ranking weights must not be negative")
+        return value
+
+    def changed_fields(self, other: "RuntimeConfig") -> list[str]:
+        """Top-level fields that differ from other."""
+        return [
+            name
+            for name in type(self).model_fields
+            if getattr(self, name) != getattr(other, name)
+        ]
+
+
+class ConfigHandle(Generic[T]):
+    """Atomically swappable reference to an immutable configuration."""
+
+    def __init__(self, value: T):
+        self._value = value
+        self.version = 0
+
+    def get(self) -> T:
+        """Current configuration snapshot."""
+        return self._value
+
+    def swap(self, value: T) -> T:
+        """Replace the configuration; returns the previous one."""
+        previous, self._value = self._value, value
+        self.version += 1
+        return previous
+
+
+def load_config_file(path: str | Path) -> RuntimeConfig:
+    """Parse and validate a JSON or YAML configuration file.
+
+    Raises:
+        ValidationError: Unreadable or invalid configuration
+    """
+    path = Path(path)
+    try:
+        text = path.read_text(encoding="utf-8")
+        if path.suffix in (".yaml", ".yml"):
+            import yaml
+
+            data = yaml.safe_load(text)
+        else:
+            data = json.loads(text)
+        return RuntimeConfig.model_validate(data or {})
+    except (OSError, ValueError, PydanticValidationError) as e:
+        raise ValidationError(f"Invalid configuration in {path}: {e}") from e
+
+
+class ConfigWatcher:
+    """Reloads a configuration file and applies changes at runtime.
+
+    Each change is validated, swapped into the handle, passed to the
+    listeners as (old, new) and published as a ConfigChanged event.
+    """
+
+    def __init__(
+        self,
+        path: str | Path,
+        handle: ConfigHandle[RuntimeConfig] | None = None,
+        event_bus: Any = None,
+        poll_interval: float = 1.0,
+    ):
+        """Initialize the watcher.
+
+        Args:
+            path: JSON or YAML file with a RuntimeConfig
+            handle: Handle to update (a new one if None)
+            event_bus: Optional MemoryEventBus receiving ConfigChanged
+            poll_interval: Seconds between file checks when started
+        """
+        self.path = Path(path)
+        self.handle = handle or ConfigHandle(RuntimeConfig())
+        self.event_bus = event_bus
+        self.poll_interval = poll_interval
+        self._listeners: list[ConfigListener] = []
+        self._digest: str | None = None
+        self._task: asyncio.Task[None] | None = None
+
+    def on_change(self, listener: ConfigListener) -> ConfigListener:
+        """Register a listener called with (old, new) after each swap."""
+        self._listeners.append(listener)
+        return listener
+
+    def bind(
+        self,
+        engine: Any = None,
+        quota_manager: Any = None,
+        load_shedder: Any = None,
+    ) -> None:
+        """Apply every change to these components (and the current config now)."""
+
+        def apply(old: RuntimeConfig, new: RuntimeConfig) -> None:
+            if engine is not None:
+                apply_to_engine(engine, new)
+            if quota_manager is not None:
+                apply_to_quota_manager(quota_manager, new, old)
+            if load_shedder is not None:
+                apply_to_load_shedder(load_shedder, new, old)
+
+        current = self.handle.get()
+        apply(current, current)
+        self.on_change(apply)
+
+    async def check(self) -> bool:
+        """Reload the file if its content changed; True when applied.
+
+        An invalid file is logged and leaves the running configuration.
+        """
+        try:
+            digest = hashlib.sha256(self.path.read_bytes()).hexdigest()
+        except OSError as e:
+            logger.warning("config_read_failed", path=str(self.path), error=str(e))
+            return False
+        if digest == self._digest:
+            return False
+        try:
+            config = load_config_file(self.path)
+        except ValidationError as e:
+            logger.error("config_reload_rejected", path=str(self.path), error=str(e))
+            self._digest = digest
+            return False
+        self._digest = digest
+        return await self.apply(config, source=str(self.path))
+
+    async def apply(self, config: RuntimeConfig, source: str | None = None) -> bool:
+        """Swap in a validated configuration; False if nothing changed."""
+        changed = config.changed_fields(self.handle.get())
+        if not changed:
+            return False
+        old = self.handle.swap(config)
+        for listener in self._listeners:
+            try:
+                result = listener(old, config)
+                if inspect.isawaitable(result):
+                    await result
+            except Exception as e:
+                logger.error("config_listener_failed", error=str(e))
+        logger.info("config_reloaded", version=self.handle.version, changed=changed)
+        if self.event_bus is not None:
+            await self.event_bus.publish(
+                ConfigChanged(
+                    version=self.handle.version, changed=changed, source=source
+                )
+            )
+        return True
+
+    def start(self) -> None:
+        """Poll the file in the background until stop()."""
+        if self._task is None:
+            self._task = asyncio.ensure_future(self._poll())
+
+    async def stop(self) -> None:
+        if self._task is not None:
+            self._task.cancel()
+            try:
+                await self._task
+            except asyncio.CancelledError:
+                pass
+            self._task = None
+
+    async def _poll(self) -> None:
+        while True:
+            await self.check()
+            await asyncio.sleep(self.poll_interval)
+
+
+def apply_to_engine(engine: Any, config: RuntimeConfig) -> None:
+    """Swap in the engine's settings overrides and ranking weights."""
+    if config.settings and engine.settings is not None:
+        engine.settings = engine.settings.model_copy(update=config.settings)
+    engine.ranking_weights = config.ranking_weights
+
+
+def apply_to_quota_manager(
+    quota_manager: Any, config: RuntimeConfig, previous: RuntimeConfig | None = None
+) -> None:
+    """Set a QuotaManager's limits; overrides dropped since previous are removed."""
+    if config.default_quota is not None:
+        quota_manager.default_quota = config.default_quota
+    for tenant_id in set(previous.quotas if previous else ()) - set(config.quotas):
+        quota_manager.remove_quota(tenant_id)
+    for tenant_id, quota in config.quotas.items():
+        quota_manager.set_quota(tenant_id, quota)
+
+
+def apply_to_load_shedder(
+    load_shedder: Any, config: RuntimeConfig, previous: RuntimeConfig | None = None
+) -> None:
+    """Set a LoadShedder's limits; overrides dropped since previous are removed."""
+    if config.default_load_limits is not None:
+        load_shedder.default_limits = config.default_load_limits
+    dropped = set(previous.load_limits if previous else ()) - set(config.load_limits)
+    for tenant_id in dropped:
+        load_shedder.remove_limits(tenant_id)
+    for tenant_id, limits in config.load_limits.items():
+        load_shedder.set_limits(tenant_id, limits)
@@ -12,6 +12,7 @@ from rae_core.config.defaults import (
     DEFAULT_DECAY_INTERVAL_HOURS,
     DEFAULT_DECAY_RATE,
     DEFAULT_EPISODIC_SIZE,
+    DEFAULT_GLOBAL_KNOWLEDGE_WEIGHT,
     DEFAULT_LLM_MAX_TOKENS,
     DEFAULT_LLM_TEMPERATURE,
     DEFAULT_LLM_TIMEOUT,
@@ -23,6 +24,7 @@ from rae_core.config.defaults import (
     DEFAULT_PROMOTION_THRESHOLD,
     DEFAULT_QUALITY_THRESHOLD,
     DEFAULT_REFLECTION_MAX_AGE_HOURS,
+    DEFAULT_RELEVANCE_FLOOR,
     DEFAULT_RERANK_TOP_K,
     DEFAULT_SEMANTIC_SIZE,
     DEFAULT_SENSORY_SIZE,
@@ -118,6 +120,18 @@ class RAESettings(BaseSettings):
         ge=1,
         description="Number of results to rerank",
     )
+    relevance_floor: float | None = Field(
+        default=DEFAULT_RELEVANCE_FLOOR,
+        ge=0.0,
+        le=1.0,
+        description="Minimum final score for recall to report a memory as relevant",
+    )
+    global_knowledge_weight: float = Field(
+        default=DEFAULT_GLOBAL_KNOWLEDGE_WEIGHT,
+        ge=0.0,
+        le=1.0,
+        description="Score multiplier of global knowledge in tenant results",
+    )
 
     # Reflection parameters
     min_memories_for_reflection: int = Field(
@@ -280,6 +294,7 @@ class RAESettings(BaseSettings):
             "top_k": self.search_top_k,
             "similarity_threshold": self.similarity_threshold,
             "rerank_top_k": self.rerank_top_k,
+            "relevance_floor": self.relevance_floor,
         }
 
     def get_reflection_config(self) -> dict[str, Any]:
@@ -0,0 +1,20 @@
+"""RAE Consistency Package."""
+
+from .contradiction import (
+    CONFLICT_TAG,
+    CONTRADICTS_KEY,
+    ContradictionDetector,
+    LlmContradictionJudge,
+    list_conflicts,
+)
+from .resolution import SUPERSEDED_TAG, resolve_conflict
+
+__all__ = [
+    "ContradictionDetector",
+    "LlmContradictionJudge",
+    "list_conflicts",
+    "resolve_conflict",
+    "CONFLICT_TAG",
+    "CONTRADICTS_KEY",
+    "SUPERSEDED_TAG",
+]
@@ -0,0 +1,269 @@
+"""
+RAE Contradiction Detector.
+Optional write-path stage that notices when a new memory disagrees with
+what is already known. The memories most similar to the new one (found
+through its stored embedding, see search.similarity) are judged against
+it by an NLI model or an LLM; when a pair is judged contradictory the new
+memory is tagged and records the conflict in its metadata, and with a
+graph store a "contradicts" edge links the two. list_conflicts reads the
+flagged pairs back so they can be reviewed and resolved.
+"""
+
+import re
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.config.defaults import (
+    DEFAULT_CONTRADICTION_CANDIDATES,
+    DEFAULT_CONTRADICTION_SIMILARITY,
+    DEFAULT_CONTRADICTION_THRESHOLD,
+)
+from rae_core.interfaces.embedding import IEmbeddingProvider
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.llm import ILLMProvider
+from rae_core.interfaces.nli import INliModel
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.consistency import Contradiction
+from rae_core.models.graph import EdgeType, NodeType
+from rae_core.models.search import SimilarityRecallOptions
+from rae_core.search.similarity import recall_similar_to
+
+logger = structlog.get_logger(__name__)
+
+CONFLICT_TAG = "contradiction"
+CONTRADICTS_KEY = "contradicts"
+
+_SYSTEM_PROMPT = (
+    "You compare two statements from an agent's memory. Reply with a single "
+    "number between 0 and 1: 1 if they cannot both be true, 0 if they agree "
+    "or are unrelated."
+)
+_NUMBER = re.compile(r"\d*\.?\d+")
+
+
+class LlmContradictionJudge:
+    """INliModel asking an LLM whether two statements contradict."""
+
+    def __init__(self, llm_provider: ILLMProvider):
+        self.llm_provider = llm_provider
+
+    async def contradiction_score(self, premise: str, hypothesis: str) -> float:
+        """Contradiction score parsed from the LLM's reply (0.0 if none)."""
+        reply = await self.llm_provider.generate(
+            f"Statement A: {premise}\nStatement B: {hypothesis}\nScore:",
+            system_prompt=_SYSTEM_PROMPT,
+            max_tokens=8,
+            temperature=0.0,
+        )
+        match = _NUMBER.search(reply or "")
+        if match is None:
+            logger.warning("contradiction_judge_unparsable", reply=reply)
+            return 0.0
+        return min(max(float(match.group()), 0.0), 1.0)
+
+
+class ContradictionDetector:
+    """Flags stored memories contradicting the most similar existing ones."""
+
+    def __init__(
+        self,
+        judge: INliModel | ILLMProvider,
+        graph_store: IGraphStore | None = None,
+        similarity_threshold: float = DEFAULT_CONTRADICTION_SIMILARITY,
+        threshold: float = DEFAULT_CONTRADICTION_THRESHOLD,
+        max_candidates: int = DEFAULT_CONTRADICTION_CANDIDATES,
+    ):
+        """Initialize detector.
+
+        Args:
+            judge: NLI model scoring pairs; an LLM provider is wrapped in an
+                LlmContradictionJudge
+            graph_store: Graph store receiving "contradicts" edges (none if
+                None)
+            similarity_threshold: Similarity an existing memory needs to be
+                judged at all; unrelated statements cannot conflict
+            threshold: Contradiction score at or above which a pair is flagged
+            max_candidates: Most similar memories judged per new memory
+        """
+        if not 0.0 <= threshold <= 1.0:
+            raise ValueError("threshold must be between 0 and 1")
+        if not isinstance(judge, INliModel):
+            judge = LlmContradictionJudge(judge)
+        self.judge = judge
+        self.graph_store = graph_store
+        self.similarity_threshold = similarity_threshold
+        self.threshold = threshold
+        self.max_candidates = max_candidates
+
+    async def check(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore,
+        embedding_provider: IEmbeddingProvider | None = None,
+        project: str | None = None,
+    ) -> list[Contradiction]:
+        """Judge a stored memory against its nearest neighbours.
+
+        Candidates are the memories of the same tenant (and project, when
+        given) at least similarity_threshold similar to it; other chunks
+        of the same content are skipped. Flagged pairs are recorded on the
+        memory and, with a graph store, as "contradicts" edges.
+
+        Returns:
+            The contradictions found, strongest first
+
+        Raises:
+            NotFoundError: The memory or its embedding does not exist
+        """
+        options = SimilarityRecallOptions(
+            top_k=self.max_candidates,
+            min_similarity=self.similarity_threshold,
+            project=project,
+            rerank=False,
+        )
+        candidates = await recall_similar_to(
+            memory_id, tenant_id, storage, vector_store, embedding_provider, options
+        )
+        if not candidates:
+            return []
+        memory = await storage.get_memory(memory_id, tenant_id)
+        if memory is None:
+            return []
+        metadata = memory.get("metadata") or {}
+        parent_id = metadata.get("parent_id")
+
+        now = datetime.now(timezone.utc)
+        found = []
+        for candidate in candidates:
+            existing = candidate.memory
+            sibling = (existing.get("metadata") or {}).get("parent_id")
+            if parent_id and sibling == parent_id:
+                continue
+            score = await self.judge.contradiction_score(
+                str(existing.get("content") or ""), str(memory.get("content") or "")
+            )
+            if score >= self.threshold:
+                found.append(
+                    Contradiction(
+                        memory_id=memory_id,
+                        conflicting_id=existing["id"],
+                        score=score,
+                        similarity=candidate.similarity,
+                        detected_at=now,
+                    )
+                )
+        if not found:
+            return []
+
+        found.sort(key=lambda c: c.score, reverse=True)
+        await self._record(memory, tenant_id, storage, found, now)
+        if self.graph_store is not None:
+            await self._link(memory, candidates, tenant_id, found)
+        logger.info(
+            "contradictions_detected",
+            memory_id=str(memory_id),
+            conflicting_ids=[str(c.conflicting_id) for c in found],
+        )
+        return found
+
+    async def _record(
+        self,
+        memory: dict[str, Any],
+        tenant_id: str,
+        storage: IMemoryStorage,
+        found: list[Contradiction],
+        now: datetime,
+    ) -> None:
+        metadata = dict(memory.get("metadata") or {})
+        recorded = {
+            entry["memory_id"]: entry for entry in metadata.get(CONTRADICTS_KEY, [])
+        }
+        for contradiction in found:
+            recorded[str(contradiction.conflicting_id)] = {
+                "memory_id": str(contradiction.conflicting_id),
+                "score": contradiction.score,
+                "similarity": contradiction.similarity,
+                "detected_at": now.isoformat(),
+            }
+        metadata[CONTRADICTS_KEY] = list(recorded.values())
+        tags = list(memory.get("tags") or [])
+        if CONFLICT_TAG not in tags:
+            tags.append(CONFLICT_TAG)
+        await storage.update_memory(
+            memory["id"], tenant_id, {"metadata": metadata, "tags": tags}
+        )
+
+    async def _link(
+        self,
+        memory: dict[str, Any],
+        candidates: list[Any],
+        tenant_id: str,
+        found: list[Contradiction],
+    ) -> None:
+        assert self.graph_store is not None
+        layers = {str(c.memory["id"]): c.memory.get("layer") for c in candidates}
+        await self.graph_store.create_node(
+            memory["id"],
+            NodeType.MEMORY.value,
+            tenant_id,
+            {"layer": memory.get("layer")},
+        )
+        for contradiction in found:
+            await self.graph_store.create_node(
+                contradiction.conflicting_id,
+                NodeType.MEMORY.value,
+                tenant_id,
+                {"layer": layers.get(str(contradiction.conflicting_id))},
+            )
+            await self.graph_store.create_edge(
+                contradiction.memory_id,
+                contradiction.conflicting_id,
+                EdgeType.CONTRADICTS.value,
+                tenant_id,
+                weight=contradiction.score,
+            )
+
+
+async def list_conflicts(
+    tenant_id: str, storage: IMemoryStorage, limit: int = 100
+) -> list[Contradiction]:
+    """Contradictions recorded for a tenant, most recently detected first.
+
+    Pairs whose conflicting memory has since been deleted are left out, so
+    deleting either side resolves a conflict.
+    """
+    flagged = await storage.list_memories(tenant_id, tags=[CONFLICT_TAG], limit=limit)
+    entries = [
+        (memory, entry)
+        for memory in flagged
+        for entry in (memory.get("metadata") or {}).get(CONTRADICTS_KEY, [])
+    ]
+    if not entries:
+        return []
+    others = await storage.get_memories_batch(
+        [UUID(entry["memory_id"]) for _, entry in entries], tenant_id
+    )
+    live = {str(m["id"]) for m in others if not m.get("deleted_at")}
+
+    conflicts = [
+        Contradiction(
+            memory_id=memory["id"],
+            conflicting_id=UUID(entry["memory_id"]),
+            score=entry["score"],
+            similarity=entry.get("similarity"),
+            detected_at=entry.get("detected_at"),
+        )
+        for memory, entry in entries
+        if entry["memory_id"] in live
+    ]
+    conflicts.sort(
+        key=lambda c: c.detected_at or datetime.min.replace(tzinfo=timezone.utc),
+        reverse=True,
+    )
+    return conflicts[:limit]
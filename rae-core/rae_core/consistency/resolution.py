@@ -0,0 +1,206 @@
+"""
+RAE Belief Revision.
+Resolves a pair of memories flagged by the ContradictionDetector. With
+"supersede" the first memory wins: the second is marked superseded_by it
+and loses most of its importance, and the winner keeps the higher of the
+two importances. With "merge" both are superseded by a merged memory the
+caller has already stored. With "keep_both" the two simply stop counting
+as a conflict. In every case the pair leaves list_conflicts and its
+"contradicts" edges are replaced; superseded memories are linked to their
+successor by "superseded_by" edges and left out of default recall.
+"""
+
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.config.defaults import DEFAULT_SUPERSEDED_IMPORTANCE_FACTOR
+from rae_core.consistency.contradiction import CONFLICT_TAG, CONTRADICTS_KEY
+from rae_core.exceptions.base import NotFoundError, ValidationError
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.consistency import (
+    SUPERSEDED_KEY,
+    ConflictResolution,
+    ResolutionResult,
+)
+from rae_core.models.graph import EdgeType, NodeType
+
+logger = structlog.get_logger(__name__)
+
+SUPERSEDED_TAG = "superseded"
+
+
+async def resolve_conflict(
+    memory_a: UUID,
+    memory_b: UUID,
+    resolution: ConflictResolution | str,
+    tenant_id: str,
+    storage: IMemoryStorage,
+    graph_store: IGraphStore | None = None,
+    merged_id: UUID | None = None,
+    superseded_importance: float = DEFAULT_SUPERSEDED_IMPORTANCE_FACTOR,
+) -> ResolutionResult:
+    """Resolve a conflict between two memories.
+
+    Args:
+        memory_a: Memory kept by "supersede"
+        memory_b: Memory superseded by memory_a with "supersede"
+        resolution: supersede, merge or keep_both
+        tenant_id: Tenant owning both memories
+        storage: Storage holding the memories
+        graph_store: Graph store whose edges are updated (none if None)
+        merged_id: Stored merged memory; required by "merge"
+        superseded_importance: Factor applied to a superseded memory's
+            importance
+
+    Raises:
+        NotFoundError: A memory does not exist for the tenant
+        ValidationError: The memories are the same, or "merge" lacks a
+            merged_id
+    """
+    resolution = ConflictResolution(resolution)
+    if memory_a == memory_b:
+        raise ValidationError("A memory cannot conflict with itself")
+    if resolution is ConflictResolution.MERGE and merged_id is None:
+        raise ValidationError("Merging a conflict needs the merged memory's id")
+
+    memories: dict[UUID, dict[str, Any]] = {}
+    for memory_id in (memory_a, memory_b, merged_id):
+        if memory_id is None:
+            continue
+        memory = await storage.get_memory(memory_id, tenant_id)
+        if memory is None:
+            raise NotFoundError("Memory", memory_id, tenant_id)
+        memories[memory_id] = memory
+
+    # Conflict bookkeeping of both sides is cleared before anything else
+    updates: dict[UUID, dict[str, Any]] = {
+        memory_id: _without_conflict(memories[memory_id], other)
+        for memory_id, other in ((memory_a, memory_b), (memory_b, memory_a))
+    }
+    result = ResolutionResult(resolution=resolution)
+    if resolution is ConflictResolution.SUPERSEDE:
+        winner, losers = memory_a, [memory_b]
+    elif resolution is ConflictResolution.MERGE:
+        assert merged_id is not None
+        winner, losers = merged_id, [memory_a, memory_b]
+        result.merged_id = merged_id
+    else:
+        winner, losers = None, []
+
+    now = datetime.now(timezone.utc)
+    if winner is not None:
+        importance = max(_importance(memories[m]) for m in (winner, *losers))
+        updates.setdefault(winner, {})["importance"] = importance
+        for loser in losers:
+            update = updates[loser]
+            update["metadata"] = {
+                **update["metadata"],
+                SUPERSEDED_KEY: str(winner),
+                "superseded_at": now.isoformat(),
+            }
+            if SUPERSEDED_TAG not in update["tags"]:
+                update["tags"] = [*update["tags"], SUPERSEDED_TAG]
+            update["importance"] = (
+                _importance(memories[loser]) * superseded_importance
+            )
+    result.kept_ids = [m for m in memories if m not in losers]
+    result.superseded_ids = losers
+
+    for memory_id, update in updates.items():
+        await storage.update_memory(memory_id, tenant_id, update)
+    if graph_store is not None:
+        await _relink(
+            graph_store, tenant_id, memories, (memory_a, memory_b), winner, losers
+        )
+
+    logger.info(
+        "conflict_resolved",
+        resolution=resolution.value,
+        memory_a=str(memory_a),
+        memory_b=str(memory_b),
+        merged_id=str(merged_id) if merged_id else None,
+    )
+    return result
+
+
+def merged_fields(
+    memories: list[dict[str, Any]], content: str | None = None
+) -> dict[str, Any]:
+    """store_memory arguments of the memory merging a conflicting pair.
+
+    The merged memory takes the first memory's agent, layer and project,
+    the union of the tags and the higher importance; content defaults to
+    both contents, one per line.
+    """
+    first = memories[0]
+    tags: list[str] = []
+    for memory in memories:
+        for tag in memory.get("tags") or []:
+            if tag not in tags and tag not in (CONFLICT_TAG, SUPERSEDED_TAG):
+                tags.append(tag)
+    fields = {
+        "content": content
+        or "\n".join(str(m.get("content") or "") for m in memories),
+        "tags": tags,
+        "importance": max(_importance(m) for m in memories),
+        "metadata": {"merged_from": [str(m["id"]) for m in memories]},
+    }
+    for key in ("agent_id", "layer", "project", "session_id"):
+        value = first.get(key)
+        if value is not None:
+            fields[key] = getattr(value, "value", value)
+    return fields
+
+
+def _importance(memory: dict[str, Any]) -> float:
+    importance = memory.get("importance")
+    return 0.5 if importance is None else float(importance)
+
+
+def _without_conflict(memory: dict[str, Any], other: UUID) -> dict[str, Any]:
+    """Metadata and tags of a memory with its conflict with other removed."""
+    metadata = dict(memory.get("metadata") or {})
+    remaining = [
+        entry
+        for entry in metadata.get(CONTRADICTS_KEY, [])
+        if entry["memory_id"] != str(other)
+    ]
+    if remaining:
+        metadata[CONTRADICTS_KEY] = remaining
+    else:
+        metadata.pop(CONTRADICTS_KEY, None)
+    tags = list(memory.get("tags") or [])
+    if not remaining and CONFLICT_TAG in tags:
+        tags.remove(CONFLICT_TAG)
+    return {"metadata": metadata, "tags": tags}
+
+
+async def _relink(
+    graph_store: IGraphStore,
+    tenant_id: str,
+    memories: dict[UUID, dict[str, Any]],
+    pair: tuple[UUID, UUID],
+    winner: UUID | None,
+    losers: list[UUID],
+) -> None:
+    for source, target in (pair, pair[::-1]):
+        await graph_store.delete_edge(
+            source, target, EdgeType.CONTRADICTS.value, tenant_id
+        )
+    if winner is None:
+        return
+    for memory_id in (winner, *losers):
+        await graph_store.create_node(
+            memory_id,
+            NodeType.MEMORY.value,
+            tenant_id,
+            {"layer": memories[memory_id].get("layer")},
+        )
+    for loser in losers:
+        await graph_store.create_edge(
+            loser, winner, EdgeType.SUPERSEDED_BY.value, tenant_id
+        )
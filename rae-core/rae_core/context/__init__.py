@@ -1,11 +1,13 @@
 """Context management for RAE-core."""
 
 from rae_core.context.builder import ContextBuilder, ContextFormat
+from rae_core.context.cards import MemoryCardRenderer
 from rae_core.context.window import ContextWindowManager, estimate_tokens
 
 __all__ = [
     "ContextBuilder",
     "ContextFormat",
     "ContextWindowManager",
+    "MemoryCardRenderer",
     "estimate_tokens",
 ]
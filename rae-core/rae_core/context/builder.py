@@ -5,8 +5,10 @@ from enum import Enum
 from typing import Any
 from uuid import UUID
 
+from rae_core.config.defaults import DEFAULT_LOW_CONFIDENCE_THRESHOLD
 from rae_core.context.window import ContextWindowManager, estimate_tokens
 from rae_core.models.context import ContextMetadata, WorkingContext
+from rae_core.models.memory import memory_confidence
 
 
 class ContextFormat(str, Enum):
@@ -28,21 +30,26 @@ class ContextBuilder:
     - Multiple formatting templates
     - Automatic truncation on overflow
     - Metadata preservation
+    - Uncertain (low-confidence) memories flagged or left out
     """
 
     def __init__(
         self,
         max_tokens: int = 4096,
         default_format: ContextFormat = ContextFormat.CONVERSATIONAL,
+        low_confidence_threshold: float = DEFAULT_LOW_CONFIDENCE_THRESHOLD,
     ):
         """Initialize context builder.
 
         Args:
             max_tokens: Maximum tokens for assembled context
             default_format: Default context format template
+            low_confidence_threshold: Confidence below which a memory is
+                marked as uncertain in the context
         """
         self.max_tokens = max_tokens
         self.default_format = default_format
+        self.low_confidence_threshold = low_confidence_threshold
         self.window_manager = ContextWindowManager(max_tokens=max_tokens)
 
     def build_context(
@@ -52,12 +59,23 @@ class ContextBuilder:
         format_type: ContextFormat | None = None,
         max_memories: int | None = None,
         include_metadata: bool = True,
+        min_confidence: float | None = None,
     ) -> tuple[str, ContextMetadata]:
-        """Build context from search results."""
+        """Build context from search results.
+
+        Memories with a confidence below min_confidence are left out; those
+        kept but below low_confidence_threshold are marked as uncertain.
+        """
         format_type = format_type or self.default_format
 
+        candidates = memories
+        if min_confidence is not None:
+            candidates = [
+                m for m in memories if memory_confidence(m) >= min_confidence
+            ]
+
         # Rank memories by priority
-        ranked_memories = self._rank_memories(memories)
+        ranked_memories = self._rank_memories(candidates)
 
         # Apply max_memories limit if specified
         if max_memories:
@@ -116,6 +134,10 @@ class ContextBuilder:
                 "truncated": len(included_memories) < len(memories),
                 "query_provided": query is not None,
                 "avg_tokens_per_memory": avg_tokens,
+                "low_confidence_dropped": len(memories) - len(candidates),
+                "uncertain_items": sum(
+                    1 for m in included_memories if self._is_uncertain(m)
+                ),
             },
         )
 
@@ -234,31 +256,49 @@ class ContextBuilder:
         memory_id = memory.get("id", "unknown")
         importance = memory.get("importance", 0.0)
         tags = memory.get("tags", [])
+        confidence = memory_confidence(memory)
+        uncertain = self._is_uncertain(memory)
+        confidence_note = f"{confidence:.2f}"
+        if uncertain:
+            confidence_note += " (uncertain)"
 
         if format_type == ContextFormat.MINIMAL:
-            return f"{content}\n"
+            return f"[Uncertain] {content}\n" if uncertain else f"{content}\n"
         elif format_type == ContextFormat.STRUCTURED:
             parts = [f"## Memory {memory_id}", f"{content}"]
             if include_metadata:
                 parts.append(f"Importance: {importance:.2f}")
+                if confidence < 1.0:
+                    parts.append(f"Confidence: {confidence_note}")
                 if tags:
                     parts.append(f"Tags: {', '.join(tags)}")
+            elif uncertain:
+                parts.append("Uncertain")
             return "\n".join(parts) + "\n\n"
         elif format_type == ContextFormat.DETAILED:
             parts = [f"### Memory: {memory_id}", f"**Content:** {content}"]
             if include_metadata:
                 parts.append(f"**Importance:** {importance:.2f}")
+                if confidence < 1.0:
+                    parts.append(f"**Confidence:** {confidence_note}")
                 if tags:
                     parts.append(f"**Tags:** {', '.join(tags)}")
                 if memory.get("created_at"):
                     parts.append(f"**Created:** {memory.get('created_at')}")
+            elif uncertain:
+                parts.append("**Uncertain**")
             return "\n".join(parts) + "\n\n"
         else:  # CONVERSATIONAL
             prefix = ""
-            if importance > 0.8:
+            if uncertain:
+                prefix = "[Uncertain] "
+            elif importance > 0.8:
                 prefix = "[Important] "
             return f"- {prefix}{content}\n"
 
+    def _is_uncertain(self, memory: dict[str, Any]) -> bool:
+        return memory_confidence(memory) < self.low_confidence_threshold
+
     def _assemble_context(self, parts: list[str], format_type: ContextFormat) -> str:
         return "\n".join(parts)
 
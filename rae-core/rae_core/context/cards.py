@@ -0,0 +1,156 @@
+"""Memory cards: memories rendered for the people they are about.
+
+A MemoryCard answers "what do you know about me?" for one memory: a short
+title and summary written by the summarizer, the memories it was derived
+from, where it came from, how confident the system is and when it was
+learned and last used. Cards are plain JSON via model_dump(mode="json").
+
+Lineage is read from the metadata the layers record: source_memory_ids
+(reflections), derived_from_episodic (long-term consolidation) and the
+derived_from list of the structured source (metadata["origin"]).
+Annotations are the strings under metadata["annotations"].
+"""
+
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.interfaces.llm import ILLMProvider
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.llm.fallback import NoLLMFallback
+from rae_core.models.card import CardSource, MemoryCard
+from rae_core.models.memory import MemoryItem
+from rae_core.models.provenance import ORIGIN_KEY, MemorySource
+
+logger = structlog.get_logger(__name__)
+
+LINEAGE_KEYS = ("source_memory_ids", "derived_from_episodic")
+ANNOTATIONS_KEY = "annotations"
+
+
+def _clip(text: str, length: int) -> str:
+    text = " ".join(text.split())
+    if len(text) <= length:
+        return text
+    return text[: length - 1].rstrip() + "…"
+
+
+def lineage_ids(metadata: dict[str, Any]) -> list[UUID]:
+    """Ids of the memories a memory was derived from, in recorded order."""
+    ids: list[UUID] = []
+    values = [metadata.get(key) for key in LINEAGE_KEYS]
+    origin = metadata.get(ORIGIN_KEY)
+    if isinstance(origin, dict):
+        values.append(origin.get("derived_from"))
+    for value in values:
+        for raw in value if isinstance(value, list) else [value]:
+            if raw is None:
+                continue
+            try:
+                memory_id = raw if isinstance(raw, UUID) else UUID(str(raw))
+            except ValueError:
+                continue
+            if memory_id not in ids:
+                ids.append(memory_id)
+    return ids
+
+
+class MemoryCardRenderer:
+    """Renders memories as MemoryCards."""
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        summarizer: ILLMProvider | None = None,
+        title_length: int = 60,
+        summary_length: int = 280,
+        excerpt_length: int = 120,
+    ):
+        """Initialize the renderer.
+
+        Args:
+            storage: Storage the lineage is read from
+            summarizer: Provider writing titles and summaries (extractive
+                NoLLMFallback if None)
+            title_length: Maximum title length in characters
+            summary_length: Maximum summary length in characters
+            excerpt_length: Maximum source excerpt length in characters
+        """
+        self.storage = storage
+        self.summarizer = summarizer or NoLLMFallback()
+        self.title_length = title_length
+        self.summary_length = summary_length
+        self.excerpt_length = excerpt_length
+
+    async def render(
+        self, memory: dict[str, Any] | MemoryItem, tenant_id: str
+    ) -> MemoryCard:
+        """Card for one memory (a storage record or a MemoryItem)."""
+        if isinstance(memory, MemoryItem):
+            memory = memory.model_dump(mode="json")
+        metadata = memory.get("metadata") or {}
+        content = memory.get("content") or ""
+
+        sources = await self._sources(metadata, tenant_id)
+        annotations = metadata.get(ANNOTATIONS_KEY) or []
+        confidence = metadata.get("confidence")
+        origin = MemorySource.from_record(memory)
+        return MemoryCard(
+            memory_id=memory["id"],
+            title=await self._summarize(content, self.title_length),
+            summary=await self._summarize(content, self.summary_length),
+            layer=str(memory.get("layer", "")),
+            tags=list(memory.get("tags") or []),
+            annotations=[str(a) for a in annotations],
+            sources=sources,
+            origin=origin.label if origin is not None else None,
+            confidence=float(confidence) if confidence is not None else None,
+            created_at=memory.get("created_at"),
+            updated_at=memory.get("modified_at") or memory.get("updated_at"),
+            last_accessed_at=memory.get("last_accessed_at"),
+            expires_at=memory.get("expires_at"),
+        )
+
+    async def render_many(
+        self, memories: list[dict[str, Any]] | list[MemoryItem], tenant_id: str
+    ) -> list[MemoryCard]:
+        return [await self.render(memory, tenant_id) for memory in memories]
+
+    async def _summarize(self, content: str, length: int) -> str:
+        """Content if it fits, else the summarizer's summary of it."""
+        if len(content) <= length:
+            return _clip(content, length)
+        try:
+            summary = await self.summarizer.summarize(content, max_length=length)
+        except Exception as e:
+            logger.warning("memory_card_summary_failed", error=str(e))
+            summary = ""
+        # Extractive summaries are empty when the first sentence is too long
+        return _clip(summary.strip() or content, length)
+
+    async def _sources(
+        self, metadata: dict[str, Any], tenant_id: str
+    ) -> list[CardSource]:
+        ids = lineage_ids(metadata)
+        if not ids:
+            return []
+        found = {
+            UUID(str(m["id"])): m
+            for m in await self.storage.get_memories_batch(ids, tenant_id)
+        }
+        # Sources deleted since are left out rather than shown as missing
+        sources = []
+        for memory_id in ids:
+            source = found.get(memory_id)
+            if source is None:
+                continue
+            sources.append(
+                CardSource(
+                    memory_id=memory_id,
+                    layer=source.get("layer"),
+                    excerpt=_clip(source.get("content") or "", self.excerpt_length),
+                    created_at=source.get("created_at"),
+                )
+            )
+        return sources
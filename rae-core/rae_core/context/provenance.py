@@ -0,0 +1,96 @@
+"""Provenance tracing: which memories a derived memory came from.
+
+Reflections, summaries and merges record the memories they were built
+from (see cards.lineage_ids); with a graph store the same links exist as
+"derived_from" edges. trace_origins follows them back, level by level,
+and reports every ancestor with its structured source, so a reflection
+can be traced to the user messages, tool outputs and imports beneath it.
+"""
+
+from typing import Any
+from uuid import UUID
+
+from rae_core.context.cards import lineage_ids
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.graph import EdgeType
+from rae_core.models.provenance import MemorySource, OriginStep
+
+
+async def trace_origins(
+    memory_id: UUID,
+    tenant_id: str,
+    storage: IMemoryStorage,
+    graph_store: IGraphStore | None = None,
+    max_depth: int = 10,
+) -> list[OriginStep]:
+    """Ancestors of a memory, nearest first.
+
+    Every ancestor is reported once, at the depth it is first reached;
+    links are read from the memories' metadata and, with a graph store,
+    from their outgoing "derived_from" edges. Ancestors are fetched one
+    batch per level.
+    """
+    records = await storage.get_memories_batch([memory_id], tenant_id)
+    frontier: list[tuple[UUID, dict[str, Any]]] = [
+        (memory_id, record) for record in records
+    ]
+    seen = {memory_id}
+    steps: dict[UUID, OriginStep] = {}
+    for depth in range(1, max_depth + 1):
+        links: list[tuple[UUID, UUID]] = []
+        for child, record in frontier:
+            parents = await _parents(child, record, tenant_id, graph_store)
+            if not parents and child in steps:
+                steps[child].root = True
+            for parent in parents:
+                if parent not in seen:
+                    seen.add(parent)
+                    links.append((parent, child))
+        if not links:
+            break
+
+        found = await storage.get_memories_batch([p for p, _ in links], tenant_id)
+        by_id = {str(record["id"]): record for record in found}
+        frontier = []
+        for parent, child in links:
+            record = by_id.get(str(parent))
+            steps[parent] = _step(parent, child, depth, record)
+            if record is not None:
+                frontier.append((parent, record))
+    return list(steps.values())
+
+
+async def _parents(
+    memory_id: UUID,
+    record: dict[str, Any],
+    tenant_id: str,
+    graph_store: IGraphStore | None,
+) -> list[UUID]:
+    parents = lineage_ids(record.get("metadata") or {})
+    if graph_store is not None:
+        for parent in await graph_store.get_neighbors(
+            memory_id,
+            tenant_id,
+            edge_type=EdgeType.DERIVED_FROM.value,
+            direction="out",
+        ):
+            if parent not in parents:
+                parents.append(parent)
+    return parents
+
+
+def _step(
+    memory_id: UUID, child: UUID, depth: int, record: dict[str, Any] | None
+) -> OriginStep:
+    if record is None:
+        return OriginStep(
+            memory_id=memory_id, depth=depth, derived_for=child, missing=True
+        )
+    return OriginStep(
+        memory_id=memory_id,
+        depth=depth,
+        derived_for=child,
+        source=MemorySource.from_record(record),
+        content=record.get("content"),
+    )
@@ -0,0 +1,109 @@
+"""CLIP image and text embeddings.
+
+A CLIP model embeds images and text into one vector space, so image
+memories stored with RAEEngine.store_image can be recalled by text queries.
+Register the provider with an EmbeddingManager (or use it as the engine's
+only provider) so its vector space is searched by recall.
+
+Requires sentence-transformers and pillow unless a model is given:
+pip install rae-core[multimodal]
+"""
+
+import asyncio
+import io
+from typing import Any
+
+from rae_core.exceptions.base import InfrastructureError, ValidationError
+from rae_core.interfaces.embedding import IMultimodalEmbeddingProvider
+
+
+class ClipEmbeddingProvider(IMultimodalEmbeddingProvider):
+    """Multimodal provider backed by a sentence-transformers CLIP model.
+
+    The model is loaded on first use; encoding runs in a worker thread.
+    """
+
+    def __init__(
+        self,
+        model_name: str = "clip-ViT-B-32",
+        model: Any = None,
+        device: str | None = None,
+    ):
+        """Initialize CLIP provider.
+
+        Args:
+            model_name: sentence-transformers CLIP model to load
+            model: Loaded SentenceTransformer-compatible model to use
+            device: Torch device of the loaded model (auto if None)
+        """
+        self.model_name = model_name
+        self.device = device
+        self._model = model
+        self._dimension: int | None = None
+
+    def _get_model(self) -> Any:
+        if self._model is None:
+            try:
+                from sentence_transformers import SentenceTransformer
+            except ImportError as e:
+                raise ImportError(
+                    "sentence-transformers is required for CLIP embeddings. "
+                    "Install with: pip install rae-core[multimodal]"
+                ) from e
+            self._model = SentenceTransformer(self.model_name, device=self.device)
+        return self._model
+
+    async def _encode(self, inputs: list[Any]) -> list[list[float]]:
+        model = self._get_model()
+        try:
+            vectors = await asyncio.to_thread(
+                model.encode, inputs, normalize_embeddings=True
+            )
+        except Exception as e:
+            raise InfrastructureError(f"CLIP encoding failed: {e}") from e
+        return [[float(x) for x in vector] for vector in vectors]
+
+    async def embed_text(
+        self, text: str, task_type: str = "search_document"
+    ) -> list[float]:
+        """Generate embedding for text (queries and captions alike)."""
+        return (await self._encode([text]))[0]
+
+    async def embed_batch(
+        self, texts: list[str], task_type: str = "search_document"
+    ) -> list[list[float]]:
+        """Generate embeddings for multiple texts."""
+        if not texts:
+            return []
+        return await self._encode(list(texts))
+
+    async def embed_image(self, data: bytes) -> list[float]:
+        """Generate embedding for an encoded image.
+
+        Raises:
+            ValidationError: The bytes are not a readable image
+        """
+        try:
+            from PIL import Image
+        except ImportError as e:
+            raise ImportError(
+                "pillow is required for CLIP image embeddings. "
+                "Install with: pip install rae-core[multimodal]"
+            ) from e
+        try:
+            image = Image.open(io.BytesIO(data))
+            image.load()
+        except Exception as e:
+            raise ValidationError(f"Unreadable image: {e}") from e
+        return (await self._encode([image.convert("RGB")]))[0]
+
+    def get_dimension(self) -> int:
+        """Get embedding dimension."""
+        if self._dimension is None:
+            model = self._get_model()
+            dimension = model.get_sentence_embedding_dimension()
+            if not dimension:
+                # CLIP models may not report it; probe with a short text
+                dimension = len(model.encode(["dimension"])[0])
+            self._dimension = int(dimension)
+        return self._dimension
@@ -0,0 +1,183 @@
+"""Vector dimension registry per tenant and embedding model.
+
+A vector store scores whatever it is given, so a query or memory embedded
+by a model of another size yields meaningless similarities instead of an
+error. DimensionRegistry records the dimension of each tenant's collection
+(embedding model) and DimensionCheckedVectorStore checks every stored and
+searched vector against it:
+
+    registry = DimensionRegistry()
+    vector_store = DimensionCheckedVectorStore(InMemoryStorage(), registry)
+
+A collection's dimension is registered explicitly (register) or by its
+first stored vector. Searching a collection without a dimension is allowed.
+"""
+
+from collections.abc import Iterable
+from typing import Any
+from uuid import UUID
+
+from rae_core.exceptions.base import DimensionMismatchError, ValidationError
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.embedding import EmbeddingSchema
+
+# Collection of plain (unnamed) vectors
+DEFAULT_MODEL = "default"
+
+
+def vector_dimensions(
+    embedding: list[float] | dict[str, list[float]],
+) -> dict[str, int]:
+    """Dimension of each collection an embedding is stored in."""
+    if isinstance(embedding, dict):
+        return {model: len(vector) for model, vector in embedding.items()}
+    return {DEFAULT_MODEL: len(embedding)}
+
+
+class DimensionRegistry:
+    """Dimensions of the tenants' vector collections."""
+
+    def __init__(self, schemas: Iterable[EmbeddingSchema] = ()):
+        """Initialize registry.
+
+        Args:
+            schemas: Previously registered dimensions (e.g. loaded from
+                configuration)
+        """
+        self._schemas: dict[tuple[str, str], EmbeddingSchema] = {
+            (schema.tenant_id, schema.model): schema for schema in schemas
+        }
+
+    def get(self, tenant_id: str, model: str = DEFAULT_MODEL) -> EmbeddingSchema | None:
+        return self._schemas.get((tenant_id, model))
+
+    def list_schemas(self, tenant_id: str) -> list[EmbeddingSchema]:
+        """The tenant's registered collections, by model name."""
+        schemas = [s for (t, _), s in self._schemas.items() if t == tenant_id]
+        return sorted(schemas, key=lambda s: s.model)
+
+    def check(self, tenant_id: str, model: str, dimension: int) -> None:
+        """Raise DimensionMismatchError unless dimension fits the collection."""
+        schema = self._schemas.get((tenant_id, model))
+        if schema is not None and schema.dimension != dimension:
+            raise DimensionMismatchError(tenant_id, model, schema.dimension, dimension)
+
+    def register(self, tenant_id: str, model: str, dimension: int) -> EmbeddingSchema:
+        """Record a collection's dimension (a no-op if already registered).
+
+        Raises:
+            ValidationError: dimension is not positive
+            DimensionMismatchError: The collection has another dimension
+        """
+        if dimension < 1:
+            raise ValidationError(f"Invalid embedding dimension: {dimension}")
+        self.check(tenant_id, model, dimension)
+        schema = self._schemas.get((tenant_id, model))
+        if schema is None:
+            schema = EmbeddingSchema(
+                tenant_id=tenant_id, model=model, dimension=dimension
+            )
+            self._schemas[(tenant_id, model)] = schema
+        return schema
+
+    def unregister(self, tenant_id: str, model: str | None = None) -> int:
+        """Forget one (or, without model, all) of a tenant's collections.
+
+        Do so after deleting the collection's vectors, so it can be
+        re-embedded with a model of another size.
+
+        Returns:
+            Number of collections forgotten
+        """
+        keys = [
+            key
+            for key in self._schemas
+            if key[0] == tenant_id and (model is None or key[1] == model)
+        ]
+        for key in keys:
+            del self._schemas[key]
+        return len(keys)
+
+    def check_embedding(
+        self, tenant_id: str, embedding: list[float] | dict[str, list[float]]
+    ) -> None:
+        """Check every vector of an embedding, then register new collections."""
+        dimensions = vector_dimensions(embedding)
+        for model, dimension in dimensions.items():
+            self.check(tenant_id, model, dimension)
+        for model, dimension in dimensions.items():
+            self.register(tenant_id, model, dimension)
+
+
+class DimensionCheckedVectorStore:
+    """IVectorStore wrapper checking vector sizes against a DimensionRegistry.
+
+    Stores raise DimensionMismatchError before anything is written (a batch
+    is checked as a whole); searches raise it for a query of the wrong size.
+    Other methods are passed through to the wrapped store.
+    """
+
+    def __init__(self, vector_store: IVectorStore, registry: DimensionRegistry):
+        self.vector_store = vector_store
+        self.registry = registry
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self.vector_store, name)
+
+    async def store_vector(
+        self,
+        memory_id: UUID,
+        embedding: list[float] | dict[str, list[float]],
+        tenant_id: str,
+        metadata: dict[str, Any] | None = None,
+    ) -> bool:
+        self.registry.check_embedding(tenant_id, embedding)
+        return await self.vector_store.store_vector(
+            memory_id, embedding, tenant_id, metadata
+        )
+
+    async def update_vector(
+        self,
+        memory_id: UUID,
+        embedding: list[float] | dict[str, list[float]],
+        tenant_id: str,
+        metadata: dict[str, Any] | None = None,
+    ) -> bool:
+        self.registry.check_embedding(tenant_id, embedding)
+        return await self.vector_store.update_vector(
+            memory_id, embedding, tenant_id, metadata
+        )
+
+    async def batch_store_vectors(
+        self,
+        vectors: list[
+            tuple[UUID, list[float] | dict[str, list[float]], dict[str, Any]]
+        ],
+        tenant_id: str,
+    ) -> int:
+        # Vectors of one batch must also agree with each other
+        dimensions: dict[str, int] = {}
+        for _, embedding, _ in vectors:
+            for model, dimension in vector_dimensions(embedding).items():
+                self.registry.check(tenant_id, model, dimension)
+                expected = dimensions.setdefault(model, dimension)
+                if dimension != expected:
+                    raise DimensionMismatchError(tenant_id, model, expected, dimension)
+        for model, dimension in dimensions.items():
+            self.registry.register(tenant_id, model, dimension)
+        return await self.vector_store.batch_store_vectors(vectors, tenant_id)
+
+    async def search_similar(
+        self,
+        query_embedding: list[float],
+        tenant_id: str,
+        *args: Any,
+        vector_name: str | None = None,
+        **kwargs: Any,
+    ) -> list[tuple[UUID, float]]:
+        # model_name is the legacy spelling of vector_name
+        model = vector_name or kwargs.get("model_name") or DEFAULT_MODEL
+        self.registry.check(tenant_id, model, len(query_embedding))
+        return await self.vector_store.search_similar(
+            query_embedding, tenant_id, *args, vector_name=vector_name, **kwargs
+        )
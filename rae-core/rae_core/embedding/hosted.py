@@ -0,0 +1,204 @@
+"""Hosted embedding APIs (Cohere, Voyage AI).
+
+Both APIs embed many texts per request and distinguish queries from
+documents, which maps onto the task_type of IEmbeddingProvider. Batches
+larger than the API limit are split into several requests.
+
+Requires httpx unless a client is given: pip install rae-core[embeddings]
+"""
+
+import os
+from typing import Any
+
+from rae_core.exceptions.base import InfrastructureError
+from rae_core.interfaces.embedding import IEmbeddingProvider
+
+
+class _HostedEmbeddingProvider(IEmbeddingProvider):
+    """Shared request, batching and dimension handling."""
+
+    service = ""
+    api_key_env = ""
+    default_url = ""
+    max_batch = 96
+    # Output dimension of well-known models
+    known_dimensions: dict[str, int] = {}
+
+    def __init__(
+        self,
+        model: str,
+        api_key: str | None = None,
+        base_url: str | None = None,
+        dimension: int | None = None,
+        timeout: float = 60.0,
+        client: Any = None,
+    ):
+        """Initialize provider.
+
+        Args:
+            model: Embedding model name
+            api_key: API key (read from the service's environment variable
+                when omitted)
+            base_url: API endpoint override (e.g. a proxy)
+            dimension: Expected dimension; known models need none, others
+                are detected on first call
+            timeout: Request timeout in seconds
+            client: httpx.AsyncClient-compatible client to use
+        """
+        self.model = model
+        self.api_key = api_key or os.environ.get(self.api_key_env)
+        if not self.api_key:
+            raise ValueError(
+                f"{self.service} API key missing: pass api_key or set "
+                f"{self.api_key_env}"
+            )
+        self.base_url = (base_url or self.default_url).rstrip("/")
+        self.timeout = timeout
+        self._dimension = dimension or self.known_dimensions.get(model)
+        self._client = client
+
+    def _get_client(self) -> Any:
+        if self._client is None:
+            import httpx
+
+            self._client = httpx.AsyncClient(timeout=self.timeout)
+        return self._client
+
+    def _request(self, texts: list[str], task_type: str) -> tuple[str, dict[str, Any]]:
+        """Endpoint path and JSON body embedding texts."""
+        raise NotImplementedError
+
+    def _parse(self, body: dict[str, Any]) -> list[list[float]]:
+        """Embeddings in input order from a response body."""
+        raise NotImplementedError
+
+    async def embed_text(
+        self, text: str, task_type: str = "search_document"
+    ) -> list[float]:
+        """Generate embedding for text."""
+        return (await self.embed_batch([text], task_type))[0]
+
+    async def embed_batch(
+        self, texts: list[str], task_type: str = "search_document"
+    ) -> list[list[float]]:
+        """Generate embeddings, max_batch texts per request."""
+        embeddings: list[list[float]] = []
+        for start in range(0, len(texts), self.max_batch):
+            chunk = texts[start : start + self.max_batch]
+            path, payload = self._request(chunk, task_type)
+            try:
+                response = await self._get_client().post(
+                    f"{self.base_url}{path}",
+                    json=payload,
+                    headers={"Authorization": f"Bearer {self.api_key}"},
+                )
+                response.raise_for_status()
+                vectors = self._parse(response.json())
+            except Exception as e:
+                raise InfrastructureError(
+                    f"{self.service} embedding request failed: {e}"
+                ) from e
+            if len(vectors) != len(chunk):
+                raise InfrastructureError(
+                    f"{self.service} returned {len(vectors)} embeddings "
+                    f"for {len(chunk)} texts"
+                )
+            for vector in vectors:
+                self._check_dimension(vector)
+            embeddings.extend([float(x) for x in v] for v in vectors)
+        return embeddings
+
+    def _check_dimension(self, vector: list[float]) -> None:
+        if self._dimension is None:
+            self._dimension = len(vector)
+        elif len(vector) != self._dimension:
+            raise InfrastructureError(
+                f"{self.service} model {self.model} returned dimension "
+                f"{len(vector)}, expected {self._dimension}"
+            )
+
+    def get_dimension(self) -> int:
+        """Return embedding dimension.
+
+        Raises:
+            RuntimeError: If the model is unknown and nothing was embedded yet
+        """
+        if self._dimension is None:
+            raise RuntimeError(
+                f"Embedding dimension of {self.model} unknown until the first "
+                "call; pass dimension="
+            )
+        return self._dimension
+
+    async def health_check(self) -> bool:
+        """Embed a probe text (one billed API request)."""
+        await self.embed_text("health check", task_type="search_query")
+        return True
+
+    async def close(self) -> None:
+        if self._client is not None:
+            await self._client.aclose()
+            self._client = None
+
+
+class CohereEmbeddingProvider(_HostedEmbeddingProvider):
+    """Embedding provider for the Cohere embed API."""
+
+    service = "Cohere"
+    api_key_env = "COHERE_API_KEY"
+    default_url = "https://api.cohere.com"
+    max_batch = 96
+    known_dimensions = {
+        "embed-english-v3.0": 1024,
+        "embed-multilingual-v3.0": 1024,
+        "embed-english-light-v3.0": 384,
+        "embed-multilingual-light-v3.0": 384,
+    }
+
+    def __init__(self, model: str = "embed-english-v3.0", **kwargs: Any):
+        super().__init__(model, **kwargs)
+
+    def _request(self, texts: list[str], task_type: str) -> tuple[str, dict[str, Any]]:
+        return "/v1/embed", {
+            "model": self.model,
+            "texts": texts,
+            "input_type": (
+                "search_query" if task_type == "search_query" else "search_document"
+            ),
+            "embedding_types": ["float"],
+        }
+
+    def _parse(self, body: dict[str, Any]) -> list[list[float]]:
+        embeddings = body["embeddings"]
+        if isinstance(embeddings, dict):
+            embeddings = embeddings["float"]
+        return list(embeddings)
+
+
+class VoyageEmbeddingProvider(_HostedEmbeddingProvider):
+    """Embedding provider for the Voyage AI embeddings API."""
+
+    service = "Voyage"
+    api_key_env = "VOYAGE_API_KEY"
+    default_url = "https://api.voyageai.com"
+    max_batch = 128
+    known_dimensions = {
+        "voyage-3": 1024,
+        "voyage-3-lite": 512,
+        "voyage-3-large": 1024,
+        "voyage-code-3": 1024,
+    }
+
+    def __init__(self, model: str = "voyage-3", **kwargs: Any):
+        super().__init__(model, **kwargs)
+
+    def _request(self, texts: list[str], task_type: str) -> tuple[str, dict[str, Any]]:
+        return "/v1/embeddings", {
+            "model": self.model,
+            "input": texts,
+            "input_type": "query" if task_type == "search_query" else "document",
+        }
+
+    def _parse(self, body: dict[str, Any]) -> list[list[float]]:
+        data = sorted(body["data"], key=lambda item: item["index"])
+        return [item["embedding"] for item in data]
@@ -1,20 +1,36 @@
-from rae_core.interfaces.embedding import IEmbeddingProvider
+from rae_core.exceptions.base import SecurityPolicyViolationError, ValidationError
+from rae_core.health import components_healthy
+from rae_core.interfaces.embedding import (
+    IEmbeddingProvider,
+    IMultimodalEmbeddingProvider,
+)
+from rae_core.models.tenant import TenantEmbeddingConfig
 
 
 class EmbeddingManager(IEmbeddingProvider):
     """
     Manages multiple embedding providers.
     Implements IEmbeddingProvider to act as a default provider/proxy.
+
+    Tenants can be pinned to a specific registered model (set_tenant_model).
+    Calls that pass tenant_id then use that model, and the dimension of the
+    tenant's collection is recorded and checked on every embedding.
     """
 
     def __init__(
-        self, default_provider: IEmbeddingProvider, default_model_name: str = "default"
+        self,
+        default_provider: IEmbeddingProvider,
+        default_model_name: str = "default",
+        tenant_configs: dict[str, TenantEmbeddingConfig] | None = None,
     ) -> None:
         self.providers: dict[str, IEmbeddingProvider] = {
             default_model_name: default_provider
         }
         self.default_model_name = default_model_name
         self._default_provider = default_provider
+        self._tenant_configs: dict[str, TenantEmbeddingConfig] = dict(
+            tenant_configs or {}
+        )
 
     def register_provider(self, model_name: str, provider: IEmbeddingProvider) -> None:
         """Register a provider for a specific model/profile name."""
@@ -23,30 +39,172 @@ class EmbeddingManager(IEmbeddingProvider):
     def get_provider(self, model_name: str) -> IEmbeddingProvider | None:
         return self.providers.get(model_name)
 
-    # IEmbeddingProvider implementation (delegates to default)
+    def set_default_model(self, model_name: str) -> str:
+        """Make a registered model the default; returns the previous default.
+
+        Existing memories keep only their old embeddings; run an
+        EmbeddingMigration for the new model to backfill them.
+        """
+        provider = self.providers.get(model_name)
+        if provider is None:
+            raise ValidationError(f"Unknown embedding model: {model_name}")
+        previous = self.default_model_name
+        self.default_model_name = model_name
+        self._default_provider = provider
+        return previous
+
+    # Tenant model selection
+    def set_tenant_model(
+        self,
+        tenant_id: str,
+        model_name: str,
+        exclusive: bool = True,
+        allow_dimension_change: bool = False,
+    ) -> TenantEmbeddingConfig:
+        """Pin a tenant to a registered model.
+
+        Raises:
+            ValidationError: Model not registered, or its dimension differs
+                from the tenant's existing collection (existing vectors would
+                become unsearchable) and allow_dimension_change is False
+        """
+        provider = self.providers.get(model_name)
+        if provider is None:
+            raise ValidationError(f"Unknown embedding model: {model_name}")
+
+        dimension = provider.get_dimension()
+        current = self._tenant_configs.get(tenant_id)
+        if (
+            current is not None
+            and current.dimension is not None
+            and current.dimension != dimension
+            and not allow_dimension_change
+        ):
+            raise ValidationError(
+                f"Model {model_name} produces {dimension}-d vectors but tenant "
+                f"{tenant_id} has a {current.dimension}-d collection"
+            )
+
+        config = TenantEmbeddingConfig(
+            model_name=model_name, dimension=dimension, exclusive=exclusive
+        )
+        self._tenant_configs[tenant_id] = config
+        return config
+
+    def get_tenant_config(self, tenant_id: str) -> TenantEmbeddingConfig | None:
+        return self._tenant_configs.get(tenant_id)
+
+    def clear_tenant_model(self, tenant_id: str) -> bool:
+        return self._tenant_configs.pop(tenant_id, None) is not None
+
+    def model_for_tenant(self, tenant_id: str | None) -> str:
+        """Model name used for a tenant (the default model if not pinned)."""
+        config = self._tenant_configs.get(tenant_id) if tenant_id else None
+        return config.model_name if config else self.default_model_name
+
+    def allowed_models(self, tenant_id: str | None) -> list[str]:
+        """Models that may embed the tenant's content and queries."""
+        config = self._tenant_configs.get(tenant_id) if tenant_id else None
+        if config is not None and config.exclusive:
+            return [config.model_name]
+        return list(self.providers)
+
+    def provider_for_tenant(self, tenant_id: str | None) -> IEmbeddingProvider:
+        model_name = self.model_for_tenant(tenant_id)
+        provider = self.providers.get(model_name)
+        if provider is None:
+            raise ValidationError(
+                f"Embedding model {model_name} of tenant {tenant_id} is not registered"
+            )
+        return provider
+
+    def _check_dimension(
+        self, tenant_id: str | None, model_name: str, vectors: list[list[float]]
+    ) -> None:
+        """Record or verify the tenant collection dimension."""
+        config = self._tenant_configs.get(tenant_id) if tenant_id else None
+        if config is None or config.model_name != model_name or not vectors:
+            return
+        dimension = len(vectors[0])
+        if config.dimension is None:
+            config.dimension = dimension
+        elif any(len(v) != config.dimension for v in vectors):
+            raise ValidationError(
+                f"Embedding dimension {dimension} does not match the "
+                f"{config.dimension}-d collection of tenant {tenant_id}"
+            )
+
+    # IEmbeddingProvider implementation (delegates to default or tenant model)
     async def embed_text(
-        self, text: str, task_type: str = "search_document"
+        self,
+        text: str,
+        task_type: str = "search_document",
+        tenant_id: str | None = None,
     ) -> list[float]:
-        return await self._default_provider.embed_text(text, task_type=task_type)
+        if tenant_id is None:
+            return await self._default_provider.embed_text(text, task_type=task_type)
+        vectors = await self.embed_batch([text], task_type, tenant_id=tenant_id)
+        return vectors[0]
 
     async def embed_batch(
-        self, texts: list[str], task_type: str = "search_document"
+        self,
+        texts: list[str],
+        task_type: str = "search_document",
+        tenant_id: str | None = None,
     ) -> list[list[float]]:
-        return await self._default_provider.embed_batch(texts, task_type=task_type)
+        if tenant_id is None:
+            return await self._default_provider.embed_batch(texts, task_type=task_type)
+        provider = self.provider_for_tenant(tenant_id)
+        vectors = await provider.embed_batch(texts, task_type=task_type)
+        self._check_dimension(tenant_id, self.model_for_tenant(tenant_id), vectors)
+        return vectors
+
+    def get_dimension(self, tenant_id: str | None = None) -> int:
+        if tenant_id is None:
+            return self._default_provider.get_dimension()
+        config = self._tenant_configs.get(tenant_id)
+        if config is not None and config.dimension is not None:
+            return config.dimension
+        return self.provider_for_tenant(tenant_id).get_dimension()
 
-    def get_dimension(self) -> int:
-        return self._default_provider.get_dimension()
+    async def health_check(self) -> bool:
+        """Check every registered provider."""
+        return await components_healthy(*self.providers.values())
 
     # Manager methods
     async def generate_all_embeddings(
-        self, texts: list[str], task_type: str = "search_document"
+        self,
+        texts: list[str],
+        task_type: str = "search_document",
+        tenant_id: str | None = None,
+        model_names: list[str] | None = None,
     ) -> dict[str, list[list[float]]]:
         """
         Generate embeddings for all registered models.
+        With tenant_id, a tenant pinned exclusively to one model only gets
+        that model's embeddings.
         Returns: Dict[model_name, embeddings_list]
+
+        Raises:
+            SecurityPolicyViolationError: model_names requests a model the
+                tenant is not allowed to use
         """
+        if tenant_id in self._tenant_configs:
+            # Fails loudly if the tenant's model was never registered
+            self.provider_for_tenant(tenant_id)
+        allowed = self.allowed_models(tenant_id)
+        if model_names is not None:
+            forbidden = [m for m in model_names if m not in allowed]
+            if forbidden:
+                raise SecurityPolicyViolationError(
+                    f"Tenant {tenant_id} may not use embedding models {forbidden}"
+                )
+            allowed = list(model_names)
+
         results = {}
         for model_name, provider in self.providers.items():
+            if model_name not in allowed:
+                continue
             # TODO: Add parallelism here using asyncio.gather if providers are async/remote
             # For now, sequential to avoid complexity in initial implementation
             try:
@@ -57,5 +215,30 @@ class EmbeddingManager(IEmbeddingProvider):
                 # For now print/log and skip
                 print(f"Failed to generate embeddings for {model_name}: {e}")
                 results[model_name] = []
+                continue
+            self._check_dimension(tenant_id, model_name, embeddings)
+
+        return results
 
+    async def generate_image_embeddings(
+        self, data: bytes, tenant_id: str | None = None
+    ) -> dict[str, list[float]]:
+        """Embed an image with every multimodal model the tenant may use.
+
+        Returns: Dict[model_name, embedding]
+
+        Raises:
+            ValidationError: No such model is registered
+        """
+        results: dict[str, list[float]] = {}
+        for model_name in self.allowed_models(tenant_id):
+            provider = self.providers.get(model_name)
+            if isinstance(provider, IMultimodalEmbeddingProvider):
+                vector = await provider.embed_image(data)
+                self._check_dimension(tenant_id, model_name, [vector])
+                results[model_name] = vector
+        if not results:
+            raise ValidationError(
+                f"No multimodal embedding model is available to tenant {tenant_id}"
+            )
         return results
@@ -0,0 +1,154 @@
+"""Backfilling embeddings for a new embedding model.
+
+Vector stores keep one embedding per model for each memory. When the
+default model changes, existing memories only have vectors of the old
+model; EmbeddingMigration embeds them with the new one.
+"""
+
+from collections.abc import AsyncIterator
+from typing import Any
+from uuid import UUID
+
+from rae_core.embedding.manager import EmbeddingManager
+from rae_core.interfaces.embedding import IEmbeddingProvider
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.namespace import NAMESPACE_KEY, memory_namespace
+
+# Memory fields copied into vector metadata so vector search filters work
+VECTOR_METADATA_FIELDS = ("layer", "agent_id", "session_id", "project", "tags")
+
+
+def vector_metadata(memory: dict[str, Any]) -> dict[str, Any]:
+    """Metadata stored with a memory's vectors (see VECTOR_METADATA_FIELDS)."""
+    metadata = {
+        field: memory[field]
+        for field in VECTOR_METADATA_FIELDS
+        if memory.get(field) is not None
+    }
+    metadata["metadata"] = memory.get("metadata") or {}
+    metadata[NAMESPACE_KEY] = memory_namespace(memory)
+    return metadata
+
+
+class EmbeddingMigration:
+    """Embeds memories that have no vector for a model yet.
+
+    Safe to re-run: memories that already have the model's vector are
+    skipped, so an interrupted migration resumes where it stopped.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore,
+        provider: IEmbeddingProvider,
+        vector_name: str,
+        batch_size: int = 64,
+    ):
+        """Initialize migration.
+
+        Args:
+            storage: Memory storage to read content from
+            vector_store: Vector store receiving the new vectors
+            provider: Provider of the new model
+            vector_name: Model name the vectors are stored under
+            batch_size: Memories read and embedded per batch
+        """
+        if batch_size < 1:
+            raise ValueError("batch_size must be positive")
+        self.storage = storage
+        self.vector_store = vector_store
+        self.provider = provider
+        self.vector_name = vector_name
+        self.batch_size = batch_size
+
+    @classmethod
+    def for_default_model(
+        cls,
+        manager: EmbeddingManager,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore,
+        batch_size: int = 64,
+    ) -> "EmbeddingMigration":
+        """Migration to the manager's current default model."""
+        return cls(
+            storage,
+            vector_store,
+            manager.providers[manager.default_model_name],
+            manager.default_model_name,
+            batch_size=batch_size,
+        )
+
+    async def _missing(
+        self, memories: list[dict[str, Any]], tenant_id: str
+    ) -> list[dict[str, Any]]:
+        missing = []
+        for memory in memories:
+            # Operational data is never embedded (see RAEEngine.store_memory)
+            if (memory.get("metadata") or {}).get("is_operational"):
+                continue
+            vector = await self.vector_store.get_vector(
+                UUID(str(memory["id"])), tenant_id, vector_name=self.vector_name
+            )
+            if vector is None:
+                missing.append(memory)
+        return missing
+
+    async def scan(
+        self, tenant_id: str
+    ) -> AsyncIterator[tuple[int, list[dict[str, Any]]]]:
+        """Page through a tenant's memories.
+
+        Yields:
+            (memories in the page, those lacking the model's vector)
+        """
+        offset = 0
+        while True:
+            page = await self.storage.list_memories(
+                tenant_id, limit=self.batch_size, offset=offset
+            )
+            if not page:
+                return
+            offset += len(page)
+            yield len(page), await self._missing(page, tenant_id)
+
+    async def pending(self, tenant_id: str) -> int:
+        """Count memories of a tenant still lacking the model's vector."""
+        count = 0
+        async for _, missing in self.scan(tenant_id):
+            count += len(missing)
+        return count
+
+    async def run(self, tenant_id: str) -> int:
+        """Embed every memory of a tenant lacking the model's vector.
+
+        Returns:
+            Number of memories embedded
+        """
+        migrated = 0
+        async for _, missing in self.scan(tenant_id):
+            migrated += await self.embed(missing, tenant_id)
+        return migrated
+
+    async def embed(self, memories: list[dict[str, Any]], tenant_id: str) -> int:
+        """Embed memories with the model and store the vectors.
+
+        Returns:
+            Number of vectors stored
+        """
+        if not memories:
+            return 0
+        embeddings = await self.provider.embed_batch(
+            [m.get("content", "") for m in memories], task_type="search_document"
+        )
+        migrated = 0
+        for memory, embedding in zip(memories, embeddings):
+            if await self.vector_store.store_vector(
+                UUID(str(memory["id"])),
+                {self.vector_name: embedding},
+                tenant_id,
+                metadata=vector_metadata(memory),
+            ):
+                migrated += 1
+        return migrated
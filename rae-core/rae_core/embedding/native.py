@@ -102,6 +102,11 @@ class NativeEmbeddingProvider(IEmbeddingProvider):
         except Exception as e:
             raise RuntimeError(f"Failed to inspect ONNX model dimension: {e}")
 
+    async def health_check(self) -> bool:
+        """Run the model on a probe text."""
+        await self.embed_text("health check")
+        return True
+
     def get_dimension(self) -> int:
         """Return embedding dimension."""
         return int(self._dimension)
@@ -0,0 +1,137 @@
+"""Ollama embedding provider for self-hosted models.
+
+Calls the /api/embeddings endpoint of a local (or LAN) Ollama server, one
+prompt per request. Batches are sent in chunks of concurrent requests so a
+large backfill does not open hundreds of connections at once.
+
+Requires httpx unless a client is given: pip install rae-core[embeddings]
+"""
+
+import asyncio
+from typing import Any
+
+from rae_core.exceptions.base import InfrastructureError
+from rae_core.interfaces.embedding import IEmbeddingProvider
+
+
+class OllamaEmbeddingProvider(IEmbeddingProvider):
+    """Embedding provider backed by an Ollama server.
+
+    The dimension is detected from the first embedding returned unless it
+    is given; every later embedding must have the same dimension.
+    """
+
+    def __init__(
+        self,
+        model: str = "nomic-embed-text",
+        base_url: str = "http://localhost:11434",
+        dimension: int | None = None,
+        batch_size: int = 16,
+        timeout: float = 60.0,
+        client: Any = None,
+    ):
+        """Initialize Ollama provider.
+
+        Args:
+            model: Name of the Ollama embedding model
+            base_url: URL of the Ollama server
+            dimension: Expected dimension (detected on first call if omitted)
+            batch_size: Requests in flight at once when embedding a batch
+            timeout: Request timeout in seconds
+            client: httpx.AsyncClient-compatible client to use
+        """
+        if batch_size < 1:
+            raise ValueError("batch_size must be positive")
+        self.model = model
+        self.base_url = base_url.rstrip("/")
+        self.batch_size = batch_size
+        self.timeout = timeout
+        self._dimension = dimension
+        self._client = client
+
+    def _get_client(self) -> Any:
+        if self._client is None:
+            import httpx
+
+            self._client = httpx.AsyncClient(timeout=self.timeout)
+        return self._client
+
+    async def embed_text(
+        self, text: str, task_type: str = "search_document"
+    ) -> list[float]:
+        """Generate embedding for text."""
+        try:
+            response = await self._get_client().post(
+                f"{self.base_url}/api/embeddings",
+                json={"model": self.model, "prompt": text},
+            )
+            response.raise_for_status()
+            embedding = response.json().get("embedding")
+        except Exception as e:
+            raise InfrastructureError(f"Ollama embedding request failed: {e}") from e
+
+        if not embedding:
+            raise InfrastructureError(
+                f"Ollama returned no embedding for model {self.model}"
+            )
+        if self._dimension is None:
+            self._dimension = len(embedding)
+        elif len(embedding) != self._dimension:
+            raise InfrastructureError(
+                f"Ollama model {self.model} returned dimension {len(embedding)}, "
+                f"expected {self._dimension}"
+            )
+        return [float(x) for x in embedding]
+
+    async def embed_batch(
+        self, texts: list[str], task_type: str = "search_document"
+    ) -> list[list[float]]:
+        """Generate embeddings for multiple texts, batch_size at a time."""
+        if texts and self._dimension is None:
+            # Detect the dimension once before fanning out
+            embeddings = [await self.embed_text(texts[0], task_type)]
+            texts = texts[1:]
+        else:
+            embeddings = []
+        for start in range(0, len(texts), self.batch_size):
+            chunk = texts[start : start + self.batch_size]
+            embeddings.extend(
+                await asyncio.gather(*(self.embed_text(t, task_type) for t in chunk))
+            )
+        return embeddings
+
+    def get_dimension(self) -> int:
+        """Return embedding dimension.
+
+        Raises:
+            RuntimeError: If it was not given and nothing was embedded yet
+        """
+        if self._dimension is None:
+            raise RuntimeError(
+                "Embedding dimension unknown until the first call; "
+                "pass dimension= or await detect_dimension()"
+            )
+        return self._dimension
+
+    async def detect_dimension(self) -> int:
+        """Embed a probe text if needed and return the dimension."""
+        if self._dimension is None:
+            await self.embed_text("dimension probe")
+        return self.get_dimension()
+
+    async def health_check(self) -> bool:
+        """Check the server is up and has the model pulled."""
+        try:
+            response = await self._get_client().get(f"{self.base_url}/api/tags")
+            response.raise_for_status()
+            models = [m.get("name", "") for m in response.json().get("models", [])]
+        except Exception as e:
+            raise InfrastructureError(f"Ollama server unreachable: {e}") from e
+        if not any(name.split(":")[0] == self.model.split(":")[0] for name in models):
+            raise InfrastructureError(f"Ollama model {self.model} is not pulled")
+        return True
+
+    async def close(self) -> None:
+        if self._client is not None:
+            await self._client.aclose()
+            self._client = None
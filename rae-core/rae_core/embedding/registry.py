@@ -0,0 +1,103 @@
+"""Embedding provider selection by name.
+
+EmbeddingProviderRegistry maps provider names to factories, so the models
+available to tenants can come from configuration:
+
+    registry = EmbeddingProviderRegistry()
+    manager = registry.build_manager(
+        {
+            "default": {"provider": "ollama", "options": {"dimension": 768}},
+            "cohere-en": {"provider": "cohere"},
+        },
+        default_model_name="default",
+    )
+    manager.set_tenant_model("acme", "cohere-en")
+"""
+
+from collections.abc import Callable, Mapping
+from typing import Any
+
+from rae_core.embedding.hosted import CohereEmbeddingProvider, VoyageEmbeddingProvider
+from rae_core.embedding.manager import EmbeddingManager
+from rae_core.embedding.ollama import OllamaEmbeddingProvider
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.embedding import IEmbeddingProvider
+from rae_core.models.embedding import EmbeddingProviderConfig
+
+EmbeddingProviderFactory = Callable[..., IEmbeddingProvider]
+
+BUILTIN_PROVIDERS: dict[str, EmbeddingProviderFactory] = {
+    "ollama": OllamaEmbeddingProvider,
+    "cohere": CohereEmbeddingProvider,
+    "voyage": VoyageEmbeddingProvider,
+}
+
+
+class EmbeddingProviderRegistry:
+    """Creates embedding providers from their registered names."""
+
+    def __init__(self, include_builtin: bool = True):
+        """Initialize registry.
+
+        Args:
+            include_builtin: Register ollama, cohere and voyage
+        """
+        self._factories: dict[str, EmbeddingProviderFactory] = (
+            dict(BUILTIN_PROVIDERS) if include_builtin else {}
+        )
+
+    def register(self, name: str, factory: EmbeddingProviderFactory) -> None:
+        """Register (or replace) the factory of a provider name."""
+        self._factories[name] = factory
+
+    def names(self) -> list[str]:
+        return sorted(self._factories)
+
+    def create(self, name: str, **options: Any) -> IEmbeddingProvider:
+        """Instantiate a provider by name.
+
+        Raises:
+            ValidationError: Unknown provider or invalid options
+        """
+        factory = self._factories.get(name)
+        if factory is None:
+            raise ValidationError(
+                f"Unknown embedding provider: {name} (known: {self.names()})"
+            )
+        try:
+            return factory(**options)
+        except (TypeError, ValueError) as e:
+            raise ValidationError(
+                f"Invalid options for embedding provider {name}: {e}"
+            ) from e
+
+    def from_config(
+        self, config: EmbeddingProviderConfig | Mapping[str, Any]
+    ) -> IEmbeddingProvider:
+        """Instantiate the provider described by a config entry."""
+        if not isinstance(config, EmbeddingProviderConfig):
+            config = EmbeddingProviderConfig.model_validate(config)
+        return self.create(config.provider, **config.options)
+
+    def build_manager(
+        self,
+        models: Mapping[str, EmbeddingProviderConfig | Mapping[str, Any]],
+        default_model_name: str = "default",
+    ) -> EmbeddingManager:
+        """EmbeddingManager with one provider per configured model name.
+
+        Raises:
+            ValidationError: default_model_name is not configured, or an
+                entry cannot be instantiated
+        """
+        if default_model_name not in models:
+            raise ValidationError(
+                f"Default embedding model {default_model_name} is not configured"
+            )
+        providers = {name: self.from_config(c) for name, c in models.items()}
+        manager = EmbeddingManager(
+            providers.pop(default_model_name), default_model_name=default_model_name
+        )
+        for name, provider in providers.items():
+            manager.register_provider(name, provider)
+        return manager
@@ -1,11 +1,53 @@
 """RAE Engine - The Intelligent Memory Manifold."""
 
+import copy
 import math
-from typing import Any
+from collections.abc import Awaitable, Callable
+from contextlib import AbstractAsyncContextManager, nullcontext
+from datetime import timedelta
+from typing import TYPE_CHECKING, Any
 
 import numpy as np
 import structlog
 
+from rae_core.embedding.dimensions import DimensionCheckedVectorStore
+from rae_core.exceptions.base import ValidationError
+from rae_core.guards.sharing import SCOPE_KEY, TEAM_KEY
+from rae_core.models.consistency import SUPERSEDED_KEY
+from rae_core.models.embedding import EmbeddingSchema
+from rae_core.models.load import PriorityClass
+from rae_core.models.memory import CONFIDENCE_KEY, memory_confidence
+from rae_core.models.namespace import (
+    DEFAULT_NAMESPACE,
+    NAMESPACE_KEY,
+    Namespace,
+    NamespaceDeletion,
+    memory_namespace,
+)
+from rae_core.models.provenance import ORIGIN_KEY, MemorySource
+from rae_core.tracing import set_span_attributes, set_tracing_enabled, span
+from rae_core.types.enums import MemoryScope
+from rae_core.utils.access_tracker import AccessTracker
+
+if TYPE_CHECKING:
+    from rae_core.models.code import CodeIngestReport
+    from rae_core.models.consistency import (
+        ConflictResolution,
+        Contradiction,
+        ResolutionResult,
+    )
+    from rae_core.models.conversation import ConversationIngestReport
+    from rae_core.models.health import SystemHealthReport
+    from rae_core.models.plan import WritePlan
+    from rae_core.models.provenance import OriginStep
+    from rae_core.models.retrieval import RetrievalRequest, RetrievalResponse
+    from rae_core.models.search import (
+        RecallResult,
+        ScoredMemory,
+        SimilarityRecallOptions,
+    )
+    from rae_core.models.stream import StreamIngestSummary
+
 logger = structlog.get_logger(__name__)
 
 
@@ -26,13 +68,64 @@ class RAEEngine:
         search_engine: Any = None,
         math_controller: Any = None,
         resonance_engine: Any = None,
+        quota_manager: Any = None,
+        template_registry: Any = None,
+        reranker: Any = None,
+        sharing_policy: Any = None,
+        load_shedder: Any = None,
+        pii_redactor: Any = None,
+        ephemeral_purger: Any = None,
+        novelty_gate: Any = None,
+        access_tracker: AccessTracker | None = None,
+        contradiction_detector: Any = None,
+        namespace_manager: Any = None,
+        dimension_registry: Any = None,
     ):
         self.memory_storage = memory_storage
+        # Optional embedding.DimensionRegistry: vectors stored and searched
+        # through vector_store are then checked against the dimensions of
+        # the tenant's collections (searches with a query of the wrong size
+        # fail instead of returning meaningless scores)
+        self.dimension_registry = dimension_registry
+        if dimension_registry is not None and vector_store is not None:
+            vector_store = DimensionCheckedVectorStore(vector_store, dimension_registry)
         self.vector_store = vector_store
         self.embedding_provider = embedding_provider
         self.llm_provider = llm_provider
         self.settings = settings
         self.cache_provider = cache_provider
+        # Optional governance.QuotaManager enforcing embeddings per minute
+        self.quota_manager = quota_manager
+        # templates.TemplateRegistry used by store_from_template
+        self.template_registry = template_registry
+        # Optional IReranker run as a second stage by recall
+        self.reranker = reranker
+        # Optional guards.SharingPolicy deciding what agent_id may recall
+        self.sharing_policy = sharing_policy
+        # Optional governance.LoadShedder admitting searches and stores
+        self.load_shedder = load_shedder
+        # Optional ingestion.PiiRedactor masking personal data before storage
+        self.pii_redactor = pii_redactor
+        # governance.EphemeralPurger deleting ephemeral memories at their TTL
+        # (created on the first remember_ephemeral call if None)
+        self.ephemeral_purger = ephemeral_purger
+        # Optional ingestion.NoveltyGate merging redundant writes into the
+        # closest existing memory
+        self.novelty_gate = novelty_gate
+        # Write-behind buffer recording the memories recall returns
+        # (last_accessed_at, access_count); flush it before shutting down
+        self.access_tracker = access_tracker or AccessTracker(memory_storage)
+        # Optional consistency.ContradictionDetector flagging new memories
+        # that contradict similar existing ones
+        self.contradiction_detector = contradiction_detector
+        # governance.NamespaceManager partitioning each tenant's memories
+        # (created on first use if None)
+        self.namespace_manager = namespace_manager
+        # Strategy weights used when a search passes none (hot-reloadable,
+        # see config.reload)
+        self.ranking_weights: dict[str, float] | None = None
+        if settings is not None:
+            set_tracing_enabled(getattr(settings, "otel_enabled", True))
 
         # Initialize Math Layer Controller (The Brain)
         from rae_core.math.controller import MathLayerController
@@ -103,6 +196,26 @@ class RAEEngine:
 
         return VectorSearchStrategy(self.vector_store, self.embedding_provider)
 
+    def _tenant_strategies(
+        self, tenant_id: str, strategies: list[str] | None
+    ) -> list[str] | None:
+        """Drop vector spaces whose model the tenant may not use.
+
+        Keeps query text away from providers other than a tenant's mandated
+        embedding model.
+        """
+        from rae_core.embedding.manager import EmbeddingManager
+
+        if not isinstance(self.embedding_provider, EmbeddingManager):
+            return strategies
+        allowed = {
+            f"vector_{name}"
+            for name in self.embedding_provider.allowed_models(tenant_id)
+        }
+        names = strategies or list(self.search_engine.strategies)
+        filtered = [s for s in names if not s.startswith("vector_") or s in allowed]
+        return filtered if filtered != names else strategies
+
     def _init_fulltext_strategy(self):
         from rae_core.search.strategies.fulltext import FullTextStrategy
 
@@ -121,7 +234,135 @@ class RAEEngine:
     ) -> list[dict[str, Any]]:
         """
         RAE Reflective Search: Retrieval -> Math Scoring -> Manifold Adjustment.
+
+        With group_by_parent=True, chunk hits of the same stored memory are
+        collapsed into one result (see search.grouping.collapse_chunks).
+        Time bounds (created_after, updated_before, accessed_after, ...; see
+        IMemoryStorage.list_memories) drop results outside the range, and
+        session_id keeps only memories of that session. Memories superseded
+        by a resolved conflict (see resolve_conflict) are left out unless
+        include_superseded=True, and soft-deleted ones (see
+        IMemoryStorage.soft_delete_memory) unless include_deleted=True.
+        With min_confidence, memories recorded as less likely to be true are
+        left out too (see store_memory's confidence). With namespace, only
+        memories of that namespace are returned.
+
+        With a sharing_policy, agent_id names the reader rather than the
+        owner: results include other agents' memories shared with it through
+        their team or tenant scope. Candidates are fetched until top_k readable
+        ones are found, so other agents' private memories cannot crowd them out.
+
+        With a load_shedder, the search holds one of the tenant's slots;
+        priority ("interactive" or "batch") selects its class.
+
+        With include_global=True, the shared global knowledge base
+        (search.global_knowledge) is searched too and merged in, its scores
+        multiplied by global_weight (settings.global_knowledge_weight by
+        default). Each result is labeled with its knowledge_source.
         """
+        include_global = kwargs.pop("include_global", False)
+        global_weight = kwargs.pop("global_weight", None)
+        with span(
+            "rae.search_memories", tenant_id=tenant_id, agent_id=agent_id, top_k=top_k
+        ) as current:
+            async with self._admit(tenant_id, kwargs.pop("priority", None)):
+                results = await self._search_memories(
+                    query, tenant_id, agent_id, layer, top_k, filters, project, **kwargs
+                )
+                if include_global:
+                    results = await self._merge_global_knowledge(
+                        query,
+                        tenant_id,
+                        results,
+                        layer,
+                        top_k,
+                        filters,
+                        global_weight,
+                        **kwargs,
+                    )
+            set_span_attributes(current, result_count=len(results))
+            return results
+
+    async def _merge_global_knowledge(
+        self,
+        query: str,
+        tenant_id: str,
+        results: list[dict[str, Any]],
+        layer: str | None,
+        top_k: int,
+        filters: dict[str, Any] | None,
+        global_weight: float | None,
+        **kwargs: Any,
+    ) -> list[dict[str, Any]]:
+        from rae_core.config.defaults import DEFAULT_GLOBAL_KNOWLEDGE_WEIGHT
+        from rae_core.search.global_knowledge import (
+            GLOBAL_TENANT_ID,
+            is_global_tenant,
+            merge_global_results,
+        )
+
+        if is_global_tenant(tenant_id):
+            return results
+        if global_weight is None:
+            global_weight = getattr(
+                self.settings,
+                "global_knowledge_weight",
+                DEFAULT_GLOBAL_KNOWLEDGE_WEIGHT,
+            )
+        # Agents, projects and sessions belong to the tenant, not to the
+        # global namespace
+        kwargs.pop("session_id", None)
+        global_results = await self._search_memories(
+            query, GLOBAL_TENANT_ID, None, layer, top_k, filters, None, **kwargs
+        )
+        return merge_global_results(results, global_results, global_weight, top_k)
+
+    async def _search_memories(
+        self,
+        query: str,
+        tenant_id: str,
+        agent_id: str | None,
+        layer: str | None,
+        top_k: int,
+        filters: dict[str, Any] | None,
+        project: str | None,
+        **kwargs: Any,
+    ) -> list[dict[str, Any]]:
+        if kwargs.pop("group_by_parent", False):
+            from rae_core.search.grouping import CHUNK_OVERFETCH, collapse_chunks
+
+            memories = await self.search_memories(
+                query,
+                tenant_id,
+                agent_id=agent_id,
+                layer=layer,
+                top_k=top_k * CHUNK_OVERFETCH,
+                filters=filters,
+                project=project,
+                **kwargs,
+            )
+            return collapse_chunks(memories, limit=top_k)
+
+        engine_limit = kwargs.pop("_engine_limit", None)
+        if self.sharing_policy is not None and agent_id and engine_limit is None:
+            return await self._search_as_reader(
+                query, tenant_id, agent_id, layer, top_k, filters, project, **kwargs
+            )
+
+        from rae_core.models.query import TIME_FILTERS, matches_range
+
+        time_bounds = {name: kwargs.pop(name, None) for name in TIME_FILTERS}
+        time_bounds = {k: v for k, v in time_bounds.items() if v is not None}
+        session_id = kwargs.get("session_id")
+        include_superseded = kwargs.pop("include_superseded", False)
+        # Left in kwargs for vector stores that can skip trashed vectors
+        include_deleted = kwargs.get("include_deleted", False)
+        min_confidence = kwargs.pop("min_confidence", None)
+        namespace = kwargs.pop("namespace", None)
+        reader = None
+        if self.sharing_policy is not None and agent_id:
+            reader, agent_id = agent_id, None
+
         search_filters = {**(filters or {})}
         if agent_id:
             search_filters["agent_id"] = agent_id
@@ -129,9 +370,13 @@ class RAEEngine:
             search_filters["project"] = project
         if layer:
             search_filters["layer"] = layer
+        if namespace is not None and namespace != DEFAULT_NAMESPACE:
+            # Strategies apply it before the engine_limit cut; memories of the
+            # default namespace may carry no key, so that one is filtered below
+            search_filters[NAMESPACE_KEY] = namespace
 
         # 1. BANDIT TUNING: Get "weights" and PARAMS (Spectrum Strategy)
-        custom_weights = kwargs.get("custom_weights")
+        custom_weights = kwargs.get("custom_weights") or self.ranking_weights
         strategy_weights = None
         engine_params = {}
 
@@ -167,7 +412,9 @@ class RAEEngine:
 
         # Prepare arguments safely
         active_strategies = kwargs.get("strategies") or search_filters.get("strategies")
-        engine_limit = self.math_ctrl.get_engine_param("limit", 100)
+        active_strategies = self._tenant_strategies(tenant_id, active_strategies)
+        if engine_limit is None:
+            engine_limit = self.math_ctrl.get_engine_param("limit", 100)
         enable_reranking = kwargs.get("enable_reranking", False)
 
         # Clean kwargs to avoid duplicates in **search_kwargs
@@ -389,8 +636,922 @@ class RAEEngine:
                                         new_score=new_top_score,
                                         recovered_id=str(memories[0]["id"]))
 
+        # Vector stores keep a soft-deleted memory's vectors until it is purged
+        if not include_deleted:
+            memories = [m for m in memories if not m.get("deleted_at")]
+        if time_bounds:
+            memories = [m for m in memories if matches_range(m, **time_bounds)]
+        if session_id:
+            memories = [m for m in memories if m.get("session_id") == session_id]
+        if not include_superseded:
+            memories = [
+                m for m in memories if not (m.get("metadata") or {}).get(SUPERSEDED_KEY)
+            ]
+        if min_confidence is not None:
+            memories = [
+                m for m in memories if memory_confidence(m) >= min_confidence
+            ]
+        if namespace is not None:
+            memories = [m for m in memories if memory_namespace(m) == namespace]
+        if reader:
+            memories = self.sharing_policy.filter(memories, reader)
         return memories[:top_k]
 
+    async def _search_as_reader(
+        self,
+        query: str,
+        tenant_id: str,
+        reader: str,
+        layer: str | None,
+        top_k: int,
+        filters: dict[str, Any] | None,
+        project: str | None,
+        **kwargs: Any,
+    ) -> list[dict[str, Any]]:
+        """Search as a reader under the sharing policy.
+
+        Other agents' private memories take candidate slots before the policy
+        drops them, so the candidate pool is widened until top_k readable
+        memories are found or it covers all of the tenant's memories.
+        """
+        limit = int(self.math_ctrl.get_engine_param("limit", 100))
+        total = await self.memory_storage.count_memories(tenant_id=tenant_id)
+        while True:
+            memories = await self._search_memories(
+                query,
+                tenant_id,
+                reader,
+                layer,
+                top_k,
+                filters,
+                project,
+                _engine_limit=limit,
+                **kwargs,
+            )
+            if len(memories) >= top_k or limit >= total:
+                return memories
+            limit *= 4
+
+    async def recall(
+        self,
+        query: str,
+        tenant_id: str,
+        floor: float | None = None,
+        rerank: bool | None = None,
+        rerank_depth: int | None = None,
+        mmr_lambda: float | None = None,
+        **kwargs: Any,
+    ) -> "RecallResult":
+        """Search memories and report explicitly when nothing is relevant.
+
+        Memories whose final score is below the floor are dropped instead of
+        being returned as least-bad matches. The floor defaults to
+        settings.relevance_floor; search arguments are passed through to
+        search_memories.
+
+        When a reranker is configured (rerank defaults to True then), the top
+        rerank_depth results (settings.rerank_top_k by default) are reordered
+        and rescored by it before the floor is applied. If reranking fails,
+        the first-stage order is kept.
+
+        With mmr_lambda set, the results are then reordered by maximal
+        marginal relevance over their stored embeddings (1 keeps the
+        relevance order, lower values push near-duplicates down).
+
+        The returned memories are recorded as accessed through
+        access_tracker, in the background.
+        """
+        with span("rae.recall", tenant_id=tenant_id) as current:
+            result = await self._recall(
+                query, tenant_id, floor, rerank, rerank_depth, mmr_lambda, **kwargs
+            )
+            set_span_attributes(current, result_count=len(result.memories))
+            return result
+
+    async def _recall(
+        self,
+        query: str,
+        tenant_id: str,
+        floor: float | None,
+        rerank: bool | None,
+        rerank_depth: int | None,
+        mmr_lambda: float | None,
+        **kwargs: Any,
+    ) -> "RecallResult":
+        from rae_core.search.relevance import apply_relevance_floor
+
+        if floor is None:
+            floor = getattr(self.settings, "relevance_floor", None)
+
+        if rerank is None:
+            rerank = self.reranker is not None
+        if not rerank:
+            memories = await self.search_memories(query, tenant_id, **kwargs)
+        else:
+            if self.reranker is None:
+                raise RuntimeError("Reranker not configured")
+            from rae_core.config.defaults import DEFAULT_RERANK_TOP_K
+            from rae_core.search.reranking import rerank_memories
+
+            if rerank_depth is None:
+                rerank_depth = getattr(
+                    self.settings, "rerank_top_k", DEFAULT_RERANK_TOP_K
+                )
+            top_k = kwargs.pop("top_k", 10)
+            memories = await self.search_memories(
+                query, tenant_id, top_k=max(top_k, rerank_depth), **kwargs
+            )
+            try:
+                memories = await rerank_memories(
+                    self.reranker, query, memories, tenant_id, depth=rerank_depth
+                )
+            except Exception as e:
+                logger.warning("recall_rerank_failed", error=str(e))
+            memories = memories[:top_k]
+
+        if mmr_lambda is not None:
+            from rae_core.search.diversity import diversify
+
+            memories = await diversify(
+                memories, self.vector_store, tenant_id, mmr_lambda
+            )
+
+        result = apply_relevance_floor(memories, floor)
+        if result.no_relevant_memory and result.rejected_count:
+            logger.info(
+                "recall_no_relevant_memory",
+                floor=floor,
+                best_rejected_score=result.best_rejected_score,
+            )
+        await self.access_tracker.record(
+            (m["id"] for m in result.memories if m.get("id")), tenant_id
+        )
+        return result
+
+    async def retrieve(
+        self, request: "RetrievalRequest", tenant_id: str
+    ) -> "RetrievalResponse":
+        """Recall described by a typed RetrievalRequest.
+
+        Results are taken in rank order while they fit in budget.max_tokens;
+        those that would exceed it are dropped and counted in tokens.dropped. With
+        options.explain, each result carries its scoring audit trail.
+        """
+        import time
+
+        from rae_core.context.window import estimate_tokens
+        from rae_core.models.retrieval import (
+            RetrievalResponse,
+            RetrievedMemory,
+            TokenUsage,
+        )
+
+        started = time.perf_counter()
+        recall_kwargs = request.recall_kwargs()
+        result = await self.recall(request.query, tenant_id, **recall_kwargs)
+
+        max_tokens = request.budget.max_tokens
+        usage = TokenUsage(budget=max_tokens)
+        results = []
+        for memory in result.memories:
+            tokens = estimate_tokens(str(memory.get("content", "")))
+            if max_tokens is not None and usage.used + tokens > max_tokens:
+                usage.dropped += 1
+                continue
+            usage.used += tokens
+            results.append(
+                RetrievedMemory.from_memory(memory, tokens, request.options.explain)
+            )
+
+        return RetrievalResponse(
+            query=request.query,
+            results=results,
+            no_relevant_memory=not results,
+            floor=result.floor,
+            rejected_count=result.rejected_count,
+            best_rejected_score=result.best_rejected_score,
+            tokens=usage,
+            elapsed_ms=(time.perf_counter() - started) * 1000,
+        )
+
+    async def recall_similar(
+        self,
+        query: str,
+        tenant_id: str,
+        options: "SimilarityRecallOptions | None" = None,
+    ) -> list["ScoredMemory"]:
+        """Vector recall returning memory records with scores and provenance.
+
+        Unlike search_memories, only the vector store is searched; its hits
+        are fetched from memory_storage in one batch, filtered, weighted by
+        recency and reranked as options say (see search.similarity). The
+        returned memories are recorded as accessed.
+        """
+        from rae_core.search.similarity import recall_similar
+
+        with span("rae.recall_similar", tenant_id=tenant_id) as current:
+            async with self._admit(tenant_id, None):
+                results = await recall_similar(
+                    query,
+                    tenant_id,
+                    self.memory_storage,
+                    self.vector_store,
+                    self.embedding_provider,
+                    options,
+                    reranker=self.reranker,
+                )
+            await self.access_tracker.record(
+                (r.memory["id"] for r in results), tenant_id
+            )
+            set_span_attributes(current, result_count=len(results))
+            return results
+
+    async def recall_similar_to(
+        self,
+        memory_id: Any,
+        tenant_id: str,
+        options: "SimilarityRecallOptions | None" = None,
+    ) -> list["ScoredMemory"]:
+        """Memories like an existing one ("show me more like this").
+
+        Works as recall_similar with the memory's stored embedding as the
+        query vector, so the embedding provider is not called; the memory
+        itself is not among the results.
+
+        Raises:
+            NotFoundError: The memory or its embedding does not exist
+        """
+        from uuid import UUID
+
+        from rae_core.search.similarity import recall_similar_to
+
+        if not isinstance(memory_id, UUID):
+            memory_id = UUID(str(memory_id))
+        with span("rae.recall_similar_to", tenant_id=tenant_id) as current:
+            async with self._admit(tenant_id, None):
+                results = await recall_similar_to(
+                    memory_id,
+                    tenant_id,
+                    self.memory_storage,
+                    self.vector_store,
+                    self.embedding_provider,
+                    options,
+                    reranker=self.reranker,
+                )
+            await self.access_tracker.record(
+                (r.memory["id"] for r in results), tenant_id
+            )
+            set_span_attributes(current, result_count=len(results))
+            return results
+
+    async def list_conflicts(
+        self, tenant_id: str, limit: int = 100
+    ) -> list["Contradiction"]:
+        """Contradicting memory pairs flagged by the contradiction_detector.
+
+        Most recently detected first; a pair disappears once either memory
+        is deleted.
+        """
+        from rae_core.consistency.contradiction import list_conflicts
+
+        return await list_conflicts(tenant_id, self.memory_storage, limit=limit)
+
+    async def trace_provenance(
+        self, memory_id: Any, tenant_id: str, graph_store: Any = None
+    ) -> list["OriginStep"]:
+        """Memories a memory was derived from, nearest first, with sources.
+
+        Follows recorded lineage (and "derived_from" edges of graph_store)
+        back to the memories that came from outside; see
+        context.provenance.trace_origins.
+        """
+        from uuid import UUID
+
+        from rae_core.context.provenance import trace_origins
+
+        if not isinstance(memory_id, UUID):
+            memory_id = UUID(str(memory_id))
+        return await trace_origins(
+            memory_id, tenant_id, self.memory_storage, graph_store=graph_store
+        )
+
+    async def resolve_conflict(
+        self,
+        memory_a: Any,
+        memory_b: Any,
+        resolution: "ConflictResolution | str",
+        tenant_id: str,
+        merged_content: str | None = None,
+        graph_store: Any = None,
+    ) -> "ResolutionResult":
+        """Resolve a pair of contradicting memories (belief revision).
+
+        "supersede" keeps memory_a and supersedes memory_b; "merge" stores
+        merged_content (both contents if None) as a new memory superseding
+        both; "keep_both" only clears the conflict. Superseded memories are
+        left out of recall by default. Edges go to graph_store, or to the
+        contradiction_detector's graph store if None; see
+        consistency.resolution.
+
+        Raises:
+            NotFoundError: A memory does not exist
+            ValidationError: The memories are the same
+        """
+        from uuid import UUID
+
+        from rae_core.consistency.resolution import merged_fields, resolve_conflict
+        from rae_core.exceptions.base import NotFoundError
+        from rae_core.models.consistency import ConflictResolution
+
+        memory_a, memory_b = (
+            m if isinstance(m, UUID) else UUID(str(m)) for m in (memory_a, memory_b)
+        )
+        resolution = ConflictResolution(resolution)
+        if graph_store is None and self.contradiction_detector is not None:
+            graph_store = self.contradiction_detector.graph_store
+
+        merged_id = None
+        if resolution is ConflictResolution.MERGE:
+            pair = []
+            for memory_id in (memory_a, memory_b):
+                memory = await self.memory_storage.get_memory(memory_id, tenant_id)
+                if memory is None:
+                    raise NotFoundError("Memory", memory_id, tenant_id)
+                pair.append(memory)
+            merged_id = await self.store_memory(
+                **merged_fields(pair, merged_content),
+                tenant_id=tenant_id,
+                check_novelty=False,
+                check_contradictions=False,
+            )
+        return await resolve_conflict(
+            memory_a,
+            memory_b,
+            resolution,
+            tenant_id,
+            self.memory_storage,
+            graph_store=graph_store,
+            merged_id=merged_id,
+        )
+
+    async def sample_memories(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        k: int = 10,
+        weighting: Any = None,
+        layer: str | None = None,
+        strategy: Any = None,
+    ) -> list[dict[str, Any]]:
+        """Weighted random sample of memories instead of the top-k.
+
+        weighting is a SamplingWeighting (strategy, importance and recency
+        exponents, pool size, seed); strategy ("weighted", "recency",
+        "importance" or "stratified") overrides its strategy. See
+        search.sampling.sample_memories.
+        """
+        from rae_core.search.sampling import sample_memories
+
+        return await sample_memories(
+            self.memory_storage,
+            tenant_id,
+            agent_id=agent_id,
+            k=k,
+            weighting=weighting,
+            layer=layer,
+            strategy=strategy,
+        )
+
+    async def memory_cards(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        limit: int = 50,
+    ) -> list[Any]:
+        """What the system knows about an agent's user, as MemoryCards.
+
+        Titles and summaries come from the LLM provider when it can
+        summarize, else from an extractive summary; see context.cards.
+        """
+        from rae_core.context.cards import MemoryCardRenderer
+
+        summarizer = self.llm_provider
+        if not hasattr(summarizer, "summarize"):
+            summarizer = None
+        memories = await self.memory_storage.list_memories(
+            tenant_id, agent_id=agent_id, layer=layer, limit=limit
+        )
+        renderer = MemoryCardRenderer(self.memory_storage, summarizer=summarizer)
+        return await renderer.render_many(memories, tenant_id)
+
+    async def generate_subject_report(
+        self,
+        subject_key: str,
+        tenant_id: str,
+        graph_store: Any = None,
+    ) -> Any:
+        """Everything stored about a data subject, as a SubjectReport.
+
+        Relationships are read from graph_store when one is given; see
+        governance.privacy for how memories are matched and rendered.
+        """
+        from rae_core.governance.privacy import generate_subject_report
+
+        return await generate_subject_report(
+            self.memory_storage, tenant_id, subject_key, graph_store=graph_store
+        )
+
+    async def erase_subject(
+        self,
+        tenant_id: str,
+        subject_key: str,
+        mode: Any = "delete",
+        graph_store: Any = None,
+        audit_logger: Any = None,
+        actor: str | None = None,
+    ) -> Any:
+        """Forget everything stored about a data subject.
+
+        Memories referencing the subject are deleted (or, with
+        mode="anonymize", replaced by re-embedded copies with the subject
+        masked) across storage, the vector store and graph_store, and an
+        ERASE entry per memory goes to audit_logger. Returns an
+        ErasureResult; see governance.privacy.
+        """
+        from rae_core.governance.privacy import SubjectEraser
+
+        eraser = SubjectEraser(
+            self.memory_storage,
+            vector_store=self.vector_store,
+            graph_store=graph_store,
+            audit_logger=audit_logger,
+            embedding_provider=self.embedding_provider,
+        )
+        return await eraser.erase(tenant_id, subject_key, mode=mode, actor=actor)
+
+    async def create_namespace(
+        self, tenant_id: str, name: str, description: str | None = None
+    ) -> Namespace:
+        """Create an empty namespace in a tenant.
+
+        Raises:
+            ValidationError: Invalid name
+            ConflictError: The namespace already exists
+        """
+        namespace: Namespace = await self._namespaces().create(
+            tenant_id, name, description
+        )
+        return namespace
+
+    async def list_namespaces(self, tenant_id: str) -> list[Namespace]:
+        """Namespaces of a tenant with their memory counts."""
+        namespaces: list[Namespace] = await self._namespaces().list_all(tenant_id)
+        return namespaces
+
+    async def delete_namespace(
+        self, tenant_id: str, name: str, graph_store: Any = None
+    ) -> NamespaceDeletion:
+        """Delete a namespace with its memories, vectors and graph nodes.
+
+        Raises:
+            ValidationError: The default namespace cannot be deleted
+            NotFoundError: The namespace does not exist
+        """
+        result: NamespaceDeletion = await self._namespaces().delete(
+            tenant_id, name, graph_store=graph_store
+        )
+        return result
+
+    def list_embedding_schemas(self, tenant_id: str) -> list[EmbeddingSchema]:
+        """Vector dimensions registered for a tenant's embedding models."""
+        if self.dimension_registry is None:
+            return []
+        schemas: list[EmbeddingSchema] = self.dimension_registry.list_schemas(
+            tenant_id
+        )
+        return schemas
+
+    def register_embedding_schema(
+        self, tenant_id: str, model: str, dimension: int
+    ) -> EmbeddingSchema:
+        """Fix the vector dimension of a tenant's embedding model up front.
+
+        Raises:
+            ValidationError: The engine has no dimension registry
+            DimensionMismatchError: The model's collection has another
+                dimension
+        """
+        if self.dimension_registry is None:
+            raise ValidationError("Embedding dimensions are not tracked")
+        schema: EmbeddingSchema = self.dimension_registry.register(
+            tenant_id, model, dimension
+        )
+        return schema
+
+    async def close_session(
+        self,
+        tenant_id: str,
+        session_id: str,
+        agent_id: str | None = None,
+    ) -> list[Any]:
+        """End a conversation and consolidate its working memories.
+
+        Each working memory of the session moves to the episodic layer (the
+        end-of-session transition of ConsolidationFSM) and keeps its
+        session_id. Importance is raised by 0.2, as in
+        LongTermMemory.consolidate_from_working.
+
+        Ephemeral memories (see remember_ephemeral) stay in the working
+        layer until they are purged.
+
+        Returns:
+            Ids of the consolidated memories
+        """
+        from rae_core.governance.ephemeral import is_ephemeral
+
+        page_size = 100
+        working: list[dict[str, Any]] = []
+        while True:
+            page = await self.memory_storage.list_memories(
+                tenant_id,
+                agent_id=agent_id,
+                layer="working",
+                session_id=session_id,
+                limit=page_size,
+                offset=len(working),
+            )
+            working.extend(page)
+            if len(page) < page_size:
+                break
+
+        consolidated = []
+        for memory in working:
+            if is_ephemeral(memory):
+                continue
+            importance = float(memory.get("importance") or 0.0)
+            updated = await self.memory_storage.update_memory(
+                memory["id"],
+                tenant_id,
+                {"layer": "episodic", "importance": min(importance + 0.2, 1.0)},
+                changed_by="close_session",
+            )
+            if updated:
+                consolidated.append(memory["id"])
+        logger.info(
+            "session_closed", session_id=session_id, consolidated=len(consolidated)
+        )
+        return consolidated
+
+    def _admit(
+        self, tenant_id: str | None, priority: PriorityClass | str | None
+    ) -> AbstractAsyncContextManager[None]:
+        """Slot of the tenant in the load shedder (a no-op without one)."""
+        if self.load_shedder is None or tenant_id is None:
+            return nullcontext()
+        return self.load_shedder.admit(
+            tenant_id, priority or PriorityClass.INTERACTIVE
+        )
+
+    async def share_memory(
+        self,
+        memory_id: Any,
+        tenant_id: str,
+        scope: MemoryScope | str = MemoryScope.TEAM,
+        team_id: str | None = None,
+    ) -> bool:
+        """Change who may recall a memory, without copying it.
+
+        The scope and team are kept in the memory's metadata (see
+        guards.sharing). Returns False if the memory does not exist.
+        """
+        scope = MemoryScope(scope)
+        if scope is MemoryScope.TEAM and not team_id:
+            raise ValueError("team_id is required for TEAM scope")
+        memory = await self.memory_storage.get_memory(memory_id, tenant_id)
+        if memory is None:
+            return False
+        metadata = {**(memory.get("metadata") or {}), SCOPE_KEY: scope.value}
+        metadata[TEAM_KEY] = team_id if scope is MemoryScope.TEAM else None
+        return await self.memory_storage.update_memory(
+            memory_id, tenant_id, {"metadata": metadata}, changed_by="share_memory"
+        )
+
+    async def store_global_knowledge(self, content: str, **kwargs: Any) -> Any:
+        """Store a memory in the shared global knowledge base.
+
+        Meant for operators maintaining knowledge common to all tenants;
+        tenants read it through search_memories(include_global=True).
+        Arguments other than tenant_id are passed to store_memory.
+        """
+        from rae_core.search.global_knowledge import GLOBAL_TENANT_ID
+
+        if "tenant_id" in kwargs:
+            raise ValueError("global knowledge is stored under its own tenant")
+        return await self.store_memory(
+            tenant_id=GLOBAL_TENANT_ID, content=content, **kwargs
+        )
+
+    async def store_from_template(
+        self,
+        template: str,
+        fields: dict[str, Any],
+        tenant_id: str,
+        **kwargs: Any,
+    ) -> Any:
+        """Store a structured memory by filling a named template.
+
+        Fields are validated against the template, the rendered content is
+        what gets embedded, and the field values are kept in metadata.
+
+        Raises:
+            ValidationError: Unknown template or invalid fields
+        """
+        if self.template_registry is None:
+            from rae_core.templates import TemplateRegistry
+
+            self.template_registry = TemplateRegistry()
+
+        built = self.template_registry.build(
+            template,
+            fields,
+            metadata=kwargs.pop("metadata", None),
+            tags=kwargs.pop("tags", None),
+        )
+        if "layer" in kwargs:
+            built["layer"] = kwargs.pop("layer")
+        return await self.store_memory(tenant_id=tenant_id, **built, **kwargs)
+
+    async def bootstrap_agent(
+        self,
+        tenant_id: str,
+        agent_id: str,
+        directory: Any,
+        graph_store: Any = None,
+    ) -> Any:
+        """Seed a new agent's semantic layer from a directory of knowledge files.
+
+        Markdown sections and JSON facts are stored, embedded and tagged
+        "seed", with their extracted entities; see maintenance.bootstrap.
+        Returns a BootstrapReport.
+
+        Raises:
+            ValidationError: Missing directory or malformed seed file
+        """
+        from rae_core.maintenance.bootstrap import AgentBootstrap
+
+        bootstrap = AgentBootstrap(self, graph_store=graph_store)
+        return await bootstrap.run(tenant_id, agent_id, directory)
+
+    async def ingest_conversation(
+        self,
+        turns: list[Any],
+        tenant_id: str,
+        agent_id: str = "default",
+        graph_store: Any = None,
+        config: Any = None,
+        **kwargs: Any,
+    ) -> "ConversationIngestReport":
+        """Store a conversation's role-tagged turns as linked working memories.
+
+        Turns are grouped into memories, chained by "follows" edges when a
+        graph_store is given and optionally summarized every N turns
+        (session_id, conversation_id and summarize_every are passed on);
+        see ingestion.conversation.ConversationIngestor.
+        """
+        from rae_core.ingestion.conversation import ConversationIngestor
+
+        ingestor = ConversationIngestor(self, graph_store=graph_store, config=config)
+        return await ingestor.ingest(turns, tenant_id, agent_id=agent_id, **kwargs)
+
+    async def ingest_stream(
+        self,
+        items: Any,
+        tenant_id: str,
+        authorize: Any = None,
+        **options: Any,
+    ) -> "StreamIngestSummary":
+        """Store a stream of items (JSONL lines, dicts or IngestItems).
+
+        Items are embedded in batches by a bounded number of workers, and
+        the stream is read no faster than they store it, so multi-GB
+        histories load in constant memory. Copies are skipped and failing
+        items reported with their reason; options (batch_size, concurrency,
+        dedupe_existing) are passed on to ingestion.stream.StreamIngestor.
+        """
+        from rae_core.ingestion.stream import StreamIngestor
+
+        ingestor = StreamIngestor(self, **options)
+        return await ingestor.ingest(items, tenant_id, authorize=authorize)
+
+    async def remember_ephemeral(
+        self,
+        content: str,
+        tenant_id: str,
+        ttl: float | timedelta,
+        agent_id: str = "default",
+        **kwargs: Any,
+    ) -> Any:
+        """Store a scratch memory that is purged once ttl (seconds) elapses.
+
+        The memory goes to the working layer as a single record, skipping the
+        ingest pipeline (no chunking, redaction or dedup hashing of the
+        value), is never consolidated and is left out of snapshots and
+        knowledge packs. At its deadline the memory and its vector are
+        hard-deleted; see governance.ephemeral.
+        """
+        from rae_core.governance.ephemeral import (
+            EPHEMERAL_KEY,
+            EPHEMERAL_LAYER,
+            EphemeralPurger,
+            ttl_seconds,
+        )
+
+        if self.ephemeral_purger is None:
+            self.ephemeral_purger = EphemeralPurger(
+                self.memory_storage, vector_store=self.vector_store
+            )
+        expires_at = self.ephemeral_purger.clock.now() + timedelta(
+            seconds=ttl_seconds(ttl)
+        )
+        metadata = {**(kwargs.pop("metadata", None) or {}), EPHEMERAL_KEY: True}
+        kwargs.pop("layer", None)
+
+        async with self._admit(tenant_id, kwargs.pop("priority", None)):
+            memory_id = await self.memory_storage.store_memory(
+                content=content,
+                tenant_id=tenant_id,
+                agent_id=agent_id,
+                layer=EPHEMERAL_LAYER,
+                metadata=metadata,
+                expires_at=expires_at,
+                **kwargs,
+            )
+            self.ephemeral_purger.schedule(memory_id, tenant_id, expires_at)
+            await self._embed_and_store_vector(
+                memory_id,
+                content,
+                tenant_id,
+                agent_id=agent_id,
+                layer=EPHEMERAL_LAYER,
+                metadata=metadata,
+            )
+        return memory_id
+
+    async def store_verbatim(
+        self,
+        content: str,
+        tenant_id: str,
+        agent_id: str = "default",
+        **kwargs: Any,
+    ) -> Any:
+        """Store content as a single memory exactly as given, and embed it.
+
+        Skips the ingest pipeline (no normalization, chunking, redaction or
+        dedup hashing), for content whose layout matters, such as the source
+        code stored by ingestion.code.CodeIngestor.
+        """
+        kwargs.setdefault("layer", "episodic")
+        self._apply_origin(kwargs)
+        self._apply_confidence(kwargs)
+        self._apply_namespace(kwargs, tenant_id)
+        async with self._admit(tenant_id, kwargs.pop("priority", None)):
+            memory_id = await self.memory_storage.store_memory(
+                content=content, tenant_id=tenant_id, agent_id=agent_id, **kwargs
+            )
+            await self._embed_and_store_vector(
+                memory_id, content, tenant_id, agent_id=agent_id, **kwargs
+            )
+        return memory_id
+
+    async def store_embedded(
+        self,
+        content: str,
+        tenant_id: str,
+        embedding: list[float] | dict[str, list[float]],
+        agent_id: str = "default",
+        **kwargs: Any,
+    ) -> Any:
+        """Store content as a single memory with an embedding computed earlier.
+
+        Like store_verbatim, but the embedding (one vector, or one per model)
+        is stored as given instead of being computed; see embed_documents.
+        """
+        kwargs.setdefault("layer", "episodic")
+        self._apply_origin(kwargs)
+        self._apply_confidence(kwargs)
+        self._apply_namespace(kwargs, tenant_id)
+        async with self._admit(tenant_id, kwargs.pop("priority", None)):
+            memory_id = await self.memory_storage.store_memory(
+                content=content, tenant_id=tenant_id, agent_id=agent_id, **kwargs
+            )
+            with span("rae.store_vector", tenant_id=tenant_id, memory_id=memory_id):
+                await self.vector_store.store_vector(
+                    memory_id,
+                    embedding,
+                    tenant_id,
+                    metadata={
+                        "agent_id": agent_id,
+                        **kwargs,
+                        NAMESPACE_KEY: memory_namespace(kwargs),
+                    },
+                )
+        return memory_id
+
+    async def ingest_code(
+        self,
+        files: dict[str, str],
+        tenant_id: str,
+        agent_id: str = "default",
+        graph_store: Any = None,
+        **kwargs: Any,
+    ) -> "CodeIngestReport":
+        """Store source files ({path: source}) as one memory per symbol.
+
+        Symbols are linked to their file and to the symbols they call when
+        a graph_store is given (project and language are passed on); see
+        ingestion.code.CodeIngestor.
+        """
+        from rae_core.ingestion.code import CodeIngestor
+
+        ingestor = CodeIngestor(self, graph_store=graph_store)
+        return await ingestor.ingest_files(
+            files, tenant_id, agent_id=agent_id, **kwargs
+        )
+
+    async def store_image(
+        self,
+        image: bytes,
+        tenant_id: str,
+        agent_id: str = "default",
+        caption: str = "",
+        media_type: str = "image/png",
+        **kwargs: Any,
+    ) -> Any:
+        """Store an image memory recallable by text queries.
+
+        The image itself is embedded by a multimodal (CLIP-style) provider,
+        either the engine's embedding provider or one registered with its
+        EmbeddingManager, so recall's text queries land in the same vector
+        space. The caption becomes the memory content and skips the ingest
+        pipeline. With an AttachmentStorage the bytes are kept as an
+        attachment of the memory.
+
+        Raises:
+            ValidationError: No multimodal embedding provider is available
+        """
+        from rae_core.adapters.blobs import AttachmentStorage
+        from rae_core.embedding.manager import EmbeddingManager
+        from rae_core.exceptions.base import ValidationError
+        from rae_core.interfaces.embedding import IMultimodalEmbeddingProvider
+        from rae_core.models.memory import Attachment
+        from rae_core.types.enums import MemoryType
+
+        provider = self.embedding_provider
+        if not isinstance(provider, EmbeddingManager) and not isinstance(
+            provider, IMultimodalEmbeddingProvider
+        ):
+            raise ValidationError("The embedding provider cannot embed images")
+        kwargs.setdefault("layer", "episodic")
+
+        async with self._admit(tenant_id, kwargs.pop("priority", None)):
+            if self.quota_manager is not None:
+                await self.quota_manager.consume_embeddings(tenant_id)
+            with span("rae.embed_image", tenant_id=tenant_id):
+                if isinstance(provider, EmbeddingManager):
+                    embedding: Any = await provider.generate_image_embeddings(
+                        image, tenant_id=tenant_id
+                    )
+                else:
+                    embedding = await provider.embed_image(image)
+
+            if isinstance(self.memory_storage, AttachmentStorage):
+                digest = await self.memory_storage.blobs.put(image, tenant_id)
+                kwargs["attachments"] = [
+                    *(kwargs.get("attachments") or []),
+                    Attachment(digest=digest, media_type=media_type, size=len(image)),
+                ]
+            memory_id = await self.memory_storage.store_memory(
+                content=caption,
+                tenant_id=tenant_id,
+                agent_id=agent_id,
+                memory_type=MemoryType.IMAGE.value,
+                **kwargs,
+            )
+            kwargs.pop("attachments", None)
+            with span("rae.store_vector", tenant_id=tenant_id, memory_id=memory_id):
+                await self.vector_store.store_vector(
+                    memory_id,
+                    embedding,
+                    tenant_id,
+                    metadata={
+                        "agent_id": agent_id,
+                        **kwargs,
+                        NAMESPACE_KEY: memory_namespace(kwargs),
+                    },
+                )
+        return memory_id
+
     async def generate_text(self, prompt: str, **kwargs) -> str:
         if not self.llm_provider:
             raise RuntimeError("LLM provider not configured")
@@ -398,10 +1559,93 @@ class RAEEngine:
 
         return cast(str, await self.llm_provider.generate_text(prompt=prompt, **kwargs))
 
+    async def what_if(
+        self, operation: Callable[["RAEEngine"], Awaitable[Any]]
+    ) -> "WritePlan":
+        """Run an engine operation without persisting anything.
+
+        operation receives a copy of this engine whose storage, vector store
+        and cache record writes into the returned plan instead of running
+        them; reads still see the real stores. Embeddings are computed, so
+        the plan holds the exact vectors, but no embedding quota is consumed.
+        The operation's return value is kept in plan.result.
+        """
+        from rae_core.adapters.planning import PlanningProxy
+        from rae_core.models.plan import WritePlan
+
+        plan = WritePlan()
+        planner = copy.copy(self)
+        planner.memory_storage = PlanningProxy(self.memory_storage, plan, "storage")
+        planner.vector_store = PlanningProxy(self.vector_store, plan, "vector")
+        if self.cache_provider is not None:
+            planner.cache_provider = PlanningProxy(self.cache_provider, plan, "cache")
+        planner.quota_manager = None
+        plan.result = await operation(planner)
+        return plan
+
     async def store_memory(self, **kwargs):
+        """Store a memory (chunked, embedded and vectorized).
+
+        With dry_run=True nothing is written and the WritePlan of the call
+        is returned instead of the memory id; see what_if. With a
+        load_shedder, the store holds one of the tenant's slots; pass
+        priority="batch" for bulk ingest.
+
+        With a novelty_gate, single-chunk content too similar to an existing
+        memory of the same agent and layer is not written: the existing
+        memory's access is recorded and its id returned. Pass
+        check_novelty=False to always write.
+
+        source is a free-text label or a MemorySource (type, ref, url, file
+        and the memories it was derived_from), kept under metadata["origin"]
+        so the memory can be traced back with trace_provenance.
+
+        confidence (0-1, default 1) is how likely the memory is to be true,
+        independent of its importance; it is kept under
+        metadata["confidence"]. Recall can drop memories below
+        min_confidence, and ContextBuilder flags uncertain ones.
+
+        namespace stores the memory in one of the tenant's namespaces (see
+        create_namespace); without one it goes to the default namespace.
+
+        With a contradiction_detector, the stored chunks are judged against
+        their most similar existing memories and conflicts are flagged (see
+        list_conflicts); detection failures never fail the store. Pass
+        check_contradictions=False to skip it.
+        """
+        if kwargs.pop("dry_run", False):
+            return await self.what_if(lambda engine: engine.store_memory(**kwargs))
+        with span(
+            "rae.store_memory",
+            tenant_id=kwargs.get("tenant_id"),
+            agent_id=kwargs.get("agent_id"),
+            layer=kwargs.get("layer"),
+        ) as current:
+            async with self._admit(
+                kwargs.get("tenant_id"), kwargs.pop("priority", None)
+            ):
+                memory_id = await self._store_memory(**kwargs)
+            set_span_attributes(current, memory_id=memory_id)
+            return memory_id
+
+    async def _store_memory(self, **kwargs):
+
         content = kwargs.get("content", "")
         tenant_id = kwargs.get("tenant_id")
         project = kwargs.get("project", "default")
+
+        # Sharing scope travels in metadata (see guards.sharing)
+        scope = kwargs.pop("scope", None)
+        team_id = kwargs.pop("team_id", None)
+        if scope is not None or team_id is not None:
+            kwargs["metadata"] = {
+                **(kwargs.get("metadata") or {}),
+                SCOPE_KEY: MemoryScope(scope or MemoryScope.TEAM).value,
+                TEAM_KEY: team_id,
+            }
+        self._apply_origin(kwargs)
+        self._apply_confidence(kwargs)
+        self._apply_namespace(kwargs)
         
         # SYSTEM 92.4: Quality Guard at Ingestion (Autonomous Firewall)
         if kwargs.get("validate") and content.strip():
@@ -427,7 +1671,7 @@ class RAEEngine:
 
         # SYSTEM 40.14: Universal Ingest Pipeline (UICTC)
         from rae_core.ingestion.pipeline import UniversalIngestPipeline
-        pipeline = UniversalIngestPipeline()
+        pipeline = UniversalIngestPipeline(redactor=self.pii_redactor)
         
         # Process text through the 5-stage pipeline
         chunks, signature, audit_trail, policy = await pipeline.process(
@@ -438,6 +1682,29 @@ class RAEEngine:
         if not chunks:
             return None
 
+        check_novelty = kwargs.pop("check_novelty", True)
+        check_contradictions = kwargs.pop("check_contradictions", True)
+        if self.novelty_gate is not None and check_novelty and len(chunks) == 1:
+            decision = await self.novelty_gate.evaluate(
+                chunks[0].content,
+                tenant_id,
+                self.embedding_provider,
+                self.vector_store,
+                agent_id=kwargs.get("agent_id"),
+                layer=kwargs["layer"],
+                project=kwargs.get("project"),
+            )
+            if not decision.novel:
+                await self.memory_storage.update_memory_access(
+                    decision.duplicate_of, tenant_id
+                )
+                logger.info(
+                    "novelty_gate_merged_write",
+                    memory_id=str(decision.duplicate_of),
+                    novelty=decision.novelty,
+                )
+                return decision.duplicate_of
+
         # SYSTEM 92.2: Dedup Hash Check (Stop the spiral)
         if self.cache_provider and chunks:
             agent_id = kwargs.get("agent_id", "default")
@@ -470,6 +1737,8 @@ class RAEEngine:
             chunk_kwargs["metadata"].update({
                 "parent_id": parent_id,
                 "chunk_index": i,
+                "chunk_offset": chunk.offset,
+                "chunk_length": chunk.length,
                 "total_chunks": len(chunks),
                 "is_chunk": True,
                 "ingest_audit": [a.__dict__ for a in audit_trail] if audit_trail else [],
@@ -499,23 +1768,152 @@ class RAEEngine:
                 logger.info("skipping_vector_store_for_operational_data", memory_id=str(m_id))
                 
             memory_ids.append(m_id)
-            
+
+        detect = self.contradiction_detector is not None and check_contradictions
+        if detect and not is_operational:
+            await self._detect_contradictions(memory_ids, tenant_id, kwargs)
+
         return memory_ids[0]
 
-    async def _embed_and_store_vector(self, m_id, content, tenant_id, **kwargs):
-        if hasattr(self.embedding_provider, "generate_all_embeddings"):
-            embs_dict = await self.embedding_provider.generate_all_embeddings(
-                [content], task_type="search_document"
+    async def _detect_contradictions(
+        self, memory_ids: list[Any], tenant_id: str, kwargs: dict[str, Any]
+    ) -> None:
+        for m_id in memory_ids:
+            try:
+                await self.contradiction_detector.check(
+                    m_id,
+                    tenant_id,
+                    self.memory_storage,
+                    self.vector_store,
+                    self.embedding_provider,
+                    project=kwargs.get("project"),
+                )
+            except Exception as e:
+                logger.warning(
+                    "contradiction_check_failed", memory_id=str(m_id), error=str(e)
+                )
+
+    def _apply_origin(self, kwargs: dict[str, Any]) -> None:
+        """Move a structured source into metadata, leaving its label.
+
+        Storage keeps free-text sources only, so a MemorySource (or its
+        dict) travels under metadata["origin"]; see models.provenance.
+        """
+        source = kwargs.get("source")
+        if source is None or isinstance(source, str):
+            return
+        origin = MemorySource.coerce(source)
+        assert origin is not None
+        kwargs["metadata"] = {
+            **(kwargs.get("metadata") or {}),
+            ORIGIN_KEY: origin.as_metadata(),
+        }
+        kwargs["source"] = origin.label
+
+    def _apply_confidence(self, kwargs: dict[str, Any]) -> None:
+        """Move a confidence argument into metadata, where storage keeps it."""
+        confidence = kwargs.pop("confidence", None)
+        if confidence is None:
+            return
+        from rae_core.exceptions.base import ValidationError
+
+        confidence = float(confidence)
+        if not 0.0 <= confidence <= 1.0:
+            raise ValidationError("confidence must be between 0 and 1")
+        kwargs["metadata"] = {
+            **(kwargs.get("metadata") or {}),
+            CONFIDENCE_KEY: confidence,
+        }
+
+    def _apply_namespace(
+        self, kwargs: dict[str, Any], tenant_id: str | None = None
+    ) -> None:
+        """Move a namespace argument into metadata, where storage keeps it."""
+        namespace = kwargs.pop("namespace", None)
+        if namespace is None:
+            return
+        tenant_id = tenant_id or kwargs.get("tenant_id", "default")
+        self._namespaces().ensure(tenant_id, namespace)
+        kwargs["metadata"] = {
+            **(kwargs.get("metadata") or {}),
+            NAMESPACE_KEY: namespace,
+        }
+
+    def _namespaces(self) -> Any:
+        if self.namespace_manager is None:
+            from rae_core.governance.namespaces import NamespaceManager
+
+            self.namespace_manager = NamespaceManager(
+                self.memory_storage, vector_store=self.vector_store
             )
-            emb = {name: e[0] for name, e in embs_dict.items() if e}
-        else:
-            emb = await self.embedding_provider.embed_text(
-                content, task_type="search_document"
+        return self.namespace_manager
+
+    async def embed_documents(
+        self, texts: list[str], tenant_id: str
+    ) -> list[list[float] | dict[str, list[float]]]:
+        """Embed texts for storage, one provider call per model.
+
+        Honours the tenant's embedding model selection and counts the
+        embeddings against its quota. Each text gets one vector, or one per
+        model with an EmbeddingManager.
+        """
+        if self.quota_manager is not None:
+            await self.quota_manager.consume_embeddings(tenant_id, len(texts))
+
+        from rae_core.embedding.manager import EmbeddingManager
+
+        with span("rae.embed", tenant_id=tenant_id, texts=len(texts)):
+            if hasattr(self.embedding_provider, "generate_all_embeddings"):
+                options = (
+                    {"tenant_id": tenant_id}
+                    if isinstance(self.embedding_provider, EmbeddingManager)
+                    else {}
+                )
+                embs_dict = await self.embedding_provider.generate_all_embeddings(
+                    texts, task_type="search_document", **options
+                )
+                return [
+                    {name: embs[i] for name, embs in embs_dict.items() if embs}
+                    for i in range(len(texts))
+                ]
+            embeddings: list[list[float] | dict[str, list[float]]] = list(
+                await self.embedding_provider.embed_batch(
+                    texts, task_type="search_document"
+                )
             )
+            return embeddings
+
+    async def _embed_and_store_vector(self, m_id, content, tenant_id, **kwargs):
+        if self.quota_manager is not None:
+            await self.quota_manager.consume_embeddings(tenant_id)
+
+        from rae_core.embedding.manager import EmbeddingManager
+
+        with span("rae.embed", tenant_id=tenant_id, memory_id=m_id):
+            if isinstance(self.embedding_provider, EmbeddingManager):
+                # Honours the tenant's embedding model selection
+                embs_dict = await self.embedding_provider.generate_all_embeddings(
+                    [content], task_type="search_document", tenant_id=tenant_id
+                )
+                emb = {name: e[0] for name, e in embs_dict.items() if e}
+            elif hasattr(self.embedding_provider, "generate_all_embeddings"):
+                embs_dict = await self.embedding_provider.generate_all_embeddings(
+                    [content], task_type="search_document"
+                )
+                emb = {name: e[0] for name, e in embs_dict.items() if e}
+            else:
+                emb = await self.embedding_provider.embed_text(
+                    content, task_type="search_document"
+                )
 
         vector_meta = kwargs.copy()
+        # Top-level, so vector stores can filter searches by namespace
+        vector_meta[NAMESPACE_KEY] = memory_namespace(kwargs)
 
-        await self.vector_store.store_vector(m_id, emb, tenant_id, metadata=vector_meta)
+        with span("rae.store_vector", tenant_id=tenant_id, memory_id=m_id):
+            await self.vector_store.store_vector(
+                m_id, emb, tenant_id, metadata=vector_meta
+            )
 
     def get_status(self) -> dict[str, Any]:
         return {
@@ -528,6 +1926,25 @@ class RAEEngine:
             },
         }
 
+    async def check_health(self, **components: Any) -> "SystemHealthReport":
+        """Check the backends of the engine with their health checks.
+
+        Storage, vector store, embedding provider and cache are checked
+        when configured; further backends (e.g. graph_store=...) can be
+        passed by name.
+        """
+        from rae_core.health import SystemHealth
+
+        return await SystemHealth(
+            {
+                "storage": self.memory_storage,
+                "vector_store": self.vector_store,
+                "embedding": self.embedding_provider,
+                "cache": self.cache_provider,
+                **components,
+            }
+        ).check_all()
+
     async def get_statistics(self, tenant_id: str = "local") -> dict[str, Any]:
         """Get memory statistics."""
         stats = {"total_count": 0, "layer_counts": {}}
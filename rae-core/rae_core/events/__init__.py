@@ -0,0 +1,42 @@
+"""Memory lifecycle events for RAE-core.
+
+Embedding, graph indexing, audit and external sync can subscribe to a
+MemoryEventBus instead of being called directly by the write path.
+External systems (webhooks, Kafka) are fed through an outbox: events are
+persisted first and delivered by an OutboxDispatcher with per-sink cursors.
+"""
+
+from rae_core.events.bus import MemoryEventBus, Subscription
+from rae_core.events.models import (
+    BudgetThresholdCrossed,
+    ConfigChanged,
+    MemoryDeleted,
+    MemoryEvent,
+    MemoryPromoted,
+    MemoryRestored,
+    MemoryStored,
+    MemoryUpdated,
+    ReflectionCreated,
+)
+from rae_core.events.outbox import OutboxDispatcher, OutboxSink
+from rae_core.events.sinks import KafkaSink, WebhookSink
+from rae_core.events.storage import EventPublishingStorage
+
+__all__ = [
+    "BudgetThresholdCrossed",
+    "ConfigChanged",
+    "EventPublishingStorage",
+    "KafkaSink",
+    "MemoryDeleted",
+    "MemoryEvent",
+    "MemoryEventBus",
+    "MemoryPromoted",
+    "MemoryRestored",
+    "MemoryStored",
+    "MemoryUpdated",
+    "OutboxDispatcher",
+    "OutboxSink",
+    "ReflectionCreated",
+    "Subscription",
+    "WebhookSink",
+]
@@ -0,0 +1,116 @@
+"""In-process publish/subscribe bus for memory lifecycle events."""
+
+import asyncio
+import inspect
+from collections.abc import Awaitable, Callable
+from typing import Any, TypeVar
+
+import structlog
+
+from rae_core.events.models import MemoryEvent
+
+logger = structlog.get_logger(__name__)
+
+E = TypeVar("E", bound=MemoryEvent)
+EventHandler = Callable[[Any], Awaitable[None] | None]
+
+
+class Subscription:
+    """Handle returned by subscribe(); call unsubscribe() to detach."""
+
+    def __init__(
+        self,
+        bus: "MemoryEventBus",
+        event_type: type[MemoryEvent],
+        handler: EventHandler,
+    ):
+        self._bus = bus
+        self.event_type = event_type
+        self.handler = handler
+
+    def unsubscribe(self) -> None:
+        self._bus._remove(self)
+
+
+class MemoryEventBus:
+    """Dispatches memory lifecycle events to registered subscribers.
+
+    Handlers subscribe to an event class and also receive its subclasses, so
+    subscribing to MemoryEvent observes everything. Handlers may be sync or
+    async; they run in registration order. A failing handler is logged and
+    does not prevent delivery to the others unless raise_errors is set.
+    """
+
+    def __init__(self, raise_errors: bool = False):
+        """Initialize event bus.
+
+        Args:
+            raise_errors: Re-raise the first handler exception after dispatch
+        """
+        self.raise_errors = raise_errors
+        self._subscriptions: list[Subscription] = []
+
+    def subscribe(
+        self, event_type: type[E], handler: Callable[[E], Awaitable[None] | None]
+    ) -> Subscription:
+        """Register a handler for an event type (and its subclasses)."""
+        subscription = Subscription(self, event_type, handler)
+        self._subscriptions.append(subscription)
+        return subscription
+
+    def on(
+        self, event_type: type[E]
+    ) -> Callable[[Callable[[E], Any]], Callable[[E], Any]]:
+        """Decorator form of subscribe()."""
+
+        def decorator(handler: Callable[[E], Any]) -> Callable[[E], Any]:
+            self.subscribe(event_type, handler)
+            return handler
+
+        return decorator
+
+    def subscriber_count(self, event_type: type[MemoryEvent] = MemoryEvent) -> int:
+        """Number of handlers that would receive an event of this type."""
+        return sum(
+            1 for s in self._subscriptions if issubclass(event_type, s.event_type)
+        )
+
+    async def publish(self, event: MemoryEvent) -> int:
+        """Deliver an event to all matching handlers.
+
+        Returns:
+            Number of handlers that completed without error
+        """
+        delivered = 0
+        first_error: Exception | None = None
+
+        for subscription in list(self._subscriptions):
+            if not isinstance(event, subscription.event_type):
+                continue
+            try:
+                result = subscription.handler(event)
+                if inspect.isawaitable(result):
+                    await result
+                delivered += 1
+            except asyncio.CancelledError:
+                raise
+            except Exception as e:
+                logger.warning(
+                    "memory_event_handler_failed",
+                    event_type=type(event).__name__,
+                    handler=getattr(subscription.handler, "__name__", "handler"),
+                    error=str(e),
+                )
+                first_error = first_error or e
+
+        if first_error is not None and self.raise_errors:
+            raise first_error
+        return delivered
+
+    def clear(self) -> None:
+        """Remove all subscriptions."""
+        self._subscriptions.clear()
+
+    def _remove(self, subscription: Subscription) -> None:
+        if subscription in self._subscriptions:
+            self._subscriptions.remove(subscription)
@@ -0,0 +1,85 @@
+"""Typed memory lifecycle events."""
+
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, Field
+
+
+class MemoryEvent(BaseModel):
+    """Base class for all memory lifecycle events."""
+
+    event_id: UUID = Field(default_factory=uuid4)
+    occurred_at: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
+    tenant_id: str
+    memory_id: UUID
+    agent_id: str | None = None
+    actor: str | None = Field(
+        default=None, description="User or service that caused the change"
+    )
+
+
+class MemoryStored(MemoryEvent):
+    """A new memory was written to storage."""
+
+    layer: str
+    content: str
+    tags: list[str] = Field(default_factory=list)
+    metadata: dict[str, Any] = Field(default_factory=dict)
+
+
+class MemoryUpdated(MemoryEvent):
+    """Fields of an existing memory were changed."""
+
+    changes: dict[str, Any] = Field(
+        default_factory=dict, description="Updated fields and their new values"
+    )
+    diff: list[dict[str, Any]] = Field(
+        default_factory=list, description="Field-level old/new values"
+    )
+
+
+class MemoryDeleted(MemoryEvent):
+    """A memory was removed (or moved to the trash when soft is set)."""
+
+    soft: bool = False
+
+
+class MemoryRestored(MemoryEvent):
+    """A soft-deleted memory was restored from the trash."""
+
+
+class MemoryPromoted(MemoryEvent):
+    """A memory moved to a different layer."""
+
+    from_layer: str
+    to_layer: str
+
+
+class ReflectionCreated(MemoryEvent):
+    """A reflection was generated; memory_id is the stored reflection."""
+
+    reflection_type: str
+    source_memory_ids: list[UUID] = Field(default_factory=list)
+
+
+class BudgetThresholdCrossed(MemoryEvent):
+    """An agent's usage crossed a budget threshold; memory_id caused it."""
+
+    resource: str
+    threshold: float = Field(description="Fraction of the limit that was crossed")
+    limit: float
+    used: float
+
+
+class ConfigChanged(MemoryEvent):
+    """Runtime configuration was reloaded; not tied to a tenant or memory."""
+
+    tenant_id: str = "*"
+    memory_id: UUID = Field(default=UUID(int=0))
+    version: int = Field(description="Configuration version after the change")
+    changed: list[str] = Field(
+        default_factory=list, description="Top-level fields that changed"
+    )
+    source: str | None = Field(default=None, description="File the change came from")
@@ -0,0 +1,142 @@
+"""Store-and-forward delivery of memory events from an outbox to sinks."""
+
+import asyncio
+import time
+from collections.abc import Awaitable, Callable
+
+import structlog
+
+from rae_core.interfaces.outbox import IOutboxStore
+from rae_core.models.outbox import OutboxEntry
+
+logger = structlog.get_logger(__name__)
+
+OutboxSink = Callable[[OutboxEntry], Awaitable[None]]
+
+
+class OutboxDispatcher:
+    """Delivers outbox entries to sinks with at-least-once guarantees.
+
+    Every sink has its own cursor in the outbox, advanced only after the sink
+    accepted an entry, so entries reach each sink in sequence order and an
+    entry is redelivered if the process stops between delivery and
+    acknowledgement (sinks should deduplicate on event_id). A failing sink is
+    retried from its cursor with exponential backoff and never holds back the
+    other sinks.
+
+    Events reach the outbox either transactionally (SQLiteStorage with an
+    outbox) or by wrapping any storage in EventPublishingStorage with the
+    outbox as its bus:
+
+        outbox = SQLiteOutboxStore("rae.db")
+        storage = SQLiteStorage("rae.db", outbox=outbox)
+        dispatcher = OutboxDispatcher(outbox, {"audit-hook": WebhookSink(url)})
+        asyncio.create_task(dispatcher.run())
+    """
+
+    def __init__(
+        self,
+        outbox: IOutboxStore,
+        sinks: dict[str, OutboxSink] | None = None,
+        batch_size: int = 100,
+        poll_interval: float = 1.0,
+        retry_backoff: float = 1.0,
+        max_backoff: float = 60.0,
+        clock: Callable[[], float] | None = None,
+    ):
+        """Initialize dispatcher.
+
+        Args:
+            outbox: Outbox the entries are read from
+            sinks: Delivery targets by name; the name keys the sink's cursor
+            batch_size: Entries read per sink and pass
+            poll_interval: Seconds between passes in run()
+            retry_backoff: Delay before the first retry of a failing sink
+            max_backoff: Upper bound of the retry delay
+            clock: Monotonic time source (seconds)
+        """
+        self.outbox = outbox
+        self.sinks: dict[str, OutboxSink] = dict(sinks or {})
+        self.batch_size = batch_size
+        self.poll_interval = poll_interval
+        self.retry_backoff = retry_backoff
+        self.max_backoff = max_backoff
+        self._clock = clock or time.monotonic
+        self._failures: dict[str, int] = {}
+        self._retry_at: dict[str, float] = {}
+        self._stopped = asyncio.Event()
+
+    def add_sink(self, name: str, sink: OutboxSink) -> None:
+        """Register a sink; a new name starts at the oldest retained entry."""
+        self.sinks[name] = sink
+
+    def failures(self, name: str) -> int:
+        """Consecutive failed deliveries of a sink."""
+        return self._failures.get(name, 0)
+
+    async def dispatch_once(self) -> dict[str, int]:
+        """Run one delivery pass over all sinks not waiting to retry.
+
+        Returns:
+            Entries delivered per sink
+        """
+        delivered = {}
+        for name, sink in list(self.sinks.items()):
+            if self._clock() < self._retry_at.get(name, 0.0):
+                continue
+            delivered[name] = await self._dispatch_sink(name, sink)
+        return delivered
+
+    async def _dispatch_sink(self, name: str, sink: OutboxSink) -> int:
+        cursor = await self.outbox.get_cursor(name)
+        entries = await self.outbox.read(cursor, limit=self.batch_size)
+        delivered = 0
+        for entry in entries:
+            try:
+                await sink(entry)
+            except asyncio.CancelledError:
+                raise
+            except Exception as e:
+                failures = self._failures.get(name, 0) + 1
+                self._failures[name] = failures
+                delay = min(self.max_backoff, self.retry_backoff * 2 ** (failures - 1))
+                self._retry_at[name] = self._clock() + delay
+                logger.warning(
+                    "outbox_delivery_failed",
+                    sink=name,
+                    sequence=entry.sequence,
+                    event_type=entry.event_type,
+                    failures=failures,
+                    retry_in=delay,
+                    error=str(e),
+                )
+                return delivered
+            await self.outbox.set_cursor(name, entry.sequence)
+            delivered += 1
+
+        self._failures.pop(name, None)
+        self._retry_at.pop(name, None)
+        return delivered
+
+    async def prune(self) -> int:
+        """Delete entries every registered sink has acknowledged."""
+        if not self.sinks:
+            return 0
+        cursors = [await self.outbox.get_cursor(name) for name in self.sinks]
+        return await self.outbox.prune(min(cursors))
+
+    async def run(self) -> None:
+        """Dispatch until stop() is called."""
+        self._stopped.clear()
+        while not self._stopped.is_set():
+            delivered = await self.dispatch_once()
+            if any(count == self.batch_size for count in delivered.values()):
+                # A sink may have more entries waiting; don't sleep
+                continue
+            try:
+                await asyncio.wait_for(self._stopped.wait(), self.poll_interval)
+            except asyncio.TimeoutError:
+                pass
+
+    def stop(self) -> None:
+        self._stopped.set()
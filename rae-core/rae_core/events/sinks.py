@@ -0,0 +1,98 @@
+"""Outbox sinks delivering memory events to external systems."""
+
+import json
+from typing import Any
+
+from rae_core.models.outbox import OutboxEntry
+
+EVENT_ID_HEADER = "X-RAE-Event-Id"
+EVENT_TYPE_HEADER = "X-RAE-Event-Type"
+
+
+def envelope(entry: OutboxEntry) -> dict[str, Any]:
+    """JSON message describing an outbox entry."""
+    return {
+        "event_id": str(entry.event_id),
+        "event_type": entry.event_type,
+        "sequence": entry.sequence,
+        "tenant_id": entry.tenant_id,
+        "event": entry.payload,
+    }
+
+
+class WebhookSink:
+    """POSTs each event as JSON to a URL; any non-2xx response is a failure.
+
+    The event id and type are also sent as headers so receivers can
+    deduplicate redeliveries. Requires httpx unless a client is given.
+    """
+
+    def __init__(
+        self,
+        url: str,
+        headers: dict[str, str] | None = None,
+        timeout: float = 10.0,
+        client: Any = None,
+    ):
+        """Initialize webhook sink.
+
+        Args:
+            url: Endpoint receiving the events
+            headers: Extra request headers (e.g. authorization)
+            timeout: Request timeout in seconds
+            client: httpx.AsyncClient-compatible client to use
+        """
+        self.url = url
+        self.headers = dict(headers or {})
+        self.timeout = timeout
+        self._client = client
+
+    def _get_client(self) -> Any:
+        if self._client is None:
+            import httpx
+
+            self._client = httpx.AsyncClient(timeout=self.timeout)
+        return self._client
+
+    async def __call__(self, entry: OutboxEntry) -> None:
+        response = await self._get_client().post(
+            self.url,
+            json=envelope(entry),
+            headers={
+                **self.headers,
+                EVENT_ID_HEADER: str(entry.event_id),
+                EVENT_TYPE_HEADER: entry.event_type,
+            },
+        )
+        response.raise_for_status()
+
+    async def close(self) -> None:
+        if self._client is not None:
+            await self._client.aclose()
+            self._client = None
+
+
+class KafkaSink:
+    """Produces each event to a Kafka topic, keyed by tenant.
+
+    Keying by tenant keeps the events of a tenant in one partition, so
+    consumers see them in outbox order.
+    """
+
+    def __init__(self, producer: Any, topic: str):
+        """Initialize Kafka sink.
+
+        Args:
+            producer: Started aiokafka.AIOKafkaProducer, or any object with
+                an async send_and_wait(topic, value=..., key=...)
+            topic: Destination topic
+        """
+        self.producer = producer
+        self.topic = topic
+
+    async def __call__(self, entry: OutboxEntry) -> None:
+        await self.producer.send_and_wait(
+            self.topic,
+            value=json.dumps(envelope(entry)).encode(),
+            key=entry.tenant_id.encode(),
+        )
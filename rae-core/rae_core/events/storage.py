@@ -0,0 +1,179 @@
+"""Storage decorator publishing lifecycle events for every write."""
+
+from typing import Any
+from uuid import UUID
+
+from rae_core.events.bus import MemoryEventBus
+from rae_core.events.models import (
+    MemoryDeleted,
+    MemoryPromoted,
+    MemoryRestored,
+    MemoryStored,
+    MemoryUpdated,
+)
+from rae_core.interfaces.outbox import IOutboxStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.utils.changelog import field_changes
+
+# Fields compared when building the diff of an update
+_DIFF_FIELDS = ("content", "layer", "tags", "metadata", "importance")
+
+
+class EventPublishingStorage:
+    """Wraps an IMemoryStorage and publishes events after successful writes.
+
+    Store, update, revert, delete, soft delete and restore publish
+    MemoryStored, MemoryUpdated (plus MemoryPromoted when the layer changes),
+    MemoryDeleted and MemoryRestored. All other calls are forwarded to the
+    wrapped storage unchanged.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        event_bus: MemoryEventBus | IOutboxStore,
+        actor: str | None = None,
+    ):
+        """Initialize the decorator.
+
+        Args:
+            storage: Storage backend receiving the calls
+            event_bus: Bus the events are published on, or an outbox to
+                persist them for OutboxDispatcher
+            actor: Default actor attributed to published events
+        """
+        self.storage = storage
+        self.event_bus = event_bus
+        self.actor = actor
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(self.storage, name)
+
+    def with_actor(self, actor: str | None) -> "EventPublishingStorage":
+        """Same storage and bus, attributing events to another actor."""
+        return EventPublishingStorage(self.storage, self.event_bus, actor)
+
+    async def store_memory(self, **kwargs: Any) -> UUID:
+        memory_id = await self.storage.store_memory(**kwargs)
+        await self.event_bus.publish(
+            MemoryStored(
+                tenant_id=kwargs["tenant_id"],
+                memory_id=memory_id,
+                agent_id=kwargs.get("agent_id"),
+                actor=self.actor,
+                layer=kwargs.get("layer") or "episodic",
+                content=kwargs.get("content", ""),
+                tags=kwargs.get("tags") or [],
+                metadata=kwargs.get("metadata") or {},
+            )
+        )
+        return memory_id
+
+    async def update_memory(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        updates: dict[str, Any],
+        changed_by: str | None = None,
+        expected_version: int | None = None,
+    ) -> bool:
+        before = await self.storage.get_memory(memory_id, tenant_id)
+
+        # Only forward attribution and versions when given, for storages
+        # predating them
+        extra: dict[str, Any] = {}
+        if changed_by is not None:
+            extra["changed_by"] = changed_by
+        if expected_version is not None:
+            extra["expected_version"] = expected_version
+        if not await self.storage.update_memory(memory_id, tenant_id, updates, **extra):
+            return False
+
+        await self._publish_update(
+            before, memory_id, tenant_id, dict(updates), changed_by or self.actor
+        )
+        return True
+
+    async def revert_to_version(
+        self, memory_id: UUID, tenant_id: str, version: int
+    ) -> bool:
+        before = await self.storage.get_memory(memory_id, tenant_id)
+        if not await self.storage.revert_to_version(memory_id, tenant_id, version):
+            return False
+
+        after = await self.storage.get_memory(memory_id, tenant_id) or {}
+        changes = {k: after[k] for k in _DIFF_FIELDS if k in after}
+        await self._publish_update(before, memory_id, tenant_id, changes, self.actor)
+        return True
+
+    async def delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        return await self._delete(memory_id, tenant_id, soft=False)
+
+    async def soft_delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        return await self._delete(memory_id, tenant_id, soft=True)
+
+    async def restore_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        if not await self.storage.restore_memory(memory_id, tenant_id):
+            return False
+
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        await self.event_bus.publish(
+            MemoryRestored(
+                tenant_id=tenant_id,
+                memory_id=memory_id,
+                agent_id=memory.get("agent_id") if memory else None,
+                actor=self.actor,
+            )
+        )
+        return True
+
+    async def _publish_update(
+        self,
+        before: dict[str, Any] | None,
+        memory_id: UUID,
+        tenant_id: str,
+        changes: dict[str, Any],
+        actor: str | None,
+    ) -> None:
+        agent_id = before.get("agent_id") if before else None
+        diff = field_changes(before or {}, changes)
+        await self.event_bus.publish(
+            MemoryUpdated(
+                tenant_id=tenant_id,
+                memory_id=memory_id,
+                agent_id=agent_id,
+                actor=actor,
+                changes={c["field"]: changes[c["field"]] for c in diff},
+                diff=diff,
+            )
+        )
+        if before and "layer" in changes and before.get("layer") != changes["layer"]:
+            await self.event_bus.publish(
+                MemoryPromoted(
+                    tenant_id=tenant_id,
+                    memory_id=memory_id,
+                    agent_id=agent_id,
+                    actor=actor,
+                    from_layer=str(before.get("layer")),
+                    to_layer=str(changes["layer"]),
+                )
+            )
+
+    async def _delete(self, memory_id: UUID, tenant_id: str, soft: bool) -> bool:
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        if soft:
+            deleted = await self.storage.soft_delete_memory(memory_id, tenant_id)
+        else:
+            deleted = await self.storage.delete_memory(memory_id, tenant_id)
+
+        if deleted:
+            await self.event_bus.publish(
+                MemoryDeleted(
+                    tenant_id=tenant_id,
+                    memory_id=memory_id,
+                    agent_id=memory.get("agent_id") if memory else None,
+                    actor=self.actor,
+                    soft=soft,
+                )
+            )
+        return deleted
@@ -0,0 +1,96 @@
+"""Conversion of backend-specific errors into the RAE error hierarchy.
+
+Adapters wrap their driver calls in backend_errors() so callers only ever see
+RAEError subclasses and can tell a missing resource (NotFoundError) from a
+conflicting write (ConflictError), an exhausted quota (QuotaExceededError) or
+a backend that is down or busy (BackendUnavailableError).
+
+Drivers are matched by exception class name so that optional dependencies
+(asyncpg, redis, qdrant-client) never need to be imported here.
+"""
+
+import sqlite3
+from collections.abc import Iterator
+from contextlib import contextmanager
+
+from rae_core.exceptions.base import (
+    BackendUnavailableError,
+    ConflictError,
+    RAEError,
+    StorageError,
+)
+
+# Constraint violations: the write conflicts with existing rows
+_CONFLICT_ERRORS = frozenset(
+    {
+        "IntegrityError",
+        "IntegrityConstraintViolationError",
+        "UniqueViolationError",
+        "ForeignKeyViolationError",
+        "SerializationError",
+        "DeadlockDetectedError",
+    }
+)
+
+# Connectivity failures: the backend may accept the operation later
+_UNAVAILABLE_ERRORS = frozenset(
+    {
+        "ConnectionError",
+        "TimeoutError",
+        "PostgresConnectionError",
+        "ConnectionDoesNotExistError",
+        "CannotConnectNowError",
+        "TooManyConnectionsError",
+        "ResponseHandlingException",
+        "BusyLoadingError",
+    }
+)
+
+# Base classes of driver errors that carry no more specific meaning
+_STORAGE_ERRORS = frozenset({"PostgresError", "RedisError", "UnexpectedResponse"})
+
+# SQLite reports lock contention and I/O trouble as OperationalError
+_SQLITE_UNAVAILABLE_MESSAGES = (
+    "database is locked",
+    "database table is locked",
+    "unable to open database",
+    "disk i/o error",
+)
+
+
+def translate_backend_error(exc: BaseException, backend: str) -> RAEError | None:
+    """Map a driver exception onto the RAE error hierarchy.
+
+    Returns:
+        The equivalent RAEError (exc itself if it already is one), or None if
+        exc is not a recognised backend error (e.g. a programming error)
+    """
+    if isinstance(exc, RAEError):
+        return exc
+
+    names = {cls.__name__ for cls in type(exc).__mro__}
+    message = str(exc) or type(exc).__name__
+
+    if names & _CONFLICT_ERRORS:
+        return ConflictError(f"{backend}: {message}")
+    if isinstance(exc, sqlite3.OperationalError):
+        if message.lower().startswith(_SQLITE_UNAVAILABLE_MESSAGES):
+            return BackendUnavailableError(backend, message)
+        return StorageError(f"{backend}: {message}")
+    if names & _UNAVAILABLE_ERRORS or isinstance(exc, (ConnectionError, TimeoutError)):
+        return BackendUnavailableError(backend, message)
+    if isinstance(exc, sqlite3.Error) or names & _STORAGE_ERRORS:
+        return StorageError(f"{backend}: {message}")
+    return None
+
+
+@contextmanager
+def backend_errors(backend: str) -> Iterator[None]:
+    """Re-raise recognised driver exceptions as RAEError subclasses."""
+    try:
+        yield
+    except Exception as e:
+        translated = translate_backend_error(e, backend)
+        if translated is None or translated is e:
+            raise
+        raise translated from e
@@ -19,11 +19,101 @@ class ValidationError(RAEError):
     pass
 
 
+class DimensionMismatchError(ValidationError):
+    """Raised when a vector's size differs from its collection's dimension.
+
+    Scores between vectors of different sizes are meaningless, so such
+    vectors are neither stored nor searched with.
+    """
+
+    def __init__(self, tenant_id: str, model: str, expected: int, actual: int) -> None:
+        super().__init__(
+            f"Embedding model {model} of tenant {tenant_id} has {expected}-d "
+            f"vectors, got {actual}"
+        )
+        self.tenant_id = tenant_id
+        self.model = model
+        self.expected = expected
+        self.actual = actual
+
+
+class NotFoundError(RAEError, LookupError):
+    """Raised when a referenced resource does not exist (for the tenant)."""
+
+    def __init__(
+        self, resource: str, identifier: object, tenant_id: str | None = None
+    ) -> None:
+        scope = f" for tenant {tenant_id}" if tenant_id is not None else ""
+        super().__init__(f"{resource} {identifier} not found{scope}")
+        self.resource = resource
+        self.identifier = identifier
+        self.tenant_id = tenant_id
+
+
+class ConflictError(RAEError):
+    """Raised when a write conflicts with existing state (duplicate, constraint)."""
+
+    pass
+
+
+class VersionConflictError(ConflictError):
+    """Raised when a memory changed since the version an update was based on.
+
+    Re-read the memory and retry with its current version.
+    """
+
+    def __init__(
+        self, memory_id: object, expected_version: int, actual_version: int
+    ) -> None:
+        super().__init__(
+            f"Memory {memory_id} is at version {actual_version}, "
+            f"expected {expected_version}"
+        )
+        self.memory_id = memory_id
+        self.expected_version = expected_version
+        self.actual_version = actual_version
+
+
+class BackendUnavailableError(StorageError):
+    """Raised when a storage backend cannot be reached or is temporarily busy.
+
+    Unlike other storage errors, retrying the operation later may succeed.
+    """
+
+    def __init__(self, backend: str, message: str) -> None:
+        super().__init__(f"{backend} unavailable: {message}")
+        self.backend = backend
+
+
 class SecurityPolicyViolationError(RAEError):
     """Exception raised when a security policy is violated (ISO 27000)."""
 
     pass
 
+class AuthenticationError(RAEError):
+    """Raised when a caller's credentials are missing, invalid or expired."""
+
+    pass
+
+
+class PermissionDeniedError(SecurityPolicyViolationError):
+    """Raised when an authenticated principal lacks a required permission."""
+
+    def __init__(
+        self,
+        subject: str,
+        action: str,
+        layer: str | None = None,
+        scope: str | None = None,
+    ) -> None:
+        target = f" on layer {layer or '*'}, scope {scope or '*'}"
+        super().__init__(f"{subject} may not {action}{target}")
+        self.subject = subject
+        self.action = action
+        self.layer = layer
+        self.scope = scope
+
+
 class ContractViolationError(RAEError):
     """Exception raised when an agentic contract is violated."""
     pass
@@ -32,3 +122,80 @@ class ContractViolationError(RAEError):
 class InfrastructureError(RAEError):
     """Exception raised for underlying infrastructure failures (Redis, Qdrant, etc)."""
     pass
+
+
+class GraphTraversalError(RAEError):
+    """Base exception for graph traversal safeguards."""
+
+    pass
+
+
+class FanOutExceededError(GraphTraversalError):
+    """Raised when a node has more neighbors than the allowed fan-out."""
+
+    def __init__(self, node_id: object, fan_out: int, limit: int) -> None:
+        super().__init__(
+            f"Node {node_id} has {fan_out} neighbors, exceeding fan-out limit {limit}"
+        )
+        self.node_id = node_id
+        self.fan_out = fan_out
+        self.limit = limit
+
+
+class VisitBudgetExceededError(GraphTraversalError):
+    """Raised when a traversal would visit more nodes than its budget."""
+
+    def __init__(self, limit: int) -> None:
+        super().__init__(f"Graph traversal exceeded node-visit budget of {limit}")
+        self.limit = limit
+
+
+class CycleDetectedError(GraphTraversalError):
+    """Raised when a traversal reaches one of its own ancestors."""
+
+    def __init__(self, node_id: object) -> None:
+        super().__init__(f"Cycle detected at node {node_id}")
+        self.node_id = node_id
+
+
+class QuotaExceededError(RAEError):
+    """Raised when a write would exceed a tenant's quota."""
+
+    def __init__(
+        self, tenant_id: str, resource: str, limit: int, current: int, requested: int
+    ) -> None:
+        super().__init__(
+            f"Tenant {tenant_id} {resource} quota exceeded: "
+            f"{current} used + {requested} requested > {limit}"
+        )
+        self.tenant_id = tenant_id
+        self.resource = resource
+        self.limit = limit
+        self.current = current
+        self.requested = requested
+
+
+class OverloadedError(RAEError):
+    """Raised when a tenant's operation queue is full and the call is shed.
+
+    Retrying later (with backoff) may succeed.
+    """
+
+    def __init__(self, tenant_id: str, priority: str, queued: int, limit: int) -> None:
+        super().__init__(
+            f"Tenant {tenant_id} overloaded: {queued} {priority} operations "
+            f"queued, limit {limit}"
+        )
+        self.tenant_id = tenant_id
+        self.priority = priority
+        self.queued = queued
+        self.limit = limit
+
+
+class ReadOnlyStorageError(StorageError):
+    """Raised when writing to a read-only backend (e.g. a knowledge pack)."""
+
+    def __init__(self, backend: str, operation: str) -> None:
+        super().__init__(f"{backend} is read-only: {operation} is not supported")
+        self.backend = backend
+        self.operation = operation
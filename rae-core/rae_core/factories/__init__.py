@@ -0,0 +1,16 @@
+"""Construction of RAE-core components from configuration."""
+
+from rae_core.factories.builder import RAEBuilder, RAESystem
+from rae_core.factories.registry import (
+    BACKENDS,
+    BackendRegistry,
+    register_backend,
+)
+
+__all__ = [
+    "BACKENDS",
+    "BackendRegistry",
+    "RAEBuilder",
+    "RAESystem",
+    "register_backend",
+]
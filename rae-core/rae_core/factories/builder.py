@@ -0,0 +1,183 @@
+"""Wiring of RAEEngine from a declarative configuration.
+
+RAEBuilder creates the backends named by a config.RAEConfig and composes
+them into an engine. Backends are looked up by kind and name in a
+factories.registry.BackendRegistry; the bundled ones are imported only when
+selected, so a configuration naming "memory" backends needs no optional
+extras:
+
+    system = await RAEBuilder.from_file("rae.toml").build()
+    await system.engine.store_memory(...)
+    await system.close()
+
+Already constructed objects can be passed with with_storage(),
+with_embedding(), ... and take precedence over the configuration.
+"""
+
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+from rae_core.config.backends import BackendConfig, RAEConfig
+from rae_core.embedding.registry import EmbeddingProviderRegistry
+from rae_core.exceptions.base import ValidationError
+from rae_core.factories.registry import (
+    BACKEND_KINDS,
+    BACKENDS,
+    BackendFactory,
+    BackendRegistry,
+)
+
+if TYPE_CHECKING:
+    from rae_core.engine import RAEEngine
+    from rae_core.models.health import SystemHealthReport
+
+
+@dataclass
+class RAESystem:
+    """An engine and the backends it was built from."""
+
+    engine: "RAEEngine"
+    storage: Any
+    vector_store: Any
+    embedding: Any
+    graph: Any = None
+    cache: Any = None
+
+    async def check_health(self) -> "SystemHealthReport":
+        """Health of every backend, the graph store included."""
+        return await self.engine.check_health(graph=self.graph)
+
+    async def close(self) -> None:
+        """Flush buffered memory accesses, then close the backends."""
+        await self.engine.access_tracker.flush()
+        closed: set[int] = set()
+        for backend in (
+            self.vector_store,
+            self.storage,
+            self.graph,
+            self.cache,
+            self.embedding,
+        ):
+            close = getattr(backend, "close", None)
+            if close is not None and id(backend) not in closed:
+                closed.add(id(backend))
+                await close()
+
+
+class RAEBuilder:
+    """Builds an RAEEngine from an RAEConfig."""
+
+    def __init__(
+        self,
+        config: RAEConfig | None = None,
+        embedding_registry: EmbeddingProviderRegistry | None = None,
+        backend_registry: BackendRegistry | None = None,
+    ):
+        """Initialize builder.
+
+        Args:
+            config: Backends to create (RAEConfig.load() if None)
+            embedding_registry: Registry creating the configured embedding
+                providers
+            backend_registry: Registry creating the configured backends (a
+                copy of the process-wide registry if None)
+        """
+        self.config = config if config is not None else RAEConfig.load()
+        self.embedding_registry = embedding_registry or EmbeddingProviderRegistry()
+        self.backend_registry = backend_registry or BACKENDS.copy()
+        self._instances: dict[str, Any] = {}
+
+    @classmethod
+    def from_file(cls, path: str | Path) -> "RAEBuilder":
+        """Builder for a TOML, YAML or JSON configuration file."""
+        return cls(RAEConfig.load(path))
+
+    def register_backend(self, kind: str, name: str, factory: BackendFactory) -> None:
+        """Register (or replace) the factory of a backend name for this builder."""
+        self.backend_registry.register(kind, name, factory)
+
+    def with_storage(self, storage: Any) -> "RAEBuilder":
+        self._instances["storage"] = storage
+        return self
+
+    def with_vector_store(self, vector_store: Any) -> "RAEBuilder":
+        self._instances["vector_store"] = vector_store
+        return self
+
+    def with_graph(self, graph: Any) -> "RAEBuilder":
+        self._instances["graph"] = graph
+        return self
+
+    def with_cache(self, cache: Any) -> "RAEBuilder":
+        self._instances["cache"] = cache
+        return self
+
+    def with_embedding(self, provider: Any) -> "RAEBuilder":
+        self._instances["embedding"] = provider
+        return self
+
+    def with_llm(self, provider: Any) -> "RAEBuilder":
+        self._instances["llm"] = provider
+        return self
+
+    def create_backend(self, kind: str, config: BackendConfig) -> Any:
+        """Instantiate a backend of a kind from its config entry.
+
+        Raises:
+            ValidationError: Unknown backend or invalid options
+        """
+        return self.backend_registry.create(kind, config.backend, **config.options)
+
+    def create_embedding(self) -> Any:
+        """Embedding provider, or an EmbeddingManager for several models.
+
+        Raises:
+            ValidationError: No embedding provider configured or given
+        """
+        if "embedding" in self._instances:
+            return self._instances["embedding"]
+        models = self.config.embedding
+        if not models:
+            raise ValidationError(
+                "No embedding provider configured; add [embedding.default] "
+                "or call with_embedding()"
+            )
+        default = self.config.default_embedding_model
+        if len(models) == 1 and default in models:
+            return self.embedding_registry.from_config(models[default])
+        return self.embedding_registry.build_manager(models, default)
+
+    def _backend(self, kind: str) -> Any:
+        if kind in self._instances:
+            return self._instances[kind]
+        config = getattr(self.config, kind)
+        return self.create_backend(kind, config) if config is not None else None
+
+    async def build(self) -> RAESystem:
+        """Create the backends, initialize them and wire the engine."""
+        from rae_core.engine import RAEEngine
+
+        backends = {kind: self._backend(kind) for kind in BACKEND_KINDS}
+        embedding = self.create_embedding()
+        for backend in backends.values():
+            initialize = getattr(backend, "initialize", None)
+            if initialize is not None:
+                await initialize()
+
+        engine = RAEEngine(
+            memory_storage=backends["storage"],
+            vector_store=backends["vector_store"],
+            embedding_provider=embedding,
+            llm_provider=self._instances.get("llm"),
+            settings=self.config.rae_settings(),
+            cache_provider=backends["cache"],
+        )
+        return RAESystem(
+            engine=engine,
+            storage=backends["storage"],
+            vector_store=backends["vector_store"],
+            embedding=embedding,
+            graph=backends["graph"],
+            cache=backends["cache"],
+        )
@@ -0,0 +1,178 @@
+"""Backend implementations selected by name.
+
+BackendRegistry maps a backend kind (storage, vector_store, graph, cache)
+and name to a factory, so configuration strings such as
+backend = "dynamo" can select implementations that live outside rae-core.
+Packages add theirs in one of two ways:
+
+- at import time, with the register_backend decorator:
+
+      @register_backend("storage", "dynamo")
+      class DynamoStorage: ...
+
+- without being imported first, through an entry point in the
+  "rae_core.backends" group named "<kind>.<name>":
+
+      [project.entry-points."rae_core.backends"]
+      "storage.dynamo" = "rae_dynamo.storage:DynamoStorage"
+
+Factories may be given as "module:attribute" strings; they are imported
+only when their backend is selected, so the bundled backends need their
+optional extras only when used.
+"""
+
+import importlib
+from collections.abc import Callable
+from functools import reduce
+from importlib.metadata import entry_points
+from typing import Any, TypeVar
+
+import structlog
+
+from rae_core.exceptions.base import ValidationError
+
+logger = structlog.get_logger(__name__)
+
+BackendFactory = Callable[..., Any]
+F = TypeVar("F", bound=BackendFactory)
+
+BACKEND_KINDS = ("storage", "vector_store", "graph", "cache")
+
+# Entry point group scanned for third-party backends ("<kind>.<name>")
+ENTRY_POINT_GROUP = "rae_core.backends"
+
+# Bundled backends by kind and name, as "module:class"
+BUILTIN_BACKENDS: dict[str, dict[str, str]] = {
+    "storage": {
+        "memory": "rae_core.adapters.memory.storage:InMemoryStorage",
+        "sqlite": "rae_core.adapters.sqlite.storage:SQLiteStorage",
+        "postgres": "rae_core.adapters.postgres:PostgreSQLStorage",
+    },
+    "vector_store": {
+        "memory": "rae_core.adapters.memory.vector:InMemoryVectorStore",
+        "sqlite": "rae_core.adapters.sqlite.vector:SQLiteVectorStore",
+        "qdrant": "rae_core.adapters.qdrant:QdrantVectorStore",
+    },
+    "graph": {
+        "memory": "rae_core.adapters.memory.graph:InMemoryGraphStore",
+        "sqlite": "rae_core.adapters.sqlite.graph:SQLiteGraphStore",
+    },
+    "cache": {
+        "memory": "rae_core.adapters.memory.cache:InMemoryCache",
+        "redis": "rae_core.adapters.redis:RedisCache",
+    },
+}
+
+
+def _import_backend(path: str) -> BackendFactory:
+    module_name, _, attribute = path.partition(":")
+    module = importlib.import_module(module_name.strip())
+    factory: BackendFactory = reduce(getattr, attribute.strip().split("."), module)
+    return factory
+
+
+class BackendRegistry:
+    """Creates storage, vector, graph and cache backends by name."""
+
+    def __init__(self, include_builtin: bool = True, discover: bool = True):
+        """Initialize registry.
+
+        Args:
+            include_builtin: Register the backends bundled with rae-core
+            discover: Look up unknown names in the rae_core.backends entry
+                points
+        """
+        self._factories: dict[str, dict[str, BackendFactory | str]] = {
+            kind: dict(BUILTIN_BACKENDS[kind]) if include_builtin else {}
+            for kind in BACKEND_KINDS
+        }
+        self._discover = discover
+        self._discovered = False
+
+    def _kind(self, kind: str) -> dict[str, BackendFactory | str]:
+        if kind not in self._factories:
+            raise ValueError(f"Unknown backend kind: {kind}")
+        return self._factories[kind]
+
+    def register(self, kind: str, name: str, factory: BackendFactory | str) -> None:
+        """Register (or replace) the factory of a backend name."""
+        self._kind(kind)[name] = factory
+
+    def backend(self, kind: str, name: str) -> Callable[[F], F]:
+        """Decorator registering a class or factory function under a name."""
+        self._kind(kind)
+
+        def decorate(factory: F) -> F:
+            self.register(kind, name, factory)
+            return factory
+
+        return decorate
+
+    def load_entry_points(self) -> None:
+        """Register the backends advertised by installed packages.
+
+        Names registered explicitly are kept; malformed entries are logged
+        and skipped.
+        """
+        self._discovered = True
+        for entry in entry_points(group=ENTRY_POINT_GROUP):
+            kind, _, name = entry.name.partition(".")
+            if kind not in self._factories or not name:
+                logger.warning("backend_entry_point_invalid", entry=entry.name)
+                continue
+            self._factories[kind].setdefault(name, entry.value)
+
+    def names(self, kind: str) -> list[str]:
+        """Registered backend names of a kind."""
+        if self._discover and not self._discovered:
+            self.load_entry_points()
+        return sorted(self._kind(kind))
+
+    def factory(self, kind: str, name: str) -> BackendFactory:
+        """Factory of a backend, importing it on first use.
+
+        Raises:
+            ValidationError: Unknown backend name
+        """
+        factories = self._kind(kind)
+        if name not in factories and self._discover and not self._discovered:
+            self.load_entry_points()
+        factory = factories.get(name)
+        if factory is None:
+            raise ValidationError(
+                f"Unknown {kind} backend: {name} (known: {self.names(kind)})"
+            )
+        if isinstance(factory, str):
+            factory = factories[name] = _import_backend(factory)
+        return factory
+
+    def create(self, kind: str, name: str, **options: Any) -> Any:
+        """Instantiate a backend by kind and name.
+
+        Raises:
+            ValidationError: Unknown backend or invalid options
+        """
+        factory = self.factory(kind, name)
+        try:
+            return factory(**options)
+        except (TypeError, ValueError) as e:
+            raise ValidationError(
+                f"Invalid options for {kind} backend {name}: {e}"
+            ) from e
+
+    def copy(self) -> "BackendRegistry":
+        """Independent registry with the same backends."""
+        registry = BackendRegistry(include_builtin=False, discover=self._discover)
+        registry._discovered = self._discovered
+        for kind, factories in self._factories.items():
+            registry._factories[kind].update(factories)
+        return registry
+
+
+# Process-wide registry used by RAEBuilder
+BACKENDS = BackendRegistry()
+
+
+def register_backend(kind: str, name: str) -> Callable[[F], F]:
+    """Decorator registering a backend in the process-wide registry."""
+    return BACKENDS.backend(kind, name)
@@ -0,0 +1,33 @@
+"""Governance controls for RAE-core (mission protocol, quotas, budgets, load,
+privacy, ephemeral memories, forgetting, namespaces)."""
+
+from rae_core.governance.budget import AgentBudgetTracker, BudgetTrackingStorage
+from rae_core.governance.ephemeral import EphemeralPurger, is_ephemeral
+from rae_core.governance.forgetting import ForgettingPolicy, ForgettingPruner
+from rae_core.governance.load import LoadShedder
+from rae_core.governance.namespaces import NamespaceManager, validate_namespace
+from rae_core.governance.privacy import (
+    SubjectEraser,
+    generate_subject_report,
+    references_subject,
+    render_subject_report,
+)
+from rae_core.governance.quota import QuotaEnforcingStorage, QuotaManager
+
+__all__ = [
+    "AgentBudgetTracker",
+    "BudgetTrackingStorage",
+    "EphemeralPurger",
+    "ForgettingPolicy",
+    "ForgettingPruner",
+    "LoadShedder",
+    "NamespaceManager",
+    "QuotaEnforcingStorage",
+    "QuotaManager",
+    "SubjectEraser",
+    "generate_subject_report",
+    "is_ephemeral",
+    "references_subject",
+    "render_subject_report",
+    "validate_namespace",
+]
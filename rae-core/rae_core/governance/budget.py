@@ -0,0 +1,349 @@
+"""Per-agent budget tracking and threshold alerts.
+
+AgentBudgetTracker measures what each agent of a tenant has written (live
+memories, content bytes, embeddings and their estimated cost) and publishes
+a BudgetThresholdCrossed event when usage crosses one of the agent's budget
+thresholds. Publish to an outbox to deliver alerts to webhooks. Budgets only
+report and alert; tenant quotas (QuotaManager) are what reject writes.
+
+Memory counts and bytes are seeded from the storage backend; embedding
+counts and spend accumulate from the writes the tracker sees.
+"""
+
+import asyncio
+from typing import Any
+from uuid import UUID
+
+from rae_core.context.window import estimate_tokens
+from rae_core.events.bus import MemoryEventBus
+from rae_core.events.models import BudgetThresholdCrossed
+from rae_core.governance.quota import _BULK_MUTATIONS, _SCAN_PAGE, content_size
+from rae_core.interfaces.outbox import IOutboxStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.budget import AgentBudget, AgentBudgetReport, BudgetResource
+
+DEFAULT_AGENT = "default"
+
+
+class _AgentUsage:
+    __slots__ = ("memories", "bytes", "embeddings", "embedding_spend", "alerted")
+
+    def __init__(self, memories: int, size: int):
+        self.memories = memories
+        self.bytes = size
+        self.embeddings = 0
+        self.embedding_spend = 0.0
+        # (resource, threshold) pairs already alerted and not yet recovered
+        self.alerted: set[tuple[BudgetResource, float]] = set()
+
+    def value(self, resource: BudgetResource) -> float:
+        return {
+            BudgetResource.MEMORIES: self.memories,
+            BudgetResource.BYTES: self.bytes,
+            BudgetResource.EMBEDDING_SPEND: self.embedding_spend,
+        }[resource]
+
+
+class AgentBudgetTracker:
+    """Tracks per-agent usage against budgets and alerts on thresholds.
+
+    Agents without an explicit budget use default_budget.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        default_budget: AgentBudget | None = None,
+        budgets: dict[tuple[str, str], AgentBudget] | None = None,
+        event_bus: MemoryEventBus | IOutboxStore | None = None,
+        embedding_cost_per_1k_tokens: float = 0.0,
+    ):
+        """Initialize budget tracker.
+
+        Args:
+            storage: Backend whose contents usage is seeded from
+            default_budget: Budget of agents without their own
+            budgets: Per-agent budgets keyed by (tenant_id, agent_id)
+            event_bus: Bus or outbox receiving BudgetThresholdCrossed events
+            embedding_cost_per_1k_tokens: Price used to estimate embedding spend
+        """
+        self.storage = storage
+        self.default_budget = default_budget or AgentBudget()
+        self._budgets = dict(budgets or {})
+        self.event_bus = event_bus
+        self.embedding_cost_per_1k_tokens = embedding_cost_per_1k_tokens
+        self._usage: dict[tuple[str, str], _AgentUsage] = {}
+        self._lock = asyncio.Lock()
+
+    def set_budget(self, tenant_id: str, agent_id: str, budget: AgentBudget) -> None:
+        self._budgets[(tenant_id, agent_id)] = budget
+
+    def get_budget(self, tenant_id: str, agent_id: str) -> AgentBudget:
+        return self._budgets.get((tenant_id, agent_id), self.default_budget)
+
+    async def report(self, tenant_id: str, agent_id: str) -> AgentBudgetReport:
+        """Current usage of an agent and its utilization of each limit."""
+        async with self._lock:
+            usage, _ = await self._agent_usage(tenant_id, agent_id)
+            return self._report(tenant_id, agent_id, usage)
+
+    async def reports(self, tenant_id: str) -> list[AgentBudgetReport]:
+        """Reports of every agent of a tenant that is tracked or budgeted."""
+        agents = {a for t, a in self._usage if t == tenant_id}
+        agents |= {a for t, a in self._budgets if t == tenant_id}
+        return [await self.report(tenant_id, agent) for agent in sorted(agents)]
+
+    async def refresh(self, tenant_id: str | None = None) -> None:
+        """Re-read tracked memory counts and bytes from storage.
+
+        Embedding counts and spend are kept.
+        """
+        async with self._lock:
+            for key in list(self._usage):
+                if tenant_id is None or key[0] == tenant_id:
+                    usage = self._usage[key]
+                    usage.memories, usage.bytes = await self._scan(*key)
+
+    async def record_store(
+        self,
+        tenant_id: str,
+        agent_id: str,
+        memory_id: UUID,
+        size: int,
+        embedded_text: str | None = None,
+    ) -> None:
+        """Account for a stored (or restored) memory, after the write.
+
+        embedded_text is the text of the embedding made for it, if any.
+        """
+        async with self._lock:
+            usage, seeded = await self._agent_usage(tenant_id, agent_id)
+            if not seeded:
+                usage.memories += 1
+                usage.bytes += size
+            if embedded_text is not None:
+                self._charge_embedding(usage, embedded_text)
+            events = self._check(tenant_id, agent_id, memory_id, usage)
+        await self._publish(events)
+
+    async def record_embedding(
+        self, tenant_id: str, agent_id: str, memory_id: UUID, text: str
+    ) -> None:
+        """Account for an embedding generated outside of store_memory."""
+        async with self._lock:
+            usage, _ = await self._agent_usage(tenant_id, agent_id)
+            self._charge_embedding(usage, text)
+            events = self._check(tenant_id, agent_id, memory_id, usage)
+        await self._publish(events)
+
+    async def record_resize(
+        self,
+        tenant_id: str,
+        agent_id: str,
+        memory_id: UUID,
+        old_size: int,
+        new_size: int,
+    ) -> None:
+        """Account for content replaced by an update, after the write."""
+        async with self._lock:
+            usage, seeded = await self._agent_usage(tenant_id, agent_id)
+            if not seeded:
+                usage.bytes = max(0, usage.bytes + new_size - old_size)
+            events = self._check(tenant_id, agent_id, memory_id, usage)
+        await self._publish(events)
+
+    async def record_release(
+        self, tenant_id: str, agent_id: str, memory_id: UUID, size: int
+    ) -> None:
+        """Account for a removed (or trashed) memory, after the write."""
+        async with self._lock:
+            usage = self._usage.get((tenant_id, agent_id))
+            if usage is None:
+                return
+            usage.memories = max(0, usage.memories - 1)
+            usage.bytes = max(0, usage.bytes - size)
+            # Releasing never crosses a threshold upwards; only re-arm alerts
+            self._check(tenant_id, agent_id, memory_id, usage)
+
+    def _charge_embedding(self, usage: _AgentUsage, text: str) -> None:
+        usage.embeddings += 1
+        usage.embedding_spend += (
+            estimate_tokens(text) * self.embedding_cost_per_1k_tokens / 1000
+        )
+
+    async def _agent_usage(
+        self, tenant_id: str, agent_id: str
+    ) -> tuple[_AgentUsage, bool]:
+        """Usage of an agent and whether it was just seeded from storage.
+
+        Freshly seeded usage already reflects the write being recorded.
+        Called with the lock held.
+        """
+        usage = self._usage.get((tenant_id, agent_id))
+        if usage is not None:
+            return usage, False
+        usage = _AgentUsage(*await self._scan(tenant_id, agent_id))
+        self._usage[(tenant_id, agent_id)] = usage
+        return usage, True
+
+    async def _scan(self, tenant_id: str, agent_id: str) -> tuple[int, int]:
+        count = size = offset = 0
+        while True:
+            page = await self.storage.list_memories(
+                tenant_id, agent_id=agent_id, limit=_SCAN_PAGE, offset=offset
+            )
+            count += len(page)
+            size += sum(content_size(m.get("content")) for m in page)
+            if len(page) < _SCAN_PAGE:
+                return count, size
+            offset += _SCAN_PAGE
+
+    def _report(
+        self, tenant_id: str, agent_id: str, usage: _AgentUsage
+    ) -> AgentBudgetReport:
+        budget = self.get_budget(tenant_id, agent_id)
+        utilization = {}
+        for resource in BudgetResource:
+            limit = budget.limit(resource)
+            if limit is not None:
+                used = usage.value(resource)
+                utilization[resource] = used / limit if limit else float(used > 0)
+        return AgentBudgetReport(
+            tenant_id=tenant_id,
+            agent_id=agent_id,
+            memories=usage.memories,
+            bytes=usage.bytes,
+            embeddings=usage.embeddings,
+            embedding_spend=usage.embedding_spend,
+            utilization=utilization,
+        )
+
+    def _check(
+        self, tenant_id: str, agent_id: str, memory_id: UUID, usage: _AgentUsage
+    ) -> list[BudgetThresholdCrossed]:
+        """Alerts for thresholds newly crossed; re-arms recovered ones."""
+        budget = self.get_budget(tenant_id, agent_id)
+        report = self._report(tenant_id, agent_id, usage)
+        events = []
+        for resource, fraction in report.utilization.items():
+            for threshold in budget.thresholds:
+                key = (resource, threshold)
+                if fraction < threshold:
+                    usage.alerted.discard(key)
+                elif key not in usage.alerted:
+                    usage.alerted.add(key)
+                    events.append(
+                        BudgetThresholdCrossed(
+                            tenant_id=tenant_id,
+                            memory_id=memory_id,
+                            agent_id=agent_id,
+                            resource=resource.value,
+                            threshold=threshold,
+                            limit=budget.limit(resource) or 0,
+                            used=usage.value(resource),
+                        )
+                    )
+        return events
+
+    async def _publish(self, events: list[BudgetThresholdCrossed]) -> None:
+        if self.event_bus is None:
+            return
+        for event in events:
+            await self.event_bus.publish(event)
+
+
+class BudgetTrackingStorage:
+    """Wraps an IMemoryStorage and reports its writes to an AgentBudgetTracker.
+
+    Writes are never rejected; the tracker alerts when an agent crosses a
+    budget threshold. Other calls are forwarded unchanged.
+    """
+
+    def __init__(self, storage: IMemoryStorage, tracker: AgentBudgetTracker):
+        self.storage = storage
+        self.tracker = tracker
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self.storage, name)
+        if name not in _BULK_MUTATIONS:
+            return attr
+
+        async def bulk_mutation(*args: Any, **kwargs: Any) -> Any:
+            try:
+                return await attr(*args, **kwargs)
+            finally:
+                await self.tracker.refresh(
+                    kwargs.get("tenant_id") or (args[0] if args else None)
+                )
+
+        return bulk_mutation
+
+    async def store_memory(self, **kwargs: Any) -> UUID:
+        memory_id = await self.storage.store_memory(**kwargs)
+        content = kwargs.get("content")
+        await self.tracker.record_store(
+            kwargs["tenant_id"],
+            kwargs.get("agent_id") or DEFAULT_AGENT,
+            memory_id,
+            content_size(content),
+            embedded_text=(
+                content or "" if kwargs.get("embedding") is not None else None
+            ),
+        )
+        return memory_id
+
+    async def update_memory(
+        self, memory_id: UUID, tenant_id: str, updates: dict[str, Any], **kwargs: Any
+    ) -> bool:
+        memory = None
+        if "content" in updates:
+            memory = await self.storage.get_memory(memory_id, tenant_id)
+        updated = await self.storage.update_memory(
+            memory_id, tenant_id, updates, **kwargs
+        )
+        if updated and memory is not None:
+            await self.tracker.record_resize(
+                tenant_id,
+                memory.get("agent_id") or DEFAULT_AGENT,
+                memory_id,
+                content_size(memory.get("content")),
+                content_size(updates["content"]),
+            )
+        return updated
+
+    async def delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        deleted = await self.storage.delete_memory(memory_id, tenant_id)
+        # Trashed memories were already released by soft_delete_memory
+        if deleted and memory is not None and not memory.get("deleted_at"):
+            await self._release(memory_id, tenant_id, memory)
+        return deleted
+
+    async def soft_delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        deleted = await self.storage.soft_delete_memory(memory_id, tenant_id)
+        if deleted and memory is not None:
+            await self._release(memory_id, tenant_id, memory)
+        return deleted
+
+    async def restore_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        restored = await self.storage.restore_memory(memory_id, tenant_id)
+        if restored and memory is not None and memory.get("deleted_at"):
+            await self.tracker.record_store(
+                tenant_id,
+                memory.get("agent_id") or DEFAULT_AGENT,
+                memory_id,
+                content_size(memory.get("content")),
+            )
+        return restored
+
+    async def _release(
+        self, memory_id: UUID, tenant_id: str, memory: dict[str, Any]
+    ) -> None:
+        await self.tracker.record_release(
+            tenant_id,
+            memory.get("agent_id") or DEFAULT_AGENT,
+            memory_id,
+            content_size(memory.get("content")),
+        )
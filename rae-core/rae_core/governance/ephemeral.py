@@ -0,0 +1,212 @@
+"""Ephemeral scratch memories.
+
+An ephemeral memory holds a sensitive intermediate value (a one-time code, a
+temporary credential) for the duration of a task. It lives in the working
+layer with EPHEMERAL_KEY set in its metadata and an expires_at deadline, and:
+
+- is never consolidated into the episodic or semantic layers
+- is left out of tenant snapshots and knowledge packs
+- is hard-deleted, together with its vector, by EphemeralPurger at its
+  deadline
+
+The purger keeps its schedule in memory; sweep() re-reads a tenant's working
+layer, so memories scheduled by a process that has since stopped are still
+purged.
+"""
+
+import asyncio
+import heapq
+from datetime import datetime, timedelta, timezone
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.utils.clock import IClock, SystemClock
+
+logger = structlog.get_logger(__name__)
+
+EPHEMERAL_KEY = "ephemeral"
+EPHEMERAL_LAYER = "working"
+
+_SWEEP_PAGE = 500
+# Delay before a failed purge is attempted again
+_RETRY_SECONDS = 5.0
+
+
+def is_ephemeral(memory: Any) -> bool:
+    """Whether a memory (dict or MemoryItem) was stored as ephemeral."""
+    if isinstance(memory, dict):
+        metadata = memory.get("metadata") or {}
+    else:
+        metadata = getattr(memory, "metadata", None) or {}
+    return bool(metadata.get(EPHEMERAL_KEY))
+
+
+def ttl_seconds(ttl: float | timedelta) -> float:
+    """TTL in seconds; raises ValueError unless it is positive."""
+    seconds = ttl.total_seconds() if isinstance(ttl, timedelta) else float(ttl)
+    if seconds <= 0:
+        raise ValueError("Ephemeral memory TTL must be positive")
+    return seconds
+
+
+def _deadline(memory: dict[str, Any]) -> datetime | None:
+    expires_at = memory.get("expires_at")
+    if isinstance(expires_at, str):
+        expires_at = datetime.fromisoformat(expires_at)
+    if isinstance(expires_at, datetime) and expires_at.tzinfo is None:
+        expires_at = expires_at.replace(tzinfo=timezone.utc)
+    return expires_at
+
+
+class EphemeralPurger:
+    """Hard-deletes ephemeral memories and their vectors at their deadline.
+
+    schedule() starts a background task (on the running event loop) that
+    sleeps until the earliest deadline; purge_due() can also be called
+    directly, e.g. from a maintenance job.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore | None = None,
+        clock: IClock | None = None,
+    ):
+        """Initialize the purger.
+
+        Args:
+            storage: Storage holding the ephemeral memories
+            vector_store: Vector store holding their embeddings
+            clock: Time source (system clock if None)
+        """
+        self.storage = storage
+        self.vector_store = vector_store
+        self.clock = clock or SystemClock()
+        # Heap of (deadline, memory id, tenant id)
+        self._deadlines: list[tuple[datetime, str, str]] = []
+        self._scheduled: set[str] = set()
+        self._wakeup = asyncio.Event()
+        self._task: asyncio.Task[None] | None = None
+
+    @property
+    def pending(self) -> int:
+        """Number of memories waiting for their deadline."""
+        return len(self._scheduled)
+
+    def schedule(self, memory_id: UUID, tenant_id: str, expires_at: datetime) -> None:
+        """Purge a memory once expires_at has passed."""
+        key = str(memory_id)
+        if key in self._scheduled:
+            return
+        self._scheduled.add(key)
+        heapq.heappush(self._deadlines, (expires_at, key, tenant_id))
+        self._wakeup.set()
+        self.start()
+
+    async def purge(self, memory_id: UUID, tenant_id: str) -> bool:
+        """Delete a memory and its vector now; False if it was already gone."""
+        self._scheduled.discard(str(memory_id))
+        if self.vector_store is not None:
+            await self.vector_store.delete_vector(memory_id, tenant_id)
+        deleted = await self.storage.delete_memory(memory_id, tenant_id)
+        logger.info(
+            "ephemeral_memory_purged", memory_id=str(memory_id), deleted=deleted
+        )
+        return bool(deleted)
+
+    async def purge_due(self) -> list[UUID]:
+        """Purge every scheduled memory whose deadline has passed."""
+        now = self.clock.now()
+        purged = []
+        while self._deadlines and self._deadlines[0][0] <= now:
+            _, key, tenant_id = heapq.heappop(self._deadlines)
+            if key not in self._scheduled:
+                continue
+            memory_id = UUID(key)
+            try:
+                deleted = await self.purge(memory_id, tenant_id)
+            except Exception as e:
+                logger.warning(
+                    "ephemeral_purge_failed", memory_id=key, error=str(e)
+                )
+                retry_at = now + timedelta(seconds=_RETRY_SECONDS)
+                self._scheduled.add(key)
+                heapq.heappush(self._deadlines, (retry_at, key, tenant_id))
+                continue
+            if deleted:
+                purged.append(memory_id)
+        return purged
+
+    async def sweep(self, tenant_id: str) -> list[UUID]:
+        """Purge a tenant's expired ephemeral memories found in storage.
+
+        Ephemeral memories not yet expired are scheduled.
+        """
+        now = self.clock.now()
+        expired: list[UUID] = []
+        offset = 0
+        while True:
+            page = await self.storage.list_memories(
+                tenant_id,
+                layer=EPHEMERAL_LAYER,
+                include_deleted=True,
+                limit=_SWEEP_PAGE,
+                offset=offset,
+            )
+            for memory in page:
+                if not is_ephemeral(memory):
+                    continue
+                deadline = _deadline(memory)
+                memory_id = UUID(str(memory["id"]))
+                if deadline is None or deadline <= now:
+                    expired.append(memory_id)
+                else:
+                    self.schedule(memory_id, tenant_id, deadline)
+            if len(page) < _SWEEP_PAGE:
+                break
+            offset += _SWEEP_PAGE
+
+        purged = []
+        for memory_id in expired:
+            if await self.purge(memory_id, tenant_id):
+                purged.append(memory_id)
+        return purged
+
+    def start(self) -> None:
+        """Start the background task if it is not running."""
+        if self._task is not None and not self._task.done():
+            return
+        try:
+            loop = asyncio.get_running_loop()
+        except RuntimeError:
+            # No loop yet; purge_due or the next schedule() call picks it up
+            return
+        self._task = loop.create_task(self._run())
+
+    async def stop(self) -> None:
+        """Cancel the background task; scheduled deadlines are kept."""
+        if self._task is None:
+            return
+        self._task.cancel()
+        try:
+            await self._task
+        except asyncio.CancelledError:
+            pass
+        self._task = None
+
+    async def _run(self) -> None:
+        while True:
+            await self.purge_due()
+            self._wakeup.clear()
+            timeout = None
+            if self._deadlines:
+                delay = self._deadlines[0][0] - self.clock.now()
+                timeout = max(delay.total_seconds(), 0.0)
+            try:
+                await asyncio.wait_for(self._wakeup.wait(), timeout=timeout)
+            except asyncio.TimeoutError:
+                pass
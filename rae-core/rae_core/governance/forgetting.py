@@ -0,0 +1,240 @@
+"""Forgetting curve and scheduled pruning.
+
+ForgettingPolicy estimates how well a memory is retained with an
+Ebbinghaus-style curve:
+
+    R = exp(-t / S)
+    S = half_life / ln(2) * (1 + importance_boost * importance)
+                          * (1 + access_boost * ln(1 + access_count))
+
+where t is the time since the memory was last accessed (or created).
+Important and frequently recalled memories are therefore forgotten more
+slowly. ForgettingPruner trashes, deletes or moves to cold storage the
+memories whose retention fell below the threshold, on demand or on a
+schedule; with dry_run=True it only reports what would be forgotten.
+
+Protected layers, very important memories and ephemeral memories (purged
+by EphemeralPurger) are never pruned.
+"""
+
+import asyncio
+import math
+from collections.abc import Awaitable, Callable, Iterable
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.governance.ephemeral import is_ephemeral
+from rae_core.interfaces.archive import IArchiveStorage
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.forgetting import (
+    ForgettingConfig,
+    ForgettingReport,
+    ForgottenMemory,
+    PruneAction,
+)
+from rae_core.utils.clock import IClock, SystemClock
+
+logger = structlog.get_logger(__name__)
+
+_PAGE = 500
+_SECONDS_PER_DAY = 86400.0
+
+
+def _timestamp(value: Any) -> datetime | None:
+    if isinstance(value, str):
+        value = datetime.fromisoformat(value)
+    if isinstance(value, datetime) and value.tzinfo is None:
+        value = value.replace(tzinfo=timezone.utc)
+    return value if isinstance(value, datetime) else None
+
+
+class ForgettingPolicy:
+    """Retention of memories along a forgetting curve."""
+
+    def __init__(self, config: ForgettingConfig | None = None):
+        self.config = config or ForgettingConfig()
+
+    def stability_days(self, importance: float, access_count: int) -> float:
+        """Days for retention to fall to 1/e."""
+        config = self.config
+        base = config.half_life_days / math.log(2)
+        return (
+            base
+            * (1 + config.importance_boost * max(0.0, importance))
+            * (1 + config.access_boost * math.log1p(max(0, access_count)))
+        )
+
+    def retention(self, memory: dict[str, Any], now: datetime) -> float:
+        """Probability (0-1) that the memory is still retained at now."""
+        reference = _timestamp(memory.get("last_accessed_at")) or _timestamp(
+            memory.get("created_at")
+        )
+        if reference is None:
+            return 1.0
+        elapsed_days = max(0.0, (now - reference).total_seconds()) / _SECONDS_PER_DAY
+        stability = self.stability_days(
+            float(memory.get("importance") or 0.0),
+            int(memory.get("access_count") or memory.get("usage_count") or 0),
+        )
+        return math.exp(-elapsed_days / stability)
+
+    def is_protected(self, memory: dict[str, Any]) -> bool:
+        """Whether the memory is exempt from pruning."""
+        layer = memory.get("layer")
+        layer = getattr(layer, "value", layer)
+        return (
+            layer in self.config.protected_layers
+            or float(memory.get("importance") or 0.0)
+            >= self.config.protected_importance
+            or is_ephemeral(memory)
+        )
+
+    def forgotten(
+        self, memories: Iterable[dict[str, Any]], now: datetime
+    ) -> list[ForgottenMemory]:
+        """Unprotected memories whose retention is below the threshold."""
+        forgotten = []
+        for memory in memories:
+            if self.is_protected(memory):
+                continue
+            retention = self.retention(memory, now)
+            if retention < self.config.retention_threshold:
+                layer = memory.get("layer")
+                forgotten.append(
+                    ForgottenMemory(
+                        id=UUID(str(memory["id"])),
+                        layer=getattr(layer, "value", layer),
+                        retention=retention,
+                        importance=float(memory.get("importance") or 0.0),
+                        access_count=int(memory.get("access_count") or 0),
+                    )
+                )
+        return forgotten
+
+
+class ForgettingPruner:
+    """Archives or deletes the memories a ForgettingPolicy has forgotten.
+
+    Call run() directly (e.g. from a maintenance job), or start() a
+    background task pruning the given tenants every interval_hours.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore | None = None,
+        policy: ForgettingPolicy | None = None,
+        clock: IClock | None = None,
+        archive: IArchiveStorage | None = None,
+    ):
+        """Initialize the pruner.
+
+        Args:
+            storage: Storage holding the memories
+            vector_store: Vector store whose vectors are deleted with
+                PruneAction.DELETE and PruneAction.COLD
+            policy: Forgetting curve and thresholds (defaults if None)
+            clock: Time source (system clock if None)
+            archive: Cold storage receiving memories with PruneAction.COLD
+
+        Raises:
+            ValueError: PruneAction.COLD without an archive
+        """
+        self.storage = storage
+        self.vector_store = vector_store
+        self.policy = policy or ForgettingPolicy()
+        self.clock = clock or SystemClock()
+        if self.policy.config.action == PruneAction.COLD and archive is None:
+            raise ValueError("PruneAction.COLD requires an archive")
+        self.archive = archive
+        self._task: asyncio.Task[None] | None = None
+        # Overridable for tests
+        self._sleep: Callable[[float], Awaitable[None]] = asyncio.sleep
+
+    async def run(self, tenant_id: str, dry_run: bool = False) -> ForgettingReport:
+        """Prune a tenant's forgotten memories (or only report them)."""
+        now = self.clock.now()
+        action = self.policy.config.action
+        memories = await self._all_memories(tenant_id)
+        report = ForgettingReport(
+            tenant_id=tenant_id,
+            dry_run=dry_run,
+            action=action,
+            scanned=len(memories),
+            forgotten=self.policy.forgotten(memories, now),
+            checked_at=now,
+        )
+        if not dry_run:
+            pruned = []
+            for memory in report.forgotten:
+                if await self._prune(memory.id, tenant_id, action):
+                    pruned.append(memory)
+            report.forgotten = pruned
+        logger.info(
+            "forgetting_pruned",
+            tenant_id=tenant_id,
+            dry_run=dry_run,
+            action=action.value,
+            scanned=report.scanned,
+            forgotten=len(report.forgotten),
+        )
+        return report
+
+    async def _all_memories(self, tenant_id: str) -> list[dict[str, Any]]:
+        memories: list[dict[str, Any]] = []
+        offset = 0
+        while True:
+            page = await self.storage.list_memories(
+                tenant_id, limit=_PAGE, offset=offset
+            )
+            memories.extend(page)
+            if len(page) < _PAGE:
+                return memories
+            offset += _PAGE
+
+    async def _prune(
+        self, memory_id: UUID, tenant_id: str, action: PruneAction
+    ) -> bool:
+        if action == PruneAction.ARCHIVE:
+            return bool(await self.storage.soft_delete_memory(memory_id, tenant_id))
+        if action == PruneAction.COLD:
+            assert self.archive is not None
+            memory = await self.storage.get_memory(memory_id, tenant_id)
+            if memory is None:
+                return False
+            await self.archive.put(dict(memory), tenant_id)
+        if self.vector_store is not None:
+            await self.vector_store.delete_vector(memory_id, tenant_id)
+        return bool(await self.storage.delete_memory(memory_id, tenant_id))
+
+    def start(self, tenant_ids: Iterable[str]) -> "asyncio.Task[None]":
+        """Prune the tenants now and then every interval_hours."""
+        if self._task is None or self._task.done():
+            self._task = asyncio.create_task(self._run(list(tenant_ids)))
+        return self._task
+
+    async def stop(self) -> None:
+        """Cancel the scheduled pruning."""
+        if self._task is None:
+            return
+        self._task.cancel()
+        try:
+            await self._task
+        except asyncio.CancelledError:
+            pass
+        self._task = None
+
+    async def _run(self, tenant_ids: list[str]) -> None:
+        while True:
+            for tenant_id in tenant_ids:
+                try:
+                    await self.run(tenant_id)
+                except Exception as e:
+                    logger.warning(
+                        "forgetting_prune_failed", tenant_id=tenant_id, error=str(e)
+                    )
+            await self._sleep(self.policy.config.interval_hours * 3600)
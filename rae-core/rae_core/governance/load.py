@@ -0,0 +1,177 @@
+"""Per-tenant load shedding.
+
+Tenants sharing a process also share its event loop, connection pools and
+embedding capacity. LoadShedder caps the operations each tenant runs at
+once, queues a bounded number more and rejects the rest with
+OverloadedError, so one noisy tenant cannot starve the others. Interactive
+operations are admitted before queued batch ones, and batch operations can
+be kept to a share of the tenant's slots.
+"""
+
+import asyncio
+from collections import deque
+from collections.abc import AsyncIterator
+from contextlib import asynccontextmanager
+from contextvars import ContextVar
+
+import structlog
+
+from rae_core.exceptions.base import OverloadedError
+from rae_core.models.load import LoadLimits, LoadStats, PriorityClass
+
+logger = structlog.get_logger(__name__)
+
+# Tenants whose slot the current task already holds; nested engine calls
+# (e.g. recall -> search_memories) run in the caller's slot
+_admitted: ContextVar[frozenset[str]] = ContextVar(
+    "rae_admitted_tenants", default=frozenset()
+)
+
+
+class _TenantLoad:
+    def __init__(self) -> None:
+        self.running = {priority: 0 for priority in PriorityClass}
+        self.waiters: dict[PriorityClass, deque[asyncio.Future[None]]] = {
+            priority: deque() for priority in PriorityClass
+        }
+        self.rejected = 0
+
+    @property
+    def queued(self) -> int:
+        return sum(len(w) for w in self.waiters.values())
+
+
+class LoadShedder:
+    """Admits engine operations per tenant.
+
+    Tenants without explicit limits use default_limits.
+    """
+
+    def __init__(
+        self,
+        default_limits: LoadLimits | None = None,
+        limits: dict[str, LoadLimits] | None = None,
+    ):
+        """Initialize the shedder.
+
+        Args:
+            default_limits: Limits for tenants without their own
+            limits: Per-tenant overrides
+        """
+        self.default_limits = default_limits or LoadLimits()
+        self._limits = dict(limits or {})
+        self._tenants: dict[str, _TenantLoad] = {}
+
+    def set_limits(self, tenant_id: str, limits: LoadLimits) -> None:
+        self._limits[tenant_id] = limits
+
+    def remove_limits(self, tenant_id: str) -> None:
+        """Return a tenant to the default limits."""
+        self._limits.pop(tenant_id, None)
+
+    def get_limits(self, tenant_id: str) -> LoadLimits:
+        return self._limits.get(tenant_id, self.default_limits)
+
+    def stats(self, tenant_id: str) -> LoadStats:
+        load = self._tenants.get(tenant_id) or _TenantLoad()
+        return LoadStats(
+            tenant_id=tenant_id,
+            running=dict(load.running),
+            queued={p: len(w) for p, w in load.waiters.items()},
+            rejected=load.rejected,
+        )
+
+    @asynccontextmanager
+    async def admit(
+        self,
+        tenant_id: str,
+        priority: PriorityClass | str = PriorityClass.INTERACTIVE,
+    ) -> AsyncIterator[None]:
+        """Hold one of the tenant's slots for the duration of the block.
+
+        Waits in the tenant's queue when all slots are taken and raises
+        OverloadedError when the queue is full too. Re-entering for a
+        tenant whose slot the current task holds does not take another.
+        """
+        held = _admitted.get()
+        if tenant_id in held:
+            yield
+            return
+
+        priority = PriorityClass(priority)
+        load = self._tenants.setdefault(tenant_id, _TenantLoad())
+        await self._acquire(tenant_id, load, priority)
+        token = _admitted.set(held | {tenant_id})
+        try:
+            yield
+        finally:
+            _admitted.reset(token)
+            load.running[priority] -= 1
+            self._wake(tenant_id, load)
+
+    def _can_run(
+        self, limits: LoadLimits, load: _TenantLoad, priority: PriorityClass
+    ) -> bool:
+        if (
+            limits.max_concurrent is not None
+            and sum(load.running.values()) >= limits.max_concurrent
+        ):
+            return False
+        return not (
+            priority is PriorityClass.BATCH
+            and limits.batch_max_concurrent is not None
+            and load.running[PriorityClass.BATCH] >= limits.batch_max_concurrent
+        )
+
+    def _ahead(self, load: _TenantLoad, priority: PriorityClass) -> bool:
+        """Whether queued operations come before a new one of this priority."""
+        if load.waiters[PriorityClass.INTERACTIVE]:
+            return True
+        return priority is PriorityClass.BATCH and bool(
+            load.waiters[PriorityClass.BATCH]
+        )
+
+    async def _acquire(
+        self, tenant_id: str, load: _TenantLoad, priority: PriorityClass
+    ) -> None:
+        limits = self.get_limits(tenant_id)
+        if not self._ahead(load, priority) and self._can_run(limits, load, priority):
+            load.running[priority] += 1
+            return
+
+        if limits.max_queued is not None and load.queued >= limits.max_queued:
+            load.rejected += 1
+            logger.warning(
+                "load_shed",
+                tenant_id=tenant_id,
+                priority=priority.value,
+                queued=load.queued,
+            )
+            raise OverloadedError(
+                tenant_id, priority.value, load.queued, limits.max_queued
+            )
+
+        waiter: asyncio.Future[None] = asyncio.get_running_loop().create_future()
+        load.waiters[priority].append(waiter)
+        try:
+            await waiter
+        except asyncio.CancelledError:
+            if waiter.done() and not waiter.cancelled():
+                # Granted just before the cancellation: hand the slot on
+                load.running[priority] -= 1
+                self._wake(tenant_id, load)
+            else:
+                load.waiters[priority].remove(waiter)
+            raise
+
+    def _wake(self, tenant_id: str, load: _TenantLoad) -> None:
+        """Grant free slots to queued operations, interactive first."""
+        limits = self.get_limits(tenant_id)
+        for priority in (PriorityClass.INTERACTIVE, PriorityClass.BATCH):
+            waiters = load.waiters[priority]
+            while waiters and self._can_run(limits, load, priority):
+                load.running[priority] += 1
+                waiters.popleft().set_result(None)
+            if waiters:
+                # Batch operations never overtake waiting interactive ones
+                break
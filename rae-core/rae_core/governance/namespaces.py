@@ -0,0 +1,181 @@
+"""Namespaces: named partitions of a tenant's memories.
+
+A tenant keeps unrelated projects or domains apart with namespaces instead
+of fake tenants. A memory's namespace travels in its metadata under
+NAMESPACE_KEY (memories without one are in DEFAULT_NAMESPACE), so every
+storage backend can filter on it and vector recall and search keep to the
+namespace they are asked for.
+
+Namespaces holding memories are found in storage and survive restarts;
+created but still empty ones live in the NamespaceManager. Deleting a
+namespace removes its memories from storage, their vectors and their graph
+nodes.
+"""
+
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.exceptions.base import ConflictError, NotFoundError, ValidationError
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.namespace import (
+    DEFAULT_NAMESPACE,
+    NAMESPACE_KEY,
+    NAMESPACE_PATTERN,
+    Namespace,
+    NamespaceDeletion,
+    memory_namespace,
+)
+
+logger = structlog.get_logger(__name__)
+
+_SCAN_PAGE = 500
+
+
+def validate_namespace(name: str) -> str:
+    """The name, if it is a valid namespace name.
+
+    Raises:
+        ValidationError: Not 1-64 lowercase letters, digits, ".", "_" or "-"
+            starting with a letter or digit
+    """
+    if not NAMESPACE_PATTERN.match(name):
+        raise ValidationError(f"Invalid namespace name: {name!r}")
+    return name
+
+
+class NamespaceManager:
+    """Creates, lists and deletes the namespaces of each tenant."""
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore | None = None,
+        page_size: int = _SCAN_PAGE,
+    ):
+        """Initialize namespace manager.
+
+        Args:
+            storage: Storage holding the memories
+            vector_store: Vector store whose vectors are deleted with them
+            page_size: Memories read per storage call while scanning
+        """
+        self.storage = storage
+        self.vector_store = vector_store
+        self.page_size = page_size
+        # Created namespaces: {tenant_id: {name: Namespace}}
+        self._created: dict[str, dict[str, Namespace]] = {}
+
+    async def create(
+        self, tenant_id: str, name: str, description: str | None = None
+    ) -> Namespace:
+        """Create an empty namespace.
+
+        Raises:
+            ValidationError: Invalid name
+            ConflictError: The namespace already exists
+        """
+        validate_namespace(name)
+        exists = name == DEFAULT_NAMESPACE or name in self._created.get(tenant_id, {})
+        if exists or await self._stored(tenant_id, name):
+            raise ConflictError(f"Namespace {name} already exists")
+        namespace = Namespace(tenant_id=tenant_id, name=name, description=description)
+        self._created.setdefault(tenant_id, {})[name] = namespace
+        logger.info("namespace_created", tenant_id=tenant_id, namespace=name)
+        return namespace
+
+    def ensure(self, tenant_id: str, name: str) -> None:
+        """Record a namespace a memory is being stored in."""
+        validate_namespace(name)
+        created = self._created.setdefault(tenant_id, {})
+        if name != DEFAULT_NAMESPACE and name not in created:
+            created[name] = Namespace(tenant_id=tenant_id, name=name)
+
+    async def list_all(self, tenant_id: str) -> list[Namespace]:
+        """Namespaces of a tenant with their memory counts, by name."""
+        counts: dict[str, int] = {}
+        for memory in await self._scan(tenant_id):
+            name = memory_namespace(memory)
+            counts[name] = counts.get(name, 0) + 1
+
+        namespaces = {
+            name: namespace.model_copy(update={"memory_count": counts.get(name, 0)})
+            for name, namespace in self._created.get(tenant_id, {}).items()
+        }
+        for name, count in counts.items():
+            if name not in namespaces:
+                namespaces[name] = Namespace(
+                    tenant_id=tenant_id, name=name, memory_count=count
+                )
+        return [namespaces[name] for name in sorted(namespaces)]
+
+    async def delete(
+        self,
+        tenant_id: str,
+        name: str,
+        graph_store: IGraphStore | None = None,
+    ) -> NamespaceDeletion:
+        """Delete a namespace and every memory stored in it.
+
+        Soft-deleted memories of the namespace are removed too.
+
+        Raises:
+            ValidationError: The default namespace cannot be deleted
+            NotFoundError: The namespace does not exist
+        """
+        if name == DEFAULT_NAMESPACE:
+            raise ValidationError("The default namespace cannot be deleted")
+        memories = await self._scan(tenant_id, {NAMESPACE_KEY: name})
+        if not memories and name not in self._created.get(tenant_id, {}):
+            raise NotFoundError("Namespace", name, tenant_id)
+
+        result = NamespaceDeletion(tenant_id=tenant_id, name=name)
+        for memory in memories:
+            memory_id = UUID(str(memory["id"]))
+            if not await self.storage.delete_memory(memory_id, tenant_id):
+                continue
+            result.memory_ids.append(memory_id)
+            if self.vector_store is not None:
+                if await self.vector_store.delete_vector(memory_id, tenant_id):
+                    result.vectors_deleted += 1
+            if graph_store is not None:
+                if await graph_store.delete_node(memory_id, tenant_id):
+                    result.graph_nodes_deleted += 1
+        self._created.get(tenant_id, {}).pop(name, None)
+
+        logger.info(
+            "namespace_deleted",
+            tenant_id=tenant_id,
+            namespace=name,
+            memories=len(result.memory_ids),
+        )
+        return result
+
+    async def _stored(self, tenant_id: str, name: str) -> bool:
+        page = await self.storage.list_memories(
+            tenant_id, filters={NAMESPACE_KEY: name}, include_deleted=True, limit=1
+        )
+        return bool(page)
+
+    async def _scan(
+        self, tenant_id: str, filters: dict[str, Any] | None = None
+    ) -> list[dict[str, Any]]:
+        """All memories of a tenant (soft-deleted included) matching filters."""
+        found: list[dict[str, Any]] = []
+        offset = 0
+        while True:
+            page = await self.storage.list_memories(
+                tenant_id,
+                filters=filters,
+                include_deleted=True,
+                limit=self.page_size,
+                offset=offset,
+            )
+            found.extend(page)
+            if len(page) < self.page_size:
+                break
+            offset += self.page_size
+        return found
@@ -0,0 +1,421 @@
+"""Data subject reports and erasure: everything stored about one person.
+
+A subject is identified by a key (a user id, an email address, a name).
+A memory references the subject when:
+
+- one of SUBJECT_METADATA_KEYS in its metadata equals the key (or is a
+  list containing it),
+- it is tagged with the key or with "subject:<key>", or
+- its content mentions the key as a whole word (case-insensitive).
+
+Soft-deleted memories are still stored and are reported as well. Facts are
+the semantic-layer memories; relationships are the knowledge graph edges
+with a reported memory at either end. Reports are plain JSON via
+model_dump(mode="json") and render_subject_report writes them as markdown.
+
+SubjectEraser removes the same memories, plus those linked to the subject's
+entity node in the knowledge graph (see maintenance.bootstrap), from storage,
+vectors and graph, and records one ERASE audit entry per memory.
+"""
+
+import hashlib
+import re
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.interfaces.audit import IAuditLogger
+from rae_core.interfaces.embedding import IEmbeddingProvider
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.maintenance.bootstrap import ENTITIES_KEY, entity_node_id
+from rae_core.models.audit import AuditEntry, AuditOperation
+from rae_core.models.subject import (
+    ErasureMode,
+    ErasureResult,
+    SubjectRelationship,
+    SubjectReport,
+)
+from rae_core.types.enums import MemoryLayer
+
+logger = structlog.get_logger(__name__)
+
+SUBJECT_METADATA_KEYS = ("subject", "subject_key", "subjects", "user_id")
+SUBJECT_TAG_PREFIX = "subject:"
+REDACTED = "[REDACTED]"
+
+_SCAN_PAGE = 500
+_EXCERPT_LENGTH = 200
+
+
+def _mention_pattern(subject_key: str) -> re.Pattern[str]:
+    return re.compile(rf"(?<!\w){re.escape(subject_key)}(?!\w)", re.IGNORECASE)
+
+
+def _mentions(text: str, subject_key: str) -> bool:
+    return _mention_pattern(subject_key).search(text) is not None
+
+
+def references_subject(memory: dict[str, Any], subject_key: str) -> bool:
+    """Whether a storage record references the subject (see module doc)."""
+    metadata = memory.get("metadata") or {}
+    for key in SUBJECT_METADATA_KEYS:
+        value = metadata.get(key)
+        values = value if isinstance(value, list) else [value]
+        if any(v is not None and str(v) == subject_key for v in values):
+            return True
+
+    tags = memory.get("tags") or []
+    if subject_key in tags or f"{SUBJECT_TAG_PREFIX}{subject_key}" in tags:
+        return True
+
+    return _mentions(memory.get("content") or "", subject_key)
+
+
+async def find_subject_memories(
+    storage: IMemoryStorage,
+    tenant_id: str,
+    subject_key: str,
+    page_size: int = _SCAN_PAGE,
+) -> list[dict[str, Any]]:
+    """All memories of a tenant referencing the subject, soft-deleted included."""
+    found: list[dict[str, Any]] = []
+    offset = 0
+    while True:
+        page = await storage.list_memories(
+            tenant_id, include_deleted=True, limit=page_size, offset=offset
+        )
+        found.extend(m for m in page if references_subject(m, subject_key))
+        if len(page) < page_size:
+            break
+        offset += page_size
+    return found
+
+
+async def _relationships(
+    graph_store: IGraphStore, tenant_id: str, memory_ids: list[UUID]
+) -> list[SubjectRelationship]:
+    wanted = set(memory_ids)
+    span = list(memory_ids)
+    for memory_id in memory_ids:
+        for neighbor in await graph_store.get_neighbors(memory_id, tenant_id):
+            if neighbor not in wanted and neighbor not in span:
+                span.append(neighbor)
+
+    subgraph = await graph_store.get_subgraph(span, tenant_id, include_edges=True)
+    relationships: list[SubjectRelationship] = []
+    seen: set[tuple[str, str, str]] = set()
+    for edge in subgraph.get("edges", []):
+        source = UUID(str(edge.get("source_id")))
+        target = UUID(str(edge.get("target_id")))
+        edge_type = edge.get("edge_type") or edge.get("type") or "relates_to"
+        key = (str(source), str(target), edge_type)
+        if key in seen or (source not in wanted and target not in wanted):
+            continue
+        seen.add(key)
+        properties = edge.get("properties")
+        relationships.append(
+            SubjectRelationship(
+                source_id=source,
+                target_id=target,
+                edge_type=edge_type,
+                weight=float(edge.get("weight", 1.0)),
+                properties=properties if isinstance(properties, dict) else {},
+            )
+        )
+    return relationships
+
+
+async def generate_subject_report(
+    storage: IMemoryStorage,
+    tenant_id: str,
+    subject_key: str,
+    graph_store: IGraphStore | None = None,
+) -> SubjectReport:
+    """Compile every memory, fact and relationship referencing a subject.
+
+    Args:
+        storage: Memory storage to scan
+        tenant_id: Tenant whose data is reported
+        subject_key: Key identifying the subject
+        graph_store: Graph store holding the tenant's relationships
+            (relationships are left empty if None)
+    """
+    if not subject_key.strip():
+        raise ValueError("subject_key must not be empty")
+
+    memories = await find_subject_memories(storage, tenant_id, subject_key)
+    report = SubjectReport(
+        subject_key=subject_key,
+        tenant_id=tenant_id,
+        generated_at=datetime.now(timezone.utc),
+        memories=[
+            m for m in memories if m.get("layer") != MemoryLayer.SEMANTIC.value
+        ],
+        facts=[m for m in memories if m.get("layer") == MemoryLayer.SEMANTIC.value],
+    )
+    if graph_store is not None and memories:
+        report.relationships = await _relationships(
+            graph_store, tenant_id, report.memory_ids
+        )
+
+    logger.info(
+        "subject_report_generated",
+        tenant_id=tenant_id,
+        memories=len(report.memories),
+        facts=len(report.facts),
+        relationships=len(report.relationships),
+    )
+    return report
+
+
+def _excerpt(text: str) -> str:
+    text = " ".join(text.split())
+    if len(text) <= _EXCERPT_LENGTH:
+        return text
+    return text[: _EXCERPT_LENGTH - 1].rstrip() + "…"
+
+
+def _memory_line(memory: dict[str, Any]) -> str:
+    details = [str(memory.get("layer") or "unknown")]
+    if memory.get("created_at"):
+        details.append(f"created {memory['created_at']}")
+    if memory.get("deleted_at"):
+        details.append("deleted")
+    content = _excerpt(memory.get("content") or "")
+    return f"- `{memory['id']}` ({', '.join(details)}): {content}"
+
+
+def render_subject_report(report: SubjectReport) -> str:
+    """Markdown rendering of a subject report."""
+    lines = [
+        f"# Subject report: {report.subject_key}",
+        "",
+        f"- Tenant: {report.tenant_id}",
+        f"- Generated: {report.generated_at.isoformat()}",
+        "",
+    ]
+    for title, memories in (("Memories", report.memories), ("Facts", report.facts)):
+        lines.append(f"## {title} ({len(memories)})")
+        lines.append("")
+        lines.extend(_memory_line(m) for m in memories)
+        if not memories:
+            lines.append("None.")
+        lines.append("")
+
+    lines.append(f"## Relationships ({len(report.relationships)})")
+    lines.append("")
+    for rel in report.relationships:
+        lines.append(
+            f"- `{rel.source_id}` -[{rel.edge_type}]-> `{rel.target_id}` "
+            f"(weight {rel.weight:g})"
+        )
+    if not report.relationships:
+        lines.append("None.")
+    return "\n".join(lines) + "\n"
+
+
+def subject_digest(subject_key: str) -> str:
+    """SHA-256 of a subject key, recorded in place of the key itself."""
+    return hashlib.sha256(subject_key.encode("utf-8")).hexdigest()
+
+
+def anonymize_memory(memory: dict[str, Any], subject_key: str) -> dict[str, Any]:
+    """Content, tags and metadata of a memory with the subject masked out.
+
+    Mentions in the content become REDACTED; subject metadata values, subject
+    tags and extracted entities naming the subject are dropped.
+    """
+    content = _mention_pattern(subject_key).sub(
+        REDACTED, memory.get("content") or ""
+    )
+
+    metadata = dict(memory.get("metadata") or {})
+    for key in SUBJECT_METADATA_KEYS:
+        value = metadata.get(key)
+        if isinstance(value, list):
+            metadata[key] = [v for v in value if str(v) != subject_key]
+        elif value is not None and str(value) == subject_key:
+            del metadata[key]
+    entities = metadata.get(ENTITIES_KEY)
+    if isinstance(entities, list):
+        metadata[ENTITIES_KEY] = [
+            e
+            for e in entities
+            if not (
+                isinstance(e, dict)
+                and str(e.get("text", "")).lower() == subject_key.lower()
+            )
+        ]
+
+    subject_tags = {subject_key, f"{SUBJECT_TAG_PREFIX}{subject_key}"}
+    tags = [t for t in memory.get("tags") or [] if t not in subject_tags]
+    return {"content": content, "tags": tags, "metadata": metadata}
+
+
+# Fields of an erased memory carried over to its anonymized copy
+_COPIED_FIELDS = (
+    "layer",
+    "agent_id",
+    "importance",
+    "expires_at",
+    "memory_type",
+    "strength",
+    "project",
+    "session_id",
+    "source",
+)
+
+
+class SubjectEraser:
+    """Forgets everything a tenant stores about a data subject.
+
+    Anonymizing replaces each memory with a masked copy under a new id and
+    deletes the original, so no revision or change log entry of the old
+    content survives. Copies are re-embedded when an embedding provider is
+    given; otherwise they have no vector.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore | None = None,
+        graph_store: IGraphStore | None = None,
+        audit_logger: IAuditLogger | None = None,
+        embedding_provider: IEmbeddingProvider | None = None,
+    ):
+        """Initialize eraser.
+
+        Args:
+            storage: Memory storage to erase from
+            vector_store: Vector store holding the memories' embeddings
+            graph_store: Graph store holding memory and entity nodes
+            audit_logger: Receives one ERASE entry per erased memory
+            embedding_provider: Embeds anonymized copies (anonymize mode)
+        """
+        self.storage = storage
+        self.vector_store = vector_store
+        self.graph_store = graph_store
+        self.audit_logger = audit_logger
+        self.embedding_provider = embedding_provider
+
+    async def erase(
+        self,
+        tenant_id: str,
+        subject_key: str,
+        mode: ErasureMode | str = ErasureMode.DELETE,
+        actor: str | None = None,
+    ) -> ErasureResult:
+        """Erase or anonymize every memory referencing the subject.
+
+        Args:
+            tenant_id: Tenant whose data is erased
+            subject_key: Key identifying the subject
+            mode: Delete the memories or replace them with anonymized copies
+            actor: Who requested the erasure (recorded in the audit log)
+        """
+        if not subject_key.strip():
+            raise ValueError("subject_key must not be empty")
+        result = ErasureResult(
+            tenant_id=tenant_id,
+            subject_digest=subject_digest(subject_key),
+            mode=ErasureMode(mode),
+        )
+
+        memories = await self._find(tenant_id, subject_key)
+        for memory in memories:
+            memory_id = UUID(str(memory["id"]))
+            if not await self.storage.delete_memory(memory_id, tenant_id):
+                continue
+            result.memory_ids.append(memory_id)
+            copy_id = None
+            if result.mode is ErasureMode.ANONYMIZE:
+                copy_id = await self._store_copy(memory, tenant_id, subject_key)
+                result.replacements[str(memory_id)] = str(copy_id)
+            if self.vector_store is not None:
+                if await self.vector_store.delete_vector(memory_id, tenant_id):
+                    result.vectors_deleted += 1
+            if self.graph_store is not None:
+                if await self.graph_store.delete_node(memory_id, tenant_id):
+                    result.graph_nodes_deleted += 1
+            await self._audit(result, memory_id, copy_id, actor)
+
+        if self.graph_store is not None:
+            entity_id = entity_node_id(tenant_id, subject_key)
+            if await self.graph_store.delete_node(entity_id, tenant_id):
+                result.graph_nodes_deleted += 1
+
+        result.completed_at = datetime.now(timezone.utc)
+        logger.info(
+            "subject_erased",
+            tenant_id=tenant_id,
+            erasure_id=str(result.erasure_id),
+            mode=result.mode.value,
+            memories=len(result.memory_ids),
+        )
+        return result
+
+    async def _find(self, tenant_id: str, subject_key: str) -> list[dict[str, Any]]:
+        """Memories referencing the subject or linked to its entity node."""
+        memories = await find_subject_memories(self.storage, tenant_id, subject_key)
+        if self.graph_store is None:
+            return memories
+
+        known = {UUID(str(m["id"])) for m in memories}
+        linked = [
+            node_id
+            for node_id in await self.graph_store.get_neighbors(
+                entity_node_id(tenant_id, subject_key), tenant_id
+            )
+            if node_id not in known
+        ]
+        if linked:
+            memories.extend(await self.storage.get_memories_batch(linked, tenant_id))
+        return memories
+
+    async def _store_copy(
+        self, memory: dict[str, Any], tenant_id: str, subject_key: str
+    ) -> UUID:
+        fields = {k: memory[k] for k in _COPIED_FIELDS if memory.get(k) is not None}
+        fields.update(anonymize_memory(memory, subject_key))
+        copy_id = await self.storage.store_memory(tenant_id=tenant_id, **fields)
+        if self.embedding_provider is not None and self.vector_store is not None:
+            embedding = await self.embedding_provider.embed_text(
+                fields["content"], task_type="search_document"
+            )
+            await self.vector_store.store_vector(
+                copy_id,
+                embedding,
+                tenant_id,
+                metadata={"layer": fields.get("layer")},
+            )
+        return copy_id
+
+    async def _audit(
+        self,
+        result: ErasureResult,
+        memory_id: UUID,
+        copy_id: UUID | None,
+        actor: str | None,
+    ) -> None:
+        if self.audit_logger is None:
+            return
+        metadata: dict[str, Any] = {
+            "erasure_id": str(result.erasure_id),
+            "subject_digest": result.subject_digest,
+            "mode": result.mode.value,
+        }
+        if copy_id is not None:
+            metadata["replaced_by"] = str(copy_id)
+        await self.audit_logger.record(
+            AuditEntry(
+                tenant_id=result.tenant_id,
+                actor=actor,
+                operation=AuditOperation.ERASE,
+                memory_id=memory_id,
+                metadata=metadata,
+            )
+        )
@@ -0,0 +1,286 @@
+"""Per-tenant quotas and embedding rate limiting.
+
+QuotaManager tracks live memory count, total content bytes and embeddings per
+minute for each tenant. Usage is seeded from the storage backend itself
+(count_memories plus a content scan), so limits hold regardless of which
+backend is used and survive restarts. QuotaEnforcingStorage applies the
+limits on the write path.
+"""
+
+import asyncio
+from collections import deque
+from datetime import timedelta
+from typing import Any
+from uuid import UUID
+
+from rae_core.exceptions.base import QuotaExceededError
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.quota import QuotaResource, QuotaUsage, TenantQuota
+from rae_core.utils.clock import IClock, SystemClock
+
+_WINDOW = timedelta(minutes=1)
+_SCAN_PAGE = 500
+
+# Bulk operations after which usage is re-read from the backend
+_BULK_MUTATIONS = frozenset(
+    {
+        "delete_memories_with_metadata_filter",
+        "delete_memories_below_importance",
+        "delete_expired_memories",
+        "clear_tenant",
+    }
+)
+
+
+def content_size(content: str | None) -> int:
+    """Bytes charged for a memory: UTF-8 size of its content."""
+    return len((content or "").encode("utf-8"))
+
+
+class QuotaManager:
+    """Enforces configurable per-tenant limits.
+
+    Tenants without an explicit quota use default_quota.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        default_quota: TenantQuota | None = None,
+        quotas: dict[str, TenantQuota] | None = None,
+        clock: IClock | None = None,
+    ):
+        """Initialize quota manager.
+
+        Args:
+            storage: Backend whose contents usage is measured against
+            default_quota: Limits for tenants without their own quota
+            quotas: Per-tenant overrides
+            clock: Time source for the embedding rate window
+        """
+        self.storage = storage
+        self.default_quota = default_quota or TenantQuota()
+        self._quotas = dict(quotas or {})
+        self._clock = clock or SystemClock()
+        # Lazily seeded from storage: {tenant_id: [memories, bytes]}
+        self._usage: dict[str, list[int]] = {}
+        self._embeddings: dict[str, deque[Any]] = {}
+        self._lock = asyncio.Lock()
+
+    def set_quota(self, tenant_id: str, quota: TenantQuota) -> None:
+        self._quotas[tenant_id] = quota
+
+    def remove_quota(self, tenant_id: str) -> None:
+        """Return a tenant to the default quota."""
+        self._quotas.pop(tenant_id, None)
+
+    def get_quota(self, tenant_id: str) -> TenantQuota:
+        return self._quotas.get(tenant_id, self.default_quota)
+
+    async def get_usage(self, tenant_id: str) -> QuotaUsage:
+        async with self._lock:
+            memories, size = await self._tenant_usage(tenant_id)
+            return QuotaUsage(
+                tenant_id=tenant_id,
+                memories=memories,
+                bytes=size,
+                embeddings_last_minute=len(self._embedding_window(tenant_id)),
+            )
+
+    async def refresh(self, tenant_id: str | None = None) -> None:
+        """Drop cached usage so it is re-read from storage on next use."""
+        async with self._lock:
+            if tenant_id is None:
+                self._usage.clear()
+            else:
+                self._usage.pop(tenant_id, None)
+
+    async def reserve_memory(self, tenant_id: str, size: int) -> None:
+        """Account for a new memory, raising if it would exceed the quota."""
+        quota = self.get_quota(tenant_id)
+        async with self._lock:
+            usage = await self._tenant_usage(tenant_id)
+            self._check(
+                tenant_id, QuotaResource.MEMORIES, quota.max_memories, usage[0], 1
+            )
+            self._check(
+                tenant_id, QuotaResource.BYTES, quota.max_bytes, usage[1], size
+            )
+            usage[0] += 1
+            usage[1] += size
+
+    async def release_memory(self, tenant_id: str, size: int) -> None:
+        """Return the allowance of a removed (or never written) memory."""
+        async with self._lock:
+            usage = self._usage.get(tenant_id)
+            if usage is not None:
+                usage[0] = max(0, usage[0] - 1)
+                usage[1] = max(0, usage[1] - size)
+
+    async def resize_memory(
+        self, tenant_id: str, old_size: int, new_size: int
+    ) -> None:
+        """Account for content replaced by an update."""
+        quota = self.get_quota(tenant_id)
+        async with self._lock:
+            usage = await self._tenant_usage(tenant_id)
+            delta = new_size - old_size
+            if delta > 0:
+                self._check(
+                    tenant_id, QuotaResource.BYTES, quota.max_bytes, usage[1], delta
+                )
+            usage[1] = max(0, usage[1] + delta)
+
+    async def consume_embeddings(self, tenant_id: str, count: int = 1) -> None:
+        """Record generated embeddings, raising if the per-minute rate is exceeded."""
+        quota = self.get_quota(tenant_id)
+        async with self._lock:
+            window = self._embedding_window(tenant_id)
+            self._check(
+                tenant_id,
+                QuotaResource.EMBEDDINGS_PER_MINUTE,
+                quota.embeddings_per_minute,
+                len(window),
+                count,
+            )
+            now = self._clock.now()
+            window.extend([now] * count)
+
+    async def _tenant_usage(self, tenant_id: str) -> list[int]:
+        """Usage counters of a tenant, seeded from storage (lock held)."""
+        usage = self._usage.get(tenant_id)
+        if usage is None:
+            count = await self.storage.count_memories(tenant_id=tenant_id)
+            size = 0
+            offset = 0
+            while True:
+                page = await self.storage.list_memories(
+                    tenant_id, limit=_SCAN_PAGE, offset=offset
+                )
+                size += sum(content_size(m.get("content")) for m in page)
+                if len(page) < _SCAN_PAGE:
+                    break
+                offset += _SCAN_PAGE
+            usage = self._usage[tenant_id] = [count, size]
+        return usage
+
+    def _embedding_window(self, tenant_id: str) -> deque[Any]:
+        window = self._embeddings.setdefault(tenant_id, deque())
+        cutoff = self._clock.now() - _WINDOW
+        while window and window[0] <= cutoff:
+            window.popleft()
+        return window
+
+    @staticmethod
+    def _check(
+        tenant_id: str,
+        resource: QuotaResource,
+        limit: int | None,
+        current: int,
+        requested: int,
+    ) -> None:
+        if limit is not None and current + requested > limit:
+            raise QuotaExceededError(
+                tenant_id, resource.value, limit, current, requested
+            )
+
+
+class QuotaEnforcingStorage:
+    """Wraps an IMemoryStorage and enforces a QuotaManager on writes.
+
+    store_memory raises QuotaExceededError when the tenant is over its memory
+    count, byte or embedding-rate limit. Other calls are forwarded unchanged.
+    """
+
+    def __init__(self, storage: IMemoryStorage, quota_manager: QuotaManager):
+        self.storage = storage
+        self.quota_manager = quota_manager
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self.storage, name)
+        if name not in _BULK_MUTATIONS:
+            return attr
+
+        async def bulk_mutation(*args: Any, **kwargs: Any) -> Any:
+            try:
+                return await attr(*args, **kwargs)
+            finally:
+                await self.quota_manager.refresh(
+                    kwargs.get("tenant_id") or (args[0] if args else None)
+                )
+
+        return bulk_mutation
+
+    async def store_memory(self, **kwargs: Any) -> UUID:
+        tenant_id = kwargs["tenant_id"]
+        size = content_size(kwargs.get("content"))
+        await self.quota_manager.reserve_memory(tenant_id, size)
+        try:
+            if kwargs.get("embedding") is not None:
+                await self.quota_manager.consume_embeddings(tenant_id)
+            return await self.storage.store_memory(**kwargs)
+        except BaseException:
+            await self.quota_manager.release_memory(tenant_id, size)
+            raise
+
+    async def update_memory(
+        self, memory_id: UUID, tenant_id: str, updates: dict[str, Any], **kwargs: Any
+    ) -> bool:
+        memory = None
+        if "content" in updates:
+            memory = await self.storage.get_memory(memory_id, tenant_id)
+            if memory is None:
+                return False
+            await self.quota_manager.resize_memory(
+                tenant_id,
+                content_size(memory.get("content")),
+                content_size(updates["content"]),
+            )
+
+        updated = False
+        try:
+            updated = await self.storage.update_memory(
+                memory_id, tenant_id, updates, **kwargs
+            )
+        finally:
+            if memory is not None and not updated:
+                await self.quota_manager.resize_memory(
+                    tenant_id,
+                    content_size(updates["content"]),
+                    content_size(memory.get("content")),
+                )
+        return updated
+
+    async def delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        deleted = await self.storage.delete_memory(memory_id, tenant_id)
+        # Trashed memories were already released by soft_delete_memory
+        if deleted and memory is not None and not memory.get("deleted_at"):
+            await self.quota_manager.release_memory(
+                tenant_id, content_size(memory.get("content"))
+            )
+        return deleted
+
+    async def soft_delete_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        deleted = await self.storage.soft_delete_memory(memory_id, tenant_id)
+        if deleted and memory is not None:
+            await self.quota_manager.release_memory(
+                tenant_id, content_size(memory.get("content"))
+            )
+        return deleted
+
+    async def restore_memory(self, memory_id: UUID, tenant_id: str) -> bool:
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        if memory is None or not memory.get("deleted_at"):
+            return await self.storage.restore_memory(memory_id, tenant_id)
+
+        size = content_size(memory.get("content"))
+        await self.quota_manager.reserve_memory(tenant_id, size)
+        restored = False
+        try:
+            restored = await self.storage.restore_memory(memory_id, tenant_id)
+        finally:
+            if not restored:
+                await self.quota_manager.release_memory(tenant_id, size)
+        return restored
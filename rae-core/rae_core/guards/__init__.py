@@ -1,5 +1,6 @@
 """Guards for RAE-core memory isolation and security."""
 
 from .isolation import MemoryIsolationGuard
+from .sharing import SharingPolicy
 
-__all__ = ["MemoryIsolationGuard"]
+__all__ = ["MemoryIsolationGuard", "SharingPolicy"]
@@ -0,0 +1,86 @@
+"""Sharing policy for memories shared between agents.
+
+Agents of a tenant can share memories without copying them. Each memory
+carries a MemoryScope: PRIVATE memories are recalled only by the agent that
+owns them, TEAM memories also by the members of their team, and TENANT
+memories by every agent of the tenant. Storage records keep the scope and
+team in their metadata under SCOPE_KEY and TEAM_KEY.
+"""
+
+from collections.abc import Iterable
+from typing import Any
+
+from ..models.memory import MemoryItem
+from ..types.enums import MemoryScope
+
+SCOPE_KEY = "scope"
+TEAM_KEY = "team_id"
+
+
+def _field(memory: MemoryItem | dict[str, Any], key: str) -> Any:
+    if not isinstance(memory, dict):
+        return getattr(memory, key, None)
+    if memory.get(key) is not None:
+        return memory[key]
+    return (memory.get("metadata") or {}).get(key)
+
+
+class SharingPolicy:
+    """Decides which agents may recall a memory.
+
+    Team membership is kept here rather than on the memories, so adding an
+    agent to a team shares all of the team's memories with it at once.
+    """
+
+    def __init__(
+        self,
+        teams: dict[str, Iterable[str]] | None = None,
+        default_scope: MemoryScope | str = MemoryScope.PRIVATE,
+    ):
+        """Initialize the policy.
+
+        Args:
+            teams: Member agent ids by team id
+            default_scope: Scope of memories stored without one
+        """
+        self._members: dict[str, set[str]] = {
+            team_id: set(agents) for team_id, agents in (teams or {}).items()
+        }
+        self.default_scope = MemoryScope(default_scope)
+
+    def add_member(self, team_id: str, agent_id: str) -> None:
+        """Add an agent to a team."""
+        self._members.setdefault(team_id, set()).add(agent_id)
+
+    def remove_member(self, team_id: str, agent_id: str) -> None:
+        """Remove an agent from a team."""
+        self._members.get(team_id, set()).discard(agent_id)
+
+    def teams_of(self, agent_id: str) -> set[str]:
+        """Teams an agent belongs to."""
+        return {
+            team_id for team_id, agents in self._members.items() if agent_id in agents
+        }
+
+    def scope_of(self, memory: MemoryItem | dict[str, Any]) -> MemoryScope:
+        """Scope of a memory, falling back to default_scope."""
+        scope = _field(memory, SCOPE_KEY)
+        return MemoryScope(scope) if scope else self.default_scope
+
+    def can_read(self, memory: MemoryItem | dict[str, Any], agent_id: str) -> bool:
+        """Whether an agent may recall a memory."""
+        if _field(memory, "agent_id") == agent_id:
+            return True
+        scope = self.scope_of(memory)
+        if scope is MemoryScope.TENANT:
+            return True
+        if scope is MemoryScope.TEAM:
+            team_id = _field(memory, TEAM_KEY)
+            return team_id is not None and agent_id in self._members.get(team_id, ())
+        return False
+
+    def filter(
+        self, memories: list[MemoryItem | dict[str, Any]], agent_id: str
+    ) -> list[MemoryItem | dict[str, Any]]:
+        """Memories the agent may recall, in their original order."""
+        return [m for m in memories if self.can_read(m, agent_id)]
@@ -0,0 +1,93 @@
+"""Health and readiness checks across the backends of a deployment.
+
+SystemHealth runs the health_check() of every component (see
+interfaces.IHealthCheck) concurrently and reports each one's status and
+latency:
+
+    health = SystemHealth({"storage": storage, "vectors": vectors})
+    report = await health.check_all()
+    if not report.healthy: ...
+
+A check that raises, returns False or exceeds the timeout marks its
+component unhealthy. Components without health_check() are reported as
+unknown and do not make the system unhealthy.
+"""
+
+import asyncio
+import time
+from typing import Any
+
+from rae_core.config.defaults import DEFAULT_HEALTH_CHECK_TIMEOUT
+from rae_core.interfaces.health import IHealthCheck
+from rae_core.models.health import ComponentHealth, HealthStatus, SystemHealthReport
+
+
+async def components_healthy(*components: Any) -> bool:
+    """Whether every component that has a health check passes it.
+
+    Used by backends wrapping other backends (overlays, replicas).
+    """
+    for component in components:
+        if isinstance(component, IHealthCheck) and not await component.health_check():
+            return False
+    return True
+
+
+class SystemHealth:
+    """Aggregate health check of named backends."""
+
+    def __init__(
+        self,
+        components: dict[str, Any],
+        timeout: float = DEFAULT_HEALTH_CHECK_TIMEOUT,
+    ):
+        """Initialize the checker.
+
+        Args:
+            components: Backend by name; None entries are skipped
+            timeout: Seconds each check may take
+        """
+        if timeout <= 0:
+            raise ValueError("timeout must be positive")
+        self.components = {
+            name: component
+            for name, component in components.items()
+            if component is not None
+        }
+        self.timeout = timeout
+
+    async def check(self, name: str, component: Any) -> ComponentHealth:
+        """Run the health check of one component."""
+        backend = type(component).__name__
+        if not isinstance(component, IHealthCheck):
+            return ComponentHealth(
+                name=name, backend=backend, status=HealthStatus.UNKNOWN
+            )
+        error = None
+        started = time.perf_counter()
+        try:
+            ok = await asyncio.wait_for(component.health_check(), self.timeout)
+            if not ok:
+                error = "health check failed"
+        except asyncio.TimeoutError:
+            error = f"timed out after {self.timeout}s"
+        except Exception as e:
+            error = str(e) or type(e).__name__
+        return ComponentHealth(
+            name=name,
+            backend=backend,
+            status=HealthStatus.UNHEALTHY if error else HealthStatus.OK,
+            latency_ms=(time.perf_counter() - started) * 1000.0,
+            error=error,
+        )
+
+    async def check_all(self) -> SystemHealthReport:
+        """Check every component concurrently."""
+        results = await asyncio.gather(
+            *(self.check(name, c) for name, c in self.components.items())
+        )
+        unhealthy = any(r.status is HealthStatus.UNHEALTHY for r in results)
+        return SystemHealthReport(
+            status=HealthStatus.UNHEALTHY if unhealthy else HealthStatus.OK,
+            components=list(results),
+        )
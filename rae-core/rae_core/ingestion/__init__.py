@@ -1,6 +1,26 @@
 """RAE Ingestion Package."""
 
 from .pipeline import UniversalIngestPipeline
-from .interfaces import ContentSignature, IngestChunk
+from .code import CodeIngestor, CodeParser
+from .conversation import ConversationIngestor
+from .interfaces import ContentSignature, IngestChunk, IPiiDetector, PiiMatch
+from .novelty import NoveltyDecision, NoveltyGate
+from .redactor import PII_METADATA_KEY, PiiRedactor, RegexDetector
+from .stream import StreamIngestor
 
-__all__ = ["UniversalIngestPipeline", "ContentSignature", "IngestChunk"]
+__all__ = [
+    "UniversalIngestPipeline",
+    "ContentSignature",
+    "IngestChunk",
+    "IPiiDetector",
+    "PiiMatch",
+    "NoveltyDecision",
+    "NoveltyGate",
+    "ConversationIngestor",
+    "CodeIngestor",
+    "CodeParser",
+    "StreamIngestor",
+    "PiiRedactor",
+    "RegexDetector",
+    "PII_METADATA_KEY",
+]
@@ -0,0 +1,467 @@
+"""
+RAE Code Ingestor.
+Stores source files as one memory per function, method or class, so coding
+agents recall the symbol they need rather than a whole file or an arbitrary
+text chunk. Python is split with the standard library's ast module; other
+languages (JavaScript, TypeScript, Go, Rust, Java) need tree-sitter
+(pip install rae-core[code]) and are otherwise stored as one memory per
+file. Symbols are stored verbatim, tagged with their language and name and
+described in metadata (path, qualified name, kind, lines, called names).
+With a graph store each symbol gets a "defined_in" edge to a node of its
+file and "calls" edges to the ingested symbols it calls.
+"""
+
+import ast
+import re
+from collections.abc import Iterable
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+from uuid import NAMESPACE_URL, UUID, uuid5
+
+import structlog
+
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.models.code import CodeIngestReport
+from rae_core.models.graph import EdgeType, NodeType
+from rae_core.models.provenance import MemorySource, SourceType
+from rae_core.types.enums import MemoryType
+
+logger = structlog.get_logger(__name__)
+
+# Languages by file extension
+LANGUAGES = {
+    ".py": "python",
+    ".js": "javascript",
+    ".jsx": "javascript",
+    ".mjs": "javascript",
+    ".ts": "typescript",
+    ".tsx": "tsx",
+    ".go": "go",
+    ".rs": "rust",
+    ".java": "java",
+}
+
+# Tree-sitter node types of definitions, by language
+_TS_DEFINITIONS: dict[str, dict[str, str]] = {
+    "javascript": {
+        "function_declaration": "function",
+        "class_declaration": "class",
+        "method_definition": "method",
+    },
+    "typescript": {
+        "function_declaration": "function",
+        "class_declaration": "class",
+        "interface_declaration": "class",
+        "method_definition": "method",
+    },
+    "go": {
+        "function_declaration": "function",
+        "method_declaration": "method",
+        "type_spec": "class",
+    },
+    "rust": {
+        "function_item": "function",
+        "struct_item": "class",
+        "enum_item": "class",
+        "trait_item": "class",
+    },
+    "java": {
+        "class_declaration": "class",
+        "interface_declaration": "class",
+        "method_declaration": "method",
+        "constructor_declaration": "method",
+    },
+}
+_TS_DEFINITIONS["tsx"] = _TS_DEFINITIONS["typescript"]
+# Nodes grouping definitions under a type name without being one (Rust impl)
+_TS_CONTAINERS = {"impl_item": "type"}
+_TS_CALLS = {"call_expression": "function", "method_invocation": "name"}
+_IDENTIFIER = re.compile(r"[A-Za-z_]\w*")
+
+
+@dataclass
+class CodeSymbol:
+    """A function, method, class or whole module of a source file."""
+
+    name: str
+    qualified_name: str
+    # function, method, class or module
+    kind: str
+    start_line: int
+    end_line: int
+    content: str
+    # Names called by the symbol, in order of first call
+    calls: list[str] = field(default_factory=list)
+
+
+def detect_language(path: str) -> str | None:
+    """Language of a source file from its extension (None if unknown)."""
+    return LANGUAGES.get(Path(path).suffix.lower())
+
+
+def _unique(names: Iterable[str]) -> list[str]:
+    return list(dict.fromkeys(names))
+
+
+def _python_calls(nodes: Iterable[ast.AST]) -> list[str]:
+    names = []
+    for root in nodes:
+        for node in ast.walk(root):
+            if isinstance(node, ast.Call):
+                if isinstance(node.func, ast.Name):
+                    names.append(node.func.id)
+                elif isinstance(node.func, ast.Attribute):
+                    names.append(node.func.attr)
+    return _unique(names)
+
+
+def parse_python(source: str) -> list[CodeSymbol]:
+    """Functions, methods and classes of Python source.
+
+    A class symbol holds its header, docstring and attributes; its methods
+    are symbols of their own.
+
+    Raises:
+        SyntaxError: The source is not valid Python
+    """
+    tree = ast.parse(source)
+    lines = source.splitlines()
+    symbols: list[CodeSymbol] = []
+    definitions = (ast.FunctionDef, ast.AsyncFunctionDef, ast.ClassDef)
+
+    def segment(start: int, end: int) -> str:
+        return "\n".join(lines[start - 1 : end]).rstrip()
+
+    def visit(body: list[ast.stmt], parent: str | None) -> None:
+        for node in body:
+            if not isinstance(node, definitions):
+                continue
+            qualified = f"{parent}.{node.name}" if parent else node.name
+            start = min([node.lineno] + [d.lineno for d in node.decorator_list])
+            end = node.end_lineno or node.lineno
+            if isinstance(node, ast.ClassDef):
+                nested = [n for n in node.body if isinstance(n, definitions)]
+                if nested:
+                    first = nested[0]
+                    end = min([first.lineno] + [d.lineno for d in first.decorator_list])
+                    end -= 1
+                calls = _python_calls(
+                    n for n in node.body if not isinstance(n, definitions)
+                )
+                kind = "class"
+            else:
+                calls = _python_calls(node.body)
+                kind = "method" if parent else "function"
+            symbols.append(
+                CodeSymbol(
+                    name=node.name,
+                    qualified_name=qualified,
+                    kind=kind,
+                    start_line=start,
+                    end_line=end,
+                    content=segment(start, end),
+                    calls=calls,
+                )
+            )
+            if isinstance(node, ast.ClassDef):
+                visit(node.body, qualified)
+
+    visit(tree.body, None)
+    return symbols
+
+
+def _tree_sitter_parser(language: str) -> Any:
+    """Tree-sitter parser of a language; None without the code extra."""
+    try:
+        from tree_sitter_language_pack import get_parser
+    except ImportError:
+        return None
+    return get_parser(language)
+
+
+def parse_tree_sitter(source: str, language: str, parser: Any) -> list[CodeSymbol]:
+    """Definitions of source in a language of _TS_DEFINITIONS."""
+    data = source.encode("utf-8")
+    definitions = _TS_DEFINITIONS[language]
+    symbols: list[CodeSymbol] = []
+
+    def text(node: Any) -> str:
+        return data[node.start_byte : node.end_byte].decode("utf-8", "replace")
+
+    def calls(node: Any, skip_definitions: bool) -> list[str]:
+        names = []
+        stack = list(node.named_children)
+        while stack:
+            child = stack.pop(0)
+            if skip_definitions and child.type in definitions:
+                continue
+            field_name = _TS_CALLS.get(child.type)
+            target = child.child_by_field_name(field_name) if field_name else None
+            if target is not None:
+                identifiers = _IDENTIFIER.findall(text(target))
+                if identifiers:
+                    names.append(identifiers[-1])
+            stack.extend(child.named_children)
+        return _unique(names)
+
+    def first_definition(node: Any) -> Any:
+        stack = list(node.named_children)
+        while stack:
+            child = stack.pop(0)
+            if child.type in definitions:
+                return child
+            stack.extend(child.named_children)
+        return None
+
+    def visit(node: Any, parent: str | None) -> None:
+        for child in node.named_children:
+            kind = definitions.get(child.type)
+            container = _TS_CONTAINERS.get(child.type)
+            if kind is None:
+                name_node = child.child_by_field_name(container) if container else None
+                visit(child, text(name_node) if name_node is not None else parent)
+                continue
+            name_node = child.child_by_field_name("name")
+            if name_node is None:
+                visit(child, parent)
+                continue
+            name = text(name_node)
+            qualified = f"{parent}.{name}" if parent else name
+            end_byte, end_point = child.end_byte, child.end_point
+            if kind == "class":
+                nested = first_definition(child)
+                if nested is not None:
+                    end_byte, end_point = nested.start_byte, nested.start_point
+            elif kind == "function" and parent:
+                kind = "method"
+            symbols.append(
+                CodeSymbol(
+                    name=name,
+                    qualified_name=qualified,
+                    kind=kind,
+                    start_line=child.start_point[0] + 1,
+                    end_line=end_point[0] + 1,
+                    content=data[child.start_byte : end_byte]
+                    .decode("utf-8", "replace")
+                    .rstrip(),
+                    calls=calls(child, skip_definitions=kind == "class"),
+                )
+            )
+            if kind == "class":
+                visit(child, qualified)
+
+    visit(parser.parse(data).root_node, None)
+    return symbols
+
+
+class CodeParser:
+    """Splits source files into symbols."""
+
+    def __init__(self, use_tree_sitter: bool = True):
+        """Initialize parser.
+
+        Args:
+            use_tree_sitter: Split non-Python languages with tree-sitter when
+                it is installed
+        """
+        self.use_tree_sitter = use_tree_sitter
+        self._parsers: dict[str, Any] = {}
+
+    def parse(self, source: str, language: str) -> list[CodeSymbol]:
+        """Symbols of source; empty when the language cannot be split."""
+        if language == "python":
+            try:
+                return parse_python(source)
+            except SyntaxError as e:
+                logger.warning("code_parse_failed", language=language, error=str(e))
+                return []
+        if not self.use_tree_sitter or language not in _TS_DEFINITIONS:
+            return []
+        if language not in self._parsers:
+            self._parsers[language] = _tree_sitter_parser(language)
+            if self._parsers[language] is None:
+                logger.info("tree_sitter_unavailable", language=language)
+        parser = self._parsers[language]
+        if parser is None:
+            return []
+        return parse_tree_sitter(source, language, parser)
+
+
+def _module_symbol(path: str, source: str) -> CodeSymbol:
+    stem = Path(path).stem
+    return CodeSymbol(
+        name=stem,
+        qualified_name=stem,
+        kind="module",
+        start_line=1,
+        end_line=max(1, len(source.splitlines())),
+        content=source.rstrip(),
+    )
+
+
+class CodeIngestor:
+    """Stores source files as linked symbol memories."""
+
+    def __init__(
+        self,
+        engine: Any,
+        graph_store: IGraphStore | None = None,
+        parser: CodeParser | None = None,
+        layer: str = "semantic",
+    ):
+        """Initialize ingestor.
+
+        Args:
+            engine: RAEEngine storing the memories
+            graph_store: Graph store receiving the edges (none if None)
+            parser: Symbol parser (tree-sitter enabled if None)
+            layer: Layer of the symbol memories
+        """
+        self.engine = engine
+        self.graph_store = graph_store
+        self.parser = parser or CodeParser()
+        self.layer = layer
+
+    async def ingest_directory(
+        self,
+        root: str | Path,
+        tenant_id: str,
+        agent_id: str = "default",
+        project: str | None = None,
+        pattern: str = "**/*",
+    ) -> CodeIngestReport:
+        """Ingest the files of a known language under root.
+
+        Paths are stored relative to root; hidden directories are skipped.
+        """
+        root = Path(root)
+        files = {}
+        for path in sorted(root.glob(pattern)):
+            relative = path.relative_to(root)
+            if any(part.startswith(".") for part in relative.parts):
+                continue
+            if path.is_file() and detect_language(path.name) is not None:
+                files[relative.as_posix()] = path.read_text("utf-8", "replace")
+        return await self.ingest_files(files, tenant_id, agent_id, project)
+
+    async def ingest_files(
+        self,
+        files: dict[str, str],
+        tenant_id: str,
+        agent_id: str = "default",
+        project: str | None = None,
+        language: str | None = None,
+    ) -> CodeIngestReport:
+        """Ingest source files given as {path: source}.
+
+        Calls are resolved among the symbols of all the files, preferring
+        a symbol of the calling file.
+
+        Args:
+            files: Source of each file by path
+            tenant_id: Tenant owning the memories
+            agent_id: Agent owning the memories
+            project: Project of the memories
+            language: Language of every file (detected per file if None)
+        """
+        report = CodeIngestReport()
+        stored: list[tuple[str, CodeSymbol, UUID]] = []
+        for path, source in files.items():
+            file_language = language or detect_language(path)
+            if file_language is None or not source.strip():
+                report.skipped.append(path)
+                continue
+            report.files += 1
+            symbols = self.parser.parse(source, file_language)
+            stored_before = len(stored)
+            for symbol in symbols or [_module_symbol(path, source)]:
+                memory_id = await self.engine.store_verbatim(
+                    symbol.content,
+                    tenant_id,
+                    agent_id=agent_id,
+                    layer=self.layer,
+                    memory_type=MemoryType.CODE.value,
+                    project=project,
+                    tags=_unique([file_language, symbol.name]),
+                    metadata={
+                        "language": file_language,
+                        "path": path,
+                        "symbol": symbol.qualified_name,
+                        "kind": symbol.kind,
+                        "start_line": symbol.start_line,
+                        "end_line": symbol.end_line,
+                        "calls": symbol.calls,
+                    },
+                    source=MemorySource(
+                        type=SourceType.IMPORT, ref=f"code:{path}", file=path
+                    ),
+                )
+                if memory_id is None:
+                    continue
+                report.memory_ids.append(memory_id)
+                report.symbols[f"{path}::{symbol.qualified_name}"] = memory_id
+                stored.append((path, symbol, memory_id))
+
+            if self.graph_store is not None and len(stored) > stored_before:
+                await self.graph_store.create_node(
+                    self.file_node_id(path, project),
+                    NodeType.FILE.value,
+                    tenant_id,
+                    {"path": path, "language": file_language, "project": project},
+                )
+
+        if self.graph_store is not None:
+            report.edges = await self._link(tenant_id, project, stored)
+        logger.info(
+            "code_ingested",
+            tenant_id=tenant_id,
+            files=report.files,
+            symbols=len(report.memory_ids),
+            edges=report.edges,
+        )
+        return report
+
+    @staticmethod
+    def file_node_id(path: str, project: str | None = None) -> UUID:
+        """Graph node id of a source file (stable across runs)."""
+        return uuid5(NAMESPACE_URL, f"rae-code:{project or ''}:{path}")
+
+    async def _link(
+        self,
+        tenant_id: str,
+        project: str | None,
+        stored: list[tuple[str, CodeSymbol, UUID]],
+    ) -> int:
+        assert self.graph_store is not None
+        by_name: dict[str, list[tuple[str, UUID]]] = {}
+        for path, symbol, memory_id in stored:
+            by_name.setdefault(symbol.name, []).append((path, memory_id))
+
+        edges = 0
+        for path, symbol, memory_id in stored:
+            await self.graph_store.create_node(
+                memory_id,
+                NodeType.MEMORY.value,
+                tenant_id,
+                {"symbol": symbol.qualified_name, "path": path},
+            )
+            await self.graph_store.create_edge(
+                memory_id,
+                self.file_node_id(path, project),
+                EdgeType.DEFINED_IN.value,
+                tenant_id,
+            )
+            edges += 1
+        for path, symbol, memory_id in stored:
+            for name in symbol.calls:
+                candidates = by_name.get(name, [])
+                local = [m for p, m in candidates if p == path]
+                for target in local or [m for _, m in candidates]:
+                    if target == memory_id:
+                        continue
+                    await self.graph_store.create_edge(
+                        memory_id, target, EdgeType.CALLS.value, tenant_id
+                    )
+                    edges += 1
+        return edges
@@ -0,0 +1,236 @@
+"""
+RAE Conversation Ingestor.
+Stores a conversation given as role-tagged turns. Consecutive turns are
+grouped into working-layer memories (one "user: ... / assistant: ..."
+exchange each by default) carrying the conversation and session ids, the
+roles and the turn range in their metadata. With a graph store the memories
+are chained by "follows" edges in conversation order, and with
+summarize_every every N turns are also summarized into one memory linked to
+its sources by "derived_from" edges.
+"""
+
+from collections.abc import Sequence
+from typing import Any
+from uuid import UUID, uuid4
+
+import structlog
+
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.llm.fallback import NoLLMFallback
+from rae_core.models.conversation import (
+    ConversationIngestConfig,
+    ConversationIngestReport,
+    ConversationTurn,
+)
+from rae_core.models.graph import EdgeType, NodeType
+from rae_core.models.provenance import MemorySource, SourceType
+from rae_core.types.enums import MemoryType
+
+logger = structlog.get_logger(__name__)
+
+CONVERSATION_KEY = "conversation_id"
+SUMMARY_TAG = "conversation_summary"
+
+
+def format_turns(turns: Sequence[ConversationTurn]) -> str:
+    """Turns as "role: content" lines."""
+    lines = []
+    for turn in turns:
+        speaker = turn.role.value
+        if turn.name:
+            speaker = f"{speaker} ({turn.name})"
+        lines.append(f"{speaker}: {turn.content}")
+    return "\n".join(lines)
+
+
+class ConversationIngestor:
+    """Turns conversations into linked working memories."""
+
+    def __init__(
+        self,
+        engine: Any,
+        graph_store: IGraphStore | None = None,
+        summarizer: Any = None,
+        config: ConversationIngestConfig | None = None,
+    ):
+        """Initialize ingestor.
+
+        Args:
+            engine: RAEEngine storing the memories
+            graph_store: Graph store receiving the edges (none if None)
+            summarizer: Object with async summarize(text, max_length) (the
+                engine's LLM provider if it has one, else NoLLMFallback)
+            config: Grouping, layer and summarization options
+        """
+        self.engine = engine
+        self.graph_store = graph_store
+        if summarizer is None:
+            provider = getattr(engine, "llm_provider", None)
+            if hasattr(provider, "summarize"):
+                summarizer = provider
+            else:
+                summarizer = NoLLMFallback()
+        self.summarizer = summarizer
+        self.config = config or ConversationIngestConfig()
+
+    async def ingest(
+        self,
+        turns: Sequence[ConversationTurn | dict[str, Any]],
+        tenant_id: str,
+        agent_id: str = "default",
+        session_id: str | None = None,
+        conversation_id: str | None = None,
+        summarize_every: int | None = None,
+    ) -> ConversationIngestReport:
+        """Store the turns of a conversation.
+
+        Args:
+            turns: Turns in conversation order
+            tenant_id: Tenant owning the memories
+            agent_id: Agent owning the memories
+            session_id: Session of the memories (new one if None)
+            conversation_id: Conversation identifier (new one if None)
+            summarize_every: Override of config.summarize_every
+        """
+        config = self.config
+        parsed = [ConversationTurn.model_validate(t) for t in turns]
+        conversation_id = conversation_id or str(uuid4())
+        session_id = session_id or conversation_id
+        report = ConversationIngestReport(
+            conversation_id=conversation_id, session_id=session_id, turns=len(parsed)
+        )
+
+        previous: UUID | None = None
+        # (first turn, last turn, memory id) of every stored group
+        stored: list[tuple[int, int, UUID]] = []
+        for start in range(0, len(parsed), config.turns_per_memory):
+            group = parsed[start : start + config.turns_per_memory]
+            end = start + len(group) - 1
+            memory_id = await self.engine.store_memory(
+                tenant_id=tenant_id,
+                agent_id=agent_id,
+                content=format_turns(group),
+                layer=config.layer,
+                memory_type=MemoryType.CONVERSATION.value,
+                session_id=session_id,
+                metadata=self._metadata(conversation_id, group, start, end),
+                source=MemorySource(
+                    type=SourceType.USER_MESSAGE, ref=conversation_id
+                ),
+                check_novelty=False,
+            )
+            if memory_id is None:
+                continue
+            report.memory_ids.append(memory_id)
+            stored.append((start, end, memory_id))
+            if self.graph_store is not None:
+                await self._node(memory_id, tenant_id, config.layer)
+                if previous is not None:
+                    await self.graph_store.create_edge(
+                        previous, memory_id, EdgeType.FOLLOWS.value, tenant_id
+                    )
+                    report.edges += 1
+            previous = memory_id
+
+        every = summarize_every or config.summarize_every
+        if every:
+            for start in range(0, len(parsed) - every + 1, every):
+                end = start + every - 1
+                sources = [
+                    m for first, last, m in stored if first <= end and last >= start
+                ]
+                summary_id = await self._summarize(
+                    tenant_id,
+                    agent_id,
+                    session_id,
+                    conversation_id,
+                    parsed[start : end + 1],
+                    start,
+                    sources,
+                )
+                if summary_id is None:
+                    continue
+                report.summary_ids.append(summary_id)
+                if self.graph_store is not None:
+                    report.edges += len(sources)
+
+        logger.info(
+            "conversation_ingested",
+            tenant_id=tenant_id,
+            conversation_id=conversation_id,
+            turns=report.turns,
+            memories=len(report.memory_ids),
+            summaries=len(report.summary_ids),
+        )
+        return report
+
+    @staticmethod
+    def _metadata(
+        conversation_id: str,
+        turns: Sequence[ConversationTurn],
+        start: int,
+        end: int,
+    ) -> dict[str, Any]:
+        timestamps = [t.timestamp for t in turns if t.timestamp is not None]
+        return {
+            CONVERSATION_KEY: conversation_id,
+            "roles": sorted({t.role.value for t in turns}),
+            "turn_start": start,
+            "turn_end": end,
+            "started_at": min(timestamps).isoformat() if timestamps else None,
+            "ended_at": max(timestamps).isoformat() if timestamps else None,
+        }
+
+    async def _node(self, memory_id: UUID, tenant_id: str, layer: str) -> None:
+        assert self.graph_store is not None
+        await self.graph_store.create_node(
+            memory_id, NodeType.MEMORY.value, tenant_id, {"layer": layer}
+        )
+
+    async def _summarize(
+        self,
+        tenant_id: str,
+        agent_id: str,
+        session_id: str,
+        conversation_id: str,
+        turns: Sequence[ConversationTurn],
+        start: int,
+        sources: list[UUID],
+    ) -> UUID | None:
+        config = self.config
+        try:
+            summary = await self.summarizer.summarize(
+                format_turns(turns), max_length=config.summary_max_length
+            )
+        except Exception as e:
+            logger.warning("conversation_summary_failed", error=str(e))
+            return None
+        if not summary or not summary.strip():
+            return None
+
+        metadata = self._metadata(
+            conversation_id, turns, start, start + len(turns) - 1
+        )
+        metadata["source_memory_ids"] = [str(m) for m in sources]
+        summary_id: UUID | None = await self.engine.store_memory(
+            tenant_id=tenant_id,
+            agent_id=agent_id,
+            content=summary.strip(),
+            layer=config.summary_layer,
+            session_id=session_id,
+            tags=[SUMMARY_TAG],
+            metadata=metadata,
+            source=MemorySource(
+                type=SourceType.CONSOLIDATION,
+                ref=conversation_id,
+                derived_from=sources,
+            ),
+            check_novelty=False,
+        )
+        if summary_id is not None and self.graph_store is not None:
+            await self._node(summary_id, tenant_id, config.summary_layer)
+            for source in sources:
+                await self.graph_store.create_edge(
+                    summary_id, source, EdgeType.DERIVED_FROM.value, tenant_id
+                )
+        return summary_id
@@ -60,3 +60,18 @@ class ICompressor(ABC):
     @abstractmethod
     def compress(self, chunks: List[IngestChunk], policy: str) -> tuple[List[IngestChunk], Dict[str, Any], IngestAudit]:
         pass
+
+@dataclass
+class PiiMatch:
+    """A span of personal data found by a detector."""
+    kind: str
+    start: int
+    end: int
+
+class IPiiDetector(ABC):
+    """Finds one kind of personal data (email, phone, ...) in text."""
+    kind: str
+
+    @abstractmethod
+    def detect(self, text: str) -> List[PiiMatch]:
+        pass
@@ -0,0 +1,84 @@
+"""
+RAE Novelty Gate.
+Optional write-path stage that keeps chatty agents from storing the same
+observation over and over. Incoming content is compared with the most
+similar memory of the same agent and layer; when its novelty (1 - cosine
+similarity) is at or below the threshold, the engine records another access of
+the existing memory instead of writing a new one, so memory grows with
+what is new rather than with what is said.
+"""
+
+from dataclasses import dataclass
+from typing import Any
+from uuid import UUID
+
+from rae_core.config.defaults import DEFAULT_NOVELTY_THRESHOLD
+
+
+@dataclass
+class NoveltyDecision:
+    """Outcome of the novelty check of one write."""
+
+    novel: bool
+    # 1 - similarity of the closest existing memory (1.0 when there is none)
+    novelty: float
+    # Existing memory absorbing the write when it is not novel
+    duplicate_of: UUID | None = None
+
+
+class NoveltyGate:
+    """Decides whether content is new enough to be stored."""
+
+    def __init__(self, threshold: float = DEFAULT_NOVELTY_THRESHOLD):
+        """Initialize gate.
+
+        Args:
+            threshold: Novelty (0-1) at or below which content counts as
+                redundant; 0 only merges exact vector matches
+        """
+        if not 0.0 <= threshold <= 1.0:
+            raise ValueError("threshold must be between 0 and 1")
+        self.threshold = threshold
+
+    async def evaluate(
+        self,
+        content: str,
+        tenant_id: str,
+        embedding_provider: Any,
+        vector_store: Any,
+        agent_id: str | None = None,
+        layer: str | None = None,
+        project: str | None = None,
+    ) -> NoveltyDecision:
+        """Compare content with the closest memory in the same scope."""
+        from rae_core.embedding.manager import EmbeddingManager
+
+        vector_name = None
+        if isinstance(embedding_provider, EmbeddingManager):
+            # Search the vector space the tenant's memories are written to
+            vector_name = embedding_provider.model_for_tenant(tenant_id)
+            embedding = await embedding_provider.embed_text(
+                content, task_type="search_document", tenant_id=tenant_id
+            )
+        else:
+            embedding = await embedding_provider.embed_text(
+                content, task_type="search_document"
+            )
+
+        closest = await vector_store.search_similar(
+            embedding,
+            tenant_id,
+            layer=layer,
+            limit=1,
+            agent_id=agent_id,
+            project=project,
+            vector_name=vector_name,
+        )
+        if not closest:
+            return NoveltyDecision(novel=True, novelty=1.0)
+
+        memory_id, similarity = closest[0]
+        novelty = min(1.0, max(0.0, 1.0 - float(similarity)))
+        if novelty <= self.threshold:
+            return NoveltyDecision(novel=False, novelty=novelty, duplicate_of=memory_id)
+        return NoveltyDecision(novel=True, novelty=novelty)
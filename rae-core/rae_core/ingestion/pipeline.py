@@ -15,6 +15,7 @@ from .detector import ContentSignatureDetector
 from .policy import IngestPolicySelector
 from .segmenter import IngestSegmenter
 from .compressor import IngestCompressor
+from .redactor import PII_METADATA_KEY, PiiRedactor
 
 logger = structlog.get_logger(__name__)
 
@@ -23,7 +24,11 @@ class UniversalIngestPipeline:
     Orchestrates ingestion from raw text to structured, multi-vector memories.
     """
     
-    def __init__(self, config_path: Optional[str] = None):
+    def __init__(
+        self,
+        config_path: Optional[str] = None,
+        redactor: Optional[PiiRedactor] = None,
+    ):
         # Load configuration
         self.config = self._load_config(config_path)
         
@@ -33,6 +38,8 @@ class UniversalIngestPipeline:
         self.policy_selector = IngestPolicySelector()
         self.segmenter = IngestSegmenter(config=self.config) # Ingesting rules
         self.compressor = IngestCompressor()
+        # Optional PII masking between normalization and detection
+        self.redactor = redactor
 
     def _load_config(self, path: Optional[str]) -> Dict[str, Any]:
         if not path:
@@ -65,6 +72,12 @@ class UniversalIngestPipeline:
         # Stage 1: Normalize
         normalized_text, audit_1 = self.normalizer.normalize(text, metadata)
         audit_trail.append(audit_1)
+
+        # Stage 1b: PII Redaction (optional)
+        pii_report = None
+        if self.redactor is not None:
+            normalized_text, pii_report, audit_pii = self.redactor.redact(normalized_text)
+            audit_trail.append(audit_pii)
         
         # Stage 2: Detect Signature (S-D-O Layer)
         signature, audit_2 = self.detector.detect(normalized_text)
@@ -82,6 +95,8 @@ class UniversalIngestPipeline:
         for chunk in chunks:
             if not chunk.metadata: chunk.metadata = {}
             chunk.metadata["content_hash"] = text_hash
+            if pii_report and pii_report["total"]:
+                chunk.metadata[PII_METADATA_KEY] = pii_report
 
         # Stage 5: Compression / Semantic Folding
         compressed_chunks, prov_map, audit_5 = self.compressor.compress(chunks, policy)
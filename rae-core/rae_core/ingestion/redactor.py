@@ -0,0 +1,159 @@
+"""
+RAE PII Redactor.
+Optional stage of the Universal Ingest Pipeline, run after normalization.
+Masks personal data (emails, phone numbers, credit card numbers) before
+anything is segmented or stored, and reports what was masked so compliance
+reports can be produced. The values themselves are never recorded.
+"""
+
+import re
+from collections import Counter
+from typing import Any, Callable, Dict, Iterable, List, Optional
+
+import structlog
+
+from .interfaces import IngestAudit, IPiiDetector, PiiMatch
+
+logger = structlog.get_logger(__name__)
+
+# Chunk metadata key holding the redaction report
+PII_METADATA_KEY = "pii_redactions"
+
+
+def luhn_valid(number: str) -> bool:
+    """Luhn checksum of the digits in number."""
+    digits = [int(c) for c in number if c.isdigit()]
+    total = 0
+    for i, digit in enumerate(reversed(digits)):
+        if i % 2:
+            digit *= 2
+            if digit > 9:
+                digit -= 9
+        total += digit
+    return bool(digits) and total % 10 == 0
+
+
+class RegexDetector(IPiiDetector):
+    """Detector matching a regular expression, optionally validated."""
+
+    def __init__(
+        self,
+        kind: str,
+        pattern: str,
+        validator: Optional[Callable[[str], bool]] = None,
+        flags: int = 0,
+    ):
+        self.kind = kind
+        self.pattern = re.compile(pattern, flags)
+        self.validator = validator
+
+    def detect(self, text: str) -> List[PiiMatch]:
+        return [
+            PiiMatch(self.kind, m.start(), m.end())
+            for m in self.pattern.finditer(text)
+            if self.validator is None or self.validator(m.group())
+        ]
+
+
+class EmailDetector(RegexDetector):
+    def __init__(self) -> None:
+        super().__init__(
+            "email", r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}"
+        )
+
+
+class PhoneDetector(RegexDetector):
+    """Phone numbers of 9-10 digits, with optional country and area codes."""
+
+    def __init__(self) -> None:
+        super().__init__(
+            "phone",
+            r"(?<![\w+])(?:\+\d{1,3}[\s.-]?)?(?:\(\d{1,4}\)[\s.-]?)?"
+            r"\d{3}[\s.-]?\d{3}[\s.-]?\d{3,4}(?!\w)",
+        )
+
+
+class CreditCardDetector(RegexDetector):
+    """Card numbers of 13-19 digits passing the Luhn check."""
+
+    def __init__(self) -> None:
+        super().__init__(
+            "credit_card", r"(?<!\d)(?:\d[ -]?){12,18}\d(?!\d)", validator=luhn_valid
+        )
+
+
+def default_detectors() -> List[IPiiDetector]:
+    return [CreditCardDetector(), EmailDetector(), PhoneDetector()]
+
+
+class PiiRedactor:
+    """
+    Masks the spans found by a set of detectors.
+    Overlapping matches keep the earliest, then the longest, span.
+    """
+
+    def __init__(
+        self,
+        detectors: Optional[Iterable[IPiiDetector]] = None,
+        mask: str = "[{kind}]",
+    ):
+        """
+        Args:
+            detectors: Detectors to run (email, phone and credit card if None)
+            mask: Replacement text; {kind} is the upper-cased detector kind
+        """
+        self.detectors = list(default_detectors() if detectors is None else detectors)
+        self.mask = mask
+
+    def add_detector(self, detector: IPiiDetector) -> None:
+        self.detectors.append(detector)
+
+    def find(self, text: str) -> List[PiiMatch]:
+        """Non-overlapping matches of all detectors, in text order."""
+        matches = [m for d in self.detectors for m in d.detect(text)]
+        matches.sort(key=lambda m: (m.start, -(m.end - m.start)))
+        kept: List[PiiMatch] = []
+        for match in matches:
+            if not kept or match.start >= kept[-1].end:
+                kept.append(match)
+        return kept
+
+    def redact(self, text: str) -> tuple[str, Dict[str, Any], IngestAudit]:
+        """
+        Returns the masked text, the redaction report (see report()) and
+        the audit entry of the stage.
+        """
+        matches = self.find(text)
+        parts = []
+        position = 0
+        for match in matches:
+            parts.append(text[position:match.start])
+            parts.append(self.mask.format(kind=match.kind.upper()))
+            position = match.end
+        parts.append(text[position:])
+        redacted = "".join(parts)
+
+        report = self.report(matches)
+        audit = IngestAudit(
+            stage="redact",
+            action="pii_masked" if matches else "skip",
+            trace={"counts": report["counts"]},
+        )
+        if matches:
+            logger.info("pii_redacted", counts=report["counts"])
+        return redacted, report, audit
+
+    @staticmethod
+    def report(matches: List[PiiMatch]) -> Dict[str, Any]:
+        """
+        Redaction report kept in metadata: total, counts by kind and the
+        kind, offset and length (in the original text) of each span.
+        """
+        return {
+            "total": len(matches),
+            "counts": dict(Counter(m.kind for m in matches)),
+            "spans": [
+                {"kind": m.kind, "offset": m.start, "length": m.end - m.start}
+                for m in matches
+            ],
+        }
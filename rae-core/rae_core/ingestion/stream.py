@@ -0,0 +1,267 @@
+"""
+RAE Stream Ingestor.
+Loads large histories given as a stream of items (JSONL lines, dicts or
+IngestItems) without holding them in memory. A reader fills a bounded queue
+of batches that a fixed number of workers drain: each batch is embedded in
+one provider call and its memories stored with their vectors. When the
+workers fall behind the queue fills up and the reader stops pulling from
+the stream, so a fast producer (e.g. an HTTP upload) is slowed to the pace
+of the embedding provider and storage.
+
+Items are stored as single memories, like RAEEngine.store_verbatim, after
+PII redaction. Copies of an earlier item of the stream, or of a memory
+already stored for the same agent and layer, are skipped by content hash,
+so an interrupted load can be re-run. Items that cannot be parsed, fail
+validation or cannot be embedded or stored are counted as failed with
+their reason; the stream goes on.
+"""
+
+import asyncio
+import hashlib
+import json
+from collections.abc import AsyncIterable, Callable, Iterable
+from typing import Any
+
+import structlog
+from pydantic import ValidationError as PydanticValidationError
+
+from rae_core.config.defaults import (
+    DEFAULT_STREAM_BATCH_SIZE,
+    DEFAULT_STREAM_CONCURRENCY,
+    DEFAULT_STREAM_MAX_REPORTED_FAILURES,
+)
+from rae_core.exceptions.base import RAEError, ValidationError
+from rae_core.models.load import PriorityClass
+from rae_core.models.provenance import MemorySource, SourceType
+from rae_core.models.stream import IngestFailure, IngestItem, StreamIngestSummary
+
+logger = structlog.get_logger(__name__)
+
+# Metadata key of the content hash shared with the ingest pipeline
+CONTENT_HASH_KEY = "content_hash"
+
+StreamItem = IngestItem | dict[str, Any] | str | bytes
+_Batch = list[tuple[int, IngestItem, str]]
+
+
+def content_hash(content: str) -> str:
+    """Hash deduplicating writes (the one the ingest pipeline records)."""
+    return hashlib.md5(content.encode()).hexdigest()
+
+
+def parse_item(raw: StreamItem) -> IngestItem | None:
+    """Item of a stream entry (None for a blank line).
+
+    Raises:
+        ValidationError: Malformed JSON or invalid item
+    """
+    if isinstance(raw, IngestItem):
+        return raw
+    if isinstance(raw, bytes):
+        raw = raw.decode("utf-8")
+    if isinstance(raw, str):
+        if not raw.strip():
+            return None
+        try:
+            raw = json.loads(raw)
+        except ValueError as e:
+            raise ValidationError(f"Malformed JSON: {e}") from e
+    try:
+        return IngestItem.model_validate(raw)
+    except PydanticValidationError as e:
+        raise ValidationError(f"Invalid item: {e.errors()[0]['msg']}") from e
+
+
+class StreamIngestor:
+    """Stores a stream of items with batched embedding and backpressure."""
+
+    def __init__(
+        self,
+        engine: Any,
+        batch_size: int = DEFAULT_STREAM_BATCH_SIZE,
+        concurrency: int = DEFAULT_STREAM_CONCURRENCY,
+        dedupe_existing: bool = True,
+        max_reported_failures: int = DEFAULT_STREAM_MAX_REPORTED_FAILURES,
+    ):
+        """Initialize ingestor.
+
+        Args:
+            engine: RAEEngine embedding and storing the memories
+            batch_size: Items embedded per provider call
+            concurrency: Batches embedded and stored at once; at most twice
+                as many batches are read ahead of the workers
+            dedupe_existing: Also skip items already stored for the same
+                agent and layer (one storage lookup per item)
+            max_reported_failures: Failures listed with their reason in the
+                summary (all of them are counted)
+        """
+        if batch_size < 1 or concurrency < 1:
+            raise ValueError("batch_size and concurrency must be positive")
+        self.engine = engine
+        self.batch_size = batch_size
+        self.concurrency = concurrency
+        self.dedupe_existing = dedupe_existing
+        self.max_reported_failures = max_reported_failures
+
+    async def ingest(
+        self,
+        items: AsyncIterable[StreamItem] | Iterable[StreamItem],
+        tenant_id: str,
+        authorize: Callable[[IngestItem], None] | None = None,
+    ) -> StreamIngestSummary:
+        """Store every item of a stream.
+
+        Args:
+            items: Stream of JSONL lines, dicts or IngestItems
+            tenant_id: Tenant receiving the memories
+            authorize: Called with each item before it is stored; a
+                RAEError it raises fails the item (e.g. a missing permission)
+        """
+        summary = StreamIngestSummary()
+        queue: asyncio.Queue[_Batch | None] = asyncio.Queue(
+            maxsize=self.concurrency * 2
+        )
+        workers = [
+            asyncio.create_task(self._work(queue, tenant_id, summary))
+            for _ in range(self.concurrency)
+        ]
+        try:
+            await self._read(items, queue, summary, authorize)
+            for _ in workers:
+                await queue.put(None)
+            await asyncio.gather(*workers)
+        finally:
+            for worker in workers:
+                worker.cancel()
+
+        logger.info(
+            "stream_ingest_completed",
+            tenant_id=tenant_id,
+            items=summary.items,
+            stored=summary.stored,
+            deduped=summary.deduped,
+            failed=summary.failed,
+        )
+        return summary
+
+    async def _read(
+        self,
+        items: AsyncIterable[StreamItem] | Iterable[StreamItem],
+        queue: asyncio.Queue[_Batch | None],
+        summary: StreamIngestSummary,
+        authorize: Callable[[IngestItem], None] | None,
+    ) -> None:
+        """Parse, dedupe and batch the stream, waiting while the queue is full."""
+        seen: set[tuple[str, str, str]] = set()
+        batch: _Batch = []
+        index = 0
+
+        async def entries() -> AsyncIterable[StreamItem]:
+            if isinstance(items, AsyncIterable):
+                async for entry in items:
+                    yield entry
+            else:
+                for entry in items:
+                    yield entry
+
+        async for raw in entries():
+            index += 1
+            try:
+                item = parse_item(raw)
+                if item is None:
+                    continue
+                if authorize is not None:
+                    authorize(item)
+            except RAEError as e:
+                summary.items += 1
+                self._fail(summary, index, str(e))
+                continue
+            summary.items += 1
+            digest = content_hash(item.content)
+            key = (digest, item.agent_id, item.layer)
+            if key in seen:
+                summary.deduped += 1
+                continue
+            seen.add(key)
+            batch.append((index, item, digest))
+            if len(batch) >= self.batch_size:
+                await queue.put(batch)
+                batch = []
+        if batch:
+            await queue.put(batch)
+
+    async def _work(
+        self,
+        queue: asyncio.Queue[_Batch | None],
+        tenant_id: str,
+        summary: StreamIngestSummary,
+    ) -> None:
+        while (batch := await queue.get()) is not None:
+            await self._store_batch(batch, tenant_id, summary)
+
+    async def _store_batch(
+        self, batch: _Batch, tenant_id: str, summary: StreamIngestSummary
+    ) -> None:
+        pending: list[tuple[int, IngestItem, str, str]] = []
+        for index, item, digest in batch:
+            try:
+                if self.dedupe_existing and await self._stored(
+                    item, digest, tenant_id
+                ):
+                    summary.deduped += 1
+                    continue
+                content = item.content
+                if self.engine.pii_redactor is not None:
+                    content, _, _ = self.engine.pii_redactor.redact(content)
+            except Exception as e:
+                self._fail(summary, index, str(e))
+                continue
+            pending.append((index, item, digest, content))
+        if not pending:
+            return
+
+        try:
+            embeddings = await self.engine.embed_documents(
+                [content for _, _, _, content in pending], tenant_id
+            )
+        except Exception as e:
+            logger.warning("stream_ingest_embedding_failed", error=str(e))
+            for index, _, _, _ in pending:
+                self._fail(summary, index, f"Embedding failed: {e}")
+            return
+
+        for (index, item, digest, content), embedding in zip(
+            pending, embeddings, strict=True
+        ):
+            fields = item.model_dump(exclude_none=True, exclude={"content"})
+            fields["metadata"] = {**fields["metadata"], CONTENT_HASH_KEY: digest}
+            fields.setdefault(
+                "source", MemorySource(type=SourceType.IMPORT, ref="stream")
+            )
+            try:
+                await self.engine.store_embedded(
+                    content,
+                    tenant_id,
+                    embedding,
+                    priority=PriorityClass.BATCH,
+                    **fields,
+                )
+            except Exception as e:
+                self._fail(summary, index, str(e))
+            else:
+                summary.stored += 1
+
+    async def _stored(self, item: IngestItem, digest: str, tenant_id: str) -> bool:
+        found = await self.engine.memory_storage.list_memories(
+            tenant_id,
+            agent_id=item.agent_id,
+            layer=item.layer,
+            filters={CONTENT_HASH_KEY: digest},
+            limit=1,
+        )
+        return bool(found)
+
+    def _fail(self, summary: StreamIngestSummary, index: int, reason: str) -> None:
+        summary.failed += 1
+        if len(summary.failures) < self.max_reported_failures:
+            summary.failures.append(IngestFailure(index=index, reason=reason))
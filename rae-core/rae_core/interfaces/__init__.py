@@ -3,10 +3,18 @@
 All storage adapters must implement these interfaces to be compatible with RAE-core.
 """
 
+from .archive import IArchiveStorage
+from .audit import IAuditLogger
+from .blobs import IBlobStore
 from .cache import ICacheProvider
-from .embedding import IEmbeddingProvider
+from .embedding import IEmbeddingProvider, IMultimodalEmbeddingProvider
 from .graph import IGraphStore
+from .health import IHealthCheck
+from .job import IMaintenanceJob
+from .keys import IKeyProvider
 from .llm import ILLMProvider
+from .nli import INliModel
+from .outbox import IOutboxStore
 from .storage import IMemoryStorage
 from .sync import ISyncProvider
 from .vector import IVectorStore
@@ -17,6 +25,15 @@ __all__ = [
     "IGraphStore",
     "ICacheProvider",
     "ILLMProvider",
+    "INliModel",
     "IEmbeddingProvider",
+    "IMultimodalEmbeddingProvider",
     "ISyncProvider",
+    "IAuditLogger",
+    "IOutboxStore",
+    "IKeyProvider",
+    "IHealthCheck",
+    "IArchiveStorage",
+    "IBlobStore",
+    "IMaintenanceJob",
 ]
@@ -0,0 +1,29 @@
+"""Abstract cold-storage interface for archived memories."""
+
+from typing import Any, Protocol, runtime_checkable
+from uuid import UUID
+
+
+@runtime_checkable
+class IArchiveStorage(Protocol):
+    """Cheap, slow storage holding whole memory records.
+
+    Records are written once when a memory is moved out of the hot storage
+    and read back on demand; see adapters.archive.ArchiveTieredStorage.
+    """
+
+    async def put(self, memory: dict[str, Any], tenant_id: str) -> None:
+        """Store a memory record (keyed by memory["id"]), replacing any copy."""
+        ...
+
+    async def get(self, memory_id: UUID, tenant_id: str) -> dict[str, Any] | None:
+        """Read an archived memory record; None if it is not archived."""
+        ...
+
+    async def delete(self, memory_id: UUID, tenant_id: str) -> bool:
+        """Remove an archived memory record."""
+        ...
+
+    async def list_ids(self, tenant_id: str) -> list[UUID]:
+        """Ids of a tenant's archived memories."""
+        ...
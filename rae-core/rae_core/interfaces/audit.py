@@ -0,0 +1,29 @@
+"""Abstract audit logger interface for RAE-core."""
+
+from datetime import datetime
+from typing import Protocol, runtime_checkable
+from uuid import UUID
+
+from rae_core.models.audit import AuditEntry, AuditOperation
+
+
+@runtime_checkable
+class IAuditLogger(Protocol):
+    """Abstract interface for append-only audit logs of memory mutations."""
+
+    async def record(self, entry: AuditEntry) -> None:
+        """Append an entry to the log."""
+        ...
+
+    async def query(
+        self,
+        tenant_id: str,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        actor: str | None = None,
+        operation: AuditOperation | None = None,
+        memory_id: UUID | None = None,
+        limit: int = 100,
+    ) -> list[AuditEntry]:
+        """Entries of a tenant in [start, end), oldest first."""
+        ...
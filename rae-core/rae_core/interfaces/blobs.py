@@ -0,0 +1,32 @@
+"""Abstract content-addressable blob store interface."""
+
+from typing import Protocol, runtime_checkable
+
+
+@runtime_checkable
+class IBlobStore(Protocol):
+    """Binary attachments (images, documents) keyed by their SHA-256 digest.
+
+    Storing the same bytes twice keeps one copy; see
+    adapters.blobs.AttachmentStorage for tying blobs to memories.
+    """
+
+    async def put(self, data: bytes, tenant_id: str) -> str:
+        """Store a blob and return its digest."""
+        ...
+
+    async def get(self, digest: str, tenant_id: str) -> bytes | None:
+        """Read a blob; None if it is not stored."""
+        ...
+
+    async def exists(self, digest: str, tenant_id: str) -> bool:
+        """Whether a blob is stored."""
+        ...
+
+    async def delete(self, digest: str, tenant_id: str) -> bool:
+        """Remove a blob."""
+        ...
+
+    async def list_digests(self, tenant_id: str) -> list[str]:
+        """Digests of a tenant's blobs."""
+        ...
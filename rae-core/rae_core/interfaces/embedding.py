@@ -32,3 +32,16 @@ class IEmbeddingProvider(Protocol):
     def get_dimension(self) -> int:
         """Get embedding dimension."""
         ...
+
+
+@runtime_checkable
+class IMultimodalEmbeddingProvider(IEmbeddingProvider, Protocol):
+    """Embedding provider placing images and text in one vector space.
+
+    With a CLIP-style model, text queries embedded by embed_text find
+    images embedded by embed_image.
+    """
+
+    async def embed_image(self, data: bytes) -> list[float]:
+        """Generate embedding for an encoded image (PNG, JPEG, ...)."""
+        ...
@@ -3,6 +3,8 @@
 from typing import Any, Protocol, runtime_checkable
 from uuid import UUID
 
+from rae_core.models.graph import TraversalLimits
+
 
 @runtime_checkable
 class IGraphStore(Protocol):
@@ -37,8 +39,13 @@ class IGraphStore(Protocol):
         edge_type: str | None = None,
         direction: str = "both",
         max_depth: int = 1,
+        limits: TraversalLimits | None = None,
     ) -> list[UUID]:
-        """Get neighboring nodes."""
+        """Get neighboring nodes.
+
+        limits bounds per-node fan-out, the total number of visited nodes and
+        optionally rejects cycles; see TraversalLimits.
+        """
         ...
 
     async def delete_node(self, node_id: UUID, tenant_id: str) -> bool:
@@ -66,7 +73,14 @@ class IGraphStore(Protocol):
         ...
 
     async def get_subgraph(
-        self, node_ids: list[UUID], tenant_id: str, include_edges: bool = True
+        self,
+        node_ids: list[UUID],
+        tenant_id: str,
+        include_edges: bool = True,
+        limits: TraversalLimits | None = None,
     ) -> dict[str, Any]:
-        """Extract a subgraph."""
+        """Extract a subgraph.
+
+        limits.max_visited caps the number of nodes returned.
+        """
         ...
@@ -0,0 +1,21 @@
+"""Abstract health check interface for RAE-core backends."""
+
+from typing import Protocol, runtime_checkable
+
+
+@runtime_checkable
+class IHealthCheck(Protocol):
+    """Backend able to report whether it can serve requests.
+
+    Storage, vector, graph, cache and embedding backends implement it next
+    to their main interface; rae_core.health.SystemHealth aggregates them.
+    """
+
+    async def health_check(self) -> bool:
+        """Check the backend with a cheap round trip (e.g. SELECT 1).
+
+        Returns:
+            True if the backend is usable; False, or a raised exception
+            describing the failure, otherwise
+        """
+        ...
@@ -0,0 +1,20 @@
+"""Abstract maintenance job interface for the scheduler."""
+
+from typing import Any, Protocol, runtime_checkable
+
+
+@runtime_checkable
+class IMaintenanceJob(Protocol):
+    """Periodic work done for one tenant at a time.
+
+    Register implementations with scheduler.JobScheduler; see
+    scheduler.jobs for the bundled decay, expiration, consolidation,
+    forgetting and re-embedding jobs.
+    """
+
+    # Unique name of the job in a scheduler
+    name: str
+
+    async def run(self, tenant_id: str) -> Any:
+        """Do the job's work for a tenant; the result is kept in its JobRun."""
+        ...
@@ -0,0 +1,20 @@
+"""Abstract key provider interface for RAE-core encryption at rest."""
+
+from typing import Protocol, runtime_checkable
+
+
+@runtime_checkable
+class IKeyProvider(Protocol):
+    """Abstract interface for per-tenant data keys (KMS, Vault, local)."""
+
+    async def current_key(self, tenant_id: str) -> tuple[str, bytes]:
+        """Get the id and 32-byte key new data of a tenant is encrypted with."""
+        ...
+
+    async def get_key(self, tenant_id: str, key_id: str) -> bytes:
+        """Get a tenant key by id, including rotated-out keys.
+
+        Raises:
+            KeyError: Unknown key id
+        """
+        ...
@@ -0,0 +1,24 @@
+"""Natural language inference interface for RAE-core.
+
+An NLI model judges whether one statement contradicts another; it is used
+to flag conflicting memories (see consistency.ContradictionDetector).
+"""
+
+from typing import Protocol, runtime_checkable
+
+
+@runtime_checkable
+class INliModel(Protocol):
+    """Abstract interface for contradiction judges."""
+
+    async def contradiction_score(self, premise: str, hypothesis: str) -> float:
+        """Score how strongly the hypothesis contradicts the premise.
+
+        Args:
+            premise: Statement already in memory
+            hypothesis: Newly stored statement
+
+        Returns:
+            Score between 0.0 (consistent or unrelated) and 1.0 (contradiction)
+        """
+        ...
@@ -0,0 +1,42 @@
+"""Abstract event outbox interface for RAE-core."""
+
+from typing import TYPE_CHECKING, Protocol, runtime_checkable
+
+from rae_core.models.outbox import OutboxEntry
+
+if TYPE_CHECKING:
+    # rae_core.events imports this interface
+    from rae_core.events.models import MemoryEvent
+
+
+@runtime_checkable
+class IOutboxStore(Protocol):
+    """Durable, ordered log of memory events with per-sink delivery cursors.
+
+    A cursor is the sequence of the last entry a sink has acknowledged; sinks
+    that never acknowledged anything are at 0.
+    """
+
+    async def append(self, events: list["MemoryEvent"]) -> list[int]:
+        """Persist events in order and return their sequences."""
+        ...
+
+    async def publish(self, event: "MemoryEvent") -> int:
+        """Persist one event (MemoryEventBus-compatible); returns its sequence."""
+        ...
+
+    async def read(self, after: int, limit: int = 100) -> list[OutboxEntry]:
+        """Entries with a sequence greater than after, oldest first."""
+        ...
+
+    async def get_cursor(self, sink: str) -> int:
+        """Last sequence acknowledged by sink."""
+        ...
+
+    async def set_cursor(self, sink: str, sequence: int) -> None:
+        """Acknowledge every entry up to sequence for sink."""
+        ...
+
+    async def prune(self, up_to: int) -> int:
+        """Delete entries with a sequence up to and including up_to."""
+        ...
@@ -8,6 +8,16 @@ from datetime import datetime
 from typing import Any, Protocol, runtime_checkable
 from uuid import UUID
 
+# Fields a storage manages itself; set_lifecycle restores them from a backup
+LIFECYCLE_FIELDS = (
+    "created_at",
+    "modified_at",
+    "last_accessed_at",
+    "access_count",
+    "version",
+    "deleted_at",
+)
+
 
 @runtime_checkable
 class IMemoryStorage(Protocol):
@@ -15,6 +25,11 @@ class IMemoryStorage(Protocol):
 
     Implementations must provide persistent storage for memories across
     all memory layers (sensory, working, episodic, semantic, reflective).
+
+    Failures are reported with rae_core.exceptions.base errors rather than
+    driver exceptions: NotFoundError, ConflictError, QuotaExceededError and
+    BackendUnavailableError (retryable), falling back to StorageError.
+    Adapters convert driver errors with exceptions.backend.backend_errors.
     """
 
     async def store_memory(self, **kwargs: Any) -> UUID:
@@ -57,8 +72,15 @@ class IMemoryStorage(Protocol):
         memory_id: UUID,
         tenant_id: str,
         updates: dict[str, Any],
+        changed_by: str | None = None,
+        expected_version: int | None = None,
     ) -> bool:
-        """Update a memory."""
+        """Update a memory, recording field-level changes attributed to changed_by.
+
+        With expected_version the update only applies if the memory is still
+        at that version (optimistic concurrency); otherwise
+        VersionConflictError is raised and nothing changes.
+        """
         ...
 
     async def delete_memory(
@@ -69,6 +91,74 @@ class IMemoryStorage(Protocol):
         """Delete a memory."""
         ...
 
+    async def get_change_log(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+    ) -> list[dict[str, Any]]:
+        """Get field-level changes of a memory, oldest first.
+
+        Each entry holds version, changed_at, changed_by and a list of changes
+        with field plus old_value/new_value (or edits for long text).
+        """
+        ...
+
+    async def get_memory_history(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+    ) -> list[dict[str, Any]]:
+        """Get retained revisions of a memory, oldest first, ending with current.
+
+        Backends that keep no revisions raise NotImplementedError.
+        """
+        ...
+
+    async def revert_to_version(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        version: int,
+    ) -> bool:
+        """Restore the content of a past revision as a new version."""
+        ...
+
+    async def soft_delete_memory(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+    ) -> bool:
+        """Move a memory to the trash by setting deleted_at."""
+        ...
+
+    async def restore_memory(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+    ) -> bool:
+        """Restore a soft-deleted memory from the trash."""
+        ...
+
+    async def purge_deleted(
+        self,
+        before: datetime,
+        tenant_id: str | None = None,
+    ) -> int:
+        """Permanently delete memories soft-deleted before the given time."""
+        ...
+
+    async def set_lifecycle(
+        self,
+        memory_id: UUID,
+        tenant_id: str,
+        fields: dict[str, Any],
+    ) -> bool:
+        """Overwrite a memory's LIFECYCLE_FIELDS, e.g. when restoring a backup.
+
+        Other keys are ignored. Returns False if the memory does not exist.
+        """
+        ...
+
     async def list_memories(
         self,
         tenant_id: str,
@@ -76,7 +166,14 @@ class IMemoryStorage(Protocol):
         layer: str | None = None,
         **kwargs: Any,
     ) -> list[dict[str, Any]]:
-        """List memories with filtering and sorting."""
+        """List memories with filtering and sorting.
+
+        Soft-deleted memories are excluded unless include_deleted=True.
+        Besides session_id, tags (any of) and filters (metadata equality),
+        the created_after/created_before, updated_after/updated_before,
+        accessed_after/accessed_before and importance_gt/importance_gte
+        bounds are supported; MemoryQuery builds these arguments.
+        """
         ...
 
     async def delete_memories_with_metadata_filter(
@@ -117,7 +214,11 @@ class IMemoryStorage(Protocol):
         limit: int = 10,
         **kwargs: Any,
     ) -> list[dict[str, Any]]:
-        """Search memories."""
+        """Search memories (soft-deleted memories are excluded).
+
+        The time bounds of list_memories (created/updated/accessed
+        after/before) restrict the results as well.
+        """
         ...
 
     async def delete_expired_memories(
@@ -168,6 +269,20 @@ class IMemoryStorage(Protocol):
         """Update access count for multiple memories."""
         ...
 
+    async def record_access(
+        self,
+        memory_ids: list[UUID],
+        tenant_id: str,
+        accessed_at: datetime | None = None,
+    ) -> int:
+        """Record reads of memories in one write.
+
+        access_count grows by the number of times an id occurs and
+        last_accessed_at moves forward to accessed_at (now by default); the
+        memory's version is unchanged. Returns the number of memories updated.
+        """
+        ...
+
     async def adjust_importance(
         self,
         memory_id: UUID,
@@ -25,6 +25,9 @@ class IVectorStore(Protocol):
     ) -> bool:
         """Store a vector embedding.
 
+        Named vectors are keyed by embedding model, so a memory can hold one
+        embedding per model side by side.
+
         Args:
             memory_id: UUID of the memory
             embedding: Vector embedding (list) or dict of named vectors
@@ -47,6 +50,7 @@ class IVectorStore(Protocol):
         session_id: str | None = None,
         filters: dict[str, Any] | None = None,
         project: str | None = None,
+        vector_name: str | None = None,
         **kwargs: Any,
     ) -> list[tuple[UUID, float]]:
         """Search for similar vectors using cosine similarity.
@@ -61,6 +65,8 @@ class IVectorStore(Protocol):
             session_id: Optional session identifier for filtering
             filters: Optional dictionary of generic metadata filters
             project: Optional project identifier for filtering
+            vector_name: Embedding model whose vectors are searched
+                (the store's default vector if None)
             **kwargs: Additional backend-specific arguments
 
         Returns:
@@ -108,12 +114,15 @@ class IVectorStore(Protocol):
         self,
         memory_id: UUID,
         tenant_id: str,
+        vector_name: str | None = None,
     ) -> list[float] | None:
         """Retrieve a vector embedding.
 
         Args:
             memory_id: UUID of the memory
             tenant_id: Tenant identifier
+            vector_name: Embedding model of the vector (the store's default
+                vector if None)
 
         Returns:
             Vector embedding or None if not found
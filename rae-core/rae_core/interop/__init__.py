@@ -0,0 +1,39 @@
+"""Interoperability with other agent memory frameworks for RAE-core."""
+
+from rae_core.interop.conversion import (
+    ConversionReport,
+    ExportedMemories,
+    ImportedMemories,
+    LossyField,
+)
+from rae_core.interop.importers import (
+    ExportFormat,
+    ImportedDocument,
+    InteropImportResult,
+    detect_format,
+    import_export,
+    parse_langchain,
+    parse_llamaindex,
+)
+from rae_core.interop.mem0 import from_mem0, to_mem0
+from rae_core.interop.zep import from_zep, to_zep
+
+__all__ = [
+    # LangChain / LlamaIndex
+    "ExportFormat",
+    "ImportedDocument",
+    "InteropImportResult",
+    "detect_format",
+    "import_export",
+    "parse_langchain",
+    "parse_llamaindex",
+    # mem0 / Zep
+    "ConversionReport",
+    "ExportedMemories",
+    "ImportedMemories",
+    "LossyField",
+    "from_mem0",
+    "from_zep",
+    "to_mem0",
+    "to_zep",
+]
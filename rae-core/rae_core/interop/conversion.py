@@ -0,0 +1,125 @@
+"""Shared shapes of schema conversions between RAE and other memory systems.
+
+Converting into RAE yields memories as RAEEngine.store_memory arguments
+(without tenant_id) plus graph nodes and relations in the normalized form
+of tenant snapshots. Entities are keyed by maintenance.bootstrap.entity_node_id, so
+they merge with the entities RAE extracts itself. Converting out of RAE
+yields the other system's JSON.
+
+Fields one side cannot represent are not smuggled into metadata: each one
+is reported as a LossyField, so an evaluation can tell what a round trip
+drops.
+"""
+
+from collections import Counter
+from datetime import datetime
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.maintenance.bootstrap import entity_node_id
+from rae_core.models.graph import NodeType
+
+
+class LossyField(BaseModel):
+    """A field of one record that the target schema cannot hold."""
+
+    record_id: str | None = None
+    field: str
+    reason: str
+
+
+class ConversionReport(BaseModel):
+    """Fields dropped by a conversion."""
+
+    converted: int = 0
+    lossy: list[LossyField] = Field(default_factory=list)
+
+    @property
+    def lossy_fields(self) -> dict[str, int]:
+        """How many records lost each field."""
+        return dict(Counter(entry.field for entry in self.lossy))
+
+    def drop(self, record_id: Any, field: str, reason: str) -> None:
+        self.lossy.append(
+            LossyField(
+                record_id=None if record_id is None else str(record_id),
+                field=field,
+                reason=reason,
+            )
+        )
+
+
+class ImportedMemories(ConversionReport):
+    """Records converted into RAE."""
+
+    memories: list[dict[str, Any]] = Field(
+        default_factory=list, description="RAEEngine.store_memory arguments"
+    )
+    nodes: list[dict[str, Any]] = Field(default_factory=list)
+    relations: list[dict[str, Any]] = Field(default_factory=list)
+
+
+class ExportedMemories(ConversionReport):
+    """RAE records converted into another system's schema."""
+
+    data: dict[str, Any] = Field(default_factory=dict)
+
+
+def entity_node(tenant_id: str, name: str, **properties: Any) -> dict[str, Any]:
+    """Graph node of a named entity."""
+    return {
+        "id": entity_node_id(tenant_id, name),
+        "node_type": NodeType.ENTITY.value,
+        "properties": {"name": name, **properties},
+    }
+
+
+def relation(
+    source_id: UUID,
+    target_id: UUID,
+    edge_type: str,
+    weight: float = 1.0,
+    **properties: Any,
+) -> dict[str, Any]:
+    """Graph relation between two nodes."""
+    return {
+        "source_id": source_id,
+        "target_id": target_id,
+        "edge_type": edge_type,
+        "weight": weight,
+        "properties": properties,
+    }
+
+
+def node_names(nodes: list[dict[str, Any]] | None) -> dict[str, str]:
+    """Entity names of graph nodes by node ID."""
+    names: dict[str, str] = {}
+    for node in nodes or []:
+        name = (node.get("properties") or {}).get("name")
+        if name:
+            names[str(node.get("id"))] = str(name)
+    return names
+
+
+def timestamp(value: Any) -> str | None:
+    """ISO form of a record timestamp."""
+    if value is None:
+        return None
+    return value if isinstance(value, str) else value.isoformat()
+
+
+def parse_timestamp(value: Any) -> datetime:
+    """Datetime of an ISO timestamp.
+
+    Raises:
+        ValidationError: Not an ISO timestamp
+    """
+    if isinstance(value, datetime):
+        return value
+    try:
+        return datetime.fromisoformat(str(value).replace("Z", "+00:00"))
+    except ValueError as e:
+        raise ValidationError(f"Invalid timestamp: {value!r}") from e
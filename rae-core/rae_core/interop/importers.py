@@ -0,0 +1,334 @@
+"""Bulk import of LangChain and LlamaIndex exports.
+
+Teams moving to RAE keep their corpus and its embeddings: documents are
+stored as memories with their original metadata, and embeddings found in
+the export go straight into the vector store instead of being computed
+again.
+
+Supported LangChain exports (JSON):
+
+- ``InMemoryVectorStore.dump()``: ``{id: {"id", "text", "vector", "metadata"}}``
+- A list of documents: ``{"page_content", "metadata", "id"?, "embedding"?}``,
+  plain or serialized with ``langchain_core.load.dumpd``
+- A Chroma ``get()`` result: ``{"ids", "documents", "metadatas", "embeddings"}``
+
+Supported LlamaIndex exports (JSON):
+
+- A ``SimpleDocumentStore`` (``docstore.json``) whose ``<namespace>/data``
+  maps node IDs to ``{"__type__", "__data__"}`` entries. Docstores do not
+  keep embeddings; pass the ``SimpleVectorStore`` file
+  (``default__vector_store.json``) as ``vectors`` to bring them along.
+
+The whole export is parsed before anything is stored, so a malformed file
+leaves the tenant untouched. Documents without an embedding are stored
+without a vector and reported in ``missing_embeddings`` for re-embedding.
+"""
+
+import json
+from enum import Enum
+from typing import Any
+from uuid import UUID
+
+import structlog
+from pydantic import BaseModel, Field
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.provenance import ORIGIN_KEY, MemorySource, SourceType
+from rae_core.types.enums import MemoryLayer
+
+logger = structlog.get_logger(__name__)
+
+# Tag added to every imported memory
+IMPORT_TAG = "imported"
+
+
+class ExportFormat(str, Enum):
+    """Framework an export comes from."""
+
+    LANGCHAIN = "langchain"
+    LLAMAINDEX = "llamaindex"
+
+
+class ImportedDocument(BaseModel):
+    """One document of an export, ready to be stored."""
+
+    content: str
+    metadata: dict[str, Any] = Field(default_factory=dict)
+    embedding: list[float] | None = None
+    source_id: str | None = Field(
+        default=None, description="ID of the document in the export"
+    )
+
+
+class InteropImportResult(BaseModel):
+    """Outcome of importing an export."""
+
+    format: ExportFormat
+    tenant_id: str
+    memories: int = 0
+    vectors: int = 0
+    id_map: dict[str, str] = Field(
+        default_factory=dict, description="Export document ID -> new memory ID"
+    )
+    missing_embeddings: list[UUID] = Field(
+        default_factory=list, description="Imported memories stored without vector"
+    )
+
+
+def _load(data: Any) -> Any:
+    if isinstance(data, bytes):
+        data = data.decode("utf-8")
+    if isinstance(data, str):
+        try:
+            return json.loads(data)
+        except ValueError as e:
+            raise ValidationError(f"Export is not valid JSON: {e}") from e
+    return data
+
+
+def _embedding(value: Any) -> list[float] | None:
+    if value is None:
+        return None
+    if not isinstance(value, list) or not value:
+        raise ValidationError("Embedding must be a non-empty list of numbers")
+    try:
+        return [float(x) for x in value]
+    except (TypeError, ValueError) as e:
+        raise ValidationError(f"Embedding must be a list of numbers: {e}") from e
+
+
+def _document(
+    content: Any, metadata: Any, embedding: Any, source_id: Any
+) -> ImportedDocument:
+    if not isinstance(content, str):
+        raise ValidationError(f"Document {source_id} has no text")
+    if metadata is not None and not isinstance(metadata, dict):
+        raise ValidationError(f"Document {source_id} has invalid metadata")
+    return ImportedDocument(
+        content=content,
+        metadata=metadata or {},
+        embedding=_embedding(embedding),
+        source_id=None if source_id is None else str(source_id),
+    )
+
+
+def _langchain_document(entry: Any) -> ImportedDocument:
+    if not isinstance(entry, dict):
+        raise ValidationError("LangChain document must be an object")
+    if entry.get("lc") is not None and isinstance(entry.get("kwargs"), dict):
+        entry = {"embedding": entry.get("embedding"), **entry["kwargs"]}
+    content = entry.get("page_content", entry.get("text"))
+    embedding = entry.get("embedding", entry.get("vector"))
+    return _document(content, entry.get("metadata"), embedding, entry.get("id"))
+
+
+def parse_langchain(data: Any) -> list[ImportedDocument]:
+    """Documents of a LangChain export.
+
+    Raises:
+        ValidationError: Unrecognized or malformed export
+    """
+    data = _load(data)
+    if isinstance(data, list):
+        return [_langchain_document(entry) for entry in data]
+    if not isinstance(data, dict):
+        raise ValidationError("Unrecognized LangChain export")
+
+    if isinstance(data.get("documents"), list) and isinstance(data.get("ids"), list):
+        ids = data["ids"]
+        columns = {
+            key: data.get(key) or [None] * len(ids)
+            for key in ("documents", "metadatas", "embeddings")
+        }
+        if any(len(column) != len(ids) for column in columns.values()):
+            raise ValidationError("Chroma export columns differ in length")
+        return [
+            _document(content, metadata, embedding, source_id)
+            for source_id, content, metadata, embedding in zip(
+                ids,
+                columns["documents"],
+                columns["metadatas"],
+                columns["embeddings"],
+                strict=True,
+            )
+        ]
+
+    documents = []
+    for key, entry in data.items():
+        document = _langchain_document(entry)
+        if document.source_id is None:
+            document.source_id = str(key)
+        documents.append(document)
+    return documents
+
+
+def _node_data(node_id: str, entry: Any) -> dict[str, Any]:
+    if not isinstance(entry, dict):
+        raise ValidationError(f"LlamaIndex node {node_id} must be an object")
+    data = entry.get("__data__", entry)
+    if isinstance(data, str):
+        try:
+            data = json.loads(data)
+        except ValueError as e:
+            raise ValidationError(f"LlamaIndex node {node_id} is malformed") from e
+    if not isinstance(data, dict):
+        raise ValidationError(f"LlamaIndex node {node_id} must be an object")
+    return data
+
+
+def parse_llamaindex(data: Any, vectors: Any = None) -> list[ImportedDocument]:
+    """Documents of a LlamaIndex docstore export.
+
+    Args:
+        data: SimpleDocumentStore JSON
+        vectors: Optional SimpleVectorStore JSON whose embedding_dict holds
+            the embeddings of the nodes
+
+    Raises:
+        ValidationError: Unrecognized or malformed export
+    """
+    data = _load(data)
+    store = _docstore_data(data)
+    if store is None:
+        raise ValidationError("Unrecognized LlamaIndex export")
+    embeddings: dict[str, Any] = {}
+    if vectors is not None:
+        vectors = _load(vectors)
+        if not isinstance(vectors, dict) or not isinstance(
+            vectors.get("embedding_dict"), dict
+        ):
+            raise ValidationError("LlamaIndex vector store has no embedding_dict")
+        embeddings = vectors["embedding_dict"]
+
+    documents = []
+    for node_id, entry in store.items():
+        node = _node_data(node_id, entry)
+        content = node.get("text")
+        if content is None and isinstance(node.get("text_resource"), dict):
+            content = node["text_resource"].get("text")
+        source_id = node.get("id_", node_id)
+        embedding = node.get("embedding") or embeddings.get(str(source_id))
+        documents.append(_document(content, node.get("metadata"), embedding, source_id))
+    return documents
+
+
+def _docstore_data(data: Any) -> dict[str, Any] | None:
+    """The node map of a docstore export (under "<namespace>/data")."""
+    if not isinstance(data, dict):
+        return None
+    for key, value in data.items():
+        if key.endswith("/data") and isinstance(value, dict):
+            return value
+    return None
+
+
+def detect_format(data: Any) -> ExportFormat:
+    """Framework an export (parsed JSON) comes from."""
+    return (
+        ExportFormat.LLAMAINDEX
+        if _docstore_data(data) is not None
+        else ExportFormat.LANGCHAIN
+    )
+
+
+async def import_export(
+    storage: IMemoryStorage,
+    data: Any,
+    tenant_id: str,
+    vector_store: IVectorStore | None = None,
+    export_format: ExportFormat | str | None = None,
+    vectors: Any = None,
+    layer: str = MemoryLayer.SEMANTIC.value,
+    agent_id: str = "default",
+    tags: list[str] | None = None,
+    vector_name: str | None = None,
+) -> InteropImportResult:
+    """Import a LangChain or LlamaIndex export into a tenant.
+
+    Args:
+        storage: Target memory storage
+        data: Export as JSON text, bytes or parsed JSON
+        tenant_id: Target tenant
+        vector_store: Vector store receiving the exported embeddings
+        export_format: Framework of the export (detected if None)
+        vectors: LlamaIndex vector store export holding the embeddings
+        layer: Memory layer the documents are stored in
+        agent_id: Agent owning the imported memories
+        tags: Tags added to every imported memory
+        vector_name: Embedding model the exported embeddings belong to
+            (the vector store's default vector if None)
+
+    Raises:
+        ValidationError: Malformed export, or embeddings of different sizes
+    """
+    data = _load(data)
+    export_format = (
+        ExportFormat(export_format) if export_format else detect_format(data)
+    )
+    if export_format == ExportFormat.LLAMAINDEX:
+        documents = parse_llamaindex(data, vectors)
+    else:
+        documents = parse_langchain(data)
+
+    dimensions = {len(d.embedding) for d in documents if d.embedding is not None}
+    if len(dimensions) > 1:
+        raise ValidationError(f"Export mixes embeddings of sizes {sorted(dimensions)}")
+
+    result = InteropImportResult(format=export_format, tenant_id=tenant_id)
+    memory_tags = list(dict.fromkeys([*(tags or []), IMPORT_TAG]))
+    for document in documents:
+        source = MemorySource(
+            type=SourceType.IMPORT,
+            ref=f"{export_format.value}:{document.source_id}"
+            if document.source_id
+            else export_format.value,
+            file=_source_file(document.metadata),
+        )
+        memory_id = await storage.store_memory(
+            content=document.content,
+            tenant_id=tenant_id,
+            agent_id=agent_id,
+            layer=layer,
+            tags=memory_tags,
+            metadata={**document.metadata, ORIGIN_KEY: source.as_metadata()},
+            source=source.label,
+        )
+        result.memories += 1
+        if document.source_id is not None:
+            result.id_map[document.source_id] = str(memory_id)
+
+        if document.embedding is None or vector_store is None:
+            result.missing_embeddings.append(memory_id)
+            continue
+        embedding = (
+            {vector_name: document.embedding} if vector_name else document.embedding
+        )
+        if await vector_store.store_vector(
+            memory_id,
+            embedding,
+            tenant_id,
+            metadata={"layer": layer, "agent_id": agent_id, "tags": memory_tags},
+        ):
+            result.vectors += 1
+        else:
+            result.missing_embeddings.append(memory_id)
+
+    logger.info(
+        "interop_import_completed",
+        tenant_id=tenant_id,
+        format=export_format.value,
+        memories=result.memories,
+        vectors=result.vectors,
+    )
+    return result
+
+
+def _source_file(metadata: dict[str, Any]) -> str | None:
+    """Path of the file a document was loaded from, as loaders record it."""
+    for key in ("source", "file_path", "file_name"):
+        value = metadata.get(key)
+        if isinstance(value, str) and value:
+            return value
+    return None
@@ -0,0 +1,190 @@
+"""Conversion between RAE memories and the mem0 memory schema.
+
+mem0 memories are facts extracted from conversations, so they map to the
+semantic layer; the ``get_all`` shape ``{"results": [...], "relations":
+[...]}`` is read and written. Graph memory relations (``source``,
+``relationship``, ``destination``) become relations between entity nodes,
+typed by their relationship.
+"""
+
+import hashlib
+from typing import Any
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interop.conversion import (
+    ExportedMemories,
+    ImportedMemories,
+    entity_node,
+    node_names,
+    parse_timestamp,
+    relation,
+    timestamp,
+)
+from rae_core.models.provenance import MemorySource, SourceType
+from rae_core.sync.snapshot import _normalize_edge, _normalize_node
+from rae_core.types.enums import MemoryLayer, MemoryType
+
+# mem0 fields kept in RAE metadata under the same key
+_METADATA_FIELDS = ("user_id", "actor_id", "role", "created_at", "updated_at")
+# mem0 fields RAE has no place for
+_DROPPED_FIELDS = {
+    "hash": "RAE keeps no content hash",
+    "score": "search score, not part of the memory",
+    "immutable": "RAE memories have no immutability flag",
+}
+# mem0 fields mapped to RAE fields
+_MAPPED_FIELDS = frozenset(
+    {"id", "memory", "metadata", "agent_id", "run_id", "categories", "expiration_date"}
+)
+
+
+def from_mem0(data: Any, tenant_id: str) -> ImportedMemories:
+    """Convert mem0 memories (and graph relations) into RAE records.
+
+    Args:
+        data: ``get_all`` result or a list of mem0 memories
+        tenant_id: Tenant the entity nodes are keyed for
+
+    Raises:
+        ValidationError: Not a mem0 export
+    """
+    records, relations = _split(data)
+    result = ImportedMemories()
+    for record in records:
+        if not isinstance(record, dict) or not isinstance(record.get("memory"), str):
+            raise ValidationError("mem0 memory must be an object with a memory text")
+        record_id = record.get("id")
+        metadata = dict(record.get("metadata") or {})
+        for key in _METADATA_FIELDS:
+            if record.get(key) is not None:
+                metadata[key] = record[key]
+        for key, value in record.items():
+            if value is None or key in _MAPPED_FIELDS or key in _METADATA_FIELDS:
+                continue
+            result.drop(record_id, key, _DROPPED_FIELDS.get(key, "no RAE equivalent"))
+
+        memory: dict[str, Any] = {
+            "content": record["memory"],
+            "layer": MemoryLayer.SEMANTIC.value,
+            "memory_type": MemoryType.TEXT.value,
+            "agent_id": record.get("agent_id") or "default",
+            "session_id": record.get("run_id"),
+            "tags": list(record.get("categories") or []),
+            "metadata": metadata,
+            "source": MemorySource(
+                type=SourceType.IMPORT,
+                ref=f"mem0:{record_id}" if record_id else "mem0",
+            ),
+        }
+        if record.get("expiration_date"):
+            memory["expires_at"] = parse_timestamp(record["expiration_date"])
+        result.memories.append(memory)
+        result.converted += 1
+
+    nodes: dict[str, dict[str, Any]] = {}
+    for entry in relations:
+        source = entry.get("source")
+        target = entry.get("destination", entry.get("target"))
+        relationship = entry.get("relationship")
+        if not (source and target and relationship):
+            raise ValidationError("mem0 relation needs source, relationship, target")
+        ends = [entity_node(tenant_id, str(name)) for name in (source, target)]
+        for node in ends:
+            nodes.setdefault(str(node["id"]), node)
+        result.relations.append(
+            relation(ends[0]["id"], ends[1]["id"], str(relationship).lower())
+        )
+    result.nodes = list(nodes.values())
+    return result
+
+
+def to_mem0(
+    memories: list[dict[str, Any]],
+    nodes: list[dict[str, Any]] | None = None,
+    relations: list[dict[str, Any]] | None = None,
+) -> ExportedMemories:
+    """Convert stored RAE memories (and graph relations) into mem0's schema.
+
+    Args:
+        memories: Memory records as returned by storage
+        nodes: Graph nodes naming the ends of relations (as in a subgraph)
+        relations: Graph relations (edges of a subgraph)
+    """
+    result = ExportedMemories()
+    nodes = [_normalize_node(node) for node in nodes or []]
+    relations = [_normalize_edge(edge) for edge in relations or []]
+    results = []
+    for memory in memories:
+        memory_id = memory.get("id")
+        metadata = dict(memory.get("metadata") or {})
+        extracted = {key: metadata.pop(key, None) for key in _METADATA_FIELDS}
+        content = memory.get("content", "")
+        results.append(
+            {
+                "id": str(memory_id),
+                "memory": content,
+                "hash": hashlib.md5(content.encode("utf-8")).hexdigest(),
+                "metadata": metadata,
+                "created_at": extracted["created_at"]
+                or timestamp(memory.get("created_at")),
+                "updated_at": extracted["updated_at"]
+                or timestamp(memory.get("modified_at")),
+                "user_id": extracted["user_id"],
+                "actor_id": extracted["actor_id"],
+                "role": extracted["role"],
+                "agent_id": memory.get("agent_id"),
+                "run_id": memory.get("session_id"),
+                "categories": list(memory.get("tags") or []),
+                "expiration_date": timestamp(memory.get("expires_at")),
+            }
+        )
+        _report_unsupported(result, memory)
+        result.converted += 1
+
+    names = node_names(nodes)
+    exported_relations = []
+    for edge in relations:
+        source = names.get(str(edge.get("source_id")))
+        target = names.get(str(edge.get("target_id")))
+        if source is None or target is None:
+            result.drop(
+                edge.get("source_id"), "relation", "an end is not a named entity"
+            )
+            continue
+        if float(edge.get("weight", 1.0)) != 1.0:
+            result.drop(
+                edge.get("source_id"), "weight", "mem0 relations are unweighted"
+            )
+        exported_relations.append(
+            {
+                "source": source,
+                "relationship": edge.get("edge_type"),
+                "destination": target,
+            }
+        )
+
+    result.data = {"results": results, "relations": exported_relations}
+    return result
+
+
+def _split(data: Any) -> tuple[list[Any], list[dict[str, Any]]]:
+    if isinstance(data, list):
+        return data, []
+    if isinstance(data, dict) and isinstance(data.get("results"), list):
+        return data["results"], list(data.get("relations") or [])
+    raise ValidationError("Unrecognized mem0 export")
+
+
+def _report_unsupported(result: ExportedMemories, memory: dict[str, Any]) -> None:
+    """Report RAE fields holding something other than mem0's implicit value."""
+    memory_id = memory.get("id")
+    if memory.get("layer", MemoryLayer.SEMANTIC.value) != MemoryLayer.SEMANTIC.value:
+        result.drop(memory_id, "layer", "mem0 memories have no layers")
+    if memory.get("importance", 0.5) != 0.5:
+        result.drop(memory_id, "importance", "mem0 memories have no importance")
+    if memory.get("memory_type", MemoryType.TEXT.value) != MemoryType.TEXT.value:
+        result.drop(memory_id, "memory_type", "mem0 memories are plain text")
+    if memory.get("strength", 1.0) != 1.0:
+        result.drop(memory_id, "strength", "mem0 memories do not decay")
+    if memory.get("project"):
+        result.drop(memory_id, "project", "mem0 memories have no project")
@@ -0,0 +1,315 @@
+"""Conversion between RAE memories and the Zep memory schema.
+
+A Zep export is read and written as::
+
+    {"sessions": [{"session_id", "user_id", "messages": [...],
+                   "summary": {...}?, "facts": [...]?}],
+     "facts": [...], "nodes": [...], "edges": [...]}
+
+Session messages map to episodic conversation memories of their session,
+summaries and facts to semantic memories (a fact's rating becomes its
+importance). Graph nodes become entity nodes and edges relations typed by
+their name; the edge's fact and validity window travel in the relation's
+properties.
+"""
+
+from typing import Any
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interop.conversion import (
+    ExportedMemories,
+    ImportedMemories,
+    entity_node,
+    node_names,
+    relation,
+    timestamp,
+)
+from rae_core.models.provenance import MemorySource, SourceType
+from rae_core.sync.snapshot import _normalize_edge, _normalize_node
+from rae_core.types.enums import MemoryLayer, MemoryType
+
+SUMMARY_TAG = "summary"
+
+# Zep message fields mapped to RAE fields or metadata
+_MESSAGE_FIELDS = frozenset(
+    {"uuid", "role", "role_type", "content", "metadata", "created_at"}
+)
+# Zep edge fields kept in relation properties
+_EDGE_PROPERTIES = ("fact", "valid_at", "invalid_at", "expired_at", "created_at")
+# Layers with a Zep counterpart
+_ZEP_LAYERS = frozenset({MemoryLayer.EPISODIC.value, MemoryLayer.SEMANTIC.value})
+
+
+def from_zep(data: Any, tenant_id: str) -> ImportedMemories:
+    """Convert a Zep export (sessions, facts and graph) into RAE records.
+
+    Args:
+        data: Zep export, or a single session with its messages
+        tenant_id: Tenant the entity nodes are keyed for
+
+    Raises:
+        ValidationError: Not a Zep export
+    """
+    if not isinstance(data, dict):
+        raise ValidationError("Unrecognized Zep export")
+    sessions = [data] if "messages" in data else list(data.get("sessions") or [])
+    result = ImportedMemories()
+
+    for session in sessions:
+        session_id = session.get("session_id")
+        user_id = session.get("user_id")
+        if session.get("metadata"):
+            result.drop(session_id, "session.metadata", "RAE sessions have no metadata")
+        for message in session.get("messages") or []:
+            _import_message(result, message, session_id, user_id)
+        summary = session.get("summary")
+        if summary and summary.get("content"):
+            result.memories.append(
+                _memory(
+                    summary["content"],
+                    summary,
+                    session_id=session_id,
+                    tags=[SUMMARY_TAG],
+                )
+            )
+            result.converted += 1
+        for fact in session.get("facts") or []:
+            _import_fact(result, fact, session_id)
+    for fact in data.get("facts") or []:
+        _import_fact(result, fact, None)
+
+    node_ids: dict[str, Any] = {}
+    for node in data.get("nodes") or []:
+        if not node.get("name"):
+            raise ValidationError(f"Zep node {node.get('uuid')} has no name")
+        properties = {
+            key: node[key]
+            for key in ("summary", "labels", "attributes")
+            if node.get(key)
+        }
+        converted = entity_node(tenant_id, node["name"], **properties)
+        node_ids[str(node.get("uuid"))] = converted["id"]
+        result.nodes.append(converted)
+
+    for edge in data.get("edges") or []:
+        source = node_ids.get(str(edge.get("source_node_uuid")))
+        target = node_ids.get(str(edge.get("target_node_uuid")))
+        if source is None or target is None:
+            result.drop(edge.get("uuid"), "edge", "an end is not among the nodes")
+            continue
+        if edge.get("episodes"):
+            result.drop(edge.get("uuid"), "episodes", "episode links are not kept")
+        properties = {k: edge[k] for k in _EDGE_PROPERTIES if edge.get(k) is not None}
+        name = str(edge.get("name") or "relates_to").lower()
+        result.relations.append(relation(source, target, name, **properties))
+    return result
+
+
+def to_zep(
+    memories: list[dict[str, Any]],
+    nodes: list[dict[str, Any]] | None = None,
+    relations: list[dict[str, Any]] | None = None,
+) -> ExportedMemories:
+    """Convert stored RAE memories (and graph relations) into Zep's schema.
+
+    Episodic memories of a session become its messages; every other memory
+    becomes a fact rated by its importance.
+
+    Args:
+        memories: Memory records as returned by storage
+        nodes: Graph nodes of the relations' entities (as in a subgraph)
+        relations: Graph relations (edges of a subgraph)
+    """
+    result = ExportedMemories()
+    nodes = [_normalize_node(node) for node in nodes or []]
+    relations = [_normalize_edge(edge) for edge in relations or []]
+    sessions: dict[str, dict[str, Any]] = {}
+    facts = []
+    for memory in memories:
+        memory_id = str(memory.get("id"))
+        metadata = dict(memory.get("metadata") or {})
+        session_id = memory.get("session_id")
+        _report_unsupported(result, memory)
+        if memory.get("layer") == MemoryLayer.EPISODIC.value and session_id:
+            session = sessions.setdefault(
+                session_id,
+                {"session_id": session_id, "user_id": None, "messages": []},
+            )
+            session["user_id"] = session["user_id"] or metadata.pop("user_id", None)
+            role = metadata.pop("role", None) or "user"
+            role_type = metadata.pop("role_type", None) or role
+            created_at = metadata.pop("created_at", None)
+            session["messages"].append(
+                {
+                    "uuid": memory_id,
+                    "role": role,
+                    "role_type": role_type,
+                    "content": memory.get("content", ""),
+                    "metadata": metadata,
+                    "created_at": created_at or timestamp(memory.get("created_at")),
+                }
+            )
+        else:
+            created_at = metadata.pop("created_at", None)
+            if metadata:
+                result.drop(memory_id, "metadata", "Zep facts have no metadata")
+            facts.append(
+                {
+                    "uuid": memory_id,
+                    "fact": memory.get("content", ""),
+                    "rating": memory.get("importance", 0.5),
+                    "created_at": created_at or timestamp(memory.get("created_at")),
+                }
+            )
+        result.converted += 1
+
+    names = node_names(nodes)
+    exported_nodes = []
+    for node in nodes:
+        properties = dict(node.get("properties") or {})
+        name = properties.pop("name", None)
+        if not name:
+            result.drop(node.get("id"), "node", "Zep nodes need a name")
+            continue
+        exported_nodes.append(
+            {
+                "uuid": str(node.get("id")),
+                "name": name,
+                "labels": properties.pop("labels", None) or [node.get("node_type")],
+                "summary": properties.pop("summary", ""),
+                "attributes": properties.pop("attributes", None) or properties,
+            }
+        )
+
+    edges = []
+    for edge in relations:
+        source_id, target_id = str(edge.get("source_id")), str(edge.get("target_id"))
+        if source_id not in names or target_id not in names:
+            result.drop(source_id, "relation", "an end is not a named entity")
+            continue
+        if float(edge.get("weight", 1.0)) != 1.0:
+            result.drop(source_id, "weight", "Zep edges are unweighted")
+        properties = dict(edge.get("properties") or {})
+        edge_type = str(edge.get("edge_type"))
+        edges.append(
+            {
+                "name": edge_type.upper(),
+                "fact": properties.get("fact")
+                or f"{names[source_id]} {edge_type} {names[target_id]}",
+                "source_node_uuid": source_id,
+                "target_node_uuid": target_id,
+                **{
+                    k: properties[k]
+                    for k in _EDGE_PROPERTIES
+                    if k != "fact" and properties.get(k) is not None
+                },
+            }
+        )
+
+    result.data = {
+        "sessions": list(sessions.values()),
+        "facts": facts,
+        "nodes": exported_nodes,
+        "edges": edges,
+    }
+    return result
+
+
+def _memory(
+    content: Any,
+    record: dict[str, Any],
+    layer: str = MemoryLayer.SEMANTIC.value,
+    memory_type: str = MemoryType.TEXT.value,
+    session_id: str | None = None,
+    tags: list[str] | None = None,
+    metadata: dict[str, Any] | None = None,
+    importance: float | None = None,
+) -> dict[str, Any]:
+    if not isinstance(content, str):
+        raise ValidationError(f"Zep record {record.get('uuid')} has no text")
+    metadata = dict(metadata or {})
+    if record.get("created_at") is not None:
+        metadata["created_at"] = record["created_at"]
+    memory: dict[str, Any] = {
+        "content": content,
+        "layer": layer,
+        "memory_type": memory_type,
+        "session_id": session_id,
+        "tags": tags or [],
+        "metadata": metadata,
+        "source": MemorySource(
+            type=SourceType.IMPORT,
+            ref=f"zep:{record['uuid']}" if record.get("uuid") else "zep",
+        ),
+    }
+    if importance is not None:
+        memory["importance"] = importance
+    return memory
+
+
+def _import_message(
+    result: ImportedMemories,
+    message: dict[str, Any],
+    session_id: str | None,
+    user_id: str | None,
+) -> None:
+    metadata = dict(message.get("metadata") or {})
+    for key in ("role", "role_type"):
+        if message.get(key) is not None:
+            metadata[key] = message[key]
+    if user_id is not None:
+        metadata["user_id"] = user_id
+    for key, value in message.items():
+        if value is not None and key not in _MESSAGE_FIELDS:
+            reason = (
+                "RAE counts tokens itself"
+                if key == "token_count"
+                else "no RAE equivalent"
+            )
+            result.drop(message.get("uuid"), key, reason)
+    result.memories.append(
+        _memory(
+            message.get("content"),
+            message,
+            layer=MemoryLayer.EPISODIC.value,
+            memory_type=MemoryType.CONVERSATION.value,
+            session_id=session_id,
+            metadata=metadata,
+        )
+    )
+    result.converted += 1
+
+
+def _import_fact(
+    result: ImportedMemories, fact: dict[str, Any], session_id: str | None
+) -> None:
+    rating = fact.get("rating")
+    result.memories.append(
+        _memory(
+            fact.get("fact"),
+            fact,
+            session_id=session_id,
+            importance=None if rating is None else float(rating),
+        )
+    )
+    result.converted += 1
+
+
+def _report_unsupported(result: ExportedMemories, memory: dict[str, Any]) -> None:
+    """Report RAE fields a Zep message or fact cannot hold."""
+    memory_id = memory.get("id")
+    layer = memory.get("layer", MemoryLayer.EPISODIC.value)
+    if layer not in _ZEP_LAYERS:
+        result.drop(memory_id, "layer", f"Zep has no {layer} layer")
+    if memory.get("tags"):
+        result.drop(memory_id, "tags", "Zep messages and facts have no tags")
+    if memory.get("agent_id") not in (None, "default"):
+        result.drop(memory_id, "agent_id", "Zep memories belong to users, not agents")
+    if memory.get("memory_type") not in (
+        None,
+        MemoryType.TEXT.value,
+        MemoryType.CONVERSATION.value,
+    ):
+        result.drop(memory_id, "memory_type", "Zep memories are plain text")
+    if memory.get("expires_at"):
+        result.drop(memory_id, "expires_at", "Zep memories do not expire")
@@ -3,6 +3,7 @@
 from typing import Any
 from uuid import UUID
 
+from ..governance.ephemeral import is_ephemeral
 from ..interfaces.storage import IMemoryStorage
 from ..models.memory import MemoryItem, MemoryLayer, ScoredMemoryItem
 from .base import MemoryLayerBase
@@ -202,7 +203,13 @@ class LongTermLayer(MemoryLayerBase):
 
         Returns:
             UUID of new long-term memory
+
+        Raises:
+            ValueError: If the memory is ephemeral
         """
+        if is_ephemeral(working_memory):
+            raise ValueError("Ephemeral memories are not consolidated")
+
         # Increase importance for consolidated memories
         new_importance = min(working_memory.importance + 0.2, 1.0)
 
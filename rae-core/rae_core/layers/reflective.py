@@ -5,6 +5,7 @@ from uuid import UUID
 
 from ..interfaces.storage import IMemoryStorage
 from ..models.memory import MemoryItem, MemoryLayer, ScoredMemoryItem
+from ..models.provenance import ORIGIN_KEY, MemorySource, SourceType
 from ..models.reflection import Reflection, ReflectionType
 from .base import MemoryLayerBase
 
@@ -95,6 +96,11 @@ class ReflectiveLayer(MemoryLayerBase):
             "priority": reflection.priority.value,
             "source_memory_ids": [str(mid) for mid in reflection.source_memory_ids],
             "confidence": reflection.confidence,
+            ORIGIN_KEY: MemorySource(
+                type=SourceType.REFLECTION,
+                ref=reflection.reflection_type.value,
+                derived_from=reflection.source_memory_ids,
+            ).as_metadata(),
         }
 
         # Map priority to importance
@@ -0,0 +1,15 @@
+"""Maintenance jobs for RAE-core."""
+
+from rae_core.maintenance.bootstrap import AgentBootstrap, load_seed_directory
+from rae_core.maintenance.consolidation import Consolidator
+from rae_core.maintenance.reembed import ReembedJob
+from rae_core.maintenance.summaries import SummaryRefresher, SummaryTracker
+
+__all__ = [
+    "AgentBootstrap",
+    "Consolidator",
+    "ReembedJob",
+    "SummaryRefresher",
+    "SummaryTracker",
+    "load_seed_directory",
+]
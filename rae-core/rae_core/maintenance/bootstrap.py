@@ -0,0 +1,276 @@
+"""Bootstrapping a new agent from seed knowledge files.
+
+A seed directory holds markdown and JSON files; every file below it is read:
+
+- Markdown (.md, .markdown): each heading starts a section and every
+  non-empty section is one fact (memory type "document"). Text before the
+  first heading is a fact without a section.
+- JSON (.json): a list of facts, or an object with a "facts" list. A fact is
+  a string or an object with "content" and optional "memory_type", "tags",
+  "importance" and "metadata".
+
+All files are parsed before anything is stored, so a malformed file leaves
+the agent untouched. Facts are stored through the engine into the semantic
+layer (chunked and embedded like any memory) with the entities extracted
+from them in metadata["entities"]. With a graph store, each entity also
+becomes a node linked to the memories that mention it.
+"""
+
+import json
+from pathlib import Path
+from typing import Any
+from uuid import NAMESPACE_URL, UUID, uuid5
+
+import structlog
+from pydantic import ValidationError as PydanticValidationError
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.llm.fallback import NoLLMFallback
+from rae_core.models.graph import EdgeType, NodeType
+from rae_core.models.load import PriorityClass
+from rae_core.models.maintenance import BootstrapReport, SeedFact
+from rae_core.models.provenance import MemorySource, SourceType
+from rae_core.tracing import span
+from rae_core.types.enums import MemoryLayer, MemoryType
+
+logger = structlog.get_logger(__name__)
+
+MARKDOWN_SUFFIXES = frozenset({".md", ".markdown"})
+JSON_SUFFIXES = frozenset({".json"})
+SEED_TAG = "seed"
+ENTITIES_KEY = "entities"
+
+
+def parse_markdown_seed(text: str, source_file: str) -> list[SeedFact]:
+    """Facts of a markdown seed file, one per non-empty section."""
+    facts: list[SeedFact] = []
+    section: str | None = None
+    body: list[str] = []
+
+    def flush() -> None:
+        content = "\n".join(body).strip()
+        if content:
+            facts.append(
+                SeedFact(
+                    content=content,
+                    memory_type=MemoryType.DOCUMENT,
+                    source_file=source_file,
+                    section=section,
+                )
+            )
+
+    for line in text.splitlines():
+        stripped = line.lstrip()
+        if stripped.startswith("#"):
+            flush()
+            section = stripped.lstrip("#").strip() or None
+            body = []
+        else:
+            body.append(line)
+    flush()
+    return facts
+
+
+def parse_json_seed(text: str, source_file: str) -> list[SeedFact]:
+    """Facts of a JSON seed file.
+
+    Raises:
+        ValidationError: Invalid JSON or facts
+    """
+    try:
+        data = json.loads(text)
+    except ValueError as e:
+        raise ValidationError(f"{source_file}: invalid JSON: {e}") from e
+    if isinstance(data, dict):
+        data = data.get("facts")
+    if not isinstance(data, list):
+        raise ValidationError(f"{source_file}: expected a list of facts")
+
+    facts = []
+    for i, item in enumerate(data):
+        fields = {"content": item} if isinstance(item, str) else item
+        if not isinstance(fields, dict):
+            raise ValidationError(f"{source_file}: fact {i} is not a string or object")
+        try:
+            facts.append(SeedFact(**{**fields, "source_file": source_file}))
+        except PydanticValidationError as e:
+            raise ValidationError(f"{source_file}: fact {i}: {e}") from e
+    return facts
+
+
+def load_seed_directory(directory: str | Path) -> dict[str, list[SeedFact]]:
+    """Facts of every seed file below a directory, keyed by relative path.
+
+    Raises:
+        ValidationError: Missing directory or malformed file
+    """
+    root = Path(directory)
+    if not root.is_dir():
+        raise ValidationError(f"Seed directory not found: {root}")
+
+    seeds: dict[str, list[SeedFact]] = {}
+    for path in sorted(root.rglob("*")):
+        suffix = path.suffix.lower()
+        if not path.is_file() or suffix not in MARKDOWN_SUFFIXES | JSON_SUFFIXES:
+            continue
+        relative = path.relative_to(root).as_posix()
+        text = path.read_text(encoding="utf-8")
+        if suffix in MARKDOWN_SUFFIXES:
+            seeds[relative] = parse_markdown_seed(text, relative)
+        else:
+            seeds[relative] = parse_json_seed(text, relative)
+    return seeds
+
+
+def entity_node_id(tenant_id: str, text: str) -> UUID:
+    """Stable graph node id of an entity, shared by all memories naming it.
+
+    The id depends on the name only (case-insensitive), so an entity can be
+    looked up without knowing the type an extractor gave it.
+    """
+    return uuid5(NAMESPACE_URL, f"rae-entity:{tenant_id}:{text.lower()}")
+
+
+class AgentBootstrap:
+    """Loads seed knowledge into an agent's semantic layer in one call."""
+
+    def __init__(
+        self,
+        engine: Any,
+        graph_store: IGraphStore | None = None,
+        entity_extractor: Any = None,
+    ):
+        """Initialize bootstrap.
+
+        Args:
+            engine: RAEEngine storing and embedding the facts
+            graph_store: Graph store receiving entity nodes (none if None)
+            entity_extractor: Object with async extract_entities(text) (the
+                engine's LLM provider if it has one, else NoLLMFallback)
+        """
+        self.engine = engine
+        self.graph_store = graph_store
+        if entity_extractor is None:
+            provider = getattr(engine, "llm_provider", None)
+            if hasattr(provider, "extract_entities"):
+                entity_extractor = provider
+            else:
+                entity_extractor = NoLLMFallback()
+        self.entity_extractor = entity_extractor
+
+    async def run(
+        self, tenant_id: str, agent_id: str, directory: str | Path
+    ) -> BootstrapReport:
+        """Ingest every seed file below directory for the agent.
+
+        Raises:
+            ValidationError: Missing directory or malformed file (nothing
+                is stored)
+        """
+        seeds = load_seed_directory(directory)
+        report = BootstrapReport(
+            tenant_id=tenant_id, agent_id=agent_id, files=list(seeds)
+        )
+        entity_ids: set[UUID] = set()
+        for facts in seeds.values():
+            for fact in facts:
+                entities = await self._extract_entities(fact.content)
+                memory_id = await self._store(tenant_id, agent_id, fact, entities)
+                if memory_id is None:
+                    report.skipped += 1
+                    continue
+                report.memory_ids.append(memory_id)
+                for entity in entities:
+                    entity_ids.add(entity_node_id(tenant_id, entity["text"]))
+                if self.graph_store is not None:
+                    with span(
+                        "rae.graph.link_entities",
+                        tenant_id=tenant_id,
+                        memory_id=memory_id,
+                        entity_count=len(entities),
+                    ):
+                        await self._link_entities(tenant_id, memory_id, entities)
+
+        report.entities = len(entity_ids)
+        logger.info(
+            "agent_bootstrapped",
+            tenant_id=tenant_id,
+            agent_id=agent_id,
+            files=len(report.files),
+            memories=len(report.memory_ids),
+            entities=report.entities,
+        )
+        return report
+
+    async def _extract_entities(self, content: str) -> list[dict[str, str]]:
+        """Distinct entities of a fact, as {"text", "type"} pairs."""
+        try:
+            found = await self.entity_extractor.extract_entities(content)
+        except Exception as e:
+            logger.warning("bootstrap_entity_extraction_failed", error=str(e))
+            return []
+        entities: list[dict[str, str]] = []
+        seen: set[tuple[str, str]] = set()
+        for entity in found:
+            text = str(entity.get("text") or "").strip()
+            entity_type = str(entity.get("type") or "ENTITY")
+            key = (text.lower(), entity_type)
+            if text and key not in seen:
+                seen.add(key)
+                entities.append({"text": text, "type": entity_type})
+        return entities
+
+    async def _store(
+        self,
+        tenant_id: str,
+        agent_id: str,
+        fact: SeedFact,
+        entities: list[dict[str, str]],
+    ) -> UUID | None:
+        metadata = {
+            **fact.metadata,
+            "seed_file": fact.source_file,
+            ENTITIES_KEY: entities,
+        }
+        if fact.section is not None:
+            metadata["section"] = fact.section
+        tags = fact.tags if SEED_TAG in fact.tags else [*fact.tags, SEED_TAG]
+        return await self.engine.store_memory(
+            tenant_id=tenant_id,
+            agent_id=agent_id,
+            content=fact.content,
+            layer=MemoryLayer.SEMANTIC.value,
+            memory_type=fact.memory_type.value,
+            tags=tags,
+            importance=fact.importance,
+            metadata=metadata,
+            source=MemorySource(
+                type=SourceType.IMPORT,
+                ref=f"seed:{fact.source_file}",
+                file=fact.source_file,
+            ),
+            priority=PriorityClass.BATCH,
+        )
+
+    async def _link_entities(
+        self, tenant_id: str, memory_id: UUID, entities: list[dict[str, str]]
+    ) -> None:
+        assert self.graph_store is not None
+        await self.graph_store.create_node(
+            memory_id,
+            NodeType.MEMORY.value,
+            tenant_id,
+            {"layer": MemoryLayer.SEMANTIC.value},
+        )
+        for entity in entities:
+            node_id = entity_node_id(tenant_id, entity["text"])
+            await self.graph_store.create_node(
+                node_id,
+                NodeType.ENTITY.value,
+                tenant_id,
+                {"name": entity["text"], "entity_type": entity["type"]},
+            )
+            await self.graph_store.create_edge(
+                memory_id, node_id, EdgeType.RELATES_TO.value, tenant_id
+            )
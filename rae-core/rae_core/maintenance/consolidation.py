@@ -0,0 +1,264 @@
+"""Consolidating old episodic memories into semantic summaries.
+
+Episodic memories older than a window are grouped (by session, by the
+entity they name or by embedding similarity) and every group is summarized
+by the LLM provider into one semantic memory. The summary lists its sources
+in metadata["source_memory_ids"]; with a graph store it is also linked to
+each of them by a derived_from edge and a summarizes edge (which
+maintenance.summaries follows to refresh it when they change). The
+originals are then moved to the trash with consolidated_into in their
+metadata, so they stop competing with their summary in recall but can
+still be restored until purged.
+"""
+
+from collections.abc import Iterable
+from datetime import timedelta
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.config.defaults import DEFAULT_DERIVED_CONFIDENCE_FACTOR
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.llm.fallback import NoLLMFallback
+from rae_core.maintenance.bootstrap import ENTITIES_KEY
+from rae_core.math.structure import cosine_similarity
+from rae_core.models.graph import EdgeType, NodeType
+from rae_core.models.load import PriorityClass
+from rae_core.models.maintenance import (
+    ConsolidationGrouping,
+    ConsolidationReport,
+    ConsolidatorConfig,
+)
+from rae_core.models.memory import derived_confidence
+from rae_core.models.namespace import NAMESPACE_KEY, memory_namespace
+from rae_core.models.provenance import MemorySource, SourceType
+from rae_core.tracing import span
+from rae_core.types.enums import MemoryLayer
+from rae_core.utils.clock import IClock, SystemClock
+
+logger = structlog.get_logger(__name__)
+
+CONSOLIDATION_TAG = "consolidated"
+SOURCES_KEY = "source_memory_ids"
+CONSOLIDATED_INTO_KEY = "consolidated_into"
+
+
+class Consolidator:
+    """Turns groups of old episodic memories into semantic summaries."""
+
+    def __init__(
+        self,
+        engine: Any,
+        graph_store: IGraphStore | None = None,
+        summarizer: Any = None,
+        config: ConsolidatorConfig | None = None,
+        clock: IClock | None = None,
+    ):
+        """Initialize consolidator.
+
+        Args:
+            engine: RAEEngine whose storage is scanned and which stores the
+                summaries
+            graph_store: Graph store receiving provenance edges (none if None)
+            summarizer: Object with async summarize(text, max_length) (the
+                engine's LLM provider if it has one, else NoLLMFallback)
+            config: Window, grouping and archiving options
+            clock: Time source (system clock if None)
+        """
+        self.engine = engine
+        self.storage = engine.memory_storage
+        self.graph_store = graph_store
+        if summarizer is None:
+            provider = getattr(engine, "llm_provider", None)
+            if hasattr(provider, "summarize"):
+                summarizer = provider
+            else:
+                summarizer = NoLLMFallback()
+        self.summarizer = summarizer
+        self.config = config or ConsolidatorConfig()
+        self.clock = clock or SystemClock()
+
+    async def run(
+        self, tenant_id: str, agent_id: str | None = None
+    ) -> ConsolidationReport:
+        """Consolidate the tenant's (or one agent's) old episodic memories."""
+        config = self.config
+        report = ConsolidationReport(tenant_id=tenant_id, agent_id=agent_id)
+        cutoff = self.clock.now() - timedelta(hours=config.min_age_hours)
+        memories = await self.storage.list_memories(
+            tenant_id,
+            agent_id=agent_id,
+            layer=MemoryLayer.EPISODIC.value,
+            created_before=cutoff,
+            limit=config.max_memories,
+        )
+        report.scanned = len(memories)
+        # Ids break ties between memories stored in the same clock tick
+        memories.sort(key=lambda m: (m["created_at"], str(m["id"])))
+
+        for group in await self._groups(tenant_id, memories):
+            for part in _split(group, config.max_group_size):
+                if len(part) < config.min_group_size:
+                    continue
+                report.groups += 1
+                with span(
+                    "rae.consolidate_group", tenant_id=tenant_id, size=len(part)
+                ):
+                    summary_id = await self._consolidate(tenant_id, part)
+                if summary_id is None:
+                    report.failed += 1
+                    continue
+                report.summary_ids.append(summary_id)
+                if config.archive:
+                    report.archived += await self._archive(
+                        tenant_id, part, summary_id
+                    )
+
+        logger.info(
+            "episodic_memories_consolidated",
+            tenant_id=tenant_id,
+            agent_id=agent_id,
+            scanned=report.scanned,
+            summaries=len(report.summary_ids),
+            archived=report.archived,
+        )
+        return report
+
+    async def _groups(
+        self, tenant_id: str, memories: list[dict[str, Any]]
+    ) -> list[list[dict[str, Any]]]:
+        """Memories grouped by the configured key; ungroupable ones dropped."""
+        grouping = self.config.group_by
+        if grouping == ConsolidationGrouping.CLUSTER:
+            return await self._clusters(tenant_id, memories)
+
+        groups: dict[tuple[str | None, str, str], list[dict[str, Any]]] = {}
+        for memory in memories:
+            if grouping == ConsolidationGrouping.SESSION:
+                key = memory.get("session_id")
+            else:
+                entities = (memory.get("metadata") or {}).get(ENTITIES_KEY) or []
+                key = str(entities[0].get("text", "")).lower() if entities else None
+            if key:
+                # Agents and namespaces never share a summary
+                owner = (memory.get("agent_id"), memory_namespace(memory))
+                groups.setdefault((*owner, key), []).append(memory)
+        return list(groups.values())
+
+    async def _clusters(
+        self, tenant_id: str, memories: list[dict[str, Any]]
+    ) -> list[list[dict[str, Any]]]:
+        """Greedy clusters: a memory joins the first similar cluster seed."""
+        clusters: list[
+            tuple[tuple[str | None, str], list[float], list[dict[str, Any]]]
+        ] = []
+        for memory in memories:
+            vector = await self.engine.vector_store.get_vector(memory["id"], tenant_id)
+            if not vector:
+                continue
+            owner = (memory.get("agent_id"), memory_namespace(memory))
+            for cluster_owner, seed, members in clusters:
+                if (
+                    cluster_owner == owner
+                    and cosine_similarity(seed, vector)
+                    >= self.config.cluster_similarity
+                ):
+                    members.append(memory)
+                    break
+            else:
+                clusters.append((owner, vector, [memory]))
+        return [members for _, _, members in clusters]
+
+    async def _consolidate(
+        self, tenant_id: str, memories: list[dict[str, Any]]
+    ) -> UUID | None:
+        """Store the summary of a group and link it to its sources."""
+        text = "\n".join(f"- {m['content']}" for m in memories)
+        try:
+            summary = await self.summarizer.summarize(
+                text, max_length=self.config.summary_max_length
+            )
+        except Exception as e:
+            logger.warning("consolidation_summary_failed", error=str(e))
+            return None
+        if not summary or not summary.strip():
+            return None
+
+        sources = [str(m["id"]) for m in memories]
+        tags = sorted(
+            {t for m in memories for t in m.get("tags") or []} | {CONSOLIDATION_TAG}
+        )
+        summary_id = await self.engine.store_memory(
+            tenant_id=tenant_id,
+            agent_id=memories[0].get("agent_id") or "default",
+            content=summary.strip(),
+            layer=MemoryLayer.SEMANTIC.value,
+            tags=tags,
+            importance=max(float(m.get("importance") or 0.5) for m in memories),
+            confidence=derived_confidence(
+                memories, DEFAULT_DERIVED_CONFIDENCE_FACTOR
+            ),
+            metadata={
+                SOURCES_KEY: sources,
+                "consolidated_by": self.config.group_by.value,
+                NAMESPACE_KEY: memory_namespace(memories[0]),
+            },
+            source=MemorySource(
+                type=SourceType.CONSOLIDATION,
+                ref="consolidation",
+                derived_from=[m["id"] for m in memories],
+            ),
+            priority=PriorityClass.BATCH,
+            check_novelty=False,
+        )
+        if summary_id is not None and self.graph_store is not None:
+            await self._link_sources(tenant_id, summary_id, memories)
+        return summary_id
+
+    async def _link_sources(
+        self, tenant_id: str, summary_id: UUID, memories: list[dict[str, Any]]
+    ) -> None:
+        assert self.graph_store is not None
+        await self.graph_store.create_node(
+            summary_id,
+            NodeType.MEMORY.value,
+            tenant_id,
+            {"layer": MemoryLayer.SEMANTIC.value},
+        )
+        for memory in memories:
+            await self.graph_store.create_node(
+                memory["id"],
+                NodeType.MEMORY.value,
+                tenant_id,
+                {"layer": MemoryLayer.EPISODIC.value},
+            )
+            await self.graph_store.create_edge(
+                summary_id, memory["id"], EdgeType.DERIVED_FROM.value, tenant_id
+            )
+            await self.graph_store.create_edge(
+                summary_id, memory["id"], EdgeType.SUMMARIZES.value, tenant_id
+            )
+
+    async def _archive(
+        self, tenant_id: str, memories: list[dict[str, Any]], summary_id: UUID
+    ) -> int:
+        archived = 0
+        for memory in memories:
+            metadata = {
+                **(memory.get("metadata") or {}),
+                CONSOLIDATED_INTO_KEY: str(summary_id),
+            }
+            await self.storage.update_memory(
+                memory["id"], tenant_id, {"metadata": metadata}, "consolidation"
+            )
+            if await self.storage.soft_delete_memory(memory["id"], tenant_id):
+                archived += 1
+        return archived
+
+
+def _split(
+    memories: list[dict[str, Any]], size: int
+) -> Iterable[list[dict[str, Any]]]:
+    for start in range(0, len(memories), size):
+        yield memories[start : start + size]
@@ -0,0 +1,176 @@
+"""Re-embedding a tenant's corpus for an embedding model upgrade.
+
+Vectors of the new model are written next to the old ones while the tenant
+keeps searching its active collection. Once every memory has a new vector
+the tenant's active model is switched in one step, so queries never see a
+half-migrated collection. The old vectors are kept for rollback.
+"""
+
+import asyncio
+import inspect
+import time
+from collections.abc import Awaitable, Callable
+from datetime import datetime, timezone
+
+import structlog
+
+from rae_core.embedding.manager import EmbeddingManager
+from rae_core.embedding.migration import EmbeddingMigration
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.embedding import IEmbeddingProvider
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.maintenance import ReembedConfig, ReembedProgress
+from rae_core.models.scoring import JobStatus
+
+logger = structlog.get_logger(__name__)
+
+ProgressCallback = Callable[[ReembedProgress], Awaitable[None] | None]
+
+
+class ReembedJob:
+    """Re-embeds all memories of a tenant and swaps its active collection.
+
+    Run it inline with `await job.run()` or in the background with
+    `job.start()`; `job.progress` is updated after every batch and passed to
+    the optional on_progress callback. Re-running after a failure or
+    cancellation only embeds memories still lacking a new vector.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore,
+        manager: EmbeddingManager,
+        tenant_id: str,
+        model_name: str,
+        provider: IEmbeddingProvider | None = None,
+        config: ReembedConfig | None = None,
+        on_progress: ProgressCallback | None = None,
+    ):
+        """Initialize job.
+
+        Args:
+            storage: Storage holding the tenant's memories
+            vector_store: Vector store receiving the new vectors
+            manager: Embedding manager holding the tenant's active model
+            tenant_id: Tenant to re-embed
+            model_name: Name of the new model (and of its vectors)
+            provider: Provider of the new model, registered under model_name
+                (defaults to the provider already registered)
+            config: Batching, throttling and swap options
+            on_progress: Called with the progress report after each batch
+
+        Raises:
+            ValidationError: If no provider is registered or given
+        """
+        if provider is not None:
+            manager.register_provider(model_name, provider)
+        provider = manager.get_provider(model_name)
+        if provider is None:
+            raise ValidationError(f"Unknown embedding model: {model_name}")
+
+        self.storage = storage
+        self.manager = manager
+        self.config = config or ReembedConfig()
+        self.on_progress = on_progress
+        self.migration = EmbeddingMigration(
+            storage,
+            vector_store,
+            provider,
+            model_name,
+            batch_size=self.config.batch_size,
+        )
+        self.progress = ReembedProgress(tenant_id=tenant_id, model_name=model_name)
+        self._cancelled = False
+        self._task: asyncio.Task[ReembedProgress] | None = None
+        # Overridable for tests
+        self._sleep: Callable[[float], Awaitable[None]] = asyncio.sleep
+
+    def start(self) -> "asyncio.Task[ReembedProgress]":
+        """Run the job in the background."""
+        if self._task is None:
+            self._task = asyncio.create_task(self.run())
+        return self._task
+
+    def cancel(self) -> None:
+        """Stop after the current batch; the active collection is unchanged."""
+        self._cancelled = True
+
+    async def run(self) -> ReembedProgress:
+        """Execute the job to completion and return the final progress."""
+        progress = self.progress
+        tenant_id = progress.tenant_id
+        progress.status = JobStatus.RUNNING
+        progress.started_at = datetime.now(timezone.utc)
+
+        try:
+            progress.total = await self.storage.count_memories(tenant_id)
+            await self._report()
+            if await self._embed_all():
+                # Catch up with memories stored while the job was running
+                await self._embed_all(report=False)
+                if self.config.swap:
+                    self._swap()
+                progress.status = JobStatus.COMPLETED
+            else:
+                progress.status = JobStatus.CANCELLED
+        except Exception as e:
+            progress.status = JobStatus.FAILED
+            progress.error = str(e)
+            logger.error("reembed_failed", tenant_id=tenant_id, error=str(e))
+
+        progress.finished_at = datetime.now(timezone.utc)
+        await self._report()
+        logger.info(
+            "reembed_finished",
+            tenant_id=tenant_id,
+            model=progress.model_name,
+            status=progress.status.value,
+            embedded=progress.embedded,
+            swapped=progress.swapped,
+        )
+        return progress
+
+    async def _embed_all(self, report: bool = True) -> bool:
+        """One pass over the tenant; False if cancelled."""
+        rate = self.config.max_texts_per_second
+        async for scanned, missing in self.migration.scan(self.progress.tenant_id):
+            if self._cancelled:
+                return False
+            started = time.monotonic()
+            self.progress.embedded += await self.migration.embed(
+                missing, self.progress.tenant_id
+            )
+            if report:
+                self.progress.processed += scanned
+                await self._report()
+            if rate is not None and missing:
+                delay = len(missing) / rate - (time.monotonic() - started)
+                if delay > 0:
+                    await self._sleep(delay)
+            else:
+                # Let other tasks run between batches
+                await asyncio.sleep(0)
+        return True
+
+    def _swap(self) -> None:
+        """Make the new model the tenant's active collection."""
+        current = self.manager.get_tenant_config(self.progress.tenant_id)
+        self.progress.previous_model = (
+            current.model_name if current else self.manager.default_model_name
+        )
+        self.manager.set_tenant_model(
+            self.progress.tenant_id,
+            self.progress.model_name,
+            exclusive=self.config.exclusive,
+            allow_dimension_change=True,
+        )
+        self.progress.swapped = True
+
+    async def _report(self) -> None:
+        if self.on_progress is None:
+            return
+        result = self.on_progress(self.progress.model_copy())
+        if inspect.isawaitable(result):
+            await result
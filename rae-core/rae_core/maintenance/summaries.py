@@ -0,0 +1,265 @@
+"""Keeping consolidated summaries in step with their sources.
+
+A summary is linked to each memory it summarizes by a "summarizes" edge of
+the graph store (Consolidator creates them; track() does it for other
+summaries). SummaryTracker follows memory events: when the content of a
+source changes, or the source is deleted, every summary with an edge to it
+gets a dirty flag in its metadata, naming the changed sources. Soft deletes
+that archive a source into its own summary (consolidated_into) and changes
+of metadata only are ignored.
+
+SummaryRefresher.refresh_stale_summaries regenerates the flagged summaries
+only: each is summarized again from the sources still left, keeping its id
+and edges, and re-embedded. A summary none of whose sources is left is
+moved to the trash.
+"""
+
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.config.defaults import DEFAULT_SUMMARY_REFRESH_BATCH
+from rae_core.events.bus import MemoryEventBus, Subscription
+from rae_core.events.models import MemoryDeleted, MemoryEvent, MemoryUpdated
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.llm.fallback import NoLLMFallback
+from rae_core.maintenance.consolidation import CONSOLIDATED_INTO_KEY, SOURCES_KEY
+from rae_core.models.graph import EdgeType, NodeType
+from rae_core.models.maintenance import ConsolidatorConfig, SummaryRefreshReport
+
+logger = structlog.get_logger(__name__)
+
+# Metadata flag of a summary whose sources changed, and the changed sources
+STALE_KEY = "summary_stale"
+STALE_SOURCES_KEY = "stale_sources"
+
+
+class SummaryTracker:
+    """Flags summaries as stale when the memories they summarize change.
+
+    Pair with EventPublishingStorage so source changes reach the bus:
+
+        tracker = SummaryTracker(storage, graph_store)
+        tracker.attach(bus)
+    """
+
+    def __init__(self, storage: Any, graph_store: IGraphStore):
+        """Initialize tracker.
+
+        Args:
+            storage: Memory storage holding the summaries
+            graph_store: Graph store with the "summarizes" edges
+        """
+        self.storage = storage
+        self.graph_store = graph_store
+
+    def attach(self, event_bus: MemoryEventBus) -> Subscription:
+        """Subscribe to the memory events on the bus."""
+        return event_bus.subscribe(MemoryEvent, self.handle)
+
+    async def handle(self, event: MemoryEvent) -> None:
+        """Flag the summaries of a source whose content changed or was deleted."""
+        if isinstance(event, MemoryUpdated):
+            if "content" in event.changes:
+                await self.mark_stale(event.tenant_id, event.memory_id)
+        elif isinstance(event, MemoryDeleted):
+            archived_into = None
+            if event.soft:
+                memory = await self.storage.get_memory(
+                    event.memory_id, event.tenant_id
+                )
+                metadata = (memory or {}).get("metadata") or {}
+                archived_into = metadata.get(CONSOLIDATED_INTO_KEY)
+            await self.mark_stale(
+                event.tenant_id, event.memory_id, except_summary=archived_into
+            )
+
+    async def track(
+        self, tenant_id: str, summary_id: UUID, source_ids: list[UUID]
+    ) -> None:
+        """Link a summary to the memories it summarizes."""
+        await self.graph_store.create_node(summary_id, NodeType.MEMORY.value, tenant_id)
+        for source_id in source_ids:
+            await self.graph_store.create_node(
+                source_id, NodeType.MEMORY.value, tenant_id
+            )
+            await self.graph_store.create_edge(
+                summary_id, source_id, EdgeType.SUMMARIZES.value, tenant_id
+            )
+
+    async def summaries_of(self, tenant_id: str, source_id: UUID) -> list[UUID]:
+        """Summaries with a "summarizes" edge to a memory."""
+        return await self.graph_store.get_neighbors(
+            source_id, tenant_id, edge_type=EdgeType.SUMMARIZES.value, direction="in"
+        )
+
+    async def sources_of(self, tenant_id: str, summary_id: UUID) -> list[UUID]:
+        """Memories a summary has "summarizes" edges to."""
+        return await self.graph_store.get_neighbors(
+            summary_id,
+            tenant_id,
+            edge_type=EdgeType.SUMMARIZES.value,
+            direction="out",
+        )
+
+    async def mark_stale(
+        self,
+        tenant_id: str,
+        source_id: UUID,
+        except_summary: str | None = None,
+    ) -> list[UUID]:
+        """Flag the summaries of a changed source.
+
+        Returns:
+            Ids of the summaries flagged
+        """
+        flagged = []
+        for summary_id in await self.summaries_of(tenant_id, source_id):
+            if except_summary is not None and str(summary_id) == str(except_summary):
+                continue
+            summary = await self.storage.get_memory(summary_id, tenant_id)
+            if summary is None:
+                continue
+            metadata = dict(summary.get("metadata") or {})
+            changed = set(metadata.get(STALE_SOURCES_KEY) or []) | {str(source_id)}
+            metadata[STALE_KEY] = True
+            metadata[STALE_SOURCES_KEY] = sorted(changed)
+            await self.storage.update_memory(
+                summary_id, tenant_id, {"metadata": metadata}, "summary_tracker"
+            )
+            flagged.append(summary_id)
+        if flagged:
+            logger.info(
+                "summaries_marked_stale",
+                tenant_id=tenant_id,
+                source_id=str(source_id),
+                summaries=len(flagged),
+            )
+        return flagged
+
+
+class SummaryRefresher:
+    """Regenerates the summaries SummaryTracker flagged as stale."""
+
+    def __init__(
+        self,
+        engine: Any,
+        graph_store: IGraphStore,
+        summarizer: Any = None,
+        max_length: int | None = None,
+        batch_size: int = DEFAULT_SUMMARY_REFRESH_BATCH,
+    ):
+        """Initialize refresher.
+
+        Args:
+            engine: RAEEngine whose storage holds the summaries and which
+                embeds the regenerated ones
+            graph_store: Graph store with the "summarizes" edges
+            summarizer: Object with async summarize(text, max_length) (the
+                engine's LLM provider if it has one, else NoLLMFallback)
+            max_length: Maximum summary length in characters (the
+                consolidation default if None)
+            batch_size: Stale summaries regenerated per run
+        """
+        self.engine = engine
+        self.storage = engine.memory_storage
+        self.tracker = SummaryTracker(self.storage, graph_store)
+        if summarizer is None:
+            provider = getattr(engine, "llm_provider", None)
+            if hasattr(provider, "summarize"):
+                summarizer = provider
+            else:
+                summarizer = NoLLMFallback()
+        self.summarizer = summarizer
+        self.max_length = max_length or ConsolidatorConfig().summary_max_length
+        self.batch_size = batch_size
+
+    async def refresh_stale_summaries(self, tenant_id: str) -> SummaryRefreshReport:
+        """Regenerate the tenant's stale summaries (up to batch_size)."""
+        report = SummaryRefreshReport(tenant_id=tenant_id)
+        stale = await self.storage.list_memories(
+            tenant_id, filters={STALE_KEY: True}, limit=self.batch_size
+        )
+        report.stale = len(stale)
+        for summary in stale:
+            try:
+                refreshed = await self._refresh(tenant_id, summary)
+            except Exception as e:
+                report.failed += 1
+                logger.warning(
+                    "summary_refresh_failed",
+                    tenant_id=tenant_id,
+                    summary_id=str(summary["id"]),
+                    error=str(e),
+                )
+                continue
+            if refreshed:
+                report.refreshed.append(summary["id"])
+            else:
+                report.removed.append(summary["id"])
+
+        logger.info(
+            "stale_summaries_refreshed",
+            tenant_id=tenant_id,
+            stale=report.stale,
+            refreshed=len(report.refreshed),
+            removed=len(report.removed),
+            failed=report.failed,
+        )
+        return report
+
+    async def _refresh(self, tenant_id: str, summary: dict[str, Any]) -> bool:
+        """Summarize a summary again; False if it was trashed instead."""
+        summary_id = summary["id"]
+        source_ids = await self.tracker.sources_of(tenant_id, summary_id)
+        sources = [
+            memory
+            for memory in await self.storage.get_memories_batch(source_ids, tenant_id)
+            if memory.get("deleted_at") is None
+            or str((memory.get("metadata") or {}).get(CONSOLIDATED_INTO_KEY))
+            == str(summary_id)
+        ]
+        left = {str(memory["id"]) for memory in sources}
+        for source_id in source_ids:
+            if str(source_id) not in left:
+                await self.tracker.graph_store.delete_edge(
+                    summary_id, source_id, EdgeType.SUMMARIZES.value, tenant_id
+                )
+        if not sources:
+            await self.storage.soft_delete_memory(summary_id, tenant_id)
+            return False
+
+        # Sources keep the order they were first summarized in
+        listed = (summary.get("metadata") or {}).get(SOURCES_KEY) or []
+        position = {source_id: i for i, source_id in enumerate(listed)}
+        sources.sort(
+            key=lambda m: (position.get(str(m["id"]), len(listed)), m["created_at"])
+        )
+        text = "\n".join(f"- {m['content']}" for m in sources)
+        content = await self.summarizer.summarize(text, max_length=self.max_length)
+        if not content or not content.strip():
+            raise ValueError("empty summary")
+        content = content.strip()
+
+        metadata = dict(summary.get("metadata") or {})
+        metadata.pop(STALE_KEY, None)
+        metadata.pop(STALE_SOURCES_KEY, None)
+        metadata[SOURCES_KEY] = [str(m["id"]) for m in sources]
+        await self.storage.update_memory(
+            summary_id,
+            tenant_id,
+            {"content": content, "metadata": metadata},
+            "summary_refresh",
+        )
+        [embedding] = await self.engine.embed_documents([content], tenant_id)
+        await self.engine.vector_store.store_vector(
+            summary_id,
+            embedding,
+            tenant_id,
+            metadata={
+                "layer": summary.get("layer"),
+                "agent_id": summary.get("agent_id"),
+            },
+        )
+        return True
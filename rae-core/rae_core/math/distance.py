@@ -0,0 +1,68 @@
+"""Similarity scores of the vector distance metrics.
+
+Vector stores report higher-is-better scores whatever the metric of a
+collection, so score thresholds and fusion work the same way for all:
+
+- COSINE: cosine similarity in [-1, 1]; only the direction of the vectors
+  counts, so they are compared as if normalized
+- DOT: the raw dot product; vector lengths count (e.g. models encoding
+  importance in the norm), so vectors are never normalized
+- EUCLID: 1 / (1 + euclidean distance), in (0, 1]
+"""
+
+import math
+from collections.abc import Mapping, Sequence
+
+from rae_core.types.enums import DistanceMetric
+
+# Metric of every collection, or of the collections named in a dict
+MetricConfig = DistanceMetric | str | Mapping[str, DistanceMetric | str]
+
+_ALIASES = {
+    "cosine": DistanceMetric.COSINE,
+    "euclid": DistanceMetric.EUCLID,
+    "euclidean": DistanceMetric.EUCLID,
+    "l2": DistanceMetric.EUCLID,
+    "dot": DistanceMetric.DOT,
+    "ip": DistanceMetric.DOT,
+}
+
+
+def as_metric(value: DistanceMetric | str) -> DistanceMetric:
+    """Metric from an enum value or its name (case-insensitive, "l2", "ip")."""
+    if isinstance(value, DistanceMetric):
+        return value
+    metric = _ALIASES.get(str(value).lower())
+    if metric is None:
+        raise ValueError(f"Unknown distance metric: {value}")
+    return metric
+
+
+def collection_metric(config: MetricConfig, collection: str) -> DistanceMetric:
+    """Metric of a collection (cosine for collections a dict does not name)."""
+    if isinstance(config, Mapping):
+        return as_metric(config.get(collection, DistanceMetric.COSINE))
+    return as_metric(config)
+
+
+def similarity(metric: DistanceMetric, a: Sequence[float], b: Sequence[float]) -> float:
+    """Score of two vectors under a metric (0.0 cosine for a zero vector)."""
+    if metric == DistanceMetric.EUCLID:
+        return euclid_score(math.sqrt(sum((x - y) * (x - y) for x, y in zip(a, b))))
+    dot = sum(x * y for x, y in zip(a, b))
+    if metric == DistanceMetric.DOT:
+        return dot
+    norm = math.sqrt(sum(x * x for x in a)) * math.sqrt(sum(y * y for y in b))
+    return dot / norm if norm else 0.0
+
+
+def euclid_score(distance: float) -> float:
+    """Score of a euclidean distance."""
+    return 1.0 / (1.0 + distance)
+
+
+def euclid_distance_limit(score_threshold: float) -> float:
+    """Largest euclidean distance scoring at least score_threshold."""
+    if score_threshold <= 0.0:
+        return math.inf
+    return max(0.0, 1.0 / score_threshold - 1.0)
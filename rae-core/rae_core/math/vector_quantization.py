@@ -0,0 +1,312 @@
+"""Lossy vector codecs that cut the memory footprint of vector collections.
+
+Every codec turns a float vector into a fixed number of bytes and scores a
+query against such a code (cosine similarity):
+
+- FixedPointCodec: the lossless-enough Q16.16 int32 encoding the in-memory
+  store uses by default (4 bytes per dimension)
+- ScalarQuantizer: one signed byte per dimension plus a float32 scale per
+  vector (about 4x smaller than float32); scoring is integer arithmetic
+- ProductQuantizer: the vector is cut into subvectors and each is replaced
+  by the index of its nearest centroid in a trained codebook, one byte per
+  subvector (8x smaller than float32 with two dimensions per subvector);
+  scoring looks the query's dot products with every centroid up in tables
+
+Codebook training (k-means) uses numpy when it is installed and falls back
+to pure Python otherwise, which is only practical for small collections.
+"""
+
+import math
+import random
+import struct
+from collections.abc import Sequence
+from typing import Any, Protocol
+
+from rae_core.math.quantization_bytes import (
+    cosine_similarity_bytes,
+    dequantize_vector_bytes,
+    quantize_vector_bytes,
+)
+from rae_core.models.quantization import QuantizationConfig, QuantizationMode
+
+try:
+    import numpy as np
+except ImportError:  # pragma: no cover - depends on the installed extras
+    np = None
+
+_SCALE = struct.Struct(">f")
+
+
+class VectorCodec(Protocol):
+    """Fixed-size byte encoding of the vectors of one collection."""
+
+    dimension: int
+
+    @property
+    def code_size(self) -> int:
+        """Bytes per encoded vector."""
+        ...
+
+    def encode(self, vector: Sequence[float]) -> bytes:
+        """Encode a vector."""
+        ...
+
+    def decode(self, code: bytes) -> list[float]:
+        """Approximate vector of a code."""
+        ...
+
+    def prepare(self, query: Sequence[float]) -> Any:
+        """Precompute what similarity() needs of a query."""
+        ...
+
+    def similarity(self, prepared: Any, code: bytes) -> float:
+        """Cosine similarity of a prepared query and a code."""
+        ...
+
+
+def _check_finite(vector: Sequence[float]) -> None:
+    for value in vector:
+        if not math.isfinite(value):
+            raise ValueError(f"Non-finite value in vector: {value}")
+
+
+class FixedPointCodec:
+    """Q16.16 int32 codes (see math.quantization_bytes)."""
+
+    def __init__(self, dimension: int):
+        self.dimension = dimension
+
+    @property
+    def code_size(self) -> int:
+        return self.dimension * 4
+
+    def encode(self, vector: Sequence[float]) -> bytes:
+        return quantize_vector_bytes(vector)
+
+    def decode(self, code: bytes) -> list[float]:
+        return dequantize_vector_bytes(code)
+
+    def prepare(self, query: Sequence[float]) -> bytes:
+        return quantize_vector_bytes(query)
+
+    def similarity(self, prepared: bytes, code: bytes) -> float:
+        return cosine_similarity_bytes(prepared, code)
+
+
+class ScalarQuantizer:
+    """int8 codes scaled per vector by its largest absolute component."""
+
+    def __init__(self, dimension: int):
+        self.dimension = dimension
+        self._codes = struct.Struct(f">{dimension}b")
+
+    @property
+    def code_size(self) -> int:
+        return _SCALE.size + self.dimension
+
+    def _quantize(self, vector: Sequence[float]) -> tuple[float, list[int]]:
+        _check_finite(vector)
+        peak = max((abs(x) for x in vector), default=0.0)
+        if peak == 0.0:
+            return 0.0, [0] * len(vector)
+        scale = peak / 127
+        return scale, [max(-127, min(127, round(x / scale))) for x in vector]
+
+    def encode(self, vector: Sequence[float]) -> bytes:
+        scale, codes = self._quantize(vector)
+        return _SCALE.pack(scale) + self._codes.pack(*codes)
+
+    def decode(self, code: bytes) -> list[float]:
+        (scale,) = _SCALE.unpack_from(code)
+        return [c * scale for c in self._codes.unpack_from(code, _SCALE.size)]
+
+    def prepare(self, query: Sequence[float]) -> tuple[tuple[int, ...], int]:
+        _, codes = self._quantize(query)
+        return tuple(codes), sum(c * c for c in codes)
+
+    def similarity(self, prepared: tuple[tuple[int, ...], int], code: bytes) -> float:
+        # The per-vector scales cancel out of the cosine
+        query, query_norm = prepared
+        codes = self._codes.unpack_from(code, _SCALE.size)
+        norm = sum(c * c for c in codes)
+        if not query_norm or not norm:
+            return 0.0
+        dot = sum(q * c for q, c in zip(query, codes))
+        return dot / math.sqrt(query_norm * norm)
+
+
+class ProductQuantizer:
+    """Byte codes indexing per-subvector codebooks trained with k-means."""
+
+    def __init__(
+        self,
+        dimension: int,
+        subvectors: int,
+        centroids: int = 256,
+        codebooks: list[list[list[float]]] | None = None,
+    ):
+        if not 1 <= subvectors <= dimension:
+            raise ValueError(
+                f"Cannot split {dimension} dimensions into {subvectors} subvectors"
+            )
+        if not 1 <= centroids <= 256:
+            raise ValueError("A codebook holds 1 to 256 centroids")
+        self.dimension = dimension
+        self.subvectors = subvectors
+        self.centroids = centroids
+        self.bounds = [dimension * i // subvectors for i in range(subvectors + 1)]
+        self.codebooks = codebooks
+        self._norms: list[list[float]] = []
+        if codebooks is not None:
+            self._set_codebooks(codebooks)
+
+    @property
+    def code_size(self) -> int:
+        return self.subvectors
+
+    @property
+    def trained(self) -> bool:
+        return self.codebooks is not None
+
+    def _set_codebooks(self, codebooks: list[list[list[float]]]) -> None:
+        if len(codebooks) != self.subvectors:
+            raise ValueError(
+                f"Expected {self.subvectors} codebooks, got {len(codebooks)}"
+            )
+        self.codebooks = codebooks
+        self._norms = [[sum(x * x for x in c) for c in book] for book in codebooks]
+
+    def _parts(self, vector: Sequence[float]) -> list[Sequence[float]]:
+        b = self.bounds
+        return [vector[b[i] : b[i + 1]] for i in range(self.subvectors)]
+
+    def train(
+        self,
+        vectors: Sequence[Sequence[float]],
+        iterations: int = 10,
+        seed: int = 0,
+    ) -> None:
+        """Fit the codebooks to sample vectors."""
+        if not vectors:
+            raise ValueError("Cannot train codebooks without vectors")
+        for vector in vectors:
+            _check_finite(vector)
+        codebooks = []
+        for i in range(self.subvectors):
+            points = [
+                list(vector[self.bounds[i] : self.bounds[i + 1]]) for vector in vectors
+            ]
+            rng = random.Random(seed * 1_000_003 + i)
+            codebooks.append(_kmeans(points, self.centroids, iterations, rng))
+        self._set_codebooks(codebooks)
+
+    def _require_codebooks(self) -> list[list[list[float]]]:
+        if self.codebooks is None:
+            raise ValueError("Product quantizer is not trained")
+        return self.codebooks
+
+    def encode(self, vector: Sequence[float]) -> bytes:
+        _check_finite(vector)
+        codebooks = self._require_codebooks()
+        return bytes(
+            _nearest(part, book) for part, book in zip(self._parts(vector), codebooks)
+        )
+
+    def decode(self, code: bytes) -> list[float]:
+        codebooks = self._require_codebooks()
+        vector: list[float] = []
+        for book, index in zip(codebooks, code):
+            vector.extend(book[index])
+        return vector
+
+    def prepare(self, query: Sequence[float]) -> tuple[list[list[float]], float]:
+        """Dot products of each query subvector with every centroid."""
+        codebooks = self._require_codebooks()
+        tables = [
+            [sum(q * c for q, c in zip(part, centroid)) for centroid in book]
+            for part, book in zip(self._parts(query), codebooks)
+        ]
+        return tables, math.sqrt(sum(x * x for x in query))
+
+    def similarity(
+        self, prepared: tuple[list[list[float]], float], code: bytes
+    ) -> float:
+        # Subvectors are disjoint, so dot products and squared norms add up
+        tables, query_norm = prepared
+        dot = 0.0
+        norm = 0.0
+        for table, norms, index in zip(tables, self._norms, code):
+            dot += table[index]
+            norm += norms[index]
+        if not query_norm or not norm:
+            return 0.0
+        return dot / (query_norm * math.sqrt(norm))
+
+    def to_dict(self) -> dict[str, Any]:
+        """Serializable form (see from_dict)."""
+        return {
+            "dimension": self.dimension,
+            "subvectors": self.subvectors,
+            "centroids": self.centroids,
+            "codebooks": self.codebooks,
+        }
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> "ProductQuantizer":
+        return cls(
+            data["dimension"],
+            data["subvectors"],
+            data.get("centroids", 256),
+            codebooks=data.get("codebooks"),
+        )
+
+
+def _nearest(point: Sequence[float], centroids: Sequence[Sequence[float]]) -> int:
+    best, best_distance = 0, math.inf
+    for index, centroid in enumerate(centroids):
+        distance = sum((p - c) * (p - c) for p, c in zip(point, centroid))
+        if distance < best_distance:
+            best, best_distance = index, distance
+    return best
+
+
+def _kmeans(
+    points: list[list[float]], k: int, iterations: int, rng: random.Random
+) -> list[list[float]]:
+    """Centroids of k-means clusters (fewer when there are fewer points)."""
+    centroids = [list(p) for p in rng.sample(points, min(k, len(points)))]
+    for _ in range(iterations):
+        if np is not None:
+            data = np.asarray(points, dtype=np.float64)
+            current = np.asarray(centroids, dtype=np.float64)
+            distances = ((data[:, None, :] - current[None, :, :]) ** 2).sum(axis=2)
+            assignment = distances.argmin(axis=1).tolist()
+        else:
+            assignment = [_nearest(point, centroids) for point in points]
+        sums = [[0.0] * len(points[0]) for _ in centroids]
+        counts = [0] * len(centroids)
+        for point, index in zip(points, assignment):
+            counts[index] += 1
+            row = sums[index]
+            for d, value in enumerate(point):
+                row[d] += value
+        moved = False
+        for index, count in enumerate(counts):
+            # An empty cluster keeps its centroid
+            if count:
+                mean = [value / count for value in sums[index]]
+                moved = moved or mean != centroids[index]
+                centroids[index] = mean
+        if not moved:
+            break
+    return centroids
+
+
+def make_codec(config: QuantizationConfig | None, dimension: int) -> VectorCodec:
+    """Codec of a collection (product codebooks still untrained)."""
+    if config is None or config.mode == QuantizationMode.NONE:
+        return FixedPointCodec(dimension)
+    if config.mode == QuantizationMode.SCALAR:
+        return ScalarQuantizer(dimension)
+    subvectors = config.subvectors or max(1, dimension // 2)
+    return ProductQuantizer(dimension, min(subvectors, dimension), config.centroids)
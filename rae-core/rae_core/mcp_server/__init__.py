@@ -0,0 +1,18 @@
+"""Model Context Protocol server exposing memory tools to agents.
+
+MemoryToolset implements the tools independently of any transport;
+create_mcp_server and run_stdio expose it via the MCP SDK (requires the mcp
+extra).
+"""
+
+from rae_core.mcp_server.server import SERVER_NAME, create_mcp_server, run_stdio
+from rae_core.mcp_server.tools import MemoryToolset, SessionBindings, ToolSession
+
+__all__ = [
+    "SERVER_NAME",
+    "MemoryToolset",
+    "SessionBindings",
+    "ToolSession",
+    "create_mcp_server",
+    "run_stdio",
+]
@@ -0,0 +1,86 @@
+"""MCP SDK binding of MemoryToolset.
+
+The first tool call of an MCP session binds it to a tenant, either the fixed
+session given to create_mcp_server (stdio: one client per process) or one
+resolved from the request context (multi-client transports); the binding
+holds until the session ends.
+
+Requires the mcp extra: pip install rae-core[mcp]
+"""
+
+import json
+from collections.abc import Callable
+from typing import Any
+
+import structlog
+
+from rae_core.mcp_server.tools import MemoryToolset, SessionBindings, ToolSession
+
+try:
+    from mcp import types
+    from mcp.server.lowlevel import Server
+    from mcp.server.stdio import stdio_server
+except ImportError:
+    Server = None
+
+logger = structlog.get_logger(__name__)
+
+SERVER_NAME = "rae-mcp"
+
+
+def _require_mcp() -> None:
+    if Server is None:
+        raise ImportError(
+            "The mcp package is required for the RAE MCP server. "
+            "Install with: pip install rae-core[mcp]"
+        )
+
+
+def create_mcp_server(
+    toolset: MemoryToolset,
+    session: ToolSession | None = None,
+    resolve_session: Callable[[Any], ToolSession] | None = None,
+    name: str = SERVER_NAME,
+) -> Any:
+    """Create an MCP server exposing toolset.
+
+    Args:
+        toolset: Tools to expose
+        session: Tenant binding used for every MCP session
+        resolve_session: Called with the MCP request context on a session's
+            first tool call to pick its binding (when session is not given)
+        name: Server name announced to clients
+    """
+    _require_mcp()
+    if (session is None) == (resolve_session is None):
+        raise ValueError("Pass exactly one of session and resolve_session")
+
+    server = Server(name)
+    bindings = SessionBindings()
+
+    @server.list_tools()
+    async def list_tools() -> list[Any]:
+        return [types.Tool(**tool) for tool in toolset.tools]
+
+    @server.call_tool()
+    async def call_tool(tool: str, arguments: dict[str, Any] | None) -> list[Any]:
+        context = server.request_context
+        if not bindings.is_bound(context.session):
+            bound = session if session is not None else resolve_session(context)
+            bindings.bind(context.session, bound)
+            logger.info("mcp_session_bound", tenant_id=bound.tenant_id)
+        result = await toolset.call(tool, arguments, bindings.get(context.session))
+        return [types.TextContent(type="text", text=json.dumps(result))]
+
+    return server
+
+
+async def run_stdio(
+    toolset: MemoryToolset, session: ToolSession, name: str = SERVER_NAME
+) -> None:
+    """Serve toolset to a single MCP client over stdin/stdout."""
+    server = create_mcp_server(toolset, session=session, name=name)
+    async with stdio_server() as (read_stream, write_stream):
+        await server.run(
+            read_stream, write_stream, server.create_initialization_options()
+        )
@@ -0,0 +1,291 @@
+"""Transport-independent MCP tools backed by RAEEngine.
+
+Tools: memory.store, memory.search, memory.get_context and (with a graph
+store) graph.related. Tool arguments never carry a tenant: every call runs
+in the ToolSession its MCP session was bound to, and arguments naming fields
+outside a tool's input schema are rejected.
+"""
+
+import weakref
+from dataclasses import dataclass
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, ConfigDict, Field
+from pydantic import ValidationError as PydanticValidationError
+
+from rae_core.context.builder import ContextBuilder, ContextFormat
+from rae_core.exceptions.base import SecurityPolicyViolationError, ValidationError
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.models.memory import memory_confidence
+from rae_core.rpc.service import to_wire
+
+
+@dataclass(frozen=True)
+class ToolSession:
+    """Tenant and agent an MCP session is bound to."""
+
+    tenant_id: str
+    agent_id: str = "default"
+    project: str | None = None
+
+
+class SessionBindings:
+    """Binds MCP sessions to a ToolSession for their lifetime.
+
+    Sessions are held weakly, so a binding disappears with its session.
+    """
+
+    def __init__(self) -> None:
+        self._bindings: weakref.WeakKeyDictionary[Any, ToolSession] = (
+            weakref.WeakKeyDictionary()
+        )
+
+    def bind(self, session: Any, tool_session: ToolSession) -> ToolSession:
+        """Bind session; rebinding to another tenant is refused.
+
+        Raises:
+            SecurityPolicyViolationError: session is bound to another tenant
+        """
+        current = self._bindings.get(session)
+        if current is not None:
+            if current.tenant_id != tool_session.tenant_id:
+                raise SecurityPolicyViolationError(
+                    f"MCP session is already bound to tenant {current.tenant_id}"
+                )
+            return current
+        self._bindings[session] = tool_session
+        return tool_session
+
+    def get(self, session: Any) -> ToolSession:
+        """Binding of session.
+
+        Raises:
+            SecurityPolicyViolationError: session is not bound
+        """
+        tool_session = self._bindings.get(session)
+        if tool_session is None:
+            raise SecurityPolicyViolationError("MCP session is not bound to a tenant")
+        return tool_session
+
+    def is_bound(self, session: Any) -> bool:
+        return session in self._bindings
+
+    def unbind(self, session: Any) -> bool:
+        return self._bindings.pop(session, None) is not None
+
+
+class _ToolArgs(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+
+
+class StoreArgs(_ToolArgs):
+    """Arguments of memory.store."""
+
+    content: str = Field(min_length=1, description="Text to remember")
+    layer: str = Field(default="episodic", description="Memory layer")
+    tags: list[str] = Field(default_factory=list)
+    metadata: dict[str, Any] = Field(default_factory=dict)
+    importance: float | None = Field(default=None, ge=0.0, le=1.0)
+    confidence: float | None = Field(
+        default=None, ge=0.0, le=1.0, description="How likely it is to be true"
+    )
+    project: str | None = None
+    namespace: str | None = None
+
+
+class SearchArgs(_ToolArgs):
+    """Arguments of memory.search."""
+
+    query: str = Field(min_length=1)
+    top_k: int = Field(default=5, ge=1, le=100)
+    layer: str | None = None
+    project: str | None = None
+    namespace: str | None = None
+
+
+class ContextArgs(_ToolArgs):
+    """Arguments of memory.get_context."""
+
+    query: str = Field(min_length=1)
+    top_k: int = Field(default=10, ge=1, le=100)
+    max_tokens: int = Field(default=2000, ge=1)
+    format: ContextFormat = ContextFormat.CONVERSATIONAL
+    project: str | None = None
+    namespace: str | None = None
+    min_confidence: float | None = Field(
+        default=None, ge=0.0, le=1.0, description="Leave out less certain memories"
+    )
+
+
+class RelatedArgs(_ToolArgs):
+    """Arguments of graph.related."""
+
+    memory_id: UUID
+    depth: int = Field(default=1, ge=1, le=5)
+    edge_type: str | None = None
+    limit: int = Field(default=10, ge=1, le=100)
+
+
+def _summary(memory: dict[str, Any]) -> dict[str, Any]:
+    summary = {
+        "id": memory.get("id"),
+        "content": memory.get("content"),
+        "layer": memory.get("layer"),
+        "tags": memory.get("tags") or [],
+    }
+    if memory.get("score") is not None:
+        summary["score"] = memory["score"]
+    confidence = memory_confidence(memory)
+    if confidence < 1.0:
+        summary["confidence"] = confidence
+    return summary
+
+
+@dataclass(frozen=True)
+class _Tool:
+    name: str
+    description: str
+    args: type[_ToolArgs]
+
+
+class MemoryToolset:
+    """Memory tools for MCP clients.
+
+    graph.related is only available when a graph store is configured.
+    """
+
+    def __init__(self, engine: Any, graph_store: IGraphStore | None = None):
+        self.engine = engine
+        self.graph_store = graph_store
+        self._tools = {
+            "memory.store": (
+                _Tool("memory.store", "Store a memory for later recall.", StoreArgs),
+                self.store,
+            ),
+            "memory.search": (
+                _Tool(
+                    "memory.search",
+                    "Search memories relevant to a query.",
+                    SearchArgs,
+                ),
+                self.search,
+            ),
+            "memory.get_context": (
+                _Tool(
+                    "memory.get_context",
+                    "Relevant memories assembled into a token-bounded context.",
+                    ContextArgs,
+                ),
+                self.get_context,
+            ),
+        }
+        if graph_store is not None:
+            self._tools["graph.related"] = (
+                _Tool(
+                    "graph.related",
+                    "Memories connected to a memory in the knowledge graph.",
+                    RelatedArgs,
+                ),
+                self.related,
+            )
+
+    @property
+    def tools(self) -> list[dict[str, Any]]:
+        """MCP tool descriptors (name, description, inputSchema)."""
+        return [
+            {
+                "name": tool.name,
+                "description": tool.description,
+                "inputSchema": tool.args.model_json_schema(),
+            }
+            for tool, _ in self._tools.values()
+        ]
+
+    async def call(
+        self, name: str, arguments: dict[str, Any] | None, session: ToolSession
+    ) -> dict[str, Any]:
+        """Run one tool in session.
+
+        Raises:
+            ValidationError: Unknown tool or invalid arguments
+        """
+        entry = self._tools.get(name)
+        if entry is None:
+            raise ValidationError(f"Unknown tool: {name}")
+        tool, handler = entry
+        try:
+            args = tool.args.model_validate(arguments or {})
+        except PydanticValidationError as e:
+            raise ValidationError(f"Invalid arguments for {name}: {e}") from e
+        return to_wire(await handler(args, session))
+
+    async def store(self, args: StoreArgs, session: ToolSession) -> dict[str, Any]:
+        fields = args.model_dump(exclude_none=True)
+        if args.project is None and session.project is not None:
+            fields["project"] = session.project
+        memory_id = await self.engine.store_memory(
+            tenant_id=session.tenant_id, agent_id=session.agent_id, **fields
+        )
+        return {"id": memory_id, "stored": memory_id is not None}
+
+    async def search(self, args: SearchArgs, session: ToolSession) -> dict[str, Any]:
+        result = await self.engine.recall(
+            args.query,
+            session.tenant_id,
+            agent_id=session.agent_id,
+            layer=args.layer,
+            top_k=args.top_k,
+            project=args.project or session.project,
+            namespace=args.namespace,
+        )
+        return {
+            "memories": [_summary(m) for m in result.memories],
+            "no_relevant_memory": result.no_relevant_memory,
+        }
+
+    async def get_context(
+        self, args: ContextArgs, session: ToolSession
+    ) -> dict[str, Any]:
+        memories = await self.engine.search_memories(
+            args.query,
+            session.tenant_id,
+            agent_id=session.agent_id,
+            top_k=args.top_k,
+            project=args.project or session.project,
+            namespace=args.namespace,
+        )
+        builder = ContextBuilder(max_tokens=args.max_tokens)
+        context, metadata = builder.build_context(
+            memories,
+            query=args.query,
+            format_type=args.format,
+            min_confidence=args.min_confidence,
+        )
+        return {
+            "context": context,
+            "memory_ids": [m.get("id") for m in memories],
+            "token_usage": metadata.token_usage,
+            "truncated": metadata.statistics.get("truncated", False),
+        }
+
+    async def related(self, args: RelatedArgs, session: ToolSession) -> dict[str, Any]:
+        assert self.graph_store is not None
+        node_ids = await self.graph_store.get_neighbors(
+            args.memory_id,
+            session.tenant_id,
+            edge_type=args.edge_type,
+            max_depth=args.depth,
+        )
+        related = []
+        for node_id in node_ids:
+            if node_id == args.memory_id:
+                continue
+            memory = await self.engine.memory_storage.get_memory(
+                node_id, session.tenant_id
+            )
+            if memory is not None:
+                related.append(_summary(memory))
+            if len(related) >= args.limit:
+                break
+        return {"memory_id": args.memory_id, "related": related}
@@ -0,0 +1,29 @@
+"""Metrics for RAE-core: a dependency-free registry and backend instrumentation."""
+
+from rae_core.metrics.instrumentation import (
+    InstrumentedComponent,
+    InstrumentedStorage,
+    InstrumentedVectorStore,
+)
+from rae_core.metrics.registry import (
+    REGISTRY,
+    Counter,
+    Gauge,
+    Histogram,
+    MetricFamily,
+    MetricsRegistry,
+    Sample,
+)
+
+__all__ = [
+    "REGISTRY",
+    "Counter",
+    "Gauge",
+    "Histogram",
+    "MetricFamily",
+    "MetricsRegistry",
+    "Sample",
+    "InstrumentedComponent",
+    "InstrumentedStorage",
+    "InstrumentedVectorStore",
+]
@@ -0,0 +1,229 @@
+"""Instrumentation of storage, vector, graph, cache and search components.
+
+InstrumentedComponent wraps any backend and records, for every coroutine
+method called through it:
+
+- rae_operations_total{component, operation, status}: calls, by outcome
+  ("ok" or "error"); the error rate is the "error" share
+- rae_operation_errors_total{component, operation, error}: failures by
+  exception type
+- rae_operation_duration_seconds{component, operation}: latency histogram
+
+InstrumentedStorage additionally keeps rae_tenant_memories{tenant_id}, the
+live memory count of each tenant it has written to, and
+InstrumentedVectorStore records rae_vector_search_results{component}, the
+number of hits returned by each similarity search (the recall depth).
+
+    storage = InstrumentedStorage(SQLiteStorage("rae.db"))
+    vectors = InstrumentedVectorStore(QdrantVectorStore(...))
+    print(REGISTRY.render_prometheus())
+"""
+
+import functools
+import inspect
+import time
+from collections.abc import Callable
+from typing import Any
+
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.metrics.registry import REGISTRY, MetricsRegistry
+
+# Hits returned by a similarity search
+RECALL_DEPTH_BUCKETS = (0, 1, 3, 5, 10, 20, 50, 100)
+
+# Writes changing a tenant's memory count by one
+_COUNT_DELTAS = {
+    "store_memory": 1,
+    "restore_memory": 1,
+    "soft_delete_memory": -1,
+}
+
+# Writes after which a tenant's memory count is re-read from the backend
+# (a hard delete may remove a memory that was already in the trash)
+_RECOUNTED = frozenset(
+    {
+        "delete_memory",
+        "purge_deleted",
+        "delete_memories_with_metadata_filter",
+        "delete_memories_below_importance",
+        "delete_expired_memories",
+        "clear_tenant",
+    }
+)
+
+# Position of tenant_id among the positional arguments of each write
+_TENANT_ARG = {
+    "restore_memory": 1,
+    "soft_delete_memory": 1,
+    "delete_memory": 1,
+    "purge_deleted": 1,
+    "delete_memories_with_metadata_filter": 0,
+    "delete_memories_below_importance": 0,
+    "delete_expired_memories": 0,
+    "clear_tenant": 0,
+}
+
+
+class InstrumentedComponent:
+    """Wraps a backend and records metrics for its coroutine methods.
+
+    Synchronous attributes are forwarded unchanged.
+    """
+
+    def __init__(
+        self,
+        component: Any,
+        name: str,
+        registry: MetricsRegistry | None = None,
+    ):
+        """Initialize the wrapper.
+
+        Args:
+            component: Backend receiving the calls
+            name: Value of the "component" label (e.g. "storage", "vector")
+            registry: Registry receiving the metrics (REGISTRY if None)
+        """
+        self.component = component
+        self.component_name = name
+        self.registry = registry or REGISTRY
+        self.operations = self.registry.counter(
+            "rae_operations_total",
+            "Backend operations by component, operation and status",
+            ("component", "operation", "status"),
+        )
+        self.errors = self.registry.counter(
+            "rae_operation_errors_total",
+            "Failed backend operations by exception type",
+            ("component", "operation", "error"),
+        )
+        self.latency = self.registry.histogram(
+            "rae_operation_duration_seconds",
+            "Latency of backend operations",
+            ("component", "operation"),
+        )
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self.component, name)
+        if name.startswith("_") or not inspect.iscoroutinefunction(attr):
+            return attr
+        return self._timed(name, attr)
+
+    def _timed(
+        self, operation: str, method: Callable[..., Any]
+    ) -> Callable[..., Any]:
+        @functools.wraps(method)
+        async def timed(*args: Any, **kwargs: Any) -> Any:
+            labels = {"component": self.component_name, "operation": operation}
+            start = time.perf_counter()
+            try:
+                result = await method(*args, **kwargs)
+            except Exception as e:
+                self.operations.inc(**labels, status="error")
+                self.errors.inc(**labels, error=type(e).__name__)
+                raise
+            finally:
+                self.latency.observe(time.perf_counter() - start, **labels)
+            self.operations.inc(**labels, status="ok")
+            await self._after(operation, args, kwargs, result)
+            return result
+
+        return timed
+
+    async def _after(
+        self,
+        operation: str,
+        args: tuple[Any, ...],
+        kwargs: dict[str, Any],
+        result: Any,
+    ) -> None:
+        """Hook run after a successful call."""
+
+
+def _tenant_of(operation: str, args: tuple[Any, ...], kwargs: dict[str, Any]) -> Any:
+    """Tenant argument of an IMemoryStorage write, wherever it was passed."""
+    if "tenant_id" in kwargs:
+        return kwargs["tenant_id"]
+    position = _TENANT_ARG.get(operation)
+    if position is None or len(args) <= position:
+        return None
+    return args[position]
+
+
+class InstrumentedStorage(InstrumentedComponent):
+    """Instrumented IMemoryStorage that also tracks per-tenant memory counts.
+
+    A tenant's count is read with count_memories the first time the wrapper
+    writes to it and after hard or bulk deletions; other writes adjust it in
+    place.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        registry: MetricsRegistry | None = None,
+        name: str = "storage",
+    ):
+        super().__init__(storage, name, registry)
+        self.tenant_memories = self.registry.gauge(
+            "rae_tenant_memories",
+            "Live memories per tenant",
+            ("tenant_id",),
+        )
+        self._tenants: set[str] = set()
+
+    async def refresh_tenant(self, tenant_id: str) -> int:
+        """Re-read a tenant's memory count from the backend."""
+        count = int(await self.component.count_memories(tenant_id=tenant_id))
+        self.tenant_memories.set(count, tenant_id=tenant_id)
+        self._tenants.add(tenant_id)
+        return count
+
+    async def _after(
+        self,
+        operation: str,
+        args: tuple[Any, ...],
+        kwargs: dict[str, Any],
+        result: Any,
+    ) -> None:
+        if operation not in _COUNT_DELTAS and operation not in _RECOUNTED:
+            return
+        tenant_id = _tenant_of(operation, args, kwargs)
+        if tenant_id is None:
+            # Tenant-wide bulk writes (purge_deleted) affect every tenant
+            if operation in _RECOUNTED:
+                for tracked in list(self._tenants):
+                    await self.refresh_tenant(tracked)
+            return
+        if operation in _RECOUNTED or tenant_id not in self._tenants:
+            await self.refresh_tenant(tenant_id)
+        elif result:
+            self.tenant_memories.inc(_COUNT_DELTAS[operation], tenant_id=tenant_id)
+
+
+class InstrumentedVectorStore(InstrumentedComponent):
+    """Instrumented IVectorStore that also records search recall depth."""
+
+    def __init__(
+        self,
+        vector_store: IVectorStore,
+        registry: MetricsRegistry | None = None,
+        name: str = "vector",
+    ):
+        super().__init__(vector_store, name, registry)
+        self.recall_depth = self.registry.histogram(
+            "rae_vector_search_results",
+            "Hits returned by similarity searches",
+            ("component",),
+            buckets=RECALL_DEPTH_BUCKETS,
+        )
+
+    async def _after(
+        self,
+        operation: str,
+        args: tuple[Any, ...],
+        kwargs: dict[str, Any],
+        result: Any,
+    ) -> None:
+        if operation.startswith("search") and isinstance(result, list):
+            self.recall_depth.observe(len(result), component=self.component_name)
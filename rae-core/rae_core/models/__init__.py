@@ -2,44 +2,178 @@
 
 This module exports all Pydantic models used across RAE-core:
 - Memory models: MemoryItem, MemoryLayer, MemoryType, etc.
-- Search models: SearchQuery, SearchStrategy, SearchResult, etc.
+- Search models: SearchQuery, SearchStrategy, SearchResult, SamplingWeighting, etc.
 - Graph models: GraphNode, GraphEdge, NodeType, EdgeType, etc.
 - Reflection models: Reflection, ReflectionType, ReflectionPolicy
 - Sync models: SyncChange, SyncOperation, SyncState, SyncConflict
+- Audit models: AuditEntry, AuditOperation
+- Outbox models: OutboxEntry
+- Query models: MemoryFilter, MemoryQuery
+- Plan models: WritePlan, PlannedRecord, PlannedVector, PlannedNode, PlannedEdge
+- Quota models: TenantQuota, QuotaUsage, QuotaResource
+- Load models: LoadLimits, LoadStats, PriorityClass
+- Health models: ComponentHealth, HealthStatus, SystemHealthReport
+- Budget models: AgentBudget, AgentBudgetReport, BudgetResource
+- Template models: MemoryTemplate, TemplateField, TemplateFieldType
+- Tenant models: TenantEmbeddingConfig
+- Embedding models: EmbeddingProviderConfig, EmbeddingSchema
+- Pipeline models: PipelineSpec, PipelineStage, PipelineResult,
+  PipelineExperiment, VariantMetrics
+- Subject models: SubjectReport, SubjectRelationship, ErasureMode,
+  ErasureResult
+- Code models: CodeIngestReport
+- Consistency models: Contradiction, ConflictResolution, ResolutionResult
+- Provenance models: MemorySource, SourceType, OriginStep
+- Namespace models: Namespace, NamespaceDeletion
+- Conversation models: ConversationTurn, ConversationRole,
+  ConversationIngestConfig, ConversationIngestRequest,
+  ConversationIngestReport
+- Stream models: IngestItem, IngestFailure, StreamIngestSummary
+- Simulation models: WorkloadSpec, OperationStats, SimulationReport
+- Quantization models: QuantizationConfig, QuantizationMode
 """
 
-from .graph import EdgeType, GraphEdge, GraphNode, GraphPath, NodeType, Subgraph
-from .memory import MemoryItem, MemoryLayer, MemoryStats, MemoryType, ScoredMemoryItem
+from .audit import AuditEntry, AuditOperation
+from .budget import AgentBudget, AgentBudgetReport, BudgetResource
+from .embedding import EmbeddingProviderConfig, EmbeddingSchema
+from .graph import (
+    EdgeSampling,
+    EdgeType,
+    GraphEdge,
+    GraphNode,
+    GraphPath,
+    NodeType,
+    Subgraph,
+    TraversalLimits,
+)
+from .card import CardSource, MemoryCard
+from .code import CodeIngestReport
+from .consistency import ConflictResolution, Contradiction, ResolutionResult
+from .conversation import (
+    ConversationIngestConfig,
+    ConversationIngestReport,
+    ConversationIngestRequest,
+    ConversationRole,
+    ConversationTurn,
+)
+from .health import ComponentHealth, HealthStatus, SystemHealthReport
+from .load import LoadLimits, LoadStats, PriorityClass
+from .memory import (
+    CONFIDENCE_KEY,
+    Attachment,
+    MemoryItem,
+    MemoryLayer,
+    MemoryStats,
+    MemoryType,
+    ScoredMemoryItem,
+    derived_confidence,
+    memory_confidence,
+)
+from .namespace import Namespace, NamespaceDeletion
+from .outbox import OutboxEntry
+from .pipeline import (
+    PipelineExperiment,
+    PipelineResult,
+    PipelineSpec,
+    PipelineStage,
+    VariantMetrics,
+)
+from .plan import (
+    PlannedEdge,
+    PlannedNode,
+    PlannedOperation,
+    PlannedRecord,
+    PlannedVector,
+    WritePlan,
+)
+from .provenance import MemorySource, OriginStep, SourceType
+from .query import MemoryFilter, MemoryQuery
+from .quota import QuotaResource, QuotaUsage, TenantQuota
 from .reflection import Reflection, ReflectionPolicy, ReflectionPriority, ReflectionType
+from .retrieval import (
+    RetrievalBudget,
+    RetrievalOptions,
+    RetrievalRequest,
+    RetrievalResponse,
+    RetrievedMemory,
+    TokenUsage,
+)
 from .search import (
+    RecallResult,
+    SamplingStrategy,
+    SamplingWeighting,
+    ScoredMemory,
     ScoringWeights,
     SearchQuery,
     SearchResponse,
     SearchResult,
     SearchStrategy,
+    SimilarityRecallOptions,
+)
+from .quantization import QuantizationConfig, QuantizationMode
+from .simulation import OperationStats, SimulationReport, WorkloadSpec
+from .stream import IngestFailure, IngestItem, StreamIngestSummary
+from .subject import (
+    ErasureMode,
+    ErasureResult,
+    SubjectRelationship,
+    SubjectReport,
 )
 from .sync import SyncChange, SyncConflict, SyncOperation, SyncState
+from .template import MemoryTemplate, TemplateField, TemplateFieldType
+from .tenant import TenantEmbeddingConfig
 
 __all__ = [
     # Memory models
     "MemoryItem",
+    "Attachment",
     "MemoryLayer",
     "MemoryType",
     "ScoredMemoryItem",
     "MemoryStats",
+    "CONFIDENCE_KEY",
+    "memory_confidence",
+    "derived_confidence",
     # Search models
     "SearchQuery",
     "SearchStrategy",
     "SearchResult",
     "SearchResponse",
     "ScoringWeights",
+    "RecallResult",
+    "RetrievalRequest",
+    "RetrievalResponse",
+    "RetrievalBudget",
+    "RetrievalOptions",
+    "CodeIngestReport",
+    "ConversationTurn",
+    "ConversationRole",
+    "ConversationIngestConfig",
+    "ConversationIngestRequest",
+    "ConversationIngestReport",
+    "IngestItem",
+    "IngestFailure",
+    "StreamIngestSummary",
+    "WorkloadSpec",
+    "OperationStats",
+    "SimulationReport",
+    "QuantizationConfig",
+    "QuantizationMode",
+    "RetrievedMemory",
+    "TokenUsage",
+    "SamplingStrategy",
+    "SamplingWeighting",
+    "ScoredMemory",
+    "SimilarityRecallOptions",
     # Graph models
     "GraphNode",
     "GraphEdge",
     "NodeType",
     "EdgeType",
+    "EdgeSampling",
     "GraphPath",
     "Subgraph",
+    "TraversalLimits",
     # Reflection models
     "Reflection",
     "ReflectionType",
@@ -50,4 +184,69 @@ __all__ = [
     "SyncOperation",
     "SyncState",
     "SyncConflict",
+    # Audit models
+    "AuditEntry",
+    "AuditOperation",
+    # Outbox models
+    "OutboxEntry",
+    # Query models
+    "MemoryFilter",
+    "MemoryQuery",
+    # Plan models
+    "WritePlan",
+    "PlannedRecord",
+    "PlannedVector",
+    "PlannedNode",
+    "PlannedEdge",
+    "PlannedOperation",
+    # Quota models
+    "TenantQuota",
+    "QuotaUsage",
+    "QuotaResource",
+    # Card models
+    "MemoryCard",
+    "CardSource",
+    # Subject models
+    "SubjectReport",
+    "SubjectRelationship",
+    "ErasureMode",
+    "ErasureResult",
+    # Load models
+    "LoadLimits",
+    "LoadStats",
+    "PriorityClass",
+    # Health models
+    "ComponentHealth",
+    "HealthStatus",
+    "SystemHealthReport",
+    # Budget models
+    "AgentBudget",
+    "AgentBudgetReport",
+    "BudgetResource",
+    # Template models
+    "MemoryTemplate",
+    "TemplateField",
+    "TemplateFieldType",
+    # Tenant models
+    "TenantEmbeddingConfig",
+    # Embedding models
+    "EmbeddingProviderConfig",
+    "EmbeddingSchema",
+    # Pipeline models
+    "PipelineSpec",
+    "PipelineStage",
+    "PipelineResult",
+    "PipelineExperiment",
+    "VariantMetrics",
+    # Consistency models
+    "Contradiction",
+    "ConflictResolution",
+    "ResolutionResult",
+    # Provenance models
+    "MemorySource",
+    "SourceType",
+    "OriginStep",
+    # Namespace models
+    "Namespace",
+    "NamespaceDeletion",
 ]
@@ -0,0 +1,38 @@
+"""Audit log models for RAE-core."""
+
+from datetime import datetime, timezone
+from enum import Enum
+from typing import Any
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, Field
+
+
+class AuditOperation(str, Enum):
+    """Kind of mutation recorded in the audit log."""
+
+    STORE = "store"
+    UPDATE = "update"
+    DELETE = "delete"
+    SOFT_DELETE = "soft_delete"
+    RESTORE = "restore"
+    PROMOTE = "promote"
+    # Removal on a data subject's request (see governance.privacy)
+    ERASE = "erase"
+
+
+class AuditEntry(BaseModel):
+    """A single recorded mutation."""
+
+    id: UUID = Field(default_factory=uuid4)
+    timestamp: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
+    tenant_id: str
+    actor: str | None = Field(
+        default=None, description="User or service that performed the mutation"
+    )
+    operation: AuditOperation
+    memory_id: UUID
+    diff: list[dict[str, Any]] = Field(
+        default_factory=list, description="Field-level old/new values"
+    )
+    metadata: dict[str, Any] = Field(default_factory=dict)
@@ -0,0 +1,69 @@
+"""Per-agent budget models for RAE-core."""
+
+from enum import Enum
+
+from pydantic import BaseModel, Field, field_validator
+
+
+class BudgetResource(str, Enum):
+    """Resource tracked by an agent budget."""
+
+    MEMORIES = "memories"
+    BYTES = "bytes"
+    EMBEDDING_SPEND = "embedding_spend"
+
+
+class AgentBudget(BaseModel):
+    """Soft limits of an agent within its tenant; None means untracked.
+
+    Budgets never block writes (tenant quotas do that). Crossing one of the
+    thresholds, given as fractions of a limit, emits a BudgetThresholdCrossed
+    event.
+    """
+
+    max_memories: int | None = Field(
+        default=None, ge=0, description="Live memories written by the agent"
+    )
+    max_bytes: int | None = Field(
+        default=None, ge=0, description="Total UTF-8 size of the agent's content"
+    )
+    max_embedding_spend: float | None = Field(
+        default=None, ge=0, description="Estimated embedding cost (currency units)"
+    )
+    thresholds: list[float] = Field(
+        default_factory=lambda: [0.8, 1.0],
+        description="Fractions of a limit that trigger an alert",
+    )
+
+    @field_validator("thresholds")
+    @classmethod
+    def _sorted_positive(cls, thresholds: list[float]) -> list[float]:
+        if any(t <= 0 for t in thresholds):
+            raise ValueError("Budget thresholds must be positive")
+        return sorted(set(thresholds))
+
+    def limit(self, resource: BudgetResource) -> float | None:
+        return {
+            BudgetResource.MEMORIES: self.max_memories,
+            BudgetResource.BYTES: self.max_bytes,
+            BudgetResource.EMBEDDING_SPEND: self.max_embedding_spend,
+        }[resource]
+
+
+class AgentBudgetReport(BaseModel):
+    """Consumption of an agent measured against its budget."""
+
+    tenant_id: str
+    agent_id: str
+    memories: int = 0
+    bytes: int = 0
+    embeddings: int = 0
+    embedding_spend: float = 0.0
+    utilization: dict[BudgetResource, float] = Field(
+        default_factory=dict, description="Usage as a fraction of each set limit"
+    )
+
+    @property
+    def exceeded(self) -> list[BudgetResource]:
+        """Resources at or over their limit."""
+        return [r for r, used in self.utilization.items() if used >= 1.0]
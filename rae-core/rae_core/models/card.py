@@ -0,0 +1,41 @@
+"""User-facing memory card models for RAE-core."""
+
+from datetime import datetime
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+
+class CardSource(BaseModel):
+    """A memory that a card's memory was derived from."""
+
+    memory_id: UUID
+    layer: str | None = None
+    excerpt: str = Field(description="Short extract of the source content")
+    created_at: datetime | None = None
+
+
+class MemoryCard(BaseModel):
+    """What the system remembers, in a form that can be shown to an end user."""
+
+    memory_id: UUID
+    title: str
+    summary: str
+    layer: str
+    tags: list[str] = Field(default_factory=list)
+    annotations: list[str] = Field(
+        default_factory=list, description="Notes attached to the memory"
+    )
+    sources: list[CardSource] = Field(
+        default_factory=list, description="Memories this one was derived from"
+    )
+    origin: str | None = Field(
+        default=None, description="Where the memory came from (e.g. 'user_input')"
+    )
+    confidence: float | None = Field(
+        default=None, ge=0.0, le=1.0, description="None when nothing recorded one"
+    )
+    created_at: datetime | None = None
+    updated_at: datetime | None = None
+    last_accessed_at: datetime | None = None
+    expires_at: datetime | None = None
@@ -0,0 +1,21 @@
+"""Code ingestion models; see ingestion.code.CodeIngestor."""
+
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+
+class CodeIngestReport(BaseModel):
+    """Outcome of ingesting source files."""
+
+    files: int = Field(default=0, description="Source files ingested")
+    memory_ids: list[UUID] = Field(
+        default_factory=list, description="Symbol memories, in file order"
+    )
+    symbols: dict[str, UUID] = Field(
+        default_factory=dict, description="Memory of each path::qualified_name"
+    )
+    edges: int = Field(default=0, description="Graph edges created")
+    skipped: list[str] = Field(
+        default_factory=list, description="Paths skipped (unknown language)"
+    )
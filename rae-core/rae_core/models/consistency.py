@@ -0,0 +1,45 @@
+"""Consistency models for RAE-core."""
+
+from datetime import datetime
+from enum import Enum
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+# Metadata key naming the memory that superseded a memory; superseded
+# memories are left out of recall unless include_superseded is set
+SUPERSEDED_KEY = "superseded_by"
+
+
+class Contradiction(BaseModel):
+    """Two memories flagged as stating conflicting facts."""
+
+    memory_id: UUID = Field(description="Memory whose store raised the conflict")
+    conflicting_id: UUID = Field(description="Existing memory it contradicts")
+    score: float = Field(ge=0.0, le=1.0, description="Judge's contradiction score")
+    similarity: float | None = Field(
+        default=None, description="Vector similarity of the two memories"
+    )
+    detected_at: datetime | None = None
+
+
+class ConflictResolution(str, Enum):
+    """How a pair of contradicting memories is resolved."""
+
+    # The first memory is right; the second is superseded by it
+    SUPERSEDE = "supersede"
+    # Both are replaced by one merged memory superseding them
+    MERGE = "merge"
+    # Both stay valid (e.g. true in different contexts)
+    KEEP_BOTH = "keep_both"
+
+
+class ResolutionResult(BaseModel):
+    """Outcome of resolving a conflict."""
+
+    resolution: ConflictResolution
+    kept_ids: list[UUID] = Field(default_factory=list)
+    superseded_ids: list[UUID] = Field(default_factory=list)
+    merged_id: UUID | None = Field(
+        default=None, description="Memory stored by a merge"
+    )
@@ -0,0 +1,84 @@
+"""Conversation ingestion models.
+
+A conversation arrives as a list of role-tagged turns; see
+ingestion.conversation.ConversationIngestor.
+"""
+
+from datetime import datetime
+from enum import Enum
+from uuid import UUID
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class ConversationRole(str, Enum):
+    """Speaker of a conversation turn."""
+
+    USER = "user"
+    ASSISTANT = "assistant"
+    SYSTEM = "system"
+    TOOL = "tool"
+
+
+class ConversationTurn(BaseModel):
+    """One message of a conversation."""
+
+    role: ConversationRole
+    content: str = Field(min_length=1)
+    timestamp: datetime | None = Field(
+        default=None, description="When the message was sent"
+    )
+    name: str | None = Field(
+        default=None, description="Speaker name (e.g. the tool called)"
+    )
+
+
+class ConversationIngestConfig(BaseModel):
+    """How turns are turned into memories."""
+
+    turns_per_memory: int = Field(
+        default=2, ge=1, description="Consecutive turns stored as one memory"
+    )
+    layer: str = Field(default="working", description="Layer of the turn memories")
+    summarize_every: int | None = Field(
+        default=None,
+        ge=1,
+        description="Summarize every N turns (no summaries if None)",
+    )
+    summary_layer: str = Field(
+        default="episodic", description="Layer of the summaries"
+    )
+    summary_max_length: int = Field(
+        default=500, ge=1, description="Maximum summary length in characters"
+    )
+
+
+class ConversationIngestRequest(BaseModel):
+    """Conversation to ingest (body of POST /v1/conversations)."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    turns: list[ConversationTurn] = Field(min_length=1)
+    agent_id: str = "default"
+    session_id: str | None = Field(
+        default=None, description="Session of the memories (new one if None)"
+    )
+    conversation_id: str | None = Field(
+        default=None, description="Conversation identifier (new one if None)"
+    )
+    summarize_every: int | None = Field(
+        default=None, ge=1, description="Override of the configured interval"
+    )
+
+
+class ConversationIngestReport(BaseModel):
+    """Outcome of ingesting a conversation."""
+
+    conversation_id: str
+    session_id: str
+    turns: int = Field(default=0, description="Turns ingested")
+    memory_ids: list[UUID] = Field(
+        default_factory=list, description="Turn memories, in conversation order"
+    )
+    summary_ids: list[UUID] = Field(default_factory=list)
+    edges: int = Field(default=0, description="Graph edges created")
@@ -0,0 +1,25 @@
+"""Embedding provider configuration models for RAE-core."""
+
+from datetime import datetime, timezone
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+
+class EmbeddingProviderConfig(BaseModel):
+    """Provider selected by name, with its constructor options."""
+
+    provider: str = Field(description="Registered provider name, e.g. 'cohere'")
+    options: dict[str, Any] = Field(
+        default_factory=dict,
+        description="Keyword arguments of the provider (model, api_key, ...)",
+    )
+
+
+class EmbeddingSchema(BaseModel):
+    """Vector dimension registered for a tenant's embedding model."""
+
+    tenant_id: str
+    model: str = Field(description="Embedding model (named vector) of the collection")
+    dimension: int = Field(ge=1)
+    registered_at: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
@@ -0,0 +1,82 @@
+"""Forgetting policy models for RAE-core."""
+
+from datetime import datetime
+from enum import Enum
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+
+class PruneAction(str, Enum):
+    """What happens to a forgotten memory."""
+
+    # Move to the trash (restorable until purged)
+    ARCHIVE = "archive"
+    # Delete the memory and its vector
+    DELETE = "delete"
+    # Move to the archive tier (see adapters.archive)
+    COLD = "cold"
+
+
+class ForgettingConfig(BaseModel):
+    """Parameters of the forgetting curve and of pruning."""
+
+    half_life_days: float = Field(
+        default=30.0,
+        gt=0,
+        description="Days until an unimportant, never-accessed memory is at 50%",
+    )
+    importance_boost: float = Field(
+        default=2.0,
+        ge=0.0,
+        description="Stability gained per unit of importance",
+    )
+    access_boost: float = Field(
+        default=1.0,
+        ge=0.0,
+        description="Stability gained per log(1 + access_count)",
+    )
+    retention_threshold: float = Field(
+        default=0.05,
+        ge=0.0,
+        le=1.0,
+        description="Memories retained less than this are forgotten",
+    )
+    protected_layers: list[str] = Field(
+        default_factory=lambda: ["semantic", "reflective"],
+        description="Layers never pruned",
+    )
+    protected_importance: float = Field(
+        default=0.9,
+        ge=0.0,
+        le=1.0,
+        description="Memories at least this important are never pruned",
+    )
+    action: PruneAction = Field(default=PruneAction.ARCHIVE)
+    interval_hours: float = Field(
+        default=24.0, gt=0, description="Time between scheduled pruning runs"
+    )
+
+
+class ForgottenMemory(BaseModel):
+    """A memory below the retention threshold."""
+
+    id: UUID
+    layer: str | None = None
+    retention: float = Field(ge=0.0, le=1.0)
+    importance: float = 0.0
+    access_count: int = 0
+
+
+class ForgettingReport(BaseModel):
+    """Outcome of a pruning run (or of a dry run)."""
+
+    tenant_id: str
+    dry_run: bool = False
+    action: PruneAction
+    scanned: int = Field(default=0, description="Memories whose retention was computed")
+    forgotten: list[ForgottenMemory] = Field(
+        default_factory=list,
+        description="Memories pruned (or that would be, in a dry run)",
+    )
+    checked_at: datetime
@@ -16,6 +16,7 @@ class NodeType(str, Enum):
     MEMORY = "memory"
     AGENT = "agent"
     EVENT = "event"
+    FILE = "file"
 
 
 class EdgeType(str, Enum):
@@ -27,7 +28,21 @@ class EdgeType(str, Enum):
     PART_OF = "part_of"
     SIMILAR_TO = "similar_to"
     CONTRADICTS = "contradicts"
+    SUPERSEDED_BY = "superseded_by"
     SUPPORTS = "supports"
+    DERIVED_FROM = "derived_from"
+    SUMMARIZES = "summarizes"
+    DEFINED_IN = "defined_in"
+    CALLS = "calls"
+
+
+class EdgeSampling(str, Enum):
+    """How neighbors are selected when a node exceeds its fan-out."""
+
+    TRUNCATE = "truncate"  # First edges in storage order
+    TOP_WEIGHT = "top_weight"  # Highest edge weight first
+    RECENCY = "recency"  # Weighted random sample favoring recent edges
+    RANDOM = "random"  # Uniform random sample
 
 
 class GraphNode(BaseModel):
@@ -66,3 +81,39 @@ class Subgraph(BaseModel):
     nodes: list[GraphNode]
     edges: list[GraphEdge]
     center_node: UUID | None = None
+
+
+class TraversalLimits(BaseModel):
+    """Safeguards applied while expanding the graph.
+
+    Protects retrieval from hub nodes with huge degree: per-node fan-out and
+    the global visit budget are truncated by default, or raise a typed
+    GraphTraversalError when strict is enabled. When a node exceeds
+    max_fan_out, `sampling` decides which of its edges are kept.
+    """
+
+    max_fan_out: int | None = Field(
+        default=None, ge=1, description="Maximum neighbors expanded per node"
+    )
+    max_visited: int | None = Field(
+        default=None, ge=1, description="Global node-visit budget per traversal"
+    )
+    fail_on_cycle: bool = Field(
+        default=False,
+        description="Raise when an edge leads back to an ancestor on the path",
+    )
+    strict: bool = Field(
+        default=False, description="Raise instead of truncating when limits are hit"
+    )
+    sampling: EdgeSampling = Field(
+        default=EdgeSampling.TRUNCATE,
+        description="Neighbor selection for nodes over max_fan_out",
+    )
+    recency_half_life_seconds: float = Field(
+        default=7 * 24 * 3600,
+        gt=0,
+        description="Edge age at which recency sampling weight halves",
+    )
+    sampling_seed: int = Field(
+        default=0, description="Seed for random/recency sampling (deterministic)"
+    )
@@ -0,0 +1,39 @@
+"""Backend health report models for RAE-core."""
+
+from datetime import datetime, timezone
+from enum import Enum
+
+from pydantic import BaseModel, Field
+
+
+class HealthStatus(str, Enum):
+    """Outcome of a component health check."""
+
+    OK = "ok"
+    UNHEALTHY = "unhealthy"
+    # The component does not implement IHealthCheck
+    UNKNOWN = "unknown"
+
+
+class ComponentHealth(BaseModel):
+    """Health of one backend."""
+
+    name: str = Field(description="Role of the component, e.g. storage")
+    backend: str = Field(description="Class name of the backend")
+    status: HealthStatus
+    latency_ms: float | None = Field(
+        default=None, description="Duration of the check (None if not checked)"
+    )
+    error: str | None = None
+
+
+class SystemHealthReport(BaseModel):
+    """Health of every backend; unhealthy if any of them is."""
+
+    status: HealthStatus
+    components: list[ComponentHealth] = Field(default_factory=list)
+    checked_at: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
+
+    @property
+    def healthy(self) -> bool:
+        return self.status is not HealthStatus.UNHEALTHY
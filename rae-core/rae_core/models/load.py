@@ -0,0 +1,41 @@
+"""Per-tenant load shedding models for RAE-core."""
+
+from enum import Enum
+
+from pydantic import BaseModel, Field
+
+
+class PriorityClass(str, Enum):
+    """Priority of an engine operation under load."""
+
+    # A user or agent is waiting on the result (recall, single stores)
+    INTERACTIVE = "interactive"
+    # Bulk work that can wait (backfills, re-embedding, imports)
+    BATCH = "batch"
+
+
+class LoadLimits(BaseModel):
+    """Concurrency limits applied to a tenant; None means unlimited."""
+
+    max_concurrent: int | None = Field(
+        default=None, ge=1, description="Operations running at once"
+    )
+    max_queued: int | None = Field(
+        default=None,
+        ge=0,
+        description="Operations waiting for a slot; further ones are rejected",
+    )
+    batch_max_concurrent: int | None = Field(
+        default=None,
+        ge=0,
+        description="Slots batch operations may use; the rest stay interactive",
+    )
+
+
+class LoadStats(BaseModel):
+    """Current load of a tenant."""
+
+    tenant_id: str
+    running: dict[PriorityClass, int] = Field(default_factory=dict)
+    queued: dict[PriorityClass, int] = Field(default_factory=dict)
+    rejected: int = 0
@@ -0,0 +1,161 @@
+"""Maintenance job models for RAE-core."""
+
+from datetime import datetime
+from enum import Enum
+from typing import Any
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, Field
+
+from rae_core.types.enums import MemoryType
+
+from .scoring import JobStatus
+
+
+class ReembedConfig(BaseModel):
+    """Configuration for re-embedding a tenant with a new model."""
+
+    batch_size: int = Field(default=64, ge=1, description="Memories per batch")
+    max_texts_per_second: float | None = Field(
+        default=None,
+        gt=0,
+        description="Throttle for provider rate limits (unlimited if None)",
+    )
+    exclusive: bool = Field(
+        default=True,
+        description="Tenant uses only the new model after the swap",
+    )
+    swap: bool = Field(
+        default=True,
+        description="Switch the tenant to the new collection when complete",
+    )
+
+
+class ReembedProgress(BaseModel):
+    """Progress report of a re-embedding job."""
+
+    job_id: UUID = Field(default_factory=uuid4)
+    tenant_id: str
+    model_name: str = Field(description="Model the tenant is re-embedded with")
+    previous_model: str | None = Field(
+        default=None, description="Active model before the swap (for rollback)"
+    )
+    status: JobStatus = Field(default=JobStatus.PENDING)
+    total: int = Field(default=0, description="Memories in the tenant")
+    processed: int = Field(default=0, description="Memories scanned so far")
+    embedded: int = Field(default=0, description="Vectors written so far")
+    swapped: bool = Field(default=False, description="New collection is active")
+    error: str | None = None
+    started_at: datetime | None = None
+    finished_at: datetime | None = None
+
+    @property
+    def fraction(self) -> float:
+        """Completed share of the job in [0, 1]."""
+        if self.status == JobStatus.COMPLETED:
+            return 1.0
+        return min(1.0, self.processed / self.total) if self.total else 0.0
+
+
+class SeedFact(BaseModel):
+    """One fact read from a seed knowledge file."""
+
+    content: str = Field(min_length=1)
+    memory_type: MemoryType = Field(default=MemoryType.TEXT)
+    tags: list[str] = Field(default_factory=list)
+    importance: float = Field(default=0.7, ge=0.0, le=1.0)
+    metadata: dict[str, Any] = Field(default_factory=dict)
+    source_file: str = Field(description="Path relative to the seed directory")
+    section: str | None = Field(
+        default=None, description="Markdown heading the fact was found under"
+    )
+
+
+class BootstrapReport(BaseModel):
+    """Outcome of bootstrapping an agent from seed files."""
+
+    tenant_id: str
+    agent_id: str
+    files: list[str] = Field(default_factory=list, description="Seed files read")
+    memory_ids: list[UUID] = Field(default_factory=list)
+    skipped: int = Field(default=0, description="Facts the engine did not store")
+    entities: int = Field(default=0, description="Distinct entities extracted")
+
+
+class ConsolidationGrouping(str, Enum):
+    """How old episodic memories are grouped into one summary."""
+
+    SESSION = "session"  # Memories of the same session_id
+    ENTITY = "entity"  # Memories naming the same first entity
+    CLUSTER = "cluster"  # Memories with similar embeddings
+
+
+class ConsolidatorConfig(BaseModel):
+    """Configuration of episodic-to-semantic consolidation."""
+
+    min_age_hours: float = Field(
+        default=168.0, gt=0, description="Only episodic memories older than this"
+    )
+    group_by: ConsolidationGrouping = Field(default=ConsolidationGrouping.SESSION)
+    min_group_size: int = Field(
+        default=2, ge=1, description="Smaller groups are left as they are"
+    )
+    max_group_size: int = Field(
+        default=50, ge=1, description="Larger groups are summarized in parts"
+    )
+    cluster_similarity: float = Field(
+        default=0.8,
+        ge=0.0,
+        le=1.0,
+        description="Cosine similarity joining a memory to a cluster",
+    )
+    summary_max_length: int = Field(
+        default=500, ge=1, description="Maximum summary length in characters"
+    )
+    max_memories: int = Field(
+        default=1000, ge=1, description="Episodic memories scanned per run"
+    )
+    archive: bool = Field(
+        default=True, description="Move consolidated originals to the trash"
+    )
+
+
+class ConsolidationReport(BaseModel):
+    """Outcome of a consolidation run."""
+
+    tenant_id: str
+    agent_id: str | None = None
+    scanned: int = Field(default=0, description="Old episodic memories found")
+    groups: int = Field(default=0, description="Groups large enough to summarize")
+    summary_ids: list[UUID] = Field(default_factory=list)
+    archived: int = Field(default=0, description="Originals moved to the trash")
+    failed: int = Field(default=0, description="Groups whose summary failed")
+
+
+class SummaryRefreshReport(BaseModel):
+    """Outcome of regenerating a tenant's stale summaries."""
+
+    tenant_id: str
+    stale: int = Field(default=0, description="Summaries flagged stale")
+    refreshed: list[UUID] = Field(
+        default_factory=list, description="Summaries regenerated from their sources"
+    )
+    removed: list[UUID] = Field(
+        default_factory=list, description="Summaries trashed as no source is left"
+    )
+    failed: int = Field(default=0, description="Summaries whose regeneration failed")
+
+
+class JobRun(BaseModel):
+    """One execution of a scheduled maintenance job for a tenant."""
+
+    job: str = Field(description="Name of the job")
+    tenant_id: str
+    status: JobStatus = Field(default=JobStatus.RUNNING)
+    scheduled_for: datetime | None = Field(
+        default=None, description="Schedule slot the run belongs to (None if manual)"
+    )
+    started_at: datetime
+    finished_at: datetime | None = None
+    result: Any = Field(default=None, description="Value returned by the job")
+    error: str | None = None
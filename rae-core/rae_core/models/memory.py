@@ -7,15 +7,37 @@ from datetime import datetime, timezone
 from typing import Any
 from uuid import UUID, uuid4
 
-from pydantic import BaseModel, ConfigDict, Field
+from pydantic import BaseModel, ConfigDict, Field, field_validator
+
+from rae_core.models.provenance import MemorySource
 
 from rae_core.types.enums import (
     InformationClass,
     MemoryLayer,
+    MemoryScope,
     MemoryType,
     OperationRiskLevel,
 )
 
+# Metadata key a memory's confidence travels under; memories without one
+# count as fully confident
+CONFIDENCE_KEY = "confidence"
+
+
+class Attachment(BaseModel):
+    """Reference to a blob (image, document, ...) attached to a memory.
+
+    Blobs are content-addressed, so memories attaching the same bytes share
+    one copy; see adapters.blobs.AttachmentStorage.
+    """
+
+    digest: str = Field(description="SHA-256 hex digest of the blob")
+    media_type: str = Field(
+        default="application/octet-stream", description="MIME type of the blob"
+    )
+    size: int = Field(ge=0, description="Blob size in bytes")
+    name: str | None = Field(default=None, description="Original file name")
+
 
 class MemoryItem(BaseModel):
     """Core memory item model.
@@ -53,15 +75,30 @@ class MemoryItem(BaseModel):
     project: str | None = Field(
         default=None, description="Project identifier (primary source)"
     )
+    namespace: str = Field(
+        default="default",
+        description="Partition of the tenant's memories (see create_namespace)",
+    )
     session_id: str | None = Field(
         default=None, description="Session identifier for grouping"
     )
+    scope: MemoryScope = Field(
+        default=MemoryScope.PRIVATE,
+        description="Agents that may recall it (see guards.SharingPolicy)",
+    )
+    team_id: str | None = Field(
+        default=None, description="Team the memory is shared with (TEAM scope)"
+    )
     tags: list[str] = Field(default_factory=list, description="Tags for filtering")
     metadata: dict[str, Any] = Field(
         default_factory=dict,
         description="Additional metadata (Szubar Mode: decision_forks, epistemic_status)",
     )
 
+    attachments: list[Attachment] = Field(
+        default_factory=list, description="Blobs attached to the memory"
+    )
+
     # Vector embedding
     embedding: list[float] | None = Field(
         default=None, description="Vector embedding for similarity search"
@@ -71,7 +108,17 @@ class MemoryItem(BaseModel):
     importance: float = Field(
         default=0.5, ge=0.0, le=1.0, description="Importance score (0.0-1.0)"
     )
+    confidence: float = Field(
+        default=1.0,
+        ge=0.0,
+        le=1.0,
+        description="How likely the memory is to be true (unlike importance, "
+        "which is how much it matters)",
+    )
     usage_count: int = Field(default=0, description="Number of times accessed")
+    version: int = Field(
+        default=1, ge=1, description="Revision number, incremented on every update"
+    )
 
     # Timestamps
     created_at: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
@@ -81,10 +128,14 @@ class MemoryItem(BaseModel):
     expires_at: datetime | None = Field(
         default=None, description="Optional expiration time for sensory memories"
     )
+    deleted_at: datetime | None = Field(
+        default=None, description="Soft-delete time; set while the memory is in trash"
+    )
 
     # Source tracking
-    source: str | None = Field(
-        default=None, description="Source of the memory (e.g., 'user_input', 'llm')"
+    source: MemorySource | None = Field(
+        default=None,
+        description="Where the memory came from (free-text labels become refs)",
     )
     context: dict[str, Any] | None = Field(
         default=None, description="Context when memory was created"
@@ -100,6 +151,11 @@ class MemoryItem(BaseModel):
         description="Sync protocol data (e.g., version, path, conflict info)",
     )
 
+    @field_validator("source", mode="before")
+    @classmethod
+    def _coerce_source(cls, value: Any) -> MemorySource | None:
+        return MemorySource.coerce(value)
+
     model_config = ConfigDict(
         json_schema_extra={
             "example": {
@@ -117,6 +173,29 @@ class MemoryItem(BaseModel):
     )
 
 
+def memory_confidence(memory: dict[str, Any]) -> float:
+    """Confidence of a stored memory record (1.0 when none was recorded)."""
+    value = (memory.get("metadata") or {}).get(CONFIDENCE_KEY)
+    if value is None:
+        value = memory.get("confidence")
+    try:
+        return min(1.0, max(0.0, float(value)))
+    except (TypeError, ValueError):
+        return 1.0
+
+
+def derived_confidence(memories: list[dict[str, Any]], factor: float) -> float:
+    """Confidence of a memory derived from others.
+
+    A summary or reflection is no surer than its sources: it gets their
+    mean confidence, reduced by factor for the derivation itself.
+    """
+    if not memories:
+        return factor
+    mean = sum(memory_confidence(m) for m in memories) / len(memories)
+    return round(mean * factor, 4)
+
+
 class ScoredMemoryItem(BaseModel):
     """Memory item with relevance score.
 
@@ -0,0 +1,44 @@
+"""Namespace models for RAE-core."""
+
+import re
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+# Metadata key a memory's namespace travels under; memories without one
+# belong to DEFAULT_NAMESPACE
+NAMESPACE_KEY = "namespace"
+DEFAULT_NAMESPACE = "default"
+NAMESPACE_PATTERN = re.compile(r"^[a-z0-9][a-z0-9._-]{0,63}$")
+
+
+def memory_namespace(memory: dict[str, Any]) -> str:
+    """Namespace of a stored memory record."""
+    namespace = (memory.get("metadata") or {}).get(NAMESPACE_KEY)
+    if namespace is None:
+        namespace = memory.get("namespace")
+    return str(namespace) if namespace else DEFAULT_NAMESPACE
+
+
+class Namespace(BaseModel):
+    """A named partition of a tenant's memories (project, domain, ...)."""
+
+    tenant_id: str
+    name: str
+    description: str | None = None
+    created_at: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
+    memory_count: int = Field(default=0, description="Memories stored in it")
+
+
+class NamespaceDeletion(BaseModel):
+    """Outcome of deleting a namespace with everything stored in it."""
+
+    tenant_id: str
+    name: str
+    memory_ids: list[UUID] = Field(
+        default_factory=list, description="Memories removed from storage"
+    )
+    vectors_deleted: int = 0
+    graph_nodes_deleted: int = 0
@@ -0,0 +1,18 @@
+"""Event outbox models for RAE-core."""
+
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+
+class OutboxEntry(BaseModel):
+    """A persisted memory event awaiting delivery to sinks."""
+
+    sequence: int = Field(description="Position in the outbox, strictly increasing")
+    event_id: UUID
+    event_type: str = Field(description="Class name of the event, e.g. MemoryStored")
+    tenant_id: str
+    payload: dict[str, Any] = Field(description="JSON-serialized event")
+    created_at: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
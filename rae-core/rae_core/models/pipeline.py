@@ -0,0 +1,104 @@
+"""Declarative retrieval pipeline models."""
+
+from typing import Any
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, ConfigDict, Field, model_validator
+
+
+class PipelineStage(BaseModel):
+    """One stage of a retrieval pipeline and its parameters.
+
+    In TOML the parameters sit next to the type:
+
+        [[stages]]
+        type = "search"
+        top_k = 30
+    """
+
+    model_config = ConfigDict(extra="forbid")
+
+    type: str = Field(description="Registered stage name, e.g. 'search'")
+    params: dict[str, Any] = Field(default_factory=dict)
+
+    @model_validator(mode="before")
+    @classmethod
+    def _inline_params(cls, data: Any) -> Any:
+        if isinstance(data, dict):
+            inline = {k: v for k, v in data.items() if k not in ("type", "params")}
+            if inline:
+                data = {
+                    "type": data.get("type"),
+                    "params": {**data.get("params", {}), **inline},
+                }
+        return data
+
+
+class PipelineSpec(BaseModel):
+    """Named, ordered list of retrieval stages."""
+
+    name: str = Field(pattern=r"^[A-Za-z0-9_.-]+$")
+    description: str | None = None
+    stages: list[PipelineStage] = Field(min_length=1)
+
+    @classmethod
+    def from_toml(cls, text: str) -> "PipelineSpec":
+        """Parse a spec from TOML (needs Python 3.11+ or the tomli package)."""
+        try:
+            import tomllib
+        except ImportError:  # Python 3.10
+            import tomli as tomllib
+        return cls.model_validate(tomllib.loads(text))
+
+
+class PipelineResult(BaseModel):
+    """Output of running a retrieval pipeline."""
+
+    pipeline: str
+    query: str
+    queries: list[str] = Field(
+        default_factory=list, description="Query plus expansions that were searched"
+    )
+    memories: list[dict[str, Any]] = Field(default_factory=list)
+    context: str | None = Field(
+        default=None, description="Packed context, when the pipeline has a pack stage"
+    )
+    trace: list[dict[str, Any]] = Field(
+        default_factory=list, description="Per-stage result counts and timings"
+    )
+    request_id: UUID = Field(default_factory=uuid4)
+    experiment: str | None = None
+    variant: str | None = Field(
+        default=None, description="Experiment variant that served the request"
+    )
+
+
+class PipelineExperiment(BaseModel):
+    """A/B test routing part of the traffic to an alternate pipeline."""
+
+    name: str = Field(pattern=r"^[A-Za-z0-9_.-]+$")
+    control: str = Field(description="Pipeline serving the remaining traffic")
+    treatment: str = Field(description="Pipeline under evaluation")
+    traffic_percent: float = Field(
+        ge=0, le=100, description="Share of requests routed to the treatment"
+    )
+    tenant_id: str | None = Field(
+        default=None, description="Restrict the experiment to one tenant"
+    )
+
+
+class VariantMetrics(BaseModel):
+    """Aggregated feedback for one experiment variant."""
+
+    variant: str
+    pipeline: str
+    requests: int = 0
+    feedback: int = Field(default=0, description="Requests with feedback")
+    mean_rating: float | None = None
+    hit_rate: float | None = Field(
+        default=None, description="Share of rated requests with a relevant result"
+    )
+    mrr: float | None = Field(
+        default=None, description="Mean reciprocal rank of the first relevant result"
+    )
+    mean_latency_ms: float | None = None
@@ -0,0 +1,71 @@
+"""Write plan models for RAE-core dry runs."""
+
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+
+class PlannedRecord(BaseModel):
+    """A memory that would be stored."""
+
+    memory_id: UUID = Field(description="Id the record would have been given")
+    fields: dict[str, Any] = Field(description="Arguments of store_memory")
+
+
+class PlannedVector(BaseModel):
+    """An embedding that would be stored."""
+
+    memory_id: UUID
+    tenant_id: str
+    embedding: list[float] | dict[str, list[float]] = Field(
+        description="Vector, or vectors by embedding model name"
+    )
+    metadata: dict[str, Any] = Field(default_factory=dict)
+
+
+class PlannedNode(BaseModel):
+    """A graph node that would be created."""
+
+    node_id: UUID
+    node_type: str
+    tenant_id: str
+    properties: dict[str, Any] = Field(default_factory=dict)
+
+
+class PlannedEdge(BaseModel):
+    """A graph edge that would be created."""
+
+    source_id: UUID
+    target_id: UUID
+    edge_type: str
+    tenant_id: str
+    weight: float = 1.0
+    properties: dict[str, Any] = Field(default_factory=dict)
+
+
+class PlannedOperation(BaseModel):
+    """Any other write (update, delete, cache write, ...) that would run."""
+
+    component: str = Field(description="storage, vector, graph or cache")
+    method: str
+    arguments: dict[str, Any] = Field(default_factory=dict)
+
+
+class WritePlan(BaseModel):
+    """Everything an engine operation would write, in call order per kind."""
+
+    records: list[PlannedRecord] = Field(default_factory=list)
+    vectors: list[PlannedVector] = Field(default_factory=list)
+    nodes: list[PlannedNode] = Field(default_factory=list)
+    edges: list[PlannedEdge] = Field(default_factory=list)
+    operations: list[PlannedOperation] = Field(default_factory=list)
+    result: Any = Field(
+        default=None, description="Return value of the planned operation"
+    )
+
+    @property
+    def is_empty(self) -> bool:
+        return not (
+            self.records or self.vectors or self.nodes or self.edges or self.operations
+        )
@@ -0,0 +1,92 @@
+"""Provenance models for RAE-core."""
+
+from enum import Enum
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+# Metadata key the structured source of a stored memory travels under
+ORIGIN_KEY = "origin"
+
+
+class SourceType(str, Enum):
+    """Where a memory came from."""
+
+    USER_MESSAGE = "user_message"
+    TOOL_OUTPUT = "tool_output"
+    # Derived by the reflection engine from other memories
+    REFLECTION = "reflection"
+    # Summary of other memories (consolidation, conversation summaries)
+    CONSOLIDATION = "consolidation"
+    # Files, documents and seed data loaded in bulk
+    IMPORT = "import"
+    OTHER = "other"
+
+
+class MemorySource(BaseModel):
+    """Structured origin of a memory.
+
+    derived_from lists the memories a derived memory (reflection, summary,
+    merge) was built from; following it back leads to the memories that
+    came from outside (see provenance.trace_origins).
+    """
+
+    type: SourceType = SourceType.OTHER
+    ref: str | None = Field(
+        default=None,
+        description="Identifier within the source (conversation, tool, job)",
+    )
+    url: str | None = None
+    file: str | None = Field(default=None, description="Path of the source file")
+    derived_from: list[UUID] = Field(default_factory=list)
+
+    @property
+    def label(self) -> str:
+        """Short human-readable description of the source."""
+        return self.ref or self.file or self.url or self.type.value
+
+    def as_metadata(self) -> dict[str, Any]:
+        """JSON form stored under metadata["origin"]."""
+        return self.model_dump(mode="json", exclude_none=True)
+
+    @classmethod
+    def coerce(cls, value: Any) -> "MemorySource | None":
+        """MemorySource from a model, its dict, or a free-text label."""
+        if value is None or isinstance(value, cls):
+            return value
+        if isinstance(value, str):
+            return cls(ref=value)
+        return cls.model_validate(value)
+
+    @classmethod
+    def from_record(cls, memory: dict[str, Any]) -> "MemorySource | None":
+        """Source of a stored memory record (None when it has none).
+
+        The structured source under metadata["origin"] wins over older
+        free-text source labels.
+        """
+        metadata = memory.get("metadata") or {}
+        for value in (
+            metadata.get(ORIGIN_KEY),
+            memory.get("source"),
+            metadata.get("source"),
+        ):
+            if value:
+                try:
+                    return cls.coerce(value)
+                except ValueError:
+                    continue
+        return None
+
+
+class OriginStep(BaseModel):
+    """One ancestor found while tracing a memory's provenance."""
+
+    memory_id: UUID
+    depth: int = Field(ge=1, description="1 for direct sources")
+    derived_for: UUID = Field(description="Memory this one was a source of")
+    source: MemorySource | None = None
+    content: str | None = None
+    root: bool = Field(default=False, description="Not derived from other memories")
+    missing: bool = Field(default=False, description="Referenced but no longer stored")
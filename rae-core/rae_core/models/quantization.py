@@ -0,0 +1,43 @@
+"""Vector quantization settings of the local vector stores."""
+
+from enum import Enum
+
+from pydantic import BaseModel, Field
+
+
+class QuantizationMode(str, Enum):
+    """How a vector collection is compressed."""
+
+    NONE = "none"  # Full-precision vectors
+    SCALAR = "scalar"  # One signed byte per dimension plus a scale (~4x smaller)
+    PRODUCT = "product"  # One byte per group of dimensions (trained codebooks)
+
+
+class QuantizationConfig(BaseModel):
+    """Quantization of one vector collection (embedding model).
+
+    Searches rank the collection by its compressed codes, then re-score the
+    best limit * rescore_factor candidates more precisely before cutting to
+    the limit. Product quantization needs codebooks: the collection keeps
+    its vectors uncompressed until it holds train_size of them, then trains
+    on those and compresses them all.
+    """
+
+    mode: QuantizationMode = QuantizationMode.SCALAR
+    subvectors: int | None = Field(
+        default=None,
+        ge=1,
+        description="Product codes per vector (dimension / 2 if None, i.e. ~8x)",
+    )
+    centroids: int = Field(
+        default=256, ge=2, le=256, description="Codebook size per subvector"
+    )
+    train_size: int = Field(
+        default=1024, ge=1, description="Vectors collected before training codebooks"
+    )
+    train_iterations: int = Field(default=10, ge=1, description="k-means iterations")
+    rescore: bool = True
+    rescore_factor: int = Field(
+        default=4, ge=1, description="Candidates re-scored per requested result"
+    )
+    seed: int = Field(default=0, description="Seed of codebook training")
@@ -0,0 +1,277 @@
+"""Memory filter models and the MemoryQuery builder."""
+
+from collections.abc import Iterable
+from datetime import datetime, timedelta, timezone
+from typing import Any
+
+from pydantic import BaseModel, ConfigDict, Field
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.types.enums import MemoryLayer
+
+
+# Time bound keyword prefix -> memory timestamp field
+TIME_FIELDS = {
+    "created": "created_at",
+    "updated": "modified_at",
+    "accessed": "last_accessed_at",
+}
+# list_memories/search_memories keyword arguments bounding time ("<prefix>_after"
+# inclusive, "<prefix>_before" exclusive)
+TIME_FILTERS = tuple(
+    f"{prefix}_{side}" for prefix in TIME_FIELDS for side in ("after", "before")
+)
+# list_memories keyword arguments bounding time and importance
+RANGE_FILTERS = (*TIME_FILTERS, "importance_gt", "importance_gte")
+
+
+def _utc(value: datetime) -> datetime:
+    if value.tzinfo is None:
+        return value.replace(tzinfo=timezone.utc)
+    return value.astimezone(timezone.utc)
+
+
+class MemoryFilter(BaseModel):
+    """Backend-independent memory filter; build one with MemoryQuery.
+
+    All conditions must hold. Storages accept it as list_memories keyword
+    arguments (to_storage_kwargs); matches() evaluates it on a memory dict.
+    """
+
+    model_config = ConfigDict(frozen=True)
+
+    layer: MemoryLayer | None = None
+    agent_id: str | None = None
+    session_id: str | None = None
+    tags_any: list[str] | None = Field(
+        default=None, description="Memory has at least one of these tags"
+    )
+    metadata: dict[str, Any] = Field(
+        default_factory=dict, description="Metadata key/value equality"
+    )
+    created_after: datetime | None = Field(default=None, description="Inclusive")
+    created_before: datetime | None = Field(default=None, description="Exclusive")
+    updated_after: datetime | None = Field(default=None, description="Inclusive")
+    updated_before: datetime | None = Field(default=None, description="Exclusive")
+    accessed_after: datetime | None = Field(default=None, description="Inclusive")
+    accessed_before: datetime | None = Field(default=None, description="Exclusive")
+    importance_gt: float | None = None
+    importance_gte: float | None = None
+    include_deleted: bool = False
+
+    def to_storage_kwargs(self) -> dict[str, Any]:
+        """Keyword arguments of IMemoryStorage.list_memories."""
+        kwargs: dict[str, Any] = {}
+        if self.layer is not None:
+            kwargs["layer"] = self.layer.value
+        if self.agent_id is not None:
+            kwargs["agent_id"] = self.agent_id
+        if self.session_id is not None:
+            kwargs["session_id"] = self.session_id
+        if self.tags_any:
+            kwargs["tags"] = list(self.tags_any)
+        if self.metadata:
+            kwargs["filters"] = dict(self.metadata)
+        for name in TIME_FILTERS:
+            value = getattr(self, name)
+            if value is not None:
+                kwargs[name] = _utc(value)
+        if self.importance_gt is not None:
+            kwargs["importance_gt"] = self.importance_gt
+        if self.importance_gte is not None:
+            kwargs["importance_gte"] = self.importance_gte
+        if self.include_deleted:
+            kwargs["include_deleted"] = True
+        return kwargs
+
+    def matches(self, memory: dict[str, Any]) -> bool:
+        """Whether a storage memory dict satisfies the filter."""
+        if self.layer is not None and memory.get("layer") != self.layer.value:
+            return False
+        if self.agent_id is not None and memory.get("agent_id") != self.agent_id:
+            return False
+        if self.session_id is not None and memory.get("session_id") != self.session_id:
+            return False
+        if self.tags_any and not set(self.tags_any) & set(memory.get("tags") or []):
+            return False
+        metadata = memory.get("metadata") or {}
+        if any(metadata.get(k) != v for k, v in self.metadata.items()):
+            return False
+        if not self.include_deleted and memory.get("deleted_at"):
+            return False
+        return matches_range(
+            memory, **{name: getattr(self, name) for name in RANGE_FILTERS}
+        )
+
+
+def matches_range(
+    memory: dict[str, Any],
+    created_after: datetime | None = None,
+    created_before: datetime | None = None,
+    importance_gt: float | None = None,
+    importance_gte: float | None = None,
+    updated_after: datetime | None = None,
+    updated_before: datetime | None = None,
+    accessed_after: datetime | None = None,
+    accessed_before: datetime | None = None,
+) -> bool:
+    """Time and importance bounds shared by the storage backends."""
+    bounds = {
+        "created": (created_after, created_before),
+        "updated": (updated_after, updated_before),
+        "accessed": (accessed_after, accessed_before),
+    }
+    for prefix, (after, before) in bounds.items():
+        if after is None and before is None:
+            continue
+        moment = memory.get(TIME_FIELDS[prefix])
+        if isinstance(moment, str):
+            moment = datetime.fromisoformat(moment)
+        if moment is None:
+            return False
+        moment = _utc(moment)
+        if after is not None and moment < _utc(after):
+            return False
+        if before is not None and moment >= _utc(before):
+            return False
+    importance = memory.get("importance")
+    if importance_gt is not None or importance_gte is not None:
+        if importance is None:
+            return False
+        if importance_gt is not None and importance <= importance_gt:
+            return False
+        if importance_gte is not None and importance < importance_gte:
+            return False
+    return True
+
+
+class MemoryQuery:
+    """Fluent, type-checked builder of memory filters.
+
+        recent = (
+            MemoryQuery()
+            .layer(MemoryLayer.SEMANTIC)
+            .tag_any(["deploy"])
+            .created_within(timedelta(days=7))
+            .importance_gt(0.6)
+        )
+        memories = await recent.fetch(storage, tenant_id)
+
+    Every method returns a new query, so partial queries can be reused.
+    Relative time bounds are resolved when the filter is built.
+    """
+
+    def __init__(self) -> None:
+        self._fields: dict[str, Any] = {}
+        self._created_within: timedelta | None = None
+        self._limit = 100
+        self._offset = 0
+
+    def _with(self, **fields: Any) -> "MemoryQuery":
+        query = MemoryQuery()
+        query._fields = {**self._fields, **fields}
+        query._created_within = self._created_within
+        query._limit = self._limit
+        query._offset = self._offset
+        return query
+
+    def layer(self, layer: MemoryLayer) -> "MemoryQuery":
+        try:
+            return self._with(layer=MemoryLayer(layer))
+        except ValueError as e:
+            raise ValidationError(f"Unknown memory layer: {layer}") from e
+
+    def agent(self, agent_id: str) -> "MemoryQuery":
+        return self._with(agent_id=agent_id)
+
+    def session(self, session_id: str) -> "MemoryQuery":
+        return self._with(session_id=session_id)
+
+    def tag_any(self, tags: Iterable[str]) -> "MemoryQuery":
+        tags = list(tags)
+        if not tags:
+            raise ValidationError("tag_any needs at least one tag")
+        return self._with(tags_any=tags)
+
+    def metadata_eq(self, key: str, value: Any) -> "MemoryQuery":
+        return self._with(metadata={**self._fields.get("metadata", {}), key: value})
+
+    def created_after(self, moment: datetime) -> "MemoryQuery":
+        return self._with(created_after=moment)
+
+    def created_before(self, moment: datetime) -> "MemoryQuery":
+        return self._with(created_before=moment)
+
+    def updated_after(self, moment: datetime) -> "MemoryQuery":
+        return self._with(updated_after=moment)
+
+    def updated_before(self, moment: datetime) -> "MemoryQuery":
+        return self._with(updated_before=moment)
+
+    def accessed_after(self, moment: datetime) -> "MemoryQuery":
+        return self._with(accessed_after=moment)
+
+    def accessed_before(self, moment: datetime) -> "MemoryQuery":
+        return self._with(accessed_before=moment)
+
+    def created_within(self, period: timedelta) -> "MemoryQuery":
+        """Created no longer than period before the filter is built."""
+        if period <= timedelta(0):
+            raise ValidationError("created_within needs a positive period")
+        query = self._with()
+        query._created_within = period
+        return query
+
+    def importance_gt(self, threshold: float) -> "MemoryQuery":
+        return self._with(importance_gt=self._importance(threshold))
+
+    def importance_gte(self, threshold: float) -> "MemoryQuery":
+        return self._with(importance_gte=self._importance(threshold))
+
+    def include_deleted(self) -> "MemoryQuery":
+        return self._with(include_deleted=True)
+
+    def limit(self, limit: int) -> "MemoryQuery":
+        if limit < 1:
+            raise ValidationError("limit must be positive")
+        query = self._with()
+        query._limit = limit
+        return query
+
+    def offset(self, offset: int) -> "MemoryQuery":
+        if offset < 0:
+            raise ValidationError("offset must not be negative")
+        query = self._with()
+        query._offset = offset
+        return query
+
+    @staticmethod
+    def _importance(threshold: float) -> float:
+        if not 0.0 <= threshold <= 1.0:
+            raise ValidationError(f"Importance must be within [0, 1]: {threshold}")
+        return threshold
+
+    def build(self, now: datetime | None = None) -> MemoryFilter:
+        """Compile to a MemoryFilter, resolving created_within against now."""
+        fields = dict(self._fields)
+        if self._created_within is not None:
+            since = (now or datetime.now(timezone.utc)) - self._created_within
+            after = fields.get("created_after")
+            fields["created_after"] = since if after is None else max(
+                _utc(after), _utc(since)
+            )
+        return MemoryFilter(**fields)
+
+    def to_storage_kwargs(self, now: datetime | None = None) -> dict[str, Any]:
+        """list_memories keyword arguments, including paging."""
+        return {
+            **self.build(now).to_storage_kwargs(),
+            "limit": self._limit,
+            "offset": self._offset,
+        }
+
+    async def fetch(
+        self, storage: Any, tenant_id: str, now: datetime | None = None
+    ) -> list[dict[str, Any]]:
+        """Run the query against an IMemoryStorage."""
+        return await storage.list_memories(tenant_id, **self.to_storage_kwargs(now))
@@ -0,0 +1,36 @@
+"""Per-tenant quota models for RAE-core."""
+
+from enum import Enum
+
+from pydantic import BaseModel, Field
+
+
+class QuotaResource(str, Enum):
+    """Resource limited by a tenant quota."""
+
+    MEMORIES = "memories"
+    BYTES = "bytes"
+    EMBEDDINGS_PER_MINUTE = "embeddings_per_minute"
+
+
+class TenantQuota(BaseModel):
+    """Limits applied to a tenant; None means unlimited."""
+
+    max_memories: int | None = Field(
+        default=None, ge=0, description="Maximum number of live memories"
+    )
+    max_bytes: int | None = Field(
+        default=None, ge=0, description="Maximum total UTF-8 size of memory content"
+    )
+    embeddings_per_minute: int | None = Field(
+        default=None, ge=0, description="Embeddings generated in any 60s window"
+    )
+
+
+class QuotaUsage(BaseModel):
+    """Current consumption of a tenant."""
+
+    tenant_id: str
+    memories: int = 0
+    bytes: int = 0
+    embeddings_last_minute: int = 0
@@ -0,0 +1,181 @@
+"""Typed retrieval request and response models.
+
+RetrievalRequest and RetrievalResponse are the stable, serializable form of
+a recall call: the engine (RAEEngine.retrieve), the HTTP API (/v1/retrieve)
+and other boundaries exchange them instead of growing keyword argument
+lists. Fields are only ever added with defaults, so older payloads stay
+valid.
+"""
+
+from typing import Any
+
+from pydantic import BaseModel, ConfigDict, Field
+
+from rae_core.models.memory import memory_confidence
+
+
+class RetrievalBudget(BaseModel):
+    """How much a retrieval may return."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    top_k: int = Field(default=10, ge=1, le=100, description="Maximum results")
+    max_tokens: int | None = Field(
+        default=None,
+        ge=1,
+        description="Token budget of the returned contents; results that do "
+        "not fit are dropped (in rank order)",
+    )
+
+
+class RetrievalOptions(BaseModel):
+    """Pipeline options of a retrieval; None uses the engine's default."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    floor: float | None = Field(
+        default=None, description="Relevance floor overriding the configured one"
+    )
+    rerank: bool | None = Field(
+        default=None, description="Rerank with the configured reranker"
+    )
+    rerank_depth: int | None = Field(default=None, ge=1)
+    mmr_lambda: float | None = Field(
+        default=None,
+        ge=0.0,
+        le=1.0,
+        description="Diversify results (1 keeps the relevance order)",
+    )
+    group_by_parent: bool = Field(
+        default=False, description="Collapse chunks of the same memory"
+    )
+    include_global: bool = Field(
+        default=False, description="Merge in the global knowledge base"
+    )
+    global_weight: float | None = Field(default=None, ge=0.0, le=1.0)
+    min_confidence: float | None = Field(
+        default=None,
+        ge=0.0,
+        le=1.0,
+        description="Drop memories recorded as less likely to be true",
+    )
+    strategies: list[str] | None = Field(
+        default=None, description="Search strategies to run (all if None)"
+    )
+    weights: dict[str, float] | None = Field(
+        default=None, description="Strategy weights overriding the tuned ones"
+    )
+    explain: bool = Field(
+        default=False, description="Include how each result was scored"
+    )
+
+
+class RetrievalRequest(BaseModel):
+    """A query with its filters, budget and pipeline options.
+
+    The tenant is not part of the request; it comes from the caller's
+    context (header, token or session).
+    """
+
+    model_config = ConfigDict(extra="forbid")
+
+    query: str = Field(min_length=1)
+    agent_id: str | None = None
+    session_id: str | None = None
+    project: str | None = None
+    namespace: str | None = None
+    layer: str | None = None
+    filters: dict[str, Any] | None = Field(
+        default=None, description="Metadata and field filters"
+    )
+    budget: RetrievalBudget = Field(default_factory=RetrievalBudget)
+    options: RetrievalOptions = Field(default_factory=RetrievalOptions)
+
+    def recall_kwargs(self) -> dict[str, Any]:
+        """Keyword arguments of RAEEngine.recall for this request."""
+        options = self.options
+        kwargs: dict[str, Any] = {
+            "top_k": self.budget.top_k,
+            "agent_id": self.agent_id,
+            "session_id": self.session_id,
+            "project": self.project,
+            "namespace": self.namespace,
+            "layer": self.layer,
+            "filters": self.filters,
+            "floor": options.floor,
+            "rerank": options.rerank,
+            "rerank_depth": options.rerank_depth,
+            "mmr_lambda": options.mmr_lambda,
+            "strategies": options.strategies,
+            "custom_weights": options.weights,
+            "global_weight": options.global_weight,
+            "min_confidence": options.min_confidence,
+        }
+        if options.group_by_parent:
+            kwargs["group_by_parent"] = True
+        if options.include_global:
+            kwargs["include_global"] = True
+        return {k: v for k, v in kwargs.items() if v is not None}
+
+
+class RetrievedMemory(BaseModel):
+    """One result of a retrieval."""
+
+    id: str
+    content: str
+    layer: str | None = None
+    tags: list[str] = Field(default_factory=list)
+    metadata: dict[str, Any] = Field(default_factory=dict)
+    score: float = Field(description="Final ranking score")
+    search_score: float | None = Field(
+        default=None, description="Score of the hybrid search stage"
+    )
+    confidence: float = Field(
+        default=1.0, description="How likely the memory is to be true"
+    )
+    source: str = Field(default="tenant", description="tenant or global")
+    tokens: int = Field(description="Estimated tokens of the content")
+    explanation: dict[str, Any] | None = Field(
+        default=None, description="Scoring details (with options.explain)"
+    )
+
+    @classmethod
+    def from_memory(
+        cls, memory: dict[str, Any], tokens: int, explain: bool = False
+    ) -> "RetrievedMemory":
+        """Result from a memory dict returned by RAEEngine.recall."""
+        layer = memory.get("layer")
+        return cls(
+            id=str(memory.get("id", "")),
+            content=str(memory.get("content", "")),
+            layer=getattr(layer, "value", layer),
+            tags=list(memory.get("tags") or []),
+            metadata=dict(memory.get("metadata") or {}),
+            score=float(memory.get("math_score") or 0.0),
+            search_score=memory.get("search_score"),
+            confidence=memory_confidence(memory),
+            source=memory.get("knowledge_source", "tenant"),
+            tokens=tokens,
+            explanation=memory.get("audit_trail") if explain else None,
+        )
+
+
+class TokenUsage(BaseModel):
+    """Token accounting of a retrieval."""
+
+    used: int = Field(default=0, description="Tokens of the returned contents")
+    budget: int | None = None
+    dropped: int = Field(default=0, description="Results left out by the budget")
+
+
+class RetrievalResponse(BaseModel):
+    """Results of a retrieval with relevance and token accounting."""
+
+    query: str
+    results: list[RetrievedMemory] = Field(default_factory=list)
+    no_relevant_memory: bool = True
+    floor: float | None = None
+    rejected_count: int = 0
+    best_rejected_score: float | None = None
+    tokens: TokenUsage = Field(default_factory=TokenUsage)
+    elapsed_ms: float = 0.0
@@ -1,6 +1,8 @@
 """Scoring models for RAE-core memory importance."""
 
 from datetime import datetime, timedelta
+from enum import Enum
+from uuid import UUID, uuid4
 
 from pydantic import BaseModel, Field
 
@@ -84,3 +86,63 @@ class DecayResult(BaseModel):
     decay_amount: float
     time_elapsed: timedelta
     next_decay_at: datetime
+
+
+class RecalibrationStrategy(str, Enum):
+    """How importance scores are recalibrated across a tenant."""
+
+    RESCALE = "rescale"  # Linear min-max rescale into [target_min, target_max]
+    UNIFORM = "uniform"  # Rank-preserving map onto a uniform distribution
+    NORMAL = "normal"  # Rank-preserving map onto a clipped normal distribution
+    SCORER = "scorer"  # Re-score every memory with a new scoring function
+
+
+class RecalibrationConfig(BaseModel):
+    """Configuration for bulk importance recalibration."""
+
+    strategy: RecalibrationStrategy = Field(default=RecalibrationStrategy.RESCALE)
+    target_min: float = Field(default=0.0, ge=0.0, le=1.0)
+    target_max: float = Field(default=1.0, ge=0.0, le=1.0)
+    target_mean: float = Field(
+        default=0.5, ge=0.0, le=1.0, description="Mean for the normal strategy"
+    )
+    target_std: float = Field(
+        default=0.15, gt=0.0, description="Standard deviation for the normal strategy"
+    )
+    batch_size: int = Field(default=200, ge=1, description="Memories per batch")
+    agent_id: str | None = Field(default=None, description="Limit to one agent")
+    layer: str | None = Field(default=None, description="Limit to one layer")
+    dry_run: bool = Field(
+        default=False, description="Compute new scores without writing them"
+    )
+
+
+class JobStatus(str, Enum):
+    """Lifecycle state of a background job."""
+
+    PENDING = "pending"
+    RUNNING = "running"
+    COMPLETED = "completed"
+    FAILED = "failed"
+    CANCELLED = "cancelled"
+
+
+class RecalibrationProgress(BaseModel):
+    """Progress report of an importance recalibration job."""
+
+    job_id: UUID = Field(default_factory=uuid4)
+    tenant_id: str
+    status: JobStatus = Field(default=JobStatus.PENDING)
+    total: int = Field(default=0, description="Memories in scope")
+    processed: int = Field(default=0, description="Memories evaluated so far")
+    updated: int = Field(default=0, description="Memories whose score changed")
+    error: str | None = None
+    started_at: datetime | None = None
+    finished_at: datetime | None = None
+
+    @property
+    def fraction(self) -> float:
+        """Completed share of the job in [0, 1]."""
+        if self.status == JobStatus.COMPLETED:
+            return 1.0
+        return self.processed / self.total if self.total else 0.0
@@ -120,6 +120,88 @@ class SearchResponse(BaseModel):
     )
 
 
+class RecallResult(BaseModel):
+    """Outcome of recall with a relevance floor applied.
+
+    When nothing clears the floor, memories is empty and has_relevant is False;
+    best_rejected_score then tells the caller how close the best miss was, so
+    an agent can ask the user instead of answering from weak recall.
+    """
+
+    memories: list[dict[str, Any]] = Field(
+        default_factory=list, description="Memories scoring at or above the floor"
+    )
+    floor: float | None = Field(default=None, description="Floor that was applied")
+    best_rejected_score: float | None = Field(
+        default=None, description="Highest score among memories below the floor"
+    )
+    rejected_count: int = Field(default=0, description="Memories below the floor")
+
+    @property
+    def has_relevant(self) -> bool:
+        """Whether at least one memory cleared the floor."""
+        return bool(self.memories)
+
+    @property
+    def no_relevant_memory(self) -> bool:
+        """Explicit signal that recall found nothing relevant."""
+        return not self.memories
+
+
+class SimilarityRecallOptions(BaseModel):
+    """Filters and ranking of a vector recall (RAEEngine.recall_similar)."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    top_k: int = Field(default=10, ge=1, le=100, description="Maximum results")
+    agent_id: str | None = None
+    layer: str | None = None
+    session_id: str | None = None
+    project: str | None = None
+    filters: dict[str, Any] | None = Field(
+        default=None, description="Metadata equality filters (tags: any of)"
+    )
+    min_similarity: float | None = Field(
+        default=None, ge=0.0, le=1.0, description="Vector similarity threshold"
+    )
+    recency_tau_hours: float | None = Field(
+        default=None,
+        gt=0.0,
+        description="Weight scores by exp(-age / tau) (see search.recency)",
+    )
+    rerank: bool | None = Field(
+        default=None, description="Rerank with the configured reranker"
+    )
+    rerank_depth: int | None = Field(default=None, ge=1)
+    overfetch: int = Field(
+        default=3,
+        ge=1,
+        description="Vector hits fetched per result, leaving room for filters",
+    )
+    include_superseded: bool = Field(
+        default=False, description="Also return memories superseded by others"
+    )
+    min_confidence: float | None = Field(
+        default=None, ge=0.0, le=1.0, description="Drop less certain memories"
+    )
+    namespace: str | None = Field(
+        default=None, description="Only memories of this namespace"
+    )
+
+
+class ScoredMemory(BaseModel):
+    """A recalled memory record with its scores and how it was found."""
+
+    memory: dict[str, Any] = Field(description="The stored memory record")
+    score: float = Field(description="Final ranking score")
+    similarity: float = Field(description="Vector similarity to the query")
+    provenance: dict[str, Any] = Field(
+        default_factory=dict,
+        description="Retrieval details: strategy, vector_name, similarity_rank, "
+        "recency_factor, reranked",
+    )
+
+
 class ScoringWeights(BaseModel):
     """Weights for unified memory scoring."""
 
@@ -137,3 +219,39 @@ class ScoringWeights(BaseModel):
         """Validate that weights sum to 1.0."""
         total = self.similarity + self.importance + self.recency
         return abs(total - 1.0) < 0.01  # Allow small floating point errors
+
+
+class SamplingStrategy(str, Enum):
+    """Which memories sample_memories favors."""
+
+    WEIGHTED = "weighted"  # Importance and recency weights combined
+    RECENCY = "recency"  # Recency weight only
+    IMPORTANCE = "importance"  # Importance weight only
+    STRATIFIED = "stratified"  # Every tag represented, weighted within tags
+
+
+class SamplingWeighting(BaseModel):
+    """How sample_memories weights memories when drawing a random sample.
+
+    A memory is drawn with weight importance ** importance times
+    0.5 ** (age / recency_half_life_hours) ** recency; with both exponents
+    at 0 the sample is uniform. The recency and importance strategies drop
+    the other factor. The stratified strategy groups memories by their first
+    tag (untagged ones form a group of their own) and gives each group a
+    share of the sample proportional to its size, but at least one memory
+    while the sample has room; within a group memories are drawn by weight.
+    """
+
+    strategy: SamplingStrategy = SamplingStrategy.WEIGHTED
+    importance: float = Field(default=1.0, ge=0.0, description="Importance exponent")
+    recency: float = Field(default=1.0, ge=0.0, description="Recency decay exponent")
+    recency_half_life_hours: float = Field(default=168.0, gt=0.0)
+    min_weight: float = Field(
+        default=0.01, gt=0.0, description="Keeps zero-importance memories drawable"
+    )
+    pool_size: int = Field(
+        default=1000, ge=1, description="Most recent memories the sample is drawn from"
+    )
+    seed: int | str | None = Field(
+        default=None, description="Fixed seed for reproducible samples"
+    )
@@ -0,0 +1,84 @@
+"""Workload simulation models."""
+
+from pydantic import BaseModel, ConfigDict, Field
+
+from rae_core.config.defaults import (
+    DEFAULT_SIM_TICKS,
+    DEFAULT_SIM_ZIPF_EXPONENT,
+    DEFAULT_TOP_K,
+)
+
+
+class WorkloadSpec(BaseModel):
+    """Shape of a synthetic multi-tenant agent workload."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    tenants: int = Field(default=3, ge=1)
+    agents_per_tenant: int = Field(default=2, ge=1)
+    ticks: int = Field(default=DEFAULT_SIM_TICKS, ge=1)
+    seed: int = 0
+    writes_per_tick: float = Field(
+        default=2.0, ge=0.0, description="Mean writes of a tenant per quiet tick"
+    )
+    burst_probability: float = Field(
+        default=0.05, ge=0.0, le=1.0, description="Chance a tick is a write burst"
+    )
+    burst_size: int = Field(
+        default=20, ge=0, description="Writes of a tenant in a burst tick"
+    )
+    reads_per_tick: float = Field(
+        default=4.0, ge=0.0, description="Mean reads of a tenant per tick"
+    )
+    zipf_exponent: float = Field(
+        default=DEFAULT_SIM_ZIPF_EXPONENT,
+        gt=0.0,
+        description="Skew of reads towards the facts written first",
+    )
+    session_length: float = Field(
+        default=10.0,
+        ge=1.0,
+        description="Mean ticks an agent session lasts before it is closed",
+    )
+    session_read_share: float = Field(
+        default=0.3,
+        ge=0.0,
+        le=1.0,
+        description="Share of reads scoped to the current session of the agent",
+    )
+    top_k: int = Field(default=DEFAULT_TOP_K, ge=1)
+    layer: str = Field(
+        default="working",
+        description="Layer of the writes (close_session moves them to episodic)",
+    )
+
+
+class OperationStats(BaseModel):
+    """Latency and error counts of one kind of operation."""
+
+    count: int = 0
+    errors: int = 0
+    p50_ms: float = 0.0
+    p95_ms: float = 0.0
+    p99_ms: float = 0.0
+    max_ms: float = 0.0
+
+
+class SimulationReport(BaseModel):
+    """Outcome of running a simulated workload."""
+
+    spec: WorkloadSpec
+    duration_s: float = 0.0
+    operations: int = 0
+    throughput_ops_s: float = 0.0
+    writes: OperationStats = Field(default_factory=OperationStats)
+    reads: OperationStats = Field(default_factory=OperationStats)
+    session_closes: OperationStats = Field(default_factory=OperationStats)
+    sessions: int = Field(default=0, description="Agent sessions started")
+    recall_at_k: float = Field(
+        default=0.0, description="Share of reads returning their fact in the top k"
+    )
+    mrr: float = Field(
+        default=0.0, description="Mean reciprocal rank of the fact read (0 if missed)"
+    )
+    per_tenant_recall: dict[str, float] = Field(default_factory=dict)
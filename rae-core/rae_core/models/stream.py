@@ -0,0 +1,47 @@
+"""Streaming ingestion models."""
+
+from typing import Any
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class IngestItem(BaseModel):
+    """One memory of an ingestion stream (one JSONL line)."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    content: str = Field(min_length=1)
+    agent_id: str = "default"
+    layer: str = "episodic"
+    memory_type: str | None = None
+    tags: list[str] = Field(default_factory=list)
+    metadata: dict[str, Any] = Field(default_factory=dict)
+    importance: float | None = Field(default=None, ge=0.0, le=1.0)
+    session_id: str | None = None
+    project: str | None = None
+    namespace: str | None = None
+    confidence: float | None = Field(default=None, ge=0.0, le=1.0)
+    source: str | dict[str, Any] | None = Field(
+        default=None, description="Free-text label or MemorySource"
+    )
+
+
+class IngestFailure(BaseModel):
+    """An item of a stream that was not stored."""
+
+    index: int = Field(description="Position of the item (line) in the stream")
+    reason: str
+
+
+class StreamIngestSummary(BaseModel):
+    """Outcome of ingesting a stream."""
+
+    items: int = Field(default=0, description="Items read from the stream")
+    stored: int = 0
+    deduped: int = Field(
+        default=0, description="Items skipped as copies of earlier or stored ones"
+    )
+    failed: int = 0
+    failures: list[IngestFailure] = Field(
+        default_factory=list, description="First failures with their reasons"
+    )
@@ -0,0 +1,70 @@
+"""Data subject report models for RAE-core."""
+
+from datetime import datetime
+from enum import Enum
+from typing import Any
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, Field
+
+
+class SubjectRelationship(BaseModel):
+    """A knowledge graph edge touching a memory about the subject."""
+
+    source_id: UUID
+    target_id: UUID
+    edge_type: str
+    weight: float = 1.0
+    properties: dict[str, Any] = Field(default_factory=dict)
+
+
+class SubjectReport(BaseModel):
+    """Everything a tenant stores that references one data subject."""
+
+    subject_key: str
+    tenant_id: str
+    generated_at: datetime
+    memories: list[dict[str, Any]] = Field(
+        default_factory=list, description="Memories outside the semantic layer"
+    )
+    facts: list[dict[str, Any]] = Field(
+        default_factory=list, description="Semantic-layer memories"
+    )
+    relationships: list[SubjectRelationship] = Field(default_factory=list)
+
+    @property
+    def memory_ids(self) -> list[UUID]:
+        """Ids of all reported memories and facts."""
+        return [UUID(str(m["id"])) for m in self.memories + self.facts]
+
+
+class ErasureMode(str, Enum):
+    """What happens to memories referencing an erased subject."""
+
+    # Memories are removed with their vectors and graph nodes
+    DELETE = "delete"
+    # Memories are replaced by copies with the subject masked out
+    ANONYMIZE = "anonymize"
+
+
+class ErasureResult(BaseModel):
+    """Outcome of erasing a data subject.
+
+    The subject key itself is not kept; subject_digest (SHA-256 of the key)
+    lets an auditor match a result to the request it answered.
+    """
+
+    erasure_id: UUID = Field(default_factory=uuid4)
+    tenant_id: str
+    subject_digest: str
+    mode: ErasureMode
+    memory_ids: list[UUID] = Field(
+        default_factory=list, description="Memories removed from storage"
+    )
+    replacements: dict[str, str] = Field(
+        default_factory=dict,
+        description="Erased memory ID -> anonymized copy ID (anonymize mode)",
+    )
+    vectors_deleted: int = 0
+    graph_nodes_deleted: int = 0
+    completed_at: datetime | None = None
@@ -0,0 +1,44 @@
+"""Memory template models for structured memory types."""
+
+from enum import Enum
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+
+class TemplateFieldType(str, Enum):
+    """Value type of a template field."""
+
+    STRING = "str"
+    INTEGER = "int"
+    FLOAT = "float"
+    BOOLEAN = "bool"
+    LIST = "list"  # list of strings
+    DATE = "date"  # ISO date
+    DATETIME = "datetime"  # ISO datetime
+
+
+class TemplateField(BaseModel):
+    """A single field of a memory template."""
+
+    name: str = Field(pattern=r"^[a-z][a-z0-9_]*$")
+    type: TemplateFieldType = Field(default=TemplateFieldType.STRING)
+    required: bool = True
+    default: Any = None
+    description: str | None = None
+
+
+class MemoryTemplate(BaseModel):
+    """Named template for a structured memory type.
+
+    content_template is a str.format string over the field names; a line
+    whose referenced fields are all empty is left out of the rendered content.
+    """
+
+    name: str = Field(pattern=r"^[a-z][a-z0-9_]*$")
+    description: str | None = None
+    fields: list[TemplateField]
+    content_template: str
+    layer: str = Field(default="episodic", description="Default memory layer")
+    tags: list[str] = Field(default_factory=list, description="Tags always applied")
+    version: int = Field(default=1, ge=1)
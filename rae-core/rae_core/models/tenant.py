@@ -0,0 +1,21 @@
+"""Per-tenant configuration models for RAE-core."""
+
+from pydantic import BaseModel, Field
+
+
+class TenantEmbeddingConfig(BaseModel):
+    """Embedding model a tenant uses for its vector collection."""
+
+    model_name: str = Field(description="Registered embedding provider/model name")
+    dimension: int | None = Field(
+        default=None,
+        ge=1,
+        description="Vector dimension of the tenant collection (set on first use)",
+    )
+    exclusive: bool = Field(
+        default=True,
+        description=(
+            "Only this model may embed the tenant's content and queries "
+            "(e.g. a mandated on-prem model); other vector spaces are skipped"
+        ),
+    )
@@ -0,0 +1,29 @@
+"""Declarative retrieval pipelines.
+
+A pipeline is a named list of stages (expand, search, graph_expand, rerank,
+mmr, recency, pack) with per-stage parameters, defined in code or TOML. Agents are
+assigned pipelines through a PipelineRegistry, so different retrieval
+setups can be compared without code changes, and an ExperimentRunner
+splits live traffic between two pipelines and aggregates feedback.
+"""
+
+from rae_core.pipelines.experiments import ExperimentRunner
+from rae_core.pipelines.pipeline import RetrievalPipeline
+from rae_core.pipelines.registry import DEFAULT_PIPELINE, PipelineRegistry
+from rae_core.pipelines.stages import (
+    BUILTIN_STAGES,
+    PipelineState,
+    StageDefinition,
+    StageServices,
+)
+
+__all__ = [
+    "BUILTIN_STAGES",
+    "DEFAULT_PIPELINE",
+    "ExperimentRunner",
+    "PipelineRegistry",
+    "PipelineState",
+    "RetrievalPipeline",
+    "StageDefinition",
+    "StageServices",
+]
@@ -0,0 +1,260 @@
+"""A/B experiments between retrieval pipelines.
+
+An experiment routes a share of retrieval requests to a treatment pipeline
+and the rest to a control pipeline. Results carry the variant and a
+request id; feedback recorded against that id is aggregated per variant
+so ranking changes can be judged on live traffic.
+"""
+
+import hashlib
+import time
+from collections import OrderedDict
+from dataclasses import dataclass
+from typing import Any
+from uuid import UUID, uuid4
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.reranking import IReranker
+from rae_core.models.pipeline import PipelineExperiment, PipelineResult, VariantMetrics
+from rae_core.pipelines.pipeline import RetrievalPipeline
+from rae_core.pipelines.registry import PipelineRegistry
+from rae_core.pipelines.stages import StageDefinition
+
+CONTROL = "control"
+TREATMENT = "treatment"
+
+
+@dataclass
+class _VariantStats:
+    requests: int = 0
+    latency_ms: float = 0.0
+    feedback: int = 0
+    ratings: int = 0
+    rating_sum: float = 0.0
+    judged: int = 0
+    hits: int = 0
+    reciprocal_rank_sum: float = 0.0
+
+
+@dataclass
+class _Served:
+    experiment: str
+    variant: str
+    memory_ids: list[str]
+
+
+class ExperimentRunner:
+    """Runs retrieval through the active experiments of a PipelineRegistry.
+
+    Requests outside every experiment use the agent's pipeline from the
+    registry. Within an experiment, control and treatment pipelines replace
+    the agent's assignment.
+    """
+
+    def __init__(
+        self,
+        registry: PipelineRegistry,
+        engine: Any,
+        graph_store: IGraphStore | None = None,
+        rerankers: dict[str, IReranker] | None = None,
+        stages: dict[str, StageDefinition] | None = None,
+        max_pending_feedback: int = 10_000,
+    ):
+        """Initialize runner.
+
+        Args:
+            registry: Pipeline specs and agent assignments
+            engine: RAEEngine (or compatible) passed to the pipelines
+            graph_store: Graph for graph_expand stages
+            rerankers: Rerankers by name for rerank stages
+            stages: Stage definitions (defaults to the built-in stages)
+            max_pending_feedback: Served requests remembered for feedback;
+                the oldest are dropped first
+        """
+        self.registry = registry
+        self.engine = engine
+        self.graph_store = graph_store
+        self.rerankers = rerankers
+        self.stages = stages
+        self.max_pending_feedback = max_pending_feedback
+        self._experiments: dict[str, PipelineExperiment] = {}
+        self._pipelines: dict[str, dict[str, RetrievalPipeline]] = {}
+        self._stats: dict[str, dict[str, _VariantStats]] = {}
+        self._served: OrderedDict[UUID, _Served] = OrderedDict()
+
+    def _pipeline(self, name: str) -> RetrievalPipeline:
+        return RetrievalPipeline(
+            self.registry.get(name),
+            self.engine,
+            graph_store=self.graph_store,
+            rerankers=self.rerankers,
+            stages=self.stages,
+        )
+
+    def start(self, experiment: PipelineExperiment) -> None:
+        """Start an experiment, resetting its metrics.
+
+        Raises:
+            ValidationError: If a pipeline is unknown or invalid, or another
+                running experiment covers the same tenant
+        """
+        for other in self._experiments.values():
+            if other.name == experiment.name:
+                continue
+            if other.tenant_id == experiment.tenant_id:
+                raise ValidationError(
+                    f"Experiment {other.name} already covers tenant "
+                    f"{experiment.tenant_id or '*'}"
+                )
+        self._pipelines[experiment.name] = {
+            CONTROL: self._pipeline(experiment.control),
+            TREATMENT: self._pipeline(experiment.treatment),
+        }
+        self._experiments[experiment.name] = experiment
+        self._stats[experiment.name] = {
+            CONTROL: _VariantStats(),
+            TREATMENT: _VariantStats(),
+        }
+
+    def stop(self, name: str) -> list[VariantMetrics]:
+        """Stop an experiment and return its final metrics."""
+        report = self.report(name)
+        del self._experiments[name]
+        del self._pipelines[name]
+        del self._stats[name]
+        return report
+
+    def experiment_for(self, tenant_id: str) -> PipelineExperiment | None:
+        """Return the experiment covering a tenant, tenant-specific first."""
+        fallback = None
+        for experiment in self._experiments.values():
+            if experiment.tenant_id == tenant_id:
+                return experiment
+            if experiment.tenant_id is None:
+                fallback = experiment
+        return fallback
+
+    @staticmethod
+    def variant_for(experiment: PipelineExperiment, unit_id: str) -> str:
+        """Assign a unit (request, session, user) to a variant.
+
+        The assignment is a hash of experiment name and unit id, so the same
+        unit always gets the same variant within an experiment.
+        """
+        digest = hashlib.sha256(f"{experiment.name}:{unit_id}".encode()).digest()
+        bucket = int.from_bytes(digest[:8], "big") / 2**64 * 100
+        return TREATMENT if bucket < experiment.traffic_percent else CONTROL
+
+    async def run(
+        self,
+        query: str,
+        tenant_id: str,
+        agent_id: str | None = None,
+        unit_id: str | None = None,
+    ) -> PipelineResult:
+        """Run retrieval, routing through an experiment if one applies.
+
+        Args:
+            query: Search query
+            tenant_id: Tenant identifier
+            agent_id: Agent identifier
+            unit_id: Key for sticky assignment, e.g. a session id; each
+                request is assigned independently if omitted
+        """
+        experiment = self.experiment_for(tenant_id)
+        if experiment is None:
+            pipeline = self.registry.build(
+                tenant_id,
+                agent_id,
+                self.engine,
+                graph_store=self.graph_store,
+                rerankers=self.rerankers,
+                stages=self.stages,
+            )
+            return await pipeline.run(query, tenant_id, agent_id)
+
+        variant = self.variant_for(experiment, unit_id or str(uuid4()))
+        started = time.perf_counter()
+        result = await self._pipelines[experiment.name][variant].run(
+            query, tenant_id, agent_id
+        )
+        elapsed_ms = (time.perf_counter() - started) * 1000
+
+        stats = self._stats[experiment.name][variant]
+        stats.requests += 1
+        stats.latency_ms += elapsed_ms
+        self._served[result.request_id] = _Served(
+            experiment.name, variant, [str(m.get("id")) for m in result.memories]
+        )
+        while len(self._served) > self.max_pending_feedback:
+            self._served.popitem(last=False)
+
+        result.experiment = experiment.name
+        result.variant = variant
+        return result
+
+    def record_feedback(
+        self,
+        request_id: UUID,
+        relevant_ids: list[UUID] | None = None,
+        rating: float | None = None,
+    ) -> bool:
+        """Record feedback for a served request (once per request).
+
+        Args:
+            request_id: request_id of the PipelineResult
+            relevant_ids: Memories the caller found relevant; an empty list
+                means none of the results were
+            rating: Free-form score, e.g. a thumbs up (1) or down (0)
+
+        Returns:
+            False if the request is unknown, expired or already rated
+        """
+        served = self._served.pop(request_id, None)
+        if served is None or served.experiment not in self._stats:
+            return False
+        stats = self._stats[served.experiment][served.variant]
+        stats.feedback += 1
+        if rating is not None:
+            stats.ratings += 1
+            stats.rating_sum += rating
+        if relevant_ids is not None:
+            stats.judged += 1
+            relevant = {str(memory_id) for memory_id in relevant_ids}
+            for rank, memory_id in enumerate(served.memory_ids, start=1):
+                if memory_id in relevant:
+                    stats.hits += 1
+                    stats.reciprocal_rank_sum += 1 / rank
+                    break
+        return True
+
+    def report(self, name: str) -> list[VariantMetrics]:
+        """Return metrics of both variants of an experiment.
+
+        Raises:
+            ValidationError: If the experiment is not running
+        """
+        if name not in self._experiments:
+            raise ValidationError(f"Unknown experiment: {name}")
+        experiment = self._experiments[name]
+        pipelines = {CONTROL: experiment.control, TREATMENT: experiment.treatment}
+        return [
+            VariantMetrics(
+                variant=variant,
+                pipeline=pipelines[variant],
+                requests=stats.requests,
+                feedback=stats.feedback,
+                mean_rating=(
+                    stats.rating_sum / stats.ratings if stats.ratings else None
+                ),
+                hit_rate=stats.hits / stats.judged if stats.judged else None,
+                mrr=(
+                    stats.reciprocal_rank_sum / stats.judged if stats.judged else None
+                ),
+                mean_latency_ms=(
+                    stats.latency_ms / stats.requests if stats.requests else None
+                ),
+            )
+            for variant, stats in self._stats[name].items()
+        ]
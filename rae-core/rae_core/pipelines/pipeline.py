@@ -0,0 +1,94 @@
+"""Execution of declarative retrieval pipelines."""
+
+import time
+from typing import Any
+
+import pydantic
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.reranking import IReranker
+from rae_core.models.pipeline import PipelineResult, PipelineSpec
+from rae_core.pipelines.stages import (
+    BUILTIN_STAGES,
+    PipelineState,
+    RerankParams,
+    StageDefinition,
+    StageServices,
+)
+
+
+class RetrievalPipeline:
+    """A PipelineSpec bound to the components its stages use.
+
+    Stage types and parameters are validated on construction.
+    """
+
+    def __init__(
+        self,
+        spec: PipelineSpec,
+        engine: Any,
+        graph_store: IGraphStore | None = None,
+        rerankers: dict[str, IReranker] | None = None,
+        stages: dict[str, StageDefinition] | None = None,
+    ):
+        """Initialize pipeline.
+
+        Args:
+            spec: Stages to run
+            engine: RAEEngine (or compatible) used for search and lookups
+            graph_store: Graph for graph_expand stages
+            rerankers: Rerankers by name for rerank stages
+            stages: Stage definitions (defaults to the built-in stages)
+
+        Raises:
+            ValidationError: Unknown stage, invalid parameters or a stage
+                whose component is missing
+        """
+        self.spec = spec
+        self.services = StageServices(engine, graph_store, dict(rerankers or {}))
+        definitions = BUILTIN_STAGES if stages is None else stages
+
+        self._stages: list[tuple[str, StageDefinition, Any]] = []
+        for index, stage in enumerate(spec.stages):
+            definition = definitions.get(stage.type)
+            where = f"stage {index} ({stage.type}) of pipeline {spec.name}"
+            if definition is None:
+                raise ValidationError(f"Unknown {where}")
+            try:
+                params = definition.params.model_validate(stage.params)
+            except pydantic.ValidationError as e:
+                raise ValidationError(f"Invalid parameters for {where}: {e}") from e
+            if definition.requires and not getattr(self.services, definition.requires):
+                raise ValidationError(f"{where} needs {definition.requires}")
+            if (
+                isinstance(params, RerankParams)
+                and params.reranker not in self.services.rerankers
+            ):
+                raise ValidationError(f"Unknown reranker {params.reranker} in {where}")
+            self._stages.append((stage.type, definition, params))
+
+    async def run(
+        self, query: str, tenant_id: str, agent_id: str | None = None
+    ) -> PipelineResult:
+        """Run the stages in order."""
+        state = PipelineState(query=query, tenant_id=tenant_id, agent_id=agent_id)
+        trace = []
+        for name, definition, params in self._stages:
+            started = time.perf_counter()
+            await definition.run(state, params, self.services)
+            trace.append(
+                {
+                    "stage": name,
+                    "memories": len(state.memories),
+                    "elapsed_ms": round((time.perf_counter() - started) * 1000, 3),
+                }
+            )
+        return PipelineResult(
+            pipeline=self.spec.name,
+            query=query,
+            queries=state.queries or [query],
+            memories=state.memories,
+            context=state.context,
+            trace=trace,
+        )
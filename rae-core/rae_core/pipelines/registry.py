@@ -0,0 +1,109 @@
+"""Named retrieval pipelines and their assignment to agents."""
+
+from pathlib import Path
+from typing import Any
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.reranking import IReranker
+from rae_core.models.pipeline import PipelineSpec, PipelineStage
+from rae_core.pipelines.pipeline import RetrievalPipeline
+from rae_core.pipelines.stages import StageDefinition
+
+DEFAULT_PIPELINE = PipelineSpec(
+    name="default",
+    description="Plain hybrid search",
+    stages=[PipelineStage(type="search")],
+)
+
+
+class PipelineRegistry:
+    """Pipeline specs by name, selected per tenant and agent.
+
+    An agent uses the pipeline assigned to it, else the one assigned to its
+    tenant, else the default pipeline. Switching an agent to another
+    pipeline (e.g. for an A/B comparison) is an assign() call or a config
+    change, not a code change.
+    """
+
+    def __init__(self, default: PipelineSpec = DEFAULT_PIPELINE):
+        self._specs: dict[str, PipelineSpec] = {default.name: default}
+        self._default = default.name
+        # (tenant_id, agent_id or None for the whole tenant) -> pipeline name
+        self._assignments: dict[tuple[str, str | None], str] = {}
+
+    def register(self, spec: PipelineSpec, replace: bool = False) -> None:
+        """Add a pipeline spec.
+
+        Raises:
+            ValidationError: If the name is taken and replace is False
+        """
+        if spec.name in self._specs and not replace:
+            raise ValidationError(f"Pipeline {spec.name} is already registered")
+        self._specs[spec.name] = spec
+
+    def get(self, name: str) -> PipelineSpec:
+        """Return the spec with the given name.
+
+        Raises:
+            ValidationError: If no such pipeline is registered
+        """
+        if name not in self._specs:
+            raise ValidationError(f"Unknown pipeline: {name}")
+        return self._specs[name]
+
+    def names(self) -> list[str]:
+        return sorted(self._specs)
+
+    def assign(self, tenant_id: str, agent_id: str | None, name: str | None) -> None:
+        """Select the pipeline of an agent, or of a tenant if agent_id is None.
+
+        Passing name=None removes the assignment.
+        """
+        key = (tenant_id, agent_id)
+        if name is None:
+            self._assignments.pop(key, None)
+            return
+        self.get(name)
+        self._assignments[key] = name
+
+    def spec_for(self, tenant_id: str, agent_id: str | None = None) -> PipelineSpec:
+        """Resolve the pipeline an agent uses."""
+        name = self._assignments.get((tenant_id, agent_id))
+        if name is None:
+            name = self._assignments.get((tenant_id, None), self._default)
+        return self._specs[name]
+
+    def load_toml(self, path: str | Path, replace: bool = False) -> PipelineSpec:
+        """Register the spec in a TOML file."""
+        try:
+            spec = PipelineSpec.from_toml(Path(path).read_text(encoding="utf-8"))
+        except ValueError as e:
+            raise ValidationError(f"Invalid pipeline spec {path}: {e}") from e
+        self.register(spec, replace=replace)
+        return spec
+
+    def load_dir(self, directory: str | Path, replace: bool = False) -> list[str]:
+        """Register every *.toml spec in a directory; returns their names."""
+        return [
+            self.load_toml(path, replace=replace).name
+            for path in sorted(Path(directory).glob("*.toml"))
+        ]
+
+    def build(
+        self,
+        tenant_id: str,
+        agent_id: str | None,
+        engine: Any,
+        graph_store: IGraphStore | None = None,
+        rerankers: dict[str, IReranker] | None = None,
+        stages: dict[str, StageDefinition] | None = None,
+    ) -> RetrievalPipeline:
+        """Build the pipeline an agent uses."""
+        return RetrievalPipeline(
+            self.spec_for(tenant_id, agent_id),
+            engine,
+            graph_store=graph_store,
+            rerankers=rerankers,
+            stages=stages,
+        )
@@ -0,0 +1,301 @@
+"""Built-in retrieval pipeline stages.
+
+A stage is an async function (state, params, services) -> None that updates
+the PipelineState in place. Each stage has a pydantic parameter model, so
+a spec with a misspelled or ill-typed parameter is rejected when the
+pipeline is built rather than when it first runs.
+"""
+
+import re
+from collections.abc import Awaitable, Callable
+from dataclasses import dataclass, field
+from datetime import datetime
+from typing import Any, Literal
+from uuid import UUID
+
+from pydantic import BaseModel, ConfigDict, Field, PositiveFloat
+
+from rae_core.context.builder import ContextBuilder, ContextFormat
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.config.defaults import DEFAULT_RECENCY_TAU_HOURS
+from rae_core.interfaces.reranking import IReranker
+from rae_core.models.query import TIME_FILTERS
+from rae_core.search.diversity import diversify
+from rae_core.search.recency import apply_recency
+from rae_core.search.reranking import rerank_memories
+
+SCORE_KEY = "math_score"
+
+
+@dataclass
+class PipelineState:
+    """Working state passed from stage to stage."""
+
+    query: str
+    tenant_id: str
+    agent_id: str | None = None
+    queries: list[str] = field(default_factory=list)
+    memories: list[dict[str, Any]] = field(default_factory=list)
+    context: str | None = None
+
+
+@dataclass
+class StageServices:
+    """Components stages may use."""
+
+    engine: Any
+    graph_store: IGraphStore | None = None
+    rerankers: dict[str, IReranker] = field(default_factory=dict)
+
+
+StageFunction = Callable[[PipelineState, Any, StageServices], Awaitable[None]]
+
+
+@dataclass(frozen=True)
+class StageDefinition:
+    """Parameter model and implementation of a stage type."""
+
+    params: type[BaseModel]
+    run: StageFunction
+    # Service attribute that must be set for the stage to be usable
+    requires: str | None = None
+
+
+class _Params(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+
+
+def _score(memory: dict[str, Any]) -> float:
+    return float(memory.get(SCORE_KEY) or 0.0)
+
+
+def _rank(memories: list[dict[str, Any]]) -> list[dict[str, Any]]:
+    return sorted(memories, key=_score, reverse=True)
+
+
+# expand ---------------------------------------------------------------------
+
+
+class ExpandParams(_Params):
+    synonyms: dict[str, list[str]] = Field(
+        default_factory=dict, description="Term -> alternatives searched as well"
+    )
+    max_queries: int = Field(default=4, ge=1, description="Including the query")
+
+
+async def expand(state: PipelineState, params: ExpandParams, _: Any) -> None:
+    """Add query variants with terms replaced by their synonyms."""
+    queries = [state.query]
+    for term, alternatives in params.synonyms.items():
+        pattern = re.compile(rf"\b{re.escape(term)}\b", re.IGNORECASE)
+        if not pattern.search(state.query):
+            continue
+        for alternative in alternatives:
+            variant = pattern.sub(alternative, state.query)
+            if variant not in queries:
+                queries.append(variant)
+    state.queries = queries[: params.max_queries]
+
+
+# search ---------------------------------------------------------------------
+
+
+class SearchParams(_Params):
+    top_k: int = Field(default=10, ge=1, description="Results per query")
+    layer: str | None = None
+    strategies: list[str] | None = None
+    group_by_parent: bool = False
+    created_after: datetime | None = None
+    created_before: datetime | None = None
+    updated_after: datetime | None = None
+    updated_before: datetime | None = None
+    accessed_after: datetime | None = None
+    accessed_before: datetime | None = None
+
+
+async def search(
+    state: PipelineState, params: SearchParams, services: StageServices
+) -> None:
+    """Search every query; a memory found by several keeps its best score."""
+    found: dict[str, dict[str, Any]] = {str(m.get("id")): m for m in state.memories}
+    for query in state.queries or [state.query]:
+        kwargs: dict[str, Any] = {}
+        if params.group_by_parent:
+            kwargs["group_by_parent"] = True
+        if params.strategies is not None:
+            kwargs["strategies"] = params.strategies
+        for name in TIME_FILTERS:
+            if getattr(params, name) is not None:
+                kwargs[name] = getattr(params, name)
+        results = await services.engine.search_memories(
+            query,
+            state.tenant_id,
+            agent_id=state.agent_id,
+            layer=params.layer,
+            top_k=params.top_k,
+            **kwargs,
+        )
+        for memory in results:
+            key = str(memory.get("id"))
+            if key not in found or _score(memory) > _score(found[key]):
+                found[key] = memory
+    state.memories = _rank(list(found.values()))
+
+
+# graph_expand ---------------------------------------------------------------
+
+
+class GraphExpandParams(_Params):
+    depth: int = Field(default=1, ge=1, le=3)
+    max_neighbors: int = Field(default=5, ge=1, description="Added per memory")
+    edge_type: str | None = None
+    score_decay: float = Field(
+        default=0.5, gt=0, le=1, description="Neighbor score relative to its source"
+    )
+
+
+async def graph_expand(
+    state: PipelineState, params: GraphExpandParams, services: StageServices
+) -> None:
+    """Add graph neighbors of the results, scored below their source."""
+    assert services.graph_store is not None
+    seen = {str(m.get("id")) for m in state.memories}
+    added = []
+    for memory in state.memories:
+        neighbors = await services.graph_store.get_neighbors(
+            UUID(str(memory["id"])),
+            state.tenant_id,
+            edge_type=params.edge_type,
+            max_depth=params.depth,
+        )
+        count = 0
+        for neighbor_id in neighbors:
+            if count >= params.max_neighbors:
+                break
+            if str(neighbor_id) in seen:
+                continue
+            neighbor = await services.engine.memory_storage.get_memory(
+                neighbor_id, state.tenant_id
+            )
+            if neighbor is None:
+                continue
+            neighbor = dict(neighbor)
+            neighbor[SCORE_KEY] = _score(memory) * params.score_decay
+            neighbor["expanded_from"] = str(memory["id"])
+            seen.add(str(neighbor_id))
+            added.append(neighbor)
+            count += 1
+    state.memories = _rank(state.memories + added)
+
+
+# rerank ---------------------------------------------------------------------
+
+
+class RerankParams(_Params):
+    reranker: str = Field(default="default", description="Name of the reranker")
+    top_k: int | None = Field(default=None, ge=1)
+
+
+async def rerank(
+    state: PipelineState, params: RerankParams, services: StageServices
+) -> None:
+    """Reorder (and optionally cut) the results with a named reranker."""
+    state.memories = await rerank_memories(
+        services.rerankers[params.reranker],
+        state.query,
+        state.memories,
+        state.tenant_id,
+        limit=params.top_k,
+        score_key=SCORE_KEY,
+    )
+
+
+# mmr ------------------------------------------------------------------------
+
+
+class MMRParams(_Params):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    lambda_: float = Field(
+        default=0.5,
+        ge=0,
+        le=1,
+        alias="lambda",
+        description="1 keeps the relevance order, lower values favor diversity",
+    )
+    top_k: int | None = Field(default=None, ge=1)
+    vector_name: str | None = Field(
+        default=None, description="Embedding model whose stored vectors are compared"
+    )
+
+
+async def mmr(state: PipelineState, params: MMRParams, services: StageServices) -> None:
+    """Reorder the results by maximal marginal relevance."""
+    state.memories = await diversify(
+        state.memories,
+        services.engine.vector_store,
+        state.tenant_id,
+        params.lambda_,
+        limit=params.top_k,
+        vector_name=params.vector_name,
+        score_key=SCORE_KEY,
+    )
+
+
+# recency --------------------------------------------------------------------
+
+
+class RecencyParams(_Params):
+    tau_hours: float = Field(
+        default=DEFAULT_RECENCY_TAU_HOURS,
+        gt=0,
+        description="Score is multiplied by exp(-age / tau_hours)",
+    )
+    layer_tau_hours: dict[str, PositiveFloat] = Field(
+        default_factory=dict, description="Layer -> tau_hours override"
+    )
+    field: Literal["created_at", "modified_at", "last_accessed_at"] = "created_at"
+
+
+async def recency(state: PipelineState, params: RecencyParams, _: Any) -> None:
+    """Weight the scores by age and reorder."""
+    state.memories = apply_recency(
+        state.memories,
+        params.tau_hours,
+        layer_tau_hours=params.layer_tau_hours,
+        field=params.field,
+        score_key=SCORE_KEY,
+    )
+
+
+# pack -----------------------------------------------------------------------
+
+
+class PackParams(_Params):
+    max_tokens: int = Field(default=4096, ge=1)
+    format: ContextFormat = ContextFormat.CONVERSATIONAL
+    max_memories: int | None = Field(default=None, ge=1)
+
+
+async def pack(state: PipelineState, params: PackParams, _: Any) -> None:
+    """Assemble the results into a token-bounded context string."""
+    builder = ContextBuilder(max_tokens=params.max_tokens)
+    state.context, _metadata = builder.build_context(
+        state.memories,
+        query=state.query,
+        format_type=params.format,
+        max_memories=params.max_memories,
+    )
+
+
+BUILTIN_STAGES: dict[str, StageDefinition] = {
+    "expand": StageDefinition(ExpandParams, expand),
+    "search": StageDefinition(SearchParams, search),
+    "graph_expand": StageDefinition(
+        GraphExpandParams, graph_expand, requires="graph_store"
+    ),
+    "rerank": StageDefinition(RerankParams, rerank, requires="rerankers"),
+    "mmr": StageDefinition(MMRParams, mmr),
+    "recency": StageDefinition(RecencyParams, recency),
+    "pack": StageDefinition(PackParams, pack),
+}
@@ -6,8 +6,11 @@ The Actor executes actions based on reflection insights.
 from typing import Any
 from uuid import UUID
 
+from rae_core.config.defaults import DEFAULT_DERIVED_CONFIDENCE_FACTOR
 from rae_core.interfaces.llm import ILLMProvider
 from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.memory import CONFIDENCE_KEY, derived_confidence
+from rae_core.models.provenance import ORIGIN_KEY, MemorySource, SourceType
 
 
 class Actor:
@@ -102,7 +105,17 @@ class Actor:
             tenant_id=tenant_id,
             agent_id=context.get("agent_id", "system"),
             tags=["consolidated"],
-            metadata={"source_memories": memory_ids},
+            metadata={
+                "source_memories": memory_ids,
+                ORIGIN_KEY: MemorySource(
+                    type=SourceType.CONSOLIDATION,
+                    ref="actor",
+                    derived_from=[m["id"] for m in memories if m.get("id")],
+                ).as_metadata(),
+                CONFIDENCE_KEY: derived_confidence(
+                    memories, DEFAULT_DERIVED_CONFIDENCE_FACTOR
+                ),
+            },
             importance=0.8,
         )
 
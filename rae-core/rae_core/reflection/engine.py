@@ -3,13 +3,20 @@
 from typing import Any, TypedDict
 from uuid import UUID
 
+from rae_core.events.bus import MemoryEventBus
+from rae_core.events.models import ReflectionCreated
 from rae_core.interfaces.llm import ILLMProvider
 from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.search import SamplingWeighting
 from rae_core.reflection.actor import Actor
 from rae_core.reflection.evaluator import Evaluator
 from rae_core.reflection.reflector import Reflector
 
 
+# Memories a single reflection is generated from
+MEMORIES_PER_REFLECTION = 10
+
+
 class ReflectionCycleResult(TypedDict):
     """Structure for reflection cycle results."""
 
@@ -35,6 +42,8 @@ class ReflectionEngine:
         memory_storage: IMemoryStorage,
         llm_provider: ILLMProvider | None = None,
         reflection_mode: str = "standard",
+        event_bus: MemoryEventBus | None = None,
+        sampling: SamplingWeighting | None = None,
     ):
         """Initialize reflection engine.
 
@@ -42,9 +51,16 @@ class ReflectionEngine:
             memory_storage: Memory storage for persistence
             llm_provider: Optional LLM provider for intelligent reflection
             reflection_mode: "minimal", "standard" or "advanced"
+            event_bus: Optional bus receiving ReflectionCreated events
+            sampling: Draw each group's memories as a weighted random sample
+                (recency, importance or stratified by tag, as its strategy
+                says) instead of taking its first ones, so reflection does
+                not keep revisiting the same dominant memories
         """
         self.memory_storage = memory_storage
         self.llm_provider = llm_provider
+        self.event_bus = event_bus
+        self.sampling = sampling
 
         # Initialize components
         self.actor = Actor(memory_storage, llm_provider)
@@ -100,10 +116,9 @@ class ReflectionEngine:
 
         # Step 2: Generate reflections
         for candidate in candidates[:3]:  # Limit to 3 candidates per cycle
-            memory_ids = [
-                UUID(mid) if isinstance(mid, str) else mid
-                for mid in candidate["memory_ids"][:10]
-            ]
+            memory_ids = await self._select_memories(
+                candidate["memory_ids"], tenant_id, MEMORIES_PER_REFLECTION
+            )
 
             reflection_result = await self.reflector.generate_reflection(
                 memory_ids=memory_ids,
@@ -114,6 +129,9 @@ class ReflectionEngine:
 
             if reflection_result.get("success"):
                 results["reflections_generated"] += 1
+                await self._publish_reflection(
+                    reflection_result, memory_ids, tenant_id, agent_id
+                )
 
                 # Step 3: Execute actions based on reflection
                 action_context = {
@@ -142,6 +160,17 @@ class ReflectionEngine:
 
         return results
 
+    async def _select_memories(
+        self, memory_ids: list[Any], tenant_id: str, k: int
+    ) -> list[UUID]:
+        ids = [UUID(mid) if isinstance(mid, str) else mid for mid in memory_ids]
+        if self.sampling is None or len(ids) <= k:
+            return ids[:k]
+        from rae_core.search.sampling import sample
+
+        memories = await self.memory_storage.get_memories_batch(ids, tenant_id)
+        return [UUID(str(m["id"])) for m in sample(memories, k, self.sampling)]
+
     async def generate_reflection(
         self,
         memory_ids: list[UUID],
@@ -160,12 +189,35 @@ class ReflectionEngine:
         Returns:
             Reflection result
         """
-        return await self.reflector.generate_reflection(
+        result = await self.reflector.generate_reflection(
             memory_ids=memory_ids,
             tenant_id=tenant_id,
             agent_id=agent_id,
             reflection_type=reflection_type,
         )
+        if result.get("success"):
+            await self._publish_reflection(result, memory_ids, tenant_id, agent_id)
+        return result
+
+    async def _publish_reflection(
+        self,
+        result: dict[str, Any],
+        memory_ids: list[UUID],
+        tenant_id: str,
+        agent_id: str,
+    ) -> None:
+        """Announce a stored reflection on the event bus, if any."""
+        if self.event_bus is None or not result.get("reflection_id"):
+            return
+        await self.event_bus.publish(
+            ReflectionCreated(
+                tenant_id=tenant_id,
+                memory_id=UUID(str(result["reflection_id"])),
+                agent_id=agent_id,
+                reflection_type=str(result.get("type", "unknown")),
+                source_memory_ids=memory_ids,
+            )
+        )
 
     async def execute_action(
         self,
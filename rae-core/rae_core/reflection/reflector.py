@@ -7,11 +7,25 @@ from datetime import datetime, timezone
 from typing import Any
 from uuid import UUID
 
+from rae_core.config.defaults import DEFAULT_DERIVED_CONFIDENCE_FACTOR
 from rae_core.interfaces.llm import ILLMProvider
 from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.memory import CONFIDENCE_KEY, derived_confidence
+from rae_core.models.provenance import ORIGIN_KEY, MemorySource, SourceType
 from rae_core.reflection.layers import ReflectionCoordinator
 
 
+def _reflection_source(
+    reflection_type: str, memories: list[dict[str, Any]]
+) -> dict[str, Any]:
+    """Structured source of a reflection derived from memories."""
+    return MemorySource(
+        type=SourceType.REFLECTION,
+        ref=reflection_type,
+        derived_from=[m["id"] for m in memories if m.get("id")],
+    ).as_metadata()
+
+
 class Reflector:
     """Reflector component that generates meta-cognitive insights.
 
@@ -174,6 +188,10 @@ class Reflector:
                 "source_memory_count": len(memories),
                 "source_memory_ids": [str(m["id"]) for m in memories],
                 "generated_at": datetime.now(timezone.utc).isoformat(),
+                ORIGIN_KEY: _reflection_source("consolidation", memories),
+                CONFIDENCE_KEY: derived_confidence(
+                    memories, DEFAULT_DERIVED_CONFIDENCE_FACTOR
+                ),
             },
             importance=0.9,
         )
@@ -231,6 +249,10 @@ class Reflector:
                 "patterns": patterns,
                 "source_memory_count": len(memories),
                 "generated_at": datetime.now(timezone.utc).isoformat(),
+                ORIGIN_KEY: _reflection_source("pattern", memories),
+                CONFIDENCE_KEY: derived_confidence(
+                    memories, DEFAULT_DERIVED_CONFIDENCE_FACTOR
+                ),
             },
             importance=0.85,
         )
@@ -285,6 +307,10 @@ class Reflector:
                 "layer_distribution": layer_dist,
                 "source_memory_count": len(memories),
                 "generated_at": datetime.now(timezone.utc).isoformat(),
+                ORIGIN_KEY: _reflection_source("insight", memories),
+                CONFIDENCE_KEY: derived_confidence(
+                    memories, DEFAULT_DERIVED_CONFIDENCE_FACTOR
+                ),
             },
             importance=0.95,
         )
@@ -0,0 +1,26 @@
+"""Network access to the memory core for non-Python agent runtimes.
+
+MemoryRpcService implements the RPCs independently of any transport;
+create_grpc_server exposes it over gRPC (requires the grpc extra).
+"""
+
+from rae_core.rpc.server import build_rpc_handler, create_grpc_server
+from rae_core.rpc.service import (
+    AGENT_HEADER,
+    AUTH_HEADER,
+    SERVICE_NAME,
+    TENANT_HEADER,
+    MemoryRpcService,
+    status_code_for,
+)
+
+__all__ = [
+    "AGENT_HEADER",
+    "AUTH_HEADER",
+    "SERVICE_NAME",
+    "TENANT_HEADER",
+    "MemoryRpcService",
+    "build_rpc_handler",
+    "create_grpc_server",
+    "status_code_for",
+]
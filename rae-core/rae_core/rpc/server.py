@@ -0,0 +1,89 @@
+"""gRPC binding of MemoryRpcService.
+
+Requests and responses are google.protobuf.Struct messages (see
+rae_memory.proto), so clients in any language can call the service with
+their stock protobuf runtime and no RAE-specific generated code. The tenant
+is taken from the x-rae-tenant-id metadata header.
+
+Requires the grpc extra: pip install rae-core[grpc]
+"""
+
+from typing import Any
+
+import structlog
+
+from rae_core.rpc.service import SERVICE_NAME, MemoryRpcService, status_code_for
+
+try:
+    import grpc
+    from google.protobuf import json_format, struct_pb2
+except ImportError:
+    grpc = None
+
+logger = structlog.get_logger(__name__)
+
+DEFAULT_ADDRESS = "[::]:50051"
+
+
+def _require_grpc() -> None:
+    if grpc is None:
+        raise ImportError(
+            "grpcio and protobuf are required for the RAE gRPC server. "
+            "Install with: pip install rae-core[grpc]"
+        )
+
+
+def build_rpc_handler(service: MemoryRpcService) -> Any:
+    """Generic gRPC handler serving every method of service."""
+    _require_grpc()
+
+    def unary(method: str) -> Any:
+        async def handle(request: Any, context: Any) -> Any:
+            metadata = {
+                key.lower(): value for key, value in context.invocation_metadata()
+            }
+            try:
+                payload = await service.call(
+                    method, json_format.MessageToDict(request), metadata
+                )
+            except Exception as e:
+                code = status_code_for(e)
+                if code == "INTERNAL":
+                    logger.exception("grpc_call_failed", method=method)
+                    await context.abort(grpc.StatusCode.INTERNAL, "Internal error")
+                await context.abort(grpc.StatusCode[code], str(e))
+            response = struct_pb2.Struct()
+            response.update(payload)
+            return response
+
+        return grpc.unary_unary_rpc_method_handler(
+            handle,
+            request_deserializer=struct_pb2.Struct.FromString,
+            response_serializer=struct_pb2.Struct.SerializeToString,
+        )
+
+    return grpc.method_handlers_generic_handler(
+        SERVICE_NAME, {method: unary(method) for method in service.methods}
+    )
+
+
+def create_grpc_server(
+    service: MemoryRpcService,
+    address: str = DEFAULT_ADDRESS,
+    credentials: Any = None,
+) -> Any:
+    """Create a grpc.aio server for service (call `await server.start()`).
+
+    Args:
+        service: Service to expose
+        address: Listen address
+        credentials: grpc.ServerCredentials for TLS; insecure when omitted
+    """
+    _require_grpc()
+    server = grpc.aio.server()
+    server.add_generic_rpc_handlers((build_rpc_handler(service),))
+    if credentials is None:
+        server.add_insecure_port(address)
+    else:
+        server.add_secure_port(address, credentials)
+    return server
@@ -0,0 +1,365 @@
+"""Transport-independent memory RPC service.
+
+Each RPC takes a JSON-like request dict plus call metadata and returns a
+JSON-like response dict. The tenant (and default agent) of a call come from
+metadata headers rather than the request body, so a gateway can authenticate
+callers and inject them. With a token codec, each call must instead carry a
+capability token in the authorization header; the tenant and agent then
+come from its principal and its permissions are checked per RPC. server.py
+binds this service to gRPC.
+"""
+
+from datetime import date, datetime
+from enum import Enum
+from typing import Any
+from uuid import UUID
+
+from rae_core.auth.principal import Action, Principal, memory_scope
+from rae_core.auth.tokens import CapabilityTokenCodec, bearer_token
+from rae_core.exceptions.base import (
+    AuthenticationError,
+    BackendUnavailableError,
+    ConflictError,
+    NotFoundError,
+    QuotaExceededError,
+    SecurityPolicyViolationError,
+    ValidationError,
+)
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.search.global_knowledge import is_global_tenant
+
+SERVICE_NAME = "rae.memory.v1.RaeMemory"
+TENANT_HEADER = "x-rae-tenant-id"
+AGENT_HEADER = "x-rae-agent-id"
+AUTH_HEADER = "authorization"
+
+# Most specific first; anything else maps to INTERNAL
+_STATUS_CODES: list[tuple[type[BaseException], str]] = [
+    (NotFoundError, "NOT_FOUND"),
+    (ConflictError, "ABORTED"),
+    (QuotaExceededError, "RESOURCE_EXHAUSTED"),
+    (AuthenticationError, "UNAUTHENTICATED"),
+    (BackendUnavailableError, "UNAVAILABLE"),
+    (SecurityPolicyViolationError, "PERMISSION_DENIED"),
+    (ValidationError, "INVALID_ARGUMENT"),
+    (ValueError, "INVALID_ARGUMENT"),
+]
+
+
+def status_code_for(exc: BaseException) -> str:
+    """Name of the gRPC status code reporting exc."""
+    for error_type, code in _STATUS_CODES:
+        if isinstance(exc, error_type):
+            return code
+    return "INTERNAL"
+
+
+def to_wire(value: Any) -> Any:
+    """Convert a storage value into JSON-compatible data."""
+    if isinstance(value, dict):
+        return {str(k): to_wire(v) for k, v in value.items()}
+    if isinstance(value, (list, tuple, set, frozenset)):
+        return [to_wire(v) for v in value]
+    if isinstance(value, (UUID, Enum)):
+        return str(value.value if isinstance(value, Enum) else value)
+    if isinstance(value, (datetime, date)):
+        return value.isoformat()
+    return value
+
+
+def _refuse_global_tenant(tenant_id: str) -> None:
+    if is_global_tenant(tenant_id):
+        raise SecurityPolicyViolationError(
+            "The global knowledge base is maintained by operators"
+        )
+
+
+def _uuid(request: dict[str, Any], key: str) -> UUID:
+    value = request.get(key)
+    if value is None:
+        raise ValidationError(f"Missing required field: {key}")
+    try:
+        return UUID(str(value))
+    except ValueError as e:
+        raise ValidationError(f"Invalid UUID for {key}: {value}") from e
+
+
+# Action each RPC needs; the layer comes from the request or stored memory
+_RPC_ACTIONS = {
+    "StoreMemory": Action.WRITE,
+    "GetMemory": Action.READ,
+    "ListMemories": Action.READ,
+    "SearchMemories": Action.READ,
+    "DeleteMemory": Action.DELETE,
+    "SearchSimilar": Action.READ,
+    "CreateNode": Action.WRITE,
+    "CreateEdge": Action.WRITE,
+    "GetNeighbors": Action.READ,
+    "ShortestPath": Action.READ,
+    "GetSubgraph": Action.READ,
+}
+
+
+def _int(request: dict[str, Any], key: str, default: int) -> int:
+    # Numbers arrive as doubles in protobuf Struct messages
+    value = request.get(key)
+    return default if value is None else int(value)
+
+
+class MemoryRpcService:
+    """Memory, vector and graph operations exposed as named RPCs.
+
+    Vector and graph RPCs are only available when the corresponding store is
+    configured.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        vector_store: IVectorStore | None = None,
+        graph_store: IGraphStore | None = None,
+        token_codec: CapabilityTokenCodec | None = None,
+    ):
+        self.storage = storage
+        self.vector_store = vector_store
+        self.graph_store = graph_store
+        # Requires and verifies capability tokens when set
+        self.token_codec = token_codec
+        self._handlers = {
+            "StoreMemory": self.store_memory,
+            "GetMemory": self.get_memory,
+            "ListMemories": self.list_memories,
+            "SearchMemories": self.search_memories,
+            "DeleteMemory": self.delete_memory,
+        }
+        if vector_store is not None:
+            self._handlers["SearchSimilar"] = self.search_similar
+        if graph_store is not None:
+            self._handlers.update(
+                {
+                    "CreateNode": self.create_node,
+                    "CreateEdge": self.create_edge,
+                    "GetNeighbors": self.get_neighbors,
+                    "ShortestPath": self.shortest_path,
+                    "GetSubgraph": self.get_subgraph,
+                }
+            )
+
+    @property
+    def methods(self) -> list[str]:
+        """Names of the RPCs this service answers."""
+        return list(self._handlers)
+
+    async def call(
+        self, method: str, request: dict[str, Any], metadata: dict[str, str]
+    ) -> dict[str, Any]:
+        """Dispatch one RPC.
+
+        Raises:
+            ValidationError: Unknown method, missing tenant header or invalid
+                request
+            AuthenticationError: Missing or invalid capability token
+            PermissionDeniedError: Principal lacks the RPC's permission
+        """
+        handler = self._handlers.get(method)
+        if handler is None:
+            raise ValidationError(f"Unknown method: {method}")
+        tenant_id = metadata.get(TENANT_HEADER)
+        agent_id = request.get("agent_id") or metadata.get(AGENT_HEADER)
+        if self.token_codec is None:
+            if not tenant_id:
+                raise ValidationError(f"Missing {TENANT_HEADER} header")
+            _refuse_global_tenant(tenant_id)
+            return to_wire(await handler(request, tenant_id, agent_id))
+
+        principal = self.token_codec.verify(bearer_token(metadata.get(AUTH_HEADER)))
+        if tenant_id and tenant_id != principal.tenant_id:
+            raise SecurityPolicyViolationError(
+                f"{principal.subject} may not access tenant {tenant_id}"
+            )
+        tenant_id = principal.tenant_id
+        _refuse_global_tenant(tenant_id)
+        agent_id = principal.acting_agent(agent_id)
+        await self._authorize(method, request, principal, tenant_id)
+        response = await handler(request, tenant_id, agent_id)
+        if "memories" in response:
+            response["memories"] = [
+                m for m in response["memories"] if principal.can_read_memory(m)
+            ]
+        return to_wire(response)
+
+    async def _authorize(
+        self,
+        method: str,
+        request: dict[str, Any],
+        principal: Principal,
+        tenant_id: str,
+    ) -> None:
+        action = _RPC_ACTIONS[method]
+        if method in ("ListMemories", "SearchMemories"):
+            # Results the principal may not read are filtered out instead
+            return
+        if method in ("GetMemory", "DeleteMemory"):
+            memory = await self.storage.get_memory(_uuid(request, "id"), tenant_id)
+            if memory is not None:
+                principal.require(action, memory.get("layer"), memory_scope(memory))
+            return
+        if method == "StoreMemory":
+            scope = memory_scope({"metadata": request.get("metadata")})
+            principal.require(action, request.get("layer") or "episodic", scope)
+            return
+        principal.require(action, request.get("layer"))
+
+    async def store_memory(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        if not request.get("content"):
+            raise ValidationError("Missing required field: content")
+        fields: dict[str, Any] = {
+            "content": request["content"],
+            "layer": request.get("layer") or "episodic",
+            "tenant_id": tenant_id,
+            "agent_id": agent_id or "default",
+            "tags": request.get("tags") or [],
+            "metadata": request.get("metadata") or {},
+        }
+        if request.get("importance") is not None:
+            fields["importance"] = float(request["importance"])
+        memory_id = await self.storage.store_memory(**fields)
+        embedding = request.get("embedding")
+        if embedding and self.vector_store is not None:
+            await self.vector_store.store_vector(
+                memory_id,
+                [float(x) for x in embedding],
+                tenant_id,
+                metadata={"layer": request.get("layer") or "episodic"},
+            )
+        return {"id": memory_id}
+
+    async def get_memory(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        memory_id = _uuid(request, "id")
+        memory = await self.storage.get_memory(memory_id, tenant_id)
+        if memory is None:
+            raise NotFoundError("Memory", memory_id, tenant_id)
+        return {"memory": memory}
+
+    async def list_memories(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        memories = await self.storage.list_memories(
+            tenant_id,
+            agent_id=agent_id,
+            layer=request.get("layer"),
+            limit=_int(request, "limit", 100),
+            offset=_int(request, "offset", 0),
+            filters=request.get("filters") or {},
+        )
+        return {"memories": memories}
+
+    async def search_memories(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        memories = await self.storage.search_memories(
+            request.get("query") or "",
+            tenant_id,
+            agent_id or "default",
+            layer=request.get("layer"),
+            limit=_int(request, "limit", 10),
+        )
+        return {"memories": memories}
+
+    async def delete_memory(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        memory_id = _uuid(request, "id")
+        deleted = await self.storage.delete_memory(memory_id, tenant_id)
+        if deleted and self.vector_store is not None:
+            await self.vector_store.delete_vector(memory_id, tenant_id)
+        return {"deleted": deleted}
+
+    async def search_similar(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        assert self.vector_store is not None
+        embedding = request.get("embedding")
+        if not embedding:
+            raise ValidationError("Missing required field: embedding")
+        results = await self.vector_store.search_similar(
+            [float(x) for x in embedding],
+            tenant_id,
+            layer=request.get("layer"),
+            limit=_int(request, "limit", 10),
+            score_threshold=request.get("score_threshold"),
+            agent_id=request.get("agent_id"),
+            filters=request.get("filters"),
+            vector_name=request.get("vector_name"),
+        )
+        return {"results": [{"id": mid, "score": score} for mid, score in results]}
+
+    async def create_node(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        assert self.graph_store is not None
+        created = await self.graph_store.create_node(
+            _uuid(request, "id"),
+            request.get("type") or "memory",
+            tenant_id,
+            properties=request.get("properties"),
+        )
+        return {"created": created}
+
+    async def create_edge(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        assert self.graph_store is not None
+        created = await self.graph_store.create_edge(
+            _uuid(request, "source_id"),
+            _uuid(request, "target_id"),
+            request.get("type") or "related_to",
+            tenant_id,
+            weight=float(request.get("weight", 1.0)),
+            properties=request.get("properties"),
+        )
+        return {"created": created}
+
+    async def get_neighbors(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        assert self.graph_store is not None
+        node_ids = await self.graph_store.get_neighbors(
+            _uuid(request, "id"),
+            tenant_id,
+            edge_type=request.get("edge_type"),
+            direction=request.get("direction") or "both",
+            max_depth=_int(request, "max_depth", 1),
+        )
+        return {"node_ids": node_ids}
+
+    async def shortest_path(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        assert self.graph_store is not None
+        path = await self.graph_store.shortest_path(
+            _uuid(request, "source_id"),
+            _uuid(request, "target_id"),
+            tenant_id,
+            max_depth=_int(request, "max_depth", 5),
+        )
+        return {"path": path}
+
+    async def get_subgraph(
+        self, request: dict[str, Any], tenant_id: str, agent_id: str | None
+    ) -> dict[str, Any]:
+        assert self.graph_store is not None
+        node_ids = [
+            _uuid({"id": value}, "id") for value in request.get("node_ids") or []
+        ]
+        return await self.graph_store.get_subgraph(
+            node_ids,
+            tenant_id,
+            include_edges=request.get("include_edges", True),
+        )
@@ -0,0 +1,26 @@
+"""Scheduled maintenance for RAE-core (cron-like job scheduler and bundled
+jobs)."""
+
+from rae_core.scheduler.cron import CronSchedule
+from rae_core.scheduler.jobs import (
+    FunctionJob,
+    consolidation_job,
+    decay_job,
+    expiration_job,
+    forgetting_job,
+    reembedding_job,
+    summary_refresh_job,
+)
+from rae_core.scheduler.scheduler import JobScheduler
+
+__all__ = [
+    "CronSchedule",
+    "FunctionJob",
+    "JobScheduler",
+    "consolidation_job",
+    "decay_job",
+    "expiration_job",
+    "forgetting_job",
+    "reembedding_job",
+    "summary_refresh_job",
+]
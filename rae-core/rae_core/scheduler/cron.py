@@ -0,0 +1,132 @@
+"""Cron-like schedules for maintenance jobs.
+
+CronSchedule understands the five standard fields
+
+    minute hour day-of-month month day-of-week
+
+each being "*", a number, a range "a-b", a list "a,b" or a step "*/n" or
+"a-b/n" (day-of-week 0 and 7 are Sunday). As in cron, a day matches when
+either day field matches if both are restricted. The shortcuts @hourly,
+@daily, @weekly, @monthly and @yearly are accepted, as is "@every <n><unit>"
+(s, m, h or d) for fixed intervals. Times are evaluated in the timezone of
+the datetime given (UTC with SystemClock).
+"""
+
+import re
+from datetime import datetime, timedelta
+
+from rae_core.exceptions.base import ValidationError
+
+_SHORTCUTS = {
+    "@hourly": "0 * * * *",
+    "@daily": "0 0 * * *",
+    "@midnight": "0 0 * * *",
+    "@weekly": "0 0 * * 0",
+    "@monthly": "0 0 1 * *",
+    "@yearly": "0 0 1 1 *",
+    "@annually": "0 0 1 1 *",
+}
+# (minimum, maximum) of each field
+_BOUNDS = ((0, 59), (0, 23), (1, 31), (1, 12), (0, 7))
+_EVERY = re.compile(r"^@every\s+(\d+)\s*([smhd])$")
+_UNITS = {"s": 1, "m": 60, "h": 3600, "d": 86400}
+# A schedule matching nothing (e.g. "0 0 31 2 *") is detected after this
+_MAX_YEARS = 8
+
+
+def _field(text: str, minimum: int, maximum: int) -> frozenset[int]:
+    values: set[int] = set()
+    for part in text.split(","):
+        spec, _, step_text = part.partition("/")
+        step = int(step_text) if step_text else 1
+        if spec == "*":
+            start, end = minimum, maximum
+        elif "-" in spec:
+            start_text, end_text = spec.split("-", 1)
+            start, end = int(start_text), int(end_text)
+        else:
+            start = int(spec)
+            end = maximum if step_text else start
+        if step < 1 or not minimum <= start <= end <= maximum:
+            raise ValueError(part)
+        values.update(range(start, end + 1, step))
+    return frozenset(values)
+
+
+class CronSchedule:
+    """When a job is due, from a cron expression."""
+
+    def __init__(self, expression: str):
+        """Parse a schedule.
+
+        Raises:
+            ValidationError: Malformed expression
+        """
+        self.expression = expression.strip()
+        text = _SHORTCUTS.get(self.expression.lower(), self.expression)
+        self.interval: timedelta | None = None
+        every = _EVERY.match(text.lower())
+        if every:
+            seconds = int(every.group(1)) * _UNITS[every.group(2)]
+            if seconds < 1:
+                raise ValidationError(f"Invalid schedule: {expression!r}")
+            self.interval = timedelta(seconds=seconds)
+            return
+
+        fields = text.split()
+        if len(fields) != 5:
+            raise ValidationError(
+                f"Invalid schedule: {expression!r} (expected 5 fields)"
+            )
+        try:
+            parsed = [
+                _field(value, low, high)
+                for value, (low, high) in zip(fields, _BOUNDS, strict=True)
+            ]
+        except ValueError as e:
+            raise ValidationError(
+                f"Invalid schedule: {expression!r} (bad field {e})"
+            ) from e
+        self.minutes, self.hours, self.days, self.months, weekdays = parsed
+        # cron weekdays: 0 and 7 are Sunday; datetime.weekday(): Monday is 0
+        self.weekdays = frozenset((d - 1) % 7 for d in weekdays)
+        self._any_day = fields[2] == "*"
+        self._any_weekday = fields[4] == "*"
+
+    def __repr__(self) -> str:
+        return f"CronSchedule({self.expression!r})"
+
+    def _day_matches(self, moment: datetime) -> bool:
+        day = moment.day in self.days
+        weekday = moment.weekday() in self.weekdays
+        if self._any_day or self._any_weekday:
+            return day and weekday
+        return day or weekday
+
+    def next_after(self, moment: datetime) -> datetime:
+        """First time strictly after moment at which the job is due.
+
+        Raises:
+            ValidationError: The schedule never matches
+        """
+        if self.interval is not None:
+            return moment + self.interval
+
+        candidate = moment.replace(second=0, microsecond=0) + timedelta(minutes=1)
+        limit = moment.year + _MAX_YEARS
+        while candidate.year <= limit:
+            if candidate.month not in self.months:
+                year = candidate.year + candidate.month // 12
+                month = candidate.month % 12 + 1
+                candidate = candidate.replace(
+                    year=year, month=month, day=1, hour=0, minute=0
+                )
+            elif not self._day_matches(candidate):
+                candidate = candidate.replace(hour=0, minute=0) + timedelta(days=1)
+            elif candidate.hour not in self.hours:
+                candidate = candidate.replace(minute=0) + timedelta(hours=1)
+            elif candidate.minute not in self.minutes:
+                candidate += timedelta(minutes=1)
+            else:
+                return candidate
+        raise ValidationError(f"Schedule {self.expression!r} never matches")
@@ -0,0 +1,84 @@
+"""Bundled maintenance jobs for the scheduler.
+
+Each helper wraps an existing maintenance operation as an IMaintenanceJob:
+
+- expiration_job: IMemoryStorage.delete_expired_memories
+- decay_job: IMemoryStorage.decay_importance
+- consolidation_job: maintenance.Consolidator.run
+- summary_refresh_job: maintenance.SummaryRefresher.refresh_stale_summaries
+- forgetting_job: governance.ForgettingPruner.run
+- reembedding_job: embedding.EmbeddingMigration.run (backfills vectors of a
+  model for memories stored without one)
+
+Custom jobs implement IMaintenanceJob directly or wrap a coroutine
+function with FunctionJob.
+"""
+
+from collections.abc import Awaitable, Callable
+from typing import Any
+
+from rae_core.interfaces.job import IMaintenanceJob
+from rae_core.interfaces.storage import IMemoryStorage
+
+
+class FunctionJob(IMaintenanceJob):
+    """Maintenance job calling a coroutine function with the tenant id."""
+
+    def __init__(self, name: str, func: Callable[[str], Awaitable[Any]]):
+        """Initialize job.
+
+        Args:
+            name: Unique name of the job
+            func: Coroutine function doing the work for a tenant
+        """
+        self.name = name
+        self.func = func
+
+    def __repr__(self) -> str:
+        return f"FunctionJob({self.name!r})"
+
+    async def run(self, tenant_id: str) -> Any:
+        return await self.func(tenant_id)
+
+
+def expiration_job(storage: IMemoryStorage, name: str = "expiration") -> FunctionJob:
+    """Job deleting memories past their expires_at."""
+    return FunctionJob(name, storage.delete_expired_memories)
+
+
+def decay_job(
+    storage: IMemoryStorage, decay_factor: float, name: str = "decay"
+) -> FunctionJob:
+    """Job multiplying the importance of memories by decay_factor."""
+
+    async def decay(tenant_id: str) -> int:
+        return await storage.decay_importance(tenant_id, decay_factor)
+
+    return FunctionJob(name, decay)
+
+
+def consolidation_job(consolidator: Any, name: str = "consolidation") -> FunctionJob:
+    """Job summarizing old episodic memories (a maintenance.Consolidator)."""
+    return FunctionJob(name, consolidator.run)
+
+
+def summary_refresh_job(
+    refresher: Any, name: str = "refresh_stale_summaries"
+) -> FunctionJob:
+    """Job regenerating stale summaries (a maintenance.SummaryRefresher)."""
+    return FunctionJob(name, refresher.refresh_stale_summaries)
+
+
+def forgetting_job(pruner: Any, name: str = "forgetting") -> FunctionJob:
+    """Job pruning forgotten memories (a governance.ForgettingPruner)."""
+    return FunctionJob(name, pruner.run)
+
+
+def reembedding_job(migration: Any, name: str | None = None) -> FunctionJob:
+    """Job embedding memories lacking a model's vector.
+
+    Args:
+        migration: embedding.EmbeddingMigration of the model
+        name: Job name (reembed:<vector name> if None)
+    """
+    return FunctionJob(name or f"reembed:{migration.vector_name}", migration.run)
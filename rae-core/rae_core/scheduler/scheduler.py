@@ -0,0 +1,273 @@
+"""Periodic execution of maintenance jobs.
+
+JobScheduler runs IMaintenanceJob instances on cron-like schedules (see
+scheduler.cron), once per tenant:
+
+- every (job, tenant) pair runs in its own task, so a slow or failing
+  tenant neither delays nor breaks the others
+- a pair still running when its next slot comes is skipped for that slot
+  instead of overlapping itself; missed slots are not caught up
+- tenants are a fixed list or an async callable re-evaluated every tick,
+  per scheduler or per job
+- finished runs (status, result or error) are kept in a bounded history
+
+Call start() for a background loop, or run_pending() from an existing
+loop or cron trigger.
+"""
+
+import asyncio
+from collections import deque
+from collections.abc import Awaitable, Callable, Iterable
+from dataclasses import dataclass, field
+from datetime import datetime
+
+import structlog
+
+from rae_core.config.defaults import DEFAULT_JOB_HISTORY
+from rae_core.exceptions.base import ConflictError, NotFoundError
+from rae_core.interfaces.job import IMaintenanceJob
+from rae_core.models.maintenance import JobRun
+from rae_core.models.scoring import JobStatus
+from rae_core.scheduler.cron import CronSchedule
+from rae_core.utils.clock import IClock, SystemClock
+
+logger = structlog.get_logger(__name__)
+
+TenantSource = Iterable[str] | Callable[[], Awaitable[Iterable[str]]]
+
+# Bounds of the background loop's sleep between ticks, in seconds
+_MIN_TICK = 1.0
+_MAX_TICK = 60.0
+
+
+@dataclass
+class _Entry:
+    job: IMaintenanceJob
+    schedule: CronSchedule
+    tenants: TenantSource | None
+    timeout: float | None
+    run_on_start: bool
+    next_runs: dict[str, datetime] = field(default_factory=dict)
+
+
+class JobScheduler:
+    """Runs maintenance jobs per tenant on cron-like schedules."""
+
+    def __init__(
+        self,
+        tenant_ids: TenantSource = (),
+        clock: IClock | None = None,
+        max_concurrency: int | None = None,
+        history_size: int = DEFAULT_JOB_HISTORY,
+    ):
+        """Initialize scheduler.
+
+        Args:
+            tenant_ids: Tenants of jobs registered without their own
+                (a list, or an async callable returning the current ones)
+            clock: Time source (system clock if None)
+            max_concurrency: Runs in flight at once (unbounded if None)
+            history_size: Finished runs kept by history()
+        """
+        if max_concurrency is not None and max_concurrency < 1:
+            raise ValueError("max_concurrency must be positive")
+        self.tenant_ids = tenant_ids
+        self.clock = clock or SystemClock()
+        self._entries: dict[str, _Entry] = {}
+        self._running: dict[tuple[str, str], asyncio.Task[JobRun]] = {}
+        self._history: deque[JobRun] = deque(maxlen=history_size)
+        self._semaphore = (
+            asyncio.Semaphore(max_concurrency) if max_concurrency else None
+        )
+        self._task: asyncio.Task[None] | None = None
+        # Overridable for tests
+        self._sleep: Callable[[float], Awaitable[None]] = asyncio.sleep
+
+    def add(
+        self,
+        job: IMaintenanceJob,
+        schedule: str | CronSchedule,
+        tenant_ids: TenantSource | None = None,
+        timeout: float | None = None,
+        run_on_start: bool = False,
+    ) -> None:
+        """Register a job.
+
+        Args:
+            job: Job to run
+            schedule: Cron expression or CronSchedule
+            tenant_ids: Tenants of this job (the scheduler's if None)
+            timeout: Seconds after which a run is cancelled (none if None)
+            run_on_start: Run at the first tick instead of the first slot
+
+        Raises:
+            ValueError: A job of the same name is registered
+            ValidationError: Malformed schedule
+        """
+        if job.name in self._entries:
+            raise ValueError(f"Job already scheduled: {job.name}")
+        if isinstance(schedule, str):
+            schedule = CronSchedule(schedule)
+        self._entries[job.name] = _Entry(
+            job, schedule, tenant_ids, timeout, run_on_start
+        )
+
+    def remove(self, name: str) -> bool:
+        """Unregister a job; runs in flight finish."""
+        return self._entries.pop(name, None) is not None
+
+    @property
+    def jobs(self) -> list[str]:
+        """Names of the registered jobs."""
+        return list(self._entries)
+
+    def next_run(self, name: str, tenant_id: str) -> datetime | None:
+        """Next slot of a job for a tenant (None before its first tick)."""
+        entry = self._entries.get(name)
+        return entry.next_runs.get(tenant_id) if entry else None
+
+    def is_running(self, name: str, tenant_id: str) -> bool:
+        """Whether a run of the job for the tenant is in flight."""
+        return (name, tenant_id) in self._running
+
+    def history(
+        self, name: str | None = None, tenant_id: str | None = None
+    ) -> list[JobRun]:
+        """Finished runs, oldest first, optionally of one job or tenant."""
+        return [
+            run
+            for run in self._history
+            if (name is None or run.job == name)
+            and (tenant_id is None or run.tenant_id == tenant_id)
+        ]
+
+    async def _tenants(self, entry: _Entry) -> list[str]:
+        source = entry.tenants if entry.tenants is not None else self.tenant_ids
+        if callable(source):
+            return list(await source())
+        return list(source)
+
+    async def run_pending(self) -> list["asyncio.Task[JobRun]"]:
+        """Start the runs whose slot has come.
+
+        Returns:
+            Tasks of the started runs
+        """
+        now = self.clock.now()
+        started = []
+        for entry in list(self._entries.values()):
+            try:
+                tenants = await self._tenants(entry)
+            except Exception as e:
+                logger.warning("job_tenants_failed", job=entry.job.name, error=str(e))
+                continue
+            for tenant_id in tenants:
+                due = entry.next_runs.get(tenant_id)
+                if due is None and not entry.run_on_start:
+                    entry.next_runs[tenant_id] = entry.schedule.next_after(now)
+                    continue
+                if due is not None and due > now:
+                    continue
+                entry.next_runs[tenant_id] = entry.schedule.next_after(now)
+                key = (entry.job.name, tenant_id)
+                if key in self._running:
+                    logger.warning(
+                        "scheduled_job_skipped",
+                        job=entry.job.name,
+                        tenant_id=tenant_id,
+                        reason="previous run still in flight",
+                    )
+                    continue
+                started.append(self._launch(entry, tenant_id, due or now))
+        return started
+
+    async def run_now(self, name: str, tenant_id: str) -> JobRun:
+        """Run a job for a tenant immediately and wait for it.
+
+        Raises:
+            NotFoundError: Unknown job
+            ConflictError: The job is already running for the tenant
+        """
+        entry = self._entries.get(name)
+        if entry is None:
+            raise NotFoundError("Job", name)
+        if (name, tenant_id) in self._running:
+            raise ConflictError(f"Job {name} is already running for {tenant_id}")
+        return await self._launch(entry, tenant_id, None)
+
+    def _launch(
+        self, entry: _Entry, tenant_id: str, scheduled_for: datetime | None
+    ) -> "asyncio.Task[JobRun]":
+        key = (entry.job.name, tenant_id)
+        task = asyncio.create_task(self._execute(entry, tenant_id, scheduled_for))
+        self._running[key] = task
+        task.add_done_callback(lambda _: self._running.pop(key, None))
+        return task
+
+    async def _execute(
+        self, entry: _Entry, tenant_id: str, scheduled_for: datetime | None
+    ) -> JobRun:
+        name = entry.job.name
+        if self._semaphore is not None:
+            await self._semaphore.acquire()
+        run = JobRun(
+            job=name,
+            tenant_id=tenant_id,
+            scheduled_for=scheduled_for,
+            started_at=self.clock.now(),
+        )
+        try:
+            run.result = await asyncio.wait_for(
+                entry.job.run(tenant_id), timeout=entry.timeout
+            )
+            run.status = JobStatus.COMPLETED
+        except asyncio.CancelledError:
+            run.status = JobStatus.CANCELLED
+            raise
+        except Exception as e:
+            run.status = JobStatus.FAILED
+            run.error = str(e) or type(e).__name__
+            logger.warning(
+                "scheduled_job_failed", job=name, tenant_id=tenant_id, error=run.error
+            )
+        finally:
+            if self._semaphore is not None:
+                self._semaphore.release()
+            run.finished_at = self.clock.now()
+            self._history.append(run)
+        logger.info(
+            "scheduled_job_finished",
+            job=name,
+            tenant_id=tenant_id,
+            status=run.status.value,
+        )
+        return run
+
+    def start(self) -> "asyncio.Task[None]":
+        """Run due jobs in the background until stop()."""
+        if self._task is None or self._task.done():
+            self._task = asyncio.create_task(self._run())
+        return self._task
+
+    async def stop(self) -> None:
+        """Stop the background loop and cancel the runs in flight."""
+        tasks = list(self._running.values())
+        if self._task is not None:
+            tasks.append(self._task)
+            self._task = None
+        for task in tasks:
+            task.cancel()
+        await asyncio.gather(*tasks, return_exceptions=True)
+
+    async def _run(self) -> None:
+        while True:
+            await self.run_pending()
+            upcoming = [
+                due
+                for entry in self._entries.values()
+                for due in entry.next_runs.values()
+            ]
+            delay = _MAX_TICK
+            if upcoming:
+                delay = (min(upcoming) - self.clock.now()).total_seconds()
+            await self._sleep(min(_MAX_TICK, max(_MIN_TICK, delay)))
@@ -0,0 +1,226 @@
+"""Bulk importance recalibration across a tenant.
+
+Used when switching importance scoring strategies: existing scores are either
+re-mapped onto a target distribution (preserving their ranking) or recomputed
+with a new scorer, then written back in batches while progress is reported.
+"""
+
+import asyncio
+import inspect
+import statistics
+from collections.abc import Awaitable, Callable
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.scoring import (
+    JobStatus,
+    RecalibrationConfig,
+    RecalibrationProgress,
+    RecalibrationStrategy,
+)
+
+logger = structlog.get_logger(__name__)
+
+ImportanceScorer = Callable[[dict[str, Any]], float | Awaitable[float]]
+ProgressCallback = Callable[[RecalibrationProgress], Awaitable[None] | None]
+
+# Attribution recorded in the change log of recalibrated memories
+RECALIBRATION_ACTOR = "system:importance_recalibration"
+
+
+def _average_ranks(values: list[float]) -> list[float]:
+    """Zero-based ranks with ties sharing their average rank."""
+    order = sorted(range(len(values)), key=lambda i: values[i])
+    ranks = [0.0] * len(values)
+    i = 0
+    while i < len(order):
+        j = i
+        while j + 1 < len(order) and values[order[j + 1]] == values[order[i]]:
+            j += 1
+        for k in range(i, j + 1):
+            ranks[order[k]] = (i + j) / 2
+        i = j + 1
+    return ranks
+
+
+def recalibrate_scores(
+    scores: list[float], config: RecalibrationConfig
+) -> list[float]:
+    """Map scores onto the distribution described by config.
+
+    Not applicable to the SCORER strategy, whose targets come from the scorer.
+    """
+    lo, hi = config.target_min, config.target_max
+    n = len(scores)
+    if n == 0:
+        return []
+
+    if config.strategy == RecalibrationStrategy.RESCALE:
+        low, high = min(scores), max(scores)
+        if high == low:
+            return [(lo + hi) / 2] * n
+        return [lo + (s - low) / (high - low) * (hi - lo) for s in scores]
+
+    ranks = _average_ranks(scores)
+    if config.strategy == RecalibrationStrategy.UNIFORM:
+        if n == 1:
+            return [(lo + hi) / 2]
+        return [lo + r / (n - 1) * (hi - lo) for r in ranks]
+
+    if config.strategy == RecalibrationStrategy.NORMAL:
+        dist = statistics.NormalDist(config.target_mean, config.target_std)
+        return [min(hi, max(lo, dist.inv_cdf((r + 0.5) / n))) for r in ranks]
+
+    raise ValueError(f"Strategy {config.strategy.value} needs a scorer")
+
+
+class ImportanceRecalibrationJob:
+    """Recalibrates importance of all memories in a tenant as a batched job.
+
+    Run it inline with `await job.run()` or in the background with
+    `job.start()`; `job.progress` is updated after every batch and passed to
+    the optional on_progress callback.
+    """
+
+    def __init__(
+        self,
+        storage: IMemoryStorage,
+        tenant_id: str,
+        config: RecalibrationConfig | None = None,
+        scorer: ImportanceScorer | None = None,
+        on_progress: ProgressCallback | None = None,
+    ):
+        """Initialize job.
+
+        Args:
+            storage: Storage holding the tenant's memories
+            tenant_id: Tenant to recalibrate
+            config: Target distribution, scope and batching
+            scorer: New importance function (required for the SCORER strategy)
+            on_progress: Called with the progress report after each batch
+        """
+        self.storage = storage
+        self.config = config or RecalibrationConfig()
+        self.scorer = scorer
+        self.on_progress = on_progress
+        self.progress = RecalibrationProgress(tenant_id=tenant_id)
+        # New importance per memory (also filled in dry runs)
+        self.results: dict[UUID, float] = {}
+        self._cancelled = False
+        self._task: asyncio.Task[RecalibrationProgress] | None = None
+
+        if self.config.strategy == RecalibrationStrategy.SCORER and scorer is None:
+            raise ValueError("The scorer strategy requires a scorer")
+
+    def start(self) -> "asyncio.Task[RecalibrationProgress]":
+        """Run the job in the background."""
+        if self._task is None:
+            self._task = asyncio.create_task(self.run())
+        return self._task
+
+    def cancel(self) -> None:
+        """Stop after the current batch; already written batches are kept."""
+        self._cancelled = True
+
+    async def run(self) -> RecalibrationProgress:
+        """Execute the job to completion and return the final progress."""
+        progress = self.progress
+        progress.status = JobStatus.RUNNING
+        progress.started_at = datetime.now(timezone.utc)
+
+        try:
+            current, targets = await self._collect()
+            progress.total = len(current)
+            await self._report()
+
+            items = list(current.items())
+            size = self.config.batch_size
+            for start in range(0, len(items), size):
+                if self._cancelled:
+                    progress.status = JobStatus.CANCELLED
+                    break
+                await self._apply_batch(items[start : start + size], targets)
+                await self._report()
+                # Let other tasks run between batches
+                await asyncio.sleep(0)
+            else:
+                progress.status = JobStatus.COMPLETED
+        except Exception as e:
+            progress.status = JobStatus.FAILED
+            progress.error = str(e)
+            logger.error(
+                "importance_recalibration_failed",
+                tenant_id=progress.tenant_id,
+                error=str(e),
+            )
+
+        progress.finished_at = datetime.now(timezone.utc)
+        await self._report()
+        logger.info(
+            "importance_recalibration_finished",
+            tenant_id=progress.tenant_id,
+            status=progress.status.value,
+            updated=progress.updated,
+        )
+        return progress
+
+    async def _collect(self) -> tuple[dict[UUID, float], dict[UUID, float]]:
+        """Scan the tenant, returning current and target importance per memory."""
+        current: dict[UUID, float] = {}
+        scored: dict[UUID, float] = {}
+        offset = 0
+        while True:
+            page = await self.storage.list_memories(
+                self.progress.tenant_id,
+                agent_id=self.config.agent_id,
+                layer=self.config.layer,
+                limit=self.config.batch_size,
+                offset=offset,
+            )
+            for memory in page:
+                memory_id = memory["id"]
+                current[memory_id] = float(memory.get("importance") or 0.0)
+                if self.scorer is not None:
+                    value = self.scorer(memory)
+                    if inspect.isawaitable(value):
+                        value = await value
+                    scored[memory_id] = float(value)
+            if len(page) < self.config.batch_size:
+                break
+            offset += self.config.batch_size
+
+        if self.config.strategy == RecalibrationStrategy.SCORER:
+            lo, hi = self.config.target_min, self.config.target_max
+            return current, {k: min(hi, max(lo, v)) for k, v in scored.items()}
+
+        ids = list(current)
+        targets = recalibrate_scores([current[i] for i in ids], self.config)
+        return current, dict(zip(ids, targets, strict=True))
+
+    async def _apply_batch(
+        self, batch: list[tuple[UUID, float]], targets: dict[UUID, float]
+    ) -> None:
+        for memory_id, old in batch:
+            new = round(targets[memory_id], 6)
+            self.results[memory_id] = new
+            self.progress.processed += 1
+            if abs(new - old) < 1e-9:
+                continue
+            if self.config.dry_run or await self.storage.update_memory(
+                memory_id,
+                self.progress.tenant_id,
+                {"importance": new},
+                changed_by=RECALIBRATION_ACTOR,
+            ):
+                self.progress.updated += 1
+
+    async def _report(self) -> None:
+        if self.on_progress is None:
+            return
+        result = self.on_progress(self.progress.model_copy())
+        if inspect.isawaitable(result):
+            await result
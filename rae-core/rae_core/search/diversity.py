@@ -0,0 +1,94 @@
+"""Maximal marginal relevance (MMR) diversification of results.
+
+Several near-duplicates of the same fact can fill the top of a result
+list. MMR reorders results so each pick balances its relevance against its
+similarity to the results already picked. Similarity uses the stored
+embeddings, so no embedding provider is called.
+"""
+
+import math
+from typing import Any
+from uuid import UUID
+
+from rae_core.interfaces.vector import IVectorStore
+
+
+def _cosine(a: list[float], b: list[float]) -> float:
+    dot = sum(x * y for x, y in zip(a, b))
+    norm = math.sqrt(sum(x * x for x in a)) * math.sqrt(sum(y * y for y in b))
+    return dot / norm if norm else 0.0
+
+
+def mmr_order(
+    memories: list[dict[str, Any]],
+    vectors: dict[str, list[float]],
+    lambda_: float = 0.5,
+    limit: int | None = None,
+    score_key: str = "math_score",
+) -> list[dict[str, Any]]:
+    """Reorder memories by maximal marginal relevance.
+
+    Each step picks the memory maximizing
+    lambda_ * relevance - (1 - lambda_) * max similarity to the picked ones,
+    with relevance min-max normalized to 0-1. lambda_=1 keeps the relevance
+    order; lower values favor diversity. Memories without a vector count as
+    dissimilar to everything.
+
+    Args:
+        memories: Ranked memories
+        vectors: Embedding by memory id (as str)
+        lambda_: Relevance/diversity trade-off in [0, 1]
+        limit: Number of memories to return (all if None)
+        score_key: Memory field holding the relevance score
+    """
+    scores = [float(m.get(score_key) or 0.0) for m in memories]
+    low, high = min(scores, default=0.0), max(scores, default=0.0)
+    spread = high - low
+    relevance = [(s - low) / spread if spread else 1.0 for s in scores]
+    embeddings = [vectors.get(str(m.get("id"))) for m in memories]
+
+    count = len(memories) if limit is None else min(limit, len(memories))
+    remaining = list(range(len(memories)))
+    # Highest similarity of each remaining memory to the picked ones
+    redundancy = [0.0] * len(memories)
+    picked: list[int] = []
+    while len(picked) < count:
+        best = max(
+            remaining,
+            key=lambda i: lambda_ * relevance[i] - (1 - lambda_) * redundancy[i],
+        )
+        picked.append(best)
+        remaining.remove(best)
+        chosen = embeddings[best]
+        if chosen is None:
+            continue
+        for i in remaining:
+            other = embeddings[i]
+            if other is not None:
+                redundancy[i] = max(redundancy[i], _cosine(chosen, other))
+    return [memories[i] for i in picked]
+
+
+async def diversify(
+    memories: list[dict[str, Any]],
+    vector_store: IVectorStore,
+    tenant_id: str,
+    lambda_: float = 0.5,
+    limit: int | None = None,
+    vector_name: str | None = None,
+    score_key: str = "math_score",
+) -> list[dict[str, Any]]:
+    """Reorder memories by MMR using their stored embeddings.
+
+    See mmr_order; vectors are read from vector_store (vector_name selects
+    the embedding model).
+    """
+    vectors: dict[str, list[float]] = {}
+    for memory in memories:
+        key = str(memory.get("id"))
+        vector = await vector_store.get_vector(
+            UUID(key), tenant_id, vector_name=vector_name
+        )
+        if vector is not None:
+            vectors[key] = list(vector)
+    return mmr_order(memories, vectors, lambda_, limit=limit, score_key=score_key)
@@ -0,0 +1,66 @@
+"""Shared global knowledge merged into tenant results.
+
+Operators keep knowledge common to every tenant (product docs, policies)
+under the reserved GLOBAL_TENANT_ID instead of copying it into each tenant.
+A tenant search reads it only when asked to (include_global=True on
+RAEEngine.search_memories / recall). The global results are then scored
+with their own weight and merged with the tenant's own. Every merged
+result is labeled with its source under SOURCE_KEY.
+"""
+
+from typing import Any
+
+# Reserved tenant holding the operator-maintained knowledge base
+GLOBAL_TENANT_ID = "__global__"
+
+# Result field naming where a merged memory came from
+SOURCE_KEY = "knowledge_source"
+TENANT_SOURCE = "tenant"
+GLOBAL_SOURCE = "global"
+
+
+def is_global_tenant(tenant_id: str | None) -> bool:
+    """Whether tenant_id is the shared global namespace."""
+    return tenant_id == GLOBAL_TENANT_ID
+
+
+def merge_global_results(
+    tenant_results: list[dict[str, Any]],
+    global_results: list[dict[str, Any]],
+    global_weight: float,
+    limit: int,
+    score_key: str = "math_score",
+) -> list[dict[str, Any]]:
+    """Merge tenant and global results into one ranking.
+
+    Global scores are multiplied by global_weight (0-1) before ranking, and
+    the unweighted score is kept as global_score. A global memory with the
+    same content as a tenant result is dropped, so the tenant's own copy
+    wins. Ties keep tenant results first.
+
+    Args:
+        tenant_results: Ranked results of the tenant
+        global_results: Ranked results of the global namespace
+        global_weight: Score multiplier of global results
+        limit: Number of results to return
+        score_key: Result field holding the ranking score
+    """
+    if not 0.0 <= global_weight <= 1.0:
+        raise ValueError("global_weight must be between 0 and 1")
+
+    merged = [{**m, SOURCE_KEY: TENANT_SOURCE} for m in tenant_results]
+    tenant_contents = {m.get("content") for m in tenant_results}
+    for memory in global_results:
+        if memory.get("content") in tenant_contents:
+            continue
+        score = float(memory.get(score_key) or 0.0)
+        merged.append(
+            {
+                **memory,
+                SOURCE_KEY: GLOBAL_SOURCE,
+                "global_score": score,
+                score_key: score * global_weight,
+            }
+        )
+    merged.sort(key=lambda m: -float(m.get(score_key) or 0.0))
+    return merged[:limit]
@@ -0,0 +1,70 @@
+"""Collapsing chunk hits into their parent memory.
+
+Long content is stored as chunks sharing a parent_id in their metadata.
+Without grouping, one long document can fill the whole top-k with its
+chunks; collapse_chunks keeps one result per parent.
+"""
+
+from typing import Any
+
+# Candidates fetched per requested result when grouping, so top_k is still
+# filled after chunks of the same parent are merged
+CHUNK_OVERFETCH = 3
+
+
+def collapse_chunks(
+    memories: list[dict[str, Any]],
+    score_key: str = "math_score",
+    limit: int | None = None,
+) -> list[dict[str, Any]]:
+    """Merge ranked hits that are chunks of the same parent memory.
+
+    Each group becomes its best-scoring chunk, placed at the rank of the
+    group's first hit, with parent_id and chunk_hits added; chunk_hits lists
+    every hit chunk (memory id, chunk index, character offset and length,
+    score) in document order. Memories that are not chunks pass through.
+
+    Args:
+        memories: Ranked memories as returned by RAEEngine.search_memories
+        score_key: Memory field holding the final score
+        limit: Maximum number of results after grouping
+    """
+    groups: dict[str, list[dict[str, Any]]] = {}
+    ordered: list[str | dict[str, Any]] = []
+    for memory in memories:
+        parent_id = (memory.get("metadata") or {}).get("parent_id")
+        if parent_id is None:
+            ordered.append(memory)
+            continue
+        parent_id = str(parent_id)
+        if parent_id not in groups:
+            groups[parent_id] = []
+            ordered.append(parent_id)
+        groups[parent_id].append(memory)
+
+    results = []
+    for entry in ordered:
+        if isinstance(entry, dict):
+            results.append(entry)
+            continue
+        hits = groups[entry]
+        best = max(hits, key=lambda m: float(m.get(score_key) or 0.0))
+        grouped = dict(best)
+        grouped["parent_id"] = entry
+        grouped["chunk_hits"] = sorted(
+            (_chunk_hit(m, score_key) for m in hits),
+            key=lambda hit: (hit["chunk_index"] is None, hit["chunk_index"] or 0),
+        )
+        results.append(grouped)
+    return results if limit is None else results[:limit]
+
+
+def _chunk_hit(memory: dict[str, Any], score_key: str) -> dict[str, Any]:
+    metadata = memory.get("metadata") or {}
+    return {
+        "memory_id": memory.get("id"),
+        "chunk_index": metadata.get("chunk_index"),
+        "offset": metadata.get("chunk_offset"),
+        "length": metadata.get("chunk_length"),
+        "score": memory.get(score_key),
+    }
@@ -0,0 +1,63 @@
+"""Recency-weighted scoring of search results.
+
+Similarity alone ranks a stale fact as high as a fresh one. Recency
+weighting multiplies each score by exp(-age / tau), so the score of a
+memory drops to about 37% after tau hours. Layers age differently (a working
+memory goes stale in hours, a semantic fact in months), so tau can be set
+per layer.
+"""
+
+import math
+from datetime import datetime, timezone
+from typing import Any
+
+from rae_core.config.defaults import DEFAULT_RECENCY_TAU_HOURS
+
+
+def recency_factor(
+    memory: dict[str, Any],
+    tau_hours: float,
+    now: datetime,
+    field: str = "created_at",
+) -> float:
+    """exp(-age / tau) of a memory; 1.0 if it has no timestamp."""
+    moment = memory.get(field)
+    if isinstance(moment, str):
+        moment = datetime.fromisoformat(moment)
+    if not isinstance(moment, datetime):
+        return 1.0
+    if moment.tzinfo is None:
+        moment = moment.replace(tzinfo=timezone.utc)
+    age_hours = max((now - moment).total_seconds() / 3600, 0.0)
+    return math.exp(-age_hours / tau_hours)
+
+
+def apply_recency(
+    memories: list[dict[str, Any]],
+    tau_hours: float = DEFAULT_RECENCY_TAU_HOURS,
+    layer_tau_hours: dict[str, float] | None = None,
+    now: datetime | None = None,
+    field: str = "created_at",
+    score_key: str = "math_score",
+) -> list[dict[str, Any]]:
+    """Weight scores by recency and reorder.
+
+    The factor is stored as recency_factor on each memory.
+
+    Args:
+        memories: Scored memories
+        tau_hours: Time constant for layers not in layer_tau_hours
+        layer_tau_hours: Time constant by layer
+        now: Reference time (current time if None)
+        field: Timestamp the age is measured from (created_at, modified_at
+            or last_accessed_at)
+        score_key: Memory field holding the score
+    """
+    now = now or datetime.now(timezone.utc)
+    layer_tau_hours = layer_tau_hours or {}
+    for memory in memories:
+        tau = layer_tau_hours.get(str(memory.get("layer")), tau_hours)
+        factor = recency_factor(memory, tau, now, field)
+        memory["recency_factor"] = factor
+        memory[score_key] = float(memory.get(score_key) or 0.0) * factor
+    return sorted(memories, key=lambda m: m[score_key], reverse=True)
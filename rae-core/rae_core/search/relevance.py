@@ -0,0 +1,40 @@
+"""Relevance floor applied to recall results."""
+
+from typing import Any
+
+from rae_core.models.search import RecallResult
+
+
+def apply_relevance_floor(
+    memories: list[dict[str, Any]],
+    floor: float | None,
+    score_key: str = "math_score",
+) -> RecallResult:
+    """Split recalled memories into those clearing the floor and rejects.
+
+    Order of the kept memories is preserved. Without a floor everything is
+    kept.
+
+    Args:
+        memories: Ranked memories as returned by RAEEngine.search_memories
+        floor: Minimum score; None disables the floor
+        score_key: Memory field holding the final score
+    """
+    if floor is None:
+        return RecallResult(memories=list(memories))
+
+    kept: list[dict[str, Any]] = []
+    rejected: list[float] = []
+    for memory in memories:
+        score = float(memory.get(score_key) or 0.0)
+        if score >= floor:
+            kept.append(memory)
+        else:
+            rejected.append(score)
+
+    return RecallResult(
+        memories=kept,
+        floor=floor,
+        best_rejected_score=max(rejected) if rejected else None,
+        rejected_count=len(rejected),
+    )
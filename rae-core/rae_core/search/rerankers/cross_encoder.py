@@ -0,0 +1,70 @@
+"""Cross-encoder reranking."""
+
+import asyncio
+import math
+from functools import partial
+from typing import Any
+from uuid import UUID
+
+from ...interfaces.reranking import IReranker
+from ...interfaces.storage import IMemoryStorage
+from ..reranking import load_documents
+
+
+class CrossEncoderReranker(IReranker):
+    """Reranker scoring each (query, memory) pair jointly with a cross-encoder.
+
+    model is anything with predict(pairs, batch_size=...) returning one
+    logit per pair, e.g. OnnxCrossEncoder or a sentence-transformers
+    CrossEncoder. Logits are mapped to 0-1 with a sigmoid so reranked
+    scores stay comparable with the relevance floor.
+    """
+
+    def __init__(
+        self,
+        model: Any,
+        memory_storage: IMemoryStorage | None = None,
+        batch_size: int = 16,
+        weight: float = 1.0,
+    ):
+        """Initialize reranker.
+
+        Args:
+            model: Cross-encoder model
+            memory_storage: Storage to read candidate content from when the
+                caller does not pass documents
+            batch_size: Pairs scored per model call
+            weight: Share of the cross-encoder score in the final score; the
+                rest is the first-stage score
+        """
+        self.model = model
+        self.memory_storage = memory_storage
+        self.batch_size = batch_size
+        self.weight = weight
+
+    async def rerank(
+        self,
+        query: str,
+        candidates: list[tuple[UUID, float, float]],
+        tenant_id: str,
+        limit: int = 10,
+        **kwargs: Any,
+    ) -> list[tuple[UUID, float, float]]:
+        """Rerank candidates; those without content keep their score."""
+        documents = await load_documents(
+            candidates, tenant_id, self.memory_storage, kwargs.get("documents")
+        )
+        scorable = [c for c in candidates if documents.get(str(c[0]))]
+        pairs = [(query, documents[str(c[0])]) for c in scorable]
+        logits = []
+        if pairs:
+            predict = partial(self.model.predict, pairs, batch_size=self.batch_size)
+            logits = list(await asyncio.to_thread(predict))
+
+        scores = {
+            str(c[0]): self.weight / (1.0 + math.exp(-float(logit)))
+            + (1.0 - self.weight) * c[1]
+            for c, logit in zip(scorable, logits)
+        }
+        reranked = [(c[0], scores.get(str(c[0]), c[1]), c[2]) for c in candidates]
+        return sorted(reranked, key=lambda x: x[1], reverse=True)[:limit]
@@ -0,0 +1,102 @@
+"""LLM-graded reranking."""
+
+import asyncio
+import re
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from ...interfaces.llm import ILLMProvider
+from ...interfaces.reranking import IReranker
+from ...interfaces.storage import IMemoryStorage
+from ..reranking import load_documents
+
+logger = structlog.get_logger(__name__)
+
+GRADE_PROMPT = """Rate how relevant the memory is to the query on a scale \
+from 0 (unrelated) to 10 (directly answers it). Reply with the number only.
+
+Query: {query}
+
+Memory: {document}
+
+Relevance:"""
+
+_NUMBER = re.compile(r"\d+(?:\.\d+)?")
+
+
+class LLMReranker(IReranker):
+    """Reranker asking an LLM to grade the relevance of each candidate.
+
+    Grades are scaled to 0-1. A candidate whose grade cannot be obtained or
+    parsed keeps its first-stage score.
+    """
+
+    def __init__(
+        self,
+        llm_provider: ILLMProvider,
+        memory_storage: IMemoryStorage | None = None,
+        weight: float = 1.0,
+        max_concurrency: int = 4,
+        max_document_chars: int = 2000,
+    ):
+        """Initialize reranker.
+
+        Args:
+            llm_provider: LLM used for grading
+            memory_storage: Storage to read candidate content from when the
+                caller does not pass documents
+            weight: Share of the LLM grade in the final score; the rest is
+                the first-stage score
+            max_concurrency: Grading requests in flight at once
+            max_document_chars: Memory content is cut to this length
+        """
+        self.llm_provider = llm_provider
+        self.memory_storage = memory_storage
+        self.weight = weight
+        self.max_document_chars = max_document_chars
+        self._semaphore = asyncio.Semaphore(max_concurrency)
+
+    async def _grade(self, query: str, document: str) -> float | None:
+        prompt = GRADE_PROMPT.format(
+            query=query, document=document[: self.max_document_chars]
+        )
+        async with self._semaphore:
+            try:
+                reply = await self.llm_provider.generate(
+                    prompt, max_tokens=8, temperature=0.0
+                )
+            except Exception as e:
+                logger.warning("llm_rerank_grade_failed", error=str(e))
+                return None
+        match = _NUMBER.search(reply or "")
+        if match is None:
+            return None
+        return min(max(float(match.group()), 0.0), 10.0) / 10.0
+
+    async def rerank(
+        self,
+        query: str,
+        candidates: list[tuple[UUID, float, float]],
+        tenant_id: str,
+        limit: int = 10,
+        **kwargs: Any,
+    ) -> list[tuple[UUID, float, float]]:
+        documents = await load_documents(
+            candidates, tenant_id, self.memory_storage, kwargs.get("documents")
+        )
+        grades = await asyncio.gather(
+            *(
+                self._grade(query, documents[str(c[0])])
+                if documents.get(str(c[0]))
+                else asyncio.sleep(0, result=None)
+                for c in candidates
+            )
+        )
+        reranked = []
+        for (memory_id, score, importance), grade in zip(candidates, grades):
+            if grade is not None:
+                score = self.weight * grade + (1.0 - self.weight) * score
+            reranked.append((memory_id, score, importance))
+        return sorted(reranked, key=lambda x: x[1], reverse=True)[:limit]
@@ -0,0 +1,74 @@
+"""Second-stage reranking of recalled memories."""
+
+from typing import Any
+from uuid import UUID
+
+from rae_core.interfaces.reranking import IReranker
+from rae_core.interfaces.storage import IMemoryStorage
+
+
+async def load_documents(
+    candidates: list[tuple[UUID, float, float]],
+    tenant_id: str,
+    memory_storage: IMemoryStorage | None,
+    documents: dict[str, str] | None = None,
+) -> dict[str, str]:
+    """Content of the candidates by memory id (as str).
+
+    Documents passed in by the caller are used as they are; the remaining
+    candidates are read from memory_storage when one is given.
+    """
+    found = {str(k): v for k, v in (documents or {}).items()}
+    missing = [c[0] for c in candidates if str(c[0]) not in found]
+    if missing and memory_storage is not None:
+        for memory in await memory_storage.get_memories_batch(missing, tenant_id):
+            found[str(memory["id"])] = str(memory.get("content") or "")
+    return found
+
+
+async def rerank_memories(
+    reranker: IReranker,
+    query: str,
+    memories: list[dict[str, Any]],
+    tenant_id: str,
+    depth: int | None = None,
+    limit: int | None = None,
+    score_key: str = "math_score",
+) -> list[dict[str, Any]]:
+    """Reorder the top memories with a reranker.
+
+    The first depth memories (all if None) are passed to the reranker with
+    their content; they come back in its order with its score as score_key
+    and the first-stage score kept in retrieval_score. Memories below the
+    depth follow unchanged.
+
+    Args:
+        reranker: Second-stage reranker
+        query: Query the memories were recalled for
+        memories: Ranked memories as returned by RAEEngine.search_memories
+        tenant_id: Tenant context
+        depth: Number of top memories to rerank
+        limit: Number of reranked memories to keep (all if None)
+        score_key: Memory field holding the final score
+    """
+    head = memories[:depth] if depth is not None else memories
+    tail = memories[len(head) :]
+    by_id = {str(m.get("id")): m for m in head}
+    candidates = [
+        (UUID(key), float(m.get(score_key) or 0.0), float(m.get("importance") or 0.0))
+        for key, m in by_id.items()
+    ]
+    ranked = await reranker.rerank(
+        query,
+        candidates,
+        tenant_id,
+        limit=limit or len(candidates),
+        documents={key: str(m.get("content") or "") for key, m in by_id.items()},
+    )
+    reranked = []
+    for memory_id, score, *_ in ranked:
+        memory = dict(by_id[str(memory_id)])
+        memory["retrieval_score"] = memory.get(score_key)
+        memory[score_key] = score
+        reranked.append(memory)
+    return reranked + tail
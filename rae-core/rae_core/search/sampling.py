@@ -0,0 +1,173 @@
+"""Weighted random sampling of memories.
+
+Top-k retrieval always returns the same dominant memories. Sampling draws
+a random subset instead, favoring important and recent memories without
+excluding the rest, e.g. to vary what reflection looks at. The stratified
+strategy also keeps rare tags from being crowded out by frequent ones.
+"""
+
+import math
+import random
+from datetime import datetime, timezone
+from typing import Any
+
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.search import SamplingStrategy, SamplingWeighting
+from rae_core.utils.clock import IClock, SystemClock
+
+
+def memory_weight(
+    memory: dict[str, Any], weighting: SamplingWeighting, now: datetime
+) -> float:
+    """Sampling weight of a memory."""
+    weight = 1.0
+    if weighting.importance and weighting.strategy != SamplingStrategy.RECENCY:
+        importance = float(memory.get("importance") or 0.0)
+        weight *= math.pow(max(importance, 0.0), weighting.importance)
+    created_at = memory.get("created_at")
+    if isinstance(created_at, str):
+        created_at = datetime.fromisoformat(created_at)
+    if (
+        weighting.recency
+        and weighting.strategy != SamplingStrategy.IMPORTANCE
+        and isinstance(created_at, datetime)
+    ):
+        if created_at.tzinfo is None:
+            created_at = created_at.replace(tzinfo=timezone.utc)
+        age_hours = max((now - created_at).total_seconds() / 3600, 0.0)
+        decay = math.pow(0.5, age_hours / weighting.recency_half_life_hours)
+        weight *= math.pow(decay, weighting.recency)
+    return max(weight, weighting.min_weight)
+
+
+def weighted_sample(
+    memories: list[dict[str, Any]],
+    k: int,
+    weighting: SamplingWeighting | None = None,
+    rng: random.Random | None = None,
+    now: datetime | None = None,
+) -> list[dict[str, Any]]:
+    """Draw k memories without replacement, proportionally to their weight.
+
+    Uses Efraimidis-Spirakis keys; the sample is ordered by key, so earlier
+    entries tend to be the heavier ones.
+    """
+    weighting = weighting or SamplingWeighting()
+    rng = rng or random.Random(weighting.seed)
+    now = now or datetime.now(timezone.utc)
+
+    def key(memory: dict[str, Any]) -> float:
+        weight = memory_weight(memory, weighting, now)
+        return math.pow(rng.random(), 1.0 / weight)
+
+    keyed = [(key(m), i) for i, m in enumerate(memories)]
+    keyed.sort(reverse=True)
+    return [memories[i] for _, i in keyed[:k]]
+
+
+def stratified_sample(
+    memories: list[dict[str, Any]],
+    k: int,
+    weighting: SamplingWeighting | None = None,
+    rng: random.Random | None = None,
+    now: datetime | None = None,
+) -> list[dict[str, Any]]:
+    """Draw k memories so that every tag gets a share of the sample.
+
+    Memories are grouped by their first tag. Each group gets a share of the
+    sample proportional to its size, but at least one memory; when k is
+    smaller than the number of groups, the groups are drawn by size.
+    Within a group memories are drawn with weighted_sample.
+    """
+    weighting = weighting or SamplingWeighting()
+    rng = rng or random.Random(weighting.seed)
+    now = now or datetime.now(timezone.utc)
+    groups: dict[str, list[dict[str, Any]]] = {}
+    for memory in memories:
+        tags = memory.get("tags") or []
+        groups.setdefault(str(tags[0]) if tags else "", []).append(memory)
+    # Groups in random order, larger ones first more often
+    order = sorted(
+        groups,
+        key=lambda tag: math.pow(rng.random(), 1.0 / len(groups[tag])),
+        reverse=True,
+    )
+    k = min(k, len(memories))
+    if k < len(order):
+        quotas = dict.fromkeys(order[:k], 1)
+    else:
+        total = len(memories)
+        quotas = {
+            tag: min(len(groups[tag]), max(1, k * len(groups[tag]) // total))
+            for tag in order
+        }
+        # The minimum of one can overshoot k: trim the largest shares
+        while sum(quotas.values()) > k:
+            largest = max(order, key=lambda tag: quotas[tag])
+            quotas[largest] -= 1
+        # Rounding leftovers go to the groups with room, in draw order
+        left = k - sum(quotas.values())
+        while left > 0:
+            for tag in order:
+                if left and quotas[tag] < len(groups[tag]):
+                    quotas[tag] += 1
+                    left -= 1
+    drawn: list[dict[str, Any]] = []
+    for tag in order:
+        if quotas.get(tag):
+            drawn.extend(
+                weighted_sample(groups[tag], quotas[tag], weighting, rng=rng, now=now)
+            )
+    return drawn
+
+
+def sample(
+    memories: list[dict[str, Any]],
+    k: int,
+    weighting: SamplingWeighting | None = None,
+    rng: random.Random | None = None,
+    now: datetime | None = None,
+) -> list[dict[str, Any]]:
+    """Draw k memories with the strategy of the weighting."""
+    weighting = weighting or SamplingWeighting()
+    if weighting.strategy == SamplingStrategy.STRATIFIED:
+        return stratified_sample(memories, k, weighting, rng=rng, now=now)
+    return weighted_sample(memories, k, weighting, rng=rng, now=now)
+
+
+async def sample_memories(
+    storage: IMemoryStorage,
+    tenant_id: str,
+    agent_id: str | None = None,
+    k: int = 10,
+    weighting: SamplingWeighting | None = None,
+    layer: str | None = None,
+    rng: random.Random | None = None,
+    clock: IClock | None = None,
+    strategy: SamplingStrategy | str | None = None,
+) -> list[dict[str, Any]]:
+    """Weighted random sample of a tenant's memories.
+
+    The sample is drawn from the weighting.pool_size most recent memories.
+
+    Args:
+        storage: Memory storage
+        tenant_id: Tenant identifier
+        agent_id: Optional agent filter
+        k: Sample size
+        weighting: Importance and recency weighting (defaults apply if None)
+        layer: Optional layer filter
+        rng: Random source (seeded from weighting.seed if None)
+        clock: Time source for recency
+        strategy: Overrides weighting.strategy
+    """
+    weighting = weighting or SamplingWeighting()
+    if strategy is not None:
+        weighting = weighting.model_copy(
+            update={"strategy": SamplingStrategy(strategy)}
+        )
+    pool = await storage.list_memories(
+        tenant_id, agent_id=agent_id, layer=layer, limit=weighting.pool_size
+    )
+    now = (clock or SystemClock()).now()
+    return sample(pool, k, weighting, rng=rng, now=now)
@@ -0,0 +1,264 @@
+"""Vector recall returning hydrated memory records.
+
+A vector store only knows ids and similarities; callers used to search it
+and then fetch every hit from storage one by one. recall_similar does the
+whole round trip: it embeds the query, searches the vector store, fetches
+the hits in one get_memories_batch call, drops records the vector index no
+longer agrees with (deleted, expired or filtered out), applies recency decay
+and reranking, and returns ScoredMemory objects carrying the record, its
+scores and how it was found.
+
+recall_similar_to does the same with an existing memory as the example
+("more like this"): its stored embedding is the query vector, so the
+embedding provider is not called, and the example itself is left out.
+"""
+
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.embedding.manager import EmbeddingManager
+from rae_core.exceptions.base import NotFoundError
+from rae_core.interfaces.embedding import IEmbeddingProvider
+from rae_core.interfaces.reranking import IReranker
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.models.consistency import SUPERSEDED_KEY
+from rae_core.models.memory import memory_confidence
+from rae_core.models.namespace import memory_namespace
+from rae_core.models.search import ScoredMemory, SimilarityRecallOptions
+from rae_core.search.recency import apply_recency
+from rae_core.search.reranking import rerank_memories
+
+logger = structlog.get_logger(__name__)
+
+# Working fields added to the records while they are ranked
+_SCORE_KEYS = ("math_score", "retrieval_score", "recency_factor", "_provenance")
+
+
+def _value(field: Any) -> Any:
+    return getattr(field, "value", field)
+
+
+def _timestamp(value: Any) -> datetime | None:
+    if isinstance(value, str):
+        value = datetime.fromisoformat(value)
+    if not isinstance(value, datetime):
+        return None
+    return value if value.tzinfo else value.replace(tzinfo=timezone.utc)
+
+
+def matches_options(
+    memory: dict[str, Any], options: SimilarityRecallOptions, now: datetime
+) -> bool:
+    """Whether a fetched record is live, current and passes the filters."""
+    if memory.get("deleted_at"):
+        return False
+    expires_at = _timestamp(memory.get("expires_at"))
+    if expires_at is not None and expires_at <= now:
+        return False
+    for field in ("agent_id", "layer", "session_id", "project"):
+        wanted = getattr(options, field)
+        if wanted is not None and _value(memory.get(field)) != wanted:
+            return False
+    metadata = memory.get("metadata") or {}
+    if metadata.get(SUPERSEDED_KEY) and not options.include_superseded:
+        return False
+    if (
+        options.min_confidence is not None
+        and memory_confidence(memory) < options.min_confidence
+    ):
+        return False
+    namespace = options.namespace
+    if namespace is not None and memory_namespace(memory) != namespace:
+        return False
+    for key, value in (options.filters or {}).items():
+        if key == "tags":
+            if not set(value) & set(memory.get("tags") or []):
+                return False
+        elif metadata.get(key) != value:
+            return False
+    return True
+
+
+async def recall_similar(
+    query: str,
+    tenant_id: str,
+    storage: IMemoryStorage,
+    vector_store: IVectorStore,
+    embedding_provider: IEmbeddingProvider,
+    options: SimilarityRecallOptions | None = None,
+    reranker: IReranker | None = None,
+    now: datetime | None = None,
+) -> list[ScoredMemory]:
+    """Memories most similar to a query, best first.
+
+    Args:
+        query: Text to recall memories for
+        tenant_id: Tenant whose memories are searched
+        storage: Storage the records are fetched from
+        vector_store: Store searched for similar vectors
+        embedding_provider: Embeds the query (an EmbeddingManager embeds it
+            with the tenant's model and searches that model's vectors)
+        options: Filters and ranking (defaults if None)
+        reranker: Second stage, used unless options.rerank is False
+        now: Reference time of expiry and recency (current time if None)
+    """
+    vector_name = _vector_name(embedding_provider, tenant_id)
+    if vector_name:
+        embedding = await embedding_provider.embed_text(
+            query, task_type="search_query", tenant_id=tenant_id
+        )
+    else:
+        embedding = await embedding_provider.embed_text(
+            query, task_type="search_query"
+        )
+    return await _recall_by_vector(
+        embedding,
+        query,
+        tenant_id,
+        storage,
+        vector_store,
+        options or SimilarityRecallOptions(),
+        vector_name,
+        {},
+        reranker,
+        now,
+    )
+
+
+async def recall_similar_to(
+    memory_id: UUID,
+    tenant_id: str,
+    storage: IMemoryStorage,
+    vector_store: IVectorStore,
+    embedding_provider: IEmbeddingProvider | None = None,
+    options: SimilarityRecallOptions | None = None,
+    reranker: IReranker | None = None,
+    now: datetime | None = None,
+) -> list[ScoredMemory]:
+    """Memories most similar to an existing memory, best first.
+
+    The example's content is the query text a reranker sees.
+
+    Args:
+        memory_id: Memory used as the example
+        embedding_provider: Only consulted for the tenant's model when it is
+            an EmbeddingManager; nothing is embedded
+        (other arguments as in recall_similar)
+
+    Raises:
+        NotFoundError: The memory does not exist for the tenant or has no
+            stored embedding
+    """
+    example = await storage.get_memory(memory_id, tenant_id)
+    if example is None:
+        raise NotFoundError("Memory", memory_id, tenant_id)
+    vector_name = _vector_name(embedding_provider, tenant_id)
+    embedding = await vector_store.get_vector(
+        memory_id, tenant_id, vector_name=vector_name
+    )
+    if not embedding:
+        raise NotFoundError("Embedding of memory", memory_id, tenant_id)
+    return await _recall_by_vector(
+        embedding,
+        str(example.get("content") or ""),
+        tenant_id,
+        storage,
+        vector_store,
+        options or SimilarityRecallOptions(),
+        vector_name,
+        {"example_id": str(memory_id)},
+        reranker,
+        now,
+        exclude=str(memory_id),
+    )
+
+
+def _vector_name(
+    embedding_provider: IEmbeddingProvider | None, tenant_id: str
+) -> str | None:
+    """Vector space of the tenant's model (None: the store's default)."""
+    if isinstance(embedding_provider, EmbeddingManager):
+        return embedding_provider.model_for_tenant(tenant_id)
+    return None
+
+
+async def _recall_by_vector(
+    embedding: list[float],
+    query: str,
+    tenant_id: str,
+    storage: IMemoryStorage,
+    vector_store: IVectorStore,
+    options: SimilarityRecallOptions,
+    vector_name: str | None,
+    provenance: dict[str, Any],
+    reranker: IReranker | None,
+    now: datetime | None,
+    exclude: str | None = None,
+) -> list[ScoredMemory]:
+    now = now or datetime.now(timezone.utc)
+    search_kwargs: dict[str, Any] = {"vector_name": vector_name} if vector_name else {}
+    hits = await vector_store.search_similar(
+        embedding,
+        tenant_id,
+        layer=options.layer,
+        limit=options.top_k * options.overfetch + (exclude is not None),
+        score_threshold=options.min_similarity,
+        agent_id=options.agent_id,
+        session_id=options.session_id,
+        filters=options.filters,
+        project=options.project,
+        **search_kwargs,
+    )
+    hits = [hit for hit in hits if str(hit[0]) != exclude]
+    if not hits:
+        return []
+
+    records = await storage.get_memories_batch([hit[0] for hit in hits], tenant_id)
+    by_id = {str(record["id"]): record for record in records}
+
+    memories = []
+    for rank, (memory_id, similarity) in enumerate(hits):
+        record = by_id.get(str(memory_id))
+        if record is None or not matches_options(record, options, now):
+            continue
+        memory = dict(record)
+        memory["math_score"] = similarity
+        memory["_provenance"] = {
+            "strategy": "vector",
+            "vector_name": vector_name or "default",
+            **provenance,
+            "similarity": similarity,
+            "similarity_rank": rank,
+            "reranked": False,
+        }
+        memories.append(memory)
+
+    if options.recency_tau_hours is not None:
+        memories = apply_recency(memories, options.recency_tau_hours, now=now)
+        for memory in memories:
+            memory["_provenance"]["recency_factor"] = memory["recency_factor"]
+
+    if reranker is not None and options.rerank is not False and memories:
+        try:
+            memories = await rerank_memories(
+                reranker, query, memories, tenant_id, depth=options.rerank_depth
+            )
+        except Exception as e:
+            logger.warning("recall_similar_rerank_failed", error=str(e))
+        for memory in memories:
+            if "retrieval_score" in memory:
+                memory["_provenance"]["reranked"] = True
+
+    return [
+        ScoredMemory(
+            memory={k: v for k, v in memory.items() if k not in _SCORE_KEYS},
+            score=float(memory["math_score"]),
+            similarity=memory["_provenance"]["similarity"],
+            provenance=memory["_provenance"],
+        )
+        for memory in memories[: options.top_k]
+    ]
@@ -2,6 +2,7 @@ from typing import Any
 from uuid import UUID
 
 from ...interfaces.storage import IMemoryStorage
+from ...models.namespace import NAMESPACE_KEY
 from . import SearchStrategy
 
 
@@ -27,6 +28,10 @@ class FullTextStrategy(SearchStrategy):
         # Default to None (search all layers) if not specified
         layer = kwargs.get("layer") or (filters or {}).get("layer")
         project = project or (filters or {}).get("project")
+        namespace = (filters or {}).get(NAMESPACE_KEY)
+        storage_kwargs: dict[str, Any] = {}
+        if namespace is not None:
+            storage_kwargs["filters"] = {NAMESPACE_KEY: namespace}
 
         results = await self.storage.search_memories(
             query=query,
@@ -35,6 +40,7 @@ class FullTextStrategy(SearchStrategy):
             agent_id=agent_id,
             layer=layer,
             project=project,
+            **storage_kwargs,
         )
 
         output: list[tuple[UUID, float, float]] = []
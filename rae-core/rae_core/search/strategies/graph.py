@@ -1,8 +1,11 @@
 from typing import Any
 from uuid import UUID
 
+from ...exceptions.base import VisitBudgetExceededError
 from ...interfaces.graph import IGraphStore
 from ...interfaces.storage import IMemoryStorage
+from ...models.graph import TraversalLimits
+from ...models.namespace import NAMESPACE_KEY, memory_namespace
 from . import SearchStrategy
 
 
@@ -14,10 +17,12 @@ class GraphTraversalStrategy(SearchStrategy):
         graph_store: IGraphStore,
         memory_storage: IMemoryStorage,
         default_weight: float = 0.5,
+        limits: TraversalLimits | None = None,
     ) -> None:
         self.graph_store = graph_store
         self.memory_storage = memory_storage
         self.default_weight = default_weight
+        self.limits = limits
 
     async def search(
         self,
@@ -53,16 +58,31 @@ class GraphTraversalStrategy(SearchStrategy):
         # Simple BFS traversal
         depth = 0
         max_depth = 2
+        limits: TraversalLimits | None = kwargs.get("traversal_limits") or self.limits
+        budget = limits.max_visited if limits else None
+        # Only pass limits when set so older graph stores keep working
+        neighbor_kwargs: dict[str, Any] = {"limits": limits} if limits else {}
+        exhausted = False
 
-        while to_visit and depth < max_depth:
+        while to_visit and depth < max_depth and not exhausted:
             current_layer = to_visit
             to_visit = []
             depth += 1
 
             for node_id in current_layer:
-                neighbors = await self.graph_store.get_neighbors(node_id, tenant_id)
+                if exhausted:
+                    break
+                neighbors = await self.graph_store.get_neighbors(
+                    node_id, tenant_id, **neighbor_kwargs
+                )
                 for neighbor_id in neighbors:
                     if neighbor_id not in visited:
+                        # Global visit budget shared by all seeds
+                        if budget is not None and len(results) >= budget:
+                            if limits is not None and limits.strict:
+                                raise VisitBudgetExceededError(budget)
+                            exhausted = True
+                            break
                         visited.add(neighbor_id)
                         to_visit.append(neighbor_id)
                         # Multi-path boost: increase score if reached via multiple paths
@@ -70,6 +90,17 @@ class GraphTraversalStrategy(SearchStrategy):
                             1.0 / depth
                         )
 
+        namespace = search_filters.get(NAMESPACE_KEY)
+        if namespace is not None and results:
+            # Edges may cross namespaces; keep the neighbors of the one asked for
+            memories = await self.memory_storage.get_memories_batch(
+                list(results), tenant_id
+            )
+            in_namespace = {
+                m["id"] for m in memories if memory_namespace(m) == namespace
+            }
+            results = {k: v for k, v in results.items() if k in in_namespace}
+
         # Sort and return
         final = sorted(results.items(), key=lambda x: x[1], reverse=True)[:limit]
         return [(m_id, score, 0.0) for m_id, score in final]
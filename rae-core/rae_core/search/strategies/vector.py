@@ -3,6 +3,8 @@ from uuid import UUID
 
 from ...interfaces.embedding import IEmbeddingProvider
 from ...interfaces.vector import IVectorStore
+from ...models.namespace import NAMESPACE_KEY
+from ...tracing import set_span_attributes, span
 from . import SearchStrategy
 
 
@@ -34,15 +36,27 @@ class VectorSearchStrategy(SearchStrategy):
         search_kwargs = kwargs.copy()
         if self.vector_name:
             search_kwargs["vector_name"] = self.vector_name
+        # Filtered in the store, so the limit counts the namespace's vectors only
+        namespace = (filters or {}).get(NAMESPACE_KEY)
+        if namespace is not None:
+            search_kwargs["filters"] = {
+                **(search_kwargs.get("filters") or {}),
+                NAMESPACE_KEY: namespace,
+            }
 
         # Generate embedding for the query
-        query_embedding = await self.embedding_provider.embed_text(
-            query, task_type="search_query"
-        )
+        with span("rae.embed_query", tenant_id=tenant_id, vector=self.vector_name):
+            query_embedding = await self.embedding_provider.embed_text(
+                query, task_type="search_query"
+            )
 
-        results = await self.vector_store.search_similar(
-            query_embedding=query_embedding, tenant_id=tenant_id, limit=limit, **search_kwargs
-        )
+        with span(
+            "rae.vector_search", tenant_id=tenant_id, vector=self.vector_name
+        ) as current:
+            results = await self.vector_store.search_similar(
+                query_embedding=query_embedding, tenant_id=tenant_id, limit=limit, **search_kwargs
+            )
+            set_span_attributes(current, result_count=len(results))
 
         # Convert to 3-tuple (id, score, importance)
         # Importance is 0.0 for now, as it's typically fetched from storage later
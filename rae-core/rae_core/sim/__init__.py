@@ -0,0 +1,16 @@
+"""Synthetic agent memory workloads.
+
+WorkloadGenerator turns a WorkloadSpec into bursty multi-tenant writes,
+Zipf-distributed reads and session churn; WorkloadSimulator runs it against
+an engine and reports throughput, latencies and recall quality.
+"""
+
+from rae_core.sim.runner import WorkloadSimulator
+from rae_core.sim.workload import Operation, WorkloadGenerator, generate_workload
+
+__all__ = [
+    "Operation",
+    "WorkloadGenerator",
+    "WorkloadSimulator",
+    "generate_workload",
+]
@@ -0,0 +1,182 @@
+"""
+RAE Workload Simulator.
+Runs a generated workload (see sim.workload) against a wired engine, tick
+by tick: the operations of a tick run concurrently, at most `concurrency`
+at once, and a tick starts when the previous one has finished. Writes go
+through store_memory, reads through search_memories and session closes
+through close_session.
+
+The report gives the throughput over the whole run, latency percentiles
+and error counts per kind of operation, and the recall quality of reads:
+the share of reads whose fact is among the top k results (recall@k) and
+the mean reciprocal rank of that fact. Reads of facts whose write failed
+are left out of the quality metrics.
+"""
+
+import asyncio
+import time
+from typing import Any
+from uuid import UUID
+
+import structlog
+
+from rae_core.config.defaults import DEFAULT_SIM_CONCURRENCY
+from rae_core.models.simulation import OperationStats, SimulationReport, WorkloadSpec
+from rae_core.sim.workload import Operation, WorkloadGenerator
+
+logger = structlog.get_logger(__name__)
+
+
+def _operation_stats(latencies: list[float], errors: int) -> OperationStats:
+    if not latencies:
+        return OperationStats(errors=errors)
+    ordered = sorted(latencies)
+
+    def pick(q: float) -> float:
+        index = min(len(ordered) - 1, int(round(q * (len(ordered) - 1))))
+        return round(ordered[index] * 1000, 3)
+
+    return OperationStats(
+        count=len(ordered),
+        errors=errors,
+        p50_ms=pick(0.5),
+        p95_ms=pick(0.95),
+        p99_ms=pick(0.99),
+        max_ms=round(ordered[-1] * 1000, 3),
+    )
+
+
+class WorkloadSimulator:
+    """Runs a synthetic workload against an engine and measures it."""
+
+    def __init__(
+        self,
+        engine: Any,
+        spec: WorkloadSpec | None = None,
+        concurrency: int = DEFAULT_SIM_CONCURRENCY,
+    ):
+        """Initialize simulator.
+
+        Args:
+            engine: RAEEngine (or an object with its store_memory,
+                search_memories and close_session)
+            spec: Workload to generate (WorkloadSpec() if None)
+            concurrency: Operations of a tick run at once
+        """
+        self.engine = engine
+        self.spec = spec or WorkloadSpec()
+        self.concurrency = max(1, concurrency)
+
+    async def run(self) -> SimulationReport:
+        """Generate the workload, run it and report the measurements."""
+        generator = WorkloadGenerator(self.spec)
+        semaphore = asyncio.Semaphore(self.concurrency)
+        stored: dict[tuple[str, int], UUID] = {}
+        latencies: dict[str, list[float]] = {
+            "write": [],
+            "read": [],
+            "close_session": [],
+        }
+        errors = dict.fromkeys(latencies, 0)
+        ranks: dict[str, list[int | None]] = {}
+
+        async def execute(operation: Operation) -> None:
+            async with semaphore:
+                started = time.perf_counter()
+                try:
+                    rank = await self._execute(operation, stored)
+                except Exception as e:
+                    errors[operation.kind] += 1
+                    logger.warning(
+                        "simulated_operation_failed",
+                        kind=operation.kind,
+                        tenant_id=operation.tenant_id,
+                        error=str(e),
+                    )
+                    return
+                latencies[operation.kind].append(time.perf_counter() - started)
+                if operation.kind == "read" and rank != -1:
+                    ranks.setdefault(operation.tenant_id, []).append(rank)
+
+        started = time.perf_counter()
+        operations = 0
+        for tick in generator.ticks():
+            operations += len(tick)
+            await asyncio.gather(*(execute(operation) for operation in tick))
+        duration = time.perf_counter() - started
+
+        report = SimulationReport(
+            spec=self.spec,
+            duration_s=round(duration, 3),
+            operations=operations,
+            throughput_ops_s=round(operations / duration, 2) if duration else 0.0,
+            writes=_operation_stats(latencies["write"], errors["write"]),
+            reads=_operation_stats(latencies["read"], errors["read"]),
+            session_closes=_operation_stats(
+                latencies["close_session"], errors["close_session"]
+            ),
+            sessions=generator.sessions_started,
+        )
+        judged = [rank for tenant_ranks in ranks.values() for rank in tenant_ranks]
+        if judged:
+            report.recall_at_k = round(
+                sum(rank is not None for rank in judged) / len(judged), 4
+            )
+            report.mrr = round(
+                sum(1.0 / rank for rank in judged if rank is not None) / len(judged),
+                4,
+            )
+        report.per_tenant_recall = {
+            tenant_id: round(
+                sum(rank is not None for rank in tenant_ranks) / len(tenant_ranks), 4
+            )
+            for tenant_id, tenant_ranks in sorted(ranks.items())
+        }
+        logger.info(
+            "simulation_completed",
+            operations=operations,
+            duration_s=report.duration_s,
+            throughput_ops_s=report.throughput_ops_s,
+            recall_at_k=report.recall_at_k,
+        )
+        return report
+
+    async def _execute(
+        self, operation: Operation, stored: dict[tuple[str, int], UUID]
+    ) -> int | None:
+        """Run an operation; a read returns the 1-based rank of its fact.
+
+        A read returns None when its fact is not in the top k, and -1 when
+        the fact was never stored.
+        """
+        engine, spec = self.engine, self.spec
+        if operation.kind == "write":
+            memory_id = await engine.store_memory(
+                content=operation.text,
+                tenant_id=operation.tenant_id,
+                agent_id=operation.agent_id,
+                layer=spec.layer,
+                session_id=operation.session_id,
+                tags=["sim"],
+            )
+            stored[(operation.tenant_id, operation.fact)] = memory_id
+            return None
+        if operation.kind == "close_session":
+            await engine.close_session(
+                operation.tenant_id, operation.session_id, agent_id=operation.agent_id
+            )
+            return None
+
+        results = await engine.search_memories(
+            operation.text,
+            operation.tenant_id,
+            top_k=spec.top_k,
+            session_id=operation.session_id if operation.session_scoped else None,
+        )
+        target = stored.get((operation.tenant_id, operation.fact))
+        if target is None:
+            return -1
+        for rank, memory in enumerate(results[: spec.top_k], 1):
+            if str(memory.get("id")) == str(target):
+                return rank
+        return None
@@ -0,0 +1,227 @@
+"""
+RAE Workload Generator.
+Turns a WorkloadSpec into a deterministic sequence of ticks, each a list of
+operations of every tenant:
+- writes store synthetic facts ("The billing service uses Redis for
+  caching") in the agent's current session; their count per tick is Poisson
+  distributed around writes_per_tick, except in burst ticks where a tenant
+  writes burst_size facts at once
+- reads ask for a fact written in an earlier tick ("Which caching does the
+  billing service use?"), picked with a Zipf distribution so the first
+  facts of a tenant are read far more often than the rest; a share of them
+  is scoped to the session that wrote the fact while it is still open
+- session closes end an agent's session after a geometrically distributed
+  number of ticks (mean session_length) and start a new one
+
+The same spec (seed included) always yields the same workload.
+"""
+
+import bisect
+import math
+import random
+from collections.abc import Iterator
+from dataclasses import dataclass, field
+
+from rae_core.models.simulation import WorkloadSpec
+
+SUBJECTS = (
+    "billing",
+    "search",
+    "checkout",
+    "auth",
+    "reporting",
+    "onboarding",
+    "inventory",
+    "notifications",
+)
+COMPONENTS = ("service", "worker", "gateway", "scheduler", "dashboard", "pipeline")
+PURPOSES = (
+    "caching",
+    "storage",
+    "queueing",
+    "monitoring",
+    "deployment",
+    "logging",
+    "authentication",
+    "tracing",
+)
+VALUES = (
+    "Redis",
+    "Postgres",
+    "Kafka",
+    "Prometheus",
+    "Kubernetes",
+    "Elasticsearch",
+    "Vault",
+    "Jaeger",
+    "RabbitMQ",
+    "S3",
+)
+
+
+@dataclass
+class Operation:
+    """One operation of a simulated workload."""
+
+    kind: str  # "write", "read" or "close_session"
+    tick: int
+    tenant_id: str
+    agent_id: str
+    session_id: str
+    fact: int | None = None  # Index of the fact among the tenant's facts
+    text: str = ""  # Content of a write, query of a read
+    session_scoped: bool = False  # Read restricted to session_id
+
+
+@dataclass
+class _Tenant:
+    tenant_id: str
+    sessions: dict[str, str]  # Current session of each agent
+    facts: list[tuple[str, str]] = field(default_factory=list)  # (query, session)
+    cumulative_weights: list[float] = field(default_factory=list)
+    topics: set[tuple[str, str, str]] = field(default_factory=set)
+
+
+def _poisson(rng: random.Random, mean: float) -> int:
+    """Knuth's sampler (the means of a workload are small)."""
+    if mean <= 0:
+        return 0
+    limit, count, product = math.exp(-mean), 0, rng.random()
+    while product > limit:
+        count += 1
+        product *= rng.random()
+    return count
+
+
+class WorkloadGenerator:
+    """Generates the ticks of a workload (see the module docstring)."""
+
+    def __init__(self, spec: WorkloadSpec):
+        self.spec = spec
+        self.sessions_started = 0
+        self._rng = random.Random(spec.seed)
+        self._session_counter = 0
+        self._tenants = [
+            _Tenant(
+                f"sim-tenant-{t}",
+                {
+                    f"agent-{a}": self._new_session(f"sim-tenant-{t}", f"agent-{a}")
+                    for a in range(spec.agents_per_tenant)
+                },
+            )
+            for t in range(spec.tenants)
+        ]
+
+    def ticks(self) -> Iterator[list[Operation]]:
+        """Operations of each tick, in order."""
+        for tick in range(self.spec.ticks):
+            yield self._tick(tick)
+
+    def _new_session(self, tenant_id: str, agent_id: str) -> str:
+        self._session_counter += 1
+        self.sessions_started += 1
+        return f"{tenant_id}-{agent_id}-s{self._session_counter}"
+
+    def _tick(self, tick: int) -> list[Operation]:
+        spec, rng = self.spec, self._rng
+        operations: list[Operation] = []
+        for tenant in self._tenants:
+            # Reads only ask for facts of earlier ticks, which are stored
+            # by the time this tick runs
+            if tenant.facts:
+                for _ in range(_poisson(rng, spec.reads_per_tick)):
+                    operations.append(self._read(tick, tenant))
+
+            burst = rng.random() < spec.burst_probability
+            writes = spec.burst_size if burst else _poisson(rng, spec.writes_per_tick)
+            for _ in range(writes):
+                operations.append(self._write(tick, tenant))
+
+            for agent_id, session_id in list(tenant.sessions.items()):
+                if rng.random() < 1.0 / spec.session_length:
+                    close = Operation(
+                        "close_session", tick, tenant.tenant_id, agent_id, session_id
+                    )
+                    operations.append(close)
+                    tenant.sessions[agent_id] = self._new_session(
+                        tenant.tenant_id, agent_id
+                    )
+        return operations
+
+    def _write(self, tick: int, tenant: _Tenant) -> Operation:
+        rng = self._rng
+        agent_id = rng.choice(sorted(tenant.sessions))
+        session_id = tenant.sessions[agent_id]
+        subject, component, purpose = self._topic(tenant)
+        value = rng.choice(VALUES)
+        index = len(tenant.facts)
+        tenant.facts.append(
+            (f"Which {purpose} does the {subject} {component} use?", session_id)
+        )
+        previous = tenant.cumulative_weights[-1] if tenant.cumulative_weights else 0.0
+        tenant.cumulative_weights.append(
+            previous + 1.0 / (index + 1) ** self.spec.zipf_exponent
+        )
+        return Operation(
+            "write",
+            tick,
+            tenant.tenant_id,
+            agent_id,
+            session_id,
+            fact=index,
+            text=f"The {subject} {component} uses {value} for {purpose}.",
+        )
+
+    def _topic(self, tenant: _Tenant) -> tuple[str, str, str]:
+        """A subject, component and purpose no earlier fact of the tenant has."""
+        rng = self._rng
+        while len(tenant.topics) < len(SUBJECTS) * len(COMPONENTS) * len(PURPOSES):
+            topic = (
+                rng.choice(SUBJECTS),
+                rng.choice(COMPONENTS),
+                rng.choice(PURPOSES),
+            )
+            if topic not in tenant.topics:
+                tenant.topics.add(topic)
+                return topic
+        # Every topic is taken: number the subject to keep facts distinct
+        subject, component, purpose = (
+            rng.choice(SUBJECTS),
+            rng.choice(COMPONENTS),
+            rng.choice(PURPOSES),
+        )
+        return f"{subject}-{len(tenant.facts)}", component, purpose
+
+    def _read(self, tick: int, tenant: _Tenant) -> Operation:
+        rng = self._rng
+        weights = tenant.cumulative_weights
+        index = bisect.bisect_left(weights, rng.random() * weights[-1])
+        query, session_id = tenant.facts[index]
+        writer = next(
+            (agent for agent, s in tenant.sessions.items() if s == session_id), None
+        )
+        if writer is not None and rng.random() < self.spec.session_read_share:
+            return Operation(
+                "read",
+                tick,
+                tenant.tenant_id,
+                writer,
+                session_id,
+                fact=index,
+                text=query,
+                session_scoped=True,
+            )
+        agent_id = rng.choice(sorted(tenant.sessions))
+        return Operation(
+            "read",
+            tick,
+            tenant.tenant_id,
+            agent_id,
+            tenant.sessions[agent_id],
+            fact=index,
+            text=query,
+        )
+
+def generate_workload(spec: WorkloadSpec) -> Iterator[list[Operation]]:
+    """Operations of each tick of a workload."""
+    return WorkloadGenerator(spec).ticks()
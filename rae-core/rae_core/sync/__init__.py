@@ -7,7 +7,6 @@ from rae_core.sync.diff import (
     calculate_memory_diff,
     get_sync_direction,
 )
-from rae_core.sync.encryption import E2EEncryption, decrypt_batch, encrypt_batch
 from rae_core.sync.merge import (
     ConflictResolutionStrategy,
     ConflictResolver,
@@ -16,6 +15,20 @@ from rae_core.sync.merge import (
     merge_memories,
 )
 from rae_core.sync.protocol import SyncMetadata, SyncProtocol, SyncRequest, SyncResponse
+from rae_core.sync.snapshot import (
+    SnapshotImportResult,
+    export_tenant,
+    import_tenant,
+    read_snapshot,
+)
+
+# Encryption requires the optional "crypto" extra
+try:
+    from rae_core.sync.encryption import E2EEncryption, decrypt_batch, encrypt_batch
+except ImportError:
+    E2EEncryption = None  # type: ignore
+    decrypt_batch = None  # type: ignore
+    encrypt_batch = None  # type: ignore
 
 __all__ = [
     # Protocol
@@ -39,4 +52,9 @@ __all__ = [
     "E2EEncryption",
     "encrypt_batch",
     "decrypt_batch",
+    # Snapshot
+    "export_tenant",
+    "import_tenant",
+    "read_snapshot",
+    "SnapshotImportResult",
 ]
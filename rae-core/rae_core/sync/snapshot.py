@@ -0,0 +1,391 @@
+"""Tenant snapshot export/import for backup and backend migration.
+
+A snapshot is a versioned JSONL stream:
+
+    {"type": "header", "format": "rae-snapshot", "version": 1, ...}
+    {"type": "memory", "data": {...}, "checksum": "<sha256>"}
+    {"type": "vector", "data": {...}, "checksum": "<sha256>"}
+    {"type": "node", "data": {...}, "checksum": "<sha256>"}
+    {"type": "edge", "data": {...}, "checksum": "<sha256>"}
+    {"type": "footer", "counts": {...}, "digest": "<sha256 of all records>"}
+
+A vector record holds one embedding model's vector of a memory (named by
+vector_name; the store's default vector when absent).
+
+Every record carries its own checksum and the footer digest covers the whole
+record sequence, so truncated or tampered archives are rejected before any
+data is written to the target backend.
+"""
+
+import hashlib
+import json
+from collections.abc import AsyncIterable, AsyncIterator, Iterable
+from datetime import datetime, timezone
+from typing import Any
+from uuid import UUID
+
+from pydantic import BaseModel, Field
+
+from rae_core.embedding.migration import vector_metadata
+from rae_core.exceptions.base import ValidationError
+from rae_core.governance.ephemeral import is_ephemeral
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.storage import LIFECYCLE_FIELDS, IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+
+SNAPSHOT_FORMAT = "rae-snapshot"
+SNAPSHOT_VERSION = 1
+
+# Fields accepted by IMemoryStorage.store_memory when restoring a record
+_RESTORABLE_FIELDS = (
+    "content",
+    "layer",
+    "agent_id",
+    "tags",
+    "metadata",
+    "importance",
+    "expires_at",
+    "memory_type",
+    "strength",
+    "project",
+    "session_id",
+    "source",
+)
+
+
+class SnapshotImportResult(BaseModel):
+    """Outcome of a snapshot import."""
+
+    source_tenant_id: str
+    target_tenant_id: str
+    memories: int = 0
+    vectors: int = 0
+    nodes: int = 0
+    edges: int = 0
+    id_map: dict[str, str] = Field(
+        default_factory=dict, description="Snapshot memory ID -> new memory ID"
+    )
+
+
+def _json_default(obj: Any) -> str:
+    if isinstance(obj, UUID):
+        return str(obj)
+    if isinstance(obj, datetime):
+        return obj.isoformat()
+    raise TypeError(f"Type {type(obj)} not serializable")
+
+
+def _canonical(data: Any) -> str:
+    return json.dumps(data, sort_keys=True, default=_json_default)
+
+
+def _checksum(data: Any) -> str:
+    return hashlib.sha256(_canonical(data).encode("utf-8")).hexdigest()
+
+
+def _parse_properties(value: Any) -> dict[str, Any]:
+    if isinstance(value, str):
+        try:
+            parsed = json.loads(value)
+        except ValueError:
+            return {}
+        return parsed if isinstance(parsed, dict) else {}
+    return dict(value) if isinstance(value, dict) else {}
+
+
+def _remap_ids(value: Any, id_map: dict[str, str]) -> Any:
+    """Copy of value with snapshot memory IDs replaced by the new ones."""
+    if isinstance(value, str):
+        return id_map.get(value, value)
+    if isinstance(value, list):
+        return [_remap_ids(item, id_map) for item in value]
+    if isinstance(value, dict):
+        return {key: _remap_ids(item, id_map) for key, item in value.items()}
+    return value
+
+
+def _normalize_node(node: dict[str, Any]) -> dict[str, Any]:
+    return {
+        "id": str(node.get("id")),
+        "node_type": node.get("node_type") or node.get("type") or "memory",
+        "properties": _parse_properties(node.get("properties")),
+    }
+
+
+def _normalize_edge(edge: dict[str, Any]) -> dict[str, Any]:
+    return {
+        "source_id": str(edge.get("source_id")),
+        "target_id": str(edge.get("target_id")),
+        "edge_type": edge.get("edge_type") or edge.get("type") or "relates_to",
+        "weight": float(edge.get("weight", 1.0)),
+        "properties": _parse_properties(edge.get("properties")),
+    }
+
+
+async def export_tenant(
+    storage: IMemoryStorage,
+    tenant_id: str,
+    vector_store: IVectorStore | None = None,
+    graph_store: IGraphStore | None = None,
+    page_size: int = 500,
+    vector_names: Iterable[str] | None = None,
+) -> AsyncIterator[bytes]:
+    """Stream all data of a tenant as a checksummed JSONL snapshot.
+
+    Graph nodes and edges are exported for the subgraph spanned by the
+    tenant's memories. Soft-deleted memories are exported with their
+    deleted_at; ephemeral memories are not exported.
+
+    Args:
+        storage: Memory storage to export from
+        tenant_id: Tenant to export
+        vector_store: Optional vector store holding the tenant's embeddings
+        graph_store: Optional graph store holding the tenant's knowledge graph
+        page_size: Number of memories fetched per storage call
+        vector_names: Embedding models whose vectors are exported (the
+            store's default vector of each memory if None)
+
+    Yields:
+        UTF-8 encoded JSONL lines
+    """
+    digest = hashlib.sha256()
+    counts = {"memory": 0, "vector": 0, "node": 0, "edge": 0}
+
+    def record(kind: str, data: dict[str, Any]) -> bytes:
+        checksum = _checksum(data)
+        digest.update(checksum.encode("utf-8"))
+        counts[kind] += 1
+        line = _canonical({"type": kind, "data": data, "checksum": checksum})
+        return (line + "\n").encode("utf-8")
+
+    header = {
+        "type": "header",
+        "format": SNAPSHOT_FORMAT,
+        "version": SNAPSHOT_VERSION,
+        "tenant_id": tenant_id,
+        "created_at": datetime.now(timezone.utc).isoformat(),
+    }
+    yield (_canonical(header) + "\n").encode("utf-8")
+
+    memory_ids: list[UUID] = []
+    offset = 0
+    while True:
+        page = await storage.list_memories(
+            tenant_id, limit=page_size, offset=offset, include_deleted=True
+        )
+        for memory in page:
+            if is_ephemeral(memory):
+                continue
+            data = {k: v for k, v in memory.items() if k != "embedding"}
+            memory_ids.append(memory["id"])
+            yield record("memory", data)
+        if len(page) < page_size:
+            break
+        offset += page_size
+
+    if vector_store is not None:
+        names: list[str | None] = list(vector_names) if vector_names else [None]
+        for memory_id in memory_ids:
+            for name in names:
+                vector = await vector_store.get_vector(memory_id, tenant_id, name)
+                if vector is None:
+                    continue
+                data = {"memory_id": str(memory_id), "embedding": vector}
+                if name is not None:
+                    data["vector_name"] = name
+                yield record("vector", data)
+
+    if graph_store is not None and memory_ids:
+        subgraph = await graph_store.get_subgraph(memory_ids, tenant_id)
+        for node in subgraph.get("nodes", []):
+            yield record("node", _normalize_node(node))
+        for edge in subgraph.get("edges", []):
+            yield record("edge", _normalize_edge(edge))
+
+    footer = {"type": "footer", "counts": counts, "digest": digest.hexdigest()}
+    yield (_canonical(footer) + "\n").encode("utf-8")
+
+
+async def _iter_chunks(
+    chunks: AsyncIterable[bytes] | Iterable[bytes],
+) -> AsyncIterator[bytes]:
+    if isinstance(chunks, AsyncIterable):
+        async for chunk in chunks:
+            yield chunk
+    else:
+        for chunk in chunks:
+            yield chunk
+
+
+async def _iter_lines(
+    chunks: AsyncIterable[bytes] | Iterable[bytes],
+) -> AsyncIterator[bytes]:
+    """Re-split arbitrary byte chunks into lines."""
+    buffer = b""
+    async for chunk in _iter_chunks(chunks):
+        buffer += chunk
+        *lines, buffer = buffer.split(b"\n")
+        for line in lines:
+            yield line
+    if buffer:
+        yield buffer
+
+
+async def read_snapshot(
+    chunks: AsyncIterable[bytes] | Iterable[bytes],
+) -> tuple[dict[str, Any], list[dict[str, Any]]]:
+    """Parse and verify a snapshot stream.
+
+    Returns:
+        (header, records)
+
+    Raises:
+        ValidationError: If the stream is malformed, of an unsupported
+            version, or fails checksum verification
+    """
+    header: dict[str, Any] | None = None
+    footer: dict[str, Any] | None = None
+    records: list[dict[str, Any]] = []
+    digest = hashlib.sha256()
+
+    async for raw in _iter_lines(chunks):
+        if not raw.strip():
+            continue
+        if footer is not None:
+            raise ValidationError("Snapshot contains data after footer")
+        try:
+            entry = json.loads(raw)
+        except ValueError as e:
+            raise ValidationError(f"Malformed snapshot line: {e}") from e
+
+        kind = entry.get("type")
+        if header is None:
+            if kind != "header" or entry.get("format") != SNAPSHOT_FORMAT:
+                raise ValidationError("Snapshot is missing its header")
+            if entry.get("version") != SNAPSHOT_VERSION:
+                raise ValidationError(
+                    f"Unsupported snapshot version: {entry.get('version')}"
+                )
+            header = entry
+        elif kind == "footer":
+            footer = entry
+        elif kind in ("memory", "vector", "node", "edge"):
+            if _checksum(entry.get("data")) != entry.get("checksum"):
+                raise ValidationError(f"Checksum mismatch in {kind} record")
+            digest.update(entry["checksum"].encode("utf-8"))
+            records.append(entry)
+        else:
+            raise ValidationError(f"Unknown snapshot record type: {kind}")
+
+    if header is None:
+        raise ValidationError("Snapshot is empty")
+    if footer is None:
+        raise ValidationError("Snapshot is truncated (no footer)")
+    if footer.get("digest") != digest.hexdigest():
+        raise ValidationError("Snapshot digest mismatch")
+
+    counts: dict[str, int] = {}
+    for entry in records:
+        counts[entry["type"]] = counts.get(entry["type"], 0) + 1
+    for kind, expected in (footer.get("counts") or {}).items():
+        if counts.get(kind, 0) != expected:
+            raise ValidationError(f"Snapshot {kind} count mismatch")
+
+    return header, records
+
+
+async def import_tenant(
+    storage: IMemoryStorage,
+    chunks: AsyncIterable[bytes] | Iterable[bytes],
+    tenant_id: str | None = None,
+    vector_store: IVectorStore | None = None,
+    graph_store: IGraphStore | None = None,
+) -> SnapshotImportResult:
+    """Restore a snapshot produced by export_tenant.
+
+    The whole stream is verified before anything is written. Memories receive
+    new IDs in the target backend; vectors, graph nodes and edges referencing
+    them are remapped accordingly, and so are snapshot memory IDs in memory
+    metadata (superseded_by, merged_from, ...). Vectors get the vector
+    metadata of their memory, so filtered vector searches work after the
+    restore. Timestamps, access counts, versions and trash state are
+    restored with IMemoryStorage.set_lifecycle.
+
+    Args:
+        storage: Target memory storage
+        chunks: Snapshot byte stream
+        tenant_id: Target tenant (defaults to the tenant in the snapshot)
+        vector_store: Optional target vector store
+        graph_store: Optional target graph store
+    """
+    header, records = await read_snapshot(chunks)
+    source_tenant = header["tenant_id"]
+    target_tenant = tenant_id or source_tenant
+
+    result = SnapshotImportResult(
+        source_tenant_id=source_tenant, target_tenant_id=target_tenant
+    )
+
+    memories = [entry["data"] for entry in records if entry["type"] == "memory"]
+    new_ids: list[UUID] = []
+    for data in memories:
+        kwargs = {k: data[k] for k in _RESTORABLE_FIELDS if k in data}
+        if isinstance(kwargs.get("expires_at"), str):
+            kwargs["expires_at"] = datetime.fromisoformat(kwargs["expires_at"])
+        new_id = await storage.store_memory(tenant_id=target_tenant, **kwargs)
+        new_ids.append(new_id)
+        result.id_map[str(data["id"])] = str(new_id)
+
+    # References can point at memories stored after the referencing one, so
+    # metadata is remapped once every memory has its new ID
+    vector_metadatas: dict[str, dict[str, Any]] = {}
+    for data, new_id in zip(memories, new_ids):
+        metadata = data.get("metadata") or {}
+        remapped = _remap_ids(metadata, result.id_map)
+        if remapped != metadata:
+            await storage.update_memory(new_id, target_tenant, {"metadata": remapped})
+        vector_metadatas[str(new_id)] = vector_metadata(
+            {**data, "metadata": remapped}
+        )
+        lifecycle = {k: data[k] for k in LIFECYCLE_FIELDS if k in data}
+        for field in ("created_at", "modified_at", "last_accessed_at", "deleted_at"):
+            if isinstance(lifecycle.get(field), str):
+                lifecycle[field] = datetime.fromisoformat(lifecycle[field])
+        # Trashing through the storage lets wrappers release quota and notify
+        if lifecycle.get("deleted_at"):
+            await storage.soft_delete_memory(new_id, target_tenant)
+        await storage.set_lifecycle(new_id, target_tenant, lifecycle)
+        result.memories += 1
+
+    def remap(old_id: str) -> UUID:
+        return UUID(result.id_map.get(old_id, old_id))
+
+    for entry in records:
+        kind, data = entry["type"], entry["data"]
+        if kind == "vector" and vector_store is not None:
+            memory_id = remap(data["memory_id"])
+            name = data.get("vector_name")
+            embedding = {name: data["embedding"]} if name else data["embedding"]
+            # A copy per vector: stores may add their own keys to it
+            metadata = dict(vector_metadatas.get(str(memory_id)) or {})
+            if await vector_store.store_vector(
+                memory_id, embedding, target_tenant, metadata=metadata
+            ):
+                result.vectors += 1
+        elif kind == "node" and graph_store is not None:
+            if await graph_store.create_node(
+                remap(data["id"]), data["node_type"], target_tenant, data["properties"]
+            ):
+                result.nodes += 1
+        elif kind == "edge" and graph_store is not None:
+            if await graph_store.create_edge(
+                remap(data["source_id"]),
+                remap(data["target_id"]),
+                data["edge_type"],
+                target_tenant,
+                weight=data["weight"],
+                properties=data["properties"],
+            ):
+                result.edges += 1
+
+    return result
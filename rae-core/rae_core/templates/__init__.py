@@ -0,0 +1,10 @@
+"""Memory templates for structured memory types (meeting notes, decisions...)."""
+
+from rae_core.templates.registry import (
+    BUILTIN_TEMPLATES,
+    DECISION_RECORD,
+    MEETING_NOTE,
+    TemplateRegistry,
+)
+
+__all__ = ["BUILTIN_TEMPLATES", "DECISION_RECORD", "MEETING_NOTE", "TemplateRegistry"]
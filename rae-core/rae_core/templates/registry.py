@@ -0,0 +1,232 @@
+"""Registry of memory templates for structured memory types."""
+
+import string
+from datetime import date, datetime
+from typing import Any
+
+import pydantic
+from pydantic import create_model
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.models.template import MemoryTemplate, TemplateField, TemplateFieldType
+
+# Metadata keys written by templates; field names may not shadow them
+TEMPLATE_KEY = "template"
+TEMPLATE_VERSION_KEY = "template_version"
+RESERVED_FIELDS = frozenset({TEMPLATE_KEY, TEMPLATE_VERSION_KEY, "info_class"})
+
+_PYTHON_TYPES: dict[TemplateFieldType, Any] = {
+    TemplateFieldType.STRING: str,
+    TemplateFieldType.INTEGER: int,
+    TemplateFieldType.FLOAT: float,
+    TemplateFieldType.BOOLEAN: bool,
+    TemplateFieldType.LIST: list[str],
+    TemplateFieldType.DATE: date,
+    TemplateFieldType.DATETIME: datetime,
+}
+
+MEETING_NOTE = MemoryTemplate(
+    name="meeting_note",
+    description="Notes taken during a meeting",
+    fields=[
+        TemplateField(name="title"),
+        TemplateField(name="date", type=TemplateFieldType.DATE),
+        TemplateField(name="summary"),
+        TemplateField(name="attendees", type=TemplateFieldType.LIST, required=False),
+        TemplateField(
+            name="action_items", type=TemplateFieldType.LIST, required=False
+        ),
+    ],
+    content_template=(
+        "Meeting note: {title}\n"
+        "Date: {date}\n"
+        "Attendees: {attendees}\n"
+        "Summary: {summary}\n"
+        "Action items: {action_items}"
+    ),
+    layer="episodic",
+    tags=["meeting"],
+)
+
+DECISION_RECORD = MemoryTemplate(
+    name="decision_record",
+    description="Architecture/decision record (ADR)",
+    fields=[
+        TemplateField(name="title"),
+        TemplateField(name="decision"),
+        TemplateField(name="status", required=False, default="accepted"),
+        TemplateField(name="context", required=False),
+        TemplateField(
+            name="alternatives", type=TemplateFieldType.LIST, required=False
+        ),
+        TemplateField(name="consequences", required=False),
+    ],
+    content_template=(
+        "Decision: {title}\n"
+        "Status: {status}\n"
+        "Context: {context}\n"
+        "Decision: {decision}\n"
+        "Alternatives considered: {alternatives}\n"
+        "Consequences: {consequences}"
+    ),
+    layer="semantic",
+    tags=["decision"],
+)
+
+BUILTIN_TEMPLATES = (MEETING_NOTE, DECISION_RECORD)
+
+
+def _is_empty(value: Any) -> bool:
+    return value is None or value == "" or value == []
+
+
+def _format_value(value: Any) -> str:
+    if isinstance(value, list):
+        return ", ".join(str(v) for v in value)
+    if isinstance(value, (date, datetime)):
+        return value.isoformat()
+    return str(value)
+
+
+class TemplateRegistry:
+    """Named memory templates.
+
+    Filling a template validates the fields, renders canonical content (which
+    is what gets embedded) and returns the structured fields as flat metadata
+    keys, so they stay queryable with metadata filters, e.g.
+    `list_memories(tenant, filters={"template": "decision_record",
+    "status": "accepted"})`.
+    """
+
+    def __init__(self, include_builtins: bool = True):
+        self._templates: dict[str, MemoryTemplate] = {}
+        self._validators: dict[str, type[pydantic.BaseModel]] = {}
+        if include_builtins:
+            for template in BUILTIN_TEMPLATES:
+                self.register(template)
+
+    def register(self, template: MemoryTemplate, replace: bool = False) -> None:
+        """Add a template.
+
+        Raises:
+            ValidationError: Name already registered (without replace), or the
+                template references unknown or reserved fields
+        """
+        if template.name in self._templates and not replace:
+            raise ValidationError(f"Template already registered: {template.name}")
+
+        names = [f.name for f in template.fields]
+        reserved = RESERVED_FIELDS.intersection(names)
+        if reserved:
+            raise ValidationError(
+                f"Reserved template field names: {sorted(reserved)}"
+            )
+        if len(set(names)) != len(names):
+            raise ValidationError(f"Duplicate fields in template {template.name}")
+        placeholders = self._placeholders(template.content_template)
+        unknown = placeholders - set(names)
+        if unknown:
+            raise ValidationError(
+                f"Template {template.name} renders unknown fields: {sorted(unknown)}"
+            )
+
+        definitions: dict[str, Any] = {}
+        for f in template.fields:
+            annotation = _PYTHON_TYPES[f.type]
+            if f.required:
+                definitions[f.name] = (annotation, ...)
+            else:
+                definitions[f.name] = (annotation | None, f.default)
+        self._validators[template.name] = create_model(
+            f"{template.name}_fields", **definitions
+        )
+        self._templates[template.name] = template
+
+    def unregister(self, name: str) -> bool:
+        self._validators.pop(name, None)
+        return self._templates.pop(name, None) is not None
+
+    def get(self, name: str) -> MemoryTemplate:
+        template = self._templates.get(name)
+        if template is None:
+            raise ValidationError(f"Unknown memory template: {name}")
+        return template
+
+    def list_templates(self) -> list[MemoryTemplate]:
+        return list(self._templates.values())
+
+    def validate(self, name: str, values: dict[str, Any]) -> dict[str, Any]:
+        """Check required fields and types, returning JSON-safe field values.
+
+        Raises:
+            ValidationError: Unknown template, unknown field, missing required
+                field or value of the wrong type
+        """
+        template = self.get(name)
+        unknown = set(values) - {f.name for f in template.fields}
+        if unknown:
+            raise ValidationError(
+                f"Unknown fields for template {name}: {sorted(unknown)}"
+            )
+        try:
+            model = self._validators[name](**values)
+        except pydantic.ValidationError as e:
+            problems = "; ".join(
+                f"{'.'.join(str(p) for p in err['loc'])}: {err['msg']}"
+                for err in e.errors()
+            )
+            raise ValidationError(
+                f"Invalid fields for template {name}: {problems}"
+            ) from e
+        return model.model_dump(mode="json")
+
+    def render(self, name: str, values: dict[str, Any]) -> str:
+        """Render canonical content from validated field values."""
+        template = self.get(name)
+        lines = []
+        for line in template.content_template.split("\n"):
+            referenced = self._placeholders(line)
+            if referenced and all(_is_empty(values.get(f)) for f in referenced):
+                continue
+            rendered = {
+                f: "" if _is_empty(values.get(f)) else _format_value(values[f])
+                for f in referenced
+            }
+            lines.append(line.format_map(rendered))
+        return "\n".join(lines)
+
+    def build(
+        self,
+        name: str,
+        values: dict[str, Any],
+        metadata: dict[str, Any] | None = None,
+        tags: list[str] | None = None,
+    ) -> dict[str, Any]:
+        """Validate and render a template into store_memory arguments.
+
+        Returns:
+            Dict with content, layer, tags and metadata
+        """
+        template = self.get(name)
+        fields = self.validate(name, values)
+        merged_tags = list(template.tags)
+        merged_tags += [t for t in tags or [] if t not in merged_tags]
+        return {
+            "content": self.render(name, fields),
+            "layer": template.layer,
+            "tags": merged_tags,
+            "metadata": {
+                **(metadata or {}),
+                **fields,
+                TEMPLATE_KEY: template.name,
+                TEMPLATE_VERSION_KEY: template.version,
+            },
+        }
+
+    @staticmethod
+    def _placeholders(text: str) -> set[str]:
+        return {
+            field_name
+            for _, field_name, _, _ in string.Formatter().parse(text)
+            if field_name
+        }
@@ -0,0 +1,36 @@
+"""Test helpers for RAE-core backend implementations.
+
+Conformance kits of property-based checks that custom backends must pass:
+storage (IMemoryStorage, optionally with its vector and graph stores),
+vector (IVectorStore) and graph (IGraphStore). Each runs as an async
+run_*_conformance function returning a ConformanceReport, or as a pytest
+base class to subclass with a factory for the backend.
+"""
+
+from rae_core.testing.conformance import (
+    ConformanceFailure,
+    ConformanceReport,
+    run_checks,
+)
+from rae_core.testing.graph import GraphConformance, run_graph_conformance
+from rae_core.testing.storage import (
+    Dataset,
+    StorageBackends,
+    StorageConformance,
+    run_storage_conformance,
+)
+from rae_core.testing.vector import VectorConformance, run_vector_conformance
+
+__all__ = [
+    "ConformanceFailure",
+    "ConformanceReport",
+    "Dataset",
+    "GraphConformance",
+    "StorageBackends",
+    "StorageConformance",
+    "VectorConformance",
+    "run_checks",
+    "run_graph_conformance",
+    "run_storage_conformance",
+    "run_vector_conformance",
+]
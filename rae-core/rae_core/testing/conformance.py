@@ -0,0 +1,131 @@
+"""Runner shared by the backend conformance kits.
+
+A kit is a set of named checks, async functions taking a seeded
+random.Random and a freshly made backend and raising AssertionError when an
+invariant is broken. run_checks runs every check on `examples` random
+datasets (seeds seed, seed + 1, ...) with a new backend each time and
+reports the first failing seed of each check, so setting seed to it and
+examples to 1 replays the case.
+
+The kits (testing.storage, testing.vector, testing.graph) expose their
+checks both as an async run_*_conformance function, usable from any test
+runner, and as a pytest base class whose test methods drive their own event
+loop, so no async test plugin is needed.
+"""
+
+import asyncio
+import random
+from collections.abc import Awaitable, Callable
+from dataclasses import dataclass, field
+from typing import Any
+
+# Random datasets each check runs on
+DEFAULT_EXAMPLES = 20
+# Dimension of the random vectors
+DIMENSION = 8
+
+Check = Callable[[random.Random, Any], Awaitable[None]]
+
+
+@dataclass
+class ConformanceFailure:
+    """A check broken by a backend, with the seed reproducing it."""
+
+    check: str
+    seed: int
+    message: str
+
+
+@dataclass
+class ConformanceReport:
+    """Outcome of running a conformance kit against a backend."""
+
+    checks: list[str] = field(default_factory=list)
+    examples: int = 0
+    failures: list[ConformanceFailure] = field(default_factory=list)
+
+    @property
+    def ok(self) -> bool:
+        return not self.failures
+
+    def __str__(self) -> str:
+        if self.ok:
+            return f"{len(self.checks)} checks passed on {self.examples} examples"
+        return "\n".join(
+            f"{f.check} failed (seed {f.seed}): {f.message}" for f in self.failures
+        )
+
+
+def random_vector(rng: random.Random) -> list[float]:
+    # Positive components keep every pair of vectors at a positive cosine, so
+    # stores that drop non-positive matches still return each vector
+    return [rng.uniform(0.05, 1.0) for _ in range(DIMENSION)]
+
+
+async def run_checks(
+    checks: dict[str, Check],
+    make_backend: Callable[[], Awaitable[Any]],
+    close_backend: Callable[[Any], Awaitable[None]] | None = None,
+    examples: int = DEFAULT_EXAMPLES,
+    seed: int = 0,
+) -> ConformanceReport:
+    """Run checks against fresh backends.
+
+    Args:
+        checks: Checks by name
+        make_backend: Creates a new, empty backend for each example
+        close_backend: Releases a backend after its example
+        examples: Random datasets per check
+        seed: Seed of the first dataset
+    """
+    report = ConformanceReport(checks=list(checks), examples=examples)
+    for name, check in checks.items():
+        for example_seed in range(seed, seed + examples):
+            backend = await make_backend()
+            try:
+                await check(random.Random(example_seed), backend)
+            except AssertionError as e:
+                report.failures.append(
+                    ConformanceFailure(name, example_seed, str(e) or "assertion")
+                )
+                break
+            finally:
+                if close_backend is not None:
+                    await close_backend(backend)
+    return report
+
+
+async def close_all(*backends: Any) -> None:
+    """Close each distinct backend that has a close method."""
+    closed: set[int] = set()
+    for backend in backends:
+        close = getattr(backend, "close", None)
+        if close is not None and id(backend) not in closed:
+            closed.add(id(backend))
+            await close()
+
+
+class ConformanceSuite:
+    """Base of the pytest suites: one test method per check of a kit."""
+
+    # Random datasets per check, and the seed of the first one
+    examples = DEFAULT_EXAMPLES
+    seed = 0
+
+    async def _make(self) -> Any:
+        raise NotImplementedError
+
+    async def _close(self, backend: Any) -> None:
+        await close_all(backend)
+
+    def _check(self, name: str, check: Check) -> None:
+        report = asyncio.run(
+            run_checks(
+                {name: check},
+                self._make,
+                self._close,
+                examples=self.examples,
+                seed=self.seed,
+            )
+        )
+        assert report.ok, str(report)
@@ -0,0 +1,233 @@
+"""Conformance kit for IGraphStore implementations.
+
+Checks (each on random graphs spread over tenants):
+- tenant_isolation: traversals and deletes see only the tenant's own graph
+- direction: out, in and both neighbors match the edges stored
+- edge_type: an edge type filter follows only edges of that type
+- traversal: multi-hop neighbors are every node within max_depth hops, in
+  breadth-first order and without the start node
+- delete_node: a deleted node loses all its edges
+
+    report = await run_graph_conformance(make_graph_store)
+    assert report.ok, str(report)
+"""
+
+import random
+from collections.abc import Awaitable, Callable
+from dataclasses import dataclass, field
+from uuid import UUID, uuid4
+
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.testing.conformance import (
+    DEFAULT_EXAMPLES,
+    Check,
+    ConformanceReport,
+    ConformanceSuite,
+    run_checks,
+)
+
+EDGE_TYPES = ("related", "causes")
+DIRECTIONS = ("out", "in", "both")
+# Upper bound of the nodes of one tenant's graph
+MAX_NODES = 12
+
+
+@dataclass
+class Graph:
+    """Nodes and edges stored for one tenant, as the checks expect to find them."""
+
+    tenant_id: str
+    nodes: list[UUID] = field(default_factory=list)
+    edges: set[tuple[UUID, UUID, str]] = field(default_factory=set)
+
+    def neighbors(
+        self, node_id: UUID, direction: str, edge_type: str | None = None
+    ) -> set[UUID]:
+        """Direct neighbors of a node."""
+        found = set()
+        for source, target, kind in self.edges:
+            if edge_type is not None and kind != edge_type:
+                continue
+            if direction in ("out", "both") and source == node_id:
+                found.add(target)
+            if direction in ("in", "both") and target == node_id:
+                found.add(source)
+        found.discard(node_id)
+        return found
+
+    def levels(self, node_id: UUID, direction: str, max_depth: int) -> list[set[UUID]]:
+        """Nodes first reached at each hop of a breadth-first traversal."""
+        visited = {node_id}
+        frontier = {node_id}
+        levels = []
+        for _ in range(max_depth):
+            frontier = {
+                neighbor
+                for current in frontier
+                for neighbor in self.neighbors(current, direction)
+            } - visited
+            if not frontier:
+                break
+            visited |= frontier
+            levels.append(frontier)
+        return levels
+
+
+async def _populate(rng: random.Random, store: IGraphStore) -> list[Graph]:
+    graphs = []
+    for i in range(rng.randint(1, 3)):
+        graph = Graph(f"tenant-{i}")
+        for _ in range(rng.randint(2, MAX_NODES)):
+            node_id = uuid4()
+            assert await store.create_node(node_id, "memory", graph.tenant_id), (
+                f"create of node {node_id} reported failure"
+            )
+            graph.nodes.append(node_id)
+        for _ in range(rng.randint(0, len(graph.nodes) * 2)):
+            source, target = rng.sample(graph.nodes, 2)
+            edge = (source, target, rng.choice(EDGE_TYPES))
+            assert await store.create_edge(*edge, graph.tenant_id), (
+                f"create of edge {edge} reported failure"
+            )
+            graph.edges.add(edge)
+        graphs.append(graph)
+    return graphs
+
+
+async def check_tenant_isolation(rng: random.Random, store: IGraphStore) -> None:
+    graphs = await _populate(rng, store)
+    for graph in graphs:
+        other = f"not-{graph.tenant_id}"
+        for node_id in graph.nodes:
+            neighbors = await store.get_neighbors(node_id, other, max_depth=2)
+            assert not neighbors, f"{other} traverses from node {node_id}"
+        node_id = rng.choice(graph.nodes)
+        assert not await store.delete_node(node_id, other), (
+            f"{other} deletes node {node_id}"
+        )
+        neighbors = await store.get_neighbors(node_id, graph.tenant_id)
+        assert set(neighbors) == graph.neighbors(node_id, "both"), (
+            f"edges of {node_id} changed after a delete by {other}"
+        )
+
+
+async def check_direction(rng: random.Random, store: IGraphStore) -> None:
+    for graph in await _populate(rng, store):
+        for node_id in graph.nodes:
+            direction = rng.choice(DIRECTIONS)
+            neighbors = await store.get_neighbors(
+                node_id, graph.tenant_id, direction=direction
+            )
+            assert len(neighbors) == len(set(neighbors)), (
+                f"{direction} neighbors of {node_id} repeat nodes"
+            )
+            assert set(neighbors) == graph.neighbors(node_id, direction), (
+                f"{direction} neighbors of {node_id} differ from the edges stored"
+            )
+
+
+async def check_edge_type(rng: random.Random, store: IGraphStore) -> None:
+    for graph in await _populate(rng, store):
+        for node_id in graph.nodes:
+            direction, edge_type = rng.choice(DIRECTIONS), rng.choice(EDGE_TYPES)
+            neighbors = await store.get_neighbors(
+                node_id, graph.tenant_id, edge_type=edge_type, direction=direction
+            )
+            assert set(neighbors) == graph.neighbors(node_id, direction, edge_type), (
+                f"{edge_type} {direction} neighbors of {node_id} follow other edges"
+            )
+
+
+async def check_traversal(rng: random.Random, store: IGraphStore) -> None:
+    for graph in await _populate(rng, store):
+        for node_id in graph.nodes:
+            direction, max_depth = rng.choice(DIRECTIONS), rng.randint(1, 3)
+            neighbors = await store.get_neighbors(
+                node_id, graph.tenant_id, direction=direction, max_depth=max_depth
+            )
+            levels = graph.levels(node_id, direction, max_depth)
+            assert len(neighbors) == len(set(neighbors)), (
+                f"traversal from {node_id} repeats nodes"
+            )
+            assert node_id not in neighbors, f"traversal from {node_id} returns it"
+            position = 0
+            for depth, level in enumerate(levels, 1):
+                reached = set(neighbors[position : position + len(level)])
+                assert reached == level, (
+                    f"{direction} hop {depth} from {node_id} differs from "
+                    f"breadth-first order (max_depth {max_depth})"
+                )
+                position += len(level)
+            assert position == len(neighbors), (
+                f"traversal from {node_id} goes past {max_depth} hops"
+            )
+
+
+async def check_delete_node(rng: random.Random, store: IGraphStore) -> None:
+    for graph in await _populate(rng, store):
+        deleted = rng.choice(graph.nodes)
+        assert await store.delete_node(deleted, graph.tenant_id), (
+            f"delete of node {deleted} reported failure"
+        )
+        graph.nodes.remove(deleted)
+        graph.edges = {e for e in graph.edges if deleted not in e[:2]}
+        assert not await store.get_neighbors(deleted, graph.tenant_id), (
+            f"deleted node {deleted} keeps edges"
+        )
+        for node_id in graph.nodes:
+            neighbors = await store.get_neighbors(node_id, graph.tenant_id)
+            assert set(neighbors) == graph.neighbors(node_id, "both"), (
+                f"{node_id} keeps edges to deleted node {deleted}"
+            )
+
+
+GRAPH_CHECKS: dict[str, Check] = {
+    "tenant_isolation": check_tenant_isolation,
+    "direction": check_direction,
+    "edge_type": check_edge_type,
+    "traversal": check_traversal,
+    "delete_node": check_delete_node,
+}
+
+
+async def run_graph_conformance(
+    make_graph_store: Callable[[], Awaitable[IGraphStore]],
+    examples: int = DEFAULT_EXAMPLES,
+    seed: int = 0,
+) -> ConformanceReport:
+    """Run the graph checks against fresh graph stores.
+
+    Args:
+        make_graph_store: Creates a new, empty graph store
+        examples: Random graphs per check
+        seed: Seed of the first graph
+    """
+    return await run_checks(
+        GRAPH_CHECKS, make_graph_store, examples=examples, seed=seed
+    )
+
+
+class GraphConformance(ConformanceSuite):
+    """pytest suite of the graph checks (see the module docstring)."""
+
+    async def make_graph_store(self) -> IGraphStore:
+        """A new, empty graph store."""
+        raise NotImplementedError
+
+    async def _make(self) -> IGraphStore:
+        return await self.make_graph_store()
+
+    def test_tenant_isolation(self) -> None:
+        self._check("tenant_isolation", check_tenant_isolation)
+
+    def test_direction(self) -> None:
+        self._check("direction", check_direction)
+
+    def test_edge_type(self) -> None:
+        self._check("edge_type", check_edge_type)
+
+    def test_traversal(self) -> None:
+        self._check("traversal", check_traversal)
+
+    def test_delete_node(self) -> None:
+        self._check("delete_node", check_delete_node)
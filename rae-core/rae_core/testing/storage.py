@@ -0,0 +1,395 @@
+"""Conformance kit for IMemoryStorage implementations.
+
+Checks (each on random tenants, agents, layers, tags and metadata):
+- tenant_isolation: a tenant lists, reads and deletes only its own memories
+- count_matches_list: count_memories matches the length of list_memories
+  for the same filters
+- filters: agent, layer, tag (any of) and metadata filters select exactly
+  the matching memories
+- pagination: limit/offset pages cover every memory exactly once
+- delete_cascade: a deleted memory is gone from storage, its vector from the
+  vector store and its edges from the graph store (when those are given)
+- operations_match_model: a random sequence of stores and deletes leaves the
+  storage matching a reference model
+
+From any test runner:
+
+    report = await run_storage_conformance(make_storage)
+    assert report.ok, str(report)
+
+or with pytest, by subclassing StorageConformance:
+
+    class TestMyStorage(StorageConformance):
+        async def make_storage(self):
+            return MyStorage(...)
+"""
+
+import random
+from collections.abc import Awaitable, Callable
+from dataclasses import dataclass, field
+from typing import Any
+from uuid import UUID
+
+from rae_core.interfaces.graph import IGraphStore
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.testing.conformance import (
+    DEFAULT_EXAMPLES,
+    Check,
+    ConformanceReport,
+    ConformanceSuite,
+    close_all,
+    random_vector,
+    run_checks,
+)
+
+AGENTS = ("planner", "coder", "reviewer")
+LAYERS = ("working", "episodic", "semantic")
+TAGS = ("billing", "deploy", "incident")
+TEAMS = ("core", "edge")
+# Upper bound of the memories of one dataset
+MAX_MEMORIES = 40
+
+
+@dataclass
+class StorageBackends:
+    """Storage under test, with the vector and graph stores deletes cascade to."""
+
+    storage: IMemoryStorage
+    vector_store: IVectorStore | None = None
+    graph_store: IGraphStore | None = None
+
+
+@dataclass
+class Dataset:
+    """Memories stored for one example, as the checks expect to find them."""
+
+    records: dict[UUID, dict[str, Any]] = field(default_factory=dict)
+
+    def ids(
+        self,
+        tenant_id: str,
+        agent_id: str | None = None,
+        layer: str | None = None,
+    ) -> set[UUID]:
+        """Ids of the memories matching the filters."""
+        return {
+            memory_id
+            for memory_id, record in self.records.items()
+            if record["tenant_id"] == tenant_id
+            and (agent_id is None or record["agent_id"] == agent_id)
+            and (layer is None or record["layer"] == layer)
+        }
+
+    @property
+    def tenants(self) -> list[str]:
+        return sorted({record["tenant_id"] for record in self.records.values()})
+
+
+def random_record(rng: random.Random, tenants: list[str]) -> dict[str, Any]:
+    """store_memory arguments of a random memory."""
+    return {
+        "content": " ".join(rng.choices(TAGS + AGENTS, k=rng.randint(1, 6))),
+        "tenant_id": rng.choice(tenants),
+        "agent_id": rng.choice(AGENTS),
+        "layer": rng.choice(LAYERS),
+        "tags": rng.sample(TAGS, rng.randint(0, 2)),
+        "metadata": {"team": rng.choice(TEAMS)},
+        "importance": round(rng.random(), 3),
+    }
+
+
+async def _populate(rng: random.Random, storage: IMemoryStorage) -> Dataset:
+    tenants = [f"tenant-{i}" for i in range(rng.randint(1, 3))]
+    dataset = Dataset()
+    for _ in range(rng.randint(0, MAX_MEMORIES)):
+        record = random_record(rng, tenants)
+        dataset.records[await storage.store_memory(**record)] = record
+    return dataset
+
+
+async def _listed(storage: IMemoryStorage, tenant_id: str, **filters: Any) -> set[UUID]:
+    memories = await storage.list_memories(
+        tenant_id, limit=MAX_MEMORIES * 2 + 10, **filters
+    )
+    return {m["id"] for m in memories}
+
+
+async def check_tenant_isolation(rng: random.Random, stores: StorageBackends) -> None:
+    storage = stores.storage
+    dataset = await _populate(rng, storage)
+    for tenant_id in dataset.tenants + ["tenant-absent"]:
+        assert await _listed(storage, tenant_id) == dataset.ids(tenant_id), (
+            f"{tenant_id} lists memories of other tenants"
+        )
+    for memory_id, record in dataset.records.items():
+        other = f"not-{record['tenant_id']}"
+        assert await storage.get_memory(memory_id, other) is None, (
+            f"{other} reads memory {memory_id}"
+        )
+        assert not await storage.delete_memory(memory_id, other), (
+            f"{other} deletes memory {memory_id}"
+        )
+        assert await storage.get_memory(memory_id, record["tenant_id"]), (
+            f"memory {memory_id} lost after a delete by {other}"
+        )
+
+
+async def check_count_matches_list(
+    rng: random.Random, stores: StorageBackends
+) -> None:
+    storage = stores.storage
+    dataset = await _populate(rng, storage)
+    for tenant_id in dataset.tenants:
+        agent_id, layer = rng.choice(AGENTS), rng.choice(LAYERS)
+        for filters in (
+            {},
+            {"agent_id": agent_id},
+            {"layer": layer},
+            {"agent_id": agent_id, "layer": layer},
+        ):
+            count = await storage.count_memories(tenant_id, **filters)
+            listed = await _listed(storage, tenant_id, **filters)
+            expected = len(dataset.ids(tenant_id, **filters))
+            assert count == len(listed) == expected, (
+                f"count {count}, listed {len(listed)}, stored {expected} "
+                f"for {tenant_id} {filters}"
+            )
+
+
+async def check_filters(rng: random.Random, stores: StorageBackends) -> None:
+    storage = stores.storage
+    dataset = await _populate(rng, storage)
+    for tenant_id in dataset.tenants:
+        agent_id, layer = rng.choice(AGENTS), rng.choice(LAYERS)
+        listed = await _listed(storage, tenant_id, agent_id=agent_id, layer=layer)
+        assert listed == dataset.ids(tenant_id, agent_id, layer), (
+            f"agent {agent_id} and layer {layer} select other memories"
+        )
+
+        tags = rng.sample(TAGS, rng.randint(1, 2))
+        expected = {
+            memory_id
+            for memory_id in dataset.ids(tenant_id)
+            if set(dataset.records[memory_id]["tags"]) & set(tags)
+        }
+        assert await _listed(storage, tenant_id, tags=tags) == expected, (
+            f"tags {tags} select memories without any of them"
+        )
+
+        team = rng.choice(TEAMS)
+        expected = {
+            memory_id
+            for memory_id in dataset.ids(tenant_id)
+            if dataset.records[memory_id]["metadata"]["team"] == team
+        }
+        listed = await _listed(storage, tenant_id, filters={"team": team})
+        assert listed == expected, f"metadata team={team} selects other memories"
+
+
+async def check_pagination(rng: random.Random, stores: StorageBackends) -> None:
+    storage = stores.storage
+    dataset = await _populate(rng, storage)
+    for tenant_id in dataset.tenants:
+        page_size = rng.randint(1, 7)
+        seen: list[UUID] = []
+        offset = 0
+        while True:
+            page = await storage.list_memories(
+                tenant_id, limit=page_size, offset=offset
+            )
+            assert len(page) <= page_size, f"page larger than {page_size}"
+            seen.extend(m["id"] for m in page)
+            if len(page) < page_size:
+                break
+            offset += page_size
+        assert len(seen) == len(set(seen)), (
+            f"pages of {page_size} repeat memories of {tenant_id}"
+        )
+        assert set(seen) == dataset.ids(tenant_id), (
+            f"pages of {page_size} miss memories of {tenant_id}"
+        )
+
+
+async def check_delete_cascade(rng: random.Random, stores: StorageBackends) -> None:
+    storage, vector_store, graph_store = (
+        stores.storage,
+        stores.vector_store,
+        stores.graph_store,
+    )
+    dataset = await _populate(rng, storage)
+    ids = list(dataset.records)
+    for memory_id in ids:
+        tenant_id = dataset.records[memory_id]["tenant_id"]
+        if vector_store is not None:
+            await vector_store.store_vector(memory_id, random_vector(rng), tenant_id)
+        if graph_store is not None:
+            await graph_store.create_node(memory_id, "memory", tenant_id)
+    if graph_store is not None:
+        for source in ids:
+            tenant_id = dataset.records[source]["tenant_id"]
+            targets = [t for t in dataset.ids(tenant_id) if t != source]
+            for target in rng.sample(targets, min(len(targets), 2)):
+                await graph_store.create_edge(source, target, "related", tenant_id)
+
+    deleted = set(rng.sample(ids, len(ids) // 2))
+    for memory_id in deleted:
+        tenant_id = dataset.records.pop(memory_id)["tenant_id"]
+        assert await storage.delete_memory(memory_id, tenant_id), (
+            f"delete of {memory_id} reported failure"
+        )
+        if vector_store is not None and vector_store is not storage:
+            await vector_store.delete_vector(memory_id, tenant_id)
+        if graph_store is not None:
+            await graph_store.delete_node(memory_id, tenant_id)
+
+    for tenant_id in dataset.tenants:
+        for memory_id in deleted:
+            assert await storage.get_memory(memory_id, tenant_id) is None, (
+                f"deleted memory {memory_id} still readable"
+            )
+        assert await _listed(storage, tenant_id) == dataset.ids(tenant_id), (
+            f"{tenant_id} still lists deleted memories"
+        )
+        if vector_store is not None:
+            for memory_id in deleted:
+                vector = await vector_store.get_vector(memory_id, tenant_id)
+                assert vector is None, f"vector of {memory_id} remains"
+            hits = await vector_store.search_similar(
+                random_vector(rng), tenant_id, limit=len(ids) + 1
+            )
+            assert not deleted & {hit[0] for hit in hits}, (
+                "search returns vectors of deleted memories"
+            )
+        if graph_store is not None:
+            for memory_id in dataset.ids(tenant_id):
+                neighbors = await graph_store.get_neighbors(memory_id, tenant_id)
+                assert not deleted & set(neighbors), (
+                    f"{memory_id} keeps edges to deleted memories"
+                )
+
+
+async def check_operations_match_model(
+    rng: random.Random, stores: StorageBackends
+) -> None:
+    storage = stores.storage
+    tenants = ["tenant-0", "tenant-1"]
+    model = Dataset()
+    for _ in range(rng.randint(1, MAX_MEMORIES)):
+        if model.records and rng.random() < 0.3:
+            memory_id = rng.choice(list(model.records))
+            tenant_id = model.records.pop(memory_id)["tenant_id"]
+            assert await storage.delete_memory(memory_id, tenant_id), (
+                f"delete of {memory_id} reported failure"
+            )
+            assert not await storage.delete_memory(memory_id, tenant_id), (
+                f"memory {memory_id} deleted twice"
+            )
+        else:
+            record = random_record(rng, tenants)
+            model.records[await storage.store_memory(**record)] = record
+        tenant_id = rng.choice(tenants)
+        assert await storage.count_memories(tenant_id) == len(model.ids(tenant_id)), (
+            f"count of {tenant_id} differs from the model"
+        )
+    for tenant_id in tenants:
+        assert await _listed(storage, tenant_id) == model.ids(tenant_id), (
+            f"{tenant_id} lists a different set than the model"
+        )
+    for memory_id, record in model.records.items():
+        stored = await storage.get_memory(memory_id, record["tenant_id"])
+        assert stored is not None, f"memory {memory_id} lost"
+        for key in ("content", "agent_id", "layer"):
+            assert stored[key] == record[key], f"{key} of {memory_id} changed"
+        assert set(stored["tags"]) == set(record["tags"]), (
+            f"tags of {memory_id} changed"
+        )
+
+
+STORAGE_CHECKS: dict[str, Check] = {
+    "tenant_isolation": check_tenant_isolation,
+    "count_matches_list": check_count_matches_list,
+    "filters": check_filters,
+    "pagination": check_pagination,
+    "delete_cascade": check_delete_cascade,
+    "operations_match_model": check_operations_match_model,
+}
+
+
+async def run_storage_conformance(
+    make_storage: Callable[[], Awaitable[IMemoryStorage]],
+    make_vector_store: Callable[[IMemoryStorage], Awaitable[IVectorStore | None]]
+    | None = None,
+    make_graph_store: Callable[[], Awaitable[IGraphStore | None]] | None = None,
+    examples: int = DEFAULT_EXAMPLES,
+    seed: int = 0,
+) -> ConformanceReport:
+    """Run the storage checks against fresh storages.
+
+    Args:
+        make_storage: Creates a new, empty storage
+        make_vector_store: Vector store of a storage (may return the storage
+            itself); deletes are checked to remove vectors when given
+        make_graph_store: Creates a new, empty graph store; deletes are
+            checked to remove edges when given
+        examples: Random datasets per check
+        seed: Seed of the first dataset
+    """
+
+    async def make() -> StorageBackends:
+        storage = await make_storage()
+        return StorageBackends(
+            storage,
+            await make_vector_store(storage) if make_vector_store else None,
+            await make_graph_store() if make_graph_store else None,
+        )
+
+    async def close(stores: StorageBackends) -> None:
+        await close_all(stores.vector_store, stores.storage, stores.graph_store)
+
+    return await run_checks(STORAGE_CHECKS, make, close, examples, seed)
+
+
+class StorageConformance(ConformanceSuite):
+    """pytest suite of the storage checks (see the module docstring)."""
+
+    async def make_storage(self) -> IMemoryStorage:
+        """A new, empty storage."""
+        raise NotImplementedError
+
+    async def make_vector_store(self, storage: IMemoryStorage) -> IVectorStore | None:
+        """Vector store of the storage (None skips vector checks)."""
+        return None
+
+    async def make_graph_store(self) -> IGraphStore | None:
+        """A new, empty graph store (None skips graph checks)."""
+        return None
+
+    async def _make(self) -> StorageBackends:
+        storage = await self.make_storage()
+        return StorageBackends(
+            storage,
+            await self.make_vector_store(storage),
+            await self.make_graph_store(),
+        )
+
+    async def _close(self, stores: StorageBackends) -> None:
+        await close_all(stores.vector_store, stores.storage, stores.graph_store)
+
+    def test_tenant_isolation(self) -> None:
+        self._check("tenant_isolation", check_tenant_isolation)
+
+    def test_count_matches_list(self) -> None:
+        self._check("count_matches_list", check_count_matches_list)
+
+    def test_filters(self) -> None:
+        self._check("filters", check_filters)
+
+    def test_pagination_covers_every_record_once(self) -> None:
+        self._check("pagination", check_pagination)
+
+    def test_delete_removes_vectors_and_edges(self) -> None:
+        self._check("delete_cascade", check_delete_cascade)
+
+    def test_operations_match_model(self) -> None:
+        self._check("operations_match_model", check_operations_match_model)
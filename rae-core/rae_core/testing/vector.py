@@ -0,0 +1,243 @@
+"""Conformance kit for IVectorStore implementations.
+
+Checks (each on random tenants, layers, agents and vectors):
+- tenant_isolation: search, get_vector and delete_vector see only the
+  tenant's own vectors
+- ranking: results are sorted by descending score, honor the limit and rank
+  a stored vector first when it is the query
+- filters: layer and agent_id select exactly the vectors stored with them
+- delete: a deleted vector is neither readable nor searchable
+- batch_store: batch_store_vectors stores every vector it reports
+
+Stores that also implement IMemoryStorage (such as InMemoryStorage, which
+only indexes vectors of stored memories) get a memory stored for each
+vector first.
+
+    report = await run_vector_conformance(make_vector_store)
+    assert report.ok, str(report)
+"""
+
+import random
+from collections.abc import Awaitable, Callable
+from typing import Any
+from uuid import UUID, uuid4
+
+from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.interfaces.vector import IVectorStore
+from rae_core.testing.conformance import (
+    DEFAULT_EXAMPLES,
+    Check,
+    ConformanceReport,
+    ConformanceSuite,
+    random_vector,
+    run_checks,
+)
+
+AGENTS = ("planner", "coder")
+LAYERS = ("working", "episodic", "semantic")
+# Upper bound of the vectors of one dataset
+MAX_VECTORS = 30
+
+
+async def _new_id(
+    store: IVectorStore, tenant_id: str, layer: str, agent_id: str
+) -> UUID:
+    if isinstance(store, IMemoryStorage):
+        return await store.store_memory(
+            content=f"{agent_id} {layer}",
+            layer=layer,
+            tenant_id=tenant_id,
+            agent_id=agent_id,
+        )
+    return uuid4()
+
+
+async def _populate(
+    rng: random.Random, store: IVectorStore
+) -> dict[UUID, dict[str, Any]]:
+    """Store random vectors; returns their tenant, layer, agent and vector."""
+    tenants = [f"tenant-{i}" for i in range(rng.randint(1, 3))]
+    records: dict[UUID, dict[str, Any]] = {}
+    for _ in range(rng.randint(1, MAX_VECTORS)):
+        record = {
+            "tenant_id": rng.choice(tenants),
+            "layer": rng.choice(LAYERS),
+            "agent_id": rng.choice(AGENTS),
+            "vector": random_vector(rng),
+        }
+        memory_id = await _new_id(
+            store, record["tenant_id"], record["layer"], record["agent_id"]
+        )
+        stored = await store.store_vector(
+            memory_id,
+            record["vector"],
+            record["tenant_id"],
+            {"layer": record["layer"], "agent_id": record["agent_id"]},
+        )
+        assert stored, f"store of vector {memory_id} reported failure"
+        records[memory_id] = record
+    return records
+
+
+def _ids(
+    records: dict[UUID, dict[str, Any]], tenant_id: str, **filters: str
+) -> set[UUID]:
+    return {
+        memory_id
+        for memory_id, record in records.items()
+        if record["tenant_id"] == tenant_id
+        and all(record[key] == value for key, value in filters.items())
+    }
+
+
+def _tenants(records: dict[UUID, dict[str, Any]]) -> list[str]:
+    return sorted({record["tenant_id"] for record in records.values()})
+
+
+async def _searched(
+    store: IVectorStore, rng: random.Random, tenant_id: str, **filters: str
+) -> set[UUID]:
+    hits = await store.search_similar(
+        random_vector(rng), tenant_id, limit=MAX_VECTORS + 1, **filters
+    )
+    return {memory_id for memory_id, _ in hits}
+
+
+async def check_tenant_isolation(rng: random.Random, store: IVectorStore) -> None:
+    records = await _populate(rng, store)
+    for tenant_id in _tenants(records) + ["tenant-absent"]:
+        assert await _searched(store, rng, tenant_id) == _ids(records, tenant_id), (
+            f"search of {tenant_id} returns vectors of other tenants"
+        )
+    for memory_id, record in records.items():
+        other = f"not-{record['tenant_id']}"
+        assert await store.get_vector(memory_id, other) is None, (
+            f"{other} reads vector {memory_id}"
+        )
+        assert not await store.delete_vector(memory_id, other), (
+            f"{other} deletes vector {memory_id}"
+        )
+        assert await store.get_vector(memory_id, record["tenant_id"]), (
+            f"vector {memory_id} lost after a delete by {other}"
+        )
+
+
+async def check_ranking(rng: random.Random, store: IVectorStore) -> None:
+    records = await _populate(rng, store)
+    target = rng.choice(list(records))
+    tenant_id = records[target]["tenant_id"]
+    limit = rng.randint(1, MAX_VECTORS)
+    hits = await store.search_similar(records[target]["vector"], tenant_id, limit=limit)
+    assert len(hits) == min(limit, len(_ids(records, tenant_id))), (
+        f"{len(hits)} results for limit {limit}"
+    )
+    scores = [score for _, score in hits]
+    assert scores == sorted(scores, reverse=True), "results not sorted by score"
+    # Ties with the top score (collinear vectors) also count as first
+    assert dict(hits).get(target, -2.0) >= scores[0] - 1e-6, (
+        f"stored vector {target} not ranked first for itself"
+    )
+
+
+async def check_filters(rng: random.Random, store: IVectorStore) -> None:
+    records = await _populate(rng, store)
+    for tenant_id in _tenants(records):
+        layer, agent_id = rng.choice(LAYERS), rng.choice(AGENTS)
+        for filters in (
+            {"layer": layer},
+            {"agent_id": agent_id},
+            {"layer": layer, "agent_id": agent_id},
+        ):
+            searched = await _searched(store, rng, tenant_id, **filters)
+            assert searched == _ids(records, tenant_id, **filters), (
+                f"{filters} select other vectors of {tenant_id}"
+            )
+
+
+async def check_delete(rng: random.Random, store: IVectorStore) -> None:
+    records = await _populate(rng, store)
+    deleted = set(rng.sample(list(records), len(records) // 2))
+    for memory_id in deleted:
+        tenant_id = records.pop(memory_id)["tenant_id"]
+        assert await store.delete_vector(memory_id, tenant_id), (
+            f"delete of vector {memory_id} reported failure"
+        )
+        assert await store.get_vector(memory_id, tenant_id) is None, (
+            f"deleted vector {memory_id} still readable"
+        )
+    for tenant_id in _tenants(records):
+        assert await _searched(store, rng, tenant_id) == _ids(records, tenant_id), (
+            f"search of {tenant_id} returns deleted vectors"
+        )
+
+
+async def check_batch_store(rng: random.Random, store: IVectorStore) -> None:
+    tenant_id = "tenant-0"
+    batch = []
+    for _ in range(rng.randint(1, MAX_VECTORS)):
+        layer, agent_id = rng.choice(LAYERS), rng.choice(AGENTS)
+        memory_id = await _new_id(store, tenant_id, layer, agent_id)
+        batch.append(
+            (memory_id, random_vector(rng), {"layer": layer, "agent_id": agent_id})
+        )
+    stored = await store.batch_store_vectors(batch, tenant_id)
+    assert stored == len(batch), f"batch of {len(batch)} stored {stored}"
+    for memory_id, _, _ in batch:
+        assert await store.get_vector(memory_id, tenant_id), (
+            f"batched vector {memory_id} not readable"
+        )
+    assert await _searched(store, rng, tenant_id) == {m for m, _, _ in batch}, (
+        "search misses batched vectors"
+    )
+
+
+VECTOR_CHECKS: dict[str, Check] = {
+    "tenant_isolation": check_tenant_isolation,
+    "ranking": check_ranking,
+    "filters": check_filters,
+    "delete": check_delete,
+    "batch_store": check_batch_store,
+}
+
+
+async def run_vector_conformance(
+    make_vector_store: Callable[[], Awaitable[IVectorStore]],
+    examples: int = DEFAULT_EXAMPLES,
+    seed: int = 0,
+) -> ConformanceReport:
+    """Run the vector checks against fresh vector stores.
+
+    Args:
+        make_vector_store: Creates a new, empty vector store
+        examples: Random datasets per check
+        seed: Seed of the first dataset
+    """
+    return await run_checks(
+        VECTOR_CHECKS, make_vector_store, examples=examples, seed=seed
+    )
+
+
+class VectorConformance(ConformanceSuite):
+    """pytest suite of the vector checks (see the module docstring)."""
+
+    async def make_vector_store(self) -> IVectorStore:
+        """A new, empty vector store."""
+        raise NotImplementedError
+
+    async def _make(self) -> IVectorStore:
+        return await self.make_vector_store()
+
+    def test_tenant_isolation(self) -> None:
+        self._check("tenant_isolation", check_tenant_isolation)
+
+    def test_ranking(self) -> None:
+        self._check("ranking", check_ranking)
+
+    def test_filters(self) -> None:
+        self._check("filters", check_filters)
+
+    def test_delete(self) -> None:
+        self._check("delete", check_delete)
+
+    def test_batch_store(self) -> None:
+        self._check("batch_store", check_batch_store)
@@ -0,0 +1,23 @@
+"""Tracing for RAE-core: OpenTelemetry spans and backend instrumentation."""
+
+from rae_core.tracing.instrumentation import TracedComponent
+from rae_core.tracing.spans import (
+    NOOP_SPAN,
+    TRACER_NAME,
+    set_span_attributes,
+    set_tracing_enabled,
+    span,
+    span_attributes,
+    tracing_available,
+)
+
+__all__ = [
+    "NOOP_SPAN",
+    "TRACER_NAME",
+    "TracedComponent",
+    "set_span_attributes",
+    "set_tracing_enabled",
+    "span",
+    "span_attributes",
+    "tracing_available",
+]
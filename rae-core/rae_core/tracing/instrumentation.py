@@ -0,0 +1,88 @@
+"""Tracing of storage, vector, graph, cache and embedding backends.
+
+TracedComponent wraps a backend and runs each coroutine method called
+through it in a span named "<component>.<operation>" (e.g.
+"vector.search_similar"), with these attributes when the call has them:
+
+- rae.component, rae.operation
+- rae.tenant_id, rae.memory_id (an argument, or the id returned by a store)
+- rae.result_count for calls returning a list
+
+    storage = TracedComponent(SQLiteStorage("rae.db"), "storage")
+    graph = TracedComponent(SQLiteGraphStore("rae.db"), "graph")
+"""
+
+import functools
+import inspect
+from collections.abc import Callable
+from typing import Any
+from uuid import UUID
+
+from rae_core.tracing.spans import set_span_attributes, span
+
+# Arguments copied onto spans, by the attribute they become
+_TRACED_ARGUMENTS = {
+    "tenant_id": "tenant_id",
+    "memory_id": "memory_id",
+    "node_id": "memory_id",
+    "source_id": "memory_id",
+}
+
+
+def _call_attributes(
+    method: Callable[..., Any], args: tuple[Any, ...], kwargs: dict[str, Any]
+) -> dict[str, Any]:
+    arguments = dict(kwargs)
+    try:
+        bound = inspect.signature(method).bind_partial(*args, **kwargs)
+        arguments.update(bound.arguments)
+    except (TypeError, ValueError):
+        pass
+    attributes: dict[str, Any] = {}
+    for argument, attribute in _TRACED_ARGUMENTS.items():
+        if arguments.get(argument) is not None and attribute not in attributes:
+            attributes[attribute] = arguments[argument]
+    return attributes
+
+
+class TracedComponent:
+    """Wraps a backend and traces its coroutine methods.
+
+    Synchronous attributes are forwarded unchanged.
+    """
+
+    def __init__(self, component: Any, name: str):
+        """Initialize the wrapper.
+
+        Args:
+            component: Backend receiving the calls
+            name: Span name prefix and rae.component attribute
+        """
+        self.component = component
+        self.component_name = name
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self.component, name)
+        if name.startswith("_") or not inspect.iscoroutinefunction(attr):
+            return attr
+        return self._traced(name, attr)
+
+    def _traced(
+        self, operation: str, method: Callable[..., Any]
+    ) -> Callable[..., Any]:
+        @functools.wraps(method)
+        async def traced(*args: Any, **kwargs: Any) -> Any:
+            with span(
+                f"{self.component_name}.{operation}",
+                component=self.component_name,
+                operation=operation,
+                **_call_attributes(method, args, kwargs),
+            ) as current:
+                result = await method(*args, **kwargs)
+                if isinstance(result, UUID):
+                    set_span_attributes(current, memory_id=result)
+                elif isinstance(result, list):
+                    set_span_attributes(current, result_count=len(result))
+                return result
+
+        return traced
@@ -0,0 +1,90 @@
+"""Tracing spans through the OpenTelemetry API.
+
+With opentelemetry-api installed (pip install "rae-core[tracing]"), span()
+starts a child of the current span, so spans opened by the engine, search
+strategies and traced backends nest into one trace per call; the
+application configures the SDK and exporter. Without the package span() is
+a no-op and instrumented code runs unchanged. set_tracing_enabled(False)
+(RAEEngine applies settings.otel_enabled) turns spans off process-wide.
+
+Attribute names are namespaced: rae.tenant_id, rae.memory_id, ...
+"""
+
+from collections.abc import Iterator
+from contextlib import contextmanager
+from typing import Any
+
+from rae_core.version import __version__
+
+try:
+    from opentelemetry import trace as otel_trace
+except ImportError:  # pragma: no cover - depends on the installed extras
+    otel_trace = None  # type: ignore[assignment]
+
+TRACER_NAME = "rae_core"
+ATTRIBUTE_PREFIX = "rae."
+
+
+class NoopSpan:
+    """Stand-in span used when OpenTelemetry is not installed."""
+
+    def set_attribute(self, key: str, value: Any) -> None:
+        pass
+
+    def set_attributes(self, attributes: dict[str, Any]) -> None:
+        pass
+
+    def record_exception(self, exception: BaseException) -> None:
+        pass
+
+    def is_recording(self) -> bool:
+        return False
+
+
+NOOP_SPAN = NoopSpan()
+
+_enabled = True
+
+
+def set_tracing_enabled(enabled: bool) -> None:
+    """Turn span creation on or off for the whole process."""
+    global _enabled
+    _enabled = enabled
+
+
+def tracing_available() -> bool:
+    """Whether spans are sent to the OpenTelemetry API."""
+    return otel_trace is not None and _enabled
+
+
+def span_attributes(**attributes: Any) -> dict[str, Any]:
+    """Prefixed span attributes; None values are dropped and ids stringified."""
+    cleaned: dict[str, Any] = {}
+    for key, value in attributes.items():
+        if value is None:
+            continue
+        if not isinstance(value, (str, bool, int, float)):
+            value = str(value)
+        cleaned[ATTRIBUTE_PREFIX + key] = value
+    return cleaned
+
+
+@contextmanager
+def span(name: str, **attributes: Any) -> Iterator[Any]:
+    """Run the block in a span named name, child of the current span.
+
+    Exceptions leaving the block are recorded on the span and re-raised.
+    """
+    if otel_trace is None or not _enabled:
+        yield NOOP_SPAN
+        return
+    tracer = otel_trace.get_tracer(TRACER_NAME, __version__)
+    with tracer.start_as_current_span(
+        name, attributes=span_attributes(**attributes)
+    ) as current:
+        yield current
+
+
+def set_span_attributes(current: Any, **attributes: Any) -> None:
+    """Add attributes to a span returned by span()."""
+    current.set_attributes(span_attributes(**attributes))
@@ -17,6 +17,14 @@ class MemoryLayer(str, Enum):
     REFLECTIVE = "reflective"
 
 
+class MemoryScope(str, Enum):
+    """Which agents of a tenant may recall a memory."""
+
+    PRIVATE = "private"
+    TEAM = "team"
+    TENANT = "tenant"
+
+
 class MemoryType(str, Enum):
     """Type of memory content."""
 
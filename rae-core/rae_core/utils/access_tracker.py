@@ -0,0 +1,98 @@
+"""Write-behind buffer for memory access tracking.
+
+Recall reads memories far more often than anything writes them. Updating
+last_accessed_at and access_count inside every recall would put a storage
+write, and on the in-memory backends the tenant's lock, on the read path.
+AccessTracker queues accesses instead and writes them in batches through
+IMemoryStorage.record_access, one call per tenant and batch, so repeated
+reads of a hot memory collapse into a single counter increment.
+
+Tracking is best effort: accesses still buffered when the process dies are
+lost, and a failed write is logged instead of failing the recall (flush()
+raises it).
+"""
+
+from collections.abc import Iterable
+from datetime import datetime
+from typing import Any
+from uuid import UUID
+
+from rae_core.config.defaults import (
+    DEFAULT_ACCESS_FLUSH_MS,
+    DEFAULT_ACCESS_MAX_PENDING,
+)
+from rae_core.utils.clock import IClock, SystemClock
+from rae_core.utils.group_commit import CommitDurability, GroupCommitter
+
+# (tenant_id, memory_id, accessed_at)
+_Access = tuple[str, UUID, datetime]
+
+
+class AccessTracker:
+    """Buffers memory accesses and records them in batches."""
+
+    def __init__(
+        self,
+        storage: Any,
+        flush_interval_ms: float = DEFAULT_ACCESS_FLUSH_MS,
+        max_pending: int = DEFAULT_ACCESS_MAX_PENDING,
+        clock: IClock | None = None,
+    ) -> None:
+        """Initialize tracker.
+
+        Args:
+            storage: IMemoryStorage the accesses are recorded in
+            flush_interval_ms: Longest time an access stays buffered
+            max_pending: Buffered accesses that trigger an immediate write
+            clock: Time source of access timestamps
+        """
+        self.storage = storage
+        self._clock = clock or SystemClock()
+        self._committer: GroupCommitter[_Access] = GroupCommitter(
+            self._write,
+            max_delay_ms=flush_interval_ms,
+            max_batch=max_pending,
+            durability=CommitDurability.ASYNC,
+        )
+
+    @property
+    def pending(self) -> int:
+        """Number of accesses not written yet."""
+        return self._committer.pending
+
+    async def record(self, memory_ids: Iterable[UUID | str], tenant_id: str) -> None:
+        """Queue accesses to memories without waiting for them to be written.
+
+        Only when max_pending accesses are buffered does the call wait for
+        the batch write, which bounds the buffer under sustained load.
+        """
+        now = self._clock.now()
+        for memory_id in memory_ids:
+            if not isinstance(memory_id, UUID):
+                try:
+                    memory_id = UUID(str(memory_id))
+                except ValueError:
+                    # Not a stored memory (e.g. a synthesized result)
+                    continue
+            await self._committer.submit((tenant_id, memory_id, now))
+
+    async def flush(self) -> None:
+        """Write every buffered access now.
+
+        Raises:
+            Exception: The error of a write that failed since the last flush
+        """
+        await self._committer.flush()
+
+    async def close(self) -> None:
+        """Flush buffered accesses; the tracker may still be used afterwards."""
+        await self._committer.close()
+
+    async def _write(self, batch: list[_Access]) -> None:
+        by_tenant: dict[str, tuple[list[UUID], datetime]] = {}
+        for tenant_id, memory_id, accessed_at in batch:
+            ids, latest = by_tenant.get(tenant_id, ([], accessed_at))
+            ids.append(memory_id)
+            by_tenant[tenant_id] = (ids, max(latest, accessed_at))
+        for tenant_id, (ids, latest) in by_tenant.items():
+            await self.storage.record_access(ids, tenant_id, accessed_at=latest)
@@ -0,0 +1,80 @@
+"""Field-level change records for memory updates.
+
+Short values are recorded as old/new pairs. Long text fields (typically the
+content of large documents) are recorded as a compact list of edits against
+the old value instead of two full copies; apply_text_edits() replays them.
+"""
+
+import copy
+import difflib
+from datetime import datetime
+from typing import Any
+
+# Text longer than this is stored as edits rather than old/new copies
+INLINE_TEXT_LIMIT = 256
+
+
+def diff_text(old: str, new: str) -> list[dict[str, Any]]:
+    """Compute edits turning old into new.
+
+    Each edit replaces old[start:end] with text; offsets refer to the old value.
+    """
+    matcher = difflib.SequenceMatcher(None, old, new, autojunk=False)
+    return [
+        {"op": tag, "start": i1, "end": i2, "text": new[j1:j2]}
+        for tag, i1, i2, j1, j2 in matcher.get_opcodes()
+        if tag != "equal"
+    ]
+
+
+def apply_text_edits(old: str, edits: list[dict[str, Any]]) -> str:
+    """Reconstruct the new value from old and the edits of diff_text()."""
+    parts = []
+    cursor = 0
+    for edit in sorted(edits, key=lambda e: e["start"]):
+        parts.append(old[cursor : edit["start"]])
+        parts.append(edit["text"])
+        cursor = edit["end"]
+    parts.append(old[cursor:])
+    return "".join(parts)
+
+
+def field_changes(
+    before: dict[str, Any], updates: dict[str, Any]
+) -> list[dict[str, Any]]:
+    """List the fields whose value actually changes, in update order."""
+    changes = []
+    for field, new in updates.items():
+        old = before.get(field)
+        if old == new:
+            continue
+        if (
+            isinstance(old, str)
+            and isinstance(new, str)
+            and max(len(old), len(new)) > INLINE_TEXT_LIMIT
+        ):
+            changes.append({"field": field, "edits": diff_text(old, new)})
+        else:
+            changes.append(
+                {
+                    "field": field,
+                    "old_value": copy.deepcopy(old),
+                    "new_value": copy.deepcopy(new),
+                }
+            )
+    return changes
+
+
+def change_entry(
+    changes: list[dict[str, Any]],
+    version: int,
+    changed_at: datetime,
+    changed_by: str | None,
+) -> dict[str, Any]:
+    """Build a change log entry for one update."""
+    return {
+        "version": version,
+        "changed_at": changed_at,
+        "changed_by": changed_by,
+        "changes": changes,
+    }
@@ -0,0 +1,238 @@
+"""Bounded breadth-first traversal shared by graph store adapters.
+
+Adapters only provide a one-hop expansion function; fan-out caps, edge
+sampling, the global node-visit budget and cycle detection are applied here so
+every backend enforces TraversalLimits the same way.
+"""
+
+import math
+import random
+from collections.abc import Awaitable, Callable, Hashable, Iterable
+from datetime import datetime
+from typing import NamedTuple, TypeVar
+from uuid import UUID
+
+from rae_core.exceptions.base import (
+    CycleDetectedError,
+    FanOutExceededError,
+    VisitBudgetExceededError,
+)
+from rae_core.models.graph import EdgeSampling, TraversalLimits
+
+
+class NeighborEdge(NamedTuple):
+    """A one-hop neighbor together with the edge leading to it."""
+
+    node_id: UUID
+    weight: float = 1.0
+    created_at: datetime | None = None
+
+
+ExpandFn = Callable[[UUID], Awaitable[list[NeighborEdge]]]
+NodeT = TypeVar("NodeT", bound=Hashable)
+
+
+def apply_fan_out(
+    node_id: UUID, neighbors: list[NeighborEdge], limits: TraversalLimits | None
+) -> list[NeighborEdge]:
+    """Cap the neighbors expanded from a single node."""
+    if limits is None or limits.max_fan_out is None:
+        return neighbors
+    if len(neighbors) <= limits.max_fan_out:
+        return neighbors
+    if limits.strict:
+        raise FanOutExceededError(node_id, len(neighbors), limits.max_fan_out)
+    return sample_edges(node_id, neighbors, limits.max_fan_out, limits)
+
+
+def sample_edges(
+    node_id: UUID, edges: list[NeighborEdge], k: int, limits: TraversalLimits
+) -> list[NeighborEdge]:
+    """Select k edges of a high-degree node according to limits.sampling.
+
+    Random strategies are seeded per node so repeated traversals return the
+    same sample.
+    """
+    if limits.sampling == EdgeSampling.TRUNCATE:
+        return edges[:k]
+    if limits.sampling == EdgeSampling.TOP_WEIGHT:
+        ranked = sorted(edges, key=lambda e: (-e.weight, e.node_id.hex))
+        return ranked[:k]
+
+    timestamps = [e.created_at for e in edges if e.created_at is not None]
+    newest = max(timestamps) if timestamps else None
+    return sorted(
+        edges,
+        key=lambda edge: sampling_key(node_id, edge, limits, newest),
+        reverse=True,
+    )[:k]
+
+
+def sampling_key(
+    node_id: UUID,
+    edge: NeighborEdge,
+    limits: TraversalLimits,
+    newest: datetime | None,
+) -> float:
+    """Random or recency sampling key of an edge; the k largest are kept.
+
+    The draw is seeded per edge, so a backend can compute keys row by row
+    (e.g. in SQL) and keep the same sample as sample_edges.
+
+    Args:
+        node_id: Node being expanded
+        edge: Edge leading to the neighbor
+        limits: Sampling strategy, seed and recency half-life
+        newest: Creation time of the node's newest edge
+    """
+    rng = random.Random(f"{limits.sampling_seed}:{node_id}:{edge.node_id}")
+    if limits.sampling != EdgeSampling.RECENCY:
+        return rng.random()
+
+    # Weighted sampling without replacement (Efraimidis-Spirakis), weight
+    # decays with age relative to the newest edge of this node.
+    weight = max(edge.weight, 1e-9)
+    if newest is not None and edge.created_at is not None:
+        age = (newest - edge.created_at).total_seconds()
+        weight *= math.pow(0.5, age / limits.recency_half_life_seconds)
+    return math.pow(rng.random(), 1.0 / max(weight, 1e-300))
+
+
+def apply_visit_budget(
+    node_ids: list[UUID], limits: TraversalLimits | None
+) -> list[UUID]:
+    """Cap the number of nodes pulled into a subgraph."""
+    if limits is None or limits.max_visited is None:
+        return node_ids
+    if len(node_ids) <= limits.max_visited:
+        return node_ids
+    if limits.strict:
+        raise VisitBudgetExceededError(limits.max_visited)
+    return node_ids[: limits.max_visited]
+
+
+def _is_ancestor(candidate: UUID, node_id: UUID, parents: dict[UUID, UUID]) -> bool:
+    current: UUID | None = node_id
+    while current is not None:
+        if current == candidate:
+            return True
+        current = parents.get(current)
+    return False
+
+
+async def bounded_bfs(
+    start: UUID,
+    expand: ExpandFn,
+    max_depth: int = 1,
+    limits: TraversalLimits | None = None,
+) -> list[UUID]:
+    """Breadth-first expansion from start honoring traversal limits.
+
+    Args:
+        start: Node to expand from (not included in the result)
+        expand: Returns direct neighbors of a node
+        max_depth: Maximum number of hops
+        limits: Optional fan-out, visit budget and cycle settings
+
+    Returns:
+        Reached node IDs in BFS order
+
+    Raises:
+        FanOutExceededError: Strict mode and a node exceeds max_fan_out
+        VisitBudgetExceededError: Strict mode and max_visited is exhausted
+        CycleDetectedError: fail_on_cycle and an edge leads back to an ancestor
+    """
+    budget = limits.max_visited if limits else None
+    fail_on_cycle = bool(limits and limits.fail_on_cycle)
+
+    visited = {start}
+    parents: dict[UUID, UUID] = {}
+    result: list[UUID] = []
+    frontier = [start]
+
+    for _ in range(max(0, max_depth)):
+        next_frontier: list[UUID] = []
+        for node_id in frontier:
+            edges = apply_fan_out(node_id, await expand(node_id), limits)
+            for neighbor, _, _ in edges:
+                if neighbor in visited:
+                    # The edge we arrived through is not a cycle
+                    if (
+                        fail_on_cycle
+                        and neighbor != parents.get(node_id)
+                        and _is_ancestor(neighbor, node_id, parents)
+                    ):
+                        raise CycleDetectedError(neighbor)
+                    continue
+                if budget is not None and len(result) >= budget:
+                    if limits is not None and limits.strict:
+                        raise VisitBudgetExceededError(budget)
+                    return result
+                visited.add(neighbor)
+                parents[neighbor] = node_id
+                result.append(neighbor)
+                next_frontier.append(neighbor)
+        if not next_frontier:
+            break
+        frontier = next_frontier
+
+    return result
+
+
+def bidirectional_path(
+    source: NodeT,
+    target: NodeT,
+    adjacent: Callable[[NodeT], Iterable[NodeT]],
+    max_depth: int,
+) -> list[NodeT] | None:
+    """Shortest path with at most max_depth edges, searching from both ends.
+
+    Each step expands the smaller frontier by one level, so the visited
+    area grows with about twice the half-depth instead of the full depth.
+
+    Args:
+        source: Start node
+        target: End node
+        adjacent: Neighbors of a node (both directions for undirected paths)
+        max_depth: Maximum number of edges in the path
+    """
+    if source == target:
+        return [source]
+    parents: tuple[dict[NodeT, NodeT | None], dict[NodeT, NodeT | None]] = (
+        {source: None},
+        {target: None},
+    )
+    frontiers: list[list[NodeT]] = [[source], [target]]
+    depth = 0
+    while frontiers[0] and frontiers[1] and depth < max_depth:
+        side = 0 if len(frontiers[0]) <= len(frontiers[1]) else 1
+        seen, other = parents[side], parents[1 - side]
+        next_frontier: list[NodeT] = []
+        for node in frontiers[side]:
+            for neighbor in adjacent(node):
+                if neighbor in seen:
+                    continue
+                seen[neighbor] = node
+                if neighbor in other:
+                    return _join_paths(neighbor, parents)
+                next_frontier.append(neighbor)
+        frontiers[side] = next_frontier
+        depth += 1
+    return None
+
+
+def _join_paths(
+    meeting: NodeT,
+    parents: tuple[dict[NodeT, NodeT | None], dict[NodeT, NodeT | None]],
+) -> list[NodeT]:
+    forward: list[NodeT] = []
+    node: NodeT | None = meeting
+    while node is not None:
+        forward.append(node)
+        node = parents[0][node]
+    path = forward[::-1]
+    node = parents[1][meeting]
+    while node is not None:
+        path.append(node)
+        node = parents[1][node]
+    return path
@@ -0,0 +1,150 @@
+"""Group commit: coalesce concurrent writes into one transaction.
+
+Every commit of a SQL backend pays a fixed cost (fsync, round trip). During
+bulk ingest (e.g. transcript backfills) many small writes arrive at once;
+a GroupCommitter holds them for up to max_delay_ms and hands them to a
+flush function that writes the whole batch in a single transaction.
+"""
+
+import asyncio
+from collections.abc import Awaitable, Callable, Coroutine
+from enum import Enum
+from typing import Any, Generic, TypeVar
+
+import structlog
+
+logger = structlog.get_logger(__name__)
+
+T = TypeVar("T")
+
+
+class CommitDurability(str, Enum):
+    """When a batched write returns to its caller."""
+
+    # Wait until the batch holding the write is committed
+    SYNC = "sync"
+    # Return at once; the write is lost if the process dies before the flush
+    ASYNC = "async"
+
+
+class GroupCommitter(Generic[T]):
+    """Buffers items and flushes them in batches.
+
+    A batch is flushed when max_batch items are pending or max_delay_ms
+    after its first item, whichever comes first. Flushes never overlap, so
+    batches are committed in submission order.
+
+    With SYNC durability a flush error is raised to every writer of the
+    batch. With ASYNC durability it is logged and raised by the next
+    flush() or close() call.
+    """
+
+    def __init__(
+        self,
+        flush: Callable[[list[T]], Awaitable[None]],
+        max_delay_ms: float = 5.0,
+        max_batch: int = 256,
+        durability: CommitDurability | str = CommitDurability.SYNC,
+    ) -> None:
+        """Initialize the committer.
+
+        Args:
+            flush: Writes a batch in one transaction
+            max_delay_ms: Longest time a write waits for companions
+            max_batch: Batch size that triggers an immediate flush
+            durability: Whether submit waits for the commit
+        """
+        if max_batch < 1:
+            raise ValueError("max_batch must be positive")
+        self._flush = flush
+        self.max_delay = max(max_delay_ms, 0.0) / 1000
+        self.max_batch = max_batch
+        self.durability = CommitDurability(durability)
+        self._pending: list[tuple[T, asyncio.Future[None]]] = []
+        self._timer: asyncio.Task[None] | None = None
+        # Flush tasks are referenced until done so they are not collected
+        self._tasks: set[asyncio.Task[None]] = set()
+        self._lock = asyncio.Lock()
+        self._error: BaseException | None = None
+
+    @property
+    def pending(self) -> int:
+        """Number of items not flushed yet."""
+        return len(self._pending)
+
+    async def submit(self, item: T) -> None:
+        """Queue an item; with SYNC durability, wait until it is committed."""
+        future: asyncio.Future[None] = asyncio.get_running_loop().create_future()
+        self._pending.append((item, future))
+        task = None
+        if len(self._pending) >= self.max_batch:
+            self._cancel_timer()
+            task = self._spawn(self._flush_pending())
+        elif self._timer is None:
+            self._timer = self._spawn(self._flush_later())
+
+        if self.durability is CommitDurability.SYNC:
+            await future
+        elif task is not None:
+            # Bound the backlog: a full batch is written before submit returns
+            await task
+
+    async def flush(self) -> None:
+        """Commit everything pending now."""
+        self._cancel_timer()
+        await self._flush_pending()
+        self._raise_deferred()
+
+    async def close(self) -> None:
+        """Flush pending items; the committer may still be used afterwards."""
+        await self.flush()
+
+    def _spawn(self, coro: Coroutine[Any, Any, None]) -> asyncio.Task[None]:
+        task = asyncio.ensure_future(coro)
+        self._tasks.add(task)
+        task.add_done_callback(self._tasks.discard)
+        return task
+
+    def _cancel_timer(self) -> None:
+        # Only a sleeping timer is cancelled; a running flush clears _timer
+        if self._timer is not None:
+            self._timer.cancel()
+        self._timer = None
+
+    async def _flush_later(self) -> None:
+        await asyncio.sleep(self.max_delay)
+        self._timer = None
+        await self._flush_pending()
+
+    async def _flush_pending(self) -> None:
+        async with self._lock:
+            while self._pending:
+                batch = self._pending[: self.max_batch]
+                del self._pending[: self.max_batch]
+                try:
+                    await self._flush([item for item, _ in batch])
+                except Exception as e:
+                    self._fail(batch, e)
+                else:
+                    for _, future in batch:
+                        if not future.done():
+                            future.set_result(None)
+
+    def _fail(
+        self, batch: list[tuple[T, asyncio.Future[None]]], error: Exception
+    ) -> None:
+        if self.durability is CommitDurability.ASYNC:
+            logger.error("group_commit_failed", items=len(batch), error=str(error))
+            self._error = error
+        for _, future in batch:
+            if future.done():
+                continue
+            if self.durability is CommitDurability.SYNC:
+                future.set_exception(error)
+            else:
+                future.set_result(None)
+
+    def _raise_deferred(self) -> None:
+        if self._error is not None:
+            error, self._error = self._error, None
+            raise error
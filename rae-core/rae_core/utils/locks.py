@@ -0,0 +1,62 @@
+"""Per-tenant lock shards for in-memory backends.
+
+One asyncio.Lock per store serializes every tenant behind the slowest
+operation of any of them. TenantLocks hashes tenants onto a fixed number
+of shards instead: operations of different tenants only wait for each other
+when their tenants share a shard, and a tenant's long scan can yield to the
+event loop without stalling everyone else. Operations spanning all tenants
+take every shard, always in the same order so they cannot deadlock.
+
+Like asyncio.Lock, a shard is released when the block holding it raises,
+so a failed operation never leaves a tenant locked.
+"""
+
+import asyncio
+from collections.abc import AsyncIterator
+from contextlib import (
+    AbstractAsyncContextManager,
+    AsyncExitStack,
+    asynccontextmanager,
+)
+
+from rae_core.config.defaults import DEFAULT_LOCK_SHARDS
+from rae_core.utils.hashing import stable_hash
+
+
+class TenantLocks:
+    """Fixed set of asyncio locks, one shard per group of tenants."""
+
+    def __init__(self, shards: int = DEFAULT_LOCK_SHARDS):
+        """Initialize locks.
+
+        Args:
+            shards: Number of locks tenants are spread over
+        """
+        if shards < 1:
+            raise ValueError("shards must be positive")
+        self._shards = [asyncio.Lock() for _ in range(shards)]
+
+    def __len__(self) -> int:
+        return len(self._shards)
+
+    def shard(self, tenant_id: str) -> asyncio.Lock:
+        """Lock of the shard holding a tenant."""
+        return self._shards[stable_hash(tenant_id) % len(self._shards)]
+
+    def hold(self, tenant_id: str | None) -> AbstractAsyncContextManager[None]:
+        """Lock a tenant's shard, or every shard without a tenant."""
+        if not tenant_id:
+            return self.all()
+        return self.shard(tenant_id)  # type: ignore[return-value]
+
+    @asynccontextmanager
+    async def all(self) -> AsyncIterator[None]:
+        """Lock every shard, for operations spanning all tenants."""
+        async with AsyncExitStack() as stack:
+            for lock in self._shards:
+                await stack.enter_async_context(lock)
+            yield
+
+    def locked(self, tenant_id: str) -> bool:
+        """Whether a tenant's shard is held."""
+        return self.shard(tenant_id).locked()
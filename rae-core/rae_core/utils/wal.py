@@ -0,0 +1,181 @@
+"""Append-only write-ahead log for the in-memory adapters.
+
+Each line holds one state change as ``<crc32 hex> <json>``. UUIDs and
+datetimes are tagged so records round-trip exactly. A torn final line (crash
+mid-write) is ignored on replay; corruption anywhere else raises StorageError.
+"""
+
+import json
+import os
+import zlib
+from collections.abc import Iterable, Iterator
+from datetime import datetime
+from enum import Enum
+from typing import Any
+from uuid import UUID
+
+from rae_core.exceptions.base import StorageError
+
+
+class FsyncPolicy(str, Enum):
+    """When appended records are forced to stable storage."""
+
+    ALWAYS = "always"  # fsync after every record
+    BATCH = "batch"  # fsync every `fsync_every` records
+    NEVER = "never"  # leave flushing to the OS
+
+
+def _encode(obj: Any) -> Any:
+    if isinstance(obj, UUID):
+        return {"__uuid__": str(obj)}
+    if isinstance(obj, datetime):
+        return {"__datetime__": obj.isoformat()}
+    if isinstance(obj, (bytes, bytearray)):
+        return {"__bytes__": bytes(obj).hex()}
+    if isinstance(obj, dict):
+        return {str(k): _encode(v) for k, v in obj.items()}
+    if isinstance(obj, (list, tuple, set)):
+        return [_encode(v) for v in obj]
+    if isinstance(obj, Enum):
+        return obj.value
+    return obj
+
+
+def _decode(obj: Any) -> Any:
+    if isinstance(obj, dict):
+        if len(obj) == 1:
+            if "__uuid__" in obj:
+                return UUID(obj["__uuid__"])
+            if "__datetime__" in obj:
+                return datetime.fromisoformat(obj["__datetime__"])
+            if "__bytes__" in obj:
+                return bytes.fromhex(obj["__bytes__"])
+        return {k: _decode(v) for k, v in obj.items()}
+    if isinstance(obj, list):
+        return [_decode(v) for v in obj]
+    return obj
+
+
+def _format_line(record: dict[str, Any]) -> str:
+    payload = json.dumps(_encode(record), sort_keys=True)
+    crc = zlib.crc32(payload.encode("utf-8"))
+    return f"{crc:08x} {payload}\n"
+
+
+class WriteAheadLog:
+    """Line-oriented append-only log with replay and compaction."""
+
+    def __init__(
+        self,
+        path: str,
+        fsync_policy: FsyncPolicy | str = FsyncPolicy.ALWAYS,
+        fsync_every: int = 100,
+    ) -> None:
+        """Initialize the log.
+
+        Args:
+            path: Log file location (created on first append)
+            fsync_policy: When to fsync appended records
+            fsync_every: Records between fsyncs for the BATCH policy
+        """
+        self.path = path
+        self.fsync_policy = FsyncPolicy(fsync_policy)
+        self.fsync_every = max(1, fsync_every)
+        # Records currently in the file / written by the last compaction
+        self.record_count = 0
+        self.compacted_count = 0
+        self._pending_sync = 0
+        self._file: Any = None
+
+    def replay(self) -> Iterator[dict[str, Any]]:
+        """Yield all intact records in append order."""
+        if not os.path.exists(self.path):
+            return
+        with open(self.path, encoding="utf-8") as f:
+            lines = f.read().split("\n")
+
+        # Trailing element is "" for a cleanly terminated log
+        if lines and lines[-1] == "":
+            lines.pop()
+        for index, line in enumerate(lines):
+            record = self._parse_line(line)
+            if record is None:
+                if index == len(lines) - 1:
+                    # Torn write at the tail: drop it so appends stay aligned
+                    valid = lines[:index]
+                    self._truncate(sum(len(x.encode("utf-8")) + 1 for x in valid))
+                    break
+                raise StorageError(f"Corrupted WAL record at line {index + 1}")
+            self.record_count += 1
+            yield record
+
+    def append(self, record: dict[str, Any]) -> None:
+        """Append a record, honoring the fsync policy."""
+        self.append_many([record])
+
+    def append_many(self, records: list[dict[str, Any]]) -> None:
+        """Append records as one write; ALWAYS fsyncs once per call."""
+        if self._file is None:
+            self._file = open(self.path, "a", encoding="utf-8")
+        self._file.write("".join(_format_line(record) for record in records))
+        self._file.flush()
+        self.record_count += len(records)
+        self._pending_sync += len(records)
+
+        if self.fsync_policy == FsyncPolicy.ALWAYS or (
+            self.fsync_policy == FsyncPolicy.BATCH
+            and self._pending_sync >= self.fsync_every
+        ):
+            self.sync()
+
+    def sync(self) -> None:
+        """Force appended records to stable storage."""
+        if self._file is not None and self._pending_sync:
+            self._file.flush()
+            os.fsync(self._file.fileno())
+        self._pending_sync = 0
+
+    def compact(self, records: Iterable[dict[str, Any]]) -> None:
+        """Atomically replace the log with a minimal set of records."""
+        self.close()
+        tmp_path = f"{self.path}.compact"
+        count = 0
+        with open(tmp_path, "w", encoding="utf-8") as f:
+            for record in records:
+                f.write(_format_line(record))
+                count += 1
+            f.flush()
+            os.fsync(f.fileno())
+        os.replace(tmp_path, self.path)
+        self.record_count = count
+        self.compacted_count = count
+
+    @property
+    def growth(self) -> int:
+        """Records appended since the last compaction."""
+        return self.record_count - self.compacted_count
+
+    def close(self) -> None:
+        """Flush and close the log file."""
+        if self._file is not None:
+            if self.fsync_policy != FsyncPolicy.NEVER:
+                self.sync()
+            self._file.close()
+            self._file = None
+        self._pending_sync = 0
+
+    def _truncate(self, size: int) -> None:
+        with open(self.path, "r+b") as f:
+            f.truncate(size)
+
+    @staticmethod
+    def _parse_line(line: str) -> dict[str, Any] | None:
+        crc, _, payload = line.partition(" ")
+        try:
+            if int(crc, 16) != zlib.crc32(payload.encode("utf-8")):
+                return None
+            record = json.loads(payload)
+        except ValueError:
+            return None
+        decoded = _decode(record)
+        return decoded if isinstance(decoded, dict) else None
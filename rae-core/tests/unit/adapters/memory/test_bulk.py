@@ -0,0 +1,85 @@
+"""Unit tests for bulk vector ingest into InMemoryStorage."""
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.utils.wal import WriteAheadLog
+
+
+class TestVectorBulkIngest:
+    """Test suite for VectorBulkIngest."""
+
+    @pytest.fixture
+    async def storage_and_ids(self):
+        storage = InMemoryStorage()
+        ids = [
+            await storage.store_memory(content=f"m{i}", tenant_id="t1")
+            for i in range(4)
+        ]
+        return storage, ids
+
+    @pytest.mark.asyncio
+    async def test_vectors_searchable_after_merge(self, storage_and_ids):
+        """Test staged vectors only become searchable once merged."""
+        storage, ids = storage_and_ids
+        async with storage.bulk_ingest() as batch:
+            batch.add(ids[0], [1.0, 0.0], "t1")
+            batch.add(ids[1], {"default": [0.0, 1.0]}, "t1", {"layer": "x"})
+            assert len(batch) == 2
+            assert await storage.search_similar([1.0, 0.0], "t1") == []
+
+        results = await storage.search_similar([1.0, 0.0], "t1")
+        assert results[0][0] == ids[0]
+        assert await storage.get_vector(ids[1], "t1") == [0.0, 1.0]
+        assert await storage.search_similar([0.0, 1.0], "t1", layer="x")
+        assert await storage.search_similar([1.0, 0.0], "t2") == []
+
+    @pytest.mark.asyncio
+    async def test_merge_updates_and_skips_missing(self, storage_and_ids):
+        """Test merging overwrites existing vectors and skips unknown memories."""
+        storage, ids = storage_and_ids
+        await storage.store_vector(ids[0], [1.0, 0.0], "t1")
+        await storage.delete_memory(ids[3], "t1")
+
+        batch = storage.bulk_ingest()
+        batch.add(ids[0], [0.0, 1.0], "t1")
+        batch.add(ids[2], [1.0, 1.0], "t1")
+        batch.add(ids[3], [1.0, 0.0], "t1")
+        assert await batch.merge() == 2
+
+        assert await storage.get_vector(ids[0], "t1") == [0.0, 1.0]
+        assert await storage.get_vector(ids[3], "t1") is None
+        assert len(storage._vector_arenas["default"]) == 2 * 2 * 4
+
+    @pytest.mark.asyncio
+    async def test_dimension_and_type_checks(self, storage_and_ids):
+        """Test bad embeddings are rejected like store_vector does."""
+        storage, ids = storage_and_ids
+        await storage.store_vector(ids[0], [1.0, 0.0], "t1")
+        batch = storage.bulk_ingest()
+        assert batch.add(ids[1], "not a vector", "t1") is False
+        with pytest.raises(ValueError, match="Dimension mismatch"):
+            batch.add(ids[1], [1.0, 0.0, 0.0], "t1")
+
+    @pytest.mark.asyncio
+    async def test_batch_store_vectors_logs_once(self, tmp_path):
+        """Test a batch is written to the log in one fsynced append."""
+        wal_path = str(tmp_path / "bulk.wal")
+        storage = InMemoryStorage(wal_path=wal_path)
+        ids = [
+            await storage.store_memory(content=f"m{i}", tenant_id="t1")
+            for i in range(3)
+        ]
+        syncs = []
+        sync = WriteAheadLog.sync
+        storage._wal.sync = lambda: (syncs.append(1), sync(storage._wal))
+
+        count = await storage.batch_store_vectors(
+            [(mid, [float(i), 1.0], {}) for i, mid in enumerate(ids)], "t1"
+        )
+        assert count == 3
+        assert len(syncs) == 1
+        await storage.close()
+
+        restored = InMemoryStorage(wal_path=wal_path)
+        assert await restored.get_vector(ids[2], "t1") == [2.0, 1.0]
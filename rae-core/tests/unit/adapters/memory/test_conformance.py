@@ -0,0 +1,32 @@
+"""Conformance of the in-memory backends to the storage, vector and graph kits."""
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.testing import GraphConformance, StorageConformance, VectorConformance
+
+
+class TestInMemoryStorageConformance(StorageConformance):
+    """InMemoryStorage, doubling as its vector store, with InMemoryGraphStore."""
+
+    async def make_storage(self):
+        return InMemoryStorage()
+
+    async def make_vector_store(self, storage):
+        return storage
+
+    async def make_graph_store(self):
+        return InMemoryGraphStore()
+
+
+class TestInMemoryVectorConformance(VectorConformance):
+    """InMemoryStorage as a vector store."""
+
+    async def make_vector_store(self):
+        return InMemoryStorage()
+
+
+class TestInMemoryGraphConformance(GraphConformance):
+    """InMemoryGraphStore."""
+
+    async def make_graph_store(self):
+        return InMemoryGraphStore()
@@ -0,0 +1,65 @@
+"""Unit tests for the distance metric of InMemoryStorage collections."""
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.models.quantization import QuantizationConfig
+from rae_core.types.enums import DistanceMetric
+
+
+async def _store(storage, embedding):
+    return await storage.store_memory(content="x", tenant_id="t1", embedding=embedding)
+
+
+class TestInMemoryDistanceMetric:
+    @pytest.mark.asyncio
+    async def test_cosine_by_default(self):
+        """Test vector lengths do not count under the default metric."""
+        storage = InMemoryStorage()
+        await _store(storage, [1.0, 0.0])
+        await _store(storage, [3.0, 0.0])
+
+        results = await storage.search_similar([1.0, 0.0], "t1")
+
+        assert [score for _, score in results] == pytest.approx([1.0, 1.0])
+
+    @pytest.mark.asyncio
+    async def test_dot_product_ranks_by_length(self):
+        """Test dot product scores are not normalized."""
+        storage = InMemoryStorage(distance_metric=DistanceMetric.DOT)
+        short = await _store(storage, [1.0, 0.0])
+        long = await _store(storage, [3.0, 0.0])
+
+        results = await storage.search_similar([1.0, 0.0], "t1", score_threshold=2.0)
+
+        assert results == [(long, pytest.approx(3.0, abs=1e-4))]
+        assert short not in [found for found, _ in results]
+
+    @pytest.mark.asyncio
+    async def test_euclid_per_collection(self):
+        """Test a dict gives one collection euclidean scores."""
+        storage = InMemoryStorage(distance_metric={"clip": "euclid"})
+        near = await _store(storage, {"default": [1.0, 0.0], "clip": [1.0, 0.0]})
+        far = await _store(storage, {"default": [3.0, 0.0], "clip": [3.0, 0.0]})
+
+        euclid = await storage.search_similar([1.0, 0.0], "t1", vector_name="clip")
+        cosine = await storage.search_similar([1.0, 0.0], "t1")
+
+        assert euclid == [
+            (near, pytest.approx(1.0, abs=1e-4)),
+            (far, pytest.approx(1 / 3, abs=1e-4)),
+        ]
+        assert [score for _, score in cosine] == pytest.approx([1.0, 1.0])
+
+    @pytest.mark.asyncio
+    async def test_rescoring_uses_metric(self):
+        """Test quantized candidates are re-scored by the collection's metric."""
+        storage = InMemoryStorage(
+            quantization=QuantizationConfig(), distance_metric="dot"
+        )
+        long = await _store(storage, [2.0, 0.0, 0.0])
+        await _store(storage, [0.0, 1.0, 0.0])
+
+        results = await storage.search_similar([1.0, 0.5, 0.0], "t1", limit=1)
+
+        assert results == [(long, pytest.approx(2.0, abs=1e-2))]
@@ -0,0 +1,290 @@
+"""Unit tests for InMemoryGraphStore."""
+
+from datetime import datetime, timedelta, timezone
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.exceptions.base import (
+    CycleDetectedError,
+    FanOutExceededError,
+    VisitBudgetExceededError,
+)
+from rae_core.models.graph import EdgeSampling, TraversalLimits
+from rae_core.utils.clock import DeterministicClock
+
+
+class TestInMemoryGraphStore:
+    """Test suite for InMemoryGraphStore."""
+
+    @pytest.fixture
+    def graph_store(self):
+        return InMemoryGraphStore()
+
+    @pytest.fixture
+    async def hub(self, graph_store):
+        """Hub node with 50 spokes, each spoke linked to one leaf."""
+        hub_id = uuid4()
+        await graph_store.create_node(hub_id, "memory", "t1")
+        spokes = []
+        for _ in range(50):
+            spoke, leaf = uuid4(), uuid4()
+            await graph_store.create_node(spoke, "memory", "t1")
+            await graph_store.create_node(leaf, "memory", "t1")
+            await graph_store.create_edge(hub_id, spoke, "relates_to", "t1")
+            await graph_store.create_edge(spoke, leaf, "relates_to", "t1")
+            spokes.append(spoke)
+        return hub_id, spokes
+
+    @pytest.mark.asyncio
+    async def test_neighbors_and_directions(self, graph_store):
+        """Test one-hop neighbors and tenant isolation."""
+        id1, id2, id3 = uuid4(), uuid4(), uuid4()
+        for node in (id1, id2, id3):
+            await graph_store.create_node(node, "P", "t1")
+        await graph_store.create_edge(id1, id2, "KNOWS", "t1")
+        await graph_store.create_edge(id3, id1, "KNOWS", "t1")
+
+        assert set(await graph_store.get_neighbors(id1, "t1")) == {id2, id3}
+        assert await graph_store.get_neighbors(id1, "t1", direction="out") == [id2]
+        assert await graph_store.get_neighbors(id1, "t1", direction="in") == [id3]
+        assert await graph_store.get_neighbors(id1, "t2") == []
+
+    @pytest.mark.asyncio
+    async def test_multi_hop_and_delete(self, graph_store):
+        """Test max_depth traversal and node deletion cascading to edges."""
+        a, b, c = uuid4(), uuid4(), uuid4()
+        for node in (a, b, c):
+            await graph_store.create_node(node, "P", "t1")
+        await graph_store.create_edge(a, b, "NEXT", "t1")
+        await graph_store.create_edge(b, c, "NEXT", "t1")
+
+        assert await graph_store.get_neighbors(a, "t1", max_depth=2) == [b, c]
+        assert await graph_store.shortest_path(a, c, "t1") == [a, b, c]
+
+        assert await graph_store.delete_node(b, "t1") is True
+        assert await graph_store.get_neighbors(a, "t1") == []
+        assert await graph_store.delete_edge(a, b, "NEXT", "t1") is False
+
+    @pytest.mark.asyncio
+    async def test_fan_out_truncated(self, graph_store, hub):
+        """Test per-node fan-out cap in lenient mode."""
+        hub_id, spokes = hub
+        limits = TraversalLimits(max_fan_out=5)
+
+        neighbors = await graph_store.get_neighbors(hub_id, "t1", limits=limits)
+        assert neighbors == spokes[:5]
+
+    @pytest.mark.asyncio
+    async def test_fan_out_strict(self, graph_store, hub):
+        """Test typed error when fan-out is exceeded in strict mode."""
+        hub_id, _ = hub
+        limits = TraversalLimits(max_fan_out=5, strict=True)
+
+        with pytest.raises(FanOutExceededError) as exc_info:
+            await graph_store.get_neighbors(hub_id, "t1", limits=limits)
+        assert exc_info.value.fan_out == 50
+        assert exc_info.value.limit == 5
+
+    @pytest.mark.asyncio
+    async def test_visit_budget(self, graph_store, hub):
+        """Test global node-visit budget across depths."""
+        hub_id, _ = hub
+
+        lenient = TraversalLimits(max_visited=60)
+        neighbors = await graph_store.get_neighbors(
+            hub_id, "t1", max_depth=2, limits=lenient
+        )
+        assert len(neighbors) == 60
+
+        strict = TraversalLimits(max_visited=60, strict=True)
+        with pytest.raises(VisitBudgetExceededError):
+            await graph_store.get_neighbors(hub_id, "t1", max_depth=2, limits=strict)
+
+    @pytest.mark.asyncio
+    async def test_cycle_detection(self, graph_store):
+        """Test cycles are skipped by default and rejected on request."""
+        a, b, c = uuid4(), uuid4(), uuid4()
+        for node in (a, b, c):
+            await graph_store.create_node(node, "P", "t1")
+        await graph_store.create_edge(a, b, "NEXT", "t1")
+        await graph_store.create_edge(b, c, "NEXT", "t1")
+        await graph_store.create_edge(c, a, "NEXT", "t1")
+
+        neighbors = await graph_store.get_neighbors(
+            a, "t1", direction="out", max_depth=5
+        )
+        assert neighbors == [b, c]
+
+        limits = TraversalLimits(fail_on_cycle=True)
+        with pytest.raises(CycleDetectedError):
+            await graph_store.get_neighbors(
+                a, "t1", direction="out", max_depth=5, limits=limits
+            )
+
+    @pytest.mark.asyncio
+    async def test_undirected_back_edge_is_not_cycle(self, graph_store):
+        """Test that walking back over the arrival edge is not a cycle."""
+        a, b = uuid4(), uuid4()
+        await graph_store.create_node(a, "P", "t1")
+        await graph_store.create_node(b, "P", "t1")
+        await graph_store.create_edge(a, b, "NEXT", "t1")
+
+        limits = TraversalLimits(fail_on_cycle=True)
+        assert await graph_store.get_neighbors(a, "t1", max_depth=3, limits=limits) == [
+            b
+        ]
+
+    @pytest.mark.asyncio
+    async def test_subgraph_budget(self, graph_store, hub):
+        """Test node budget applied to subgraph extraction."""
+        hub_id, spokes = hub
+        node_ids = [hub_id, *spokes]
+
+        subgraph = await graph_store.get_subgraph(
+            node_ids, "t1", limits=TraversalLimits(max_visited=3)
+        )
+        assert len(subgraph["nodes"]) == 3
+        assert len(subgraph["edges"]) == 2
+
+        with pytest.raises(VisitBudgetExceededError):
+            await graph_store.get_subgraph(
+                node_ids, "t1", limits=TraversalLimits(max_visited=3, strict=True)
+            )
+
+    @pytest.fixture
+    async def weighted_hub(self):
+        """Hub with 20 edges; later edges are newer and heavier."""
+        start = datetime(2025, 1, 1, tzinfo=timezone.utc)
+        clock = DeterministicClock(start)
+        graph_store = InMemoryGraphStore(clock=clock)
+        hub_id = uuid4()
+        await graph_store.create_node(hub_id, "memory", "t1")
+        spokes = []
+        for i in range(20):
+            spoke = uuid4()
+            clock.set_time(start + timedelta(days=i))
+            await graph_store.create_node(spoke, "memory", "t1")
+            await graph_store.create_edge(
+                hub_id, spoke, "relates_to", "t1", weight=(i + 1) / 20
+            )
+            spokes.append(spoke)
+        return graph_store, hub_id, spokes
+
+    @pytest.mark.asyncio
+    async def test_top_weight_sampling(self, weighted_hub):
+        """Test strongest edges are kept for high-degree nodes."""
+        graph_store, hub_id, spokes = weighted_hub
+        limits = TraversalLimits(max_fan_out=3, sampling=EdgeSampling.TOP_WEIGHT)
+
+        neighbors = await graph_store.get_neighbors(hub_id, "t1", limits=limits)
+        assert neighbors == spokes[:-4:-1]
+
+    @pytest.mark.asyncio
+    async def test_random_sampling_is_seeded(self, weighted_hub):
+        """Test random sampling is stable per seed and differs across seeds."""
+        graph_store, hub_id, spokes = weighted_hub
+
+        samples = []
+        for seed in (1, 1, 2):
+            limits = TraversalLimits(
+                max_fan_out=5, sampling=EdgeSampling.RANDOM, sampling_seed=seed
+            )
+            samples.append(
+                await graph_store.get_neighbors(hub_id, "t1", limits=limits)
+            )
+
+        assert len(samples[0]) == 5
+        assert set(samples[0]) <= set(spokes)
+        assert samples[0] == samples[1]
+        assert samples[0] != samples[2]
+
+    @pytest.mark.asyncio
+    async def test_recency_sampling_favors_recent_edges(self, weighted_hub):
+        """Test recency-weighted sampling prefers newer edges."""
+        graph_store, hub_id, spokes = weighted_hub
+        recent = set(spokes[10:])
+
+        hits = 0
+        for seed in range(20):
+            limits = TraversalLimits(
+                max_fan_out=4,
+                sampling=EdgeSampling.RECENCY,
+                recency_half_life_seconds=2 * 24 * 3600,
+                sampling_seed=seed,
+            )
+            neighbors = await graph_store.get_neighbors(hub_id, "t1", limits=limits)
+            assert len(neighbors) == 4
+            hits += len(recent & set(neighbors))
+
+        assert hits / 80 > 0.9
+
+    @pytest.mark.asyncio
+    async def test_wal_replay(self, tmp_path):
+        """Test graph state survives a restart through the write-ahead log."""
+        wal_path = str(tmp_path / "graph.wal")
+        graph_store = InMemoryGraphStore(wal_path=wal_path, fsync_policy="batch")
+        a, b, c = uuid4(), uuid4(), uuid4()
+        for node in (a, b, c):
+            await graph_store.create_node(node, "P", "t1", {"name": str(node)})
+        await graph_store.create_edge(a, b, "NEXT", "t1", weight=0.3)
+        await graph_store.create_edge(b, c, "NEXT", "t1")
+        await graph_store.delete_edge(b, c, "NEXT", "t1")
+        await graph_store.delete_node(c, "t1")
+        await graph_store.close()
+
+        restored = InMemoryGraphStore(wal_path=wal_path)
+        subgraph = await restored.get_subgraph([a, b, c], "t1")
+
+        assert {n["id"] for n in subgraph["nodes"]} == {a, b}
+        assert subgraph["edges"][0]["weight"] == 0.3
+        assert await restored.compact_log() == 3
+
+    @pytest.mark.asyncio
+    async def test_snapshot(self, graph_store):
+        """Test snapshots copy the graph and ignore later writes."""
+        a, b, c = uuid4(), uuid4(), uuid4()
+        await graph_store.create_node(c, "P", "t1")
+        await graph_store.create_edge(a, b, "NEXT", "t1", weight=0.5)
+        await graph_store.create_edge(b, a, "OTHER", "t1")
+
+        snapshot = await graph_store.snapshot("t1", edge_type="NEXT")
+        await graph_store.delete_edge(a, b, "NEXT", "t1")
+        await graph_store.delete_edge(b, a, "OTHER", "t1")
+
+        assert snapshot.node_count == 3
+        assert snapshot.edge_count == 1
+        assert snapshot.neighbors(a, "out") == [b]
+        assert await graph_store.get_neighbors(a, "t1") == []
+
+    @pytest.mark.asyncio
+    async def test_adjacency_maps_follow_direction(self, graph_store, hub):
+        """Test outgoing/incoming maps serve hops, subgraphs and deletions."""
+        hub_id, spokes = hub
+        inbound = uuid4()
+        await graph_store.create_edge(inbound, hub_id, "relates_to", "t1")
+        await graph_store.create_edge(hub_id, hub_id, "self", "t1")
+
+        assert await graph_store.get_neighbors(hub_id, "t1", direction="in") == [
+            inbound
+        ]
+        out = await graph_store.get_neighbors(hub_id, "t1", direction="out")
+        assert out == spokes
+        assert await graph_store.shortest_path(inbound, spokes[0], "t1") == [
+            inbound,
+            hub_id,
+            spokes[0],
+        ]
+
+        subgraph = await graph_store.get_subgraph([spokes[0], hub_id], "t1")
+        assert {(e["source_id"], e["target_id"]) for e in subgraph["edges"]} == {
+            (hub_id, spokes[0]),
+            (hub_id, hub_id),
+        }
+
+        assert await graph_store.delete_node(hub_id, "t1") is True
+        assert hub_id not in graph_store._outgoing["t1"]
+        assert hub_id not in graph_store._incoming["t1"]
+        assert inbound not in graph_store._outgoing["t1"]
+        assert await graph_store.get_neighbors(spokes[0], "t1", direction="in") == []
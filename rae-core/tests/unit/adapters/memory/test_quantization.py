@@ -0,0 +1,184 @@
+"""Unit tests for quantized vector collections of InMemoryStorage."""
+
+import asyncio
+import random
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.math.vector_quantization import (
+    FixedPointCodec,
+    ProductQuantizer,
+    ScalarQuantizer,
+)
+from rae_core.models.quantization import QuantizationConfig, QuantizationMode
+
+
+def _vectors(count, dim, seed=7):
+    rng = random.Random(seed)
+    return [[rng.uniform(-1.0, 1.0) for _ in range(dim)] for _ in range(count)]
+
+
+async def _store(storage, embedding):
+    return await storage.store_memory(content="x", tenant_id="t1", embedding=embedding)
+
+
+def _product(**kwargs):
+    return QuantizationConfig(
+        mode=QuantizationMode.PRODUCT,
+        subvectors=2,
+        centroids=8,
+        train_size=20,
+        train_iterations=3,
+        **kwargs,
+    )
+
+
+class TestQuantizedInMemoryStorage:
+    @pytest.mark.asyncio
+    async def test_scalar_arena_is_smaller(self):
+        """Test scalar codes take a byte per dimension plus the scale."""
+        storage = InMemoryStorage(quantization=QuantizationConfig())
+        vectors = _vectors(10, 16)
+        for vector in vectors:
+            await _store(storage, vector)
+
+        assert isinstance(storage._codecs["default"], ScalarQuantizer)
+        assert len(storage._vector_arenas["default"]) == 10 * (4 + 16)
+
+    @pytest.mark.asyncio
+    async def test_scalar_search_rescored(self):
+        """Test the best match comes first with a near-exact score."""
+        storage = InMemoryStorage(quantization=QuantizationConfig())
+        vectors = _vectors(30, 16)
+        ids = [await _store(storage, vector) for vector in vectors]
+
+        results = await storage.search_similar(vectors[3], "t1", limit=5)
+
+        assert len(results) == 5
+        assert results[0][0] == ids[3]
+        assert results[0][1] == pytest.approx(1.0, abs=1e-3)
+        assert [s for _, s in results] == sorted((s for _, s in results), reverse=True)
+
+    @pytest.mark.asyncio
+    async def test_threshold_applies_to_rescored_scores(self):
+        """Test the score threshold filters re-scored candidates."""
+        storage = InMemoryStorage(quantization=QuantizationConfig())
+        kept = await _store(storage, [1.0, 0.0, 0.0])
+        await _store(storage, [0.5, 0.5, 0.0])
+
+        results = await storage.search_similar(
+            [1.0, 0.0, 0.0], "t1", score_threshold=0.9
+        )
+
+        assert [memory_id for memory_id, _ in results] == [kept]
+
+    @pytest.mark.asyncio
+    async def test_per_collection_config(self):
+        """Test only the configured collection is quantized."""
+        storage = InMemoryStorage(quantization={"clip": QuantizationConfig()})
+        memory_id = await _store(storage, {"default": [1.0, 0.0], "clip": [0.6, 0.8]})
+
+        assert isinstance(storage._codecs["default"], FixedPointCodec)
+        assert isinstance(storage._codecs["clip"], ScalarQuantizer)
+        assert await storage.get_vector(memory_id, "t1") == [1.0, 0.0]
+        clip = await storage.get_vector(memory_id, "t1", vector_name="clip")
+        assert clip == pytest.approx([0.6, 0.8], abs=0.01)
+
+    @pytest.mark.asyncio
+    async def test_product_trains_at_train_size(self):
+        """Test product codes replace fixed point once enough vectors exist."""
+        storage = InMemoryStorage(quantization=_product())
+        vectors = _vectors(25, 8)
+        ids = [await _store(storage, vector) for vector in vectors[:19]]
+
+        assert isinstance(storage._codecs["default"], FixedPointCodec)
+
+        ids += [await _store(storage, vector) for vector in vectors[19:]]
+
+        codec = storage._codecs["default"]
+        assert isinstance(codec, ProductQuantizer) and codec.trained
+        assert len(storage._vector_arenas["default"]) == 25 * 2
+        results = await storage.search_similar(vectors[0], "t1", limit=25)
+        assert {memory_id for memory_id, _ in results} <= set(ids)
+        assert ids[0] in [memory_id for memory_id, _ in results[:5]]
+
+    @pytest.mark.asyncio
+    async def test_training_waits_for_scans_of_other_tenants(self, monkeypatch):
+        """Test a store due to train does not re-encode under a paused scan."""
+        from rae_core.adapters.memory import storage as storage_module
+
+        monkeypatch.setattr(storage_module, "_SCAN_YIELD_EVERY", 2)
+        storage = InMemoryStorage(quantization=_product())
+        scanned = "t-0"
+        other = next(
+            f"t-{i}"
+            for i in range(1, 1000)
+            if storage._locks.shard(f"t-{i}") is not storage._locks.shard(scanned)
+        )
+        vectors = _vectors(20, 8)
+        for vector in vectors[:19]:
+            await storage.store_memory(
+                content="x", tenant_id=scanned, embedding=vector
+            )
+        expected = await storage.search_similar(vectors[0], scanned, limit=19)
+
+        search = asyncio.create_task(
+            storage.search_similar(vectors[0], scanned, limit=19)
+        )
+        await asyncio.sleep(0)
+        assert storage._locks.locked(scanned)
+        store = asyncio.create_task(
+            storage.store_memory(content="x", tenant_id=other, embedding=vectors[19])
+        )
+        await asyncio.sleep(0)
+        assert isinstance(storage._codecs["default"], FixedPointCodec)
+
+        assert await search == expected
+        await store
+        assert isinstance(storage._codecs["default"], ProductQuantizer)
+
+    @pytest.mark.asyncio
+    async def test_product_codes_replayed(self, tmp_path):
+        """Test codebooks and codes survive a restart."""
+        wal_path = str(tmp_path / "memories.wal")
+        storage = InMemoryStorage(wal_path=wal_path, quantization=_product())
+        vectors = _vectors(22, 8)
+        ids = [await _store(storage, vector) for vector in vectors]
+        expected = await storage.get_vector(ids[21], "t1")
+        await storage.close()
+
+        restored = InMemoryStorage(wal_path=wal_path, quantization=_product())
+
+        codec = restored._codecs["default"]
+        assert isinstance(codec, ProductQuantizer) and codec.trained
+        assert await restored.get_vector(ids[21], "t1") == pytest.approx(expected)
+
+    @pytest.mark.asyncio
+    async def test_enabling_quantization_reencodes_log(self, tmp_path):
+        """Test vectors logged unquantized are encoded on replay."""
+        wal_path = str(tmp_path / "memories.wal")
+        storage = InMemoryStorage(wal_path=wal_path)
+        memory_id = await _store(storage, [0.6, 0.8])
+        await storage.close()
+
+        restored = InMemoryStorage(wal_path=wal_path, quantization=QuantizationConfig())
+
+        assert len(restored._vector_arenas["default"]) == 4 + 2
+        vector = await restored.get_vector(memory_id, "t1")
+        assert vector == pytest.approx([0.6, 0.8], abs=0.01)
+
+    @pytest.mark.asyncio
+    async def test_bulk_ingest_encodes(self):
+        """Test bulk merges store the collection's codes."""
+        storage = InMemoryStorage(quantization=QuantizationConfig())
+        ids = []
+        async with storage.bulk_ingest() as batch:
+            for vector in _vectors(3, 4):
+                memory_id = await storage.store_memory(content="x", tenant_id="t1")
+                ids.append(memory_id)
+                batch.add(memory_id, vector, "t1")
+
+        assert len(storage._vector_arenas["default"]) == 3 * (4 + 4)
+        results = await storage.search_similar(_vectors(3, 4)[1], "t1", limit=1)
+        assert results[0][0] == ids[1]
@@ -1,11 +1,13 @@
 """Unit tests for InMemoryStorage adapter."""
 
-from datetime import datetime, timezone
+import asyncio
+from datetime import datetime, timedelta, timezone
 from uuid import uuid4
 
 import pytest
 
 from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import VersionConflictError
 
 
 class TestInMemoryStorage:
@@ -289,6 +291,65 @@ class TestInMemoryStorage:
         )
         assert count == 1
 
+    @pytest.mark.asyncio
+    async def test_indexes_follow_updates_and_deletes(self, storage):
+        """Test index-backed lookups after updates, trashing and deletes."""
+        memory_id = await storage.store_memory(
+            content="M1", layer="working", tenant_id="t1", agent_id="a1", tags=["x"]
+        )
+        other = await storage.store_memory(
+            content="M2", layer="working", tenant_id="t1", agent_id="a2"
+        )
+
+        await storage.update_memory(
+            memory_id, "t1", {"layer": "episodic", "tags": ["y"]}
+        )
+        assert await storage.count_memories("t1", layer="working") == 1
+        assert await storage.count_memories("t1", layer="episodic") == 1
+        assert await storage.list_memories("t1", tags=["x"]) == []
+        assert [m["id"] for m in await storage.list_memories("t1", tags=["y"])] == [
+            memory_id
+        ]
+        # Emptied index entries are dropped rather than kept around
+        assert ("t1", "x") not in storage._by_tags
+        assert ("t1", "working") in storage._by_layer
+
+        await storage.soft_delete_memory(other, "t1")
+        assert await storage.count_memories("t1") == 1
+        assert await storage.count_memories("t1", agent_id="a2") == 0
+        assert len(await storage.list_memories("t1", include_deleted=True)) == 2
+
+        await storage.delete_memory(other, "t1")
+        await storage.delete_memory(memory_id, "t1")
+        assert await storage.count_memories("t1") == 0
+        assert "t1" not in storage._by_tenant
+        assert "t1" not in storage._deleted_by_tenant
+        assert not storage._by_agent and not storage._by_layer
+
+    @pytest.mark.asyncio
+    async def test_list_memories_pages_newest_first(self):
+        """Test pagination over an index-selected set is newest first."""
+        from datetime import timedelta
+
+        from rae_core.utils.clock import DeterministicClock
+
+        clock = DeterministicClock(datetime(2025, 1, 1, tzinfo=timezone.utc))
+        storage = InMemoryStorage(clock=clock)
+        ids = []
+        for i in range(5):
+            clock.set_time(datetime(2025, 1, 1, tzinfo=timezone.utc) + timedelta(i))
+            ids.append(
+                await storage.store_memory(content=f"M{i}", tenant_id="t1")
+            )
+            await storage.store_memory(content=f"other {i}", tenant_id="t2")
+
+        page = await storage.list_memories("t1", limit=2, offset=1)
+
+        assert [m["id"] for m in page] == [ids[3], ids[2]]
+        # Pages are copies
+        page[0]["content"] = "changed"
+        assert (await storage.get_memory(ids[3], "t1"))["content"] == "M3"
+
     @pytest.mark.asyncio
     async def test_increment_access_count(self, storage):
         """Test incrementing access count."""
@@ -313,6 +374,29 @@ class TestInMemoryStorage:
         success = await storage.increment_access_count(uuid4(), "tenant1")
         assert success is False
 
+    @pytest.mark.asyncio
+    async def test_record_access_batch(self, storage):
+        """Test one record_access call counts repeated ids and skips others."""
+        hot = await storage.store_memory(content="hot", tenant_id="tenant1")
+        cold = await storage.store_memory(content="cold", tenant_id="tenant1")
+        later = datetime.now(timezone.utc) + timedelta(hours=1)
+
+        updated = await storage.record_access(
+            [hot, cold, hot, uuid4()], "tenant1", accessed_at=later
+        )
+        assert updated == 2
+        assert await storage.record_access([hot], "tenant2") == 0
+
+        # An older timestamp never moves last_accessed_at back
+        await storage.record_access([hot], "tenant1", accessed_at=later - timedelta(1))
+
+        memory = await storage.get_memory(hot, "tenant1")
+        assert memory["access_count"] == 3
+        assert memory["usage_count"] == 3
+        assert memory["last_accessed_at"] == later
+        assert memory["version"] == 1
+        assert (await storage.get_memory(cold, "tenant1"))["access_count"] == 1
+
     @pytest.mark.asyncio
     async def test_clear_tenant(self, storage):
         """Test clearing all memories for a tenant."""
@@ -552,3 +636,181 @@ class TestInMemoryStorage:
 
         new_val = await storage.adjust_importance(memory_id, -2.0, "t")
         assert new_val == 0.0
+
+    @pytest.mark.asyncio
+    async def test_soft_delete_and_restore(self, storage):
+        """Test trash hides memories until restored."""
+        memory_id = await storage.store_memory(
+            content="Forgotten fact",
+            tenant_id="t",
+            agent_id="a",
+            embedding=[1.0, 0.0],
+        )
+
+        assert await storage.soft_delete_memory(memory_id, "other") is False
+        assert await storage.soft_delete_memory(memory_id, "t") is True
+        assert await storage.soft_delete_memory(memory_id, "t") is False
+
+        assert await storage.list_memories("t") == []
+        assert await storage.count_memories("t") == 0
+        assert await storage.search_memories("Forgotten", "t", "a") == []
+        assert await storage.search_similar([1.0, 0.0], "t") == []
+        assert (await storage.get_memory(memory_id, "t"))["deleted_at"] is not None
+        assert len(await storage.list_memories("t", include_deleted=True)) == 1
+
+        assert await storage.restore_memory(memory_id, "t") is True
+        assert await storage.restore_memory(memory_id, "t") is False
+        assert await storage.count_memories("t") == 1
+        assert (await storage.search_similar([1.0, 0.0], "t"))[0][0] == memory_id
+
+    @pytest.mark.asyncio
+    async def test_search_scans_only_its_tenant(self, storage):
+        """Test vectors of other tenants are neither scanned nor returned."""
+        mine = await storage.store_memory(
+            content="mine", tenant_id="t1", embedding=[1.0, 0.0]
+        )
+        await storage.store_memory(
+            content="theirs", tenant_id="t2", embedding=[1.0, 0.0]
+        )
+
+        assert [m for m, _ in await storage.search_similar([1.0, 0.0], "t1")] == [
+            mine
+        ]
+        assert storage._vectors_by_tenant["default"]["t1"] == {mine}
+
+        await storage.delete_memory(mine, "t1")
+        assert await storage.search_similar([1.0, 0.0], "t1") == []
+        assert storage._vectors_by_tenant["default"]["t1"] == set()
+
+    @pytest.mark.asyncio
+    async def test_long_search_does_not_block_other_tenants(
+        self, storage, monkeypatch
+    ):
+        """Test other tenants write while a tenant's long scan yields."""
+        from rae_core.adapters.memory import storage as storage_module
+
+        monkeypatch.setattr(storage_module, "_SCAN_YIELD_EVERY", 2)
+        scanned = "t-0"
+        other = next(
+            f"t-{i}"
+            for i in range(1, 1000)
+            if storage._locks.shard(f"t-{i}") is not storage._locks.shard(scanned)
+        )
+        for i in range(10):
+            await storage.store_memory(
+                content=f"m{i}", tenant_id=scanned, embedding=[1.0, float(i)]
+            )
+
+        search = asyncio.create_task(storage.search_similar([1.0, 0.0], scanned))
+        await asyncio.sleep(0)
+        assert storage._locks.locked(scanned)
+
+        await storage.store_memory(content="x", tenant_id=other, embedding=[1.0, 0.0])
+        assert not search.done()
+        assert len(await search) == 10
+
+    @pytest.mark.asyncio
+    async def test_purge_deleted(self):
+        """Test purge only removes memories trashed before the cutoff."""
+        from datetime import timedelta
+
+        from rae_core.utils.clock import DeterministicClock
+
+        start = datetime(2025, 1, 1, tzinfo=timezone.utc)
+        clock = DeterministicClock(start)
+        storage = InMemoryStorage(clock=clock)
+        old = await storage.store_memory(content="old", tenant_id="t")
+        recent = await storage.store_memory(content="recent", tenant_id="t")
+        other = await storage.store_memory(content="other", tenant_id="t2")
+
+        await storage.soft_delete_memory(old, "t")
+        await storage.soft_delete_memory(other, "t2")
+        clock.set_time(start + timedelta(days=10))
+        await storage.soft_delete_memory(recent, "t")
+
+        cutoff = start + timedelta(days=5)
+        assert await storage.purge_deleted(cutoff, tenant_id="t") == 1
+        assert await storage.get_memory(old, "t") is None
+        assert await storage.restore_memory(recent, "t") is True
+        assert await storage.purge_deleted(cutoff) == 1
+        assert await storage.get_memory(other, "t2") is None
+
+    @pytest.mark.asyncio
+    async def test_memory_history_and_revert(self, storage):
+        """Test updates keep past revisions and revert creates a new one."""
+        memory_id = await storage.store_memory(
+            content="v1", tenant_id="t", tags=["a"], importance=0.3
+        )
+        await storage.update_memory(memory_id, "t", {"content": "v2"})
+        await storage.update_memory(memory_id, "t", {"content": "v3", "tags": ["b"]})
+
+        history = await storage.get_memory_history(memory_id, "t")
+        assert [r["version"] for r in history] == [1, 2, 3]
+        assert [r["content"] for r in history] == ["v1", "v2", "v3"]
+        assert history[0]["tags"] == ["a"]
+        assert await storage.get_memory_history(memory_id, "other") == []
+
+        assert await storage.revert_to_version(memory_id, "t", 1) is True
+        memory = await storage.get_memory(memory_id, "t")
+        assert memory["content"] == "v1"
+        assert memory["tags"] == ["a"]
+        assert memory["version"] == 4
+        assert await storage.revert_to_version(memory_id, "t", 99) is False
+
+    @pytest.mark.asyncio
+    async def test_memory_history_limit(self):
+        """Test only the configured number of past revisions is retained."""
+        storage = InMemoryStorage(history_limit=2)
+        memory_id = await storage.store_memory(content="v1", tenant_id="t")
+        for i in range(2, 6):
+            await storage.update_memory(memory_id, "t", {"content": f"v{i}"})
+
+        history = await storage.get_memory_history(memory_id, "t")
+        assert [r["version"] for r in history] == [3, 4, 5]
+        assert await storage.revert_to_version(memory_id, "t", 1) is False
+
+        await storage.delete_memory(memory_id, "t")
+        assert await storage.get_memory_history(memory_id, "t") == []
+
+    @pytest.mark.asyncio
+    async def test_change_log(self, storage):
+        """Test field-level changes with author are recorded per update."""
+        memory_id = await storage.store_memory(
+            content="Original", tenant_id="t", importance=0.3
+        )
+        await storage.update_memory(
+            memory_id, "t", {"content": "Edited", "importance": 0.3}, changed_by="bob"
+        )
+        await storage.update_memory(memory_id, "t", {"importance": 0.3})
+        await storage.update_memory(memory_id, "t", {"tags": ["x"]})
+
+        log = await storage.get_change_log(memory_id, "t")
+        assert [e["version"] for e in log] == [2, 4]
+        assert log[0]["changed_by"] == "bob"
+        assert log[0]["changes"] == [
+            {"field": "content", "old_value": "Original", "new_value": "Edited"}
+        ]
+        assert log[1]["changed_by"] is None
+        assert await storage.get_change_log(memory_id, "other") == []
+
+        await storage.delete_memory(memory_id, "t")
+        assert await storage.get_change_log(memory_id, "t") == []
+
+    @pytest.mark.asyncio
+    async def test_update_expected_version(self, storage):
+        """Test an update based on a stale version is rejected."""
+        memory_id = await storage.store_memory(content="v1", tenant_id="t")
+
+        assert await storage.update_memory(
+            memory_id, "t", {"content": "v2"}, expected_version=1
+        )
+        with pytest.raises(VersionConflictError) as exc:
+            await storage.update_memory(
+                memory_id, "t", {"content": "stale"}, expected_version=1
+            )
+
+        assert exc.value.expected_version == 1
+        assert exc.value.actual_version == 2
+        memory = await storage.get_memory(memory_id, "t")
+        assert memory["content"] == "v2"
+        assert memory["version"] == 2
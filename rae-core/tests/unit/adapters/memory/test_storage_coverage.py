@@ -5,6 +5,7 @@ from uuid import uuid4
 import pytest
 
 from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import NotFoundError
 
 
 class TestInMemoryStorageCoverage:
@@ -36,7 +37,7 @@ class TestInMemoryStorageCoverage:
         mid = await storage.store_memory(
             content="content", layer="layer", tenant_id="tenant1", agent_id="agent"
         )
-        with pytest.raises(ValueError, match="Access Denied"):
+        with pytest.raises(NotFoundError, match="not found for tenant"):
             await storage.save_embedding(mid, "model", [0.1, 0.2], "tenant2")
 
     @pytest.mark.asyncio
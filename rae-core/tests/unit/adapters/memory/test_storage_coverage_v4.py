@@ -5,6 +5,7 @@ import asyncio
 from uuid import uuid4
 from datetime import datetime, timedelta, timezone
 from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import NotFoundError
 from rae_core.utils.clock import IClock
 
 class MockClock(IClock):
@@ -80,11 +81,11 @@ async def test_get_memories_batch_coverage():
 
 @pytest.mark.asyncio
 async def test_save_embedding_access_denied():
-    """Test save_embedding raises ValueError on tenant mismatch (Lines 475-476)."""
+    """Test save_embedding raises NotFoundError on tenant mismatch (Lines 475-476)."""
     storage = InMemoryStorage()
     mid = await storage.store_memory(tenant_id="t1")
     
-    with pytest.raises(ValueError, match="Access Denied"):
+    with pytest.raises(NotFoundError, match="not found for tenant"):
         await storage.save_embedding(mid, "default", [0.1, 0.2], "t2")
 
 @pytest.mark.asyncio
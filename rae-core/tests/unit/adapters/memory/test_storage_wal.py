@@ -0,0 +1,98 @@
+"""Unit tests for InMemoryStorage write-ahead persistence."""
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+
+
+class TestInMemoryStorageWAL:
+    """Test suite for replaying InMemoryStorage from its log."""
+
+    @pytest.fixture
+    def wal_path(self, tmp_path):
+        return str(tmp_path / "memories.wal")
+
+    @pytest.mark.asyncio
+    async def test_restart_replays_state(self, wal_path):
+        """Test memories, updates, vectors and deletes survive a restart."""
+        storage = InMemoryStorage(wal_path=wal_path)
+        kept = await storage.store_memory(
+            content="User likes tea",
+            tenant_id="t1",
+            agent_id="a1",
+            tags=["pref"],
+            embedding=[1.0, 0.0],
+        )
+        dropped = await storage.store_memory(content="temp", tenant_id="t1")
+        await storage.update_memory(kept, "t1", {"layer": "semantic"})
+        await storage.update_memory_access(kept, "t1")
+        await storage.delete_memory(dropped, "t1")
+        await storage.close()
+
+        restored = InMemoryStorage(wal_path=wal_path)
+        memory = await restored.get_memory(kept, "t1")
+
+        assert memory["layer"] == "semantic"
+        assert memory["version"] == 2
+        assert memory["access_count"] == 1
+        assert await restored.get_memory(dropped, "t1") is None
+        assert await restored.count_memories("t1", layer="semantic") == 1
+        assert await restored.list_memories("t1", tags=["pref"]) != []
+        assert await restored.get_vector(kept, "t1") == [1.0, 0.0]
+
+        results = await restored.search_similar([1.0, 0.0], "t1")
+        assert results[0][0] == kept
+
+        history = await restored.get_memory_history(kept, "t1")
+        assert [r["layer"] for r in history] == ["episodic", "semantic"]
+        log = await restored.get_change_log(kept, "t1")
+        assert log[0]["changes"][0]["new_value"] == "semantic"
+
+    @pytest.mark.asyncio
+    async def test_bulk_delete_and_clear_replayed(self, wal_path):
+        """Test deletes issued by bulk operations are logged."""
+        storage = InMemoryStorage(wal_path=wal_path)
+        for importance in (0.1, 0.9):
+            await storage.store_memory(
+                content="x",
+                tenant_id="t1",
+                agent_id="a1",
+                layer="episodic",
+                importance=importance,
+            )
+        await storage.delete_memories_below_importance("t1", "a1", "episodic", 0.5)
+        await storage.close()
+
+        assert await InMemoryStorage(wal_path=wal_path).count_memories("t1") == 1
+
+        storage = InMemoryStorage(wal_path=wal_path)
+        await storage.clear_all()
+        await storage.close()
+
+        assert await InMemoryStorage(wal_path=wal_path).count_memories() == 0
+
+    @pytest.mark.asyncio
+    async def test_compaction(self, wal_path):
+        """Test automatic and manual log compaction."""
+        storage = InMemoryStorage(wal_path=wal_path, compact_after=10)
+        memory_id = await storage.store_memory(content="counter", tenant_id="t1")
+        for _ in range(25):
+            await storage.update_memory_access(memory_id, "t1")
+
+        with open(wal_path, encoding="utf-8") as f:
+            assert len(f.readlines()) < 10
+
+        assert await storage.compact_log() == 1
+        await storage.close()
+
+        restored = InMemoryStorage(wal_path=wal_path)
+        assert (await restored.get_memory(memory_id, "t1"))["access_count"] == 25
+
+    @pytest.mark.asyncio
+    async def test_no_log_by_default(self, tmp_path):
+        """Test storage stays purely in-memory without wal_path."""
+        storage = InMemoryStorage()
+        await storage.store_memory(content="x", tenant_id="t1")
+
+        assert await storage.compact_log() == 0
+        assert list(tmp_path.iterdir()) == []
@@ -0,0 +1,48 @@
+"""Unit tests for SQLiteAuditLogger."""
+
+from datetime import datetime, timedelta, timezone
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.sqlite.audit import SQLiteAuditLogger
+from rae_core.models.audit import AuditEntry, AuditOperation
+
+
+class TestSQLiteAuditLogger:
+    """Test suite for the SQLite audit log."""
+
+    @pytest.fixture
+    def audit_logger(self, tmp_path):
+        return SQLiteAuditLogger(str(tmp_path / "audit.db"))
+
+    @pytest.mark.asyncio
+    async def test_record_and_query(self, audit_logger):
+        """Test entries round-trip and filter by actor and time range."""
+        start = datetime(2025, 1, 1, tzinfo=timezone.utc)
+        memory_id = uuid4()
+        for i, actor in enumerate(["alice", "bob", "alice"]):
+            await audit_logger.record(
+                AuditEntry(
+                    timestamp=start + timedelta(hours=i),
+                    tenant_id="t",
+                    actor=actor,
+                    operation=AuditOperation.UPDATE,
+                    memory_id=memory_id,
+                    diff=[{"field": "importance", "old_value": i, "new_value": i + 1}],
+                )
+            )
+
+        entries = await audit_logger.query("t", actor="alice")
+        assert [e.timestamp for e in entries] == [start, start + timedelta(hours=2)]
+        assert entries[1].diff[0]["new_value"] == 3
+        assert entries[0].memory_id == memory_id
+
+        window = await audit_logger.query(
+            "t", start=start + timedelta(minutes=30), end=start + timedelta(hours=2)
+        )
+        assert [e.actor for e in window] == ["bob"]
+        assert await audit_logger.query("other") == []
+        assert (
+            await audit_logger.query("t", operation=AuditOperation.DELETE) == []
+        )
@@ -0,0 +1,68 @@
+"""Conformance of the SQLite backends to the storage, vector and graph kits."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.sqlite.graph import SQLiteGraphStore
+from rae_core.adapters.sqlite.storage import SQLiteStorage
+from rae_core.adapters.sqlite.vector import SQLiteVectorStore
+from rae_core.testing import GraphConformance, StorageConformance, VectorConformance
+
+
+class TestSQLiteStorageConformance(StorageConformance):
+    """SQLiteStorage, SQLiteVectorStore and SQLiteGraphStore on one file."""
+
+    # Every example creates a database file
+    examples = 10
+
+    @pytest.fixture(autouse=True)
+    def _db_dir(self, tmp_path):
+        self.db_dir = tmp_path
+
+    async def make_storage(self):
+        # One database file per example, shared with its vector and graph stores
+        self.db_path = str(self.db_dir / f"{uuid4()}.db")
+        storage = SQLiteStorage(self.db_path)
+        await storage.initialize()
+        return storage
+
+    async def make_vector_store(self, storage):
+        store = SQLiteVectorStore(self.db_path)
+        await store.initialize()
+        return store
+
+    async def make_graph_store(self):
+        store = SQLiteGraphStore(self.db_path)
+        await store.initialize()
+        return store
+
+
+class TestSQLiteVectorConformance(VectorConformance):
+    """SQLiteVectorStore on a database file per example."""
+
+    examples = 10
+
+    @pytest.fixture(autouse=True)
+    def _db_dir(self, tmp_path):
+        self.db_dir = tmp_path
+
+    async def make_vector_store(self):
+        store = SQLiteVectorStore(str(self.db_dir / f"{uuid4()}.db"))
+        await store.initialize()
+        return store
+
+
+class TestSQLiteGraphConformance(GraphConformance):
+    """SQLiteGraphStore on a database file per example."""
+
+    examples = 10
+
+    @pytest.fixture(autouse=True)
+    def _db_dir(self, tmp_path):
+        self.db_dir = tmp_path
+
+    async def make_graph_store(self):
+        store = SQLiteGraphStore(str(self.db_dir / f"{uuid4()}.db"))
+        await store.initialize()
+        return store
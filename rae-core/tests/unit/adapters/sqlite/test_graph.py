@@ -1,8 +1,11 @@
 from uuid import uuid4
 
+import aiosqlite
 import pytest
 
 from rae_core.adapters.sqlite.graph import SQLiteGraphStore
+from rae_core.exceptions.base import FanOutExceededError
+from rae_core.models.graph import EdgeSampling, TraversalLimits
 
 
 @pytest.fixture
@@ -110,3 +113,43 @@ class TestSQLiteGraphStore:
         subgraph = await graph_store.get_subgraph([id1, id2], "t1")
         assert len(subgraph["nodes"]) == 2
         assert len(subgraph["edges"]) == 1
+
+    @pytest.mark.asyncio
+    async def test_fan_out_bounded_in_sql(self, graph_store, monkeypatch):
+        """Test hub nodes fetch only the edges kept by max_fan_out."""
+        hub = uuid4()
+        await graph_store.create_node(hub, "P", "t1")
+        spokes = []
+        for i in range(30):
+            spoke = uuid4()
+            await graph_store.create_node(spoke, "P", "t1")
+            await graph_store.create_edge(hub, spoke, "KNOWS", "t1", weight=i / 30)
+            spokes.append(spoke)
+
+        fetched = []
+        fetchall = aiosqlite.Cursor.fetchall
+
+        async def counting_fetchall(cursor):
+            rows = await fetchall(cursor)
+            fetched.append(len(rows))
+            return rows
+
+        monkeypatch.setattr(aiosqlite.Cursor, "fetchall", counting_fetchall)
+
+        top = TraversalLimits(max_fan_out=3, sampling=EdgeSampling.TOP_WEIGHT)
+        assert await graph_store.get_neighbors(hub, "t1", limits=top) == spokes[:-4:-1]
+
+        sampled = TraversalLimits(
+            max_fan_out=3, sampling=EdgeSampling.RANDOM, sampling_seed=1
+        )
+        sample = await graph_store.get_neighbors(hub, "t1", limits=sampled)
+        assert len(sample) == 3
+        assert set(sample) <= set(spokes)
+        assert await graph_store.get_neighbors(hub, "t1", limits=sampled) == sample
+
+        strict = TraversalLimits(max_fan_out=3, strict=True)
+        with pytest.raises(FanOutExceededError) as exc:
+            await graph_store.get_neighbors(hub, "t1", limits=strict)
+        assert exc.value.fan_out == 30
+
+        assert fetched and max(fetched) <= 3
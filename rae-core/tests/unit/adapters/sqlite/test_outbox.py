@@ -0,0 +1,97 @@
+"""Unit tests for SQLiteOutboxStore and transactional outbox writes."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.sqlite.outbox import SQLiteOutboxStore
+from rae_core.adapters.sqlite.storage import SQLiteStorage
+from rae_core.events.models import MemoryStored
+
+
+class TestSQLiteOutboxStore:
+    """Test suite for the SQLite outbox."""
+
+    @pytest.fixture
+    def db_path(self, tmp_path):
+        return str(tmp_path / "rae.db")
+
+    @pytest.mark.asyncio
+    async def test_append_read_cursor_prune(self, db_path):
+        """Test entries and cursors persist across instances."""
+        outbox = SQLiteOutboxStore(db_path)
+        event = MemoryStored(
+            tenant_id="t1", memory_id=uuid4(), layer="episodic", content="x"
+        )
+        assert await outbox.append([event]) == [1]
+        assert await outbox.publish(event) == 2
+        await outbox.set_cursor("hook", 1)
+
+        reopened = SQLiteOutboxStore(db_path)
+        entries = await reopened.read(0)
+        assert [e.sequence for e in entries] == [1, 2]
+        assert entries[0].event_id == event.event_id
+        assert entries[0].payload["content"] == "x"
+        assert await reopened.get_cursor("hook") == 1
+
+        assert await reopened.prune(2) == 2
+        assert await reopened.publish(event) == 3
+
+    @pytest.mark.asyncio
+    async def test_storage_writes_events_transactionally(self, db_path):
+        """Test each committed write leaves its events in the outbox."""
+        outbox = SQLiteOutboxStore(db_path)
+        storage = SQLiteStorage(db_path, outbox=outbox)
+
+        memory_id = await storage.store_memory(
+            content="draft", layer="episodic", tenant_id="t1", agent_id="a1"
+        )
+        await storage.update_memory(
+            memory_id, "t1", {"layer": "semantic"}, changed_by="alice"
+        )
+        await storage.soft_delete_memory(memory_id, "t1")
+        await storage.restore_memory(memory_id, "t1")
+        await storage.delete_memory(memory_id, "t1")
+        # No-op writes publish nothing
+        assert not await storage.delete_memory(memory_id, "t1")
+
+        entries = await outbox.read(0)
+        assert [e.event_type for e in entries] == [
+            "MemoryStored",
+            "MemoryUpdated",
+            "MemoryPromoted",
+            "MemoryDeleted",
+            "MemoryRestored",
+            "MemoryDeleted",
+        ]
+        assert {e.payload["memory_id"] for e in entries} == {str(memory_id)}
+        assert entries[1].payload["changes"] == {"layer": "semantic"}
+        assert entries[1].payload["actor"] == "alice"
+        assert entries[2].payload["to_layer"] == "semantic"
+        assert entries[3].payload["soft"] is True
+        assert entries[4].payload["agent_id"] == "a1"
+
+    @pytest.mark.asyncio
+    async def test_failed_write_leaves_no_event(self, db_path):
+        """Test an event is rolled back with the write that failed."""
+        outbox = SQLiteOutboxStore(db_path)
+        storage = SQLiteStorage(db_path, outbox=outbox)
+        await storage.initialize()
+
+        async def failing_append(db, events):
+            await SQLiteOutboxStore.append_in(outbox, db, events)
+            raise RuntimeError("crash before commit")
+
+        outbox.append_in = failing_append
+        with pytest.raises(RuntimeError):
+            await storage.store_memory(
+                content="lost", layer="episodic", tenant_id="t1", agent_id="a1"
+            )
+
+        assert await storage.count_memories("t1") == 0
+        assert await outbox.read(0) == []
+
+    def test_outbox_must_share_database(self, db_path, tmp_path):
+        """Test a separate outbox file is rejected."""
+        with pytest.raises(ValueError, match="database file"):
+            SQLiteStorage(db_path, outbox=SQLiteOutboxStore(str(tmp_path / "o.db")))
@@ -6,11 +6,15 @@ and JSON metadata storage.
 
 import asyncio
 import json
+from datetime import datetime, timedelta, timezone
 from uuid import UUID, uuid4
 
 import pytest
 
 from rae_core.adapters.sqlite.storage import SQLiteStorage
+from rae_core.exceptions.base import NotFoundError, VersionConflictError
+from rae_core.models.query import MemoryQuery
+from rae_core.types.enums import MemoryLayer
 
 
 @pytest.fixture
@@ -387,6 +391,107 @@ class TestSQLiteStorageListOperations:
         )
         assert count == 1
 
+    @pytest.mark.asyncio
+    async def test_list_memories_with_query(self, storage):
+        """Test MemoryQuery bounds are applied in SQL."""
+        for i, (layer, importance) in enumerate(
+            [("semantic", 0.9), ("semantic", 0.6), ("episodic", 0.95)]
+        ):
+            await storage.store_memory(
+                content=f"Memory {i}",
+                layer=layer,
+                tenant_id="tenant-1",
+                agent_id="agent-1",
+                tags=["deploy"] if i < 2 else ["misc"],
+                importance=importance,
+            )
+
+        query = (
+            MemoryQuery()
+            .layer(MemoryLayer.SEMANTIC)
+            .tag_any(["deploy"])
+            .created_within(timedelta(days=7))
+        )
+        memories = await query.importance_gt(0.6).fetch(storage, "tenant-1")
+        assert [m["content"] for m in memories] == ["Memory 0"]
+        assert len(await query.importance_gte(0.6).fetch(storage, "tenant-1")) == 2
+
+        later = datetime.now(timezone.utc) + timedelta(days=8)
+        assert await query.fetch(storage, "tenant-1", now=later) == []
+
+    @pytest.mark.asyncio
+    async def test_session_scoping(self, storage):
+        """Test session_id is stored and filters listing and search."""
+        for session_id in ("s1", "s2", None):
+            await storage.store_memory(
+                content=f"Turn in {session_id}",
+                layer="working",
+                tenant_id="tenant-1",
+                agent_id="agent-1",
+                session_id=session_id,
+            )
+
+        listed = await MemoryQuery().session("s1").fetch(storage, "tenant-1")
+        assert [m["session_id"] for m in listed] == ["s1"]
+        found = await storage.search_memories(
+            "Turn", "tenant-1", "agent-1", session_id="s2"
+        )
+        assert [r["content"] for r in found] == ["Turn in s2"]
+        assert len(await storage.list_memories("tenant-1")) == 3
+
+    @pytest.mark.asyncio
+    async def test_group_commit(self, tmp_path):
+        """Test concurrent stores are batched and all committed."""
+        store = SQLiteStorage(
+            db_path=str(tmp_path / "batched.db"),
+            group_commit_ms=50,
+            group_commit_durability="async",
+        )
+        await store.initialize()
+
+        ids = await asyncio.gather(
+            *(
+                store.store_memory(
+                    content=f"Line {i}",
+                    layer="episodic",
+                    tenant_id="tenant-1",
+                    agent_id="agent-1",
+                    session_id="backfill",
+                )
+                for i in range(20)
+            )
+        )
+        assert store._committer.pending == 20
+
+        await store.flush()
+        listed = await store.list_memories("tenant-1", limit=50)
+        assert {m["id"] for m in listed} == set(ids)
+        assert {m["session_id"] for m in listed} == {"backfill"}
+        await store.close()
+
+    @pytest.mark.asyncio
+    async def test_access_time_bounds(self, storage):
+        """Test accessed/updated bounds in list_memories and search_memories."""
+        ids = [
+            await storage.store_memory(
+                content=f"Note {i}",
+                layer="episodic",
+                tenant_id="tenant-1",
+                agent_id="agent-1",
+            )
+            for i in range(2)
+        ]
+        mark = datetime.now(timezone.utc)
+        await storage.update_memory_access(ids[1], "tenant-1")
+
+        recent = await MemoryQuery().accessed_after(mark).fetch(storage, "tenant-1")
+        assert [m["id"] for m in recent] == [ids[1]]
+        assert await MemoryQuery().updated_after(mark).fetch(storage, "tenant-1") == []
+        found = await storage.search_memories(
+            "Note", "tenant-1", "agent-1", accessed_before=mark
+        )
+        assert [str(r["id"]) for r in found] == [str(ids[0])]
+
 
 class TestSQLiteStorageAccessTracking:
     """Test access count tracking."""
@@ -435,6 +540,28 @@ class TestSQLiteStorageAccessTracking:
         success = await storage.increment_access_count(uuid4(), "tenant-1")
         assert success is False
 
+    @pytest.mark.asyncio
+    async def test_record_access_batch(self, storage, sample_memory_data):
+        """Test one record_access call counts repeated ids and skips others."""
+        tenant_id = sample_memory_data["tenant_id"]
+        hot = await storage.store_memory(**sample_memory_data)
+        cold = await storage.store_memory(**sample_memory_data)
+        later = datetime.now(timezone.utc) + timedelta(hours=1)
+
+        updated = await storage.record_access(
+            [hot, cold, hot, uuid4()], tenant_id, accessed_at=later
+        )
+        assert updated == 2
+        assert await storage.record_access([hot], "other-tenant") == 0
+
+        # An older timestamp never moves last_accessed_at back
+        await storage.record_access([hot], tenant_id, accessed_at=later - timedelta(1))
+
+        memory = await storage.get_memory(hot, tenant_id)
+        assert memory["access_count"] == 3
+        assert memory["last_accessed_at"] == later.isoformat()
+        assert (await storage.get_memory(cold, tenant_id))["access_count"] == 1
+
 
 class TestSQLiteStorageFullTextSearch:
     """Test FTS5 full-text search functionality."""
@@ -848,7 +975,7 @@ class TestSQLiteStorageEmbeddings:
         memory_id = await storage.store_memory(
             content="T", layer="w", tenant_id="t1", agent_id="a"
         )
-        with pytest.raises(ValueError, match="Access Denied"):
+        with pytest.raises(NotFoundError, match="not found for tenant"):
             await storage.save_embedding(memory_id, "model-v1", [0.1], "t2")
 
 
@@ -886,3 +1013,143 @@ class TestSQLiteStorageTagsAndFilters:
             "t", order_by="dangerous_injection; DROP TABLE memories;"
         )
         assert len(results) == 1
+
+
+class TestSQLiteStorageSoftDelete:
+    """Test trash and restore operations."""
+
+    @pytest.mark.asyncio
+    async def test_soft_delete_hides_memory(self, storage, sample_memory_data):
+        """Test soft-deleted memories are excluded from list, count and search."""
+        memory_id = await storage.store_memory(**sample_memory_data)
+        tenant_id = sample_memory_data["tenant_id"]
+
+        assert await storage.soft_delete_memory(memory_id, tenant_id) is True
+        assert await storage.soft_delete_memory(memory_id, tenant_id) is False
+
+        assert await storage.list_memories(tenant_id) == []
+        assert await storage.count_memories(tenant_id) == 0
+        assert await storage.search_full_text("memory", tenant_id) == []
+        assert (
+            await storage.search_memories("Test", tenant_id, "agent-1") == []
+        )
+
+        trashed = await storage.list_memories(tenant_id, include_deleted=True)
+        assert len(trashed) == 1
+        assert trashed[0]["deleted_at"] is not None
+
+    @pytest.mark.asyncio
+    async def test_restore_memory(self, storage, sample_memory_data):
+        """Test restoring a memory from the trash."""
+        memory_id = await storage.store_memory(**sample_memory_data)
+        tenant_id = sample_memory_data["tenant_id"]
+        await storage.soft_delete_memory(memory_id, tenant_id)
+
+        assert await storage.restore_memory(memory_id, "tenant-2") is False
+        assert await storage.restore_memory(memory_id, tenant_id) is True
+        assert await storage.restore_memory(memory_id, tenant_id) is False
+
+        memories = await storage.list_memories(tenant_id)
+        assert len(memories) == 1
+        assert memories[0]["deleted_at"] is None
+
+    @pytest.mark.asyncio
+    async def test_purge_deleted(self, storage, sample_memory_data):
+        """Test purging trashed memories older than a cutoff."""
+        from datetime import datetime, timedelta, timezone
+
+        trashed = await storage.store_memory(**sample_memory_data)
+        kept = await storage.store_memory(**sample_memory_data)
+        tenant_id = sample_memory_data["tenant_id"]
+        await storage.soft_delete_memory(trashed, tenant_id)
+
+        past = datetime.now(timezone.utc) - timedelta(hours=1)
+        assert await storage.purge_deleted(past) == 0
+
+        future = datetime.now(timezone.utc) + timedelta(hours=1)
+        assert await storage.purge_deleted(future, tenant_id=tenant_id) == 1
+        assert await storage.get_memory(trashed, tenant_id) is None
+        assert await storage.get_memory(kept, tenant_id) is not None
+
+
+class TestSQLiteStorageVersioning:
+    """Test revision history and revert."""
+
+    @pytest.mark.asyncio
+    async def test_history_and_revert(self, storage, sample_memory_data):
+        """Test each update keeps the previous revision."""
+        memory_id = await storage.store_memory(**sample_memory_data)
+        tenant_id = sample_memory_data["tenant_id"]
+        await storage.update_memory(memory_id, tenant_id, {"content": "Second"})
+        await storage.update_memory(
+            memory_id, tenant_id, {"content": "Third", "tags": ["new"]}
+        )
+
+        history = await storage.get_memory_history(memory_id, tenant_id)
+        assert [r["version"] for r in history] == [1, 2, 3]
+        assert history[0]["content"] == sample_memory_data["content"]
+        assert history[0]["tags"] == sample_memory_data["tags"]
+        assert history[-1]["content"] == "Third"
+
+        assert await storage.revert_to_version(memory_id, tenant_id, 1) is True
+        memory = await storage.get_memory(memory_id, tenant_id)
+        assert memory["content"] == sample_memory_data["content"]
+        assert memory["version"] == 4
+        assert await storage.revert_to_version(memory_id, tenant_id, 42) is False
+
+    @pytest.mark.asyncio
+    async def test_history_limit_and_cleanup(self, tmp_path, sample_memory_data):
+        """Test retention limit and removal of history on delete."""
+        storage = SQLiteStorage(str(tmp_path / "versions.db"), history_limit=2)
+        memory_id = await storage.store_memory(**sample_memory_data)
+        tenant_id = sample_memory_data["tenant_id"]
+        for i in range(4):
+            await storage.update_memory(memory_id, tenant_id, {"content": f"v{i}"})
+
+        history = await storage.get_memory_history(memory_id, tenant_id)
+        assert [r["version"] for r in history] == [3, 4, 5]
+
+        await storage.delete_memory(memory_id, tenant_id)
+        assert await storage.get_memory_history(memory_id, tenant_id) == []
+
+    @pytest.mark.asyncio
+    async def test_change_log(self, storage, sample_memory_data):
+        """Test field-level changes are recorded with their author."""
+        memory_id = await storage.store_memory(**sample_memory_data)
+        tenant_id = sample_memory_data["tenant_id"]
+        await storage.update_memory(
+            memory_id, tenant_id, {"importance": 0.2}, changed_by="alice"
+        )
+        await storage.update_memory(memory_id, tenant_id, {"importance": 0.2})
+
+        log = await storage.get_change_log(memory_id, tenant_id)
+        assert len(log) == 1
+        assert log[0]["version"] == 2
+        assert log[0]["changed_by"] == "alice"
+        assert log[0]["changes"] == [
+            {"field": "importance", "old_value": 0.8, "new_value": 0.2}
+        ]
+
+    @pytest.mark.asyncio
+    async def test_update_expected_version(self, storage, sample_memory_data):
+        """Test concurrent updates based on the same version: one wins."""
+        memory_id = await storage.store_memory(**sample_memory_data)
+        tenant_id = sample_memory_data["tenant_id"]
+
+        results = await asyncio.gather(
+            *(
+                storage.update_memory(
+                    memory_id, tenant_id, {"content": f"edit {i}"}, expected_version=1
+                )
+                for i in range(2)
+            ),
+            return_exceptions=True,
+        )
+
+        assert results.count(True) == 1
+        conflict = next(r for r in results if r is not True)
+        assert isinstance(conflict, VersionConflictError)
+        assert conflict.actual_version == 2
+        memory = await storage.get_memory(memory_id, tenant_id)
+        assert memory["version"] == 2
+        assert len(await storage.get_change_log(memory_id, tenant_id)) == 1
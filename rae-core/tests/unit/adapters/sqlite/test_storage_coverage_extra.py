@@ -7,6 +7,7 @@ import pytest
 import aiosqlite
 
 from rae_core.adapters.sqlite.storage import SQLiteStorage
+from rae_core.exceptions.base import NotFoundError
 
 @pytest.fixture
 def db_path(tmp_path):
@@ -100,7 +101,7 @@ class TestSQLiteStorageCoverage:
         storage = SQLiteStorage(db_path)
         m_id = await storage.store_memory(content="m", layer="w", tenant_id="t1", agent_id="a1")
         
-        with pytest.raises(ValueError, match="Access Denied"):
+        with pytest.raises(NotFoundError, match="not found for tenant"):
             await storage.save_embedding(m_id, "model", [0.1], "wrong_tenant")
 
     @pytest.mark.asyncio
@@ -10,6 +10,8 @@ from uuid import UUID, uuid4
 import pytest
 
 from rae_core.adapters.sqlite.vector import SQLiteVectorStore
+from rae_core.math.vector_quantization import ProductQuantizer
+from rae_core.models.quantization import QuantizationConfig, QuantizationMode
 
 
 @pytest.fixture
@@ -373,6 +375,24 @@ class TestSQLiteVectorStoreSimilaritySearch:
         assert id1 in result_ids
         assert id3 in result_ids
 
+    @pytest.mark.asyncio
+    async def test_search_similar_with_session_filter(self, vector_store):
+        """Test filtering by session."""
+        in_session, other = uuid4(), uuid4()
+        for memory_id, session_id in ((in_session, "s1"), (other, "s2")):
+            await vector_store.store_vector(
+                memory_id=memory_id,
+                embedding=[1.0, 0.0, 0.0],
+                tenant_id="tenant-1",
+                metadata={"session_id": session_id},
+            )
+
+        results = await vector_store.search_similar(
+            query_embedding=[1.0, 0.0, 0.0], tenant_id="tenant-1", session_id="s1"
+        )
+
+        assert [r[0] for r in results] == [in_session]
+
     @pytest.mark.asyncio
     async def test_search_similar_tenant_isolation(self, vector_store):
         """Test tenant isolation in search."""
@@ -479,6 +499,64 @@ class TestSQLiteVectorStoreBatchOperations:
         assert count == 2
 
 
+class TestSQLiteVectorStoreNamedVectors:
+    """Test per-model (named) vectors."""
+
+    @pytest.mark.asyncio
+    async def test_named_vectors_stored_per_model(self, vector_store):
+        """Test each model's vector is retrievable by name."""
+        memory_id = uuid4()
+        await vector_store.store_vector(
+            memory_id, {"small": [1.0, 0.0], "large": [0.0, 1.0, 0.0]}, "tenant-1"
+        )
+
+        assert await vector_store.get_vector(
+            memory_id, "tenant-1", vector_name="large"
+        ) == [0.0, 1.0, 0.0]
+        assert await vector_store.get_vector(memory_id, "tenant-1") == [1.0, 0.0]
+        assert (
+            await vector_store.get_vector(memory_id, "tenant-1", vector_name="other")
+            is None
+        )
+
+    @pytest.mark.asyncio
+    async def test_backfill_keeps_default_vector(self, vector_store):
+        """Test adding another model's vector does not replace the default."""
+        memory_id = uuid4()
+        await vector_store.store_vector(memory_id, [1.0, 0.0], "tenant-1")
+        await vector_store.store_vector(memory_id, {"new": [0.0, 0.0, 1.0]}, "tenant-1")
+
+        assert await vector_store.get_vector(memory_id, "tenant-1") == [1.0, 0.0]
+        assert await vector_store.get_vector(
+            memory_id, "tenant-1", vector_name="new"
+        ) == [0.0, 0.0, 1.0]
+
+    @pytest.mark.asyncio
+    async def test_search_selects_model(self, vector_store):
+        """Test search_similar only compares vectors of the chosen model."""
+        id1, id2 = uuid4(), uuid4()
+        await vector_store.store_vector(id1, {"m1": [1.0, 0.0], "m2": [0, 1.0, 0]}, "t")
+        await vector_store.store_vector(id2, {"m1": [0.0, 1.0], "m2": [1.0, 0, 0]}, "t")
+
+        results = await vector_store.search_similar(
+            [1.0, 0.0, 0.0], "t", vector_name="m2"
+        )
+
+        assert results[0][0] == id2
+
+    @pytest.mark.asyncio
+    async def test_delete_removes_named_vectors(self, vector_store):
+        """Test delete_vector drops every model's vector."""
+        memory_id = uuid4()
+        await vector_store.store_vector(memory_id, {"m1": [1.0, 0.0]}, "tenant-1")
+
+        assert await vector_store.delete_vector(memory_id, "tenant-1")
+        assert (
+            await vector_store.get_vector(memory_id, "tenant-1", vector_name="m1")
+            is None
+        )
+
+
 class TestSQLiteVectorStoreTenantOperations:
     """Test tenant-level operations."""
 
@@ -664,3 +742,97 @@ class TestSQLiteVectorStoreEdgeCases:
         assert len(results) == 1
         # Cosine similarity should be approximately 0.5
         assert abs(results[0][1] - 0.5) < 0.01
+
+
+class TestSQLiteVectorStoreQuantization:
+    """Test searches over quantized codes."""
+
+    @pytest.mark.asyncio
+    async def test_scalar_codes_rescored(self, tmp_path):
+        """Test scalar codes rank and full vectors give exact scores."""
+        store = SQLiteVectorStore(
+            str(tmp_path / "q.db"), quantization=QuantizationConfig()
+        )
+        ids = {}
+        for name, vector in {
+            "x": [1.0, 0.0, 0.0],
+            "xy": [0.7071, 0.7071, 0.0],
+            "z": [0.0, 0.0, 1.0],
+        }.items():
+            ids[name] = uuid4()
+            await store.store_vector(ids[name], vector, "t1")
+
+        results = await store.search_similar(
+            [1.0, 0.0, 0.0], "t1", limit=2, score_threshold=0.5
+        )
+
+        assert [memory_id for memory_id, _ in results] == [ids["x"], ids["xy"]]
+        assert results[0][1] == pytest.approx(1.0, abs=1e-6)
+        assert results[1][1] == pytest.approx(0.7071, abs=1e-4)
+        # Full vectors are kept
+        assert await store.get_vector(ids["xy"], "t1") == pytest.approx(
+            [0.7071, 0.7071, 0.0], abs=1e-6
+        )
+
+    @pytest.mark.asyncio
+    async def test_product_codebook_trained_and_persisted(self, tmp_path):
+        """Test codebooks are trained at train_size and reloaded."""
+        db_path = str(tmp_path / "pq.db")
+        config = QuantizationConfig(
+            mode=QuantizationMode.PRODUCT,
+            subvectors=2,
+            centroids=4,
+            train_size=10,
+            train_iterations=3,
+        )
+        store = SQLiteVectorStore(db_path, quantization={"clip": config})
+        vectors = [
+            (uuid4(), {"clip": [float(i % 3), float(i % 5), 1.0, 0.5]}, {})
+            for i in range(12)
+        ]
+        assert await store.batch_store_vectors(vectors, "t1") == 12
+
+        reopened = SQLiteVectorStore(db_path, quantization={"clip": config})
+        await reopened.initialize()
+
+        codec = reopened._codecs["clip"]
+        assert isinstance(codec, ProductQuantizer) and codec.trained
+        memory_id, embedding, _ = vectors[4]
+        results = await reopened.search_similar(
+            embedding["clip"], "t1", limit=3, vector_name="clip"
+        )
+        assert memory_id in [found for found, _ in results]
+        assert results[0][1] == pytest.approx(1.0, abs=1e-6)
+
+
+class TestSQLiteVectorStoreDistanceMetric:
+    """Test per-collection distance metrics."""
+
+    @pytest.mark.asyncio
+    async def test_dot_and_euclid_collections(self, tmp_path):
+        """Test vector lengths count for dot product and euclidean scores."""
+        store = SQLiteVectorStore(
+            str(tmp_path / "m.db"), distance_metric={"default": "dot", "clip": "l2"}
+        )
+        short, long = uuid4(), uuid4()
+        for memory_id, x in ((short, 1.0), (long, 3.0)):
+            await store.store_vector(memory_id, [x, 0.0], "t1")
+            await store.store_vector(memory_id, {"clip": [x, 0.0]}, "t1")
+
+        dot = await store.search_similar([1.0, 0.0], "t1")
+        euclid = await store.search_similar([1.0, 0.0], "t1", vector_name="clip")
+
+        # Cosine would tie the two at 1.0
+        assert dot == [(long, pytest.approx(3.0)), (short, pytest.approx(1.0))]
+        assert euclid == [(short, pytest.approx(1.0)), (long, pytest.approx(1 / 3))]
+
+    @pytest.mark.asyncio
+    async def test_zero_query_scores_by_distance(self, tmp_path):
+        """Test a zero query still has euclidean distances to score."""
+        store = SQLiteVectorStore(str(tmp_path / "m.db"), distance_metric="euclid")
+        memory_id = uuid4()
+        await store.store_vector(memory_id, [0.0, 3.0, 4.0], "t1")
+
+        results = await store.search_similar([0.0, 0.0, 0.0], "t1")
+
+        assert results == [(memory_id, pytest.approx(1 / 6))]
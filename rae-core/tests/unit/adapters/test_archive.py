@@ -0,0 +1,134 @@
+"""Unit tests for the archive tier."""
+
+import io
+from datetime import datetime, timezone
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.archive import (
+    ARCHIVED_KEY,
+    REHYDRATION_MS_KEY,
+    ArchiveTieredStorage,
+    FilesystemArchiveStorage,
+    S3ArchiveStorage,
+    decode_record,
+    encode_record,
+)
+from rae_core.adapters.memory.storage import InMemoryStorage
+
+
+class MissingKeyError(Exception):
+    def __init__(self):
+        self.response = {"Error": {"Code": "NoSuchKey"}}
+
+
+class FakeS3Client:
+    """In-memory stand-in for a boto3 S3 client."""
+
+    def __init__(self):
+        self.objects = {}
+
+    def put_object(self, Bucket, Key, Body, **kwargs):
+        self.objects[(Bucket, Key)] = Body
+
+    def get_object(self, Bucket, Key):
+        if (Bucket, Key) not in self.objects:
+            raise MissingKeyError()
+        return {"Body": io.BytesIO(self.objects[(Bucket, Key)])}
+
+    def head_object(self, Bucket, Key):
+        self.get_object(Bucket, Key)
+
+    def delete_object(self, Bucket, Key):
+        del self.objects[(Bucket, Key)]
+
+    def get_paginator(self, name):
+        client = self
+
+        class Paginator:
+            def paginate(self, Bucket, Prefix):
+                keys = [k for b, k in client.objects if k.startswith(Prefix)]
+                yield {"Contents": [{"Key": k} for k in keys]}
+
+        return Paginator()
+
+
+def _record(**fields):
+    return {
+        "id": uuid4(),
+        "content": "old note",
+        "layer": "episodic",
+        "metadata": {"source": "chat"},
+        "created_at": datetime(2025, 1, 1, tzinfo=timezone.utc),
+        **fields,
+    }
+
+
+def test_record_round_trip():
+    """Test records keep their id and timestamps through encoding."""
+    record = _record()
+    assert decode_record(encode_record(record)) == record
+
+
+@pytest.mark.parametrize("backend", ["filesystem", "s3"])
+@pytest.mark.asyncio
+async def test_archive_backends(backend, tmp_path):
+    """Test put, get, list and delete on both archive backends."""
+    if backend == "filesystem":
+        archive = FilesystemArchiveStorage(tmp_path)
+    else:
+        archive = S3ArchiveStorage("bucket", client=FakeS3Client())
+    record = _record()
+
+    await archive.put(record, "acme/eu")
+
+    assert await archive.get(record["id"], "acme/eu") == record
+    assert await archive.get(record["id"], "other") is None
+    assert await archive.list_ids("acme/eu") == [record["id"]]
+    assert await archive.list_ids("other") == []
+    assert await archive.delete(record["id"], "acme/eu") is True
+    assert await archive.delete(record["id"], "acme/eu") is False
+    assert await archive.get(record["id"], "acme/eu") is None
+
+
+class TestArchiveTieredStorage:
+    """Test suite for ArchiveTieredStorage."""
+
+    @pytest.mark.asyncio
+    async def test_archived_memories_are_rehydrated(self, tmp_path):
+        """Test archiving drops the hot copy and get_memory still finds it."""
+        hot = InMemoryStorage()
+        storage = ArchiveTieredStorage(
+            hot, FilesystemArchiveStorage(tmp_path), vector_store=hot
+        )
+        memory_id = await storage.store_memory(
+            content="old note", tenant_id="t1", metadata={"source": "chat"}
+        )
+        await hot.store_vector(memory_id, [0.1, 0.2], "t1")
+
+        assert await storage.archive_memory(memory_id, "t1") is True
+
+        assert await hot.get_memory(memory_id, "t1") is None
+        assert await hot.get_vector(memory_id, "t1") is None
+        memory = await storage.get_memory(memory_id, "t1")
+        assert memory["content"] == "old note"
+        assert memory["metadata"]["source"] == "chat"
+        assert memory["metadata"][ARCHIVED_KEY] is True
+        assert memory["metadata"][REHYDRATION_MS_KEY] >= 0
+        assert await storage.archive_memory(memory_id, "t1") is False
+
+        assert await storage.delete_memory(memory_id, "t1") is True
+        assert await storage.get_memory(memory_id, "t1") is None
+
+    @pytest.mark.asyncio
+    async def test_hot_memories_are_returned_unflagged(self, tmp_path):
+        """Test memories still in hot storage are read from it."""
+        storage = ArchiveTieredStorage(
+            InMemoryStorage(), FilesystemArchiveStorage(tmp_path)
+        )
+        memory_id = await storage.store_memory(content="fresh", tenant_id="t1")
+
+        memory = await storage.get_memory(memory_id, "t1")
+
+        assert ARCHIVED_KEY not in (memory.get("metadata") or {})
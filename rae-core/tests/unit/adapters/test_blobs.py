@@ -0,0 +1,164 @@
+"""Unit tests for blob stores and memory attachments."""
+
+import io
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.adapters.blobs import (
+    ATTACHMENTS_KEY,
+    AttachmentStorage,
+    FilesystemBlobStore,
+    S3BlobStore,
+    attachments_of,
+    blob_digest,
+)
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import ValidationError
+from rae_core.models.memory import Attachment
+
+
+class MissingKeyError(Exception):
+    def __init__(self):
+        self.response = {"Error": {"Code": "404"}}
+
+
+class FakeS3Client:
+    """In-memory stand-in for a boto3 S3 client."""
+
+    def __init__(self):
+        self.objects = {}
+
+    def put_object(self, Bucket, Key, Body, **kwargs):
+        self.objects[(Bucket, Key)] = Body
+
+    def get_object(self, Bucket, Key):
+        if (Bucket, Key) not in self.objects:
+            raise MissingKeyError()
+        return {"Body": io.BytesIO(self.objects[(Bucket, Key)])}
+
+    def head_object(self, Bucket, Key):
+        self.get_object(Bucket, Key)
+
+    def delete_object(self, Bucket, Key):
+        del self.objects[(Bucket, Key)]
+
+    def get_paginator(self, name):
+        client = self
+
+        class Paginator:
+            def paginate(self, Bucket, Prefix):
+                keys = [k for b, k in client.objects if k.startswith(Prefix)]
+                yield {"Contents": [{"Key": k} for k in keys]}
+
+        return Paginator()
+
+
+class TestBlobStores:
+    """Test suite for the filesystem and S3 blob stores."""
+
+    async def _round_trip(self, blobs):
+        digest = await blobs.put(b"%PDF-1.7", "acme/eu")
+
+        assert digest == blob_digest(b"%PDF-1.7")
+        assert await blobs.put(b"%PDF-1.7", "acme/eu") == digest
+        assert await blobs.get(digest, "acme/eu") == b"%PDF-1.7"
+        assert await blobs.exists(digest, "acme/eu") is True
+        assert await blobs.get(digest, "other") is None
+        assert await blobs.list_digests("acme/eu") == [digest]
+        assert await blobs.delete(digest, "acme/eu") is True
+        assert await blobs.delete(digest, "acme/eu") is False
+        assert await blobs.exists(digest, "acme/eu") is False
+
+    @pytest.mark.asyncio
+    async def test_filesystem_round_trip(self, tmp_path):
+        """Test put, get, list and delete on the filesystem store."""
+        await self._round_trip(FilesystemBlobStore(tmp_path))
+
+    @pytest.mark.asyncio
+    async def test_s3_round_trip(self):
+        """Test put, get, list and delete on the S3 store."""
+        client = FakeS3Client()
+
+        await self._round_trip(S3BlobStore("bucket", client=client))
+
+        assert client.objects == {}
+
+    @pytest.mark.asyncio
+    async def test_invalid_digest_is_rejected(self, tmp_path):
+        """Test digests cannot escape the store's directory."""
+        with pytest.raises(ValidationError):
+            await FilesystemBlobStore(tmp_path).get("../secret", "t1")
+
+
+class TestAttachmentStorage:
+    """Test suite for AttachmentStorage."""
+
+    @pytest.fixture
+    def storage(self, tmp_path):
+        return AttachmentStorage(InMemoryStorage(), FilesystemBlobStore(tmp_path))
+
+    @pytest.mark.asyncio
+    async def test_attach_and_read(self, storage):
+        """Test attached bytes are recorded on the memory and readable."""
+        memory_id = await storage.store_memory(content="scan", tenant_id="t1")
+
+        attachment = await storage.attach(
+            memory_id, "t1", b"\x89PNG", media_type="image/png", name="scan.png"
+        )
+
+        memory = await storage.get_memory(memory_id, "t1")
+        assert attachments_of(memory) == [attachment]
+        assert attachment.size == 4
+        assert await storage.read_attachment(attachment, "t1") == b"\x89PNG"
+
+    @pytest.mark.asyncio
+    async def test_store_requires_stored_blobs(self, storage):
+        """Test attachments must reference blobs already stored."""
+        missing = Attachment(digest=blob_digest(b"nope"), size=4)
+
+        with pytest.raises(ValidationError):
+            await storage.store_memory(
+                content="doc", tenant_id="t1", attachments=[missing]
+            )
+
+        digest = await storage.blobs.put(b"doc", "t1")
+        memory_id = await storage.store_memory(
+            content="doc",
+            tenant_id="t1",
+            metadata={"source": "upload"},
+            attachments=[{"digest": digest, "size": 3}],
+        )
+        metadata = (await storage.get_memory(memory_id, "t1"))["metadata"]
+        assert metadata["source"] == "upload"
+        assert metadata[ATTACHMENTS_KEY][0]["digest"] == digest
+
+    @pytest.mark.asyncio
+    async def test_deleting_last_reference_removes_blob(self, storage):
+        """Test shared blobs live until their last memory is deleted."""
+        first = await storage.store_memory(content="a", tenant_id="t1")
+        second = await storage.store_memory(content="b", tenant_id="t1")
+        shared = await storage.attach(first, "t1", b"shared")
+        await storage.attach(second, "t1", b"shared")
+
+        await storage.delete_memory(first, "t1")
+        assert await storage.blobs.exists(shared.digest, "t1") is True
+
+        await storage.soft_delete_memory(second, "t1")
+        assert await storage.blobs.exists(shared.digest, "t1") is True
+
+        purged = await storage.purge_deleted(
+            datetime.now(timezone.utc) + timedelta(days=1), tenant_id="t1"
+        )
+        assert purged == 1
+        assert await storage.blobs.exists(shared.digest, "t1") is False
+
+    @pytest.mark.asyncio
+    async def test_clear_tenant_removes_blobs(self, storage):
+        """Test clearing a tenant drops its blobs."""
+        memory_id = await storage.store_memory(content="a", tenant_id="t1")
+        await storage.attach(memory_id, "t1", b"data")
+
+        await storage.clear_tenant("t1")
+
+        assert await storage.blobs.list_digests("t1") == []
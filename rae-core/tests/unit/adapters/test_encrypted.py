@@ -0,0 +1,119 @@
+"""Unit tests for encryption at rest."""
+
+import pytest
+
+from rae_core.adapters.encrypted import (
+    ENCRYPTED_METADATA_KEY,
+    EncryptedStorage,
+    LocalKeyProvider,
+    is_sealed,
+)
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import StorageError
+from rae_core.interfaces.keys import IKeyProvider
+
+
+class TestEncryptedStorage:
+    """Test suite for EncryptedStorage."""
+
+    @pytest.fixture
+    def keys(self):
+        return LocalKeyProvider({"k1": b"m" * 32})
+
+    @pytest.fixture
+    def backend(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def storage(self, backend, keys):
+        return EncryptedStorage(backend, keys, cleartext_metadata=["source"])
+
+    async def store(self, storage, tenant_id="t1", **kwargs):
+        return await storage.store_memory(
+            content=kwargs.pop("content", "alice's phone is 555-0100"),
+            layer="episodic",
+            tenant_id=tenant_id,
+            agent_id="a1",
+            tags=["contact"],
+            metadata={"source": "chat", "email": "alice@example.com"},
+            **kwargs,
+        )
+
+    def test_local_provider_is_key_provider(self, keys):
+        """Test LocalKeyProvider satisfies IKeyProvider."""
+        assert isinstance(keys, IKeyProvider)
+
+    @pytest.mark.asyncio
+    async def test_round_trip_keeps_backend_ciphertext(self, storage, backend):
+        """Test reads decrypt while the backend only holds ciphertext."""
+        memory_id = await self.store(storage)
+
+        raw = await backend.get_memory(memory_id, "t1")
+        assert is_sealed(raw["content"])
+        assert "555-0100" not in raw["content"]
+        assert raw["metadata"]["source"] == "chat"
+        assert "email" not in raw["metadata"]
+        assert is_sealed(raw["metadata"][ENCRYPTED_METADATA_KEY])
+        assert raw["tags"] == ["contact"]
+
+        memory = await storage.get_memory(memory_id, "t1")
+        assert memory["content"] == "alice's phone is 555-0100"
+        assert memory["metadata"] == {"source": "chat", "email": "alice@example.com"}
+
+    @pytest.mark.asyncio
+    async def test_cleartext_fields_still_filter(self, storage):
+        """Test tag and cleartext metadata filters match sealed memories."""
+        memory_id = await self.store(storage)
+        await storage.store_memory(
+            content="unrelated", layer="episodic", tenant_id="t1", agent_id="a1"
+        )
+
+        by_tag = await storage.list_memories("t1", tags=["contact"])
+        assert [m["id"] for m in by_tag] == [memory_id]
+        assert by_tag[0]["content"] == "alice's phone is 555-0100"
+        by_source = await storage.list_memories("t1", filters={"source": "chat"})
+        assert [m["id"] for m in by_source] == [memory_id]
+
+    @pytest.mark.asyncio
+    async def test_ciphertext_bound_to_tenant(self, storage, backend):
+        """Test a sealed value copied to another tenant does not decrypt."""
+        memory_id = await self.store(storage)
+        raw = await backend.get_memory(memory_id, "t1")
+        copied = await backend.store_memory(
+            content=raw["content"], layer="episodic", tenant_id="t2", agent_id="a1"
+        )
+
+        with pytest.raises(StorageError):
+            await storage.get_memory(copied, "t2")
+
+    @pytest.mark.asyncio
+    async def test_key_rotation(self, storage, backend, keys):
+        """Test old data stays readable after rotating to a new key."""
+        old_id = await self.store(storage)
+        keys.add_key("k2", b"n" * 32)
+        new_id = await self.store(storage, content="bob's phone is 555-0199")
+
+        assert ":k2:" in (await backend.get_memory(new_id, "t1"))["content"]
+        assert (await storage.get_memory(old_id, "t1"))["content"] == (
+            "alice's phone is 555-0100"
+        )
+        assert (await storage.get_memory(new_id, "t1"))["content"] == (
+            "bob's phone is 555-0199"
+        )
+
+    @pytest.mark.asyncio
+    async def test_update_and_change_log(self, storage, backend):
+        """Test updates are sealed and the change log reads back decrypted."""
+        memory_id = await self.store(storage)
+        await storage.update_memory(
+            memory_id, "t1", {"content": "alice moved"}, changed_by="test"
+        )
+
+        assert is_sealed((await backend.get_memory(memory_id, "t1"))["content"])
+        assert (await storage.get_memory(memory_id, "t1"))["content"] == (
+            "alice moved"
+        )
+        log = await storage.get_change_log(memory_id, "t1")
+        change = log[-1]["changes"][0]
+        assert change["field"] == "content"
+        assert change["new_value"] == "alice moved"
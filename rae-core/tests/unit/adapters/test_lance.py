@@ -0,0 +1,154 @@
+"""Tests of the LanceDB vector store on a temporary directory."""
+
+from uuid import uuid4
+
+import pytest
+
+pytest.importorskip("lancedb")
+pytest.importorskip("pyarrow")
+
+from rae_core.adapters.lance import LanceDBVectorStore, LanceIndex  # noqa: E402
+from rae_core.exceptions.base import ValidationError  # noqa: E402
+from rae_core.testing import VectorConformance  # noqa: E402
+from rae_core.testing.conformance import DIMENSION  # noqa: E402
+
+
+class TestLanceDBVectorConformance(VectorConformance):
+    """LanceDBVectorStore on a directory per example."""
+
+    examples = 5
+
+    @pytest.fixture(autouse=True)
+    def _db_dir(self, tmp_path):
+        self.db_dir = tmp_path
+
+    async def make_vector_store(self):
+        store = LanceDBVectorStore(
+            str(self.db_dir / str(uuid4())), embedding_dim=DIMENSION
+        )
+        await store.initialize()
+        return store
+
+
+@pytest.fixture
+def store(tmp_path):
+    return LanceDBVectorStore(str(tmp_path / "vectors"), embedding_dim=3)
+
+
+class TestLanceDBVectorStore:
+    @pytest.mark.asyncio
+    async def test_vectors_survive_reopen(self, tmp_path):
+        """Test vectors are read back by a new store on the same directory."""
+        path = str(tmp_path / "vectors")
+        memory_id = uuid4()
+        store = LanceDBVectorStore(path, embedding_dim=3)
+        await store.store_vector(memory_id, [1.0, 0.0, 0.0], "t1", {"layer": "x"})
+        await store.close()
+
+        reopened = LanceDBVectorStore(path, embedding_dim=3)
+        assert await reopened.get_vector(memory_id, "t1") == [1.0, 0.0, 0.0]
+        [(found, score)] = await reopened.search_similar([1.0, 0.0, 0.0], "t1")
+        assert found == memory_id
+        assert score == pytest.approx(1.0)
+
+    @pytest.mark.asyncio
+    async def test_store_overwrites_vector(self, store):
+        """Test storing a memory's vector again replaces it."""
+        memory_id = uuid4()
+        await store.store_vector(memory_id, [1.0, 0.0, 0.0], "t1")
+        assert await store.update_vector(memory_id, [0.0, 1.0, 0.0], "t1")
+
+        assert await store.get_vector(memory_id, "t1") == [0.0, 1.0, 0.0]
+        assert not await store.update_vector(uuid4(), [0.0, 1.0, 0.0], "t1")
+
+    @pytest.mark.asyncio
+    async def test_rejects_wrong_dimension(self, store):
+        """Test default vectors must match the table dimension."""
+        with pytest.raises(ValidationError):
+            await store.store_vector(uuid4(), [1.0, 0.0], "t1")
+
+    @pytest.mark.asyncio
+    async def test_named_vectors(self, store):
+        """Test each model's vectors get a table of their own dimension."""
+        memory_id = uuid4()
+        await store.store_vector(
+            memory_id, {"dense": [1.0, 0.0, 0.0], "clip": [0.6, 0.8]}, "t1"
+        )
+
+        assert await store.get_vector(memory_id, "t1", vector_name="clip") == [
+            pytest.approx(0.6),
+            pytest.approx(0.8),
+        ]
+        hits = await store.search_similar([0.6, 0.8], "t1", vector_name="clip")
+        assert [found for found, _ in hits] == [memory_id]
+        assert await store.search_similar([1.0, 0.0], "t1", vector_name="none") == []
+
+        assert await store.delete_vector(memory_id, "t1")
+        assert await store.get_vector(memory_id, "t1", vector_name="clip") is None
+
+    @pytest.mark.asyncio
+    async def test_metadata_filters(self, store):
+        """Test generic filters match the stored metadata."""
+        kept, other = uuid4(), uuid4()
+        await store.store_vector(kept, [1.0, 0.0, 0.0], "t1", {"topic": "a"})
+        await store.store_vector(other, [1.0, 0.1, 0.0], "t1", {"topic": "b"})
+
+        hits = await store.search_similar([1.0, 0.0, 0.0], "t1", filters={"topic": "a"})
+
+        assert [found for found, _ in hits] == [kept]
+
+    @pytest.mark.asyncio
+    async def test_distance_metrics(self, tmp_path):
+        """Test euclidean and dot product tables score by their metric."""
+        store = LanceDBVectorStore(
+            str(tmp_path / "vectors"),
+            embedding_dim=3,
+            distance_metric={"default": "euclid", "clip": "dot"},
+        )
+        near, long = uuid4(), uuid4()
+        for memory_id, x in ((near, 1.0), (long, 3.0)):
+            await store.store_vector(
+                memory_id, {"dense": [x, 0.0, 0.0], "clip": [x, 0.0]}, "t1"
+            )
+
+        euclid = await store.search_similar([1.0, 0.0, 0.0], "t1")
+        dot = await store.search_similar([1.0, 0.0], "t1", vector_name="clip")
+
+        assert euclid == [
+            (near, pytest.approx(1.0)),
+            (long, pytest.approx(1.0 / 3.0)),
+        ]
+        assert dot == [(long, pytest.approx(3.0)), (near, pytest.approx(1.0))]
+
+    @pytest.mark.asyncio
+    async def test_index_trained_at_threshold(self, tmp_path):
+        """Test the ANN index is built once the table is large enough."""
+        store = LanceDBVectorStore(
+            str(tmp_path / "vectors"),
+            embedding_dim=16,
+            index_type=LanceIndex.IVF_PQ,
+            index_min_rows=300,
+        )
+        batch = [
+            (uuid4(), [float((i * j) % 7 + 1) for j in range(16)], {})
+            for i in range(299)
+        ]
+        assert await store.batch_store_vectors(batch, "t1") == 299
+        assert store.table_name not in store._indexed
+
+        await store.store_vector(uuid4(), [1.0] * 16, "t1")
+
+        assert store.table_name in store._indexed
+        hits = await store.search_similar(batch[5][1], "t1", limit=3)
+        assert len(hits) == 3
+
+    @pytest.mark.asyncio
+    async def test_clear_tenant(self, store):
+        """Test clearing a tenant keeps other tenants' vectors."""
+        await store.store_vector(uuid4(), [1.0, 0.0, 0.0], "t1")
+        kept = uuid4()
+        await store.store_vector(kept, [1.0, 0.0, 0.0], "t2")
+
+        assert await store.clear_tenant("t1") == 1
+        assert await store.search_similar([1.0, 0.0, 0.0], "t1") == []
+        assert await store.get_vector(kept, "t2") == [1.0, 0.0, 0.0]
@@ -0,0 +1,130 @@
+"""Unit tests for overlay storage composition."""
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.adapters.overlay import OverlayStorage, OverlayVectorStore
+from rae_core.adapters.pack import KnowledgePackStore, build_knowledge_pack
+from rae_core.exceptions.base import VersionConflictError
+
+
+class TestOverlayStorage:
+    """Test suite for OverlayStorage over a knowledge pack."""
+
+    @pytest.fixture
+    async def stack(self, tmp_path):
+        source = InMemoryStorage()
+        ids = {}
+        for name, vector in (("paris", [1.0, 0.0]), ("berlin", [0.0, 1.0])):
+            ids[name] = await source.store_memory(
+                content=f"{name} is a capital",
+                layer="semantic",
+                tenant_id="t1",
+                agent_id="a1",
+                tags=["city"],
+            )
+            await source.store_vector(ids[name], vector, "t1")
+        path = tmp_path / "kb.pack"
+        await build_knowledge_pack(path, source, "t1", vector_store=source)
+        pack = KnowledgePackStore(path)
+        upper = InMemoryStorage()
+        yield OverlayStorage(upper, [pack]), upper, pack, ids
+        await pack.close()
+
+    @pytest.mark.asyncio
+    async def test_reads_resolve_through_layers(self, stack):
+        """Test memories of the upper and lower stores are both visible."""
+        overlay, upper, _, ids = stack
+        live = await overlay.store_memory(
+            content="lunch at noon", layer="semantic", tenant_id="t1", agent_id="a1"
+        )
+        assert await upper.get_memory(live, "t1") is not None
+        assert (await overlay.get_memory(ids["paris"], "t1"))["content"] == (
+            "paris is a capital"
+        )
+        listed = await overlay.list_memories("t1", layer="semantic")
+        assert [m["id"] for m in listed][0] == live
+        assert {m["id"] for m in listed} == {live, ids["paris"], ids["berlin"]}
+        assert await overlay.count_memories("t1") == 3
+
+    @pytest.mark.asyncio
+    async def test_update_copies_up(self, stack):
+        """Test changing a lower memory copies it into the upper store."""
+        overlay, upper, pack, ids = stack
+        assert await overlay.update_memory(
+            ids["paris"], "t1", {"content": "Paris is the capital of France"}
+        )
+        memory = await overlay.get_memory(ids["paris"], "t1")
+        assert memory["id"] == ids["paris"]
+        assert memory["content"] == "Paris is the capital of France"
+        assert memory["tags"] == ["city"]
+        assert (await pack.get_memory(ids["paris"], "t1"))["content"] == (
+            "paris is a capital"
+        )
+        assert await upper.count_memories("t1") == 1
+        assert await overlay.count_memories("t1") == 2
+
+        listed = await overlay.list_memories("t1")
+        assert sorted(m["content"] for m in listed) == [
+            "Paris is the capital of France",
+            "berlin is a capital",
+        ]
+        found = await overlay.search_memories("capital", "t1", "a1")
+        assert sorted(str(r["id"]) for r in found) == sorted(
+            str(i) for i in ids.values()
+        )
+
+    @pytest.mark.asyncio
+    async def test_update_expected_version(self, stack):
+        """Test versions are checked against the lower memory, then the copy."""
+        overlay, upper, _, ids = stack
+        version = (await overlay.get_memory(ids["paris"], "t1"))["version"]
+
+        with pytest.raises(VersionConflictError):
+            await overlay.update_memory(
+                ids["paris"], "t1", {"content": "x"}, expected_version=version + 1
+            )
+        assert await upper.count_memories("t1") == 0
+
+        assert await overlay.update_memory(
+            ids["paris"], "t1", {"content": "Paris"}, expected_version=version
+        )
+        copy = await overlay.get_memory(ids["paris"], "t1")
+        with pytest.raises(VersionConflictError):
+            await overlay.update_memory(
+                ids["paris"], "t1", {"content": "x"}, expected_version=version
+            )
+        assert await overlay.update_memory(
+            ids["paris"], "t1", {"content": "Paris!"}, expected_version=copy["version"]
+        )
+
+    @pytest.mark.asyncio
+    async def test_delete_records_whiteout(self, stack):
+        """Test deleting a lower memory hides it, also for a new overlay."""
+        overlay, upper, pack, ids = stack
+        await overlay.update_memory(ids["berlin"], "t1", {"importance": 0.9})
+        assert await overlay.delete_memory(ids["paris"], "t1")
+        assert await overlay.delete_memory(ids["berlin"], "t1")
+        assert await overlay.delete_memory(ids["berlin"], "t1") is False
+
+        for view in (overlay, OverlayStorage(upper, [pack])):
+            assert await view.get_memory(ids["paris"], "t1") is None
+            assert await view.get_memory(ids["berlin"], "t1") is None
+            assert await view.list_memories("t1") == []
+            assert await view.search_memories("capital", "t1", "a1") == []
+            assert await view.count_memories("t1") == 0
+        assert await pack.count_memories("t1") == 2
+
+    @pytest.mark.asyncio
+    async def test_vector_overlay_hides_deleted(self, stack):
+        """Test vector search merges layers and skips whiteouts."""
+        overlay, upper, pack, ids = stack
+        vectors = OverlayVectorStore(upper, [pack], storage=overlay)
+        results = await vectors.search_similar([1.0, 0.2], "t1")
+        assert [memory_id for memory_id, _ in results] == [ids["paris"], ids["berlin"]]
+
+        await overlay.delete_memory(ids["paris"], "t1")
+        results = await vectors.search_similar([1.0, 0.2], "t1")
+        assert [memory_id for memory_id, _ in results] == [ids["berlin"]]
+        assert await vectors.get_vector(ids["paris"], "t1") is None
+        assert await vectors.get_vector(ids["berlin"], "t1") == [0.0, 1.0]
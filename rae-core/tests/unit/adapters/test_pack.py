@@ -0,0 +1,154 @@
+"""Unit tests for knowledge packs."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.adapters.pack import KnowledgePackStore, build_knowledge_pack
+from rae_core.exceptions.base import ReadOnlyStorageError, ValidationError
+
+
+class TestKnowledgePack:
+    """Test suite for build_knowledge_pack and KnowledgePackStore."""
+
+    @pytest.fixture
+    async def source(self):
+        storage = InMemoryStorage()
+        graph = InMemoryGraphStore()
+        ids = {}
+        for name, layer, vector in (
+            ("paris", "semantic", [1.0, 0.0, 0.0]),
+            ("berlin", "semantic", [0.0, 1.0, 0.0]),
+            ("lunch", "episodic", [1.0, 0.0, 0.0]),
+        ):
+            memory_id = await storage.store_memory(
+                content=f"{name} fact",
+                layer=layer,
+                tenant_id="t1",
+                agent_id="a1",
+                tags=[name],
+                metadata={"topic": name},
+            )
+            await storage.store_vector(memory_id, vector, "t1")
+            ids[name] = memory_id
+        for name in ("paris", "berlin"):
+            await graph.create_node(ids[name], "memory", "t1")
+        await graph.create_edge(ids["paris"], ids["berlin"], "near", "t1")
+        return storage, graph, ids
+
+    @pytest.fixture
+    async def pack(self, source, tmp_path):
+        storage, graph, _ = source
+        path = tmp_path / "kb.pack"
+        await build_knowledge_pack(
+            path, storage, "t1", vector_store=storage, graph_store=graph
+        )
+        store = KnowledgePackStore(path)
+        yield store
+        await store.close()
+
+    @pytest.mark.asyncio
+    async def test_manifest_counts(self, source, tmp_path):
+        """Test the manifest describes the packed semantic layer."""
+        storage, graph, _ = source
+        manifest = await build_knowledge_pack(
+            tmp_path / "kb.pack", storage, "t1", vector_store=storage, graph_store=graph
+        )
+        assert manifest["counts"] == {
+            "memories": 2,
+            "vectors": 2,
+            "nodes": 2,
+            "edges": 1,
+        }
+        assert manifest["dimension"] == 3
+        assert not (tmp_path / "kb.pack.tmp").exists()
+
+    @pytest.mark.asyncio
+    async def test_reads_packed_memories(self, pack, source):
+        """Test only semantic memories are packed and served."""
+        _, _, ids = source
+        memory = await pack.get_memory(ids["paris"], "t1")
+        assert memory["content"] == "paris fact"
+        assert memory["id"] == ids["paris"]
+        assert await pack.get_memory(ids["lunch"], "t1") is None
+        assert await pack.count_memories("t1") == 2
+
+        listed = await pack.list_memories("t1", tags=["berlin"])
+        assert [m["id"] for m in listed] == [ids["berlin"]]
+        listed = await pack.list_memories("t1", filters={"topic": "paris"})
+        assert [m["id"] for m in listed] == [ids["paris"]]
+
+        found = await pack.search_memories("berlin", "t1", "a1")
+        assert [r["id"] for r in found] == [ids["berlin"]]
+
+    @pytest.mark.asyncio
+    async def test_vector_search(self, pack, source):
+        """Test similarity search over the memory-mapped vectors."""
+        _, _, ids = source
+        results = await pack.search_similar([0.9, 0.1, 0.0], "t1")
+        assert [memory_id for memory_id, _ in results] == [ids["paris"], ids["berlin"]]
+        assert await pack.get_vector(ids["berlin"], "t1") == [0.0, 1.0, 0.0]
+        assert await pack.search_similar([1.0, 0.0, 0.0], "t1", vector_name="x") == []
+
+    @pytest.mark.asyncio
+    async def test_graph_reads(self, pack, source):
+        """Test the packed entity graph is traversable."""
+        _, _, ids = source
+        assert await pack.get_neighbors(ids["paris"], "t1") == [ids["berlin"]]
+        path = await pack.shortest_path(ids["berlin"], ids["paris"], "t1")
+        assert path == [ids["berlin"], ids["paris"]]
+
+    @pytest.mark.asyncio
+    async def test_writes_are_rejected(self, pack, source):
+        """Test writes raise while access tracking is ignored."""
+        _, _, ids = source
+        with pytest.raises(ReadOnlyStorageError):
+            await pack.store_memory(content="x", tenant_id="t1")
+        with pytest.raises(ReadOnlyStorageError):
+            await pack.delete_memory(ids["paris"], "t1")
+        with pytest.raises(ReadOnlyStorageError):
+            await pack.store_vector(ids["paris"], [1.0, 0.0, 0.0], "t1")
+        with pytest.raises(ReadOnlyStorageError):
+            await pack.create_edge(ids["paris"], ids["berlin"], "near", "t1")
+        assert await pack.update_memory_access(ids["paris"], "t1") is False
+
+    @pytest.mark.asyncio
+    async def test_mount_as_other_tenant(self, source, tmp_path):
+        """Test a pack can be served under another tenant and agent."""
+        storage, _, ids = source
+        path = tmp_path / "kb.pack"
+        await build_knowledge_pack(path, storage, "t1")
+        store = KnowledgePackStore(path, tenant_id="shipped", agent_id="bot")
+        try:
+            memory = await store.get_memory(ids["paris"], "shipped")
+            assert memory["tenant_id"] == "shipped"
+            assert memory["agent_id"] == "bot"
+            assert await store.get_memory(ids["paris"], "t1") is None
+            assert await store.search_similar([1.0, 0.0, 0.0], "shipped") == []
+        finally:
+            await store.close()
+
+    @pytest.mark.asyncio
+    async def test_rejects_invalid_files(self, source, tmp_path):
+        """Test foreign and corrupted files are refused."""
+        storage, _, _ = source
+        other = tmp_path / "other.bin"
+        other.write_bytes(b"not a pack at all")
+        with pytest.raises(ValidationError):
+            KnowledgePackStore(other)
+
+        path = tmp_path / "kb.pack"
+        await build_knowledge_pack(path, storage, "t1")
+        data = bytearray(path.read_bytes())
+        data[-2] ^= 0xFF
+        path.write_bytes(bytes(data))
+        with pytest.raises(ValidationError):
+            KnowledgePackStore(path, verify=True)
+
+    @pytest.mark.asyncio
+    async def test_unknown_memory(self, pack):
+        """Test unknown ids read as missing."""
+        assert await pack.get_memory(uuid4(), "t1") is None
+        assert await pack.get_vector(uuid4(), "t1") is None
@@ -0,0 +1,229 @@
+from unittest.mock import AsyncMock, MagicMock
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters import pgvector
+from rae_core.adapters.pgvector import PgVectorIndex, PgVectorStore
+from rae_core.adapters.postgres import PostgreSQLStorage
+from rae_core.exceptions.base import ValidationError
+from rae_core.types.enums import DistanceMetric
+
+
+@pytest.fixture
+def mock_conn():
+    conn = AsyncMock()
+    conn.transaction = MagicMock()
+    conn.execute.return_value = "OK"
+    return conn
+
+
+@pytest.fixture
+def mock_pool(mock_conn):
+    pool = MagicMock()
+    pool.acquire.return_value.__aenter__.return_value = mock_conn
+    pool.close = AsyncMock()
+    return pool
+
+
+@pytest.fixture
+def store(mock_pool):
+    return PgVectorStore(pool=mock_pool, embedding_dim=3)
+
+
+def executed(conn):
+    return [call.args[0] for call in conn.execute.call_args_list]
+
+
+class TestPgVectorStore:
+    @pytest.mark.asyncio
+    async def test_shares_storage_pool(self, mock_pool, mock_conn):
+        """Test the store runs on the pool of a PostgreSQLStorage."""
+        storage = PostgreSQLStorage(pool=mock_pool)
+        store = PgVectorStore(storage=storage, embedding_dim=3)
+
+        await store.store_vector(uuid4(), [0.1, 0.2, 0.3], "t1")
+        await store.close()
+
+        assert mock_conn.execute.called
+        mock_pool.close.assert_not_awaited()
+
+    @pytest.mark.asyncio
+    async def test_requires_connection_source(self):
+        """Test a store without dsn, pool or storage cannot connect."""
+        with pytest.raises(ValueError):
+            await PgVectorStore().health_check()
+
+    def test_rejects_unsafe_table_name(self):
+        """Test table names are restricted to plain identifiers."""
+        with pytest.raises(ValueError):
+            PgVectorStore(pool=MagicMock(), table_name="vectors; DROP TABLE x")
+
+    @pytest.mark.asyncio
+    async def test_initialize_creates_hnsw_index(self, store, mock_conn):
+        """Test initialization creates the extension, tables and HNSW index."""
+        await store.initialize()
+        await store.initialize()
+
+        statements = executed(mock_conn)
+        assert statements[0] == "CREATE EXTENSION IF NOT EXISTS vector"
+        assert any("vector(3)" in s for s in statements)
+        [index] = [s for s in statements if "USING hnsw" in s]
+        assert "vector_cosine_ops" in index
+        assert "m = 16" in index and "ef_construction = 64" in index
+        assert len([s for s in statements if "CREATE EXTENSION" in s]) == 1
+
+    @pytest.mark.asyncio
+    async def test_create_index_switches_type(self, store, mock_conn):
+        """Test switching to IVFFlat drops the HNSW index."""
+        store.ivfflat_lists = 50
+        await store.create_index(PgVectorIndex.IVFFLAT)
+
+        statements = executed(mock_conn)
+        assert "DROP INDEX IF EXISTS memory_vectors_embedding_hnsw" in statements
+        assert any("USING ivfflat" in s and "lists = 50" in s for s in statements)
+        assert store.index_type == PgVectorIndex.IVFFLAT
+
+    @pytest.mark.asyncio
+    async def test_drop_index(self, store, mock_conn):
+        """Test dropping the index leaves exact search."""
+        await store.drop_index()
+        await store.reindex()
+
+        statements = executed(mock_conn)
+        assert "DROP INDEX IF EXISTS memory_vectors_embedding_ivfflat" in statements
+        assert not any(s.startswith("REINDEX") for s in statements)
+        assert store.index_type == PgVectorIndex.NONE
+
+    @pytest.mark.asyncio
+    async def test_store_vector_rejects_wrong_dimension(self, store):
+        """Test default vectors must match the column dimension."""
+        with pytest.raises(ValidationError):
+            await store.store_vector(uuid4(), [0.1, 0.2], "t1")
+
+    @pytest.mark.asyncio
+    async def test_store_named_vectors(self, store, mock_conn):
+        """Test named vectors go to the named table, dense to the default one."""
+        memory_id = uuid4()
+        await store.store_vector(
+            memory_id, {"dense": [1.0, 0.0, 0.0], "clip": [0.5, 0.5]}, "t1"
+        )
+
+        insert = mock_conn.execute.call_args_list[-1]
+        assert "DO UPDATE" in insert.args[0]
+        assert insert.args[3] == "[1.0,0.0,0.0]"
+        rows = mock_conn.executemany.call_args.args[1]
+        assert {row[1] for row in rows} == {"dense", "clip"}
+
+    @pytest.mark.asyncio
+    async def test_search_similar_builds_filters(self, store, mock_conn):
+        """Test search orders by cosine distance within the tenant and filters."""
+        memory_id = uuid4()
+        mock_conn.fetch.return_value = [{"memory_id": memory_id, "score": 0.9}]
+        store.ef_search = 80
+
+        results = await store.search_similar(
+            [1.0, 0.0, 0.0],
+            "t1",
+            layer="episodic",
+            limit=5,
+            score_threshold=0.5,
+            filters={"topic": "x"},
+        )
+
+        assert results == [(memory_id, 0.9)]
+        query, *params = mock_conn.fetch.call_args.args
+        assert "ORDER BY embedding <=> $1::vector" in query
+        assert "metadata->>'layer' = $3" in query
+        assert "metadata @> $4::jsonb" in query
+        assert params == ["[1.0,0.0,0.0]", "t1", "episodic", '{"topic": "x"}', 0.5, 5]
+        assert "SET LOCAL hnsw.ef_search = 80" in executed(mock_conn)
+
+    @pytest.mark.asyncio
+    async def test_search_named_vectors(self, store, mock_conn):
+        """Test searching one model's vectors compares equal dimensions only."""
+        mock_conn.fetch.return_value = []
+
+        await store.search_similar([0.5, 0.5], "t1", vector_name="clip")
+
+        query, *params = mock_conn.fetch.call_args.args
+        assert "FROM memory_vectors_named" in query
+        assert "vector_dims(embedding) = $4" in query
+        assert params[2:4] == ["clip", 2]
+
+    @pytest.mark.asyncio
+    async def test_search_zero_query(self, store, mock_conn):
+        """Test a zero query vector matches nothing."""
+        assert await store.search_similar([0.0, 0.0, 0.0], "t1") == []
+        assert not mock_conn.fetch.called
+
+    @pytest.mark.asyncio
+    async def test_distance_metrics(self, mock_pool, mock_conn):
+        """Test each collection is indexed and scored by its metric."""
+        store = PgVectorStore(
+            pool=mock_pool,
+            embedding_dim=3,
+            distance_metric={"default": "l2", "clip": DistanceMetric.DOT},
+        )
+        mock_conn.fetch.return_value = []
+
+        await store.search_similar([0.0, 0.0, 0.0], "t1", score_threshold=0.25)
+        query, *params = mock_conn.fetch.call_args.args
+        [index] = [s for s in executed(mock_conn) if "USING hnsw" in s]
+
+        assert "vector_l2_ops" in index
+        assert "1 / (1 + (embedding <-> $1::vector)) AS score" in query
+        # A zero query has a distance; a 0.25 score is at most 3 away
+        assert params[-2:] == [3.0, 10]
+
+        await store.search_similar([0.5, 0.5], "t1", vector_name="clip")
+        query, *params = mock_conn.fetch.call_args.args
+
+        assert "-(embedding <#> $1::vector) AS score" in query
+        assert "ORDER BY embedding <#> $1::vector" in query
+
+    @pytest.mark.asyncio
+    async def test_get_and_delete_vector(self, store, mock_conn):
+        """Test vectors are parsed from text and deletes report the row count."""
+        mock_conn.fetchval.return_value = "[0.1,0.2,0.3]"
+        assert await store.get_vector(uuid4(), "t1") == [0.1, 0.2, 0.3]
+
+        mock_conn.execute.return_value = "DELETE 0"
+        assert await store.delete_vector(uuid4(), "t1") is False
+        mock_conn.execute.return_value = "DELETE 1"
+        assert await store.delete_vector(uuid4(), "t1") is True
+
+    @pytest.mark.asyncio
+    async def test_update_vector_missing(self, store, mock_conn):
+        """Test updating a memory without a vector fails."""
+        mock_conn.fetchval.return_value = None
+        assert await store.update_vector(uuid4(), [0.1, 0.2, 0.3], "t1") is False
+
+    @pytest.mark.asyncio
+    async def test_batch_store_skips_invalid(self, store, mock_conn):
+        """Test a batch is written in one transaction without invalid vectors."""
+        count = await store.batch_store_vectors(
+            [
+                (uuid4(), [0.1, 0.2, 0.3], {}),
+                (uuid4(), [0.1], {}),
+                (uuid4(), {"default": [0.3, 0.2, 0.1]}, {"layer": "semantic"}),
+            ],
+            "t1",
+        )
+
+        assert count == 2
+        inserts = [s for s in executed(mock_conn) if "INSERT INTO" in s]
+        assert len(inserts) == 2
+
+    @pytest.mark.asyncio
+    async def test_close_owned_pool(self, mock_pool, monkeypatch):
+        """Test a pool opened by the store is closed with it."""
+        store = PgVectorStore(dsn="postgresql://localhost/db")
+        monkeypatch.setattr(
+            pgvector.asyncpg, "create_pool", AsyncMock(return_value=mock_pool)
+        )
+
+        await store.health_check()
+        await store.close()
+
+        mock_pool.close.assert_awaited_once()
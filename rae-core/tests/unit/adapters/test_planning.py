@@ -0,0 +1,86 @@
+"""Unit tests for the dry-run planning proxy."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.cache import InMemoryCache
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.adapters.planning import PlanningProxy
+from rae_core.models.plan import WritePlan
+
+
+class TestPlanningProxy:
+    """Test suite for PlanningProxy."""
+
+    @pytest.fixture
+    def plan(self):
+        return WritePlan()
+
+    @pytest.mark.asyncio
+    async def test_storage_writes_are_recorded(self, plan):
+        """Test stores, updates and deletes never reach the storage."""
+        storage = InMemoryStorage()
+        existing = await storage.store_memory(
+            content="kept", layer="episodic", tenant_id="t1", agent_id="a1"
+        )
+        proxy = PlanningProxy(storage, plan, "storage")
+
+        planned = await proxy.store_memory(
+            content="new", layer="semantic", tenant_id="t1", agent_id="a1"
+        )
+        assert (await proxy.get_memory(planned, "t1"))["content"] == "new"
+        assert await proxy.get_memory(planned, "t2") is None
+        assert (await proxy.get_memory(existing, "t1"))["content"] == "kept"
+
+        await proxy.update_memory(existing, "t1", {"importance": 0.1})
+        await proxy.delete_memory(existing, "t1")
+
+        assert await storage.count_memories("t1") == 1
+        assert (await storage.get_memory(existing, "t1"))["importance"] == 0.5
+        assert [r.memory_id for r in plan.records] == [planned]
+        assert [(o.method, o.arguments) for o in plan.operations] == [
+            (
+                "update_memory",
+                {
+                    "memory_id": existing,
+                    "tenant_id": "t1",
+                    "updates": {"importance": 0.1},
+                },
+            ),
+            ("delete_memory", {"memory_id": existing, "tenant_id": "t1"}),
+        ]
+
+    @pytest.mark.asyncio
+    async def test_vectors_and_graph(self, plan):
+        """Test vectors, nodes and edges become typed plan entries."""
+        vectors = PlanningProxy(InMemoryStorage(), plan, "vector")
+        memory_id = uuid4()
+        await vectors.store_vector(memory_id, {"default": [1.0, 0.0]}, "t1")
+        assert await vectors.get_vector(memory_id, "t1") == {"default": [1.0, 0.0]}
+
+        graph_store = InMemoryGraphStore()
+        graph = PlanningProxy(graph_store, plan, "graph")
+        other = uuid4()
+        assert await graph.create_node(memory_id, "memory", "t1")
+        assert await graph.create_edge(memory_id, other, "supports", "t1", 0.5)
+        assert await graph_store.get_neighbors(memory_id, "t1") == []
+
+        assert plan.vectors[0].embedding == {"default": [1.0, 0.0]}
+        assert plan.nodes[0].node_id == memory_id
+        assert (plan.edges[0].target_id, plan.edges[0].weight) == (other, 0.5)
+
+    @pytest.mark.asyncio
+    async def test_cache_reads_pass_writes_recorded(self, plan):
+        """Test cache reads hit the cache while sets are only planned."""
+        cache = InMemoryCache()
+        await cache.set("k", "v")
+        proxy = PlanningProxy(cache, plan, "cache")
+
+        assert await proxy.get("k") == "v"
+        await proxy.set("k", "changed")
+        assert await cache.get("k") == "v"
+        assert plan.operations[0].component == "cache"
+        assert not plan.is_empty
+        assert WritePlan().is_empty
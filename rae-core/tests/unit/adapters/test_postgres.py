@@ -1,3 +1,4 @@
+import asyncio
 from datetime import datetime, timezone
 from unittest.mock import AsyncMock, MagicMock
 from uuid import uuid4
@@ -5,6 +6,7 @@ from uuid import uuid4
 import pytest
 
 from rae_core.adapters.postgres import PostgreSQLStorage
+from rae_core.exceptions.base import VersionConflictError
 
 
 @pytest.fixture
@@ -45,6 +47,24 @@ class TestPostgreSQLStorage:
         assert isinstance(memory_id, uuid4().__class__)
         assert mock_conn.execute.called
 
+    @pytest.mark.asyncio
+    async def test_store_memory_group_commit(self, mock_pool, mock_conn):
+        """Test concurrent stores share one executemany in a transaction."""
+        mock_conn.transaction = MagicMock()
+        storage = PostgreSQLStorage(pool=mock_pool, group_commit_ms=10)
+
+        await asyncio.gather(
+            *(
+                storage.store_memory(content=f"c{i}", layer="working", tenant_id="t1")
+                for i in range(3)
+            )
+        )
+
+        mock_conn.executemany.assert_awaited_once()
+        assert len(mock_conn.executemany.call_args.args[1]) == 3
+        mock_conn.transaction.assert_called_once()
+        assert not mock_conn.execute.called
+
     @pytest.mark.asyncio
     async def test_get_memory(self, pg_storage, mock_conn):
         """Test retrieving a memory."""
@@ -87,11 +107,46 @@ class TestPostgreSQLStorage:
     @pytest.mark.asyncio
     async def test_update_memory(self, pg_storage, mock_conn):
         """Test updating memory."""
+        mock_conn.execute.return_value = "UPDATE 1"
         result = await pg_storage.update_memory(
             memory_id=uuid4(), tenant_id="tenant1", updates={"content": "new"}
         )
         assert result is True
 
+    @pytest.mark.asyncio
+    async def test_update_memory_expected_version(self, pg_storage, mock_conn):
+        """Test updates are guarded by the expected version."""
+        memory_id = uuid4()
+        mock_conn.execute.return_value = "UPDATE 1"
+        assert await pg_storage.update_memory(
+            memory_id, "tenant1", {"importance": 0.9}, expected_version=3
+        )
+        sql, *params = mock_conn.execute.call_args.args
+        assert sql.endswith("AND version = $4")
+        assert "version = version + 1" in sql
+        assert params == [memory_id, "tenant1", 0.9, 3]
+
+        mock_conn.execute.return_value = "UPDATE 0"
+        mock_conn.fetchval.return_value = 4
+        with pytest.raises(VersionConflictError) as exc:
+            await pg_storage.update_memory(
+                memory_id, "tenant1", {"importance": 0.9}, expected_version=3
+            )
+        assert exc.value.actual_version == 4
+
+        mock_conn.fetchval.return_value = None
+        assert not await pg_storage.update_memory(
+            memory_id, "tenant1", {"importance": 0.9}, expected_version=3
+        )
+
+    @pytest.mark.asyncio
+    async def test_revisions_not_supported(self, pg_storage):
+        """Test history and revert fail loudly instead of reporting nothing."""
+        with pytest.raises(NotImplementedError):
+            await pg_storage.get_memory_history(uuid4(), "tenant1")
+        with pytest.raises(NotImplementedError):
+            await pg_storage.revert_to_version(uuid4(), "tenant1", 1)
+
     @pytest.mark.asyncio
     async def test_delete_memory(self, pg_storage, mock_conn):
         """Test deleting memory."""
@@ -103,3 +158,13 @@ class TestPostgreSQLStorage:
         """Test updating memory expiration."""
         result = await pg_storage.update_memory_expiration(uuid4(), "tenant1", None)
         assert result is True
+
+    @pytest.mark.asyncio
+    async def test_replication_lag(self, pg_storage, mock_conn):
+        """Test replication lag is read from the replay timestamp."""
+        mock_conn.fetchval.return_value = 2.5
+        assert await pg_storage.replication_lag() == 2.5
+        assert "pg_last_xact_replay_timestamp" in mock_conn.fetchval.call_args[0][0]
+
+        mock_conn.fetchval.return_value = None
+        assert await pg_storage.replication_lag() == 0.0
@@ -295,3 +295,28 @@ async def test_add_vector_legacy(qdrant_store, mock_qdrant_client):
     success = await qdrant_store.add_vector(uuid4(), [0.1]*384, "tenant1", agent_id="agent1", layer="working")
     assert success is True
     mock_qdrant_client.upsert.assert_called_once()
+
+@pytest.mark.asyncio
+async def test_euclid_collection(mock_qdrant_client):
+    store = QdrantVectorStore(client=mock_qdrant_client, distance_metric={"dense": "l2"})
+    mock_qdrant_client.get_collections.return_value = CollectionsResponse(collections=[])
+    mem_id = uuid4()
+    mock_qdrant_client.query_points.return_value = models.QueryResponse(
+        points=[
+            ScoredPoint(
+                id=str(mem_id),
+                version=1,
+                score=1.0,
+                payload={"memory_id": str(mem_id), "tenant_id": "tenant1"},
+                vector=None
+            )
+        ]
+    )
+
+    results = await store.search_similar([0.1] * 384, "tenant1", score_threshold=0.25)
+
+    params = mock_qdrant_client.create_collection.call_args.kwargs["vectors_config"]
+    assert params["dense"].distance == Distance.EUCLID
+    # Distance 1.0 scores 0.5; a 0.25 score threshold is a distance of at most 3
+    assert results == [(mem_id, 0.5)]
+    assert mock_qdrant_client.query_points.call_args.kwargs["score_threshold"] == 3.0
@@ -0,0 +1,127 @@
+"""Unit tests for read replica routing."""
+
+import math
+from datetime import timedelta
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.adapters.replicas import ReadReplicaStorage
+from rae_core.exceptions.base import BackendUnavailableError
+from rae_core.utils.clock import DeterministicClock
+
+
+class TestReadReplicaStorage:
+    """Test suite for ReadReplicaStorage."""
+
+    @pytest.fixture
+    def clock(self):
+        return DeterministicClock()
+
+    @pytest.fixture
+    def primary(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def replica(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def lags(self):
+        return {}
+
+    @pytest.fixture
+    def router(self, primary, replica, lags, clock):
+        async def probe(storage):
+            return lags.get(id(storage), 0.0)
+
+        return ReadReplicaStorage(
+            primary, [replica], lag_probe=probe, lag_check_interval=0, clock=clock
+        )
+
+    @pytest.mark.asyncio
+    async def test_writes_go_to_primary(self, router, primary, replica):
+        """Test writes are applied to the primary only."""
+        memory_id = await router.store_memory(content="m", tenant_id="t")
+        assert await primary.get_memory(memory_id, "t") is not None
+        assert await replica.get_memory(memory_id, "t") is None
+
+    @pytest.mark.asyncio
+    async def test_reads_use_primary_without_hint(self, primary, replica, router):
+        """Test reads without a staleness hint are served by the primary."""
+        await primary.store_memory(content="primary", tenant_id="t")
+        await replica.store_memory(content="replica", tenant_id="t")
+
+        memories = await router.list_memories("t")
+        assert [m["content"] for m in memories] == ["primary"]
+        assert await router.count_memories(tenant_id="t", max_staleness=0) == 1
+
+    @pytest.mark.asyncio
+    async def test_staleness_hint_routes_to_replica(
+        self, primary, replica, router, lags
+    ):
+        """Test a replica within the staleness bound serves the read."""
+        await primary.store_memory(content="primary", tenant_id="t")
+        await replica.store_memory(content="replica", tenant_id="t")
+        lags[id(replica)] = 3.0
+
+        memories = await router.list_memories("t", max_staleness=5)
+        assert [m["content"] for m in memories] == ["replica"]
+
+        memories = await router.list_memories("t", max_staleness=1)
+        assert [m["content"] for m in memories] == ["primary"]
+
+    @pytest.mark.asyncio
+    async def test_read_your_writes(self, replica, router, lags, clock):
+        """Test a lagging replica is skipped until it has the tenant's write."""
+        lags[id(replica)] = 2.0
+        memory_id = await router.store_memory(content="m", tenant_id="t")
+
+        assert await router.get_memory(memory_id, "t", max_staleness=10) is not None
+
+        clock.set_time(clock.now() + timedelta(seconds=3))
+        # Replica is now expected to contain the write, so it serves the read
+        assert await router.get_memory(memory_id, "t", max_staleness=10) is None
+        # Other tenants are not affected by the write
+        assert await router.count_memories(tenant_id="u", max_staleness=10) == 0
+
+    @pytest.mark.asyncio
+    async def test_unknown_lag(self, primary, replica, clock):
+        """Test replicas without a lag probe need unbounded staleness."""
+        await replica.store_memory(content="replica", tenant_id="t")
+        router = ReadReplicaStorage(primary, [replica], clock=clock)
+
+        assert await router.count_memories(tenant_id="t", max_staleness=60) == 0
+        assert await router.count_memories(tenant_id="t", max_staleness=math.inf) == 1
+        assert await router.replica_lags() == [None]
+
+    @pytest.mark.asyncio
+    async def test_unavailable_replica_falls_back(self, primary, replica, router):
+        """Test reads fall back to the primary when a replica is down."""
+        await primary.store_memory(content="primary", tenant_id="t")
+
+        async def down(*args, **kwargs):
+            raise BackendUnavailableError("replica", "connection refused")
+
+        replica.list_memories = down
+        memories = await router.list_memories("t", max_staleness=5)
+        assert [m["content"] for m in memories] == ["primary"]
+        assert await router.replica_lags() == [0.0]
+
+    @pytest.mark.asyncio
+    async def test_default_max_staleness(self, primary, replica, clock):
+        """Test the router-wide default applies to reads without a hint."""
+        await replica.store_memory(content="replica", tenant_id="t")
+
+        async def probe(storage):
+            return 0.5
+
+        router = ReadReplicaStorage(
+            primary,
+            [replica],
+            default_max_staleness=1.0,
+            lag_probe=probe,
+            clock=clock,
+        )
+        assert await router.count_memories(tenant_id="t") == 1
+        assert await router.count_memories(tenant_id="t", max_staleness=None) == 0
@@ -0,0 +1,103 @@
+"""Unit tests for AccessPatternAnalyzer."""
+
+from datetime import datetime, timedelta, timezone
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.analytics.access_patterns import AccessPatternAnalyzer
+from rae_core.utils.clock import DeterministicClock
+
+
+class TestAccessPatternAnalyzer:
+    """Test suite for AccessPatternAnalyzer."""
+
+    @pytest.fixture
+    def clock(self):
+        return DeterministicClock(datetime(2025, 1, 1, tzinfo=timezone.utc))
+
+    @pytest.fixture
+    def analyzer(self, clock):
+        return AccessPatternAnalyzer(clock=clock)
+
+    def test_empty_report(self, analyzer):
+        """Test report without any data."""
+        report = analyzer.build_report("tenant1")
+
+        assert report.total_memories == 0
+        assert report.total_hits == 0
+        assert report.recommended_cache_size == 0
+        assert report.tiers is None
+
+    def test_cache_size_covers_target_ratio(self, analyzer, clock):
+        """Test that the hottest memories are chosen to cover the target ratio."""
+        created = clock.now() - timedelta(hours=1)
+        hot, warm, cold = uuid4(), uuid4(), uuid4()
+        for _ in range(8):
+            analyzer.record_access(hot, created)
+        analyzer.record_access(warm, created)
+        analyzer.record_access(cold, created)
+
+        report = analyzer.build_report("tenant1", target_hit_ratio=0.8)
+
+        assert report.total_hits == 10
+        assert report.recommended_cache_size == 1
+        assert report.achieved_hit_ratio == pytest.approx(0.8)
+        assert report.tiers.hot_min_accesses == 8
+        assert report.hit_distribution["5-9"] == 1
+        assert report.hit_distribution["1"] == 2
+
+    def test_reuse_delay_percentiles(self, analyzer, clock):
+        """Test creation-to-access delay tracking."""
+        created = clock.now()
+        analyzer.record_access(uuid4(), created, created + timedelta(seconds=10))
+        analyzer.record_access(uuid4(), created, created + timedelta(seconds=30))
+
+        report = analyzer.build_report("tenant1")
+
+        assert report.reuse_delay_seconds["p50"] in (10.0, 30.0)
+        assert report.reuse_delay_seconds["p99"] == 30.0
+
+    def test_invalid_target_ratio(self, analyzer):
+        """Test validation of the target hit ratio."""
+        with pytest.raises(ValueError):
+            analyzer.build_report("tenant1", target_hit_ratio=0.0)
+
+    @pytest.mark.asyncio
+    async def test_collect_from_storage(self, analyzer, clock):
+        """Test ingestion of access counters from storage."""
+        storage = InMemoryStorage(clock=clock)
+        read_often = await storage.store_memory(
+            content="often", tenant_id="tenant1", agent_id="agent1"
+        )
+        await storage.store_memory(
+            content="never", tenant_id="tenant1", agent_id="agent1"
+        )
+        await storage.store_memory(
+            content="other tenant", tenant_id="tenant2", agent_id="agent1"
+        )
+        for _ in range(3):
+            await storage.update_memory_access(read_often, "tenant1")
+
+        collected = await analyzer.collect(storage, "tenant1", page_size=1)
+        report = analyzer.build_report("tenant1")
+
+        assert collected == 2
+        assert report.total_memories == 2
+        assert report.accessed_memories == 1
+        assert report.total_hits == 3
+        assert report.hit_distribution["0"] == 1
+        assert report.recommended_cache_size == 1
+
+    def test_live_events_take_precedence(self, analyzer, clock):
+        """Test stored counters don't double count live-tracked memories."""
+        memory_id = uuid4()
+        created = clock.now()
+        analyzer.record_access(memory_id, created)
+        analyzer.ingest_memories(
+            [{"id": memory_id, "created_at": created, "access_count": 50}]
+        )
+
+        report = analyzer.build_report("tenant1")
+        assert report.total_hits == 1
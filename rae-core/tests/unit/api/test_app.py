@@ -0,0 +1,483 @@
+"""Unit tests for the HTTP API."""
+
+from unittest.mock import AsyncMock, Mock
+from uuid import uuid4
+
+import pytest
+
+pytest.importorskip("fastapi")
+pytest.importorskip("httpx")
+
+from fastapi.testclient import TestClient  # noqa: E402
+
+from rae_core.api import TENANT_HEADER, create_app, openapi_document  # noqa: E402
+from rae_core.auth import (  # noqa: E402
+    BUILTIN_ROLES,
+    CapabilityTokenCodec,
+    Principal,
+)
+from rae_core.exceptions.base import (  # noqa: E402
+    ConflictError,
+    DimensionMismatchError,
+    QuotaExceededError,
+    SecurityPolicyViolationError,
+)
+from rae_core.models.consistency import (  # noqa: E402
+    ConflictResolution,
+    Contradiction,
+    ResolutionResult,
+)
+from rae_core.models.conversation import ConversationIngestReport  # noqa: E402
+from rae_core.models.embedding import EmbeddingSchema  # noqa: E402
+from rae_core.models.health import ComponentHealth, SystemHealthReport  # noqa: E402
+from rae_core.models.namespace import Namespace, NamespaceDeletion  # noqa: E402
+from rae_core.models.retrieval import (  # noqa: E402
+    RetrievalResponse,
+    RetrievedMemory,
+)
+from rae_core.models.search import RecallResult  # noqa: E402
+from rae_core.models.stream import IngestItem, StreamIngestSummary  # noqa: E402
+from rae_core.search.global_knowledge import GLOBAL_TENANT_ID  # noqa: E402
+
+HEADERS = {TENANT_HEADER: "tenant-a"}
+
+
+@pytest.fixture
+def engine():
+    engine = Mock()
+    engine.store_memory = AsyncMock()
+    engine.recall = AsyncMock()
+    engine.generate_reflections = AsyncMock(return_value=[])
+    engine.get_statistics = AsyncMock(
+        return_value={"total_count": 2, "layer_counts": {"episodic": 2}}
+    )
+    engine.get_status.return_value = {
+        "engine": "RAE-Core",
+        "components": {"storage": "InMemoryStorage"},
+    }
+    engine.memory_storage.soft_delete_memory = AsyncMock(return_value=True)
+    engine.memory_storage.delete_memory = AsyncMock(return_value=False)
+    engine.vector_store.delete_vector = AsyncMock()
+    return engine
+
+
+@pytest.fixture
+def client(engine):
+    return TestClient(create_app(engine))
+
+
+class TestHttpApi:
+    """Test suite for the HTTP API."""
+
+    def test_remember(self, client, engine):
+        """Test remember stores the memory for the header tenant."""
+        memory_id = uuid4()
+        engine.store_memory.return_value = memory_id
+
+        response = client.post(
+            "/v1/remember", json={"content": "hello", "tags": ["x"]}, headers=HEADERS
+        )
+
+        assert response.status_code == 200
+        assert response.json() == {"id": str(memory_id), "stored": True}
+        kwargs = engine.store_memory.call_args.kwargs
+        assert kwargs["tenant_id"] == "tenant-a"
+        assert kwargs["tags"] == ["x"]
+
+    def test_tenant_header_required(self, client):
+        """Test requests without a tenant header are rejected."""
+        response = client.post("/v1/remember", json={"content": "hello"})
+        assert response.status_code == 422
+
+    def test_global_tenant_refused(self, client, engine):
+        """Test the global knowledge tenant cannot be addressed directly."""
+        response = client.post(
+            "/v1/remember",
+            json={"content": "policy"},
+            headers={TENANT_HEADER: GLOBAL_TENANT_ID},
+        )
+        assert response.status_code == 403
+        engine.store_memory.assert_not_awaited()
+
+    def test_recall(self, client, engine):
+        """Test recall reports memories and the relevance outcome."""
+        engine.recall.return_value = RecallResult(
+            memories=[], floor=0.5, best_rejected_score=0.2, rejected_count=3
+        )
+
+        response = client.post(
+            "/v1/recall", json={"query": "q", "top_k": 5}, headers=HEADERS
+        )
+
+        body = response.json()
+        assert body["no_relevant_memory"] is True
+        assert body["rejected_count"] == 3
+        engine.recall.assert_awaited_once_with("q", "tenant-a", floor=None, top_k=5)
+
+    def test_retrieve(self, client, engine):
+        """Test retrieve passes the typed request through to the engine."""
+        engine.retrieve = AsyncMock(
+            return_value=RetrievalResponse(
+                query="q",
+                results=[RetrievedMemory(id="m1", content="c", score=0.9, tokens=1)],
+                no_relevant_memory=False,
+            )
+        )
+
+        response = client.post(
+            "/v1/retrieve",
+            json={"query": "q", "budget": {"top_k": 3, "max_tokens": 50}},
+            headers=HEADERS,
+        )
+
+        assert response.status_code == 200
+        assert response.json()["results"][0]["id"] == "m1"
+        request, tenant_id = engine.retrieve.call_args.args
+        assert request.budget.max_tokens == 50
+        assert tenant_id == "tenant-a"
+
+        response = client.post(
+            "/v1/retrieve", json={"query": "q", "top_k": 3}, headers=HEADERS
+        )
+        assert response.status_code == 422
+
+    def test_ingest_conversation(self, client, engine):
+        """Test conversations are passed to the engine as typed turns."""
+        engine.ingest_conversation = AsyncMock(
+            return_value=ConversationIngestReport(
+                conversation_id="c1", session_id="s1", turns=2, memory_ids=[uuid4()]
+            )
+        )
+
+        response = client.post(
+            "/v1/conversations",
+            json={
+                "turns": [
+                    {"role": "user", "content": "hi"},
+                    {"role": "assistant", "content": "hello"},
+                ],
+                "session_id": "s1",
+            },
+            headers=HEADERS,
+        )
+
+        assert response.status_code == 200
+        assert response.json()["conversation_id"] == "c1"
+        turns, tenant_id = engine.ingest_conversation.call_args.args
+        assert [t.role.value for t in turns] == ["user", "assistant"]
+        assert tenant_id == "tenant-a"
+        assert engine.ingest_conversation.call_args.kwargs == {
+            "agent_id": "default",
+            "session_id": "s1",
+        }
+
+        response = client.post(
+            "/v1/conversations",
+            json={"turns": [{"role": "narrator", "content": "x"}]},
+            headers=HEADERS,
+        )
+        assert response.status_code == 422
+
+    def test_forget(self, client, engine):
+        """Test forget trashes by default and 404s on unknown memories."""
+        memory_id = uuid4()
+        response = client.delete(f"/v1/memories/{memory_id}", headers=HEADERS)
+        assert response.json() == {"id": str(memory_id), "deleted": True, "hard": False}
+
+        response = client.delete(
+            f"/v1/memories/{memory_id}", params={"hard": True}, headers=HEADERS
+        )
+        assert response.status_code == 404
+        assert response.json()["error"] == "NotFoundError"
+
+    def test_list_conflicts(self, client, engine):
+        """Test conflicts are listed for the header tenant."""
+        conflict = Contradiction(memory_id=uuid4(), conflicting_id=uuid4(), score=0.9)
+        engine.list_conflicts = AsyncMock(return_value=[conflict])
+
+        response = client.get("/v1/conflicts", params={"limit": 5}, headers=HEADERS)
+
+        engine.list_conflicts.assert_awaited_once_with("tenant-a", limit=5)
+        assert response.json()[0]["conflicting_id"] == str(conflict.conflicting_id)
+
+    def test_resolve_conflict(self, client, engine):
+        """Test a conflict resolution is passed to the engine."""
+        a, b = uuid4(), uuid4()
+        engine.resolve_conflict = AsyncMock(
+            return_value=ResolutionResult(
+                resolution="supersede", kept_ids=[a], superseded_ids=[b]
+            )
+        )
+
+        response = client.post(
+            "/v1/conflicts/resolve",
+            json={"memory_a": str(a), "memory_b": str(b), "resolution": "supersede"},
+            headers=HEADERS,
+        )
+
+        assert response.json()["superseded_ids"] == [str(b)]
+        engine.resolve_conflict.assert_awaited_once_with(
+            a, b, ConflictResolution.SUPERSEDE, "tenant-a", merged_content=None
+        )
+
+    def test_namespaces(self, client, engine):
+        """Test namespaces are listed, created and deleted for the tenant."""
+        work = Namespace(tenant_id="tenant-a", name="work", memory_count=2)
+        engine.list_namespaces = AsyncMock(return_value=[work])
+        engine.create_namespace = AsyncMock(
+            side_effect=[work, ConflictError("Namespace work already exists")]
+        )
+        engine.delete_namespace = AsyncMock(
+            return_value=NamespaceDeletion(tenant_id="tenant-a", name="work")
+        )
+
+        listed = client.get("/v1/namespaces", headers=HEADERS)
+        created = client.post(
+            "/v1/namespaces", json={"name": "work"}, headers=HEADERS
+        )
+        duplicate = client.post(
+            "/v1/namespaces", json={"name": "work"}, headers=HEADERS
+        )
+        deleted = client.delete("/v1/namespaces/work", headers=HEADERS)
+
+        assert listed.json()[0]["memory_count"] == 2
+        assert created.json()["name"] == "work"
+        engine.create_namespace.assert_awaited_with("tenant-a", "work", None)
+        assert duplicate.status_code == 409
+        assert deleted.json()["name"] == "work"
+        engine.delete_namespace.assert_awaited_once_with("tenant-a", "work")
+
+    def test_embedding_schemas(self, client, engine):
+        """Test the tenant's embedding dimensions are listed and registered."""
+        schema = EmbeddingSchema(tenant_id="tenant-a", model="default", dimension=3)
+        engine.list_embedding_schemas.return_value = [schema]
+        engine.register_embedding_schema.side_effect = [
+            schema,
+            DimensionMismatchError("tenant-a", "default", 3, 4),
+        ]
+
+        listed = client.get("/v1/embedding-schemas", headers=HEADERS)
+        registered = client.put(
+            "/v1/embedding-schemas/default", json={"dimension": 3}, headers=HEADERS
+        )
+        mismatch = client.put(
+            "/v1/embedding-schemas/default", json={"dimension": 4}, headers=HEADERS
+        )
+
+        assert listed.json()[0]["dimension"] == 3
+        engine.list_embedding_schemas.assert_called_once_with("tenant-a")
+        assert registered.json()["model"] == "default"
+        engine.register_embedding_schema.assert_any_call("tenant-a", "default", 3)
+        assert mismatch.status_code == 422
+        assert mismatch.json()["error"] == "DimensionMismatchError"
+
+    def test_ingest_stream(self, client, engine):
+        """Test a JSONL body is streamed into the engine line by line."""
+        received = []
+
+        async def ingest_stream(items, tenant_id, authorize=None):
+            async for line in items:
+                received.append(line)
+            return StreamIngestSummary(items=2, stored=2)
+
+        engine.ingest_stream = AsyncMock(side_effect=ingest_stream)
+
+        response = client.post(
+            "/v1/ingest/stream",
+            content=b'{"content": "a"}\n{"content": "b"}\n',
+            headers={**HEADERS, "Content-Type": "application/x-ndjson"},
+        )
+
+        assert response.json()["stored"] == 2
+        assert received == [b'{"content": "a"}', b'{"content": "b"}']
+        assert engine.ingest_stream.await_args.args[1] == "tenant-a"
+
+    def test_typed_errors_map_to_status_codes(self, client, engine):
+        """Test RAE errors are reported with matching HTTP status codes."""
+        engine.store_memory.side_effect = QuotaExceededError(
+            "tenant-a", "memories", 1, 1, 1
+        )
+        response = client.post(
+            "/v1/remember", json={"content": "hello"}, headers=HEADERS
+        )
+        assert response.status_code == 429
+
+    def test_capability_tokens(self, engine):
+        """Test memory endpoints require a token allowing the operation."""
+        codec = CapabilityTokenCodec("s3cret")
+        client = TestClient(create_app(engine, token_codec=codec))
+        reader = codec.issue(
+            Principal(
+                subject="agent-1",
+                tenant_id="tenant-a",
+                roles=[BUILTIN_ROLES["reader"]],
+            )
+        )
+        auth = {**HEADERS, "Authorization": f"Bearer {reader}"}
+
+        response = client.post("/v1/remember", json={"content": "hi"}, headers=HEADERS)
+        assert response.status_code == 401
+        response = client.post("/v1/remember", json={"content": "hi"}, headers=auth)
+        assert response.status_code == 403
+        response = client.post(
+            "/v1/remember",
+            json={"content": "hi"},
+            headers={**auth, TENANT_HEADER: "tenant-b"},
+        )
+        assert response.status_code == 403
+        engine.store_memory.assert_not_called()
+
+    def test_token_agent_is_enforced(self, engine):
+        """Test a token bound to an agent remembers and recalls as it."""
+        codec = CapabilityTokenCodec("s3cret")
+        client = TestClient(create_app(engine, token_codec=codec))
+        token = codec.issue(
+            Principal(
+                subject="user-1",
+                tenant_id="tenant-a",
+                agent_id="bot",
+                roles=[BUILTIN_ROLES["writer"]],
+            )
+        )
+        auth = {**HEADERS, "Authorization": f"Bearer {token}"}
+        engine.store_memory.return_value = uuid4()
+        engine.recall.return_value = RecallResult(memories=[])
+
+        response = client.post("/v1/remember", json={"content": "hi"}, headers=auth)
+        assert response.status_code == 200
+        assert engine.store_memory.call_args.kwargs["agent_id"] == "bot"
+        response = client.post("/v1/recall", json={"query": "q"}, headers=auth)
+        assert response.status_code == 200
+        assert engine.recall.call_args.kwargs["agent_id"] == "bot"
+
+        for path, body in (
+            ("/v1/remember", {"content": "hi", "agent_id": "agent-1"}),
+            ("/v1/recall", {"query": "q", "agent_id": "agent-1"}),
+        ):
+            response = client.post(path, json=body, headers=auth)
+            assert response.status_code == 403
+        assert engine.store_memory.await_count == 1
+        assert engine.recall.await_count == 1
+
+    def test_token_agent_is_enforced_on_retrieve_and_ingestion(self, engine):
+        """Test a token bound to an agent retrieves and ingests as it."""
+        codec = CapabilityTokenCodec("s3cret")
+        client = TestClient(create_app(engine, token_codec=codec))
+        token = codec.issue(
+            Principal(
+                subject="user-1",
+                tenant_id="tenant-a",
+                agent_id="bot",
+                roles=[BUILTIN_ROLES["writer"]],
+            )
+        )
+        auth = {**HEADERS, "Authorization": f"Bearer {token}"}
+        engine.retrieve = AsyncMock(return_value=RetrievalResponse(query="q"))
+        engine.ingest_conversation = AsyncMock(
+            return_value=ConversationIngestReport(
+                conversation_id="c1", session_id="s1", turns=1, memory_ids=[]
+            )
+        )
+        turns = [{"role": "user", "content": "hi"}]
+
+        response = client.post("/v1/retrieve", json={"query": "q"}, headers=auth)
+        assert response.status_code == 200
+        assert engine.retrieve.call_args.args[0].agent_id == "bot"
+        response = client.post("/v1/conversations", json={"turns": turns}, headers=auth)
+        assert response.status_code == 200
+        assert engine.ingest_conversation.call_args.kwargs["agent_id"] == "bot"
+
+        for path, body in (
+            ("/v1/retrieve", {"query": "q", "agent_id": "agent-1"}),
+            ("/v1/conversations", {"turns": turns, "agent_id": "agent-1"}),
+        ):
+            response = client.post(path, json=body, headers=auth)
+            assert response.status_code == 403
+        assert engine.retrieve.await_count == 1
+        assert engine.ingest_conversation.await_count == 1
+
+        authorized = []
+
+        async def ingest_stream(items, tenant_id, authorize=None):
+            for line in [line async for line in items]:
+                item = IngestItem.model_validate_json(line)
+                try:
+                    authorize(item)
+                except SecurityPolicyViolationError:
+                    continue
+                authorized.append(item.agent_id)
+            return StreamIngestSummary(items=2, stored=len(authorized))
+
+        engine.ingest_stream = AsyncMock(side_effect=ingest_stream)
+        response = client.post(
+            "/v1/ingest/stream",
+            content=b'{"content": "a"}\n{"content": "b", "agent_id": "agent-1"}\n',
+            headers={**auth, "Content-Type": "application/x-ndjson"},
+        )
+        assert response.status_code == 200
+        assert authorized == ["bot"]
+
+    def test_tenant_metrics_require_a_token(self, engine):
+        """Test per-tenant statistics are only served to the tenant's tokens."""
+        codec = CapabilityTokenCodec("s3cret")
+        client = TestClient(create_app(engine, token_codec=codec))
+        token = codec.issue(
+            Principal(
+                subject="agent-1",
+                tenant_id="tenant-a",
+                roles=[BUILTIN_ROLES["reader"]],
+            )
+        )
+        auth = {**HEADERS, "Authorization": f"Bearer {token}"}
+
+        assert client.get("/metrics", headers=HEADERS).status_code == 401
+        response = client.get("/metrics", headers={**auth, TENANT_HEADER: "tenant-b"})
+        assert response.status_code == 403
+        engine.get_statistics.assert_not_called()
+        response = client.get("/metrics", headers=auth)
+        assert response.json()["total_count"] == 2
+
+    def test_ops_endpoints(self, client):
+        """Test health and metrics endpoints."""
+        assert client.get("/health").json()["status"] == "ok"
+        metrics = client.get("/metrics", headers=HEADERS).json()
+        assert metrics == {
+            "tenant_id": "tenant-a",
+            "total_count": 2,
+            "layer_counts": {"episodic": 2},
+        }
+
+    def test_healthz(self, client, engine):
+        """Test backend health is reported with 503 when a backend is down."""
+        components = [
+            ComponentHealth(
+                name="storage", backend="SQLiteStorage", status="ok", latency_ms=1.5
+            )
+        ]
+        engine.check_health = AsyncMock(
+            return_value=SystemHealthReport(status="ok", components=components)
+        )
+        response = client.get("/healthz")
+        assert response.status_code == 200
+        assert response.json()["components"][0]["latency_ms"] == 1.5
+
+        engine.check_health.return_value = SystemHealthReport(status="unhealthy")
+        assert client.get("/healthz").status_code == 503
+
+    def test_prometheus_endpoint(self, client):
+        """Test process metrics are served in the Prometheus text format."""
+        from rae_core.metrics import REGISTRY
+
+        REGISTRY.counter("rae_test_api_hits_total", "API test counter").inc()
+        response = client.get("/metrics/prometheus")
+        assert response.status_code == 200
+        assert response.headers["content-type"].startswith("text/plain")
+        assert "rae_test_api_hits_total" in response.text
+
+    def test_openapi_document(self):
+        """Test the OpenAPI document is generated from the handlers."""
+        document = openapi_document()
+        assert {"/v1/remember", "/v1/recall", "/v1/reflect", "/health"} <= set(
+            document["paths"]
+        )
+        assert "RememberRequest" in document["components"]["schemas"]
@@ -0,0 +1,74 @@
+"""Unit tests for audit recording of memory mutations."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.adapters.memory.audit import InMemoryAuditLogger
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.audit import AuditOperation, AuditRecorder
+from rae_core.events import EventPublishingStorage, MemoryEventBus
+
+
+class TestAuditRecorder:
+    """Test suite for AuditRecorder with the in-memory logger."""
+
+    @pytest.fixture
+    def setup(self):
+        bus = MemoryEventBus(raise_errors=True)
+        audit_logger = InMemoryAuditLogger()
+        AuditRecorder(audit_logger).attach(bus)
+        storage = EventPublishingStorage(InMemoryStorage(), bus, actor="alice")
+        return storage, audit_logger
+
+    @pytest.mark.asyncio
+    async def test_all_mutations_recorded(self, setup):
+        """Test store, update, promote, delete and restore are audited."""
+        storage, audit_logger = setup
+        memory_id = await storage.store_memory(
+            content="Budget is 10k", tenant_id="t", agent_id="a", layer="working"
+        )
+        await storage.with_actor("bob").update_memory(
+            memory_id, "t", {"content": "Budget is 12k", "layer": "semantic"}
+        )
+        await storage.soft_delete_memory(memory_id, "t")
+        await storage.restore_memory(memory_id, "t")
+        await storage.delete_memory(memory_id, "t")
+
+        entries = await audit_logger.query("t")
+        assert [e.operation for e in entries] == [
+            AuditOperation.STORE,
+            AuditOperation.UPDATE,
+            AuditOperation.PROMOTE,
+            AuditOperation.SOFT_DELETE,
+            AuditOperation.RESTORE,
+            AuditOperation.DELETE,
+        ]
+        assert {e.memory_id for e in entries} == {memory_id}
+        assert entries[1].actor == "bob"
+        assert entries[1].diff[0] == {
+            "field": "content",
+            "old_value": "Budget is 10k",
+            "new_value": "Budget is 12k",
+        }
+        assert entries[0].metadata == {"agent_id": "a"}
+
+    @pytest.mark.asyncio
+    async def test_query_filters(self, setup):
+        """Test filtering by actor, operation, time range and tenant."""
+        storage, audit_logger = setup
+        first = await storage.store_memory(content="one", tenant_id="t")
+        await storage.with_actor("bob").store_memory(content="two", tenant_id="t")
+        await storage.store_memory(content="other", tenant_id="t2")
+        await storage.update_memory(first, "t", {"importance": 0.9})
+
+        assert len(await audit_logger.query("t")) == 3
+        assert len(await audit_logger.query("t", actor="bob")) == 1
+        assert len(await audit_logger.query("t2")) == 1
+
+        updates = await audit_logger.query("t", operation=AuditOperation.UPDATE)
+        assert [e.memory_id for e in updates] == [first]
+
+        future = datetime.now(timezone.utc) + timedelta(hours=1)
+        assert await audit_logger.query("t", start=future) == []
+        assert len(await audit_logger.query("t", end=future, limit=2)) == 2
@@ -0,0 +1,72 @@
+"""Unit tests for principals, roles and permissions."""
+
+import pytest
+
+from rae_core.auth import BUILTIN_ROLES, Action, Permission, Principal
+from rae_core.exceptions.base import (
+    PermissionDeniedError,
+    SecurityPolicyViolationError,
+)
+
+
+class TestPrincipal:
+    """Test suite for permission checks."""
+
+    def test_parse_permission(self):
+        """Test permissions parse from and format to "action:layer:scope"."""
+        permission = Permission.parse("read:episodic")
+
+        assert permission == Permission(action=Action.READ, layer="episodic")
+        assert str(permission) == "read:episodic:*"
+        with pytest.raises(ValueError):
+            Permission.parse("admin")
+
+    def test_builtin_roles(self):
+        """Test reader, writer and admin grant increasing actions."""
+        reader = Principal(subject="a", tenant_id="t", roles=[BUILTIN_ROLES["reader"]])
+        admin = Principal(subject="b", tenant_id="t", roles=[BUILTIN_ROLES["admin"]])
+
+        assert reader.can("read", "semantic", "team")
+        assert not reader.can("write", "semantic")
+        assert admin.can(Action.DELETE)
+
+    def test_layer_and_scope_restrictions(self):
+        """Test narrow permissions do not cover other or unknown layers."""
+        principal = Principal(
+            subject="agent-1",
+            tenant_id="t",
+            permissions=[Permission.parse("write:working:private")],
+        )
+
+        assert principal.can("write", "working", "private")
+        assert not principal.can("write", "working", "tenant")
+        assert not principal.can("write", "episodic", "private")
+        assert not principal.can("write")
+        with pytest.raises(PermissionDeniedError) as exc:
+            principal.require("write", "episodic", "private")
+        assert (exc.value.action, exc.value.layer) == ("write", "episodic")
+
+    def test_can_read_memory(self):
+        """Test stored memories are checked by layer and metadata scope."""
+        principal = Principal(
+            subject="agent-1",
+            tenant_id="t",
+            permissions=[Permission.parse("read:*:team")],
+        )
+
+        team = {"layer": "episodic", "metadata": {"scope": "team"}}
+        unscoped = {"layer": "episodic", "metadata": {}}
+        assert principal.can_read_memory(team)
+        assert not principal.can_read_memory(unscoped)
+
+    def test_acting_agent(self):
+        """Test a token's agent cannot be overridden by the request."""
+        unbound = Principal(subject="user-1", tenant_id="t")
+        bound = Principal(subject="user-1", tenant_id="t", agent_id="bot")
+
+        assert unbound.acting_agent() == "user-1"
+        assert unbound.acting_agent("other") == "other"
+        assert bound.acting_agent() == "bot"
+        assert bound.acting_agent("bot") == "bot"
+        with pytest.raises(SecurityPolicyViolationError):
+            bound.acting_agent("other")
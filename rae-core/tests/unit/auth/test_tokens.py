@@ -0,0 +1,92 @@
+"""Unit tests for capability tokens."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.auth import (
+    BUILTIN_ROLES,
+    CapabilityTokenCodec,
+    Permission,
+    Principal,
+    Role,
+    bearer_token,
+)
+from rae_core.exceptions.base import AuthenticationError
+from rae_core.utils.clock import DeterministicClock
+
+START = datetime(2025, 1, 1, tzinfo=timezone.utc)
+
+
+class TestCapabilityTokenCodec:
+    """Test suite for CapabilityTokenCodec."""
+
+    @pytest.fixture
+    def clock(self):
+        return DeterministicClock(START)
+
+    @pytest.fixture
+    def codec(self, clock):
+        return CapabilityTokenCodec("s3cret", clock=clock)
+
+    @pytest.fixture
+    def principal(self):
+        return Principal(
+            subject="agent-1",
+            tenant_id="tenant-a",
+            agent_id="agent-1",
+            roles=[BUILTIN_ROLES["reader"]],
+            permissions=[Permission.parse("write:working:private")],
+        )
+
+    def test_roundtrip(self, codec, principal):
+        """Test a verified token yields the issued principal."""
+        token = codec.issue(principal)
+
+        assert token.startswith("rae1.")
+        assert codec.verify(token) == principal
+
+    def test_tampered_or_foreign_tokens_rejected(self, codec, principal):
+        """Test tokens with a changed payload or another secret fail."""
+        token = codec.issue(principal)
+        prefix, body, signature = token.split(".")
+        forged = codec.issue(principal.model_copy(update={"tenant_id": "tenant-b"}))
+
+        with pytest.raises(AuthenticationError, match="signature"):
+            codec.verify(f"{prefix}.{forged.split('.')[1]}.{signature}")
+        with pytest.raises(AuthenticationError, match="signature"):
+            CapabilityTokenCodec("other").verify(token)
+        with pytest.raises(AuthenticationError, match="Malformed"):
+            codec.verify("not-a-token")
+
+    def test_expiry(self, codec, clock, principal):
+        """Test tokens stop verifying after their time to live."""
+        token = codec.issue(principal, ttl_seconds=60)
+
+        clock.set_time(START + timedelta(seconds=59))
+        codec.verify(token)
+        clock.set_time(START + timedelta(seconds=60))
+        with pytest.raises(AuthenticationError, match="expired"):
+            codec.verify(token)
+
+    def test_roles_resolved_at_verification(self, clock, principal):
+        """Test role names map onto the verifier's role table."""
+        issuer = CapabilityTokenCodec("s3cret", clock=clock)
+        token = issuer.issue(principal)
+
+        narrowed = Role(name="reader", permissions=[Permission.parse("read:working")])
+        verifier = CapabilityTokenCodec(
+            "s3cret", roles={"reader": narrowed}, clock=clock
+        )
+        assert not verifier.verify(token).can("read", "episodic", "private")
+
+        with pytest.raises(AuthenticationError, match="Unknown roles"):
+            CapabilityTokenCodec("s3cret", roles={}, clock=clock).verify(token)
+
+    def test_bearer_token(self):
+        """Test tokens are taken from Bearer authorization values."""
+        assert bearer_token("Bearer abc") == "abc"
+        with pytest.raises(AuthenticationError):
+            bearer_token("Basic abc")
+        with pytest.raises(AuthenticationError):
+            bearer_token(None)
@@ -0,0 +1,157 @@
+"""Unit tests for the rae-cli commands."""
+
+import io
+import json
+from types import SimpleNamespace
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.cli import EmbeddedBackend, GrpcBackend, HttpBackend, build_parser, run
+from rae_core.exceptions.base import InfrastructureError, ValidationError
+
+
+@pytest.fixture
+def storage():
+    return InMemoryStorage()
+
+
+@pytest.fixture
+def backend(storage):
+    engine = Mock()
+    engine.recall = AsyncMock(
+        return_value=SimpleNamespace(memories=[{"content": "hit", "score": 0.9}])
+    )
+    engine.get_statistics = AsyncMock(
+        return_value={"total_count": 2, "layer_counts": {"episodic": 2}}
+    )
+
+    async def store_memory(**kwargs):
+        return await storage.store_memory(**kwargs)
+
+    engine.store_memory = AsyncMock(side_effect=store_memory)
+    system = SimpleNamespace(
+        engine=engine,
+        storage=storage,
+        vector_store=storage,
+        graph=None,
+        close=AsyncMock(),
+    )
+    return EmbeddedBackend(system)
+
+
+async def _run(backend, *argv):
+    out = io.StringIO()
+    await run(build_parser().parse_args(["--tenant", "t1", *argv]), backend, out)
+    return out.getvalue()
+
+
+class TestEmbeddedCommands:
+    """Test suite for commands against the embedded backend."""
+
+    @pytest.mark.asyncio
+    async def test_store_list_and_search(self, backend, storage):
+        """Test store passes its options and list/search print JSON."""
+        printed = json.loads(
+            await _run(
+                backend,
+                "store",
+                "Invoices live in S3",
+                "--layer",
+                "semantic",
+                "--tag",
+                "billing",
+                "--metadata",
+                '{"team": "ops"}',
+            )
+        )
+        assert printed["stored"] is True
+
+        [memory] = json.loads(await _run(backend, "list", "--layer", "semantic"))
+        assert memory["id"] == printed["id"]
+        assert (memory["tags"], memory["metadata"]) == (["billing"], {"team": "ops"})
+
+        hits = json.loads(await _run(backend, "search", "invoices", "--top-k", "3"))
+        assert hits == [{"content": "hit", "score": 0.9}]
+        _, kwargs = backend.engine.recall.await_args
+        assert kwargs["top_k"] == 3
+
+    @pytest.mark.asyncio
+    async def test_export_import_round_trip(self, backend, storage, tmp_path):
+        """Test a tenant exported to a file is restored into another tenant."""
+        await storage.store_memory(content="a fact", tenant_id="t1", layer="semantic")
+        snapshot = tmp_path / "t1.jsonl"
+
+        await _run(backend, "export", "-o", str(snapshot))
+        out = io.StringIO()
+        args = build_parser().parse_args(["--tenant", "t2", "import", str(snapshot)])
+        await run(args, backend, out)
+
+        result = json.loads(out.getvalue())
+        assert (result["source_tenant_id"], result["memories"]) == ("t1", 1)
+        [restored] = await storage.list_memories("t2")
+        assert restored["content"] == "a fact"
+
+    @pytest.mark.asyncio
+    async def test_stats_and_maintenance(self, backend):
+        """Test stats prints the counters and maintenance reports each job run."""
+        stats = json.loads(await _run(backend, "stats"))
+        assert stats["total_count"] == 2
+
+        runs = json.loads(
+            await _run(backend, "maintenance", "run", "--job", "expiration")
+        )
+        assert [(r["job"], r["status"]) for r in runs] == [("expiration", "completed")]
+
+    @pytest.mark.asyncio
+    async def test_tenant_required(self, backend):
+        """Test commands other than import refuse to run without a tenant."""
+        with pytest.raises(ValidationError):
+            await run(build_parser().parse_args(["stats"]), backend, io.StringIO())
+
+
+class TestRemoteBackends:
+    """Test suite for the HTTP and gRPC backends."""
+
+    @pytest.mark.asyncio
+    async def test_http_store_and_search(self):
+        """Test commands map to API calls with the tenant header and token."""
+        client = Mock()
+        responses = [{"id": "m1", "stored": True}, {"memories": [{"content": "x"}]}]
+        client.request = AsyncMock(
+            side_effect=[
+                Mock(status_code=200, json=Mock(return_value=body))
+                for body in responses
+            ]
+        )
+        backend = HttpBackend("http://rae:8000/", token="tok", client=client)
+
+        assert await backend.store("t1", "hello", layer="semantic") == "m1"
+        assert await backend.search("t1", "hello", top_k=2) == [{"content": "x"}]
+
+        store_call, search_call = client.request.await_args_list
+        assert store_call.args == ("POST", "http://rae:8000/v1/remember")
+        assert store_call.kwargs["headers"] == {
+            "X-Tenant-Id": "t1",
+            "Authorization": "Bearer tok",
+        }
+        assert search_call.kwargs["json"] == {"query": "hello", "top_k": 2}
+
+    @pytest.mark.asyncio
+    async def test_http_errors_and_unsupported_commands(self):
+        """Test server errors and commands the transport lacks are reported."""
+        client = Mock()
+        client.request = AsyncMock(
+            return_value=Mock(
+                status_code=403, json=Mock(return_value={"detail": "denied"})
+            )
+        )
+        backend = HttpBackend("http://rae:8000", client=client)
+
+        with pytest.raises(InfrastructureError, match="403: denied"):
+            await backend.stats("t1")
+        with pytest.raises(ValidationError, match="export is not available"):
+            backend.export("t1")
+        with pytest.raises(ValidationError, match="over gRPC"):
+            await GrpcBackend("rae:50051").stats("t1")
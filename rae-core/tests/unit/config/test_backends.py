@@ -0,0 +1,90 @@
+"""Unit tests for the declarative backend configuration."""
+
+import json
+
+import pytest
+
+from rae_core.config.backends import RAEConfig
+from rae_core.exceptions.base import ValidationError
+
+TOML = """
+[storage]
+backend = "sqlite"
+options = { db_path = "rae.db", history_limit = 3 }
+
+[vector_store]
+backend = "qdrant"
+options = { url = "http://qdrant:6333" }
+
+[embedding.default]
+provider = "ollama"
+options = { dimension = 768 }
+
+[settings]
+relevance_floor = 0.3
+"""
+
+
+class TestRAEConfig:
+    """Test suite for RAEConfig."""
+
+    def test_defaults(self):
+        """Test in-memory backends are used when nothing is configured."""
+        config = RAEConfig.load()
+        assert config.storage.backend == "memory"
+        assert config.vector_store.backend == "memory"
+        assert config.graph is None
+        assert config.embedding == {}
+
+    def test_load_toml(self, tmp_path):
+        """Test a TOML file declares backends, options and settings."""
+        path = tmp_path / "rae.toml"
+        path.write_text(TOML)
+
+        config = RAEConfig.load(path)
+
+        assert config.storage.backend == "sqlite"
+        assert config.storage.options == {"db_path": "rae.db", "history_limit": 3}
+        assert config.vector_store.options["url"] == "http://qdrant:6333"
+        assert config.embedding["default"].provider == "ollama"
+        assert config.rae_settings().relevance_floor == 0.3
+
+    def test_load_yaml_and_json(self, tmp_path):
+        """Test YAML and JSON files are read by their suffix."""
+        pytest.importorskip("yaml")
+        yaml_path = tmp_path / "rae.yaml"
+        yaml_path.write_text(
+            "graph:\n  backend: sqlite\n  options:\n    db_path: g.db\n"
+        )
+        json_path = tmp_path / "rae.json"
+        json_path.write_text(json.dumps({"cache": {"backend": "redis"}}))
+
+        assert RAEConfig.load(yaml_path).graph.options == {"db_path": "g.db"}
+        assert RAEConfig.load(json_path).cache.backend == "redis"
+
+    def test_environment_overrides_file(self, tmp_path, monkeypatch):
+        """Test RAE_* variables override nested values of the file."""
+        path = tmp_path / "rae.toml"
+        path.write_text(TOML)
+        monkeypatch.setenv("RAE_STORAGE__OPTIONS__DB_PATH", "/data/rae.db")
+        monkeypatch.setenv("RAE_GRAPH__BACKEND", "memory")
+
+        config = RAEConfig.load(path)
+
+        assert config.storage.options == {"db_path": "/data/rae.db", "history_limit": 3}
+        assert config.graph.backend == "memory"
+
+    def test_invalid(self, tmp_path):
+        """Test unreadable or invalid files raise ValidationError."""
+        for name, text in (
+            ("bad.toml", "storage = ["),
+            ("bad.json", json.dumps({"storage": {"backend": "x", "extra": 1}})),
+            ("settings.json", json.dumps({"settings": {"no_such_setting": 1}})),
+            ("list.json", "[]"),
+        ):
+            path = tmp_path / name
+            path.write_text(text)
+            with pytest.raises(ValidationError):
+                RAEConfig.load(path)
+        with pytest.raises(ValidationError):
+            RAEConfig.load(tmp_path / "missing.toml")
@@ -0,0 +1,110 @@
+"""Unit tests for hot configuration reload."""
+
+import json
+from types import SimpleNamespace
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.config import RAESettings
+from rae_core.config.reload import (
+    ConfigHandle,
+    ConfigWatcher,
+    RuntimeConfig,
+    load_config_file,
+)
+from rae_core.events import ConfigChanged, MemoryEventBus
+from rae_core.exceptions.base import ValidationError
+from rae_core.governance import LoadShedder, QuotaManager
+
+
+class TestConfigReload:
+    """Test suite for ConfigWatcher and RuntimeConfig."""
+
+    @pytest.fixture
+    def path(self, tmp_path):
+        return tmp_path / "runtime.json"
+
+    def write(self, path, data):
+        path.write_text(json.dumps(data))
+
+    def test_validation(self, path):
+        """Test unknown settings and invalid values are rejected."""
+        self.write(path, {"settings": {"rerank_top_k": 20}})
+        assert load_config_file(path).settings == {"rerank_top_k": 20}
+
+        for bad in (
+            {"settings": {"no_such_setting": 1}},
+            {"settings": {"decay_rate": 5.0}},
+            {"ranking_weights": {"vector": -1.0}},
+            {"schedules": {"decay": 0}},
+            {"unknown_section": {}},
+        ):
+            self.write(path, bad)
+            with pytest.raises(ValidationError):
+                load_config_file(path)
+
+    def test_handle_swap(self):
+        """Test swapping returns the previous value and bumps the version."""
+        handle = ConfigHandle(RuntimeConfig())
+        new = RuntimeConfig(schedules={"decay": 60})
+
+        previous = handle.swap(new)
+
+        assert previous == RuntimeConfig()
+        assert handle.get() is new
+        assert handle.version == 1
+
+    @pytest.mark.asyncio
+    async def test_reload_applies_and_publishes(self, path):
+        """Test a changed file is swapped in, applied and announced."""
+        bus = MemoryEventBus()
+        events = []
+        bus.subscribe(ConfigChanged, events.append)
+        engine = SimpleNamespace(settings=RAESettings(), ranking_weights=None)
+        quotas = QuotaManager(InMemoryStorage())
+        shedder = LoadShedder()
+        watcher = ConfigWatcher(path, event_bus=bus)
+        watcher.bind(engine=engine, quota_manager=quotas, load_shedder=shedder)
+
+        self.write(
+            path,
+            {
+                "settings": {"relevance_floor": 0.3},
+                "ranking_weights": {"fulltext": 0.2, "vector": 0.8},
+                "quotas": {"t1": {"max_memories": 10}},
+                "default_load_limits": {"max_concurrent": 4},
+            },
+        )
+        assert await watcher.check()
+        assert not await watcher.check()
+
+        assert engine.settings.relevance_floor == 0.3
+        assert engine.ranking_weights == {"fulltext": 0.2, "vector": 0.8}
+        assert quotas.get_quota("t1").max_memories == 10
+        assert shedder.get_limits("t2").max_concurrent == 4
+        assert [e.version for e in events] == [1]
+        assert set(events[0].changed) == {
+            "settings",
+            "ranking_weights",
+            "quotas",
+            "default_load_limits",
+        }
+
+        # Dropping a tenant override returns it to the default
+        self.write(path, {"settings": {"relevance_floor": 0.3}})
+        assert await watcher.check()
+        assert quotas.get_quota("t1").max_memories is None
+        assert engine.ranking_weights is None
+
+    @pytest.mark.asyncio
+    async def test_invalid_file_keeps_running_config(self, path):
+        """Test a broken file is rejected without touching the config."""
+        watcher = ConfigWatcher(path)
+        self.write(path, {"schedules": {"decay": 30}})
+        await watcher.check()
+
+        path.write_text("{not json")
+        assert not await watcher.check()
+        assert watcher.handle.get().schedules == {"decay": 30}
+        assert watcher.handle.version == 1
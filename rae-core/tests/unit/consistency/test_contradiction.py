@@ -0,0 +1,132 @@
+"""Unit tests for contradiction detection between stored memories."""
+
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.consistency.contradiction import (
+    CONFLICT_TAG,
+    CONTRADICTS_KEY,
+    ContradictionDetector,
+    LlmContradictionJudge,
+    list_conflicts,
+)
+
+
+class KeywordJudge:
+    """Flags pairs where one side says Monday and the other Friday."""
+
+    def __init__(self):
+        self.pairs = []
+
+    async def contradiction_score(self, premise: str, hypothesis: str) -> float:
+        self.pairs.append((premise, hypothesis))
+        days = {"Monday" in premise, "Monday" in hypothesis}
+        return 0.9 if days == {True, False} else 0.1
+
+
+class TestContradictionDetector:
+    """Test suite for ContradictionDetector."""
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    async def _store(self, storage, content, vector, **kwargs):
+        memory_id = await storage.store_memory(
+            content=content, tenant_id="t1", **kwargs
+        )
+        await storage.store_vector(memory_id, vector, "t1")
+        return memory_id
+
+    @pytest.mark.asyncio
+    async def test_flags_similar_contradicting_memories(self, storage):
+        """Test only similar memories are judged and conflicts are recorded."""
+        monday = await self._store(storage, "The review is on Monday", [1.0, 0.1])
+        agreeing = await self._store(storage, "The review is on Friday", [1.0, 0.2])
+        await self._store(storage, "Cats like Monday naps", [0.1, 1.0])
+        new = await self._store(storage, "The review moved to Friday", [1.0, 0.0])
+        judge = KeywordJudge()
+        graph = InMemoryGraphStore()
+        detector = ContradictionDetector(judge, graph_store=graph)
+
+        found = await detector.check(new, "t1", storage, storage)
+
+        assert len(judge.pairs) == 2
+        assert [c.conflicting_id for c in found] == [monday]
+        assert found[0].score == 0.9
+        memory = await storage.get_memory(new, "t1")
+        assert CONFLICT_TAG in memory["tags"]
+        assert memory["metadata"][CONTRADICTS_KEY][0]["memory_id"] == str(monday)
+        neighbors = await graph.get_neighbors(
+            new, "t1", edge_type="contradicts", direction="out"
+        )
+        assert neighbors == [monday]
+        assert agreeing not in neighbors
+
+    @pytest.mark.asyncio
+    async def test_chunks_of_the_same_content_are_skipped(self, storage):
+        """Test sibling chunks are not judged against each other."""
+        first = await self._store(
+            storage, "Monday part", [1.0, 0.0], metadata={"parent_id": "p"}
+        )
+        await self._store(
+            storage, "Friday part", [1.0, 0.1], metadata={"parent_id": "p"}
+        )
+        judge = KeywordJudge()
+
+        found = await ContradictionDetector(judge).check(first, "t1", storage, storage)
+
+        assert found == []
+        assert judge.pairs == []
+
+    @pytest.mark.asyncio
+    async def test_list_conflicts(self, storage):
+        """Test conflicts are listed until either memory is deleted."""
+        monday = await self._store(storage, "Standup is on Monday", [1.0, 0.1])
+        new = await self._store(storage, "Standup is on Friday", [1.0, 0.0])
+        await ContradictionDetector(KeywordJudge()).check(new, "t1", storage, storage)
+
+        conflicts = await list_conflicts("t1", storage)
+        assert [(c.memory_id, c.conflicting_id) for c in conflicts] == [(new, monday)]
+        assert conflicts[0].detected_at is not None
+        assert await list_conflicts("t2", storage) == []
+
+        await storage.soft_delete_memory(monday, "t1")
+        assert await list_conflicts("t1", storage) == []
+
+    def test_rejects_invalid_threshold(self):
+        """Test threshold must be a probability."""
+        with pytest.raises(ValueError):
+            ContradictionDetector(KeywordJudge(), threshold=1.5)
+
+
+class TestLlmContradictionJudge:
+    """Test suite for LlmContradictionJudge."""
+
+    @pytest.mark.asyncio
+    async def test_parses_the_reply(self):
+        """Test the score is read from the reply and clamped to 0-1."""
+        llm = Mock()
+        llm.generate = AsyncMock(return_value="0.8")
+        judge = LlmContradictionJudge(llm)
+
+        assert await judge.contradiction_score("a", "b") == 0.8
+        assert "Statement B: b" in llm.generate.await_args.args[0]
+
+        llm.generate.return_value = "Score: 7"
+        assert await judge.contradiction_score("a", "b") == 1.0
+        llm.generate.return_value = "unsure"
+        assert await judge.contradiction_score("a", "b") == 0.0
+
+    def test_llm_providers_are_wrapped(self):
+        """Test an LLM provider passed as judge is wrapped."""
+
+        class Llm:
+            async def generate(self, prompt, **kwargs):
+                return "1"
+
+        detector = ContradictionDetector(Llm())
+        assert isinstance(detector.judge, LlmContradictionJudge)
@@ -0,0 +1,144 @@
+"""Unit tests for resolving contradicting memories."""
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.consistency.contradiction import CONFLICT_TAG, CONTRADICTS_KEY
+from rae_core.consistency.resolution import (
+    SUPERSEDED_TAG,
+    merged_fields,
+    resolve_conflict,
+)
+from rae_core.exceptions.base import NotFoundError, ValidationError
+from rae_core.models.consistency import SUPERSEDED_KEY, ConflictResolution
+
+
+class TestResolveConflict:
+    """Test suite for resolve_conflict."""
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def graph(self):
+        return InMemoryGraphStore()
+
+    async def _conflict(self, storage, graph):
+        """Two memories flagged as a conflict the way the detector does it."""
+        old = await storage.store_memory(
+            content="Launch is in April", tenant_id="t1", importance=0.8
+        )
+        new = await storage.store_memory(
+            content="Launch is in May",
+            tenant_id="t1",
+            importance=0.4,
+            tags=[CONFLICT_TAG],
+            metadata={CONTRADICTS_KEY: [{"memory_id": str(old), "score": 0.9}]},
+        )
+        await graph.create_edge(new, old, "contradicts", "t1")
+        return old, new
+
+    @pytest.mark.asyncio
+    async def test_supersede(self, storage, graph):
+        """Test the second memory is superseded and the conflict cleared."""
+        old, new = await self._conflict(storage, graph)
+
+        result = await resolve_conflict(
+            new, old, "supersede", "t1", storage, graph_store=graph
+        )
+
+        assert result.kept_ids == [new]
+        assert result.superseded_ids == [old]
+        winner = await storage.get_memory(new, "t1")
+        loser = await storage.get_memory(old, "t1")
+        assert CONFLICT_TAG not in winner["tags"]
+        assert CONTRADICTS_KEY not in winner["metadata"]
+        assert winner["importance"] == 0.8
+        assert loser["metadata"][SUPERSEDED_KEY] == str(new)
+        assert SUPERSEDED_TAG in loser["tags"]
+        assert loser["importance"] == pytest.approx(0.08)
+        assert await graph.get_neighbors(new, "t1", edge_type="contradicts") == []
+        assert await graph.get_neighbors(
+            old, "t1", edge_type="superseded_by", direction="out"
+        ) == [new]
+
+    @pytest.mark.asyncio
+    async def test_merge(self, storage, graph):
+        """Test both memories are superseded by the merged one."""
+        old, new = await self._conflict(storage, graph)
+        merged = await storage.store_memory(
+            content="Launch moved from April to May", tenant_id="t1"
+        )
+
+        result = await resolve_conflict(
+            old,
+            new,
+            ConflictResolution.MERGE,
+            "t1",
+            storage,
+            graph_store=graph,
+            merged_id=merged,
+        )
+
+        assert result.merged_id == merged
+        assert result.kept_ids == [merged]
+        assert sorted(result.superseded_ids) == sorted([old, new])
+        for memory_id in (old, new):
+            memory = await storage.get_memory(memory_id, "t1")
+            assert memory["metadata"][SUPERSEDED_KEY] == str(merged)
+        assert (await storage.get_memory(merged, "t1"))["importance"] == 0.8
+        assert sorted(
+            await graph.get_neighbors(
+                merged, "t1", edge_type="superseded_by", direction="in"
+            )
+        ) == sorted([old, new])
+
+        with pytest.raises(ValidationError):
+            await resolve_conflict(old, new, "merge", "t1", storage)
+
+    @pytest.mark.asyncio
+    async def test_keep_both(self, storage, graph):
+        """Test keep_both only clears the conflict."""
+        old, new = await self._conflict(storage, graph)
+
+        result = await resolve_conflict(
+            old, new, "keep_both", "t1", storage, graph_store=graph
+        )
+
+        assert result.superseded_ids == []
+        for memory_id in (old, new):
+            memory = await storage.get_memory(memory_id, "t1")
+            assert SUPERSEDED_KEY not in memory["metadata"]
+            assert CONFLICT_TAG not in memory["tags"]
+        assert (await storage.get_memory(old, "t1"))["importance"] == 0.8
+        assert await graph.get_neighbors(new, "t1", edge_type="contradicts") == []
+
+    @pytest.mark.asyncio
+    async def test_invalid_pairs(self, storage, graph):
+        """Test unknown memories and self-conflicts are refused."""
+        old, new = await self._conflict(storage, graph)
+
+        with pytest.raises(NotFoundError):
+            await resolve_conflict(old, new, "supersede", "t2", storage)
+        with pytest.raises(ValidationError):
+            await resolve_conflict(old, old, "supersede", "t1", storage)
+        with pytest.raises(ValueError):
+            await resolve_conflict(old, new, "ignore", "t1", storage)
+
+    def test_merged_fields(self):
+        """Test the merged memory's arguments combine both memories."""
+        fields = merged_fields(
+            [
+                {"id": 1, "content": "a", "tags": ["x", CONFLICT_TAG], "layer": "l"},
+                {"id": 2, "content": "b", "tags": ["y"], "importance": 0.9},
+            ]
+        )
+
+        assert fields["content"] == "a\nb"
+        assert fields["tags"] == ["x", "y"]
+        assert fields["importance"] == 0.9
+        assert fields["layer"] == "l"
+        assert fields["metadata"] == {"merged_from": ["1", "2"]}
+        assert merged_fields([{"id": 1}], "c")["content"] == "c"
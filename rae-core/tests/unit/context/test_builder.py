@@ -132,6 +132,33 @@ class TestContextBuilder:
         assert "**Tags:**" in context
         assert metadata.statistics["format"] == ContextFormat.DETAILED
 
+    def test_build_context_flags_uncertain_memories(self, builder):
+        """Test low-confidence memories are marked or left out."""
+        memories = [
+            {"id": str(uuid4()), "content": "Sure fact", "importance": 0.5},
+            {
+                "id": str(uuid4()),
+                "content": "Shaky fact",
+                "importance": 0.5,
+                "metadata": {"confidence": 0.2},
+            },
+        ]
+
+        context, metadata = builder.build_context(memories)
+        structured, _ = builder.build_context(
+            memories, format_type=ContextFormat.STRUCTURED
+        )
+        filtered, filtered_metadata = builder.build_context(
+            memories, min_confidence=0.5
+        )
+
+        assert "- [Uncertain] Shaky fact" in context
+        assert "- Sure fact" in context
+        assert metadata.statistics["uncertain_items"] == 1
+        assert "Confidence: 0.20 (uncertain)" in structured
+        assert "Shaky fact" not in filtered
+        assert filtered_metadata.statistics["low_confidence_dropped"] == 1
+
     def test_build_context_without_metadata(self, builder, sample_memories):
         """Test context building without metadata."""
         context, metadata = builder.build_context(
@@ -0,0 +1,123 @@
+"""Unit tests for memory card rendering."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.context.cards import MemoryCardRenderer, lineage_ids
+from rae_core.models.memory import MemoryItem, MemoryLayer
+
+
+class StubSummarizer:
+    """Summarizer recording its calls."""
+
+    def __init__(self):
+        self.calls = []
+
+    async def summarize(self, text, max_length=200):
+        self.calls.append(max_length)
+        return f"summary {max_length}"
+
+
+class TestMemoryCardRenderer:
+    """Test suite for MemoryCardRenderer."""
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.mark.asyncio
+    async def test_card_with_lineage(self, storage):
+        """Test a reflection's card lists its sources and confidence."""
+        first = await storage.store_memory(
+            content="User asked for Python examples",
+            layer="episodic",
+            tenant_id="t1",
+            agent_id="a1",
+        )
+        second = await storage.store_memory(
+            content="User rewrote the service in Python",
+            layer="episodic",
+            tenant_id="t1",
+            agent_id="a1",
+        )
+        reflection = await storage.store_memory(
+            content="User prefers Python. " * 30,
+            layer="reflective",
+            tenant_id="t1",
+            agent_id="a1",
+            tags=["preference"],
+            metadata={
+                "source_memory_ids": [str(first), str(second), str(uuid4())],
+                "confidence": 0.8,
+                "annotations": ["confirmed by user"],
+                "source": "reflection",
+            },
+        )
+        summarizer = StubSummarizer()
+        renderer = MemoryCardRenderer(storage, summarizer=summarizer)
+
+        card = await renderer.render(await storage.get_memory(reflection, "t1"), "t1")
+
+        assert card.memory_id == reflection
+        assert card.title == "summary 60"
+        assert card.summary == "summary 280"
+        assert [s.memory_id for s in card.sources] == [first, second]
+        assert card.sources[0].excerpt == "User asked for Python examples"
+        assert card.confidence == 0.8
+        assert card.annotations == ["confirmed by user"]
+        assert card.origin == "reflection"
+        assert card.tags == ["preference"]
+        data = card.model_dump(mode="json")
+        assert data["memory_id"] == str(reflection)
+        assert data["created_at"] is not None
+
+    @pytest.mark.asyncio
+    async def test_short_content_not_summarized(self, storage):
+        """Test content that fits is shown as is, from a MemoryItem too."""
+        summarizer = StubSummarizer()
+        renderer = MemoryCardRenderer(storage, summarizer=summarizer)
+        item = MemoryItem(
+            content="Lives in Warsaw",
+            layer=MemoryLayer.SEMANTIC,
+            tenant_id="t1",
+            agent_id="a1",
+        )
+
+        card = await renderer.render(item, "t1")
+
+        assert card.title == card.summary == "Lives in Warsaw"
+        assert card.layer == "semantic"
+        assert card.sources == []
+        assert card.confidence is None
+        assert summarizer.calls == []
+
+    @pytest.mark.asyncio
+    async def test_default_summarizer_clips(self, storage):
+        """Test the extractive fallback never exceeds the title length."""
+        renderer = MemoryCardRenderer(storage, title_length=20)
+        item = MemoryItem(
+            content="A single very long sentence without any stop " * 5,
+            layer=MemoryLayer.EPISODIC,
+            tenant_id="t1",
+            agent_id="a1",
+        )
+
+        card = await renderer.render(item, "t1")
+
+        assert 0 < len(card.title) <= 20
+
+    def test_lineage_ids(self):
+        """Test lineage keys are merged, deduplicated and validated."""
+        a, b = uuid4(), uuid4()
+        metadata = {
+            "source_memory_ids": [str(a), "not-a-uuid"],
+            "derived_from_episodic": str(a),
+        }
+        assert lineage_ids(metadata) == [a]
+        assert lineage_ids({"derived_from_episodic": str(b)}) == [b]
+        assert lineage_ids({}) == []
+        origin = {"origin": {"type": "reflection", "derived_from": [str(b)]}}
+        assert lineage_ids({**metadata, **origin}) == [a, b]
+        assert lineage_ids({"origin": "l4_cognitive"}) == []
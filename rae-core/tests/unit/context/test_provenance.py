@@ -0,0 +1,80 @@
+"""Unit tests for provenance tracing."""
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.context.provenance import trace_origins
+from rae_core.models.provenance import ORIGIN_KEY, MemorySource, SourceType
+
+
+def _origin(source_type, *parents, **kwargs):
+    source = MemorySource(type=source_type, derived_from=list(parents), **kwargs)
+    return {ORIGIN_KEY: source.as_metadata()}
+
+
+class TestTraceOrigins:
+    """Test suite for trace_origins."""
+
+    @pytest.mark.asyncio
+    async def test_follows_lineage_to_the_roots(self):
+        """Test a reflection is traced through a summary to its messages."""
+        storage = InMemoryStorage()
+        message = await storage.store_memory(
+            content="I moved to Oslo",
+            tenant_id="t1",
+            metadata=_origin(SourceType.USER_MESSAGE, ref="chat-1"),
+        )
+        tool = await storage.store_memory(
+            content="Weather in Oslo: rain",
+            tenant_id="t1",
+            metadata=_origin(SourceType.TOOL_OUTPUT, url="https://weather.test"),
+        )
+        summary = await storage.store_memory(
+            content="User lives in rainy Oslo",
+            tenant_id="t1",
+            metadata=_origin(SourceType.CONSOLIDATION, message, tool),
+        )
+        # Older reflections only record source_memory_ids
+        reflection = await storage.store_memory(
+            content="User may like indoor activities",
+            tenant_id="t1",
+            metadata={"source_memory_ids": [str(summary), str(message)]},
+        )
+
+        steps = await trace_origins(reflection, "t1", storage)
+
+        assert [(s.memory_id, s.depth) for s in steps] == [
+            (summary, 1),
+            (message, 1),
+            (tool, 2),
+        ]
+        by_id = {s.memory_id: s for s in steps}
+        assert by_id[summary].root is False
+        assert by_id[message].root is True
+        assert by_id[message].source.type == SourceType.USER_MESSAGE
+        assert by_id[tool].derived_for == summary
+        assert by_id[tool].source.label == "https://weather.test"
+
+    @pytest.mark.asyncio
+    async def test_graph_edges_and_missing_sources(self):
+        """Test derived_from edges are followed and deleted sources flagged."""
+        storage = InMemoryStorage()
+        graph = InMemoryGraphStore()
+        source = await storage.store_memory(content="raw", tenant_id="t1")
+        gone = await storage.store_memory(content="gone", tenant_id="t1")
+        derived = await storage.store_memory(
+            content="derived",
+            tenant_id="t1",
+            metadata=_origin(SourceType.REFLECTION, gone),
+        )
+        await graph.create_edge(derived, source, "derived_from", "t1")
+        await storage.delete_memory(gone, "t1")
+
+        steps = await trace_origins(derived, "t1", storage)
+        assert [s.memory_id for s in steps] == [gone]
+
+        steps = await trace_origins(derived, "t1", storage, graph_store=graph)
+
+        assert {s.memory_id: s.missing for s in steps} == {gone: True, source: False}
+        assert await trace_origins(derived, "t2", storage, graph_store=graph) == []
@@ -0,0 +1,62 @@
+"""Unit tests for the CLIP embedding provider."""
+
+import io
+
+import pytest
+
+from rae_core.embedding.clip import ClipEmbeddingProvider
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.embedding import IMultimodalEmbeddingProvider
+
+
+class FakeClipModel:
+    """Stand-in for a sentence-transformers CLIP model."""
+
+    def __init__(self):
+        self.inputs = []
+
+    def encode(self, inputs, normalize_embeddings=False):
+        self.inputs.extend(inputs)
+        return [[1.0, 0.0] if isinstance(x, str) else [0.0, 1.0] for x in inputs]
+
+    def get_sentence_embedding_dimension(self):
+        return None
+
+
+class TestClipEmbeddingProvider:
+    """Test suite for ClipEmbeddingProvider."""
+
+    @pytest.fixture
+    def model(self):
+        return FakeClipModel()
+
+    @pytest.fixture
+    def provider(self, model):
+        return ClipEmbeddingProvider(model=model)
+
+    def test_is_multimodal(self, provider):
+        """Test the provider satisfies the multimodal interface."""
+        assert isinstance(provider, IMultimodalEmbeddingProvider)
+
+    @pytest.mark.asyncio
+    async def test_embed_text(self, provider, model):
+        """Test texts are encoded by the model."""
+        assert await provider.embed_text("a cat") == [1.0, 0.0]
+        assert await provider.embed_batch(["a", "b"]) == [[1.0, 0.0], [1.0, 0.0]]
+        assert await provider.embed_batch([]) == []
+        assert model.inputs == ["a cat", "a", "b"]
+
+    def test_dimension_is_probed(self, provider):
+        """Test the dimension is probed when the model does not report it."""
+        assert provider.get_dimension() == 2
+
+    @pytest.mark.asyncio
+    async def test_embed_image(self, provider):
+        """Test images are decoded and encoded by the model."""
+        image_module = pytest.importorskip("PIL.Image")
+        buffer = io.BytesIO()
+        image_module.new("RGB", (4, 4)).save(buffer, format="PNG")
+
+        assert await provider.embed_image(buffer.getvalue()) == [0.0, 1.0]
+        with pytest.raises(ValidationError):
+            await provider.embed_image(b"not an image")
@@ -0,0 +1,111 @@
+"""Unit tests for the embedding dimension registry."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.embedding.dimensions import (
+    DimensionCheckedVectorStore,
+    DimensionRegistry,
+)
+from rae_core.exceptions.base import DimensionMismatchError, ValidationError
+from rae_core.models.embedding import EmbeddingSchema
+
+
+class TestDimensionRegistry:
+    def test_register_and_check(self):
+        """Test a registered dimension is enforced per tenant and model."""
+        registry = DimensionRegistry()
+        schema = registry.register("t1", "default", 3)
+
+        assert registry.register("t1", "default", 3) is schema
+        registry.check("t1", "default", 3)
+        registry.check("t1", "clip", 512)
+        registry.check("t2", "default", 4)
+        with pytest.raises(DimensionMismatchError) as error:
+            registry.check("t1", "default", 4)
+
+        assert (error.value.expected, error.value.actual) == (3, 4)
+        assert error.value.model == "default"
+        assert isinstance(error.value, ValidationError)
+
+    def test_list_and_unregister(self):
+        """Test schemas are listed per tenant and can be forgotten."""
+        registry = DimensionRegistry(
+            [EmbeddingSchema(tenant_id="t1", model="dense", dimension=3)]
+        )
+        registry.register("t1", "clip", 2)
+        registry.register("t2", "dense", 5)
+
+        assert [s.model for s in registry.list_schemas("t1")] == ["clip", "dense"]
+        assert registry.unregister("t1", "clip") == 1
+        assert registry.get("t1", "clip") is None
+        assert registry.unregister("t1") == 1
+        assert registry.list_schemas("t1") == []
+        assert registry.get("t2", "dense").dimension == 5
+
+    def test_invalid_dimension(self):
+        """Test empty vectors cannot register a collection."""
+        with pytest.raises(ValidationError):
+            DimensionRegistry().register("t1", "default", 0)
+
+
+class TestDimensionCheckedVectorStore:
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def store(self, storage):
+        return DimensionCheckedVectorStore(storage, DimensionRegistry())
+
+    @pytest.mark.asyncio
+    async def test_first_vector_registers_dimension(self, storage, store):
+        """Test vectors of another size are refused before being stored."""
+        first = await storage.store_memory(content="a", tenant_id="t1")
+        second = await storage.store_memory(content="b", tenant_id="t1")
+        assert await store.store_vector(first, [1.0, 0.0], "t1")
+
+        with pytest.raises(DimensionMismatchError):
+            await store.store_vector(second, [1.0, 0.0, 0.0], "t1")
+
+        assert store.registry.get("t1").dimension == 2
+        assert await storage.get_vector(second, "t1") is None
+
+    @pytest.mark.asyncio
+    async def test_search_checks_query(self, storage, store):
+        """Test a query of the wrong size raises instead of scoring."""
+        memory_id = await storage.store_memory(content="a", tenant_id="t1")
+        await store.store_vector(memory_id, {"dense": [1.0, 0.0], "clip": [1.0]}, "t1")
+
+        hits = await store.search_similar([1.0], "t1", vector_name="clip")
+
+        assert [found for found, _ in hits] == [memory_id]
+        with pytest.raises(DimensionMismatchError):
+            await store.search_similar([1.0], "t1", vector_name="dense")
+        # Collections without vectors can be searched with any size
+        assert await store.search_similar([1.0, 0.0, 0.0], "t2") == []
+
+    @pytest.mark.asyncio
+    async def test_batch_checked_as_a_whole(self, storage, store):
+        """Test a batch mixing sizes stores nothing."""
+        ids = [await storage.store_memory(content="x", tenant_id="t1") for _ in "ab"]
+
+        with pytest.raises(DimensionMismatchError):
+            await store.batch_store_vectors(
+                [(ids[0], [1.0, 0.0], {}), (ids[1], [1.0], {})], "t1"
+            )
+
+        assert store.registry.get("t1") is None
+        assert await storage.get_vector(ids[0], "t1") is None
+        assert await store.batch_store_vectors([(ids[0], [1.0], {})], "t1") == 1
+
+    @pytest.mark.asyncio
+    async def test_other_methods_passed_through(self, storage, store):
+        """Test reads and deletes reach the wrapped store."""
+        memory_id = await storage.store_memory(content="a", tenant_id="t1")
+        await store.store_vector(memory_id, [0.0, 1.0], "t1")
+
+        assert await store.get_vector(memory_id, "t1") == [0.0, 1.0]
+        assert await store.delete_vector(memory_id, "t1")
@@ -0,0 +1,153 @@
+"""Unit tests for the hosted embedding providers and provider registry."""
+
+import pytest
+
+from rae_core.embedding.hosted import CohereEmbeddingProvider, VoyageEmbeddingProvider
+from rae_core.embedding.ollama import OllamaEmbeddingProvider
+from rae_core.embedding.registry import EmbeddingProviderRegistry
+from rae_core.exceptions.base import InfrastructureError, ValidationError
+
+
+class Response:
+    def __init__(self, body, status=200):
+        self.body = body
+        self.status = status
+
+    def raise_for_status(self):
+        if self.status >= 400:
+            raise RuntimeError(f"HTTP {self.status}")
+
+    def json(self):
+        return self.body
+
+
+class Client:
+    """Fake API answering each request with respond(payload)."""
+
+    def __init__(self, respond):
+        self.respond = respond
+        self.requests = []
+
+    async def post(self, url, json, headers):
+        self.requests.append((url, json, headers))
+        return Response(self.respond(json))
+
+
+def cohere_response(payload):
+    return {"embeddings": {"float": [[float(len(t)), 0.0] for t in payload["texts"]]}}
+
+
+def voyage_response(payload):
+    data = [
+        {"index": i, "embedding": [float(len(t)), 1.0]}
+        for i, t in enumerate(payload["input"])
+    ]
+    return {"data": list(reversed(data))}
+
+
+class TestHostedProviders:
+    """Test suite for CohereEmbeddingProvider and VoyageEmbeddingProvider."""
+
+    @pytest.mark.asyncio
+    async def test_cohere_requests(self):
+        """Test Cohere batches are split and query/document types mapped."""
+        client = Client(cohere_response)
+        provider = CohereEmbeddingProvider(api_key="k", dimension=2, client=client)
+        provider.max_batch = 2
+
+        vectors = await provider.embed_batch(["a", "bb", "ccc"])
+        assert [v[0] for v in vectors] == [1.0, 2.0, 3.0]
+        url, body, headers = client.requests[0]
+        assert url == "https://api.cohere.com/v1/embed"
+        assert body["input_type"] == "search_document"
+        assert body["texts"] == ["a", "bb"]
+        assert headers["Authorization"] == "Bearer k"
+        assert len(client.requests) == 2
+
+        await provider.embed_text("q", task_type="search_query")
+        assert client.requests[-1][1]["input_type"] == "search_query"
+
+    @pytest.mark.asyncio
+    async def test_voyage_requests(self, monkeypatch):
+        """Test Voyage results are ordered by index and keys come from env."""
+        monkeypatch.setenv("VOYAGE_API_KEY", "env-key")
+        client = Client(voyage_response)
+        provider = VoyageEmbeddingProvider(
+            model="custom-model", base_url="https://proxy.local/", client=client
+        )
+        with pytest.raises(RuntimeError, match="unknown"):
+            provider.get_dimension()
+
+        vectors = await provider.embed_batch(["a", "bb"], task_type="search_query")
+        assert vectors == [[1.0, 1.0], [2.0, 1.0]]
+        assert provider.get_dimension() == 2
+        url, body, headers = client.requests[0]
+        assert url == "https://proxy.local/v1/embeddings"
+        assert body["input_type"] == "query"
+        assert headers["Authorization"] == "Bearer env-key"
+
+    def test_known_dimensions_and_api_key(self, monkeypatch):
+        """Test known models report their dimension and keys are required."""
+        monkeypatch.delenv("COHERE_API_KEY", raising=False)
+        with pytest.raises(ValueError, match="COHERE_API_KEY"):
+            CohereEmbeddingProvider()
+        provider = VoyageEmbeddingProvider("voyage-3-lite", api_key="k")
+        assert provider.get_dimension() == 512
+
+    @pytest.mark.asyncio
+    async def test_errors(self):
+        """Test mismatched counts and dimensions raise InfrastructureError."""
+        client = Client(lambda payload: {"embeddings": [[1.0, 2.0, 3.0]]})
+        provider = CohereEmbeddingProvider(api_key="k", client=client)
+        with pytest.raises(InfrastructureError, match="expected 1024"):
+            await provider.embed_text("x")
+        with pytest.raises(InfrastructureError, match="1 embeddings for 2 texts"):
+            await provider.embed_batch(["x", "y"])
+
+
+class TestEmbeddingProviderRegistry:
+    """Test suite for EmbeddingProviderRegistry."""
+
+    def test_create_by_name(self):
+        """Test built-in providers are created from names and options."""
+        registry = EmbeddingProviderRegistry()
+        assert registry.names() == ["cohere", "ollama", "voyage"]
+
+        provider = registry.from_config(
+            {"provider": "voyage", "options": {"model": "voyage-3", "api_key": "k"}}
+        )
+        assert isinstance(provider, VoyageEmbeddingProvider)
+        assert provider.get_dimension() == 1024
+
+        with pytest.raises(ValidationError, match="Unknown embedding provider"):
+            registry.create("openai")
+        with pytest.raises(ValidationError, match="Invalid options"):
+            registry.create("ollama", temperature=0.2)
+
+    def test_custom_factories(self):
+        """Test custom factories can be registered alongside or instead."""
+        registry = EmbeddingProviderRegistry(include_builtin=False)
+        assert registry.names() == []
+        registry.register(
+            "local", lambda dimension=8: OllamaEmbeddingProvider(dimension=dimension)
+        )
+        assert registry.create("local", dimension=16).get_dimension() == 16
+
+    def test_build_manager_for_tenants(self):
+        """Test a manager is built from config and tenants pick models."""
+        registry = EmbeddingProviderRegistry()
+        manager = registry.build_manager(
+            {
+                "default": {"provider": "ollama", "options": {"dimension": 768}},
+                "cohere-en": {"provider": "cohere", "options": {"api_key": "k"}},
+            }
+        )
+        assert set(manager.providers) == {"default", "cohere-en"}
+        assert manager.get_dimension() == 768
+
+        config = manager.set_tenant_model("acme", "cohere-en")
+        assert config.dimension == 1024
+        assert manager.model_for_tenant("acme") == "cohere-en"
+
+        with pytest.raises(ValidationError, match="not configured"):
+            registry.build_manager({"x": {"provider": "ollama"}}, "default")
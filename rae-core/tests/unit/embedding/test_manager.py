@@ -0,0 +1,117 @@
+"""Unit tests for EmbeddingManager tenant model selection."""
+
+import pytest
+
+from rae_core.embedding.manager import EmbeddingManager
+from rae_core.exceptions.base import SecurityPolicyViolationError, ValidationError
+
+
+class FakeProvider:
+    def __init__(self, dimension: int):
+        self.dimension = dimension
+        self.calls: list[list[str]] = []
+
+    async def embed_text(self, text, task_type="search_document"):
+        return (await self.embed_batch([text], task_type))[0]
+
+    async def embed_batch(self, texts, task_type="search_document"):
+        self.calls.append(list(texts))
+        return [[0.1] * self.dimension for _ in texts]
+
+    def get_dimension(self):
+        return self.dimension
+
+
+class FakeClipProvider(FakeProvider):
+    async def embed_image(self, data):
+        return [0.2] * self.dimension
+
+
+class TestTenantEmbeddingModels:
+    """Test suite for per-tenant embedding model selection."""
+
+    @pytest.fixture
+    def cloud(self):
+        return FakeProvider(4)
+
+    @pytest.fixture
+    def onprem(self):
+        return FakeProvider(3)
+
+    @pytest.fixture
+    def manager(self, cloud, onprem):
+        manager = EmbeddingManager(cloud, default_model_name="cloud")
+        manager.register_provider("onprem", onprem)
+        return manager
+
+    @pytest.mark.asyncio
+    async def test_tenant_uses_its_model(self, manager, cloud, onprem):
+        """Test a pinned tenant is embedded by its own model."""
+        config = manager.set_tenant_model("acme", "onprem")
+        assert config.dimension == 3
+
+        assert len(await manager.embed_text("x", tenant_id="acme")) == 3
+        assert len(await manager.embed_text("x", tenant_id="other")) == 4
+        assert len(await manager.embed_text("x")) == 4
+        assert manager.get_dimension(tenant_id="acme") == 3
+        assert manager.model_for_tenant("other") == "cloud"
+
+    @pytest.mark.asyncio
+    async def test_exclusive_model_is_enforced(self, manager, cloud, onprem):
+        """Test content of an exclusive tenant never reaches other providers."""
+        manager.set_tenant_model("acme", "onprem")
+
+        result = await manager.generate_all_embeddings(["secret"], tenant_id="acme")
+        assert list(result) == ["onprem"]
+        assert cloud.calls == []
+
+        with pytest.raises(SecurityPolicyViolationError):
+            await manager.generate_all_embeddings(
+                ["secret"], tenant_id="acme", model_names=["cloud"]
+            )
+
+        everyone = await manager.generate_all_embeddings(["public"], tenant_id="b")
+        assert set(everyone) == {"cloud", "onprem"}
+
+    @pytest.mark.asyncio
+    async def test_non_exclusive_tenant(self, manager):
+        """Test a non-exclusive tenant keeps all vector spaces."""
+        manager.set_tenant_model("acme", "onprem", exclusive=False)
+        result = await manager.generate_all_embeddings(["x"], tenant_id="acme")
+        assert set(result) == {"cloud", "onprem"}
+        assert manager.allowed_models("acme") == ["cloud", "onprem"]
+
+    @pytest.mark.asyncio
+    async def test_dimension_bookkeeping(self, manager, onprem):
+        """Test the tenant collection dimension is tracked and enforced."""
+        manager.set_tenant_model("acme", "onprem")
+
+        with pytest.raises(ValidationError, match="3-d collection"):
+            manager.set_tenant_model("acme", "cloud")
+        manager.set_tenant_model("acme", "cloud", allow_dimension_change=True)
+        assert manager.get_tenant_config("acme").dimension == 4
+
+        # A provider silently changing its output size is caught
+        manager.set_tenant_model("acme", "onprem", allow_dimension_change=True)
+        onprem.dimension = 5
+        with pytest.raises(ValidationError, match="does not match"):
+            await manager.embed_batch(["x"], tenant_id="acme")
+
+    def test_unknown_model(self, manager):
+        """Test pinning a tenant to an unregistered model fails."""
+        with pytest.raises(ValidationError, match="Unknown embedding model"):
+            manager.set_tenant_model("acme", "missing")
+        assert manager.clear_tenant_model("acme") is False
+
+    @pytest.mark.asyncio
+    async def test_image_embeddings(self, manager):
+        """Test images are embedded by the multimodal models only."""
+        with pytest.raises(ValidationError, match="No multimodal"):
+            await manager.generate_image_embeddings(b"png")
+
+        manager.register_provider("clip", FakeClipProvider(2))
+        assert await manager.generate_image_embeddings(b"png") == {"clip": [0.2, 0.2]}
+
+        manager.set_tenant_model("acme", "onprem")
+        with pytest.raises(ValidationError, match="No multimodal"):
+            await manager.generate_image_embeddings(b"png", tenant_id="acme")
@@ -0,0 +1,114 @@
+"""Unit tests for per-model embeddings and EmbeddingMigration."""
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.embedding.manager import EmbeddingManager
+from rae_core.embedding.migration import EmbeddingMigration
+from rae_core.exceptions.base import ValidationError
+
+
+class FakeProvider:
+    """Provider embedding a text as a one-hot vector of its length."""
+
+    def __init__(self, dimension: int):
+        self.dimension = dimension
+        self.calls: list[list[str]] = []
+
+    async def embed_text(self, text, task_type="search_document"):
+        return (await self.embed_batch([text], task_type))[0]
+
+    async def embed_batch(self, texts, task_type="search_document"):
+        self.calls.append(list(texts))
+        vectors = []
+        for text in texts:
+            vector = [0.0] * self.dimension
+            vector[len(text) % self.dimension] = 1.0
+            vectors.append(vector)
+        return vectors
+
+    def get_dimension(self):
+        return self.dimension
+
+
+@pytest.fixture
+def manager():
+    manager = EmbeddingManager(FakeProvider(3), default_model_name="old")
+    manager.register_provider("new", FakeProvider(5))
+    return manager
+
+
+async def store(storage, content, **kwargs):
+    memory_id = await storage.store_memory(content=content, tenant_id="t1", **kwargs)
+    vector = [1.0, 0.0, 0.0]
+    await storage.store_vector(memory_id, {"old": vector}, "t1", {"layer": "episodic"})
+    return memory_id
+
+
+class TestPerModelVectors:
+    """Test suite for vectors keyed by embedding model."""
+
+    @pytest.mark.asyncio
+    async def test_search_and_get_select_model(self):
+        """Test vector_name picks the model's vectors without fallback."""
+        storage = InMemoryStorage()
+        memory_id = await storage.store_memory(content="x", tenant_id="t1")
+        await storage.store_vector(memory_id, {"a": [1.0, 0.0], "b": [0, 0, 1.0]}, "t1")
+
+        assert await storage.get_vector(memory_id, "t1", vector_name="b") == [0, 0, 1]
+        assert await storage.get_vector(memory_id, "t1", vector_name="c") is None
+        results = await storage.search_similar([0, 0, 1.0], "t1", vector_name="b")
+        assert [r[0] for r in results] == [memory_id]
+        assert await storage.search_similar([0, 0, 1.0], "t1", vector_name="a") == []
+
+    def test_set_default_model(self, manager):
+        """Test switching the default model returns the previous one."""
+        assert manager.set_default_model("new") == "old"
+        assert manager.default_model_name == "new"
+        assert manager.get_dimension() == 5
+        with pytest.raises(ValidationError):
+            manager.set_default_model("missing")
+
+
+class TestEmbeddingMigration:
+    """Test suite for EmbeddingMigration."""
+
+    @pytest.mark.asyncio
+    async def test_backfills_new_default_model(self, manager):
+        """Test memories get the new model's vector next to the old one."""
+        storage = InMemoryStorage()
+        ids = [await store(storage, "a" * n, layer="episodic") for n in range(1, 6)]
+        manager.set_default_model("new")
+        migration = EmbeddingMigration.for_default_model(
+            manager, storage, storage, batch_size=2
+        )
+
+        assert await migration.pending("t1") == 5
+        assert await migration.run("t1") == 5
+        assert await migration.pending("t1") == 0
+
+        assert await storage.get_vector(ids[0], "t1", vector_name="old") == [1, 0, 0]
+        assert len(await storage.get_vector(ids[0], "t1", vector_name="new")) == 5
+        provider = manager.providers["new"]
+        assert [len(batch) for batch in provider.calls] == [2, 2, 1]
+
+        # Layer metadata is carried over so filtered search still works
+        results = await storage.search_similar(
+            [0, 1.0, 0, 0, 0], "t1", layer="episodic", vector_name="new"
+        )
+        assert ids[0] in [r[0] for r in results]
+
+    @pytest.mark.asyncio
+    async def test_rerun_only_embeds_missing(self, manager):
+        """Test an interrupted migration resumes with the remaining memories."""
+        storage = InMemoryStorage()
+        await store(storage, "first")
+        provider = manager.providers["new"]
+        migration = EmbeddingMigration(storage, storage, provider, "new")
+        assert await migration.run("t1") == 1
+
+        await store(storage, "second")
+        await store(storage, "ops", metadata={"is_operational": True})
+
+        assert await migration.run("t1") == 1
+        assert provider.calls[-1] == ["second"]
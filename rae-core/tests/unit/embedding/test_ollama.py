@@ -0,0 +1,103 @@
+"""Unit tests for the Ollama embedding provider."""
+
+import asyncio
+
+import pytest
+
+from rae_core.embedding.ollama import OllamaEmbeddingProvider
+from rae_core.exceptions.base import InfrastructureError
+from rae_core.interfaces.embedding import IEmbeddingProvider
+
+
+class Response:
+    def __init__(self, status, body):
+        self.status = status
+        self.body = body
+
+    def raise_for_status(self):
+        if self.status >= 400:
+            raise RuntimeError(f"HTTP {self.status}")
+
+    def json(self):
+        return self.body
+
+
+class OllamaClient:
+    """Fake Ollama server embedding a prompt as [len(prompt), 1.0, ...]."""
+
+    def __init__(self, dimension=3):
+        self.dimension = dimension
+        self.requests = []
+        self.in_flight = 0
+        self.max_in_flight = 0
+        self.status = 200
+
+    async def post(self, url, json):
+        self.requests.append((url, json))
+        self.in_flight += 1
+        self.max_in_flight = max(self.max_in_flight, self.in_flight)
+        try:
+            await asyncio.sleep(0)
+            vector = [float(len(json["prompt"]))] + [1.0] * (self.dimension - 1)
+            return Response(self.status, {"embedding": vector})
+        finally:
+            self.in_flight -= 1
+
+
+class TestOllamaEmbeddingProvider:
+    """Test suite for OllamaEmbeddingProvider."""
+
+    @pytest.mark.asyncio
+    async def test_embed_text_and_dimension_detection(self):
+        """Test requests hit /api/embeddings and the dimension is detected."""
+        client = OllamaClient(dimension=4)
+        provider = OllamaEmbeddingProvider(
+            model="mxbai-embed-large", base_url="http://gpu-box:11434/", client=client
+        )
+        assert isinstance(provider, IEmbeddingProvider)
+        with pytest.raises(RuntimeError, match="dimension unknown"):
+            provider.get_dimension()
+
+        assert await provider.embed_text("abc") == [3.0, 1.0, 1.0, 1.0]
+        assert provider.get_dimension() == 4
+        assert client.requests[0] == (
+            "http://gpu-box:11434/api/embeddings",
+            {"model": "mxbai-embed-large", "prompt": "abc"},
+        )
+
+    @pytest.mark.asyncio
+    async def test_embed_batch_in_chunks(self):
+        """Test batches keep input order and respect batch_size."""
+        client = OllamaClient()
+        provider = OllamaEmbeddingProvider(batch_size=2, client=client)
+        texts = ["a" * n for n in range(1, 8)]
+
+        embeddings = await provider.embed_batch(texts)
+        assert [e[0] for e in embeddings] == [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]
+        assert len(client.requests) == 7
+        assert client.max_in_flight == 2
+        assert await provider.embed_batch([]) == []
+
+    @pytest.mark.asyncio
+    async def test_detect_dimension(self):
+        """Test the dimension can be probed before embedding anything."""
+        client = OllamaClient(dimension=5)
+        provider = OllamaEmbeddingProvider(client=client)
+        assert await provider.detect_dimension() == 5
+        assert await provider.detect_dimension() == 5
+        assert len(client.requests) == 1
+
+    @pytest.mark.asyncio
+    async def test_errors(self):
+        """Test server errors and dimension changes raise InfrastructureError."""
+        client = OllamaClient(dimension=3)
+        provider = OllamaEmbeddingProvider(dimension=4, client=client)
+        with pytest.raises(InfrastructureError, match="expected 4"):
+            await provider.embed_text("x")
+
+        client.status = 500
+        with pytest.raises(InfrastructureError, match="request failed"):
+            await provider.embed_text("x")
+
+        with pytest.raises(ValueError):
+            OllamaEmbeddingProvider(batch_size=0)
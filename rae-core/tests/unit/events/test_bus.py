@@ -0,0 +1,136 @@
+"""Unit tests for MemoryEventBus and EventPublishingStorage."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.events import (
+    EventPublishingStorage,
+    MemoryDeleted,
+    MemoryEvent,
+    MemoryEventBus,
+    MemoryPromoted,
+    MemoryStored,
+    MemoryUpdated,
+)
+
+
+class TestMemoryEventBus:
+    """Test suite for subscription and dispatch."""
+
+    @pytest.mark.asyncio
+    async def test_dispatch_by_type(self):
+        """Test handlers receive their event type and its subclasses."""
+        bus = MemoryEventBus()
+        stored, everything = [], []
+
+        async def on_stored(event):
+            stored.append(event)
+
+        bus.subscribe(MemoryStored, on_stored)
+        bus.subscribe(MemoryEvent, everything.append)
+
+        memory_id = uuid4()
+        await bus.publish(
+            MemoryStored(
+                tenant_id="t", memory_id=memory_id, layer="episodic", content="x"
+            )
+        )
+        await bus.publish(MemoryDeleted(tenant_id="t", memory_id=memory_id))
+
+        assert [e.memory_id for e in stored] == [memory_id]
+        assert [type(e) for e in everything] == [MemoryStored, MemoryDeleted]
+        assert bus.subscriber_count(MemoryStored) == 2
+        assert bus.subscriber_count(MemoryDeleted) == 1
+
+    @pytest.mark.asyncio
+    async def test_unsubscribe_and_decorator(self):
+        """Test decorator registration and unsubscribing."""
+        bus = MemoryEventBus()
+        seen = []
+
+        @bus.on(MemoryDeleted)
+        def on_deleted(event):
+            seen.append(event)
+
+        subscription = bus.subscribe(MemoryDeleted, seen.append)
+        subscription.unsubscribe()
+
+        await bus.publish(MemoryDeleted(tenant_id="t", memory_id=uuid4()))
+        assert len(seen) == 1
+
+    @pytest.mark.asyncio
+    async def test_failing_handler_isolated(self):
+        """Test one failing handler does not block the others."""
+        bus = MemoryEventBus()
+        seen = []
+
+        def broken(event):
+            raise RuntimeError("boom")
+
+        bus.subscribe(MemoryEvent, broken)
+        bus.subscribe(MemoryEvent, seen.append)
+
+        event = MemoryDeleted(tenant_id="t", memory_id=uuid4())
+        assert await bus.publish(event) == 1
+        assert seen == [event]
+
+        strict = MemoryEventBus(raise_errors=True)
+        strict.subscribe(MemoryEvent, broken)
+        with pytest.raises(RuntimeError):
+            await strict.publish(event)
+
+
+class TestEventPublishingStorage:
+    """Test suite for the event publishing storage decorator."""
+
+    @pytest.fixture
+    def setup(self):
+        bus = MemoryEventBus()
+        events = []
+        bus.subscribe(MemoryEvent, events.append)
+        return EventPublishingStorage(InMemoryStorage(), bus), events
+
+    @pytest.mark.asyncio
+    async def test_lifecycle_events(self, setup):
+        """Test store, update, promote and delete publish events."""
+        storage, events = setup
+        memory_id = await storage.store_memory(
+            content="User likes tea", tenant_id="t", agent_id="a", layer="working"
+        )
+        await storage.update_memory(memory_id, "t", {"importance": 0.9})
+        await storage.update_memory(memory_id, "t", {"layer": "semantic"})
+        await storage.soft_delete_memory(memory_id, "t")
+        await storage.delete_memory(memory_id, "t")
+
+        assert [type(e) for e in events] == [
+            MemoryStored,
+            MemoryUpdated,
+            MemoryUpdated,
+            MemoryPromoted,
+            MemoryDeleted,
+            MemoryDeleted,
+        ]
+        assert events[0].layer == "working"
+        assert events[1].changes == {"importance": 0.9}
+        assert (events[3].from_layer, events[3].to_layer) == ("working", "semantic")
+        assert events[3].agent_id == "a"
+        assert [e.soft for e in events[4:]] == [True, False]
+
+    @pytest.mark.asyncio
+    async def test_failed_writes_publish_nothing(self, setup):
+        """Test no events for writes that did not happen."""
+        storage, events = setup
+        missing = uuid4()
+
+        assert await storage.update_memory(missing, "t", {"content": "x"}) is False
+        assert await storage.delete_memory(missing, "t") is False
+        assert events == []
+
+    @pytest.mark.asyncio
+    async def test_other_calls_forwarded(self, setup):
+        """Test non-write methods reach the wrapped storage."""
+        storage, _ = setup
+        await storage.store_memory(content="x", tenant_id="t")
+        assert await storage.count_memories("t") == 1
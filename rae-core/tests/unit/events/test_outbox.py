@@ -0,0 +1,225 @@
+"""Unit tests for the event outbox and its dispatcher."""
+
+import json
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.outbox import InMemoryOutboxStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.events import (
+    EventPublishingStorage,
+    KafkaSink,
+    MemoryDeleted,
+    MemoryStored,
+    OutboxDispatcher,
+    WebhookSink,
+)
+
+
+def stored_event(tenant_id: str = "t1", content: str = "hello") -> MemoryStored:
+    return MemoryStored(
+        tenant_id=tenant_id, memory_id=uuid4(), layer="episodic", content=content
+    )
+
+
+class RecordingSink:
+    """Sink recording sequences; fails while `failing` is set."""
+
+    def __init__(self):
+        self.sequences: list[int] = []
+        self.failing = False
+
+    async def __call__(self, entry):
+        if self.failing:
+            raise ConnectionError("sink down")
+        self.sequences.append(entry.sequence)
+
+
+class FakeClock:
+    def __init__(self):
+        self.now = 0.0
+
+    def __call__(self) -> float:
+        return self.now
+
+
+class TestInMemoryOutboxStore:
+    """Test suite for InMemoryOutboxStore."""
+
+    @pytest.mark.asyncio
+    async def test_append_read_and_cursors(self):
+        """Test entries are sequenced and read after a cursor."""
+        outbox = InMemoryOutboxStore()
+        events = [stored_event(content=f"m{i}") for i in range(3)]
+        assert await outbox.append(events) == [1, 2, 3]
+
+        entries = await outbox.read(1)
+        assert [e.sequence for e in entries] == [2, 3]
+        assert entries[0].event_type == "MemoryStored"
+        assert entries[0].event_id == events[1].event_id
+        assert entries[0].payload["content"] == "m1"
+
+        assert await outbox.get_cursor("hook") == 0
+        await outbox.set_cursor("hook", 2)
+        assert await outbox.get_cursor("hook") == 2
+
+    @pytest.mark.asyncio
+    async def test_sequences_not_reused_after_prune(self):
+        """Test pruning keeps sequences increasing."""
+        outbox = InMemoryOutboxStore()
+        await outbox.append([stored_event(), stored_event()])
+        assert await outbox.prune(2) == 2
+        assert await outbox.publish(stored_event()) == 3
+
+
+class TestOutboxDispatcher:
+    """Test suite for OutboxDispatcher."""
+
+    @pytest.fixture
+    def outbox(self):
+        return InMemoryOutboxStore()
+
+    @pytest.mark.asyncio
+    async def test_storage_writes_reach_sinks(self, outbox):
+        """Test writes through EventPublishingStorage are delivered in order."""
+        storage = EventPublishingStorage(InMemoryStorage(), outbox)
+        memory_id = await storage.store_memory(
+            content="note", layer="episodic", tenant_id="t1", agent_id="a"
+        )
+        await storage.delete_memory(memory_id, "t1")
+
+        sink = RecordingSink()
+        dispatcher = OutboxDispatcher(outbox, {"hook": sink})
+        assert await dispatcher.dispatch_once() == {"hook": 2}
+        assert sink.sequences == [1, 2]
+        assert await dispatcher.dispatch_once() == {"hook": 0}
+
+        entries = await outbox.read(0)
+        assert [e.event_type for e in entries] == ["MemoryStored", "MemoryDeleted"]
+
+    @pytest.mark.asyncio
+    async def test_failing_sink_retried_without_loss(self, outbox):
+        """Test a failing sink resumes at its cursor after backoff."""
+        await outbox.append([stored_event(), stored_event()])
+        clock = FakeClock()
+        healthy, flaky = RecordingSink(), RecordingSink()
+        flaky.failing = True
+        dispatcher = OutboxDispatcher(
+            outbox,
+            {"healthy": healthy, "flaky": flaky},
+            retry_backoff=5.0,
+            clock=clock,
+        )
+
+        assert await dispatcher.dispatch_once() == {"healthy": 2, "flaky": 0}
+        assert dispatcher.failures("flaky") == 1
+        assert await outbox.get_cursor("flaky") == 0
+
+        # Waiting out the backoff; the healthy sink keeps going meanwhile
+        flaky.failing = False
+        await outbox.publish(stored_event())
+        assert await dispatcher.dispatch_once() == {"healthy": 1}
+
+        clock.now = 5.0
+        assert await dispatcher.dispatch_once() == {"healthy": 0, "flaky": 3}
+        assert flaky.sequences == [1, 2, 3]
+        assert dispatcher.failures("flaky") == 0
+
+    @pytest.mark.asyncio
+    async def test_backoff_grows_and_is_capped(self, outbox):
+        """Test retry delays double up to max_backoff."""
+        await outbox.publish(stored_event())
+        clock = FakeClock()
+        sink = RecordingSink()
+        sink.failing = True
+        dispatcher = OutboxDispatcher(
+            outbox, {"s": sink}, retry_backoff=1.0, max_backoff=3.0, clock=clock
+        )
+
+        retry_times = []
+        for _ in range(4):
+            clock.now = dispatcher._retry_at.get("s", 0.0)
+            await dispatcher.dispatch_once()
+            retry_times.append(dispatcher._retry_at["s"] - clock.now)
+        assert retry_times == [1.0, 2.0, 3.0, 3.0]
+
+    @pytest.mark.asyncio
+    async def test_prune_keeps_unacknowledged(self, outbox):
+        """Test pruning stops at the slowest sink's cursor."""
+        await outbox.append([stored_event() for _ in range(3)])
+        fast, slow = RecordingSink(), RecordingSink()
+        dispatcher = OutboxDispatcher(outbox, {"fast": fast, "slow": slow})
+        await dispatcher.dispatch_once()
+        await outbox.set_cursor("slow", 1)
+
+        assert await dispatcher.prune() == 1
+        assert [e.sequence for e in await outbox.read(0)] == [2, 3]
+
+
+class TestSinks:
+    """Test suite for the webhook and Kafka sinks."""
+
+    @pytest.mark.asyncio
+    async def test_webhook_sink(self):
+        """Test events are posted with dedup headers; errors propagate."""
+
+        class Response:
+            def __init__(self, status):
+                self.status = status
+
+            def raise_for_status(self):
+                if self.status >= 400:
+                    raise RuntimeError(f"HTTP {self.status}")
+
+        class Client:
+            def __init__(self):
+                self.requests = []
+                self.status = 200
+
+            async def post(self, url, json, headers):
+                self.requests.append((url, json, headers))
+                return Response(self.status)
+
+        outbox = InMemoryOutboxStore()
+        event = MemoryDeleted(tenant_id="t1", memory_id=uuid4(), soft=True)
+        await outbox.publish(event)
+        (entry,) = await outbox.read(0)
+
+        client = Client()
+        sink = WebhookSink(
+            "https://hooks.example/rae", {"Authorization": "Bearer x"}, client=client
+        )
+        await sink(entry)
+        url, body, headers = client.requests[0]
+        assert url == "https://hooks.example/rae"
+        assert body["event_type"] == "MemoryDeleted"
+        assert body["event"]["soft"] is True
+        assert headers["X-RAE-Event-Id"] == str(event.event_id)
+        assert headers["Authorization"] == "Bearer x"
+
+        client.status = 503
+        with pytest.raises(RuntimeError):
+            await sink(entry)
+
+    @pytest.mark.asyncio
+    async def test_kafka_sink(self):
+        """Test events are produced keyed by tenant."""
+
+        class Producer:
+            def __init__(self):
+                self.sent = []
+
+            async def send_and_wait(self, topic, value, key):
+                self.sent.append((topic, value, key))
+
+        outbox = InMemoryOutboxStore()
+        await outbox.publish(stored_event(tenant_id="acme"))
+        (entry,) = await outbox.read(0)
+
+        producer = Producer()
+        await KafkaSink(producer, "rae.events")(entry)
+        topic, value, key = producer.sent[0]
+        assert topic == "rae.events"
+        assert key == b"acme"
+        assert json.loads(value)["sequence"] == 1
@@ -0,0 +1,80 @@
+"""Unit tests for backend error conversion."""
+
+import sqlite3
+
+import pytest
+
+from rae_core.adapters.sqlite.storage import SQLiteStorage
+from rae_core.exceptions.backend import backend_errors, translate_backend_error
+from rae_core.exceptions.base import (
+    BackendUnavailableError,
+    ConflictError,
+    NotFoundError,
+    QuotaExceededError,
+    RAEError,
+    StorageError,
+)
+
+
+class UniqueViolationError(Exception):
+    """Stand-in for asyncpg.exceptions.UniqueViolationError."""
+
+
+class CannotConnectNowError(Exception):
+    """Stand-in for asyncpg.exceptions.CannotConnectNowError."""
+
+
+class TestBackendErrors:
+    """Test suite for translate_backend_error and backend_errors."""
+
+    @pytest.mark.parametrize(
+        ("exc", "expected"),
+        [
+            (sqlite3.IntegrityError("UNIQUE constraint failed"), ConflictError),
+            (sqlite3.OperationalError("database is locked"), BackendUnavailableError),
+            (sqlite3.OperationalError("no such table: x"), StorageError),
+            (sqlite3.DatabaseError("file is not a database"), StorageError),
+            (UniqueViolationError("duplicate key"), ConflictError),
+            (CannotConnectNowError("starting up"), BackendUnavailableError),
+            (ConnectionRefusedError("refused"), BackendUnavailableError),
+            (TimeoutError(), BackendUnavailableError),
+        ],
+    )
+    def test_translate(self, exc, expected):
+        """Test driver exceptions map onto the RAE error hierarchy."""
+        translated = translate_backend_error(exc, "db")
+        assert type(translated) is expected
+
+    def test_unrecognised_errors_are_not_translated(self):
+        """Test programming errors and RAE errors are left alone."""
+        assert translate_backend_error(KeyError("x"), "db") is None
+        error = QuotaExceededError("t", "memories", 1, 1, 1)
+        assert translate_backend_error(error, "db") is error
+
+    def test_backend_errors_chains_original(self):
+        """Test the context manager raises the translation from the original."""
+        with pytest.raises(BackendUnavailableError) as exc:
+            with backend_errors("sqlite"):
+                raise sqlite3.OperationalError("database is locked")
+        assert exc.value.backend == "sqlite"
+        assert isinstance(exc.value, StorageError)
+        assert isinstance(exc.value.__cause__, sqlite3.OperationalError)
+
+        with pytest.raises(KeyError):
+            with backend_errors("sqlite"):
+                raise KeyError("x")
+
+    def test_not_found_error(self):
+        """Test NotFoundError carries the resource and is a LookupError."""
+        error = NotFoundError("Memory", "m1", "t1")
+        assert isinstance(error, RAEError)
+        assert isinstance(error, LookupError)
+        assert str(error) == "Memory m1 not found for tenant t1"
+        assert error.tenant_id == "t1"
+
+    @pytest.mark.asyncio
+    async def test_sqlite_unreachable_database(self, tmp_path):
+        """Test an unopenable SQLite database surfaces as unavailable."""
+        storage = SQLiteStorage(str(tmp_path / "missing" / "rae.db"))
+        with pytest.raises(BackendUnavailableError):
+            await storage.store_memory(content="x", tenant_id="t")
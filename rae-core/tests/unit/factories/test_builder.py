@@ -0,0 +1,112 @@
+"""Unit tests for building an engine from configuration."""
+
+import pytest
+
+from rae_core.adapters.memory.cache import InMemoryCache
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.adapters.memory.vector import InMemoryVectorStore
+from rae_core.config.backends import BackendConfig, RAEConfig
+from rae_core.embedding.manager import EmbeddingManager
+from rae_core.embedding.ollama import OllamaEmbeddingProvider
+from rae_core.exceptions.base import ValidationError
+from rae_core.factories.builder import RAEBuilder
+
+
+class StubEmbedding:
+    async def embed_text(self, text, task_type="search_document"):
+        return [1.0, 0.0]
+
+    async def embed_batch(self, texts, task_type="search_document"):
+        return [[1.0, 0.0] for _ in texts]
+
+    def get_dimension(self):
+        return 2
+
+
+class TestRAEBuilder:
+    """Test suite for RAEBuilder."""
+
+    def test_create_backends(self):
+        """Test configured backends are created with their options."""
+        builder = RAEBuilder(
+            RAEConfig(
+                graph=BackendConfig(backend="memory"),
+                cache=BackendConfig(backend="memory"),
+            )
+        )
+
+        assert isinstance(builder._backend("storage"), InMemoryStorage)
+        assert isinstance(builder._backend("vector_store"), InMemoryVectorStore)
+        assert isinstance(builder._backend("graph"), InMemoryGraphStore)
+        assert isinstance(builder._backend("cache"), InMemoryCache)
+
+    def test_unknown_backend_and_options(self):
+        """Test unknown names and bad options raise ValidationError."""
+        builder = RAEBuilder(RAEConfig())
+        with pytest.raises(ValidationError, match="known"):
+            builder.create_backend("storage", BackendConfig(backend="nope"))
+        with pytest.raises(ValidationError, match="Invalid options"):
+            builder.create_backend(
+                "graph", BackendConfig(backend="memory", options={"bogus": 1})
+            )
+
+    def test_registered_backend(self):
+        """Test custom backends can be registered by name."""
+        builder = RAEBuilder(RAEConfig(storage=BackendConfig(backend="custom")))
+        builder.register_backend("storage", "custom", lambda: "custom-storage")
+
+        assert builder._backend("storage") == "custom-storage"
+        with pytest.raises(ValueError):
+            builder.register_backend("queue", "custom", object)
+
+    def test_embedding(self):
+        """Test one model gives a provider and several an EmbeddingManager."""
+        single = RAEBuilder(
+            RAEConfig(embedding={"default": {"provider": "ollama"}})
+        ).create_embedding()
+        assert isinstance(single, OllamaEmbeddingProvider)
+
+        manager = RAEBuilder(
+            RAEConfig(
+                embedding={
+                    "default": {"provider": "ollama"},
+                    "large": {
+                        "provider": "ollama",
+                        "options": {"model": "mxbai-embed-large"},
+                    },
+                }
+            )
+        ).create_embedding()
+        assert isinstance(manager, EmbeddingManager)
+        assert set(manager.providers) == {"default", "large"}
+
+        with pytest.raises(ValidationError, match="No embedding provider"):
+            RAEBuilder(RAEConfig()).create_embedding()
+
+
+@pytest.mark.asyncio
+async def test_build_wires_engine():
+    """Test build() returns an engine over the configured backends."""
+    pytest.importorskip("numpy")
+    storage = InMemoryStorage()
+    system = await (
+        RAEBuilder(
+            RAEConfig(
+                graph=BackendConfig(backend="memory"),
+                settings={"relevance_floor": 0.4},
+            )
+        )
+        .with_storage(storage)
+        .with_embedding(StubEmbedding())
+        .build()
+    )
+
+    assert system.engine.memory_storage is storage
+    assert isinstance(system.engine.vector_store, InMemoryVectorStore)
+    assert system.engine.settings.relevance_floor == 0.4
+    assert isinstance(system.graph, InMemoryGraphStore)
+    report = await system.check_health()
+    assert report.healthy
+    assert "graph" in {c.name for c in report.components}
+    await system.close()
@@ -0,0 +1,87 @@
+"""Unit tests for the backend registry."""
+
+from types import SimpleNamespace
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.config.backends import BackendConfig, RAEConfig
+from rae_core.exceptions.base import ValidationError
+from rae_core.factories import registry as registry_module
+from rae_core.factories.builder import RAEBuilder
+from rae_core.factories.registry import BackendRegistry
+
+
+class CustomStore:
+    def __init__(self, table="memories"):
+        self.table = table
+
+
+class TestBackendRegistry:
+    """Test suite for BackendRegistry."""
+
+    def test_builtin_backends_are_imported_lazily(self):
+        """Test bundled backends resolve from their module paths."""
+        registry = BackendRegistry(discover=False)
+
+        assert "sqlite" in registry.names("storage")
+        assert isinstance(registry.create("storage", "memory"), InMemoryStorage)
+
+    def test_decorator_registration(self):
+        """Test a class registers itself under a backend name."""
+        registry = BackendRegistry(include_builtin=False, discover=False)
+
+        @registry.backend("vector_store", "custom")
+        class CustomVectors:
+            pass
+
+        assert registry.names("vector_store") == ["custom"]
+        assert isinstance(registry.create("vector_store", "custom"), CustomVectors)
+        with pytest.raises(ValueError, match="kind"):
+            registry.backend("queue", "custom")
+
+    def test_unknown_names_and_invalid_options(self):
+        """Test selection errors are reported as ValidationError."""
+        registry = BackendRegistry(discover=False)
+        registry.register("storage", "custom", CustomStore)
+
+        with pytest.raises(ValidationError, match="known"):
+            registry.create("storage", "nope")
+        with pytest.raises(ValidationError, match="Invalid options"):
+            registry.create("storage", "custom", bogus=1)
+        assert registry.create("storage", "custom", table="t").table == "t"
+
+    def test_entry_points_are_discovered(self, monkeypatch):
+        """Test installed packages provide backends through entry points."""
+        path = "rae_core.adapters.memory.storage:InMemoryStorage"
+        entries = [
+            SimpleNamespace(name="storage.custom", value=path),
+            SimpleNamespace(name="malformed", value=path),
+        ]
+        monkeypatch.setattr(registry_module, "entry_points", lambda group: entries)
+        registry = BackendRegistry()
+
+        assert isinstance(registry.create("storage", "custom"), InMemoryStorage)
+        assert "malformed" not in registry.names("storage")
+
+    def test_copy_is_independent(self):
+        """Test registering on a copy leaves the original untouched."""
+        registry = BackendRegistry(discover=False)
+        copy = registry.copy()
+        copy.register("cache", "custom", object)
+
+        assert "custom" in copy.names("cache")
+        assert "custom" not in registry.names("cache")
+
+    def test_builder_selects_registered_backend(self):
+        """Test RAEBuilder creates backends from the given registry."""
+        registry = BackendRegistry(discover=False)
+        registry.register("storage", "custom", CustomStore)
+        builder = RAEBuilder(
+            RAEConfig(
+                storage=BackendConfig(backend="custom", options={"table": "t"})
+            ),
+            backend_registry=registry,
+        )
+
+        assert builder._backend("storage").table == "t"
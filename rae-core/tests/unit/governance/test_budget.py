@@ -0,0 +1,126 @@
+"""Unit tests for per-agent budgets."""
+
+import pytest
+
+from rae_core.adapters.memory.outbox import InMemoryOutboxStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.events import BudgetThresholdCrossed, MemoryEventBus
+from rae_core.governance import AgentBudgetTracker, BudgetTrackingStorage
+from rae_core.models.budget import AgentBudget, BudgetResource
+
+
+class TestAgentBudgetTracker:
+    """Test suite for AgentBudgetTracker and BudgetTrackingStorage."""
+
+    @pytest.fixture
+    def backend(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def alerts(self):
+        return []
+
+    @pytest.fixture
+    def bus(self, alerts):
+        bus = MemoryEventBus()
+        bus.subscribe(BudgetThresholdCrossed, alerts.append)
+        return bus
+
+    @pytest.mark.asyncio
+    async def test_threshold_alerts_fire_once(self, backend, bus, alerts):
+        """Test each threshold alerts when crossed and re-arms on recovery."""
+        tracker = AgentBudgetTracker(
+            backend, default_budget=AgentBudget(max_memories=4), event_bus=bus
+        )
+        storage = BudgetTrackingStorage(backend, tracker)
+
+        ids = [
+            await storage.store_memory(content=str(i), tenant_id="t", agent_id="a")
+            for i in range(4)
+        ]
+        assert [(e.threshold, e.used) for e in alerts] == [(0.8, 4), (1.0, 4)]
+        assert alerts[0].agent_id == "a"
+        assert alerts[0].memory_id == ids[3]
+        assert alerts[0].resource == "memories"
+
+        # Writes are not blocked, and alerts do not repeat
+        await storage.store_memory(content="5", tenant_id="t", agent_id="a")
+        assert len(alerts) == 2
+
+        # Dropping below a threshold re-arms it
+        for memory_id in ids[:3]:
+            await storage.delete_memory(memory_id, "t")
+        await storage.store_memory(content="6", tenant_id="t", agent_id="a")
+        assert len(alerts) == 2
+        await storage.store_memory(content="7", tenant_id="t", agent_id="a")
+        assert [e.threshold for e in alerts] == [0.8, 1.0, 0.8, 1.0]
+
+    @pytest.mark.asyncio
+    async def test_agents_tracked_separately(self, backend, bus, alerts):
+        """Test budgets apply per agent and reports cover each agent."""
+        tracker = AgentBudgetTracker(
+            backend,
+            budgets={("t", "runaway"): AgentBudget(max_bytes=10, thresholds=[1.0])},
+            event_bus=bus,
+        )
+        storage = BudgetTrackingStorage(backend, tracker)
+
+        await storage.store_memory(content="x" * 8, tenant_id="t", agent_id="calm")
+        memory_id = await storage.store_memory(
+            content="x" * 8, tenant_id="t", agent_id="runaway"
+        )
+        assert alerts == []
+        await storage.update_memory(memory_id, "t", {"content": "x" * 12})
+        assert [(e.agent_id, e.resource, e.used) for e in alerts] == [
+            ("runaway", "bytes", 12)
+        ]
+
+        reports = {r.agent_id: r for r in await tracker.reports("t")}
+        assert reports["runaway"].utilization == {BudgetResource.BYTES: 1.2}
+        assert reports["runaway"].exceeded == [BudgetResource.BYTES]
+        assert reports["calm"].bytes == 8
+        assert reports["calm"].utilization == {}
+
+    @pytest.mark.asyncio
+    async def test_usage_seeded_from_backend(self, backend):
+        """Test existing memories count and bulk deletes resync usage."""
+        for _ in range(3):
+            await backend.store_memory(content="ab", tenant_id="t", agent_id="a")
+        tracker = AgentBudgetTracker(backend)
+        storage = BudgetTrackingStorage(backend, tracker)
+
+        await storage.store_memory(content="cd", tenant_id="t", agent_id="a")
+        report = await tracker.report("t", "a")
+        assert (report.memories, report.bytes) == (4, 8)
+
+        await storage.clear_tenant("t")
+        assert (await tracker.report("t", "a")).memories == 0
+
+    @pytest.mark.asyncio
+    async def test_embedding_spend_alerts_via_outbox(self, backend):
+        """Test embedding spend is estimated and alerts reach the outbox."""
+        outbox = InMemoryOutboxStore()
+        tracker = AgentBudgetTracker(
+            backend,
+            default_budget=AgentBudget(max_embedding_spend=0.01, thresholds=[1.0]),
+            event_bus=outbox,
+            embedding_cost_per_1k_tokens=0.1,
+        )
+        storage = BudgetTrackingStorage(backend, tracker)
+
+        await storage.store_memory(
+            content="x" * 400, tenant_id="t", agent_id="a", embedding=[0.1, 0.2]
+        )
+        report = await tracker.report("t", "a")
+        assert report.embeddings == 1
+        assert report.embedding_spend == pytest.approx(0.01)
+
+        (entry,) = await outbox.read(after=0)
+        assert entry.event_type == "BudgetThresholdCrossed"
+        assert entry.payload["resource"] == "embedding_spend"
+
+    def test_budget_thresholds_validated(self):
+        """Test thresholds are positive, deduplicated and sorted."""
+        assert AgentBudget(thresholds=[1.0, 0.5, 1.0]).thresholds == [0.5, 1.0]
+        with pytest.raises(ValueError):
+            AgentBudget(thresholds=[0.0])
@@ -0,0 +1,131 @@
+"""Unit tests for ephemeral scratch memories."""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.governance.ephemeral import (
+    EPHEMERAL_KEY,
+    EphemeralPurger,
+    is_ephemeral,
+    ttl_seconds,
+)
+from rae_core.sync.snapshot import export_tenant
+from rae_core.utils.clock import DeterministicClock
+
+START = datetime(2026, 1, 1, tzinfo=timezone.utc)
+
+
+async def _store(storage, content, expires_at=None, ephemeral=True):
+    metadata = {EPHEMERAL_KEY: True} if ephemeral else {}
+    memory_id = await storage.store_memory(
+        content=content,
+        tenant_id="t1",
+        layer="working",
+        metadata=metadata,
+        expires_at=expires_at,
+    )
+    await storage.store_vector(memory_id, [0.1, 0.2], "t1")
+    return memory_id
+
+
+class TestEphemeralPurger:
+    """Test suite for EphemeralPurger."""
+
+    @pytest.fixture
+    def clock(self):
+        return DeterministicClock(START)
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def purger(self, storage, clock):
+        return EphemeralPurger(storage, vector_store=storage, clock=clock)
+
+    @pytest.mark.asyncio
+    async def test_purge_at_deadline(self, storage, purger, clock):
+        """Test the memory and its vector are deleted once the TTL passes."""
+        deadline = START + timedelta(seconds=30)
+        memory_id = await _store(storage, "OTP 482913", deadline)
+        purger.schedule(memory_id, "t1", deadline)
+        await purger.stop()
+
+        assert await purger.purge_due() == []
+        assert await storage.get_memory(memory_id, "t1") is not None
+
+        clock.set_time(deadline)
+        assert await purger.purge_due() == [memory_id]
+        assert await storage.get_memory(memory_id, "t1") is None
+        assert await storage.get_vector(memory_id, "t1") is None
+        assert purger.pending == 0
+
+    @pytest.mark.asyncio
+    async def test_failed_purge_is_retried(self, storage, purger, clock):
+        """Test a deadline is kept when the backend fails."""
+        memory_id = await _store(storage, "OTP 1", START)
+        purger.schedule(memory_id, "t1", START)
+        await purger.stop()
+        storage.delete_memory = AsyncMock(side_effect=ConnectionError("down"))
+
+        assert await purger.purge_due() == []
+        assert purger.pending == 1
+
+    @pytest.mark.asyncio
+    async def test_sweep(self, storage, purger):
+        """Test a sweep purges expired memories and schedules the rest."""
+        expired = await _store(storage, "old code", START - timedelta(minutes=1))
+        live = await _store(storage, "new code", START + timedelta(minutes=1))
+        kept = await _store(storage, "draft", START - timedelta(minutes=1), False)
+
+        assert await purger.sweep("t1") == [expired]
+        await purger.stop()
+
+        assert await storage.get_memory(live, "t1") is not None
+        assert await storage.get_memory(kept, "t1") is not None
+        assert purger.pending == 1
+
+    @pytest.mark.asyncio
+    async def test_background_task_purges(self, storage):
+        """Test schedule() purges without an explicit call."""
+        purger = EphemeralPurger(storage, vector_store=storage)
+        deadline = datetime.now(timezone.utc)
+        memory_id = await _store(storage, "OTP 2", deadline)
+
+        purger.schedule(memory_id, "t1", deadline)
+        for _ in range(50):
+            if purger.pending == 0:
+                break
+            await asyncio.sleep(0.01)
+        await purger.stop()
+
+        assert await storage.get_memory(memory_id, "t1") is None
+
+
+class TestEphemeralExclusions:
+    """Test suite for where ephemeral memories are left out."""
+
+    def test_is_ephemeral_and_ttl(self):
+        """Test the metadata marker and TTL validation."""
+        assert is_ephemeral({"metadata": {EPHEMERAL_KEY: True}})
+        assert not is_ephemeral({"metadata": {}})
+        assert ttl_seconds(timedelta(minutes=2)) == 120
+        with pytest.raises(ValueError):
+            ttl_seconds(0)
+
+    @pytest.mark.asyncio
+    async def test_snapshot_skips_ephemeral(self):
+        """Test tenant snapshots do not contain ephemeral memories."""
+        storage = InMemoryStorage()
+        await _store(storage, "OTP 3", START + timedelta(hours=1))
+        await _store(storage, "kept note", ephemeral=False)
+
+        lines = [line async for line in export_tenant(storage, "t1")]
+        body = b"".join(lines)
+
+        assert b"kept note" in body
+        assert b"OTP 3" not in body
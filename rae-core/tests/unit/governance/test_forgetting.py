@@ -0,0 +1,160 @@
+"""Unit tests for the forgetting curve and pruning."""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.governance.ephemeral import EPHEMERAL_KEY
+from rae_core.governance.forgetting import ForgettingPolicy, ForgettingPruner
+from rae_core.models.forgetting import ForgettingConfig, PruneAction
+from rae_core.utils.clock import DeterministicClock
+
+START = datetime(2026, 1, 1, tzinfo=timezone.utc)
+
+
+def _memory(days_ago, importance=0.0, access_count=0, layer="episodic"):
+    return {
+        "id": "00000000-0000-0000-0000-000000000001",
+        "layer": layer,
+        "importance": importance,
+        "access_count": access_count,
+        "created_at": START - timedelta(days=days_ago),
+    }
+
+
+class TestForgettingPolicy:
+    """Test suite for ForgettingPolicy."""
+
+    def test_half_life(self):
+        """Test an unimportant, never-accessed memory halves per half-life."""
+        policy = ForgettingPolicy(ForgettingConfig(half_life_days=10))
+
+        assert policy.retention(_memory(0), START) == pytest.approx(1.0)
+        assert policy.retention(_memory(10), START) == pytest.approx(0.5)
+        assert policy.retention(_memory(20), START) == pytest.approx(0.25)
+
+    def test_importance_and_access_slow_forgetting(self):
+        """Test important and frequently accessed memories are kept longer."""
+        policy = ForgettingPolicy()
+        plain = policy.retention(_memory(60), START)
+
+        assert policy.retention(_memory(60, importance=0.8), START) > plain
+        assert policy.retention(_memory(60, access_count=20), START) > plain
+
+    def test_last_access_resets_the_curve(self):
+        """Test retention is measured from the last access."""
+        policy = ForgettingPolicy()
+        memory = {**_memory(365), "last_accessed_at": START - timedelta(days=1)}
+
+        assert policy.retention(memory, START) > 0.9
+
+    def test_protected_memories(self):
+        """Test protected layers, important and ephemeral memories are kept."""
+        policy = ForgettingPolicy()
+        old = 3650
+
+        assert policy.forgotten([_memory(old)], START)
+        assert not policy.forgotten([_memory(old, layer="semantic")], START)
+        assert not policy.forgotten([_memory(old, importance=0.95)], START)
+        ephemeral = {**_memory(old), "metadata": {EPHEMERAL_KEY: True}}
+        assert not policy.forgotten([ephemeral], START)
+
+
+class TestForgettingPruner:
+    """Test suite for ForgettingPruner."""
+
+    @pytest.fixture
+    def clock(self):
+        return DeterministicClock(START - timedelta(days=400))
+
+    @pytest.fixture
+    def storage(self, clock):
+        return InMemoryStorage(clock=clock)
+
+    async def _seed(self, storage, clock):
+        old = await storage.store_memory(
+            content="old chatter", tenant_id="t1", layer="episodic", importance=0.1
+        )
+        await storage.store_vector(old, [0.1, 0.2], "t1")
+        fact = await storage.store_memory(
+            content="old fact", tenant_id="t1", layer="semantic", importance=0.1
+        )
+        clock.set_time(START)
+        fresh = await storage.store_memory(
+            content="fresh", tenant_id="t1", layer="episodic", importance=0.1
+        )
+        return old, fact, fresh
+
+    @pytest.mark.asyncio
+    async def test_dry_run_reports_without_pruning(self, storage, clock):
+        """Test a dry run lists forgotten memories and changes nothing."""
+        old, _, _ = await self._seed(storage, clock)
+
+        report = await ForgettingPruner(storage, clock=clock).run("t1", dry_run=True)
+
+        assert report.dry_run is True
+        assert report.scanned == 3
+        assert [m.id for m in report.forgotten] == [old]
+        assert await storage.get_memory(old, "t1") is not None
+        assert len(await storage.list_memories("t1")) == 3
+
+    @pytest.mark.asyncio
+    async def test_archive_and_delete(self, storage, clock):
+        """Test forgotten memories are trashed, or deleted with their vector."""
+        old, fact, fresh = await self._seed(storage, clock)
+
+        report = await ForgettingPruner(storage, clock=clock).run("t1")
+
+        assert report.action == PruneAction.ARCHIVE
+        remaining = {m["id"] for m in await storage.list_memories("t1")}
+        assert remaining == {fact, fresh}
+        assert await storage.restore_memory(old, "t1")
+
+        policy = ForgettingPolicy(ForgettingConfig(action=PruneAction.DELETE))
+        pruner = ForgettingPruner(storage, storage, policy=policy, clock=clock)
+        await pruner.run("t1")
+
+        assert await storage.get_memory(old, "t1") is None
+        assert await storage.get_vector(old, "t1") is None
+
+    @pytest.mark.asyncio
+    async def test_scheduled_pruning(self, storage, clock):
+        """Test start() prunes every interval until stopped."""
+        await self._seed(storage, clock)
+        pruner = ForgettingPruner(storage, clock=clock)
+        sleeps = []
+
+        async def sleep(seconds):
+            sleeps.append(seconds)
+            await asyncio.sleep(0)
+
+        pruner._sleep = sleep
+        pruner.start(["t1"])
+        for _ in range(3):
+            await asyncio.sleep(0)
+        await pruner.stop()
+
+        assert sleeps and sleeps[0] == 24 * 3600
+        assert len(await storage.list_memories("t1")) == 2
+
+    @pytest.mark.asyncio
+    async def test_cold_action_moves_memories_to_the_archive(
+        self, storage, clock, tmp_path
+    ):
+        """Test PruneAction.COLD archives forgotten memories."""
+        from rae_core.adapters.archive import FilesystemArchiveStorage
+
+        old, _, _ = await self._seed(storage, clock)
+        archive = FilesystemArchiveStorage(tmp_path)
+        policy = ForgettingPolicy(ForgettingConfig(action=PruneAction.COLD))
+
+        await ForgettingPruner(
+            storage, storage, policy=policy, clock=clock, archive=archive
+        ).run("t1")
+
+        assert await storage.get_memory(old, "t1") is None
+        assert (await archive.get(old, "t1"))["content"] == "old chatter"
+        with pytest.raises(ValueError):
+            ForgettingPruner(storage, policy=policy)
@@ -0,0 +1,120 @@
+"""Unit tests for per-tenant load shedding."""
+
+import asyncio
+
+import pytest
+
+from rae_core.exceptions.base import OverloadedError
+from rae_core.governance import LoadShedder
+from rae_core.models.load import LoadLimits, PriorityClass
+
+
+class TestLoadShedder:
+    """Test suite for LoadShedder."""
+
+    @pytest.mark.asyncio
+    async def test_queue_limit_raises_overloaded(self):
+        """Test operations past the slots and queue are rejected."""
+        shedder = LoadShedder(LoadLimits(max_concurrent=1, max_queued=1))
+        release = asyncio.Event()
+
+        async def hold():
+            async with shedder.admit("t"):
+                await release.wait()
+
+        running = asyncio.ensure_future(hold())
+        queued = asyncio.ensure_future(hold())
+        await asyncio.sleep(0)
+
+        with pytest.raises(OverloadedError) as exc:
+            async with shedder.admit("t"):
+                pass
+        assert (exc.value.tenant_id, exc.value.limit) == ("t", 1)
+        assert shedder.stats("t").rejected == 1
+
+        # Other tenants keep their own slots
+        async with shedder.admit("other"):
+            pass
+
+        release.set()
+        await asyncio.gather(running, queued)
+        assert shedder.stats("t").running[PriorityClass.INTERACTIVE] == 0
+
+    @pytest.mark.asyncio
+    async def test_interactive_before_batch(self):
+        """Test queued interactive operations are admitted before batch ones."""
+        shedder = LoadShedder(LoadLimits(max_concurrent=1))
+        order = []
+        release = asyncio.Event()
+
+        async def run(name, priority):
+            async with shedder.admit("t", priority):
+                order.append(name)
+                if name == "first":
+                    await release.wait()
+
+        tasks = [asyncio.ensure_future(run("first", "batch"))]
+        await asyncio.sleep(0)
+        tasks.append(asyncio.ensure_future(run("batch", "batch")))
+        tasks.append(asyncio.ensure_future(run("interactive", "interactive")))
+        await asyncio.sleep(0)
+        assert shedder.stats("t").queued[PriorityClass.BATCH] == 1
+
+        release.set()
+        await asyncio.gather(*tasks)
+        assert order == ["first", "interactive", "batch"]
+
+    @pytest.mark.asyncio
+    async def test_batch_share_keeps_interactive_slots(self):
+        """Test batch operations are capped at batch_max_concurrent."""
+        shedder = LoadShedder(
+            limits={"t": LoadLimits(max_concurrent=2, batch_max_concurrent=1)}
+        )
+        release = asyncio.Event()
+
+        async def hold(priority):
+            async with shedder.admit("t", priority):
+                await release.wait()
+
+        tasks = [asyncio.ensure_future(hold("batch")) for _ in range(2)]
+        await asyncio.sleep(0)
+        stats = shedder.stats("t")
+        assert stats.running[PriorityClass.BATCH] == 1
+        assert stats.queued[PriorityClass.BATCH] == 1
+
+        # The remaining slot is still free for interactive work
+        async with shedder.admit("t"):
+            pass
+
+        release.set()
+        await asyncio.gather(*tasks)
+
+    @pytest.mark.asyncio
+    async def test_nested_admission_is_reentrant(self):
+        """Test a nested admit for the same tenant reuses the held slot."""
+        shedder = LoadShedder(LoadLimits(max_concurrent=1, max_queued=0))
+
+        async with shedder.admit("t"):
+            async with shedder.admit("t"):
+                assert shedder.stats("t").running[PriorityClass.INTERACTIVE] == 1
+
+    @pytest.mark.asyncio
+    async def test_cancelled_waiter_leaves_queue(self):
+        """Test cancelling a queued operation frees its queue place."""
+        shedder = LoadShedder(LoadLimits(max_concurrent=1))
+        release = asyncio.Event()
+
+        async def hold():
+            async with shedder.admit("t"):
+                await release.wait()
+
+        running = asyncio.ensure_future(hold())
+        waiting = asyncio.ensure_future(hold())
+        await asyncio.sleep(0)
+        waiting.cancel()
+        with pytest.raises(asyncio.CancelledError):
+            await waiting
+
+        assert shedder.stats("t").queued[PriorityClass.INTERACTIVE] == 0
+        release.set()
+        await running
@@ -0,0 +1,101 @@
+"""Unit tests for tenant namespaces."""
+
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import ConflictError, NotFoundError, ValidationError
+from rae_core.governance.namespaces import NamespaceManager, validate_namespace
+from rae_core.models.namespace import NAMESPACE_KEY, memory_namespace
+
+
+class TestNamespaceManager:
+    """Test suite for NamespaceManager."""
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def vectors(self):
+        vectors = Mock()
+        vectors.delete_vector = AsyncMock(return_value=True)
+        return vectors
+
+    @pytest.fixture
+    def manager(self, storage, vectors):
+        return NamespaceManager(storage, vector_store=vectors, page_size=2)
+
+    async def _store(self, manager, content, namespace=None, tenant_id="t1"):
+        metadata = {NAMESPACE_KEY: namespace} if namespace else {}
+        return await manager.storage.store_memory(
+            content=content, tenant_id=tenant_id, metadata=metadata
+        )
+
+    @pytest.mark.asyncio
+    async def test_create_and_list(self, storage, manager):
+        """Test created and stored-in namespaces are listed with counts."""
+        await self._store(manager, "a", "work")
+        await self._store(manager, "b", "work")
+        await self._store(manager, "c", "work")
+        await self._store(manager, "d")
+        await self._store(manager, "e", "other", tenant_id="t2")
+        await manager.create("t1", "empty", description="Nothing yet")
+
+        namespaces = await manager.list_all("t1")
+
+        assert [(n.name, n.memory_count) for n in namespaces] == [
+            ("default", 1),
+            ("empty", 0),
+            ("work", 3),
+        ]
+        assert namespaces[1].description == "Nothing yet"
+        with pytest.raises(ConflictError):
+            await manager.create("t1", "work")
+        with pytest.raises(ConflictError):
+            await manager.create("t1", "default")
+
+    @pytest.mark.asyncio
+    async def test_delete_cascades(self, storage, vectors, manager):
+        """Test deleting a namespace removes its memories, vectors and nodes."""
+        graph = InMemoryGraphStore()
+        doomed = [await self._store(manager, f"w{i}", "work") for i in range(3)]
+        kept = await self._store(manager, "kept")
+        await storage.soft_delete_memory(doomed[0], "t1")
+        for memory_id in (*doomed, kept):
+            await graph.create_node(memory_id, "memory", "t1")
+
+        result = await manager.delete("t1", "work", graph_store=graph)
+
+        assert sorted(result.memory_ids) == sorted(doomed)
+        assert result.vectors_deleted == 3
+        assert result.graph_nodes_deleted == 3
+        remaining = await storage.list_memories("t1", include_deleted=True)
+        assert [m["id"] for m in remaining] == [kept]
+        assert sorted(c.args[0] for c in vectors.delete_vector.await_args_list) == (
+            sorted(doomed)
+        )
+        assert [n.name for n in await manager.list_all("t1")] == ["default"]
+
+    @pytest.mark.asyncio
+    async def test_delete_refusals(self, manager):
+        """Test the default and unknown namespaces cannot be deleted."""
+        with pytest.raises(ValidationError):
+            await manager.delete("t1", "default")
+        with pytest.raises(NotFoundError):
+            await manager.delete("t1", "missing")
+
+        manager.ensure("t1", "fresh")
+        result = await manager.delete("t1", "fresh")
+        assert result.memory_ids == []
+
+    def test_names(self):
+        """Test namespace names are validated and read from records."""
+        assert validate_namespace("team-a.v2") == "team-a.v2"
+        for name in ("", "Work", "-x", "a b", "x" * 65):
+            with pytest.raises(ValidationError):
+                validate_namespace(name)
+        assert memory_namespace({"metadata": {NAMESPACE_KEY: "work"}}) == "work"
+        assert memory_namespace({"metadata": {}}) == "default"
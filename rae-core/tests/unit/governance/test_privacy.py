@@ -0,0 +1,204 @@
+"""Unit tests for data subject reports."""
+
+from unittest.mock import AsyncMock
+from uuid import UUID, uuid4
+
+import pytest
+
+from rae_core.adapters.memory.audit import InMemoryAuditLogger
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.governance.privacy import (
+    SubjectEraser,
+    generate_subject_report,
+    references_subject,
+    render_subject_report,
+    subject_digest,
+)
+from rae_core.maintenance.bootstrap import entity_node_id
+from rae_core.models.audit import AuditOperation
+from rae_core.models.subject import ErasureMode
+
+
+class TestReferencesSubject:
+    """Test suite for subject matching."""
+
+    def test_metadata_tags_and_content(self):
+        """Test each way a memory can reference a subject."""
+        assert references_subject({"metadata": {"user_id": "u-7"}}, "u-7")
+        assert references_subject({"metadata": {"subjects": ["a", "u-7"]}}, "u-7")
+        assert references_subject({"tags": ["subject:u-7"]}, "u-7")
+        assert references_subject(
+            {"content": "Mail from ANNA@example.com"}, "anna@example.com"
+        )
+
+    def test_partial_words_do_not_match(self):
+        """Test a key inside a longer word is not a reference."""
+        assert not references_subject({"content": "Annabelle called"}, "Anna")
+        assert not references_subject({"metadata": {"user_id": "u-77"}}, "u-7")
+
+
+class TestGenerateSubjectReport:
+    """Test suite for generate_subject_report."""
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.mark.asyncio
+    async def test_report_memories_facts_and_relationships(self, storage):
+        """Test memories, facts and graph edges are collected."""
+        graph = InMemoryGraphStore()
+        episode = await storage.store_memory(
+            content="Anna asked about invoices",
+            layer="episodic",
+            tenant_id="t1",
+            agent_id="a1",
+        )
+        fact = await storage.store_memory(
+            content="Prefers email contact",
+            layer="semantic",
+            tenant_id="t1",
+            agent_id="a1",
+            metadata={"subject": "anna"},
+        )
+        deleted = await storage.store_memory(
+            content="Anna's old address",
+            layer="episodic",
+            tenant_id="t1",
+            agent_id="a1",
+        )
+        await storage.soft_delete_memory(deleted, "t1")
+        await storage.store_memory(
+            content="Bob likes tea", layer="semantic", tenant_id="t1", agent_id="a1"
+        )
+        await storage.store_memory(
+            content="Anna in another tenant",
+            layer="episodic",
+            tenant_id="t2",
+            agent_id="a1",
+        )
+        topic = uuid4()
+        for node_id in (episode, fact, topic):
+            await graph.create_node(node_id, "memory", "t1")
+        await graph.create_edge(episode, fact, "supports", "t1")
+        await graph.create_edge(fact, topic, "about", "t1", weight=0.5)
+
+        report = await generate_subject_report(
+            storage, "t1", "anna", graph_store=graph
+        )
+
+        assert {m["id"] for m in report.memories} == {episode, deleted}
+        assert [m["id"] for m in report.facts] == [fact]
+        edges = {(r.source_id, r.target_id, r.edge_type) for r in report.relationships}
+        assert edges == {(episode, fact, "supports"), (fact, topic, "about")}
+        exported = report.model_dump(mode="json")
+        assert exported["subject_key"] == "anna"
+        assert len(exported["relationships"]) == 2
+
+    @pytest.mark.asyncio
+    async def test_render_markdown(self, storage):
+        """Test the markdown rendering lists every section."""
+        fact = await storage.store_memory(
+            content="Prefers email contact",
+            layer="semantic",
+            tenant_id="t1",
+            agent_id="a1",
+            tags=["subject:anna"],
+        )
+
+        markdown = render_subject_report(
+            await generate_subject_report(storage, "t1", "anna")
+        )
+
+        assert markdown.startswith("# Subject report: anna\n")
+        assert "## Memories (0)\n\nNone." in markdown
+        assert f"- `{fact}` (semantic, created " in markdown
+        assert "Prefers email contact" in markdown
+        assert "## Relationships (0)\n\nNone." in markdown
+
+    @pytest.mark.asyncio
+    async def test_empty_key_rejected(self, storage):
+        """Test an empty subject key is rejected."""
+        with pytest.raises(ValueError):
+            await generate_subject_report(storage, "t1", " ")
+
+
+class TestSubjectEraser:
+    """Test suite for SubjectEraser."""
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def vector_store(self):
+        store = AsyncMock()
+        store.delete_vector.return_value = True
+        return store
+
+    @pytest.mark.asyncio
+    async def test_delete_across_stores(self, storage, vector_store):
+        """Test matching and entity-linked memories are deleted everywhere."""
+        graph = InMemoryGraphStore()
+        audit = InMemoryAuditLogger()
+        mention = await storage.store_memory(
+            content="Anna asked about invoices", tenant_id="t1", agent_id="a1"
+        )
+        linked = await storage.store_memory(
+            content="Prefers window seats", tenant_id="t1", agent_id="a1"
+        )
+        kept = await storage.store_memory(
+            content="Bob likes tea", tenant_id="t1", agent_id="a1"
+        )
+        entity = entity_node_id("t1", "Anna")
+        for node_id, node_type in ((mention, "memory"), (linked, "memory")):
+            await graph.create_node(node_id, node_type, "t1")
+        await graph.create_node(entity, "entity", "t1", {"name": "Anna"})
+        await graph.create_edge(linked, entity, "relates_to", "t1")
+        eraser = SubjectEraser(
+            storage, vector_store=vector_store, graph_store=graph, audit_logger=audit
+        )
+
+        result = await eraser.erase("t1", "anna", actor="dpo")
+
+        assert set(result.memory_ids) == {mention, linked}
+        assert [m["id"] for m in await storage.list_memories("t1")] == [kept]
+        assert result.vectors_deleted == 2
+        assert result.graph_nodes_deleted == 3
+        assert (await graph.get_subgraph([entity, linked], "t1"))["nodes"] == []
+        entries = await audit.query("t1")
+        assert {e.memory_id for e in entries} == {mention, linked}
+        assert {e.operation for e in entries} == {AuditOperation.ERASE}
+        assert entries[0].actor == "dpo"
+        assert entries[0].metadata["subject_digest"] == subject_digest("anna")
+        assert "anna" not in str([e.model_dump() for e in entries]).lower()
+
+    @pytest.mark.asyncio
+    async def test_anonymize_replaces_memories(self, storage, vector_store):
+        """Test anonymized copies replace the originals without the subject."""
+        embedder = AsyncMock()
+        embedder.embed_text.return_value = [0.1, 0.2]
+        original = await storage.store_memory(
+            content="Anna asked about invoices",
+            layer="semantic",
+            tenant_id="t1",
+            agent_id="a1",
+            tags=["subject:anna", "billing"],
+            metadata={"subject": "anna", "topic": "invoices"},
+        )
+        eraser = SubjectEraser(
+            storage, vector_store=vector_store, embedding_provider=embedder
+        )
+
+        result = await eraser.erase("t1", "anna", mode=ErasureMode.ANONYMIZE)
+
+        assert result.memory_ids == [original]
+        assert await storage.get_memory(original, "t1") is None
+        copy_id = result.replacements[str(original)]
+        copy = await storage.get_memory(UUID(copy_id), "t1")
+        assert copy["content"] == "[REDACTED] asked about invoices"
+        assert copy["tags"] == ["billing"]
+        assert copy["metadata"] == {"topic": "invoices"}
+        assert copy["layer"] == "semantic"
+        vector_store.store_vector.assert_awaited_once()
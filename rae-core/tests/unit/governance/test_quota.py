@@ -0,0 +1,109 @@
+"""Unit tests for per-tenant quotas."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import QuotaExceededError
+from rae_core.governance import QuotaEnforcingStorage, QuotaManager
+from rae_core.models.quota import TenantQuota
+from rae_core.utils.clock import DeterministicClock
+
+
+class TestQuotaManager:
+    """Test suite for QuotaManager and QuotaEnforcingStorage."""
+
+    @pytest.fixture
+    def backend(self):
+        return InMemoryStorage()
+
+    @pytest.mark.asyncio
+    async def test_memory_count_limit(self, backend):
+        """Test store_memory raises a typed error past the memory limit."""
+        manager = QuotaManager(backend, default_quota=TenantQuota(max_memories=2))
+        storage = QuotaEnforcingStorage(backend, manager)
+
+        first = await storage.store_memory(content="a", tenant_id="t")
+        await storage.store_memory(content="b", tenant_id="t")
+        with pytest.raises(QuotaExceededError) as exc:
+            await storage.store_memory(content="c", tenant_id="t")
+
+        assert exc.value.resource == "memories"
+        assert (exc.value.limit, exc.value.current) == (2, 2)
+        assert await backend.count_memories("t") == 2
+
+        # Other tenants are unaffected; deleting frees a slot
+        await storage.store_memory(content="x", tenant_id="t2")
+        await storage.delete_memory(first, "t")
+        await storage.store_memory(content="c", tenant_id="t")
+
+    @pytest.mark.asyncio
+    async def test_byte_limit_on_store_and_update(self, backend):
+        """Test content size is charged on store and update."""
+        manager = QuotaManager(backend, quotas={"t": TenantQuota(max_bytes=10)})
+        storage = QuotaEnforcingStorage(backend, manager)
+
+        memory_id = await storage.store_memory(content="12345", tenant_id="t")
+        with pytest.raises(QuotaExceededError):
+            await storage.store_memory(content="123456", tenant_id="t")
+        with pytest.raises(QuotaExceededError):
+            await storage.update_memory(memory_id, "t", {"content": "x" * 11})
+
+        assert await storage.update_memory(memory_id, "t", {"content": "x" * 10})
+        usage = await manager.get_usage("t")
+        assert (usage.memories, usage.bytes) == (1, 10)
+
+    @pytest.mark.asyncio
+    async def test_usage_seeded_from_backend(self, backend):
+        """Test pre-existing data counts against the quota."""
+        for i in range(3):
+            await backend.store_memory(content="ab", tenant_id="t")
+        manager = QuotaManager(backend, default_quota=TenantQuota(max_memories=3))
+        storage = QuotaEnforcingStorage(backend, manager)
+
+        usage = await manager.get_usage("t")
+        assert (usage.memories, usage.bytes) == (3, 6)
+        with pytest.raises(QuotaExceededError):
+            await storage.store_memory(content="c", tenant_id="t")
+
+        # Bulk deletes resync usage from the backend
+        await storage.clear_tenant("t")
+        assert (await manager.get_usage("t")).memories == 0
+
+    @pytest.mark.asyncio
+    async def test_soft_delete_and_restore(self, backend):
+        """Test trashed memories release quota and restore re-checks it."""
+        manager = QuotaManager(backend, default_quota=TenantQuota(max_memories=1))
+        storage = QuotaEnforcingStorage(backend, manager)
+
+        trashed = await storage.store_memory(content="a", tenant_id="t")
+        await storage.soft_delete_memory(trashed, "t")
+        await storage.store_memory(content="b", tenant_id="t")
+
+        with pytest.raises(QuotaExceededError):
+            await storage.restore_memory(trashed, "t")
+        await storage.delete_memory(trashed, "t")
+        assert (await manager.get_usage("t")).memories == 1
+
+    @pytest.mark.asyncio
+    async def test_embeddings_per_minute(self, backend):
+        """Test the sliding one-minute embedding window."""
+        start = datetime(2025, 1, 1, tzinfo=timezone.utc)
+        clock = DeterministicClock(start)
+        manager = QuotaManager(
+            backend,
+            default_quota=TenantQuota(embeddings_per_minute=2),
+            clock=clock,
+        )
+        storage = QuotaEnforcingStorage(backend, manager)
+
+        await storage.store_memory(content="a", tenant_id="t", embedding=[1.0])
+        await manager.consume_embeddings("t")
+        with pytest.raises(QuotaExceededError) as exc:
+            await storage.store_memory(content="b", tenant_id="t", embedding=[1.0])
+        assert exc.value.resource == "embeddings_per_minute"
+        assert (await manager.get_usage("t")).memories == 1
+
+        clock.set_time(start + timedelta(seconds=61))
+        await manager.consume_embeddings("t", count=2)
@@ -0,0 +1,63 @@
+"""Unit tests for SharingPolicy."""
+
+import pytest
+
+from rae_core.guards.sharing import SharingPolicy
+from rae_core.models.memory import MemoryItem
+from rae_core.types.enums import MemoryLayer, MemoryScope
+
+
+class TestSharingPolicy:
+    @pytest.fixture
+    def policy(self):
+        return SharingPolicy(teams={"research": ["a1", "a2"]})
+
+    def test_owner_always_reads(self, policy):
+        """Test an agent recalls its own private memories."""
+        memory = {"agent_id": "a1", "metadata": {"scope": "private"}}
+
+        assert policy.can_read(memory, "a1")
+        assert not policy.can_read(memory, "a2")
+
+    def test_team_scope(self, policy):
+        """Test TEAM memories are shared with team members only."""
+        memory = {
+            "agent_id": "a1",
+            "metadata": {"scope": "team", "team_id": "research"},
+        }
+
+        assert policy.can_read(memory, "a2")
+        assert not policy.can_read(memory, "a3")
+
+        policy.add_member("research", "a3")
+        assert policy.can_read(memory, "a3")
+        policy.remove_member("research", "a2")
+        assert not policy.can_read(memory, "a2")
+
+    def test_tenant_scope_and_default(self, policy):
+        """Test TENANT memories are shared and unscoped ones use the default."""
+        shared = {"agent_id": "a1", "metadata": {"scope": "tenant"}}
+        unscoped = {"agent_id": "a1", "metadata": {}}
+
+        assert policy.can_read(shared, "a9")
+        assert not policy.can_read(unscoped, "a9")
+        assert SharingPolicy(default_scope="tenant").can_read(unscoped, "a9")
+
+    def test_filter_memory_items(self, policy):
+        """Test filtering MemoryItem objects keeps the order."""
+        items = [
+            MemoryItem(
+                content=f"m{i}",
+                layer=MemoryLayer.EPISODIC,
+                tenant_id="t1",
+                agent_id="a1",
+                scope=scope,
+                team_id="research",
+            )
+            for i, scope in enumerate(
+                [MemoryScope.TEAM, MemoryScope.PRIVATE, MemoryScope.TENANT]
+            )
+        ]
+
+        assert [m.content for m in policy.filter(items, "a2")] == ["m0", "m2"]
+        assert policy.teams_of("a2") == {"research"}
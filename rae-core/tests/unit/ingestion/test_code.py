@@ -0,0 +1,180 @@
+"""Unit tests for code-aware ingestion."""
+
+import textwrap
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.ingestion.code import (
+    CodeIngestor,
+    CodeParser,
+    detect_language,
+    parse_python,
+)
+from rae_core.models.graph import EdgeType
+
+SOURCE = textwrap.dedent(
+    '''
+    import os
+
+
+    def load(path):
+        return parse(os.path.join("data", path))
+
+
+    def parse(text):
+        return text.split()
+
+
+    class Store:
+        """Keeps parsed items."""
+
+        limit = 10
+
+        @property
+        def size(self):
+            return len(self.items)
+
+        async def refresh(self):
+            self.items = load("items.txt")
+    '''
+).lstrip()
+
+
+@pytest.fixture
+def storage():
+    return InMemoryStorage()
+
+
+@pytest.fixture
+def engine(storage):
+    engine = Mock()
+
+    async def store_verbatim(content, tenant_id, **kwargs):
+        return await storage.store_memory(
+            content=content, tenant_id=tenant_id, **kwargs
+        )
+
+    engine.store_verbatim = AsyncMock(side_effect=store_verbatim)
+    return engine
+
+
+class TestParsing:
+    """Test suite for splitting source into symbols."""
+
+    def test_detect_language(self):
+        """Test languages are detected from extensions."""
+        assert detect_language("src/app.py") == "python"
+        assert detect_language("web/App.TSX") == "tsx"
+        assert detect_language("README.md") is None
+
+    def test_python_symbols(self):
+        """Test functions, classes and methods become symbols."""
+        symbols = {s.qualified_name: s for s in parse_python(SOURCE)}
+
+        assert list(symbols) == [
+            "load",
+            "parse",
+            "Store",
+            "Store.size",
+            "Store.refresh",
+        ]
+        load = symbols["load"]
+        assert load.kind == "function"
+        assert (load.start_line, load.end_line) == (4, 5)
+        assert load.calls == ["parse", "join"]
+        store = symbols["Store"]
+        assert store.kind == "class"
+        assert store.content.endswith("limit = 10")
+        size = symbols["Store.size"]
+        assert size.kind == "method"
+        assert size.content.lstrip().startswith("@property")
+        assert symbols["Store.refresh"].calls == ["load"]
+
+    def test_unsplittable_source(self):
+        """Test invalid Python and unsupported languages yield no symbols."""
+        parser = CodeParser(use_tree_sitter=False)
+
+        assert parser.parse("def broken(:", "python") == []
+        assert parser.parse("function f() {}", "javascript") == []
+
+    def test_tree_sitter_symbols(self):
+        """Test JavaScript is split with tree-sitter when installed."""
+        pytest.importorskip("tree_sitter_language_pack")
+        source = "class A {\n  run() { helper(); }\n}\nfunction helper() {}\n"
+
+        symbols = CodeParser().parse(source, "javascript")
+
+        by_name = {s.qualified_name: s for s in symbols}
+        assert set(by_name) == {"A", "A.run", "helper"}
+        assert by_name["A.run"].calls == ["helper"]
+
+
+class TestCodeIngestor:
+    """Test suite for CodeIngestor."""
+
+    @pytest.mark.asyncio
+    async def test_symbols_become_linked_code_memories(self, engine, storage):
+        """Test symbols are stored, tagged and linked in the graph."""
+        graph = InMemoryGraphStore()
+        ingestor = CodeIngestor(engine, graph_store=graph)
+
+        report = await ingestor.ingest_files(
+            {"app/store.py": SOURCE, "notes.txt": "todo"}, "t1", project="p1"
+        )
+
+        assert report.files == 1
+        assert report.skipped == ["notes.txt"]
+        assert len(report.memory_ids) == 5
+        load_id = report.symbols["app/store.py::load"]
+        memory = await storage.get_memory(load_id, "t1")
+        assert memory["memory_type"] == "code"
+        assert memory["layer"] == "semantic"
+        assert memory["tags"] == ["python", "load"]
+        assert memory["content"].startswith("def load(path):")
+        assert memory["metadata"]["symbol"] == "load"
+        assert memory["metadata"]["path"] == "app/store.py"
+
+        file_id = CodeIngestor.file_node_id("app/store.py", "p1")
+        assert await graph.get_neighbors(
+            load_id, "t1", EdgeType.DEFINED_IN.value, direction="out"
+        ) == [file_id]
+        assert await graph.get_neighbors(
+            load_id, "t1", EdgeType.CALLS.value, direction="out"
+        ) == [report.symbols["app/store.py::parse"]]
+        refresh_id = report.symbols["app/store.py::Store.refresh"]
+        assert await graph.get_neighbors(
+            refresh_id, "t1", EdgeType.CALLS.value, direction="out"
+        ) == [load_id]
+        # 5 defined_in edges, load -> parse and refresh -> load
+        assert report.edges == 7
+
+    @pytest.mark.asyncio
+    async def test_unsplit_files_are_stored_whole(self, engine, storage):
+        """Test files that cannot be split become one module memory."""
+        ingestor = CodeIngestor(engine, parser=CodeParser(use_tree_sitter=False))
+
+        report = await ingestor.ingest_files(
+            {"web/app.js": "console.log('hi');\n"}, "t1"
+        )
+
+        memory = await storage.get_memory(report.memory_ids[0], "t1")
+        assert memory["content"] == "console.log('hi');"
+        assert memory["metadata"]["kind"] == "module"
+        assert report.symbols == {"web/app.js::app": report.memory_ids[0]}
+
+    @pytest.mark.asyncio
+    async def test_ingest_directory(self, engine, tmp_path):
+        """Test source files under a directory are ingested."""
+        (tmp_path / "pkg").mkdir()
+        (tmp_path / "pkg" / "mod.py").write_text("def f():\n    return 1\n")
+        (tmp_path / ".venv").mkdir()
+        (tmp_path / ".venv" / "lib.py").write_text("def g():\n    return 2\n")
+        (tmp_path / "README.md").write_text("# readme\n")
+
+        report = await CodeIngestor(engine).ingest_directory(tmp_path, "t1")
+
+        assert report.files == 1
+        assert list(report.symbols) == ["pkg/mod.py::f"]
@@ -0,0 +1,145 @@
+"""Unit tests for conversation ingestion."""
+
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.ingestion.conversation import (
+    CONVERSATION_KEY,
+    SUMMARY_TAG,
+    ConversationIngestor,
+    format_turns,
+)
+from rae_core.models.conversation import ConversationIngestConfig, ConversationTurn
+from rae_core.models.graph import EdgeType
+
+
+class StubSummarizer:
+    def __init__(self):
+        self.texts = []
+
+    async def summarize(self, text, max_length=200):
+        self.texts.append(text)
+        return f"summary of {text.count(chr(10)) + 1} turns"
+
+
+@pytest.fixture
+def storage():
+    return InMemoryStorage()
+
+
+@pytest.fixture
+def engine(storage):
+    engine = Mock()
+    engine.memory_storage = storage
+
+    async def store_memory(**kwargs):
+        kwargs.pop("check_novelty", None)
+        return await storage.store_memory(**kwargs)
+
+    engine.store_memory = AsyncMock(side_effect=store_memory)
+    return engine
+
+
+def _turns(count):
+    roles = ["user", "assistant"]
+    return [
+        {
+            "role": roles[i % 2],
+            "content": f"message {i}",
+            "timestamp": datetime(2025, 6, 1, 12, i, tzinfo=timezone.utc),
+        }
+        for i in range(count)
+    ]
+
+
+class TestConversationIngestor:
+    """Test suite for ConversationIngestor."""
+
+    def test_format_turns(self):
+        """Test turns are rendered as role-prefixed lines."""
+        turns = [
+            ConversationTurn(role="user", content="weather?"),
+            ConversationTurn(role="tool", content="sunny", name="forecast"),
+        ]
+        assert format_turns(turns) == "user: weather?\ntool (forecast): sunny"
+
+    @pytest.mark.asyncio
+    async def test_turns_become_linked_working_memories(self, engine, storage):
+        """Test turns are grouped, tagged and chained by follows edges."""
+        graph = InMemoryGraphStore()
+        ingestor = ConversationIngestor(
+            engine, graph_store=graph, summarizer=StubSummarizer()
+        )
+
+        report = await ingestor.ingest(
+            _turns(5), "t1", agent_id="a1", conversation_id="c1"
+        )
+
+        assert report.session_id == "c1"
+        assert report.turns == 5
+        assert len(report.memory_ids) == 3
+        assert report.summary_ids == []
+        first = await storage.get_memory(report.memory_ids[0], "t1")
+        assert first["content"] == "user: message 0\nassistant: message 1"
+        assert first["layer"] == "working"
+        assert first["memory_type"] == "conversation"
+        assert first["session_id"] == "c1"
+        assert first["metadata"][CONVERSATION_KEY] == "c1"
+        assert first["metadata"]["roles"] == ["assistant", "user"]
+        assert first["metadata"]["turn_end"] == 1
+        assert first["metadata"]["started_at"] == "2025-06-01T12:00:00+00:00"
+        last = await storage.get_memory(report.memory_ids[2], "t1")
+        assert last["metadata"]["turn_start"] == 4
+
+        assert report.edges == 2
+        assert await graph.get_neighbors(
+            report.memory_ids[0], "t1", EdgeType.FOLLOWS.value, direction="out"
+        ) == [report.memory_ids[1]]
+
+    @pytest.mark.asyncio
+    async def test_summaries_every_n_turns(self, engine, storage):
+        """Test every N turns are summarized and linked to their sources."""
+        graph = InMemoryGraphStore()
+        summarizer = StubSummarizer()
+        ingestor = ConversationIngestor(
+            engine,
+            graph_store=graph,
+            summarizer=summarizer,
+            config=ConversationIngestConfig(turns_per_memory=1, summarize_every=4),
+        )
+
+        report = await ingestor.ingest(_turns(9), "t1", session_id="s1")
+
+        assert len(report.memory_ids) == 9
+        assert len(report.summary_ids) == 2
+        assert summarizer.texts[1].startswith("user: message 4")
+        summary = await storage.get_memory(report.summary_ids[0], "t1")
+        assert summary["content"] == "summary of 4 turns"
+        assert summary["layer"] == "episodic"
+        assert summary["tags"] == [SUMMARY_TAG]
+        assert summary["metadata"]["source_memory_ids"] == [
+            str(m) for m in report.memory_ids[:4]
+        ]
+        sources = await graph.get_neighbors(
+            report.summary_ids[0],
+            "t1",
+            EdgeType.DERIVED_FROM.value,
+            direction="out",
+        )
+        assert set(sources) == set(report.memory_ids[:4])
+        assert report.edges == 8 + 8
+
+    @pytest.mark.asyncio
+    async def test_summary_interval_override(self, engine):
+        """Test summarize_every can be given per call."""
+        ingestor = ConversationIngestor(engine, summarizer=StubSummarizer())
+
+        report = await ingestor.ingest(_turns(4), "t1", summarize_every=2)
+
+        assert len(report.memory_ids) == 2
+        assert len(report.summary_ids) == 2
+        assert report.edges == 0
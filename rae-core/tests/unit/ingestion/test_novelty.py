@@ -0,0 +1,71 @@
+"""Unit tests for the novelty gate."""
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.ingestion.novelty import NoveltyGate
+
+VECTORS = {
+    "the user likes tea": [1.0, 0.0, 0.0],
+    "the user likes tea!": [0.999, 0.02, 0.0],
+    "the deploy failed": [0.0, 1.0, 0.0],
+}
+
+
+class StubEmbedding:
+    async def embed_text(self, text, task_type="search_document"):
+        return VECTORS[text]
+
+
+@pytest.fixture
+async def store():
+    store = InMemoryStorage()
+    memory_id = await store.store_memory(
+        content="the user likes tea", tenant_id="t1", agent_id="a1"
+    )
+    await store.store_vector(
+        memory_id,
+        VECTORS["the user likes tea"],
+        "t1",
+        metadata={"agent_id": "a1", "layer": "episodic"},
+    )
+    return store
+
+
+class TestNoveltyGate:
+    """Test suite for NoveltyGate."""
+
+    @pytest.mark.asyncio
+    async def test_redundant_content_points_at_existing_memory(self, store):
+        """Test near-identical content is not novel."""
+        gate = NoveltyGate(threshold=0.05)
+        [existing] = await store.list_memories("t1")
+
+        decision = await gate.evaluate(
+            "the user likes tea!", "t1", StubEmbedding(), store, agent_id="a1"
+        )
+
+        assert decision.novel is False
+        assert decision.duplicate_of == existing["id"]
+        assert decision.novelty < 0.05
+
+    @pytest.mark.asyncio
+    async def test_new_content_and_other_scopes_are_novel(self, store):
+        """Test unrelated content, other agents and empty stores pass."""
+        gate = NoveltyGate()
+        embedding = StubEmbedding()
+
+        different = await gate.evaluate("the deploy failed", "t1", embedding, store)
+        other_agent = await gate.evaluate(
+            "the user likes tea", "t1", embedding, store, agent_id="a2"
+        )
+        other_tenant = await gate.evaluate("the user likes tea", "t2", embedding, store)
+
+        assert different.novel and different.duplicate_of is None
+        assert other_agent.novel
+        assert other_tenant.novel and other_tenant.novelty == 1.0
+
+    def test_threshold_range(self):
+        """Test thresholds outside 0-1 are rejected."""
+        with pytest.raises(ValueError):
+            NoveltyGate(threshold=1.5)
@@ -0,0 +1,71 @@
+import pytest
+
+from rae_core.ingestion.pipeline import UniversalIngestPipeline
+from rae_core.ingestion.redactor import (
+    PII_METADATA_KEY,
+    PiiRedactor,
+    RegexDetector,
+    luhn_valid,
+)
+
+
+def test_redact_email_phone_and_card():
+    redactor = PiiRedactor()
+    text = (
+        "Mail jan.kowalski@example.com or call +48 600 123 456. "
+        "Card 4111 1111 1111 1111 expires soon."
+    )
+
+    redacted, report, audit = redactor.redact(text)
+
+    assert redacted == (
+        "Mail [EMAIL] or call [PHONE]. Card [CREDIT_CARD] expires soon."
+    )
+    assert report["total"] == 3
+    assert report["counts"] == {"email": 1, "phone": 1, "credit_card": 1}
+    first = report["spans"][0]
+    assert text[first["offset"] : first["offset"] + first["length"]] == (
+        "jan.kowalski@example.com"
+    )
+    assert audit.stage == "redact"
+    assert "example.com" not in str(report) + str(audit.trace)
+
+
+def test_dates_and_invalid_cards_untouched():
+    redactor = PiiRedactor()
+    text = "2023-01-01 10:00:00 order 4111 1111 1111 1112 shipped"
+
+    redacted, report, audit = redactor.redact(text)
+
+    assert redacted == text
+    assert report["total"] == 0
+    assert audit.action == "skip"
+
+
+def test_luhn():
+    assert luhn_valid("4111-1111-1111-1111")
+    assert not luhn_valid("4111-1111-1111-1112")
+    assert not luhn_valid("")
+
+
+def test_custom_detector_and_mask():
+    redactor = PiiRedactor(detectors=[], mask="<{kind}>")
+    redactor.add_detector(RegexDetector("pesel", r"\b\d{11}\b"))
+
+    redacted, report, _ = redactor.redact("PESEL 44051401359 on file")
+
+    assert redacted == "PESEL <PESEL> on file"
+    assert report["counts"] == {"pesel": 1}
+
+
+@pytest.mark.asyncio
+async def test_pipeline_records_redactions():
+    pipeline = UniversalIngestPipeline(redactor=PiiRedactor())
+
+    chunks, _, audit_trail, _ = await pipeline.process(
+        "Contact me at anna@example.org about the invoice."
+    )
+
+    assert all("anna@example.org" not in c.content for c in chunks)
+    assert chunks[0].metadata[PII_METADATA_KEY]["counts"] == {"email": 1}
+    assert [a.stage for a in audit_trail][:2] == ["normalize", "redact"]
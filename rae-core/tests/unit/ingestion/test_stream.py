@@ -0,0 +1,150 @@
+"""Unit tests for streaming ingestion."""
+
+import asyncio
+import json
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import PermissionDeniedError
+from rae_core.ingestion.stream import CONTENT_HASH_KEY, StreamIngestor, content_hash
+
+
+@pytest.fixture
+def storage():
+    return InMemoryStorage()
+
+
+@pytest.fixture
+def engine(storage):
+    engine = Mock()
+    engine.memory_storage = storage
+    engine.pii_redactor = None
+    engine.batches = []
+
+    async def embed_documents(texts, tenant_id):
+        engine.batches.append(len(texts))
+        return [[float(len(text))] for text in texts]
+
+    async def store_embedded(content, tenant_id, embedding, **kwargs):
+        kwargs.pop("priority", None)
+        kwargs.pop("source", None)
+        kwargs.pop("confidence", None)
+        kwargs.pop("namespace", None)
+        return await storage.store_memory(
+            content=content, tenant_id=tenant_id, **kwargs
+        )
+
+    engine.embed_documents = AsyncMock(side_effect=embed_documents)
+    engine.store_embedded = AsyncMock(side_effect=store_embedded)
+    return engine
+
+
+def _lines(*items):
+    return [json.dumps(item).encode() for item in items]
+
+
+class TestStreamIngestor:
+    """Test suite for StreamIngestor."""
+
+    @pytest.mark.asyncio
+    async def test_stores_in_batches(self, storage, engine):
+        """Test items are embedded in batches and stored with their hash."""
+        items = [{"content": f"fact {i}", "tags": ["t"]} for i in range(5)]
+        ingestor = StreamIngestor(engine, batch_size=2, concurrency=2)
+
+        summary = await ingestor.ingest(_lines(*items), "t1")
+
+        assert (summary.items, summary.stored, summary.failed) == (5, 5, 0)
+        assert sorted(engine.batches) == [1, 2, 2]
+        stored = await storage.list_memories("t1")
+        assert sorted(m["content"] for m in stored) == [f"fact {i}" for i in range(5)]
+        assert stored[0]["metadata"][CONTENT_HASH_KEY] == content_hash(
+            stored[0]["content"]
+        )
+        _, kwargs = engine.store_embedded.await_args
+        assert kwargs["priority"].value == "batch"
+        assert kwargs["source"].ref == "stream"
+
+    @pytest.mark.asyncio
+    async def test_dedupes_and_reports_failures(self, storage, engine):
+        """Test copies are skipped and bad lines fail with their reason."""
+        await storage.store_memory(
+            content="already here",
+            tenant_id="t1",
+            layer="episodic",
+            metadata={CONTENT_HASH_KEY: content_hash("already here")},
+        )
+        stream = [
+            *_lines({"content": "a"}, {"content": "a"}),
+            b"",
+            b"{broken",
+            *_lines({"content": "x", "colour": "red"}, {"content": "already here"}),
+            *_lines({"content": "a", "layer": "semantic"}),
+        ]
+
+        summary = await StreamIngestor(engine).ingest(stream, "t1")
+
+        assert (summary.items, summary.stored, summary.deduped) == (6, 2, 2)
+        assert summary.failed == 2
+        assert [f.index for f in summary.failures] == [4, 5]
+        assert "Malformed JSON" in summary.failures[0].reason
+
+    @pytest.mark.asyncio
+    async def test_embedding_failure_fails_batch(self, engine):
+        """Test a failed embedding call fails its items, not the stream."""
+        engine.embed_documents.side_effect = RuntimeError("provider down")
+
+        summary = await StreamIngestor(engine, max_reported_failures=1).ingest(
+            _lines({"content": "a"}, {"content": "b"}), "t1"
+        )
+
+        assert (summary.stored, summary.failed) == (0, 2)
+        assert len(summary.failures) == 1
+        assert "provider down" in summary.failures[0].reason
+
+    @pytest.mark.asyncio
+    async def test_authorize_fails_items(self, engine):
+        """Test items refused by authorize are reported as failed."""
+
+        def authorize(item):
+            if item.layer == "semantic":
+                raise PermissionDeniedError("bot", "write", "semantic")
+
+        summary = await StreamIngestor(engine).ingest(
+            _lines({"content": "a"}, {"content": "b", "layer": "semantic"}),
+            "t1",
+            authorize=authorize,
+        )
+
+        assert (summary.stored, summary.failed) == (1, 1)
+        assert summary.failures[0].index == 2
+
+    @pytest.mark.asyncio
+    async def test_backpressure_bounds_read_ahead(self, engine):
+        """Test the stream is not read far ahead of stalled workers."""
+        release = asyncio.Event()
+        pulled = 0
+
+        async def slow_embed(texts, tenant_id):
+            await release.wait()
+            return [[1.0] for _ in texts]
+
+        async def produce():
+            nonlocal pulled
+            for i in range(1000):
+                pulled += 1
+                yield json.dumps({"content": f"item {i}"})
+
+        engine.embed_documents.side_effect = slow_embed
+        ingestor = StreamIngestor(engine, batch_size=10, concurrency=2)
+        task = asyncio.create_task(ingestor.ingest(produce(), "t1"))
+        for _ in range(50):
+            await asyncio.sleep(0)
+
+        # 2 batches in the workers, 4 queued and 1 being filled
+        assert pulled <= 10 * (2 + 4 + 1) + 1
+        release.set()
+        summary = await task
+        assert (pulled, summary.stored) == (1000, 1000)
@@ -0,0 +1,160 @@
+"""Unit tests for importing LangChain and LlamaIndex exports."""
+
+import json
+from uuid import UUID
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import ValidationError
+from rae_core.interop.importers import (
+    ExportFormat,
+    detect_format,
+    import_export,
+    parse_langchain,
+    parse_llamaindex,
+)
+
+
+@pytest.fixture
+def langchain_dump():
+    return {
+        "a1": {
+            "id": "a1",
+            "text": "Refunds take five days",
+            "vector": [0.1, 0.2, 0.3],
+            "metadata": {"source": "docs/refunds.md", "page": 2},
+        },
+        "a2": {"text": "Support is open on weekdays", "metadata": {}},
+    }
+
+
+@pytest.fixture
+def llamaindex_docstore():
+    return {
+        "docstore/data": {
+            "n1": {
+                "__type__": "1",
+                "__data__": {
+                    "id_": "n1",
+                    "text": "Invoices go out on the 1st",
+                    "metadata": {"file_name": "billing.txt"},
+                    "embedding": None,
+                },
+            },
+            "n2": {
+                "__type__": "1",
+                "__data__": json.dumps(
+                    {
+                        "id_": "n2",
+                        "text_resource": {"text": "Late fees are 2%"},
+                        "metadata": {},
+                    }
+                ),
+            },
+        },
+        "docstore/metadata": {},
+    }
+
+
+class TestParsing:
+    """Test suite for reading exports."""
+
+    def test_langchain_formats(self, langchain_dump):
+        """Test dumps, document lists and Chroma results are read."""
+        docs = parse_langchain(json.dumps(langchain_dump))
+        assert [(d.source_id, d.embedding) for d in docs] == [
+            ("a1", [0.1, 0.2, 0.3]),
+            ("a2", None),
+        ]
+        assert docs[0].metadata == {"source": "docs/refunds.md", "page": 2}
+
+        serialized = {
+            "lc": 1,
+            "type": "constructor",
+            "id": ["langchain", "schema", "document", "Document"],
+            "kwargs": {"page_content": "Hi", "metadata": {"k": "v"}},
+        }
+        docs = parse_langchain([serialized, {"page_content": "Yo", "id": 7}])
+        assert [(d.content, d.source_id) for d in docs] == [("Hi", None), ("Yo", "7")]
+
+        docs = parse_langchain(
+            {
+                "ids": ["c1", "c2"],
+                "documents": ["one", "two"],
+                "metadatas": [{"x": 1}, None],
+                "embeddings": [[1, 0], [0, 1]],
+            }
+        )
+        assert [(d.content, d.metadata, d.embedding) for d in docs] == [
+            ("one", {"x": 1}, [1.0, 0.0]),
+            ("two", {}, [0.0, 1.0]),
+        ]
+
+    def test_llamaindex_docstore(self, llamaindex_docstore):
+        """Test docstore nodes are read with embeddings from the vector store."""
+        vectors = {"embedding_dict": {"n1": [0.5, 0.5]}, "metadata_dict": {}}
+
+        docs = parse_llamaindex(llamaindex_docstore, vectors=vectors)
+
+        assert [(d.source_id, d.content, d.embedding) for d in docs] == [
+            ("n1", "Invoices go out on the 1st", [0.5, 0.5]),
+            ("n2", "Late fees are 2%", None),
+        ]
+        assert detect_format(llamaindex_docstore) == ExportFormat.LLAMAINDEX
+        assert detect_format([]) == ExportFormat.LANGCHAIN
+
+    def test_malformed_exports(self):
+        """Test malformed exports are rejected."""
+        for data in ("{not json", [{"metadata": {}}], [{"text": "x", "vector": "v"}]):
+            with pytest.raises(ValidationError):
+                parse_langchain(data)
+        with pytest.raises(ValidationError):
+            parse_llamaindex({"nodes": []})
+        with pytest.raises(ValidationError):
+            parse_langchain({"ids": ["a"], "documents": ["x", "y"]})
+
+
+class TestImportExport:
+    """Test suite for import_export."""
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.mark.asyncio
+    async def test_imports_memories_and_embeddings(self, storage, langchain_dump):
+        """Test documents become memories and exported embeddings are kept."""
+        result = await import_export(
+            storage, langchain_dump, "t1", vector_store=storage, tags=["kb"]
+        )
+
+        assert result.format == ExportFormat.LANGCHAIN
+        assert (result.memories, result.vectors) == (2, 1)
+        first = await storage.get_memory(UUID(result.id_map["a1"]), "t1")
+        assert first["content"] == "Refunds take five days"
+        assert first["metadata"]["page"] == 2
+        assert first["metadata"]["origin"] == {
+            "type": "import",
+            "ref": "langchain:a1",
+            "file": "docs/refunds.md",
+            "derived_from": [],
+        }
+        assert first["tags"] == ["kb", "imported"]
+        assert first["layer"] == "semantic"
+        vector = await storage.get_vector(first["id"], "t1")
+        assert vector == pytest.approx([0.1, 0.2, 0.3], abs=1e-3)
+        assert [str(m) for m in result.missing_embeddings] == [result.id_map["a2"]]
+
+    @pytest.mark.asyncio
+    async def test_rejects_mixed_dimensions_before_storing(self, storage):
+        """Test nothing is stored when embeddings differ in size."""
+        export = [
+            {"page_content": "a", "embedding": [1.0, 0.0]},
+            {"page_content": "b", "embedding": [1.0]},
+        ]
+
+        with pytest.raises(ValidationError):
+            await import_export(storage, export, "t1", vector_store=storage)
+
+        assert await storage.list_memories("t1") == []
@@ -0,0 +1,224 @@
+"""Unit tests for mem0 and Zep schema conversion."""
+
+from datetime import datetime, timezone
+from uuid import uuid4
+
+import pytest
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.interop.mem0 import from_mem0, to_mem0
+from rae_core.interop.zep import from_zep, to_zep
+from rae_core.maintenance.bootstrap import entity_node_id
+
+
+@pytest.fixture
+def mem0_export():
+    return {
+        "results": [
+            {
+                "id": "m1",
+                "memory": "Prefers tea over coffee",
+                "hash": "abc",
+                "metadata": {"topic": "food"},
+                "created_at": "2024-05-01T10:00:00+00:00",
+                "updated_at": None,
+                "user_id": "alice",
+                "agent_id": "butler",
+                "run_id": "r1",
+                "categories": ["preferences"],
+                "score": 0.8,
+            }
+        ],
+        "relations": [
+            {"source": "alice", "relationship": "LIKES", "destination": "tea"}
+        ],
+    }
+
+
+@pytest.fixture
+def zep_export():
+    return {
+        "sessions": [
+            {
+                "session_id": "s1",
+                "user_id": "bob",
+                "metadata": {"channel": "web"},
+                "messages": [
+                    {
+                        "uuid": "u1",
+                        "role": "bob",
+                        "role_type": "user",
+                        "content": "I moved to Berlin",
+                        "created_at": "2024-05-02T09:00:00Z",
+                        "token_count": 5,
+                    }
+                ],
+                "summary": {"uuid": "sum1", "content": "Bob relocated"},
+            }
+        ],
+        "facts": [{"uuid": "f1", "fact": "Bob lives in Berlin", "rating": 0.9}],
+        "nodes": [
+            {"uuid": "n1", "name": "Bob", "labels": ["Person"]},
+            {"uuid": "n2", "name": "Berlin", "summary": "Capital of Germany"},
+        ],
+        "edges": [
+            {
+                "uuid": "e1",
+                "name": "LIVES_IN",
+                "fact": "Bob lives in Berlin",
+                "source_node_uuid": "n1",
+                "target_node_uuid": "n2",
+                "valid_at": "2024-05-02T09:00:00Z",
+                "episodes": ["u1"],
+            },
+            {"uuid": "e2", "name": "KNOWS", "source_node_uuid": "n1"},
+        ],
+    }
+
+
+def _stored(**fields):
+    return {
+        "id": uuid4(),
+        "layer": "semantic",
+        "agent_id": "default",
+        "tags": [],
+        "metadata": {},
+        "importance": 0.5,
+        "memory_type": "text",
+        "strength": 1.0,
+        "session_id": None,
+        "created_at": datetime(2024, 5, 3, tzinfo=timezone.utc),
+        **fields,
+    }
+
+
+class TestMem0:
+    """Test suite for mem0 conversion."""
+
+    def test_from_mem0(self, mem0_export):
+        """Test memories and relations map to RAE and dropped fields are reported."""
+        result = from_mem0(mem0_export, "t1")
+
+        [memory] = result.memories
+        assert memory["content"] == "Prefers tea over coffee"
+        assert (memory["layer"], memory["agent_id"], memory["session_id"]) == (
+            "semantic",
+            "butler",
+            "r1",
+        )
+        assert memory["tags"] == ["preferences"]
+        assert memory["metadata"] == {
+            "topic": "food",
+            "user_id": "alice",
+            "created_at": "2024-05-01T10:00:00+00:00",
+        }
+        assert memory["source"].ref == "mem0:m1"
+        assert result.lossy_fields == {"hash": 1, "score": 1}
+
+        [edge] = result.relations
+        assert edge["source_id"] == entity_node_id("t1", "alice")
+        assert edge["target_id"] == entity_node_id("t1", "tea")
+        assert edge["edge_type"] == "likes"
+        assert {n["properties"]["name"] for n in result.nodes} == {"alice", "tea"}
+
+        with pytest.raises(ValidationError):
+            from_mem0({"memories": []}, "t1")
+
+    def test_round_trip(self, mem0_export):
+        """Test RAE records convert back to mem0, reporting what mem0 cannot hold."""
+        imported = from_mem0(mem0_export, "t1")
+        memory = imported.memories[0]
+        stored = _stored(
+            content=memory["content"],
+            agent_id=memory["agent_id"],
+            session_id=memory["session_id"],
+            tags=memory["tags"],
+            metadata=memory["metadata"],
+        )
+        episodic = _stored(content="Said hello", layer="episodic", importance=0.9)
+
+        result = to_mem0([stored, episodic], imported.nodes, imported.relations)
+
+        first = result.data["results"][0]
+        assert (first["memory"], first["user_id"], first["run_id"]) == (
+            "Prefers tea over coffee",
+            "alice",
+            "r1",
+        )
+        assert first["metadata"] == {"topic": "food"}
+        assert first["created_at"] == "2024-05-01T10:00:00+00:00"
+        assert first["categories"] == ["preferences"]
+        assert result.data["relations"] == [
+            {"source": "alice", "relationship": "likes", "destination": "tea"}
+        ]
+        assert result.converted == 2
+        assert result.lossy_fields == {"layer": 1, "importance": 1}
+        assert {e.record_id for e in result.lossy} == {str(episodic["id"])}
+
+
+class TestZep:
+    """Test suite for Zep conversion."""
+
+    def test_from_zep(self, zep_export):
+        """Test sessions, facts and graph map to RAE records."""
+        result = from_zep(zep_export, "t1")
+
+        message, summary, fact = result.memories
+        assert (message["layer"], message["memory_type"], message["session_id"]) == (
+            "episodic",
+            "conversation",
+            "s1",
+        )
+        assert message["metadata"] == {
+            "role": "bob",
+            "role_type": "user",
+            "user_id": "bob",
+            "created_at": "2024-05-02T09:00:00Z",
+        }
+        assert (summary["content"], summary["tags"]) == ("Bob relocated", ["summary"])
+        assert (fact["layer"], fact["importance"]) == ("semantic", 0.9)
+
+        [edge] = result.relations
+        assert edge["source_id"] == entity_node_id("t1", "Bob")
+        assert edge["edge_type"] == "lives_in"
+        assert edge["properties"] == {
+            "fact": "Bob lives in Berlin",
+            "valid_at": "2024-05-02T09:00:00Z",
+        }
+        assert result.lossy_fields == {
+            "session.metadata": 1,
+            "token_count": 1,
+            "episodes": 1,
+            "edge": 1,
+        }
+
+    def test_to_zep(self, zep_export):
+        """Test episodic memories become session messages and the rest facts."""
+        imported = from_zep(zep_export, "t1")
+        message = imported.memories[0]
+        stored = [
+            _stored(
+                content=message["content"],
+                layer="episodic",
+                memory_type="conversation",
+                session_id="s1",
+                metadata=message["metadata"],
+            ),
+            _stored(content="Bob lives in Berlin", importance=0.9),
+            _stored(content="Scratch note", layer="working", tags=["tmp"]),
+        ]
+
+        result = to_zep(stored, imported.nodes, imported.relations)
+
+        [session] = result.data["sessions"]
+        assert session["user_id"] == "bob"
+        assert session["messages"][0]["role_type"] == "user"
+        assert session["messages"][0]["metadata"] == {}
+        assert [(f["fact"], f["rating"]) for f in result.data["facts"]] == [
+            ("Bob lives in Berlin", 0.9),
+            ("Scratch note", 0.5),
+        ]
+        [edge] = result.data["edges"]
+        assert (edge["name"], edge["fact"]) == ("LIVES_IN", "Bob lives in Berlin")
+        assert {n["name"] for n in result.data["nodes"]} == {"Bob", "Berlin"}
+        assert result.lossy_fields == {"layer": 1, "tags": 1}
@@ -0,0 +1,155 @@
+"""Unit tests for bootstrapping agents from seed files."""
+
+import json
+from unittest.mock import AsyncMock, Mock
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.exceptions.base import ValidationError
+from rae_core.maintenance.bootstrap import (
+    AgentBootstrap,
+    entity_node_id,
+    load_seed_directory,
+    parse_json_seed,
+    parse_markdown_seed,
+)
+from rae_core.types.enums import MemoryType
+
+
+class StubExtractor:
+    async def extract_entities(self, text):
+        return [
+            {"text": word.strip(".,"), "type": "ENTITY", "confidence": 0.5}
+            for word in text.split()
+            if word[0].isupper()
+        ]
+
+
+@pytest.fixture
+def seed_dir(tmp_path):
+    (tmp_path / "product.md").write_text(
+        "Intro line\n\n# Pricing\nAcme Pro costs 20 EUR.\n\n## Empty\n\n# Support\n"
+        "Acme answers within a day.\n",
+        encoding="utf-8",
+    )
+    (tmp_path / "facts").mkdir()
+    (tmp_path / "facts" / "team.json").write_text(
+        json.dumps(
+            {
+                "facts": [
+                    "Lena leads support",
+                    {
+                        "content": "def greet(): ...",
+                        "memory_type": "code",
+                        "tags": ["x"],
+                    },
+                ]
+            }
+        ),
+        encoding="utf-8",
+    )
+    (tmp_path / "notes.txt").write_text("ignored", encoding="utf-8")
+    return tmp_path
+
+
+@pytest.fixture
+def engine():
+    engine = Mock()
+    engine.llm_provider = None
+    engine.store_memory = AsyncMock(side_effect=lambda **kwargs: uuid4())
+    return engine
+
+
+def test_parse_markdown_sections():
+    facts = parse_markdown_seed("Intro\n# A\nfirst\n# B\n\n## C\nthird", "k.md")
+
+    assert [(f.section, f.content) for f in facts] == [
+        (None, "Intro"),
+        ("A", "first"),
+        ("C", "third"),
+    ]
+    assert {f.memory_type for f in facts} == {MemoryType.DOCUMENT}
+
+
+def test_parse_json_rejects_invalid_facts():
+    with pytest.raises(ValidationError):
+        parse_json_seed("{not json", "bad.json")
+    with pytest.raises(ValidationError):
+        parse_json_seed('[{"content": "x", "importance": 3}]', "bad.json")
+    with pytest.raises(ValidationError):
+        parse_json_seed('{"other": []}', "bad.json")
+
+
+def test_load_seed_directory(seed_dir):
+    seeds = load_seed_directory(seed_dir)
+
+    assert list(seeds) == ["facts/team.json", "product.md"]
+    assert seeds["facts/team.json"][1].memory_type == MemoryType.CODE
+    assert len(seeds["product.md"]) == 3
+
+
+class TestAgentBootstrap:
+    """Test suite for AgentBootstrap."""
+
+    @pytest.mark.asyncio
+    async def test_run_stores_semantic_facts(self, seed_dir, engine):
+        """Test every fact is stored in the semantic layer with entities."""
+        bootstrap = AgentBootstrap(engine, entity_extractor=StubExtractor())
+
+        report = await bootstrap.run("t1", "a1", seed_dir)
+
+        assert report.files == ["facts/team.json", "product.md"]
+        assert len(report.memory_ids) == 5
+        calls = [c.kwargs for c in engine.store_memory.call_args_list]
+        assert {c["layer"] for c in calls} == {"semantic"}
+        assert {c["agent_id"] for c in calls} == {"a1"}
+        code = calls[1]
+        assert code["memory_type"] == "code"
+        assert code["tags"] == ["x", "seed"]
+        assert code["source"].type == "import"
+        assert code["source"].ref == "seed:facts/team.json"
+        assert code["source"].file == "facts/team.json"
+        pricing = calls[3]
+        assert pricing["metadata"]["section"] == "Pricing"
+        assert pricing["metadata"]["entities"] == [
+            {"text": "Acme", "type": "ENTITY"},
+            {"text": "Pro", "type": "ENTITY"},
+            {"text": "EUR", "type": "ENTITY"},
+        ]
+        # Lena, Intro, Acme, Pro and EUR; Acme appears in two facts
+        assert report.entities == 5
+
+    @pytest.mark.asyncio
+    async def test_entities_linked_in_graph(self, seed_dir, engine):
+        """Test entities become shared graph nodes."""
+        graph = InMemoryGraphStore()
+        bootstrap = AgentBootstrap(
+            engine, graph_store=graph, entity_extractor=StubExtractor()
+        )
+
+        report = await bootstrap.run("t1", "a1", seed_dir)
+
+        acme = entity_node_id("t1", "acme")
+        mentions = await graph.get_neighbors(acme, "t1")
+        assert set(mentions) == {report.memory_ids[3], report.memory_ids[4]}
+
+    @pytest.mark.asyncio
+    async def test_malformed_file_stores_nothing(self, seed_dir, engine):
+        """Test a malformed file aborts before any fact is stored."""
+        (seed_dir / "broken.json").write_text("[1, 2]", encoding="utf-8")
+
+        with pytest.raises(ValidationError):
+            await AgentBootstrap(engine).run("t1", "a1", seed_dir)
+
+        engine.store_memory.assert_not_called()
+
+    @pytest.mark.asyncio
+    async def test_deduplicated_facts_are_skipped(self, seed_dir, engine):
+        """Test facts the engine declines to store are counted as skipped."""
+        engine.store_memory.side_effect = [None, uuid4(), uuid4(), None, uuid4()]
+
+        report = await AgentBootstrap(engine).run("t1", "a1", seed_dir)
+
+        assert (len(report.memory_ids), report.skipped) == (3, 2)
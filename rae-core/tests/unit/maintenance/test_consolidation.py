@@ -0,0 +1,148 @@
+"""Unit tests for consolidating episodic memories into summaries."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.maintenance.consolidation import (
+    CONSOLIDATED_INTO_KEY,
+    CONSOLIDATION_TAG,
+    SOURCES_KEY,
+    Consolidator,
+)
+from rae_core.models.graph import EdgeType
+from rae_core.models.maintenance import ConsolidationGrouping, ConsolidatorConfig
+from rae_core.utils.clock import DeterministicClock
+
+NOW = datetime(2025, 6, 1, tzinfo=timezone.utc)
+
+
+class StubSummarizer:
+    def __init__(self):
+        self.texts = []
+
+    async def summarize(self, text, max_length=200):
+        self.texts.append(text)
+        return f"summary of {text.count('- ')} memories"
+
+
+@pytest.fixture
+def clock():
+    clock = DeterministicClock(NOW - timedelta(days=30))
+    # Memories get distinct, increasing timestamps
+    clock.set_auto_increment(1)
+    return clock
+
+
+@pytest.fixture
+def storage(clock):
+    return InMemoryStorage(clock=clock)
+
+
+@pytest.fixture
+def engine(storage):
+    engine = Mock()
+    engine.memory_storage = storage
+
+    async def store_memory(**kwargs):
+        kwargs.pop("priority", None)
+        kwargs.pop("check_novelty", None)
+        return await storage.store_memory(**kwargs)
+
+    engine.store_memory = AsyncMock(side_effect=store_memory)
+    return engine
+
+
+async def _episode(storage, content, session_id=None, agent_id="a1", **kwargs):
+    return await storage.store_memory(
+        content=content,
+        tenant_id="t1",
+        agent_id=agent_id,
+        layer="episodic",
+        session_id=session_id,
+        **kwargs,
+    )
+
+
+class TestConsolidator:
+    """Test suite for Consolidator."""
+
+    @pytest.mark.asyncio
+    async def test_sessions_become_semantic_summaries(self, engine, storage, clock):
+        """Test old sessions are summarized, linked and archived."""
+        first = await _episode(storage, "asked about tea", "s1", tags=["drinks"])
+        second = await _episode(storage, "ordered green tea", "s1")
+        lone = await _episode(storage, "said hello", "s2")
+        clock.set_time(NOW)
+        recent = await _episode(storage, "asked about coffee", "s1")
+        graph = InMemoryGraphStore()
+        summarizer = StubSummarizer()
+
+        report = await Consolidator(
+            engine, graph_store=graph, summarizer=summarizer, clock=clock
+        ).run("t1")
+
+        assert (report.scanned, report.groups, report.archived) == (3, 1, 2)
+        [summary_id] = report.summary_ids
+        summary = await storage.get_memory(summary_id, "t1")
+        assert summary["layer"] == "semantic"
+        assert summary["content"] == "summary of 2 memories"
+        assert set(summary["tags"]) == {"drinks", CONSOLIDATION_TAG}
+        assert set(summary["metadata"][SOURCES_KEY]) == {str(first), str(second)}
+        assert summarizer.texts == ["- asked about tea\n- ordered green tea"]
+
+        neighbors = await graph.get_neighbors(
+            summary_id, "t1", edge_type=EdgeType.DERIVED_FROM.value, direction="out"
+        )
+        assert set(neighbors) == {first, second}
+
+        remaining = {m["id"] for m in await storage.list_memories("t1")}
+        assert remaining == {lone, recent, summary_id}
+        archived = await storage.get_memory(first, "t1")
+        assert archived["metadata"][CONSOLIDATED_INTO_KEY] == str(summary_id)
+
+    @pytest.mark.asyncio
+    async def test_entity_grouping_keeps_agents_apart(self, engine, storage, clock):
+        """Test entity groups are formed per agent and originals can be kept."""
+        acme = {"entities": [{"text": "Acme", "type": "ORG"}]}
+        await _episode(storage, "Acme called", metadata=acme)
+        await _episode(storage, "Acme paid", metadata=acme)
+        await _episode(storage, "Acme emailed", agent_id="a2", metadata=acme)
+        clock.set_time(NOW)
+        config = ConsolidatorConfig(
+            group_by=ConsolidationGrouping.ENTITY, archive=False
+        )
+
+        report = await Consolidator(
+            engine, summarizer=StubSummarizer(), config=config, clock=clock
+        ).run("t1")
+
+        assert report.groups == 1
+        assert report.archived == 0
+        assert len(await storage.list_memories("t1", layer="episodic")) == 3
+
+    @pytest.mark.asyncio
+    async def test_cluster_grouping_and_failed_summaries(self, engine, storage, clock):
+        """Test similar embeddings cluster and summary failures are counted."""
+        for content, vector in [
+            ("tea", [1.0, 0.0]),
+            ("green tea", [0.95, 0.1]),
+            ("deploy", [0.0, 1.0]),
+        ]:
+            memory_id = await _episode(storage, content)
+            await storage.store_vector(memory_id, vector, "t1")
+        engine.vector_store = storage
+        clock.set_time(NOW)
+        summarizer = Mock()
+        summarizer.summarize = AsyncMock(side_effect=RuntimeError("llm down"))
+        config = ConsolidatorConfig(group_by=ConsolidationGrouping.CLUSTER)
+
+        report = await Consolidator(
+            engine, summarizer=summarizer, config=config, clock=clock
+        ).run("t1")
+
+        assert (report.groups, report.failed, report.archived) == (1, 1, 0)
+        assert report.summary_ids == []
@@ -0,0 +1,177 @@
+"""Unit tests for the re-embedding maintenance job."""
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.embedding.manager import EmbeddingManager
+from rae_core.exceptions.base import ValidationError
+from rae_core.maintenance import ReembedJob
+from rae_core.models.maintenance import ReembedConfig
+from rae_core.models.scoring import JobStatus
+
+
+class FakeProvider:
+    def __init__(self, dimension: int, fail_on: str | None = None):
+        self.dimension = dimension
+        self.fail_on = fail_on
+        self.calls: list[list[str]] = []
+
+    async def embed_text(self, text, task_type="search_document"):
+        return (await self.embed_batch([text], task_type))[0]
+
+    async def embed_batch(self, texts, task_type="search_document"):
+        if self.fail_on in texts:
+            raise RuntimeError("provider unavailable")
+        self.calls.append(list(texts))
+        return [[1.0] + [0.0] * (self.dimension - 1) for _ in texts]
+
+    def get_dimension(self):
+        return self.dimension
+
+
+@pytest.fixture
+async def corpus():
+    storage = InMemoryStorage()
+    manager = EmbeddingManager(FakeProvider(768), default_model_name="small")
+    manager.set_tenant_model("t1", "small")
+    for i in range(5):
+        memory_id = await storage.store_memory(content=f"memory {i}", tenant_id="t1")
+        await storage.store_vector(memory_id, {"small": [1.0] * 768}, "t1")
+    return storage, manager
+
+
+class TestReembedJob:
+    """Test suite for ReembedJob."""
+
+    @pytest.mark.asyncio
+    async def test_reembeds_and_swaps(self, corpus):
+        """Test every memory gets a new vector before the tenant switches."""
+        storage, manager = corpus
+        large = FakeProvider(1024)
+        seen = []
+
+        def on_progress(progress):
+            seen.append((progress.processed, progress.swapped))
+
+        job = ReembedJob(
+            storage,
+            storage,
+            manager,
+            "t1",
+            "large",
+            provider=large,
+            config=ReembedConfig(batch_size=2),
+            on_progress=on_progress,
+        )
+        progress = await job.run()
+
+        assert progress.status == JobStatus.COMPLETED
+        assert progress.total == 5
+        assert progress.embedded == 5
+        assert progress.previous_model == "small"
+        assert progress.swapped and progress.fraction == 1.0
+        assert [len(batch) for batch in large.calls] == [2, 2, 1]
+        # Not swapped until the last batch was written
+        assert seen[:-1] == [(0, False), (2, False), (4, False), (5, False)]
+
+        config = manager.get_tenant_config("t1")
+        assert config.model_name == "large"
+        assert config.dimension == 1024
+        results = await storage.search_similar(
+            [1.0] + [0.0] * 1023, "t1", vector_name="large"
+        )
+        assert len(results) == 5
+        # Old vectors are kept for rollback
+        old = await storage.search_similar([1.0] * 768, "t1", vector_name="small")
+        assert len(old) == 5
+
+    @pytest.mark.asyncio
+    async def test_rate_limit_sleeps_between_batches(self, corpus):
+        """Test batches are throttled to max_texts_per_second."""
+        storage, manager = corpus
+        job = ReembedJob(
+            storage,
+            storage,
+            manager,
+            "t1",
+            "large",
+            provider=FakeProvider(1024),
+            config=ReembedConfig(batch_size=2, max_texts_per_second=4),
+        )
+        delays = []
+
+        async def sleep(delay):
+            delays.append(delay)
+
+        job._sleep = sleep
+        await job.run()
+
+        assert len(delays) == 3
+        assert delays[0] == pytest.approx(0.5, abs=0.05)
+        assert delays[2] == pytest.approx(0.25, abs=0.05)
+
+    @pytest.mark.asyncio
+    async def test_failure_keeps_active_collection(self, corpus):
+        """Test a failed job does not swap and a rerun resumes it."""
+        storage, manager = corpus
+        provider = FakeProvider(1024, fail_on="memory 3")
+        job = ReembedJob(
+            storage,
+            storage,
+            manager,
+            "t1",
+            "large",
+            provider=provider,
+            config=ReembedConfig(batch_size=2),
+        )
+
+        progress = await job.run()
+        assert progress.status == JobStatus.FAILED
+        assert "provider unavailable" in progress.error
+        assert manager.get_tenant_config("t1").model_name == "small"
+
+        provider.fail_on = None
+        retry = await ReembedJob(storage, storage, manager, "t1", "large").run()
+        assert retry.status == JobStatus.COMPLETED
+        assert retry.embedded == 5 - progress.embedded
+        assert manager.get_tenant_config("t1").model_name == "large"
+
+    @pytest.mark.asyncio
+    async def test_cancel_before_swap(self, corpus):
+        """Test a cancelled job leaves the tenant on its old model."""
+        storage, manager = corpus
+        job = ReembedJob(
+            storage, storage, manager, "t1", "large", provider=FakeProvider(1024)
+        )
+        job.cancel()
+
+        progress = await job.start()
+
+        assert progress.status == JobStatus.CANCELLED
+        assert not progress.swapped
+        assert manager.get_tenant_config("t1").model_name == "small"
+
+    @pytest.mark.asyncio
+    async def test_without_swap(self, corpus):
+        """Test swap=False only writes the new vectors."""
+        storage, manager = corpus
+        job = ReembedJob(
+            storage,
+            storage,
+            manager,
+            "t1",
+            "large",
+            provider=FakeProvider(1024),
+            config=ReembedConfig(swap=False),
+        )
+        progress = await job.run()
+
+        assert progress.embedded == 5
+        assert not progress.swapped
+        assert manager.get_tenant_config("t1").model_name == "small"
+
+    def test_unknown_model(self, corpus):
+        """Test a model without provider is rejected."""
+        storage, manager = corpus
+        with pytest.raises(ValidationError):
+            ReembedJob(storage, storage, manager, "t1", "missing")
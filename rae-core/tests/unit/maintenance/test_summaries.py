@@ -0,0 +1,235 @@
+"""Unit tests for refreshing summaries whose sources changed."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.events.bus import MemoryEventBus
+from rae_core.events.storage import EventPublishingStorage
+from rae_core.maintenance.consolidation import SOURCES_KEY, Consolidator
+from rae_core.maintenance.summaries import (
+    STALE_KEY,
+    STALE_SOURCES_KEY,
+    SummaryRefresher,
+    SummaryTracker,
+)
+from rae_core.models.graph import EdgeType
+from rae_core.utils.clock import DeterministicClock
+
+NOW = datetime(2025, 6, 1, tzinfo=timezone.utc)
+
+
+class StubSummarizer:
+    def __init__(self):
+        self.texts = []
+        self.fail = False
+
+    async def summarize(self, text, max_length=200):
+        if self.fail:
+            raise RuntimeError("llm down")
+        self.texts.append(text)
+        return f"summary of: {text}"
+
+
+@pytest.fixture
+def clock():
+    return DeterministicClock(NOW - timedelta(days=30))
+
+
+@pytest.fixture
+def bus():
+    return MemoryEventBus()
+
+
+@pytest.fixture
+def storage(clock, bus):
+    return EventPublishingStorage(InMemoryStorage(clock=clock), bus)
+
+
+@pytest.fixture
+def graph():
+    return InMemoryGraphStore()
+
+
+@pytest.fixture
+def tracker(storage, graph, bus):
+    tracker = SummaryTracker(storage, graph)
+    tracker.attach(bus)
+    return tracker
+
+
+@pytest.fixture
+def engine(storage):
+    engine = Mock()
+    engine.memory_storage = storage
+
+    async def store_memory(**kwargs):
+        for key in ("priority", "check_novelty", "confidence", "source"):
+            kwargs.pop(key, None)
+        return await storage.store_memory(**kwargs)
+
+    engine.store_memory = AsyncMock(side_effect=store_memory)
+    engine.embed_documents = AsyncMock(return_value=[[0.1, 0.2]])
+    engine.vector_store = Mock(store_vector=AsyncMock(return_value=True))
+    return engine
+
+
+@pytest.fixture
+def summarizer():
+    return StubSummarizer()
+
+
+async def _consolidate(engine, storage, graph, clock, summarizer, session_id):
+    clock.set_time(NOW - timedelta(days=30))
+    ids = []
+    for i in range(2):
+        clock.set_time(clock.now() + timedelta(minutes=1))
+        ids.append(
+            await storage.store_memory(
+                content=f"{session_id} fact {i}",
+                tenant_id="t1",
+                agent_id="a1",
+                layer="episodic",
+                session_id=session_id,
+            )
+        )
+    clock.set_time(NOW)
+    report = await Consolidator(
+        engine, graph_store=graph, summarizer=summarizer, clock=clock
+    ).run("t1")
+    return report.summary_ids[-1], ids
+
+
+class TestSummaryTracker:
+    """Test suite for SummaryTracker."""
+
+    @pytest.mark.asyncio
+    async def test_content_change_flags_only_its_summaries(
+        self, engine, storage, graph, clock, summarizer, tracker
+    ):
+        """Test editing a source flags its summary and no other."""
+        summary_id, (first, _) = await _consolidate(
+            engine, storage, graph, clock, summarizer, "s1"
+        )
+        other_id, _ = await _consolidate(
+            engine, storage, graph, clock, summarizer, "s2"
+        )
+        summary = await storage.get_memory(summary_id, "t1")
+        assert STALE_KEY not in summary["metadata"]
+
+        await storage.update_memory(first, "t1", {"content": "s1 fact 0, revised"})
+
+        summary = await storage.get_memory(summary_id, "t1")
+        assert summary["metadata"][STALE_KEY] is True
+        assert summary["metadata"][STALE_SOURCES_KEY] == [str(first)]
+        other = await storage.get_memory(other_id, "t1")
+        assert STALE_KEY not in other["metadata"]
+
+    @pytest.mark.asyncio
+    async def test_metadata_changes_and_archiving_are_ignored(
+        self, engine, storage, graph, clock, summarizer, tracker
+    ):
+        """Test only content changes and deletes make a summary stale."""
+        summary_id, (first, second) = await _consolidate(
+            engine, storage, graph, clock, summarizer, "s1"
+        )
+
+        await storage.update_memory(first, "t1", {"metadata": {"seen": True}})
+        summary = await storage.get_memory(summary_id, "t1")
+        assert STALE_KEY not in summary["metadata"]
+
+        await storage.delete_memory(second, "t1")
+        summary = await storage.get_memory(summary_id, "t1")
+        assert summary["metadata"][STALE_SOURCES_KEY] == [str(second)]
+
+    @pytest.mark.asyncio
+    async def test_track_links_any_summary(self, storage, graph, tracker):
+        """Test track() makes a hand-made summary follow its sources."""
+        source = await storage.store_memory(content="raw", tenant_id="t1")
+        summary = await storage.store_memory(content="digest", tenant_id="t1")
+
+        await tracker.track("t1", summary, [source])
+
+        assert await tracker.summaries_of("t1", source) == [summary]
+        assert await tracker.mark_stale("t1", source) == [summary]
+
+
+class TestSummaryRefresher:
+    """Test suite for SummaryRefresher."""
+
+    @pytest.mark.asyncio
+    async def test_regenerates_only_stale_summaries(
+        self, engine, storage, graph, clock, summarizer, tracker
+    ):
+        """Test a stale summary is rewritten from its sources and re-embedded."""
+        summary_id, (first, second) = await _consolidate(
+            engine, storage, graph, clock, summarizer, "s1"
+        )
+        await _consolidate(engine, storage, graph, clock, summarizer, "s2")
+        await storage.update_memory(first, "t1", {"content": "s1 fact 0, revised"})
+        summarizer.texts.clear()
+
+        report = await SummaryRefresher(
+            engine, graph, summarizer=summarizer
+        ).refresh_stale_summaries("t1")
+
+        assert (report.stale, report.refreshed, report.failed) == (1, [summary_id], 0)
+        assert summarizer.texts == ["- s1 fact 0, revised\n- s1 fact 1"]
+        summary = await storage.get_memory(summary_id, "t1")
+        assert summary["content"] == f"summary of: {summarizer.texts[0]}"
+        assert STALE_KEY not in summary["metadata"]
+        assert STALE_SOURCES_KEY not in summary["metadata"]
+        assert summary["metadata"][SOURCES_KEY] == [str(first), str(second)]
+        engine.vector_store.store_vector.assert_awaited_once()
+        assert engine.vector_store.store_vector.await_args.args[0] == summary_id
+
+        report = await SummaryRefresher(engine, graph).refresh_stale_summaries("t1")
+        assert report.stale == 0
+
+    @pytest.mark.asyncio
+    async def test_deleted_sources_are_dropped(
+        self, engine, storage, graph, clock, summarizer, tracker
+    ):
+        """Test purged sources leave the summary, which is trashed when empty."""
+        summary_id, (first, second) = await _consolidate(
+            engine, storage, graph, clock, summarizer, "s1"
+        )
+        refresher = SummaryRefresher(engine, graph, summarizer=summarizer)
+
+        await storage.delete_memory(first, "t1")
+        report = await refresher.refresh_stale_summaries("t1")
+        assert report.refreshed == [summary_id]
+        summary = await storage.get_memory(summary_id, "t1")
+        assert summary["metadata"][SOURCES_KEY] == [str(second)]
+        neighbors = await graph.get_neighbors(
+            summary_id, "t1", edge_type=EdgeType.SUMMARIZES.value, direction="out"
+        )
+        assert neighbors == [second]
+
+        await storage.delete_memory(second, "t1")
+        report = await refresher.refresh_stale_summaries("t1")
+        assert report.removed == [summary_id]
+        summary = await storage.get_memory(summary_id, "t1")
+        assert summary["deleted_at"] is not None
+
+    @pytest.mark.asyncio
+    async def test_failed_refresh_stays_stale(
+        self, engine, storage, graph, clock, summarizer, tracker
+    ):
+        """Test a summary whose regeneration fails is retried on the next run."""
+        summary_id, (first, _) = await _consolidate(
+            engine, storage, graph, clock, summarizer, "s1"
+        )
+        await storage.update_memory(first, "t1", {"content": "changed"})
+        summarizer.fail = True
+        refresher = SummaryRefresher(engine, graph, summarizer=summarizer)
+
+        report = await refresher.refresh_stale_summaries("t1")
+        assert (report.failed, report.refreshed) == (1, [])
+
+        summarizer.fail = False
+        report = await refresher.refresh_stale_summaries("t1")
+        assert report.refreshed == [summary_id]
@@ -0,0 +1,57 @@
+"""Unit tests for the distance metric scores."""
+
+import math
+
+import pytest
+
+from rae_core.math.distance import (
+    as_metric,
+    collection_metric,
+    euclid_distance_limit,
+    euclid_score,
+    similarity,
+)
+from rae_core.types.enums import DistanceMetric
+
+
+class TestMetricConfig:
+    def test_names_and_aliases(self):
+        """Test metrics are read from enum values, names and aliases."""
+        assert as_metric(DistanceMetric.DOT) == DistanceMetric.DOT
+        assert as_metric("Cosine") == DistanceMetric.COSINE
+        assert as_metric("l2") == DistanceMetric.EUCLID
+        assert as_metric("ip") == DistanceMetric.DOT
+        with pytest.raises(ValueError, match="Unknown distance metric"):
+            as_metric("manhattan")
+
+    def test_collection_metric(self):
+        """Test a dict sets the metric of the collections it names."""
+        config = {"clip": "dot"}
+
+        assert collection_metric("euclid", "clip") == DistanceMetric.EUCLID
+        assert collection_metric(config, "clip") == DistanceMetric.DOT
+        assert collection_metric(config, "default") == DistanceMetric.COSINE
+
+
+class TestSimilarity:
+    def test_cosine_ignores_length(self):
+        """Test cosine compares directions only."""
+        assert similarity(DistanceMetric.COSINE, [1.0, 0.0], [3.0, 0.0]) == 1.0
+        assert similarity(DistanceMetric.COSINE, [0.0, 0.0], [1.0, 0.0]) == 0.0
+
+    def test_dot_keeps_length(self):
+        """Test dot products are not normalized."""
+        assert similarity(DistanceMetric.DOT, [1.0, 2.0], [3.0, 4.0]) == 11.0
+
+    def test_euclid(self):
+        """Test euclidean scores fall from 1 with the distance."""
+        assert similarity(DistanceMetric.EUCLID, [1.0, 0.0], [1.0, 0.0]) == 1.0
+        assert similarity(DistanceMetric.EUCLID, [0.0, 0.0], [3.0, 4.0]) == 1 / 6
+        assert euclid_score(1.0) == 0.5
+
+    def test_distance_limit(self):
+        """Test score thresholds map to the distances scoring them."""
+        assert euclid_distance_limit(0.5) == 1.0
+        assert euclid_score(euclid_distance_limit(0.2)) == pytest.approx(0.2)
+        assert euclid_distance_limit(0.0) == math.inf
+        assert euclid_distance_limit(2.0) == 0.0
@@ -0,0 +1,119 @@
+"""Unit tests for the scalar and product vector codecs."""
+
+import random
+
+import pytest
+
+from rae_core.math.distance import similarity
+from rae_core.math.vector_quantization import (
+    FixedPointCodec,
+    ProductQuantizer,
+    ScalarQuantizer,
+    make_codec,
+)
+from rae_core.models.quantization import QuantizationConfig, QuantizationMode
+from rae_core.types.enums import DistanceMetric
+
+
+def _vectors(count, dim, seed=1):
+    rng = random.Random(seed)
+    return [[rng.uniform(-1.0, 1.0) for _ in range(dim)] for _ in range(count)]
+
+
+class TestScalarQuantizer:
+    def test_round_trip(self):
+        """Test decoded vectors stay within one quantization step."""
+        codec = ScalarQuantizer(4)
+        vector = [0.5, -1.0, 0.25, 0.0]
+
+        code = codec.encode(vector)
+
+        assert len(code) == codec.code_size == 8
+        assert codec.decode(code) == pytest.approx(vector, abs=1.0 / 127)
+
+    def test_similarity_close_to_exact(self):
+        """Test integer scoring tracks the float cosine."""
+        codec = ScalarQuantizer(16)
+        query, *others = _vectors(20, 16)
+        prepared = codec.prepare(query)
+
+        for vector in others:
+            assert codec.similarity(prepared, codec.encode(vector)) == pytest.approx(
+                similarity(DistanceMetric.COSINE, query, vector), abs=0.02
+            )
+
+    def test_zero_and_non_finite(self):
+        """Test zero vectors score 0 and non-finite values are rejected."""
+        codec = ScalarQuantizer(2)
+
+        assert codec.similarity(codec.prepare([1.0, 0.0]), codec.encode([0, 0])) == 0
+        with pytest.raises(ValueError, match="Non-finite"):
+            codec.encode([float("nan"), 1.0])
+
+
+class TestProductQuantizer:
+    def test_untrained_rejects_encoding(self):
+        """Test codes need trained codebooks."""
+        quantizer = ProductQuantizer(4, 2)
+
+        assert not quantizer.trained
+        with pytest.raises(ValueError, match="not trained"):
+            quantizer.encode([1.0, 0.0, 0.0, 0.0])
+
+    def test_invalid_shape(self):
+        """Test subvector and centroid counts are validated."""
+        with pytest.raises(ValueError):
+            ProductQuantizer(4, 5)
+        with pytest.raises(ValueError):
+            ProductQuantizer(4, 2, centroids=300)
+
+    def test_training_reproduces_few_distinct_vectors(self):
+        """Test vectors are exact when there are fewer than centroids."""
+        vectors = _vectors(8, 6)
+        quantizer = ProductQuantizer(6, 3, centroids=16)
+
+        quantizer.train(vectors, iterations=5)
+
+        assert quantizer.code_size == 3
+        for vector in vectors:
+            assert quantizer.decode(quantizer.encode(vector)) == pytest.approx(vector)
+
+    def test_similarity_matches_decoded_cosine(self):
+        """Test table lookups score like the decoded vector."""
+        vectors = _vectors(200, 8)
+        quantizer = ProductQuantizer(8, 4, centroids=16)
+        quantizer.train(vectors, iterations=5, seed=3)
+        query = vectors[0]
+        prepared = quantizer.prepare(query)
+
+        for vector in vectors[1:20]:
+            code = quantizer.encode(vector)
+            assert quantizer.similarity(prepared, code) == pytest.approx(
+                similarity(DistanceMetric.COSINE, query, quantizer.decode(code))
+            )
+
+    def test_serialization(self):
+        """Test codebooks survive to_dict/from_dict."""
+        vectors = _vectors(30, 4)
+        quantizer = ProductQuantizer(4, 2, centroids=8)
+        quantizer.train(vectors)
+
+        restored = ProductQuantizer.from_dict(quantizer.to_dict())
+
+        assert restored.trained
+        assert restored.encode(vectors[0]) == quantizer.encode(vectors[0])
+
+
+class TestMakeCodec:
+    def test_modes(self):
+        """Test each mode gets its codec."""
+        assert isinstance(make_codec(None, 4), FixedPointCodec)
+        none = QuantizationConfig(mode=QuantizationMode.NONE)
+        assert isinstance(make_codec(none, 4), FixedPointCodec)
+        assert isinstance(make_codec(QuantizationConfig(), 4), ScalarQuantizer)
+
+        product = make_codec(QuantizationConfig(mode="product"), 768)
+
+        assert isinstance(product, ProductQuantizer)
+        # Two dimensions per byte: 8x smaller than float32
+        assert product.code_size * 8 == 768 * 4
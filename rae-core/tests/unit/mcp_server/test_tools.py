@@ -0,0 +1,192 @@
+"""Unit tests for the MCP memory tools."""
+
+from typing import Any
+from uuid import UUID
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import SecurityPolicyViolationError, ValidationError
+from rae_core.mcp_server import MemoryToolset, SessionBindings, ToolSession
+from rae_core.models.search import RecallResult
+
+SESSION = ToolSession(tenant_id="tenant-a", agent_id="agent-1")
+
+
+class FakeEngine:
+    """Engine surface used by the tools, backed by InMemoryStorage."""
+
+    def __init__(self, storage: InMemoryStorage):
+        self.memory_storage = storage
+        self.stored: list[dict[str, Any]] = []
+
+    async def store_memory(self, **kwargs: Any) -> Any:
+        self.stored.append(kwargs)
+        return await self.memory_storage.store_memory(**kwargs)
+
+    async def search_memories(
+        self, query: str, tenant_id: str, agent_id: str | None = None, **kwargs: Any
+    ) -> list[dict[str, Any]]:
+        return await self.memory_storage.search_memories(
+            query,
+            tenant_id,
+            agent_id or "default",
+            layer=kwargs.get("layer"),
+            limit=kwargs.get("top_k", 10),
+        )
+
+    async def recall(self, query: str, tenant_id: str, **kwargs: Any) -> RecallResult:
+        memories = await self.search_memories(query, tenant_id, **kwargs)
+        return RecallResult(memories=memories)
+
+
+class SessionKey:
+    """Weak-referenceable stand-in for an MCP session."""
+
+
+class TestMemoryToolset:
+    """Test suite for MemoryToolset."""
+
+    @pytest.fixture
+    def engine(self):
+        return FakeEngine(InMemoryStorage())
+
+    @pytest.fixture
+    def graph(self):
+        return InMemoryGraphStore()
+
+    @pytest.fixture
+    def toolset(self, engine, graph):
+        return MemoryToolset(engine, graph_store=graph)
+
+    def test_tool_descriptors(self, engine, toolset):
+        """Test tools carry JSON input schemas without a tenant field."""
+        names = [tool["name"] for tool in toolset.tools]
+        assert names == [
+            "memory.store",
+            "memory.search",
+            "memory.get_context",
+            "graph.related",
+        ]
+        for tool in toolset.tools:
+            assert tool["inputSchema"]["type"] == "object"
+            assert "tenant_id" not in tool["inputSchema"]["properties"]
+
+        without_graph = MemoryToolset(engine)
+        assert "graph.related" not in [t["name"] for t in without_graph.tools]
+
+    @pytest.mark.asyncio
+    async def test_store_and_search_in_session_tenant(self, engine, toolset):
+        """Test memories are stored and searched in the bound tenant."""
+        stored = await toolset.call(
+            "memory.store", {"content": "Staging DB runs on port 5433"}, SESSION
+        )
+        assert stored["stored"] is True
+        assert isinstance(stored["id"], str)
+        assert engine.stored[0]["tenant_id"] == "tenant-a"
+        assert engine.stored[0]["agent_id"] == "agent-1"
+
+        found = await toolset.call("memory.search", {"query": "staging"}, SESSION)
+        assert [m["id"] for m in found["memories"]] == [stored["id"]]
+
+        other = ToolSession(tenant_id="tenant-b", agent_id="agent-1")
+        found = await toolset.call("memory.search", {"query": "staging"}, other)
+        assert found["memories"] == []
+
+    @pytest.mark.asyncio
+    async def test_session_project_is_default(self, engine, toolset):
+        """Test the session project applies unless the call names one."""
+        session = ToolSession(tenant_id="tenant-a", project="apollo")
+        await toolset.call("memory.store", {"content": "one"}, session)
+        await toolset.call(
+            "memory.store", {"content": "two", "project": "gemini"}, session
+        )
+        assert [s["project"] for s in engine.stored] == ["apollo", "gemini"]
+
+    @pytest.mark.asyncio
+    async def test_tenant_cannot_be_overridden(self, toolset):
+        """Test arguments outside the schema (like tenant_id) are rejected."""
+        with pytest.raises(ValidationError, match="Invalid arguments"):
+            await toolset.call(
+                "memory.store", {"content": "x", "tenant_id": "tenant-b"}, SESSION
+            )
+
+    @pytest.mark.asyncio
+    async def test_invalid_calls(self, toolset):
+        """Test unknown tools and missing arguments raise ValidationError."""
+        with pytest.raises(ValidationError, match="Unknown tool"):
+            await toolset.call("memory.drop_all", {}, SESSION)
+        with pytest.raises(ValidationError):
+            await toolset.call("memory.search", None, SESSION)
+
+    @pytest.mark.asyncio
+    async def test_get_context(self, toolset):
+        """Test context is assembled from the relevant memories."""
+        await toolset.call(
+            "memory.store", {"content": "Releases ship on Tuesdays"}, SESSION
+        )
+        result = await toolset.call(
+            "memory.get_context", {"query": "releases", "format": "minimal"}, SESSION
+        )
+        assert "Releases ship on Tuesdays" in result["context"]
+        assert len(result["memory_ids"]) == 1
+        assert result["token_usage"] > 0
+        assert result["truncated"] is False
+
+    @pytest.mark.asyncio
+    async def test_graph_related(self, graph, toolset):
+        """Test related memories are hydrated within the session tenant."""
+        ids = []
+        for content in ["auth service", "token rotation", "session store"]:
+            stored = await toolset.call("memory.store", {"content": content}, SESSION)
+            ids.append(stored["id"])
+        auth, token, store = (UUID(i) for i in ids)
+        for node in (auth, token, store):
+            await graph.create_node(node, "memory", "tenant-a")
+        await graph.create_edge(auth, token, "depends_on", "tenant-a")
+        await graph.create_edge(token, store, "depends_on", "tenant-a")
+
+        result = await toolset.call("graph.related", {"memory_id": ids[0]}, SESSION)
+        assert [m["content"] for m in result["related"]] == ["token rotation"]
+
+        deeper = await toolset.call(
+            "graph.related", {"memory_id": ids[0], "depth": 2}, SESSION
+        )
+        assert {m["content"] for m in deeper["related"]} == {
+            "token rotation",
+            "session store",
+        }
+
+        other = ToolSession(tenant_id="tenant-b")
+        result = await toolset.call("graph.related", {"memory_id": ids[0]}, other)
+        assert result["related"] == []
+
+
+class TestSessionBindings:
+    """Test suite for SessionBindings."""
+
+    def test_bind_once_per_tenant(self):
+        """Test a session keeps its tenant and cannot be rebound to another."""
+        bindings = SessionBindings()
+        key = SessionKey()
+        assert not bindings.is_bound(key)
+
+        bindings.bind(key, SESSION)
+        assert bindings.get(key) == SESSION
+        assert bindings.bind(key, ToolSession("tenant-a", "agent-2")) == SESSION
+
+        with pytest.raises(SecurityPolicyViolationError, match="already bound"):
+            bindings.bind(key, ToolSession("tenant-b"))
+
+        assert bindings.unbind(key)
+        with pytest.raises(SecurityPolicyViolationError, match="not bound"):
+            bindings.get(key)
+
+    def test_binding_ends_with_session(self):
+        """Test bindings do not outlive their session."""
+        bindings = SessionBindings()
+        key = SessionKey()
+        bindings.bind(key, SESSION)
+        del key
+        assert len(bindings._bindings) == 0
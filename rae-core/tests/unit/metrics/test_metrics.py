@@ -0,0 +1,133 @@
+"""Unit tests for the metrics registry and instrumented backends."""
+
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.metrics import (
+    InstrumentedStorage,
+    InstrumentedVectorStore,
+    MetricsRegistry,
+)
+
+
+class TestMetricsRegistry:
+    """Test suite for MetricsRegistry."""
+
+    def test_render_prometheus(self):
+        """Test counters, gauges and histograms in the exposition format."""
+        registry = MetricsRegistry()
+        registry.counter("jobs_total", "Jobs run", ("kind",)).inc(kind="a")
+        registry.gauge("queue_depth", "Queued jobs").set(3)
+        histogram = registry.histogram("job_seconds", "Job time", buckets=(1, 5))
+        histogram.observe(0.5)
+        histogram.observe(7)
+
+        text = registry.render_prometheus()
+
+        assert "# TYPE jobs_total counter" in text
+        assert 'jobs_total{kind="a"} 1' in text
+        assert "queue_depth 3" in text
+        assert 'job_seconds_bucket{le="1"} 1' in text
+        assert 'job_seconds_bucket{le="5"} 1' in text
+        assert 'job_seconds_bucket{le="+Inf"} 2' in text
+        assert "job_seconds_sum 7.5" in text
+        assert "job_seconds_count 2" in text
+
+    def test_register_twice(self):
+        """Test re-registration returns the metric unless it conflicts."""
+        registry = MetricsRegistry()
+        counter = registry.counter("hits_total", "Hits", ("route",))
+
+        assert registry.counter("hits_total", "Hits", ("route",)) is counter
+        with pytest.raises(ValueError):
+            registry.gauge("hits_total", "Hits", ("route",))
+        with pytest.raises(ValueError):
+            registry.counter("hits_total", "Hits", ("path",))
+
+    def test_label_validation(self):
+        """Test samples must carry exactly the declared labels."""
+        counter = MetricsRegistry().counter("hits_total", "Hits", ("route",))
+
+        with pytest.raises(ValueError):
+            counter.inc(path="/")
+        with pytest.raises(ValueError):
+            counter.inc(-1, route="/")
+
+
+class TestInstrumentedStorage:
+    """Test suite for InstrumentedStorage."""
+
+    @pytest.fixture
+    def registry(self):
+        return MetricsRegistry()
+
+    @pytest.fixture
+    def storage(self, registry):
+        return InstrumentedStorage(InMemoryStorage(), registry=registry)
+
+    @pytest.mark.asyncio
+    async def test_operations_and_tenant_count(self, storage, registry):
+        """Test calls are counted and the tenant gauge follows writes."""
+        ids = [
+            await storage.store_memory(content=f"m{i}", tenant_id="t1")
+            for i in range(3)
+        ]
+        gauge = registry.get("rae_tenant_memories")
+        assert gauge.value(tenant_id="t1") == 3
+
+        await storage.soft_delete_memory(ids[0], "t1")
+        assert gauge.value(tenant_id="t1") == 2
+
+        # Hard-deleting the trashed memory leaves the live count unchanged
+        await storage.delete_memory(ids[0], tenant_id="t1")
+        assert gauge.value(tenant_id="t1") == 2
+
+        await storage.delete_memory(ids[1], "t1")
+        assert gauge.value(tenant_id="t1") == 1
+
+        operations = registry.get("rae_operations_total")
+        assert (
+            operations.value(
+                component="storage", operation="store_memory", status="ok"
+            )
+            == 3
+        )
+        latency = registry.get("rae_operation_duration_seconds")
+        assert latency.count(component="storage", operation="delete_memory") == 2
+
+    @pytest.mark.asyncio
+    async def test_errors_recorded(self, registry):
+        """Test failures are counted by exception type and re-raised."""
+        backend = Mock()
+        backend.get_memory = AsyncMock(side_effect=ConnectionError("down"))
+        storage = InstrumentedStorage(backend, registry=registry)
+
+        with pytest.raises(ConnectionError):
+            await storage.get_memory("id", "t1")
+
+        labels = {"component": "storage", "operation": "get_memory"}
+        operations = registry.get("rae_operations_total")
+        errors = registry.get("rae_operation_errors_total")
+        assert operations.value(**labels, status="error") == 1
+        assert errors.value(**labels, error="ConnectionError") == 1
+
+
+class TestInstrumentedVectorStore:
+    """Test suite for InstrumentedVectorStore."""
+
+    @pytest.mark.asyncio
+    async def test_recall_depth(self):
+        """Test the number of search hits is observed."""
+        registry = MetricsRegistry()
+        backend = Mock()
+        backend.search_similar = AsyncMock(return_value=[("a", 0.9), ("b", 0.8)])
+        vectors = InstrumentedVectorStore(backend, registry=registry)
+
+        hits = await vectors.search_similar([0.1, 0.2], "t1", limit=5)
+
+        assert len(hits) == 2
+        depth = registry.get("rae_vector_search_results")
+        assert depth.count(component="vector") == 1
+        assert depth.sum(component="vector") == 2
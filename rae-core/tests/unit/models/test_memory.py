@@ -0,0 +1,43 @@
+"""Unit tests for memory confidence helpers."""
+
+import pytest
+
+from rae_core.models.memory import (
+    MemoryItem,
+    derived_confidence,
+    memory_confidence,
+)
+
+
+class TestConfidence:
+    """Test suite for memory confidence."""
+
+    def test_memory_confidence(self):
+        """Test confidence is read from metadata, defaulting to certain."""
+        assert memory_confidence({"metadata": {"confidence": 0.3}}) == 0.3
+        assert memory_confidence({"confidence": 0.6}) == 0.6
+        assert memory_confidence({"metadata": {}}) == 1.0
+        assert memory_confidence({"metadata": {"confidence": "high"}}) == 1.0
+        assert memory_confidence({"metadata": {"confidence": 7}}) == 1.0
+
+    def test_derived_confidence(self):
+        """Test derived memories are no surer than their sources."""
+        sources = [{"metadata": {"confidence": 0.4}}, {"metadata": {}}]
+
+        assert derived_confidence(sources, 0.9) == pytest.approx(0.63)
+        assert derived_confidence([], 0.9) == 0.9
+
+    def test_memory_item_confidence_is_separate_from_importance(self):
+        """Test the model field defaults to certain and is bounded."""
+        item = MemoryItem(content="c", layer="episodic", tenant_id="t", agent_id="a")
+
+        assert item.confidence == 1.0
+        assert item.importance == 0.5
+        with pytest.raises(ValueError):
+            MemoryItem(
+                content="c",
+                layer="episodic",
+                tenant_id="t",
+                agent_id="a",
+                confidence=1.5,
+            )
@@ -0,0 +1,59 @@
+"""Unit tests for structured memory sources."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.models.memory import MemoryItem
+from rae_core.models.provenance import ORIGIN_KEY, MemorySource, SourceType
+
+
+class TestMemorySource:
+    """Test suite for MemorySource."""
+
+    def test_coerce(self):
+        """Test labels, dicts and models all become a MemorySource."""
+        parent = uuid4()
+        source = MemorySource(type=SourceType.REFLECTION, derived_from=[parent])
+
+        assert MemorySource.coerce(None) is None
+        assert MemorySource.coerce(source) is source
+        assert MemorySource.coerce("slack") == MemorySource(ref="slack")
+        assert MemorySource.coerce(source.as_metadata()) == source
+        assert source.as_metadata() == {
+            "type": "reflection",
+            "derived_from": [str(parent)],
+        }
+        with pytest.raises(ValueError):
+            MemorySource.coerce({"type": "rumor"})
+
+    def test_label(self):
+        """Test the label prefers the most specific reference."""
+        assert MemorySource(type=SourceType.IMPORT).label == "import"
+        assert MemorySource(url="https://x.test").label == "https://x.test"
+        assert MemorySource(file="a.md", url="https://x.test").label == "a.md"
+        assert MemorySource(ref="chat-1", file="a.md").label == "chat-1"
+
+    def test_from_record(self):
+        """Test the structured origin wins over free-text sources."""
+        origin = MemorySource(type=SourceType.TOOL_OUTPUT, ref="search")
+        record = {
+            "source": "legacy",
+            "metadata": {ORIGIN_KEY: origin.as_metadata(), "source": "slack"},
+        }
+
+        assert MemorySource.from_record(record) == origin
+        assert MemorySource.from_record({"source": "legacy"}).ref == "legacy"
+        assert MemorySource.from_record({"metadata": {"source": "slack"}}).ref == (
+            "slack"
+        )
+        assert MemorySource.from_record({"metadata": {}}) is None
+
+    def test_memory_item_source(self):
+        """Test MemoryItem keeps free-text sources as references."""
+        item = MemoryItem(
+            content="x", layer="episodic", tenant_id="t", agent_id="a", source="llm"
+        )
+
+        assert item.source == MemorySource(ref="llm")
+        assert item.model_dump(mode="json")["source"]["type"] == "other"
@@ -0,0 +1,182 @@
+"""Unit tests for MemoryQuery and MemoryFilter."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import ValidationError
+from rae_core.models.query import MemoryFilter, MemoryQuery
+from rae_core.types.enums import MemoryLayer
+from rae_core.utils.clock import DeterministicClock
+
+NOW = datetime(2025, 6, 1, 12, 0, tzinfo=timezone.utc)
+
+
+class TestMemoryQuery:
+    """Test suite for the MemoryQuery builder."""
+
+    def test_compiles_to_filter_and_storage_kwargs(self):
+        """Test builder calls map onto the list_memories arguments."""
+        query = (
+            MemoryQuery()
+            .layer(MemoryLayer.SEMANTIC)
+            .agent("agent-1")
+            .tag_any(["x", "y"])
+            .metadata_eq("source", "slack")
+            .created_within(timedelta(days=7))
+            .importance_gt(0.6)
+            .limit(20)
+        )
+        built = query.build(now=NOW)
+        assert built == MemoryFilter(
+            layer=MemoryLayer.SEMANTIC,
+            agent_id="agent-1",
+            tags_any=["x", "y"],
+            metadata={"source": "slack"},
+            created_after=NOW - timedelta(days=7),
+            importance_gt=0.6,
+        )
+        assert query.to_storage_kwargs(now=NOW) == {
+            "layer": "semantic",
+            "agent_id": "agent-1",
+            "tags": ["x", "y"],
+            "filters": {"source": "slack"},
+            "created_after": NOW - timedelta(days=7),
+            "importance_gt": 0.6,
+            "limit": 20,
+            "offset": 0,
+        }
+
+    def test_queries_are_immutable(self):
+        """Test refining a query leaves the original unchanged."""
+        base = MemoryQuery().layer(MemoryLayer.EPISODIC)
+        refined = base.importance_gte(0.5).metadata_eq("a", 1).metadata_eq("b", 2)
+        assert base.build() == MemoryFilter(layer=MemoryLayer.EPISODIC)
+        assert refined.build().metadata == {"a": 1, "b": 2}
+
+    def test_created_within_keeps_tighter_bound(self):
+        """Test an explicit created_after later than the window wins."""
+        later = NOW - timedelta(days=1)
+        query = MemoryQuery().created_after(later).created_within(timedelta(days=7))
+        assert query.build(now=NOW).created_after == later
+
+    def test_invalid_arguments(self):
+        """Test out-of-range values are rejected when building."""
+        with pytest.raises(ValidationError):
+            MemoryQuery().layer("archive")  # type: ignore[arg-type]
+        with pytest.raises(ValidationError):
+            MemoryQuery().importance_gt(1.5)
+        with pytest.raises(ValidationError):
+            MemoryQuery().created_within(timedelta(0))
+        with pytest.raises(ValidationError):
+            MemoryQuery().tag_any([])
+        with pytest.raises(ValidationError):
+            MemoryQuery().limit(0)
+
+
+class TestMemoryFilter:
+    """Test suite for MemoryFilter evaluation."""
+
+    def test_matches(self):
+        """Test every condition of the filter is applied to a memory dict."""
+        memory = {
+            "layer": "semantic",
+            "agent_id": "agent-1",
+            "tags": ["x"],
+            "metadata": {"source": "slack"},
+            "created_at": (NOW - timedelta(days=2)).isoformat(),
+            "importance": 0.7,
+        }
+        query = (
+            MemoryQuery()
+            .layer(MemoryLayer.SEMANTIC)
+            .tag_any(["x", "z"])
+            .metadata_eq("source", "slack")
+            .created_within(timedelta(days=7))
+            .importance_gt(0.6)
+        )
+        assert query.build(now=NOW).matches(memory)
+        assert not query.importance_gt(0.7).build(now=NOW).matches(memory)
+        assert not query.build(now=NOW + timedelta(days=6)).matches(memory)
+        assert not query.tag_any(["z"]).build(now=NOW).matches(memory)
+        assert not query.build(now=NOW).matches({**memory, "deleted_at": NOW})
+        assert query.include_deleted().build(now=NOW).matches(
+            {**memory, "deleted_at": NOW}
+        )
+
+
+class TestInMemoryStorageQuery:
+    """Test MemoryQuery against InMemoryStorage."""
+
+    @pytest.mark.asyncio
+    async def test_fetch(self):
+        """Test range bounds are applied by the in-memory backend."""
+        storage = InMemoryStorage()
+        for content, importance in [("high", 0.9), ("edge", 0.6), ("low", 0.2)]:
+            await storage.store_memory(
+                content=content,
+                layer="semantic",
+                tenant_id="t1",
+                agent_id="a1",
+                tags=["deploy"],
+                importance=importance,
+            )
+
+        query = MemoryQuery().layer(MemoryLayer.SEMANTIC).tag_any(["deploy"])
+        above = await query.importance_gt(0.6).fetch(storage, "t1")
+        assert {m["content"] for m in above} == {"high"}
+        at_least = await query.importance_gte(0.6).fetch(storage, "t1")
+        assert {m["content"] for m in at_least} == {"high", "edge"}
+
+        recent = query.created_within(timedelta(days=7))
+        assert len(await recent.fetch(storage, "t1")) == 3
+        later = datetime.now(timezone.utc) + timedelta(days=8)
+        assert await recent.fetch(storage, "t1", now=later) == []
+
+    @pytest.mark.asyncio
+    async def test_update_and_access_bounds(self):
+        """Test updated/accessed bounds in list_memories and search_memories."""
+        clock = DeterministicClock(NOW - timedelta(days=10))
+        storage = InMemoryStorage(clock=clock)
+        ids = {}
+        for content in ("alpha note", "beta note", "gamma note"):
+            ids[content] = await storage.store_memory(
+                content=content, tenant_id="t1", agent_id="a1"
+            )
+        clock.set_time(NOW - timedelta(days=1))
+        await storage.update_memory(ids["alpha note"], "t1", {"importance": 0.9})
+        await storage.update_memory_access(ids["beta note"], "t1")
+        clock.set_time(NOW)
+
+        since = NOW - timedelta(days=2)
+        updated = await MemoryQuery().updated_after(since).fetch(storage, "t1")
+        assert [m["content"] for m in updated] == ["alpha note"]
+        stale = await MemoryQuery().accessed_before(since).fetch(storage, "t1")
+        assert {m["content"] for m in stale} == {"alpha note", "gamma note"}
+
+        found = await storage.search_memories(
+            "note", "t1", "a1", accessed_after=since
+        )
+        assert [r["id"] for r in found] == [ids["beta note"]]
+
+    @pytest.mark.asyncio
+    async def test_session(self):
+        """Test memories are scoped to a session."""
+        storage = InMemoryStorage()
+        for session_id in ("s1", "s2"):
+            await storage.store_memory(
+                content=f"said in {session_id}",
+                tenant_id="t1",
+                agent_id="a1",
+                session_id=session_id,
+            )
+
+        query = MemoryQuery().session("s1")
+        assert [m["content"] for m in await query.fetch(storage, "t1")] == [
+            "said in s1"
+        ]
+        assert query.to_storage_kwargs()["session_id"] == "s1"
+        assert not query.build().matches({"session_id": "s2"})
+        found = await storage.search_memories("said", "t1", "a1", session_id="s2")
+        assert [r["content"] for r in found] == ["said in s2"]
@@ -0,0 +1,111 @@
+"""Unit tests for the typed retrieval request and response models."""
+
+import pytest
+from pydantic import ValidationError
+
+from rae_core.models.retrieval import (
+    RetrievalOptions,
+    RetrievalRequest,
+    RetrievalResponse,
+    RetrievedMemory,
+)
+
+
+class TestRetrievalRequest:
+    """Test suite for RetrievalRequest."""
+
+    def test_defaults_map_to_minimal_recall_kwargs(self):
+        """Test unset options are left to the engine's defaults."""
+        request = RetrievalRequest(query="q")
+        assert request.recall_kwargs() == {"top_k": 10}
+
+    def test_options_map_to_recall_kwargs(self):
+        """Test filters, budget and pipeline options reach recall."""
+        request = RetrievalRequest.model_validate(
+            {
+                "query": "q",
+                "agent_id": "agent-1",
+                "namespace": "work",
+                "filters": {"source": "slack"},
+                "budget": {"top_k": 3, "max_tokens": 100},
+                "options": {
+                    "floor": 0.4,
+                    "rerank": False,
+                    "mmr_lambda": 0.7,
+                    "group_by_parent": True,
+                    "include_global": True,
+                    "weights": {"vector": 1.0},
+                    "min_confidence": 0.5,
+                },
+            }
+        )
+        assert request.recall_kwargs() == {
+            "top_k": 3,
+            "agent_id": "agent-1",
+            "namespace": "work",
+            "filters": {"source": "slack"},
+            "floor": 0.4,
+            "rerank": False,
+            "mmr_lambda": 0.7,
+            "custom_weights": {"vector": 1.0},
+            "min_confidence": 0.5,
+            "group_by_parent": True,
+            "include_global": True,
+        }
+
+    def test_rejects_unknown_and_invalid_fields(self):
+        """Test typos and out-of-range values fail instead of being ignored."""
+        with pytest.raises(ValidationError):
+            RetrievalRequest.model_validate({"query": "q", "topk": 3})
+        with pytest.raises(ValidationError):
+            RetrievalOptions(mmr_lambda=2.0)
+        with pytest.raises(ValidationError):
+            RetrievalRequest(query="")
+
+    def test_round_trips_through_json(self):
+        """Test the request serializes for other processes and back."""
+        request = RetrievalRequest(
+            query="q", options=RetrievalOptions(explain=True, strategies=["vector"])
+        )
+        assert RetrievalRequest.model_validate_json(request.model_dump_json()) == (
+            request
+        )
+
+
+class TestRetrievalResponse:
+    """Test suite for RetrievedMemory and RetrievalResponse."""
+
+    def test_from_memory(self):
+        """Test a recalled memory dict becomes a typed result."""
+        memory = {
+            "id": "m1",
+            "content": "User prefers Python",
+            "layer": "semantic",
+            "tags": ["lang"],
+            "math_score": 0.9,
+            "search_score": 0.7,
+            "knowledge_source": "global",
+            "audit_trail": {"tier": 1},
+            "metadata": {"confidence": 0.4},
+        }
+
+        result = RetrievedMemory.from_memory(memory, tokens=5)
+        explained = RetrievedMemory.from_memory(memory, tokens=5, explain=True)
+
+        assert result.score == 0.9
+        assert result.search_score == 0.7
+        assert result.source == "global"
+        assert result.confidence == 0.4
+        assert result.explanation is None
+        assert explained.explanation == {"tier": 1}
+
+    def test_round_trips_through_json(self):
+        """Test the response serializes with its token accounting."""
+        response = RetrievalResponse(
+            query="q",
+            results=[RetrievedMemory(id="m1", content="c", score=0.5, tokens=1)],
+            no_relevant_memory=False,
+        )
+        restored = RetrievalResponse.model_validate_json(response.model_dump_json())
+        assert restored == response
+        assert restored.tokens.used == 0
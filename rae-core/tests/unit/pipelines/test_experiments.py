@@ -0,0 +1,134 @@
+"""Unit tests for A/B experiments between retrieval pipelines."""
+
+import pytest
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.models.pipeline import PipelineExperiment, PipelineSpec
+from rae_core.pipelines import ExperimentRunner, PipelineRegistry
+
+
+class FakeEngine:
+    """Engine returning a fixed ranking, top_k at a time."""
+
+    def __init__(self):
+        self.ranking = [{"id": f"m{i}", "math_score": 1 - i / 10} for i in range(5)]
+
+    async def search_memories(self, query, tenant_id, **kwargs):
+        return self.ranking[: kwargs["top_k"]]
+
+
+def make_runner():
+    registry = PipelineRegistry()
+    for name, top_k in [("narrow", 1), ("wide", 4)]:
+        spec = PipelineSpec(name=name, stages=[{"type": "search", "top_k": top_k}])
+        registry.register(spec)
+    return ExperimentRunner(registry, FakeEngine())
+
+
+def experiment(percent, tenant_id=None, name="exp"):
+    return PipelineExperiment(
+        name=name,
+        control="narrow",
+        treatment="wide",
+        traffic_percent=percent,
+        tenant_id=tenant_id,
+    )
+
+
+class TestExperimentRunner:
+    """Test suite for ExperimentRunner."""
+
+    @pytest.mark.asyncio
+    async def test_results_tagged_with_variant(self):
+        """Test requests run the variant's pipeline and carry its name."""
+        runner = make_runner()
+        runner.start(experiment(100))
+
+        result = await runner.run("q", "t1")
+
+        assert result.experiment == "exp"
+        assert result.variant == "treatment"
+        assert len(result.memories) == 4
+
+    @pytest.mark.asyncio
+    async def test_traffic_split(self):
+        """Test roughly traffic_percent of requests reach the treatment."""
+        runner = make_runner()
+        runner.start(experiment(20))
+
+        variants = [(await runner.run("q", "t1")).variant for _ in range(500)]
+
+        assert 60 < variants.count("treatment") < 140
+        report = {m.variant: m for m in runner.report("exp")}
+        assert report["treatment"].requests == variants.count("treatment")
+        assert report["control"].requests + report["treatment"].requests == 500
+
+    def test_unit_assignment_is_sticky(self):
+        """Test the same unit id always maps to the same variant."""
+        exp = experiment(50)
+        variants = {ExperimentRunner.variant_for(exp, f"s{i}") for i in range(50)}
+        assert variants == {"control", "treatment"}
+        assert len({ExperimentRunner.variant_for(exp, "s1") for _ in range(10)}) == 1
+
+    @pytest.mark.asyncio
+    async def test_feedback_aggregated_per_variant(self):
+        """Test ratings, hit rate and MRR are computed from feedback."""
+        runner = make_runner()
+        runner.start(experiment(100))
+        first = await runner.run("q", "t1")
+        second = await runner.run("q", "t1")
+        third = await runner.run("q", "t1")
+
+        assert runner.record_feedback(first.request_id, relevant_ids=["m1"], rating=1)
+        assert runner.record_feedback(second.request_id, relevant_ids=[], rating=0)
+        assert runner.record_feedback(third.request_id, rating=1)
+        assert not runner.record_feedback(third.request_id, rating=1)
+
+        treatment = {m.variant: m for m in runner.report("exp")}["treatment"]
+        assert treatment.requests == 3
+        assert treatment.feedback == 3
+        assert treatment.mean_rating == pytest.approx(2 / 3)
+        assert treatment.hit_rate == 0.5
+        assert treatment.mrr == pytest.approx(0.25)
+        assert treatment.mean_latency_ms is not None
+
+    @pytest.mark.asyncio
+    async def test_tenant_scoping(self):
+        """Test tenant experiments win over global ones; others use the registry."""
+        runner = make_runner()
+        runner.start(experiment(100, tenant_id="t1", name="t1-exp"))
+
+        assert (await runner.run("q", "t1")).experiment == "t1-exp"
+        outside = await runner.run("q", "t2")
+        assert outside.experiment is None
+        assert outside.pipeline == "default"
+
+        runner.start(experiment(0, name="global"))
+        assert (await runner.run("q", "t1")).experiment == "t1-exp"
+        assert (await runner.run("q", "t2")).variant == "control"
+
+    def test_start_validates(self):
+        """Test unknown pipelines and overlapping experiments are rejected."""
+        runner = make_runner()
+        with pytest.raises(ValidationError):
+            runner.start(
+                PipelineExperiment(
+                    name="x", control="narrow", treatment="missing", traffic_percent=5
+                )
+            )
+        runner.start(experiment(10))
+        with pytest.raises(ValidationError):
+            runner.start(experiment(10, name="other"))
+
+    @pytest.mark.asyncio
+    async def test_stop_returns_final_report(self):
+        """Test stopping an experiment returns traffic to the registry."""
+        runner = make_runner()
+        runner.start(experiment(100))
+        served = await runner.run("q", "t1")
+
+        report = runner.stop("exp")
+
+        assert sum(m.requests for m in report) == 1
+        assert not runner.record_feedback(served.request_id, rating=1)
+        assert (await runner.run("q", "t1")).variant is None
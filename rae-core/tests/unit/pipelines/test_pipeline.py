@@ -0,0 +1,306 @@
+"""Unit tests for declarative retrieval pipelines."""
+
+from datetime import datetime, timedelta, timezone
+from uuid import UUID
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import ValidationError
+from rae_core.models.pipeline import PipelineSpec, PipelineStage
+from rae_core.pipelines import PipelineRegistry, RetrievalPipeline
+from rae_core.utils.clock import DeterministicClock
+
+NOW = datetime.now(timezone.utc)
+
+SPEC_TOML = """
+name = "graph-heavy"
+description = "Search, expand over the graph, rerank"
+
+[[stages]]
+type = "expand"
+synonyms = { car = ["automobile"] }
+
+[[stages]]
+type = "search"
+top_k = 5
+
+[[stages]]
+type = "graph_expand"
+depth = 1
+score_decay = 0.5
+
+[[stages]]
+type = "rerank"
+reranker = "reverse"
+
+[[stages]]
+type = "pack"
+max_tokens = 500
+"""
+
+
+class FakeEngine:
+    """Engine returning stored memories whose content contains the query."""
+
+    def __init__(self, storage):
+        self.memory_storage = storage
+        self.calls = []
+
+    async def search_memories(self, query, tenant_id, **kwargs):
+        self.calls.append((query, kwargs))
+        memories = await self.memory_storage.list_memories(tenant_id, limit=100)
+        results = []
+        for memory in memories:
+            if query.lower() in memory["content"].lower():
+                results.append({**memory, "math_score": memory["importance"]})
+        return results[: kwargs.get("top_k", 10)]
+
+
+class ReverseReranker:
+    """Reranker that reverses the ranking."""
+
+    async def rerank(self, query, candidates, tenant_id, limit=10, **kwargs):
+        return list(reversed(candidates))[:limit]
+
+
+@pytest.fixture
+async def setup():
+    storage = InMemoryStorage()
+    graph = InMemoryGraphStore()
+    ids = {}
+    for name, content, importance in [
+        ("car", "red car parked", 0.9),
+        ("auto", "automobile repair notes", 0.6),
+        ("garage", "garage opening hours", 0.3),
+    ]:
+        ids[name] = await storage.store_memory(
+            content=content, tenant_id="t1", importance=importance
+        )
+    for memory_id in ids.values():
+        await graph.create_node(memory_id, "memory", "t1")
+    await graph.create_edge(ids["car"], ids["garage"], "related_to", "t1")
+    return FakeEngine(storage), graph, ids
+
+
+class TestPipelineSpec:
+    """Test suite for pipeline spec parsing."""
+
+    def test_from_toml_inlines_params(self):
+        """Test stage keys next to type become its parameters."""
+        spec = PipelineSpec.from_toml(SPEC_TOML)
+        assert spec.name == "graph-heavy"
+        assert [s.type for s in spec.stages] == [
+            "expand",
+            "search",
+            "graph_expand",
+            "rerank",
+            "pack",
+        ]
+        assert spec.stages[1].params == {"top_k": 5}
+
+    def test_empty_pipeline_rejected(self):
+        """Test a spec needs at least one stage."""
+        with pytest.raises(ValueError):
+            PipelineSpec(name="empty", stages=[])
+
+
+class TestRetrievalPipeline:
+    """Test suite for RetrievalPipeline."""
+
+    @pytest.mark.asyncio
+    async def test_full_pipeline(self, setup):
+        """Test every built-in stage runs in order and is traced."""
+        engine, graph, ids = setup
+        pipeline = RetrievalPipeline(
+            PipelineSpec.from_toml(SPEC_TOML),
+            engine,
+            graph_store=graph,
+            rerankers={"reverse": ReverseReranker()},
+        )
+
+        result = await pipeline.run("car", "t1")
+
+        assert result.queries == ["car", "automobile"]
+        assert [query for query, _ in engine.calls] == ["car", "automobile"]
+        # Search finds car (0.9) and auto (0.6); the graph adds garage at
+        # 0.45; the reranker reverses that order
+        assert [m["id"] for m in result.memories] == [
+            ids["garage"],
+            ids["auto"],
+            ids["car"],
+        ]
+        assert result.memories[0]["expanded_from"] == str(ids["car"])
+        assert result.context and "red car parked" in result.context
+        assert [t["stage"] for t in result.trace] == [
+            "expand",
+            "search",
+            "graph_expand",
+            "rerank",
+            "pack",
+        ]
+        assert [t["memories"] for t in result.trace] == [0, 2, 3, 3, 3]
+
+    @pytest.mark.asyncio
+    async def test_search_parameters_passed_to_engine(self, setup):
+        """Test search stage parameters reach search_memories."""
+        engine, _, _ = setup
+        spec = PipelineSpec(
+            name="p",
+            stages=[
+                PipelineStage(
+                    type="search",
+                    params={"top_k": 1, "layer": "semantic", "group_by_parent": True},
+                )
+            ],
+        )
+
+        result = await RetrievalPipeline(spec, engine).run("a", "t1", agent_id="a1")
+
+        _, kwargs = engine.calls[0]
+        assert kwargs["top_k"] == 1
+        assert kwargs["layer"] == "semantic"
+        assert kwargs["agent_id"] == "a1"
+        assert kwargs["group_by_parent"] is True
+        assert len(result.memories) == 1
+        assert result.context is None
+
+    @pytest.mark.asyncio
+    async def test_mmr_pushes_down_near_duplicates(self):
+        """Test the mmr stage reorders results by stored embeddings."""
+        storage = InMemoryStorage()
+        ids = {}
+        for name, importance, vector in [
+            ("fact", 0.9, [1.0, 0.0]),
+            ("fact again", 0.8, [0.99, 0.05]),
+            ("other fact", 0.5, [0.0, 1.0]),
+        ]:
+            ids[name] = await storage.store_memory(
+                content=name, tenant_id="t1", importance=importance
+            )
+            await storage.store_vector(ids[name], vector, "t1")
+        engine = FakeEngine(storage)
+        engine.vector_store = storage
+        spec = PipelineSpec.from_toml(
+            'name = "diverse"\n[[stages]]\ntype = "search"\n'
+            '[[stages]]\ntype = "mmr"\nlambda = 0.5\n'
+        )
+
+        result = await RetrievalPipeline(spec, engine).run("fact", "t1")
+
+        assert [m["id"] for m in result.memories] == [
+            ids["fact"],
+            ids["other fact"],
+            ids["fact again"],
+        ]
+
+    @pytest.mark.asyncio
+    async def test_recency_prefers_recent_memories(self):
+        """Test the recency stage decays scores with a per-layer tau."""
+        clock = DeterministicClock(NOW - timedelta(days=30))
+        storage = InMemoryStorage(clock=clock)
+        old = await storage.store_memory(
+            content="old fact", tenant_id="t1", importance=0.9, layer="semantic"
+        )
+        clock.set_time(NOW)
+        new = await storage.store_memory(
+            content="new fact", tenant_id="t1", importance=0.5, layer="episodic"
+        )
+        spec = PipelineSpec.from_toml(
+            'name = "fresh"\n[[stages]]\ntype = "search"\n'
+            '[[stages]]\ntype = "recency"\ntau_hours = 24\n'
+        )
+
+        result = await RetrievalPipeline(spec, FakeEngine(storage)).run("fact", "t1")
+        assert [m["id"] for m in result.memories][0] == new
+        assert result.memories[1]["recency_factor"] < 0.01
+
+        spec = PipelineSpec.from_toml(
+            'name = "slow"\n[[stages]]\ntype = "search"\n'
+            '[[stages]]\ntype = "recency"\ntau_hours = 24\n'
+            "layer_tau_hours = { semantic = 100000 }\n"
+        )
+        result = await RetrievalPipeline(spec, FakeEngine(storage)).run("fact", "t1")
+        assert [m["id"] for m in result.memories][0] == old
+
+    @pytest.mark.parametrize(
+        "stage",
+        [
+            {"type": "unknown"},
+            {"type": "mmr", "lambda": 1.5},
+            {"type": "recency", "tau_hours": 0},
+            {"type": "recency", "layer_tau_hours": {"working": -1}},
+            {"type": "search", "top_k": 0},
+            {"type": "search", "topk": 5},
+            {"type": "graph_expand"},
+            {"type": "rerank", "reranker": "missing"},
+        ],
+    )
+    def test_invalid_stage_rejected_at_build(self, stage):
+        """Test unknown stages, bad parameters and missing components fail early."""
+        spec = PipelineSpec.model_validate({"name": "bad", "stages": [stage]})
+        with pytest.raises(ValidationError):
+            RetrievalPipeline(spec, engine=None)
+
+
+class TestPipelineRegistry:
+    """Test suite for PipelineRegistry."""
+
+    def test_agent_then_tenant_then_default(self):
+        """Test pipeline selection falls back from agent to tenant to default."""
+        registry = PipelineRegistry()
+        registry.register(PipelineSpec.from_toml(SPEC_TOML))
+        registry.register(
+            PipelineSpec(name="wide", stages=[{"type": "search", "top_k": 50}])
+        )
+
+        registry.assign("t1", None, "wide")
+        registry.assign("t1", "agent-b", "graph-heavy")
+
+        assert registry.spec_for("t1", "agent-a").name == "wide"
+        assert registry.spec_for("t1", "agent-b").name == "graph-heavy"
+        assert registry.spec_for("t2", "agent-b").name == "default"
+
+        registry.assign("t1", "agent-b", None)
+        assert registry.spec_for("t1", "agent-b").name == "wide"
+
+    def test_register_and_assign_validate_names(self):
+        """Test duplicate registration and unknown assignments are rejected."""
+        registry = PipelineRegistry()
+        with pytest.raises(ValidationError):
+            registry.register(PipelineSpec(name="default", stages=[{"type": "pack"}]))
+        with pytest.raises(ValidationError):
+            registry.assign("t1", "a1", "missing")
+
+    def test_load_dir(self, tmp_path):
+        """Test specs are loaded from every TOML file in a directory."""
+        (tmp_path / "graph.toml").write_text(SPEC_TOML)
+        (tmp_path / "b.toml").write_text('name = "b"\n[[stages]]\ntype = "search"\n')
+        registry = PipelineRegistry()
+
+        assert registry.load_dir(tmp_path) == ["b", "graph-heavy"]
+        assert registry.names() == ["b", "default", "graph-heavy"]
+
+    def test_load_invalid_toml(self, tmp_path):
+        """Test a malformed spec file raises ValidationError."""
+        path = tmp_path / "bad.toml"
+        path.write_text('name = "bad name"\n[[stages]]\ntype = "search"\n')
+        with pytest.raises(ValidationError):
+            PipelineRegistry().load_toml(path)
+
+    @pytest.mark.asyncio
+    async def test_build_uses_assigned_pipeline(self, setup):
+        """Test build() binds the agent's pipeline to the components."""
+        engine, _, _ = setup
+        registry = PipelineRegistry()
+        registry.register(
+            PipelineSpec(name="packed", stages=[{"type": "search"}, {"type": "pack"}])
+        )
+        registry.assign("t1", "a1", "packed")
+
+        result = await registry.build("t1", "a1", engine).run("garage", "t1")
+
+        assert result.pipeline == "packed"
+        assert isinstance(result.memories[0]["id"], UUID)
+        assert "garage" in result.context
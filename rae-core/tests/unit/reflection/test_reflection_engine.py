@@ -5,6 +5,7 @@ import pytest
 
 from rae_core.interfaces.llm import ILLMProvider
 from rae_core.interfaces.storage import IMemoryStorage
+from rae_core.models.search import SamplingWeighting
 from rae_core.reflection.engine import ReflectionEngine
 
 
@@ -117,3 +118,74 @@ async def test_run_reflection_cycle_generation_failure(reflection_engine):
     assert results["actions_executed"] == 0
 
     reflection_engine.actor.execute_action.assert_not_called()
+
+
+@pytest.mark.asyncio
+async def test_generate_reflection_publishes_event(reflection_engine):
+    from rae_core.events import MemoryEventBus, ReflectionCreated
+
+    bus = MemoryEventBus()
+    events = []
+    bus.subscribe(ReflectionCreated, events.append)
+    reflection_engine.event_bus = bus
+
+    reflection_id = uuid4()
+    source_ids = [uuid4(), uuid4()]
+    reflection_engine.reflector.generate_reflection.return_value = {
+        "success": True,
+        "reflection_id": str(reflection_id),
+        "type": "consolidation",
+    }
+
+    await reflection_engine.generate_reflection(source_ids, "t", "a")
+
+    assert len(events) == 1
+    assert events[0].memory_id == reflection_id
+    assert events[0].source_memory_ids == source_ids
+    assert events[0].reflection_type == "consolidation"
+
+
+@pytest.mark.asyncio
+async def test_run_reflection_cycle_samples_memories(reflection_engine, mock_storage):
+    ids = [uuid4() for _ in range(30)]
+    mock_storage.get_memories_batch = AsyncMock(
+        return_value=[{"id": mid, "importance": 0.5} for mid in ids]
+    )
+    reflection_engine.sampling = SamplingWeighting(recency=0, seed=3)
+    reflection_engine.reflector.identify_reflection_candidates.return_value = [
+        {"memory_ids": ids, "type": "group"}
+    ]
+    reflection_engine.reflector.generate_reflection.return_value = {"success": False}
+
+    await reflection_engine.run_reflection_cycle(tenant_id="t", agent_id="a")
+
+    sampled = reflection_engine.reflector.generate_reflection.call_args.kwargs[
+        "memory_ids"
+    ]
+    assert len(set(sampled)) == 10
+    assert set(sampled) <= set(ids)
+    assert sampled != ids[:10]
+
+
+@pytest.mark.asyncio
+async def test_run_reflection_cycle_samples_every_tag(reflection_engine, mock_storage):
+    ids = [uuid4() for _ in range(30)]
+    mock_storage.get_memories_batch = AsyncMock(
+        return_value=[
+            {"id": mid, "importance": 0.5, "tags": ["rare" if i == 29 else "common"]}
+            for i, mid in enumerate(ids)
+        ]
+    )
+    reflection_engine.sampling = SamplingWeighting(strategy="stratified", seed=3)
+    reflection_engine.reflector.identify_reflection_candidates.return_value = [
+        {"memory_ids": ids, "type": "group"}
+    ]
+    reflection_engine.reflector.generate_reflection.return_value = {"success": False}
+
+    await reflection_engine.run_reflection_cycle(tenant_id="t", agent_id="a")
+
+    sampled = reflection_engine.reflector.generate_reflection.call_args.kwargs[
+        "memory_ids"
+    ]
+    assert len(sampled) == 10
+    assert ids[29] in sampled
@@ -60,6 +60,14 @@ async def test_generate_consolidation_llm(reflector_with_llm, mock_storage, mock
     # Verify storage
     mock_storage.store_memory.assert_called_once()
     assert mock_storage.store_memory.call_args.kwargs["layer"] == "reflective"
+    assert mock_storage.store_memory.call_args.kwargs["metadata"]["origin"] == {
+        "type": "reflection",
+        "ref": "consolidation",
+        "derived_from": [str(id1), str(id2)],
+    }
+    # Sources without a recorded confidence count as certain
+    metadata = mock_storage.store_memory.call_args.kwargs["metadata"]
+    assert metadata["confidence"] == 0.9
 
 
 @pytest.mark.asyncio
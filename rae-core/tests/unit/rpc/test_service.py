@@ -0,0 +1,219 @@
+"""Unit tests for the memory RPC service."""
+
+from uuid import UUID, uuid4
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.auth import BUILTIN_ROLES, CapabilityTokenCodec, Permission, Principal
+from rae_core.exceptions.base import (
+    AuthenticationError,
+    BackendUnavailableError,
+    NotFoundError,
+    PermissionDeniedError,
+    QuotaExceededError,
+    SecurityPolicyViolationError,
+    ValidationError,
+)
+from rae_core.rpc import (
+    AGENT_HEADER,
+    AUTH_HEADER,
+    TENANT_HEADER,
+    MemoryRpcService,
+    create_grpc_server,
+    status_code_for,
+)
+from rae_core.search.global_knowledge import GLOBAL_TENANT_ID
+
+HEADERS = {TENANT_HEADER: "tenant-a", AGENT_HEADER: "agent-1"}
+
+
+class TestMemoryRpcService:
+    """Test suite for MemoryRpcService."""
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    @pytest.fixture
+    def service(self, storage):
+        return MemoryRpcService(storage, storage, InMemoryGraphStore())
+
+    @pytest.mark.asyncio
+    async def test_store_get_list(self, service):
+        """Test memories round-trip with JSON-compatible responses."""
+        stored = await service.call(
+            "StoreMemory",
+            {"content": "Deploy on Fridays is banned", "tags": ["ops"]},
+            HEADERS,
+        )
+        assert isinstance(stored["id"], str)
+
+        got = await service.call("GetMemory", {"id": stored["id"]}, HEADERS)
+        memory = got["memory"]
+        assert memory["content"] == "Deploy on Fridays is banned"
+        assert memory["agent_id"] == "agent-1"
+        assert isinstance(memory["created_at"], str)
+
+        listed = await service.call("ListMemories", {"limit": 10.0}, HEADERS)
+        assert [m["id"] for m in listed["memories"]] == [stored["id"]]
+
+        found = await service.call("SearchMemories", {"query": "fridays"}, HEADERS)
+        assert len(found["memories"]) == 1
+
+    @pytest.mark.asyncio
+    async def test_tenant_comes_from_headers(self, service):
+        """Test calls are scoped to the tenant header and require it."""
+        stored = await service.call("StoreMemory", {"content": "secret"}, HEADERS)
+
+        other = {TENANT_HEADER: "tenant-b"}
+        with pytest.raises(NotFoundError):
+            await service.call("GetMemory", {"id": stored["id"]}, other)
+        with pytest.raises(ValidationError, match=TENANT_HEADER):
+            await service.call("GetMemory", {"id": stored["id"]}, {})
+        with pytest.raises(SecurityPolicyViolationError):
+            await service.call(
+                "StoreMemory", {"content": "policy"}, {TENANT_HEADER: GLOBAL_TENANT_ID}
+            )
+
+    @pytest.mark.asyncio
+    async def test_vector_search(self, service):
+        """Test stored embeddings are searchable."""
+        stored = await service.call(
+            "StoreMemory", {"content": "vec", "embedding": [1.0, 0.0]}, HEADERS
+        )
+        result = await service.call(
+            "SearchSimilar", {"embedding": [1.0, 0.0], "limit": 5}, HEADERS
+        )
+        assert result["results"][0]["id"] == stored["id"]
+
+        deleted = await service.call("DeleteMemory", {"id": stored["id"]}, HEADERS)
+        assert deleted == {"deleted": True}
+
+    @pytest.mark.asyncio
+    async def test_graph_operations(self, service):
+        """Test graph RPCs operate on the configured graph store."""
+        a, b, c = (str(uuid4()) for _ in range(3))
+        for node in (a, b, c):
+            await service.call("CreateNode", {"id": node}, HEADERS)
+        await service.call("CreateEdge", {"source_id": a, "target_id": b}, HEADERS)
+        await service.call("CreateEdge", {"source_id": b, "target_id": c}, HEADERS)
+
+        neighbors = await service.call("GetNeighbors", {"id": a}, HEADERS)
+        assert neighbors == {"node_ids": [b]}
+        path = await service.call(
+            "ShortestPath", {"source_id": a, "target_id": c}, HEADERS
+        )
+        assert path == {"path": [a, b, c]}
+        subgraph = await service.call("GetSubgraph", {"node_ids": [a, b]}, HEADERS)
+        assert len(subgraph["nodes"]) == 2
+        assert len(subgraph["edges"]) == 1
+
+    @pytest.mark.asyncio
+    async def test_invalid_requests(self, service, storage):
+        """Test malformed requests and unconfigured stores are rejected."""
+        with pytest.raises(ValidationError, match="Invalid UUID"):
+            await service.call("GetMemory", {"id": "nope"}, HEADERS)
+        with pytest.raises(ValidationError, match="content"):
+            await service.call("StoreMemory", {}, HEADERS)
+
+        storage_only = MemoryRpcService(storage)
+        assert "CreateNode" not in storage_only.methods
+        with pytest.raises(ValidationError, match="Unknown method"):
+            await storage_only.call("CreateNode", {"id": str(uuid4())}, HEADERS)
+
+    @pytest.mark.asyncio
+    async def test_capability_tokens(self, storage):
+        """Test token principals set the tenant and are checked per RPC."""
+        codec = CapabilityTokenCodec("s3cret")
+        service = MemoryRpcService(storage, token_codec=codec)
+        writer = codec.issue(
+            Principal(
+                subject="agent-1",
+                tenant_id="tenant-a",
+                roles=[BUILTIN_ROLES["writer"]],
+            )
+        )
+        reader = codec.issue(
+            Principal(
+                subject="agent-2",
+                tenant_id="tenant-a",
+                permissions=[Permission.parse("read:*:team")],
+            )
+        )
+
+        with pytest.raises(AuthenticationError):
+            await service.call("ListMemories", {}, HEADERS)
+        auth = {AUTH_HEADER: f"Bearer {writer}"}
+        private = await service.call("StoreMemory", {"content": "mine"}, auth)
+        shared = await service.call(
+            "StoreMemory",
+            {"content": "ours", "metadata": {"scope": "team"}},
+            auth,
+        )
+        stored = await storage.get_memory(UUID(private["id"]), "tenant-a")
+        assert stored["agent_id"] == "agent-1"
+
+        with pytest.raises(PermissionDeniedError):
+            await service.call("DeleteMemory", {"id": private["id"]}, auth)
+        with pytest.raises(SecurityPolicyViolationError):
+            await service.call("ListMemories", {}, {**auth, TENANT_HEADER: "tenant-b"})
+
+        auth = {AUTH_HEADER: f"Bearer {reader}"}
+        listed = await service.call("ListMemories", {"agent_id": "agent-1"}, auth)
+        assert [m["id"] for m in listed["memories"]] == [shared["id"]]
+        with pytest.raises(PermissionDeniedError):
+            await service.call("GetMemory", {"id": private["id"]}, auth)
+        with pytest.raises(PermissionDeniedError):
+            await service.call("StoreMemory", {"content": "x"}, auth)
+        assert status_code_for(AuthenticationError("no")) == "UNAUTHENTICATED"
+
+    @pytest.mark.asyncio
+    async def test_token_agent_is_enforced(self, storage):
+        """Test a token bound to an agent cannot act as another one."""
+        codec = CapabilityTokenCodec("s3cret")
+        service = MemoryRpcService(storage, token_codec=codec)
+        token = codec.issue(
+            Principal(
+                subject="user-1",
+                tenant_id="tenant-a",
+                agent_id="bot",
+                roles=[BUILTIN_ROLES["writer"]],
+            )
+        )
+        auth = {AUTH_HEADER: f"Bearer {token}"}
+
+        stored = await service.call("StoreMemory", {"content": "mine"}, auth)
+        memory = await storage.get_memory(UUID(stored["id"]), "tenant-a")
+        assert memory["agent_id"] == "bot"
+        await service.call("ListMemories", {"agent_id": "bot"}, auth)
+
+        with pytest.raises(SecurityPolicyViolationError):
+            await service.call(
+                "StoreMemory", {"content": "x", "agent_id": "agent-1"}, auth
+            )
+        with pytest.raises(SecurityPolicyViolationError):
+            await service.call("ListMemories", {}, {**auth, AGENT_HEADER: "agent-1"})
+
+    def test_status_codes(self):
+        """Test typed errors map onto gRPC status codes."""
+        assert status_code_for(NotFoundError("Memory", "m")) == "NOT_FOUND"
+        assert status_code_for(BackendUnavailableError("pg", "down")) == "UNAVAILABLE"
+        assert (
+            status_code_for(QuotaExceededError("t", "memories", 1, 1, 1))
+            == "RESOURCE_EXHAUSTED"
+        )
+        assert status_code_for(ValidationError("bad")) == "INVALID_ARGUMENT"
+        assert status_code_for(RuntimeError("boom")) == "INTERNAL"
+
+    @pytest.mark.asyncio
+    async def test_grpc_server_requires_extra(self, service):
+        """Test creating the gRPC server needs grpcio installed."""
+        try:
+            import grpc  # noqa: F401
+        except ImportError:
+            with pytest.raises(ImportError, match="rae-core\\[grpc\\]"):
+                create_grpc_server(service)
+        else:
+            assert create_grpc_server(service, "127.0.0.1:0") is not None
@@ -0,0 +1,101 @@
+"""Unit tests for cron-like schedules."""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.exceptions.base import ValidationError
+from rae_core.scheduler.cron import CronSchedule
+
+# A Thursday
+START = datetime(2026, 1, 1, 10, 30, tzinfo=timezone.utc)
+
+
+class TestCronSchedule:
+    """Test suite for CronSchedule."""
+
+    def test_every_minute(self):
+        """Test '* * * * *' is due at the start of the next minute."""
+        moment = START.replace(second=42)
+
+        assert CronSchedule("* * * * *").next_after(moment) == START.replace(
+            minute=31
+        )
+
+    def test_next_is_strictly_after(self):
+        """Test a moment on a slot yields the following slot."""
+        schedule = CronSchedule("30 10 * * *")
+
+        assert schedule.next_after(START) == START + timedelta(days=1)
+
+    def test_steps_ranges_and_lists(self):
+        """Test step, range and list fields."""
+        assert CronSchedule("*/15 * * * *").next_after(START) == START.replace(
+            minute=45
+        )
+        assert CronSchedule("0 9-11 * * *").next_after(START) == START.replace(
+            hour=11, minute=0
+        )
+        assert CronSchedule("0 8,20 * * *").next_after(START) == START.replace(
+            hour=20, minute=0
+        )
+
+    def test_shortcuts(self):
+        """Test @hourly, @daily, @weekly and @monthly."""
+        assert CronSchedule("@hourly").next_after(START) == START.replace(
+            hour=11, minute=0
+        )
+        assert CronSchedule("@daily").next_after(START) == datetime(
+            2026, 1, 2, tzinfo=timezone.utc
+        )
+        # Sunday
+        assert CronSchedule("@weekly").next_after(START) == datetime(
+            2026, 1, 4, tzinfo=timezone.utc
+        )
+        assert CronSchedule("@monthly").next_after(START) == datetime(
+            2026, 2, 1, tzinfo=timezone.utc
+        )
+
+    def test_every_interval(self):
+        """Test '@every' schedules run at a fixed interval."""
+        schedule = CronSchedule("@every 90s")
+
+        assert schedule.interval == timedelta(seconds=90)
+        assert schedule.next_after(START) == START + timedelta(seconds=90)
+        assert CronSchedule("@every 6h").next_after(START) == START + timedelta(
+            hours=6
+        )
+
+    def test_weekday_seven_is_sunday(self):
+        """Test day-of-week 7 means Sunday, like 0."""
+        assert CronSchedule("0 0 * * 7").next_after(START) == datetime(
+            2026, 1, 4, tzinfo=timezone.utc
+        )
+
+    def test_either_day_field_matches(self):
+        """Test restricted day-of-month and day-of-week match either."""
+        # The 15th or a Monday: Monday the 5th comes first
+        schedule = CronSchedule("0 0 15 * 1")
+
+        assert schedule.next_after(START) == datetime(
+            2026, 1, 5, tzinfo=timezone.utc
+        )
+
+    def test_month_rollover(self):
+        """Test a schedule in an earlier month is due next year."""
+        schedule = CronSchedule("0 0 1 1 *")
+
+        assert schedule.next_after(START) == datetime(
+            2027, 1, 1, tzinfo=timezone.utc
+        )
+
+    def test_invalid_expressions(self):
+        """Test malformed expressions are rejected."""
+        for expression in ["* * * *", "60 * * * *", "a * * * *", "*/0 * * * *"]:
+            with pytest.raises(ValidationError):
+                CronSchedule(expression)
+
+    def test_never_matching_schedule(self):
+        """Test a schedule matching no date raises instead of looping."""
+        with pytest.raises(ValidationError):
+            CronSchedule("0 0 31 2 *").next_after(START)
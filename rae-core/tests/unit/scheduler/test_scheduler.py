@@ -0,0 +1,231 @@
+"""Unit tests for the maintenance job scheduler."""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import ConflictError, NotFoundError
+from rae_core.interfaces.job import IMaintenanceJob
+from rae_core.models.scoring import JobStatus
+from rae_core.scheduler import FunctionJob, JobScheduler, expiration_job
+from rae_core.utils.clock import DeterministicClock
+
+START = datetime(2026, 1, 1, tzinfo=timezone.utc)
+
+
+class RecordingJob:
+    """Job recording the tenants it ran for."""
+
+    def __init__(self, name="record", fail_for=(), block=None):
+        self.name = name
+        self.calls: list[str] = []
+        self.fail_for = set(fail_for)
+        self.block = block
+
+    async def run(self, tenant_id):
+        self.calls.append(tenant_id)
+        if self.block is not None:
+            await self.block.wait()
+        if tenant_id in self.fail_for:
+            raise RuntimeError(f"boom {tenant_id}")
+        return len(self.calls)
+
+
+@pytest.fixture
+def clock():
+    return DeterministicClock(START)
+
+
+class TestJobScheduler:
+    """Test suite for JobScheduler."""
+
+    def test_jobs_implement_protocol(self):
+        """Test bundled and custom jobs satisfy IMaintenanceJob."""
+        assert isinstance(RecordingJob(), IMaintenanceJob)
+        assert isinstance(FunctionJob("f", lambda t: None), IMaintenanceJob)
+
+    def test_duplicate_job_name_rejected(self, clock):
+        """Test two jobs cannot share a name."""
+        scheduler = JobScheduler(["t1"], clock=clock)
+        scheduler.add(RecordingJob(), "@hourly")
+
+        with pytest.raises(ValueError):
+            scheduler.add(RecordingJob(), "@daily")
+        assert scheduler.jobs == ["record"]
+        assert scheduler.remove("record")
+        assert scheduler.jobs == []
+
+    @pytest.mark.asyncio
+    async def test_runs_when_due(self, clock):
+        """Test a job runs at its slot, once per tenant."""
+        job = RecordingJob()
+        scheduler = JobScheduler(["t1", "t2"], clock=clock)
+        scheduler.add(job, "@hourly")
+
+        assert await scheduler.run_pending() == []
+        assert scheduler.next_run("record", "t1") == START + timedelta(hours=1)
+
+        clock.set_time(START + timedelta(minutes=30))
+        assert await scheduler.run_pending() == []
+
+        clock.set_time(START + timedelta(hours=1))
+        runs = await asyncio.gather(*await scheduler.run_pending())
+
+        assert sorted(job.calls) == ["t1", "t2"]
+        assert all(run.status == JobStatus.COMPLETED for run in runs)
+        assert runs[0].scheduled_for == START + timedelta(hours=1)
+        assert scheduler.next_run("record", "t1") == START + timedelta(hours=2)
+
+    @pytest.mark.asyncio
+    async def test_run_on_start(self, clock):
+        """Test run_on_start runs at the first tick."""
+        job = RecordingJob()
+        scheduler = JobScheduler(["t1"], clock=clock)
+        scheduler.add(job, "@daily", run_on_start=True)
+
+        await asyncio.gather(*await scheduler.run_pending())
+
+        assert job.calls == ["t1"]
+
+    @pytest.mark.asyncio
+    async def test_failing_tenant_is_isolated(self, clock):
+        """Test one tenant's failure does not affect the others."""
+        job = RecordingJob(fail_for={"t1"})
+        scheduler = JobScheduler(["t1", "t2"], clock=clock)
+        scheduler.add(job, "@hourly", run_on_start=True)
+
+        await asyncio.gather(*await scheduler.run_pending())
+
+        failed = scheduler.history(tenant_id="t1")[0]
+        assert failed.status == JobStatus.FAILED
+        assert failed.error == "boom t1"
+        assert scheduler.history(tenant_id="t2")[0].status == JobStatus.COMPLETED
+
+    @pytest.mark.asyncio
+    async def test_overlapping_run_is_skipped(self, clock):
+        """Test a run still in flight at the next slot is not started again."""
+        block = asyncio.Event()
+        job = RecordingJob(block=block)
+        scheduler = JobScheduler(["t1"], clock=clock)
+        scheduler.add(job, "* * * * *", run_on_start=True)
+
+        first = await scheduler.run_pending()
+        await asyncio.sleep(0)
+        assert scheduler.is_running("record", "t1")
+
+        clock.set_time(START + timedelta(minutes=1))
+        assert await scheduler.run_pending() == []
+        assert scheduler.next_run("record", "t1") == START + timedelta(minutes=2)
+
+        block.set()
+        await asyncio.gather(*first)
+        assert job.calls == ["t1"]
+        assert not scheduler.is_running("record", "t1")
+
+    @pytest.mark.asyncio
+    async def test_per_job_tenants_and_callable_source(self, clock):
+        """Test jobs can have their own tenants, resolved on every tick."""
+        tenants = ["t1"]
+
+        async def list_tenants():
+            return tenants
+
+        job = RecordingJob()
+        other = RecordingJob("other")
+        scheduler = JobScheduler(list_tenants, clock=clock)
+        scheduler.add(job, "@hourly", run_on_start=True)
+        scheduler.add(other, "@hourly", tenant_ids=["t9"], run_on_start=True)
+
+        await asyncio.gather(*await scheduler.run_pending())
+        tenants.append("t2")
+        await asyncio.gather(*await scheduler.run_pending())
+
+        assert job.calls == ["t1", "t2"]
+        assert other.calls == ["t9"]
+
+    @pytest.mark.asyncio
+    async def test_timeout_fails_run(self, clock):
+        """Test a run exceeding its timeout is recorded as failed."""
+        job = RecordingJob(block=asyncio.Event())
+        scheduler = JobScheduler(["t1"], clock=clock)
+        scheduler.add(job, "@hourly", timeout=0.01)
+
+        run = await scheduler.run_now("record", "t1")
+
+        assert run.status == JobStatus.FAILED
+        assert run.error == "TimeoutError"
+
+    @pytest.mark.asyncio
+    async def test_run_now(self, clock):
+        """Test run_now runs immediately and rejects unknown or busy jobs."""
+        block = asyncio.Event()
+        job = RecordingJob(block=block)
+        scheduler = JobScheduler(clock=clock)
+        scheduler.add(job, "@daily")
+
+        with pytest.raises(NotFoundError):
+            await scheduler.run_now("missing", "t1")
+
+        pending = asyncio.create_task(scheduler.run_now("record", "t1"))
+        await asyncio.sleep(0)
+        with pytest.raises(ConflictError):
+            await scheduler.run_now("record", "t1")
+
+        block.set()
+        run = await pending
+        assert run.status == JobStatus.COMPLETED
+        assert run.result == 1
+        assert run.scheduled_for is None
+
+    @pytest.mark.asyncio
+    async def test_history_is_bounded(self, clock):
+        """Test the history keeps the most recent runs."""
+        scheduler = JobScheduler(clock=clock, history_size=2)
+        scheduler.add(RecordingJob(), "@daily")
+
+        for _ in range(3):
+            await scheduler.run_now("record", "t1")
+
+        assert [run.result for run in scheduler.history("record")] == [2, 3]
+
+    @pytest.mark.asyncio
+    async def test_bundled_expiration_job(self, clock):
+        """Test the expiration job deletes expired memories."""
+        storage = InMemoryStorage()
+        await storage.store_memory(
+            content="old",
+            layer="working",
+            tenant_id="t1",
+            agent_id="a",
+            expires_at=datetime.now(timezone.utc) - timedelta(hours=1),
+        )
+        scheduler = JobScheduler(clock=clock)
+        scheduler.add(expiration_job(storage), "@hourly")
+
+        run = await scheduler.run_now("expiration", "t1")
+
+        assert run.result == 1
+        assert await storage.count_memories("t1") == 0
+
+    @pytest.mark.asyncio
+    async def test_background_loop(self, clock):
+        """Test start() runs due jobs and sleeps until the next slot."""
+        job = RecordingJob()
+        sleeps = []
+
+        async def sleep(seconds):
+            sleeps.append(seconds)
+            await asyncio.sleep(0)
+
+        scheduler = JobScheduler(["t1"], clock=clock)
+        scheduler._sleep = sleep
+        scheduler.add(job, "@every 30s", run_on_start=True)
+        scheduler.start()
+        for _ in range(3):
+            await asyncio.sleep(0)
+        await scheduler.stop()
+
+        assert job.calls == ["t1"]
+        assert sleeps and sleeps[0] == 30
@@ -0,0 +1,134 @@
+"""Unit tests for bulk importance recalibration."""
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.models.scoring import (
+    JobStatus,
+    RecalibrationConfig,
+    RecalibrationStrategy,
+)
+from rae_core.scoring.recalibration import (
+    RECALIBRATION_ACTOR,
+    ImportanceRecalibrationJob,
+    recalibrate_scores,
+)
+
+
+class TestRecalibrateScores:
+    """Test suite for distribution mapping."""
+
+    def test_rescale(self):
+        """Test min-max rescaling into the target range."""
+        config = RecalibrationConfig(target_min=0.2, target_max=0.8)
+        assert recalibrate_scores([0.1, 0.3, 0.5], config) == pytest.approx(
+            [0.2, 0.5, 0.8]
+        )
+        assert recalibrate_scores([0.4, 0.4], config) == pytest.approx([0.5, 0.5])
+
+    def test_uniform_preserves_rank_and_ties(self):
+        """Test rank mapping with tied scores sharing a value."""
+        config = RecalibrationConfig(strategy=RecalibrationStrategy.UNIFORM)
+        result = recalibrate_scores([0.9, 0.1, 0.1, 0.5], config)
+        assert result == pytest.approx([1.0, 1 / 6, 1 / 6, 2 / 3])
+
+    def test_normal_is_clipped_and_monotonic(self):
+        """Test normal mapping stays ordered within bounds."""
+        config = RecalibrationConfig(
+            strategy=RecalibrationStrategy.NORMAL, target_mean=0.5, target_std=0.5
+        )
+        result = recalibrate_scores([0.01 * i for i in range(50)], config)
+        assert result == sorted(result)
+        assert min(result) >= 0.0 and max(result) <= 1.0
+        assert sum(result) / len(result) == pytest.approx(0.5, abs=0.01)
+
+
+class TestImportanceRecalibrationJob:
+    """Test suite for the batched job."""
+
+    @pytest.fixture
+    async def storage(self):
+        storage = InMemoryStorage()
+        for i, importance in enumerate([0.5, 0.55, 0.6, 0.65, 0.7]):
+            await storage.store_memory(
+                content=f"memory {i}", tenant_id="t", importance=importance
+            )
+        await storage.store_memory(content="other", tenant_id="t2", importance=0.5)
+        return storage
+
+    @pytest.mark.asyncio
+    async def test_rescale_with_progress(self, storage):
+        """Test scores are rewritten in batches and progress is reported."""
+        reports = []
+        job = ImportanceRecalibrationJob(
+            storage,
+            "t",
+            RecalibrationConfig(batch_size=2),
+            on_progress=reports.append,
+        )
+        progress = await job.run()
+
+        assert progress.status == JobStatus.COMPLETED
+        assert (progress.total, progress.processed, progress.updated) == (5, 5, 5)
+        assert [r.processed for r in reports] == [0, 2, 4, 5, 5]
+
+        scores = sorted(m["importance"] for m in await storage.list_memories("t"))
+        assert scores == pytest.approx([0.0, 0.25, 0.5, 0.75, 1.0])
+        assert (await storage.list_memories("t2"))[0]["importance"] == 0.5
+
+        changed = next(iter(job.results))
+        log = await storage.get_change_log(changed, "t")
+        assert log[-1]["changed_by"] == RECALIBRATION_ACTOR
+
+    @pytest.mark.asyncio
+    async def test_scorer_dry_run(self, storage):
+        """Test a new scorer is evaluated without writing in dry-run mode."""
+
+        async def scorer(memory):
+            return 2.0 if memory["content"].endswith("4") else 0.1
+
+        job = ImportanceRecalibrationJob(
+            storage,
+            "t",
+            RecalibrationConfig(strategy=RecalibrationStrategy.SCORER, dry_run=True),
+            scorer=scorer,
+        )
+        progress = await job.start()
+
+        assert progress.updated == 5
+        assert sorted(job.results.values()) == [0.1, 0.1, 0.1, 0.1, 1.0]
+        scores = {m["importance"] for m in await storage.list_memories("t")}
+        assert 0.1 not in scores
+
+    @pytest.mark.asyncio
+    async def test_cancel_and_failure(self, storage):
+        """Test cancellation stops between batches and errors are reported."""
+        job = ImportanceRecalibrationJob(
+            storage,
+            "t",
+            RecalibrationConfig(batch_size=2),
+            on_progress=lambda p: job.cancel() if p.processed else None,
+        )
+        progress = await job.run()
+        assert progress.status == JobStatus.CANCELLED
+        assert progress.processed == 2
+
+        def broken(memory):
+            raise RuntimeError("scorer down")
+
+        failing = ImportanceRecalibrationJob(
+            storage,
+            "t",
+            RecalibrationConfig(strategy=RecalibrationStrategy.SCORER),
+            scorer=broken,
+        )
+        progress = await failing.run()
+        assert progress.status == JobStatus.FAILED
+        assert progress.error == "scorer down"
+
+        with pytest.raises(ValueError):
+            ImportanceRecalibrationJob(
+                storage,
+                "t",
+                RecalibrationConfig(strategy=RecalibrationStrategy.SCORER),
+            )
@@ -93,3 +93,14 @@ def test_get_strategy_name(strategy):
 
 def test_get_strategy_weight(strategy):
     assert strategy.get_strategy_weight() == 1.0
+
+
+@pytest.mark.asyncio
+async def test_fulltext_strategy_passes_namespace(strategy, storage):
+    storage.search_memories.return_value = []
+
+    await strategy.search("q", "tenant1", filters={"namespace": "work"})
+
+    assert storage.search_memories.call_args.kwargs["filters"] == {
+        "namespace": "work"
+    }
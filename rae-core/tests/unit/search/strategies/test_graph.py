@@ -0,0 +1,81 @@
+"""Unit tests for GraphTraversalStrategy traversal limits."""
+
+from unittest.mock import MagicMock
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import VisitBudgetExceededError
+from rae_core.models.graph import TraversalLimits
+from rae_core.search.strategies.graph import GraphTraversalStrategy
+
+
+@pytest.fixture
+async def star():
+    graph_store = InMemoryGraphStore()
+    seed = uuid4()
+    await graph_store.create_node(seed, "memory", "t1")
+    for _ in range(20):
+        spoke = uuid4()
+        await graph_store.create_node(spoke, "memory", "t1")
+        await graph_store.create_edge(seed, spoke, "relates_to", "t1")
+    return graph_store, seed
+
+
+@pytest.mark.asyncio
+async def test_fan_out_limit_applied(star):
+    graph_store, seed = star
+    strategy = GraphTraversalStrategy(
+        graph_store, MagicMock(), limits=TraversalLimits(max_fan_out=4)
+    )
+
+    results = await strategy.search("q", "t1", filters={"seed_ids": [seed]}, limit=50)
+    assert len(results) == 4
+
+
+@pytest.mark.asyncio
+async def test_visit_budget_per_call(star):
+    graph_store, seed = star
+    strategy = GraphTraversalStrategy(graph_store, MagicMock())
+
+    results = await strategy.search(
+        "q",
+        "t1",
+        filters={"seed_ids": [seed]},
+        limit=50,
+        traversal_limits=TraversalLimits(max_visited=7),
+    )
+    assert len(results) == 7
+
+    with pytest.raises(VisitBudgetExceededError):
+        await strategy.search(
+            "q",
+            "t1",
+            filters={"seed_ids": [seed]},
+            traversal_limits=TraversalLimits(max_visited=7, strict=True),
+        )
+
+
+@pytest.mark.asyncio
+async def test_namespace_scopes_neighbors():
+    storage = InMemoryStorage()
+    graph_store = InMemoryGraphStore()
+    seed = await storage.store_memory(content="seed", tenant_id="t1")
+    await graph_store.create_node(seed, "memory", "t1")
+    kept = set()
+    for namespace in ("work", "home", "work"):
+        spoke = await storage.store_memory(
+            content="spoke", tenant_id="t1", metadata={"namespace": namespace}
+        )
+        await graph_store.create_node(spoke, "memory", "t1")
+        await graph_store.create_edge(seed, spoke, "relates_to", "t1")
+        if namespace == "work":
+            kept.add(spoke)
+    strategy = GraphTraversalStrategy(graph_store, storage)
+
+    results = await strategy.search(
+        "q", "t1", filters={"seed_ids": [seed], "namespace": "work"}, limit=50
+    )
+    assert {r[0] for r in results} == kept
@@ -0,0 +1,61 @@
+"""Unit tests for MMR diversification."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.search.diversity import diversify, mmr_order
+
+
+def _ranked(*scores):
+    return [{"id": uuid4(), "math_score": score} for score in scores]
+
+
+class TestMMROrder:
+    """Test suite for mmr_order."""
+
+    def test_lambda_one_keeps_relevance_order(self):
+        """Test pure relevance leaves the ranking unchanged."""
+        memories = _ranked(0.9, 0.8, 0.7)
+        vectors = {str(m["id"]): [1.0, 0.0] for m in memories}
+        assert mmr_order(memories, vectors, lambda_=1.0) == memories
+
+    def test_near_duplicates_are_pushed_down(self):
+        """Test a duplicate of the top result ranks below a distinct one."""
+        top, duplicate, distinct = _ranked(0.9, 0.85, 0.6)
+        vectors = {
+            str(top["id"]): [1.0, 0.0],
+            str(duplicate["id"]): [1.0, 0.01],
+            str(distinct["id"]): [0.0, 1.0],
+        }
+        ordered = mmr_order([top, duplicate, distinct], vectors, lambda_=0.5)
+        assert ordered == [top, distinct, duplicate]
+        assert mmr_order([top, duplicate, distinct], vectors, 0.5, limit=2) == [
+            top,
+            distinct,
+        ]
+
+    def test_memories_without_vectors_count_as_distinct(self):
+        """Test missing embeddings are never treated as redundant."""
+        memories = _ranked(0.9, 0.8)
+        assert mmr_order(memories, {}, lambda_=0.3) == memories
+        assert mmr_order([], {}) == []
+
+
+class TestDiversify:
+    """Test suite for diversify."""
+
+    @pytest.mark.asyncio
+    async def test_uses_stored_vectors(self):
+        """Test embeddings are read from the vector store."""
+        storage = InMemoryStorage()
+        memories = []
+        for score, vector in ((0.9, [1.0, 0.0]), (0.8, [1.0, 0.0]), (0.5, [0.0, 1.0])):
+            memory_id = await storage.store_memory(content="m", tenant_id="t1")
+            await storage.store_vector(memory_id, vector, "t1")
+            memories.append({"id": memory_id, "math_score": score})
+
+        ordered = await diversify(memories, storage, "t1", lambda_=0.5)
+
+        assert ordered == [memories[0], memories[2], memories[1]]
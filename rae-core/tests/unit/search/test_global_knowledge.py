@@ -0,0 +1,53 @@
+"""Unit tests for merging global knowledge into tenant results."""
+
+import pytest
+
+from rae_core.search.global_knowledge import (
+    GLOBAL_TENANT_ID,
+    SOURCE_KEY,
+    is_global_tenant,
+    merge_global_results,
+)
+
+
+class TestMergeGlobalResults:
+    """Test suite for merge_global_results."""
+
+    def test_weighted_and_labeled(self):
+        """Test global scores are weighted and every result gets its source."""
+        tenant = [{"id": "t1", "content": "a", "math_score": 0.6}]
+        shared = [
+            {"id": "g1", "content": "b", "math_score": 0.9},
+            {"id": "g2", "content": "c", "math_score": 0.5},
+        ]
+
+        merged = merge_global_results(tenant, shared, global_weight=0.5, limit=10)
+
+        assert [m["id"] for m in merged] == ["t1", "g1", "g2"]
+        assert [m[SOURCE_KEY] for m in merged] == ["tenant", "global", "global"]
+        assert merged[1]["math_score"] == pytest.approx(0.45)
+        assert merged[1]["global_score"] == 0.9
+        assert shared[0]["math_score"] == 0.9
+
+    def test_tenant_copy_wins_and_limit(self):
+        """Test duplicates of tenant content are dropped before the limit."""
+        tenant = [{"id": "t1", "content": "same", "math_score": 0.2}]
+        shared = [
+            {"id": "g1", "content": "same", "math_score": 1.0},
+            {"id": "g2", "content": "other", "math_score": 1.0},
+        ]
+
+        merged = merge_global_results(tenant, shared, global_weight=1.0, limit=1)
+
+        assert [m["id"] for m in merged] == ["g2"]
+
+    def test_invalid_weight(self):
+        """Test weights outside 0-1 are rejected."""
+        with pytest.raises(ValueError):
+            merge_global_results([], [], global_weight=1.5, limit=5)
+
+    def test_is_global_tenant(self):
+        """Test only the reserved tenant id is global."""
+        assert is_global_tenant(GLOBAL_TENANT_ID)
+        assert not is_global_tenant("tenant-a")
+        assert not is_global_tenant(None)
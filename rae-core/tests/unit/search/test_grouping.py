@@ -0,0 +1,61 @@
+"""Unit tests for collapsing chunk hits by parent memory."""
+
+from rae_core.search.grouping import collapse_chunks
+
+
+def chunk(memory_id, parent_id, index, score):
+    return {
+        "id": memory_id,
+        "math_score": score,
+        "metadata": {
+            "parent_id": parent_id,
+            "chunk_index": index,
+            "chunk_offset": index * 100,
+            "chunk_length": 100,
+        },
+    }
+
+
+class TestCollapseChunks:
+    """Test suite for collapse_chunks."""
+
+    def test_chunks_of_one_parent_collapse(self):
+        """Test a document's chunks become one result with all hit offsets."""
+        memories = [
+            chunk("c3", "doc", 3, 0.9),
+            {"id": "note", "math_score": 0.8, "metadata": {}},
+            chunk("c1", "doc", 1, 0.7),
+            chunk("x0", "other", 0, 0.6),
+        ]
+        results = collapse_chunks(memories)
+
+        assert [r["id"] for r in results] == ["c3", "note", "x0"]
+        doc = results[0]
+        assert doc["parent_id"] == "doc"
+        assert doc["math_score"] == 0.9
+        assert [(h["chunk_index"], h["offset"]) for h in doc["chunk_hits"]] == [
+            (1, 100),
+            (3, 300),
+        ]
+        assert doc["chunk_hits"][1]["score"] == 0.9
+        assert "chunk_hits" not in results[1]
+
+    def test_best_chunk_represents_group(self):
+        """Test the highest-scoring chunk is kept even if ranked later."""
+        memories = [chunk("a", "doc", 0, 0.5), chunk("b", "doc", 1, 0.95)]
+        (result,) = collapse_chunks(memories)
+        assert result["id"] == "b"
+        assert len(result["chunk_hits"]) == 2
+
+    def test_limit_applies_after_grouping(self):
+        """Test top-k counts parents, not chunks."""
+        memories = [chunk(f"a{i}", "a", i, 0.9 - i / 100) for i in range(10)]
+        memories += [chunk("b0", "b", 0, 0.5), chunk("c0", "c", 0, 0.4)]
+        results = collapse_chunks(memories, limit=2)
+        assert [r["parent_id"] for r in results] == ["a", "b"]
+
+    def test_input_not_modified(self):
+        """Test grouped results are copies."""
+        memories = [chunk("a", "doc", 0, 0.5)]
+        collapse_chunks(memories)
+        assert "chunk_hits" not in memories[0]
@@ -0,0 +1,52 @@
+"""Unit tests for recency-weighted scoring."""
+
+import math
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.search.recency import apply_recency, recency_factor
+
+NOW = datetime(2025, 6, 1, 12, 0, tzinfo=timezone.utc)
+
+
+def _memory(name, score, age_hours, layer="episodic"):
+    return {
+        "id": name,
+        "layer": layer,
+        "math_score": score,
+        "created_at": NOW - timedelta(hours=age_hours),
+        "last_accessed_at": (NOW - timedelta(hours=1)).isoformat(),
+    }
+
+
+class TestRecency:
+    """Test suite for recency_factor and apply_recency."""
+
+    def test_factor_is_exponential_decay(self):
+        """Test the factor equals exp(-age / tau)."""
+        memory = _memory("a", 1.0, 48)
+        assert recency_factor(memory, 24, NOW) == pytest.approx(math.exp(-2))
+        assert recency_factor(memory, 24, NOW, "last_accessed_at") == pytest.approx(
+            math.exp(-1 / 24)
+        )
+        assert recency_factor({"id": "x"}, 24, NOW) == 1.0
+
+    def test_recent_memory_overtakes(self):
+        """Test scores are multiplied and the results reordered."""
+        memories = [_memory("old", 0.9, 72), _memory("new", 0.6, 0)]
+        ranked = apply_recency(memories, tau_hours=24, now=NOW)
+
+        assert [m["id"] for m in ranked] == ["new", "old"]
+        assert ranked[0]["math_score"] == pytest.approx(0.6)
+        assert ranked[1]["math_score"] == pytest.approx(0.9 * math.exp(-3))
+        assert ranked[1]["recency_factor"] == pytest.approx(math.exp(-3))
+
+    def test_tau_per_layer(self):
+        """Test a layer-specific tau overrides the default."""
+        memories = [_memory("fact", 0.9, 72, "semantic"), _memory("new", 0.6, 0)]
+        ranked = apply_recency(
+            memories, tau_hours=24, layer_tau_hours={"semantic": 24 * 365}, now=NOW
+        )
+
+        assert [m["id"] for m in ranked] == ["fact", "new"]
@@ -0,0 +1,39 @@
+"""Unit tests for the recall relevance floor."""
+
+from rae_core.search.relevance import apply_relevance_floor
+
+
+class TestRelevanceFloor:
+    """Test suite for apply_relevance_floor."""
+
+    def test_no_floor_keeps_everything(self):
+        """Test a disabled floor returns all memories."""
+        memories = [{"id": 1, "math_score": 0.1}]
+        result = apply_relevance_floor(memories, None)
+
+        assert result.memories == memories
+        assert result.has_relevant is True
+        assert result.best_rejected_score is None
+
+    def test_weak_matches_rejected(self):
+        """Test nothing clearing the floor yields the no-relevant signal."""
+        memories = [{"id": 1, "math_score": 0.42}, {"id": 2, "math_score": 0.3}]
+        result = apply_relevance_floor(memories, 0.6)
+
+        assert result.no_relevant_memory is True
+        assert result.memories == []
+        assert result.best_rejected_score == 0.42
+        assert result.rejected_count == 2
+        assert result.floor == 0.6
+
+    def test_partial_rejection_preserves_order(self):
+        """Test kept memories keep their ranking."""
+        memories = [
+            {"id": 1, "math_score": 0.9},
+            {"id": 2, "math_score": 0.2},
+            {"id": 3, "math_score": 0.7},
+        ]
+        result = apply_relevance_floor(memories, 0.5)
+
+        assert [m["id"] for m in result.memories] == [1, 3]
+        assert result.best_rejected_score == 0.2
@@ -0,0 +1,156 @@
+"""Unit tests for weighted memory sampling."""
+
+import random
+from collections import Counter
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.models.search import SamplingStrategy, SamplingWeighting
+from rae_core.search.sampling import (
+    memory_weight,
+    sample,
+    sample_memories,
+    stratified_sample,
+    weighted_sample,
+)
+
+NOW = datetime(2024, 6, 1, tzinfo=timezone.utc)
+
+
+def _memory(name, importance=0.5, age_hours=0.0, tags=None):
+    return {
+        "id": name,
+        "importance": importance,
+        "created_at": NOW - timedelta(hours=age_hours),
+        "tags": tags or [],
+    }
+
+
+class TestWeightedSample:
+    """Test suite for weighted_sample and memory_weight."""
+
+    def test_weight_combines_importance_and_recency(self):
+        """Test importance scales the weight and age halves it per half-life."""
+        weighting = SamplingWeighting(recency_half_life_hours=10)
+        fresh = memory_weight(_memory("a", 0.8), weighting, NOW)
+        old = memory_weight(_memory("b", 0.8, age_hours=10), weighting, NOW)
+        assert fresh == pytest.approx(0.8)
+        assert old == pytest.approx(0.4)
+        assert memory_weight(_memory("c", 0.0), weighting, NOW) == 0.01
+
+    def test_uniform_when_exponents_are_zero(self):
+        """Test zero exponents give every memory the same weight."""
+        weighting = SamplingWeighting(importance=0, recency=0)
+        weights = {
+            memory_weight(_memory(n, i, a), weighting, NOW)
+            for n, i, a in (("a", 0.1, 0), ("b", 0.9, 500))
+        }
+        assert weights == {1.0}
+
+    def test_sample_without_replacement(self):
+        """Test the sample has k distinct memories."""
+        memories = [_memory(str(i)) for i in range(20)]
+        sample = weighted_sample(memories, 5, rng=random.Random(1), now=NOW)
+        assert len({m["id"] for m in sample}) == 5
+        assert len(weighted_sample(memories[:3], 5, now=NOW)) == 3
+
+    def test_heavier_memories_are_drawn_more_often(self):
+        """Test draws favor important memories without excluding the rest."""
+        memories = [_memory("dominant", 1.0)] + [
+            _memory(f"m{i}", 0.1) for i in range(9)
+        ]
+        rng = random.Random(7)
+        counts = Counter(
+            weighted_sample(memories, 1, rng=rng, now=NOW)[0]["id"] for _ in range(2000)
+        )
+        assert counts["dominant"] > 800
+        assert len(counts) == 10
+
+    def test_seed_makes_samples_reproducible(self):
+        """Test a seeded weighting draws the same sample."""
+        memories = [_memory(str(i), i / 20) for i in range(20)]
+        weighting = SamplingWeighting(seed=42)
+        first = weighted_sample(memories, 4, weighting, now=NOW)
+        assert weighted_sample(memories, 4, weighting, now=NOW) == first
+
+
+class TestSamplingStrategies:
+    """Test suite for the recency, importance and stratified strategies."""
+
+    def test_recency_and_importance_drop_the_other_factor(self):
+        """Test each single-factor strategy ignores the other factor."""
+        memory = _memory("a", 0.5, age_hours=10)
+        recency = SamplingWeighting(
+            strategy=SamplingStrategy.RECENCY, recency_half_life_hours=10
+        )
+        importance = SamplingWeighting(strategy=SamplingStrategy.IMPORTANCE)
+        assert memory_weight(memory, recency, NOW) == pytest.approx(0.5)
+        assert memory_weight(_memory("b", 0.1), recency, NOW) == 1.0
+        assert memory_weight(memory, importance, NOW) == pytest.approx(0.5)
+        assert memory_weight(_memory("c", 0.5, 1000), importance, NOW) == 0.5
+
+    def test_stratified_represents_every_tag(self):
+        """Test rare tags get a memory and groups a proportional share."""
+        memories = (
+            [_memory(f"b{i}", 1.0, tags=["billing"]) for i in range(16)]
+            + [_memory(f"d{i}", 0.1, tags=["deploy", "ops"]) for i in range(3)]
+            + [_memory("u0", 0.1)]
+        )
+        weighting = SamplingWeighting(strategy=SamplingStrategy.STRATIFIED, seed=1)
+
+        drawn = stratified_sample(memories, 10, weighting, now=NOW)
+
+        groups = Counter(m["id"][0] for m in drawn)
+        assert len({m["id"] for m in drawn}) == 10
+        assert groups["u"] == 1
+        assert groups["d"] >= 1
+        assert groups["b"] >= 7
+
+    def test_stratified_with_fewer_slots_than_tags(self):
+        """Test a sample smaller than the tag count takes one per drawn tag."""
+        memories = [_memory(str(i), tags=[f"t{i % 5}"]) for i in range(20)]
+
+        drawn = sample(
+            memories, 3, SamplingWeighting(strategy="stratified", seed=2), now=NOW
+        )
+
+        assert len({m["tags"][0] for m in drawn}) == 3
+        assert len(stratified_sample(memories[:2], 5, now=NOW)) == 2
+
+
+class TestSampleMemories:
+    """Test suite for sample_memories."""
+
+    @pytest.mark.asyncio
+    async def test_samples_from_storage(self):
+        """Test the sample is drawn from the tenant's memories."""
+        storage = InMemoryStorage()
+        for i in range(6):
+            await storage.store_memory(
+                content=f"m{i}", tenant_id="t1", agent_id="a1", importance=0.5
+            )
+        await storage.store_memory(content="other", tenant_id="t2", agent_id="a1")
+
+        sample = await sample_memories(
+            storage, "t1", agent_id="a1", k=3, weighting=SamplingWeighting(seed=1)
+        )
+        assert len(sample) == 3
+        assert all(m["tenant_id"] == "t1" for m in sample)
+
+    @pytest.mark.asyncio
+    async def test_strategy_argument_overrides_weighting(self):
+        """Test strategy= selects stratified sampling over the pool."""
+        storage = InMemoryStorage()
+        for i in range(12):
+            await storage.store_memory(
+                content=f"m{i}", tenant_id="t1", tags=["a" if i else "b"]
+            )
+
+        weighting = SamplingWeighting(seed=4)
+        drawn = await sample_memories(
+            storage, "t1", k=2, weighting=weighting, strategy="stratified"
+        )
+
+        assert sorted(m["tags"][0] for m in drawn) == ["a", "b"]
@@ -0,0 +1,124 @@
+"""Unit tests for second-stage rerankers and rerank_memories."""
+
+from unittest.mock import AsyncMock, MagicMock
+from uuid import uuid4
+
+import pytest
+
+from rae_core.search.reranking import rerank_memories
+from rae_core.search.rerankers.cross_encoder import CrossEncoderReranker
+from rae_core.search.rerankers.llm import LLMReranker
+
+
+class KeywordModel:
+    """Cross-encoder stand-in scoring documents containing 'paris' high."""
+
+    def __init__(self):
+        self.calls = []
+
+    def predict(self, pairs, batch_size=32):
+        self.calls.append(pairs)
+        return [4.0 if "paris" in doc else -4.0 for _, doc in pairs]
+
+
+class TestCrossEncoderReranker:
+    """Test suite for CrossEncoderReranker."""
+
+    @pytest.mark.asyncio
+    async def test_reorders_by_cross_encoder(self):
+        """Test candidates are ranked by the sigmoid of the model logits."""
+        a, b = uuid4(), uuid4()
+        reranker = CrossEncoderReranker(KeywordModel())
+        ranked = await reranker.rerank(
+            "capital",
+            [(a, 0.9, 0.5), (b, 0.2, 0.5)],
+            "t1",
+            documents={str(a): "berlin", str(b): "paris"},
+        )
+        assert [r[0] for r in ranked] == [b, a]
+        assert 0.98 < ranked[0][1] < 1.0
+
+    @pytest.mark.asyncio
+    async def test_loads_content_from_storage(self):
+        """Test content missing from documents is read from storage."""
+        a, b = uuid4(), uuid4()
+        storage = MagicMock()
+        storage.get_memories_batch = AsyncMock(
+            return_value=[{"id": b, "content": "paris"}]
+        )
+        model = KeywordModel()
+        reranker = CrossEncoderReranker(model, memory_storage=storage, weight=0.5)
+        ranked = await reranker.rerank(
+            "q", [(a, 0.9, 0.5), (b, 0.2, 0.5)], "t1", documents={str(a): "rome"}
+        )
+        storage.get_memories_batch.assert_awaited_once_with([b], "t1")
+        assert len(model.calls[0]) == 2
+        assert ranked[0][0] == b
+
+    @pytest.mark.asyncio
+    async def test_candidates_without_content_keep_score(self):
+        """Test a candidate with no content is not scored by the model."""
+        a = uuid4()
+        model = KeywordModel()
+        ranked = await CrossEncoderReranker(model).rerank("q", [(a, 0.4, 0.1)], "t1")
+        assert ranked == [(a, 0.4, 0.1)]
+        assert model.calls == []
+
+
+class TestLLMReranker:
+    """Test suite for LLMReranker."""
+
+    @pytest.mark.asyncio
+    async def test_grades_candidates(self):
+        """Test LLM grades become scores and unparsable replies are ignored."""
+        a, b, c = uuid4(), uuid4(), uuid4()
+        replies = {"alpha": "2", "beta": "Relevance: 9/10", "gamma": "no idea"}
+        llm = MagicMock()
+
+        async def generate(prompt, **kwargs):
+            return next(v for k, v in replies.items() if k in prompt)
+
+        llm.generate = generate
+        reranker = LLMReranker(llm)
+        ranked = await reranker.rerank(
+            "q",
+            [(a, 0.9, 0.5), (b, 0.1, 0.5), (c, 0.5, 0.5)],
+            "t1",
+            documents={str(a): "alpha", str(b): "beta", str(c): "gamma"},
+        )
+        assert ranked == [(b, 0.9, 0.5), (c, 0.5, 0.5), (a, 0.2, 0.5)]
+
+    @pytest.mark.asyncio
+    async def test_failed_grade_keeps_score(self):
+        """Test an LLM error leaves the first-stage score in place."""
+        a = uuid4()
+        llm = MagicMock()
+        llm.generate = AsyncMock(side_effect=RuntimeError("down"))
+        ranked = await LLMReranker(llm).rerank(
+            "q", [(a, 0.7, 0.5)], "t1", documents={str(a): "text"}
+        )
+        assert ranked == [(a, 0.7, 0.5)]
+
+
+class TestRerankMemories:
+    """Test suite for rerank_memories."""
+
+    @pytest.mark.asyncio
+    async def test_reranks_only_up_to_depth(self):
+        """Test memories below the depth follow the reranked head unchanged."""
+        memories = [
+            {"id": uuid4(), "content": "berlin", "math_score": 0.9},
+            {"id": uuid4(), "content": "paris", "math_score": 0.8},
+            {"id": uuid4(), "content": "paris too", "math_score": 0.7},
+        ]
+        reranked = await rerank_memories(
+            CrossEncoderReranker(KeywordModel()), "q", memories, "t1", depth=2
+        )
+        assert [m["id"] for m in reranked] == [
+            memories[1]["id"],
+            memories[0]["id"],
+            memories[2]["id"],
+        ]
+        assert reranked[0]["retrieval_score"] == 0.8
+        assert reranked[0]["math_score"] > 0.9
+        assert reranked[2] is memories[2]
@@ -0,0 +1,210 @@
+"""Unit tests for vector recall (by query and by example) with hydrated records."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock, Mock
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import NotFoundError
+from rae_core.models.search import SimilarityRecallOptions
+from rae_core.search.similarity import recall_similar, recall_similar_to
+
+NOW = datetime(2026, 1, 1, tzinfo=timezone.utc)
+
+
+class TestRecallSimilar:
+    """Test suite for recall_similar."""
+
+    @pytest.fixture
+    def embedder(self):
+        embedder = Mock()
+        embedder.embed_text = AsyncMock(return_value=[1.0, 0.0])
+        return embedder
+
+    @pytest.fixture
+    def storage(self):
+        return InMemoryStorage()
+
+    async def _store(self, storage, content, vector, **kwargs):
+        kwargs.setdefault("tenant_id", "t1")
+        memory_id = await storage.store_memory(content=content, **kwargs)
+        await storage.store_vector(memory_id, vector, kwargs["tenant_id"])
+        return memory_id
+
+    @pytest.mark.asyncio
+    async def test_hits_are_fetched_in_one_batch(self, storage, embedder):
+        """Test results carry the stored record, ranked by similarity."""
+        close = await self._store(storage, "close", [1.0, 0.1])
+        far = await self._store(storage, "far", [0.2, 1.0])
+        await self._store(storage, "other tenant", [1.0, 0.0], tenant_id="t2")
+        storage.get_memories_batch = AsyncMock(wraps=storage.get_memories_batch)
+
+        results = await recall_similar("q", "t1", storage, storage, embedder)
+
+        storage.get_memories_batch.assert_awaited_once()
+        embedder.embed_text.assert_awaited_once_with("q", task_type="search_query")
+        assert [r.memory["id"] for r in results] == [close, far]
+        assert results[0].memory["content"] == "close"
+        assert "math_score" not in results[0].memory
+        assert results[0].score == results[0].similarity > results[1].similarity
+        assert results[0].provenance["strategy"] == "vector"
+        assert results[1].provenance["similarity_rank"] == 1
+
+    @pytest.mark.asyncio
+    async def test_records_are_filtered_after_fetching(self, storage, embedder):
+        """Test deleted, expired and non-matching records are dropped."""
+        kept = await self._store(
+            storage, "kept", [1.0, 0.0], agent_id="a1", metadata={"topic": "x"}
+        )
+        other_agent = await self._store(
+            storage, "other", [1.0, 0.0], agent_id="a2", metadata={"topic": "x"}
+        )
+        expired = await self._store(
+            storage,
+            "expired",
+            [1.0, 0.0],
+            agent_id="a1",
+            metadata={"topic": "x"},
+            expires_at=NOW - timedelta(hours=1),
+        )
+        deleted = await self._store(
+            storage, "deleted", [1.0, 0.0], agent_id="a1", metadata={"topic": "x"}
+        )
+        await storage.soft_delete_memory(deleted, "t1")
+        # The vector index may lag the records it points to
+        storage.search_similar = AsyncMock(
+            return_value=[(m, 0.9) for m in (other_agent, expired, deleted, kept)]
+        )
+        options = SimilarityRecallOptions(agent_id="a1", filters={"topic": "x"})
+
+        results = await recall_similar(
+            "q", "t1", storage, storage, embedder, options, now=NOW
+        )
+
+        assert [r.memory["id"] for r in results] == [kept]
+        assert storage.search_similar.await_args.kwargs["agent_id"] == "a1"
+        assert storage.search_similar.await_args.kwargs["limit"] == 30
+
+    @pytest.mark.asyncio
+    async def test_superseded_memories_are_dropped(self, storage, embedder):
+        """Test superseded memories only come back with include_superseded."""
+        current = await self._store(storage, "current", [1.0, 0.0])
+        stale = await self._store(
+            storage, "stale", [1.0, 0.1], metadata={"superseded_by": str(current)}
+        )
+
+        results = await recall_similar("q", "t1", storage, storage, embedder)
+        assert [r.memory["id"] for r in results] == [current]
+
+        options = SimilarityRecallOptions(include_superseded=True)
+        results = await recall_similar("q", "t1", storage, storage, embedder, options)
+        assert [r.memory["id"] for r in results] == [current, stale]
+
+    @pytest.mark.asyncio
+    async def test_low_confidence_memories_are_dropped(self, storage, embedder):
+        """Test min_confidence drops memories recorded as less certain."""
+        sure = await self._store(storage, "sure", [1.0, 0.0])
+        shaky = await self._store(
+            storage, "shaky", [1.0, 0.1], metadata={"confidence": 0.3}
+        )
+
+        results = await recall_similar("q", "t1", storage, storage, embedder)
+        assert [r.memory["id"] for r in results] == [sure, shaky]
+
+        options = SimilarityRecallOptions(min_confidence=0.5)
+        results = await recall_similar("q", "t1", storage, storage, embedder, options)
+        assert [r.memory["id"] for r in results] == [sure]
+
+    @pytest.mark.asyncio
+    async def test_namespace_keeps_to_its_memories(self, storage, embedder):
+        """Test a namespace only recalls its own memories."""
+        default = await self._store(storage, "default", [1.0, 0.0])
+        work = await self._store(
+            storage, "work", [1.0, 0.1], metadata={"namespace": "work"}
+        )
+
+        options = SimilarityRecallOptions(namespace="work")
+        results = await recall_similar("q", "t1", storage, storage, embedder, options)
+        assert [r.memory["id"] for r in results] == [work]
+
+        options = SimilarityRecallOptions(namespace="default")
+        results = await recall_similar("q", "t1", storage, storage, embedder, options)
+        assert [r.memory["id"] for r in results] == [default]
+
+    @pytest.mark.asyncio
+    async def test_recency_and_rerank(self, storage, embedder):
+        """Test decay and reranking reorder results and show in provenance."""
+        old = await self._store(storage, "old", [1.0, 0.0])
+        new = await self._store(storage, "new", [1.0, 0.3])
+        await storage.update_memory(old, "t1", {"created_at": NOW - timedelta(30)})
+        await storage.update_memory(new, "t1", {"created_at": NOW})
+        options = SimilarityRecallOptions(recency_tau_hours=24, top_k=1)
+
+        results = await recall_similar(
+            "q", "t1", storage, storage, embedder, options, now=NOW
+        )
+        assert [r.memory["id"] for r in results] == [new]
+        assert results[0].provenance["recency_factor"] == 1.0
+
+        reranker = Mock()
+        reranker.rerank = AsyncMock(return_value=[(old, 0.99, 0.5), (new, 0.1, 0.5)])
+        results = await recall_similar(
+            "q", "t1", storage, storage, embedder, reranker=reranker, now=NOW
+        )
+        assert [r.memory["id"] for r in results] == [old, new]
+        assert results[0].score == 0.99
+        assert results[0].provenance["reranked"] is True
+
+        options = SimilarityRecallOptions(rerank=False)
+        results = await recall_similar(
+            "q", "t1", storage, storage, embedder, options, reranker=reranker
+        )
+        assert results[0].memory["id"] == old
+        assert results[0].provenance["reranked"] is False
+
+
+class TestRecallSimilarTo:
+    """Test suite for recall_similar_to."""
+
+    @pytest.mark.asyncio
+    async def test_uses_the_stored_embedding(self):
+        """Test the example's vector is the query and the example is left out."""
+        storage = InMemoryStorage()
+        ids = {}
+        for name, vector in (
+            ("example", [1.0, 0.0]),
+            ("near", [0.9, 0.1]),
+            ("far", [0.5, 1.0]),
+        ):
+            ids[name] = await storage.store_memory(content=name, tenant_id="t1")
+            await storage.store_vector(ids[name], vector, "t1")
+        embedder = Mock()
+        embedder.embed_text = AsyncMock()
+
+        results = await recall_similar_to(
+            ids["example"], "t1", storage, storage, embedder
+        )
+
+        embedder.embed_text.assert_not_awaited()
+        assert [r.memory["id"] for r in results] == [ids["near"], ids["far"]]
+        assert results[0].provenance["example_id"] == str(ids["example"])
+
+        reranker = Mock()
+        reranker.rerank = AsyncMock(return_value=[(ids["near"], 0.9, 0.5)])
+        options = SimilarityRecallOptions(top_k=1)
+        await recall_similar_to(
+            ids["example"], "t1", storage, storage, options=options, reranker=reranker
+        )
+        assert reranker.rerank.await_args.args[0] == "example"
+
+    @pytest.mark.asyncio
+    async def test_missing_memory_or_embedding(self):
+        """Test NotFoundError without the memory or its embedding."""
+        storage = InMemoryStorage()
+        memory_id = await storage.store_memory(content="no vector", tenant_id="t1")
+
+        with pytest.raises(NotFoundError):
+            await recall_similar_to(memory_id, "t1", storage, storage)
+        with pytest.raises(NotFoundError):
+            await recall_similar_to(memory_id, "t2", storage, storage)
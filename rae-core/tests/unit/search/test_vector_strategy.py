@@ -3,6 +3,7 @@ from uuid import uuid4
 
 import pytest
 
+from rae_core.adapters.memory.storage import InMemoryStorage
 from rae_core.search.strategies.vector import VectorSearchStrategy
 
 
@@ -26,3 +27,29 @@ async def test_vector_strategy_flow():
     assert results[0][0] == expected_id
     assert results[0][1] == 0.85
     assert results[0][2] == 0.0  # importance stub
+
+
+@pytest.mark.asyncio
+async def test_vector_strategy_filters_namespace_in_store():
+    """A small namespace is not crowded out by a large one before the limit."""
+    store = InMemoryStorage()
+    beta = set()
+    for i in range(603):
+        namespace = "beta" if i < 3 else "alpha"
+        memory_id = await store.store_memory(
+            content="x", tenant_id="t1", metadata={"namespace": namespace}
+        )
+        # The large namespace's vectors all score higher
+        vector = [0.9, 0.1, 0.0] if namespace == "beta" else [1.0, 0.0, 0.0]
+        await store.store_vector(memory_id, vector, "t1", {"namespace": namespace})
+        if namespace == "beta":
+            beta.add(memory_id)
+    embedder = AsyncMock()
+    embedder.embed_text.return_value = [1.0, 0.0, 0.0]
+    strategy = VectorSearchStrategy(store, embedder)
+
+    results = await strategy.search(
+        "q", "t1", filters={"namespace": "beta"}, limit=100
+    )
+
+    assert {r[0] for r in results} == beta
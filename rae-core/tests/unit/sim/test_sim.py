@@ -0,0 +1,181 @@
+"""Unit tests for workload simulation."""
+
+from collections import Counter
+from uuid import uuid4
+
+import pytest
+
+from rae_core.models.simulation import WorkloadSpec
+from rae_core.sim import WorkloadGenerator, WorkloadSimulator, generate_workload
+
+
+class FakeEngine:
+    """Engine whose search ranks memories by words shared with the query."""
+
+    def __init__(self, fail_writes=False):
+        self.memories = {}
+        self.closed = []
+        self.fail_writes = fail_writes
+
+    async def store_memory(self, **kwargs):
+        if self.fail_writes:
+            raise RuntimeError("storage down")
+        memory_id = uuid4()
+        self.memories[memory_id] = {"id": memory_id, **kwargs}
+        return memory_id
+
+    async def search_memories(self, query, tenant_id, top_k=10, session_id=None):
+        words = set(query.lower().rstrip("?").split()) - {"which", "does", "the"}
+        scored = [
+            (len(words & set(m["content"].lower().rstrip(".").split())), m)
+            for m in self.memories.values()
+            if m["tenant_id"] == tenant_id
+            and (session_id is None or m["session_id"] == session_id)
+        ]
+        scored.sort(key=lambda pair: pair[0], reverse=True)
+        return [m for _, m in scored[:top_k]]
+
+    async def close_session(self, tenant_id, session_id, agent_id=None):
+        self.closed.append(session_id)
+        return []
+
+
+def _ticks(spec):
+    return list(generate_workload(spec))
+
+
+class TestWorkloadGenerator:
+    """Test suite for WorkloadGenerator."""
+
+    def test_same_seed_same_workload(self):
+        """Test the workload is a function of the spec."""
+        spec = WorkloadSpec(ticks=20, seed=7)
+
+        assert _ticks(spec) == _ticks(spec)
+        assert _ticks(spec) != _ticks(WorkloadSpec(ticks=20, seed=8))
+
+    def test_reads_ask_for_earlier_facts(self):
+        """Test reads only target facts written in earlier ticks."""
+        written = {}
+        for tick in _ticks(WorkloadSpec(ticks=30, reads_per_tick=6)):
+            for op in tick:
+                if op.kind == "read":
+                    assert written[(op.tenant_id, op.fact)] < op.tick
+            for op in tick:
+                if op.kind == "write":
+                    written[(op.tenant_id, op.fact)] = op.tick
+        assert written
+
+    def test_bursts(self):
+        """Test burst ticks write burst_size facts per tenant."""
+        spec = WorkloadSpec(
+            tenants=1, ticks=10, writes_per_tick=0, burst_probability=1, burst_size=5
+        )
+        writes = [sum(op.kind == "write" for op in tick) for tick in _ticks(spec)]
+
+        assert writes == [5] * 10
+
+    def test_zipf_reads_favor_first_facts(self):
+        """Test reads concentrate on the first facts of a tenant."""
+        spec = WorkloadSpec(tenants=1, ticks=60, reads_per_tick=10, zipf_exponent=1.5)
+        reads = Counter(
+            op.fact for tick in _ticks(spec) for op in tick if op.kind == "read"
+        )
+        writes = sum(op.kind == "write" for tick in _ticks(spec) for op in tick)
+
+        assert writes > 20
+        assert reads.most_common(1)[0][0] == 0
+        assert reads[0] + reads[1] > sum(reads.values()) / 2
+
+    def test_session_churn(self):
+        """Test closed sessions are replaced and writes go to open sessions."""
+        generator = WorkloadGenerator(
+            WorkloadSpec(tenants=2, agents_per_tenant=2, ticks=40, session_length=4)
+        )
+        closed = set()
+        for tick in generator.ticks():
+            for op in tick:
+                if op.kind == "write":
+                    assert op.session_id not in closed
+                elif op.kind == "close_session":
+                    closed.add(op.session_id)
+
+        assert closed
+        assert generator.sessions_started == len(closed) + 4
+
+    def test_session_scoped_reads(self):
+        """Test scoped reads name the open session that wrote the fact."""
+        sessions = {}
+        scoped = 0
+        for tick in _ticks(WorkloadSpec(ticks=30, session_read_share=1.0)):
+            for op in tick:
+                if op.kind == "write":
+                    sessions[(op.tenant_id, op.fact)] = op.session_id
+                elif op.kind == "read" and op.session_scoped:
+                    scoped += 1
+                    assert op.session_id == sessions[(op.tenant_id, op.fact)]
+        assert scoped
+
+    def test_facts_are_distinct(self):
+        """Test no two facts of a tenant share a query."""
+        spec = WorkloadSpec(tenants=1, ticks=50, burst_probability=0.5)
+        reads = {}
+        for tick in _ticks(spec):
+            for op in tick:
+                if op.kind == "read":
+                    assert reads.setdefault(op.text, op.fact) == op.fact
+
+
+class TestWorkloadSimulator:
+    """Test suite for WorkloadSimulator."""
+
+    @pytest.mark.asyncio
+    async def test_reports_throughput_latency_and_quality(self):
+        """Test a run reports every operation and perfect recall."""
+        engine = FakeEngine()
+        spec = WorkloadSpec(ticks=15, seed=3)
+        operations = [op for tick in _ticks(spec) for op in tick]
+
+        report = await WorkloadSimulator(engine, spec, concurrency=4).run()
+
+        kinds = Counter(op.kind for op in operations)
+        assert report.operations == len(operations)
+        assert report.writes.count == kinds["write"] == len(engine.memories)
+        assert report.reads.count == kinds["read"]
+        assert report.session_closes.count == len(engine.closed)
+        assert report.throughput_ops_s > 0
+        assert report.reads.p50_ms <= report.reads.p95_ms <= report.reads.max_ms
+        assert report.recall_at_k == report.mrr == 1.0
+        assert set(report.per_tenant_recall.values()) == {1.0}
+        assert {m["layer"] for m in engine.memories.values()} == {"working"}
+
+    @pytest.mark.asyncio
+    async def test_ranks_below_top_k_are_misses(self):
+        """Test facts outside the top k lower recall@k and MRR."""
+
+        class ReversedEngine(FakeEngine):
+            async def search_memories(self, query, tenant_id, top_k=10, **kwargs):
+                results = await super().search_memories(query, tenant_id, 1000)
+                return results[::-1][:top_k]
+
+        engine = ReversedEngine()
+        spec = WorkloadSpec(tenants=1, ticks=30, top_k=1, session_read_share=0)
+
+        report = await WorkloadSimulator(engine, spec).run()
+
+        assert report.reads.count > 0
+        assert report.recall_at_k < 0.5
+        assert report.mrr == report.recall_at_k
+
+    @pytest.mark.asyncio
+    async def test_failed_operations_are_counted(self):
+        """Test failures are counted and their facts left out of quality."""
+        spec = WorkloadSpec(ticks=10)
+
+        report = await WorkloadSimulator(FakeEngine(fail_writes=True), spec).run()
+
+        assert report.writes.count == 0
+        assert report.writes.errors > 0
+        assert report.reads.count > 0
+        assert report.recall_at_k == 0.0
+        assert report.per_tenant_recall == {}
@@ -0,0 +1,263 @@
+"""Unit tests for tenant snapshot export/import."""
+
+import json
+from uuid import UUID
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import ValidationError
+from rae_core.interfaces.storage import LIFECYCLE_FIELDS
+from rae_core.sync.snapshot import export_tenant, import_tenant, read_snapshot
+from rae_core.utils.clock import DeterministicClock
+
+
+class FakeGraphStore:
+    """Minimal graph store recording nodes and edges."""
+
+    def __init__(self):
+        self.nodes = {}
+        self.edges = []
+
+    async def create_node(self, node_id, node_type, tenant_id, properties=None):
+        self.nodes[node_id] = (node_type, tenant_id, properties or {})
+        return True
+
+    async def create_edge(
+        self, source_id, target_id, edge_type, tenant_id, weight=1.0, properties=None
+    ):
+        self.edges.append((source_id, target_id, edge_type, tenant_id, weight))
+        return True
+
+    async def get_subgraph(self, node_ids, tenant_id, include_edges=True):
+        ids = set(node_ids)
+        return {
+            "nodes": [
+                {"id": nid, "type": t, "properties": json.dumps(p)}
+                for nid, (t, tid, p) in self.nodes.items()
+                if nid in ids and tid == tenant_id
+            ],
+            "edges": [
+                {"source_id": s, "target_id": t, "type": e, "weight": w}
+                for s, t, e, tid, w in self.edges
+                if s in ids and t in ids and tid == tenant_id
+            ],
+        }
+
+
+async def _collect(stream):
+    return [chunk async for chunk in stream]
+
+
+class TestSnapshot:
+    """Test suite for export_tenant/import_tenant."""
+
+    @pytest.fixture
+    async def source(self):
+        storage = InMemoryStorage()
+        graph = FakeGraphStore()
+        first = await storage.store_memory(
+            content="User likes tea",
+            layer="semantic",
+            tenant_id="tenant1",
+            agent_id="agent1",
+            tags=["preference"],
+            importance=0.8,
+        )
+        second = await storage.store_memory(
+            content="User visited Krakow",
+            layer="episodic",
+            tenant_id="tenant1",
+            agent_id="agent1",
+        )
+        await storage.store_memory(
+            content="Other tenant", tenant_id="tenant2", agent_id="agent1"
+        )
+        await storage.store_vector(first, [1.0, 0.0, 0.0], "tenant1")
+        await graph.create_node(first, "memory", "tenant1", {"label": "tea"})
+        await graph.create_node(second, "memory", "tenant1")
+        await graph.create_edge(first, second, "relates_to", "tenant1", weight=0.5)
+        return storage, graph
+
+    @pytest.mark.asyncio
+    async def test_export_contains_all_record_types(self, source):
+        """Test export stream structure."""
+        storage, graph = source
+        chunks = await _collect(
+            export_tenant(storage, "tenant1", vector_store=storage, graph_store=graph)
+        )
+        entries = [json.loads(c) for c in chunks]
+
+        assert entries[0]["type"] == "header"
+        assert entries[0]["version"] == 1
+        assert entries[-1]["type"] == "footer"
+        assert entries[-1]["counts"] == {"memory": 2, "vector": 1, "node": 2, "edge": 1}
+
+    @pytest.mark.asyncio
+    async def test_roundtrip_to_new_backend(self, source):
+        """Test importing a snapshot into a fresh backend and tenant."""
+        storage, graph = source
+        chunks = await _collect(
+            export_tenant(storage, "tenant1", vector_store=storage, graph_store=graph)
+        )
+        # Re-chunk arbitrarily to simulate a network stream
+        blob = b"".join(chunks)
+        rechunked = [blob[i : i + 7] for i in range(0, len(blob), 7)]
+
+        target = InMemoryStorage()
+        target_graph = FakeGraphStore()
+        result = await import_tenant(
+            target,
+            rechunked,
+            tenant_id="restored",
+            vector_store=target,
+            graph_store=target_graph,
+        )
+
+        assert result.memories == 2
+        assert result.vectors == 1
+        assert result.nodes == 2
+        assert result.edges == 1
+        assert await target.count_memories(tenant_id="restored") == 2
+
+        memories = await target.list_memories("restored")
+        tea = next(m for m in memories if m["content"] == "User likes tea")
+        assert tea["importance"] == 0.8
+        assert tea["tags"] == ["preference"]
+        assert await target.get_vector(tea["id"], "restored") is not None
+        assert target_graph.nodes[tea["id"]][2] == {"label": "tea"}
+
+        source_id, target_id, *_ = target_graph.edges[0]
+        assert str(source_id) in result.id_map.values()
+        assert isinstance(target_id, UUID)
+
+    @pytest.mark.asyncio
+    async def test_roundtrip_keeps_vectors_and_references(self):
+        """Test every model's vector, its metadata and ID references survive."""
+        storage = InMemoryStorage()
+        old = await storage.store_memory(
+            content="Launch in April",
+            tenant_id="tenant1",
+            agent_id="agent1",
+            session_id="s1",
+            metadata={"namespace": "launch"},
+        )
+        new = await storage.store_memory(
+            content="Launch in May",
+            tenant_id="tenant1",
+            agent_id="agent1",
+            session_id="s1",
+            metadata={"namespace": "launch", "supersedes": [str(old)]},
+        )
+        superseded = {"namespace": "launch", "superseded_by": str(new)}
+        await storage.update_memory(old, "tenant1", {"metadata": superseded})
+        for memory_id, vector in ((old, [1.0, 0.0]), (new, [0.0, 1.0])):
+            await storage.store_vector(
+                memory_id,
+                {"default": vector, "multilingual": [*vector, 0.5]},
+                "tenant1",
+            )
+        chunks = await _collect(
+            export_tenant(
+                storage,
+                "tenant1",
+                vector_store=storage,
+                vector_names=["default", "multilingual"],
+            )
+        )
+
+        target = InMemoryStorage()
+        result = await import_tenant(
+            target, chunks, tenant_id="restored", vector_store=target
+        )
+
+        assert result.vectors == 4
+        new_old, new_new = (UUID(result.id_map[str(m)]) for m in (old, new))
+        restored_old = await target.get_memory(new_old, "restored")
+        restored_new = await target.get_memory(new_new, "restored")
+        assert restored_old["metadata"]["superseded_by"] == str(new_new)
+        assert restored_new["metadata"]["supersedes"] == [str(new_old)]
+        assert await target.get_vector(
+            new_new, "restored", "multilingual"
+        ) == pytest.approx([0.0, 1.0, 0.5], abs=1e-4)
+
+        hits = await target.search_similar(
+            [0.0, 1.0, 0.5],
+            "restored",
+            session_id="s1",
+            filters={"namespace": "launch"},
+            vector_name="multilingual",
+        )
+        assert [memory_id for memory_id, _ in hits][0] == new_new
+        assert not await target.search_similar(
+            [0.0, 1.0], "restored", filters={"namespace": "other"}
+        )
+
+    @pytest.mark.asyncio
+    async def test_roundtrip_keeps_lifecycle(self):
+        """Test timestamps, counters and trash state survive a restore."""
+        clock = DeterministicClock()
+        clock.set_auto_increment(1000)
+        storage = InMemoryStorage(clock=clock)
+        kept = await storage.store_memory(
+            content="Kept", tenant_id="tenant1", agent_id="agent1"
+        )
+        trashed = await storage.store_memory(
+            content="Trashed", tenant_id="tenant1", agent_id="agent1"
+        )
+        await storage.update_memory(kept, "tenant1", {"content": "Kept, edited"})
+        await storage.record_access([kept, kept, kept], "tenant1")
+        await storage.soft_delete_memory(trashed, "tenant1")
+        chunks = await _collect(export_tenant(storage, "tenant1"))
+
+        target = InMemoryStorage()
+        result = await import_tenant(target, chunks, tenant_id="restored")
+
+        assert result.memories == 2
+        for old_id in (kept, trashed):
+            before = await storage.get_memory(old_id, "tenant1")
+            after = await target.get_memory(
+                UUID(result.id_map[str(old_id)]), "restored"
+            )
+            for field in LIFECYCLE_FIELDS:
+                assert after[field] == before[field], field
+        [live] = await target.list_memories("restored")
+        assert live["content"] == "Kept, edited"
+
+    @pytest.mark.asyncio
+    async def test_tampered_record_rejected(self, source):
+        """Test record checksum verification."""
+        storage, _ = source
+        chunks = await _collect(export_tenant(storage, "tenant1"))
+        chunks[1] = chunks[1].replace(b"User", b"Evil")
+
+        target = InMemoryStorage()
+        with pytest.raises(ValidationError):
+            await import_tenant(target, chunks)
+        assert await target.count_memories() == 0
+
+    @pytest.mark.asyncio
+    async def test_truncated_snapshot_rejected(self, source):
+        """Test missing footer detection."""
+        storage, _ = source
+        chunks = await _collect(export_tenant(storage, "tenant1"))
+
+        with pytest.raises(ValidationError, match="truncated"):
+            await read_snapshot(chunks[:-1])
+
+    @pytest.mark.asyncio
+    async def test_dropped_record_rejected(self, source):
+        """Test whole-stream digest verification."""
+        storage, _ = source
+        chunks = await _collect(export_tenant(storage, "tenant1"))
+        del chunks[1]
+
+        with pytest.raises(ValidationError, match="digest"):
+            await read_snapshot(chunks)
+
+    @pytest.mark.asyncio
+    async def test_unsupported_version_rejected(self):
+        """Test header version check."""
+        header = {"type": "header", "format": "rae-snapshot", "version": 99}
+        with pytest.raises(ValidationError, match="version"):
+            await read_snapshot([json.dumps(header).encode() + b"\n"])
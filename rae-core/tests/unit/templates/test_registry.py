@@ -0,0 +1,159 @@
+"""Unit tests for the memory template registry."""
+
+from datetime import date
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.exceptions.base import ValidationError
+from rae_core.models.template import MemoryTemplate, TemplateField, TemplateFieldType
+from rae_core.templates import TemplateRegistry
+
+
+class TestTemplateRegistry:
+    """Test suite for TemplateRegistry."""
+
+    @pytest.fixture
+    def registry(self):
+        return TemplateRegistry()
+
+    def test_builtin_templates(self, registry):
+        """Test meeting note and decision record are registered by default."""
+        names = {t.name for t in registry.list_templates()}
+        assert names == {"meeting_note", "decision_record"}
+        assert TemplateRegistry(include_builtins=False).list_templates() == []
+
+    def test_validate_missing_required_field(self, registry):
+        """Test a missing required field is rejected."""
+        with pytest.raises(ValidationError, match="summary"):
+            registry.validate(
+                "meeting_note", {"title": "Sync", "date": "2024-05-01"}
+            )
+
+    def test_validate_wrong_type(self, registry):
+        """Test a value of the wrong type is rejected."""
+        with pytest.raises(ValidationError, match="date"):
+            registry.validate(
+                "meeting_note",
+                {"title": "Sync", "date": "yesterday", "summary": "..."},
+            )
+
+    def test_validate_unknown_field_and_template(self, registry):
+        """Test unknown fields and unknown templates are rejected."""
+        with pytest.raises(ValidationError, match="owner"):
+            registry.validate(
+                "decision_record", {"title": "t", "decision": "d", "owner": "x"}
+            )
+        with pytest.raises(ValidationError, match="Unknown memory template"):
+            registry.validate("incident", {})
+
+    def test_validate_applies_defaults(self, registry):
+        """Test optional fields get their defaults and dates become strings."""
+        fields = registry.validate(
+            "meeting_note",
+            {"title": "Sync", "date": date(2024, 5, 1), "summary": "Went well"},
+        )
+        assert fields["date"] == "2024-05-01"
+        assert fields["attendees"] is None
+        assert registry.validate(
+            "decision_record", {"title": "t", "decision": "d"}
+        )["status"] == "accepted"
+
+    def test_render_drops_empty_optional_lines(self, registry):
+        """Test lines whose fields are all empty are left out of the content."""
+        content = registry.render(
+            "meeting_note",
+            {
+                "title": "Sync",
+                "date": "2024-05-01",
+                "summary": "Went well",
+                "attendees": ["ana", "bo"],
+                "action_items": None,
+            },
+        )
+        assert content == (
+            "Meeting note: Sync\n"
+            "Date: 2024-05-01\n"
+            "Attendees: ana, bo\n"
+            "Summary: Went well"
+        )
+
+    def test_build(self, registry):
+        """Test build returns store_memory arguments with fields in metadata."""
+        built = registry.build(
+            "decision_record",
+            {"title": "Use SQLite", "decision": "Adopt it"},
+            metadata={"source": "adr"},
+            tags=["storage", "decision"],
+        )
+        assert built["layer"] == "semantic"
+        assert built["tags"] == ["decision", "storage"]
+        assert built["content"].startswith("Decision: Use SQLite\nStatus: accepted")
+        assert built["metadata"] == {
+            "source": "adr",
+            "title": "Use SQLite",
+            "decision": "Adopt it",
+            "status": "accepted",
+            "context": None,
+            "alternatives": None,
+            "consequences": None,
+            "template": "decision_record",
+            "template_version": 1,
+        }
+
+    @pytest.mark.asyncio
+    async def test_structured_fields_are_queryable(self, registry):
+        """Test stored template fields can be filtered on via metadata."""
+        storage = InMemoryStorage()
+        for title, status in [("A", "accepted"), ("B", "superseded")]:
+            built = registry.build(
+                "decision_record",
+                {"title": title, "decision": "d", "status": status},
+            )
+            await storage.store_memory(tenant_id="t", **built)
+        await storage.store_memory(tenant_id="t", content="plain note")
+
+        memories = await storage.list_memories(
+            "t", filters={"template": "decision_record", "status": "accepted"}
+        )
+        assert [m["metadata"]["title"] for m in memories] == ["A"]
+
+    def test_register_custom_template(self, registry):
+        """Test custom templates can be registered and replaced."""
+        incident = MemoryTemplate(
+            name="incident",
+            fields=[
+                TemplateField(name="service"),
+                TemplateField(name="severity", type=TemplateFieldType.INTEGER),
+            ],
+            content_template="Incident in {service} (sev {severity})",
+        )
+        registry.register(incident)
+        assert registry.build("incident", {"service": "api", "severity": 2})[
+            "content"
+        ] == "Incident in api (sev 2)"
+
+        with pytest.raises(ValidationError, match="already registered"):
+            registry.register(incident)
+        registry.register(incident, replace=True)
+        assert registry.unregister("incident") is True
+        assert registry.unregister("incident") is False
+
+    def test_register_rejects_invalid_templates(self, registry):
+        """Test reserved field names and unknown placeholders are rejected."""
+        with pytest.raises(ValidationError, match="Reserved"):
+            registry.register(
+                MemoryTemplate(
+                    name="bad",
+                    fields=[TemplateField(name="template")],
+                    content_template="{template}",
+                )
+            )
+        with pytest.raises(ValidationError, match="unknown fields"):
+            registry.register(
+                MemoryTemplate(
+                    name="bad",
+                    fields=[TemplateField(name="title")],
+                    content_template="{title} {body}",
+                )
+            )
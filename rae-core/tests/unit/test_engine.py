@@ -1,3 +1,4 @@
+import asyncio
 from unittest.mock import AsyncMock, Mock, patch
 from uuid import uuid4
 
@@ -178,3 +179,783 @@ async def test_get_status(rae_engine):
     assert "engine" in status
     assert "components" in status
     assert "search_strategies" in status
+
+
+@pytest.mark.asyncio
+async def test_recall_reports_no_relevant_memory(rae_engine):
+    rae_engine.search_memories = AsyncMock(
+        return_value=[{"id": uuid4(), "content": "weak", "math_score": 0.35}]
+    )
+    rae_engine.settings = Mock(relevance_floor=0.5)
+
+    result = await rae_engine.recall("query", "tenant")
+
+    assert result.no_relevant_memory is True
+    assert result.best_rejected_score == 0.35
+
+    result = await rae_engine.recall("query", "tenant", floor=0.3)
+    assert len(result.memories) == 1
+
+
+@pytest.mark.asyncio
+async def test_store_from_template(rae_engine):
+    rae_engine.store_memory = AsyncMock(return_value="mem-1")
+
+    result = await rae_engine.store_from_template(
+        "decision_record",
+        {"title": "Use SQLite", "decision": "Adopt SQLite for local mode"},
+        tenant_id="tenant",
+        agent_id="agent",
+    )
+
+    assert result == "mem-1"
+    kwargs = rae_engine.store_memory.call_args.kwargs
+    assert kwargs["tenant_id"] == "tenant"
+    assert kwargs["agent_id"] == "agent"
+    assert kwargs["layer"] == "semantic"
+    assert kwargs["content"].startswith("Decision: Use SQLite")
+    assert kwargs["metadata"]["template"] == "decision_record"
+    assert kwargs["metadata"]["status"] == "accepted"
+
+
+def test_tenant_strategies_respect_embedding_model(rae_engine):
+    from rae_core.embedding.manager import EmbeddingManager
+
+    provider = Mock(spec=IEmbeddingProvider)
+    provider.get_dimension.return_value = 3
+    manager = EmbeddingManager(provider, default_model_name="cloud")
+    manager.register_provider("onprem", provider)
+    manager.set_tenant_model("acme", "onprem")
+    rae_engine.embedding_provider = manager
+    rae_engine.search_engine.strategies = {
+        "fulltext": Mock(),
+        "vector_cloud": Mock(),
+        "vector_onprem": Mock(),
+    }
+
+    assert rae_engine._tenant_strategies("acme", None) == [
+        "fulltext",
+        "vector_onprem",
+    ]
+    assert rae_engine._tenant_strategies("other", None) is None
+
+
+@pytest.mark.asyncio
+async def test_store_memory_dry_run(rae_engine, mock_memory_storage, mock_vector_store):
+    rae_engine.embedding_provider.embed_text = AsyncMock(return_value=[0.1, 0.2])
+    mock_vector_store.store_vector = AsyncMock()
+
+    plan = await rae_engine.store_memory(
+        content="The staging cluster runs in eu-west-1",
+        tenant_id="tenant",
+        agent_id="agent",
+        dry_run=True,
+    )
+
+    mock_memory_storage.store_memory.assert_not_called()
+    mock_vector_store.store_vector.assert_not_called()
+    assert len(plan.records) == 1
+    record = plan.records[0]
+    assert record.fields["tenant_id"] == "tenant"
+    assert record.fields["layer"] == "episodic"
+    assert "dry_run" not in record.fields
+    assert plan.vectors[0].memory_id == record.memory_id
+    assert plan.vectors[0].embedding == [0.1, 0.2]
+    assert plan.result == record.memory_id
+
+
+@pytest.mark.asyncio
+async def test_recall_records_accesses(rae_engine, mock_memory_storage):
+    kept, dropped = uuid4(), uuid4()
+    rae_engine.search_memories = AsyncMock(
+        return_value=[
+            {"id": kept, "content": "strong", "math_score": 0.9},
+            {"id": dropped, "content": "weak", "math_score": 0.2},
+        ]
+    )
+    mock_memory_storage.record_access = AsyncMock(return_value=1)
+
+    await rae_engine.recall("query", "tenant", floor=0.5)
+    mock_memory_storage.record_access.assert_not_awaited()
+    await rae_engine.access_tracker.flush()
+
+    mock_memory_storage.record_access.assert_awaited_once()
+    assert mock_memory_storage.record_access.await_args.args[:2] == ([kept], "tenant")
+
+
+@pytest.mark.asyncio
+async def test_recall_similar_returns_records(rae_engine):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+    from rae_core.models.search import SimilarityRecallOptions
+
+    storage = InMemoryStorage()
+    memory_id = await storage.store_memory(content="fact", tenant_id="t1")
+    await storage.store_vector(memory_id, [1.0, 0.0], "t1")
+    rae_engine.memory_storage = rae_engine.vector_store = storage
+    rae_engine.embedding_provider.embed_text = AsyncMock(return_value=[1.0, 0.0])
+    rae_engine.access_tracker.storage = storage
+
+    results = await rae_engine.recall_similar(
+        "query", "t1", SimilarityRecallOptions(top_k=5)
+    )
+    await rae_engine.access_tracker.flush()
+
+    assert [r.memory["content"] for r in results] == ["fact"]
+    assert (await storage.get_memory(memory_id, "t1"))["access_count"] == 1
+
+    related = await rae_engine.recall_similar_to(str(memory_id), "t1")
+    assert related == []
+
+
+@pytest.mark.asyncio
+async def test_recall_reranks_top_results(rae_engine):
+    first, second, third = uuid4(), uuid4(), uuid4()
+    rae_engine.search_memories = AsyncMock(
+        return_value=[
+            {"id": first, "content": "a", "math_score": 0.9},
+            {"id": second, "content": "b", "math_score": 0.8},
+            {"id": third, "content": "c", "math_score": 0.7},
+        ]
+    )
+    rae_engine.settings = Mock(relevance_floor=None, rerank_top_k=2)
+    rae_engine.reranker = Mock()
+    rae_engine.reranker.rerank = AsyncMock(
+        return_value=[(second, 0.95, 0.5), (first, 0.6, 0.5)]
+    )
+
+    result = await rae_engine.recall("query", "tenant", top_k=2)
+
+    rae_engine.search_memories.assert_awaited_once_with("query", "tenant", top_k=2)
+    assert [m["id"] for m in result.memories] == [second, first]
+    assert result.memories[0]["retrieval_score"] == 0.8
+
+    rae_engine.reranker.rerank.side_effect = RuntimeError("model down")
+    result = await rae_engine.recall("query", "tenant", top_k=2, rerank_depth=3)
+    assert [m["id"] for m in result.memories] == [first, second]
+
+    result = await rae_engine.recall("query", "tenant", rerank=False)
+    assert len(result.memories) == 3
+
+
+@pytest.mark.asyncio
+async def test_close_session_consolidates_working_memories(rae_engine):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    ids = {}
+    for name, layer, session_id in [
+        ("turn", "working", "s1"),
+        ("fact", "semantic", "s1"),
+        ("other", "working", "s2"),
+    ]:
+        ids[name] = await storage.store_memory(
+            content=name,
+            layer=layer,
+            tenant_id="t1",
+            agent_id="a1",
+            session_id=session_id,
+            importance=0.5,
+        )
+
+    consolidated = await rae_engine.close_session("t1", "s1")
+
+    assert consolidated == [ids["turn"]]
+    turn = await storage.get_memory(ids["turn"], "t1")
+    assert turn["layer"] == "episodic"
+    assert turn["session_id"] == "s1"
+    assert turn["importance"] == pytest.approx(0.7)
+    assert (await storage.get_memory(ids["other"], "t1"))["layer"] == "working"
+    assert await rae_engine.close_session("t1", "s1") == []
+
+
+@pytest.mark.asyncio
+async def test_share_memory_and_recall_through_policy(rae_engine):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+    from rae_core.guards.sharing import SharingPolicy
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    ids = [
+        await storage.store_memory(
+            content=f"note {i}", layer="episodic", tenant_id="t1", agent_id="a1"
+        )
+        for i in range(2)
+    ]
+
+    assert await rae_engine.share_memory(ids[0], "t1", team_id="research")
+    shared = await storage.get_memory(ids[0], "t1")
+    assert shared["metadata"]["scope"] == "team"
+    assert shared["metadata"]["team_id"] == "research"
+    with pytest.raises(ValueError):
+        await rae_engine.share_memory(ids[1], "t1", scope="team")
+
+    rae_engine.sharing_policy = SharingPolicy(teams={"research": ["a2"]})
+    rae_engine.search_engine.search = AsyncMock(
+        return_value=[(mid, 0.9, 0.9, {}) for mid in ids]
+    )
+    results = await rae_engine.search_memories(
+        "note", "t1", agent_id="a2", custom_weights={"fulltext": 1.0}
+    )
+
+    assert [m["id"] for m in results] == [ids[0]]
+    search_filters = rae_engine.search_engine.search.call_args.kwargs["filters"]
+    assert "agent_id" not in search_filters
+
+
+@pytest.mark.asyncio
+async def test_reader_keeps_its_memories_among_private_ones(rae_engine):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+    from rae_core.guards.sharing import SharingPolicy
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    rae_engine.sharing_policy = SharingPolicy()
+    others = [
+        await storage.store_memory(content="note", tenant_id="t1", agent_id="b")
+        for _ in range(600)
+    ]
+    own = [
+        await storage.store_memory(content="note", tenant_id="t1", agent_id="a")
+        for _ in range(3)
+    ]
+    # Agent b's private memories outrank the reader's own
+    ranked = [(mid, 0.9, 0.5, {}) for mid in others] + [
+        (mid, 0.5, 0.5, {}) for mid in own
+    ]
+
+    async def search(**kwargs):
+        return ranked[: kwargs["limit"]]
+
+    rae_engine.search_engine.search = AsyncMock(side_effect=search)
+    results = await rae_engine.search_memories(
+        "note", "t1", agent_id="a", top_k=3, custom_weights={"fulltext": 1.0}
+    )
+
+    assert {m["id"] for m in results} == set(own)
+
+
+@pytest.mark.asyncio
+async def test_load_shedder_rejects_saturated_tenant(rae_engine):
+    from rae_core.exceptions.base import OverloadedError
+    from rae_core.governance import LoadShedder
+    from rae_core.models.load import LoadLimits
+
+    shedder = LoadShedder(LoadLimits(max_concurrent=1, max_queued=0))
+    rae_engine.load_shedder = shedder
+    release = asyncio.Event()
+
+    async def hold():
+        async with shedder.admit("t1"):
+            await release.wait()
+
+    holder = asyncio.ensure_future(hold())
+    await asyncio.sleep(0)
+
+    with pytest.raises(OverloadedError):
+        await rae_engine.search_memories("query", "t1", priority="batch")
+    with pytest.raises(OverloadedError):
+        await rae_engine.store_memory(content="note", tenant_id="t1")
+    assert shedder.stats("t1").rejected == 2
+
+    release.set()
+    await holder
+
+
+@pytest.mark.asyncio
+async def test_memory_cards(rae_engine):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    rae_engine.llm_provider = None
+    memory_id = await storage.store_memory(
+        content="Prefers tea", layer="semantic", tenant_id="t1", agent_id="a1"
+    )
+    await storage.store_memory(
+        content="Other user", layer="semantic", tenant_id="t1", agent_id="a2"
+    )
+
+    cards = await rae_engine.memory_cards("t1", agent_id="a1")
+
+    assert [(c.memory_id, c.title) for c in cards] == [(memory_id, "Prefers tea")]
+
+
+@pytest.mark.asyncio
+async def test_store_memory_redacts_pii(
+    rae_engine, mock_memory_storage, mock_embedding_provider
+):
+    from rae_core.ingestion.redactor import PII_METADATA_KEY, PiiRedactor
+
+    rae_engine.pii_redactor = PiiRedactor()
+    mock_memory_storage.store_memory.return_value = uuid4()
+    mock_embedding_provider.embed_text.return_value = [0.1, 0.2]
+
+    await rae_engine.store_memory(
+        tenant_id="t1", agent_id="a1", content="Reach me at ola@example.com"
+    )
+
+    stored = mock_memory_storage.store_memory.call_args.kwargs
+    assert stored["content"] == "Reach me at [EMAIL]"
+    assert stored["metadata"][PII_METADATA_KEY]["counts"] == {"email": 1}
+    mock_embedding_provider.embed_text.assert_called_once_with(
+        "Reach me at [EMAIL]", task_type="search_document"
+    )
+
+
+@pytest.mark.asyncio
+async def test_generate_subject_report(rae_engine):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    memory_id = await storage.store_memory(
+        content="Anna prefers tea", layer="episodic", tenant_id="t1", agent_id="a1"
+    )
+    await storage.store_memory(
+        content="Bob prefers coffee", layer="episodic", tenant_id="t1", agent_id="a1"
+    )
+
+    report = await rae_engine.generate_subject_report("anna", "t1")
+
+    assert [m["id"] for m in report.memories] == [memory_id]
+    assert report.facts == [] and report.relationships == []
+
+
+@pytest.mark.asyncio
+async def test_erase_subject(rae_engine):
+    from rae_core.adapters.memory.audit import InMemoryAuditLogger
+    from rae_core.adapters.memory.storage import InMemoryStorage
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    rae_engine.vector_store = None
+    audit = InMemoryAuditLogger()
+    memory_id = await storage.store_memory(
+        content="Anna prefers tea", tenant_id="t1", agent_id="a1"
+    )
+
+    result = await rae_engine.erase_subject("t1", "anna", audit_logger=audit)
+
+    assert result.memory_ids == [memory_id]
+    assert await storage.get_memory(memory_id, "t1") is None
+    assert len(await audit.query("t1")) == 1
+
+
+@pytest.mark.asyncio
+async def test_remember_ephemeral(rae_engine, mock_vector_store):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    rae_engine.embedding_provider.embed_text.return_value = [0.1, 0.2]
+
+    memory_id = await rae_engine.remember_ephemeral(
+        "OTP 482913", "t1", ttl=60, agent_id="a1", session_id="s1"
+    )
+    purger = rae_engine.ephemeral_purger
+    await purger.stop()
+
+    memory = await storage.get_memory(memory_id, "t1")
+    assert memory["layer"] == "working"
+    assert memory["metadata"]["ephemeral"] is True
+    assert memory["expires_at"] is not None
+    assert purger.pending == 1
+    mock_vector_store.store_vector.assert_called_once()
+    assert await rae_engine.close_session("t1", "s1") == []
+    with pytest.raises(ValueError):
+        await rae_engine.remember_ephemeral("code", "t1", ttl=0)
+
+    await purger.purge(memory_id, "t1")
+    assert await storage.get_memory(memory_id, "t1") is None
+
+
+@pytest.mark.asyncio
+async def test_search_memories_merges_global_knowledge(rae_engine):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+    from rae_core.search.global_knowledge import GLOBAL_TENANT_ID, SOURCE_KEY
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    private = await storage.store_memory(
+        content="our refund window is 60 days", tenant_id="t1", agent_id="a1"
+    )
+    shared = await storage.store_memory(
+        content="refunds follow the product policy",
+        tenant_id=GLOBAL_TENANT_ID,
+        agent_id="operator",
+    )
+    hits = {"t1": [(private, 0.9, 0.5, {})], GLOBAL_TENANT_ID: [(shared, 0.9, 0.5, {})]}
+    rae_engine.search_engine.search = AsyncMock(
+        side_effect=lambda **kwargs: hits[kwargs["tenant_id"]]
+    )
+
+    results = await rae_engine.search_memories(
+        "refund", "t1", agent_id="a1", custom_weights={"fulltext": 1.0}
+    )
+    assert [m["id"] for m in results] == [private]
+
+    results = await rae_engine.search_memories(
+        "refund",
+        "t1",
+        agent_id="a1",
+        custom_weights={"fulltext": 1.0},
+        include_global=True,
+    )
+    by_source = {m[SOURCE_KEY]: m for m in results}
+    assert by_source["tenant"]["id"] == private
+    assert by_source["global"]["id"] == shared
+    assert by_source["global"]["math_score"] == pytest.approx(
+        by_source["global"]["global_score"] * 0.8
+    )
+    global_call = rae_engine.search_engine.search.call_args_list[-1].kwargs
+    assert "agent_id" not in global_call["filters"]
+
+
+@pytest.mark.asyncio
+async def test_store_global_knowledge(rae_engine):
+    from rae_core.search.global_knowledge import GLOBAL_TENANT_ID
+
+    rae_engine.store_memory = AsyncMock(return_value=uuid4())
+
+    await rae_engine.store_global_knowledge("Support hours are 9-17 CET")
+
+    kwargs = rae_engine.store_memory.call_args.kwargs
+    assert kwargs["tenant_id"] == GLOBAL_TENANT_ID
+    with pytest.raises(ValueError):
+        await rae_engine.store_global_knowledge("x", tenant_id="t1")
+
+
+@pytest.mark.asyncio
+async def test_retrieve_applies_token_budget(rae_engine):
+    from rae_core.models.retrieval import RetrievalRequest
+    from rae_core.models.search import RecallResult
+
+    rae_engine.recall = AsyncMock(
+        return_value=RecallResult(
+            memories=[
+                {"id": "m1", "content": "a" * 40, "math_score": 0.9},
+                {"id": "m2", "content": "b" * 80, "math_score": 0.8},
+                {"id": "m3", "content": "c" * 8, "math_score": 0.7},
+            ],
+            floor=0.5,
+        )
+    )
+    request = RetrievalRequest.model_validate(
+        {"query": "q", "budget": {"top_k": 3, "max_tokens": 15}}
+    )
+
+    response = await rae_engine.retrieve(request, "tenant")
+
+    rae_engine.recall.assert_awaited_once_with("q", "tenant", top_k=3)
+    assert [r.id for r in response.results] == ["m1", "m3"]
+    assert response.tokens.used == 12
+    assert response.tokens.dropped == 1
+    assert response.floor == 0.5
+    assert response.no_relevant_memory is False
+
+
+@pytest.mark.asyncio
+async def test_store_memory_merges_redundant_writes(
+    rae_engine, mock_memory_storage, mock_embedding_provider
+):
+    from rae_core.ingestion.novelty import NoveltyDecision
+
+    existing = uuid4()
+    rae_engine.novelty_gate = Mock()
+    rae_engine.novelty_gate.evaluate = AsyncMock(
+        return_value=NoveltyDecision(novel=False, novelty=0.01, duplicate_of=existing)
+    )
+    mock_memory_storage.update_memory_access = AsyncMock(return_value=True)
+    mock_memory_storage.store_memory.return_value = uuid4()
+    mock_embedding_provider.embed_text.return_value = [0.1, 0.2]
+
+    memory_id = await rae_engine.store_memory(
+        tenant_id="t1", agent_id="a1", content="The user likes tea"
+    )
+
+    assert memory_id == existing
+    mock_memory_storage.update_memory_access.assert_awaited_once_with(existing, "t1")
+    mock_memory_storage.store_memory.assert_not_called()
+
+    await rae_engine.store_memory(
+        tenant_id="t1", agent_id="a1", content="The user likes tea", check_novelty=False
+    )
+    assert "check_novelty" not in mock_memory_storage.store_memory.call_args.kwargs
+
+
+@pytest.mark.asyncio
+async def test_store_memory_checks_contradictions(
+    rae_engine, mock_memory_storage, mock_embedding_provider
+):
+    memory_id = uuid4()
+    mock_memory_storage.store_memory.return_value = memory_id
+    mock_embedding_provider.embed_text.return_value = [0.1, 0.2]
+    rae_engine.contradiction_detector = Mock()
+    rae_engine.contradiction_detector.check = AsyncMock(side_effect=RuntimeError)
+
+    result = await rae_engine.store_memory(
+        tenant_id="t1", content="The meeting is on Monday", project="p1"
+    )
+
+    assert result == memory_id
+    args = rae_engine.contradiction_detector.check.await_args
+    assert args.args[:2] == (memory_id, "t1")
+    assert args.kwargs["project"] == "p1"
+
+    await rae_engine.store_memory(
+        tenant_id="t1", content="The meeting is on Friday", check_contradictions=False
+    )
+    assert rae_engine.contradiction_detector.check.await_count == 1
+    assert "check_contradictions" not in (
+        mock_memory_storage.store_memory.call_args.kwargs
+    )
+
+
+@pytest.mark.asyncio
+async def test_store_memory_keeps_structured_source(
+    rae_engine, mock_memory_storage, mock_embedding_provider
+):
+    from rae_core.models.provenance import MemorySource, SourceType
+
+    mock_memory_storage.store_memory.return_value = uuid4()
+    mock_embedding_provider.embed_text.return_value = [0.1, 0.2]
+    source = MemorySource(type=SourceType.TOOL_OUTPUT, url="https://x.test/a")
+
+    await rae_engine.store_memory(
+        tenant_id="t1", content="Page says hello", metadata={"k": 1}, source=source
+    )
+
+    stored = mock_memory_storage.store_memory.call_args.kwargs
+    assert stored["metadata"]["k"] == 1
+    assert stored["metadata"]["origin"] == {
+        "type": "tool_output",
+        "url": "https://x.test/a",
+        "derived_from": [],
+    }
+    assert stored["source"].startswith("https://x.test/a")
+
+
+@pytest.mark.asyncio
+async def test_store_memory_records_confidence(
+    rae_engine, mock_memory_storage, mock_embedding_provider
+):
+    from rae_core.exceptions.base import ValidationError
+
+    mock_memory_storage.store_memory.return_value = uuid4()
+    mock_embedding_provider.embed_text.return_value = [0.1, 0.2]
+
+    await rae_engine.store_memory(
+        tenant_id="t1", content="Maybe it rains", importance=0.9, confidence=0.3
+    )
+
+    stored = mock_memory_storage.store_memory.call_args.kwargs
+    assert stored["metadata"]["confidence"] == 0.3
+    assert stored["importance"] == 0.9
+    assert "confidence" not in stored
+    with pytest.raises(ValidationError):
+        await rae_engine.store_memory(tenant_id="t1", content="x", confidence=2.0)
+
+
+@pytest.mark.asyncio
+async def test_namespaces_partition_store_and_search(
+    rae_engine, mock_memory_storage, mock_embedding_provider
+):
+    from rae_core.adapters.memory.storage import InMemoryStorage
+    from rae_core.exceptions.base import ValidationError
+
+    mock_memory_storage.store_memory.return_value = uuid4()
+    mock_embedding_provider.embed_text.return_value = [0.1, 0.2]
+
+    await rae_engine.store_memory(tenant_id="t1", content="Q3 plan", namespace="work")
+
+    stored = mock_memory_storage.store_memory.call_args.kwargs
+    assert stored["metadata"]["namespace"] == "work"
+    assert "namespace" not in stored
+    with pytest.raises(ValidationError):
+        await rae_engine.store_memory(tenant_id="t1", content="x", namespace="Bad Name")
+
+    storage = InMemoryStorage()
+    rae_engine.memory_storage = storage
+    work = await storage.store_memory(
+        content="plan a", tenant_id="t1", metadata={"namespace": "work"}
+    )
+    other = await storage.store_memory(content="plan b", tenant_id="t1")
+    rae_engine.search_engine.search = AsyncMock(
+        return_value=[(work, 0.9, 0.5, {}), (other, 0.9, 0.5, {})]
+    )
+
+    results = await rae_engine.search_memories(
+        "plan", "t1", custom_weights={"fulltext": 1.0}, namespace="work"
+    )
+
+    assert [m["id"] for m in results] == [work]
+    # Strategies get the namespace, so it applies before the engine limit
+    filters = rae_engine.search_engine.search.call_args.kwargs["filters"]
+    assert filters["namespace"] == "work"
+
+
+@pytest.mark.asyncio
+async def test_resolve_conflict_merge_stores_the_merged_memory(
+    rae_engine, mock_memory_storage, mock_embedding_provider
+):
+    from rae_core.models.consistency import SUPERSEDED_KEY
+    from rae_core.models.graph import EdgeType
+
+    a, b, merged = uuid4(), uuid4(), uuid4()
+    records = {
+        a: {"id": a, "content": "Launch in April", "importance": 0.7},
+        b: {"id": b, "content": "Launch in May", "importance": 0.4},
+        merged: {"id": merged, "content": "merged"},
+    }
+    mock_memory_storage.get_memory.side_effect = lambda m, t: records.get(m)
+    mock_memory_storage.store_memory.return_value = merged
+    mock_memory_storage.update_memory = AsyncMock(return_value=True)
+    mock_embedding_provider.embed_text.return_value = [0.1, 0.2]
+    rae_engine.contradiction_detector = Mock()
+    rae_engine.contradiction_detector.check = AsyncMock()
+    graph_store = rae_engine.contradiction_detector.graph_store = AsyncMock()
+
+    result = await rae_engine.resolve_conflict(
+        str(a), b, "merge", "t1", merged_content="Launch moved to May"
+    )
+
+    assert result.merged_id == merged
+    stored = mock_memory_storage.store_memory.call_args.kwargs
+    assert stored["content"] == "Launch moved to May"
+    assert stored["importance"] == 0.7
+    rae_engine.contradiction_detector.check.assert_not_awaited()
+    updates = {
+        call.args[0]: call.args[2]
+        for call in mock_memory_storage.update_memory.await_args_list
+    }
+    assert updates[a]["metadata"][SUPERSEDED_KEY] == str(merged)
+    assert updates[b]["metadata"][SUPERSEDED_KEY] == str(merged)
+    deleted = {call.args[:3] for call in graph_store.delete_edge.await_args_list}
+    assert deleted == {
+        (a, b, EdgeType.CONTRADICTS.value),
+        (b, a, EdgeType.CONTRADICTS.value),
+    }
+    created = {call.args[:3] for call in graph_store.create_edge.await_args_list}
+    assert created == {
+        (a, merged, EdgeType.SUPERSEDED_BY.value),
+        (b, merged, EdgeType.SUPERSEDED_BY.value),
+    }
+
+
+@pytest.mark.asyncio
+async def test_store_image_embeds_the_image(
+    rae_engine, mock_memory_storage, mock_vector_store
+):
+    from rae_core.exceptions.base import ValidationError
+
+    with pytest.raises(ValidationError):
+        await rae_engine.store_image(b"\x89PNG", "t1")
+
+    class Clip:
+        embed_text = AsyncMock(return_value=[0.1, 0.2])
+        embed_batch = AsyncMock(return_value=[[0.1, 0.2]])
+        embed_image = AsyncMock(return_value=[0.3, 0.4])
+
+        def get_dimension(self):
+            return 2
+
+    rae_engine.embedding_provider = Clip()
+    memory_id = uuid4()
+    mock_memory_storage.store_memory.return_value = memory_id
+
+    result = await rae_engine.store_image(b"\x89PNG", "t1", caption="a cat")
+
+    assert result == memory_id
+    stored = mock_memory_storage.store_memory.call_args.kwargs
+    assert stored["content"] == "a cat"
+    assert stored["memory_type"] == "image"
+    vector_args = mock_vector_store.store_vector.call_args.args
+    assert vector_args == (memory_id, [0.3, 0.4], "t1")
+
+
+@pytest.mark.asyncio
+async def test_store_verbatim_skips_the_ingest_pipeline(
+    rae_engine, mock_memory_storage, mock_embedding_provider, mock_vector_store
+):
+    code = "def f():\n\n\n    return  1"
+    memory_id = uuid4()
+    mock_memory_storage.store_memory.return_value = memory_id
+    mock_embedding_provider.embed_text.return_value = [0.1, 0.2]
+
+    result = await rae_engine.store_verbatim(
+        code, "t1", agent_id="a1", layer="semantic", memory_type="code"
+    )
+
+    assert result == memory_id
+    mock_memory_storage.store_memory.assert_called_once_with(
+        content=code,
+        tenant_id="t1",
+        agent_id="a1",
+        layer="semantic",
+        memory_type="code",
+    )
+    mock_embedding_provider.embed_text.assert_called_once_with(
+        code, task_type="search_document"
+    )
+    mock_vector_store.store_vector.assert_called_once()
+
+
+@pytest.mark.asyncio
+async def test_embed_documents_and_store_embedded(
+    rae_engine, mock_memory_storage, mock_vector_store, mock_embedding_provider
+):
+    memory_id = uuid4()
+    mock_memory_storage.store_memory.return_value = memory_id
+    mock_embedding_provider.embed_batch.return_value = [[0.1], [0.2]]
+
+    embeddings = await rae_engine.embed_documents(["a", "b"], "t1")
+    stored = await rae_engine.store_embedded(
+        "a", "t1", embeddings[0], layer="semantic", confidence=0.5
+    )
+
+    assert embeddings == [[0.1], [0.2]]
+    assert stored == memory_id
+    mock_embedding_provider.embed_batch.assert_called_once_with(
+        ["a", "b"], task_type="search_document"
+    )
+    mock_embedding_provider.embed_text.assert_not_called()
+    assert mock_memory_storage.store_memory.call_args.kwargs["metadata"] == {
+        "confidence": 0.5
+    }
+    args = mock_vector_store.store_vector.call_args.args
+    assert args[:3] == (memory_id, [0.1], "t1")
+
+
+@pytest.mark.asyncio
+async def test_search_memories_skips_soft_deleted(rae_engine, tmp_path):
+    pytest.importorskip("aiosqlite")
+    from rae_core.adapters.sqlite import SQLiteStorage, SQLiteVectorStore
+
+    db_path = str(tmp_path / "rae.db")
+    storage, vectors = SQLiteStorage(db_path), SQLiteVectorStore(db_path)
+    await storage.initialize()
+    kept = await storage.store_memory(content="green tea", tenant_id="t1")
+    trashed = await storage.store_memory(content="black tea", tenant_id="t1")
+    for memory_id in (kept, trashed):
+        await vectors.store_vector(memory_id, [1.0, 0.0], "t1")
+    assert await storage.soft_delete_memory(trashed, "t1")
+
+    async def vector_search(query, tenant_id, **kwargs):
+        # The vector store still returns the trashed memory's vector
+        hits = await vectors.search_similar([1.0, 0.0], tenant_id)
+        return [(memory_id, score, 0.5, {}) for memory_id, score in hits]
+
+    rae_engine.memory_storage = storage
+    rae_engine.vector_store = vectors
+    rae_engine.search_engine.search = AsyncMock(side_effect=vector_search)
+
+    results = await rae_engine.search_memories(
+        "tea", "t1", custom_weights={"vector": 1.0}
+    )
+    with_trash = await rae_engine.search_memories(
+        "tea", "t1", custom_weights={"vector": 1.0}, include_deleted=True
+    )
+
+    assert [str(m["id"]) for m in results] == [str(kept)]
+    assert {str(m["id"]) for m in with_trash} == {str(kept), str(trashed)}
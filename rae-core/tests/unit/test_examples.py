@@ -0,0 +1,61 @@
+"""Smoke tests running the example programs end to end.
+
+The examples compose RAEEngine over SQLite backends with the offline hashing
+embedder, so they exercise the public API the way an application would.
+"""
+
+import importlib.util
+from pathlib import Path
+from types import ModuleType
+
+import pytest
+
+pytest.importorskip("numpy")
+pytest.importorskip("aiosqlite")
+
+EXAMPLES_DIR = Path(__file__).resolve().parents[2] / "examples"
+
+
+def load_example(name: str) -> ModuleType:
+    spec = importlib.util.spec_from_file_location(name, EXAMPLES_DIR / f"{name}.py")
+    assert spec is not None and spec.loader is not None
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+
+class TestExamples:
+    """Test suite for the example programs."""
+
+    @pytest.mark.asyncio
+    async def test_chat_agent(self, tmp_path):
+        """Test remember, recall, session close and reflection."""
+        chat_agent = load_example("chat_agent")
+
+        result = await chat_agent.run(tmp_path)
+
+        assert len(result["remembered"]) == len(chat_agent.CONVERSATION)
+        assert result["consolidated"]
+        assert result["reflection"]["success"] is True
+        assert result["answer"]
+
+    @pytest.mark.asyncio
+    async def test_knowledge_ingest(self, tmp_path):
+        """Test seeding, document ingest and recall."""
+        knowledge_ingest = load_example("knowledge_ingest")
+
+        result = await knowledge_ingest.run(tmp_path)
+
+        report = result["report"]
+        assert len(report.files) == 2
+        assert report.memory_ids
+        assert result["runbook_id"] is not None
+        answers = result["answers"]
+        assert answers["Where are invoices stored?"].memories
+
+    def test_main_uses_temporary_directory(self, capsys):
+        """Test the command line entry point runs without arguments."""
+        chat_agent = load_example("chat_agent")
+
+        assert chat_agent.main([]) == 0
+        assert "question>" in capsys.readouterr().out
@@ -0,0 +1,112 @@
+"""Unit tests for backend health checks."""
+
+import asyncio
+
+import pytest
+
+from rae_core.adapters.memory.cache import InMemoryCache
+from rae_core.adapters.memory.graph import InMemoryGraphStore
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.adapters.overlay import OverlayStorage
+from rae_core.health import SystemHealth, components_healthy
+from rae_core.models.health import HealthStatus
+
+
+class FailingBackend:
+    async def health_check(self) -> bool:
+        raise ConnectionError("connection refused")
+
+
+class SlowBackend:
+    async def health_check(self) -> bool:
+        await asyncio.sleep(1)
+        return True
+
+
+class DownBackend:
+    async def health_check(self) -> bool:
+        return False
+
+
+class TestSystemHealth:
+    """Test suite for SystemHealth."""
+
+    @pytest.mark.asyncio
+    async def test_all_healthy(self):
+        """Test in-memory backends report ok with a latency."""
+        health = SystemHealth(
+            {
+                "storage": InMemoryStorage(),
+                "graph": InMemoryGraphStore(),
+                "cache": InMemoryCache(),
+                "llm": None,
+            }
+        )
+
+        report = await health.check_all()
+
+        assert report.healthy
+        assert [c.name for c in report.components] == ["storage", "graph", "cache"]
+        assert all(c.status is HealthStatus.OK for c in report.components)
+        assert all(c.latency_ms is not None for c in report.components)
+
+    @pytest.mark.asyncio
+    async def test_failures(self):
+        """Test errors, False results and timeouts mark components unhealthy."""
+        health = SystemHealth(
+            {
+                "storage": FailingBackend(),
+                "vector_store": DownBackend(),
+                "embedding": SlowBackend(),
+                "custom": object(),
+            },
+            timeout=0.01,
+        )
+
+        report = await health.check_all()
+        by_name = {c.name: c for c in report.components}
+
+        assert report.status is HealthStatus.UNHEALTHY
+        assert by_name["storage"].error == "connection refused"
+        assert by_name["vector_store"].status is HealthStatus.UNHEALTHY
+        assert "timed out" in by_name["embedding"].error
+        assert by_name["custom"].status is HealthStatus.UNKNOWN
+        assert by_name["custom"].latency_ms is None
+
+    @pytest.mark.asyncio
+    async def test_unknown_components_keep_system_healthy(self):
+        """Test components without a health check do not fail readiness."""
+        report = await SystemHealth({"custom": object()}).check_all()
+        assert report.healthy
+
+    @pytest.mark.asyncio
+    async def test_wrapped_backends(self):
+        """Test wrappers are healthy only if every wrapped backend is."""
+        assert await components_healthy(InMemoryStorage(), object())
+        overlay = OverlayStorage(InMemoryStorage(), [InMemoryStorage()])
+        assert await overlay.health_check()
+        overlay.lower.append(DownBackend())
+        assert not await overlay.health_check()
+
+
+@pytest.mark.asyncio
+async def test_sqlite_health_check(tmp_path):
+    """Test the SQLite backends answer their health checks."""
+    pytest.importorskip("aiosqlite")
+    pytest.importorskip("numpy")
+    from rae_core.adapters.sqlite import (
+        SQLiteGraphStore,
+        SQLiteStorage,
+        SQLiteVectorStore,
+    )
+
+    db_path = str(tmp_path / "rae.db")
+    report = await SystemHealth(
+        {
+            "storage": SQLiteStorage(db_path),
+            "vector_store": SQLiteVectorStore(db_path),
+            "graph": SQLiteGraphStore(db_path),
+        }
+    ).check_all()
+
+    assert report.healthy
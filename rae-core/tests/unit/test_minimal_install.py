@@ -0,0 +1,82 @@
+"""Guard the minimal default install against optional dependencies.
+
+Core interfaces, models and in-memory adapters must import without any of the
+packages that live behind optional extras in pyproject.toml.
+"""
+
+import subprocess
+import sys
+import textwrap
+from pathlib import Path
+
+import pytest
+
+OPTIONAL_PACKAGES = [
+    "numpy",
+    "aiosqlite",
+    "asyncpg",
+    "redis",
+    "qdrant_client",
+    "onnxruntime",
+    "tokenizers",
+    "cryptography",
+    "fastapi",
+    "httpx",
+    "grpc",
+    "google",
+    "mcp",
+    "opentelemetry",
+]
+
+CORE_MODULES = [
+    "rae_core.interfaces",
+    "rae_core.models",
+    "rae_core.adapters",
+    "rae_core.adapters.memory",
+    "rae_core.events",
+    "rae_core.audit",
+    "rae_core.templates",
+    "rae_core.rpc",
+    "rae_core.mcp_server",
+    "rae_core.pipelines",
+    "rae_core.embedding.registry",
+    "rae_core.embedding.clip",
+    "rae_core.maintenance",
+    "rae_core.consistency",
+    "rae_core.search.engine",
+    "rae_core.scheduler",
+    "rae_core.sync",
+    "rae_core.tracing",
+    "rae_core.metrics",
+    "rae_core.health",
+    "rae_core.factories.builder",
+    "rae_core.factories.registry",
+    "rae_core.utils.wal",
+]
+
+
+@pytest.mark.parametrize("module", CORE_MODULES)
+def test_core_module_imports_without_extras(module):
+    script = textwrap.dedent(
+        f"""
+        import importlib, importlib.abc, sys
+
+        blocked = set({OPTIONAL_PACKAGES!r})
+
+        class Blocker(importlib.abc.MetaPathFinder):
+            def find_spec(self, name, path, target=None):
+                if name.split(".")[0] in blocked:
+                    raise ImportError(f"optional dependency {{name}} blocked")
+
+        sys.meta_path.insert(0, Blocker())
+        importlib.import_module({module!r})
+        """
+    )
+    root = Path(__file__).resolve().parents[2]
+    result = subprocess.run(
+        [sys.executable, "-c", script],
+        cwd=root,
+        capture_output=True,
+        text=True,
+    )
+    assert result.returncode == 0, result.stderr
@@ -0,0 +1,160 @@
+"""Unit tests for tracing spans and traced backends."""
+
+from contextlib import contextmanager
+from types import SimpleNamespace
+from unittest.mock import AsyncMock, Mock
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.tracing import (
+    NOOP_SPAN,
+    TracedComponent,
+    set_span_attributes,
+    set_tracing_enabled,
+    span,
+)
+from rae_core.tracing import spans as spans_module
+
+
+class FakeSpan:
+    def __init__(self, name, attributes, parent):
+        self.name = name
+        self.attributes = dict(attributes or {})
+        self.parent = parent
+
+    def set_attributes(self, attributes):
+        self.attributes.update(attributes)
+
+
+class FakeTracer:
+    """Records spans with their parent, like the OpenTelemetry SDK would."""
+
+    def __init__(self):
+        self.spans = []
+        self._stack = []
+
+    @contextmanager
+    def start_as_current_span(self, name, attributes=None):
+        parent = self._stack[-1] if self._stack else None
+        current = FakeSpan(name, attributes, parent)
+        self.spans.append(current)
+        self._stack.append(current)
+        try:
+            yield current
+        finally:
+            self._stack.pop()
+
+    def named(self, name):
+        return next(s for s in self.spans if s.name == name)
+
+
+@pytest.fixture
+def tracer(monkeypatch):
+    tracer = FakeTracer()
+    fake_trace = SimpleNamespace(get_tracer=lambda *args: tracer)
+    monkeypatch.setattr(spans_module, "otel_trace", fake_trace)
+    yield tracer
+    set_tracing_enabled(True)
+
+
+class TestSpans:
+    """Test suite for span()."""
+
+    def test_noop_without_opentelemetry(self, monkeypatch):
+        """Test span() yields a no-op span when the API is missing."""
+        monkeypatch.setattr(spans_module, "otel_trace", None)
+
+        with span("rae.recall", tenant_id="t1") as current:
+            current.set_attribute("rae.result_count", 1)
+
+        assert current is NOOP_SPAN
+
+    def test_nesting_and_attributes(self, tracer):
+        """Test spans nest and attributes are prefixed and stringified."""
+        memory_id = uuid4()
+        with span("rae.store_memory", tenant_id="t1", agent_id=None) as outer:
+            with span("rae.embed", memory_id=memory_id):
+                pass
+            set_span_attributes(outer, memory_id=memory_id)
+
+        outer_span = tracer.named("rae.store_memory")
+        inner_span = tracer.named("rae.embed")
+        assert inner_span.parent is outer_span
+        assert outer_span.attributes == {
+            "rae.tenant_id": "t1",
+            "rae.memory_id": str(memory_id),
+        }
+        assert inner_span.attributes == {"rae.memory_id": str(memory_id)}
+
+    def test_disabled(self, tracer):
+        """Test set_tracing_enabled(False) stops span creation."""
+        set_tracing_enabled(False)
+
+        with span("rae.recall") as current:
+            pass
+
+        assert current is NOOP_SPAN
+        assert tracer.spans == []
+
+
+class TestTracedComponent:
+    """Test suite for TracedComponent."""
+
+    @pytest.mark.asyncio
+    async def test_storage_calls(self, tracer):
+        """Test each backend call gets a span with tenant and memory ids."""
+        storage = TracedComponent(InMemoryStorage(), "storage")
+
+        memory_id = await storage.store_memory(content="hello", tenant_id="t1")
+        await storage.get_memory(memory_id, "t1")
+        await storage.list_memories("t1")
+
+        store, get, listed = tracer.spans
+        assert store.name == "storage.store_memory"
+        assert store.attributes["rae.memory_id"] == str(memory_id)
+        assert store.attributes["rae.tenant_id"] == "t1"
+        assert get.attributes["rae.memory_id"] == str(memory_id)
+        assert get.attributes["rae.operation"] == "get_memory"
+        assert listed.attributes["rae.result_count"] == 1
+
+    @pytest.mark.asyncio
+    async def test_sync_attributes_forwarded(self, tracer):
+        """Test non-coroutine attributes are returned unwrapped."""
+        backend = Mock()
+        backend.get_dimension.return_value = 3
+        backend.search_similar = AsyncMock(side_effect=TimeoutError())
+        vectors = TracedComponent(backend, "vector")
+
+        assert vectors.get_dimension() == 3
+        with pytest.raises(TimeoutError):
+            await vectors.search_similar([0.1], tenant_id="t1")
+
+        assert tracer.named("vector.search_similar").attributes[
+            "rae.tenant_id"
+        ] == "t1"
+
+
+@pytest.mark.asyncio
+async def test_engine_store_memory_spans(tracer):
+    """Test store_memory spans nest embedding and vector writes."""
+    pytest.importorskip("numpy")
+    from rae_core.engine import RAEEngine
+
+    memory_id = uuid4()
+    storage = Mock()
+    storage.store_memory = AsyncMock(return_value=memory_id)
+    vector_store = Mock()
+    vector_store.store_vector = AsyncMock()
+    embedder = Mock(spec=["embed_text", "embed_batch", "get_dimension"])
+    embedder.embed_text = AsyncMock(return_value=[0.1, 0.2])
+    engine = RAEEngine(storage, vector_store, embedder)
+
+    await engine.store_memory(tenant_id="t1", agent_id="a1", content="content")
+
+    root = tracer.named("rae.store_memory")
+    assert root.attributes["rae.memory_id"] == str(memory_id)
+    assert root.attributes["rae.tenant_id"] == "t1"
+    assert tracer.named("rae.embed").parent is root
+    assert tracer.named("rae.store_vector").parent is root
@@ -0,0 +1,101 @@
+"""Unit tests for AccessTracker."""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from rae_core.adapters.memory.storage import InMemoryStorage
+from rae_core.utils.access_tracker import AccessTracker
+from rae_core.utils.clock import DeterministicClock
+
+
+class TestAccessTracker:
+    """Test suite for AccessTracker."""
+
+    @pytest.fixture
+    def clock(self):
+        return DeterministicClock(datetime(2026, 1, 1, tzinfo=timezone.utc))
+
+    @pytest.fixture
+    def storage(self, clock):
+        return InMemoryStorage(clock=clock)
+
+    @pytest.mark.asyncio
+    async def test_accesses_are_written_on_flush(self, storage, clock):
+        """Test accesses stay buffered until flushed, then update counters."""
+        hot = await storage.store_memory(content="hot", tenant_id="t1")
+        cold = await storage.store_memory(content="cold", tenant_id="t1")
+        tracker = AccessTracker(storage, flush_interval_ms=10_000, clock=clock)
+
+        clock.set_time(clock.now() + timedelta(minutes=5))
+        await tracker.record([hot, cold], "t1")
+        await tracker.record([str(hot)], "t1")
+        assert tracker.pending == 3
+        assert (await storage.get_memory(hot, "t1"))["access_count"] == 0
+
+        await tracker.flush()
+
+        hot_memory = await storage.get_memory(hot, "t1")
+        assert hot_memory["access_count"] == 2
+        assert hot_memory["last_accessed_at"] == clock.now()
+        assert hot_memory["version"] == 1
+        assert (await storage.get_memory(cold, "t1"))["access_count"] == 1
+        assert tracker.pending == 0
+
+    @pytest.mark.asyncio
+    async def test_one_write_per_tenant(self, clock):
+        """Test a batch is written with one record_access call per tenant."""
+        storage = AsyncMock()
+        a, b, c = uuid4(), uuid4(), uuid4()
+        tracker = AccessTracker(storage, flush_interval_ms=10_000, clock=clock)
+
+        await tracker.record([a, b], "t1")
+        clock.set_time(clock.now() + timedelta(seconds=30))
+        await tracker.record([c, a], "t2")
+        await tracker.record(["not-a-memory-id"], "t2")
+        await tracker.flush()
+
+        assert storage.record_access.await_count == 2
+        storage.record_access.assert_any_await(
+            [a, b], "t1", accessed_at=clock.now() - timedelta(seconds=30)
+        )
+        storage.record_access.assert_any_await([c, a], "t2", accessed_at=clock.now())
+
+    @pytest.mark.asyncio
+    async def test_interval_flushes_in_background(self, storage):
+        """Test buffered accesses are written after the flush interval."""
+        memory_id = await storage.store_memory(content="m", tenant_id="t1")
+        tracker = AccessTracker(storage, flush_interval_ms=10)
+
+        await tracker.record([memory_id], "t1")
+        await asyncio.sleep(0.05)
+
+        assert (await storage.get_memory(memory_id, "t1"))["access_count"] == 1
+
+    @pytest.mark.asyncio
+    async def test_record_does_not_wait_for_the_tenant_lock(self, storage):
+        """Test recording an access returns while the tenant's lock is held."""
+        memory_id = await storage.store_memory(content="m", tenant_id="t1")
+        tracker = AccessTracker(storage, flush_interval_ms=10_000)
+
+        async with storage._locks.hold("t1"):
+            await asyncio.wait_for(tracker.record([memory_id], "t1"), timeout=1)
+
+        await tracker.flush()
+        assert (await storage.get_memory(memory_id, "t1"))["access_count"] == 1
+
+    @pytest.mark.asyncio
+    async def test_failed_write_is_raised_by_flush(self):
+        """Test a failing write does not fail record() but is raised later."""
+        storage = AsyncMock()
+        storage.record_access.side_effect = RuntimeError("backend down")
+        tracker = AccessTracker(storage, flush_interval_ms=10_000, max_pending=1)
+
+        await tracker.record([uuid4()], "t1")
+
+        with pytest.raises(RuntimeError):
+            await tracker.flush()
+        await tracker.flush()
@@ -0,0 +1,37 @@
+"""Unit tests for field-level change records."""
+
+from rae_core.utils.changelog import (
+    INLINE_TEXT_LIMIT,
+    apply_text_edits,
+    diff_text,
+    field_changes,
+)
+
+
+class TestChangelog:
+    """Test suite for change computation."""
+
+    def test_only_changed_fields_recorded(self):
+        """Test unchanged values are skipped and short values kept inline."""
+        before = {"content": "a", "importance": 0.5, "tags": ["x"]}
+        changes = field_changes(before, {"importance": 0.5, "tags": ["x", "y"]})
+
+        assert changes == [
+            {"field": "tags", "old_value": ["x"], "new_value": ["x", "y"]}
+        ]
+
+    def test_long_text_stored_as_edits(self):
+        """Test large documents are recorded as edits, not full copies."""
+        old = "lorem ipsum " * (INLINE_TEXT_LIMIT // 6)
+        new = old.replace("ipsum", "dolor", 1) + "tail"
+        (change,) = field_changes({"content": old}, {"content": new})
+
+        assert "old_value" not in change
+        assert len(change["edits"]) == 2
+        assert apply_text_edits(old, change["edits"]) == new
+
+    def test_diff_roundtrip(self):
+        """Test edits reproduce inserts, deletes and replacements."""
+        old, new = "The quick brown fox", "A quick red fox jumps"
+        assert apply_text_edits(old, diff_text(old, new)) == new
+        assert diff_text(old, old) == []
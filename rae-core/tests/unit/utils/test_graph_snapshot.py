@@ -0,0 +1,64 @@
+"""Unit tests for GraphSnapshot."""
+
+from uuid import uuid4
+
+import pytest
+
+from rae_core.utils.graph_snapshot import GraphSnapshot
+
+
+class TestGraphSnapshot:
+    """Test suite for GraphSnapshot."""
+
+    @pytest.fixture
+    def chain(self):
+        nodes = [uuid4() for _ in range(4)]
+        edges = [(nodes[i], nodes[i + 1], 1.0) for i in range(3)]
+        return nodes, GraphSnapshot.from_edges(edges)
+
+    def test_counts_and_isolated_nodes(self, chain):
+        """Test explicit nodes are kept even without edges."""
+        nodes, _ = chain
+        lonely = uuid4()
+        snapshot = GraphSnapshot.from_edges([(nodes[0], nodes[1], 1.0)], [lonely])
+        assert snapshot.node_count == 3
+        assert snapshot.edge_count == 1
+        assert snapshot.neighbors(lonely) == []
+
+    def test_neighbors_by_direction(self, chain):
+        """Test out, in and undirected neighbors."""
+        nodes, snapshot = chain
+        assert snapshot.neighbors(nodes[1], "out") == [nodes[2]]
+        assert snapshot.neighbors(nodes[1], "in") == [nodes[0]]
+        assert set(snapshot.neighbors(nodes[1])) == {nodes[0], nodes[2]}
+        assert snapshot.neighbors(uuid4()) == []
+
+    def test_shortest_path(self, chain):
+        """Test undirected shortest paths respect max_depth."""
+        nodes, snapshot = chain
+        assert snapshot.shortest_path(nodes[3], nodes[0]) == nodes[::-1]
+        assert snapshot.shortest_path(nodes[0], nodes[3], max_depth=2) is None
+        assert snapshot.shortest_path(nodes[0], uuid4()) is None
+
+    def test_pagerank_ranks_hub_highest(self):
+        """Test PageRank sums to 1 and favors the most linked node."""
+        hub = uuid4()
+        leaves = [uuid4() for _ in range(5)]
+        edges = [(leaf, hub, 1.0) for leaf in leaves]
+        edges.append((hub, leaves[0], 1.0))
+        ranks = GraphSnapshot.from_edges(edges).pagerank()
+
+        assert sum(ranks.values()) == pytest.approx(1.0)
+        assert max(ranks, key=ranks.get) == hub
+
+    def test_communities_split_cliques(self):
+        """Test label propagation separates loosely joined cliques."""
+        left = [uuid4() for _ in range(4)]
+        right = [uuid4() for _ in range(3)]
+        edges = []
+        for group in (left, right):
+            edges += [(a, b, 1.0) for a in group for b in group if a != b]
+        edges.append((left[0], right[0], 0.1))
+        communities = GraphSnapshot.from_edges(edges).communities()
+
+        assert communities == [set(left), set(right)]
@@ -0,0 +1,93 @@
+"""Unit tests for GroupCommitter."""
+
+import asyncio
+
+import pytest
+
+from rae_core.utils.group_commit import CommitDurability, GroupCommitter
+
+
+class TestGroupCommitter:
+    """Test suite for GroupCommitter."""
+
+    @pytest.fixture
+    def batches(self):
+        return []
+
+    @pytest.fixture
+    def flush(self, batches):
+        async def flush(items):
+            batches.append(items)
+
+        return flush
+
+    @pytest.mark.asyncio
+    async def test_concurrent_submits_share_one_flush(self, flush, batches):
+        """Test writes arriving within the delay are flushed together."""
+        committer = GroupCommitter(flush, max_delay_ms=20)
+
+        await asyncio.gather(*(committer.submit(i) for i in range(5)))
+
+        assert batches == [[0, 1, 2, 3, 4]]
+        assert committer.pending == 0
+
+    @pytest.mark.asyncio
+    async def test_full_batch_flushes_immediately(self, flush, batches):
+        """Test max_batch items are flushed without waiting for the delay."""
+        committer = GroupCommitter(flush, max_delay_ms=10_000, max_batch=2)
+
+        await asyncio.wait_for(
+            asyncio.gather(*(committer.submit(i) for i in range(4))), timeout=1
+        )
+
+        assert batches == [[0, 1], [2, 3]]
+
+    @pytest.mark.asyncio
+    async def test_async_durability_returns_before_commit(self, flush, batches):
+        """Test ASYNC submits return at once and flush() commits them."""
+        committer = GroupCommitter(
+            flush, max_delay_ms=10_000, durability=CommitDurability.ASYNC
+        )
+
+        await committer.submit("a")
+        await committer.submit("b")
+        assert batches == []
+        assert committer.pending == 2
+
+        await committer.flush()
+        assert batches == [["a", "b"]]
+
+    @pytest.mark.asyncio
+    async def test_sync_error_reaches_every_writer(self):
+        """Test a failed flush raises in all writers of the batch."""
+
+        async def failing(items):
+            raise RuntimeError("disk full")
+
+        committer = GroupCommitter(failing, max_delay_ms=5)
+
+        results = await asyncio.gather(
+            committer.submit(1), committer.submit(2), return_exceptions=True
+        )
+
+        assert [str(r) for r in results] == ["disk full", "disk full"]
+
+    @pytest.mark.asyncio
+    async def test_async_error_is_raised_by_flush(self):
+        """Test an ASYNC flush error is deferred to the next flush()."""
+
+        async def failing(items):
+            raise RuntimeError("disk full")
+
+        committer = GroupCommitter(failing, max_delay_ms=5, durability="async")
+        await committer.submit(1)
+
+        with pytest.raises(RuntimeError, match="disk full"):
+            await committer.flush()
+        # The error is reported once
+        await committer.flush()
+
+    def test_invalid_max_batch(self, flush):
+        """Test max_batch must be positive."""
+        with pytest.raises(ValueError):
+            GroupCommitter(flush, max_batch=0)
@@ -0,0 +1,80 @@
+"""Unit tests for per-tenant lock shards."""
+
+import asyncio
+
+import pytest
+
+from rae_core.utils.locks import TenantLocks
+
+
+def _tenants_on_distinct_shards(locks):
+    first = "tenant-0"
+    for i in range(1, 1000):
+        other = f"tenant-{i}"
+        if locks.shard(other) is not locks.shard(first):
+            return first, other
+    raise AssertionError("no two shards found")
+
+
+class TestTenantLocks:
+    """Test suite for TenantLocks."""
+
+    def test_shard_is_stable(self):
+        """Test a tenant always maps to the same shard."""
+        locks = TenantLocks(shards=8)
+
+        assert len(locks) == 8
+        assert locks.shard("t1") is locks.shard("t1")
+        with pytest.raises(ValueError):
+            TenantLocks(shards=0)
+
+    @pytest.mark.asyncio
+    async def test_tenants_do_not_wait_on_each_other(self):
+        """Test holding one tenant's shard leaves other shards free."""
+        locks = TenantLocks(shards=8)
+        first, other = _tenants_on_distinct_shards(locks)
+
+        async with locks.hold(first):
+            assert locks.locked(first)
+            assert not locks.locked(other)
+            async with locks.hold(other):
+                assert locks.locked(other)
+
+    @pytest.mark.asyncio
+    async def test_all_waits_for_every_shard(self):
+        """Test all() is only acquired once no tenant holds a shard."""
+        locks = TenantLocks(shards=4)
+        release = asyncio.Event()
+        order = []
+
+        async def tenant_op():
+            async with locks.hold("t1"):
+                await release.wait()
+                order.append("tenant")
+
+        async def global_op():
+            async with locks.hold(None):
+                order.append("all")
+                assert all(locks.locked(f"t{i}") for i in range(10))
+
+        task = asyncio.create_task(tenant_op())
+        await asyncio.sleep(0)
+        other = asyncio.create_task(global_op())
+        await asyncio.sleep(0)
+        assert order == []
+
+        release.set()
+        await asyncio.gather(task, other)
+        assert order == ["tenant", "all"]
+        assert not locks.locked("t1")
+
+    @pytest.mark.asyncio
+    async def test_released_on_error(self):
+        """Test a failing operation does not leave its shard locked."""
+        locks = TenantLocks(shards=2)
+
+        with pytest.raises(RuntimeError):
+            async with locks.hold("t1"):
+                raise RuntimeError("boom")
+
+        assert not locks.locked("t1")
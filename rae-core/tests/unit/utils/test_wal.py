@@ -0,0 +1,99 @@
+"""Unit tests for WriteAheadLog."""
+
+from datetime import datetime, timezone
+from unittest.mock import patch
+from uuid import uuid4
+
+import pytest
+
+from rae_core.exceptions.base import StorageError
+from rae_core.utils.wal import FsyncPolicy, WriteAheadLog
+
+
+class TestWriteAheadLog:
+    """Test suite for WriteAheadLog."""
+
+    @pytest.fixture
+    def path(self, tmp_path):
+        return str(tmp_path / "state.wal")
+
+    def test_roundtrip_preserves_types(self, path):
+        """Test UUID, datetime and bytes survive a replay."""
+        record = {
+            "op": "memory",
+            "id": uuid4(),
+            "at": datetime(2025, 1, 1, tzinfo=timezone.utc),
+            "data": b"\x00\x01",
+            "tags": ["a"],
+        }
+        wal = WriteAheadLog(path)
+        wal.append(record)
+        wal.close()
+
+        replayed = list(WriteAheadLog(path).replay())
+        assert replayed == [record]
+
+    def test_torn_tail_is_dropped(self, path):
+        """Test a partial last line is ignored and truncated."""
+        wal = WriteAheadLog(path)
+        wal.append({"op": "a"})
+        wal.close()
+        with open(path, "a", encoding="utf-8") as f:
+            f.write('1234abcd {"op": "b"')
+
+        reopened = WriteAheadLog(path)
+        assert list(reopened.replay()) == [{"op": "a"}]
+        reopened.append({"op": "c"})
+        reopened.close()
+
+        assert list(WriteAheadLog(path).replay()) == [{"op": "a"}, {"op": "c"}]
+
+    def test_corruption_in_middle_raises(self, path):
+        """Test checksum failure before the tail is fatal."""
+        wal = WriteAheadLog(path)
+        wal.append({"op": "a"})
+        wal.append({"op": "b"})
+        wal.close()
+        with open(path, encoding="utf-8") as f:
+            lines = f.readlines()
+        lines[0] = lines[0].replace('"a"', '"x"')
+        with open(path, "w", encoding="utf-8") as f:
+            f.writelines(lines)
+
+        with pytest.raises(StorageError):
+            list(WriteAheadLog(path).replay())
+
+    def test_batch_fsync_policy(self, path):
+        """Test fsync is issued once per batch."""
+        wal = WriteAheadLog(path, fsync_policy=FsyncPolicy.BATCH, fsync_every=3)
+        with patch("rae_core.utils.wal.os.fsync") as fsync:
+            for i in range(7):
+                wal.append({"op": i})
+            assert fsync.call_count == 2
+        wal.close()
+
+    def test_never_fsync_policy(self, path):
+        """Test the never policy leaves flushing to the OS."""
+        wal = WriteAheadLog(path, fsync_policy="never")
+        with patch("rae_core.utils.wal.os.fsync") as fsync:
+            wal.append({"op": 1})
+            wal.close()
+            assert fsync.call_count == 0
+
+    def test_compact_replaces_log(self, path):
+        """Test compaction rewrites the log and resets growth."""
+        wal = WriteAheadLog(path)
+        for i in range(5):
+            wal.append({"op": i})
+        assert wal.growth == 5
+
+        wal.compact([{"op": "snapshot"}])
+        assert wal.growth == 0
+        assert wal.record_count == 1
+        wal.append({"op": "after"})
+        wal.close()
+
+        assert list(WriteAheadLog(path).replay()) == [
+            {"op": "snapshot"},
+            {"op": "after"},
+        ]